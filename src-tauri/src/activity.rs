@@ -0,0 +1,224 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::database::get_database;
+use crate::database::repositories::TimeTrackingRepository;
+
+/// Emitted with an `IdleSegment` when the user goes idle while a session runs
+pub const IDLE_STARTED_EVENT: &str = "idle-started";
+
+/// Emitted with the closed `IdleSegment` when the user is back, to ask whether to keep the time
+pub const IDLE_ENDED_EVENT: &str = "idle-ended";
+
+/// How often the OS idle time is read
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Idle time after which a running session counts as idle; zero turns detection off
+#[derive(Default)]
+pub struct IdleSettings {
+    threshold_secs: AtomicU64,
+}
+
+impl IdleSettings {
+    pub fn set_threshold(&self, threshold: Duration) {
+        self.threshold_secs
+            .store(threshold.as_secs(), Ordering::Relaxed);
+    }
+
+    fn threshold(&self) -> Option<Duration> {
+        match self.threshold_secs.load(Ordering::Relaxed) {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+}
+
+/// Time the user was away while a session was running
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdleSegment {
+    pub session_id: String,
+    /// Last input before going idle
+    pub started_at: DateTime<Utc>,
+    /// First input after coming back; unset while still idle
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum IdleTransition {
+    Started(IdleSegment),
+    Ended(IdleSegment),
+}
+
+/// Turns idle time readings into idle segments
+#[derive(Debug, Default)]
+pub(crate) struct IdleTracker {
+    open: Option<IdleSegment>,
+}
+
+impl IdleTracker {
+    /// Feed one reading of the OS idle time
+    ///
+    /// A segment opens when the idle time reaches `threshold` while `running_session` is set,
+    /// and closes at the next reading below the threshold, whatever happened to the session.
+    pub fn observe(
+        &mut self,
+        idle: Duration,
+        threshold: Duration,
+        now: DateTime<Utc>,
+        running_session: Option<&str>,
+    ) -> Option<IdleTransition> {
+        let last_input = now - chrono::Duration::from_std(idle).ok()?;
+        match self.open.take() {
+            None => {
+                let session_id = running_session.filter(|_| idle >= threshold)?;
+                let segment = IdleSegment {
+                    session_id: session_id.to_string(),
+                    started_at: last_input,
+                    ended_at: None,
+                };
+                self.open = Some(segment.clone());
+                Some(IdleTransition::Started(segment))
+            }
+            Some(segment) if idle >= threshold => {
+                self.open = Some(segment);
+                None
+            }
+            Some(mut segment) => {
+                segment.ended_at = Some(last_input.max(segment.started_at));
+                Some(IdleTransition::Ended(segment))
+            }
+        }
+    }
+}
+
+/// Start the background loop that watches for the user going idle during a session
+///
+/// Nothing is read until the frontend sets a threshold with `IdleSettings`; platforms whose
+/// idle time can't be read never report idle time.
+pub fn start_idle_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut tracker = IdleTracker::default();
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            let Some(threshold) = app.state::<IdleSettings>().threshold() else {
+                continue;
+            };
+            let Some(idle) = idle_time() else {
+                continue;
+            };
+
+            let running_session = match running_session_id().await {
+                Ok(session) => session,
+                Err(e) => {
+                    eprintln!("Failed to look up the running session: {}", e);
+                    continue;
+                }
+            };
+            let transition =
+                tracker.observe(idle, threshold, Utc::now(), running_session.as_deref());
+            let result = match transition {
+                Some(IdleTransition::Started(segment)) => app.emit(IDLE_STARTED_EVENT, segment),
+                Some(IdleTransition::Ended(segment)) => app.emit(IDLE_ENDED_EVENT, segment),
+                None => Ok(()),
+            };
+            if let Err(e) = result {
+                eprintln!("Failed to emit idle event: {}", e);
+            }
+        }
+    });
+}
+
+async fn running_session_id() -> Result<Option<String>, sea_orm::DbErr> {
+    let db = get_database().await?;
+    let session = TimeTrackingRepository::new(db)
+        .find_any_active_session()
+        .await?;
+    Ok(session.map(|session| session.id))
+}
+
+/// Time since the last keyboard or mouse input, if the platform tells
+#[cfg(windows)]
+pub fn idle_time() -> Option<Duration> {
+    use windows_sys::Win32::System::SystemInformation::GetTickCount;
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    // SAFETY: `info` is a valid LASTINPUTINFO with `cbSize` set, as the call requires
+    if unsafe { GetLastInputInfo(&mut info) } == 0 {
+        return None;
+    }
+    // SAFETY: GetTickCount has no preconditions
+    let now = unsafe { GetTickCount() };
+    Some(Duration::from_millis(now.wrapping_sub(info.dwTime) as u64))
+}
+
+/// Time since the last keyboard or mouse input, read from the HID system's `HIDIdleTime`
+#[cfg(target_os = "macos")]
+pub fn idle_time() -> Option<Duration> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-c", "IOHIDSystem", "-d", "4"])
+        .output()
+        .ok()?;
+    let output = String::from_utf8_lossy(&output.stdout);
+    let line = output
+        .lines()
+        .find(|line| line.contains("\"HIDIdleTime\""))?;
+    let nanos: u64 = line.rsplit('=').next()?.trim().parse().ok()?;
+    Some(Duration::from_nanos(nanos))
+}
+
+/// Time since the last keyboard or mouse input
+///
+/// X11 sessions answer through `xprintidle`, GNOME (including Wayland) through Mutter's idle
+/// monitor; other desktops report nothing.
+#[cfg(target_os = "linux")]
+pub fn idle_time() -> Option<Duration> {
+    use std::process::Command;
+
+    let xprintidle = Command::new("xprintidle")
+        .output()
+        .ok()
+        .filter(|output| output.status.success());
+    if let Some(output) = xprintidle {
+        let millis: u64 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .ok()?;
+        return Some(Duration::from_millis(millis));
+    }
+
+    let output = Command::new("gdbus")
+        .args([
+            "call",
+            "--session",
+            "--dest",
+            "org.gnome.Mutter.IdleMonitor",
+            "--object-path",
+            "/org/gnome/Mutter/IdleMonitor/Core",
+            "--method",
+            "org.gnome.Mutter.IdleMonitor.GetIdletime",
+        ])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    // The reply looks like `(uint64 12345,)`
+    let millis: u64 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .strip_prefix("(uint64 ")?
+        .trim_end_matches(",)")
+        .parse()
+        .ok()?;
+    Some(Duration::from_millis(millis))
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "linux")))]
+pub fn idle_time() -> Option<Duration> {
+    None
+}
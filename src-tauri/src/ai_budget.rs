@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Utc};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+
+use crate::database::repositories::ai_repository::ModelCost;
+use crate::database::repositories::AiRepository;
+use crate::notifications::{Delivery, NotificationDispatcher};
+
+/// Emitted with an `AiBudgetStatus` the first time a month's spend crosses a threshold
+pub const AI_BUDGET_WARNING_EVENT: &str = "ai-budget-warning";
+
+/// Percentages of the monthly budget that trigger a warning
+const THRESHOLDS: [u32; 2] = [100, 80];
+
+/// Estimated spend on remote AI providers this month against the monthly budget
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiBudgetStatus {
+    /// `YYYY-MM`, local time
+    pub month: String,
+    pub budget: f64,
+    pub spent: f64,
+    /// Highest threshold reached (80 or 100), if any
+    pub threshold: Option<u32>,
+}
+
+/// Periods a cost report can cover, each the current one in local time
+pub const COST_REPORT_PERIODS: [&str; 3] = ["day", "week", "month"];
+
+/// Estimated spend on AI providers over the current day, week or month
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiCostReport {
+    pub period: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub total_cost: f64, // US dollars
+    pub request_count: u64,
+    pub by_model: Vec<ModelCost>,
+}
+
+/// Highest warning threshold `spent` has reached
+pub(crate) fn reached_threshold(spent: f64, budget: f64) -> Option<u32> {
+    THRESHOLDS
+        .into_iter()
+        .find(|&percent| spent >= budget * percent as f64 / 100.0)
+}
+
+/// Compare this month's estimated spend with `budget` (US dollars)
+///
+/// Reaching 80% or 100% for the first time in a month shows a notification and emits
+/// `AI_BUDGET_WARNING_EVENT`.
+pub async fn check_budget(
+    app: &AppHandle,
+    db: Arc<DatabaseConnection>,
+    budget: f64,
+) -> Result<AiBudgetStatus> {
+    if !budget.is_finite() || budget <= 0.0 {
+        anyhow::bail!("The monthly budget must be a positive amount");
+    }
+
+    let (month_start, next_month) = period_days("month", Local::now().date_naive())?;
+    let start = local_midnight(month_start)?;
+    let end = local_midnight(next_month)?;
+    let spent = AiRepository::new(db.clone())
+        .get_estimated_spend(start, end)
+        .await?;
+
+    let status = AiBudgetStatus {
+        month: month_start.format("%Y-%m").to_string(),
+        budget,
+        spent,
+        threshold: reached_threshold(spent, budget),
+    };
+    if let Some(threshold) = status.threshold {
+        let delivery = NotificationDispatcher::new(app, db)
+            .send(
+                "ai_budget",
+                format!("ai_budget:{}:{}", status.month, threshold),
+                "AI budget",
+                format!(
+                    "Estimated AI spend this month is ${:.2}, {}% of your ${:.2} budget",
+                    spent,
+                    (spent / budget * 100.0).floor(),
+                    budget
+                ),
+            )
+            .await?;
+        if delivery == Delivery::Shown {
+            if let Err(e) = app.emit(AI_BUDGET_WARNING_EVENT, status.clone()) {
+                eprintln!("Failed to emit {} event: {}", AI_BUDGET_WARNING_EVENT, e);
+            }
+        }
+    }
+    Ok(status)
+}
+
+/// Estimated spend per provider and model over the current `period` ("day", "week"
+/// starting Monday, or "month")
+pub async fn cost_report(db: Arc<DatabaseConnection>, period: &str) -> Result<AiCostReport> {
+    let (first_day, next_day) = period_days(period, Local::now().date_naive())?;
+    let start = local_midnight(first_day)?;
+    let end = local_midnight(next_day)?;
+    let by_model = AiRepository::new(db).get_cost_breakdown(start, end).await?;
+
+    Ok(AiCostReport {
+        period: period.to_string(),
+        start,
+        end,
+        total_cost: by_model.iter().map(|model| model.cost).sum(),
+        request_count: by_model.iter().map(|model| model.request_count).sum(),
+        by_model,
+    })
+}
+
+/// First day of the `period` containing `today`, and the first day after it
+pub(crate) fn period_days(period: &str, today: NaiveDate) -> Result<(NaiveDate, NaiveDate)> {
+    match period {
+        "day" => Ok((today, today + chrono::Duration::days(1))),
+        "week" => {
+            let monday =
+                today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+            Ok((monday, monday + chrono::Duration::weeks(1)))
+        }
+        "month" => {
+            let first_day =
+                NaiveDate::from_ymd_opt(today.year(), today.month(), 1).context("Invalid month")?;
+            let next_month = first_day
+                .checked_add_months(chrono::Months::new(1))
+                .context("Invalid month")?;
+            Ok((first_day, next_month))
+        }
+        _ => anyhow::bail!(
+            "Unknown period '{}', expected one of: {}",
+            period,
+            COST_REPORT_PERIODS.join(", ")
+        ),
+    }
+}
+
+fn local_midnight(date: NaiveDate) -> Result<DateTime<Utc>> {
+    Ok(Local
+        .from_local_datetime(&date.and_time(chrono::NaiveTime::MIN))
+        .earliest()
+        .context("Local midnight does not exist")?
+        .with_timezone(&Utc))
+}
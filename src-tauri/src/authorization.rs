@@ -0,0 +1,246 @@
+use sea_orm::{DatabaseConnection, DbErr};
+use std::sync::Arc;
+
+use crate::database::entities::api_tokens;
+use crate::database::repositories::{api_token_repository::token_scopes, ApiTokenRepository};
+
+/// Mutating commands open to integrations, grouped by the scope they need
+///
+/// Checked before the read prefixes, so commands that read a file from any path the caller
+/// names (import previews, bundle inspection) need `data:transfer` rather than `read`.
+const COMMAND_SCOPES: [(&str, &[&str]); 6] = [
+    (
+        "tasks:write",
+        &[
+            "create_task",
+            "quick_capture",
+            "update_task",
+            "delete_task",
+            "create_subtask",
+            "reparent_task",
+            "archive_task",
+            "unarchive_task",
+            "reorder_tasks",
+            "reorder_task",
+            "add_task_actual_time",
+            "add_task_dependency",
+            "remove_task_dependency",
+            "add_task_relation",
+            "remove_task_relation",
+            "set_task_custom_field_value",
+            "set_task_custom_field_values",
+            "rename_tag",
+            "merge_tags",
+            "save_task_as_template",
+            "instantiate_task_template",
+            "generate_pending_instances",
+            "generate_instance_from_template",
+            "check_and_generate_instances",
+            "groom_backlog",
+            "set_task_reminders",
+            "snooze_reminder",
+            "remind_again_in",
+            "delete_task_reminder",
+            "create_checklist_item",
+            "update_checklist_item",
+            "reorder_checklist_item",
+            "delete_checklist_item",
+            "remove_task_attachment",
+            "clean_up_attachments",
+            "add_task_note",
+            "update_task_note",
+            "delete_task_note",
+            "undo_last_change",
+            "redo",
+            "save_daily_note",
+            "delete_daily_note",
+            "link_task_to_github_issue",
+            "unlink_task_from_github_issue",
+            "set_task_source",
+        ],
+    ),
+    (
+        "lists:write",
+        &[
+            "create_task_list",
+            "update_task_list",
+            "reorder_task_list",
+            "reorder_task_lists",
+            "archive_task_list",
+            "unarchive_task_list",
+            "delete_task_list",
+            "move_task_to_list",
+            "set_task_list_targets",
+            "set_task_list_hourly_rate",
+            "create_project",
+            "update_project",
+            "delete_project",
+        ],
+    ),
+    (
+        "time:write",
+        &[
+            "create_time_session",
+            "log_time_session",
+            "add_manual_time_entry",
+            "adjust_time_session",
+            "split_time_session",
+            "merge_time_sessions",
+            "update_time_session",
+            "stop_time_session",
+            "pause_time_session",
+            "start_break",
+            "end_break",
+            "discard_idle_time",
+            "resume_time_session",
+            "delete_time_session",
+            "complete_subtask_during_session",
+            "start_focus_session",
+            "record_focus_distraction",
+            "add_focus_break",
+            "complete_focus_session",
+            "delete_focus_session",
+            "record_productivity_pattern",
+            "derive_productivity_patterns",
+            "delete_productivity_pattern",
+            "start_focus_from_template",
+            "start_pomodoro",
+            "skip_break",
+            "stop_pomodoro",
+        ],
+    ),
+    (
+        "settings:write",
+        &[
+            "create_task_status",
+            "update_task_status",
+            "delete_task_status",
+            "create_custom_field",
+            "update_custom_field",
+            "delete_custom_field",
+            "create_periodic_task_template",
+            "update_periodic_task_template",
+            "delete_periodic_task_template",
+            "pause_periodic_template",
+            "resume_periodic_template",
+            "skip_next_occurrence",
+            "create_task_template",
+            "update_task_template",
+            "delete_task_template",
+            "create_saved_filter",
+            "update_saved_filter",
+            "delete_saved_filter",
+            "create_focus_template",
+            "update_focus_template",
+            "delete_focus_template",
+            "save_working_hours",
+            "save_rollover_mode",
+            "create_goal",
+            "delete_goal",
+        ],
+    ),
+    (
+        "threads:write",
+        &[
+            "create_thread",
+            "create_thread_with_similar",
+            "update_thread",
+            "update_thread_summary",
+            "delete_thread",
+            "create_thread_message",
+            "update_thread_message",
+            "delete_thread_message",
+            "set_thread_persona",
+        ],
+    ),
+    (
+        "data:transfer",
+        &[
+            "export_data_to_file",
+            "export_conversation_dataset",
+            "export_calendar",
+            "export_billing_report",
+            "export_timesheet",
+            "sync_caldav",
+            "reset_caldav_sync",
+            "import_github_issues",
+            "refresh_github_issues",
+            "export_task_bundle",
+            "export_task_list_bundle",
+            "import_task_bundle",
+            "import_tasks",
+            "import_time_entries",
+            "import_todoist",
+            "export_template_pack",
+            "import_template_pack",
+            "export_periodic_templates",
+            "import_periodic_templates",
+            "validate_backup_file",
+            "validate_backup_comprehensive",
+            "inspect_task_bundle",
+            "inspect_template_pack",
+            "inspect_settings_bundle",
+            "preview_task_import",
+            "preview_time_import",
+            "preview_todoist_import",
+        ],
+    ),
+];
+
+/// Scope a token needs to run `command`
+///
+/// Commands that are not listed require `admin`, so new commands are denied to integrations
+/// until they are classified here.
+pub fn required_scope(command: &str) -> &'static str {
+    const READ_PREFIXES: [&str; 11] = [
+        "get_",
+        "find_",
+        "search_",
+        "filter_",
+        "validate_",
+        "inspect_",
+        "count_",
+        "calculate_",
+        "preview_",
+        "parse_",
+        "list_",
+    ];
+    if let Some((scope, _)) = COMMAND_SCOPES
+        .iter()
+        .find(|(_, commands)| commands.contains(&command))
+    {
+        return *scope;
+    }
+    if READ_PREFIXES
+        .iter()
+        .any(|prefix| command.starts_with(prefix))
+    {
+        return "read";
+    }
+    "admin"
+}
+
+/// Check that the integration presenting `secret` (REST API, MCP server, CLI) may run `command`
+///
+/// The desktop UI acts for the local user and is not checked. Returns the authenticated token.
+pub async fn authorize(
+    db: Arc<DatabaseConnection>,
+    secret: &str,
+    command: &str,
+) -> Result<api_tokens::Model, DbErr> {
+    let token = ApiTokenRepository::new(db).authenticate(secret).await?;
+    let scope = required_scope(command);
+    let scopes = token_scopes(&token);
+
+    if scopes
+        .iter()
+        .any(|granted| granted == scope || granted == "admin")
+    {
+        Ok(token)
+    } else {
+        Err(DbErr::Custom(format!(
+            "FORBIDDEN_ERROR: Token '{}' lacks the '{}' scope required by '{}'",
+            token.name, scope, command
+        )))
+    }
+}
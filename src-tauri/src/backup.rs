@@ -1,15 +1,26 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use sea_orm::DatabaseConnection;
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{Read, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
 
-use crate::database::repositories::{AiRepository, PeriodicTaskRepository, TaskRepository, TimeTrackingRepository};
+use crate::database::config::DatabaseConfig;
+use crate::database::migration::initialization::{
+    validate_database_integrity, DatabaseIntegrityReport,
+};
+use crate::database::migration::run_migrations;
+use crate::database::repositories::{
+    AiRepository, AttachmentRepository, ChecklistRepository, CustomFieldRepository,
+    DailyNoteRepository, PeriodicTaskRepository, ReminderRepository, TagRepository,
+    TaskListRepository, TaskNoteRepository, TaskRepository, TaskStatusRepository,
+    TimeTrackingRepository,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BackupValidationResult {
@@ -28,6 +39,30 @@ pub struct BackupData {
     pub ai_interactions: Vec<serde_json::Value>,
     pub task_dependencies: Vec<serde_json::Value>,
     pub periodic_task_templates: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub custom_fields: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub custom_field_values: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub task_statuses: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub checklist_items: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub daily_notes: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub attachments: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub task_notes: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub task_reminders: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub task_status_history: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub session_subtask_completions: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub react_chains: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub react_steps: Vec<serde_json::Value>,
     pub settings: HashMap<String, serde_json::Value>,
 }
 
@@ -42,13 +77,71 @@ pub struct BackupMetadata {
     pub periodic_template_count: usize,
 }
 
+/// Rows of one table after a restore rehearsal
+#[derive(Debug, Serialize)]
+pub struct RestoredTableCount {
+    pub table: String,
+    /// Records of the table in the backup
+    pub expected: usize,
+    /// Rows in the scratch database after the restore
+    pub restored: u64,
+}
+
+/// Result of restoring a backup into a scratch database
+#[derive(Debug, Serialize)]
+pub struct RestoreRehearsalReport {
+    /// The restore succeeded, every table got all its rows and every check passed
+    pub restorable: bool,
+    pub metadata: BackupMetadata,
+    pub tables: Vec<RestoredTableCount>,
+    pub integrity: Option<DatabaseIntegrityReport>,
+    /// Problems found by SQLite's integrity and foreign key checks
+    pub database_issues: Vec<String>,
+    /// Attachment files the backup references but doesn't contain
+    pub missing_attachment_files: Vec<String>,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Receives the share of work done (0-1) and the current step
+type ProgressCallback = Box<dyn Fn(f64, &str) + Send + Sync>;
+
+/// Folder inside the backup archive holding the attachment files
+const ATTACHMENTS_ARCHIVE_DIR: &str = "attachments";
+
 pub struct BackupService {
     db: Arc<DatabaseConnection>,
+    progress: Option<ProgressCallback>,
+    attachments_dir: Option<PathBuf>,
 }
 
 impl BackupService {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self { db }
+        Self {
+            db,
+            progress: None,
+            attachments_dir: None,
+        }
+    }
+
+    /// Include the attachment files stored in `attachments_dir` in exports and imports
+    ///
+    /// Without it only attachment metadata is backed up.
+    pub fn with_attachments_dir(mut self, attachments_dir: PathBuf) -> Self {
+        self.attachments_dir = Some(attachments_dir);
+        self
+    }
+
+    /// Report progress of exports and imports (0-1 and the current step) to `progress`
+    pub fn with_progress(mut self, progress: impl Fn(f64, &str) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    fn report(&self, progress: f64, message: &str) {
+        if let Some(report) = &self.progress {
+            report(progress, message);
+        }
     }
 
     /// Export all user data to a ZIP file
@@ -62,7 +155,9 @@ impl BackupService {
             .unix_permissions(0o755);
 
         // Collect all data
+        self.report(0.0, "Collecting data");
         let backup_data = self.collect_backup_data().await?;
+        self.report(0.5, "Writing backup file");
 
         // Create metadata
         let metadata = BackupMetadata {
@@ -106,11 +201,35 @@ impl BackupService {
         let periodic_json = serde_json::to_string_pretty(&backup_data.periodic_task_templates)?;
         zip.write_all(periodic_json.as_bytes())?;
 
+        zip.start_file("custom_fields.json", options)?;
+        let custom_fields_json = serde_json::to_string_pretty(&backup_data.custom_fields)?;
+        zip.write_all(custom_fields_json.as_bytes())?;
+
         zip.start_file("settings.json", options)?;
         let settings_json = serde_json::to_string_pretty(&backup_data.settings)?;
         zip.write_all(settings_json.as_bytes())?;
 
+        // Add attachment files
+        if let Some(attachments_dir) = &self.attachments_dir {
+            self.report(0.75, "Writing attachments");
+            for stored_name in attachment_stored_names(&backup_data.attachments) {
+                let path = attachments_dir.join(&stored_name);
+                if !path.is_file() {
+                    log::warn!("Attachment file {} is missing, skipping", stored_name);
+                    continue;
+                }
+                zip.start_file(
+                    format!("{}/{}", ATTACHMENTS_ARCHIVE_DIR, stored_name),
+                    options,
+                )?;
+                let contents = std::fs::read(&path)
+                    .with_context(|| format!("Failed to read attachment: {}", stored_name))?;
+                zip.write_all(&contents)?;
+            }
+        }
+
         zip.finish()?;
+        self.report(1.0, "Backup written");
 
         Ok(metadata)
     }
@@ -123,21 +242,32 @@ impl BackupService {
         let mut archive = ZipArchive::new(file)?;
 
         // Read and validate metadata
+        self.report(0.0, "Reading backup file");
         let metadata = self.read_metadata_from_archive(&mut archive)?;
 
         // Read backup data
         let backup_data = self.read_data_from_archive(&mut archive)?;
 
         // Validate data integrity
+        self.report(0.2, "Validating backup");
         self.validate_backup_data(&backup_data)?;
 
         // Import data
         if overwrite {
+            self.report(0.3, "Clearing existing data");
             self.clear_existing_data().await?;
         }
 
+        self.report(0.4, "Importing data");
+        let stored_names = attachment_stored_names(&backup_data.attachments);
         self.import_backup_data(backup_data).await?;
 
+        if let Some(attachments_dir) = &self.attachments_dir {
+            self.report(0.9, "Restoring attachments");
+            restore_attachment_files(&mut archive, attachments_dir, &stored_names)?;
+        }
+        self.report(1.0, "Backup imported");
+
         Ok(metadata)
     }
 
@@ -247,15 +377,129 @@ impl BackupService {
         Ok(result)
     }
 
+    /// Restore a backup into a scratch in-memory database and check the result
+    ///
+    /// Live data is never written: the live task lists are copied into the scratch database
+    /// because backups reference them without containing them, and attachment files are only
+    /// looked up in the archive.
+    pub async fn rehearse_restore(&self, file_path: &str) -> Result<RestoreRehearsalReport> {
+        let file = File::open(file_path)
+            .with_context(|| format!("Failed to open backup file: {}", file_path))?;
+
+        let mut archive = ZipArchive::new(file)?;
+
+        self.report(0.0, "Reading backup file");
+        let metadata = self.read_metadata_from_archive(&mut archive)?;
+        let backup_data = self.read_data_from_archive(&mut archive)?;
+
+        let mut report = RestoreRehearsalReport {
+            restorable: false,
+            metadata,
+            tables: Vec::new(),
+            integrity: None,
+            database_issues: Vec::new(),
+            missing_attachment_files: attachment_stored_names(&backup_data.attachments)
+                .into_iter()
+                .filter(|name| {
+                    archive
+                        .index_for_name(&format!("{}/{}", ATTACHMENTS_ARCHIVE_DIR, name))
+                        .is_none()
+                })
+                .collect(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+
+        // The same checks an import runs before touching the database
+        if let Err(e) = self.validate_backup_data(&backup_data) {
+            report.errors.push(format!("Data validation failed: {}", e));
+            return Ok(report);
+        }
+        if let Err(e) = self.validate_data_integrity(&backup_data) {
+            report
+                .warnings
+                .push(format!("Data integrity warning: {}", e));
+        }
+
+        self.report(0.2, "Creating scratch database");
+        let scratch = Arc::new(
+            DatabaseConfig::new()
+                .with_database_url("sqlite::memory:".to_string())
+                .with_max_connections(1)
+                .with_sqlx_logging(false)
+                .connect()
+                .await
+                .context("Failed to create scratch database")?,
+        );
+        run_migrations(&scratch)
+            .await
+            .context("Failed to migrate scratch database")?;
+
+        let scratch_lists = TaskListRepository::new(scratch.clone());
+        scratch_lists
+            .delete_all_task_lists()
+            .await
+            .context("Failed to clear scratch task lists")?;
+        for task_list in TaskListRepository::new(self.db.clone())
+            .find_all_task_lists()
+            .await
+            .context("Failed to fetch task lists")?
+        {
+            scratch_lists
+                .import_task_list(task_list)
+                .await
+                .context("Failed to copy task list")?;
+        }
+
+        self.report(0.3, "Restoring into scratch database");
+        let expected = expected_row_counts(&backup_data);
+        if let Err(e) = BackupService::new(scratch.clone())
+            .import_backup_data(backup_data)
+            .await
+        {
+            report.errors.push(format!("Restore failed: {:#}", e));
+        }
+
+        self.report(0.8, "Checking restored data");
+        for (table, expected) in expected {
+            report.tables.push(RestoredTableCount {
+                table: table.to_string(),
+                expected,
+                restored: count_rows(&scratch, table).await?,
+            });
+        }
+        match validate_database_integrity(&scratch).await {
+            Ok(integrity) => report.integrity = Some(integrity),
+            Err(e) => report.errors.push(format!("Integrity check failed: {}", e)),
+        }
+        report.database_issues = sqlite_issues(&scratch).await?;
+
+        report.restorable = report.errors.is_empty()
+            && report.database_issues.is_empty()
+            && report
+                .tables
+                .iter()
+                .all(|table| table.restored == table.expected as u64)
+            && report
+                .integrity
+                .as_ref()
+                .is_some_and(|integrity| integrity.is_healthy);
+        self.report(1.0, "Rehearsal finished");
+
+        Ok(report)
+    }
+
     async fn collect_backup_data(&self) -> Result<BackupData> {
         let task_repo = TaskRepository::new(self.db.clone());
         let time_repo = TimeTrackingRepository::new(self.db.clone());
         let ai_repo = AiRepository::new(self.db.clone());
         let periodic_repo = PeriodicTaskRepository::new(self.db.clone());
+        let custom_field_repo = CustomFieldRepository::new(self.db.clone());
+        let status_repo = TaskStatusRepository::new(self.db.clone());
 
         // Collect all tasks
         let tasks = task_repo
-            .find_all(None, None)
+            .get_all_tasks()
             .await
             .context("Failed to fetch tasks")?
             .into_iter()
@@ -271,6 +515,15 @@ impl BackupService {
             .map(|session| serde_json::to_value(session).unwrap_or_default())
             .collect();
 
+        // Collect the subtasks checked off during sessions
+        let session_subtask_completions = time_repo
+            .get_all_subtask_completions()
+            .await
+            .context("Failed to fetch session subtask completions")?
+            .into_iter()
+            .map(|completion| serde_json::to_value(completion).unwrap_or_default())
+            .collect();
+
         // Collect all AI interactions
         let ai_interactions = ai_repo
             .find_all(None, None)
@@ -280,6 +533,31 @@ impl BackupService {
             .map(|interaction| serde_json::to_value(interaction).unwrap_or_default())
             .collect();
 
+        // Collect the ReAct chains of AI interaction logs and their tool calls
+        let react_chains = ai_repo
+            .find_all_react_chains()
+            .await
+            .context("Failed to fetch ReAct chains")?
+            .into_iter()
+            .map(|chain| serde_json::to_value(chain).unwrap_or_default())
+            .collect();
+        let react_steps = ai_repo
+            .find_all_react_steps()
+            .await
+            .context("Failed to fetch ReAct steps")?
+            .into_iter()
+            .map(|step| serde_json::to_value(step).unwrap_or_default())
+            .collect();
+
+        // Collect the status history of all tasks
+        let task_status_history = task_repo
+            .get_all_status_history()
+            .await
+            .context("Failed to fetch task status history")?
+            .into_iter()
+            .map(|entry| serde_json::to_value(entry).unwrap_or_default())
+            .collect();
+
         // Collect all task dependencies
         let task_dependencies = task_repo
             .get_all_dependencies()
@@ -298,6 +576,77 @@ impl BackupService {
             .map(|template| serde_json::to_value(template).unwrap_or_default())
             .collect();
 
+        // Collect custom field definitions and values
+        let custom_fields = custom_field_repo
+            .get_all_fields()
+            .await
+            .context("Failed to fetch custom fields")?
+            .into_iter()
+            .map(|field| serde_json::to_value(field).unwrap_or_default())
+            .collect();
+
+        let custom_field_values = custom_field_repo
+            .get_all_values()
+            .await
+            .context("Failed to fetch custom field values")?
+            .into_iter()
+            .map(|value| serde_json::to_value(value).unwrap_or_default())
+            .collect();
+
+        // Collect checklist items
+        let checklist_items = ChecklistRepository::new(self.db.clone())
+            .get_all_items()
+            .await
+            .context("Failed to fetch checklist items")?
+            .into_iter()
+            .map(|item| serde_json::to_value(item).unwrap_or_default())
+            .collect();
+
+        // Collect task notes
+        let task_notes = TaskNoteRepository::new(self.db.clone())
+            .get_all_notes()
+            .await
+            .context("Failed to fetch task notes")?
+            .into_iter()
+            .map(|note| serde_json::to_value(note).unwrap_or_default())
+            .collect();
+
+        // Collect task reminders
+        let task_reminders = ReminderRepository::new(self.db.clone())
+            .get_all_reminders()
+            .await
+            .context("Failed to fetch task reminders")?
+            .into_iter()
+            .map(|reminder| serde_json::to_value(reminder).unwrap_or_default())
+            .collect();
+
+        // Collect daily notes
+        let daily_notes = DailyNoteRepository::new(self.db.clone())
+            .get_all_notes()
+            .await
+            .context("Failed to fetch daily notes")?
+            .into_iter()
+            .map(|note| serde_json::to_value(note).unwrap_or_default())
+            .collect();
+
+        // Collect attachment metadata; the files are added to the archive separately
+        let attachments = AttachmentRepository::new(self.db.clone(), PathBuf::new())
+            .get_all_attachments()
+            .await
+            .context("Failed to fetch attachments")?
+            .into_iter()
+            .map(|attachment| serde_json::to_value(attachment).unwrap_or_default())
+            .collect();
+
+        // Collect task status definitions
+        let task_statuses = status_repo
+            .find_all()
+            .await
+            .context("Failed to fetch task statuses")?
+            .into_iter()
+            .map(|status| serde_json::to_value(status).unwrap_or_default())
+            .collect();
+
         // Collect settings (placeholder - would need to implement settings storage)
         let settings = HashMap::new();
 
@@ -309,6 +658,18 @@ impl BackupService {
             ai_interactions,
             task_dependencies,
             periodic_task_templates,
+            custom_fields,
+            custom_field_values,
+            task_statuses,
+            checklist_items,
+            daily_notes,
+            attachments,
+            task_notes,
+            task_reminders,
+            task_status_history,
+            session_subtask_completions,
+            react_chains,
+            react_steps,
             settings,
         })
     }
@@ -501,8 +862,14 @@ impl BackupService {
         let time_repo = TimeTrackingRepository::new(self.db.clone());
         let ai_repo = AiRepository::new(self.db.clone());
         let periodic_repo = PeriodicTaskRepository::new(self.db.clone());
+        let custom_field_repo = CustomFieldRepository::new(self.db.clone());
 
         // Clear in correct order to respect foreign key constraints
+        custom_field_repo
+            .delete_all()
+            .await
+            .context("Failed to clear existing custom fields")?;
+
         time_repo
             .delete_all_sessions()
             .await
@@ -513,6 +880,27 @@ impl BackupService {
             .await
             .context("Failed to clear existing AI interactions")?;
 
+        DailyNoteRepository::new(self.db.clone())
+            .delete_all()
+            .await
+            .context("Failed to clear existing daily notes")?;
+
+        // Attachment rows go with their tasks; remove the files they point at
+        if let Some(attachments_dir) = &self.attachments_dir {
+            let attachment_repo =
+                AttachmentRepository::new(self.db.clone(), attachments_dir.clone());
+            let attachments = attachment_repo
+                .get_all_attachments()
+                .await
+                .context("Failed to fetch existing attachments")?;
+            attachment_repo.remove_files(&attachments).await;
+        }
+
+        ReminderRepository::new(self.db.clone())
+            .delete_all()
+            .await
+            .context("Failed to clear existing task reminders")?;
+
         task_repo
             .delete_all_dependencies()
             .await
@@ -523,6 +911,12 @@ impl BackupService {
             .await
             .context("Failed to clear existing tasks")?;
 
+        // Tags are rebuilt from the imported tasks
+        TagRepository::new(self.db.clone())
+            .delete_all_tags()
+            .await
+            .context("Failed to clear existing tags")?;
+
         // Clear periodic task templates (should be done after tasks to respect foreign keys)
         periodic_repo
             .delete_all_templates()
@@ -537,6 +931,18 @@ impl BackupService {
         let time_repo = TimeTrackingRepository::new(self.db.clone());
         let ai_repo = AiRepository::new(self.db.clone());
         let periodic_repo = PeriodicTaskRepository::new(self.db.clone());
+        let custom_field_repo = CustomFieldRepository::new(self.db.clone());
+        let status_repo = TaskStatusRepository::new(self.db.clone());
+
+        // Import status definitions missing from this database
+        for status_value in backup_data.task_statuses {
+            if let Ok(status) = serde_json::from_value(status_value) {
+                status_repo
+                    .import_status(status)
+                    .await
+                    .context("Failed to import task status")?;
+            }
+        }
 
         // Import periodic task templates first (before tasks that might reference them)
         for template_value in backup_data.periodic_task_templates {
@@ -558,6 +964,63 @@ impl BackupService {
             }
         }
 
+        // Import the status history of the tasks
+        for entry_value in backup_data.task_status_history {
+            if let Ok(entry) = serde_json::from_value(entry_value) {
+                task_repo
+                    .import_status_change(entry)
+                    .await
+                    .context("Failed to import task status history")?;
+            }
+        }
+
+        // Import checklist items
+        let checklist_repo = ChecklistRepository::new(self.db.clone());
+        for item_value in backup_data.checklist_items {
+            if let Ok(item) = serde_json::from_value(item_value) {
+                checklist_repo
+                    .import_item(item)
+                    .await
+                    .context("Failed to import checklist item")?;
+            }
+        }
+
+        // Import task notes
+        let task_note_repo = TaskNoteRepository::new(self.db.clone());
+        for note_value in backup_data.task_notes {
+            if let Ok(note) = serde_json::from_value(note_value) {
+                task_note_repo
+                    .import_note(note)
+                    .await
+                    .context("Failed to import task note")?;
+            }
+        }
+
+        // Import task reminders
+        let reminder_repo = ReminderRepository::new(self.db.clone());
+        for reminder_value in backup_data.task_reminders {
+            if let Ok(reminder) = serde_json::from_value(reminder_value) {
+                reminder_repo
+                    .import_reminder(reminder)
+                    .await
+                    .context("Failed to import task reminder")?;
+            }
+        }
+
+        // Import attachment metadata
+        let attachment_repo = AttachmentRepository::new(
+            self.db.clone(),
+            self.attachments_dir.clone().unwrap_or_default(),
+        );
+        for attachment_value in backup_data.attachments {
+            if let Ok(attachment) = serde_json::from_value(attachment_value) {
+                attachment_repo
+                    .import_attachment(attachment)
+                    .await
+                    .context("Failed to import attachment")?;
+            }
+        }
+
         // Import task dependencies
         for dep_value in backup_data.task_dependencies {
             if let Ok(dependency) = serde_json::from_value(dep_value) {
@@ -568,6 +1031,25 @@ impl BackupService {
             }
         }
 
+        // Import custom fields, then the values that reference them and their tasks
+        for field_value in backup_data.custom_fields {
+            if let Ok(field) = serde_json::from_value(field_value) {
+                custom_field_repo
+                    .import_field(field)
+                    .await
+                    .context("Failed to import custom field")?;
+            }
+        }
+
+        for value in backup_data.custom_field_values {
+            if let Ok(value) = serde_json::from_value(value) {
+                custom_field_repo
+                    .import_value(value)
+                    .await
+                    .context("Failed to import custom field value")?;
+            }
+        }
+
         // Import time sessions
         for session_value in backup_data.time_sessions {
             if let Ok(session) = serde_json::from_value(session_value) {
@@ -578,6 +1060,27 @@ impl BackupService {
             }
         }
 
+        // Import the subtasks checked off during those sessions
+        for completion_value in backup_data.session_subtask_completions {
+            if let Ok(completion) = serde_json::from_value(completion_value) {
+                time_repo
+                    .import_subtask_completion(completion)
+                    .await
+                    .context("Failed to import session subtask completion")?;
+            }
+        }
+
+        // Import daily notes
+        let daily_note_repo = DailyNoteRepository::new(self.db.clone());
+        for note_value in backup_data.daily_notes {
+            if let Ok(note) = serde_json::from_value(note_value) {
+                daily_note_repo
+                    .import_note(note)
+                    .await
+                    .context("Failed to import daily note")?;
+            }
+        }
+
         // Import AI interactions
         for ai_value in backup_data.ai_interactions {
             if let Ok(interaction) = serde_json::from_value(ai_value) {
@@ -588,6 +1091,135 @@ impl BackupService {
             }
         }
 
+        // Import the ReAct chains of AI interaction logs, then their tool calls
+        for chain_value in backup_data.react_chains {
+            if let Ok(chain) = serde_json::from_value(chain_value) {
+                ai_repo
+                    .import_react_chain(chain)
+                    .await
+                    .context("Failed to import ReAct chain")?;
+            }
+        }
+        for step_value in backup_data.react_steps {
+            if let Ok(step) = serde_json::from_value(step_value) {
+                ai_repo
+                    .import_react_step(step)
+                    .await
+                    .context("Failed to import ReAct step")?;
+            }
+        }
+
         Ok(())
     }
 }
+
+/// Tables a restore fills with one row per backup record, in import order
+fn expected_row_counts(backup_data: &BackupData) -> Vec<(&'static str, usize)> {
+    vec![
+        (
+            "periodic_task_templates",
+            backup_data.periodic_task_templates.len(),
+        ),
+        ("tasks", backup_data.tasks.len()),
+        ("task_status_history", backup_data.task_status_history.len()),
+        ("checklist_items", backup_data.checklist_items.len()),
+        ("task_notes", backup_data.task_notes.len()),
+        ("task_reminders", backup_data.task_reminders.len()),
+        ("attachments", backup_data.attachments.len()),
+        ("task_dependencies", backup_data.task_dependencies.len()),
+        ("custom_fields", backup_data.custom_fields.len()),
+        ("custom_field_values", backup_data.custom_field_values.len()),
+        ("time_sessions", backup_data.time_sessions.len()),
+        (
+            "session_subtask_completions",
+            backup_data.session_subtask_completions.len(),
+        ),
+        ("daily_notes", backup_data.daily_notes.len()),
+        ("ai_interactions", backup_data.ai_interactions.len()),
+        ("react_chains", backup_data.react_chains.len()),
+        ("react_steps", backup_data.react_steps.len()),
+    ]
+}
+
+async fn count_rows(db: &DatabaseConnection, table: &str) -> Result<u64> {
+    let row = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            format!("SELECT COUNT(*) AS count FROM {}", table),
+        ))
+        .await?
+        .with_context(|| format!("Failed to count rows of {}", table))?;
+    Ok(row.try_get::<i64>("", "count")? as u64)
+}
+
+/// Problems reported by SQLite's `integrity_check` and `foreign_key_check`
+async fn sqlite_issues(db: &DatabaseConnection) -> Result<Vec<String>> {
+    let backend = db.get_database_backend();
+    let mut issues = Vec::new();
+
+    let integrity = db
+        .query_all(Statement::from_string(backend, "PRAGMA integrity_check"))
+        .await?;
+    for row in integrity {
+        let message: String = row.try_get_by_index(0)?;
+        if message != "ok" {
+            issues.push(message);
+        }
+    }
+
+    let violations = db
+        .query_all(Statement::from_string(backend, "PRAGMA foreign_key_check"))
+        .await?;
+    for row in violations {
+        let table: String = row.try_get_by_index(0)?;
+        let parent: String = row.try_get_by_index(2)?;
+        issues.push(format!(
+            "A row of {} references a missing {} row",
+            table, parent
+        ));
+    }
+
+    Ok(issues)
+}
+
+/// Stored file names of backed-up attachment rows
+fn attachment_stored_names(attachments: &[serde_json::Value]) -> Vec<String> {
+    attachments
+        .iter()
+        .filter_map(|attachment| attachment.get("stored_name").and_then(|v| v.as_str()))
+        .map(String::from)
+        .collect()
+}
+
+/// Extract attachment files from a backup archive into the attachments directory
+fn restore_attachment_files(
+    archive: &mut ZipArchive<File>,
+    attachments_dir: &std::path::Path,
+    stored_names: &[String],
+) -> Result<()> {
+    std::fs::create_dir_all(attachments_dir).context("Failed to create attachments directory")?;
+
+    for stored_name in stored_names {
+        // Stored names are generated by the app; anything else could escape the directory
+        if stored_name.contains(['/', '\\']) || stored_name.starts_with('.') {
+            log::warn!("Skipping attachment with invalid name: {}", stored_name);
+            continue;
+        }
+
+        let archive_path = format!("{}/{}", ATTACHMENTS_ARCHIVE_DIR, stored_name);
+        let mut file = match archive.by_name(&archive_path) {
+            Ok(file) => file,
+            Err(zip::result::ZipError::FileNotFound) => {
+                log::warn!("Attachment file {} is missing from the backup", stored_name);
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+        std::fs::write(attachments_dir.join(stored_name), contents)
+            .with_context(|| format!("Failed to restore attachment: {}", stored_name))?;
+    }
+
+    Ok(())
+}
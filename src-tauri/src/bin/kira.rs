@@ -0,0 +1,5 @@
+// `kira`, a command line companion that shares the app's database
+
+fn main() -> std::process::ExitCode {
+    kirapilot_app_lib::cli::run(std::env::args().skip(1).collect())
+}
@@ -0,0 +1,366 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, Timelike, Utc};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::database::entities::{periodic_task_templates, tasks};
+use crate::database::repositories::{PeriodicTaskRepository, TaskRepository, TaskStatusRepository};
+
+const PRODUCT_ID: &str = "-//KiraPilot//Task Calendar//EN";
+const UID_DOMAIN: &str = "kirapilot.app";
+
+/// Length of a timed event for a task without a time estimate, in minutes
+const DEFAULT_EVENT_MINUTES: i32 = 30;
+
+/// Dates to export and what to include
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarExportRange {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    #[serde(default)]
+    pub include_completed: bool,
+    /// Export active periodic templates as recurring events instead of their instances
+    #[serde(default = "default_include_periodic")]
+    pub include_periodic: bool,
+}
+
+fn default_include_periodic() -> bool {
+    true
+}
+
+/// A periodic template that could not be written as a recurring event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedTemplate {
+    pub id: String,
+    pub title: String,
+    pub reason: String,
+}
+
+/// Result of exporting a calendar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarExportSummary {
+    pub file_path: String,
+    /// Events for scheduled tasks
+    pub scheduled_count: usize,
+    /// All-day events for due dates
+    pub due_count: usize,
+    /// Recurring events for periodic templates
+    pub recurring_count: usize,
+    pub skipped_templates: Vec<SkippedTemplate>,
+}
+
+/// Exports scheduled tasks and due dates as an iCalendar (.ics) file
+///
+/// Tasks become `VEVENT`s rather than `VTODO`s since most calendar apps, Google Calendar
+/// included, ignore to-dos on import. Event UIDs are derived from task IDs so importing a
+/// newer export updates events instead of duplicating them.
+pub struct CalendarExportService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl CalendarExportService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Write the calendar for `range` to `file_path`
+    pub async fn export(
+        &self,
+        range: &CalendarExportRange,
+        file_path: &str,
+    ) -> Result<CalendarExportSummary> {
+        let (calendar, mut summary) = self.render(range).await?;
+        tokio::fs::write(file_path, calendar)
+            .await
+            .with_context(|| format!("Failed to write {}", file_path))?;
+
+        summary.file_path = file_path.to_string();
+        Ok(summary)
+    }
+
+    /// Build the calendar for `range` without writing it
+    pub async fn render(
+        &self,
+        range: &CalendarExportRange,
+    ) -> Result<(String, CalendarExportSummary)> {
+        if range.end < range.start {
+            anyhow::bail!("Calendar range ends before it starts");
+        }
+
+        let task_repo = TaskRepository::new(self.db.clone());
+        let periodic_repo = PeriodicTaskRepository::new(self.db.clone());
+        let stamp = format_utc(Utc::now());
+
+        let mut summary = CalendarExportSummary {
+            file_path: String::new(),
+            scheduled_count: 0,
+            due_count: 0,
+            recurring_count: 0,
+            skipped_templates: Vec::new(),
+        };
+        let mut events: Vec<Vec<String>> = Vec::new();
+
+        // Instances of templates exported as recurring events would show up twice
+        let mut recurring_templates = HashSet::new();
+        if range.include_periodic {
+            let templates = periodic_repo
+                .find_active()
+                .await
+                .context("Failed to fetch periodic templates")?;
+            for template in templates {
+                if template.start_date > range.end
+                    || template
+                        .ends_at
+                        .is_some_and(|ends_at| ends_at < range.start)
+                {
+                    continue;
+                }
+                match recurrence_rule(&template) {
+                    Ok(rule) => {
+                        events.push(template_event(&template, &rule, &stamp));
+                        recurring_templates.insert(template.id.clone());
+                        summary.recurring_count += 1;
+                    }
+                    Err(reason) => summary.skipped_templates.push(SkippedTemplate {
+                        id: template.id,
+                        title: template.title,
+                        reason,
+                    }),
+                }
+            }
+        }
+
+        let tasks = task_repo
+            .find_all(None, None)
+            .await
+            .context("Failed to fetch tasks")?;
+        let completed = TaskStatusRepository::completed_statuses_in(&*self.db)
+            .await
+            .context("Failed to fetch task statuses")?;
+        for task in tasks.iter().rev() {
+            if completed.contains(&task.status) && !range.include_completed {
+                continue;
+            }
+            if task
+                .periodic_template_id
+                .as_ref()
+                .is_some_and(|id| recurring_templates.contains(id))
+            {
+                continue;
+            }
+
+            let in_range = |date: &DateTime<Utc>| *date >= range.start && *date <= range.end;
+            if let Some(scheduled) = task.scheduled_date.filter(in_range) {
+                events.push(scheduled_event(task, scheduled, &stamp));
+                summary.scheduled_count += 1;
+            }
+            if let Some(due) = task.due_date.filter(in_range) {
+                events.push(due_event(task, due, &stamp));
+                summary.due_count += 1;
+            }
+        }
+
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:{}", PRODUCT_ID),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            "X-WR-CALNAME:KiraPilot".to_string(),
+        ];
+        for event in events {
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.extend(event);
+            lines.push("END:VEVENT".to_string());
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        let calendar: String = lines.iter().map(|line| fold_line(line) + "\r\n").collect();
+        Ok((calendar, summary))
+    }
+}
+
+/// Event covering the task's scheduled time, or its whole day when scheduled without a time
+fn scheduled_event(task: &tasks::Model, scheduled: DateTime<Utc>, stamp: &str) -> Vec<String> {
+    let mut event = vec![
+        format!("UID:{}-scheduled@{}", task.id, UID_DOMAIN),
+        format!("DTSTAMP:{}", stamp),
+    ];
+
+    match all_day(scheduled) {
+        Some(date) => event.push(format!("DTSTART;VALUE=DATE:{}", format_date(date))),
+        None => {
+            let minutes = if task.time_estimate > 0 {
+                task.time_estimate
+            } else {
+                DEFAULT_EVENT_MINUTES
+            };
+            event.push(format!("DTSTART:{}", format_utc(scheduled)));
+            event.push(format!("DURATION:PT{}M", minutes));
+        }
+    }
+
+    event.push(format!("SUMMARY:{}", escape_text(&task.title)));
+    event.extend(task_properties(task));
+    event
+}
+
+/// All-day event on the day a task is due
+fn due_event(task: &tasks::Model, due: DateTime<Utc>, stamp: &str) -> Vec<String> {
+    let due = due.with_timezone(&Local);
+    let mut event = vec![
+        format!("UID:{}-due@{}", task.id, UID_DOMAIN),
+        format!("DTSTAMP:{}", stamp),
+        format!("DTSTART;VALUE=DATE:{}", format_date(due.date_naive())),
+        format!("SUMMARY:{}", escape_text(&format!("Due: {}", task.title))),
+        "TRANSP:TRANSPARENT".to_string(),
+    ];
+    event.extend(task_properties(task));
+    event
+}
+
+/// Recurring event for a periodic template, starting at its start date
+fn template_event(
+    template: &periodic_task_templates::Model,
+    rule: &str,
+    stamp: &str,
+) -> Vec<String> {
+    let mut event = vec![
+        format!("UID:periodic-{}@{}", template.id, UID_DOMAIN),
+        format!("DTSTAMP:{}", stamp),
+    ];
+    match all_day(template.start_date) {
+        Some(date) => event.push(format!("DTSTART;VALUE=DATE:{}", format_date(date))),
+        None => {
+            let minutes = if template.time_estimate > 0 {
+                template.time_estimate
+            } else {
+                DEFAULT_EVENT_MINUTES
+            };
+            event.push(format!("DTSTART:{}", format_utc(template.start_date)));
+            event.push(format!("DURATION:PT{}M", minutes));
+        }
+    }
+    event.push(format!("RRULE:{}", rule));
+    event.push(format!("SUMMARY:{}", escape_text(&template.title)));
+    if let Some(description) = template.description.as_deref().filter(|d| !d.is_empty()) {
+        event.push(format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    event.extend(priority_property(template.priority));
+    event
+}
+
+fn task_properties(task: &tasks::Model) -> Vec<String> {
+    let mut properties = Vec::new();
+    if let Some(description) = task.description.as_deref().filter(|d| !d.is_empty()) {
+        properties.push(format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    let tags: Vec<String> = task
+        .tags
+        .as_deref()
+        .and_then(|tags| serde_json::from_str(tags).ok())
+        .unwrap_or_default();
+    if !tags.is_empty() {
+        let tags: Vec<String> = tags.iter().map(|tag| escape_text(tag)).collect();
+        properties.push(format!("CATEGORIES:{}", tags.join(",")));
+    }
+    properties.extend(priority_property(task.priority));
+    properties.push(format!("LAST-MODIFIED:{}", format_utc(task.updated_at)));
+    properties
+}
+
+/// iCalendar priority, where 1 is highest and 9 lowest
+fn priority_property(priority: i32) -> Option<String> {
+    let value = match priority {
+        3 => 1, // urgent
+        2 => 3, // high
+        1 => 5, // medium
+        _ => return None,
+    };
+    Some(format!("PRIORITY:{}", value))
+}
+
+/// RRULE for a template's recurrence; cron rules have no general equivalent
+pub(crate) fn recurrence_rule(template: &periodic_task_templates::Model) -> Result<String, String> {
+    let interval = template.recurrence_interval.max(1);
+    let (frequency, interval) = match template.recurrence_type.as_str() {
+        "daily" => ("DAILY", interval),
+        "weekly" => ("WEEKLY", interval),
+        "biweekly" => ("WEEKLY", 2),
+        "every_three_weeks" => ("WEEKLY", 3),
+        "monthly" => ("MONTHLY", interval),
+        "custom" => match template.recurrence_unit.as_deref() {
+            Some("days") => ("DAILY", interval),
+            Some("weeks") => ("WEEKLY", interval),
+            Some("months") => ("MONTHLY", interval),
+            _ => return Err("Unsupported custom recurrence unit".to_string()),
+        },
+        "cron" => return Err("Cron recurrences can't be expressed as an RRULE".to_string()),
+        other => return Err(format!("Unknown recurrence type '{}'", other)),
+    };
+
+    let mut rule = format!("FREQ={}", frequency);
+    if interval > 1 {
+        rule.push_str(&format!(";INTERVAL={}", interval));
+    }
+    // RFC 5545 forbids UNTIL and COUNT together; the end date wins. UNTIL must match the
+    // value type of DTSTART, so all-day templates end on a date.
+    if let Some(ends_at) = template.ends_at {
+        let until = match all_day(template.start_date) {
+            Some(_) => format_date(ends_at.with_timezone(&Local).date_naive()),
+            None => format_utc(ends_at),
+        };
+        rule.push_str(&format!(";UNTIL={}", until));
+    } else if let Some(max_occurrences) = template.max_occurrences {
+        rule.push_str(&format!(";COUNT={}", max_occurrences));
+    }
+    Ok(rule)
+}
+
+/// The local date of a time at local midnight, which the app uses for dates without a time
+pub(crate) fn all_day(time: DateTime<Utc>) -> Option<NaiveDate> {
+    let local = time.with_timezone(&Local);
+    (local.hour() == 0 && local.minute() == 0 && local.second() == 0).then(|| local.date_naive())
+}
+
+pub(crate) fn format_utc(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+pub(crate) fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+/// Escape a TEXT value per RFC 5545
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fold a content line to 75 octets, continuing with a leading space
+pub(crate) fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        // Continuation lines start with a space, which counts towards their 75 octets
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
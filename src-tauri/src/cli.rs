@@ -0,0 +1,311 @@
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveTime, TimeZone, Utc};
+use sea_orm::DatabaseConnection;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use crate::authorization::authorize;
+use crate::database::entities::tasks;
+use crate::database::initialize_database;
+use crate::database::repositories::task_repository::CreateTaskRequest;
+use crate::database::repositories::time_tracking_repository::CreateTimeSessionRequest;
+use crate::database::repositories::{TaskRepository, TaskStatusRepository, TimeTrackingRepository};
+use crate::database::services::date_parser::parse_natural_date;
+
+const USAGE: &str = "Usage:
+  kira add <title> [--due <when>]   Create a task, e.g. --due \"friday 5pm\"
+  kira list [--today] [--all]       List open tasks, or those scheduled for today;
+                                    --all includes done and cancelled tasks
+  kira start <task>                 Start the timer on a task (ID, ID prefix or title)
+  kira stop                         Stop the running timer
+
+Set KIRA_TOKEN to an API token issued in the app's settings";
+
+/// Environment variable holding the API token `kira` presents
+const TOKEN_VAR: &str = "KIRA_TOKEN";
+
+/// Characters of a task ID shown in listings; enough to pass to `kira start`
+const SHORT_ID_LEN: usize = 8;
+
+/// A parsed `kira` command line
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Command {
+    Add { title: String, due: Option<String> },
+    List { today: bool, all: bool },
+    Start { task: String },
+    Stop,
+    Help,
+}
+
+impl Command {
+    /// App command whose scope a token needs to run this
+    pub(crate) fn authorized_as(&self) -> &'static str {
+        match self {
+            Command::Add { .. } => "create_task",
+            Command::List { .. } | Command::Help => "get_all_tasks",
+            Command::Start { .. } => "create_time_session",
+            Command::Stop => "stop_time_session",
+        }
+    }
+}
+
+/// Run the `kira` command line companion with the arguments after the program name
+///
+/// It opens the app's SQLite file the same way the app does. SQLite's file locks keep the two
+/// from writing at once; a busy connection waits, and writes are retried while the app holds
+/// the lock, so `kira` can run while the app is open. Every command but `help` needs an API
+/// token in `KIRA_TOKEN` carrying the command's scope.
+pub fn run(args: Vec<String>) -> ExitCode {
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("kira: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("kira: failed to start: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match runtime.block_on(execute(command)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("kira: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub(crate) fn parse_args(args: &[String]) -> Result<Command, String> {
+    let Some((name, rest)) = args.split_first() else {
+        return Ok(Command::Help);
+    };
+
+    match name.as_str() {
+        "add" => {
+            let mut words = Vec::new();
+            let mut due = None;
+            let mut rest = rest.iter();
+            while let Some(arg) = rest.next() {
+                match arg.as_str() {
+                    "--due" => {
+                        let when = rest.next().ok_or("--due needs a date")?;
+                        due = Some(when.clone());
+                    }
+                    _ => words.push(arg.as_str()),
+                }
+            }
+            let title = words.join(" ").trim().to_string();
+            if title.is_empty() {
+                return Err("add needs a task title".to_string());
+            }
+            Ok(Command::Add { title, due })
+        }
+        "list" => {
+            let (mut today, mut all) = (false, false);
+            for arg in rest {
+                match arg.as_str() {
+                    "--today" => today = true,
+                    "--all" => all = true,
+                    _ => return Err(format!("unexpected arguments to list: {}", rest.join(" "))),
+                }
+            }
+            Ok(Command::List { today, all })
+        }
+        "start" => {
+            let task = rest.join(" ").trim().to_string();
+            if task.is_empty() {
+                return Err("start needs a task ID or title".to_string());
+            }
+            Ok(Command::Start { task })
+        }
+        "stop" if rest.is_empty() => Ok(Command::Stop),
+        "stop" => Err(format!("unexpected arguments to stop: {}", rest.join(" "))),
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        other => Err(format!("unknown command '{}'", other)),
+    }
+}
+
+async fn execute(command: Command) -> Result<()> {
+    if command == Command::Help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let token = std::env::var(TOKEN_VAR)
+        .ok()
+        .filter(|token| !token.trim().is_empty())
+        .with_context(|| format!("{} is not set; issue an API token in the app", TOKEN_VAR))?;
+    let db = initialize_database()
+        .await
+        .context("Failed to open the KiraPilot database")?;
+    dispatch(db, token.trim(), command).await
+}
+
+/// Run `command` once the token presenting `secret` is allowed to
+pub(crate) async fn dispatch(
+    db: Arc<DatabaseConnection>,
+    secret: &str,
+    command: Command,
+) -> Result<()> {
+    authorize(db.clone(), secret, command.authorized_as()).await?;
+
+    let task_repo = TaskRepository::new(db.clone()).with_audit_source("cli");
+    let time_repo = TimeTrackingRepository::new(db.clone());
+
+    match command {
+        Command::Add { title, due } => {
+            let due_date = match due {
+                Some(when) => Some(
+                    parse_natural_date(&when, &Local::now())
+                        .with_context(|| format!("Could not understand the date '{}'", when))?
+                        .date,
+                ),
+                None => None,
+            };
+            let task = task_repo
+                .create_task(CreateTaskRequest {
+                    title,
+                    description: None,
+                    priority: 1,
+                    status: None,
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: None,
+                    due_date,
+                    scheduled_date: None,
+                    tags: None,
+                    project_id: None,
+                    parent_task_id: None,
+                    task_list_id: None,
+                    periodic_template_id: None,
+                    is_periodic_instance: None,
+                    generation_date: None,
+                    effort: None,
+                    impact: None,
+                })
+                .await?;
+            println!("Added {}  {}", short_id(&task.id), task.title);
+        }
+        Command::List { today, all } => {
+            let tasks = if today {
+                let start = Local
+                    .from_local_datetime(&Local::now().date_naive().and_time(NaiveTime::MIN))
+                    .earliest()
+                    .context("Local midnight does not exist")?
+                    .with_timezone(&Utc);
+                task_repo
+                    .find_scheduled_between(start, start + chrono::Duration::days(1))
+                    .await?
+            } else {
+                task_repo.find_all(None, None).await?
+            };
+            let listed = listed_tasks(&db, tasks, all).await?;
+            if listed.is_empty() {
+                println!("{}", if all { "No tasks" } else { "No open tasks" });
+            }
+            for task in listed {
+                println!("{}  {:<12} {}", short_id(&task.id), task.status, task.title);
+            }
+        }
+        Command::Start { task } => {
+            if let Some(running) = time_repo.find_any_active_session().await? {
+                anyhow::bail!(
+                    "A timer is already running (session {}); run `kira stop` first",
+                    short_id(&running.id)
+                );
+            }
+            let task = resolve_task(&task_repo, &task).await?;
+            time_repo
+                .create_session(CreateTimeSessionRequest {
+                    task_id: task.id.clone(),
+                    start_time: Utc::now(),
+                    notes: None,
+                    auto_switch: false,
+                })
+                .await?;
+            println!("Started timer on {}  {}", short_id(&task.id), task.title);
+        }
+        Command::Stop => {
+            let running = time_repo
+                .find_any_active_session()
+                .await?
+                .context("No timer is running")?;
+            let session = time_repo.stop_session(&running.id, None).await?;
+            let minutes = session
+                .end_time
+                .map_or(0, |end| (end - session.start_time).num_minutes());
+            let title = task_repo
+                .find_by_id(&session.task_id)
+                .await?
+                .map(|task| task.title)
+                .unwrap_or_default();
+            println!("Stopped timer on {} after {} min", title, minutes);
+        }
+        Command::Help => unreachable!("help is handled before opening the database"),
+    }
+    Ok(())
+}
+
+/// Find a task by full ID, ID prefix or title, failing when the query is ambiguous
+pub(crate) async fn resolve_task(repo: &TaskRepository, query: &str) -> Result<tasks::Model> {
+    if let Some(task) = repo.find_by_id(query).await? {
+        return Ok(task);
+    }
+
+    let tasks = repo.find_all(None, None).await?;
+    let mut matches: Vec<&tasks::Model> = tasks
+        .iter()
+        .filter(|task| task.id.starts_with(query) || task.title.eq_ignore_ascii_case(query))
+        .collect();
+    if matches.is_empty() {
+        let query = query.to_lowercase();
+        matches = tasks
+            .iter()
+            .filter(|task| task.title.to_lowercase().contains(&query))
+            .collect();
+    }
+
+    match matches.as_slice() {
+        [task] => Ok((*task).clone()),
+        [] => anyhow::bail!("No task matches '{}'", query),
+        _ => {
+            let candidates: Vec<String> = matches
+                .iter()
+                .take(5)
+                .map(|task| format!("  {}  {}", short_id(&task.id), task.title))
+                .collect();
+            anyhow::bail!(
+                "'{}' matches {} tasks; use an ID:\n{}",
+                query,
+                matches.len(),
+                candidates.join("\n")
+            )
+        }
+    }
+}
+
+/// Tasks `kira list` shows: those not in a done-category status (which includes cancelled),
+/// or all of them
+pub(crate) async fn listed_tasks(
+    db: &DatabaseConnection,
+    tasks: Vec<tasks::Model>,
+    all: bool,
+) -> Result<Vec<tasks::Model>> {
+    if all {
+        return Ok(tasks);
+    }
+    let done = TaskStatusRepository::done_statuses_in(db).await?;
+    Ok(tasks
+        .into_iter()
+        .filter(|task| !done.contains(&task.status))
+        .collect())
+}
+
+fn short_id(id: &str) -> &str {
+    &id[..id.len().min(SHORT_ID_LEN)]
+}
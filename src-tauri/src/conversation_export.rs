@@ -0,0 +1,293 @@
+use anyhow::{Context, Result};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::repositories::{AiRepository, ThreadRepository};
+
+/// Keys whose `key=value` values are treated as secrets
+const SECRET_KEYS: [&str; 7] = [
+    "password", "passwd", "pwd", "secret", "token", "api_key", "apikey",
+];
+
+/// Which conversations to export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationExportRequest {
+    #[serde(default)]
+    pub thread_ids: Vec<String>,
+    /// Logged AI interactions, each exported as a single exchange
+    #[serde(default)]
+    pub interaction_ids: Vec<String>,
+    /// System message placed at the start of every example
+    pub system_prompt: Option<String>,
+    /// Replace emails, phone numbers, keys and similar values; on unless turned off
+    #[serde(default = "default_redact")]
+    pub redact: bool,
+}
+
+fn default_redact() -> bool {
+    true
+}
+
+/// A conversation that was selected but not written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedConversation {
+    pub id: String,
+    pub reason: String,
+}
+
+/// Result of exporting conversations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationExportSummary {
+    pub file_path: String,
+    /// Lines written, one per conversation
+    pub example_count: usize,
+    pub message_count: usize,
+    /// Values replaced by redaction
+    pub redaction_count: usize,
+    pub skipped: Vec<SkippedConversation>,
+}
+
+/// A message in the chat format used by fine-tuning and evaluation tools
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+/// One line of the exported file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatExample {
+    pub messages: Vec<ChatMessage>,
+}
+
+/// Exports threads and logged interactions as JSONL chat examples
+///
+/// Each line holds a `messages` array of `system`, `user` and `assistant` messages.
+/// Conversations without an assistant reply are skipped since they teach nothing.
+pub struct ConversationExportService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ConversationExportService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Write the selected conversations to `file_path`, one example per line
+    pub async fn export(
+        &self,
+        request: &ConversationExportRequest,
+        file_path: &str,
+    ) -> Result<ConversationExportSummary> {
+        if request.thread_ids.is_empty() && request.interaction_ids.is_empty() {
+            anyhow::bail!("No threads or interactions selected");
+        }
+
+        let thread_repo = ThreadRepository::new(self.db.clone());
+        let ai_repo = AiRepository::new(self.db.clone());
+        let skip = |id: &str, reason: &str| SkippedConversation {
+            id: id.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let mut conversations: Vec<Vec<ChatMessage>> = Vec::new();
+        let mut skipped = Vec::new();
+
+        for thread_id in &request.thread_ids {
+            if thread_repo
+                .find_by_id(thread_id)
+                .await
+                .context("Failed to fetch thread")?
+                .is_none()
+            {
+                skipped.push(skip(thread_id, "Thread not found"));
+                continue;
+            }
+
+            let messages: Vec<ChatMessage> = thread_repo
+                .find_messages(thread_id)
+                .await
+                .context("Failed to fetch thread messages")?
+                .into_iter()
+                .filter(|message| !message.content.trim().is_empty())
+                .map(|message| ChatMessage {
+                    role: if message.r#type == "user" {
+                        "user".to_string()
+                    } else {
+                        "assistant".to_string()
+                    },
+                    content: message.content,
+                })
+                .collect();
+
+            if !messages.iter().any(|message| message.role == "assistant") {
+                skipped.push(skip(thread_id, "No assistant replies"));
+                continue;
+            }
+            conversations.push(messages);
+        }
+
+        for interaction_id in &request.interaction_ids {
+            let Some(interaction) = ai_repo
+                .find_by_id(interaction_id)
+                .await
+                .context("Failed to fetch AI interaction")?
+            else {
+                skipped.push(skip(interaction_id, "Interaction not found"));
+                continue;
+            };
+
+            if interaction.response.trim().is_empty() {
+                skipped.push(skip(interaction_id, "No assistant replies"));
+                continue;
+            }
+
+            conversations.push(vec![
+                ChatMessage {
+                    role: "user".to_string(),
+                    content: interaction.message,
+                },
+                ChatMessage {
+                    role: "assistant".to_string(),
+                    content: interaction.response,
+                },
+            ]);
+        }
+
+        let mut summary = ConversationExportSummary {
+            file_path: file_path.to_string(),
+            example_count: 0,
+            message_count: 0,
+            redaction_count: 0,
+            skipped,
+        };
+        let mut output = String::new();
+        for mut messages in conversations {
+            if let Some(system_prompt) = &request.system_prompt {
+                messages.insert(
+                    0,
+                    ChatMessage {
+                        role: "system".to_string(),
+                        content: system_prompt.clone(),
+                    },
+                );
+            }
+            if request.redact {
+                for message in &mut messages {
+                    let (content, count) = redact_sensitive(&message.content);
+                    message.content = content;
+                    summary.redaction_count += count;
+                }
+            }
+
+            summary.example_count += 1;
+            summary.message_count += messages.len();
+            output.push_str(&serde_json::to_string(&ChatExample { messages })?);
+            output.push('\n');
+        }
+
+        tokio::fs::write(file_path, output)
+            .await
+            .with_context(|| format!("Failed to write {}", file_path))?;
+
+        Ok(summary)
+    }
+}
+
+/// Replace personal data and credentials in `text`, returning the number of replacements
+///
+/// Works word by word, so values split by spaces (such as `(555) 123-4567`) are not caught.
+pub(crate) fn redact_sensitive(text: &str) -> (String, usize) {
+    let is_wrapper = |c: char| "()[]<>{}\"'`,;:.!?".contains(c);
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut count = 0;
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let word = piece.trim_end_matches(char::is_whitespace);
+        let start = word.len() - word.trim_start_matches(is_wrapper).len();
+        let end = start + word[start..].trim_end_matches(is_wrapper).len();
+
+        match sensitive_replacement(&word[start..end]) {
+            Some(replacement) => {
+                redacted.push_str(&word[..start]);
+                redacted.push_str(&replacement);
+                redacted.push_str(&piece[end..]);
+                count += 1;
+            }
+            None => redacted.push_str(piece),
+        }
+    }
+
+    (redacted, count)
+}
+
+fn sensitive_replacement(word: &str) -> Option<String> {
+    if let Some((key, value)) = word.split_once('=') {
+        let lowercase_key = key.to_lowercase();
+        if !value.is_empty() && SECRET_KEYS.iter().any(|k| lowercase_key.ends_with(k)) {
+            return Some(format!("{}=[SECRET_REDACTED]", key));
+        }
+    }
+
+    for prefix in ["/Users/", "/home/", "C:\\Users\\"] {
+        if let Some(rest) = word.strip_prefix(prefix) {
+            let user_end = rest.find(['/', '\\']).unwrap_or(rest.len());
+            return (user_end > 0).then(|| format!("[PATH_REDACTED]{}", &rest[user_end..]));
+        }
+    }
+
+    if ["sk-", "pk-", "rk-"]
+        .iter()
+        .any(|prefix| word.starts_with(prefix))
+        && word.len() >= 23
+    {
+        return Some("[API_KEY_REDACTED]".to_string());
+    }
+    if word.starts_with("eyJ") && word.matches('.').count() == 2 {
+        return Some("[JWT_REDACTED]".to_string());
+    }
+    if is_email(word) {
+        return Some("[EMAIL_REDACTED]".to_string());
+    }
+    if is_ip_address(word) {
+        return Some("[IP_REDACTED]".to_string());
+    }
+
+    // Card and phone numbers, possibly grouped with dashes, dots or parentheses
+    if word
+        .chars()
+        .all(|c| c.is_ascii_digit() || "+-.()".contains(c))
+    {
+        let digits = word.chars().filter(char::is_ascii_digit).count();
+        if (13..=19).contains(&digits) {
+            return Some("[CARD_REDACTED]".to_string());
+        }
+        if (10..=12).contains(&digits) && word.contains(['-', '.', '+', '(']) {
+            return Some("[PHONE_REDACTED]".to_string());
+        }
+    }
+
+    None
+}
+
+fn is_email(word: &str) -> bool {
+    let Some((local, domain)) = word.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._%+-".contains(c))
+        && domain.rsplit_once('.').is_some_and(|(host, tld)| {
+            !host.is_empty() && tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic())
+        })
+}
+
+fn is_ip_address(word: &str) -> bool {
+    let octets: Vec<&str> = word.split('.').collect();
+    octets.len() == 4
+        && octets
+            .iter()
+            .all(|octet| !octet.is_empty() && octet.len() <= 3 && octet.parse::<u8>().is_ok())
+}
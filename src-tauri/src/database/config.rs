@@ -39,7 +39,6 @@ impl DatabaseConfig {
     }
 
     /// Set the database URL
-    #[allow(dead_code)]
     pub fn with_database_url(mut self, url: String) -> Self {
         self.database_url = url;
         self
@@ -93,6 +92,23 @@ pub async fn create_connection_with_config(
 
 /// Get the proper database path in the application data directory
 fn get_database_path() -> Result<PathBuf, std::io::Error> {
+    Ok(get_app_data_dir()?.join("kirapilot.db"))
+}
+
+/// Get the directory where task attachments are stored, creating it if needed
+pub fn get_attachments_dir() -> Result<PathBuf, std::io::Error> {
+    let attachments_dir = get_app_data_dir()?.join("attachments");
+    std::fs::create_dir_all(&attachments_dir)?;
+    Ok(attachments_dir)
+}
+
+/// Get the path of the key this install signs task bundles with
+pub fn get_bundle_signing_key_path() -> Result<PathBuf, std::io::Error> {
+    Ok(get_app_data_dir()?.join("bundle_signing.key"))
+}
+
+/// Get the application data directory, creating it if needed
+fn get_app_data_dir() -> Result<PathBuf, std::io::Error> {
     let app_data_dir = if cfg!(target_os = "macos") {
         dirs::data_local_dir()
             .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "Cannot find local data directory"))?
@@ -111,5 +127,5 @@ fn get_database_path() -> Result<PathBuf, std::io::Error> {
     // Create the directory if it doesn't exist
     std::fs::create_dir_all(&app_data_dir)?;
 
-    Ok(app_data_dir.join("kirapilot.db"))
+    Ok(app_data_dir)
 }
@@ -13,6 +13,10 @@ pub struct Model {
     pub reasoning: Option<String>,
     pub tools_used: Option<String>, // JSON string
     pub confidence: Option<f64>,
+    #[serde(default)]
+    pub model_info: Option<String>, // JSON string
+    #[serde(default)]
+    pub token_count: Option<i64>,
     pub created_at: DateTimeUtc,
 }
 
@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+/// One request sent to an AI provider
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ai_provider_metrics")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub provider: String, // "local" or "gemini"
+    pub model: Option<String>,
+    pub latency: i64, // milliseconds
+    pub success: bool,
+    pub timed_out: bool,
+    pub error_code: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub input_tokens: Option<i32>,
+    pub output_tokens: Option<i32>,
+    /// US dollars, estimated from the token counts; `None` for local models
+    pub estimated_cost: Option<f64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            timed_out: Set(false),
+            created_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
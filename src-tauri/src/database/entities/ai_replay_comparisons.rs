@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ai_replay_comparisons")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub interaction_log_id: String,
+    pub original_model: String,
+    pub replay_model: String,
+    pub prompt: String,
+    pub original_response: String,
+    pub replay_response: String,
+    /// Tool calls answered from the logged results
+    pub mocked_tool_calls: i32,
+    /// Tool calls with no logged result to replay
+    pub unmatched_tool_calls: i32,
+    /// Word overlap of the two responses, from 0.0 to 1.0
+    pub similarity: f64,
+    pub response_time: Option<i64>, // milliseconds
+    pub error: Option<String>,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            created_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
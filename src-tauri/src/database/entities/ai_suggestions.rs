@@ -19,6 +19,8 @@ pub struct Model {
     pub created_at: DateTimeUtc,
     pub dismissed_at: Option<DateTimeUtc>,
     pub applied_at: Option<DateTimeUtc>,
+    pub expires_at: Option<DateTimeUtc>,
+    pub interaction_log_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
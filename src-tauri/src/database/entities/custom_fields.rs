@@ -0,0 +1,52 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "custom_fields")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub task_list_id: String,
+    pub name: String,
+    pub field_type: String,      // 'text', 'number', 'select', 'date'
+    pub options: Option<String>, // JSON array of choices for select fields
+    pub order_num: i32,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::task_lists::Entity",
+        from = "Column::TaskListId",
+        to = "super::task_lists::Column::Id"
+    )]
+    TaskList,
+    #[sea_orm(has_many = "super::custom_field_values::Entity")]
+    Values,
+}
+
+impl Related<super::task_lists::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskList.def()
+    }
+}
+
+impl Related<super::custom_field_values::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Values.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
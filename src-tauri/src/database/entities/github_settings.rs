@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+/// The GitHub connection; a single row with id "default"
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "github_settings")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    /// Personal access token; never sent back to the frontend
+    #[serde(skip_serializing)]
+    pub token: String,
+    pub api_url: String,
+    /// List for tasks imported from issues; the default list when unset
+    pub task_list_id: Option<String>,
+    /// Refresh issue states on the background scheduler
+    pub auto_refresh: bool,
+    pub last_refreshed_at: Option<DateTimeUtc>,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set("default".to_string()),
+            updated_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
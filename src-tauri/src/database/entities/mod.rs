@@ -1,15 +1,50 @@
 pub mod ai_interactions;
+pub mod ai_personas;
+pub mod ai_provider_metrics;
+pub mod ai_replay_comparisons;
 pub mod ai_suggestions;
+pub mod api_tokens;
+pub mod attachments;
+pub mod checklist_items;
+pub mod custom_field_values;
+pub mod custom_fields;
+pub mod daily_notes;
+pub mod focus_session_templates;
 pub mod focus_sessions;
+pub mod github_settings;
+pub mod goals;
+pub mod integrity_reports;
+pub mod notification_deliveries;
 pub mod periodic_task_templates;
 pub mod productivity_patterns;
+pub mod projects;
+pub mod prompt_eval_runs;
+pub mod react_chains;
+pub mod react_steps;
+pub mod saved_filters;
+pub mod session_pauses;
+pub mod session_subtask_completions;
+pub mod sync_items;
+pub mod sync_states;
+pub mod tags;
+pub mod task_audit_log;
 pub mod task_dependencies;
+pub mod task_github_links;
 pub mod task_lists;
+pub mod task_notes;
+pub mod task_reminders;
+pub mod task_sources;
+pub mod task_status_history;
+pub mod task_statuses;
+pub mod task_tags;
+pub mod task_templates;
 pub mod tasks;
 pub mod thread_messages;
 pub mod threads;
 pub mod time_sessions;
 pub mod user_preferences;
+pub mod webhook_deliveries;
+pub mod webhooks;
 
 #[cfg(test)]
 mod tests;
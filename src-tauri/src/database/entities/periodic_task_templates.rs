@@ -16,9 +16,14 @@ pub struct Model {
     pub recurrence_type: String,
     pub recurrence_interval: i32,
     pub recurrence_unit: Option<String>,
+    pub recurrence_rule: Option<String>, // Cron expression for the "cron" recurrence type
     pub start_date: DateTimeUtc,
     pub next_generation_date: DateTimeUtc,
     pub is_active: bool,
+    pub ends_at: Option<DateTimeUtc>, // No occurrences after this date
+    pub max_occurrences: Option<i32>, // Most instances the template generates
+    pub paused_until: Option<DateTimeUtc>, // No occurrences before this date
+    pub missed_occurrence_policy: Option<String>, // "catch_up" (default) or "skip"
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
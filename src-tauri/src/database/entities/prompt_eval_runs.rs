@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "prompt_eval_runs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    /// Prompt template that was evaluated, such as "system"
+    pub template_name: String,
+    /// SHA-256 of the template text
+    pub template_hash: String,
+    pub template: String,
+    pub model: String,
+    pub judge_model: Option<String>,
+    pub results: String, // JSON serialized PromptEvalResult[]
+    /// Mean judge score of the cases that were scored, from 0 to 10
+    pub average_score: Option<f64>,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            created_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+/// The ReAct chain that answered an interaction log; it shares the log's id
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "react_chains")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub session_id: String,
+    pub model_type: String, // "local" or "gemini"
+    pub duration: i64,      // milliseconds
+    pub error: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ai_interactions::Entity",
+        from = "Column::Id",
+        to = "super::ai_interactions::Column::Id"
+    )]
+    AiInteraction,
+    #[sea_orm(has_many = "super::react_steps::Entity")]
+    ReactSteps,
+}
+
+impl Related<super::ai_interactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AiInteraction.def()
+    }
+}
+
+impl Related<super::react_steps::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReactSteps.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            duration: Set(0),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
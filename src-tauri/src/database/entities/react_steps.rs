@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+/// A tool call made by a ReAct chain, with its result
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "react_steps")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub chain_id: String,
+    pub step_index: i32, // Position within the chain, first call first
+    pub tool_name: String,
+    pub arguments: String, // JSON string
+    pub result: String,    // JSON string
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration: i64, // milliseconds
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::react_chains::Entity",
+        from = "Column::ChainId",
+        to = "super::react_chains::Column::Id"
+    )]
+    ReactChain,
+}
+
+impl Related<super::react_chains::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReactChain.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            duration: Set(0),
+            created_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
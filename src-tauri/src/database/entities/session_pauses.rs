@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "session_pauses")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub session_id: String,
+    pub reason: Option<String>, // "meeting", "lunch", "interruption" or "other"
+    pub started_at: DateTimeUtc,
+    /// Unset while the session is paused
+    pub ended_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::time_sessions::Entity",
+        from = "Column::SessionId",
+        to = "super::time_sessions::Column::Id"
+    )]
+    TimeSession,
+}
+
+impl Related<super::time_sessions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TimeSession.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            started_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
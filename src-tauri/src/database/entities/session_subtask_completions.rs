@@ -0,0 +1,51 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "session_subtask_completions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub session_id: String,
+    pub subtask_id: String,
+    pub completed_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::time_sessions::Entity",
+        from = "Column::SessionId",
+        to = "super::time_sessions::Column::Id"
+    )]
+    TimeSession,
+    #[sea_orm(
+        belongs_to = "super::tasks::Entity",
+        from = "Column::SubtaskId",
+        to = "super::tasks::Column::Id"
+    )]
+    Subtask,
+}
+
+impl Related<super::time_sessions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TimeSession.def()
+    }
+}
+
+impl Related<super::tasks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Subtask.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            completed_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
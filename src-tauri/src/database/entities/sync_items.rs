@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sync_items")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub calendar_url: String,
+    pub task_id: String,
+    /// iCalendar UID of the remote item
+    pub uid: String,
+    /// Resource URL of the item on the server
+    pub href: String,
+    /// ETag of the item when it was last pushed or pulled
+    pub etag: Option<String>,
+    /// `updated_at` of the task when it was last synced; a newer value is a local change
+    pub task_updated_at: DateTimeUtc,
+    pub synced_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            synced_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sync_states")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub calendar_url: String,
+    /// WebDAV sync token of the last completed sync
+    pub sync_token: Option<String>,
+    pub last_synced_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
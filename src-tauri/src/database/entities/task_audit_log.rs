@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+/// One recorded change to a task
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "task_audit_log")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub task_id: String,           // Kept after the task is deleted
+    pub change_id: Option<String>, // Shared by the entries of one operation
+    pub action: String,            // create, update, delete
+    pub field: Option<String>,     // None for create and delete
+    pub old_value: Option<String>, // JSON
+    pub new_value: Option<String>, // JSON
+    pub source: String,            // user, ai
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            source: Set("user".to_string()),
+            created_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
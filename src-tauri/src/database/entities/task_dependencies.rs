@@ -8,7 +8,9 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
     pub task_id: String,
-    pub depends_on_id: String,
+    pub depends_on_id: String, // The related task
+    #[serde(default = "default_relation_type")] // Missing from older backups and bundles
+    pub relation_type: String, // depends_on, blocks, relates_to, duplicates or follows
     pub created_at: DateTimeUtc,
 }
 
@@ -34,10 +36,15 @@ impl Related<super::tasks::Entity> for Entity {
     }
 }
 
+fn default_relation_type() -> String {
+    "depends_on".to_string()
+}
+
 impl ActiveModelBehavior for ActiveModel {
     fn new() -> Self {
         Self {
             id: Set(uuid::Uuid::new_v4().to_string()),
+            relation_type: Set(default_relation_type()),
             created_at: Set(chrono::Utc::now()),
             ..ActiveModelTrait::default()
         }
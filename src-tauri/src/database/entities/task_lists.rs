@@ -9,6 +9,20 @@ pub struct Model {
     pub id: String,
     pub name: String,
     pub is_default: bool,
+    /// Position among task lists, lowest first
+    pub order_num: i32,
+    pub target_backlog_hours: Option<i32>, // Max hours a task should wait before being started
+    pub target_cycle_hours: Option<i32>,   // Max hours from start to completion
+    /// Rate billed per hour of work; unset for lists that are not billable
+    pub hourly_rate: Option<f64>,
+    pub color: Option<String>, // Hex color, e.g. '#3b82f6'
+    pub icon: Option<String>,  // Icon name, e.g. 'Briefcase'
+    /// Hidden from the task lists and stats without deleting anything
+    #[serde(default)]
+    pub is_archived: bool,
+    /// Project the list belongs to
+    #[serde(default)]
+    pub project_id: Option<String>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -17,6 +31,12 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::tasks::Entity")]
     Tasks,
+    #[sea_orm(
+        belongs_to = "super::projects::Entity",
+        from = "Column::ProjectId",
+        to = "super::projects::Column::Id"
+    )]
+    Project,
 }
 
 impl Related<super::tasks::Entity> for Entity {
@@ -25,10 +45,18 @@ impl Related<super::tasks::Entity> for Entity {
     }
 }
 
+impl Related<super::projects::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Project.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {
     fn new() -> Self {
         Self {
             id: Set(uuid::Uuid::new_v4().to_string()),
+            order_num: Set(0),
+            is_archived: Set(false),
             created_at: Set(chrono::Utc::now()),
             updated_at: Set(chrono::Utc::now()),
             ..ActiveModelTrait::default()
@@ -21,11 +21,17 @@ pub struct Model {
     pub project_id: Option<String>,
     pub parent_task_id: Option<String>,
     pub task_list_id: Option<String>,
-    pub subtasks: Option<String>, // JSON string
+    pub subtasks: Option<String>, // Legacy JSON string, superseded by checklist_items
     pub periodic_template_id: Option<String>,
     pub is_periodic_instance: bool,
     pub generation_date: Option<DateTimeUtc>,
+    pub effort: Option<i32>, // 1-10, optional
+    pub impact: Option<i32>, // 1-10, optional
     pub completed_at: Option<DateTimeUtc>,
+    pub archived_at: Option<DateTimeUtc>,
+    #[sea_orm(default_value = 0)]
+    #[serde(default)]
+    pub reschedule_count: i32, // Times rolled over from a day it wasn't finished on
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -50,6 +56,12 @@ pub enum Relation {
         to = "super::periodic_task_templates::Column::Id"
     )]
     PeriodicTaskTemplate,
+    #[sea_orm(
+        belongs_to = "super::projects::Entity",
+        from = "Column::ProjectId",
+        to = "super::projects::Column::Id"
+    )]
+    Project,
 }
 
 impl Related<super::task_dependencies::Entity> for Entity {
@@ -82,6 +94,12 @@ impl Related<super::periodic_task_templates::Entity> for Entity {
     }
 }
 
+impl Related<super::projects::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Project.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {
     fn new() -> Self {
         Self {
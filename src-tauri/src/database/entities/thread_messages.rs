@@ -15,6 +15,7 @@ pub struct Model {
     pub suggestions: Option<String>, // JSON serialized AISuggestion[]
     pub tool_executions: Option<String>, // JSON serialized ToolExecution[]
     pub user_feedback: Option<String>, // JSON serialized UserFeedback
+    pub action_results: Option<String>, // JSON serialized MessageActionResults
     pub timestamp: DateTimeUtc,
     pub created_at: DateTimeUtc,
 }
@@ -14,6 +14,11 @@ pub struct Model {
     pub assignment_context: Option<String>, // JSON for additional context
     pub message_count: i32,
     pub last_message_at: Option<DateTimeUtc>,
+    pub persona_id: Option<String>, // Persona the assistant answers as; unset is plain Kira
+    #[serde(default)]
+    pub summary: Option<String>, // Rolling summary of the messages up to summarized_until
+    #[serde(default)]
+    pub summarized_until: Option<DateTimeUtc>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -13,6 +13,9 @@ pub struct Model {
     pub notifications: String,     // JSON string
     pub theme: Option<String>,
     pub language: Option<String>,
+    #[sea_orm(default_value = "off")]
+    pub task_rollover: String, // "off", "today" or "backlog"
+    pub prompt_templates: Option<String>, // JSON string
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
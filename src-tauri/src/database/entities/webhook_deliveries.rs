@@ -0,0 +1,52 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, Set};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_deliveries")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub webhook_id: String,
+    pub event: String,
+    /// The exact JSON body sent, so retries carry the same signature
+    pub payload: String,
+    /// "pending", "delivered" or "failed"
+    pub status: String,
+    pub attempts: i32,
+    /// HTTP status of the last attempt, when the endpoint answered
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    /// When the next attempt is due; unset once delivered or given up
+    pub next_attempt_at: Option<DateTimeUtc>,
+    pub created_at: DateTimeUtc,
+    pub delivered_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::webhooks::Entity",
+        from = "Column::WebhookId",
+        to = "super::webhooks::Column::Id"
+    )]
+    Webhook,
+}
+
+impl Related<super::webhooks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Webhook.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {
+    fn new() -> Self {
+        Self {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            status: Set("pending".to_string()),
+            attempts: Set(0),
+            created_at: Set(chrono::Utc::now()),
+            ..ActiveModelTrait::default()
+        }
+    }
+}
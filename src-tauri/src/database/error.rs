@@ -16,6 +16,8 @@ pub enum DatabaseError {
     ValidationError(String),
     /// Transaction errors
     TransactionError(String),
+    /// The database stayed busy or locked after retrying; the operation can be tried again
+    Busy(String),
     /// Generic database errors
     DatabaseError(String),
 }
@@ -29,6 +31,7 @@ impl std::fmt::Display for DatabaseError {
             DatabaseError::NotFound(msg) => write!(f, "Not found: {}", msg),
             DatabaseError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
             DatabaseError::TransactionError(msg) => write!(f, "Transaction error: {}", msg),
+            DatabaseError::Busy(msg) => write!(f, "{}: {}", DATABASE_BUSY_ERROR, msg),
             DatabaseError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
         }
     }
@@ -38,6 +41,15 @@ impl std::error::Error for DatabaseError {}
 
 impl From<DbErr> for DatabaseError {
     fn from(err: DbErr) -> Self {
+        if let DbErr::Custom(msg) = &err {
+            if let Some(detail) = msg.strip_prefix(DATABASE_BUSY_ERROR) {
+                return DatabaseError::Busy(detail.trim_start_matches(": ").to_string());
+            }
+        }
+        if is_busy_error(&err) {
+            return DatabaseError::Busy(err.to_string());
+        }
+
         match err {
             DbErr::ConnectionAcquire(e) => DatabaseError::ConnectionError(e.to_string()),
             DbErr::Migration(e) => DatabaseError::MigrationError(e),
@@ -50,6 +62,40 @@ impl From<DbErr> for DatabaseError {
     }
 }
 
+/// Error code prefix for operations that failed because the database was busy or locked
+///
+/// The frontend looks for this code to offer a "try again" action instead of a generic failure.
+pub const DATABASE_BUSY_ERROR: &str = "DATABASE_BUSY";
+
+/// Whether an error was caused by SQLite being busy (SQLITE_BUSY) or locked (SQLITE_LOCKED)
+///
+/// Errors already tagged by [`busy_error`] are not: their retries ran out, and retrying them in
+/// an outer [`retry_on_busy`](crate::database::repositories::retry::retry_on_busy) would only
+/// multiply the attempts.
+pub fn is_busy_error(err: &DbErr) -> bool {
+    let message = err.to_string();
+    if message.contains(DATABASE_BUSY_ERROR) {
+        return false;
+    }
+    let message = message.to_lowercase();
+    message.contains("database is locked")
+        || message.contains("database table is locked")
+        || message.contains("database schema is locked")
+        || message.contains("(code: 5)")
+        || message.contains("(code: 6)")
+}
+
+/// Build the error returned once retries on a busy database are exhausted
+///
+/// Only the `DATABASE_BUSY` marker is kept; the SQLite message is logged instead.
+pub fn busy_error(err: &DbErr) -> DbErr {
+    log::warn!("Giving up on a busy database: {}", err);
+    DbErr::Custom(format!(
+        "{}: The database is busy, please try again",
+        DATABASE_BUSY_ERROR
+    ))
+}
+
 /// Result type alias for database operations
 #[allow(dead_code)]
 pub type DatabaseResult<T> = Result<T, DatabaseError>;
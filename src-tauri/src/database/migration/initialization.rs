@@ -137,7 +137,7 @@ mod tests {
             title: "Orphaned Task 1".to_string(),
             description: Some("Test orphaned task".to_string()),
             priority: 1,
-            status: Some("todo".to_string()),
+            status: Some("pending".to_string()),
             dependencies: None,
             due_date: None,
             scheduled_date: None,
@@ -146,6 +146,12 @@ mod tests {
             parent_task_id: None,
             task_list_id: None, // This will be set to default during creation
             time_estimate: Some(0),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         }).await.expect("Failed to create task");
 
         // Manually set task_list_id to null to simulate orphaned state
@@ -62,7 +62,7 @@ mod integration_tests {
             title: "Task in custom list".to_string(),
             description: Some("Test task".to_string()),
             priority: 1,
-            status: Some("todo".to_string()),
+            status: Some("pending".to_string()),
             dependencies: None,
             due_date: None,
             scheduled_date: None,
@@ -71,13 +71,19 @@ mod integration_tests {
             parent_task_id: None,
             task_list_id: Some(custom_list.id.clone()),
             time_estimate: Some(0),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         }).await.expect("Failed to create task");
 
         let task2 = task_repo.create_task(CreateTaskRequest {
             title: "Task in default list".to_string(),
             description: Some("Test task".to_string()),
             priority: 1,
-            status: Some("todo".to_string()),
+            status: Some("pending".to_string()),
             dependencies: None,
             due_date: None,
             scheduled_date: None,
@@ -86,6 +92,12 @@ mod integration_tests {
             parent_task_id: None,
             task_list_id: None, // Will be assigned to default
             time_estimate: Some(0),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         }).await.expect("Failed to create task");
 
         // Manually create an orphaned task by setting task_list_id to null
@@ -140,7 +152,7 @@ mod integration_tests {
                 title: "Task 1".to_string(),
                 description: Some("Test task 1".to_string()),
                 priority: 1,
-                status: Some("todo".to_string()),
+                status: Some("pending".to_string()),
                 dependencies: None,
                 due_date: None,
                 scheduled_date: None,
@@ -149,6 +161,12 @@ mod integration_tests {
                 parent_task_id: None,
                 task_list_id: None,
                 time_estimate: Some(0),
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
             }).await.expect("Failed to create task 1"),
             task_repo.create_task(CreateTaskRequest {
                 title: "Task 2".to_string(),
@@ -163,6 +181,12 @@ mod integration_tests {
                 parent_task_id: None,
                 task_list_id: None,
                 time_estimate: Some(0),
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
             }).await.expect("Failed to create task 2"),
             task_repo.create_task(CreateTaskRequest {
                 title: "Task 3".to_string(),
@@ -177,6 +201,12 @@ mod integration_tests {
                 parent_task_id: None,
                 task_list_id: None,
                 time_estimate: Some(0),
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
             }).await.expect("Failed to create task 3"),
         ];
 
@@ -239,7 +269,7 @@ mod integration_tests {
             title: "Parent Task".to_string(),
             description: Some("Parent task".to_string()),
             priority: 1,
-            status: Some("todo".to_string()),
+            status: Some("pending".to_string()),
             dependencies: None,
             due_date: None,
             scheduled_date: None,
@@ -248,13 +278,19 @@ mod integration_tests {
             parent_task_id: None,
             task_list_id: None,
             time_estimate: Some(0),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         }).await.expect("Failed to create parent task");
 
         let task2 = task_repo.create_task(CreateTaskRequest {
             title: "Child Task".to_string(),
             description: Some("Child task".to_string()),
             priority: 2,
-            status: Some("todo".to_string()),
+            status: Some("pending".to_string()),
             dependencies: None,
             due_date: None,
             scheduled_date: None,
@@ -263,6 +299,12 @@ mod integration_tests {
             parent_task_id: Some(task1.id.clone()),
             task_list_id: None,
             time_estimate: Some(0),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         }).await.expect("Failed to create child task");
 
         // Add dependency
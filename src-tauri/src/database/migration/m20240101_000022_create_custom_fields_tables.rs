@@ -0,0 +1,177 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomFields::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CustomFields::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CustomFields::TaskListId).string().not_null())
+                    .col(ColumnDef::new(CustomFields::Name).string().not_null())
+                    .col(ColumnDef::new(CustomFields::FieldType).string().not_null())
+                    .col(ColumnDef::new(CustomFields::Options).text())
+                    .col(
+                        ColumnDef::new(CustomFields::OrderNum)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(CustomFields::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CustomFields::UpdatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_custom_fields_task_list_id")
+                            .from(CustomFields::Table, CustomFields::TaskListId)
+                            .to(TaskLists::Table, TaskLists::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_custom_fields_task_list_name")
+                    .table(CustomFields::Table)
+                    .col(CustomFields::TaskListId)
+                    .col(CustomFields::Name)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomFieldValues::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CustomFieldValues::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CustomFieldValues::TaskId).string().not_null())
+                    .col(ColumnDef::new(CustomFieldValues::FieldId).string().not_null())
+                    .col(ColumnDef::new(CustomFieldValues::Value).text().not_null())
+                    .col(
+                        ColumnDef::new(CustomFieldValues::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CustomFieldValues::UpdatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_custom_field_values_task_id")
+                            .from(CustomFieldValues::Table, CustomFieldValues::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_custom_field_values_field_id")
+                            .from(CustomFieldValues::Table, CustomFieldValues::FieldId)
+                            .to(CustomFields::Table, CustomFields::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_custom_field_values_task_field")
+                    .table(CustomFieldValues::Table)
+                    .col(CustomFieldValues::TaskId)
+                    .col(CustomFieldValues::FieldId)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_custom_field_values_field_value")
+                    .table(CustomFieldValues::Table)
+                    .col(CustomFieldValues::FieldId)
+                    .col(CustomFieldValues::Value)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CustomFieldValues::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CustomFields::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustomFields {
+    Table,
+    Id,
+    TaskListId,
+    Name,
+    FieldType,
+    Options,
+    OrderNum,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum CustomFieldValues {
+    Table,
+    Id,
+    TaskId,
+    FieldId,
+    Value,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum TaskLists {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
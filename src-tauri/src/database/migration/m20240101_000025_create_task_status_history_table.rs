@@ -0,0 +1,109 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskStatusHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskStatusHistory::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskStatusHistory::TaskId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TaskStatusHistory::FromStatus).string())
+                    .col(
+                        ColumnDef::new(TaskStatusHistory::ToStatus)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskStatusHistory::ChangedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_status_history_task_id")
+                            .from(TaskStatusHistory::Table, TaskStatusHistory::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_status_history_task_changed")
+                    .table(TaskStatusHistory::Table)
+                    .col(TaskStatusHistory::TaskId)
+                    .col(TaskStatusHistory::ChangedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Seed history for existing tasks: creation, plus the move to their current status
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO task_status_history (id, task_id, from_status, to_status, changed_at)
+                SELECT lower(hex(randomblob(16))), id, NULL, 'pending', created_at
+                FROM tasks
+                "#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO task_status_history (id, task_id, from_status, to_status, changed_at)
+                SELECT lower(hex(randomblob(16))), id, 'pending', status,
+                       COALESCE(completed_at, updated_at)
+                FROM tasks
+                WHERE status <> 'pending'
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskStatusHistory::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskStatusHistory {
+    Table,
+    Id,
+    TaskId,
+    FromStatus,
+    ToStatus,
+    ChangedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Target hours a task may wait in the backlog before it is started
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .add_column(ColumnDef::new(TaskLists::TargetBacklogHours).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Target hours from starting a task to completing it
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .add_column(ColumnDef::new(TaskLists::TargetCycleHours).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .drop_column(TaskLists::TargetCycleHours)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .drop_column(TaskLists::TargetBacklogHours)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskLists {
+    Table,
+    TargetBacklogHours,
+    TargetCycleHours,
+}
@@ -0,0 +1,110 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskStatuses::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskStatuses::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TaskStatuses::Name).string().not_null())
+                    .col(ColumnDef::new(TaskStatuses::Category).string().not_null())
+                    .col(ColumnDef::new(TaskStatuses::Color).string())
+                    .col(
+                        ColumnDef::new(TaskStatuses::OrderNum)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(TaskStatuses::IsSystem)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(TaskStatuses::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TaskStatuses::UpdatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_statuses_name")
+                    .table(TaskStatuses::Table)
+                    .col(TaskStatuses::Name)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Built-in statuses the application logic relies on
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT OR IGNORE INTO task_statuses (id, name, category, color, order_num, is_system)
+                VALUES
+                    ('status-pending', 'pending', 'todo', '#6b7280', 0, 1),
+                    ('status-in-progress', 'in_progress', 'doing', '#3b82f6', 1, 1),
+                    ('status-completed', 'completed', 'done', '#22c55e', 2, 1),
+                    ('status-cancelled', 'cancelled', 'done', '#ef4444', 3, 1)
+                "#,
+            )
+            .await?;
+
+        // Keep any other status values already stored on tasks
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT OR IGNORE INTO task_statuses (id, name, category, order_num, is_system)
+                SELECT lower(hex(randomblob(16))), status, 'todo', 100, 0
+                FROM (SELECT DISTINCT status FROM tasks)
+                WHERE status NOT IN ('pending', 'in_progress', 'completed', 'cancelled')
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskStatuses::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskStatuses {
+    Table,
+    Id,
+    Name,
+    Category,
+    Color,
+    OrderNum,
+    IsSystem,
+    CreatedAt,
+    UpdatedAt,
+}
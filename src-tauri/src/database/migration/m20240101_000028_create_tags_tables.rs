@@ -0,0 +1,170 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tags::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Tags::Id).string().not_null().primary_key())
+                    .col(ColumnDef::new(Tags::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(Tags::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Tags::UpdatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tags_name")
+                    .table(Tags::Table)
+                    .col(Tags::Name)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskTags::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskTags::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TaskTags::TaskId).string().not_null())
+                    .col(ColumnDef::new(TaskTags::TagId).string().not_null())
+                    .col(
+                        ColumnDef::new(TaskTags::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_tags_task_id")
+                            .from(TaskTags::Table, TaskTags::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_tags_tag_id")
+                            .from(TaskTags::Table, TaskTags::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_tags_task_tag")
+                    .table(TaskTags::Table)
+                    .col(TaskTags::TaskId)
+                    .col(TaskTags::TagId)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_tags_tag_id")
+                    .table(TaskTags::Table)
+                    .col(TaskTags::TagId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Copy tags out of the JSON column on tasks
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT OR IGNORE INTO tags (id, name)
+                SELECT lower(hex(randomblob(16))), name
+                FROM (
+                    SELECT DISTINCT trim(tag.value) AS name
+                    FROM tasks, json_each(
+                        CASE WHEN json_valid(tasks.tags) THEN tasks.tags ELSE '[]' END
+                    ) AS tag
+                )
+                WHERE name <> ''
+                "#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT OR IGNORE INTO task_tags (id, task_id, tag_id)
+                SELECT lower(hex(randomblob(16))), tasks.id, tags.id
+                FROM tasks, json_each(
+                    CASE WHEN json_valid(tasks.tags) THEN tasks.tags ELSE '[]' END
+                ) AS tag
+                JOIN tags ON tags.name = trim(tag.value)
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskTags::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Tags::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tags {
+    Table,
+    Id,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum TaskTags {
+    Table,
+    Id,
+    TaskId,
+    TagId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
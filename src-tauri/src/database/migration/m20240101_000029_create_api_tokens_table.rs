@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApiTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ApiTokens::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ApiTokens::Name).string().not_null())
+                    .col(ColumnDef::new(ApiTokens::TokenHash).string().not_null())
+                    .col(ColumnDef::new(ApiTokens::Scopes).text().not_null())
+                    .col(ColumnDef::new(ApiTokens::LastUsedAt).timestamp().null())
+                    .col(ColumnDef::new(ApiTokens::RevokedAt).timestamp().null())
+                    .col(
+                        ColumnDef::new(ApiTokens::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_api_tokens_token_hash")
+                    .table(ApiTokens::Table)
+                    .col(ApiTokens::TokenHash)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApiTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ApiTokens {
+    Table,
+    Id,
+    Name,
+    TokenHash,
+    Scopes,
+    LastUsedAt,
+    RevokedAt,
+    CreatedAt,
+}
@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Cron expression used by templates with the "cron" recurrence type
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .add_column(ColumnDef::new(PeriodicTaskTemplates::RecurrenceRule).text())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .drop_column(PeriodicTaskTemplates::RecurrenceRule)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PeriodicTaskTemplates {
+    Table,
+    RecurrenceRule,
+}
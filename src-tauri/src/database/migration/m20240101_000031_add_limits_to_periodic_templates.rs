@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Optional end of a series: no occurrences after this date
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .add_column(
+                        ColumnDef::new(PeriodicTaskTemplates::EndsAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Optional cap on the number of generated instances
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .add_column(
+                        ColumnDef::new(PeriodicTaskTemplates::MaxOccurrences)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .drop_column(PeriodicTaskTemplates::MaxOccurrences)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .drop_column(PeriodicTaskTemplates::EndsAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PeriodicTaskTemplates {
+    Table,
+    EndsAt,
+    MaxOccurrences,
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // No occurrences are generated before this date
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .add_column(
+                        ColumnDef::new(PeriodicTaskTemplates::PausedUntil)
+                            .timestamp()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // What happens to occurrences missed while paused: "catch_up" (default) or "skip"
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .add_column(
+                        ColumnDef::new(PeriodicTaskTemplates::MissedOccurrencePolicy)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .drop_column(PeriodicTaskTemplates::MissedOccurrencePolicy)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PeriodicTaskTemplates::Table)
+                    .drop_column(PeriodicTaskTemplates::PausedUntil)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PeriodicTaskTemplates {
+    Table,
+    PausedUntil,
+    MissedOccurrencePolicy,
+}
@@ -0,0 +1,124 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FocusSessionTemplates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::Name)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::FocusDuration)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::ShortBreakDuration)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::LongBreakDuration)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::SessionsBeforeLongBreak)
+                            .integer()
+                            .not_null()
+                            .default(4),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::DistractionLevel)
+                            .string()
+                            .not_null()
+                            .default("moderate"),
+                    )
+                    .col(ColumnDef::new(FocusSessionTemplates::BackgroundAudio).text())
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::IsBuiltin)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(FocusSessionTemplates::UpdatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_focus_session_templates_name")
+                    .table(FocusSessionTemplates::Table)
+                    .col(FocusSessionTemplates::Name)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Built-in presets
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT OR IGNORE INTO focus_session_templates
+                    (id, name, focus_duration, short_break_duration, long_break_duration,
+                     sessions_before_long_break, distraction_level, is_builtin)
+                VALUES
+                    ('focus-template-pomodoro', 'Pomodoro', 25, 5, 15, 4, 'moderate', 1),
+                    ('focus-template-52-17', '52/17', 52, 17, 17, 1, 'minimal', 1)
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FocusSessionTemplates::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum FocusSessionTemplates {
+    Table,
+    Id,
+    Name,
+    FocusDuration,
+    ShortBreakDuration,
+    LongBreakDuration,
+    SessionsBeforeLongBreak,
+    DistractionLevel,
+    BackgroundAudio,
+    IsBuiltin,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,114 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SessionSubtaskCompletions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SessionSubtaskCompletions::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SessionSubtaskCompletions::SessionId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SessionSubtaskCompletions::SubtaskId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SessionSubtaskCompletions::CompletedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_session_subtask_completions_session_id")
+                            .from(
+                                SessionSubtaskCompletions::Table,
+                                SessionSubtaskCompletions::SessionId,
+                            )
+                            .to(TimeSessions::Table, TimeSessions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_session_subtask_completions_subtask_id")
+                            .from(
+                                SessionSubtaskCompletions::Table,
+                                SessionSubtaskCompletions::SubtaskId,
+                            )
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_session_subtask_completions_session")
+                    .table(SessionSubtaskCompletions::Table)
+                    .col(SessionSubtaskCompletions::SessionId)
+                    .col(SessionSubtaskCompletions::CompletedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_session_subtask_completions_subtask")
+                    .table(SessionSubtaskCompletions::Table)
+                    .col(SessionSubtaskCompletions::SubtaskId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(SessionSubtaskCompletions::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SessionSubtaskCompletions {
+    Table,
+    Id,
+    SessionId,
+    SubtaskId,
+    CompletedAt,
+}
+
+#[derive(DeriveIden)]
+enum TimeSessions {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
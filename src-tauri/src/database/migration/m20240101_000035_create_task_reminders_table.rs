@@ -0,0 +1,91 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskReminders::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskReminders::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TaskReminders::TaskId).string().not_null())
+                    .col(ColumnDef::new(TaskReminders::Anchor).string().not_null())
+                    .col(
+                        ColumnDef::new(TaskReminders::OffsetMinutes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(TaskReminders::RemindAt).timestamp())
+                    .col(ColumnDef::new(TaskReminders::SnoozedUntil).timestamp())
+                    .col(ColumnDef::new(TaskReminders::LastFiredFor).timestamp())
+                    .col(
+                        ColumnDef::new(TaskReminders::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TaskReminders::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_reminders_task_id")
+                            .from(TaskReminders::Table, TaskReminders::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_reminders_task_id")
+                    .table(TaskReminders::Table)
+                    .col(TaskReminders::TaskId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskReminders::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskReminders {
+    Table,
+    Id,
+    TaskId,
+    Anchor,
+    OffsetMinutes,
+    RemindAt,
+    SnoozedUntil,
+    LastFiredFor,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
@@ -0,0 +1,115 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AiReplayComparisons::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::InteractionLogId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::OriginalModel)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::ReplayModel)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::Prompt)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::OriginalResponse)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::ReplayResponse)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::MockedToolCalls)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::UnmatchedToolCalls)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::Similarity)
+                            .double()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(ColumnDef::new(AiReplayComparisons::ResponseTime).big_integer())
+                    .col(ColumnDef::new(AiReplayComparisons::Error).text())
+                    .col(
+                        ColumnDef::new(AiReplayComparisons::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ai_replay_comparisons_interaction_log_id")
+                    .table(AiReplayComparisons::Table)
+                    .col(AiReplayComparisons::InteractionLogId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AiReplayComparisons::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiReplayComparisons {
+    Table,
+    Id,
+    InteractionLogId,
+    OriginalModel,
+    ReplayModel,
+    Prompt,
+    OriginalResponse,
+    ReplayResponse,
+    MockedToolCalls,
+    UnmatchedToolCalls,
+    Similarity,
+    ResponseTime,
+    Error,
+    CreatedAt,
+}
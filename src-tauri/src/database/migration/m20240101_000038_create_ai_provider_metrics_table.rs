@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AiProviderMetrics::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AiProviderMetrics::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AiProviderMetrics::Provider)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AiProviderMetrics::Model).string())
+                    .col(
+                        ColumnDef::new(AiProviderMetrics::Latency)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiProviderMetrics::Success)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AiProviderMetrics::TimedOut)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(AiProviderMetrics::ErrorCode).string())
+                    .col(
+                        ColumnDef::new(AiProviderMetrics::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ai_provider_metrics_provider_created_at")
+                    .table(AiProviderMetrics::Table)
+                    .col(AiProviderMetrics::Provider)
+                    .col(AiProviderMetrics::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AiProviderMetrics::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiProviderMetrics {
+    Table,
+    Id,
+    Provider,
+    Model,
+    Latency,
+    Success,
+    TimedOut,
+    ErrorCode,
+    CreatedAt,
+}
@@ -0,0 +1,168 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChecklistItems::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ChecklistItems::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ChecklistItems::TaskId).string().not_null())
+                    .col(ColumnDef::new(ChecklistItems::Title).string().not_null())
+                    .col(
+                        ColumnDef::new(ChecklistItems::OrderNum)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(ChecklistItems::CompletedAt).timestamp())
+                    .col(
+                        ColumnDef::new(ChecklistItems::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ChecklistItems::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_items_task_id")
+                            .from(ChecklistItems::Table, ChecklistItems::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_checklist_items_task_id_order")
+                    .table(ChecklistItems::Table)
+                    .col(ChecklistItems::TaskId)
+                    .col(ChecklistItems::OrderNum)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Entries of the JSON column that name an existing task are real subtasks; link them
+        // through parent_task_id instead of copying them as checklist items
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                UPDATE tasks AS child
+                SET parent_task_id = (
+                    SELECT parent.id
+                    FROM tasks AS parent, json_each(
+                        CASE
+                            WHEN NOT json_valid(parent.subtasks) THEN '[]'
+                            WHEN json_type(parent.subtasks) = 'array' THEN parent.subtasks
+                            ELSE '[]'
+                        END
+                    ) AS item
+                    WHERE item.type = 'text' AND item.value = child.id AND parent.id <> child.id
+                    LIMIT 1
+                )
+                WHERE child.parent_task_id IS NULL
+                AND EXISTS (
+                    SELECT 1
+                    FROM tasks AS parent, json_each(
+                        CASE
+                            WHEN NOT json_valid(parent.subtasks) THEN '[]'
+                            WHEN json_type(parent.subtasks) = 'array' THEN parent.subtasks
+                            ELSE '[]'
+                        END
+                    ) AS item
+                    WHERE item.type = 'text' AND item.value = child.id AND parent.id <> child.id
+                )
+                "#,
+            )
+            .await?;
+
+        // Everything else becomes a checklist item, keeping the original order
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO checklist_items
+                    (id, task_id, title, order_num, completed_at, created_at, updated_at)
+                SELECT lower(hex(randomblob(16))), task_id, title, (position + 1) * 1024,
+                    CASE WHEN done THEN updated_at END, created_at, updated_at
+                FROM (
+                    SELECT tasks.id AS task_id,
+                        trim(CASE
+                            WHEN item.type = 'text' THEN item.value
+                            WHEN item.type = 'object' THEN coalesce(
+                                json_extract(item.value, '$.title'),
+                                json_extract(item.value, '$.text')
+                            )
+                        END) AS title,
+                        item.key AS position,
+                        CASE WHEN item.type = 'object' THEN coalesce(
+                            json_extract(item.value, '$.completed'),
+                            json_extract(item.value, '$.done'),
+                            0
+                        ) ELSE 0 END AS done,
+                        tasks.created_at,
+                        tasks.updated_at
+                    FROM tasks, json_each(
+                        CASE
+                            WHEN NOT json_valid(tasks.subtasks) THEN '[]'
+                            WHEN json_type(tasks.subtasks) = 'array' THEN tasks.subtasks
+                            ELSE '[]'
+                        END
+                    ) AS item
+                    WHERE NOT EXISTS (
+                        SELECT 1 FROM tasks AS existing
+                        WHERE item.type = 'text' AND existing.id = item.value
+                    )
+                )
+                WHERE title IS NOT NULL AND title <> ''
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ChecklistItems::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ChecklistItems {
+    Table,
+    Id,
+    TaskId,
+    Title,
+    OrderNum,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DailyNotes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DailyNotes::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(DailyNotes::Date).date().not_null())
+                    .col(
+                        ColumnDef::new(DailyNotes::Content)
+                            .text()
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(
+                        ColumnDef::new(DailyNotes::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(DailyNotes::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_daily_notes_date")
+                    .table(DailyNotes::Table)
+                    .col(DailyNotes::Date)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DailyNotes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum DailyNotes {
+    Table,
+    Id,
+    Date,
+    Content,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Existing rows are plain dependencies
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskDependencies::Table)
+                    .add_column(
+                        ColumnDef::new(TaskDependencies::RelationType)
+                            .string()
+                            .not_null()
+                            .default("depends_on"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_dependencies_relation_type")
+                    .table(TaskDependencies::Table)
+                    .col(TaskDependencies::RelationType)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_task_dependencies_relation_type")
+                    .table(TaskDependencies::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskDependencies::Table)
+                    .drop_column(TaskDependencies::RelationType)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskDependencies {
+    Table,
+    RelationType,
+}
@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // No foreign key on task_id: the history of a task outlives the task
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskAuditLog::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskAuditLog::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TaskAuditLog::TaskId).string().not_null())
+                    .col(ColumnDef::new(TaskAuditLog::Action).string().not_null())
+                    .col(ColumnDef::new(TaskAuditLog::Field).string().null())
+                    .col(ColumnDef::new(TaskAuditLog::OldValue).text().null())
+                    .col(ColumnDef::new(TaskAuditLog::NewValue).text().null())
+                    .col(
+                        ColumnDef::new(TaskAuditLog::Source)
+                            .string()
+                            .not_null()
+                            .default("user"),
+                    )
+                    .col(
+                        ColumnDef::new(TaskAuditLog::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_audit_log_task_id_created_at")
+                    .table(TaskAuditLog::Table)
+                    .col(TaskAuditLog::TaskId)
+                    .col(TaskAuditLog::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_audit_log_created_at")
+                    .table(TaskAuditLog::Table)
+                    .col(TaskAuditLog::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskAuditLog::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskAuditLog {
+    Table,
+    Id,
+    TaskId,
+    Action,
+    Field,
+    OldValue,
+    NewValue,
+    Source,
+    CreatedAt,
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Groups the entries written by one operation; existing rows stay ungrouped
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskAuditLog::Table)
+                    .add_column(ColumnDef::new(TaskAuditLog::ChangeId).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_audit_log_change_id")
+                    .table(TaskAuditLog::Table)
+                    .col(TaskAuditLog::ChangeId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_task_audit_log_change_id")
+                    .table(TaskAuditLog::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskAuditLog::Table)
+                    .drop_column(TaskAuditLog::ChangeId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskAuditLog {
+    Table,
+    ChangeId,
+}
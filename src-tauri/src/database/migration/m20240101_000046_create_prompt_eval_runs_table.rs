@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PromptEvalRuns::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PromptEvalRuns::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PromptEvalRuns::TemplateName)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PromptEvalRuns::TemplateHash)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PromptEvalRuns::Template).text().not_null())
+                    .col(ColumnDef::new(PromptEvalRuns::Model).string().not_null())
+                    .col(ColumnDef::new(PromptEvalRuns::JudgeModel).string())
+                    .col(ColumnDef::new(PromptEvalRuns::Results).text().not_null())
+                    .col(ColumnDef::new(PromptEvalRuns::AverageScore).double())
+                    .col(
+                        ColumnDef::new(PromptEvalRuns::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_prompt_eval_runs_template_name")
+                    .table(PromptEvalRuns::Table)
+                    .col(PromptEvalRuns::TemplateName)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PromptEvalRuns::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PromptEvalRuns {
+    Table,
+    Id,
+    TemplateName,
+    TemplateHash,
+    Template,
+    Model,
+    JudgeModel,
+    Results,
+    AverageScore,
+    CreatedAt,
+}
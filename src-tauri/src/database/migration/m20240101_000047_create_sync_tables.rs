@@ -0,0 +1,114 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // No foreign key on task_id: a deleted task's item is needed to delete it remotely
+        manager
+            .create_table(
+                Table::create()
+                    .table(SyncItems::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SyncItems::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SyncItems::CalendarUrl).string().not_null())
+                    .col(ColumnDef::new(SyncItems::TaskId).string().not_null())
+                    .col(ColumnDef::new(SyncItems::Uid).string().not_null())
+                    .col(ColumnDef::new(SyncItems::Href).string().not_null())
+                    .col(ColumnDef::new(SyncItems::Etag).string().null())
+                    .col(
+                        ColumnDef::new(SyncItems::TaskUpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SyncItems::SyncedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sync_items_calendar_url_task_id")
+                    .table(SyncItems::Table)
+                    .col(SyncItems::CalendarUrl)
+                    .col(SyncItems::TaskId)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sync_items_calendar_url_href")
+                    .table(SyncItems::Table)
+                    .col(SyncItems::CalendarUrl)
+                    .col(SyncItems::Href)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SyncStates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SyncStates::CalendarUrl)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SyncStates::SyncToken).string().null())
+                    .col(ColumnDef::new(SyncStates::LastSyncedAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SyncStates::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(SyncItems::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SyncItems {
+    Table,
+    Id,
+    CalendarUrl,
+    TaskId,
+    Uid,
+    Href,
+    Etag,
+    TaskUpdatedAt,
+    SyncedAt,
+}
+
+#[derive(DeriveIden)]
+enum SyncStates {
+    Table,
+    CalendarUrl,
+    SyncToken,
+    LastSyncedAt,
+}
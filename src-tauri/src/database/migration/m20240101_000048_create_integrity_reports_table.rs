@@ -0,0 +1,100 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IntegrityReports::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(IntegrityReports::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::IsHealthy)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::HasDefaultTaskList)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::DefaultTaskListId)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::OrphanedTasksCount)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::TotalTasks)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::TotalTaskLists)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::Notified)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrityReports::CheckedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_integrity_reports_checked_at")
+                    .table(IntegrityReports::Table)
+                    .col(IntegrityReports::CheckedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IntegrityReports::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum IntegrityReports {
+    Table,
+    Id,
+    IsHealthy,
+    HasDefaultTaskList,
+    DefaultTaskListId,
+    OrphanedTasksCount,
+    TotalTasks,
+    TotalTaskLists,
+    Notified,
+    CheckedAt,
+}
@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GithubSettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GithubSettings::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(GithubSettings::Token).string().not_null())
+                    .col(ColumnDef::new(GithubSettings::ApiUrl).string().not_null())
+                    .col(ColumnDef::new(GithubSettings::TaskListId).string().null())
+                    .col(
+                        ColumnDef::new(GithubSettings::AutoRefresh)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(GithubSettings::LastRefreshedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(GithubSettings::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskGithubLinks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskGithubLinks::TaskId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskGithubLinks::Repository)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskGithubLinks::IssueNumber)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskGithubLinks::IssueUrl)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskGithubLinks::IssueState)
+                            .string()
+                            .not_null()
+                            .default("open"),
+                    )
+                    .col(
+                        ColumnDef::new(TaskGithubLinks::SyncedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_github_links_task_id")
+                            .from(TaskGithubLinks::Table, TaskGithubLinks::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_github_links_issue")
+                    .table(TaskGithubLinks::Table)
+                    .col(TaskGithubLinks::Repository)
+                    .col(TaskGithubLinks::IssueNumber)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskGithubLinks::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(GithubSettings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GithubSettings {
+    Table,
+    Id,
+    Token,
+    ApiUrl,
+    TaskListId,
+    AutoRefresh,
+    LastRefreshedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum TaskGithubLinks {
+    Table,
+    TaskId,
+    Repository,
+    IssueNumber,
+    IssueUrl,
+    IssueState,
+    SyncedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskSources::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskSources::TaskId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TaskSources::SourceType).string().not_null())
+                    .col(ColumnDef::new(TaskSources::ExternalId).string().null())
+                    .col(ColumnDef::new(TaskSources::Url).text().null())
+                    .col(
+                        ColumnDef::new(TaskSources::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_sources_task_id")
+                            .from(TaskSources::Table, TaskSources::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_sources_external_id")
+                    .table(TaskSources::Table)
+                    .col(TaskSources::SourceType)
+                    .col(TaskSources::ExternalId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskSources::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskSources {
+    Table,
+    TaskId,
+    SourceType,
+    ExternalId,
+    Url,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
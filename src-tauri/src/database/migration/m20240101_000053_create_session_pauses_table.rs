@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SessionPauses::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SessionPauses::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SessionPauses::SessionId).string().not_null())
+                    .col(ColumnDef::new(SessionPauses::Reason).string().null())
+                    .col(
+                        ColumnDef::new(SessionPauses::StartedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SessionPauses::EndedAt).timestamp().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_session_pauses_session_id")
+                            .from(SessionPauses::Table, SessionPauses::SessionId)
+                            .to(TimeSessions::Table, TimeSessions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_session_pauses_session")
+                    .table(SessionPauses::Table)
+                    .col(SessionPauses::SessionId)
+                    .col(SessionPauses::StartedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SessionPauses::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SessionPauses {
+    Table,
+    Id,
+    SessionId,
+    Reason,
+    StartedAt,
+    EndedAt,
+}
+
+#[derive(DeriveIden)]
+enum TimeSessions {
+    Table,
+    Id,
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite adds one column per ALTER TABLE statement
+        for column in [
+            ColumnDef::new(AiProviderMetrics::InputTokens)
+                .integer()
+                .to_owned(),
+            ColumnDef::new(AiProviderMetrics::OutputTokens)
+                .integer()
+                .to_owned(),
+            ColumnDef::new(AiProviderMetrics::EstimatedCost)
+                .double()
+                .to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(AiProviderMetrics::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            AiProviderMetrics::InputTokens,
+            AiProviderMetrics::OutputTokens,
+            AiProviderMetrics::EstimatedCost,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(AiProviderMetrics::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiProviderMetrics {
+    Table,
+    InputTokens,
+    OutputTokens,
+    EstimatedCost,
+}
@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AiPersonas::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AiPersonas::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AiPersonas::Name)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(AiPersonas::SystemPrompt).text().not_null())
+                    .col(ColumnDef::new(AiPersonas::Tone).string().not_null())
+                    .col(ColumnDef::new(AiPersonas::AllowedTools).text().null())
+                    .col(ColumnDef::new(AiPersonas::DefaultModel).string().null())
+                    .col(
+                        ColumnDef::new(AiPersonas::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(AiPersonas::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite can't add a foreign key to an existing table; deleting a persona clears it instead
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Threads::Table)
+                    .add_column(ColumnDef::new(Threads::PersonaId).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Threads::Table)
+                    .drop_column(Threads::PersonaId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AiPersonas::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiPersonas {
+    Table,
+    Id,
+    Name,
+    SystemPrompt,
+    Tone,
+    AllowedTools,
+    DefaultModel,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Threads {
+    Table,
+    PersonaId,
+}
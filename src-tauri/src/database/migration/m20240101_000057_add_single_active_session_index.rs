@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Keep the latest running session; older ones are left paused so no time is lost
+        let pause_duplicates_sql = r#"
+            UPDATE time_sessions SET is_active = 0
+            WHERE is_active = 1 AND id NOT IN (
+                SELECT id FROM time_sessions
+                WHERE is_active = 1
+                ORDER BY start_time DESC
+                LIMIT 1
+            )
+        "#;
+        manager
+            .get_connection()
+            .execute_unprepared(pause_duplicates_sql)
+            .await?;
+
+        // At most one session may be running, even when commands race
+        let create_partial_index_sql = r#"
+            CREATE UNIQUE INDEX IF NOT EXISTS idx_time_sessions_single_active
+            ON time_sessions (is_active)
+            WHERE is_active = 1
+        "#;
+        manager
+            .get_connection()
+            .execute_unprepared(create_partial_index_sql)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS idx_time_sessions_single_active")
+            .await?;
+
+        Ok(())
+    }
+}
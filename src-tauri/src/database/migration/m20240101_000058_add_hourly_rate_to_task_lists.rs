@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Rate billed per hour of work on the list's tasks; lists without one are not billable
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .add_column(ColumnDef::new(TaskLists::HourlyRate).double())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .drop_column(TaskLists::HourlyRate)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskLists {
+    Table,
+    HourlyRate,
+}
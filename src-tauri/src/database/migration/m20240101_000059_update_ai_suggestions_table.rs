@@ -0,0 +1,77 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The entity has always read the type as `suggestion_type`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiSuggestions::Table)
+                    .rename_column(AiSuggestions::Type, AiSuggestions::SuggestionType)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Unanswered suggestions are dropped once expired
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiSuggestions::Table)
+                    .add_column(ColumnDef::new(AiSuggestions::ExpiresAt).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        // AI interaction log the suggestion came from, if any
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiSuggestions::Table)
+                    .add_column(ColumnDef::new(AiSuggestions::InteractionLogId).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiSuggestions::Table)
+                    .drop_column(AiSuggestions::InteractionLogId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiSuggestions::Table)
+                    .drop_column(AiSuggestions::ExpiresAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiSuggestions::Table)
+                    .rename_column(AiSuggestions::SuggestionType, AiSuggestions::Type)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiSuggestions {
+    Table,
+    Type,
+    SuggestionType,
+    ExpiresAt,
+    InteractionLogId,
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // How often a task was moved on from a day it wasn't finished on
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tasks::Table)
+                    .add_column(
+                        ColumnDef::new(Tasks::RescheduleCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Where unfinished tasks go at the end of the day: "off", "today" or "backlog"
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPreferences::Table)
+                    .add_column(
+                        ColumnDef::new(UserPreferences::TaskRollover)
+                            .string()
+                            .not_null()
+                            .default("off"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPreferences::Table)
+                    .drop_column(UserPreferences::TaskRollover)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tasks::Table)
+                    .drop_column(Tasks::RescheduleCount)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    RescheduleCount,
+}
+
+#[derive(DeriveIden)]
+enum UserPreferences {
+    Table,
+    TaskRollover,
+}
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Goals::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Goals::Id).string().not_null().primary_key())
+                    .col(ColumnDef::new(Goals::Title).string().not_null())
+                    .col(ColumnDef::new(Goals::Metric).string().not_null())
+                    .col(ColumnDef::new(Goals::Target).integer().not_null())
+                    .col(ColumnDef::new(Goals::Period).string().not_null())
+                    .col(
+                        ColumnDef::new(Goals::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Goals::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Goals::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Goals {
+    Table,
+    Id,
+    Title,
+    Metric,
+    Target,
+    Period,
+    CreatedAt,
+    UpdatedAt,
+}
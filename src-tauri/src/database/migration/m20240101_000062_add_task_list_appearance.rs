@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Hex color the sidebar marks the list with
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .add_column(ColumnDef::new(TaskLists::Color).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Name of the icon shown next to the list
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .add_column(ColumnDef::new(TaskLists::Icon).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .drop_column(TaskLists::Icon)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .drop_column(TaskLists::Color)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskLists {
+    Table,
+    Color,
+    Icon,
+}
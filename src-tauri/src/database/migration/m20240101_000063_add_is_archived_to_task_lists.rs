@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Archived lists are hidden from the sidebar and stats but keep their tasks
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .add_column(
+                        ColumnDef::new(TaskLists::IsArchived)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .drop_column(TaskLists::IsArchived)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskLists {
+    Table,
+    IsArchived,
+}
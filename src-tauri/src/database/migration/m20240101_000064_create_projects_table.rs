@@ -0,0 +1,157 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Projects::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Projects::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Projects::Name).string().not_null())
+                    .col(ColumnDef::new(Projects::Description).text())
+                    .col(
+                        ColumnDef::new(Projects::Status)
+                            .string()
+                            .not_null()
+                            .default("active"),
+                    )
+                    .col(ColumnDef::new(Projects::TargetDate).timestamp())
+                    .col(
+                        ColumnDef::new(Projects::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Projects::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Project a task list belongs to
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .add_column(ColumnDef::new(TaskLists::ProjectId).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_lists_project_id")
+                    .table(TaskLists::Table)
+                    .col(TaskLists::ProjectId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tasks_project_id")
+                    .table(Tasks::Table)
+                    .col(Tasks::ProjectId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // tasks.project_id used to be free text; give every value still in use a project of
+        // the same ID so the tasks keep their grouping. SQLite can't add a foreign key to an
+        // existing table, so the repositories enforce the reference from here on.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                UPDATE tasks SET project_id = NULL WHERE trim(project_id) = ''
+                "#,
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT OR IGNORE INTO projects (id, name)
+                SELECT DISTINCT project_id, project_id FROM tasks WHERE project_id IS NOT NULL
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_tasks_project_id")
+                    .table(Tasks::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_task_lists_project_id")
+                    .table(TaskLists::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaskLists::Table)
+                    .drop_column(TaskLists::ProjectId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Projects::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+    Name,
+    Description,
+    Status,
+    TargetDate,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum TaskLists {
+    Table,
+    ProjectId,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    ProjectId,
+}
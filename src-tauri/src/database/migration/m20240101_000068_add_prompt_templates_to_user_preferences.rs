@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The user's rewrites of Kira's prompts, as JSON; unset parts use the built-in ones
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPreferences::Table)
+                    .add_column(
+                        ColumnDef::new(UserPreferences::PromptTemplates)
+                            .text()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPreferences::Table)
+                    .drop_column(UserPreferences::PromptTemplates)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserPreferences {
+    Table,
+    PromptTemplates,
+}
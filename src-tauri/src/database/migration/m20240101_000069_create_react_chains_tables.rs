@@ -0,0 +1,219 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A chain shares its id with the interaction log it answers
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReactChains::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReactChains::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ReactChains::SessionId).string().not_null())
+                    .col(ColumnDef::new(ReactChains::ModelType).string().not_null())
+                    .col(
+                        ColumnDef::new(ReactChains::Duration)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(ReactChains::Error).text())
+                    .col(ColumnDef::new(ReactChains::ErrorCode).string())
+                    .col(
+                        ColumnDef::new(ReactChains::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ReactChains::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_react_chains_id")
+                            .from(ReactChains::Table, ReactChains::Id)
+                            .to(AiInteractions::Table, AiInteractions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReactSteps::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReactSteps::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ReactSteps::ChainId).string().not_null())
+                    .col(ColumnDef::new(ReactSteps::StepIndex).integer().not_null())
+                    .col(ColumnDef::new(ReactSteps::ToolName).string().not_null())
+                    .col(ColumnDef::new(ReactSteps::Arguments).text().not_null())
+                    .col(ColumnDef::new(ReactSteps::Result).text().not_null())
+                    .col(ColumnDef::new(ReactSteps::Success).boolean().not_null())
+                    .col(ColumnDef::new(ReactSteps::Error).text())
+                    .col(
+                        ColumnDef::new(ReactSteps::Duration)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(ReactSteps::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_react_steps_chain_id")
+                            .from(ReactSteps::Table, ReactSteps::ChainId)
+                            .to(ReactChains::Table, ReactChains::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_react_chains_created_at")
+                    .table(ReactChains::Table)
+                    .col(ReactChains::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_react_steps_chain_id_step_index")
+                    .table(ReactSteps::Table)
+                    .col(ReactSteps::ChainId)
+                    .col(ReactSteps::StepIndex)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Interaction logs were tagged "{model_type}:{session_id}" in action_taken, and their
+        // tool calls stored as interactions tagged "tool_execution:{interaction_log_id}"
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            INSERT INTO react_chains (id, session_id, model_type, duration, created_at, updated_at)
+            SELECT
+                id,
+                substr(action_taken, instr(action_taken, ':') + 1),
+                substr(action_taken, 1, instr(action_taken, ':') - 1),
+                0,
+                created_at,
+                created_at
+            FROM ai_interactions
+            WHERE action_taken LIKE 'local:%' OR action_taken LIKE 'gemini:%'
+            "#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"
+            INSERT INTO react_steps (
+                id, chain_id, step_index, tool_name, arguments, result, success, error,
+                duration, created_at
+            )
+            SELECT
+                execution.id,
+                chain.id,
+                (
+                    SELECT COUNT(*)
+                    FROM ai_interactions AS earlier
+                    WHERE earlier.action_taken = execution.action_taken
+                    AND (
+                        earlier.created_at < execution.created_at
+                        OR (earlier.created_at = execution.created_at AND earlier.id < execution.id)
+                    )
+                ),
+                CASE
+                    WHEN execution.message LIKE 'Tool: %' THEN substr(execution.message, 7)
+                    ELSE execution.message
+                END,
+                COALESCE(execution.tools_used, '{}'),
+                execution.response,
+                COALESCE(execution.confidence, 1.0) <> 0.0,
+                execution.reasoning,
+                0,
+                execution.created_at
+            FROM ai_interactions AS execution
+            JOIN react_chains AS chain ON execution.action_taken = 'tool_execution:' || chain.id
+            "#,
+        )
+        .await?;
+        db.execute_unprepared(
+            "DELETE FROM ai_interactions WHERE action_taken LIKE 'tool_execution:%'",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReactSteps::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ReactChains::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ReactChains {
+    Table,
+    Id,
+    SessionId,
+    ModelType,
+    Duration,
+    Error,
+    ErrorCode,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum ReactSteps {
+    Table,
+    Id,
+    ChainId,
+    StepIndex,
+    ToolName,
+    Arguments,
+    Result,
+    Success,
+    Error,
+    Duration,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum AiInteractions {
+    Table,
+    Id,
+}
@@ -34,7 +34,7 @@ mod manual_test {
             title: "Existing Task 1".to_string(),
             description: Some("This task existed before task lists".to_string()),
             priority: 1,
-            status: Some("todo".to_string()),
+            status: Some("pending".to_string()),
             dependencies: None,
             due_date: None,
             scheduled_date: None,
@@ -43,6 +43,12 @@ mod manual_test {
             parent_task_id: None,
             task_list_id: None, // This will be assigned to default
             time_estimate: Some(60),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         }).await.expect("Failed to create task 1");
 
         let task2 = task_repo.create_task(CreateTaskRequest {
@@ -58,6 +64,12 @@ mod manual_test {
             parent_task_id: None,
             task_list_id: None, // This will be assigned to default
             time_estimate: Some(120),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         }).await.expect("Failed to create task 2");
 
         println!("Created tasks: {} and {}", task1.id, task2.id);
@@ -20,6 +20,56 @@ pub mod m20240101_000016_create_threads_table;
 pub mod m20240101_000017_create_periodic_task_templates_table;
 pub mod m20240101_000018_add_periodic_columns_to_tasks;
 pub mod m20240101_000019_create_periodic_task_indexes;
+pub mod m20240101_000020_create_saved_filters_table;
+pub mod m20240101_000021_create_task_templates_table;
+pub mod m20240101_000022_create_custom_fields_tables;
+pub mod m20240101_000023_add_effort_impact_to_tasks;
+pub mod m20240101_000024_add_archived_at_to_tasks;
+pub mod m20240101_000025_create_task_status_history_table;
+pub mod m20240101_000026_add_targets_to_task_lists;
+pub mod m20240101_000027_create_task_statuses_table;
+pub mod m20240101_000028_create_tags_tables;
+pub mod m20240101_000029_create_api_tokens_table;
+pub mod m20240101_000030_add_recurrence_rule_to_periodic_templates;
+pub mod m20240101_000031_add_limits_to_periodic_templates;
+pub mod m20240101_000032_add_pause_to_periodic_templates;
+pub mod m20240101_000033_create_focus_session_templates_table;
+pub mod m20240101_000034_create_session_subtask_completions_table;
+pub mod m20240101_000035_create_task_reminders_table;
+pub mod m20240101_000036_create_ai_replay_comparisons_table;
+pub mod m20240101_000037_add_order_num_to_task_lists;
+pub mod m20240101_000038_create_ai_provider_metrics_table;
+pub mod m20240101_000039_create_checklist_items_table;
+pub mod m20240101_000040_create_daily_notes_table;
+pub mod m20240101_000041_create_attachments_table;
+pub mod m20240101_000042_create_task_notes_table;
+pub mod m20240101_000043_add_relation_type_to_task_dependencies;
+pub mod m20240101_000044_create_task_audit_log_table;
+pub mod m20240101_000045_add_change_id_to_task_audit_log;
+pub mod m20240101_000046_create_prompt_eval_runs_table;
+pub mod m20240101_000047_create_sync_tables;
+pub mod m20240101_000048_create_integrity_reports_table;
+pub mod m20240101_000049_create_github_tables;
+pub mod m20240101_000050_create_webhooks_tables;
+pub mod m20240101_000051_create_task_sources_table;
+pub mod m20240101_000052_create_notification_deliveries_table;
+pub mod m20240101_000053_create_session_pauses_table;
+pub mod m20240101_000054_add_usage_to_ai_provider_metrics;
+pub mod m20240101_000055_add_action_results_to_thread_messages;
+pub mod m20240101_000056_create_ai_personas_table;
+pub mod m20240101_000057_add_single_active_session_index;
+pub mod m20240101_000058_add_hourly_rate_to_task_lists;
+pub mod m20240101_000059_update_ai_suggestions_table;
+pub mod m20240101_000060_add_task_rollover;
+pub mod m20240101_000061_create_goals_table;
+pub mod m20240101_000062_add_task_list_appearance;
+pub mod m20240101_000063_add_is_archived_to_task_lists;
+pub mod m20240101_000064_create_projects_table;
+pub mod m20240101_000065_add_summary_to_threads;
+pub mod m20240101_000066_add_model_info_to_ai_interactions;
+pub mod m20240101_000067_add_token_count_to_ai_interactions;
+pub mod m20240101_000068_add_prompt_templates_to_user_preferences;
+pub mod m20240101_000069_create_react_chains_tables;
 
 pub mod initialization;
 
@@ -57,6 +107,56 @@ impl MigratorTrait for Migrator {
             Box::new(m20240101_000017_create_periodic_task_templates_table::Migration),
             Box::new(m20240101_000018_add_periodic_columns_to_tasks::Migration),
             Box::new(m20240101_000019_create_periodic_task_indexes::Migration),
+            Box::new(m20240101_000020_create_saved_filters_table::Migration),
+            Box::new(m20240101_000021_create_task_templates_table::Migration),
+            Box::new(m20240101_000022_create_custom_fields_tables::Migration),
+            Box::new(m20240101_000023_add_effort_impact_to_tasks::Migration),
+            Box::new(m20240101_000024_add_archived_at_to_tasks::Migration),
+            Box::new(m20240101_000025_create_task_status_history_table::Migration),
+            Box::new(m20240101_000026_add_targets_to_task_lists::Migration),
+            Box::new(m20240101_000027_create_task_statuses_table::Migration),
+            Box::new(m20240101_000028_create_tags_tables::Migration),
+            Box::new(m20240101_000029_create_api_tokens_table::Migration),
+            Box::new(m20240101_000030_add_recurrence_rule_to_periodic_templates::Migration),
+            Box::new(m20240101_000031_add_limits_to_periodic_templates::Migration),
+            Box::new(m20240101_000032_add_pause_to_periodic_templates::Migration),
+            Box::new(m20240101_000033_create_focus_session_templates_table::Migration),
+            Box::new(m20240101_000034_create_session_subtask_completions_table::Migration),
+            Box::new(m20240101_000035_create_task_reminders_table::Migration),
+            Box::new(m20240101_000036_create_ai_replay_comparisons_table::Migration),
+            Box::new(m20240101_000037_add_order_num_to_task_lists::Migration),
+            Box::new(m20240101_000038_create_ai_provider_metrics_table::Migration),
+            Box::new(m20240101_000039_create_checklist_items_table::Migration),
+            Box::new(m20240101_000040_create_daily_notes_table::Migration),
+            Box::new(m20240101_000041_create_attachments_table::Migration),
+            Box::new(m20240101_000042_create_task_notes_table::Migration),
+            Box::new(m20240101_000043_add_relation_type_to_task_dependencies::Migration),
+            Box::new(m20240101_000044_create_task_audit_log_table::Migration),
+            Box::new(m20240101_000045_add_change_id_to_task_audit_log::Migration),
+            Box::new(m20240101_000046_create_prompt_eval_runs_table::Migration),
+            Box::new(m20240101_000047_create_sync_tables::Migration),
+            Box::new(m20240101_000048_create_integrity_reports_table::Migration),
+            Box::new(m20240101_000049_create_github_tables::Migration),
+            Box::new(m20240101_000050_create_webhooks_tables::Migration),
+            Box::new(m20240101_000051_create_task_sources_table::Migration),
+            Box::new(m20240101_000052_create_notification_deliveries_table::Migration),
+            Box::new(m20240101_000053_create_session_pauses_table::Migration),
+            Box::new(m20240101_000054_add_usage_to_ai_provider_metrics::Migration),
+            Box::new(m20240101_000055_add_action_results_to_thread_messages::Migration),
+            Box::new(m20240101_000056_create_ai_personas_table::Migration),
+            Box::new(m20240101_000057_add_single_active_session_index::Migration),
+            Box::new(m20240101_000058_add_hourly_rate_to_task_lists::Migration),
+            Box::new(m20240101_000059_update_ai_suggestions_table::Migration),
+            Box::new(m20240101_000060_add_task_rollover::Migration),
+            Box::new(m20240101_000061_create_goals_table::Migration),
+            Box::new(m20240101_000062_add_task_list_appearance::Migration),
+            Box::new(m20240101_000063_add_is_archived_to_task_lists::Migration),
+            Box::new(m20240101_000064_create_projects_table::Migration),
+            Box::new(m20240101_000065_add_summary_to_threads::Migration),
+            Box::new(m20240101_000066_add_model_info_to_ai_interactions::Migration),
+            Box::new(m20240101_000067_add_token_count_to_ai_interactions::Migration),
+            Box::new(m20240101_000068_add_prompt_templates_to_user_preferences::Migration),
+            Box::new(m20240101_000069_create_react_chains_tables::Migration),
         ]
     }
 }
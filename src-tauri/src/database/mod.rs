@@ -15,9 +15,11 @@ mod tests;
 #[cfg(test)]
 mod integration_test;
 
-use config::{create_connection_with_config, DatabaseConfig};
+use config::{create_connection_with_config, get_attachments_dir, DatabaseConfig};
 use migration::{MigrationStatus, MigrationTestResult};
 use migration::initialization::{DatabaseIntegrityReport, validate_database_integrity, run_post_migration_initialization};
+use repositories::attachment_repository::AttachmentStorage;
+use repositories::AttachmentRepository;
 
 // Global database connection instance
 static DB_CONNECTION: OnceCell<Arc<DatabaseConnection>> = OnceCell::const_new();
@@ -71,15 +73,26 @@ pub async fn check_database_health() -> Result<DatabaseHealth, DbErr> {
     let result = db.ping().await;
 
     match result {
-        Ok(_) => Ok(DatabaseHealth {
-            is_healthy: true,
-            connection_pool_size: 1, // SeaORM manages this internally
-            last_migration: migration::get_last_migration(&*db).await.ok(),
-        }),
+        Ok(_) => {
+            let attachment_storage = match get_attachments_dir() {
+                Ok(dir) => AttachmentRepository::new(db.clone(), dir)
+                    .storage_usage()
+                    .await
+                    .ok(),
+                Err(_) => None,
+            };
+            Ok(DatabaseHealth {
+                is_healthy: true,
+                connection_pool_size: 1, // SeaORM manages this internally
+                last_migration: migration::get_last_migration(&*db).await.ok(),
+                attachment_storage,
+            })
+        }
         Err(_e) => Ok(DatabaseHealth {
             is_healthy: false,
             connection_pool_size: 0,
             last_migration: None,
+            attachment_storage: None,
         }),
     }
 }
@@ -127,4 +140,5 @@ pub struct DatabaseHealth {
     pub is_healthy: bool,
     pub connection_pool_size: u32,
     pub last_migration: Option<String>,
+    pub attachment_storage: Option<AttachmentStorage>,
 }
@@ -0,0 +1,289 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{ai_personas, threads};
+
+/// Tones a persona can answer in, matching the assistant's interaction styles
+pub const PERSONA_TONES: [&str; 3] = ["casual", "professional", "friendly"];
+
+/// Longest system prompt a persona may add, in characters
+const MAX_SYSTEM_PROMPT_LENGTH: usize = 4000;
+
+/// Request structure for creating a new persona
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAiPersonaRequest {
+    pub name: String,
+    pub system_prompt: String,
+    pub tone: String,
+    pub allowed_tools: Option<Vec<String>>, // Unset allows every tool
+    pub default_model: Option<String>,
+}
+
+/// Request structure for updating an existing persona
+///
+/// An empty `allowed_tools` list leaves the persona without tools; `allow_all_tools` lifts
+/// the restriction instead. A blank `default_model` goes back to the app's default model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAiPersonaRequest {
+    pub name: Option<String>,
+    pub system_prompt: Option<String>,
+    pub tone: Option<String>,
+    pub allowed_tools: Option<Vec<String>>,
+    #[serde(default)]
+    pub allow_all_tools: bool,
+    pub default_model: Option<String>,
+}
+
+/// AI persona repository for SeaORM-based database operations
+pub struct AiPersonaRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AiPersonaRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new persona
+    pub async fn create_persona(
+        &self,
+        request: CreateAiPersonaRequest,
+    ) -> Result<ai_personas::Model, DbErr> {
+        let name = Self::validate_name(&request.name)?;
+        let system_prompt = Self::validate_system_prompt(&request.system_prompt)?;
+        Self::validate_tone(&request.tone)?;
+        let allowed_tools = request
+            .allowed_tools
+            .map(|tools| Self::serialize_tools(&tools))
+            .transpose()?;
+
+        if self.find_by_name(&name).await?.is_some() {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: A persona named '{}' already exists",
+                name
+            )));
+        }
+
+        let persona = ai_personas::ActiveModel {
+            name: Set(name),
+            system_prompt: Set(system_prompt),
+            tone: Set(request.tone),
+            allowed_tools: Set(allowed_tools),
+            default_model: Set(Self::normalize_model(request.default_model)),
+            ..Default::default()
+        };
+
+        persona.insert(&*self.db).await
+    }
+
+    /// Find a persona by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<ai_personas::Model>, DbErr> {
+        ai_personas::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find a persona by name
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<ai_personas::Model>, DbErr> {
+        ai_personas::Entity::find()
+            .filter(ai_personas::Column::Name.eq(name.trim()))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find all personas by name
+    pub async fn find_all(&self) -> Result<Vec<ai_personas::Model>, DbErr> {
+        ai_personas::Entity::find()
+            .order_by_asc(ai_personas::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Update a persona
+    pub async fn update_persona(
+        &self,
+        id: &str,
+        request: UpdateAiPersonaRequest,
+    ) -> Result<ai_personas::Model, DbErr> {
+        let persona = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Persona not found".to_string()))?;
+
+        let name = match request.name {
+            Some(name) => Some(Self::validate_name(&name)?).filter(|name| *name != persona.name),
+            None => None,
+        };
+        if let Some(name) = &name {
+            if self.find_by_name(name).await?.is_some() {
+                return Err(DbErr::Custom(format!(
+                    "DUPLICATE_ERROR: A persona named '{}' already exists",
+                    name
+                )));
+            }
+        }
+        let system_prompt = request
+            .system_prompt
+            .map(|prompt| Self::validate_system_prompt(&prompt))
+            .transpose()?;
+        if let Some(tone) = &request.tone {
+            Self::validate_tone(tone)?;
+        }
+        let allowed_tools = request
+            .allowed_tools
+            .map(|tools| Self::serialize_tools(&tools))
+            .transpose()?;
+
+        let mut active: ai_personas::ActiveModel = persona.into();
+        if let Some(name) = name {
+            active.name = Set(name);
+        }
+        if let Some(system_prompt) = system_prompt {
+            active.system_prompt = Set(system_prompt);
+        }
+        if let Some(tone) = request.tone {
+            active.tone = Set(tone);
+        }
+        if request.allow_all_tools {
+            active.allowed_tools = Set(None);
+        } else if let Some(allowed_tools) = allowed_tools {
+            active.allowed_tools = Set(Some(allowed_tools));
+        }
+        if let Some(default_model) = request.default_model {
+            active.default_model = Set(Self::normalize_model(Some(default_model)));
+        }
+        active.updated_at = Set(chrono::Utc::now());
+
+        active.update(&*self.db).await
+    }
+
+    /// Delete a persona, moving the threads that used it back to plain Kira
+    pub async fn delete_persona(&self, id: &str) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        let result = ai_personas::Entity::delete_by_id(id).exec(&txn).await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Persona not found".to_string()));
+        }
+
+        threads::Entity::update_many()
+            .col_expr(
+                threads::Column::PersonaId,
+                sea_orm::sea_query::Expr::value(Option::<String>::None),
+            )
+            .filter(threads::Column::PersonaId.eq(id))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await
+    }
+
+    /// Choose the persona a thread answers as; `None` goes back to plain Kira
+    pub async fn set_thread_persona(
+        &self,
+        thread_id: &str,
+        persona_id: Option<String>,
+    ) -> Result<threads::Model, DbErr> {
+        let thread = threads::Entity::find_by_id(thread_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound(format!("Thread '{}' not found", thread_id)))?;
+
+        if let Some(persona_id) = &persona_id {
+            if self.find_by_id(persona_id).await?.is_none() {
+                return Err(DbErr::RecordNotFound(format!(
+                    "Persona '{}' not found",
+                    persona_id
+                )));
+            }
+        }
+
+        let mut active: threads::ActiveModel = thread.into();
+        active.persona_id = Set(persona_id);
+        active.updated_at = Set(chrono::Utc::now());
+        active.update(&*self.db).await
+    }
+
+    /// Find the persona a thread answers as, if any
+    pub async fn find_for_thread(
+        &self,
+        thread_id: &str,
+    ) -> Result<Option<ai_personas::Model>, DbErr> {
+        let persona_id = threads::Entity::find_by_id(thread_id)
+            .one(&*self.db)
+            .await?
+            .and_then(|thread| thread.persona_id);
+        match persona_id {
+            Some(persona_id) => self.find_by_id(&persona_id).await,
+            None => Ok(None),
+        }
+    }
+
+    fn validate_name(name: &str) -> Result<String, DbErr> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Persona name cannot be empty".to_string(),
+            ));
+        }
+        if name.len() > 50 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Persona name cannot exceed 50 characters".to_string(),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    fn validate_system_prompt(system_prompt: &str) -> Result<String, DbErr> {
+        let system_prompt = system_prompt.trim();
+        if system_prompt.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Persona system prompt cannot be empty".to_string(),
+            ));
+        }
+        if system_prompt.chars().count() > MAX_SYSTEM_PROMPT_LENGTH {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Persona system prompt cannot exceed {} characters",
+                MAX_SYSTEM_PROMPT_LENGTH
+            )));
+        }
+        Ok(system_prompt.to_string())
+    }
+
+    fn validate_tone(tone: &str) -> Result<(), DbErr> {
+        if !PERSONA_TONES.contains(&tone) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown tone '{}', expected one of: {}",
+                tone,
+                PERSONA_TONES.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Trim and deduplicate tool names into the stored JSON array
+    fn serialize_tools(tools: &[String]) -> Result<String, DbErr> {
+        let mut names: Vec<&str> = Vec::new();
+        for tool in tools {
+            let tool = tool.trim();
+            if tool.is_empty() {
+                return Err(DbErr::Custom(
+                    "VALIDATION_ERROR: Tool names cannot be empty".to_string(),
+                ));
+            }
+            if !names.contains(&tool) {
+                names.push(tool);
+            }
+        }
+        serde_json::to_string(&names).map_err(|e| DbErr::Custom(e.to_string()))
+    }
+
+    /// A blank model means the app's default model
+    fn normalize_model(model: Option<String>) -> Option<String> {
+        model
+            .map(|model| model.trim().to_string())
+            .filter(|model| !model.is_empty())
+    }
+}
@@ -1,11 +1,15 @@
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
-    QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 
-use crate::database::entities::ai_interactions;
+use crate::database::entities::{
+    ai_interactions, ai_provider_metrics, ai_replay_comparisons, prompt_eval_runs, react_chains,
+    react_steps,
+};
 
 /// Request structure for creating a new AI interaction
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,6 +80,27 @@ pub struct CreateToolExecutionLogRequest {
     pub error: Option<String>,
 }
 
+/// Filters for listing interaction logs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractionLogFilter {
+    pub start_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub end_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub model_type: Option<String>,
+    pub has_errors: Option<bool>,
+    pub contains_tool_calls: Option<bool>,
+    pub search_text: Option<String>,
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+/// An interaction log and the ReAct chain that answered it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionLog {
+    pub interaction: ai_interactions::Model,
+    pub chain: react_chains::Model,
+}
+
 /// AI interaction statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AiStats {
@@ -83,6 +108,9 @@ pub struct AiStats {
     pub average_confidence: f64,
     pub most_common_actions: Vec<ActionCount>,
     pub most_used_tools: Vec<ToolCount>,
+    /// Tokens of the interactions whose count was reported by the provider
+    pub total_tokens: u64,
+    pub average_tokens: f64,
 }
 
 /// Tool count for statistics
@@ -110,6 +138,118 @@ pub struct AiLogStorageStats {
     pub average_response_time: f64,
 }
 
+/// A tool call recorded for an interaction, replayed instead of running the tool again
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayToolResult {
+    pub tool_name: String,
+    pub arguments: String, // JSON string
+    pub result: String,    // JSON string
+    pub success: bool,
+}
+
+/// Everything needed to send a logged interaction to another model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionReplay {
+    pub interaction_log_id: String,
+    pub model_type: String,
+    pub session_id: String,
+    pub user_message: String,
+    pub original_response: String,
+    /// Tool results in the order they were logged
+    pub tool_results: Vec<ReplayToolResult>,
+}
+
+/// Request structure for storing the outcome of a replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReplayComparisonRequest {
+    pub interaction_log_id: String,
+    pub replay_model: String,
+    pub replay_response: String,
+    pub mocked_tool_calls: i32,
+    pub unmatched_tool_calls: i32,
+    pub response_time: Option<i64>, // milliseconds
+    pub error: Option<String>,
+}
+
+/// The response to one evaluation case and how the judge scored it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptEvalResult {
+    pub case_id: String,
+    pub response: String,
+    /// Judge score from 0 to 10, missing when the case wasn't judged
+    pub judge_score: Option<f64>,
+    pub judge_notes: Option<String>,
+    pub error: Option<String>,
+    pub response_time: Option<i64>, // milliseconds
+}
+
+/// Request structure for storing a run of the evaluation suite against a prompt template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePromptEvalRunRequest {
+    pub template_name: String,
+    pub template: String,
+    pub model: String,
+    pub judge_model: Option<String>,
+    pub results: Vec<PromptEvalResult>,
+}
+
+/// Request structure for recording one request sent to an AI provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordProviderRequest {
+    pub provider: String, // "local" or "gemini"
+    pub model: Option<String>,
+    pub latency: i64, // milliseconds
+    pub success: bool,
+    #[serde(default)]
+    pub timed_out: bool,
+    pub error_code: Option<String>,
+    #[serde(default)]
+    pub input_tokens: Option<i32>,
+    #[serde(default)]
+    pub output_tokens: Option<i32>,
+}
+
+/// Latency and reliability of one AI provider over a date range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderMetrics {
+    pub provider: String,
+    pub request_count: u64,
+    /// Failed requests, including timeouts
+    pub error_count: u64,
+    pub timeout_count: u64,
+    /// Share of failed requests, from 0.0 to 1.0
+    pub error_rate: f64,
+    pub average_latency: f64, // milliseconds
+    pub p95_latency: i64,     // milliseconds
+    pub max_latency: i64,     // milliseconds
+}
+
+/// Estimated spend on one model of a provider over a date range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelCost {
+    pub provider: String,
+    pub model: Option<String>,
+    pub request_count: u64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cost: f64, // US dollars
+}
+
+/// US dollars per million input and output tokens, by model name prefix; the first match wins
+const MODEL_PRICES: [(&str, f64, f64); 5] = [
+    ("gemini-2.5-pro", 1.25, 10.0),
+    ("gemini-2.5-flash", 0.30, 2.50),
+    ("gemini-2.0-flash", 0.10, 0.40),
+    ("gemini-1.5-pro", 1.25, 5.0),
+    ("gemini-1.5-flash", 0.075, 0.30),
+];
+
+/// Providers running on the user's machine, which cost nothing
+const LOCAL_PROVIDERS: [&str; 2] = ["local", "ollama"];
+
+/// Price of remote models missing from `MODEL_PRICES`
+const DEFAULT_PRICE: (f64, f64) = (0.30, 2.50);
+
 /// AI repository for SeaORM-based database operations
 pub struct AiRepository {
     db: Arc<DatabaseConnection>,
@@ -233,9 +373,20 @@ impl AiRepository {
 
     /// Delete an AI interaction
     pub async fn delete_interaction(&self, id: &str) -> Result<(), DbErr> {
+        react_steps::Entity::delete_many()
+            .filter(react_steps::Column::ChainId.eq(id))
+            .exec(&*self.db)
+            .await?;
+        react_chains::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
         ai_interactions::Entity::delete_by_id(id)
             .exec(&*self.db)
             .await?;
+        ai_replay_comparisons::Entity::delete_many()
+            .filter(ai_replay_comparisons::Column::InteractionLogId.eq(id))
+            .exec(&*self.db)
+            .await?;
         Ok(())
     }
 
@@ -289,11 +440,26 @@ impl AiRepository {
         most_used_tools.sort_by(|a, b| b.count.cmp(&a.count));
         most_used_tools.truncate(10); // Top 10 tools
 
+        // Token usage, over the interactions with a reported count
+        let token_counts: Vec<u64> = interactions
+            .iter()
+            .filter_map(|i| i.token_count)
+            .map(|count| count.max(0) as u64)
+            .collect();
+        let total_tokens: u64 = token_counts.iter().sum();
+        let average_tokens = if !token_counts.is_empty() {
+            total_tokens as f64 / token_counts.len() as f64
+        } else {
+            0.0
+        };
+
         Ok(AiStats {
             total_interactions,
             average_confidence,
             most_common_actions,
             most_used_tools,
+            total_tokens,
+            average_tokens,
         })
     }
 
@@ -314,6 +480,19 @@ impl AiRepository {
         &self,
         older_than: chrono::DateTime<chrono::Utc>,
     ) -> Result<u64, DbErr> {
+        let mut old_chains = sea_orm::sea_query::Query::select();
+        old_chains
+            .column(react_chains::Column::Id)
+            .from(react_chains::Entity)
+            .and_where(react_chains::Column::CreatedAt.lt(older_than));
+        react_steps::Entity::delete_many()
+            .filter(react_steps::Column::ChainId.in_subquery(old_chains.to_owned()))
+            .exec(&*self.db)
+            .await?;
+        react_chains::Entity::delete_many()
+            .filter(react_chains::Column::CreatedAt.lt(older_than))
+            .exec(&*self.db)
+            .await?;
         let result = ai_interactions::Entity::delete_many()
             .filter(ai_interactions::Column::CreatedAt.lt(older_than))
             .exec(&*self.db)
@@ -347,24 +526,19 @@ impl AiRepository {
             .max_by_key(|i| &i.created_at)
             .map(|i| i.created_at.to_rfc3339());
 
-        // Count logs by model (using action_taken as a proxy for model type)
+        // Count logs by model and time their chains
+        let chains = react_chains::Entity::find().all(&*self.db).await?;
         let mut logs_by_model = std::collections::HashMap::new();
-        for interaction in &interactions {
-            if let Some(action) = &interaction.action_taken {
-                // Extract model type from action or use a default categorization
-                let model_type = if action.contains("local") || action.contains("llama") {
-                    "local".to_string()
-                } else if action.contains("gemini") {
-                    "gemini".to_string()
-                } else {
-                    "unknown".to_string()
-                };
-                *logs_by_model.entry(model_type).or_insert(0) += 1;
-            }
+        for chain in &chains {
+            *logs_by_model.entry(chain.model_type.clone()).or_insert(0) += 1;
         }
 
-        // Calculate average response time (mock data for now)
-        let average_response_time = 1500.0; // 1.5 seconds average
+        let total_duration: i64 = chains.iter().map(|chain| chain.duration).sum();
+        let average_response_time = if !chains.is_empty() {
+            total_duration as f64 / chains.len() as f64
+        } else {
+            0.0
+        };
 
         Ok(AiLogStorageStats {
             total_logs,
@@ -394,6 +568,11 @@ impl AiRepository {
 
     /// Delete all AI interactions
     pub async fn delete_all_interactions(&self) -> Result<u64, DbErr> {
+        react_steps::Entity::delete_many().exec(&*self.db).await?;
+        react_chains::Entity::delete_many().exec(&*self.db).await?;
+        ai_replay_comparisons::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
         let result = ai_interactions::Entity::delete_many()
             .exec(&*self.db)
             .await?;
@@ -413,30 +592,147 @@ impl AiRepository {
             reasoning: Set(interaction.reasoning),
             tools_used: Set(interaction.tools_used),
             confidence: Set(interaction.confidence),
+            model_info: Set(interaction.model_info),
+            token_count: Set(interaction.token_count),
             created_at: Set(interaction.created_at),
         };
 
         active_interaction.insert(&*self.db).await
     }
 
-    /// Create a comprehensive AI interaction log
+    /// Get all ReAct chains of interaction logs (for backup)
+    pub async fn find_all_react_chains(&self) -> Result<Vec<react_chains::Model>, DbErr> {
+        react_chains::Entity::find().all(&*self.db).await
+    }
+
+    /// Get all tool calls of ReAct chains (for backup)
+    pub async fn find_all_react_steps(&self) -> Result<Vec<react_steps::Model>, DbErr> {
+        react_steps::Entity::find().all(&*self.db).await
+    }
+
+    /// Import a ReAct chain (for backup restore)
+    pub async fn import_react_chain(
+        &self,
+        chain: react_chains::Model,
+    ) -> Result<react_chains::Model, DbErr> {
+        let active_model: react_chains::ActiveModel = chain.into();
+        active_model.insert(&*self.db).await
+    }
+
+    /// Import a tool call of a ReAct chain (for backup restore)
+    pub async fn import_react_step(
+        &self,
+        step: react_steps::Model,
+    ) -> Result<react_steps::Model, DbErr> {
+        let active_model: react_steps::ActiveModel = step.into();
+        active_model.insert(&*self.db).await
+    }
+
+    /// Create a comprehensive AI interaction log, with the ReAct chain that answers it
     pub async fn create_interaction_log(
         &self,
         request: CreateAiInteractionLogRequest,
     ) -> Result<ai_interactions::Model, DbErr> {
-        // For now, map the comprehensive log to the existing ai_interactions table
-        // In a production system, you might want a separate table for detailed logs
+        let txn = self.db.begin().await?;
+
         let interaction = ai_interactions::ActiveModel {
             message: Set(request.user_message),
             response: Set(request.ai_response),
-            action_taken: Set(Some(format!("{}:{}", request.model_type, request.session_id))),
+            action_taken: Set(None),
             reasoning: Set(request.reasoning),
             tools_used: Set(Some(request.actions)), // Store actions as tools_used for now
             confidence: Set(None), // Could derive from response_time or other metrics
+            model_info: Set(Some(request.model_info.to_string())),
+            token_count: Set(request.token_count),
             ..Default::default()
-        };
+        }
+        .insert(&txn)
+        .await?;
 
-        interaction.insert(&*self.db).await
+        react_chains::ActiveModel {
+            id: Set(interaction.id.clone()),
+            session_id: Set(request.session_id),
+            model_type: Set(request.model_type),
+            duration: Set(request.response_time.max(0)),
+            error: Set(request.error),
+            error_code: Set(request.error_code),
+            created_at: Set(interaction.created_at),
+            updated_at: Set(interaction.created_at),
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+        Ok(interaction)
+    }
+
+    /// Find an interaction log with its chain
+    pub async fn find_interaction_log(&self, id: &str) -> Result<Option<InteractionLog>, DbErr> {
+        let log = react_chains::Entity::find_by_id(id)
+            .find_also_related(ai_interactions::Entity)
+            .one(&*self.db)
+            .await?;
+
+        Ok(log.and_then(|(chain, interaction)| {
+            interaction.map(|interaction| InteractionLog { interaction, chain })
+        }))
+    }
+
+    /// Find interaction logs, newest first
+    pub async fn find_interaction_logs(
+        &self,
+        filter: InteractionLogFilter,
+    ) -> Result<Vec<InteractionLog>, DbErr> {
+        let mut query = react_chains::Entity::find()
+            .find_also_related(ai_interactions::Entity)
+            .order_by_desc(react_chains::Column::CreatedAt);
+
+        if let Some(start_date) = filter.start_date {
+            query = query.filter(react_chains::Column::CreatedAt.gte(start_date));
+        }
+        if let Some(end_date) = filter.end_date {
+            query = query.filter(react_chains::Column::CreatedAt.lte(end_date));
+        }
+        if let Some(model_type) = &filter.model_type {
+            query = query.filter(react_chains::Column::ModelType.eq(model_type.clone()));
+        }
+        match filter.has_errors {
+            Some(true) => query = query.filter(react_chains::Column::Error.is_not_null()),
+            Some(false) => query = query.filter(react_chains::Column::Error.is_null()),
+            None => {}
+        }
+        if let Some(contains_tool_calls) = filter.contains_tool_calls {
+            let mut with_steps = sea_orm::sea_query::Query::select();
+            with_steps
+                .column(react_steps::Column::ChainId)
+                .from(react_steps::Entity);
+            query = query.filter(if contains_tool_calls {
+                react_chains::Column::Id.in_subquery(with_steps.to_owned())
+            } else {
+                react_chains::Column::Id.not_in_subquery(with_steps.to_owned())
+            });
+        }
+        if let Some(search) = filter.search_text.as_ref().filter(|s| !s.trim().is_empty()) {
+            let search_pattern = format!("%{}%", search.trim());
+            query = query.filter(
+                ai_interactions::Column::Message
+                    .like(&search_pattern)
+                    .or(ai_interactions::Column::Response.like(&search_pattern)),
+            );
+        }
+
+        let logs = query
+            .limit(filter.limit.unwrap_or(1000))
+            .offset(filter.offset)
+            .all(&*self.db)
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .filter_map(|(chain, interaction)| {
+                interaction.map(|interaction| InteractionLog { interaction, chain })
+            })
+            .collect())
     }
 
     /// Update a comprehensive AI interaction log
@@ -450,6 +746,21 @@ impl AiRepository {
             .await?
             .ok_or_else(|| DbErr::RecordNotFound("AI interaction log not found".to_string()))?;
 
+        if let Some(chain) = react_chains::Entity::find_by_id(id).one(&*self.db).await? {
+            let mut chain: react_chains::ActiveModel = chain.into();
+            if let Some(response_time) = request.response_time {
+                chain.duration = Set(response_time.max(0));
+            }
+            if let Some(error) = request.error {
+                chain.error = Set(Some(error));
+            }
+            if let Some(error_code) = request.error_code {
+                chain.error_code = Set(Some(error_code));
+            }
+            chain.updated_at = Set(chrono::Utc::now());
+            chain.update(&*self.db).await?;
+        }
+
         let mut interaction: ai_interactions::ActiveModel = interaction.into();
 
         if let Some(ai_response) = request.ai_response {
@@ -461,26 +772,423 @@ impl AiRepository {
         if let Some(reasoning) = request.reasoning {
             interaction.reasoning = Set(Some(reasoning));
         }
+        if let Some(token_count) = request.token_count {
+            interaction.token_count = Set(Some(token_count));
+        }
 
         interaction.update(&*self.db).await
     }
 
-    /// Create a tool execution log (for now, store as a regular interaction)
+    /// Record a tool call as the next step of an interaction log's chain
     pub async fn create_tool_execution_log(
         &self,
         request: CreateToolExecutionLogRequest,
-    ) -> Result<ai_interactions::Model, DbErr> {
-        // For now, create a special interaction record for tool execution
-        let interaction = ai_interactions::ActiveModel {
-            message: Set(format!("Tool: {}", request.tool_name)),
-            response: Set(request.result),
-            action_taken: Set(Some(format!("tool_execution:{}", request.interaction_log_id))),
-            reasoning: Set(request.error),
-            tools_used: Set(Some(request.arguments)),
-            confidence: Set(if request.success { Some(1.0) } else { Some(0.0) }),
+    ) -> Result<react_steps::Model, DbErr> {
+        let chain = react_chains::Entity::find_by_id(&request.interaction_log_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("AI interaction log not found".to_string()))?;
+        let step_index = react_steps::Entity::find()
+            .filter(react_steps::Column::ChainId.eq(chain.id.as_str()))
+            .count(&*self.db)
+            .await?;
+
+        let step = react_steps::ActiveModel {
+            chain_id: Set(chain.id),
+            step_index: Set(step_index as i32),
+            tool_name: Set(request.tool_name),
+            arguments: Set(request.arguments),
+            result: Set(request.result),
+            success: Set(request.success),
+            error: Set(request.error),
+            duration: Set(request.execution_time.max(0)),
             ..Default::default()
         };
 
-        interaction.insert(&*self.db).await
+        step.insert(&*self.db).await
+    }
+
+    /// Tool calls of an interaction log's chain, in the order they were made
+    pub async fn find_tool_execution_logs(
+        &self,
+        interaction_log_id: &str,
+    ) -> Result<Vec<react_steps::Model>, DbErr> {
+        react_steps::Entity::find()
+            .filter(react_steps::Column::ChainId.eq(interaction_log_id))
+            .order_by_asc(react_steps::Column::StepIndex)
+            .order_by_asc(react_steps::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Collect the prompt and logged tool results of an interaction log for a replay
+    pub async fn get_interaction_replay(
+        &self,
+        interaction_log_id: &str,
+    ) -> Result<InteractionReplay, DbErr> {
+        let log = self
+            .find_by_id(interaction_log_id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("AI interaction log not found".to_string()))?;
+
+        // Only interactions answered by a logged chain have a model to compare against
+        let chain = react_chains::Entity::find_by_id(interaction_log_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| {
+                DbErr::Custom(
+                    "VALIDATION_ERROR: Only AI interaction logs can be replayed".to_string(),
+                )
+            })?;
+
+        let tool_results = self
+            .find_tool_execution_logs(interaction_log_id)
+            .await?
+            .into_iter()
+            .map(|step| ReplayToolResult {
+                tool_name: step.tool_name,
+                arguments: step.arguments,
+                result: step.result,
+                success: step.success,
+            })
+            .collect();
+
+        Ok(InteractionReplay {
+            interaction_log_id: log.id,
+            model_type: chain.model_type,
+            session_id: chain.session_id,
+            user_message: log.message,
+            original_response: log.response,
+            tool_results,
+        })
+    }
+
+    /// Store the outcome of replaying an interaction log against another model
+    pub async fn save_replay_comparison(
+        &self,
+        request: CreateReplayComparisonRequest,
+    ) -> Result<ai_replay_comparisons::Model, DbErr> {
+        if request.replay_model.trim().is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Replay model cannot be empty".to_string(),
+            ));
+        }
+        let replay = self
+            .get_interaction_replay(&request.interaction_log_id)
+            .await?;
+
+        let comparison = ai_replay_comparisons::ActiveModel {
+            interaction_log_id: Set(replay.interaction_log_id),
+            original_model: Set(replay.model_type),
+            replay_model: Set(request.replay_model.trim().to_string()),
+            prompt: Set(replay.user_message),
+            similarity: Set(response_similarity(
+                &replay.original_response,
+                &request.replay_response,
+            )),
+            original_response: Set(replay.original_response),
+            replay_response: Set(request.replay_response),
+            mocked_tool_calls: Set(request.mocked_tool_calls.max(0)),
+            unmatched_tool_calls: Set(request.unmatched_tool_calls.max(0)),
+            response_time: Set(request.response_time),
+            error: Set(request.error),
+            ..Default::default()
+        };
+
+        comparison.insert(&*self.db).await
+    }
+
+    /// Find replay comparisons, newest first, optionally for a single interaction log
+    pub async fn find_replay_comparisons(
+        &self,
+        interaction_log_id: Option<&str>,
+    ) -> Result<Vec<ai_replay_comparisons::Model>, DbErr> {
+        let mut query = ai_replay_comparisons::Entity::find()
+            .order_by_desc(ai_replay_comparisons::Column::CreatedAt);
+        if let Some(interaction_log_id) = interaction_log_id {
+            query = query
+                .filter(ai_replay_comparisons::Column::InteractionLogId.eq(interaction_log_id));
+        }
+        query.all(&*self.db).await
+    }
+
+    /// Delete a replay comparison
+    pub async fn delete_replay_comparison(&self, id: &str) -> Result<(), DbErr> {
+        let result = ai_replay_comparisons::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound(
+                "Replay comparison not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Store a run of the prompt evaluation suite
+    pub async fn save_prompt_eval_run(
+        &self,
+        request: CreatePromptEvalRunRequest,
+    ) -> Result<prompt_eval_runs::Model, DbErr> {
+        let template_name = request.template_name.trim().to_string();
+        if template_name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Template name cannot be empty".to_string(),
+            ));
+        }
+        if request.model.trim().is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Model cannot be empty".to_string(),
+            ));
+        }
+        if request.results.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Evaluation run has no results".to_string(),
+            ));
+        }
+
+        let scores: Vec<f64> = request
+            .results
+            .iter()
+            .filter_map(|result| result.judge_score)
+            .collect();
+        if scores.iter().any(|score| !(0.0..=10.0).contains(score)) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Judge scores must be between 0 and 10".to_string(),
+            ));
+        }
+        let average_score =
+            (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64);
+
+        let hash: String = Sha256::digest(request.template.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        let results = serde_json::to_string(&request.results)
+            .map_err(|e| DbErr::Custom(format!("Failed to serialize results: {}", e)))?;
+
+        let run = prompt_eval_runs::ActiveModel {
+            template_name: Set(template_name),
+            template_hash: Set(hash),
+            template: Set(request.template),
+            model: Set(request.model.trim().to_string()),
+            judge_model: Set(request.judge_model),
+            results: Set(results),
+            average_score: Set(average_score),
+            ..Default::default()
+        };
+
+        run.insert(&*self.db).await
+    }
+
+    /// Find prompt evaluation runs, newest first, optionally for a single template
+    pub async fn find_prompt_eval_runs(
+        &self,
+        template_name: Option<&str>,
+    ) -> Result<Vec<prompt_eval_runs::Model>, DbErr> {
+        let mut query =
+            prompt_eval_runs::Entity::find().order_by_desc(prompt_eval_runs::Column::CreatedAt);
+        if let Some(template_name) = template_name {
+            query = query.filter(prompt_eval_runs::Column::TemplateName.eq(template_name));
+        }
+        query.all(&*self.db).await
+    }
+
+    /// Find a prompt evaluation run by ID
+    pub async fn find_prompt_eval_run(
+        &self,
+        id: &str,
+    ) -> Result<Option<prompt_eval_runs::Model>, DbErr> {
+        prompt_eval_runs::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await
+    }
+
+    /// Delete a prompt evaluation run
+    pub async fn delete_prompt_eval_run(&self, id: &str) -> Result<(), DbErr> {
+        let result = prompt_eval_runs::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound(
+                "Prompt evaluation run not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Record the latency and outcome of a request sent to an AI provider
+    pub async fn record_provider_request(
+        &self,
+        request: RecordProviderRequest,
+    ) -> Result<ai_provider_metrics::Model, DbErr> {
+        let provider = request.provider.trim().to_string();
+        if provider.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Provider cannot be empty".to_string(),
+            ));
+        }
+        if request.latency < 0 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Latency cannot be negative".to_string(),
+            ));
+        }
+
+        let metric = ai_provider_metrics::ActiveModel {
+            provider: Set(provider),
+            model: Set(request.model),
+            latency: Set(request.latency),
+            // A timed out request never succeeded, whatever the caller reported
+            success: Set(request.success && !request.timed_out),
+            timed_out: Set(request.timed_out),
+            error_code: Set(request.error_code),
+            input_tokens: Set(request.input_tokens),
+            output_tokens: Set(request.output_tokens),
+            estimated_cost: Set(estimate_cost(
+                &provider,
+                request.model.as_deref(),
+                request.input_tokens,
+                request.output_tokens,
+            )),
+            ..Default::default()
+        };
+
+        metric.insert(&*self.db).await
+    }
+
+    /// Estimated spend on remote providers for requests in a date range, in US dollars
+    pub async fn get_estimated_spend(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+    ) -> Result<f64, DbErr> {
+        let requests = ai_provider_metrics::Entity::find()
+            .filter(ai_provider_metrics::Column::CreatedAt.gte(start_date))
+            .filter(ai_provider_metrics::Column::CreatedAt.lt(end_date))
+            .filter(ai_provider_metrics::Column::EstimatedCost.is_not_null())
+            .all(&*self.db)
+            .await?;
+
+        Ok(requests.iter().filter_map(|r| r.estimated_cost).sum())
+    }
+
+    /// Estimated spend per provider and model for requests in a date range, ordered by
+    /// provider and model
+    pub async fn get_cost_breakdown(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<ModelCost>, DbErr> {
+        let requests = ai_provider_metrics::Entity::find()
+            .filter(ai_provider_metrics::Column::CreatedAt.gte(start_date))
+            .filter(ai_provider_metrics::Column::CreatedAt.lt(end_date))
+            .all(&*self.db)
+            .await?;
+
+        let mut by_model: std::collections::BTreeMap<(String, Option<String>), ModelCost> =
+            std::collections::BTreeMap::new();
+        for request in requests {
+            let entry = by_model
+                .entry((request.provider.clone(), request.model.clone()))
+                .or_insert_with(|| ModelCost {
+                    provider: request.provider.clone(),
+                    model: request.model.clone(),
+                    request_count: 0,
+                    input_tokens: 0,
+                    output_tokens: 0,
+                    cost: 0.0,
+                });
+            entry.request_count += 1;
+            entry.input_tokens += request.input_tokens.unwrap_or(0).max(0) as i64;
+            entry.output_tokens += request.output_tokens.unwrap_or(0).max(0) as i64;
+            entry.cost += request.estimated_cost.unwrap_or(0.0);
+        }
+
+        Ok(by_model.into_values().collect())
+    }
+
+    /// Per-provider latency, error rate and timeout counts for requests in a date range,
+    /// ordered by provider
+    pub async fn get_provider_metrics(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<ProviderMetrics>, DbErr> {
+        let requests = ai_provider_metrics::Entity::find()
+            .filter(ai_provider_metrics::Column::CreatedAt.gte(start_date))
+            .filter(ai_provider_metrics::Column::CreatedAt.lte(end_date))
+            .all(&*self.db)
+            .await?;
+
+        let mut by_provider: std::collections::BTreeMap<String, Vec<ai_provider_metrics::Model>> =
+            std::collections::BTreeMap::new();
+        for request in requests {
+            by_provider
+                .entry(request.provider.clone())
+                .or_default()
+                .push(request);
+        }
+
+        Ok(by_provider
+            .into_iter()
+            .map(|(provider, requests)| {
+                let request_count = requests.len() as u64;
+                let error_count = requests.iter().filter(|r| !r.success).count() as u64;
+                let timeout_count = requests.iter().filter(|r| r.timed_out).count() as u64;
+
+                let mut latencies: Vec<i64> = requests.iter().map(|r| r.latency).collect();
+                latencies.sort_unstable();
+                // Nearest-rank percentile
+                let p95_index = ((latencies.len() as f64 * 0.95).ceil() as usize).max(1) - 1;
+
+                ProviderMetrics {
+                    provider,
+                    request_count,
+                    error_count,
+                    timeout_count,
+                    error_rate: error_count as f64 / request_count as f64,
+                    average_latency: latencies.iter().sum::<i64>() as f64 / request_count as f64,
+                    p95_latency: latencies[p95_index],
+                    max_latency: latencies.last().copied().unwrap_or(0),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Estimated cost of a request in US dollars, or `None` for local models and unknown usage
+pub fn estimate_cost(
+    provider: &str,
+    model: Option<&str>,
+    input_tokens: Option<i32>,
+    output_tokens: Option<i32>,
+) -> Option<f64> {
+    if LOCAL_PROVIDERS.contains(&provider) || (input_tokens.is_none() && output_tokens.is_none()) {
+        return None;
+    }
+
+    let model = model.unwrap_or_default().to_lowercase();
+    let (input_price, output_price) = MODEL_PRICES
+        .iter()
+        .find(|(prefix, _, _)| model.starts_with(prefix))
+        .map_or(DEFAULT_PRICE, |&(_, input, output)| (input, output));
+    let tokens = |count: Option<i32>| count.unwrap_or(0).max(0) as f64;
+    let per_million = tokens(input_tokens) * input_price + tokens(output_tokens) * output_price;
+    Some(per_million / 1_000_000.0)
+}
+
+/// Word overlap (Jaccard index) of two responses, ignoring case and punctuation
+pub fn response_similarity(a: &str, b: &str) -> f64 {
+    let words = |text: &str| -> std::collections::HashSet<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let (a, b) = (words(a), words(b));
+
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
     }
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
 }
@@ -0,0 +1,198 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::ai_suggestions;
+
+/// Days an unanswered suggestion without its own expiry stays active
+pub const DEFAULT_SUGGESTION_TTL_DAYS: i64 = 7;
+
+/// Request structure for storing a suggestion from the assistant or the analytics engine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAiSuggestionRequest {
+    pub suggestion_type: String,
+    pub title: String,
+    pub description: String,
+    pub confidence: f64, // 0-1
+    pub actionable: Option<bool>,
+    pub priority: Option<i32>,
+    pub estimated_impact: Option<f64>,
+    pub reasoning: Option<String>,
+    pub actions: Option<serde_json::Value>,
+    /// Unset keeps the suggestion for `DEFAULT_SUGGESTION_TTL_DAYS`
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub interaction_log_id: Option<String>,
+}
+
+/// AI suggestion repository for SeaORM-based database operations
+pub struct AiSuggestionRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AiSuggestionRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Store a batch of suggestions, all or none
+    pub async fn create_suggestions(
+        &self,
+        requests: Vec<CreateAiSuggestionRequest>,
+    ) -> Result<Vec<ai_suggestions::Model>, DbErr> {
+        for request in &requests {
+            Self::validate(request)?;
+        }
+
+        let txn = self.db.begin().await?;
+        let mut created = Vec::with_capacity(requests.len());
+        for request in requests {
+            let actions = request
+                .actions
+                .map(|actions| serde_json::to_string(&actions).unwrap_or_default());
+            let suggestion = ai_suggestions::ActiveModel {
+                suggestion_type: Set(request.suggestion_type.trim().to_string()),
+                title: Set(request.title.trim().to_string()),
+                description: Set(request.description),
+                confidence: Set(request.confidence),
+                actionable: Set(request.actionable.unwrap_or(true)),
+                priority: Set(request.priority.unwrap_or(1)),
+                estimated_impact: Set(request.estimated_impact.unwrap_or(0.0)),
+                reasoning: Set(request.reasoning),
+                actions: Set(actions),
+                expires_at: Set(request.expires_at),
+                interaction_log_id: Set(request.interaction_log_id),
+                ..Default::default()
+            };
+            created.push(suggestion.insert(&txn).await?);
+        }
+        txn.commit().await?;
+
+        Ok(created)
+    }
+
+    /// Find a suggestion by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<ai_suggestions::Model>, DbErr> {
+        ai_suggestions::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find the suggestions still waiting for an answer at `now`, most important first
+    pub async fn find_active(
+        &self,
+        suggestion_type: Option<&str>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<ai_suggestions::Model>, DbErr> {
+        let mut query = ai_suggestions::Entity::find()
+            .filter(ai_suggestions::Column::DismissedAt.is_null())
+            .filter(ai_suggestions::Column::AppliedAt.is_null())
+            .filter(Self::unexpired(now));
+
+        if let Some(suggestion_type) = suggestion_type {
+            query = query.filter(ai_suggestions::Column::SuggestionType.eq(suggestion_type));
+        }
+
+        query
+            .order_by_desc(ai_suggestions::Column::Priority)
+            .order_by_desc(ai_suggestions::Column::Confidence)
+            .order_by_desc(ai_suggestions::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Find the suggestions made in an AI interaction log
+    pub async fn find_for_interaction_log(
+        &self,
+        interaction_log_id: &str,
+    ) -> Result<Vec<ai_suggestions::Model>, DbErr> {
+        ai_suggestions::Entity::find()
+            .filter(ai_suggestions::Column::InteractionLogId.eq(interaction_log_id))
+            .order_by_asc(ai_suggestions::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Mark a suggestion as accepted
+    pub async fn accept_suggestion(&self, id: &str) -> Result<ai_suggestions::Model, DbErr> {
+        let mut suggestion = self.find_unanswered(id).await?;
+        suggestion.applied_at = Set(Some(chrono::Utc::now()));
+        suggestion.update(&*self.db).await
+    }
+
+    /// Mark a suggestion as dismissed
+    pub async fn dismiss_suggestion(&self, id: &str) -> Result<ai_suggestions::Model, DbErr> {
+        let mut suggestion = self.find_unanswered(id).await?;
+        suggestion.dismissed_at = Set(Some(chrono::Utc::now()));
+        suggestion.update(&*self.db).await
+    }
+
+    /// Delete the unanswered suggestions that expired by `now`, returning how many there were
+    ///
+    /// Accepted and dismissed suggestions are kept as history.
+    pub async fn expire_stale(&self, now: chrono::DateTime<chrono::Utc>) -> Result<u64, DbErr> {
+        let result = ai_suggestions::Entity::delete_many()
+            .filter(ai_suggestions::Column::DismissedAt.is_null())
+            .filter(ai_suggestions::Column::AppliedAt.is_null())
+            .filter(Self::expired(now))
+            .exec(&*self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Suggestions whose expiry, or default lifetime, has not passed at `now`
+    fn unexpired(now: chrono::DateTime<chrono::Utc>) -> Condition {
+        let default_cutoff = now - chrono::Duration::days(DEFAULT_SUGGESTION_TTL_DAYS);
+        Condition::any()
+            .add(ai_suggestions::Column::ExpiresAt.gt(now))
+            .add(
+                Condition::all()
+                    .add(ai_suggestions::Column::ExpiresAt.is_null())
+                    .add(ai_suggestions::Column::CreatedAt.gt(default_cutoff)),
+            )
+    }
+
+    /// Suggestions whose expiry, or default lifetime, has passed at `now`
+    fn expired(now: chrono::DateTime<chrono::Utc>) -> Condition {
+        let default_cutoff = now - chrono::Duration::days(DEFAULT_SUGGESTION_TTL_DAYS);
+        Condition::any()
+            .add(ai_suggestions::Column::ExpiresAt.lte(now))
+            .add(
+                Condition::all()
+                    .add(ai_suggestions::Column::ExpiresAt.is_null())
+                    .add(ai_suggestions::Column::CreatedAt.lte(default_cutoff)),
+            )
+    }
+
+    async fn find_unanswered(&self, id: &str) -> Result<ai_suggestions::ActiveModel, DbErr> {
+        let suggestion = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Suggestion not found".to_string()))?;
+        if suggestion.applied_at.is_some() || suggestion.dismissed_at.is_some() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Suggestion was already answered".to_string(),
+            ));
+        }
+        Ok(suggestion.into())
+    }
+
+    fn validate(request: &CreateAiSuggestionRequest) -> Result<(), DbErr> {
+        if request.suggestion_type.trim().is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Suggestion type cannot be empty".to_string(),
+            ));
+        }
+        if request.title.trim().is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Suggestion title cannot be empty".to_string(),
+            ));
+        }
+        if !(0.0..=1.0).contains(&request.confidence) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Confidence must be between 0 and 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+use crate::database::entities::api_tokens;
+
+/// Scopes that can be granted to an API token
+///
+/// `admin` grants every command, including destructive data operations.
+pub const API_TOKEN_SCOPES: [&str; 8] = [
+    "read",
+    "tasks:write",
+    "lists:write",
+    "time:write",
+    "settings:write",
+    "threads:write",
+    "data:transfer",
+    "admin",
+];
+
+/// Prefix of every issued token secret, so leaked tokens are easy to recognise
+const TOKEN_PREFIX: &str = "kp_";
+
+/// Request structure for issuing a new API token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiTokenRequest {
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+/// A freshly issued token; the secret is only ever returned here
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuedApiToken {
+    pub token: api_tokens::Model,
+    pub secret: String,
+}
+
+/// API token repository for SeaORM-based database operations
+pub struct ApiTokenRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ApiTokenRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Issue a new token with the given scopes
+    pub async fn create_token(
+        &self,
+        request: CreateApiTokenRequest,
+    ) -> Result<IssuedApiToken, DbErr> {
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Token name cannot be empty".to_string(),
+            ));
+        }
+        let scopes = Self::validate_scopes(&request.scopes)?;
+
+        let secret = format!(
+            "{}{}{}",
+            TOKEN_PREFIX,
+            uuid::Uuid::new_v4().simple(),
+            uuid::Uuid::new_v4().simple()
+        );
+
+        let token = api_tokens::ActiveModel {
+            name: Set(name),
+            token_hash: Set(hash_secret(&secret)),
+            scopes: Set(serde_json::to_string(&scopes).unwrap_or_default()),
+            ..Default::default()
+        };
+
+        Ok(IssuedApiToken {
+            token: token.insert(&*self.db).await?,
+            secret,
+        })
+    }
+
+    /// Find a token by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<api_tokens::Model>, DbErr> {
+        api_tokens::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find all tokens, newest first, including revoked ones
+    pub async fn find_all(&self) -> Result<Vec<api_tokens::Model>, DbErr> {
+        api_tokens::Entity::find()
+            .order_by_desc(api_tokens::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Revoke a token; revoking twice keeps the original revocation time
+    pub async fn revoke_token(&self, id: &str) -> Result<api_tokens::Model, DbErr> {
+        let token = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("API token not found".to_string()))?;
+
+        if token.revoked_at.is_some() {
+            return Ok(token);
+        }
+
+        let mut active: api_tokens::ActiveModel = token.into();
+        active.revoked_at = Set(Some(chrono::Utc::now()));
+        active.update(&*self.db).await
+    }
+
+    /// Resolve a token secret to its active token, recording the use
+    pub async fn authenticate(&self, secret: &str) -> Result<api_tokens::Model, DbErr> {
+        let token = api_tokens::Entity::find()
+            .filter(api_tokens::Column::TokenHash.eq(hash_secret(secret.trim())))
+            .filter(api_tokens::Column::RevokedAt.is_null())
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| {
+                DbErr::Custom("UNAUTHORIZED_ERROR: Invalid or revoked API token".to_string())
+            })?;
+
+        let mut active: api_tokens::ActiveModel = token.into();
+        active.last_used_at = Set(Some(chrono::Utc::now()));
+        active.update(&*self.db).await
+    }
+
+    fn validate_scopes(scopes: &[String]) -> Result<Vec<String>, DbErr> {
+        let mut valid = Vec::new();
+        for scope in scopes {
+            let scope = scope.trim();
+            if !API_TOKEN_SCOPES.contains(&scope) {
+                return Err(DbErr::Custom(format!(
+                    "VALIDATION_ERROR: Unknown token scope '{}'",
+                    scope
+                )));
+            }
+            if !valid.iter().any(|s| s == scope) {
+                valid.push(scope.to_string());
+            }
+        }
+
+        if valid.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A token needs at least one scope".to_string(),
+            ));
+        }
+        Ok(valid)
+    }
+}
+
+/// Scopes granted to a token
+pub fn token_scopes(token: &api_tokens::Model) -> Vec<String> {
+    serde_json::from_str(&token.scopes).unwrap_or_default()
+}
+
+fn hash_secret(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -0,0 +1,367 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::database::entities::{attachments, tasks};
+
+/// Space all stored attachment files may take together (1 GiB)
+pub const DEFAULT_ATTACHMENT_QUOTA_BYTES: i64 = 1024 * 1024 * 1024;
+
+/// Untracked files younger than this may belong to an attachment still being added
+const ORPHAN_MIN_AGE: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Attachment storage in use, for the health report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttachmentStorage {
+    pub attachment_count: usize,
+    pub used_bytes: i64,
+    pub quota_bytes: i64,
+}
+
+/// Attachment files a cleanup removes
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AttachmentCleanup {
+    /// Attachments of archived tasks; their rows are removed along with the files
+    pub archived_attachments: Vec<attachments::Model>,
+    /// Stored files no attachment refers to, e.g. left behind by a failed delete
+    pub orphaned_files: Vec<String>,
+    pub reclaimed_bytes: i64,
+}
+
+/// Attachment repository for SeaORM-based database operations
+///
+/// Attachment metadata lives in the database; the files themselves are copied into
+/// `attachments_dir` so they stay available when the original is moved or deleted.
+pub struct AttachmentRepository {
+    db: Arc<DatabaseConnection>,
+    attachments_dir: PathBuf,
+    quota_bytes: i64,
+}
+
+impl AttachmentRepository {
+    pub fn new(db: Arc<DatabaseConnection>, attachments_dir: PathBuf) -> Self {
+        Self {
+            db,
+            attachments_dir,
+            quota_bytes: DEFAULT_ATTACHMENT_QUOTA_BYTES,
+        }
+    }
+
+    /// Limit the space stored attachment files may take together
+    pub fn with_quota(mut self, quota_bytes: i64) -> Self {
+        self.quota_bytes = quota_bytes;
+        self
+    }
+
+    /// Copy a file into the attachments directory and attach it to a task
+    pub async fn add_attachment(
+        &self,
+        task_id: &str,
+        source: &Path,
+    ) -> Result<attachments::Model, DbErr> {
+        tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let file_name = source
+            .file_name()
+            .and_then(|name| name.to_str())
+            .filter(|_| source.is_file())
+            .ok_or_else(|| {
+                DbErr::Custom(format!(
+                    "VALIDATION_ERROR: {} is not a file",
+                    source.display()
+                ))
+            })?
+            .to_string();
+        let contents = tokio::fs::read(source)
+            .await
+            .map_err(|e| file_error("read attachment", e))?;
+        let storage = self.storage_usage().await?;
+        if storage.used_bytes + contents.len() as i64 > storage.quota_bytes {
+            return Err(DbErr::Custom(format!(
+                "BUSINESS_RULE_ERROR: Adding {} ({} bytes) would exceed the attachment quota \
+                 of {} bytes ({} in use); clean up attachments first",
+                file_name,
+                contents.len(),
+                storage.quota_bytes,
+                storage.used_bytes
+            )));
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let extension = source
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase());
+        let stored_name = match &extension {
+            Some(ext) => format!("{}.{}", id, ext),
+            None => id.clone(),
+        };
+
+        tokio::fs::create_dir_all(&self.attachments_dir)
+            .await
+            .map_err(|e| file_error("create attachments directory", e))?;
+        let stored_path = self.attachments_dir.join(&stored_name);
+        tokio::fs::write(&stored_path, &contents)
+            .await
+            .map_err(|e| file_error("store attachment", e))?;
+
+        let attachment = attachments::ActiveModel {
+            id: Set(id),
+            task_id: Set(task_id.to_string()),
+            file_name: Set(file_name),
+            stored_name: Set(stored_name),
+            mime_type: Set(extension.as_deref().and_then(mime_type).map(String::from)),
+            size: Set(contents.len() as i64),
+            checksum: Set(checksum(&contents)),
+            ..Default::default()
+        };
+
+        match attachment.insert(&*self.db).await {
+            Ok(attachment) => Ok(attachment),
+            Err(e) => {
+                // Don't leave an untracked copy behind
+                let _ = tokio::fs::remove_file(&stored_path).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Find an attachment by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<attachments::Model>, DbErr> {
+        attachments::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find the attachments of a task, oldest first
+    pub async fn find_by_task(&self, task_id: &str) -> Result<Vec<attachments::Model>, DbErr> {
+        attachments::Entity::find()
+            .filter(attachments::Column::TaskId.eq(task_id))
+            .order_by_asc(attachments::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Remove an attachment and its stored file
+    pub async fn remove_attachment(&self, id: &str) -> Result<(), DbErr> {
+        let attachment = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Attachment not found".to_string()))?;
+
+        attachments::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        self.remove_files(&[attachment]).await;
+        Ok(())
+    }
+
+    /// Location of an attachment's stored file
+    pub fn file_path(&self, attachment: &attachments::Model) -> PathBuf {
+        self.attachments_dir.join(&attachment.stored_name)
+    }
+
+    /// Delete the stored files of attachments whose rows are gone
+    ///
+    /// Missing files are ignored; other failures are logged so they don't undo the deletion.
+    pub async fn remove_files(&self, attachments: &[attachments::Model]) {
+        for attachment in attachments {
+            match tokio::fs::remove_file(self.file_path(attachment)).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => log::warn!(
+                    "Failed to delete attachment file {}: {}",
+                    attachment.stored_name,
+                    e
+                ),
+            }
+        }
+    }
+
+    /// Count attachments and the space their stored files take
+    pub async fn storage_usage(&self) -> Result<AttachmentStorage, DbErr> {
+        let attachments = attachments::Entity::find().all(&*self.db).await?;
+        Ok(AttachmentStorage {
+            attachment_count: attachments.len(),
+            used_bytes: attachments.iter().map(|attachment| attachment.size).sum(),
+            quota_bytes: self.quota_bytes,
+        })
+    }
+
+    /// Find what [`Self::clean_up`] would remove, without removing anything
+    pub async fn preview_cleanup(&self) -> Result<AttachmentCleanup, DbErr> {
+        let archived_attachments = attachments::Entity::find()
+            .inner_join(tasks::Entity)
+            .filter(tasks::Column::ArchivedAt.is_not_null())
+            .order_by_asc(attachments::Column::CreatedAt)
+            .all(&*self.db)
+            .await?;
+
+        let known: HashSet<String> = attachments::Entity::find()
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|attachment| attachment.stored_name)
+            .collect();
+        let mut orphaned_files = Vec::new();
+        let mut reclaimed_bytes: i64 = archived_attachments.iter().map(|a| a.size).sum();
+        for (name, size) in self.settled_files().await? {
+            if !known.contains(&name) {
+                reclaimed_bytes += size;
+                orphaned_files.push(name);
+            }
+        }
+        orphaned_files.sort();
+
+        Ok(AttachmentCleanup {
+            archived_attachments,
+            orphaned_files,
+            reclaimed_bytes,
+        })
+    }
+
+    /// Remove the attachments of archived tasks and stored files no attachment refers to
+    ///
+    /// Meant to run after the user confirmed a [`Self::preview_cleanup`]; returns what was
+    /// removed.
+    pub async fn clean_up(&self) -> Result<AttachmentCleanup, DbErr> {
+        let cleanup = self.preview_cleanup().await?;
+
+        let ids: Vec<String> = cleanup
+            .archived_attachments
+            .iter()
+            .map(|attachment| attachment.id.clone())
+            .collect();
+        attachments::Entity::delete_many()
+            .filter(attachments::Column::Id.is_in(ids))
+            .exec(&*self.db)
+            .await?;
+        self.remove_files(&cleanup.archived_attachments).await;
+
+        for name in &cleanup.orphaned_files {
+            if let Err(e) = tokio::fs::remove_file(self.attachments_dir.join(name)).await {
+                log::warn!("Failed to delete orphaned attachment file {}: {}", name, e);
+            }
+        }
+
+        Ok(cleanup)
+    }
+
+    /// Names and sizes of the files in the attachments directory older than [`ORPHAN_MIN_AGE`]
+    async fn settled_files(&self) -> Result<Vec<(String, i64)>, DbErr> {
+        let mut entries = match tokio::fs::read_dir(&self.attachments_dir).await {
+            Ok(entries) => entries,
+            // Nothing was ever attached
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(file_error("read attachments directory", e)),
+        };
+
+        let mut files = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| file_error("read attachments directory", e))?
+        {
+            let metadata = entry
+                .metadata()
+                .await
+                .map_err(|e| file_error("read attachment file", e))?;
+            let settled = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age >= ORPHAN_MIN_AGE);
+            if metadata.is_file() && settled {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                files.push((name, metadata.len() as i64));
+            }
+        }
+        Ok(files)
+    }
+
+    /// Delete the attachment rows of a task
+    pub async fn delete_by_task<C: ConnectionTrait>(conn: &C, task_id: &str) -> Result<u64, DbErr> {
+        let result = attachments::Entity::delete_many()
+            .filter(attachments::Column::TaskId.eq(task_id))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Find the attachments of the given tasks, oldest first
+    pub async fn find_by_tasks_in<C: ConnectionTrait>(
+        conn: &C,
+        task_ids: Vec<String>,
+    ) -> Result<Vec<attachments::Model>, DbErr> {
+        attachments::Entity::find()
+            .filter(attachments::Column::TaskId.is_in(task_ids))
+            .order_by_asc(attachments::Column::CreatedAt)
+            .all(conn)
+            .await
+    }
+
+    /// Get all attachments (for backup)
+    pub async fn get_all_attachments(&self) -> Result<Vec<attachments::Model>, DbErr> {
+        attachments::Entity::find().all(&*self.db).await
+    }
+
+    /// Import an attachment row (for backup restore); the file is restored separately
+    pub async fn import_attachment(
+        &self,
+        attachment: attachments::Model,
+    ) -> Result<attachments::Model, DbErr> {
+        Self::import_attachment_in(&*self.db, attachment).await
+    }
+
+    /// Import an attachment row on the given connection, e.g. inside a bundle import
+    pub async fn import_attachment_in<C: ConnectionTrait>(
+        conn: &C,
+        attachment: attachments::Model,
+    ) -> Result<attachments::Model, DbErr> {
+        let active_model: attachments::ActiveModel = attachment.into();
+        active_model.insert(conn).await
+    }
+}
+
+fn file_error(action: &str, error: std::io::Error) -> DbErr {
+    DbErr::Custom(format!("FILE_ERROR: Failed to {}: {}", action, error))
+}
+
+/// sha256:<hex> digest of a file's contents
+pub fn checksum(contents: &[u8]) -> String {
+    let digest = Sha256::digest(contents);
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("sha256:{}", hex)
+}
+
+/// MIME type of common attachment extensions
+fn mime_type(extension: &str) -> Option<&'static str> {
+    let mime = match extension {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        _ => return None,
+    };
+    Some(mime)
+}
@@ -0,0 +1,270 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{checklist_items, tasks};
+use crate::database::repositories::rank::{rank_between, spaced_rank};
+use crate::database::repositories::retry::retry_on_busy;
+
+/// Request structure for adding an item to a task's checklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateChecklistItemRequest {
+    pub task_id: String,
+    pub title: String,
+}
+
+/// Request structure for updating a checklist item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateChecklistItemRequest {
+    pub title: Option<String>,
+    pub completed: Option<bool>,
+}
+
+/// Checklist item repository for SeaORM-based database operations
+pub struct ChecklistRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ChecklistRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Add an item to the end of a task's checklist
+    pub async fn create_item(
+        &self,
+        request: CreateChecklistItemRequest,
+    ) -> Result<checklist_items::Model, DbErr> {
+        let title = Self::validate_title(&request.title)?;
+        tasks::Entity::find_by_id(&request.task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let last_rank = checklist_items::Entity::find()
+            .filter(checklist_items::Column::TaskId.eq(&request.task_id))
+            .order_by_desc(checklist_items::Column::OrderNum)
+            .one(&*self.db)
+            .await?
+            .map(|item| item.order_num);
+
+        let item = checklist_items::ActiveModel {
+            task_id: Set(request.task_id),
+            title: Set(title),
+            order_num: Set(rank_between(last_rank, None).unwrap_or(i32::MAX)),
+            ..Default::default()
+        };
+
+        item.insert(&*self.db).await
+    }
+
+    /// Find a checklist item by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<checklist_items::Model>, DbErr> {
+        checklist_items::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find the checklist of a task, in order
+    pub async fn find_by_task(&self, task_id: &str) -> Result<Vec<checklist_items::Model>, DbErr> {
+        checklist_items::Entity::find()
+            .filter(checklist_items::Column::TaskId.eq(task_id))
+            .order_by_asc(checklist_items::Column::OrderNum)
+            .order_by_asc(checklist_items::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Rename a checklist item or check it off
+    ///
+    /// Checking off an item that is already done keeps its original completion time.
+    pub async fn update_item(
+        &self,
+        id: &str,
+        request: UpdateChecklistItemRequest,
+    ) -> Result<checklist_items::Model, DbErr> {
+        let item = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Checklist item not found".to_string()))?;
+
+        let completed_at = item.completed_at;
+        let mut active: checklist_items::ActiveModel = item.into();
+        if let Some(title) = request.title {
+            active.title = Set(Self::validate_title(&title)?);
+        }
+        match request.completed {
+            Some(true) if completed_at.is_none() => {
+                active.completed_at = Set(Some(chrono::Utc::now()));
+            }
+            Some(false) => active.completed_at = Set(None),
+            _ => {}
+        }
+        active.updated_at = Set(chrono::Utc::now());
+
+        active.update(&*self.db).await
+    }
+
+    /// Delete a checklist item
+    pub async fn delete_item(&self, id: &str) -> Result<(), DbErr> {
+        let result = checklist_items::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound(
+                "Checklist item not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Move a checklist item between two neighbours of the same task
+    ///
+    /// Either neighbour may be omitted to move the item to the start or end of the checklist.
+    pub async fn reorder_item(
+        &self,
+        id: &str,
+        before_id: Option<&str>,
+        after_id: Option<&str>,
+    ) -> Result<checklist_items::Model, DbErr> {
+        retry_on_busy(|| self.try_reorder_item(id, before_id, after_id)).await
+    }
+
+    async fn try_reorder_item(
+        &self,
+        id: &str,
+        before_id: Option<&str>,
+        after_id: Option<&str>,
+    ) -> Result<checklist_items::Model, DbErr> {
+        let txn = self.db.begin().await?;
+        let item = checklist_items::Entity::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Checklist item not found".to_string()))?;
+
+        let before = Self::find_rank_neighbour(&txn, &item, before_id).await?;
+        let after = Self::find_rank_neighbour(&txn, &item, after_id).await?;
+        if before.is_none() && after.is_none() {
+            return Ok(item);
+        }
+
+        let rank = rank_between(
+            before.as_ref().map(|item| item.order_num),
+            after.as_ref().map(|item| item.order_num),
+        );
+        let rank = match rank {
+            Some(rank) => rank,
+            None => {
+                let mut ids: Vec<String> = checklist_items::Entity::find()
+                    .filter(checklist_items::Column::TaskId.eq(&item.task_id))
+                    .filter(checklist_items::Column::Id.ne(id))
+                    .order_by_asc(checklist_items::Column::OrderNum)
+                    .order_by_asc(checklist_items::Column::CreatedAt)
+                    .all(&txn)
+                    .await?
+                    .into_iter()
+                    .map(|item| item.id)
+                    .collect();
+
+                let position = match (&before, &after) {
+                    (Some(before), _) => ids.iter().position(|i| *i == before.id).map(|i| i + 1),
+                    (None, Some(after)) => ids.iter().position(|i| *i == after.id),
+                    (None, None) => None,
+                }
+                .unwrap_or(0);
+                ids.insert(position, id.to_string());
+
+                for (index, item_id) in ids.iter().enumerate().filter(|(_, i)| *i != id) {
+                    checklist_items::Entity::update_many()
+                        .col_expr(
+                            checklist_items::Column::OrderNum,
+                            sea_orm::sea_query::Expr::value(spaced_rank(index)),
+                        )
+                        .filter(checklist_items::Column::Id.eq(item_id.clone()))
+                        .exec(&txn)
+                        .await?;
+                }
+                spaced_rank(position)
+            }
+        };
+
+        let mut active: checklist_items::ActiveModel = item.into();
+        active.order_num = Set(rank);
+        active.updated_at = Set(chrono::Utc::now());
+        let updated = active.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(updated)
+    }
+
+    async fn find_rank_neighbour<C: ConnectionTrait>(
+        conn: &C,
+        item: &checklist_items::Model,
+        neighbour_id: Option<&str>,
+    ) -> Result<Option<checklist_items::Model>, DbErr> {
+        let Some(neighbour_id) = neighbour_id else {
+            return Ok(None);
+        };
+        if neighbour_id == item.id {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A checklist item cannot be placed next to itself".to_string(),
+            ));
+        }
+
+        let neighbour = checklist_items::Entity::find_by_id(neighbour_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| {
+                DbErr::RecordNotFound("Neighbouring checklist item not found".to_string())
+            })?;
+        if neighbour.task_id != item.task_id {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Checklist items can only be reordered within the same task"
+                    .to_string(),
+            ));
+        }
+        Ok(Some(neighbour))
+    }
+
+    /// Delete the checklist of a task
+    pub async fn delete_by_task<C: ConnectionTrait>(conn: &C, task_id: &str) -> Result<u64, DbErr> {
+        let result = checklist_items::Entity::delete_many()
+            .filter(checklist_items::Column::TaskId.eq(task_id))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Get all checklist items (for backup)
+    pub async fn get_all_items(&self) -> Result<Vec<checklist_items::Model>, DbErr> {
+        checklist_items::Entity::find().all(&*self.db).await
+    }
+
+    /// Import a checklist item (for backup restore)
+    pub async fn import_item(
+        &self,
+        item: checklist_items::Model,
+    ) -> Result<checklist_items::Model, DbErr> {
+        Self::import_item_in(&*self.db, item).await
+    }
+
+    /// Import a checklist item on `conn`
+    pub async fn import_item_in<C: ConnectionTrait>(
+        conn: &C,
+        item: checklist_items::Model,
+    ) -> Result<checklist_items::Model, DbErr> {
+        let active_model: checklist_items::ActiveModel = item.into();
+        active_model.insert(conn).await
+    }
+
+    fn validate_title(title: &str) -> Result<String, DbErr> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Checklist item title cannot be empty".to_string(),
+            ));
+        }
+        Ok(title.to_string())
+    }
+}
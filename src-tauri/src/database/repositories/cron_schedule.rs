@@ -0,0 +1,242 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike};
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const DAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// How far ahead to look for the next occurrence; covers rules like "every Feb 29th"
+const MAX_SEARCH_DAYS: i64 = 366 * 8;
+
+/// A parsed five-field cron expression: `minute hour day-of-month month day-of-week`
+///
+/// Besides the usual `*`, lists, ranges and steps (and the `@daily`, `@weekly`, `@monthly`
+/// and `@yearly` shortcuts), a few extensions cover calendar rules:
+///
+/// - `L` in day-of-month is the last day of the month, `LW` the last weekday
+/// - `5L` in day-of-week is the last Friday of the month
+/// - `2#2` in day-of-week is the second Tuesday of the month
+///
+/// As in standard cron, a day matches either day field when both are restricted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    last_day_of_month: bool,
+    last_weekday_of_month: bool,
+    /// Weekday bits of `nL` entries
+    last_days_of_week: u64,
+    /// `(weekday, n)` pairs of `d#n` entries
+    nth_days_of_week: Vec<(u32, u32)>,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parse a cron expression, describing the first problem found on failure
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let expression = match expression.trim().to_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *".to_string(),
+            "@monthly" => "0 0 1 * *".to_string(),
+            "@weekly" => "0 0 * * 0".to_string(),
+            "@daily" | "@midnight" => "0 0 * * *".to_string(),
+            _ => expression.trim().to_uppercase(),
+        };
+
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(format!(
+                "expected 5 fields (minute hour day-of-month month day-of-week), found {}",
+                fields.len()
+            ));
+        };
+
+        let mut schedule = Self {
+            minutes: parse_field(minute, 0, 59, &[])?,
+            hours: parse_field(hour, 0, 23, &[])?,
+            days_of_month: 0,
+            months: parse_field(month, 1, 12, &MONTH_NAMES)?,
+            days_of_week: 0,
+            last_day_of_month: false,
+            last_weekday_of_month: false,
+            last_days_of_week: 0,
+            nth_days_of_week: Vec::new(),
+            day_of_month_restricted: !is_wildcard(day_of_month),
+            day_of_week_restricted: !is_wildcard(day_of_week),
+        };
+
+        for part in day_of_month.split(',') {
+            match part {
+                "L" => schedule.last_day_of_month = true,
+                "LW" => schedule.last_weekday_of_month = true,
+                _ => schedule.days_of_month |= parse_field(part, 1, 31, &[])?,
+            }
+        }
+
+        for part in day_of_week.split(',') {
+            if let Some((day, n)) = part.split_once('#') {
+                let day = parse_day_of_week(day)?;
+                let n = n
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| (1..=5).contains(n))
+                    .ok_or_else(|| format!("invalid occurrence in '{}', expected 1-5", part))?;
+                schedule.nth_days_of_week.push((day, n));
+            } else if let Some(day) = part.strip_suffix('L').filter(|day| !day.is_empty()) {
+                schedule.last_days_of_week |= 1 << parse_day_of_week(day)?;
+            } else {
+                let days = parse_field(part, 0, 7, &DAY_NAMES)?;
+                // Both 0 and 7 mean Sunday
+                schedule.days_of_week |= (days | (days >> 7)) & 0x7f;
+            }
+        }
+
+        Ok(schedule)
+    }
+
+    /// The first occurrence strictly after `after`, in the same time zone
+    ///
+    /// Local times skipped by a DST change are not occurrences. Returns `None` when the
+    /// expression never matches (e.g. `0 0 31 2 *`).
+    pub fn next_after<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let timezone = after.timezone();
+        let start = after.naive_local();
+
+        for offset in 0..MAX_SEARCH_DAYS {
+            let date = start.date() + Duration::days(offset);
+            if !self.matches_date(date) {
+                continue;
+            }
+
+            for hour in bits(self.hours) {
+                for minute in bits(self.minutes) {
+                    if offset == 0 && (hour, minute) <= (start.hour(), start.minute()) {
+                        continue;
+                    }
+                    let candidate = date.and_hms_opt(hour, minute, 0)?;
+                    if let Some(occurrence) = timezone.from_local_datetime(&candidate).earliest() {
+                        return Some(occurrence);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+
+        let last_day = days_in_month(date);
+        let weekday = date.weekday().num_days_from_sunday();
+
+        let day_of_month_match = self.days_of_month & (1 << date.day()) != 0
+            || (self.last_day_of_month && date.day() == last_day)
+            || (self.last_weekday_of_month && date.day() == last_weekday(date, last_day));
+        let day_of_week_match = self.days_of_week & (1 << weekday) != 0
+            || (self.last_days_of_week & (1 << weekday) != 0 && date.day() + 7 > last_day)
+            || self
+                .nth_days_of_week
+                .iter()
+                .any(|&(day, n)| day == weekday && (date.day() - 1) / 7 + 1 == n);
+
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => day_of_month_match || day_of_week_match,
+            (true, false) => day_of_month_match,
+            (false, true) => day_of_week_match,
+            (false, false) => true,
+        }
+    }
+}
+
+fn is_wildcard(field: &str) -> bool {
+    field == "*" || field == "?"
+}
+
+/// Parse a cron field into a bit set of allowed values
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, String> {
+    let mut set = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| format!("invalid step in '{}'", part))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if is_wildcard(range) {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, min, max, names)?,
+                parse_value(end, min, max, names)?,
+            )
+        } else {
+            let value = parse_value(range, min, max, names)?;
+            // "5/15" means every 15 starting at 5
+            (value, if part.contains('/') { max } else { value })
+        };
+
+        if start > end {
+            return Err(format!("invalid range '{}'", part));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            set |= 1 << value;
+        }
+    }
+
+    Ok(set)
+}
+
+fn parse_value(value: &str, min: u32, max: u32, names: &[&str]) -> Result<u32, String> {
+    if let Some(index) = names.iter().position(|name| *name == value) {
+        // Month names start at 1, day names at 0
+        return Ok(index as u32 + min);
+    }
+
+    value
+        .parse::<u32>()
+        .ok()
+        .filter(|value| (min..=max).contains(value))
+        .ok_or_else(|| format!("invalid value '{}', expected {}-{}", value, min, max))
+}
+
+fn parse_day_of_week(value: &str) -> Result<u32, String> {
+    parse_value(value, 0, 7, &DAY_NAMES).map(|day| day % 7)
+}
+
+fn bits(set: u64) -> impl Iterator<Item = u32> {
+    (0..64).filter(move |bit| set & (1 << bit) != 0)
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|first| first.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(28)
+}
+
+fn last_weekday(date: NaiveDate, last_day: u32) -> u32 {
+    let last = date.with_day(last_day).unwrap_or(date);
+    match last.weekday().num_days_from_sunday() {
+        0 => last_day - 2, // Sunday -> Friday
+        6 => last_day - 1, // Saturday -> Friday
+        _ => last_day,
+    }
+}
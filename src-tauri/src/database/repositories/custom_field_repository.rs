@@ -0,0 +1,410 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::database::entities::{custom_field_values, custom_fields, task_lists, tasks};
+
+/// Supported custom field types
+pub const CUSTOM_FIELD_TYPES: [&str; 4] = ["text", "number", "select", "date"];
+
+/// Request structure for creating a new custom field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCustomFieldRequest {
+    pub task_list_id: String,
+    pub name: String,
+    pub field_type: String,
+    pub options: Option<Vec<String>>,
+}
+
+/// Request structure for updating an existing custom field
+///
+/// The field type cannot be changed once values may have been stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCustomFieldRequest {
+    pub name: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub order_num: Option<i32>,
+}
+
+/// A custom field value joined with its field definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskCustomFieldValue {
+    pub field_id: String,
+    pub name: String,
+    pub field_type: String,
+    pub value: String,
+}
+
+/// Custom field repository for SeaORM-based database operations
+pub struct CustomFieldRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl CustomFieldRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new custom field on a task list
+    pub async fn create_field(
+        &self,
+        request: CreateCustomFieldRequest,
+    ) -> Result<custom_fields::Model, DbErr> {
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Custom field name cannot be empty".to_string(),
+            ));
+        }
+
+        if !CUSTOM_FIELD_TYPES.contains(&request.field_type.as_str()) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unsupported custom field type '{}'",
+                request.field_type
+            )));
+        }
+
+        let options = Self::validate_options(&request.field_type, request.options)?;
+
+        if task_lists::Entity::find_by_id(&request.task_list_id)
+            .one(&*self.db)
+            .await?
+            .is_none()
+        {
+            return Err(DbErr::RecordNotFound(format!(
+                "Task list '{}' not found",
+                request.task_list_id
+            )));
+        }
+
+        let existing = custom_fields::Entity::find()
+            .filter(custom_fields::Column::TaskListId.eq(&request.task_list_id))
+            .filter(custom_fields::Column::Name.eq(&name))
+            .one(&*self.db)
+            .await?;
+        if existing.is_some() {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: A custom field named '{}' already exists in this task list",
+                name
+            )));
+        }
+
+        let order_num = custom_fields::Entity::find()
+            .filter(custom_fields::Column::TaskListId.eq(&request.task_list_id))
+            .count(&*self.db)
+            .await? as i32;
+
+        let field = custom_fields::ActiveModel {
+            task_list_id: Set(request.task_list_id),
+            name: Set(name),
+            field_type: Set(request.field_type),
+            options: Set(options),
+            order_num: Set(order_num),
+            ..Default::default()
+        };
+
+        field.insert(&*self.db).await
+    }
+
+    /// Find a custom field by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<custom_fields::Model>, DbErr> {
+        custom_fields::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find the custom fields defined on a task list
+    pub async fn find_by_task_list(
+        &self,
+        task_list_id: &str,
+    ) -> Result<Vec<custom_fields::Model>, DbErr> {
+        custom_fields::Entity::find()
+            .filter(custom_fields::Column::TaskListId.eq(task_list_id))
+            .order_by_asc(custom_fields::Column::OrderNum)
+            .order_by_asc(custom_fields::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Update a custom field
+    pub async fn update_field(
+        &self,
+        id: &str,
+        request: UpdateCustomFieldRequest,
+    ) -> Result<custom_fields::Model, DbErr> {
+        let field = custom_fields::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Custom field not found".to_string()))?;
+
+        let field_type = field.field_type.clone();
+        let task_list_id = field.task_list_id.clone();
+        let mut field: custom_fields::ActiveModel = field.into();
+
+        if let Some(name) = request.name {
+            let name = name.trim().to_string();
+            if name.is_empty() {
+                return Err(DbErr::Custom(
+                    "VALIDATION_ERROR: Custom field name cannot be empty".to_string(),
+                ));
+            }
+            let existing = custom_fields::Entity::find()
+                .filter(custom_fields::Column::TaskListId.eq(&task_list_id))
+                .filter(custom_fields::Column::Name.eq(&name))
+                .filter(custom_fields::Column::Id.ne(id))
+                .one(&*self.db)
+                .await?;
+            if existing.is_some() {
+                return Err(DbErr::Custom(format!(
+                    "DUPLICATE_ERROR: A custom field named '{}' already exists in this task list",
+                    name
+                )));
+            }
+            field.name = Set(name);
+        }
+        if let Some(options) = request.options {
+            field.options = Set(Self::validate_options(&field_type, Some(options))?);
+        }
+        if let Some(order_num) = request.order_num {
+            field.order_num = Set(order_num);
+        }
+
+        field.updated_at = Set(chrono::Utc::now());
+
+        field.update(&*self.db).await
+    }
+
+    /// Delete a custom field together with its stored values
+    pub async fn delete_field(&self, id: &str) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        custom_field_values::Entity::delete_many()
+            .filter(custom_field_values::Column::FieldId.eq(id))
+            .exec(&txn)
+            .await?;
+
+        custom_fields::Entity::delete_by_id(id).exec(&txn).await?;
+
+        txn.commit().await
+    }
+
+    /// Set or clear a task's value for a custom field
+    ///
+    /// Passing `None` or an empty string removes the value.
+    pub async fn set_value(
+        &self,
+        task_id: &str,
+        field_id: &str,
+        value: Option<String>,
+    ) -> Result<Option<custom_field_values::Model>, DbErr> {
+        let task = tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let field = self
+            .find_by_id(field_id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Custom field not found".to_string()))?;
+
+        if task.task_list_id.as_deref() != Some(field.task_list_id.as_str()) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Custom field '{}' does not belong to the task's list",
+                field.name
+            )));
+        }
+
+        let existing = custom_field_values::Entity::find()
+            .filter(custom_field_values::Column::TaskId.eq(task_id))
+            .filter(custom_field_values::Column::FieldId.eq(field_id))
+            .one(&*self.db)
+            .await?;
+
+        let Some(value) = value.filter(|v| !v.trim().is_empty()) else {
+            if let Some(existing) = existing {
+                custom_field_values::Entity::delete_by_id(existing.id)
+                    .exec(&*self.db)
+                    .await?;
+            }
+            return Ok(None);
+        };
+
+        let value = Self::normalize_value(&field, &value)?;
+
+        let saved = match existing {
+            Some(existing) => {
+                let mut active: custom_field_values::ActiveModel = existing.into();
+                active.value = Set(value);
+                active.updated_at = Set(chrono::Utc::now());
+                active.update(&*self.db).await?
+            }
+            None => {
+                custom_field_values::ActiveModel {
+                    task_id: Set(task_id.to_string()),
+                    field_id: Set(field_id.to_string()),
+                    value: Set(value),
+                    ..Default::default()
+                }
+                .insert(&*self.db)
+                .await?
+            }
+        };
+
+        Ok(Some(saved))
+    }
+
+    /// Set several values at once, addressing fields by name within the task's list
+    pub async fn set_values_by_name(
+        &self,
+        task_id: &str,
+        values: HashMap<String, Option<String>>,
+    ) -> Result<Vec<TaskCustomFieldValue>, DbErr> {
+        let task = tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let task_list_id = task.task_list_id.unwrap_or_default();
+        let fields = self.find_by_task_list(&task_list_id).await?;
+
+        for (name, value) in values {
+            let field = fields
+                .iter()
+                .find(|f| f.name.eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| {
+                    DbErr::RecordNotFound(format!("Custom field '{}' not found in task list", name))
+                })?;
+            self.set_value(task_id, &field.id, value).await?;
+        }
+
+        self.get_task_values(task_id).await
+    }
+
+    /// Get all custom field values of a task
+    pub async fn get_task_values(&self, task_id: &str) -> Result<Vec<TaskCustomFieldValue>, DbErr> {
+        let values = custom_field_values::Entity::find()
+            .filter(custom_field_values::Column::TaskId.eq(task_id))
+            .find_also_related(custom_fields::Entity)
+            .order_by_asc(custom_fields::Column::OrderNum)
+            .all(&*self.db)
+            .await?;
+
+        Ok(values
+            .into_iter()
+            .filter_map(|(value, field)| {
+                field.map(|field| TaskCustomFieldValue {
+                    field_id: field.id,
+                    name: field.name,
+                    field_type: field.field_type,
+                    value: value.value,
+                })
+            })
+            .collect())
+    }
+
+    /// Get all custom fields (for backup)
+    pub async fn get_all_fields(&self) -> Result<Vec<custom_fields::Model>, DbErr> {
+        custom_fields::Entity::find().all(&*self.db).await
+    }
+
+    /// Get all custom field values (for backup)
+    pub async fn get_all_values(&self) -> Result<Vec<custom_field_values::Model>, DbErr> {
+        custom_field_values::Entity::find().all(&*self.db).await
+    }
+
+    /// Import a custom field (for backup restore)
+    pub async fn import_field(
+        &self,
+        field: custom_fields::Model,
+    ) -> Result<custom_fields::Model, DbErr> {
+        let active_model: custom_fields::ActiveModel = field.into();
+        active_model.insert(&*self.db).await
+    }
+
+    /// Import a custom field value (for backup restore)
+    pub async fn import_value(
+        &self,
+        value: custom_field_values::Model,
+    ) -> Result<custom_field_values::Model, DbErr> {
+        let active_model: custom_field_values::ActiveModel = value.into();
+        active_model.insert(&*self.db).await
+    }
+
+    /// Delete all custom field values, keeping the field definitions
+    pub async fn delete_all_values(&self) -> Result<u64, DbErr> {
+        let result = custom_field_values::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Delete all custom fields and their values
+    pub async fn delete_all(&self) -> Result<u64, DbErr> {
+        self.delete_all_values().await?;
+        let result = custom_fields::Entity::delete_many().exec(&*self.db).await?;
+        Ok(result.rows_affected)
+    }
+
+    fn validate_options(
+        field_type: &str,
+        options: Option<Vec<String>>,
+    ) -> Result<Option<String>, DbErr> {
+        if field_type != "select" {
+            return Ok(None);
+        }
+
+        let options: Vec<String> = options
+            .unwrap_or_default()
+            .into_iter()
+            .map(|o| o.trim().to_string())
+            .filter(|o| !o.is_empty())
+            .collect();
+
+        if options.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Select fields need at least one option".to_string(),
+            ));
+        }
+
+        Ok(Some(serde_json::to_string(&options).unwrap_or_default()))
+    }
+
+    /// Check a raw value against the field type and return its stored form
+    fn normalize_value(field: &custom_fields::Model, value: &str) -> Result<String, DbErr> {
+        let value = value.trim();
+        let invalid = |expected: &str| {
+            DbErr::Custom(format!(
+                "VALIDATION_ERROR: '{}' is not a valid {} for field '{}'",
+                value, expected, field.name
+            ))
+        };
+
+        match field.field_type.as_str() {
+            "number" => value
+                .parse::<f64>()
+                .ok()
+                .filter(|n| n.is_finite())
+                .map(|n| n.to_string())
+                .ok_or_else(|| invalid("number")),
+            "date" => chrono::DateTime::parse_from_rfc3339(value)
+                .map(|d| d.date_naive())
+                .or_else(|_| chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .map_err(|_| invalid("date")),
+            "select" => {
+                let options: Vec<String> = field
+                    .options
+                    .as_deref()
+                    .and_then(|o| serde_json::from_str(o).ok())
+                    .unwrap_or_default();
+                options
+                    .into_iter()
+                    .find(|o| o.eq_ignore_ascii_case(value))
+                    .ok_or_else(|| invalid("option"))
+            }
+            _ => Ok(value.to_string()),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{daily_notes, tasks, time_sessions};
+
+/// A day of the journal: its note together with the work done that day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyNoteDay {
+    pub date: NaiveDate,
+    pub note: Option<daily_notes::Model>,
+    /// Tasks completed during the day, in completion order
+    pub completed_tasks: Vec<tasks::Model>,
+    /// Time sessions started during the day, earliest first
+    pub sessions: Vec<time_sessions::Model>,
+    /// Tracked time of those sessions, excluding pauses
+    pub tracked_seconds: i64,
+}
+
+/// Daily note repository for SeaORM-based database operations
+pub struct DailyNoteRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl DailyNoteRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Find the note of a day
+    pub async fn find_by_date(&self, date: NaiveDate) -> Result<Option<daily_notes::Model>, DbErr> {
+        daily_notes::Entity::find()
+            .filter(daily_notes::Column::Date.eq(date))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find the notes of the days between `start_date` and `end_date` (inclusive), newest first
+    pub async fn find_between(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<daily_notes::Model>, DbErr> {
+        daily_notes::Entity::find()
+            .filter(daily_notes::Column::Date.between(start_date, end_date))
+            .order_by_desc(daily_notes::Column::Date)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Create or replace the note of a day
+    pub async fn save_note(
+        &self,
+        date: NaiveDate,
+        content: String,
+    ) -> Result<daily_notes::Model, DbErr> {
+        match self.find_by_date(date).await? {
+            Some(note) => {
+                let mut active: daily_notes::ActiveModel = note.into();
+                active.content = Set(content);
+                active.updated_at = Set(Utc::now());
+                active.update(&*self.db).await
+            }
+            None => {
+                let note = daily_notes::ActiveModel {
+                    date: Set(date),
+                    content: Set(content),
+                    ..Default::default()
+                };
+                note.insert(&*self.db).await
+            }
+        }
+    }
+
+    /// Delete the note of a day
+    pub async fn delete_note(&self, date: NaiveDate) -> Result<(), DbErr> {
+        let result = daily_notes::Entity::delete_many()
+            .filter(daily_notes::Column::Date.eq(date))
+            .exec(&*self.db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Daily note not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Get the note of a day together with the tasks completed and sessions tracked that day
+    ///
+    /// Days run from midnight to midnight in `timezone`.
+    pub async fn get_day<Tz: TimeZone>(
+        &self,
+        date: NaiveDate,
+        timezone: &Tz,
+    ) -> Result<DailyNoteDay, DbErr> {
+        let (start, end) = day_bounds(date, timezone)?;
+
+        let completed_tasks = tasks::Entity::find()
+            .filter(tasks::Column::CompletedAt.gte(start))
+            .filter(tasks::Column::CompletedAt.lt(end))
+            .order_by_asc(tasks::Column::CompletedAt)
+            .all(&*self.db)
+            .await?;
+
+        let sessions = time_sessions::Entity::find()
+            .filter(time_sessions::Column::StartTime.gte(start))
+            .filter(time_sessions::Column::StartTime.lt(end))
+            .order_by_asc(time_sessions::Column::StartTime)
+            .all(&*self.db)
+            .await?;
+
+        let now = Utc::now();
+        let tracked_seconds = sessions
+            .iter()
+            .map(|session| {
+                let elapsed = (session.end_time.unwrap_or(now) - session.start_time).num_seconds();
+                (elapsed - session.paused_time.max(0) as i64).max(0)
+            })
+            .sum();
+
+        Ok(DailyNoteDay {
+            date,
+            note: self.find_by_date(date).await?,
+            completed_tasks,
+            sessions,
+            tracked_seconds,
+        })
+    }
+
+    /// Get all daily notes (for backup)
+    pub async fn get_all_notes(&self) -> Result<Vec<daily_notes::Model>, DbErr> {
+        daily_notes::Entity::find().all(&*self.db).await
+    }
+
+    /// Import a daily note (for backup restore)
+    pub async fn import_note(&self, note: daily_notes::Model) -> Result<daily_notes::Model, DbErr> {
+        let active_model: daily_notes::ActiveModel = note.into();
+        active_model.insert(&*self.db).await
+    }
+
+    /// Delete all daily notes
+    pub async fn delete_all(&self) -> Result<u64, DbErr> {
+        let result = daily_notes::Entity::delete_many().exec(&*self.db).await?;
+        Ok(result.rows_affected)
+    }
+}
+
+/// Start and end of a local calendar day, in UTC
+fn day_bounds<Tz: TimeZone>(
+    date: NaiveDate,
+    timezone: &Tz,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), DbErr> {
+    // Where DST skips midnight, the day starts at the first hour that exists
+    let local_midnight = |date: NaiveDate| {
+        (0..3)
+            .filter_map(|hour| date.and_hms_opt(hour, 0, 0))
+            .find_map(|start| timezone.from_local_datetime(&start).earliest())
+            .map(|start| start.with_timezone(&Utc))
+    };
+
+    let start = local_midnight(date);
+    let end = date.succ_opt().and_then(local_midnight);
+    match (start, end) {
+        (Some(start), Some(end)) => Ok((start, end)),
+        _ => Err(DbErr::Custom(format!(
+            "VALIDATION_ERROR: Invalid date {}",
+            date
+        ))),
+    }
+}
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 use crate::database::entities::{focus_sessions, tasks};
+use crate::database::repositories::FocusTemplateRepository;
 
 /// Request structure for creating a new focus session
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +119,26 @@ impl FocusRepository {
         session.insert(&*self.db).await
     }
 
+    /// Start a focus session on a task; only one focus session runs at a time
+    pub async fn start_session(
+        &self,
+        request: CreateFocusSessionRequest,
+    ) -> Result<focus_sessions::Model, DbErr> {
+        if request.planned_duration <= 0 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Planned duration must be positive".to_string(),
+            ));
+        }
+        FocusTemplateRepository::validate_distraction_level(&request.distraction_level)?;
+        if self.find_active_session().await?.is_some() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: A focus session is already running".to_string(),
+            ));
+        }
+
+        self.create_session(request).await
+    }
+
     /// Find a focus session by ID
     pub async fn find_by_id(&self, id: &str) -> Result<Option<focus_sessions::Model>, DbErr> {
         focus_sessions::Entity::find_by_id(id).one(&*self.db).await
@@ -214,6 +235,12 @@ impl FocusRepository {
             .await?
             .ok_or_else(|| DbErr::RecordNotFound("Focus session not found".to_string()))?;
 
+        if session.completed_at.is_some() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Focus session is already completed".to_string(),
+            ));
+        }
+
         let mut session: focus_sessions::ActiveModel = session.into();
 
         session.actual_duration = Set(Some(actual_duration));
@@ -228,6 +255,47 @@ impl FocusRepository {
         session.update(&*self.db).await
     }
 
+    /// Count one more distraction on a running focus session
+    pub async fn record_distraction(&self, id: &str) -> Result<focus_sessions::Model, DbErr> {
+        let session = focus_sessions::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Focus session not found".to_string()))?;
+        if session.completed_at.is_some() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Focus session is already completed".to_string(),
+            ));
+        }
+
+        let distraction_count = session.distraction_count + 1;
+        let mut session: focus_sessions::ActiveModel = session.into();
+        session.distraction_count = Set(distraction_count);
+        session.update(&*self.db).await
+    }
+
+    /// Add a break to the breaks recorded on a focus session
+    pub async fn add_break(
+        &self,
+        id: &str,
+        focus_break: FocusBreak,
+    ) -> Result<focus_sessions::Model, DbErr> {
+        let session = focus_sessions::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Focus session not found".to_string()))?;
+
+        let mut breaks: Vec<FocusBreak> = session
+            .breaks
+            .as_deref()
+            .and_then(|breaks| serde_json::from_str(breaks).ok())
+            .unwrap_or_default();
+        breaks.push(focus_break);
+
+        let mut session: focus_sessions::ActiveModel = session.into();
+        session.breaks = Set(Some(serde_json::to_string(&breaks).unwrap_or_default()));
+        session.update(&*self.db).await
+    }
+
     /// Delete a focus session
     pub async fn delete_session(&self, id: &str) -> Result<(), DbErr> {
         focus_sessions::Entity::delete_by_id(id)
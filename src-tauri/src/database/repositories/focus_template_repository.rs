@@ -0,0 +1,288 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{focus_session_templates, focus_sessions};
+use crate::database::repositories::focus_repository::{CreateFocusSessionRequest, FocusRepository};
+
+/// Distraction levels a focus session can run with
+pub const DISTRACTION_LEVELS: [&str; 4] = ["none", "minimal", "moderate", "full"];
+
+/// Longest focus block or break a template may define, in minutes
+const MAX_DURATION_MINUTES: i32 = 480;
+
+/// Request structure for creating a new focus session template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateFocusTemplateRequest {
+    pub name: String,
+    pub focus_duration: i32,
+    pub short_break_duration: i32,
+    pub long_break_duration: i32,
+    pub sessions_before_long_break: i32,
+    pub distraction_level: String,
+    pub background_audio: Option<String>, // JSON string
+}
+
+/// Request structure for updating an existing focus session template
+///
+/// Built-in presets keep their name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateFocusTemplateRequest {
+    pub name: Option<String>,
+    pub focus_duration: Option<i32>,
+    pub short_break_duration: Option<i32>,
+    pub long_break_duration: Option<i32>,
+    pub sessions_before_long_break: Option<i32>,
+    pub distraction_level: Option<String>,
+    pub background_audio: Option<String>,
+}
+
+/// Focus session template repository for SeaORM-based database operations
+pub struct FocusTemplateRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl FocusTemplateRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new custom template
+    pub async fn create_template(
+        &self,
+        request: CreateFocusTemplateRequest,
+    ) -> Result<focus_session_templates::Model, DbErr> {
+        let name = Self::validate_name(&request.name)?;
+        Self::validate_durations(
+            request.focus_duration,
+            request.short_break_duration,
+            request.long_break_duration,
+            request.sessions_before_long_break,
+        )?;
+        Self::validate_distraction_level(&request.distraction_level)?;
+
+        if self.find_by_name(&name).await?.is_some() {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: A focus template named '{}' already exists",
+                name
+            )));
+        }
+
+        let template = focus_session_templates::ActiveModel {
+            name: Set(name),
+            focus_duration: Set(request.focus_duration),
+            short_break_duration: Set(request.short_break_duration),
+            long_break_duration: Set(request.long_break_duration),
+            sessions_before_long_break: Set(request.sessions_before_long_break),
+            distraction_level: Set(request.distraction_level),
+            background_audio: Set(request.background_audio),
+            is_builtin: Set(false),
+            ..Default::default()
+        };
+
+        template.insert(&*self.db).await
+    }
+
+    /// Find a template by ID
+    pub async fn find_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<focus_session_templates::Model>, DbErr> {
+        focus_session_templates::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find a template by name
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<focus_session_templates::Model>, DbErr> {
+        focus_session_templates::Entity::find()
+            .filter(focus_session_templates::Column::Name.eq(name.trim()))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find all templates, built-in presets first
+    pub async fn find_all(&self) -> Result<Vec<focus_session_templates::Model>, DbErr> {
+        focus_session_templates::Entity::find()
+            .order_by_desc(focus_session_templates::Column::IsBuiltin)
+            .order_by_asc(focus_session_templates::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Update a template
+    pub async fn update_template(
+        &self,
+        id: &str,
+        request: UpdateFocusTemplateRequest,
+    ) -> Result<focus_session_templates::Model, DbErr> {
+        let template = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Focus template not found".to_string()))?;
+
+        let name = match request.name {
+            Some(name) => Some(Self::validate_name(&name)?).filter(|name| *name != template.name),
+            None => None,
+        };
+        if let Some(name) = &name {
+            if template.is_builtin {
+                return Err(DbErr::Custom(
+                    "BUSINESS_RULE_ERROR: Built-in focus templates cannot be renamed".to_string(),
+                ));
+            }
+            if self.find_by_name(name).await?.is_some() {
+                return Err(DbErr::Custom(format!(
+                    "DUPLICATE_ERROR: A focus template named '{}' already exists",
+                    name
+                )));
+            }
+        }
+
+        Self::validate_durations(
+            request.focus_duration.unwrap_or(template.focus_duration),
+            request
+                .short_break_duration
+                .unwrap_or(template.short_break_duration),
+            request
+                .long_break_duration
+                .unwrap_or(template.long_break_duration),
+            request
+                .sessions_before_long_break
+                .unwrap_or(template.sessions_before_long_break),
+        )?;
+        if let Some(distraction_level) = &request.distraction_level {
+            Self::validate_distraction_level(distraction_level)?;
+        }
+
+        let mut active: focus_session_templates::ActiveModel = template.into();
+        if let Some(name) = name {
+            active.name = Set(name);
+        }
+        if let Some(focus_duration) = request.focus_duration {
+            active.focus_duration = Set(focus_duration);
+        }
+        if let Some(short_break_duration) = request.short_break_duration {
+            active.short_break_duration = Set(short_break_duration);
+        }
+        if let Some(long_break_duration) = request.long_break_duration {
+            active.long_break_duration = Set(long_break_duration);
+        }
+        if let Some(sessions_before_long_break) = request.sessions_before_long_break {
+            active.sessions_before_long_break = Set(sessions_before_long_break);
+        }
+        if let Some(distraction_level) = request.distraction_level {
+            active.distraction_level = Set(distraction_level);
+        }
+        if let Some(background_audio) = request.background_audio {
+            active.background_audio = Set(Some(background_audio));
+        }
+        active.updated_at = Set(chrono::Utc::now());
+
+        active.update(&*self.db).await
+    }
+
+    /// Delete a custom template
+    pub async fn delete_template(&self, id: &str) -> Result<(), DbErr> {
+        let template = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Focus template not found".to_string()))?;
+
+        if template.is_builtin {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Built-in focus templates cannot be deleted".to_string(),
+            ));
+        }
+
+        focus_session_templates::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Start a focus session on a task with the settings of a template
+    ///
+    /// The session is planned for one focus block; breaks are driven by the frontend timer
+    /// following the template's break pattern.
+    pub async fn start_focus_from_template(
+        &self,
+        id: &str,
+        task_id: &str,
+    ) -> Result<focus_sessions::Model, DbErr> {
+        let template = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Focus template not found".to_string()))?;
+
+        FocusRepository::new(self.db.clone())
+            .create_session(CreateFocusSessionRequest {
+                task_id: task_id.to_string(),
+                planned_duration: template.focus_duration,
+                distraction_level: template.distraction_level,
+                background_audio: template.background_audio,
+                notes: None,
+            })
+            .await
+    }
+
+    fn validate_name(name: &str) -> Result<String, DbErr> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Focus template name cannot be empty".to_string(),
+            ));
+        }
+        if name.len() > 50 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Focus template name cannot exceed 50 characters".to_string(),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    pub(crate) fn validate_durations(
+        focus_duration: i32,
+        short_break_duration: i32,
+        long_break_duration: i32,
+        sessions_before_long_break: i32,
+    ) -> Result<(), DbErr> {
+        if !(1..=MAX_DURATION_MINUTES).contains(&focus_duration) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Focus duration must be between 1 and {} minutes",
+                MAX_DURATION_MINUTES
+            )));
+        }
+        if !(0..=MAX_DURATION_MINUTES).contains(&short_break_duration)
+            || !(0..=MAX_DURATION_MINUTES).contains(&long_break_duration)
+        {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Break durations must be between 0 and {} minutes",
+                MAX_DURATION_MINUTES
+            )));
+        }
+        if sessions_before_long_break < 1 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Sessions before a long break must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate_distraction_level(distraction_level: &str) -> Result<(), DbErr> {
+        if !DISTRACTION_LEVELS.contains(&distraction_level) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown distraction level '{}', expected one of: {}",
+                distraction_level,
+                DISTRACTION_LEVELS.join(", ")
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{github_settings, task_github_links, tasks};
+
+const SETTINGS_ID: &str = "default";
+
+pub const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// Request structure for saving the GitHub connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveGitHubSettingsRequest {
+    /// New access token; the stored one is kept when unset
+    pub token: Option<String>,
+    /// GitHub Enterprise API root; api.github.com when unset
+    pub api_url: Option<String>,
+    pub task_list_id: Option<String>,
+    pub auto_refresh: Option<bool>,
+}
+
+/// Request structure for linking a task to an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkGitHubIssueRequest {
+    pub task_id: String,
+    /// "owner/name"
+    pub repository: String,
+    pub issue_number: i64,
+    pub issue_url: Option<String>,
+    pub issue_state: Option<String>,
+}
+
+/// GitHub repository for SeaORM-based database operations
+pub struct GitHubRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl GitHubRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Get the GitHub connection, if one was saved
+    pub async fn get_settings(&self) -> Result<Option<github_settings::Model>, DbErr> {
+        github_settings::Entity::find_by_id(SETTINGS_ID)
+            .one(&*self.db)
+            .await
+    }
+
+    /// Save the GitHub connection; a token is required the first time
+    pub async fn save_settings(
+        &self,
+        request: SaveGitHubSettingsRequest,
+    ) -> Result<github_settings::Model, DbErr> {
+        let token = request
+            .token
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty());
+        let api_url = request
+            .api_url
+            .map(|url| url.trim().trim_end_matches('/').to_string())
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| DEFAULT_API_URL.to_string());
+        let task_list_id = request.task_list_id.filter(|id| !id.is_empty());
+
+        match self.get_settings().await? {
+            Some(existing) => {
+                let auto_refresh = request.auto_refresh.unwrap_or(existing.auto_refresh);
+                let mut settings: github_settings::ActiveModel = existing.into();
+                if let Some(token) = token {
+                    settings.token = Set(token);
+                }
+                settings.api_url = Set(api_url);
+                settings.task_list_id = Set(task_list_id);
+                settings.auto_refresh = Set(auto_refresh);
+                settings.updated_at = Set(chrono::Utc::now());
+                settings.update(&*self.db).await
+            }
+            None => {
+                let token = token.ok_or_else(|| {
+                    DbErr::Custom("VALIDATION_ERROR: A GitHub access token is required".to_string())
+                })?;
+                let settings = github_settings::ActiveModel {
+                    token: Set(token),
+                    api_url: Set(api_url),
+                    task_list_id: Set(task_list_id),
+                    auto_refresh: Set(request.auto_refresh.unwrap_or(true)),
+                    last_refreshed_at: Set(None),
+                    ..Default::default()
+                };
+                settings.insert(&*self.db).await
+            }
+        }
+    }
+
+    /// Forget the GitHub connection; task links are kept
+    pub async fn delete_settings(&self) -> Result<(), DbErr> {
+        github_settings::Entity::delete_by_id(SETTINGS_ID)
+            .exec(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Record when issue states were last refreshed
+    pub async fn mark_refreshed(&self) -> Result<(), DbErr> {
+        if let Some(existing) = self.get_settings().await? {
+            let mut settings: github_settings::ActiveModel = existing.into();
+            settings.last_refreshed_at = Set(Some(chrono::Utc::now()));
+            settings.update(&*self.db).await?;
+        }
+        Ok(())
+    }
+
+    /// Link a task to an issue, replacing the task's previous link
+    pub async fn link_task(
+        &self,
+        request: LinkGitHubIssueRequest,
+    ) -> Result<task_github_links::Model, DbErr> {
+        let repository = request.repository.trim().to_string();
+        if !is_repository_name(&repository) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Repository must be written as owner/name".to_string(),
+            ));
+        }
+        if request.issue_number <= 0 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Issue number must be positive".to_string(),
+            ));
+        }
+        let issue_state = request.issue_state.unwrap_or_else(|| "open".to_string());
+        if !matches!(issue_state.as_str(), "open" | "closed") {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Issue state must be open or closed".to_string(),
+            ));
+        }
+
+        tasks::Entity::find_by_id(&request.task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        if let Some(linked) = self
+            .find_link_by_issue(&repository, request.issue_number)
+            .await?
+            .filter(|link| link.task_id != request.task_id)
+        {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: {}#{} is already linked to another task ({})",
+                repository, request.issue_number, linked.task_id
+            )));
+        }
+
+        let issue_url = request
+            .issue_url
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| {
+                format!(
+                    "https://github.com/{}/issues/{}",
+                    repository, request.issue_number
+                )
+            });
+
+        match self.find_link(&request.task_id).await? {
+            Some(existing) => {
+                let mut link: task_github_links::ActiveModel = existing.into();
+                link.repository = Set(repository);
+                link.issue_number = Set(request.issue_number);
+                link.issue_url = Set(issue_url);
+                link.issue_state = Set(issue_state);
+                link.synced_at = Set(chrono::Utc::now());
+                link.update(&*self.db).await
+            }
+            None => {
+                let link = task_github_links::ActiveModel {
+                    task_id: Set(request.task_id),
+                    repository: Set(repository),
+                    issue_number: Set(request.issue_number),
+                    issue_url: Set(issue_url),
+                    issue_state: Set(issue_state),
+                    ..Default::default()
+                };
+                link.insert(&*self.db).await
+            }
+        }
+    }
+
+    /// Find the issue linked to a task
+    pub async fn find_link(
+        &self,
+        task_id: &str,
+    ) -> Result<Option<task_github_links::Model>, DbErr> {
+        task_github_links::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find the task linked to an issue
+    pub async fn find_link_by_issue(
+        &self,
+        repository: &str,
+        issue_number: i64,
+    ) -> Result<Option<task_github_links::Model>, DbErr> {
+        task_github_links::Entity::find()
+            .filter(task_github_links::Column::Repository.eq(repository))
+            .filter(task_github_links::Column::IssueNumber.eq(issue_number))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find every task link, least recently synced first
+    pub async fn find_links(&self) -> Result<Vec<task_github_links::Model>, DbErr> {
+        task_github_links::Entity::find()
+            .order_by_asc(task_github_links::Column::SyncedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Record the issue state seen on GitHub
+    pub async fn update_link_state(
+        &self,
+        task_id: &str,
+        issue_state: &str,
+    ) -> Result<task_github_links::Model, DbErr> {
+        let link = self
+            .find_link(task_id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("GitHub link not found".to_string()))?;
+
+        let mut link: task_github_links::ActiveModel = link.into();
+        link.issue_state = Set(issue_state.to_string());
+        link.synced_at = Set(chrono::Utc::now());
+        link.update(&*self.db).await
+    }
+
+    /// Remove a task's issue link; the issue itself is left alone
+    pub async fn unlink_task(&self, task_id: &str) -> Result<bool, DbErr> {
+        let result = task_github_links::Entity::delete_by_id(task_id)
+            .exec(&*self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+/// Whether a name looks like "owner/name"
+fn is_repository_name(repository: &str) -> bool {
+    let mut parts = repository.split('/');
+    let valid_part = |part: Option<&str>| {
+        part.is_some_and(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+        })
+    };
+    valid_part(parts.next()) && valid_part(parts.next()) && parts.next().is_none()
+}
@@ -0,0 +1,93 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait, QueryOrder, Set};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::goals;
+
+/// What a goal measures: minutes of tracked time, minutes in completed focus sessions, or
+/// tasks completed
+pub const GOAL_METRICS: [&str; 3] = ["tracked_minutes", "focus_minutes", "completed_tasks"];
+
+/// How often a goal's target has to be reached
+pub const GOAL_PERIODS: [&str; 2] = ["day", "week"];
+
+/// Request structure for creating a new goal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateGoalRequest {
+    pub title: String,
+    pub metric: String,
+    pub target: i32,
+    pub period: String,
+}
+
+/// Goal repository for SeaORM-based database operations
+pub struct GoalRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl GoalRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new goal
+    pub async fn create_goal(&self, request: CreateGoalRequest) -> Result<goals::Model, DbErr> {
+        let title = request.title.trim();
+        if title.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Goal title cannot be empty".to_string(),
+            ));
+        }
+        if !GOAL_METRICS.contains(&request.metric.as_str()) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown goal metric '{}', expected one of: {}",
+                request.metric,
+                GOAL_METRICS.join(", ")
+            )));
+        }
+        if !GOAL_PERIODS.contains(&request.period.as_str()) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown goal period '{}', expected one of: {}",
+                request.period,
+                GOAL_PERIODS.join(", ")
+            )));
+        }
+        if request.target <= 0 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Goal target must be positive".to_string(),
+            ));
+        }
+
+        let goal = goals::ActiveModel {
+            title: Set(title.to_string()),
+            metric: Set(request.metric),
+            target: Set(request.target),
+            period: Set(request.period),
+            ..Default::default()
+        };
+
+        goal.insert(&*self.db).await
+    }
+
+    /// Find a goal by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<goals::Model>, DbErr> {
+        goals::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find all goals, oldest first
+    pub async fn find_all(&self) -> Result<Vec<goals::Model>, DbErr> {
+        goals::Entity::find()
+            .order_by_asc(goals::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Delete a goal
+    pub async fn delete_goal(&self, id: &str) -> Result<(), DbErr> {
+        let result = goals::Entity::delete_by_id(id).exec(&*self.db).await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Goal not found".to_string()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use sea_orm::{
+    ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait, QueryOrder, QuerySelect, Set,
+};
+use std::sync::Arc;
+
+use crate::database::entities::integrity_reports;
+use crate::database::migration::initialization::DatabaseIntegrityReport;
+
+/// Integrity report repository for SeaORM-based database operations
+pub struct IntegrityReportRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl IntegrityReportRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Store the result of an integrity check
+    pub async fn record(
+        &self,
+        report: &DatabaseIntegrityReport,
+        notified: bool,
+    ) -> Result<integrity_reports::Model, DbErr> {
+        let record = integrity_reports::ActiveModel {
+            is_healthy: Set(report.is_healthy),
+            has_default_task_list: Set(report.has_default_task_list),
+            default_task_list_id: Set(
+                Some(report.default_task_list_id.clone()).filter(|id| !id.is_empty())
+            ),
+            orphaned_tasks_count: Set(report.orphaned_tasks_count as i64),
+            total_tasks: Set(report.total_tasks as i64),
+            total_task_lists: Set(report.total_task_lists as i64),
+            notified: Set(notified),
+            ..Default::default()
+        };
+
+        record.insert(&*self.db).await
+    }
+
+    /// Get the most recent report, if a check ever ran
+    pub async fn find_latest(&self) -> Result<Option<integrity_reports::Model>, DbErr> {
+        integrity_reports::Entity::find()
+            .order_by_desc(integrity_reports::Column::CheckedAt)
+            .one(&*self.db)
+            .await
+    }
+
+    /// Get past reports, newest first
+    pub async fn find_history(&self, limit: u64) -> Result<Vec<integrity_reports::Model>, DbErr> {
+        integrity_reports::Entity::find()
+            .order_by_desc(integrity_reports::Column::CheckedAt)
+            .limit(limit)
+            .all(&*self.db)
+            .await
+    }
+}
@@ -1,18 +1,69 @@
+pub mod ai_persona_repository;
 pub mod ai_repository;
+pub mod ai_suggestion_repository;
+pub mod api_token_repository;
+pub mod attachment_repository;
+pub mod checklist_repository;
+pub mod cron_schedule;
+pub mod custom_field_repository;
+pub mod daily_note_repository;
 pub mod focus_repository;
+pub mod focus_template_repository;
+pub mod github_repository;
+pub mod goal_repository;
+pub mod integrity_report_repository;
+pub mod notification_repository;
 pub mod pattern_repository;
 pub mod periodic_task_repository;
+pub mod preferences_repository;
+pub mod project_repository;
+pub mod rank;
+pub mod reminder_repository;
+pub mod retry;
+pub mod saved_filter_repository;
+pub mod sync_repository;
+pub mod tag_repository;
+pub mod task_audit_repository;
 pub mod task_list_repository;
+pub mod task_note_repository;
 pub mod task_repository;
+pub mod task_source_repository;
+pub mod task_status_repository;
+pub mod task_template_repository;
 pub mod thread_repository;
 pub mod time_tracking_repository;
+pub mod webhook_repository;
 
 #[cfg(test)]
 pub mod tests;
 
+pub use ai_persona_repository::AiPersonaRepository;
 pub use ai_repository::AiRepository;
+pub use ai_suggestion_repository::AiSuggestionRepository;
+pub use api_token_repository::ApiTokenRepository;
+pub use attachment_repository::AttachmentRepository;
+pub use checklist_repository::ChecklistRepository;
+pub use custom_field_repository::CustomFieldRepository;
+pub use daily_note_repository::DailyNoteRepository;
+pub use focus_template_repository::FocusTemplateRepository;
+pub use github_repository::GitHubRepository;
+pub use goal_repository::GoalRepository;
+pub use integrity_report_repository::IntegrityReportRepository;
+pub use notification_repository::NotificationRepository;
 pub use periodic_task_repository::PeriodicTaskRepository;
+pub use preferences_repository::PreferencesRepository;
+pub use project_repository::ProjectRepository;
+pub use reminder_repository::ReminderRepository;
+pub use saved_filter_repository::SavedFilterRepository;
+pub use sync_repository::SyncRepository;
+pub use tag_repository::TagRepository;
+pub use task_audit_repository::TaskAuditRepository;
 pub use task_list_repository::TaskListRepository;
+pub use task_note_repository::TaskNoteRepository;
 pub use task_repository::TaskRepository;
+pub use task_source_repository::TaskSourceRepository;
+pub use task_status_repository::TaskStatusRepository;
+pub use task_template_repository::TaskTemplateRepository;
 pub use thread_repository::ThreadRepository;
 pub use time_tracking_repository::TimeTrackingRepository;
+pub use webhook_repository::WebhookRepository;
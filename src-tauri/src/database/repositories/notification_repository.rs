@@ -0,0 +1,103 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::notification_deliveries;
+
+/// Request structure for reserving a notification before it is shown
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReserveNotificationRequest {
+    pub dedup_key: String,
+    pub kind: String,
+    pub title: String,
+    pub body: String,
+}
+
+/// Notification repository for SeaORM-based database operations
+pub struct NotificationRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl NotificationRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Record a notification before it is shown, or `None` if its key was already delivered
+    ///
+    /// The record's status is "rate_limited" once `max_per_hour` notifications were shown in
+    /// the hour before `now`; such notifications are dropped, not queued.
+    pub async fn reserve(
+        &self,
+        request: ReserveNotificationRequest,
+        now: DateTime<Utc>,
+        max_per_hour: u64,
+    ) -> Result<Option<notification_deliveries::Model>, DbErr> {
+        let txn = self.db.begin().await?;
+        let existing = notification_deliveries::Entity::find()
+            .filter(notification_deliveries::Column::DedupKey.eq(&request.dedup_key))
+            .one(&txn)
+            .await?;
+        if existing.is_some() {
+            return Ok(None);
+        }
+
+        let shown_last_hour = notification_deliveries::Entity::find()
+            .filter(notification_deliveries::Column::Status.eq("shown"))
+            .filter(notification_deliveries::Column::CreatedAt.gt(now - Duration::hours(1)))
+            .count(&txn)
+            .await?;
+        let status = if shown_last_hour < max_per_hour {
+            "shown"
+        } else {
+            "rate_limited"
+        };
+
+        let delivery = notification_deliveries::ActiveModel {
+            dedup_key: Set(request.dedup_key),
+            kind: Set(request.kind),
+            title: Set(request.title),
+            body: Set(request.body),
+            status: Set(status.to_string()),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        txn.commit().await?;
+        Ok(Some(delivery))
+    }
+
+    /// Forget a reservation whose notification could not be shown, so it is tried again
+    pub async fn release(&self, id: &str) -> Result<(), DbErr> {
+        notification_deliveries::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Recent notifications, newest first
+    pub async fn find_recent(
+        &self,
+        limit: u64,
+    ) -> Result<Vec<notification_deliveries::Model>, DbErr> {
+        notification_deliveries::Entity::find()
+            .order_by_desc(notification_deliveries::Column::CreatedAt)
+            .limit(limit)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Delete delivery records created before `cutoff`, returning how many were removed
+    pub async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        let result = notification_deliveries::Entity::delete_many()
+            .filter(notification_deliveries::Column::CreatedAt.lt(cutoff))
+            .exec(&*self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
@@ -1,6 +1,6 @@
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
-    Set,
+    Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -223,6 +223,39 @@ impl PatternRepository {
         }
     }
 
+    /// Replace a user's patterns of the given types with `patterns`, as one change
+    pub async fn replace_patterns(
+        &self,
+        user_id: &str,
+        pattern_types: &[&str],
+        patterns: Vec<CreatePatternRequest>,
+    ) -> Result<Vec<productivity_patterns::Model>, DbErr> {
+        let txn = self.db.begin().await?;
+
+        productivity_patterns::Entity::delete_many()
+            .filter(productivity_patterns::Column::UserId.eq(user_id))
+            .filter(productivity_patterns::Column::PatternType.is_in(pattern_types.iter().copied()))
+            .exec(&txn)
+            .await?;
+
+        let mut recorded = Vec::with_capacity(patterns.len());
+        for request in patterns {
+            let pattern = productivity_patterns::ActiveModel {
+                user_id: Set(request.user_id),
+                pattern_type: Set(request.pattern_type),
+                time_slot: Set(request.time_slot),
+                productivity_score: Set(request.productivity_score),
+                confidence_level: Set(request.confidence_level),
+                sample_size: Set(request.sample_size),
+                ..Default::default()
+            };
+            recorded.push(pattern.insert(&txn).await?);
+        }
+
+        txn.commit().await?;
+        Ok(recorded)
+    }
+
     /// Delete a pattern
     pub async fn delete_pattern(&self, id: &str) -> Result<(), DbErr> {
         productivity_patterns::Entity::delete_by_id(id)
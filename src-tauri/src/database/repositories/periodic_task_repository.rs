@@ -1,11 +1,22 @@
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 use crate::database::entities::{periodic_task_templates, tasks};
+use crate::database::repositories::cron_schedule::CronSchedule;
+
+/// Most occurrences returned by a recurrence preview
+const MAX_PREVIEW_OCCURRENCES: u32 = 100;
+
+/// Most upcoming occurrences counted when reporting what is left of a series
+const MAX_COUNTED_OCCURRENCES: u64 = 10_000;
+
+/// Ways to handle occurrences missed while a template was paused: generate them once the
+/// pause ends, or drop them
+pub const MISSED_OCCURRENCE_POLICIES: [&str; 2] = ["catch_up", "skip"];
 
 /// Request structure for creating a new periodic task template
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,7 +30,19 @@ pub struct CreatePeriodicTaskTemplateRequest {
     pub recurrence_type: String,
     pub recurrence_interval: i32,
     pub recurrence_unit: Option<String>,
+    /// Cron expression, required for the "cron" recurrence type
+    #[serde(default)]
+    pub recurrence_rule: Option<String>,
     pub start_date: chrono::DateTime<chrono::Utc>,
+    /// No occurrences are generated after this date
+    #[serde(default)]
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Stop after this many generated instances
+    #[serde(default)]
+    pub max_occurrences: Option<i32>,
+    /// "catch_up" (default) or "skip"
+    #[serde(default)]
+    pub missed_occurrence_policy: Option<String>,
 }
 
 /// Request structure for updating an existing periodic task template
@@ -34,7 +57,30 @@ pub struct UpdatePeriodicTaskTemplateRequest {
     pub recurrence_type: Option<String>,
     pub recurrence_interval: Option<i32>,
     pub recurrence_unit: Option<String>,
+    #[serde(default)]
+    pub recurrence_rule: Option<String>,
     pub is_active: Option<bool>,
+    #[serde(default)]
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    pub clear_ends_at: Option<bool>, // Explicitly remove the end date
+    #[serde(default)]
+    pub max_occurrences: Option<i32>,
+    #[serde(default)]
+    pub clear_max_occurrences: Option<bool>, // Explicitly remove the occurrence limit
+    #[serde(default)]
+    pub missed_occurrence_policy: Option<String>,
+}
+
+/// Request structure for previewing the occurrences of a recurrence pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurrencePreviewRequest {
+    pub start_date: chrono::DateTime<chrono::Utc>,
+    pub recurrence_type: String,
+    pub recurrence_interval: i32,
+    pub recurrence_unit: Option<String>,
+    pub recurrence_rule: Option<String>,
+    pub count: u32,
 }
 
 /// Periodic task repository for SeaORM-based database operations
@@ -54,7 +100,15 @@ impl PeriodicTaskRepository {
     ) -> Result<periodic_task_templates::Model, DbErr> {
         // For the first instance, the next generation date should be the start date
         // This ensures that if someone creates a daily task today, it generates an instance today
-        let next_generation_date = request.start_date;
+        let next_generation_date = Self::first_generation_date(
+            request.start_date,
+            &request.recurrence_type,
+            request.recurrence_rule.as_deref(),
+        )?;
+        Self::validate_limits(request.start_date, request.ends_at, request.max_occurrences)?;
+        if let Some(policy) = &request.missed_occurrence_policy {
+            Self::validate_missed_occurrence_policy(policy)?;
+        }
 
         let template = periodic_task_templates::ActiveModel {
             title: Set(request.title),
@@ -68,9 +122,13 @@ impl PeriodicTaskRepository {
             recurrence_type: Set(request.recurrence_type),
             recurrence_interval: Set(request.recurrence_interval),
             recurrence_unit: Set(request.recurrence_unit),
+            recurrence_rule: Set(request.recurrence_rule),
             start_date: Set(request.start_date),
             next_generation_date: Set(next_generation_date),
             is_active: Set(true),
+            ends_at: Set(request.ends_at),
+            max_occurrences: Set(request.max_occurrences),
+            missed_occurrence_policy: Set(request.missed_occurrence_policy),
             ..Default::default()
         };
 
@@ -131,6 +189,11 @@ impl PeriodicTaskRepository {
         let templates_needing_generation = periodic_task_templates::Entity::find()
             .filter(periodic_task_templates::Column::IsActive.eq(true))
             .filter(periodic_task_templates::Column::NextGenerationDate.lte(current_time))
+            .filter(
+                Condition::any()
+                    .add(periodic_task_templates::Column::PausedUntil.is_null())
+                    .add(periodic_task_templates::Column::PausedUntil.lte(current_time)),
+            )
             .order_by_asc(periodic_task_templates::Column::NextGenerationDate)
             .all(&*self.db)
             .await?;
@@ -182,9 +245,41 @@ impl PeriodicTaskRepository {
         if let Some(recurrence_unit) = request.recurrence_unit {
             template.recurrence_unit = Set(Some(recurrence_unit));
         }
+        if let Some(recurrence_rule) = request.recurrence_rule {
+            template.recurrence_rule = Set(Some(recurrence_rule));
+        }
+        if let (Some(recurrence_type), Some(recurrence_rule)) = (
+            template.recurrence_type.try_as_ref(),
+            template.recurrence_rule.try_as_ref(),
+        ) {
+            if recurrence_type == "cron" {
+                Self::cron_schedule(recurrence_rule.as_deref())?;
+            }
+        }
         if let Some(is_active) = request.is_active {
             template.is_active = Set(is_active);
         }
+        if request.clear_ends_at == Some(true) {
+            template.ends_at = Set(None);
+        } else if let Some(ends_at) = request.ends_at {
+            template.ends_at = Set(Some(ends_at));
+        }
+        if request.clear_max_occurrences == Some(true) {
+            template.max_occurrences = Set(None);
+        } else if let Some(max_occurrences) = request.max_occurrences {
+            template.max_occurrences = Set(Some(max_occurrences));
+        }
+        if let (Some(start_date), Some(ends_at), Some(max_occurrences)) = (
+            template.start_date.try_as_ref(),
+            template.ends_at.try_as_ref(),
+            template.max_occurrences.try_as_ref(),
+        ) {
+            Self::validate_limits(*start_date, *ends_at, *max_occurrences)?;
+        }
+        if let Some(policy) = request.missed_occurrence_policy {
+            Self::validate_missed_occurrence_policy(&policy)?;
+            template.missed_occurrence_policy = Set(Some(policy));
+        }
 
         template.updated_at = Set(chrono::Utc::now());
 
@@ -209,6 +304,100 @@ impl PeriodicTaskRepository {
         template.update(&*self.db).await
     }
 
+    /// Deactivate a template whose series has ended
+    pub async fn deactivate_template(
+        &self,
+        id: &str,
+    ) -> Result<periodic_task_templates::Model, DbErr> {
+        let template = periodic_task_templates::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Periodic task template not found".to_string()))?;
+
+        let mut template: periodic_task_templates::ActiveModel = template.into();
+        template.is_active = Set(false);
+        template.updated_at = Set(chrono::Utc::now());
+
+        template.update(&*self.db).await
+    }
+
+    /// Pause a template until the given date
+    ///
+    /// Occurrences falling in the pause are handled by the template's missed occurrence
+    /// policy once the pause ends.
+    pub async fn pause_template(
+        &self,
+        id: &str,
+        until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<periodic_task_templates::Model, DbErr> {
+        if until <= chrono::Utc::now() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A pause must end in the future".to_string(),
+            ));
+        }
+
+        let template = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Periodic task template not found".to_string()))?;
+
+        let mut template: periodic_task_templates::ActiveModel = template.into();
+        template.paused_until = Set(Some(until));
+        template.updated_at = Set(chrono::Utc::now());
+
+        template.update(&*self.db).await
+    }
+
+    /// End a pause early
+    ///
+    /// The pause is moved to end now rather than removed, so the next generation run still
+    /// applies the missed occurrence policy.
+    pub async fn resume_template(&self, id: &str) -> Result<periodic_task_templates::Model, DbErr> {
+        let template = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Periodic task template not found".to_string()))?;
+
+        let now = chrono::Utc::now();
+        if template.paused_until.is_none_or(|until| until <= now) {
+            return Ok(template);
+        }
+
+        let mut template: periodic_task_templates::ActiveModel = template.into();
+        template.paused_until = Set(Some(now));
+        template.updated_at = Set(now);
+
+        template.update(&*self.db).await
+    }
+
+    /// Clear a pause that has ended, once its missed occurrences have been handled
+    pub async fn clear_pause(&self, id: &str) -> Result<periodic_task_templates::Model, DbErr> {
+        let template = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Periodic task template not found".to_string()))?;
+
+        let mut template: periodic_task_templates::ActiveModel = template.into();
+        template.paused_until = Set(None);
+        template.updated_at = Set(chrono::Utc::now());
+
+        template.update(&*self.db).await
+    }
+
+    /// Skip the next occurrence of a template without generating it
+    pub async fn skip_next_occurrence(
+        &self,
+        id: &str,
+    ) -> Result<periodic_task_templates::Model, DbErr> {
+        let template = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Periodic task template not found".to_string()))?;
+
+        let next_date = self.next_occurrence_after(&template, template.next_generation_date)?;
+        self.update_next_generation_date(id, next_date).await
+    }
+
     /// Delete a periodic task template
     pub async fn delete_template(&self, id: &str) -> Result<(), DbErr> {
         let txn = self.db.begin().await?;
@@ -270,16 +459,31 @@ impl PeriodicTaskRepository {
     }
 
     /// Calculate the next generation date based on recurrence pattern
+    ///
+    /// Cron rules are evaluated in the local time zone, so "every day at 9:00" follows the
+    /// user's clock.
     pub fn calculate_next_generation_date(
         &self,
         current_date: chrono::DateTime<chrono::Utc>,
         recurrence_type: &str,
         interval: i32,
         unit: Option<&str>,
+        rule: Option<&str>,
     ) -> Result<chrono::DateTime<chrono::Utc>, DbErr> {
         let mut next_date = current_date;
 
         match recurrence_type {
+            "cron" => {
+                next_date = Self::cron_schedule(rule)?
+                    .next_after(&current_date.with_timezone(&chrono::Local))
+                    .ok_or_else(|| {
+                        DbErr::Custom(
+                            "VALIDATION_ERROR: Recurrence rule has no upcoming occurrence"
+                                .to_string(),
+                        )
+                    })?
+                    .with_timezone(&chrono::Utc);
+            }
             "daily" => {
                 next_date = next_date + chrono::Duration::days(interval as i64);
             }
@@ -332,6 +536,161 @@ impl PeriodicTaskRepository {
         Ok(next_date)
     }
 
+    /// Preview the first `count` occurrences of a recurrence pattern, starting at its start date
+    pub fn preview_recurrence_dates(
+        &self,
+        request: RecurrencePreviewRequest,
+    ) -> Result<Vec<chrono::DateTime<chrono::Utc>>, DbErr> {
+        let count = request.count.min(MAX_PREVIEW_OCCURRENCES);
+        let mut dates = Vec::new();
+        if count == 0 {
+            return Ok(dates);
+        }
+
+        let mut current_date = Self::first_generation_date(
+            request.start_date,
+            &request.recurrence_type,
+            request.recurrence_rule.as_deref(),
+        )?;
+        dates.push(current_date);
+
+        while dates.len() < count as usize {
+            current_date = self.calculate_next_generation_date(
+                current_date,
+                &request.recurrence_type,
+                request.recurrence_interval,
+                request.recurrence_unit.as_deref(),
+                request.recurrence_rule.as_deref(),
+            )?;
+            dates.push(current_date);
+        }
+
+        Ok(dates)
+    }
+
+    /// First occurrence of a pattern: the start date itself, or the first cron match from it
+    fn first_generation_date(
+        start_date: chrono::DateTime<chrono::Utc>,
+        recurrence_type: &str,
+        rule: Option<&str>,
+    ) -> Result<chrono::DateTime<chrono::Utc>, DbErr> {
+        if recurrence_type != "cron" {
+            return Ok(start_date);
+        }
+
+        let just_before = start_date - chrono::Duration::seconds(1);
+        Self::cron_schedule(rule)?
+            .next_after(&just_before.with_timezone(&chrono::Local))
+            .map(|date| date.with_timezone(&chrono::Utc))
+            .ok_or_else(|| {
+                DbErr::Custom(
+                    "VALIDATION_ERROR: Recurrence rule has no upcoming occurrence".to_string(),
+                )
+            })
+    }
+
+    /// The occurrence of a template following `date`
+    pub fn next_occurrence_after(
+        &self,
+        template: &periodic_task_templates::Model,
+        date: chrono::DateTime<chrono::Utc>,
+    ) -> Result<chrono::DateTime<chrono::Utc>, DbErr> {
+        self.calculate_next_generation_date(
+            date,
+            &template.recurrence_type,
+            template.recurrence_interval,
+            template.recurrence_unit.as_deref(),
+            template.recurrence_rule.as_deref(),
+        )
+    }
+
+    /// Whether the template's end date and occurrence limit allow an occurrence on `date`,
+    /// given how many instances it has generated so far
+    pub fn allows_occurrence(
+        template: &periodic_task_templates::Model,
+        date: chrono::DateTime<chrono::Utc>,
+        generated: u64,
+    ) -> bool {
+        template.ends_at.is_none_or(|ends_at| date <= ends_at)
+            && template
+                .max_occurrences
+                .is_none_or(|max| generated < max.max(0) as u64)
+    }
+
+    /// Number of occurrences left before the template's end date or occurrence limit
+    ///
+    /// Returns `None` for open-ended templates. Counting stops at `MAX_COUNTED_OCCURRENCES`.
+    pub fn remaining_occurrences(
+        &self,
+        template: &periodic_task_templates::Model,
+        generated: u64,
+    ) -> Result<Option<u64>, DbErr> {
+        if template.ends_at.is_none() && template.max_occurrences.is_none() {
+            return Ok(None);
+        }
+
+        let mut remaining = 0;
+        let mut date = template.next_generation_date;
+        while remaining < MAX_COUNTED_OCCURRENCES
+            && Self::allows_occurrence(template, date, generated + remaining)
+        {
+            remaining += 1;
+            date = self.calculate_next_generation_date(
+                date,
+                &template.recurrence_type,
+                template.recurrence_interval,
+                template.recurrence_unit.as_deref(),
+                template.recurrence_rule.as_deref(),
+            )?;
+        }
+
+        Ok(Some(remaining))
+    }
+
+    fn validate_missed_occurrence_policy(policy: &str) -> Result<(), DbErr> {
+        if !MISSED_OCCURRENCE_POLICIES.contains(&policy) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown missed occurrence policy '{}', expected one of: {}",
+                policy,
+                MISSED_OCCURRENCE_POLICIES.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_limits(
+        start_date: chrono::DateTime<chrono::Utc>,
+        ends_at: Option<chrono::DateTime<chrono::Utc>>,
+        max_occurrences: Option<i32>,
+    ) -> Result<(), DbErr> {
+        if ends_at.is_some_and(|ends_at| ends_at < start_date) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: End date cannot be before the start date".to_string(),
+            ));
+        }
+        if max_occurrences.is_some_and(|max| max < 1) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Occurrence limit must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse the cron expression of a "cron" recurrence
+    fn cron_schedule(rule: Option<&str>) -> Result<CronSchedule, DbErr> {
+        let rule = rule.filter(|rule| !rule.trim().is_empty()).ok_or_else(|| {
+            DbErr::Custom(
+                "VALIDATION_ERROR: Cron recurrence requires a recurrence rule".to_string(),
+            )
+        })?;
+        CronSchedule::parse(rule).map_err(|e| {
+            DbErr::Custom(format!(
+                "VALIDATION_ERROR: Invalid recurrence rule '{}': {}",
+                rule, e
+            ))
+        })
+    }
+
     /// Check if a template should generate an instance
     #[allow(dead_code)]
     pub fn should_generate_instance(
@@ -339,7 +698,11 @@ impl PeriodicTaskRepository {
         template: &periodic_task_templates::Model,
         current_time: chrono::DateTime<chrono::Utc>,
     ) -> bool {
-        template.is_active && template.next_generation_date <= current_time
+        template.is_active
+            && template.next_generation_date <= current_time
+            && template
+                .paused_until
+                .is_none_or(|until| until <= current_time)
     }
 
     /// Get all periodic task templates for backup
@@ -365,9 +728,14 @@ impl PeriodicTaskRepository {
             recurrence_type: Set(template.recurrence_type),
             recurrence_interval: Set(template.recurrence_interval),
             recurrence_unit: Set(template.recurrence_unit),
+            recurrence_rule: Set(template.recurrence_rule),
             start_date: Set(template.start_date),
             next_generation_date: Set(template.next_generation_date),
             is_active: Set(template.is_active),
+            ends_at: Set(template.ends_at),
+            max_occurrences: Set(template.max_occurrences),
+            paused_until: Set(template.paused_until),
+            missed_occurrence_policy: Set(template.missed_occurrence_policy),
             created_at: Set(template.created_at),
             updated_at: Set(template.updated_at),
         };
@@ -399,6 +767,19 @@ pub struct PeriodicTaskStats {
     pub active_templates: u64,
     pub inactive_templates: u64,
     pub total_instances: u64,
+    /// Templates with an end date or occurrence limit, and what is left of their series
+    pub limited_templates: Vec<PeriodicTemplateOccurrences>,
+}
+
+/// Occurrence progress of a template with an end date or occurrence limit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodicTemplateOccurrences {
+    pub template_id: String,
+    pub title: String,
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub max_occurrences: Option<i32>,
+    pub generated_occurrences: u64,
+    pub remaining_occurrences: u64,
 }
 
 impl PeriodicTaskRepository {
@@ -418,11 +799,37 @@ impl PeriodicTaskRepository {
             .count(&*self.db)
             .await?;
 
+        let mut limited_templates = Vec::new();
+        for template in periodic_task_templates::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(periodic_task_templates::Column::EndsAt.is_not_null())
+                    .add(periodic_task_templates::Column::MaxOccurrences.is_not_null()),
+            )
+            .order_by_asc(periodic_task_templates::Column::Title)
+            .all(&*self.db)
+            .await?
+        {
+            let generated_occurrences = self.count_template_instances(&template.id).await?;
+            let remaining_occurrences = self
+                .remaining_occurrences(&template, generated_occurrences)?
+                .unwrap_or_default();
+            limited_templates.push(PeriodicTemplateOccurrences {
+                template_id: template.id,
+                title: template.title,
+                ends_at: template.ends_at,
+                max_occurrences: template.max_occurrences,
+                generated_occurrences,
+                remaining_occurrences,
+            });
+        }
+
         Ok(PeriodicTaskStats {
             total_templates,
             active_templates,
             inactive_templates,
             total_instances,
+            limited_templates,
         })
     }
 }
@@ -0,0 +1,426 @@
+use chrono::{DateTime, Datelike, NaiveDate, Offset, TimeZone, Timelike, Utc};
+use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait, Set};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::user_preferences;
+
+/// ID of the single preferences row
+const PREFERENCES_ID: &str = "default";
+
+/// Furthest ahead the next working day is looked for
+const MAX_LOOKAHEAD_DAYS: i64 = 366;
+
+/// Days ahead holidays are listed in the workday context
+const UPCOMING_HOLIDAY_DAYS: i64 = 30;
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// Longest prompt template accepted, in characters
+const MAX_PROMPT_TEMPLATE_CHARS: usize = 20_000;
+
+/// When the user works: the daily hours, the days of the week and days off
+///
+/// Times are `HH:MM` in local time, `24:00` ending at midnight; days are numbered from 0 for
+/// Sunday as in JavaScript. Hours spanning midnight are not supported.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkingHours {
+    pub start: String,
+    pub end: String,
+    #[serde(default = "default_work_days")]
+    pub days: Vec<u32>,
+    #[serde(default)]
+    pub holidays: Vec<NaiveDate>,
+}
+
+fn default_work_days() -> Vec<u32> {
+    vec![1, 2, 3, 4, 5]
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self {
+            start: "09:00".to_string(),
+            end: "17:00".to_string(),
+            days: default_work_days(),
+            holidays: Vec::new(),
+        }
+    }
+}
+
+/// Where the user stands in their working week, for the assistant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkdayContext {
+    pub now: DateTime<Utc>,
+    /// Offset of the time zone the working hours run in, e.g. "+02:00"
+    pub utc_offset: String,
+    pub today: NaiveDate,
+    pub working_hours: WorkingHours,
+    pub is_workday: bool,
+    pub is_holiday: bool,
+    pub is_working_time: bool,
+    /// Working minutes left today
+    pub minutes_left_today: i64,
+    /// Start of the next working period; unset during working time
+    pub next_working_time: Option<DateTime<Utc>>,
+    /// Holidays in the next 30 days
+    pub upcoming_holidays: Vec<NaiveDate>,
+}
+
+/// Where unfinished tasks scheduled for a past day go
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RolloverMode {
+    /// Leave them on their day
+    #[default]
+    Off,
+    /// Move them to today
+    Today,
+    /// Unschedule them
+    Backlog,
+}
+
+impl RolloverMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RolloverMode::Off => "off",
+            RolloverMode::Today => "today",
+            RolloverMode::Backlog => "backlog",
+        }
+    }
+
+    fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "off" => Some(RolloverMode::Off),
+            "today" => Some(RolloverMode::Today),
+            "backlog" => Some(RolloverMode::Backlog),
+            _ => None,
+        }
+    }
+}
+
+/// The user's rewrites of the prompts Kira answers with; unset parts use the built-in ones
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PromptTemplates {
+    /// Who Kira is, its role and guidelines
+    pub system_persona: Option<String>,
+    /// How Kira reasons about a request before answering
+    pub thought: Option<String>,
+    /// How models without native tool calling call a tool
+    pub action: Option<String>,
+    /// How models without native tool calling give their final answer
+    pub final_answer: Option<String>,
+}
+
+impl PromptTemplates {
+    /// Trim each template, unsetting blank ones
+    fn normalize(self) -> Result<Self, DbErr> {
+        let normalize = |template: Option<String>| -> Result<Option<String>, DbErr> {
+            let Some(template) = template else {
+                return Ok(None);
+            };
+            let template = template.trim();
+            if template.chars().count() > MAX_PROMPT_TEMPLATE_CHARS {
+                return Err(DbErr::Custom(format!(
+                    "VALIDATION_ERROR: Prompt templates cannot be longer than {} characters",
+                    MAX_PROMPT_TEMPLATE_CHARS
+                )));
+            }
+            Ok((!template.is_empty()).then(|| template.to_string()))
+        };
+
+        Ok(Self {
+            system_persona: normalize(self.system_persona)?,
+            thought: normalize(self.thought)?,
+            action: normalize(self.action)?,
+            final_answer: normalize(self.final_answer)?,
+        })
+    }
+}
+
+impl WorkingHours {
+    /// Every minute of every day, for callers that ignore working hours
+    pub fn around_the_clock() -> Self {
+        Self {
+            start: "00:00".to_string(),
+            end: "24:00".to_string(),
+            days: (0..7).collect(),
+            holidays: Vec::new(),
+        }
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Whether `date` is a working day that is not a holiday
+    pub fn is_workday(&self, date: NaiveDate) -> bool {
+        self.days.contains(&date.weekday().num_days_from_sunday()) && !self.is_holiday(date)
+    }
+
+    /// Length of a working day
+    pub fn daily_minutes(&self) -> i64 {
+        let (start, end) = self.window();
+        (end - start) as i64 / 60
+    }
+
+    /// Whether `at` falls within the working hours of a working day in `timezone`
+    pub fn is_working_time<Tz: TimeZone>(&self, at: DateTime<Utc>, timezone: &Tz) -> bool {
+        let local = at.with_timezone(timezone);
+        let (start, end) = self.window();
+        let second = local.num_seconds_from_midnight();
+        self.is_workday(local.date_naive()) && second >= start && second < end
+    }
+
+    /// `at` itself during working time, otherwise the start of the next working period
+    ///
+    /// Falls back to `at` when no working day comes within a year.
+    pub fn next_working_time<Tz: TimeZone>(
+        &self,
+        at: DateTime<Utc>,
+        timezone: &Tz,
+    ) -> DateTime<Utc> {
+        if self.is_working_time(at, timezone) {
+            return at;
+        }
+        let today = at.with_timezone(timezone).date_naive();
+        (0..=MAX_LOOKAHEAD_DAYS)
+            .map(|offset| today + chrono::Duration::days(offset))
+            .filter(|&date| self.is_workday(date))
+            .filter_map(|date| self.local_at(date, self.window().0, timezone))
+            .find(|&start| start > at)
+            .unwrap_or(at)
+    }
+
+    /// `at` moved by whole days to the first working day on or after it, at the same local time
+    ///
+    /// Falls back to `at` when no working day comes within a year.
+    pub fn next_workday_at<Tz: TimeZone>(&self, at: DateTime<Utc>, timezone: &Tz) -> DateTime<Utc> {
+        let local = at.with_timezone(timezone).naive_local();
+        (0..=MAX_LOOKAHEAD_DAYS)
+            .map(|offset| local + chrono::Duration::days(offset))
+            .find(|moved| self.is_workday(moved.date()))
+            .and_then(|moved| timezone.from_local_datetime(&moved).earliest())
+            .map(|moved| moved.with_timezone(&Utc))
+            .unwrap_or(at)
+    }
+
+    /// Seconds between `from` and `to` that fall in working hours in `timezone`
+    pub fn working_seconds_between<Tz: TimeZone>(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        timezone: &Tz,
+    ) -> i64 {
+        if to <= from {
+            return 0;
+        }
+        let (start, end) = self.window();
+        from.with_timezone(timezone)
+            .date_naive()
+            .iter_days()
+            .take_while(|&date| date <= to.with_timezone(timezone).date_naive())
+            .filter(|&date| self.is_workday(date))
+            .filter_map(|date| {
+                let window_start = self.local_at(date, start, timezone)?.max(from);
+                let window_end = self.local_at(date, end, timezone)?.min(to);
+                Some((window_end - window_start).num_seconds().max(0))
+            })
+            .sum()
+    }
+
+    /// The working day as seen at `now` in `timezone`
+    pub fn workday_context<Tz: TimeZone>(
+        &self,
+        now: DateTime<Utc>,
+        timezone: &Tz,
+    ) -> WorkdayContext {
+        let today = now.with_timezone(timezone).date_naive();
+        let end_of_day = self
+            .local_at(today, self.window().1, timezone)
+            .unwrap_or(now);
+        let is_working_time = self.is_working_time(now, timezone);
+        let upcoming_holidays = today
+            .iter_days()
+            .take(UPCOMING_HOLIDAY_DAYS as usize)
+            .filter(|&date| self.is_holiday(date))
+            .collect();
+
+        WorkdayContext {
+            now,
+            utc_offset: now.with_timezone(timezone).offset().fix().to_string(),
+            today,
+            working_hours: self.clone(),
+            is_workday: self.is_workday(today),
+            is_holiday: self.is_holiday(today),
+            is_working_time,
+            minutes_left_today: self.working_seconds_between(now, end_of_day, timezone) / 60,
+            next_working_time: (!is_working_time).then(|| self.next_working_time(now, timezone)),
+            upcoming_holidays,
+        }
+    }
+
+    /// Working hours as seconds from midnight
+    ///
+    /// Stored hours are validated, so the defaults only cover hand-edited rows.
+    fn window(&self) -> (u32, u32) {
+        match (parse_time(&self.start), parse_time(&self.end)) {
+            (Some(start), Some(end)) if start < end => (start, end),
+            _ => (9 * 60 * 60, 17 * 60 * 60),
+        }
+    }
+
+    fn local_at<Tz: TimeZone>(
+        &self,
+        date: NaiveDate,
+        seconds: u32,
+        timezone: &Tz,
+    ) -> Option<DateTime<Utc>> {
+        let local = date.and_hms_opt(0, 0, 0)? + chrono::Duration::seconds(seconds as i64);
+        timezone
+            .from_local_datetime(&local)
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+    }
+
+    fn validate(&self) -> Result<(), DbErr> {
+        let (Some(start), Some(end)) = (parse_time(&self.start), parse_time(&self.end)) else {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Working hours must be HH:MM times".to_string(),
+            ));
+        };
+        if start >= end {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Working hours must end after they start".to_string(),
+            ));
+        }
+        if self.days.is_empty() || self.days.iter().any(|&day| day > 6) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Working days must be at least one of 0 (Sunday) to 6 (Saturday)"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Seconds from midnight of an `HH:MM` time, allowing `24:00`
+fn parse_time(time: &str) -> Option<u32> {
+    let (hours, minutes) = time.split_once(':')?;
+    let (hours, minutes): (u32, u32) = (hours.parse().ok()?, minutes.parse().ok()?);
+    let seconds = (hours * 60 + minutes) * 60;
+    (minutes < 60 && seconds <= SECONDS_PER_DAY).then_some(seconds)
+}
+
+/// Repository for the user preferences the backend works with
+pub struct PreferencesRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl PreferencesRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// The stored working hours, or the default Monday to Friday, 9:00 to 17:00
+    pub async fn get_working_hours(&self) -> Result<WorkingHours, DbErr> {
+        let preferences = user_preferences::Entity::find_by_id(PREFERENCES_ID)
+            .one(&*self.db)
+            .await?;
+        Ok(preferences
+            .and_then(|preferences| serde_json::from_str(&preferences.working_hours).ok())
+            .unwrap_or_default())
+    }
+
+    /// Replace the working hours, keeping days and holidays sorted and unique
+    pub async fn save_working_hours(
+        &self,
+        mut working_hours: WorkingHours,
+    ) -> Result<WorkingHours, DbErr> {
+        working_hours.validate()?;
+        working_hours.days.sort_unstable();
+        working_hours.days.dedup();
+        working_hours.holidays.sort_unstable();
+        working_hours.holidays.dedup();
+
+        let json = serde_json::to_string(&working_hours)
+            .map_err(|e| DbErr::Custom(format!("Failed to serialize working hours: {}", e)))?;
+        self.save(|preferences| preferences.working_hours = Set(json))
+            .await?;
+
+        Ok(working_hours)
+    }
+
+    /// Where unfinished tasks go at the end of the day; off unless set
+    pub async fn get_rollover_mode(&self) -> Result<RolloverMode, DbErr> {
+        let preferences = user_preferences::Entity::find_by_id(PREFERENCES_ID)
+            .one(&*self.db)
+            .await?;
+        Ok(preferences
+            .and_then(|preferences| RolloverMode::parse(&preferences.task_rollover))
+            .unwrap_or_default())
+    }
+
+    pub async fn save_rollover_mode(&self, mode: RolloverMode) -> Result<RolloverMode, DbErr> {
+        self.save(|preferences| preferences.task_rollover = Set(mode.as_str().to_string()))
+            .await?;
+        Ok(mode)
+    }
+
+    /// The user's prompt templates; all unset unless customized
+    pub async fn get_prompt_templates(&self) -> Result<PromptTemplates, DbErr> {
+        let preferences = user_preferences::Entity::find_by_id(PREFERENCES_ID)
+            .one(&*self.db)
+            .await?;
+        Ok(preferences
+            .and_then(|preferences| preferences.prompt_templates)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default())
+    }
+
+    /// Replace the prompt templates; blank ones fall back to the built-in prompts
+    pub async fn save_prompt_templates(
+        &self,
+        templates: PromptTemplates,
+    ) -> Result<PromptTemplates, DbErr> {
+        let templates = templates.normalize()?;
+        let json = serde_json::to_string(&templates)
+            .map_err(|e| DbErr::Custom(format!("Failed to serialize prompt templates: {}", e)))?;
+        self.save(|preferences| preferences.prompt_templates = Set(Some(json)))
+            .await?;
+
+        Ok(templates)
+    }
+
+    /// Go back to the built-in prompts
+    pub async fn reset_prompt_templates(&self) -> Result<PromptTemplates, DbErr> {
+        self.save(|preferences| preferences.prompt_templates = Set(None))
+            .await?;
+        Ok(PromptTemplates::default())
+    }
+
+    /// Apply `change` to the preferences row, creating it with defaults if missing
+    async fn save(
+        &self,
+        change: impl FnOnce(&mut user_preferences::ActiveModel),
+    ) -> Result<(), DbErr> {
+        let existing = user_preferences::Entity::find_by_id(PREFERENCES_ID)
+            .one(&*self.db)
+            .await?;
+        let is_new = existing.is_none();
+        let mut preferences: user_preferences::ActiveModel = match existing {
+            Some(preferences) => preferences.into(),
+            None => Default::default(),
+        };
+        change(&mut preferences);
+
+        if is_new {
+            preferences.insert(&*self.db).await?;
+        } else {
+            preferences.updated_at = Set(Utc::now());
+            preferences.update(&*self.db).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder, Set, Statement, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::database::entities::{projects, task_lists, tasks};
+
+/// Where a project stands
+pub const PROJECT_STATUSES: [&str; 4] = ["active", "on_hold", "completed", "cancelled"];
+
+/// Request structure for creating a new project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateProjectRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// Defaults to "active"
+    pub status: Option<String>,
+    pub target_date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Request structure for updating an existing project
+///
+/// Unset fields are left alone; an empty description clears it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateProjectRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub target_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub clear_target_date: Option<bool>,
+}
+
+/// A project with the rollup of its work; times are in minutes
+///
+/// A project's tasks are the ones in its task lists and the ones linked to it directly.
+/// Archived tasks are left out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectProgress {
+    pub project: projects::Model,
+    pub task_list_count: i64,
+    pub total_tasks: i64,
+    /// Tasks in a done status
+    pub completed_tasks: i64,
+    /// Share of the tasks completed, from 0 to 100; 0 without tasks
+    pub percent_complete: f64,
+    /// Open tasks past their due date
+    pub overdue_tasks: i64,
+    pub estimated_minutes: i64,
+    pub actual_minutes: i64,
+    /// The target date has passed while the project is still active or on hold
+    pub is_past_target: bool,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct ProjectRollupRow {
+    project_id: String,
+    task_list_count: i64,
+    total_tasks: i64,
+    completed_tasks: i64,
+    overdue_tasks: i64,
+    estimated_minutes: i64,
+    actual_minutes: i64,
+}
+
+/// Project repository for SeaORM-based database operations
+///
+/// Projects group task lists, and tasks may also point at a project directly. SQLite can't add
+/// foreign keys to the existing tables, so references to projects are checked here and in the
+/// task and task list repositories.
+pub struct ProjectRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ProjectRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new project
+    pub async fn create_project(
+        &self,
+        request: CreateProjectRequest,
+    ) -> Result<projects::Model, DbErr> {
+        let name = validate_project_name(&request.name)?;
+        let status = request.status.unwrap_or_else(|| "active".to_string());
+        validate_project_status(&status)?;
+
+        let project = projects::ActiveModel {
+            name: Set(name),
+            description: Set(request.description.filter(|d| !d.trim().is_empty())),
+            status: Set(status),
+            target_date: Set(request.target_date),
+            ..Default::default()
+        };
+
+        project.insert(&*self.db).await
+    }
+
+    /// Find a project by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<projects::Model>, DbErr> {
+        projects::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find all projects by name
+    pub async fn find_all(&self) -> Result<Vec<projects::Model>, DbErr> {
+        projects::Entity::find()
+            .order_by_asc(projects::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Check that a project exists, for the tables referencing it
+    pub async fn ensure_exists<C>(db: &C, id: &str) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let count = projects::Entity::find_by_id(id).count(db).await?;
+        if count == 0 {
+            return Err(DbErr::RecordNotFound(format!(
+                "RECORD_NOT_FOUND: Project with ID '{}' not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Update a project's name, description, status or target date
+    pub async fn update_project(
+        &self,
+        id: &str,
+        request: UpdateProjectRequest,
+    ) -> Result<projects::Model, DbErr> {
+        let project = self.find_by_id(id).await?.ok_or_else(|| {
+            DbErr::RecordNotFound(format!(
+                "RECORD_NOT_FOUND: Project with ID '{}' not found",
+                id
+            ))
+        })?;
+
+        let mut project: projects::ActiveModel = project.into();
+        if let Some(name) = &request.name {
+            project.name = Set(validate_project_name(name)?);
+        }
+        if let Some(description) = request.description {
+            project.description = Set(Some(description).filter(|d| !d.trim().is_empty()));
+        }
+        if let Some(status) = request.status {
+            validate_project_status(&status)?;
+            project.status = Set(status);
+        }
+        if request.clear_target_date.unwrap_or(false) {
+            project.target_date = Set(None);
+        } else if let Some(target_date) = request.target_date {
+            project.target_date = Set(Some(target_date));
+        }
+        project.updated_at = Set(chrono::Utc::now());
+
+        project.update(&*self.db).await
+    }
+
+    /// Delete a project, unlinking its task lists and tasks
+    pub async fn delete_project(&self, id: &str) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        let now = chrono::Utc::now();
+        task_lists::Entity::update_many()
+            .col_expr(
+                task_lists::Column::ProjectId,
+                sea_orm::sea_query::Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                task_lists::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(now),
+            )
+            .filter(task_lists::Column::ProjectId.eq(id))
+            .exec(&txn)
+            .await?;
+        tasks::Entity::update_many()
+            .col_expr(
+                tasks::Column::ProjectId,
+                sea_orm::sea_query::Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                tasks::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(now),
+            )
+            .filter(tasks::Column::ProjectId.eq(id))
+            .exec(&txn)
+            .await?;
+
+        let result = projects::Entity::delete_by_id(id).exec(&txn).await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound(format!(
+                "RECORD_NOT_FOUND: Project with ID '{}' not found",
+                id
+            )));
+        }
+        txn.commit().await
+    }
+
+    /// Progress of one project, or of every project when `project_id` is unset
+    pub async fn get_progress(
+        &self,
+        project_id: Option<&str>,
+    ) -> Result<Vec<ProjectProgress>, DbErr> {
+        let projects = match project_id {
+            Some(id) => vec![self.find_by_id(id).await?.ok_or_else(|| {
+                DbErr::RecordNotFound(format!(
+                    "RECORD_NOT_FOUND: Project with ID '{}' not found",
+                    id
+                ))
+            })?],
+            None => self.find_all().await?,
+        };
+
+        let sql = r#"
+            WITH done AS (SELECT name FROM task_statuses WHERE category = 'done')
+            SELECT
+                p.id AS project_id,
+                (SELECT COUNT(*) FROM task_lists l WHERE l.project_id = p.id) AS task_list_count,
+                COUNT(t.id) AS total_tasks,
+                COUNT(CASE WHEN t.status IN (SELECT name FROM done) THEN 1 END) AS completed_tasks,
+                COUNT(CASE WHEN julianday(t.due_date) < julianday($1)
+                    AND t.status NOT IN (SELECT name FROM done) THEN 1 END) AS overdue_tasks,
+                COALESCE(SUM(t.time_estimate), 0) AS estimated_minutes,
+                COALESCE(SUM(t.actual_time), 0) AS actual_minutes
+            FROM projects p
+            LEFT JOIN tasks t ON t.archived_at IS NULL
+                AND (t.project_id = p.id
+                     OR t.task_list_id IN (SELECT id FROM task_lists WHERE project_id = p.id))
+            WHERE $2 IS NULL OR p.id = $2
+            GROUP BY p.id
+        "#;
+        let now = chrono::Utc::now();
+        let mut rollups: HashMap<String, ProjectRollupRow> =
+            ProjectRollupRow::find_by_statement(Statement::from_sql_and_values(
+                self.db.get_database_backend(),
+                sql,
+                [now.into(), project_id.map(str::to_string).into()],
+            ))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|row| (row.project_id.clone(), row))
+            .collect();
+
+        Ok(projects
+            .into_iter()
+            .map(|project| {
+                let rollup = rollups.remove(&project.id);
+                let count = |field: fn(&ProjectRollupRow) -> i64| rollup.as_ref().map_or(0, field);
+                let total_tasks = count(|r| r.total_tasks);
+                let completed_tasks = count(|r| r.completed_tasks);
+                ProjectProgress {
+                    task_list_count: count(|r| r.task_list_count),
+                    total_tasks,
+                    completed_tasks,
+                    percent_complete: if total_tasks > 0 {
+                        completed_tasks as f64 * 100.0 / total_tasks as f64
+                    } else {
+                        0.0
+                    },
+                    overdue_tasks: count(|r| r.overdue_tasks),
+                    estimated_minutes: count(|r| r.estimated_minutes),
+                    actual_minutes: count(|r| r.actual_minutes),
+                    is_past_target: project.target_date.is_some_and(|date| date < now)
+                        && matches!(project.status.as_str(), "active" | "on_hold"),
+                    project,
+                }
+            })
+            .collect())
+    }
+}
+
+fn validate_project_name(name: &str) -> Result<String, DbErr> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DbErr::Custom(
+            "VALIDATION_ERROR: Project name cannot be empty".to_string(),
+        ));
+    }
+    if name.len() > 255 {
+        return Err(DbErr::Custom(
+            "VALIDATION_ERROR: Project name cannot exceed 255 characters".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_project_status(status: &str) -> Result<(), DbErr> {
+    if !PROJECT_STATUSES.contains(&status) {
+        return Err(DbErr::Custom(format!(
+            "VALIDATION_ERROR: Unknown project status '{}', expected one of: {}",
+            status,
+            PROJECT_STATUSES.join(", ")
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,24 @@
+/// Space left between neighbouring ranks, so most moves only update the moved row
+pub const RANK_GAP: i32 = 1024;
+
+/// Rank for an item placed between the items ranked `before` and `after`
+///
+/// Either neighbour may be missing at the start or end of a list; with neither, the item is
+/// the first of its list. Returns `None` when there is no room left between the neighbours
+/// and the list needs [`spaced_rank`] renumbering.
+pub fn rank_between(before: Option<i32>, after: Option<i32>) -> Option<i32> {
+    match (before, after) {
+        (Some(before), Some(after)) => {
+            let gap = i64::from(after) - i64::from(before);
+            (gap > 1).then(|| before + (gap / 2) as i32)
+        }
+        (Some(before), None) => before.checked_add(RANK_GAP),
+        (None, Some(after)) => after.checked_sub(RANK_GAP),
+        (None, None) => Some(RANK_GAP),
+    }
+}
+
+/// Rank of the item at `index` after renumbering a list
+pub fn spaced_rank(index: usize) -> i32 {
+    (index as i32 + 1) * RANK_GAP
+}
@@ -0,0 +1,349 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::database::entities::{task_reminders, task_statuses, tasks};
+use crate::database::repositories::preferences_repository::WorkingHours;
+
+/// Dates a reminder can be anchored to; `once` reminders fire at a fixed time
+pub const REMINDER_ANCHORS: [&str; 3] = ["due_date", "scheduled_date", "once"];
+
+/// Longest lead time of a reminder, four weeks
+const MAX_OFFSET_MINUTES: i32 = 4 * 7 * 24 * 60;
+
+/// Longest snooze, one week
+const MAX_SNOOZE_MINUTES: i32 = 7 * 24 * 60;
+
+/// Reminders missed by more than this (e.g. while the app was closed) are dropped, not fired
+const STALE_AFTER_HOURS: i64 = 24;
+
+/// One reminder of a task, as sent when replacing the reminders of a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderOffsetRequest {
+    pub anchor: String,
+    pub offset_minutes: i32,
+}
+
+/// A reminder together with its task and when it fires
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledReminder {
+    pub reminder: task_reminders::Model,
+    pub task_id: String,
+    pub task_title: String,
+    /// The due date, scheduled date or fixed time the reminder is about
+    pub target_at: DateTime<Utc>,
+    pub fire_at: DateTime<Utc>,
+}
+
+/// Reminder repository for SeaORM-based database operations
+pub struct ReminderRepository {
+    db: Arc<DatabaseConnection>,
+    working_hours: Option<WorkingHours>,
+}
+
+impl ReminderRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db,
+            working_hours: None,
+        }
+    }
+
+    /// Hold reminders falling outside `working_hours`, in local time, until work starts again
+    ///
+    /// Snoozed reminders fire when asked to.
+    pub fn with_working_hours(mut self, working_hours: WorkingHours) -> Self {
+        self.working_hours = Some(working_hours);
+        self
+    }
+
+    /// Replace the due date and scheduled date reminders of a task
+    ///
+    /// Reminders that are kept keep their firing state, so an unchanged reminder doesn't fire
+    /// twice. Pending "notify me again" reminders are left alone.
+    pub async fn set_task_reminders(
+        &self,
+        task_id: &str,
+        reminders: Vec<ReminderOffsetRequest>,
+    ) -> Result<Vec<task_reminders::Model>, DbErr> {
+        tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let mut wanted: Vec<(String, i32)> = Vec::new();
+        for reminder in reminders {
+            Self::validate_reminder(&reminder)?;
+            let key = (reminder.anchor, reminder.offset_minutes);
+            if !wanted.contains(&key) {
+                wanted.push(key);
+            }
+        }
+
+        let existing = task_reminders::Entity::find()
+            .filter(task_reminders::Column::TaskId.eq(task_id))
+            .filter(task_reminders::Column::Anchor.ne("once"))
+            .all(&*self.db)
+            .await?;
+
+        let txn = self.db.begin().await?;
+        let mut kept = HashSet::new();
+        for reminder in existing {
+            let key = (reminder.anchor.clone(), reminder.offset_minutes);
+            if wanted.contains(&key) && kept.insert(key) {
+                continue;
+            }
+            task_reminders::Entity::delete_by_id(reminder.id)
+                .exec(&txn)
+                .await?;
+        }
+        for (anchor, offset_minutes) in wanted {
+            if kept.contains(&(anchor.clone(), offset_minutes)) {
+                continue;
+            }
+            let reminder = task_reminders::ActiveModel {
+                task_id: Set(task_id.to_string()),
+                anchor: Set(anchor),
+                offset_minutes: Set(offset_minutes),
+                ..Default::default()
+            };
+            reminder.insert(&txn).await?;
+        }
+        txn.commit().await?;
+
+        self.find_by_task(task_id).await
+    }
+
+    /// Find all reminders of a task
+    pub async fn find_by_task(&self, task_id: &str) -> Result<Vec<task_reminders::Model>, DbErr> {
+        task_reminders::Entity::find()
+            .filter(task_reminders::Column::TaskId.eq(task_id))
+            .order_by_asc(task_reminders::Column::Anchor)
+            .order_by_desc(task_reminders::Column::OffsetMinutes)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Fire a reminder again after `minutes`, regardless of its anchor date
+    pub async fn snooze_reminder(
+        &self,
+        id: &str,
+        minutes: i32,
+    ) -> Result<task_reminders::Model, DbErr> {
+        Self::validate_snooze(minutes)?;
+        let reminder = task_reminders::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Reminder not found".to_string()))?;
+
+        let mut active: task_reminders::ActiveModel = reminder.into();
+        active.snoozed_until = Set(Some(Utc::now() + Duration::minutes(minutes as i64)));
+        active.updated_at = Set(Utc::now());
+        active.update(&*self.db).await
+    }
+
+    /// Add a one-off reminder for a task that fires after `minutes`
+    pub async fn remind_again_in(
+        &self,
+        task_id: &str,
+        minutes: i32,
+    ) -> Result<task_reminders::Model, DbErr> {
+        Self::validate_snooze(minutes)?;
+        tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let reminder = task_reminders::ActiveModel {
+            task_id: Set(task_id.to_string()),
+            anchor: Set("once".to_string()),
+            offset_minutes: Set(0),
+            remind_at: Set(Some(Utc::now() + Duration::minutes(minutes as i64))),
+            ..Default::default()
+        };
+        reminder.insert(&*self.db).await
+    }
+
+    /// Delete a reminder
+    pub async fn delete_reminder(&self, id: &str) -> Result<(), DbErr> {
+        let result = task_reminders::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Reminder not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Reminders that should fire now, earliest first
+    pub async fn find_due_reminders(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ScheduledReminder>, DbErr> {
+        let stale_before = now - Duration::hours(STALE_AFTER_HOURS);
+        Ok(self
+            .scheduled_reminders()
+            .await?
+            .into_iter()
+            .filter(|scheduled| {
+                scheduled.fire_at <= now
+                    && (scheduled.fire_at > stale_before
+                        || scheduled.reminder.snoozed_until.is_some())
+            })
+            .collect())
+    }
+
+    /// Reminders firing after `now` and up to `until`, earliest first
+    pub async fn find_upcoming_reminders(
+        &self,
+        now: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<ScheduledReminder>, DbErr> {
+        Ok(self
+            .scheduled_reminders()
+            .await?
+            .into_iter()
+            .filter(|scheduled| scheduled.fire_at > now && scheduled.fire_at <= until)
+            .collect())
+    }
+
+    /// Record that a reminder fired, so it stays quiet until its date changes
+    ///
+    /// One-off reminders are deleted once they fired.
+    pub async fn mark_fired(&self, scheduled: &ScheduledReminder) -> Result<(), DbErr> {
+        if scheduled.reminder.anchor == "once" {
+            task_reminders::Entity::delete_by_id(scheduled.reminder.id.clone())
+                .exec(&*self.db)
+                .await?;
+            return Ok(());
+        }
+
+        let mut active: task_reminders::ActiveModel = scheduled.reminder.clone().into();
+        active.last_fired_for = Set(Some(scheduled.target_at));
+        active.snoozed_until = Set(None);
+        active.updated_at = Set(Utc::now());
+        active.update(&*self.db).await?;
+        Ok(())
+    }
+
+    /// Get all reminders (for backup)
+    pub async fn get_all_reminders(&self) -> Result<Vec<task_reminders::Model>, DbErr> {
+        task_reminders::Entity::find().all(&*self.db).await
+    }
+
+    /// Import a reminder (for backup restore)
+    pub async fn import_reminder(
+        &self,
+        reminder: task_reminders::Model,
+    ) -> Result<task_reminders::Model, DbErr> {
+        let active_model: task_reminders::ActiveModel = reminder.into();
+        active_model.insert(&*self.db).await
+    }
+
+    /// Delete all reminders
+    pub async fn delete_all(&self) -> Result<u64, DbErr> {
+        let result = task_reminders::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Every reminder of an open task that still has to fire, earliest first
+    async fn scheduled_reminders(&self) -> Result<Vec<ScheduledReminder>, DbErr> {
+        let done_statuses: Vec<String> = task_statuses::Entity::find()
+            .filter(task_statuses::Column::Category.eq("done"))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|status| status.name)
+            .collect();
+
+        let reminders = task_reminders::Entity::find()
+            .find_also_related(tasks::Entity)
+            .all(&*self.db)
+            .await?;
+
+        let mut scheduled: Vec<ScheduledReminder> = reminders
+            .into_iter()
+            .filter_map(|(reminder, task)| {
+                let task = task?;
+                if task.archived_at.is_some() || done_statuses.contains(&task.status) {
+                    return None;
+                }
+                let (target_at, mut fire_at) = next_fire(&reminder, &task)?;
+                if let Some(working_hours) = self
+                    .working_hours
+                    .as_ref()
+                    .filter(|_| reminder.snoozed_until.is_none())
+                {
+                    fire_at = working_hours.next_working_time(fire_at, &chrono::Local);
+                }
+                Some(ScheduledReminder {
+                    task_id: task.id,
+                    task_title: task.title,
+                    target_at,
+                    fire_at,
+                    reminder,
+                })
+            })
+            .collect();
+        scheduled.sort_by_key(|scheduled| scheduled.fire_at);
+        Ok(scheduled)
+    }
+
+    fn validate_reminder(reminder: &ReminderOffsetRequest) -> Result<(), DbErr> {
+        if !REMINDER_ANCHORS.contains(&reminder.anchor.as_str()) || reminder.anchor == "once" {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown reminder anchor '{}', expected due_date or scheduled_date",
+                reminder.anchor
+            )));
+        }
+        if !(0..=MAX_OFFSET_MINUTES).contains(&reminder.offset_minutes) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Reminder offset must be between 0 and {} minutes",
+                MAX_OFFSET_MINUTES
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_snooze(minutes: i32) -> Result<(), DbErr> {
+        if !(1..=MAX_SNOOZE_MINUTES).contains(&minutes) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Reminders can be postponed by 1 to {} minutes",
+                MAX_SNOOZE_MINUTES
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The date a reminder is about and when it fires next, if it still has to
+///
+/// A snooze overrides the regular firing time.
+fn next_fire(
+    reminder: &task_reminders::Model,
+    task: &tasks::Model,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let target_at = match reminder.anchor.as_str() {
+        "due_date" => task.due_date,
+        "scheduled_date" => task.scheduled_date,
+        _ => reminder.remind_at,
+    }?;
+
+    if let Some(snoozed_until) = reminder.snoozed_until {
+        return Some((target_at, snoozed_until));
+    }
+    if reminder.last_fired_for == Some(target_at) {
+        return None;
+    }
+    Some((
+        target_at,
+        target_at - Duration::minutes(reminder.offset_minutes as i64),
+    ))
+}
@@ -0,0 +1,39 @@
+use sea_orm::DbErr;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::database::error::{busy_error, is_busy_error};
+
+/// Attempts made before giving up on a busy database
+pub const MAX_BUSY_ATTEMPTS: u32 = 5;
+
+/// Delay before the first retry; doubled after every further attempt
+const INITIAL_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Run a database operation, retrying with exponential backoff while SQLite reports busy/locked
+///
+/// The operation is started from scratch on every attempt, so it must be safe to repeat as a
+/// whole (a single statement or a complete transaction). When the database is still busy after
+/// the last attempt, a `DATABASE_BUSY` error is returned so the UI can offer a retry.
+pub async fn retry_on_busy<T, F, Fut>(mut operation: F) -> Result<T, DbErr>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DbErr>>,
+{
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 1;
+
+    loop {
+        match operation().await {
+            Err(err) if is_busy_error(&err) => {
+                if attempt >= MAX_BUSY_ATTEMPTS {
+                    return Err(busy_error(&err));
+                }
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::task_repository::TaskFilter;
+use crate::database::entities::saved_filters;
+
+/// Request structure for creating a new saved filter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSavedFilterRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub criteria: TaskFilter,
+}
+
+/// Request structure for updating an existing saved filter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSavedFilterRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub criteria: Option<TaskFilter>,
+}
+
+/// Saved filter repository for SeaORM-based database operations
+pub struct SavedFilterRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SavedFilterRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new saved filter, rejecting duplicate names
+    pub async fn create_filter(
+        &self,
+        request: CreateSavedFilterRequest,
+    ) -> Result<saved_filters::Model, DbErr> {
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Saved filter name cannot be empty".to_string(),
+            ));
+        }
+
+        if self.find_by_name(&name).await?.is_some() {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: A saved filter with the name '{}' already exists",
+                name
+            )));
+        }
+
+        let filter = saved_filters::ActiveModel {
+            name: Set(name),
+            description: Set(request.description),
+            criteria: Set(Self::encode_criteria(&request.criteria)?),
+            ..Default::default()
+        };
+
+        filter.insert(&*self.db).await
+    }
+
+    /// Find a saved filter by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<saved_filters::Model>, DbErr> {
+        saved_filters::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find a saved filter by its exact name
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<saved_filters::Model>, DbErr> {
+        saved_filters::Entity::find()
+            .filter(saved_filters::Column::Name.eq(name))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find all saved filters ordered by name
+    pub async fn find_all(&self) -> Result<Vec<saved_filters::Model>, DbErr> {
+        saved_filters::Entity::find()
+            .order_by_asc(saved_filters::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Update a saved filter
+    pub async fn update_filter(
+        &self,
+        id: &str,
+        request: UpdateSavedFilterRequest,
+    ) -> Result<saved_filters::Model, DbErr> {
+        let filter = saved_filters::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Saved filter not found".to_string()))?;
+
+        let mut filter: saved_filters::ActiveModel = filter.into();
+
+        if let Some(name) = request.name {
+            let name = name.trim().to_string();
+            if let Some(existing) = self.find_by_name(&name).await? {
+                if existing.id != id {
+                    return Err(DbErr::Custom(format!(
+                        "DUPLICATE_ERROR: A saved filter with the name '{}' already exists",
+                        name
+                    )));
+                }
+            }
+            filter.name = Set(name);
+        }
+        if let Some(description) = request.description {
+            filter.description = Set(Some(description));
+        }
+        if let Some(criteria) = request.criteria {
+            filter.criteria = Set(Self::encode_criteria(&criteria)?);
+        }
+
+        filter.updated_at = Set(chrono::Utc::now());
+
+        filter.update(&*self.db).await
+    }
+
+    /// Delete a saved filter
+    pub async fn delete_filter(&self, id: &str) -> Result<(), DbErr> {
+        saved_filters::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Decode the task criteria stored on a saved filter
+    pub fn parse_criteria(filter: &saved_filters::Model) -> Result<TaskFilter, DbErr> {
+        serde_json::from_str(&filter.criteria).map_err(|e| {
+            DbErr::Custom(format!(
+                "Invalid criteria in saved filter '{}': {}",
+                filter.name, e
+            ))
+        })
+    }
+
+    fn encode_criteria(criteria: &TaskFilter) -> Result<String, DbErr> {
+        serde_json::to_string(criteria)
+            .map_err(|e| DbErr::Custom(format!("Failed to serialize filter criteria: {}", e)))
+    }
+}
@@ -0,0 +1,131 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{sync_items, sync_states};
+
+/// Request structure for recording the remote copy of a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveSyncItemRequest {
+    pub calendar_url: String,
+    pub task_id: String,
+    pub uid: String,
+    pub href: String,
+    pub etag: Option<String>,
+    pub task_updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Sync repository for SeaORM-based database operations
+pub struct SyncRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SyncRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Find the synced items of a calendar
+    pub async fn find_items(&self, calendar_url: &str) -> Result<Vec<sync_items::Model>, DbErr> {
+        sync_items::Entity::find()
+            .filter(sync_items::Column::CalendarUrl.eq(calendar_url))
+            .order_by_asc(sync_items::Column::SyncedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Record the remote copy of a task, replacing the previous record for the task
+    pub async fn save_item(
+        &self,
+        request: SaveSyncItemRequest,
+    ) -> Result<sync_items::Model, DbErr> {
+        if request.href.trim().is_empty() || request.uid.trim().is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Synced items need an href and a UID".to_string(),
+            ));
+        }
+
+        let existing = sync_items::Entity::find()
+            .filter(sync_items::Column::CalendarUrl.eq(&request.calendar_url))
+            .filter(sync_items::Column::TaskId.eq(&request.task_id))
+            .one(&*self.db)
+            .await?;
+
+        match existing {
+            Some(existing) => {
+                let mut item: sync_items::ActiveModel = existing.into();
+                item.uid = Set(request.uid);
+                item.href = Set(request.href);
+                item.etag = Set(request.etag);
+                item.task_updated_at = Set(request.task_updated_at);
+                item.synced_at = Set(chrono::Utc::now());
+                item.update(&*self.db).await
+            }
+            None => {
+                let item = sync_items::ActiveModel {
+                    calendar_url: Set(request.calendar_url),
+                    task_id: Set(request.task_id),
+                    uid: Set(request.uid),
+                    href: Set(request.href),
+                    etag: Set(request.etag),
+                    task_updated_at: Set(request.task_updated_at),
+                    ..Default::default()
+                };
+                item.insert(&*self.db).await
+            }
+        }
+    }
+
+    /// Forget a synced item
+    pub async fn delete_item(&self, id: &str) -> Result<(), DbErr> {
+        let result = sync_items::Entity::delete_by_id(id).exec(&*self.db).await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Sync item not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Get the sync state of a calendar, if it was ever synced
+    pub async fn find_state(
+        &self,
+        calendar_url: &str,
+    ) -> Result<Option<sync_states::Model>, DbErr> {
+        sync_states::Entity::find_by_id(calendar_url)
+            .one(&*self.db)
+            .await
+    }
+
+    /// Store the sync token of a completed sync
+    pub async fn save_state(
+        &self,
+        calendar_url: &str,
+        sync_token: Option<String>,
+    ) -> Result<sync_states::Model, DbErr> {
+        let state = sync_states::ActiveModel {
+            calendar_url: Set(calendar_url.to_string()),
+            sync_token: Set(sync_token),
+            last_synced_at: Set(Some(chrono::Utc::now())),
+        };
+
+        if self.find_state(calendar_url).await?.is_some() {
+            state.update(&*self.db).await
+        } else {
+            state.insert(&*self.db).await
+        }
+    }
+
+    /// Forget the items and sync token of a calendar so the next sync starts over
+    pub async fn reset(&self, calendar_url: &str) -> Result<u64, DbErr> {
+        let result = sync_items::Entity::delete_many()
+            .filter(sync_items::Column::CalendarUrl.eq(calendar_url))
+            .exec(&*self.db)
+            .await?;
+        sync_states::Entity::delete_by_id(calendar_url)
+            .exec(&*self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
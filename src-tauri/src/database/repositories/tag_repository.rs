@@ -0,0 +1,292 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    FromQueryResult, QueryFilter, Set, Statement, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::database::entities::{tags, task_tags, tasks};
+
+/// A tag together with the number of tasks carrying it
+#[derive(Debug, Clone, Serialize, Deserialize, FromQueryResult)]
+pub struct TagWithCount {
+    pub id: String,
+    pub name: String,
+    pub task_count: i64,
+}
+
+/// Tag repository for SeaORM-based database operations
+///
+/// Tags live in the `tags` table and are linked to tasks through `task_tags`. The JSON `tags`
+/// column on tasks is kept in sync as a copy for existing readers.
+pub struct TagRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TagRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Find all tags with their task counts, alphabetically
+    pub async fn find_all(&self) -> Result<Vec<TagWithCount>, DbErr> {
+        TagWithCount::find_by_statement(Statement::from_string(
+            self.db.get_database_backend(),
+            r#"
+            SELECT tags.id, tags.name, COUNT(task_tags.id) AS task_count
+            FROM tags
+            LEFT JOIN task_tags ON task_tags.tag_id = tags.id
+            GROUP BY tags.id, tags.name
+            ORDER BY tags.name COLLATE NOCASE
+            "#,
+        ))
+        .all(&*self.db)
+        .await
+    }
+
+    /// Find a tag by name
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<tags::Model>, DbErr> {
+        tags::Entity::find()
+            .filter(tags::Column::Name.eq(name.trim()))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Rename a tag on every task carrying it
+    ///
+    /// Renaming onto an existing tag is rejected; use [`merge_tags`](Self::merge_tags) instead.
+    pub async fn rename_tag(&self, old_name: &str, new_name: &str) -> Result<tags::Model, DbErr> {
+        let new_name = validate_tag_name(new_name)?;
+        let tag = self
+            .find_by_name(old_name)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound(format!("Tag '{}' not found", old_name)))?;
+
+        if tag.name == new_name {
+            return Ok(tag);
+        }
+        if self.find_by_name(&new_name).await?.is_some() {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: A tag named '{}' already exists; merge the tags instead",
+                new_name
+            )));
+        }
+
+        let task_ids = Self::task_ids_for_tags(&*self.db, std::slice::from_ref(&tag.id)).await?;
+        let renames = HashMap::from([(tag.name.clone(), new_name.clone())]);
+
+        let txn = self.db.begin().await?;
+        let mut active: tags::ActiveModel = tag.into();
+        active.name = Set(new_name);
+        active.updated_at = Set(chrono::Utc::now());
+        let updated = active.update(&txn).await?;
+        Self::rewrite_task_tags(&txn, &task_ids, &renames).await?;
+        txn.commit().await?;
+
+        Ok(updated)
+    }
+
+    /// Merge the source tags into `target`, creating the target tag when missing
+    ///
+    /// Tasks carrying any of the sources end up tagged with the target once; the source tags
+    /// are deleted.
+    pub async fn merge_tags(
+        &self,
+        source_names: &[String],
+        target_name: &str,
+    ) -> Result<tags::Model, DbErr> {
+        let target_name = validate_tag_name(target_name)?;
+        let sources: Vec<tags::Model> = tags::Entity::find()
+            .filter(tags::Column::Name.is_in(source_names.iter().map(|name| name.trim())))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .filter(|tag| tag.name != target_name)
+            .collect();
+
+        if sources.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: No existing tags to merge".to_string(),
+            ));
+        }
+
+        let source_ids: Vec<String> = sources.iter().map(|tag| tag.id.clone()).collect();
+        let task_ids = Self::task_ids_for_tags(&*self.db, &source_ids).await?;
+        let renames: HashMap<String, String> = sources
+            .into_iter()
+            .map(|tag| (tag.name, target_name.clone()))
+            .collect();
+
+        let txn = self.db.begin().await?;
+        let target = Self::find_or_create(&txn, &target_name).await?;
+
+        task_tags::Entity::delete_many()
+            .filter(task_tags::Column::TagId.is_in(source_ids.clone()))
+            .exec(&txn)
+            .await?;
+        tags::Entity::delete_many()
+            .filter(tags::Column::Id.is_in(source_ids))
+            .exec(&txn)
+            .await?;
+
+        for task_id in &task_ids {
+            Self::link(&txn, task_id, &target.id).await?;
+        }
+        Self::rewrite_task_tags(&txn, &task_ids, &renames).await?;
+        txn.commit().await?;
+
+        Ok(target)
+    }
+
+    /// Replace the tags of a task, returning the cleaned-up tag names
+    ///
+    /// Names are trimmed and de-duplicated; tags that don't exist yet are created.
+    pub async fn set_task_tags<C: ConnectionTrait>(
+        conn: &C,
+        task_id: &str,
+        names: &[String],
+    ) -> Result<Vec<String>, DbErr> {
+        let names = normalize_tag_names(names);
+
+        task_tags::Entity::delete_many()
+            .filter(task_tags::Column::TaskId.eq(task_id))
+            .exec(conn)
+            .await?;
+
+        for name in &names {
+            let tag = Self::find_or_create(conn, name).await?;
+            Self::link(conn, task_id, &tag.id).await?;
+        }
+
+        Ok(names)
+    }
+
+    /// Remove every tag link of the given tasks
+    pub async fn unlink_tasks<C: ConnectionTrait>(
+        conn: &C,
+        task_ids: Vec<String>,
+    ) -> Result<u64, DbErr> {
+        let result = task_tags::Entity::delete_many()
+            .filter(task_tags::Column::TaskId.is_in(task_ids))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Delete all tags and tag links
+    pub async fn delete_all_tags(&self) -> Result<u64, DbErr> {
+        task_tags::Entity::delete_many().exec(&*self.db).await?;
+        let result = tags::Entity::delete_many().exec(&*self.db).await?;
+        Ok(result.rows_affected)
+    }
+
+    async fn find_or_create<C: ConnectionTrait>(
+        conn: &C,
+        name: &str,
+    ) -> Result<tags::Model, DbErr> {
+        let existing = tags::Entity::find()
+            .filter(tags::Column::Name.eq(name))
+            .one(conn)
+            .await?;
+
+        match existing {
+            Some(tag) => Ok(tag),
+            None => {
+                let tag = tags::ActiveModel {
+                    name: Set(name.to_string()),
+                    ..Default::default()
+                };
+                tag.insert(conn).await
+            }
+        }
+    }
+
+    async fn link<C: ConnectionTrait>(conn: &C, task_id: &str, tag_id: &str) -> Result<(), DbErr> {
+        let exists = task_tags::Entity::find()
+            .filter(task_tags::Column::TaskId.eq(task_id))
+            .filter(task_tags::Column::TagId.eq(tag_id))
+            .one(conn)
+            .await?
+            .is_some();
+
+        if !exists {
+            let link = task_tags::ActiveModel {
+                task_id: Set(task_id.to_string()),
+                tag_id: Set(tag_id.to_string()),
+                ..Default::default()
+            };
+            link.insert(conn).await?;
+        }
+        Ok(())
+    }
+
+    async fn task_ids_for_tags<C: ConnectionTrait>(
+        conn: &C,
+        tag_ids: &[String],
+    ) -> Result<Vec<String>, DbErr> {
+        let task_ids: HashSet<String> = task_tags::Entity::find()
+            .filter(task_tags::Column::TagId.is_in(tag_ids.iter().cloned()))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|link| link.task_id)
+            .collect();
+        Ok(task_ids.into_iter().collect())
+    }
+
+    /// Apply tag renames to the JSON tag column of the given tasks
+    async fn rewrite_task_tags<C: ConnectionTrait>(
+        conn: &C,
+        task_ids: &[String],
+        renames: &HashMap<String, String>,
+    ) -> Result<(), DbErr> {
+        let affected = tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(task_ids.iter().cloned()))
+            .all(conn)
+            .await?;
+
+        for task in affected {
+            let names: Vec<String> = task
+                .tags
+                .as_deref()
+                .and_then(|tags| serde_json::from_str::<Vec<String>>(tags).ok())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|name| renames.get(name.trim()).cloned().unwrap_or(name))
+                .collect();
+            let names = normalize_tag_names(&names);
+
+            let mut active: tasks::ActiveModel = task.into();
+            active.tags = Set(Some(serde_json::to_string(&names).unwrap_or_default()));
+            active.updated_at = Set(chrono::Utc::now());
+            active.update(conn).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Trim tag names, dropping empty ones and duplicates while keeping their order
+pub fn normalize_tag_names(names: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    names
+        .iter()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty() && seen.insert(name.clone()))
+        .collect()
+}
+
+fn validate_tag_name(name: &str) -> Result<String, DbErr> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DbErr::Custom(
+            "VALIDATION_ERROR: Tag name cannot be empty".to_string(),
+        ));
+    }
+    if name.len() > 50 {
+        return Err(DbErr::Custom(
+            "VALIDATION_ERROR: Tag name cannot exceed 50 characters".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
@@ -0,0 +1,181 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use std::sync::Arc;
+
+use crate::database::entities::{task_audit_log, tasks};
+
+/// Who made a change: the user through the UI or integrations, or an AI tool call
+pub const AUDIT_SOURCES: [&str; 2] = ["user", "ai"];
+
+/// Fields that change on every write and would only add noise to the history
+const UNAUDITED_FIELDS: [&str; 1] = ["updated_at"];
+
+/// Task audit repository for SeaORM-based database operations
+pub struct TaskAuditRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TaskAuditRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Ensure `source` is one of the known audit sources
+    pub fn validate_source(source: &str) -> Result<(), DbErr> {
+        if AUDIT_SOURCES.contains(&source) {
+            Ok(())
+        } else {
+            Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown change source '{}'. Expected one of: {}",
+                source,
+                AUDIT_SOURCES.join(", ")
+            )))
+        }
+    }
+
+    /// ID grouping the entries recorded by one operation
+    pub fn new_change_id() -> String {
+        uuid::Uuid::new_v4().to_string()
+    }
+
+    /// Record the change from `before` to `after` as part of the change `change_id`
+    ///
+    /// A created task (no `before`) is stored as one `create` entry holding the new task, a
+    /// deleted one (no `after`) as one `delete` entry holding the old task, and an update as
+    /// one `update` entry per changed field.
+    pub async fn record<C: ConnectionTrait>(
+        conn: &C,
+        change_id: &str,
+        before: Option<&tasks::Model>,
+        after: Option<&tasks::Model>,
+        source: &str,
+    ) -> Result<(), DbErr> {
+        let entries = match (before, after) {
+            (None, Some(task)) => vec![entry(&task.id, "create", None, None, Some(to_json(task)))],
+            (Some(task), None) => vec![entry(&task.id, "delete", None, Some(to_json(task)), None)],
+            (Some(before), Some(after)) => changed_fields(before, after)
+                .into_iter()
+                .map(|(field, old, new)| entry(&after.id, "update", Some(field), old, new))
+                .collect(),
+            (None, None) => Vec::new(),
+        };
+
+        for mut entry in entries {
+            entry.change_id = Set(Some(change_id.to_string()));
+            entry.source = Set(source.to_string());
+            entry.insert(conn).await?;
+        }
+        Ok(())
+    }
+
+    /// Get the history of a task, newest first
+    pub async fn get_history(&self, task_id: &str) -> Result<Vec<task_audit_log::Model>, DbErr> {
+        task_audit_log::Entity::find()
+            .filter(task_audit_log::Column::TaskId.eq(task_id))
+            .order_by_desc(task_audit_log::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Get the entries of one change in the order they were recorded
+    pub async fn find_change(&self, change_id: &str) -> Result<Vec<task_audit_log::Model>, DbErr> {
+        task_audit_log::Entity::find()
+            .filter(task_audit_log::Column::ChangeId.eq(change_id))
+            .order_by_asc(task_audit_log::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// IDs of the changes recorded since `since`, newest first
+    pub async fn change_ids_since(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<String>, DbErr> {
+        let entries = task_audit_log::Entity::find()
+            .filter(task_audit_log::Column::CreatedAt.gte(since))
+            .filter(task_audit_log::Column::ChangeId.is_not_null())
+            .order_by_desc(task_audit_log::Column::CreatedAt)
+            .all(&*self.db)
+            .await?;
+
+        let mut change_ids: Vec<String> = Vec::new();
+        for change_id in entries.into_iter().filter_map(|entry| entry.change_id) {
+            if !change_ids.contains(&change_id) {
+                change_ids.push(change_id);
+            }
+        }
+        Ok(change_ids)
+    }
+
+    /// Delete audit entries recorded before `older_than`
+    pub async fn clear_older_than(
+        &self,
+        older_than: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, DbErr> {
+        let result = task_audit_log::Entity::delete_many()
+            .filter(task_audit_log::Column::CreatedAt.lt(older_than))
+            .exec(&*self.db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Delete the whole audit log
+    pub async fn clear_all(&self) -> Result<u64, DbErr> {
+        let result = task_audit_log::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
+
+fn entry(
+    task_id: &str,
+    action: &str,
+    field: Option<String>,
+    old_value: Option<String>,
+    new_value: Option<String>,
+) -> task_audit_log::ActiveModel {
+    task_audit_log::ActiveModel {
+        task_id: Set(task_id.to_string()),
+        action: Set(action.to_string()),
+        field: Set(field),
+        old_value: Set(old_value),
+        new_value: Set(new_value),
+        ..Default::default()
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Fields that differ between two versions of a task, with their old and new JSON values
+fn changed_fields(
+    before: &tasks::Model,
+    after: &tasks::Model,
+) -> Vec<(String, Option<String>, Option<String>)> {
+    let before = serde_json::to_value(before).unwrap_or_default();
+    let after = serde_json::to_value(after).unwrap_or_default();
+    let (Some(before), Some(after)) = (before.as_object(), after.as_object()) else {
+        return Vec::new();
+    };
+
+    let json_value =
+        |value: Option<&serde_json::Value>| value.filter(|value| !value.is_null()).map(to_json);
+
+    after
+        .keys()
+        .filter(|field| !UNAUDITED_FIELDS.contains(&field.as_str()))
+        .filter(|field| before.get(*field) != after.get(*field))
+        .map(|field| {
+            (
+                field.clone(),
+                json_value(before.get(field)),
+                json_value(after.get(field)),
+            )
+        })
+        .collect()
+}
@@ -1,11 +1,18 @@
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder, Set, Statement, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
-use crate::database::entities::{task_lists, tasks};
+use crate::database::entities::{custom_field_values, custom_fields, task_lists, tasks};
+use crate::database::repositories::project_repository::ProjectRepository;
+use crate::database::repositories::rank::{rank_between, spaced_rank};
+use crate::database::repositories::retry::retry_on_busy;
+
+/// Longest icon name a task list may have
+const MAX_ICON_LENGTH: usize = 50;
 
 /// Request structure for creating a new task list
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,9 +21,14 @@ pub struct CreateTaskListRequest {
 }
 
 /// Request structure for updating an existing task list
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Unset fields are left alone; an empty color, icon or project ID clears it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateTaskListRequest {
-    pub name: String,
+    pub name: Option<String>,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub project_id: Option<String>,
 }
 
 /// Task list repository for SeaORM-based database operations
@@ -31,15 +43,27 @@ impl TaskListRepository {
 
     /// Create a new task list with comprehensive validation and error handling
     pub async fn create_task_list(&self, name: String) -> Result<task_lists::Model, DbErr> {
+        retry_on_busy(|| self.try_create_task_list(name.clone())).await
+    }
+
+    async fn try_create_task_list(&self, name: String) -> Result<task_lists::Model, DbErr> {
+        Self::create_task_list_in(&*self.db, name).await
+    }
+
+    /// Create a task list on `conn`, e.g. inside a transaction importing tasks into it
+    pub async fn create_task_list_in<C: ConnectionTrait>(
+        conn: &C,
+        name: String,
+    ) -> Result<task_lists::Model, DbErr> {
         // Validate task list name
-        self.validate_task_list_name(&name)?;
+        Self::check_name(&name)?;
 
         let trimmed_name = name.trim().to_string();
 
         // Check for duplicate names (case-insensitive)
         let existing = task_lists::Entity::find()
             .filter(task_lists::Column::Name.eq(&trimmed_name))
-            .one(&*self.db)
+            .one(conn)
             .await
             .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to check for duplicate names: {}", e)))?;
 
@@ -50,85 +74,370 @@ impl TaskListRepository {
             )));
         }
 
+        // New lists go to the end
+        let last_rank = task_lists::Entity::find()
+            .order_by_desc(task_lists::Column::OrderNum)
+            .one(conn)
+            .await?
+            .map(|list| list.order_num);
+
         let task_list = task_lists::ActiveModel {
             name: Set(trimmed_name),
             is_default: Set(false),
+            order_num: Set(rank_between(last_rank, None).unwrap_or(i32::MAX)),
             ..Default::default()
         };
 
-        task_list.insert(&*self.db).await
+        task_list.insert(conn).await
             .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to create task list: {}", e)))
     }
 
-    /// Find all task lists ordered by name
+    /// Find all task lists in their display order, then by name
     pub async fn find_all_task_lists(&self) -> Result<Vec<task_lists::Model>, DbErr> {
         task_lists::Entity::find()
+            .order_by_asc(task_lists::Column::OrderNum)
+            .order_by_asc(task_lists::Column::IsDefault)
+            .order_by_asc(task_lists::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Find the task lists that are not archived, in the same order as `find_all_task_lists`
+    pub async fn find_active_task_lists(&self) -> Result<Vec<task_lists::Model>, DbErr> {
+        task_lists::Entity::find()
+            .filter(task_lists::Column::IsArchived.eq(false))
+            .order_by_asc(task_lists::Column::OrderNum)
             .order_by_asc(task_lists::Column::IsDefault)
             .order_by_asc(task_lists::Column::Name)
             .all(&*self.db)
             .await
     }
 
+    /// Move a task list between the lists directly above (`before_id`) and below (`after_id`) it
+    ///
+    /// Like tasks, lists are renumbered only when the neighbours have no room left between them.
+    pub async fn reorder_task_list(
+        &self,
+        id: &str,
+        before_id: Option<&str>,
+        after_id: Option<&str>,
+    ) -> Result<task_lists::Model, DbErr> {
+        retry_on_busy(|| self.try_reorder_task_list(id, before_id, after_id)).await
+    }
+
+    async fn try_reorder_task_list(
+        &self,
+        id: &str,
+        before_id: Option<&str>,
+        after_id: Option<&str>,
+    ) -> Result<task_lists::Model, DbErr> {
+        let txn = self.db.begin().await?;
+        let task_list = task_lists::Entity::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task list not found".to_string()))?;
+
+        let before = Self::find_rank_neighbour(&txn, id, before_id).await?;
+        let after = Self::find_rank_neighbour(&txn, id, after_id).await?;
+        if before.is_none() && after.is_none() {
+            return Ok(task_list);
+        }
+
+        let rank = rank_between(
+            before.as_ref().map(|list| list.order_num),
+            after.as_ref().map(|list| list.order_num),
+        );
+        let rank = match rank {
+            Some(rank) => rank,
+            None => {
+                let mut ids: Vec<String> = task_lists::Entity::find()
+                    .filter(task_lists::Column::Id.ne(id))
+                    .order_by_asc(task_lists::Column::OrderNum)
+                    .order_by_asc(task_lists::Column::IsDefault)
+                    .order_by_asc(task_lists::Column::Name)
+                    .all(&txn)
+                    .await?
+                    .into_iter()
+                    .map(|list| list.id)
+                    .collect();
+
+                let position = match (&before, &after) {
+                    (Some(before), _) => ids.iter().position(|i| *i == before.id).map(|i| i + 1),
+                    (None, Some(after)) => ids.iter().position(|i| *i == after.id),
+                    (None, None) => None,
+                }
+                .unwrap_or(0);
+                ids.insert(position, id.to_string());
+
+                for (index, list_id) in ids.iter().enumerate().filter(|(_, i)| *i != id) {
+                    task_lists::Entity::update_many()
+                        .col_expr(
+                            task_lists::Column::OrderNum,
+                            sea_orm::sea_query::Expr::value(spaced_rank(index)),
+                        )
+                        .filter(task_lists::Column::Id.eq(list_id.clone()))
+                        .exec(&txn)
+                        .await?;
+                }
+                spaced_rank(position)
+            }
+        };
+
+        let mut active: task_lists::ActiveModel = task_list.into();
+        active.order_num = Set(rank);
+        active.updated_at = Set(chrono::Utc::now());
+        let updated = active.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(updated)
+    }
+
+    async fn find_rank_neighbour<C: ConnectionTrait>(
+        conn: &C,
+        id: &str,
+        neighbour_id: Option<&str>,
+    ) -> Result<Option<task_lists::Model>, DbErr> {
+        let Some(neighbour_id) = neighbour_id else {
+            return Ok(None);
+        };
+        if neighbour_id == id {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A task list cannot be placed next to itself".to_string(),
+            ));
+        }
+
+        task_lists::Entity::find_by_id(neighbour_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Neighbouring task list not found".to_string()))
+            .map(Some)
+    }
+
+    /// Put the given task lists in this order at the top; lists left out follow in their
+    /// current order
+    pub async fn reorder_task_lists(
+        &self,
+        ids: &[String],
+    ) -> Result<Vec<task_lists::Model>, DbErr> {
+        retry_on_busy(|| self.try_reorder_task_lists(ids)).await
+    }
+
+    async fn try_reorder_task_lists(
+        &self,
+        ids: &[String],
+    ) -> Result<Vec<task_lists::Model>, DbErr> {
+        let txn = self.db.begin().await?;
+        let mut lists = task_lists::Entity::find()
+            .order_by_asc(task_lists::Column::OrderNum)
+            .order_by_asc(task_lists::Column::IsDefault)
+            .order_by_asc(task_lists::Column::Name)
+            .all(&txn)
+            .await?;
+
+        let mut ordered: Vec<task_lists::Model> = Vec::with_capacity(lists.len());
+        for id in ids {
+            let Some(position) = lists.iter().position(|list| list.id == *id) else {
+                return Err(if ordered.iter().any(|list| list.id == *id) {
+                    DbErr::Custom(format!(
+                        "VALIDATION_ERROR: Task list '{}' is listed twice",
+                        id
+                    ))
+                } else {
+                    DbErr::RecordNotFound(format!(
+                        "RECORD_NOT_FOUND: Task list with ID '{}' not found",
+                        id
+                    ))
+                });
+            };
+            ordered.push(lists.remove(position));
+        }
+        ordered.append(&mut lists);
+
+        let now = chrono::Utc::now();
+        let mut updated = Vec::with_capacity(ordered.len());
+        for (index, list) in ordered.into_iter().enumerate() {
+            if list.order_num == spaced_rank(index) {
+                updated.push(list);
+                continue;
+            }
+            let mut active: task_lists::ActiveModel = list.into();
+            active.order_num = Set(spaced_rank(index));
+            active.updated_at = Set(now);
+            updated.push(active.update(&txn).await?);
+        }
+
+        txn.commit().await?;
+        Ok(updated)
+    }
+
     /// Find a task list by ID
     #[allow(dead_code)]
     pub async fn find_by_id(&self, id: &str) -> Result<Option<task_lists::Model>, DbErr> {
         task_lists::Entity::find_by_id(id).one(&*self.db).await
     }
 
-    /// Update a task list with comprehensive validation and error handling
+    /// Find a task list by its exact (trimmed) name
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<task_lists::Model>, DbErr> {
+        Self::find_by_name_in(&*self.db, name).await
+    }
+
+    /// Find a task list by its exact (trimmed) name on `conn`
+    pub async fn find_by_name_in<C: ConnectionTrait>(
+        conn: &C,
+        name: &str,
+    ) -> Result<Option<task_lists::Model>, DbErr> {
+        task_lists::Entity::find()
+            .filter(task_lists::Column::Name.eq(name.trim()))
+            .one(conn)
+            .await
+    }
+
+    /// Find a task list by name, creating it when missing
+    pub async fn find_or_create_by_name(&self, name: &str) -> Result<task_lists::Model, DbErr> {
+        match self.find_by_name(name).await? {
+            Some(existing) => Ok(existing),
+            None => self.create_task_list(name.to_string()).await,
+        }
+    }
+
+    /// Update a task list's name, color, icon or project with comprehensive validation and error
+    /// handling
     pub async fn update_task_list(
         &self,
         id: &str,
-        name: String,
+        request: UpdateTaskListRequest,
+    ) -> Result<task_lists::Model, DbErr> {
+        retry_on_busy(|| self.try_update_task_list(id, request.clone())).await
+    }
+
+    async fn try_update_task_list(
+        &self,
+        id: &str,
+        request: UpdateTaskListRequest,
     ) -> Result<task_lists::Model, DbErr> {
         // Validate input
         if id.trim().is_empty() {
             return Err(DbErr::Custom("VALIDATION_ERROR: Task list ID cannot be empty".to_string()));
         }
 
-        // Validate task list name
-        self.validate_task_list_name(&name)?;
-
-        let trimmed_name = name.trim().to_string();
-
         let task_list = task_lists::Entity::find_by_id(id)
             .one(&*self.db)
             .await
             .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to find task list: {}", e)))?
             .ok_or_else(|| DbErr::RecordNotFound(format!("RECORD_NOT_FOUND: Task list with ID '{}' not found", id)))?;
 
-        // Prevent updating the default task list name
-        if task_list.is_default {
-            return Err(DbErr::Custom(
-                "BUSINESS_RULE_ERROR: Cannot update the default task list name".to_string(),
-            ));
+        let trimmed_name = match &request.name {
+            Some(name) => {
+                // Validate task list name
+                self.validate_task_list_name(name)?;
+                Some(name.trim().to_string()).filter(|name| *name != task_list.name)
+            }
+            None => None,
+        };
+        let color = request
+            .color
+            .as_deref()
+            .map(Self::normalize_color)
+            .transpose()?;
+        let icon = request
+            .icon
+            .as_deref()
+            .map(Self::normalize_icon)
+            .transpose()?;
+        let project_id = request
+            .project_id
+            .map(|project_id| project_id.trim().to_string());
+        if let Some(project_id) = project_id.as_deref().filter(|id| !id.is_empty()) {
+            ProjectRepository::ensure_exists(&*self.db, project_id).await?;
         }
 
-        // Check for duplicate names (case-insensitive), excluding current task list
-        let existing = task_lists::Entity::find()
-            .filter(task_lists::Column::Name.eq(&trimmed_name))
-            .filter(task_lists::Column::Id.ne(id))
-            .one(&*self.db)
-            .await
-            .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to check for duplicate names: {}", e)))?;
+        if let Some(trimmed_name) = &trimmed_name {
+            // Prevent updating the default task list name
+            if task_list.is_default {
+                return Err(DbErr::Custom(
+                    "BUSINESS_RULE_ERROR: Cannot update the default task list name".to_string(),
+                ));
+            }
 
-        if existing.is_some() {
-            return Err(DbErr::Custom(format!(
-                "DUPLICATE_ERROR: A task list with the name '{}' already exists",
-                trimmed_name
-            )));
+            // Check for duplicate names (case-insensitive), excluding current task list
+            let existing = task_lists::Entity::find()
+                .filter(task_lists::Column::Name.eq(trimmed_name))
+                .filter(task_lists::Column::Id.ne(id))
+                .one(&*self.db)
+                .await
+                .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to check for duplicate names: {}", e)))?;
+
+            if existing.is_some() {
+                return Err(DbErr::Custom(format!(
+                    "DUPLICATE_ERROR: A task list with the name '{}' already exists",
+                    trimmed_name
+                )));
+            }
         }
 
         let mut task_list: task_lists::ActiveModel = task_list.into();
-        task_list.name = Set(trimmed_name);
+        if let Some(trimmed_name) = trimmed_name {
+            task_list.name = Set(trimmed_name);
+        }
+        if let Some(color) = color {
+            task_list.color = Set(color);
+        }
+        if let Some(icon) = icon {
+            task_list.icon = Set(icon);
+        }
+        if let Some(project_id) = project_id {
+            task_list.project_id = Set(Some(project_id).filter(|id| !id.is_empty()));
+        }
         task_list.updated_at = Set(chrono::Utc::now());
 
         task_list.update(&*self.db).await
             .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to update task list: {}", e)))
     }
 
+    /// Hide a task list and its tasks from the task lists and stats, keeping everything stored
+    pub async fn archive_task_list(&self, id: &str) -> Result<task_lists::Model, DbErr> {
+        self.set_archived(id, true).await
+    }
+
+    /// Bring an archived task list back
+    pub async fn unarchive_task_list(&self, id: &str) -> Result<task_lists::Model, DbErr> {
+        self.set_archived(id, false).await
+    }
+
+    async fn set_archived(&self, id: &str, is_archived: bool) -> Result<task_lists::Model, DbErr> {
+        let task_list = task_lists::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| {
+                DbErr::RecordNotFound(format!(
+                    "RECORD_NOT_FOUND: Task list with ID '{}' not found",
+                    id
+                ))
+            })?;
+
+        // New tasks fall back to the default list, so it always stays visible
+        if is_archived && task_list.is_default {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Cannot archive the default task list".to_string(),
+            ));
+        }
+        if task_list.is_archived == is_archived {
+            return Ok(task_list);
+        }
+
+        let mut task_list: task_lists::ActiveModel = task_list.into();
+        task_list.is_archived = Set(is_archived);
+        task_list.updated_at = Set(chrono::Utc::now());
+
+        task_list.update(&*self.db).await
+    }
+
     /// Delete a task list with comprehensive error handling and rollback
     pub async fn delete_task_list(&self, id: &str) -> Result<(), DbErr> {
+        retry_on_busy(|| self.try_delete_task_list(id)).await
+    }
+
+    async fn try_delete_task_list(&self, id: &str) -> Result<(), DbErr> {
         // Validate input
         if id.trim().is_empty() {
             return Err(DbErr::Custom("VALIDATION_ERROR: Task list ID cannot be empty".to_string()));
@@ -184,6 +493,30 @@ impl TaskListRepository {
             )));
         }
 
+        // Drop the list's custom fields; their values no longer apply in the default list
+        let field_ids: Vec<String> = custom_fields::Entity::find()
+            .filter(custom_fields::Column::TaskListId.eq(id))
+            .all(&txn)
+            .await
+            .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to find custom fields: {}", e)))?
+            .into_iter()
+            .map(|field| field.id)
+            .collect();
+
+        if !field_ids.is_empty() {
+            custom_field_values::Entity::delete_many()
+                .filter(custom_field_values::Column::FieldId.is_in(field_ids))
+                .exec(&txn)
+                .await
+                .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to delete custom field values: {}", e)))?;
+
+            custom_fields::Entity::delete_many()
+                .filter(custom_fields::Column::TaskListId.eq(id))
+                .exec(&txn)
+                .await
+                .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to delete custom fields: {}", e)))?;
+        }
+
         // Delete the task list
         let delete_result = task_lists::Entity::delete_by_id(id).exec(&txn).await
             .map_err(|e| DbErr::Custom(format!("DATABASE_ERROR: Failed to delete task list: {}", e)))?;
@@ -244,11 +577,12 @@ impl TaskListRepository {
         Ok(count > 0)
     }
 
-    /// Get task list statistics
+    /// Get task list statistics, leaving out archived lists
     pub async fn get_task_list_stats(&self) -> Result<TaskListStats, DbErr> {
-        let total_lists = task_lists::Entity::find().count(&*self.db).await?;
+        let active = || task_lists::Entity::find().filter(task_lists::Column::IsArchived.eq(false));
+        let total_lists = active().count(&*self.db).await?;
 
-        let lists_with_tasks = task_lists::Entity::find()
+        let lists_with_tasks = active()
             .find_with_related(tasks::Entity)
             .all(&*self.db)
             .await?
@@ -265,6 +599,247 @@ impl TaskListRepository {
         })
     }
 
+    /// Set the target backlog and cycle times for a task list (`None` removes a target)
+    pub async fn set_targets(
+        &self,
+        id: &str,
+        target_backlog_hours: Option<i32>,
+        target_cycle_hours: Option<i32>,
+    ) -> Result<task_lists::Model, DbErr> {
+        for (label, hours) in [
+            ("Target backlog time", target_backlog_hours),
+            ("Target cycle time", target_cycle_hours),
+        ] {
+            if hours.is_some_and(|h| h <= 0) {
+                return Err(DbErr::Custom(format!(
+                    "VALIDATION_ERROR: {} must be a positive number of hours",
+                    label
+                )));
+            }
+        }
+
+        let task_list = task_lists::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| {
+                DbErr::RecordNotFound(format!(
+                    "RECORD_NOT_FOUND: Task list with ID '{}' not found",
+                    id
+                ))
+            })?;
+
+        let mut task_list: task_lists::ActiveModel = task_list.into();
+        task_list.target_backlog_hours = Set(target_backlog_hours);
+        task_list.target_cycle_hours = Set(target_cycle_hours);
+        task_list.updated_at = Set(chrono::Utc::now());
+
+        task_list.update(&*self.db).await
+    }
+
+    /// Set the hourly rate billed for work on a task list (`None` makes it not billable)
+    pub async fn set_hourly_rate(
+        &self,
+        id: &str,
+        hourly_rate: Option<f64>,
+    ) -> Result<task_lists::Model, DbErr> {
+        if hourly_rate.is_some_and(|rate| !rate.is_finite() || rate < 0.0) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Hourly rate must be zero or a positive number".to_string(),
+            ));
+        }
+
+        let task_list = task_lists::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| {
+                DbErr::RecordNotFound(format!(
+                    "RECORD_NOT_FOUND: Task list with ID '{}' not found",
+                    id
+                ))
+            })?;
+
+        let mut task_list: task_lists::ActiveModel = task_list.into();
+        task_list.hourly_rate = Set(hourly_rate);
+        task_list.updated_at = Set(chrono::Utc::now());
+
+        task_list.update(&*self.db).await
+    }
+
+    /// Get aging statistics per task list, computed from the task status history
+    ///
+    /// Backlog age is measured from when a pending task last became pending, in-progress age
+    /// from when it last entered progress, and cycle time from the first start to completion.
+    /// Archived tasks are ignored, as are archived lists unless asked for by `task_list_id`.
+    pub async fn get_aging_stats(
+        &self,
+        task_list_id: Option<&str>,
+    ) -> Result<Vec<TaskListAgingStats>, DbErr> {
+        let sql = r#"
+            WITH task_ages AS (
+                SELECT
+                    t.task_list_id,
+                    t.status,
+                    t.created_at,
+                    t.completed_at,
+                    COALESCE(
+                        (SELECT MAX(h.changed_at) FROM task_status_history h
+                         WHERE h.task_id = t.id AND h.to_status = t.status),
+                        t.created_at
+                    ) AS status_since,
+                    (SELECT MIN(h.changed_at) FROM task_status_history h
+                     WHERE h.task_id = t.id AND h.to_status = 'in_progress') AS started_at
+                FROM tasks t
+                WHERE t.archived_at IS NULL
+            ),
+            durations AS (
+                SELECT
+                    task_list_id,
+                    status,
+                    (julianday($1) - julianday(status_since)) * 24.0 AS status_hours,
+                    CASE WHEN status = 'completed' AND completed_at IS NOT NULL
+                        THEN (julianday(completed_at)
+                              - julianday(COALESCE(started_at, created_at))) * 24.0
+                    END AS cycle_hours
+                FROM task_ages
+            )
+            SELECT
+                l.id AS task_list_id,
+                l.name AS task_list_name,
+                l.target_backlog_hours,
+                l.target_cycle_hours,
+                COUNT(CASE WHEN d.status = 'pending' THEN 1 END) AS backlog_count,
+                AVG(CASE WHEN d.status = 'pending' THEN d.status_hours END)
+                    AS avg_backlog_hours,
+                MAX(CASE WHEN d.status = 'pending' THEN d.status_hours END)
+                    AS max_backlog_hours,
+                COUNT(CASE WHEN d.status = 'in_progress' THEN 1 END) AS in_progress_count,
+                AVG(CASE WHEN d.status = 'in_progress' THEN d.status_hours END)
+                    AS avg_in_progress_hours,
+                MAX(CASE WHEN d.status = 'in_progress' THEN d.status_hours END)
+                    AS max_in_progress_hours,
+                COUNT(d.cycle_hours) AS completed_count,
+                AVG(d.cycle_hours) AS avg_cycle_hours,
+                COUNT(CASE WHEN d.status = 'pending'
+                    AND d.status_hours > l.target_backlog_hours THEN 1 END) AS backlog_breaches,
+                COUNT(CASE WHEN d.status = 'in_progress'
+                    AND d.status_hours > l.target_cycle_hours THEN 1 END) AS in_progress_breaches,
+                COUNT(CASE WHEN d.cycle_hours > l.target_cycle_hours THEN 1 END)
+                    AS completed_breaches
+            FROM task_lists l
+            LEFT JOIN durations d ON d.task_list_id = l.id
+            WHERE ($2 IS NULL AND NOT l.is_archived) OR l.id = $2
+            GROUP BY l.id
+            ORDER BY l.is_default DESC, l.name ASC
+        "#;
+
+        TaskListAgingStats::find_by_statement(Statement::from_sql_and_values(
+            self.db.get_database_backend(),
+            sql,
+            [
+                chrono::Utc::now().into(),
+                task_list_id.map(str::to_string).into(),
+            ],
+        ))
+        .all(&*self.db)
+        .await
+    }
+
+    /// Get task counts by status, overdue tasks, time and last activity per task list or tag
+    ///
+    /// Archived tasks and lists are left out; overdue tasks are past due and not in a done
+    /// status. Lists come in their display order, tags by name.
+    pub async fn get_breakdown(
+        &self,
+        group_by: BreakdownGroup,
+    ) -> Result<Vec<TaskBreakdown>, DbErr> {
+        let grouped = match group_by {
+            BreakdownGroup::List => {
+                r#"
+                SELECT l.id AS group_id, l.name AS group_name, l.order_num AS group_order,
+                       t.id AS task_id, t.status, t.due_date, t.time_estimate, t.actual_time,
+                       t.updated_at
+                FROM task_lists l
+                LEFT JOIN tasks t ON t.task_list_id = l.id AND t.archived_at IS NULL
+                WHERE NOT l.is_archived
+                "#
+            }
+            BreakdownGroup::Tag => {
+                r#"
+                SELECT g.id AS group_id, g.name AS group_name, 0 AS group_order,
+                       t.id AS task_id, t.status, t.due_date, t.time_estimate, t.actual_time,
+                       t.updated_at
+                FROM tags g
+                LEFT JOIN task_tags tt ON tt.tag_id = g.id
+                LEFT JOIN tasks t ON t.id = tt.task_id AND t.archived_at IS NULL
+                    AND NOT EXISTS (SELECT 1 FROM task_lists l
+                                    WHERE l.id = t.task_list_id AND l.is_archived)
+                "#
+            }
+        };
+        let backend = self.db.get_database_backend();
+
+        let totals_sql = format!(
+            r#"
+            WITH grouped AS ({grouped})
+            SELECT
+                group_id AS id,
+                group_name AS name,
+                COUNT(task_id) AS total_tasks,
+                COUNT(CASE WHEN julianday(due_date) < julianday($1)
+                    AND status NOT IN (SELECT name FROM task_statuses WHERE category = 'done')
+                    THEN 1 END) AS overdue_count,
+                COALESCE(SUM(time_estimate), 0) AS estimated_minutes,
+                COALESCE(SUM(actual_time), 0) AS actual_minutes,
+                MAX(updated_at) AS last_activity_at
+            FROM grouped
+            GROUP BY group_id, group_name
+            ORDER BY MIN(group_order), group_name COLLATE NOCASE
+            "#
+        );
+        let totals = BreakdownTotalsRow::find_by_statement(Statement::from_sql_and_values(
+            backend,
+            &totals_sql,
+            [chrono::Utc::now().into()],
+        ))
+        .all(&*self.db)
+        .await?;
+
+        let status_sql = format!(
+            r#"
+            WITH grouped AS ({grouped})
+            SELECT group_id, status, COUNT(*) AS task_count
+            FROM grouped
+            WHERE task_id IS NOT NULL
+            GROUP BY group_id, status
+            "#
+        );
+        let status_rows =
+            BreakdownStatusRow::find_by_statement(Statement::from_string(backend, status_sql))
+                .all(&*self.db)
+                .await?;
+        let mut status_counts: HashMap<String, BTreeMap<String, i64>> = HashMap::new();
+        for row in status_rows {
+            status_counts
+                .entry(row.group_id)
+                .or_default()
+                .insert(row.status, row.task_count);
+        }
+
+        Ok(totals
+            .into_iter()
+            .map(|row| TaskBreakdown {
+                status_counts: status_counts.remove(&row.id).unwrap_or_default(),
+                id: row.id,
+                name: row.name,
+                total_tasks: row.total_tasks,
+                overdue_count: row.overdue_count,
+                estimated_minutes: row.estimated_minutes,
+                actual_minutes: row.actual_minutes,
+                last_activity_at: row.last_activity_at,
+            })
+            .collect())
+    }
+
     /// Internal helper to get default task list with custom database connection
     async fn get_default_task_list_internal<C>(&self, db: &C) -> Result<task_lists::Model, DbErr>
     where
@@ -279,6 +854,10 @@ impl TaskListRepository {
 
     /// Validate task list name with comprehensive error messages
     pub fn validate_task_list_name(&self, name: &str) -> Result<(), DbErr> {
+        Self::check_name(name)
+    }
+
+    fn check_name(name: &str) -> Result<(), DbErr> {
         let trimmed_name = name.trim();
 
         if trimmed_name.is_empty() {
@@ -316,15 +895,50 @@ impl TaskListRepository {
         Ok(())
     }
 
-    /// Delete all task lists (for testing purposes)
-    #[cfg(test)]
+    /// A `#rrggbb` color in lowercase; an empty color clears it
+    fn normalize_color(color: &str) -> Result<Option<String>, DbErr> {
+        let color = color.trim();
+        if color.is_empty() {
+            return Ok(None);
+        }
+        let is_hex = color.len() == 7
+            && color.starts_with('#')
+            && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+        if !is_hex {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Task list color '{}' must be a hex color like #3b82f6",
+                color
+            )));
+        }
+        Ok(Some(color.to_ascii_lowercase()))
+    }
+
+    /// An icon name of letters, digits, dashes and underscores; an empty name clears it
+    fn normalize_icon(icon: &str) -> Result<Option<String>, DbErr> {
+        let icon = icon.trim();
+        if icon.is_empty() {
+            return Ok(None);
+        }
+        let is_name = icon.len() <= MAX_ICON_LENGTH
+            && icon
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !is_name {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Task list icon must be a name of up to {} letters, digits, dashes or underscores",
+                MAX_ICON_LENGTH
+            )));
+        }
+        Ok(Some(icon.to_string()))
+    }
+
+    /// Delete all task lists, as in tests or when preparing a scratch database
     pub async fn delete_all_task_lists(&self) -> Result<u64, DbErr> {
         let result = task_lists::Entity::delete_many().exec(&*self.db).await?;
         Ok(result.rows_affected)
     }
 
     /// Import a task list from backup data
-    #[allow(dead_code)]
     pub async fn import_task_list(
         &self,
         task_list: task_lists::Model,
@@ -333,6 +947,14 @@ impl TaskListRepository {
             id: Set(task_list.id),
             name: Set(task_list.name),
             is_default: Set(task_list.is_default),
+            order_num: Set(task_list.order_num),
+            target_backlog_hours: Set(task_list.target_backlog_hours),
+            target_cycle_hours: Set(task_list.target_cycle_hours),
+            hourly_rate: Set(task_list.hourly_rate),
+            color: Set(task_list.color),
+            icon: Set(task_list.icon),
+            is_archived: Set(task_list.is_archived),
+            project_id: Set(task_list.project_id),
             created_at: Set(task_list.created_at),
             updated_at: Set(task_list.updated_at),
         };
@@ -346,6 +968,72 @@ impl TaskListRepository {
     }
 }
 
+/// Aging statistics for one task list; durations are in hours
+#[derive(Debug, Clone, Serialize, Deserialize, FromQueryResult)]
+pub struct TaskListAgingStats {
+    pub task_list_id: String,
+    pub task_list_name: String,
+    pub target_backlog_hours: Option<i32>,
+    pub target_cycle_hours: Option<i32>,
+    pub backlog_count: i64,
+    pub avg_backlog_hours: Option<f64>,
+    pub max_backlog_hours: Option<f64>,
+    pub in_progress_count: i64,
+    pub avg_in_progress_hours: Option<f64>,
+    pub max_in_progress_hours: Option<f64>,
+    pub completed_count: i64,
+    pub avg_cycle_hours: Option<f64>,
+    /// Pending tasks waiting longer than the backlog target
+    pub backlog_breaches: i64,
+    /// In-progress tasks open longer than the cycle target
+    pub in_progress_breaches: i64,
+    /// Completed tasks whose cycle time exceeded the cycle target
+    pub completed_breaches: i64,
+}
+
+/// What `get_breakdown` groups tasks by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakdownGroup {
+    #[default]
+    List,
+    Tag,
+}
+
+/// Task counts and time for one task list or tag; times are in minutes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskBreakdown {
+    /// ID of the task list or tag
+    pub id: String,
+    pub name: String,
+    pub total_tasks: i64,
+    /// Tasks per status, keyed by the status name
+    pub status_counts: BTreeMap<String, i64>,
+    pub overdue_count: i64,
+    pub estimated_minutes: i64,
+    pub actual_minutes: i64,
+    /// Last time one of the tasks changed; unset when there are none
+    pub last_activity_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct BreakdownTotalsRow {
+    id: String,
+    name: String,
+    total_tasks: i64,
+    overdue_count: i64,
+    estimated_minutes: i64,
+    actual_minutes: i64,
+    last_activity_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct BreakdownStatusRow {
+    group_id: String,
+    status: String,
+    task_count: i64,
+}
+
 /// Task list statistics structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskListStats {
@@ -0,0 +1,122 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{task_notes, tasks};
+
+/// Request structure for adding a note to a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaskNoteRequest {
+    pub task_id: String,
+    pub content: String,
+}
+
+/// Task note repository for SeaORM-based database operations
+pub struct TaskNoteRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TaskNoteRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Append a note to a task
+    pub async fn create_note(
+        &self,
+        request: CreateTaskNoteRequest,
+    ) -> Result<task_notes::Model, DbErr> {
+        let content = Self::validate_content(&request.content)?;
+        tasks::Entity::find_by_id(&request.task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let note = task_notes::ActiveModel {
+            task_id: Set(request.task_id),
+            content: Set(content),
+            ..Default::default()
+        };
+
+        note.insert(&*self.db).await
+    }
+
+    /// Find a task note by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<task_notes::Model>, DbErr> {
+        task_notes::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find the notes of a task, oldest first
+    pub async fn find_by_task(&self, task_id: &str) -> Result<Vec<task_notes::Model>, DbErr> {
+        task_notes::Entity::find()
+            .filter(task_notes::Column::TaskId.eq(task_id))
+            .order_by_asc(task_notes::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Edit a task note; it keeps its original timestamp
+    pub async fn update_note(&self, id: &str, content: &str) -> Result<task_notes::Model, DbErr> {
+        let content = Self::validate_content(content)?;
+        let note = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task note not found".to_string()))?;
+
+        let mut active: task_notes::ActiveModel = note.into();
+        active.content = Set(content);
+        active.updated_at = Set(chrono::Utc::now());
+
+        active.update(&*self.db).await
+    }
+
+    /// Delete a task note
+    pub async fn delete_note(&self, id: &str) -> Result<(), DbErr> {
+        let result = task_notes::Entity::delete_by_id(id).exec(&*self.db).await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Task note not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Delete the notes of a task
+    pub async fn delete_by_task<C: ConnectionTrait>(conn: &C, task_id: &str) -> Result<u64, DbErr> {
+        let result = task_notes::Entity::delete_many()
+            .filter(task_notes::Column::TaskId.eq(task_id))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Get all task notes (for backup)
+    pub async fn get_all_notes(&self) -> Result<Vec<task_notes::Model>, DbErr> {
+        task_notes::Entity::find().all(&*self.db).await
+    }
+
+    /// Import a task note (for backup restore)
+    pub async fn import_note(&self, note: task_notes::Model) -> Result<task_notes::Model, DbErr> {
+        Self::import_note_in(&*self.db, note).await
+    }
+
+    /// Import a task note on `conn`
+    pub async fn import_note_in<C: ConnectionTrait>(
+        conn: &C,
+        note: task_notes::Model,
+    ) -> Result<task_notes::Model, DbErr> {
+        let active_model: task_notes::ActiveModel = note.into();
+        active_model.insert(conn).await
+    }
+
+    fn validate_content(content: &str) -> Result<String, DbErr> {
+        let content = content.trim();
+        if content.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Task note cannot be empty".to_string(),
+            ));
+        }
+        Ok(content.to_string())
+    }
+}
@@ -1,11 +1,27 @@
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::database::entities::{task_dependencies, task_lists, tasks};
+use crate::database::entities::{
+    attachments, checklist_items, custom_field_values, session_subtask_completions, tags,
+    task_audit_log, task_dependencies, task_lists, task_notes, task_reminders, task_status_history,
+    task_statuses, task_tags, tasks,
+};
+use crate::database::repositories::attachment_repository::AttachmentRepository;
+use crate::database::repositories::checklist_repository::ChecklistRepository;
+use crate::database::repositories::project_repository::ProjectRepository;
+use crate::database::repositories::rank::{rank_between, spaced_rank};
+use crate::database::repositories::retry::retry_on_busy;
+use crate::database::repositories::tag_repository::{normalize_tag_names, TagRepository};
+use crate::database::repositories::task_audit_repository::TaskAuditRepository;
+use crate::database::repositories::task_note_repository::TaskNoteRepository;
+use crate::database::repositories::task_status_repository::TaskStatusRepository;
+use crate::database::services::date_parser::deserialize_flexible_date;
 
 /// Request structure for creating a new task
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,7 +33,10 @@ pub struct CreateTaskRequest {
     pub order_num: Option<i32>,
     pub dependencies: Option<Vec<String>>,
     pub time_estimate: Option<i32>,
+    /// RFC 3339, or a phrase such as "tomorrow 3pm" or "in 2 weeks"
+    #[serde(default, deserialize_with = "deserialize_flexible_date")]
     pub due_date: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, deserialize_with = "deserialize_flexible_date")]
     pub scheduled_date: Option<chrono::DateTime<chrono::Utc>>,
     pub tags: Option<Vec<String>>,
     pub project_id: Option<String>,
@@ -26,6 +45,8 @@ pub struct CreateTaskRequest {
     pub periodic_template_id: Option<String>,
     pub is_periodic_instance: Option<bool>,
     pub generation_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub effort: Option<i32>, // 1-10
+    pub impact: Option<i32>, // 1-10
 }
 
 /// Request structure for updating an existing task
@@ -47,20 +68,44 @@ pub struct UpdateTaskRequest {
     pub parent_task_id: Option<String>,
     pub task_list_id: Option<String>,
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub effort: Option<i32>, // 1-10, 0 clears the score
+    pub impact: Option<i32>, // 1-10, 0 clears the score
+    /// `updated_at` the client last saw; the update is rejected if the task changed since
+    pub expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// Task repository for SeaORM-based database operations
 pub struct TaskRepository {
     db: Arc<DatabaseConnection>,
+    audit_source: String,
 }
 
 impl TaskRepository {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self { db }
+        Self {
+            db,
+            audit_source: "user".to_string(),
+        }
+    }
+
+    /// Attribute the changes made through this repository to `source` in the task history
+    pub fn with_audit_source(mut self, source: &str) -> Self {
+        self.audit_source = source.to_string();
+        self
     }
 
     /// Create a new task
     pub async fn create_task(&self, request: CreateTaskRequest) -> Result<tasks::Model, DbErr> {
+        retry_on_busy(|| self.try_create_task(request.clone())).await
+    }
+
+    async fn try_create_task(&self, request: CreateTaskRequest) -> Result<tasks::Model, DbErr> {
+        validate_score("Effort", request.effort)?;
+        validate_score("Impact", request.impact)?;
+        if let Some(status) = &request.status {
+            self.validate_status(status).await?;
+        }
+
         // Determine the task list ID to use
         let task_list_id = if let Some(task_list_id) = request.task_list_id {
             // If a task list ID is provided, validate it exists
@@ -98,6 +143,13 @@ impl TaskRepository {
             }
         };
 
+        let project_id = request.project_id.filter(|id| !id.trim().is_empty());
+        if let Some(project_id) = &project_id {
+            ProjectRepository::ensure_exists(&*self.db, project_id).await?;
+        }
+
+        let tags = request.tags.map(|tags| normalize_tag_names(&tags));
+
         let task = tasks::ActiveModel {
             title: Set(request.title),
             description: Set(request.description),
@@ -111,21 +163,66 @@ impl TaskRepository {
             actual_time: Set(0),
             due_date: Set(request.due_date),
             scheduled_date: Set(request.scheduled_date),
-            tags: Set(request
-                .tags
-                .map(|tags| serde_json::to_string(&tags).unwrap_or_default())),
-            project_id: Set(request.project_id),
+            tags: Set(tags
+                .as_ref()
+                .map(|tags| serde_json::to_string(tags).unwrap_or_default())),
+            project_id: Set(project_id),
             parent_task_id: Set(request.parent_task_id),
             task_list_id: Set(final_task_list_id),
             subtasks: Set(None),
             periodic_template_id: Set(request.periodic_template_id),
             is_periodic_instance: Set(request.is_periodic_instance.unwrap_or(false)),
             generation_date: Set(request.generation_date),
+            effort: Set(request.effort),
+            impact: Set(request.impact),
             completed_at: Set(None),
             ..Default::default()
         };
 
-        task.insert(&*self.db).await
+        let txn = self.db.begin().await?;
+        let task = task.insert(&txn).await?;
+        Self::record_status_change(&txn, &task.id, None, &task.status).await?;
+        self.audit(&txn, None, Some(&task)).await?;
+        if let Some(tags) = &tags {
+            TagRepository::set_task_tags(&txn, &task.id, tags).await?;
+        }
+        txn.commit().await?;
+
+        Ok(task)
+    }
+
+    /// Ensure `status` is one of the defined task statuses
+    async fn validate_status(&self, status: &str) -> Result<(), DbErr> {
+        let exists = task_statuses::Entity::find()
+            .filter(task_statuses::Column::Name.eq(status))
+            .count(&*self.db)
+            .await?
+            > 0;
+
+        if !exists {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown task status '{}'",
+                status
+            )));
+        }
+        Ok(())
+    }
+
+    /// Append an entry to the task status history
+    async fn record_status_change<C: ConnectionTrait>(
+        conn: &C,
+        task_id: &str,
+        from_status: Option<&str>,
+        to_status: &str,
+    ) -> Result<(), DbErr> {
+        let entry = task_status_history::ActiveModel {
+            task_id: Set(task_id.to_string()),
+            from_status: Set(from_status.map(str::to_string)),
+            to_status: Set(to_status.to_string()),
+            ..Default::default()
+        };
+        entry.insert(conn).await?;
+        Ok(())
     }
 
     /// Find a task by ID
@@ -143,14 +240,7 @@ impl TaskRepository {
             None => return Ok(None),
         };
 
-        let dependencies = task_dependencies::Entity::find()
-            .filter(task_dependencies::Column::TaskId.eq(id))
-            .find_also_related(tasks::Entity)
-            .all(&*self.db)
-            .await?
-            .into_iter()
-            .filter_map(|(_, dep_task)| dep_task)
-            .collect();
+        let dependencies = self.get_dependencies(id).await?;
 
         Ok(Some((task, dependencies)))
     }
@@ -161,7 +251,7 @@ impl TaskRepository {
         status: Option<&str>,
         project_id: Option<&str>,
     ) -> Result<Vec<tasks::Model>, DbErr> {
-        let mut query = tasks::Entity::find();
+        let mut query = tasks::Entity::find().filter(tasks::Column::ArchivedAt.is_null());
 
         if let Some(status) = status {
             query = query.filter(tasks::Column::Status.eq(status));
@@ -185,16 +275,79 @@ impl TaskRepository {
     ) -> Result<Vec<tasks::Model>, DbErr> {
         tasks::Entity::find()
             .filter(tasks::Column::ScheduledDate.between(start_date, end_date))
+            .filter(tasks::Column::ArchivedAt.is_null())
+            .order_by_asc(tasks::Column::ScheduledDate)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Find the unfinished tasks scheduled before `before`, earliest first
+    ///
+    /// Tasks in a status of the done category and archived tasks are left out.
+    pub async fn find_unfinished_scheduled_before(
+        &self,
+        before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<tasks::Model>, DbErr> {
+        let done_statuses: Vec<String> = task_statuses::Entity::find()
+            .filter(task_statuses::Column::Category.eq("done"))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|status| status.name)
+            .collect();
+
+        tasks::Entity::find()
+            .filter(tasks::Column::ScheduledDate.lt(before))
+            .filter(tasks::Column::Status.is_not_in(done_statuses))
+            .filter(tasks::Column::ArchivedAt.is_null())
             .order_by_asc(tasks::Column::ScheduledDate)
             .all(&*self.db)
             .await
     }
 
-    /// Find tasks in backlog (no scheduled date)
+    /// Move tasks to a new scheduled date, or back to the backlog when it is unset, counting
+    /// the reschedule on each task
+    ///
+    /// All moves are recorded as one change in the task history.
+    pub async fn reschedule_tasks(
+        &self,
+        moves: &[(String, Option<chrono::DateTime<chrono::Utc>>)],
+    ) -> Result<Vec<tasks::Model>, DbErr> {
+        let txn = self.db.begin().await?;
+        let change_id = TaskAuditRepository::new_change_id();
+        let mut rescheduled = Vec::with_capacity(moves.len());
+        for (id, scheduled_date) in moves {
+            let previous = tasks::Entity::find_by_id(id)
+                .one(&txn)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+            let mut task: tasks::ActiveModel = previous.clone().into();
+            task.scheduled_date = Set(*scheduled_date);
+            task.reschedule_count = Set(previous.reschedule_count + 1);
+            task.updated_at = Set(chrono::Utc::now());
+            let task = task.update(&txn).await?;
+            TaskAuditRepository::record(
+                &txn,
+                &change_id,
+                Some(&previous),
+                Some(&task),
+                &self.audit_source,
+            )
+            .await?;
+            rescheduled.push(task);
+        }
+        txn.commit().await?;
+
+        Ok(rescheduled)
+    }
+
+    /// Find tasks in backlog (no scheduled date and not done)
     pub async fn find_backlog(&self) -> Result<Vec<tasks::Model>, DbErr> {
+        let done = TaskStatusRepository::done_statuses_in(&*self.db).await?;
         tasks::Entity::find()
             .filter(tasks::Column::ScheduledDate.is_null())
-            .filter(tasks::Column::Status.ne("completed"))
+            .filter(tasks::Column::Status.is_not_in(done))
+            .filter(tasks::Column::ArchivedAt.is_null())
             .order_by_desc(tasks::Column::Priority)
             .order_by_desc(tasks::Column::CreatedAt)
             .all(&*self.db)
@@ -205,11 +358,223 @@ impl TaskRepository {
     pub async fn find_by_task_list(&self, task_list_id: &str) -> Result<Vec<tasks::Model>, DbErr> {
         tasks::Entity::find()
             .filter(tasks::Column::TaskListId.eq(Some(task_list_id.to_string())))
+            .filter(tasks::Column::ArchivedAt.is_null())
             .order_by_desc(tasks::Column::CreatedAt)
             .all(&*self.db)
             .await
     }
 
+    /// Create a subtask under an existing parent task
+    ///
+    /// The subtask inherits the parent's task list unless one is given explicitly.
+    pub async fn create_subtask(
+        &self,
+        parent_task_id: &str,
+        mut request: CreateTaskRequest,
+    ) -> Result<tasks::Model, DbErr> {
+        let parent = tasks::Entity::find_by_id(parent_task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Parent task not found".to_string()))?;
+
+        request.parent_task_id = Some(parent.id);
+        if request
+            .task_list_id
+            .as_ref()
+            .is_none_or(|id| id.trim().is_empty())
+        {
+            request.task_list_id = parent.task_list_id;
+        }
+
+        self.create_task(request).await
+    }
+
+    /// Find the direct subtasks of a task
+    pub async fn find_subtasks(&self, parent_task_id: &str) -> Result<Vec<tasks::Model>, DbErr> {
+        tasks::Entity::find()
+            .filter(tasks::Column::ParentTaskId.eq(parent_task_id))
+            .filter(tasks::Column::ArchivedAt.is_null())
+            .order_by_asc(tasks::Column::OrderNum)
+            .order_by_asc(tasks::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Build the subtask tree rooted at a task, with completion and time rolled up to each parent
+    pub async fn get_task_tree(&self, root_task_id: &str) -> Result<Option<TaskTreeNode>, DbErr> {
+        let root = match self.find_by_id(root_task_id).await? {
+            Some(task) => task,
+            None => return Ok(None),
+        };
+
+        // Load every task that has a parent once, then assemble the tree in memory
+        let mut children_by_parent: HashMap<String, Vec<tasks::Model>> = HashMap::new();
+        for task in tasks::Entity::find()
+            .filter(tasks::Column::ParentTaskId.is_not_null())
+            .filter(tasks::Column::ArchivedAt.is_null())
+            .order_by_asc(tasks::Column::OrderNum)
+            .order_by_asc(tasks::Column::CreatedAt)
+            .all(&*self.db)
+            .await?
+        {
+            if let Some(parent_id) = task.parent_task_id.clone() {
+                children_by_parent.entry(parent_id).or_default().push(task);
+            }
+        }
+
+        let completed = TaskStatusRepository::completed_statuses_in(&*self.db).await?;
+        let mut visited = HashSet::new();
+        Ok(Some(Self::build_tree_node(
+            root,
+            &mut children_by_parent,
+            &completed,
+            &mut visited,
+        )))
+    }
+
+    fn build_tree_node(
+        task: tasks::Model,
+        children_by_parent: &mut HashMap<String, Vec<tasks::Model>>,
+        completed: &HashSet<String>,
+        visited: &mut HashSet<String>,
+    ) -> TaskTreeNode {
+        visited.insert(task.id.clone());
+
+        let mut children = Vec::new();
+        for child in children_by_parent.remove(&task.id).unwrap_or_default() {
+            // Guard against legacy cycles that predate reparent validation
+            if !visited.contains(&child.id) {
+                children.push(Self::build_tree_node(
+                    child,
+                    children_by_parent,
+                    completed,
+                    visited,
+                ));
+            }
+        }
+
+        let mut rollup = TaskRollup {
+            total_subtasks: 0,
+            completed_subtasks: 0,
+            completion_percentage: 0.0,
+            total_time_estimate: task.time_estimate,
+            total_actual_time: task.actual_time,
+        };
+
+        for child in &children {
+            rollup.total_subtasks += 1 + child.rollup.total_subtasks;
+            rollup.completed_subtasks += child.rollup.completed_subtasks;
+            if completed.contains(&child.task.status) {
+                rollup.completed_subtasks += 1;
+            }
+            rollup.total_time_estimate += child.rollup.total_time_estimate;
+            rollup.total_actual_time += child.rollup.total_actual_time;
+        }
+
+        rollup.completion_percentage = if rollup.total_subtasks > 0 {
+            (rollup.completed_subtasks as f64 / rollup.total_subtasks as f64) * 100.0
+        } else if completed.contains(&task.status) {
+            100.0
+        } else {
+            0.0
+        };
+
+        TaskTreeNode {
+            task,
+            children,
+            rollup,
+        }
+    }
+
+    /// Move a task under a new parent, or detach it when `new_parent_id` is `None`
+    ///
+    /// Rejects moves that would make a task its own ancestor.
+    pub async fn reparent_task(
+        &self,
+        task_id: &str,
+        new_parent_id: Option<&str>,
+    ) -> Result<tasks::Model, DbErr> {
+        let task = tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        if let Some(new_parent_id) = new_parent_id {
+            self.validate_parent(task_id, new_parent_id).await?;
+        }
+
+        let mut task: tasks::ActiveModel = task.into();
+        task.parent_task_id = Set(new_parent_id.map(|id| id.to_string()));
+        task.updated_at = Set(chrono::Utc::now());
+
+        self.update_audited(task_id, task).await
+    }
+
+    /// Save the changed fields of task `id` and record the change in the task history
+    ///
+    /// The previous state is read in the same transaction as the write, so a change saved in
+    /// between by someone else is not recorded as part of this one.
+    async fn update_audited(
+        &self,
+        id: &str,
+        task: tasks::ActiveModel,
+    ) -> Result<tasks::Model, DbErr> {
+        let txn = self.db.begin().await?;
+        let previous = tasks::Entity::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+        let task = task.update(&txn).await?;
+        self.audit(&txn, Some(&previous), Some(&task)).await?;
+        txn.commit().await?;
+        Ok(task)
+    }
+
+    /// Record a single-task change made through this repository in the task history
+    async fn audit<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        before: Option<&tasks::Model>,
+        after: Option<&tasks::Model>,
+    ) -> Result<(), DbErr> {
+        let change_id = TaskAuditRepository::new_change_id();
+        TaskAuditRepository::record(conn, &change_id, before, after, &self.audit_source).await
+    }
+
+    /// Ensure `parent_id` exists and is not `task_id` or one of its descendants
+    async fn validate_parent(&self, task_id: &str, parent_id: &str) -> Result<(), DbErr> {
+        if task_id == parent_id {
+            return Err(DbErr::Custom("A task cannot be its own parent".to_string()));
+        }
+
+        let mut visited = HashSet::new();
+        let mut current = Some(parent_id.to_string());
+
+        while let Some(ancestor_id) = current {
+            if ancestor_id == task_id {
+                return Err(DbErr::Custom(
+                    "Cannot move a task under one of its own subtasks".to_string(),
+                ));
+            }
+            if !visited.insert(ancestor_id.clone()) {
+                break;
+            }
+
+            let ancestor = tasks::Entity::find_by_id(&ancestor_id)
+                .one(&*self.db)
+                .await?;
+            current = match ancestor {
+                Some(ancestor) => ancestor.parent_task_id,
+                None if ancestor_id == parent_id => {
+                    return Err(DbErr::RecordNotFound("Parent task not found".to_string()));
+                }
+                None => None,
+            };
+        }
+
+        Ok(())
+    }
+
     /// Move a task to a different task list
     pub async fn move_task_to_list(
         &self,
@@ -237,7 +602,7 @@ impl TaskRepository {
         task.task_list_id = Set(Some(task_list_id.to_string()));
         task.updated_at = Set(chrono::Utc::now());
 
-        task.update(&*self.db).await
+        self.update_audited(task_id, task).await
     }
 
     /// Migrate orphaned tasks (tasks without a task_list_id) to the default task list
@@ -271,6 +636,14 @@ impl TaskRepository {
         &self,
         id: &str,
         request: UpdateTaskRequest,
+    ) -> Result<tasks::Model, DbErr> {
+        retry_on_busy(|| self.try_update_task(id, request.clone())).await
+    }
+
+    async fn try_update_task(
+        &self,
+        id: &str,
+        request: UpdateTaskRequest,
     ) -> Result<tasks::Model, DbErr> {
         let task = tasks::Entity::find_by_id(id)
             .one(&*self.db)
@@ -289,14 +662,15 @@ impl TaskRepository {
             task.priority = Set(priority);
         }
         if let Some(status) = request.status {
-            task.status = Set(status.clone());
-            // Automatically set completed_at when task is marked as completed
-            if status == "completed" {
+            self.validate_status(&status).await?;
+            // Set completed_at when the task moves to a completing status, including custom
+            // statuses of the done category, and clear it otherwise
+            if TaskStatusRepository::is_completed_in(&*self.db, &status).await? {
                 task.completed_at = Set(Some(chrono::Utc::now()));
-            } else if status != "completed" {
-                // Clear completed_at if status is changed from completed to something else
+            } else {
                 task.completed_at = Set(None);
             }
+            task.status = Set(status);
         }
         if let Some(order_num) = request.order_num {
             task.order_num = Set(order_num);
@@ -323,13 +697,21 @@ impl TaskRepository {
         } else if let Some(scheduled_date) = request.scheduled_date {
             task.scheduled_date = Set(Some(scheduled_date));
         }
-        if let Some(tags) = request.tags {
-            task.tags = Set(Some(serde_json::to_string(&tags).unwrap_or_default()));
+        let tags = request.tags.map(|tags| normalize_tag_names(&tags));
+        if let Some(tags) = &tags {
+            task.tags = Set(Some(serde_json::to_string(tags).unwrap_or_default()));
         }
         if let Some(project_id) = request.project_id {
-            task.project_id = Set(Some(project_id));
+            // An empty project ID unlinks the task
+            if project_id.trim().is_empty() {
+                task.project_id = Set(None);
+            } else {
+                ProjectRepository::ensure_exists(&*self.db, &project_id).await?;
+                task.project_id = Set(Some(project_id));
+            }
         }
         if let Some(parent_task_id) = request.parent_task_id {
+            self.validate_parent(id, &parent_task_id).await?;
             task.parent_task_id = Set(Some(parent_task_id));
         }
         if let Some(task_list_id) = request.task_list_id {
@@ -342,123 +724,861 @@ impl TaskRepository {
         if let Some(completed_at) = request.completed_at {
             task.completed_at = Set(Some(completed_at));
         }
+        if let Some(effort) = request.effort {
+            task.effort = Set(optional_score("Effort", effort)?);
+        }
+        if let Some(impact) = request.impact {
+            task.impact = Set(optional_score("Impact", impact)?);
+        }
 
         task.updated_at = Set(chrono::Utc::now());
 
-        task.update(&*self.db).await
+        let txn = self.db.begin().await?;
+        // Only the fields set above are written; the task as it stands inside the transaction
+        // is what the history and the conflict check compare against
+        let previous = tasks::Entity::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+        if let Some(expected) = request.expected_updated_at {
+            // Clients hold millisecond precision (JavaScript dates)
+            if previous.updated_at.timestamp_millis() != expected.timestamp_millis() {
+                return Err(conflict_error(&previous));
+            }
+        }
+        let task = task.update(&txn).await?;
+        if task.status != previous.status {
+            Self::record_status_change(&txn, &task.id, Some(&previous.status), &task.status)
+                .await?;
+        }
+        self.audit(&txn, Some(&previous), Some(&task)).await?;
+        if let Some(tags) = &tags {
+            TagRepository::set_task_tags(&txn, &task.id, tags).await?;
+        }
+        txn.commit().await?;
+
+        Ok(task)
     }
 
-    /// Delete a task and its dependencies
-    pub async fn delete_task(&self, id: &str) -> Result<(), DbErr> {
-        let txn = self.db.begin().await?;
+    /// Add (or, when negative, subtract) minutes to a task's actual time
+    ///
+    /// The change is applied in a single UPDATE so concurrent timer, AI and UI writes can't
+    /// overwrite each other; the result never drops below zero.
+    pub async fn add_actual_time(&self, id: &str, minutes: i32) -> Result<tasks::Model, DbErr> {
+        let result = retry_on_busy(|| {
+            tasks::Entity::update_many()
+                .col_expr(
+                    tasks::Column::ActualTime,
+                    sea_orm::sea_query::Expr::cust_with_values(
+                        "MAX(0, actual_time + ?)",
+                        [minutes],
+                    ),
+                )
+                .col_expr(
+                    tasks::Column::UpdatedAt,
+                    sea_orm::sea_query::Expr::value(chrono::Utc::now()),
+                )
+                .filter(tasks::Column::Id.eq(id))
+                .exec(&*self.db)
+        })
+        .await?;
 
-        // Delete task dependencies
-        task_dependencies::Entity::delete_many()
-            .filter(task_dependencies::Column::TaskId.eq(id))
-            .exec(&txn)
-            .await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Task not found".to_string()));
+        }
 
-        // Delete dependencies on this task
-        task_dependencies::Entity::delete_many()
-            .filter(task_dependencies::Column::DependsOnId.eq(id))
-            .exec(&txn)
-            .await?;
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))
+    }
 
-        // Delete the task
-        tasks::Entity::delete_by_id(id).exec(&txn).await?;
+    /// Rank tasks by their position in `task_ids`, in one transaction
+    ///
+    /// Ranks are spaced out like those of [`Self::reorder_task`], so later single moves still
+    /// fit between neighbours. Reordering a column touches many rows; doing it atomically keeps
+    /// concurrent reorders from interleaving into duplicate or missing positions.
+    pub async fn reorder_tasks(&self, task_ids: &[String]) -> Result<u64, DbErr> {
+        retry_on_busy(|| self.try_reorder_tasks(task_ids)).await
+    }
 
-        txn.commit().await
+    async fn try_reorder_tasks(&self, task_ids: &[String]) -> Result<u64, DbErr> {
+        let now = chrono::Utc::now();
+        let txn = self.db.begin().await?;
+        let mut updated = 0;
+
+        for (index, task_id) in task_ids.iter().enumerate() {
+            let result = tasks::Entity::update_many()
+                .col_expr(
+                    tasks::Column::OrderNum,
+                    sea_orm::sea_query::Expr::value(spaced_rank(index)),
+                )
+                .col_expr(
+                    tasks::Column::UpdatedAt,
+                    sea_orm::sea_query::Expr::value(now),
+                )
+                .filter(tasks::Column::Id.eq(task_id))
+                .exec(&txn)
+                .await?;
+            updated += result.rows_affected;
+        }
+
+        txn.commit().await?;
+        Ok(updated)
     }
 
-    /// Add a dependency between tasks
-    pub async fn add_dependency(
+    /// Move a task between the tasks directly above (`before_id`) and below (`after_id`) it
+    ///
+    /// Ranks are spaced out, so a move normally only updates the moved task. When its
+    /// neighbours have no room left between them, the task's list is renumbered; the other
+    /// tasks keep their `updated_at` so edits open elsewhere don't run into conflicts.
+    pub async fn reorder_task(
         &self,
         task_id: &str,
-        depends_on_id: &str,
-    ) -> Result<task_dependencies::Model, DbErr> {
-        // Check if both tasks exist
-        let task_exists = tasks::Entity::find_by_id(task_id)
-            .one(&*self.db)
-            .await?
-            .is_some();
-        let depends_on_exists = tasks::Entity::find_by_id(depends_on_id)
-            .one(&*self.db)
+        before_id: Option<&str>,
+        after_id: Option<&str>,
+    ) -> Result<tasks::Model, DbErr> {
+        retry_on_busy(|| self.try_reorder_task(task_id, before_id, after_id)).await
+    }
+
+    async fn try_reorder_task(
+        &self,
+        task_id: &str,
+        before_id: Option<&str>,
+        after_id: Option<&str>,
+    ) -> Result<tasks::Model, DbErr> {
+        let txn = self.db.begin().await?;
+        let task = tasks::Entity::find_by_id(task_id)
+            .one(&txn)
             .await?
-            .is_some();
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
 
-        if !task_exists || !depends_on_exists {
-            return Err(DbErr::RecordNotFound(
-                "One or both tasks not found".to_string(),
-            ));
+        let before = Self::find_rank_neighbour(&txn, &task, before_id).await?;
+        let after = Self::find_rank_neighbour(&txn, &task, after_id).await?;
+        if before.is_none() && after.is_none() {
+            return Ok(task);
         }
 
-        // Check if dependency already exists
-        let existing = task_dependencies::Entity::find()
-            .filter(task_dependencies::Column::TaskId.eq(task_id))
-            .filter(task_dependencies::Column::DependsOnId.eq(depends_on_id))
-            .one(&*self.db)
-            .await?;
+        let rank = rank_between(
+            before.as_ref().map(|t| t.order_num),
+            after.as_ref().map(|t| t.order_num),
+        );
+        let rank = match rank {
+            Some(rank) => rank,
+            None => {
+                let mut list_filter = sea_orm::Condition::all().add(tasks::Column::Id.ne(task_id));
+                list_filter = match &task.task_list_id {
+                    Some(list_id) => list_filter.add(tasks::Column::TaskListId.eq(list_id.clone())),
+                    None => list_filter.add(tasks::Column::TaskListId.is_null()),
+                };
+                let mut ids: Vec<String> = tasks::Entity::find()
+                    .filter(list_filter)
+                    .order_by_asc(tasks::Column::OrderNum)
+                    .order_by_asc(tasks::Column::CreatedAt)
+                    .all(&txn)
+                    .await?
+                    .into_iter()
+                    .map(|t| t.id)
+                    .collect();
+
+                let position = match (&before, &after) {
+                    (Some(before), _) => ids.iter().position(|id| *id == before.id).map(|i| i + 1),
+                    (None, Some(after)) => ids.iter().position(|id| *id == after.id),
+                    (None, None) => None,
+                }
+                .unwrap_or(0);
+                ids.insert(position, task.id.clone());
+
+                for (index, id) in ids.iter().enumerate().filter(|(_, id)| **id != task.id) {
+                    tasks::Entity::update_many()
+                        .col_expr(
+                            tasks::Column::OrderNum,
+                            sea_orm::sea_query::Expr::value(spaced_rank(index)),
+                        )
+                        .filter(tasks::Column::Id.eq(id.clone()))
+                        .exec(&txn)
+                        .await?;
+                }
+                spaced_rank(position)
+            }
+        };
 
-        if existing.is_some() {
-            return Err(DbErr::Custom("Dependency already exists".to_string()));
-        }
+        let mut active: tasks::ActiveModel = task.into();
+        active.order_num = Set(rank);
+        active.updated_at = Set(chrono::Utc::now());
+        let updated = active.update(&txn).await?;
 
-        let dependency = task_dependencies::ActiveModel {
-            task_id: Set(task_id.to_string()),
-            depends_on_id: Set(depends_on_id.to_string()),
-            ..Default::default()
+        txn.commit().await?;
+        Ok(updated)
+    }
+
+    /// Load a reorder neighbour, which must be another task of the same list
+    async fn find_rank_neighbour<C: ConnectionTrait>(
+        conn: &C,
+        task: &tasks::Model,
+        neighbour_id: Option<&str>,
+    ) -> Result<Option<tasks::Model>, DbErr> {
+        let Some(neighbour_id) = neighbour_id else {
+            return Ok(None);
         };
+        if neighbour_id == task.id {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A task cannot be placed next to itself".to_string(),
+            ));
+        }
 
-        dependency.insert(&*self.db).await
+        let neighbour = tasks::Entity::find_by_id(neighbour_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Neighbouring task not found".to_string()))?;
+        if neighbour.task_list_id != task.task_list_id {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Tasks can only be ordered among tasks of the same list"
+                    .to_string(),
+            ));
+        }
+        Ok(Some(neighbour))
     }
 
-    /// Remove a dependency between tasks
-    pub async fn remove_dependency(&self, task_id: &str, depends_on_id: &str) -> Result<(), DbErr> {
-        task_dependencies::Entity::delete_many()
-            .filter(task_dependencies::Column::TaskId.eq(task_id))
-            .filter(task_dependencies::Column::DependsOnId.eq(depends_on_id))
-            .exec(&*self.db)
-            .await?;
-
-        Ok(())
+    /// Delete a task and its dependencies
+    pub async fn delete_task(&self, id: &str) -> Result<(), DbErr> {
+        retry_on_busy(|| self.try_delete_task(id)).await
     }
 
-    /// Get task dependencies
-    pub async fn get_dependencies(&self, task_id: &str) -> Result<Vec<tasks::Model>, DbErr> {
-        let results = task_dependencies::Entity::find()
-            .filter(task_dependencies::Column::TaskId.eq(task_id))
-            .find_also_related(tasks::Entity)
-            .all(&*self.db)
+    async fn try_delete_task(&self, id: &str) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        self.delete_task_in(&txn, id, &TaskAuditRepository::new_change_id())
             .await?;
-
-        Ok(results.into_iter().filter_map(|(_, task)| task).collect())
+        txn.commit().await
     }
 
-    /// Get tasks that depend on this task
-    pub async fn get_dependents(&self, task_id: &str) -> Result<Vec<tasks::Model>, DbErr> {
-        let results = task_dependencies::Entity::find()
-            .filter(task_dependencies::Column::DependsOnId.eq(task_id))
-            .find_also_related(tasks::Entity)
-            .all(&*self.db)
+    /// Delete a task and the rows that belong to it, recording the deletion under `change_id`
+    async fn delete_task_in(
+        &self,
+        txn: &DatabaseTransaction,
+        id: &str,
+        change_id: &str,
+    ) -> Result<(), DbErr> {
+        // Delete task dependencies
+        task_dependencies::Entity::delete_many()
+            .filter(task_dependencies::Column::TaskId.eq(id))
+            .exec(txn)
             .await?;
 
-        Ok(results.into_iter().filter_map(|(_, task)| task).collect())
-    }
+        // Delete dependencies on this task
+        task_dependencies::Entity::delete_many()
+            .filter(task_dependencies::Column::DependsOnId.eq(id))
+            .exec(txn)
+            .await?;
 
-    /// Get task statistics
-    pub async fn get_task_stats(&self) -> Result<TaskStats, DbErr> {
-        let total = tasks::Entity::find().count(&*self.db).await?;
-        let completed = tasks::Entity::find()
-            .filter(tasks::Column::Status.eq("completed"))
-            .count(&*self.db)
+        // Delete custom field values
+        custom_field_values::Entity::delete_many()
+            .filter(custom_field_values::Column::TaskId.eq(id))
+            .exec(txn)
             .await?;
-        let in_progress = tasks::Entity::find()
-            .filter(tasks::Column::Status.eq("in_progress"))
-            .count(&*self.db)
+
+        // Delete status history
+        task_status_history::Entity::delete_many()
+            .filter(task_status_history::Column::TaskId.eq(id))
+            .exec(txn)
             .await?;
-        let pending = tasks::Entity::find()
-            .filter(tasks::Column::Status.eq("pending"))
-            .count(&*self.db)
+
+        // Delete tag links
+        TagRepository::unlink_tasks(txn, vec![id.to_string()]).await?;
+
+        // Delete reminders
+        task_reminders::Entity::delete_many()
+            .filter(task_reminders::Column::TaskId.eq(id))
+            .exec(txn)
+            .await?;
+
+        // Delete checklist items
+        ChecklistRepository::delete_by_task(txn, id).await?;
+
+        // Delete attachment rows; callers remove the stored files
+        AttachmentRepository::delete_by_task(txn, id).await?;
+
+        // Delete notes
+        TaskNoteRepository::delete_by_task(txn, id).await?;
+
+        // Delete records of the task being checked off during time sessions
+        session_subtask_completions::Entity::delete_many()
+            .filter(session_subtask_completions::Column::SubtaskId.eq(id))
+            .exec(txn)
+            .await?;
+
+        // Detach subtasks so they don't point at a missing parent
+        let subtasks = tasks::Entity::find()
+            .filter(tasks::Column::ParentTaskId.eq(id))
+            .all(txn)
+            .await?;
+        tasks::Entity::update_many()
+            .col_expr(
+                tasks::Column::ParentTaskId,
+                sea_orm::sea_query::Expr::value(None::<String>),
+            )
+            .filter(tasks::Column::ParentTaskId.eq(id))
+            .exec(txn)
+            .await?;
+
+        for previous in &subtasks {
+            let subtask = tasks::Entity::find_by_id(&previous.id).one(txn).await?;
+            TaskAuditRepository::record(
+                txn,
+                change_id,
+                Some(previous),
+                subtask.as_ref(),
+                &self.audit_source,
+            )
+            .await?;
+        }
+
+        // Delete the task; its history is kept
+        if let Some(task) = tasks::Entity::find_by_id(id).one(txn).await? {
+            TaskAuditRepository::record(txn, change_id, Some(&task), None, &self.audit_source)
+                .await?;
+        }
+        tasks::Entity::delete_by_id(id).exec(txn).await?;
+
+        Ok(())
+    }
+
+    /// Reverse a recorded change: delete the tasks it created, recreate the ones it deleted
+    /// and restore the previous values of the fields it updated
+    ///
+    /// The reversal is recorded as a new change, whose ID is returned. Deleted tasks come back
+    /// with their tags and subtasks; their checklist items, notes, attachments and
+    /// dependencies are not restored.
+    pub async fn revert_change(&self, entries: &[task_audit_log::Model]) -> Result<String, DbErr> {
+        self.apply_change(entries, true).await
+    }
+
+    /// Apply a reverted change again; returns the ID of the change recorded for it
+    pub async fn reapply_change(&self, entries: &[task_audit_log::Model]) -> Result<String, DbErr> {
+        self.apply_change(entries, false).await
+    }
+
+    async fn apply_change(
+        &self,
+        entries: &[task_audit_log::Model],
+        revert: bool,
+    ) -> Result<String, DbErr> {
+        if entries.is_empty() {
+            return Err(DbErr::RecordNotFound("Change not found".to_string()));
+        }
+
+        // A change is reverted from its last entry back to its first
+        let mut ordered: Vec<&task_audit_log::Model> = entries.iter().collect();
+        if revert {
+            ordered.reverse();
+        }
+
+        let change_id = TaskAuditRepository::new_change_id();
+        let txn = self.db.begin().await?;
+        let mut index = 0;
+        while index < ordered.len() {
+            let entry = ordered[index];
+            let snapshot = if revert {
+                &entry.old_value
+            } else {
+                &entry.new_value
+            };
+
+            match (entry.action.as_str(), revert) {
+                ("create", true) | ("delete", false) => {
+                    self.delete_task_in(&txn, &entry.task_id, &change_id)
+                        .await?;
+                    index += 1;
+                }
+                ("create", false) | ("delete", true) => {
+                    self.restore_task_in(&txn, snapshot.as_deref(), &change_id)
+                        .await?;
+                    index += 1;
+                }
+                _ => {
+                    // Consecutive field updates of one task are applied together
+                    let mut fields = Vec::new();
+                    while let Some(update) = ordered.get(index).filter(|update| {
+                        update.action == "update" && update.task_id == entry.task_id
+                    }) {
+                        let value = if revert {
+                            &update.old_value
+                        } else {
+                            &update.new_value
+                        };
+                        fields.push((update.field.clone().unwrap_or_default(), value.clone()));
+                        index += 1;
+                    }
+                    self.set_fields_in(&txn, &entry.task_id, &fields, &change_id)
+                        .await?;
+                }
+            }
+        }
+        txn.commit().await?;
+
+        Ok(change_id)
+    }
+
+    /// Insert a task from its recorded JSON snapshot
+    async fn restore_task_in(
+        &self,
+        txn: &DatabaseTransaction,
+        snapshot: Option<&str>,
+        change_id: &str,
+    ) -> Result<(), DbErr> {
+        let task: tasks::Model = snapshot
+            .and_then(|snapshot| serde_json::from_str(snapshot).ok())
+            .ok_or_else(|| {
+                DbErr::Custom("BUSINESS_RULE_ERROR: The change has no task to restore".to_string())
+            })?;
+        if tasks::Entity::find_by_id(&task.id)
+            .one(txn)
+            .await?
+            .is_some()
+        {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: Task '{}' already exists",
+                task.title
+            )));
+        }
+
+        let task = tasks::ActiveModel::from(task)
+            .reset_all()
+            .insert(txn)
+            .await?;
+        TagRepository::set_task_tags(txn, &task.id, &stored_tags(&task)).await?;
+        TaskAuditRepository::record(txn, change_id, None, Some(&task), &self.audit_source).await
+    }
+
+    /// Set task fields to recorded JSON values (`None` clears the field)
+    async fn set_fields_in(
+        &self,
+        txn: &DatabaseTransaction,
+        task_id: &str,
+        fields: &[(String, Option<String>)],
+        change_id: &str,
+    ) -> Result<(), DbErr> {
+        let previous = tasks::Entity::find_by_id(task_id)
+            .one(txn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        let mut task = serde_json::to_value(&previous).unwrap_or_default();
+        for (field, value) in fields {
+            task[field.as_str()] = value
+                .as_deref()
+                .and_then(|value| serde_json::from_str(value).ok())
+                .unwrap_or(serde_json::Value::Null);
+        }
+        let mut task: tasks::Model = serde_json::from_value(task).map_err(|e| {
+            DbErr::Custom(format!("BUSINESS_RULE_ERROR: Cannot restore task: {}", e))
+        })?;
+        task.updated_at = chrono::Utc::now();
+
+        let task = tasks::ActiveModel::from(task)
+            .reset_all()
+            .update(txn)
+            .await?;
+        if task.status != previous.status {
+            Self::record_status_change(txn, &task.id, Some(&previous.status), &task.status).await?;
+        }
+        if task.tags != previous.tags {
+            TagRepository::set_task_tags(txn, &task.id, &stored_tags(&task)).await?;
+        }
+        TaskAuditRepository::record(
+            txn,
+            change_id,
+            Some(&previous),
+            Some(&task),
+            &self.audit_source,
+        )
+        .await
+    }
+
+    /// Archive a task and its subtasks
+    ///
+    /// Archived tasks keep their time sessions and dependencies but are hidden from list queries.
+    pub async fn archive_task(&self, id: &str) -> Result<tasks::Model, DbErr> {
+        self.set_archived_at(id, Some(chrono::Utc::now())).await
+    }
+
+    /// Restore an archived task and its subtasks
+    pub async fn unarchive_task(&self, id: &str) -> Result<tasks::Model, DbErr> {
+        self.set_archived_at(id, None).await
+    }
+
+    async fn set_archived_at(
+        &self,
+        id: &str,
+        archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<tasks::Model, DbErr> {
+        if self.find_by_id(id).await?.is_none() {
+            return Err(DbErr::RecordNotFound("Task not found".to_string()));
+        }
+
+        // Collect the task and all of its descendants
+        let mut ids = vec![id.to_string()];
+        let mut frontier = vec![id.to_string()];
+        while !frontier.is_empty() {
+            let children: Vec<String> = tasks::Entity::find()
+                .filter(tasks::Column::ParentTaskId.is_in(frontier))
+                .all(&*self.db)
+                .await?
+                .into_iter()
+                .map(|task| task.id)
+                .filter(|child_id| !ids.contains(child_id))
+                .collect();
+            ids.extend(children.iter().cloned());
+            frontier = children;
+        }
+
+        let txn = self.db.begin().await?;
+        let previous = tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(ids.clone()))
+            .all(&txn)
+            .await?;
+        tasks::Entity::update_many()
+            .col_expr(
+                tasks::Column::ArchivedAt,
+                sea_orm::sea_query::Expr::value(archived_at),
+            )
+            .col_expr(
+                tasks::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(chrono::Utc::now()),
+            )
+            .filter(tasks::Column::Id.is_in(ids))
+            .exec(&txn)
+            .await?;
+        let change_id = TaskAuditRepository::new_change_id();
+        for previous in &previous {
+            let task = tasks::Entity::find_by_id(&previous.id).one(&txn).await?;
+            TaskAuditRepository::record(
+                &txn,
+                &change_id,
+                Some(previous),
+                task.as_ref(),
+                &self.audit_source,
+            )
+            .await?;
+        }
+        txn.commit().await?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))
+    }
+
+    /// Find archived tasks, most recently archived first
+    pub async fn find_archived(
+        &self,
+        task_list_id: Option<&str>,
+    ) -> Result<Vec<tasks::Model>, DbErr> {
+        let mut query = tasks::Entity::find().filter(tasks::Column::ArchivedAt.is_not_null());
+
+        if let Some(task_list_id) = task_list_id {
+            query = query.filter(tasks::Column::TaskListId.eq(task_list_id));
+        }
+
+        query
+            .order_by_desc(tasks::Column::ArchivedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Add a dependency between tasks
+    pub async fn add_dependency(
+        &self,
+        task_id: &str,
+        depends_on_id: &str,
+    ) -> Result<task_dependencies::Model, DbErr> {
+        self.add_relation(task_id, depends_on_id, "depends_on")
+            .await
+    }
+
+    /// Relate two tasks, read as "`task_id` `relation_type` `related_id`"
+    pub async fn add_relation(
+        &self,
+        task_id: &str,
+        related_id: &str,
+        relation_type: &str,
+    ) -> Result<task_dependencies::Model, DbErr> {
+        validate_relation_type(relation_type)?;
+
+        // Check if both tasks exist
+        let task_exists = tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .is_some();
+        let related_exists = tasks::Entity::find_by_id(related_id)
+            .one(&*self.db)
+            .await?
+            .is_some();
+
+        if !task_exists || !related_exists {
+            return Err(DbErr::RecordNotFound(
+                "One or both tasks not found".to_string(),
+            ));
+        }
+
+        if task_id == related_id {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A task cannot be related to itself".to_string(),
+            ));
+        }
+
+        // Reject ordering relations that would make a task (transitively) wait on itself
+        if let Some((dependent, depends_on)) = dependency_edge(task_id, related_id, relation_type) {
+            let edges = self.dependency_edges().await?;
+            if let Some(path) = find_dependency_path(&edges, depends_on, dependent) {
+                let mut cycle = vec![dependent.to_string()];
+                cycle.extend(path);
+                return Err(DbErr::Custom(format!(
+                    "VALIDATION_ERROR: Dependency would create a cycle: {}",
+                    self.describe_path(&cycle).await?
+                )));
+            }
+        }
+
+        // Check if the relation already exists
+        let existing = task_dependencies::Entity::find()
+            .filter(relation_condition(task_id, related_id, relation_type))
+            .one(&*self.db)
+            .await?;
+
+        if existing.is_some() {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: Tasks are already related by {}",
+                relation_type
+            )));
+        }
+
+        let relation = task_dependencies::ActiveModel {
+            task_id: Set(task_id.to_string()),
+            depends_on_id: Set(related_id.to_string()),
+            relation_type: Set(relation_type.to_string()),
+            ..Default::default()
+        };
+
+        relation.insert(&*self.db).await
+    }
+
+    /// Load the dependency graph as `task_id -> [depends_on_id]`
+    ///
+    /// `blocks` relations are included in reverse: a task depends on the tasks that block it.
+    pub async fn dependency_edges(&self) -> Result<HashMap<String, Vec<String>>, DbErr> {
+        let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+        for relation in task_dependencies::Entity::find()
+            .order_by_asc(task_dependencies::Column::CreatedAt)
+            .all(&*self.db)
+            .await?
+        {
+            if let Some((dependent, depends_on)) = dependency_edge(
+                &relation.task_id,
+                &relation.depends_on_id,
+                &relation.relation_type,
+            ) {
+                edges
+                    .entry(dependent.to_string())
+                    .or_default()
+                    .push(depends_on.to_string());
+            }
+        }
+        Ok(edges)
+    }
+
+    /// Render a path of task IDs as `"Title A" -> "Title B" -> ...`
+    async fn describe_path(&self, task_ids: &[String]) -> Result<String, DbErr> {
+        let titles: HashMap<String, String> = tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(task_ids.to_vec()))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|task| (task.id, task.title))
+            .collect();
+
+        Ok(task_ids
+            .iter()
+            .map(|id| format!("\"{}\"", titles.get(id).unwrap_or(id)))
+            .collect::<Vec<_>>()
+            .join(" -> "))
+    }
+
+    /// Check the whole dependency graph for cycles and references to missing tasks
+    pub async fn validate_dependency_graph(&self) -> Result<DependencyGraphReport, DbErr> {
+        let titles: HashMap<String, String> = tasks::Entity::find()
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|task| (task.id, task.title))
+            .collect();
+        let edges = self.dependency_edges().await?;
+
+        let dependency_count = edges.values().map(Vec::len).sum();
+        let dangling_dependencies = edges
+            .iter()
+            .flat_map(|(task_id, depends_on)| {
+                depends_on
+                    .iter()
+                    .map(move |depends_on_id| (task_id.clone(), depends_on_id.clone()))
+            })
+            .filter(|(task_id, depends_on_id)| {
+                !titles.contains_key(task_id) || !titles.contains_key(depends_on_id)
+            })
+            .collect();
+
+        let cycles: Vec<DependencyCycle> = find_dependency_cycles(&edges)
+            .into_iter()
+            .map(|task_ids| DependencyCycle {
+                titles: task_ids
+                    .iter()
+                    .map(|id| titles.get(id).cloned().unwrap_or_else(|| id.clone()))
+                    .collect(),
+                task_ids,
+            })
+            .collect();
+
+        Ok(DependencyGraphReport {
+            task_count: titles.len() as u64,
+            dependency_count,
+            is_valid: cycles.is_empty(),
+            cycles,
+            dangling_dependencies,
+        })
+    }
+
+    /// Remove a dependency between tasks
+    pub async fn remove_dependency(&self, task_id: &str, depends_on_id: &str) -> Result<(), DbErr> {
+        self.remove_relation(task_id, depends_on_id, "depends_on")
+            .await
+    }
+
+    /// Remove a relation between tasks
+    pub async fn remove_relation(
+        &self,
+        task_id: &str,
+        related_id: &str,
+        relation_type: &str,
+    ) -> Result<(), DbErr> {
+        validate_relation_type(relation_type)?;
+
+        task_dependencies::Entity::delete_many()
+            .filter(relation_condition(task_id, related_id, relation_type))
+            .exec(&*self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get task dependencies
+    pub async fn get_dependencies(&self, task_id: &str) -> Result<Vec<tasks::Model>, DbErr> {
+        let ids = self
+            .dependency_edges()
+            .await?
+            .remove(task_id)
+            .unwrap_or_default();
+
+        tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(ids))
+            .all(&*self.db)
+            .await
+    }
+
+    /// Get tasks that depend on this task
+    pub async fn get_dependents(&self, task_id: &str) -> Result<Vec<tasks::Model>, DbErr> {
+        let ids: Vec<String> = self
+            .dependency_edges()
+            .await?
+            .into_iter()
+            .filter(|(_, depends_on)| depends_on.iter().any(|id| id == task_id))
+            .map(|(dependent, _)| dependent)
+            .collect();
+
+        tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(ids))
+            .all(&*self.db)
+            .await
+    }
+
+    /// Get the tasks related to a task, in both directions, optionally of a single type
+    pub async fn get_relations(
+        &self,
+        task_id: &str,
+        relation_type: Option<&str>,
+    ) -> Result<Vec<TaskRelation>, DbErr> {
+        let mut query = task_dependencies::Entity::find().filter(
+            Condition::any()
+                .add(task_dependencies::Column::TaskId.eq(task_id))
+                .add(task_dependencies::Column::DependsOnId.eq(task_id)),
+        );
+        if let Some(relation_type) = relation_type {
+            validate_relation_type(relation_type)?;
+            query = query.filter(task_dependencies::Column::RelationType.eq(relation_type));
+        }
+        let relations = query
+            .order_by_asc(task_dependencies::Column::CreatedAt)
+            .all(&*self.db)
+            .await?;
+
+        let other_ids: Vec<String> = relations
+            .iter()
+            .map(|relation| other_end(relation, task_id).to_string())
+            .collect();
+        let related_tasks: HashMap<String, tasks::Model> = tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(other_ids))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|task| (task.id.clone(), task))
+            .collect();
+
+        Ok(relations
+            .iter()
+            .filter_map(|relation| {
+                let task = related_tasks.get(other_end(relation, task_id))?.clone();
+                let direction = if relation.task_id == task_id {
+                    "outgoing"
+                } else {
+                    "incoming"
+                };
+                Some(TaskRelation {
+                    id: relation.id.clone(),
+                    relation_type: relation.relation_type.clone(),
+                    direction: direction.to_string(),
+                    task,
+                })
+            })
+            .collect())
+    }
+
+    /// Get task statistics, leaving out archived tasks and the tasks of archived lists
+    pub async fn get_task_stats(&self) -> Result<TaskStats, DbErr> {
+        let archived_lists = sea_orm::sea_query::Query::select()
+            .column(task_lists::Column::Id)
+            .from(task_lists::Entity)
+            .and_where(task_lists::Column::IsArchived.eq(true))
+            .to_owned();
+        let active = || {
+            tasks::Entity::find()
+                .filter(tasks::Column::ArchivedAt.is_null())
+                .filter(
+                    Condition::any()
+                        .add(tasks::Column::TaskListId.is_null())
+                        .add(tasks::Column::TaskListId.not_in_subquery(archived_lists.clone())),
+                )
+        };
+
+        let completed_statuses = TaskStatusRepository::completed_statuses_in(&*self.db).await?;
+        let total = active().count(&*self.db).await?;
+        let completed = active()
+            .filter(tasks::Column::Status.is_in(completed_statuses))
+            .count(&*self.db)
+            .await?;
+        let in_progress = active()
+            .filter(tasks::Column::Status.eq("in_progress"))
+            .count(&*self.db)
+            .await?;
+        let pending = active()
+            .filter(tasks::Column::Status.eq("pending"))
+            .count(&*self.db)
             .await?;
 
         Ok(TaskStats {
@@ -479,11 +1599,199 @@ impl TaskRepository {
                     .like(&search_pattern)
                     .or(tasks::Column::Description.like(&search_pattern)),
             )
+            .filter(tasks::Column::ArchivedAt.is_null())
+            .order_by_desc(tasks::Column::UpdatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Find tasks matching a set of filter criteria
+    pub async fn find_by_filter(&self, filter: &TaskFilter) -> Result<Vec<tasks::Model>, DbErr> {
+        let mut query = tasks::Entity::find();
+
+        if !filter.include_archived.unwrap_or(false) {
+            query = query.filter(tasks::Column::ArchivedAt.is_null());
+        }
+
+        if let Some(statuses) = filter.statuses.as_ref().filter(|s| !s.is_empty()) {
+            query = query.filter(tasks::Column::Status.is_in(statuses.clone()));
+        }
+
+        if let Some(min_priority) = filter.min_priority {
+            query = query.filter(tasks::Column::Priority.gte(min_priority));
+        }
+
+        if let Some(max_priority) = filter.max_priority {
+            query = query.filter(tasks::Column::Priority.lte(max_priority));
+        }
+
+        if let Some(task_list_id) = &filter.task_list_id {
+            query = query.filter(tasks::Column::TaskListId.eq(task_list_id.clone()));
+        }
+
+        if let Some(names) = filter.tags.as_ref().filter(|t| !t.is_empty()) {
+            let names = normalize_tag_names(names);
+            let mut tagged = sea_orm::sea_query::Query::select();
+            tagged
+                .column((task_tags::Entity, task_tags::Column::TaskId))
+                .from(task_tags::Entity)
+                .inner_join(
+                    tags::Entity,
+                    sea_orm::sea_query::Expr::col((tags::Entity, tags::Column::Id))
+                        .equals((task_tags::Entity, task_tags::Column::TagId)),
+                )
+                .and_where(tags::Column::Name.is_in(names.clone()));
+            if filter.match_all_tags.unwrap_or(false) {
+                tagged
+                    .group_by_col((task_tags::Entity, task_tags::Column::TaskId))
+                    .and_having(
+                        sea_orm::sea_query::Expr::col((
+                            task_tags::Entity,
+                            task_tags::Column::TagId,
+                        ))
+                        .count_distinct()
+                        .eq(names.len() as i64),
+                    );
+            }
+            query = query.filter(tasks::Column::Id.in_subquery(tagged.to_owned()));
+        }
+
+        if let Some(search) = filter.search.as_ref().filter(|s| !s.trim().is_empty()) {
+            let search_pattern = format!("%{}%", search.trim());
+            query = query.filter(
+                tasks::Column::Title
+                    .like(&search_pattern)
+                    .or(tasks::Column::Description.like(&search_pattern)),
+            );
+        }
+
+        if let Some(due_after) = filter.due_after {
+            query = query.filter(tasks::Column::DueDate.gte(due_after));
+        }
+
+        if let Some(due_before) = filter.due_before {
+            query = query.filter(tasks::Column::DueDate.lte(due_before));
+        }
+
+        match filter.is_scheduled {
+            Some(true) => query = query.filter(tasks::Column::ScheduledDate.is_not_null()),
+            Some(false) => query = query.filter(tasks::Column::ScheduledDate.is_null()),
+            None => {}
+        }
+
+        for criterion in filter.custom_fields.iter().flatten() {
+            query = query.filter(
+                tasks::Column::Id.in_subquery(
+                    sea_orm::sea_query::Query::select()
+                        .column(custom_field_values::Column::TaskId)
+                        .from(custom_field_values::Entity)
+                        .and_where(custom_field_values::Column::FieldId.eq(&criterion.field_id))
+                        .and_where(custom_field_values::Column::Value.eq(&criterion.value))
+                        .to_owned(),
+                ),
+            );
+        }
+
+        query
+            .order_by_desc(tasks::Column::Priority)
             .order_by_desc(tasks::Column::UpdatedAt)
             .all(&*self.db)
             .await
     }
 
+    /// Find active tasks carrying any (or, with `match_all`, every) of the given tags
+    pub async fn find_by_tags(
+        &self,
+        tags: &[String],
+        match_all: bool,
+    ) -> Result<Vec<tasks::Model>, DbErr> {
+        if normalize_tag_names(tags).is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.find_by_filter(&TaskFilter {
+            tags: Some(tags.to_vec()),
+            match_all_tags: Some(match_all),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Sort open tasks into Eisenhower quadrants and effort/impact buckets
+    ///
+    /// Importance comes from the impact score when set (6 or more), otherwise from a high or
+    /// urgent priority. A task is urgent when it is due within two days or has urgent priority.
+    pub async fn get_priority_matrix(
+        &self,
+        task_list_id: Option<&str>,
+    ) -> Result<PriorityMatrix, DbErr> {
+        let done = TaskStatusRepository::done_statuses_in(&*self.db).await?;
+        let mut query = tasks::Entity::find()
+            .filter(tasks::Column::Status.is_not_in(done))
+            .filter(tasks::Column::ArchivedAt.is_null());
+
+        if let Some(task_list_id) = task_list_id {
+            query = query.filter(tasks::Column::TaskListId.eq(task_list_id));
+        }
+
+        let mut open_tasks = query.all(&*self.db).await?;
+        open_tasks.sort_by(compare_by_value);
+
+        let urgent_before = chrono::Utc::now() + chrono::Duration::days(2);
+        let mut matrix = PriorityMatrix::default();
+
+        for task in open_tasks {
+            match (task.effort, task.impact) {
+                (Some(effort), Some(impact)) => {
+                    let bucket = match (impact >= 6, effort >= 6) {
+                        (true, false) => &mut matrix.effort_impact.quick_wins,
+                        (true, true) => &mut matrix.effort_impact.major_projects,
+                        (false, false) => &mut matrix.effort_impact.fill_ins,
+                        (false, true) => &mut matrix.effort_impact.thankless_tasks,
+                    };
+                    bucket.push(task.clone());
+                }
+                _ => matrix.unscored.push(task.clone()),
+            }
+
+            let important = task.impact.map_or(task.priority >= 2, |impact| impact >= 6);
+            let urgent =
+                task.priority >= 3 || task.due_date.is_some_and(|due| due <= urgent_before);
+            let quadrant = match (urgent, important) {
+                (true, true) => &mut matrix.eisenhower.do_first,
+                (false, true) => &mut matrix.eisenhower.schedule,
+                (true, false) => &mut matrix.eisenhower.delegate,
+                (false, false) => &mut matrix.eisenhower.eliminate,
+            };
+            quadrant.push(task);
+        }
+
+        Ok(matrix)
+    }
+
+    /// Recommend what to work on next: urgent and important work first, then important work,
+    /// each ordered by value score (impact per unit of effort) and priority
+    pub async fn recommend_tasks(&self, limit: usize) -> Result<Vec<tasks::Model>, DbErr> {
+        let matrix = self.get_priority_matrix(None).await?;
+
+        Ok(matrix
+            .eisenhower
+            .do_first
+            .into_iter()
+            .chain(matrix.eisenhower.schedule)
+            .chain(matrix.eisenhower.delegate)
+            .take(limit)
+            .collect())
+    }
+
+    /// Get every task, including archived ones
+    pub async fn get_all_tasks(&self) -> Result<Vec<tasks::Model>, DbErr> {
+        tasks::Entity::find()
+            .order_by_asc(tasks::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
     /// Delete all task dependencies
     pub async fn delete_all_dependencies(&self) -> Result<u64, DbErr> {
         let result = task_dependencies::Entity::delete_many()
@@ -494,6 +1802,21 @@ impl TaskRepository {
 
     /// Delete all tasks
     pub async fn delete_all_tasks(&self) -> Result<u64, DbErr> {
+        task_reminders::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
+        checklist_items::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
+        attachments::Entity::delete_many().exec(&*self.db).await?;
+        task_notes::Entity::delete_many().exec(&*self.db).await?;
+        session_subtask_completions::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
+        task_status_history::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
+        task_tags::Entity::delete_many().exec(&*self.db).await?;
         let result = tasks::Entity::delete_many().exec(&*self.db).await?;
         Ok(result.rows_affected)
     }
@@ -505,6 +1828,14 @@ impl TaskRepository {
 
     /// Import a task from backup data
     pub async fn import_task(&self, task: tasks::Model) -> Result<tasks::Model, DbErr> {
+        Self::import_task_in(&*self.db, task).await
+    }
+
+    /// Import a task on `conn`, so callers can import several rows in one transaction
+    pub async fn import_task_in<C: ConnectionTrait>(
+        conn: &C,
+        task: tasks::Model,
+    ) -> Result<tasks::Model, DbErr> {
         let active_task = tasks::ActiveModel {
             id: Set(task.id),
             title: Set(task.title),
@@ -525,27 +1856,65 @@ impl TaskRepository {
             periodic_template_id: Set(task.periodic_template_id),
             is_periodic_instance: Set(task.is_periodic_instance),
             generation_date: Set(task.generation_date),
+            effort: Set(task.effort),
+            impact: Set(task.impact),
             completed_at: Set(task.completed_at),
+            archived_at: Set(task.archived_at),
+            reschedule_count: Set(task.reschedule_count),
             created_at: Set(task.created_at),
             updated_at: Set(task.updated_at),
         };
 
-        active_task.insert(&*self.db).await
+        let task = active_task.insert(conn).await?;
+        let tags: Vec<String> = task
+            .tags
+            .as_deref()
+            .and_then(|tags| serde_json::from_str(tags).ok())
+            .unwrap_or_default();
+        TagRepository::set_task_tags(conn, &task.id, &tags).await?;
+
+        Ok(task)
     }
 
     /// Import a task dependency from backup data
     pub async fn import_dependency(
         &self,
         dependency: task_dependencies::Model,
+    ) -> Result<task_dependencies::Model, DbErr> {
+        Self::import_dependency_in(&*self.db, dependency).await
+    }
+
+    /// Import a task dependency on `conn`
+    pub async fn import_dependency_in<C: ConnectionTrait>(
+        conn: &C,
+        dependency: task_dependencies::Model,
     ) -> Result<task_dependencies::Model, DbErr> {
         let active_dependency = task_dependencies::ActiveModel {
             id: Set(dependency.id),
             task_id: Set(dependency.task_id),
             depends_on_id: Set(dependency.depends_on_id),
+            relation_type: Set(dependency.relation_type),
             created_at: Set(dependency.created_at),
         };
 
-        active_dependency.insert(&*self.db).await
+        active_dependency.insert(conn).await
+    }
+
+    /// Get the status history of all tasks for backup
+    pub async fn get_all_status_history(&self) -> Result<Vec<task_status_history::Model>, DbErr> {
+        task_status_history::Entity::find()
+            .order_by_asc(task_status_history::Column::ChangedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Import a status history entry from backup data
+    pub async fn import_status_change(
+        &self,
+        entry: task_status_history::Model,
+    ) -> Result<task_status_history::Model, DbErr> {
+        let active_entry: task_status_history::ActiveModel = entry.into();
+        active_entry.insert(&*self.db).await
     }
 
     /// Count orphaned tasks (tasks without a task_list_id)
@@ -570,3 +1939,307 @@ pub struct TaskStats {
     pub in_progress: u64,
     pub pending: u64,
 }
+
+/// Eisenhower quadrants of open tasks
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EisenhowerMatrix {
+    pub do_first: Vec<tasks::Model>,
+    pub schedule: Vec<tasks::Model>,
+    pub delegate: Vec<tasks::Model>,
+    pub eliminate: Vec<tasks::Model>,
+}
+
+/// Effort/impact buckets of open tasks that have both scores
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EffortImpactMatrix {
+    pub quick_wins: Vec<tasks::Model>,
+    pub major_projects: Vec<tasks::Model>,
+    pub fill_ins: Vec<tasks::Model>,
+    pub thankless_tasks: Vec<tasks::Model>,
+}
+
+/// Prioritization views over open tasks, each bucket ordered by value score
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PriorityMatrix {
+    pub eisenhower: EisenhowerMatrix,
+    pub effort_impact: EffortImpactMatrix,
+    /// Open tasks missing an effort or impact score
+    pub unscored: Vec<tasks::Model>,
+}
+
+/// A dependency cycle; the first task is repeated at the end to close the loop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyCycle {
+    pub task_ids: Vec<String>,
+    pub titles: Vec<String>,
+}
+
+/// Kinds of relation between two tasks, read as "task <relation> related task"
+///
+/// `depends_on` and `blocks` order work and make up the dependency graph; the others only link
+/// tasks for reference.
+pub const RELATION_TYPES: [&str; 5] = [
+    "depends_on",
+    "blocks",
+    "relates_to",
+    "duplicates",
+    "follows",
+];
+
+/// A task related to the one queried, with the kind and direction of the relation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRelation {
+    pub id: String,
+    pub relation_type: String,
+    /// `outgoing` when the queried task is the subject of the relation, `incoming` otherwise
+    pub direction: String,
+    pub task: tasks::Model,
+}
+
+/// Diagnostics for the task dependency graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyGraphReport {
+    pub task_count: u64,
+    pub dependency_count: usize,
+    pub is_valid: bool,
+    pub cycles: Vec<DependencyCycle>,
+    /// `(task_id, depends_on_id)` pairs where either task no longer exists
+    pub dangling_dependencies: Vec<(String, String)>,
+}
+
+fn validate_relation_type(relation_type: &str) -> Result<(), DbErr> {
+    if !RELATION_TYPES.contains(&relation_type) {
+        return Err(DbErr::Custom(format!(
+            "VALIDATION_ERROR: Unknown relation type '{}', expected one of: {}",
+            relation_type,
+            RELATION_TYPES.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// The `(dependent, depends_on)` edge a relation adds to the dependency graph, if any
+fn dependency_edge<'a>(
+    task_id: &'a str,
+    related_id: &'a str,
+    relation_type: &str,
+) -> Option<(&'a str, &'a str)> {
+    match relation_type {
+        "depends_on" => Some((task_id, related_id)),
+        "blocks" => Some((related_id, task_id)),
+        _ => None,
+    }
+}
+
+/// Match a relation between two tasks; `relates_to` reads the same both ways
+fn relation_condition(task_id: &str, related_id: &str, relation_type: &str) -> Condition {
+    let pair = |from: &str, to: &str| {
+        Condition::all()
+            .add(task_dependencies::Column::TaskId.eq(from))
+            .add(task_dependencies::Column::DependsOnId.eq(to))
+    };
+    let pairs = if relation_type == "relates_to" {
+        Condition::any()
+            .add(pair(task_id, related_id))
+            .add(pair(related_id, task_id))
+    } else {
+        pair(task_id, related_id)
+    };
+    pairs.add(task_dependencies::Column::RelationType.eq(relation_type))
+}
+
+/// The task at the other end of a relation from `task_id`
+fn other_end<'a>(relation: &'a task_dependencies::Model, task_id: &str) -> &'a str {
+    if relation.task_id == task_id {
+        &relation.depends_on_id
+    } else {
+        &relation.task_id
+    }
+}
+
+/// Find a chain of dependencies leading from `from` to `to`, both ends included
+fn find_dependency_path(
+    edges: &HashMap<String, Vec<String>>,
+    from: &str,
+    to: &str,
+) -> Option<Vec<String>> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue = std::collections::VecDeque::from([from]);
+    let mut visited = HashSet::from([from]);
+
+    while let Some(current) = queue.pop_front() {
+        if current == to {
+            let mut path = vec![to.to_string()];
+            let mut node = to;
+            while let Some(&prev) = previous.get(node) {
+                path.push(prev.to_string());
+                node = prev;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in edges.get(current).into_iter().flatten() {
+            if visited.insert(next.as_str()) {
+                previous.insert(next.as_str(), current);
+                queue.push_back(next.as_str());
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the dependency cycles in the graph, one per back edge found by a depth-first search
+fn find_dependency_cycles(edges: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    fn visit<'a>(
+        node: &'a str,
+        edges: &'a HashMap<String, Vec<String>>,
+        stack: &mut Vec<&'a str>,
+        on_stack: &mut HashSet<&'a str>,
+        done: &mut HashSet<&'a str>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        stack.push(node);
+        on_stack.insert(node);
+
+        for next in edges.get(node).into_iter().flatten() {
+            let next = next.as_str();
+            if on_stack.contains(next) {
+                let start = stack.iter().position(|&n| n == next).unwrap_or(0);
+                let mut cycle: Vec<String> = stack[start..].iter().map(|n| n.to_string()).collect();
+                cycle.push(next.to_string());
+                cycles.push(cycle);
+            } else if !done.contains(next) {
+                visit(next, edges, stack, on_stack, done, cycles);
+            }
+        }
+
+        stack.pop();
+        on_stack.remove(node);
+        done.insert(node);
+    }
+
+    let mut roots: Vec<&String> = edges.keys().collect();
+    roots.sort();
+
+    let mut cycles = Vec::new();
+    let mut done = HashSet::new();
+    for root in roots {
+        if !done.contains(root.as_str()) {
+            visit(
+                root,
+                edges,
+                &mut Vec::new(),
+                &mut HashSet::new(),
+                &mut done,
+                &mut cycles,
+            );
+        }
+    }
+    cycles
+}
+
+/// Impact gained per unit of effort, if both scores are set
+pub fn value_score(task: &tasks::Model) -> Option<f64> {
+    match (task.effort, task.impact) {
+        (Some(effort), Some(impact)) if effort > 0 => Some(impact as f64 / effort as f64),
+        _ => None,
+    }
+}
+
+/// Order by value score (scored tasks first), then priority, then due date
+fn compare_by_value(a: &tasks::Model, b: &tasks::Model) -> std::cmp::Ordering {
+    let score = |t: &tasks::Model| value_score(t).unwrap_or(-1.0);
+    score(b)
+        .total_cmp(&score(a))
+        .then_with(|| b.priority.cmp(&a.priority))
+        .then_with(|| match (a.due_date, b.due_date) {
+            (Some(a_due), Some(b_due)) => a_due.cmp(&b_due),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        })
+}
+
+fn validate_score(name: &str, score: Option<i32>) -> Result<(), DbErr> {
+    match score {
+        Some(value) if !(1..=10).contains(&value) => Err(DbErr::Custom(format!(
+            "VALIDATION_ERROR: {} must be between 1 and 10",
+            name
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Marker preceding the current task state in a conflict error
+pub const CONFLICT_STATE_MARKER: &str = "Current state: ";
+
+/// Build the error returned when a task changed after the client read it
+///
+/// The current row is embedded as JSON so the frontend can merge instead of overwriting.
+fn conflict_error(current: &tasks::Model) -> DbErr {
+    DbErr::Custom(format!(
+        "CONFLICT_ERROR: Task was modified since it was loaded. {}{}",
+        CONFLICT_STATE_MARKER,
+        serde_json::to_string(current).unwrap_or_default()
+    ))
+}
+
+/// Tag names stored on a task row
+fn stored_tags(task: &tasks::Model) -> Vec<String> {
+    task.tags
+        .as_deref()
+        .and_then(|tags| serde_json::from_str(tags).ok())
+        .unwrap_or_default()
+}
+
+/// Map an update value to the stored score, treating 0 as "clear"
+fn optional_score(name: &str, score: i32) -> Result<Option<i32>, DbErr> {
+    if score == 0 {
+        return Ok(None);
+    }
+    validate_score(name, Some(score))?;
+    Ok(Some(score))
+}
+
+/// Criteria for querying tasks, also persisted by saved filters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskFilter {
+    pub statuses: Option<Vec<String>>,
+    pub min_priority: Option<i32>,
+    pub max_priority: Option<i32>,
+    pub task_list_id: Option<String>,
+    pub tags: Option<Vec<String>>, // Matches tasks carrying any of the tags
+    pub match_all_tags: Option<bool>, // Require every tag instead of any
+    pub search: Option<String>,
+    pub due_after: Option<chrono::DateTime<chrono::Utc>>,
+    pub due_before: Option<chrono::DateTime<chrono::Utc>>,
+    pub is_scheduled: Option<bool>,
+    pub custom_fields: Option<Vec<CustomFieldCriterion>>,
+    pub include_archived: Option<bool>,
+}
+
+/// Matches tasks whose custom field holds exactly the given (normalized) value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldCriterion {
+    pub field_id: String,
+    pub value: String,
+}
+
+/// Aggregated progress of a task and all of its descendants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRollup {
+    pub total_subtasks: u64,
+    pub completed_subtasks: u64,
+    pub completion_percentage: f64,
+    pub total_time_estimate: i32,
+    pub total_actual_time: i32,
+}
+
+/// A task with its nested subtasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTreeNode {
+    pub task: tasks::Model,
+    pub children: Vec<TaskTreeNode>,
+    pub rollup: TaskRollup,
+}
@@ -0,0 +1,105 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait, Set};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{task_sources, tasks};
+
+/// Kinds of origin a task can record
+pub const TASK_SOURCE_TYPES: [&str; 9] = [
+    "csv",
+    "markdown",
+    "todoist",
+    "toggl",
+    "clockify",
+    "github",
+    "caldav",
+    "email",
+    "deep_link",
+];
+
+/// Request structure for recording where a task came from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordTaskSourceRequest {
+    pub task_id: String,
+    pub source_type: String,
+    pub external_id: Option<String>,
+    pub url: Option<String>,
+}
+
+impl RecordTaskSourceRequest {
+    /// Source of a task imported from a line of a file
+    pub fn file_line(task_id: &str, source_type: &str, file_path: &str, line: usize) -> Self {
+        Self {
+            task_id: task_id.to_string(),
+            source_type: source_type.to_string(),
+            external_id: Some(format!("line {}", line)),
+            url: Some(file_path.to_string()),
+        }
+    }
+}
+
+/// Task source repository for SeaORM-based database operations
+pub struct TaskSourceRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TaskSourceRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Record the origin of a task, replacing any earlier record
+    pub async fn record_source(
+        &self,
+        request: RecordTaskSourceRequest,
+    ) -> Result<task_sources::Model, DbErr> {
+        let source_type = request.source_type.trim().to_string();
+        if !TASK_SOURCE_TYPES.contains(&source_type.as_str()) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Unknown task source '{}'. Expected one of: {}",
+                source_type,
+                TASK_SOURCE_TYPES.join(", ")
+            )));
+        }
+        let external_id = request
+            .external_id
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty());
+        let url = request
+            .url
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty());
+
+        tasks::Entity::find_by_id(&request.task_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        match self.find_by_task(&request.task_id).await? {
+            Some(existing) => {
+                let mut source: task_sources::ActiveModel = existing.into();
+                source.source_type = Set(source_type);
+                source.external_id = Set(external_id);
+                source.url = Set(url);
+                source.update(&*self.db).await
+            }
+            None => {
+                let source = task_sources::ActiveModel {
+                    task_id: Set(request.task_id),
+                    source_type: Set(source_type),
+                    external_id: Set(external_id),
+                    url: Set(url),
+                    ..Default::default()
+                };
+                source.insert(&*self.db).await
+            }
+        }
+    }
+
+    /// Find where a task came from; tasks created in the app have no source
+    pub async fn find_by_task(&self, task_id: &str) -> Result<Option<task_sources::Model>, DbErr> {
+        task_sources::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await
+    }
+}
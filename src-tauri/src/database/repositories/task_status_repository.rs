@@ -0,0 +1,331 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::database::entities::{task_statuses, tasks};
+
+/// Categories a status can belong to
+pub const STATUS_CATEGORIES: [&str; 3] = ["todo", "doing", "done"];
+
+/// Built-in status of the done category that closes a task without completing it
+pub const CANCELLED_STATUS: &str = "cancelled";
+
+/// Request structure for creating a new task status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaskStatusRequest {
+    pub name: String,
+    pub category: String,
+    pub color: Option<String>,
+    pub order_num: Option<i32>,
+}
+
+/// Request structure for updating an existing task status
+///
+/// Built-in statuses only accept color and order changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaskStatusRequest {
+    pub name: Option<String>,
+    pub category: Option<String>,
+    pub color: Option<String>,
+    pub order_num: Option<i32>,
+}
+
+/// Task status repository for SeaORM-based database operations
+pub struct TaskStatusRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TaskStatusRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new custom status
+    pub async fn create_status(
+        &self,
+        request: CreateTaskStatusRequest,
+    ) -> Result<task_statuses::Model, DbErr> {
+        let name = Self::validate_name(&request.name)?;
+        Self::validate_category(&request.category)?;
+        let color = Self::validate_color(request.color)?;
+
+        if self.find_by_name(&name).await?.is_some() {
+            return Err(DbErr::Custom(format!(
+                "DUPLICATE_ERROR: A status named '{}' already exists",
+                name
+            )));
+        }
+
+        let order_num = match request.order_num {
+            Some(order_num) => order_num,
+            None => task_statuses::Entity::find().count(&*self.db).await? as i32,
+        };
+
+        let status = task_statuses::ActiveModel {
+            name: Set(name),
+            category: Set(request.category),
+            color: Set(color),
+            order_num: Set(order_num),
+            is_system: Set(false),
+            ..Default::default()
+        };
+
+        status.insert(&*self.db).await
+    }
+
+    /// Find a status by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<task_statuses::Model>, DbErr> {
+        task_statuses::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find a status by the name stored on tasks
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<task_statuses::Model>, DbErr> {
+        task_statuses::Entity::find()
+            .filter(task_statuses::Column::Name.eq(name))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find all statuses in display order
+    pub async fn find_all(&self) -> Result<Vec<task_statuses::Model>, DbErr> {
+        task_statuses::Entity::find()
+            .order_by_asc(task_statuses::Column::OrderNum)
+            .order_by_asc(task_statuses::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Names of the statuses in the done category, which close a task
+    pub async fn done_statuses_in<C: ConnectionTrait>(conn: &C) -> Result<HashSet<String>, DbErr> {
+        Ok(task_statuses::Entity::find()
+            .filter(task_statuses::Column::Category.eq("done"))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|status| status.name)
+            .collect())
+    }
+
+    /// Names of the statuses that complete a task: the done category except [`CANCELLED_STATUS`]
+    pub async fn completed_statuses_in<C: ConnectionTrait>(
+        conn: &C,
+    ) -> Result<HashSet<String>, DbErr> {
+        let mut statuses = Self::done_statuses_in(conn).await?;
+        statuses.remove(CANCELLED_STATUS);
+        Ok(statuses)
+    }
+
+    /// Whether a task in `status` is completed, see [`Self::completed_statuses_in`]
+    pub async fn is_completed_in<C: ConnectionTrait>(
+        conn: &C,
+        status: &str,
+    ) -> Result<bool, DbErr> {
+        let statuses = Self::completed_statuses_in(conn).await?;
+        Ok(statuses.contains(status))
+    }
+
+    /// Name of the status reopened tasks go back to: the first status of the todo category
+    pub async fn reopen_status(&self) -> Result<String, DbErr> {
+        let status = task_statuses::Entity::find()
+            .filter(task_statuses::Column::Category.eq("todo"))
+            .order_by_asc(task_statuses::Column::OrderNum)
+            .order_by_asc(task_statuses::Column::Name)
+            .one(&*self.db)
+            .await?;
+        Ok(status.map_or_else(|| "pending".to_string(), |status| status.name))
+    }
+
+    /// Update a status; renaming a custom status also renames it on its tasks
+    pub async fn update_status(
+        &self,
+        id: &str,
+        request: UpdateTaskStatusRequest,
+    ) -> Result<task_statuses::Model, DbErr> {
+        let status = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task status not found".to_string()))?;
+
+        if status.is_system && (request.name.is_some() || request.category.is_some()) {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Built-in statuses cannot be renamed or recategorized"
+                    .to_string(),
+            ));
+        }
+
+        let old_name = status.name.clone();
+        let new_name = match request.name {
+            Some(name) => Some(Self::validate_name(&name)?).filter(|name| *name != old_name),
+            None => None,
+        };
+        if let Some(name) = &new_name {
+            if self.find_by_name(name).await?.is_some() {
+                return Err(DbErr::Custom(format!(
+                    "DUPLICATE_ERROR: A status named '{}' already exists",
+                    name
+                )));
+            }
+        }
+
+        let txn = self.db.begin().await?;
+        let mut active: task_statuses::ActiveModel = status.into();
+
+        if let Some(name) = new_name {
+            tasks::Entity::update_many()
+                .col_expr(
+                    tasks::Column::Status,
+                    sea_orm::sea_query::Expr::value(name.clone()),
+                )
+                .filter(tasks::Column::Status.eq(&old_name))
+                .exec(&txn)
+                .await?;
+            active.name = Set(name);
+        }
+        if let Some(category) = request.category {
+            Self::validate_category(&category)?;
+            active.category = Set(category);
+        }
+        if request.color.is_some() {
+            active.color = Set(Self::validate_color(request.color)?);
+        }
+        if let Some(order_num) = request.order_num {
+            active.order_num = Set(order_num);
+        }
+        active.updated_at = Set(chrono::Utc::now());
+
+        let updated = active.update(&txn).await?;
+        txn.commit().await?;
+
+        Ok(updated)
+    }
+
+    /// Delete a custom status, moving its tasks to `replacement` when given
+    ///
+    /// Fails if tasks still use the status and no replacement is provided.
+    pub async fn delete_status(&self, id: &str, replacement: Option<&str>) -> Result<u64, DbErr> {
+        let status = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task status not found".to_string()))?;
+
+        if status.is_system {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Built-in statuses cannot be deleted".to_string(),
+            ));
+        }
+
+        let in_use = tasks::Entity::find()
+            .filter(tasks::Column::Status.eq(&status.name))
+            .count(&*self.db)
+            .await?;
+
+        let replacement = if in_use > 0 {
+            let replacement = replacement.ok_or_else(|| {
+                DbErr::Custom(format!(
+                    "BUSINESS_RULE_ERROR: Status '{}' is used by {} task(s); choose a replacement status",
+                    status.name, in_use
+                ))
+            })?;
+            if replacement == status.name || self.find_by_name(replacement).await?.is_none() {
+                return Err(DbErr::Custom(format!(
+                    "VALIDATION_ERROR: Invalid replacement status '{}'",
+                    replacement
+                )));
+            }
+            Some(replacement)
+        } else {
+            None
+        };
+
+        let txn = self.db.begin().await?;
+
+        if let Some(replacement) = replacement {
+            tasks::Entity::update_many()
+                .col_expr(
+                    tasks::Column::Status,
+                    sea_orm::sea_query::Expr::value(replacement.to_string()),
+                )
+                .col_expr(
+                    tasks::Column::UpdatedAt,
+                    sea_orm::sea_query::Expr::value(chrono::Utc::now()),
+                )
+                .filter(tasks::Column::Status.eq(&status.name))
+                .exec(&txn)
+                .await?;
+        }
+
+        task_statuses::Entity::delete_by_id(id).exec(&txn).await?;
+        txn.commit().await?;
+
+        Ok(in_use)
+    }
+
+    /// Import a status from backup data, keeping an existing status with the same name
+    pub async fn import_status(&self, status: task_statuses::Model) -> Result<(), DbErr> {
+        if self.find_by_name(&status.name).await?.is_some() {
+            return Ok(());
+        }
+
+        let active_status = task_statuses::ActiveModel {
+            id: Set(status.id),
+            name: Set(status.name),
+            category: Set(status.category),
+            color: Set(status.color),
+            order_num: Set(status.order_num),
+            is_system: Set(status.is_system),
+            created_at: Set(status.created_at),
+            updated_at: Set(status.updated_at),
+        };
+
+        active_status.insert(&*self.db).await?;
+        Ok(())
+    }
+
+    fn validate_name(name: &str) -> Result<String, DbErr> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Status name cannot be empty".to_string(),
+            ));
+        }
+        if name.len() > 50 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Status name cannot exceed 50 characters".to_string(),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    fn validate_category(category: &str) -> Result<(), DbErr> {
+        if !STATUS_CATEGORIES.contains(&category) {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Status category must be one of {}",
+                STATUS_CATEGORIES.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Accept `#rrggbb` colors; an empty string clears the color
+    fn validate_color(color: Option<String>) -> Result<Option<String>, DbErr> {
+        match color.map(|c| c.trim().to_lowercase()) {
+            Some(color) if color.is_empty() => Ok(None),
+            Some(color)
+                if color.len() == 7
+                    && color.starts_with('#')
+                    && color[1..].chars().all(|c| c.is_ascii_hexdigit()) =>
+            {
+                Ok(Some(color))
+            }
+            Some(color) => Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Invalid status color '{}'",
+                color
+            ))),
+            None => Ok(None),
+        }
+    }
+}
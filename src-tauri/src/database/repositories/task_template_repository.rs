@@ -0,0 +1,296 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{task_templates, tasks};
+use crate::database::repositories::task_repository::{
+    CreateTaskRequest, TaskRepository, TaskTreeNode,
+};
+
+/// A task blueprint stored inside a task template, with nested subtasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTemplateItem {
+    pub title: String,
+    pub description: Option<String>,
+    #[serde(default = "default_priority")]
+    pub priority: i32,
+    #[serde(default)]
+    pub time_estimate: i32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub effort: Option<i32>,
+    #[serde(default)]
+    pub impact: Option<i32>,
+    #[serde(default)]
+    pub subtasks: Vec<TaskTemplateItem>,
+}
+
+impl TaskTemplateItem {
+    /// Capture a task tree as a blueprint; progress (status, time spent, dates) is left out
+    fn from_tree(node: &TaskTreeNode) -> Self {
+        Self {
+            title: node.task.title.clone(),
+            description: node.task.description.clone(),
+            priority: node.task.priority,
+            time_estimate: node.task.time_estimate,
+            tags: node
+                .task
+                .tags
+                .as_deref()
+                .and_then(|tags| serde_json::from_str(tags).ok())
+                .unwrap_or_default(),
+            effort: node.task.effort,
+            impact: node.task.impact,
+            subtasks: node.children.iter().map(Self::from_tree).collect(),
+        }
+    }
+
+    fn to_create_request(&self, task_list_id: Option<String>) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            priority: self.priority,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: Some(self.time_estimate),
+            due_date: None,
+            scheduled_date: None,
+            tags: Some(self.tags.clone()),
+            project_id: None,
+            parent_task_id: None,
+            task_list_id,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: self.effort,
+            impact: self.impact,
+        }
+    }
+}
+
+fn default_priority() -> i32 {
+    1
+}
+
+/// Request structure for creating a new task template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaskTemplateRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub content: TaskTemplateItem,
+}
+
+/// Request structure for updating an existing task template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaskTemplateRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub content: Option<TaskTemplateItem>,
+}
+
+/// Request structure for saving an existing task (and its subtasks) as a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveTaskAsTemplateRequest {
+    pub task_id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Options applied to the root task when instantiating a template
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstantiateTaskTemplateRequest {
+    pub task_list_id: Option<String>,
+    pub title: Option<String>, // Overrides the template's root title
+    pub scheduled_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub due_date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Task template repository for SeaORM-based database operations
+pub struct TaskTemplateRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TaskTemplateRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Create a new task template
+    pub async fn create_template(
+        &self,
+        request: CreateTaskTemplateRequest,
+    ) -> Result<task_templates::Model, DbErr> {
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Task template name cannot be empty".to_string(),
+            ));
+        }
+
+        let template = task_templates::ActiveModel {
+            name: Set(name),
+            description: Set(request.description),
+            content: Set(Self::encode_content(&request.content)?),
+            ..Default::default()
+        };
+
+        template.insert(&*self.db).await
+    }
+
+    /// Find a task template by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<task_templates::Model>, DbErr> {
+        task_templates::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find a task template by its exact name
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<task_templates::Model>, DbErr> {
+        task_templates::Entity::find()
+            .filter(task_templates::Column::Name.eq(name))
+            .one(&*self.db)
+            .await
+    }
+
+    /// Find all task templates ordered by name
+    pub async fn find_all(&self) -> Result<Vec<task_templates::Model>, DbErr> {
+        task_templates::Entity::find()
+            .order_by_asc(task_templates::Column::Name)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Update a task template
+    pub async fn update_template(
+        &self,
+        id: &str,
+        request: UpdateTaskTemplateRequest,
+    ) -> Result<task_templates::Model, DbErr> {
+        let template = task_templates::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task template not found".to_string()))?;
+
+        let mut template: task_templates::ActiveModel = template.into();
+
+        if let Some(name) = request.name {
+            template.name = Set(name.trim().to_string());
+        }
+        if let Some(description) = request.description {
+            template.description = Set(Some(description));
+        }
+        if let Some(content) = request.content {
+            template.content = Set(Self::encode_content(&content)?);
+        }
+
+        template.updated_at = Set(chrono::Utc::now());
+
+        template.update(&*self.db).await
+    }
+
+    /// Save an existing task, including its subtasks, as a new template
+    pub async fn save_task_as_template(
+        &self,
+        request: SaveTaskAsTemplateRequest,
+    ) -> Result<task_templates::Model, DbErr> {
+        let tree = TaskRepository::new(self.db.clone())
+            .get_task_tree(&request.task_id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task not found".to_string()))?;
+
+        self.create_template(CreateTaskTemplateRequest {
+            name: request.name,
+            description: request.description,
+            content: TaskTemplateItem::from_tree(&tree),
+        })
+        .await
+    }
+
+    /// Create a task tree from a template, returning the root task
+    ///
+    /// If creating any task fails, the tasks created so far are removed again.
+    pub async fn instantiate_template(
+        &self,
+        template_id: &str,
+        request: InstantiateTaskTemplateRequest,
+    ) -> Result<tasks::Model, DbErr> {
+        let template = self
+            .find_by_id(template_id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Task template not found".to_string()))?;
+        let content = Self::parse_content(&template)?;
+
+        let task_repo = TaskRepository::new(self.db.clone());
+        let mut root_request = content.to_create_request(request.task_list_id);
+        if let Some(title) = request.title.filter(|t| !t.trim().is_empty()) {
+            root_request.title = title;
+        }
+        root_request.scheduled_date = request.scheduled_date;
+        root_request.due_date = request.due_date;
+
+        let root = task_repo.create_task(root_request).await?;
+        let mut created = vec![root.id.clone()];
+
+        // Breadth-first so every parent exists before its subtasks
+        let mut pending: Vec<(String, &TaskTemplateItem)> = content
+            .subtasks
+            .iter()
+            .map(|item| (root.id.clone(), item))
+            .collect();
+        while !pending.is_empty() {
+            let mut next = Vec::new();
+            for (order, (parent_id, item)) in pending.into_iter().enumerate() {
+                let mut subtask_request = item.to_create_request(None);
+                subtask_request.order_num = Some(order as i32);
+
+                match task_repo.create_subtask(&parent_id, subtask_request).await {
+                    Ok(task) => {
+                        created.push(task.id.clone());
+                        next.extend(item.subtasks.iter().map(|child| (task.id.clone(), child)));
+                    }
+                    Err(e) => {
+                        for id in created.iter().rev() {
+                            let _ = task_repo.delete_task(id).await;
+                        }
+                        return Err(e);
+                    }
+                }
+            }
+            pending = next;
+        }
+
+        Ok(root)
+    }
+
+    /// Delete a task template
+    pub async fn delete_template(&self, id: &str) -> Result<(), DbErr> {
+        task_templates::Entity::delete_by_id(id)
+            .exec(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Decode the task blueprint stored on a template
+    pub fn parse_content(template: &task_templates::Model) -> Result<TaskTemplateItem, DbErr> {
+        serde_json::from_str(&template.content).map_err(|e| {
+            DbErr::Custom(format!(
+                "Invalid content in task template '{}': {}",
+                template.name, e
+            ))
+        })
+    }
+
+    fn encode_content(content: &TaskTemplateItem) -> Result<String, DbErr> {
+        if content.title.trim().is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Task template title cannot be empty".to_string(),
+            ));
+        }
+
+        serde_json::to_string(content)
+            .map_err(|e| DbErr::Custom(format!("Failed to serialize task template: {}", e)))
+    }
+}
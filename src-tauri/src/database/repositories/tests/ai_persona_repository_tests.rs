@@ -0,0 +1,129 @@
+use crate::database::repositories::ai_persona_repository::{
+    AiPersonaRepository, CreateAiPersonaRequest, UpdateAiPersonaRequest,
+};
+use crate::database::repositories::thread_repository::{CreateThreadRequest, ThreadRepository};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+
+    fn persona_request(name: &str) -> CreateAiPersonaRequest {
+        CreateAiPersonaRequest {
+            name: name.to_string(),
+            system_prompt: "Plan the week with me and keep answers short.".to_string(),
+            tone: "professional".to_string(),
+            allowed_tools: Some(vec![
+                "get_tasks".to_string(),
+                " create_task ".to_string(),
+                "get_tasks".to_string(),
+            ]),
+            default_model: Some(" ".to_string()),
+        }
+    }
+
+    fn empty_update() -> UpdateAiPersonaRequest {
+        UpdateAiPersonaRequest {
+            name: None,
+            system_prompt: None,
+            tone: None,
+            allowed_tools: None,
+            allow_all_tools: false,
+            default_model: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_persona_crud() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiPersonaRepository::new(db);
+
+        let persona = repo
+            .create_persona(persona_request(" Planner "))
+            .await
+            .unwrap();
+        assert_eq!(persona.name, "Planner");
+        assert_eq!(
+            persona.allowed_tools.as_deref(),
+            Some(r#"["get_tasks","create_task"]"#)
+        );
+        assert_eq!(persona.default_model, None);
+
+        assert!(repo
+            .create_persona(persona_request("Planner"))
+            .await
+            .is_err());
+        let mut bad_tone = persona_request("Coach");
+        bad_tone.tone = "sarcastic".to_string();
+        assert!(repo.create_persona(bad_tone).await.is_err());
+        let mut no_prompt = persona_request("Coach");
+        no_prompt.system_prompt = "  ".to_string();
+        assert!(repo.create_persona(no_prompt).await.is_err());
+
+        let coach = repo.create_persona(persona_request("Coach")).await.unwrap();
+        let names: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, vec!["Coach", "Planner"]);
+
+        let mut rename = empty_update();
+        rename.name = Some("Coach".to_string());
+        assert!(repo.update_persona(&persona.id, rename).await.is_err());
+
+        let mut update = empty_update();
+        update.tone = Some("casual".to_string());
+        update.default_model = Some("gemini-2.5-pro".to_string());
+        update.allow_all_tools = true;
+        let updated = repo.update_persona(&persona.id, update).await.unwrap();
+        assert_eq!(updated.tone, "casual");
+        assert_eq!(updated.default_model.as_deref(), Some("gemini-2.5-pro"));
+        assert_eq!(updated.allowed_tools, None);
+        assert_eq!(updated.system_prompt, persona.system_prompt);
+
+        repo.delete_persona(&coach.id).await.unwrap();
+        assert!(repo.find_by_id(&coach.id).await.unwrap().is_none());
+        assert!(repo.delete_persona(&coach.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_thread_persona() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiPersonaRepository::new(db.clone());
+        let thread = ThreadRepository::new(db)
+            .create_thread(CreateThreadRequest {
+                assignment_type: Some("general".to_string()),
+                assignment_task_id: None,
+                assignment_date: None,
+                assignment_context: None,
+            })
+            .await
+            .unwrap();
+        assert!(repo.find_for_thread(&thread.id).await.unwrap().is_none());
+
+        let persona = repo
+            .create_persona(persona_request("Planner"))
+            .await
+            .unwrap();
+        assert!(repo
+            .set_thread_persona(&thread.id, Some("missing".to_string()))
+            .await
+            .is_err());
+        let updated = repo
+            .set_thread_persona(&thread.id, Some(persona.id.clone()))
+            .await
+            .unwrap();
+        assert_eq!(updated.persona_id.as_deref(), Some(persona.id.as_str()));
+        let found = repo.find_for_thread(&thread.id).await.unwrap().unwrap();
+        assert_eq!(found.id, persona.id);
+
+        // Deleting the persona moves the thread back to plain Kira
+        repo.delete_persona(&persona.id).await.unwrap();
+        assert!(repo.find_for_thread(&thread.id).await.unwrap().is_none());
+        let cleared = repo.set_thread_persona(&thread.id, None).await.unwrap();
+        assert_eq!(cleared.persona_id, None);
+    }
+}
@@ -0,0 +1,445 @@
+use crate::database::repositories::ai_repository::{
+    estimate_cost, response_similarity, AiRepository, CreateAiInteractionLogRequest,
+    CreateAiInteractionRequest, CreateReplayComparisonRequest, CreateToolExecutionLogRequest,
+    InteractionLogFilter, RecordProviderRequest, UpdateAiInteractionLogRequest,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+
+    fn log_request(message: &str, response: &str) -> CreateAiInteractionLogRequest {
+        CreateAiInteractionLogRequest {
+            session_id: "session-1".to_string(),
+            model_type: "gemini".to_string(),
+            model_info: serde_json::json!({}),
+            user_message: message.to_string(),
+            system_prompt: None,
+            context: "{}".to_string(),
+            ai_response: response.to_string(),
+            actions: "[]".to_string(),
+            suggestions: "[]".to_string(),
+            reasoning: None,
+            response_time: 1200,
+            token_count: None,
+            error: None,
+            error_code: None,
+            contains_sensitive_data: false,
+            data_classification: "internal".to_string(),
+        }
+    }
+
+    fn tool_request(log_id: &str, tool_name: &str, result: &str) -> CreateToolExecutionLogRequest {
+        CreateToolExecutionLogRequest {
+            interaction_log_id: log_id.to_string(),
+            tool_name: tool_name.to_string(),
+            arguments: r#"{"status":"todo"}"#.to_string(),
+            result: result.to_string(),
+            execution_time: 15,
+            success: true,
+            error: None,
+        }
+    }
+
+    async fn log_ids(repo: &AiRepository, filter: InteractionLogFilter) -> Vec<String> {
+        let logs = repo.find_interaction_logs(filter).await.unwrap();
+        logs.into_iter().map(|log| log.interaction.id).collect()
+    }
+
+    fn comparison_request(log_id: &str, response: &str) -> CreateReplayComparisonRequest {
+        CreateReplayComparisonRequest {
+            interaction_log_id: log_id.to_string(),
+            replay_model: "gemini-2.5-flash".to_string(),
+            replay_response: response.to_string(),
+            mocked_tool_calls: 1,
+            unmatched_tool_calls: 0,
+            response_time: Some(900),
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interaction_replay_includes_logged_tool_results() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+
+        let log = repo
+            .create_interaction_log(log_request("What is on my list?", "You have 2 tasks."))
+            .await
+            .unwrap();
+        repo.create_tool_execution_log(tool_request(&log.id, "get_tasks", r#"{"count":2}"#))
+            .await
+            .unwrap();
+        let other = repo
+            .create_interaction_log(log_request("Hello", "Hi!"))
+            .await
+            .unwrap();
+        repo.create_tool_execution_log(tool_request(&other.id, "start_timer", "{}"))
+            .await
+            .unwrap();
+
+        let replay = repo.get_interaction_replay(&log.id).await.unwrap();
+        assert_eq!(replay.model_type, "gemini");
+        assert_eq!(replay.session_id, "session-1");
+        assert_eq!(replay.user_message, "What is on my list?");
+        assert_eq!(replay.original_response, "You have 2 tasks.");
+        assert_eq!(replay.tool_results.len(), 1);
+        assert_eq!(replay.tool_results[0].tool_name, "get_tasks");
+        assert_eq!(replay.tool_results[0].result, r#"{"count":2}"#);
+        assert!(replay.tool_results[0].success);
+    }
+
+    #[tokio::test]
+    async fn test_interaction_log_keeps_model_info() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+
+        let model_info = serde_json::json!({
+            "name": "llama3.2:latest",
+            "provider": "local",
+            "failover": { "from": "gemini", "reason": "Generation timed out after 120 seconds" },
+        });
+        let log = repo
+            .create_interaction_log(CreateAiInteractionLogRequest {
+                model_info: model_info.clone(),
+                ..log_request("What is due?", "Nothing today.")
+            })
+            .await
+            .unwrap();
+
+        let stored: serde_json::Value =
+            serde_json::from_str(log.model_info.as_deref().unwrap()).unwrap();
+        assert_eq!(stored, model_info);
+    }
+
+    #[tokio::test]
+    async fn test_ai_stats_include_reported_token_counts() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+
+        let log = repo
+            .create_interaction_log(log_request("What is due?", ""))
+            .await
+            .unwrap();
+        let log = repo
+            .update_interaction_log(
+                &log.id,
+                UpdateAiInteractionLogRequest {
+                    ai_response: Some("Nothing today.".to_string()),
+                    actions: None,
+                    suggestions: None,
+                    reasoning: None,
+                    response_time: Some(900),
+                    token_count: Some(850),
+                    error: None,
+                    error_code: None,
+                    contains_sensitive_data: None,
+                    data_classification: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(log.token_count, Some(850));
+        repo.create_interaction_log(CreateAiInteractionLogRequest {
+            token_count: Some(150),
+            ..log_request("Hello", "Hi!")
+        })
+        .await
+        .unwrap();
+        repo.create_interaction_log(log_request("Unreported", "No count"))
+            .await
+            .unwrap();
+
+        let stats = repo.get_ai_stats().await.unwrap();
+        assert_eq!(stats.total_interactions, 3);
+        assert_eq!(stats.total_tokens, 1000);
+        assert_eq!(stats.average_tokens, 500.0);
+    }
+
+    #[tokio::test]
+    async fn test_only_interaction_logs_can_be_replayed() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+
+        let interaction = repo
+            .create_interaction(CreateAiInteractionRequest {
+                message: "List tasks".to_string(),
+                response: "Done".to_string(),
+                action_taken: None,
+                reasoning: None,
+                tools_used: None,
+                confidence: None,
+            })
+            .await
+            .unwrap();
+
+        let result = repo.get_interaction_replay(&interaction.id).await;
+        assert!(result.unwrap_err().to_string().contains("VALIDATION_ERROR"));
+    }
+
+    #[tokio::test]
+    async fn test_interaction_logs_are_stored_as_react_chains() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+
+        let log = repo
+            .create_interaction_log(log_request("What is on my list?", "You have 2 tasks."))
+            .await
+            .unwrap();
+        assert_eq!(log.action_taken, None);
+        repo.create_tool_execution_log(tool_request(&log.id, "get_tasks", "[]"))
+            .await
+            .unwrap();
+        repo.create_tool_execution_log(CreateToolExecutionLogRequest {
+            success: false,
+            error: Some("Timer already running".to_string()),
+            ..tool_request(&log.id, "start_timer", "{}")
+        })
+        .await
+        .unwrap();
+        assert!(repo
+            .create_tool_execution_log(tool_request("missing", "get_tasks", "[]"))
+            .await
+            .is_err());
+
+        let failed = repo
+            .create_interaction_log(CreateAiInteractionLogRequest {
+                model_type: "local".to_string(),
+                error: Some("Generation timed out".to_string()),
+                error_code: Some("TIMEOUT".to_string()),
+                ..log_request("Plan my day", "")
+            })
+            .await
+            .unwrap();
+
+        let steps = repo.find_tool_execution_logs(&log.id).await.unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].tool_name, "get_tasks");
+        assert_eq!(steps[1].tool_name, "start_timer");
+        assert_eq!(steps[1].step_index, 1);
+        assert!(!steps[1].success);
+        assert_eq!(steps[1].duration, 15);
+
+        let stored = repo.find_interaction_log(&log.id).await.unwrap().unwrap();
+        assert_eq!(stored.chain.session_id, "session-1");
+        assert_eq!(stored.chain.model_type, "gemini");
+        assert_eq!(stored.chain.duration, 1200);
+        assert_eq!(stored.interaction.message, "What is on my list?");
+
+        assert_eq!(
+            log_ids(&repo, InteractionLogFilter::default()).await,
+            vec![failed.id.clone(), log.id.clone()]
+        );
+        let local = InteractionLogFilter {
+            model_type: Some("local".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(log_ids(&repo, local).await, vec![failed.id.clone()]);
+        let with_errors = InteractionLogFilter {
+            has_errors: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(log_ids(&repo, with_errors).await, vec![failed.id.clone()]);
+        let with_tool_calls = InteractionLogFilter {
+            contains_tool_calls: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(log_ids(&repo, with_tool_calls).await, vec![log.id.clone()]);
+        let search = InteractionLogFilter {
+            search_text: Some("2 tasks".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(log_ids(&repo, search).await, vec![log.id.clone()]);
+
+        repo.delete_interaction(&log.id).await.unwrap();
+        assert!(repo.find_interaction_log(&log.id).await.unwrap().is_none());
+        let steps = repo.find_tool_execution_logs(&log.id).await.unwrap();
+        assert!(steps.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_comparisons_are_stored_and_cleaned_up() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+
+        let log = repo
+            .create_interaction_log(log_request("What is on my list?", "You have 2 tasks."))
+            .await
+            .unwrap();
+
+        let comparison = repo
+            .save_replay_comparison(comparison_request(&log.id, "You have 2 tasks today."))
+            .await
+            .unwrap();
+        assert_eq!(comparison.original_model, "gemini");
+        assert_eq!(comparison.replay_model, "gemini-2.5-flash");
+        assert_eq!(comparison.prompt, "What is on my list?");
+        assert!(comparison.similarity > 0.5 && comparison.similarity < 1.0);
+
+        let missing = repo
+            .save_replay_comparison(comparison_request("missing", "Hi"))
+            .await;
+        assert!(missing.is_err());
+
+        let comparisons = repo.find_replay_comparisons(Some(&log.id)).await.unwrap();
+        assert_eq!(comparisons.len(), 1);
+
+        repo.delete_interaction(&log.id).await.unwrap();
+        assert!(repo.find_replay_comparisons(None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_provider_metrics_per_provider() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+        let start = chrono::Utc::now() - chrono::Duration::minutes(1);
+
+        let record =
+            |provider: &str, latency: i64, success: bool, timed_out: bool| RecordProviderRequest {
+                provider: provider.to_string(),
+                model: None,
+                latency,
+                success,
+                timed_out,
+                error_code: None,
+                input_tokens: None,
+                output_tokens: None,
+            };
+        for request in [
+            record("gemini", 100, true, false),
+            record("gemini", 300, true, false),
+            record("gemini", 200, false, false),
+            record("gemini", 30000, true, true),
+            record("local", 50, true, false),
+        ] {
+            repo.record_provider_request(request).await.unwrap();
+        }
+        assert!(repo
+            .record_provider_request(record(" ", 10, true, false))
+            .await
+            .is_err());
+
+        let metrics = repo
+            .get_provider_metrics(start, chrono::Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(metrics.len(), 2);
+
+        let gemini = &metrics[0];
+        assert_eq!(gemini.provider, "gemini");
+        assert_eq!(gemini.request_count, 4);
+        assert_eq!(gemini.error_count, 2);
+        assert_eq!(gemini.timeout_count, 1);
+        assert_eq!(gemini.error_rate, 0.5);
+        assert_eq!(gemini.average_latency, 7650.0);
+        assert_eq!(gemini.p95_latency, 30000);
+
+        assert_eq!(metrics[1].provider, "local");
+        assert_eq!(metrics[1].error_rate, 0.0);
+
+        let earlier = repo
+            .get_provider_metrics(start - chrono::Duration::days(7), start)
+            .await
+            .unwrap();
+        assert!(earlier.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_estimated_spend_counts_remote_usage() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+        let start = chrono::Utc::now() - chrono::Duration::minutes(1);
+
+        let usage = |provider: &str, model: &str, input: i32, output: i32| RecordProviderRequest {
+            provider: provider.to_string(),
+            model: Some(model.to_string()),
+            latency: 100,
+            success: true,
+            timed_out: false,
+            error_code: None,
+            input_tokens: Some(input),
+            output_tokens: Some(output),
+        };
+        let metric = repo
+            .record_provider_request(usage("gemini", "gemini-2.0-flash", 1_000_000, 500_000))
+            .await
+            .unwrap();
+        assert_eq!(metric.estimated_cost, Some(0.3));
+        repo.record_provider_request(usage("gemini", "Gemini-2.5-Pro", 200_000, 100_000))
+            .await
+            .unwrap();
+        repo.record_provider_request(usage("local", "llama", 1_000_000, 1_000_000))
+            .await
+            .unwrap();
+
+        let spend = repo
+            .get_estimated_spend(start, chrono::Utc::now())
+            .await
+            .unwrap();
+        assert!((spend - 1.55).abs() < 1e-9, "{}", spend);
+
+        assert_eq!(estimate_cost("gemini", None, None, None), None);
+        assert_eq!(
+            estimate_cost("ollama", Some("llama3.2"), Some(1_000_000), None),
+            None
+        );
+        assert_eq!(
+            estimate_cost("gemini", Some("unknown"), Some(1_000_000), None),
+            Some(0.3)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cost_breakdown_groups_by_model() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db);
+        let start = chrono::Utc::now() - chrono::Duration::minutes(1);
+
+        let usage = |provider: &str, model: &str, input: i32, output: i32| RecordProviderRequest {
+            provider: provider.to_string(),
+            model: Some(model.to_string()),
+            latency: 100,
+            success: true,
+            timed_out: false,
+            error_code: None,
+            input_tokens: Some(input),
+            output_tokens: Some(output),
+        };
+        for _ in 0..2 {
+            repo.record_provider_request(usage("gemini", "gemini-2.0-flash", 500_000, 250_000))
+                .await
+                .unwrap();
+        }
+        repo.record_provider_request(usage("ollama", "llama3.2", 1_000, 500))
+            .await
+            .unwrap();
+
+        let breakdown = repo
+            .get_cost_breakdown(start, chrono::Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(breakdown.len(), 2);
+
+        let flash = &breakdown[0];
+        assert_eq!(flash.provider, "gemini");
+        assert_eq!(flash.model.as_deref(), Some("gemini-2.0-flash"));
+        assert_eq!(flash.request_count, 2);
+        assert_eq!(flash.input_tokens, 1_000_000);
+        assert_eq!(flash.output_tokens, 500_000);
+        assert!((flash.cost - 0.3).abs() < 1e-9, "{}", flash.cost);
+
+        let local = &breakdown[1];
+        assert_eq!(local.provider, "ollama");
+        assert_eq!(local.request_count, 1);
+        assert_eq!(local.cost, 0.0);
+    }
+
+    #[test]
+    fn test_response_similarity() {
+        assert_eq!(response_similarity("Done!", "done"), 1.0);
+        assert_eq!(response_similarity("", ""), 1.0);
+        assert_eq!(response_similarity("one two", "three four"), 0.0);
+        assert_eq!(response_similarity("one two", "two three"), 1.0 / 3.0);
+    }
+}
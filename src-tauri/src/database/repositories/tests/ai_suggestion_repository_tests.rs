@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::setup_migrated_test_db;
+    use crate::database::repositories::ai_suggestion_repository::{
+        AiSuggestionRepository, CreateAiSuggestionRequest,
+    };
+
+    fn suggestion(title: &str, priority: i32) -> CreateAiSuggestionRequest {
+        CreateAiSuggestionRequest {
+            suggestion_type: "productivity".to_string(),
+            title: title.to_string(),
+            description: format!("{} description", title),
+            confidence: 0.8,
+            actionable: None,
+            priority: Some(priority),
+            estimated_impact: None,
+            reasoning: None,
+            actions: None,
+            expires_at: None,
+            interaction_log_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_suggestion_lifecycle() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = AiSuggestionRepository::new(db);
+        let now = chrono::Utc::now();
+
+        let mut linked = suggestion("Take a break", 1);
+        linked.interaction_log_id = Some("log-1".to_string());
+        linked.actions = Some(serde_json::json!([{ "type": "start_break" }]));
+        let created = repo
+            .create_suggestions(vec![linked, suggestion("Batch email", 3)])
+            .await
+            .unwrap();
+        assert_eq!(created.len(), 2);
+        assert_eq!(
+            created[0].actions.as_deref(),
+            Some(r#"[{"type":"start_break"}]"#)
+        );
+
+        let active = repo.find_active(None, now).await.unwrap();
+        let titles: Vec<&str> = active.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Batch email", "Take a break"]);
+        assert_eq!(
+            repo.find_for_interaction_log("log-1").await.unwrap().len(),
+            1
+        );
+
+        let accepted = repo.accept_suggestion(&created[0].id).await.unwrap();
+        assert!(accepted.applied_at.is_some());
+        let dismissed = repo.dismiss_suggestion(&created[1].id).await.unwrap();
+        assert!(dismissed.dismissed_at.is_some());
+        assert!(repo.find_active(None, now).await.unwrap().is_empty());
+
+        let err = repo.dismiss_suggestion(&created[0].id).await.unwrap_err();
+        assert!(err.to_string().contains("already answered"));
+    }
+
+    #[tokio::test]
+    async fn test_expire_stale_suggestions() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = AiSuggestionRepository::new(db);
+        let now = chrono::Utc::now();
+
+        let mut short_lived = suggestion("Short lived", 1);
+        short_lived.expires_at = Some(now + chrono::Duration::hours(1));
+        let created = repo
+            .create_suggestions(vec![short_lived, suggestion("Default lifetime", 1)])
+            .await
+            .unwrap();
+        repo.accept_suggestion(&created[1].id).await.unwrap();
+
+        let later = now + chrono::Duration::days(2);
+        assert!(repo.find_active(None, later).await.unwrap().is_empty());
+        assert_eq!(repo.expire_stale(later).await.unwrap(), 1);
+        assert!(repo.find_by_id(&created[0].id).await.unwrap().is_none());
+        // Answered suggestions stay as history
+        assert!(repo.find_by_id(&created[1].id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_suggestions() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = AiSuggestionRepository::new(db);
+
+        let mut invalid = suggestion("Overconfident", 1);
+        invalid.confidence = 1.5;
+        let result = repo
+            .create_suggestions(vec![suggestion("Valid", 1), invalid])
+            .await;
+        assert!(result.is_err());
+        assert!(repo
+            .find_active(None, chrono::Utc::now())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::authorization::{authorize, required_scope};
+use crate::database::repositories::api_token_repository::{
+    ApiTokenRepository, CreateApiTokenRequest,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+
+    #[tokio::test]
+    async fn test_token_scopes_and_revocation() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = ApiTokenRepository::new(db.clone());
+
+        let issued = repo
+            .create_token(CreateApiTokenRequest {
+                name: "CLI".to_string(),
+                scopes: vec!["read".to_string(), "tasks:write".to_string()],
+            })
+            .await
+            .unwrap();
+        assert!(issued.secret.starts_with("kp_"));
+        assert_ne!(issued.token.token_hash, issued.secret);
+
+        authorize(db.clone(), &issued.secret, "get_all_tasks")
+            .await
+            .unwrap();
+        let token = authorize(db.clone(), &issued.secret, "create_task")
+            .await
+            .unwrap();
+        assert!(token.last_used_at.is_some());
+
+        let forbidden = authorize(db.clone(), &issued.secret, "create_time_session").await;
+        assert!(forbidden
+            .unwrap_err()
+            .to_string()
+            .contains("FORBIDDEN_ERROR"));
+        let unknown = authorize(db.clone(), &issued.secret, "clear_all_data").await;
+        assert!(unknown.unwrap_err().to_string().contains("'admin'"));
+
+        repo.revoke_token(&issued.token.id).await.unwrap();
+        let revoked = authorize(db.clone(), &issued.secret, "get_all_tasks").await;
+        assert!(revoked
+            .unwrap_err()
+            .to_string()
+            .contains("UNAUTHORIZED_ERROR"));
+
+        let invalid_scope = repo
+            .create_token(CreateApiTokenRequest {
+                name: "MCP".to_string(),
+                scopes: vec!["everything".to_string()],
+            })
+            .await;
+        assert!(invalid_scope.is_err());
+        assert_eq!(required_scope("delete_task_list"), "lists:write");
+        // Previews read files from any path, so they are not plain reads
+        assert_eq!(required_scope("get_all_tasks"), "read");
+        assert_eq!(required_scope("preview_task_import"), "data:transfer");
+        assert_eq!(required_scope("inspect_task_bundle"), "data:transfer");
+    }
+}
@@ -0,0 +1,160 @@
+use crate::database::repositories::attachment_repository::{
+    checksum, AttachmentRepository, AttachmentStorage,
+};
+use crate::database::repositories::task_repository::TaskRepository;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::BackupService;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    #[tokio::test]
+    async fn test_attachments_are_copied_and_removed() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let files = tempfile::tempdir().unwrap();
+        let attachments_dir = files.path().join("attachments");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = AttachmentRepository::new(db, attachments_dir.clone());
+
+        let task = task_repo
+            .create_task(task_request("Review contract"))
+            .await
+            .unwrap();
+        let source = files.path().join("Contract.PDF");
+        std::fs::write(&source, b"%PDF-1.7").unwrap();
+
+        let attachment = repo.add_attachment(&task.id, &source).await.unwrap();
+        assert_eq!(attachment.file_name, "Contract.PDF");
+        assert_eq!(attachment.mime_type.as_deref(), Some("application/pdf"));
+        assert_eq!(attachment.size, 8);
+        assert_eq!(attachment.checksum, checksum(b"%PDF-1.7"));
+        assert!(attachment.stored_name.ends_with(".pdf"));
+
+        // The copy outlives the original
+        std::fs::remove_file(&source).unwrap();
+        let stored = repo.file_path(&attachment);
+        assert_eq!(std::fs::read(&stored).unwrap(), b"%PDF-1.7");
+        assert_eq!(repo.find_by_task(&task.id).await.unwrap().len(), 1);
+
+        assert!(repo.add_attachment(&task.id, files.path()).await.is_err());
+        assert!(repo.add_attachment("missing", &stored).await.is_err());
+
+        repo.remove_attachment(&attachment.id).await.unwrap();
+        assert!(!stored.exists());
+        assert!(repo.find_by_task(&task.id).await.unwrap().is_empty());
+        assert!(repo.remove_attachment(&attachment.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_attachments_survive_backup_round_trip() {
+        let files = tempfile::tempdir().unwrap();
+        let source = files.path().join("notes.txt");
+        std::fs::write(&source, "Call the landlord").unwrap();
+        let backup_path = files.path().join("backup.zip");
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let attachments_dir = files.path().join("attachments");
+        let task = TaskRepository::new(db.clone())
+            .create_task(task_request("Renew lease"))
+            .await
+            .unwrap();
+        let attachment = AttachmentRepository::new(db.clone(), attachments_dir.clone())
+            .add_attachment(&task.id, &source)
+            .await
+            .unwrap();
+        BackupService::new(db)
+            .with_attachments_dir(attachments_dir)
+            .export_data(backup_path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        let restored_db = setup_migrated_test_db().await.unwrap();
+        let restored_dir = files.path().join("restored");
+        BackupService::new(restored_db.clone())
+            .with_attachments_dir(restored_dir.clone())
+            .import_data(backup_path.to_str().unwrap(), true)
+            .await
+            .unwrap();
+
+        let repo = AttachmentRepository::new(restored_db, restored_dir);
+        let restored = repo.find_by_task(&task.id).await.unwrap();
+        assert_eq!(restored, vec![attachment]);
+        assert_eq!(
+            std::fs::read_to_string(repo.file_path(&restored[0])).unwrap(),
+            "Call the landlord"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_attachments_respect_the_quota() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let files = tempfile::tempdir().unwrap();
+        let task = TaskRepository::new(db.clone())
+            .create_task(task_request("Scan receipts"))
+            .await
+            .unwrap();
+        let repo = AttachmentRepository::new(db, files.path().join("attachments")).with_quota(10);
+
+        let small = files.path().join("small.txt");
+        std::fs::write(&small, b"123456").unwrap();
+        repo.add_attachment(&task.id, &small).await.unwrap();
+        assert_eq!(
+            repo.storage_usage().await.unwrap(),
+            AttachmentStorage {
+                attachment_count: 1,
+                used_bytes: 6,
+                quota_bytes: 10,
+            }
+        );
+
+        let over = repo.add_attachment(&task.id, &small).await;
+        assert!(over.unwrap_err().to_string().contains("quota"));
+        assert_eq!(repo.find_by_task(&task.id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_removes_archived_and_orphaned_files() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let files = tempfile::tempdir().unwrap();
+        let attachments_dir = files.path().join("attachments");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = AttachmentRepository::new(db, attachments_dir.clone());
+
+        let active = task_repo
+            .create_task(task_request("Current"))
+            .await
+            .unwrap();
+        let archived = task_repo.create_task(task_request("Old")).await.unwrap();
+        let source = files.path().join("scan.png");
+        std::fs::write(&source, b"png").unwrap();
+        let kept = repo.add_attachment(&active.id, &source).await.unwrap();
+        let old = repo.add_attachment(&archived.id, &source).await.unwrap();
+        task_repo.archive_task(&archived.id).await.unwrap();
+
+        // An untracked file is only an orphan once it is old enough not to be mid-upload
+        let orphan = attachments_dir.join("left-behind.pdf");
+        std::fs::write(&orphan, b"%PDF").unwrap();
+        let fresh = attachments_dir.join("uploading.pdf");
+        std::fs::write(&fresh, b"%PDF").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&orphan)
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() - std::time::Duration::from_secs(7200))
+            .unwrap();
+
+        let preview = repo.preview_cleanup().await.unwrap();
+        assert_eq!(preview.archived_attachments, vec![old.clone()]);
+        assert_eq!(preview.orphaned_files, vec!["left-behind.pdf"]);
+        assert_eq!(preview.reclaimed_bytes, 3 + 4);
+        assert!(orphan.exists());
+
+        assert_eq!(repo.clean_up().await.unwrap(), preview);
+        assert!(!orphan.exists());
+        assert!(!repo.file_path(&old).exists());
+        assert!(repo.find_by_id(&old.id).await.unwrap().is_none());
+        assert!(repo.file_path(&kept).exists());
+        assert!(fresh.exists());
+    }
+}
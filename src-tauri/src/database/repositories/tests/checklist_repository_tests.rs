@@ -0,0 +1,173 @@
+use crate::database::repositories::checklist_repository::{
+    ChecklistRepository, CreateChecklistItemRequest, UpdateChecklistItemRequest,
+};
+use crate::database::repositories::task_repository::TaskRepository;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::config::DatabaseConfig;
+    use crate::database::entities::tasks;
+    use crate::database::migration::Migrator;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+    use sea_orm::{ConnectionTrait, EntityTrait, Set};
+    use sea_orm_migration::MigratorTrait;
+    use std::sync::Arc;
+
+    fn item_request(task_id: &str, title: &str) -> CreateChecklistItemRequest {
+        CreateChecklistItemRequest {
+            task_id: task_id.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checklist_items_keep_order_and_completion_time() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = ChecklistRepository::new(db);
+
+        let task = task_repo
+            .create_task(task_request("Pack for trip"))
+            .await
+            .unwrap();
+        let passport = repo
+            .create_item(item_request(&task.id, " Passport "))
+            .await
+            .unwrap();
+        let charger = repo
+            .create_item(item_request(&task.id, "Charger"))
+            .await
+            .unwrap();
+        let tickets = repo
+            .create_item(item_request(&task.id, "Tickets"))
+            .await
+            .unwrap();
+        assert_eq!(passport.title, "Passport");
+        assert!(repo
+            .create_item(item_request(&task.id, "  "))
+            .await
+            .is_err());
+        assert!(repo
+            .create_item(item_request("missing", "Socks"))
+            .await
+            .is_err());
+
+        repo.reorder_item(&tickets.id, None, Some(&passport.id))
+            .await
+            .unwrap();
+        let titles: Vec<String> = repo
+            .find_by_task(&task.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|item| item.title)
+            .collect();
+        assert_eq!(titles, vec!["Tickets", "Passport", "Charger"]);
+
+        let checked = repo
+            .update_item(
+                &charger.id,
+                UpdateChecklistItemRequest {
+                    title: None,
+                    completed: Some(true),
+                },
+            )
+            .await
+            .unwrap();
+        let completed_at = checked.completed_at.expect("item should be completed");
+        let checked_again = repo
+            .update_item(
+                &charger.id,
+                UpdateChecklistItemRequest {
+                    title: Some("Phone charger".to_string()),
+                    completed: Some(true),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(checked_again.completed_at, Some(completed_at));
+        assert_eq!(checked_again.title, "Phone charger");
+
+        let unchecked = repo
+            .update_item(
+                &charger.id,
+                UpdateChecklistItemRequest {
+                    title: None,
+                    completed: Some(false),
+                },
+            )
+            .await
+            .unwrap();
+        assert!(unchecked.completed_at.is_none());
+
+        task_repo.delete_task(&task.id).await.unwrap();
+        assert!(repo.find_by_task(&task.id).await.unwrap().is_empty());
+    }
+
+    /// Insert a task into a database whose tasks table may lack later columns, returning its ID
+    async fn insert_task<C: ConnectionTrait>(db: &C, title: &str) -> String {
+        let task = tasks::ActiveModel {
+            title: Set(title.to_string()),
+            priority: Set(1),
+            status: Set("pending".to_string()),
+            order_num: Set(0),
+            time_estimate: Set(0),
+            actual_time: Set(0),
+            is_periodic_instance: Set(false),
+            ..Default::default()
+        };
+        let id = task.id.clone().unwrap();
+        tasks::Entity::insert(task)
+            .exec_without_returning(db)
+            .await
+            .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_json_subtasks_are_migrated_to_checklist_items() {
+        let db = DatabaseConfig::new()
+            .with_database_url("sqlite::memory:".to_string())
+            .with_max_connections(1)
+            .with_sqlx_logging(false)
+            .connect()
+            .await
+            .unwrap();
+        // Stop just before the checklist migration, while subtasks still live in JSON
+        let checklist_migration = Migrator::migrations()
+            .iter()
+            .position(|m| m.name() == "m20240101_000039_create_checklist_items_table")
+            .unwrap() as u32;
+        Migrator::up(&db, Some(checklist_migration)).await.unwrap();
+
+        // Insert directly: the repository needs tables from later migrations
+        let db = Arc::new(db);
+        let parent_id = insert_task(&*db, "Move house").await;
+        let child_id = insert_task(&*db, "Book van").await;
+        db.execute_unprepared(&format!(
+            r#"UPDATE tasks SET subtasks = '["Pack boxes", "{}", {{"title": "Cancel internet", "completed": true}}, " "]' WHERE id = '{}'"#,
+            child_id, parent_id
+        ))
+        .await
+        .unwrap();
+
+        Migrator::up(&*db, None).await.unwrap();
+
+        let items = ChecklistRepository::new(db.clone())
+            .find_by_task(&parent_id)
+            .await
+            .unwrap();
+        let titles: Vec<&str> = items.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!(titles, vec!["Pack boxes", "Cancel internet"]);
+        assert!(items[0].completed_at.is_none());
+        assert!(items[1].completed_at.is_some());
+
+        let child = TaskRepository::new(db.clone())
+            .find_by_id(&child_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(child.parent_task_id, Some(parent_id));
+    }
+}
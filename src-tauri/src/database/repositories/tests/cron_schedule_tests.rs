@@ -0,0 +1,100 @@
+use crate::database::repositories::cron_schedule::CronSchedule;
+use crate::database::repositories::periodic_task_repository::{
+    PeriodicTaskRepository, RecurrencePreviewRequest,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use chrono::{Datelike, Local, TimeZone, Timelike, Utc, Weekday};
+
+    fn next(expression: &str, after: (i32, u32, u32, u32, u32)) -> Option<String> {
+        let (year, month, day, hour, minute) = after;
+        let after = Utc
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap();
+        CronSchedule::parse(expression)
+            .unwrap()
+            .next_after(&after)
+            .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+    }
+
+    #[test]
+    fn test_cron_rules_find_calendar_occurrences() {
+        // Jan 31st 2026 is a Saturday
+        assert_eq!(
+            next("0 9 LW * *", (2026, 1, 1, 0, 0)).as_deref(),
+            Some("2026-01-30 09:00")
+        );
+        assert_eq!(
+            next("30 14 * * 2#2", (2026, 1, 1, 0, 0)).as_deref(),
+            Some("2026-01-13 14:30")
+        );
+        assert_eq!(
+            next("0 8 * * FRIL", (2026, 2, 1, 0, 0)).as_deref(),
+            Some("2026-02-27 08:00")
+        );
+        assert_eq!(
+            next("*/15 * * * *", (2026, 3, 1, 10, 7)).as_deref(),
+            Some("2026-03-01 10:15")
+        );
+        assert_eq!(
+            next("@monthly", (2026, 12, 1, 0, 0)).as_deref(),
+            Some("2027-01-01 00:00")
+        );
+        assert_eq!(next("0 0 31 2 *", (2026, 1, 1, 0, 0)), None);
+
+        assert!(CronSchedule::parse("61 * * * *").is_err());
+        assert!(CronSchedule::parse("* * *").is_err());
+        assert!(CronSchedule::parse("0 9 * * 2#6").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_preview_recurrence_dates() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = PeriodicTaskRepository::new(db);
+        let start_date = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+
+        let weekly = repo
+            .preview_recurrence_dates(RecurrencePreviewRequest {
+                start_date,
+                recurrence_type: "weekly".to_string(),
+                recurrence_interval: 1,
+                recurrence_unit: None,
+                recurrence_rule: None,
+                count: 3,
+            })
+            .unwrap();
+        assert_eq!(weekly.len(), 3);
+        assert_eq!(weekly[0], start_date);
+        assert_eq!(weekly[2], start_date + chrono::Duration::weeks(2));
+
+        let workdays = repo
+            .preview_recurrence_dates(RecurrencePreviewRequest {
+                start_date,
+                recurrence_type: "cron".to_string(),
+                recurrence_interval: 1,
+                recurrence_unit: None,
+                recurrence_rule: Some("0 9 * * MON-FRI".to_string()),
+                count: 10,
+            })
+            .unwrap();
+        assert_eq!(workdays.len(), 10);
+        assert!(workdays.windows(2).all(|pair| pair[0] < pair[1]));
+        for date in workdays.iter().map(|date| date.with_timezone(&Local)) {
+            assert_eq!((date.hour(), date.minute()), (9, 0));
+            assert!(!matches!(date.weekday(), Weekday::Sat | Weekday::Sun));
+        }
+
+        let missing_rule = repo.preview_recurrence_dates(RecurrencePreviewRequest {
+            start_date,
+            recurrence_type: "cron".to_string(),
+            recurrence_interval: 1,
+            recurrence_unit: None,
+            recurrence_rule: None,
+            count: 3,
+        });
+        assert!(missing_rule.is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::database::repositories::custom_field_repository::{
+    CreateCustomFieldRequest, CustomFieldRepository,
+};
+use crate::database::repositories::task_repository::{
+    CreateTaskRequest, CustomFieldCriterion, TaskFilter, TaskRepository,
+};
+use crate::database::repositories::TaskListRepository;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn list_task_request(title: &str, task_list_id: &str) -> CreateTaskRequest {
+        CreateTaskRequest {
+            task_list_id: Some(task_list_id.to_string()),
+            ..task_request(title)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_field_values_are_validated_by_type() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let list = TaskListRepository::new(db.clone())
+            .create_task_list("Sales".to_string())
+            .await
+            .unwrap();
+        let repo = CustomFieldRepository::new(db.clone());
+        let task = TaskRepository::new(db.clone())
+            .create_task(list_task_request("Call ACME", &list.id))
+            .await
+            .unwrap();
+
+        let amount = repo
+            .create_field(CreateCustomFieldRequest {
+                task_list_id: list.id.clone(),
+                name: "Amount".to_string(),
+                field_type: "number".to_string(),
+                options: None,
+            })
+            .await
+            .unwrap();
+        let stage = repo
+            .create_field(CreateCustomFieldRequest {
+                task_list_id: list.id.clone(),
+                name: "Stage".to_string(),
+                field_type: "select".to_string(),
+                options: Some(vec!["Lead".to_string(), "Won".to_string()]),
+            })
+            .await
+            .unwrap();
+
+        assert!(repo
+            .set_value(&task.id, &amount.id, Some("lots".to_string()))
+            .await
+            .is_err());
+        assert!(repo
+            .set_value(&task.id, &stage.id, Some("Lost".to_string()))
+            .await
+            .is_err());
+
+        let values = repo
+            .set_values_by_name(
+                &task.id,
+                HashMap::from([
+                    ("amount".to_string(), Some("1200.50".to_string())),
+                    ("Stage".to_string(), Some("won".to_string())),
+                ]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].value, "1200.5");
+        assert_eq!(values[1].value, "Won");
+
+        repo.set_value(&task.id, &amount.id, None).await.unwrap();
+        assert_eq!(repo.get_task_values(&task.id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_filter_tasks_by_custom_field() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let list = TaskListRepository::new(db.clone())
+            .create_task_list("Bugs".to_string())
+            .await
+            .unwrap();
+        let repo = CustomFieldRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db.clone());
+
+        let severity = repo
+            .create_field(CreateCustomFieldRequest {
+                task_list_id: list.id.clone(),
+                name: "Severity".to_string(),
+                field_type: "select".to_string(),
+                options: Some(vec!["Low".to_string(), "High".to_string()]),
+            })
+            .await
+            .unwrap();
+
+        let crash = task_repo
+            .create_task(list_task_request("Crash on start", &list.id))
+            .await
+            .unwrap();
+        let typo = task_repo
+            .create_task(list_task_request("Typo in menu", &list.id))
+            .await
+            .unwrap();
+        repo.set_value(&crash.id, &severity.id, Some("High".to_string()))
+            .await
+            .unwrap();
+        repo.set_value(&typo.id, &severity.id, Some("Low".to_string()))
+            .await
+            .unwrap();
+
+        let filter = TaskFilter {
+            custom_fields: Some(vec![CustomFieldCriterion {
+                field_id: severity.id.clone(),
+                value: "High".to_string(),
+            }]),
+            ..Default::default()
+        };
+        let found = task_repo.find_by_filter(&filter).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, crash.id);
+
+        task_repo.delete_task(&crash.id).await.unwrap();
+        assert!(repo.get_task_values(&crash.id).await.unwrap().is_empty());
+    }
+}
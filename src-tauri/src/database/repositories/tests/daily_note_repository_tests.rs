@@ -0,0 +1,137 @@
+use crate::database::repositories::daily_note_repository::DailyNoteRepository;
+use crate::database::repositories::task_repository::{TaskRepository, UpdateTaskRequest};
+use crate::database::repositories::time_tracking_repository::{
+    CreateTimeSessionRequest, TimeTrackingRepository, UpdateTimeSessionRequest,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    fn completion(completed_at: chrono::DateTime<Utc>) -> UpdateTaskRequest {
+        UpdateTaskRequest {
+            title: None,
+            description: None,
+            priority: None,
+            status: Some("completed".to_string()),
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            actual_time: None,
+            due_date: None,
+            scheduled_date: None,
+            clear_scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            completed_at: Some(completed_at),
+            effort: None,
+            impact: None,
+            expected_updated_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_daily_note_is_saved_once_per_day() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = DailyNoteRepository::new(db);
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+
+        let note = repo
+            .save_note(date, "# Sunday\nSlow start".to_string())
+            .await
+            .unwrap();
+        let updated = repo
+            .save_note(date, "# Sunday\nShipped the release".to_string())
+            .await
+            .unwrap();
+        assert_eq!(updated.id, note.id);
+        assert_eq!(updated.content, "# Sunday\nShipped the release");
+
+        repo.save_note(date.succ_opt().unwrap(), "Monday".to_string())
+            .await
+            .unwrap();
+        let notes = repo
+            .find_between(date, date.succ_opt().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].content, "Monday");
+
+        repo.delete_note(date).await.unwrap();
+        assert!(repo.find_by_date(date).await.unwrap().is_none());
+        assert!(repo.delete_note(date).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_daily_note_day_links_completed_tasks_and_sessions() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let time_repo = TimeTrackingRepository::new(db.clone());
+        let repo = DailyNoteRepository::new(db);
+
+        let done = task_repo
+            .create_task(task_request("Write changelog"))
+            .await
+            .unwrap();
+        task_repo
+            .update_task(
+                &done.id,
+                completion(Utc.with_ymd_and_hms(2024, 3, 10, 15, 0, 0).unwrap()),
+            )
+            .await
+            .unwrap();
+        let next_day = task_repo
+            .create_task(task_request("Tag release"))
+            .await
+            .unwrap();
+        task_repo
+            .update_task(
+                &next_day.id,
+                completion(Utc.with_ymd_and_hms(2024, 3, 11, 0, 30, 0).unwrap()),
+            )
+            .await
+            .unwrap();
+
+        let session = time_repo
+            .create_session(CreateTimeSessionRequest {
+                task_id: done.id.clone(),
+                start_time: Utc.with_ymd_and_hms(2024, 3, 10, 9, 0, 0).unwrap(),
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .unwrap();
+        time_repo
+            .update_session(
+                &session.id,
+                UpdateTimeSessionRequest {
+                    end_time: Some(Utc.with_ymd_and_hms(2024, 3, 10, 10, 0, 0).unwrap()),
+                    paused_time: Some(600),
+                    is_active: Some(false),
+                    notes: None,
+                    breaks: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let day = repo.get_day(date, &Utc).await.unwrap();
+        assert!(day.note.is_none());
+        assert_eq!(day.completed_tasks.len(), 1);
+        assert_eq!(day.completed_tasks[0].id, done.id);
+        assert_eq!(day.sessions.len(), 1);
+        assert_eq!(day.tracked_seconds, 3000);
+
+        // The same instants fall on other days two hours further east
+        let east = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let day = repo.get_day(date, &east).await.unwrap();
+        assert_eq!(day.completed_tasks.len(), 1);
+        let day = repo.get_day(date.succ_opt().unwrap(), &east).await.unwrap();
+        assert_eq!(day.completed_tasks[0].id, next_day.id);
+    }
+}
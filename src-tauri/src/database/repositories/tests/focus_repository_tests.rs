@@ -3,7 +3,8 @@ mod tests {
     use super::super::super::tests::setup_test_db;
     use crate::database::repositories::{
         focus_repository::{
-            CreateFocusSessionRequest, FocusMetrics, FocusRepository, UpdateFocusSessionRequest,
+            CreateFocusSessionRequest, FocusBreak, FocusMetrics, FocusRepository,
+            UpdateFocusSessionRequest,
         },
         task_repository::{CreateTaskRequest, TaskRepository},
     };
@@ -23,6 +24,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let task = repo
@@ -391,6 +398,62 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_add_break() {
+        let db = setup_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = FocusRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+        let session = repo
+            .create_session(CreateFocusSessionRequest {
+                task_id,
+                planned_duration: 25,
+                distraction_level: "minimal".to_string(),
+                background_audio: None,
+                notes: None,
+            })
+            .await
+            .expect("Failed to create focus session");
+
+        let start = Utc::now();
+        for (offset, break_type) in [(25, "short"), (55, "long")] {
+            repo.add_break(
+                &session.id,
+                FocusBreak {
+                    start_time: start + chrono::Duration::minutes(offset),
+                    end_time: start + chrono::Duration::minutes(offset + 5),
+                    break_type: break_type.to_string(),
+                    reason: None,
+                },
+            )
+            .await
+            .expect("Failed to add break");
+        }
+
+        let session = repo.find_by_id(&session.id).await.unwrap().unwrap();
+        let breaks: Vec<FocusBreak> = serde_json::from_str(&session.breaks.unwrap()).unwrap();
+        assert_eq!(breaks.len(), 2);
+        assert_eq!(breaks[0].break_type, "short");
+        assert_eq!(breaks[1].break_type, "long");
+        assert_eq!(breaks[1].start_time, start + chrono::Duration::minutes(55));
+
+        let missing = repo
+            .add_break(
+                "missing",
+                FocusBreak {
+                    start_time: start,
+                    end_time: start,
+                    break_type: "short".to_string(),
+                    reason: None,
+                },
+            )
+            .await;
+        assert!(matches!(missing, Err(sea_orm::DbErr::RecordNotFound(_))));
+    }
+
     #[tokio::test]
     async fn test_delete_focus_session() {
         let db = setup_test_db()
@@ -426,4 +489,48 @@ mod tests {
             .expect("Failed to query focus session");
         assert!(found_session.is_none());
     }
+
+    #[tokio::test]
+    async fn test_start_session_and_record_distractions() {
+        let db = setup_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = FocusRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+        let request = |distraction_level: &str| CreateFocusSessionRequest {
+            task_id: task_id.clone(),
+            planned_duration: 25,
+            distraction_level: distraction_level.to_string(),
+            background_audio: None,
+            notes: None,
+        };
+
+        assert!(repo.start_session(request("loud")).await.is_err());
+        let session = repo
+            .start_session(request("minimal"))
+            .await
+            .expect("Failed to start focus session");
+
+        // Only one focus session runs at a time
+        assert!(repo.start_session(request("minimal")).await.is_err());
+
+        repo.record_distraction(&session.id).await.unwrap();
+        let distracted = repo.record_distraction(&session.id).await.unwrap();
+        assert_eq!(distracted.distraction_count, 2);
+
+        repo.complete_session(&session.id, 24, 80.0, 2, None)
+            .await
+            .expect("Failed to complete focus session");
+        assert!(repo.record_distraction(&session.id).await.is_err());
+        assert!(repo
+            .complete_session(&session.id, 24, 80.0, 2, None)
+            .await
+            .is_err());
+
+        repo.start_session(request("full"))
+            .await
+            .expect("Failed to start the next focus session");
+    }
 }
@@ -0,0 +1,137 @@
+use crate::database::repositories::focus_template_repository::{
+    CreateFocusTemplateRequest, FocusTemplateRepository, UpdateFocusTemplateRequest,
+};
+use crate::database::repositories::task_repository::{CreateTaskRequest, TaskRepository};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+
+    fn custom_template(name: &str) -> CreateFocusTemplateRequest {
+        CreateFocusTemplateRequest {
+            name: name.to_string(),
+            focus_duration: 90,
+            short_break_duration: 20,
+            long_break_duration: 30,
+            sessions_before_long_break: 2,
+            distraction_level: "none".to_string(),
+            background_audio: Some(r#"{"type":"white_noise","volume":40}"#.to_string()),
+        }
+    }
+
+    fn no_changes() -> UpdateFocusTemplateRequest {
+        UpdateFocusTemplateRequest {
+            name: None,
+            focus_duration: None,
+            short_break_duration: None,
+            long_break_duration: None,
+            sessions_before_long_break: None,
+            distraction_level: None,
+            background_audio: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builtin_and_custom_focus_templates() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = FocusTemplateRepository::new(db);
+
+        let builtin: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(builtin, ["52/17", "Pomodoro"]);
+
+        let pomodoro = repo.find_by_name("Pomodoro").await.unwrap().unwrap();
+        assert!(pomodoro.is_builtin);
+        assert_eq!(pomodoro.focus_duration, 25);
+
+        // Built-ins can be tuned but keep their name and can't be deleted
+        let mut rename = no_changes();
+        rename.name = Some("Tomato".to_string());
+        assert!(repo.update_template(&pomodoro.id, rename).await.is_err());
+        assert!(repo.delete_template(&pomodoro.id).await.is_err());
+
+        let mut longer = no_changes();
+        longer.focus_duration = Some(30);
+        let tuned = repo.update_template(&pomodoro.id, longer).await.unwrap();
+        assert_eq!(tuned.focus_duration, 30);
+        assert_eq!(tuned.name, "Pomodoro");
+
+        let deep_work = repo
+            .create_template(custom_template("Deep work"))
+            .await
+            .unwrap();
+        assert!(!deep_work.is_builtin);
+        assert!(repo
+            .create_template(custom_template("Deep work"))
+            .await
+            .is_err());
+
+        let mut invalid = custom_template("Broken");
+        invalid.focus_duration = 0;
+        assert!(repo.create_template(invalid).await.is_err());
+        let mut invalid = custom_template("Broken");
+        invalid.distraction_level = "loud".to_string();
+        assert!(repo.create_template(invalid).await.is_err());
+
+        let mut rename = no_changes();
+        rename.name = Some("Deep work (90)".to_string());
+        let renamed = repo.update_template(&deep_work.id, rename).await.unwrap();
+        assert_eq!(renamed.name, "Deep work (90)");
+
+        repo.delete_template(&deep_work.id).await.unwrap();
+        assert!(repo.find_by_id(&deep_work.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_start_focus_from_template() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = FocusTemplateRepository::new(db.clone());
+        let task = TaskRepository::new(db)
+            .create_task(CreateTaskRequest {
+                title: "Write report".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+
+        let template = repo
+            .create_template(custom_template("Deep work"))
+            .await
+            .unwrap();
+        let session = repo
+            .start_focus_from_template(&template.id, &task.id)
+            .await
+            .unwrap();
+        assert_eq!(session.task_id, task.id);
+        assert_eq!(session.planned_duration, 90);
+        assert_eq!(session.distraction_level, "none");
+        assert_eq!(session.background_audio, template.background_audio);
+
+        assert!(repo
+            .start_focus_from_template("missing", &task.id)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::database::repositories::github_repository::{
+    GitHubRepository, LinkGitHubIssueRequest, SaveGitHubSettingsRequest, DEFAULT_API_URL,
+};
+use crate::database::repositories::task_repository::TaskRepository;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn link_request(task_id: &str, repository: &str, issue_number: i64) -> LinkGitHubIssueRequest {
+        LinkGitHubIssueRequest {
+            task_id: task_id.to_string(),
+            repository: repository.to_string(),
+            issue_number,
+            issue_url: None,
+            issue_state: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_settings_keep_the_token_when_none_is_given() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = GitHubRepository::new(db);
+
+        let missing_token = SaveGitHubSettingsRequest {
+            token: None,
+            api_url: None,
+            task_list_id: None,
+            auto_refresh: None,
+        };
+        assert!(repo.save_settings(missing_token.clone()).await.is_err());
+
+        let saved = repo
+            .save_settings(SaveGitHubSettingsRequest {
+                token: Some(" ghp_secret ".to_string()),
+                ..missing_token.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(saved.token, "ghp_secret");
+        assert_eq!(saved.api_url, DEFAULT_API_URL);
+        assert!(saved.auto_refresh);
+
+        let updated = repo
+            .save_settings(SaveGitHubSettingsRequest {
+                api_url: Some("https://github.example.com/api/v3/".to_string()),
+                auto_refresh: Some(false),
+                ..missing_token
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.token, "ghp_secret");
+        assert_eq!(updated.api_url, "https://github.example.com/api/v3");
+        assert!(!updated.auto_refresh);
+
+        repo.delete_settings().await.unwrap();
+        assert!(repo.get_settings().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_links_are_unique_per_task_and_issue() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = GitHubRepository::new(db);
+        let first = task_repo
+            .create_task(task_request("Fix login"))
+            .await
+            .unwrap();
+        let second = task_repo
+            .create_task(task_request("Fix logout"))
+            .await
+            .unwrap();
+
+        let link = repo
+            .link_task(link_request(&first.id, "acme/app", 12))
+            .await
+            .unwrap();
+        assert_eq!(link.issue_url, "https://github.com/acme/app/issues/12");
+        assert_eq!(link.issue_state, "open");
+
+        // Relinking a task replaces its link
+        repo.link_task(link_request(&first.id, "acme/app", 13))
+            .await
+            .unwrap();
+        assert!(repo
+            .find_link_by_issue("acme/app", 12)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(repo
+            .link_task(link_request(&second.id, "acme/app", 13))
+            .await
+            .is_err());
+
+        assert!(repo
+            .link_task(link_request(&second.id, "acme", 14))
+            .await
+            .is_err());
+        assert!(repo
+            .link_task(link_request(&second.id, "acme/app", 0))
+            .await
+            .is_err());
+        assert!(repo
+            .link_task(link_request("missing", "acme/app", 14))
+            .await
+            .is_err());
+
+        let closed = repo.update_link_state(&first.id, "closed").await.unwrap();
+        assert_eq!(closed.issue_state, "closed");
+        assert_eq!(repo.find_links().await.unwrap().len(), 1);
+
+        assert!(repo.unlink_task(&first.id).await.unwrap());
+        assert!(!repo.unlink_task(&first.id).await.unwrap());
+        assert!(repo.find_link(&first.id).await.unwrap().is_none());
+    }
+}
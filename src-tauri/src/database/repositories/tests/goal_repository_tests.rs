@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::setup_migrated_test_db;
+    use crate::database::repositories::goal_repository::{CreateGoalRequest, GoalRepository};
+
+    fn goal(title: &str, metric: &str, target: i32, period: &str) -> CreateGoalRequest {
+        CreateGoalRequest {
+            title: title.to_string(),
+            metric: metric.to_string(),
+            target,
+            period: period.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_goal_lifecycle() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = GoalRepository::new(db);
+
+        let deep_work = repo
+            .create_goal(goal("  Deep work  ", "tracked_minutes", 240, "day"))
+            .await
+            .unwrap();
+        assert_eq!(deep_work.title, "Deep work");
+        repo.create_goal(goal("Ship tasks", "completed_tasks", 5, "week"))
+            .await
+            .unwrap();
+
+        let titles: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|goal| goal.title)
+            .collect();
+        assert_eq!(titles, vec!["Deep work", "Ship tasks"]);
+
+        repo.delete_goal(&deep_work.id).await.unwrap();
+        assert!(repo.find_by_id(&deep_work.id).await.unwrap().is_none());
+        assert!(repo.delete_goal(&deep_work.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_goals() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = GoalRepository::new(db);
+
+        for invalid in [
+            goal(" ", "tracked_minutes", 60, "day"),
+            goal("Steps", "steps", 60, "day"),
+            goal("Deep work", "tracked_minutes", 60, "month"),
+            goal("Deep work", "tracked_minutes", 0, "day"),
+        ] {
+            assert!(repo.create_goal(invalid).await.is_err());
+        }
+        assert!(repo.find_all().await.unwrap().is_empty());
+    }
+}
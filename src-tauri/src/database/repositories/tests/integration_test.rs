@@ -30,6 +30,12 @@ mod integration_tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let task = repo
@@ -76,6 +82,12 @@ mod integration_tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let task = task_repo
@@ -88,6 +100,7 @@ mod integration_tests {
             task_id: task.id.clone(),
             start_time: Utc::now(),
             notes: Some("Test session".to_string()),
+            auto_switch: false,
         };
 
         let session = time_repo
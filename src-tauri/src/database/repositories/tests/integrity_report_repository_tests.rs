@@ -0,0 +1,46 @@
+use crate::database::migration::initialization::DatabaseIntegrityReport;
+use crate::database::repositories::IntegrityReportRepository;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_test_db;
+
+    fn report(orphaned_tasks_count: u64) -> DatabaseIntegrityReport {
+        DatabaseIntegrityReport {
+            has_default_task_list: true,
+            default_task_list_id: "list-1".to_string(),
+            orphaned_tasks_count,
+            total_tasks: 12,
+            total_task_lists: 2,
+            is_healthy: orphaned_tasks_count == 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_are_kept_newest_first() {
+        let db = setup_test_db().await.unwrap();
+        let repo = IntegrityReportRepository::new(db);
+        assert!(repo.find_latest().await.unwrap().is_none());
+
+        let healthy = repo.record(&report(0), false).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        let unhealthy = repo.record(&report(3), true).await.unwrap();
+
+        assert!(healthy.is_healthy);
+        assert!(!unhealthy.is_healthy);
+        assert_eq!(unhealthy.orphaned_tasks_count, 3);
+        assert_eq!(unhealthy.default_task_list_id.as_deref(), Some("list-1"));
+        assert!(unhealthy.notified);
+
+        let latest = repo.find_latest().await.unwrap().unwrap();
+        assert_eq!(latest.id, unhealthy.id);
+
+        let history = repo.find_history(10).await.unwrap();
+        assert_eq!(
+            history.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(),
+            vec![unhealthy.id.as_str(), healthy.id.as_str()]
+        );
+        assert_eq!(repo.find_history(1).await.unwrap().len(), 1);
+    }
+}
@@ -1,13 +1,45 @@
+pub mod ai_persona_repository_tests;
+pub mod ai_repository_tests;
+pub mod ai_suggestion_repository_tests;
+pub mod api_token_repository_tests;
+pub mod attachment_repository_tests;
+pub mod checklist_repository_tests;
+pub mod cron_schedule_tests;
+pub mod custom_field_repository_tests;
+pub mod daily_note_repository_tests;
 pub mod focus_repository_tests;
+pub mod focus_template_repository_tests;
+pub mod github_repository_tests;
+pub mod goal_repository_tests;
 pub mod integration_test;
+pub mod integrity_report_repository_tests;
+pub mod notification_repository_tests;
 pub mod pattern_repository_tests;
+pub mod preferences_repository_tests;
+pub mod project_repository_tests;
+pub mod reminder_repository_tests;
+pub mod retry_tests;
+pub mod sync_repository_tests;
+pub mod tag_repository_tests;
+pub mod task_audit_repository_tests;
 pub mod task_list_repository_tests;
+pub mod task_note_repository_tests;
 pub mod task_repository_tests;
+pub mod task_source_repository_tests;
+pub mod task_status_repository_tests;
+pub mod task_template_repository_tests;
+pub mod thread_repository_tests;
 pub mod time_tracking_repository_tests;
+pub mod webhook_repository_tests;
 
 use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbErr, Statement};
 use std::sync::Arc;
 
+use crate::database::config::DatabaseConfig;
+use crate::database::migration::initialization::run_post_migration_initialization;
+use crate::database::migration::run_migrations;
+use crate::database::repositories::task_repository::CreateTaskRequest;
+
 /// Create an in-memory SQLite database for testing
 pub async fn create_test_db() -> Result<Arc<DatabaseConnection>, DbErr> {
     let db = Database::connect("sqlite::memory:").await?;
@@ -24,6 +56,47 @@ pub async fn setup_test_db() -> Result<Arc<DatabaseConnection>, DbErr> {
     Ok(db)
 }
 
+/// Setup test database using the real migrations and post-migration initialization
+///
+/// Unlike `setup_test_db`, the schema always matches production and a default task list exists.
+pub async fn setup_migrated_test_db() -> Result<Arc<DatabaseConnection>, DbErr> {
+    let db = DatabaseConfig::new()
+        .with_database_url("sqlite::memory:".to_string())
+        .with_max_connections(1)
+        .with_sqlx_logging(false)
+        .connect()
+        .await?;
+
+    run_migrations(&db).await?;
+    run_post_migration_initialization(&db).await?;
+
+    Ok(Arc::new(db))
+}
+
+/// Request for a task with only a title; tests set the fields they care about on top of it
+pub fn task_request(title: &str) -> CreateTaskRequest {
+    CreateTaskRequest {
+        title: title.to_string(),
+        description: None,
+        priority: 1,
+        status: None,
+        order_num: None,
+        dependencies: None,
+        time_estimate: None,
+        due_date: None,
+        scheduled_date: None,
+        tags: None,
+        project_id: None,
+        parent_task_id: None,
+        task_list_id: None,
+        periodic_template_id: None,
+        is_periodic_instance: None,
+        generation_date: None,
+        effort: None,
+        impact: None,
+    }
+}
+
 /// Create test database tables
 async fn create_test_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     // Create tasks table
@@ -51,6 +124,9 @@ async fn create_test_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
             periodic_template_id TEXT,
             is_periodic_instance BOOLEAN NOT NULL DEFAULT FALSE,
             generation_date TEXT,
+            effort INTEGER,
+            impact INTEGER,
+            archived_at TEXT,
             FOREIGN KEY (task_list_id) REFERENCES task_lists(id),
             FOREIGN KEY (periodic_template_id) REFERENCES periodic_task_templates(id)
         )
@@ -62,6 +138,7 @@ async fn create_test_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
             id TEXT PRIMARY KEY NOT NULL,
             task_id TEXT NOT NULL,
             depends_on_id TEXT NOT NULL,
+            relation_type TEXT NOT NULL DEFAULT 'depends_on',
             created_at TEXT NOT NULL,
             FOREIGN KEY (task_id) REFERENCES tasks(id),
             FOREIGN KEY (depends_on_id) REFERENCES tasks(id)
@@ -133,12 +210,247 @@ async fn create_test_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
         )
     "#;
 
+    // Create ai_replay_comparisons table
+    let create_ai_replay_comparisons_sql = r#"
+        CREATE TABLE IF NOT EXISTS ai_replay_comparisons (
+            id TEXT PRIMARY KEY NOT NULL,
+            interaction_log_id TEXT NOT NULL,
+            original_model TEXT NOT NULL,
+            replay_model TEXT NOT NULL,
+            prompt TEXT NOT NULL,
+            original_response TEXT NOT NULL,
+            replay_response TEXT NOT NULL,
+            mocked_tool_calls INTEGER NOT NULL DEFAULT 0,
+            unmatched_tool_calls INTEGER NOT NULL DEFAULT 0,
+            similarity REAL NOT NULL DEFAULT 0,
+            response_time INTEGER,
+            error TEXT,
+            created_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create prompt_eval_runs table
+    let create_prompt_eval_runs_sql = r#"
+        CREATE TABLE IF NOT EXISTS prompt_eval_runs (
+            id TEXT PRIMARY KEY NOT NULL,
+            template_name TEXT NOT NULL,
+            template_hash TEXT NOT NULL,
+            template TEXT NOT NULL,
+            model TEXT NOT NULL,
+            judge_model TEXT,
+            results TEXT NOT NULL,
+            average_score REAL,
+            created_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create ReAct chain tables
+    let create_react_chains_sql = r#"
+        CREATE TABLE IF NOT EXISTS react_chains (
+            id TEXT PRIMARY KEY NOT NULL,
+            session_id TEXT NOT NULL,
+            model_type TEXT NOT NULL,
+            duration INTEGER NOT NULL DEFAULT 0,
+            error TEXT,
+            error_code TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+    "#;
+
+    let create_react_steps_sql = r#"
+        CREATE TABLE IF NOT EXISTS react_steps (
+            id TEXT PRIMARY KEY NOT NULL,
+            chain_id TEXT NOT NULL,
+            step_index INTEGER NOT NULL,
+            tool_name TEXT NOT NULL,
+            arguments TEXT NOT NULL,
+            result TEXT NOT NULL,
+            success BOOLEAN NOT NULL,
+            error TEXT,
+            duration INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create sync tables
+    let create_sync_items_sql = r#"
+        CREATE TABLE IF NOT EXISTS sync_items (
+            id TEXT PRIMARY KEY NOT NULL,
+            calendar_url TEXT NOT NULL,
+            task_id TEXT NOT NULL,
+            uid TEXT NOT NULL,
+            href TEXT NOT NULL,
+            etag TEXT,
+            task_updated_at TEXT NOT NULL,
+            synced_at TEXT NOT NULL,
+            UNIQUE (calendar_url, task_id),
+            UNIQUE (calendar_url, href)
+        )
+    "#;
+
+    let create_sync_states_sql = r#"
+        CREATE TABLE IF NOT EXISTS sync_states (
+            calendar_url TEXT PRIMARY KEY NOT NULL,
+            sync_token TEXT,
+            last_synced_at TEXT
+        )
+    "#;
+
+    // Create integrity_reports table
+    let create_integrity_reports_sql = r#"
+        CREATE TABLE IF NOT EXISTS integrity_reports (
+            id TEXT PRIMARY KEY NOT NULL,
+            is_healthy BOOLEAN NOT NULL,
+            has_default_task_list BOOLEAN NOT NULL,
+            default_task_list_id TEXT,
+            orphaned_tasks_count INTEGER NOT NULL DEFAULT 0,
+            total_tasks INTEGER NOT NULL DEFAULT 0,
+            total_task_lists INTEGER NOT NULL DEFAULT 0,
+            notified BOOLEAN NOT NULL DEFAULT 0,
+            checked_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create github_settings table
+    let create_github_settings_sql = r#"
+        CREATE TABLE IF NOT EXISTS github_settings (
+            id TEXT PRIMARY KEY NOT NULL,
+            token TEXT NOT NULL,
+            api_url TEXT NOT NULL,
+            task_list_id TEXT,
+            auto_refresh BOOLEAN NOT NULL DEFAULT 1,
+            last_refreshed_at TEXT,
+            updated_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create task_github_links table
+    let create_task_github_links_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_github_links (
+            task_id TEXT PRIMARY KEY NOT NULL,
+            repository TEXT NOT NULL,
+            issue_number INTEGER NOT NULL,
+            issue_url TEXT NOT NULL,
+            issue_state TEXT NOT NULL DEFAULT 'open',
+            synced_at TEXT NOT NULL,
+            UNIQUE (repository, issue_number),
+            FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE
+        )
+    "#;
+
+    // Create webhooks table
+    let create_webhooks_sql = r#"
+        CREATE TABLE IF NOT EXISTS webhooks (
+            id TEXT PRIMARY KEY NOT NULL,
+            url TEXT NOT NULL,
+            events TEXT NOT NULL,
+            secret TEXT NOT NULL,
+            is_active BOOLEAN NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create webhook_deliveries table
+    let create_webhook_deliveries_sql = r#"
+        CREATE TABLE IF NOT EXISTS webhook_deliveries (
+            id TEXT PRIMARY KEY NOT NULL,
+            webhook_id TEXT NOT NULL,
+            event TEXT NOT NULL,
+            payload TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'pending',
+            attempts INTEGER NOT NULL DEFAULT 0,
+            response_status INTEGER,
+            error TEXT,
+            next_attempt_at TEXT,
+            created_at TEXT NOT NULL,
+            delivered_at TEXT,
+            FOREIGN KEY (webhook_id) REFERENCES webhooks (id) ON DELETE CASCADE
+        )
+    "#;
+
+    // Create task_sources table
+    let create_task_sources_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_sources (
+            task_id TEXT PRIMARY KEY NOT NULL,
+            source_type TEXT NOT NULL,
+            external_id TEXT,
+            url TEXT,
+            created_at TEXT NOT NULL,
+            FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE
+        )
+    "#;
+
+    // Create notification_deliveries table
+    let create_notification_deliveries_sql = r#"
+        CREATE TABLE IF NOT EXISTS notification_deliveries (
+            id TEXT PRIMARY KEY NOT NULL,
+            dedup_key TEXT NOT NULL UNIQUE,
+            kind TEXT NOT NULL,
+            title TEXT NOT NULL,
+            body TEXT NOT NULL,
+            status TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create session_pauses table
+    let create_session_pauses_sql = r#"
+        CREATE TABLE IF NOT EXISTS session_pauses (
+            id TEXT PRIMARY KEY NOT NULL,
+            session_id TEXT NOT NULL,
+            reason TEXT,
+            started_at TEXT NOT NULL,
+            ended_at TEXT,
+            FOREIGN KEY (session_id) REFERENCES time_sessions (id) ON DELETE CASCADE
+        )
+    "#;
+
+    // Create ai_provider_metrics table
+    let create_ai_provider_metrics_sql = r#"
+        CREATE TABLE IF NOT EXISTS ai_provider_metrics (
+            id TEXT PRIMARY KEY NOT NULL,
+            provider TEXT NOT NULL,
+            model TEXT,
+            latency INTEGER NOT NULL,
+            success BOOLEAN NOT NULL,
+            timed_out BOOLEAN NOT NULL DEFAULT 0,
+            error_code TEXT,
+            created_at TEXT NOT NULL,
+            input_tokens INTEGER,
+            output_tokens INTEGER,
+            estimated_cost REAL
+        )
+    "#;
+
     // Create task_lists table
     let create_task_lists_sql = r#"
         CREATE TABLE IF NOT EXISTS task_lists (
             id TEXT PRIMARY KEY NOT NULL,
             name TEXT NOT NULL,
             is_default BOOLEAN NOT NULL DEFAULT FALSE,
+            order_num INTEGER NOT NULL DEFAULT 0,
+            target_backlog_hours INTEGER,
+            target_cycle_hours INTEGER,
+            hourly_rate REAL,
+            color TEXT,
+            icon TEXT,
+            is_archived BOOLEAN NOT NULL DEFAULT FALSE,
+            project_id TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create projects table
+    let create_projects_sql = r#"
+        CREATE TABLE IF NOT EXISTS projects (
+            id TEXT PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            description TEXT,
+            status TEXT NOT NULL DEFAULT 'active',
+            target_date TEXT,
             created_at TEXT NOT NULL,
             updated_at TEXT NOT NULL
         )
@@ -157,15 +469,168 @@ async fn create_test_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
             recurrence_type TEXT NOT NULL,
             recurrence_interval INTEGER NOT NULL DEFAULT 1,
             recurrence_unit TEXT,
+            recurrence_rule TEXT,
             start_date TEXT NOT NULL,
             next_generation_date TEXT NOT NULL,
             is_active BOOLEAN NOT NULL DEFAULT TRUE,
             created_at TEXT NOT NULL,
             updated_at TEXT NOT NULL,
+            ends_at TEXT,
+            max_occurrences INTEGER,
+            paused_until TEXT,
+            missed_occurrence_policy TEXT,
             FOREIGN KEY (task_list_id) REFERENCES task_lists(id)
         )
     "#;
 
+    // Create task_status_history table
+    let create_task_status_history_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_status_history (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL,
+            from_status TEXT,
+            to_status TEXT NOT NULL,
+            changed_at TEXT NOT NULL,
+            FOREIGN KEY (task_id) REFERENCES tasks(id)
+        )
+    "#;
+
+    // Create task_statuses table with the built-in statuses
+    let create_task_statuses_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_statuses (
+            id TEXT PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL UNIQUE,
+            category TEXT NOT NULL,
+            color TEXT,
+            order_num INTEGER NOT NULL DEFAULT 0,
+            is_system BOOLEAN NOT NULL DEFAULT FALSE,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+    "#;
+    let seed_task_statuses_sql = r#"
+        INSERT INTO task_statuses (id, name, category, order_num, is_system)
+        VALUES
+            ('status-pending', 'pending', 'todo', 0, 1),
+            ('status-in-progress', 'in_progress', 'doing', 1, 1),
+            ('status-completed', 'completed', 'done', 2, 1),
+            ('status-cancelled', 'cancelled', 'done', 3, 1)
+    "#;
+
+    // Create tags and task_tags tables
+    let create_tags_sql = r#"
+        CREATE TABLE IF NOT EXISTS tags (
+            id TEXT PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL UNIQUE,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+    "#;
+    let create_task_tags_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_tags (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL,
+            tag_id TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE (task_id, tag_id)
+        )
+    "#;
+
+    // Create task_reminders table
+    let create_task_reminders_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_reminders (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL,
+            anchor TEXT NOT NULL,
+            offset_minutes INTEGER NOT NULL DEFAULT 0,
+            remind_at TEXT,
+            snoozed_until TEXT,
+            last_fired_for TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            FOREIGN KEY (task_id) REFERENCES tasks(id)
+        )
+    "#;
+
+    // Create checklist_items table
+    let create_checklist_items_sql = r#"
+        CREATE TABLE IF NOT EXISTS checklist_items (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL,
+            title TEXT NOT NULL,
+            order_num INTEGER NOT NULL DEFAULT 0,
+            completed_at TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            FOREIGN KEY (task_id) REFERENCES tasks(id)
+        )
+    "#;
+
+    // Create daily_notes table
+    let create_daily_notes_sql = r#"
+        CREATE TABLE IF NOT EXISTS daily_notes (
+            id TEXT PRIMARY KEY NOT NULL,
+            date TEXT NOT NULL UNIQUE,
+            content TEXT NOT NULL DEFAULT '',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+    "#;
+
+    // Create attachments table
+    let create_attachments_sql = r#"
+        CREATE TABLE IF NOT EXISTS attachments (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL,
+            file_name TEXT NOT NULL,
+            stored_name TEXT NOT NULL,
+            mime_type TEXT,
+            size INTEGER NOT NULL DEFAULT 0,
+            checksum TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            FOREIGN KEY (task_id) REFERENCES tasks(id)
+        )
+    "#;
+
+    // Create task_notes table
+    let create_task_notes_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_notes (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL,
+            content TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            FOREIGN KEY (task_id) REFERENCES tasks(id)
+        )
+    "#;
+
+    // Create task_audit_log table
+    let create_task_audit_log_sql = r#"
+        CREATE TABLE IF NOT EXISTS task_audit_log (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL,
+            action TEXT NOT NULL,
+            field TEXT,
+            old_value TEXT,
+            new_value TEXT,
+            source TEXT NOT NULL DEFAULT 'user',
+            created_at TEXT NOT NULL,
+            change_id TEXT
+        )
+    "#;
+
+    // Create session_subtask_completions table
+    let create_session_subtask_completions_sql = r#"
+        CREATE TABLE IF NOT EXISTS session_subtask_completions (
+            id TEXT PRIMARY KEY NOT NULL,
+            session_id TEXT NOT NULL,
+            subtask_id TEXT NOT NULL,
+            completed_at TEXT NOT NULL,
+            FOREIGN KEY (session_id) REFERENCES time_sessions(id),
+            FOREIGN KEY (subtask_id) REFERENCES tasks(id)
+        )
+    "#;
+
     // Execute table creation statements
     db.execute(Statement::from_string(
         sea_orm::DatabaseBackend::Sqlite,
@@ -203,17 +668,185 @@ async fn create_test_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     ))
     .await?;
 
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_ai_replay_comparisons_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_prompt_eval_runs_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_react_chains_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_react_steps_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_sync_items_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_sync_states_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_integrity_reports_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_github_settings_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_github_links_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_webhooks_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_webhook_deliveries_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_sources_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_notification_deliveries_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_session_pauses_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_ai_provider_metrics_sql.to_string(),
+    ))
+    .await?;
+
     db.execute(Statement::from_string(
         sea_orm::DatabaseBackend::Sqlite,
         create_task_lists_sql.to_string(),
     ))
     .await?;
 
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_projects_sql.to_string(),
+    ))
+    .await?;
+
     db.execute(Statement::from_string(
         sea_orm::DatabaseBackend::Sqlite,
         create_periodic_task_templates_sql.to_string(),
     ))
     .await?;
 
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_status_history_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_statuses_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        seed_task_statuses_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_tags_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_tags_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_session_subtask_completions_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_reminders_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_checklist_items_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_daily_notes_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_attachments_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_notes_sql.to_string(),
+    ))
+    .await?;
+
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        create_task_audit_log_sql.to_string(),
+    ))
+    .await?;
+
     Ok(())
 }
@@ -0,0 +1,95 @@
+use chrono::{Duration, Utc};
+
+use crate::database::repositories::notification_repository::ReserveNotificationRequest;
+use crate::database::repositories::NotificationRepository;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_test_db;
+
+    fn request(dedup_key: &str) -> ReserveNotificationRequest {
+        ReserveNotificationRequest {
+            dedup_key: dedup_key.to_string(),
+            kind: "reminder".to_string(),
+            title: "Write report".to_string(),
+            body: "Due Mon Jan 1, 09:00".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_same_key_is_delivered_once() {
+        let db = setup_test_db().await.unwrap();
+        let repo = NotificationRepository::new(db);
+        let now = Utc::now();
+
+        let first = repo.reserve(request("reminder:1"), now, 10).await.unwrap();
+        assert_eq!(first.unwrap().status, "shown");
+
+        // A restart scans the same due reminder again
+        let again = repo
+            .reserve(request("reminder:1"), now + Duration::minutes(1), 10)
+            .await
+            .unwrap();
+        assert!(again.is_none());
+        assert_eq!(repo.find_recent(10).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_notifications_over_hourly_limit_are_rate_limited() {
+        let db = setup_test_db().await.unwrap();
+        let repo = NotificationRepository::new(db);
+        let now = Utc::now();
+
+        for key in ["a", "b"] {
+            let delivery = repo.reserve(request(key), now, 2).await.unwrap().unwrap();
+            assert_eq!(delivery.status, "shown");
+        }
+        let limited = repo.reserve(request("c"), now, 2).await.unwrap().unwrap();
+        assert_eq!(limited.status, "rate_limited");
+
+        // Rate limited alerts are dropped for good, not replayed later
+        let later = now + Duration::minutes(61);
+        assert!(repo
+            .reserve(request("c"), later, 2)
+            .await
+            .unwrap()
+            .is_none());
+        let next = repo.reserve(request("d"), later, 2).await.unwrap().unwrap();
+        assert_eq!(next.status, "shown");
+    }
+
+    #[tokio::test]
+    async fn test_released_reservation_can_be_retried() {
+        let db = setup_test_db().await.unwrap();
+        let repo = NotificationRepository::new(db);
+        let now = Utc::now();
+
+        let delivery = repo.reserve(request("a"), now, 10).await.unwrap().unwrap();
+        repo.release(&delivery.id).await.unwrap();
+
+        assert!(repo.reserve(request("a"), now, 10).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_old_deliveries_are_pruned() {
+        let db = setup_test_db().await.unwrap();
+        let repo = NotificationRepository::new(db);
+        let now = Utc::now();
+
+        repo.reserve(request("old"), now - Duration::days(8), 10)
+            .await
+            .unwrap();
+        repo.reserve(request("new"), now, 10).await.unwrap();
+
+        let removed = repo
+            .delete_older_than(now - Duration::days(7))
+            .await
+            .unwrap();
+        assert_eq!(removed, 1);
+
+        let recent = repo.find_recent(10).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].dedup_key, "new");
+    }
+}
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::setup_migrated_test_db;
+    use crate::database::repositories::preferences_repository::{
+        PreferencesRepository, PromptTemplates, RolloverMode, WorkingHours,
+    };
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    fn at(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn date(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_working_hours() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = PreferencesRepository::new(db);
+
+        assert_eq!(
+            repo.get_working_hours().await.unwrap(),
+            WorkingHours::default()
+        );
+
+        let saved = repo
+            .save_working_hours(WorkingHours {
+                start: "08:30".to_string(),
+                end: "16:30".to_string(),
+                days: vec![4, 1, 2, 3, 1],
+                holidays: vec![date("2024-12-25"), date("2024-12-24")],
+            })
+            .await
+            .unwrap();
+        assert_eq!(saved.days, vec![1, 2, 3, 4]);
+        assert_eq!(saved.holidays, vec![date("2024-12-24"), date("2024-12-25")]);
+        assert_eq!(repo.get_working_hours().await.unwrap(), saved);
+
+        // Saving again updates the stored row
+        let updated = repo
+            .save_working_hours(WorkingHours {
+                end: "17:30".to_string(),
+                ..saved
+            })
+            .await
+            .unwrap();
+        assert_eq!(repo.get_working_hours().await.unwrap(), updated);
+
+        for invalid in [
+            WorkingHours {
+                start: "18:00".to_string(),
+                ..WorkingHours::default()
+            },
+            WorkingHours {
+                end: "25:00".to_string(),
+                ..WorkingHours::default()
+            },
+            WorkingHours {
+                days: vec![],
+                ..WorkingHours::default()
+            },
+            WorkingHours {
+                days: vec![7],
+                ..WorkingHours::default()
+            },
+        ] {
+            assert!(repo.save_working_hours(invalid).await.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_rollover_mode() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = PreferencesRepository::new(db);
+
+        assert_eq!(repo.get_rollover_mode().await.unwrap(), RolloverMode::Off);
+        repo.save_rollover_mode(RolloverMode::Backlog)
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.get_rollover_mode().await.unwrap(),
+            RolloverMode::Backlog
+        );
+
+        // Working hours saved later keep the rollover mode
+        repo.save_working_hours(WorkingHours::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.get_rollover_mode().await.unwrap(),
+            RolloverMode::Backlog
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_reset_prompt_templates() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = PreferencesRepository::new(db);
+
+        assert_eq!(
+            repo.get_prompt_templates().await.unwrap(),
+            PromptTemplates::default()
+        );
+
+        let saved = repo
+            .save_prompt_templates(PromptTemplates {
+                system_persona: Some("  You are Kira, a terse planner.\n".to_string()),
+                thought: Some("   ".to_string()),
+                ..PromptTemplates::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            saved,
+            PromptTemplates {
+                system_persona: Some("You are Kira, a terse planner.".to_string()),
+                ..PromptTemplates::default()
+            }
+        );
+        assert_eq!(repo.get_prompt_templates().await.unwrap(), saved);
+
+        // Other preferences saved later keep the templates
+        repo.save_rollover_mode(RolloverMode::Today).await.unwrap();
+        assert_eq!(repo.get_prompt_templates().await.unwrap(), saved);
+
+        let too_long = PromptTemplates {
+            action: Some("a".repeat(20_001)),
+            ..PromptTemplates::default()
+        };
+        assert!(repo.save_prompt_templates(too_long).await.is_err());
+
+        repo.reset_prompt_templates().await.unwrap();
+        assert_eq!(
+            repo.get_prompt_templates().await.unwrap(),
+            PromptTemplates::default()
+        );
+    }
+
+    #[test]
+    fn test_working_time() {
+        // 2024-01-05 is a Friday, the Monday after is a holiday
+        let hours = WorkingHours {
+            holidays: vec![date("2024-01-08")],
+            ..WorkingHours::default()
+        };
+        assert_eq!(hours.daily_minutes(), 480);
+        assert!(hours.is_working_time(at("2024-01-05T09:00:00Z"), &Utc));
+        assert!(!hours.is_working_time(at("2024-01-05T17:00:00Z"), &Utc));
+        assert!(!hours.is_workday(date("2024-01-06")));
+        assert!(!hours.is_workday(date("2024-01-08")));
+
+        // Friday evening waits for Tuesday morning
+        assert_eq!(
+            hours.next_working_time(at("2024-01-05T18:00:00Z"), &Utc),
+            at("2024-01-09T09:00:00Z")
+        );
+        assert_eq!(
+            hours.next_working_time(at("2024-01-05T08:00:00Z"), &Utc),
+            at("2024-01-05T09:00:00Z")
+        );
+        assert_eq!(
+            hours.next_workday_at(at("2024-01-06T07:00:00Z"), &Utc),
+            at("2024-01-09T07:00:00Z")
+        );
+
+        // Friday 16:00 to Tuesday 10:00 has one hour on each working day
+        assert_eq!(
+            hours.working_seconds_between(
+                at("2024-01-05T16:00:00Z"),
+                at("2024-01-09T10:00:00Z"),
+                &Utc
+            ),
+            2 * 60 * 60
+        );
+    }
+
+    #[test]
+    fn test_workday_context() {
+        let hours = WorkingHours {
+            holidays: vec![date("2024-01-08")],
+            ..WorkingHours::default()
+        };
+
+        let context = hours.workday_context(at("2024-01-05T15:30:00Z"), &Utc);
+        assert!(context.is_workday);
+        assert!(context.is_working_time);
+        assert_eq!(context.minutes_left_today, 90);
+        assert!(context.next_working_time.is_none());
+        assert_eq!(context.upcoming_holidays, vec![date("2024-01-08")]);
+
+        let context = hours.workday_context(at("2024-01-06T10:00:00Z"), &Utc);
+        assert!(!context.is_workday);
+        assert_eq!(context.minutes_left_today, 0);
+        assert_eq!(context.next_working_time, Some(at("2024-01-09T09:00:00Z")));
+    }
+}
@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::setup_migrated_test_db;
+    use crate::database::repositories::project_repository::{
+        CreateProjectRequest, ProjectRepository, UpdateProjectRequest,
+    };
+    use crate::database::repositories::task_list_repository::{
+        TaskListRepository, UpdateTaskListRequest,
+    };
+    use crate::database::repositories::task_repository::{
+        CreateTaskRequest, TaskRepository, UpdateTaskRequest,
+    };
+    use chrono::{Duration, Utc};
+    use sea_orm::DbErr;
+
+    fn project(name: &str) -> CreateProjectRequest {
+        CreateProjectRequest {
+            name: name.to_string(),
+            description: None,
+            status: None,
+            target_date: None,
+        }
+    }
+
+    fn task(title: &str, status: &str, task_list_id: &str) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority: 1,
+            status: Some(status.to_string()),
+            order_num: None,
+            dependencies: None,
+            time_estimate: Some(30),
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: Some(task_list_id.to_string()),
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_project_lifecycle() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = ProjectRepository::new(db);
+
+        let launch = repo
+            .create_project(CreateProjectRequest {
+                description: Some("Ship v2".to_string()),
+                target_date: Some(Utc::now() - Duration::days(1)),
+                ..project("  Launch  ")
+            })
+            .await
+            .unwrap();
+        assert_eq!(launch.name, "Launch");
+        assert_eq!(launch.status, "active");
+        repo.create_project(project("Archive cleanup"))
+            .await
+            .unwrap();
+
+        let names: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|project| project.name)
+            .collect();
+        assert_eq!(names, ["Archive cleanup", "Launch"]);
+
+        let updated = repo
+            .update_project(
+                &launch.id,
+                UpdateProjectRequest {
+                    status: Some("on_hold".to_string()),
+                    description: Some(String::new()),
+                    clear_target_date: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.status, "on_hold");
+        assert!(updated.description.is_none());
+        assert!(updated.target_date.is_none());
+
+        assert!(repo.create_project(project(" ")).await.is_err());
+        let result = repo
+            .update_project(
+                &launch.id,
+                UpdateProjectRequest {
+                    status: Some("paused".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(DbErr::Custom(msg)) if msg.contains("VALIDATION_ERROR")));
+
+        repo.delete_project(&launch.id).await.unwrap();
+        assert!(repo.find_by_id(&launch.id).await.unwrap().is_none());
+        assert!(repo.delete_project(&launch.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_project_progress_and_references() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = ProjectRepository::new(db.clone());
+        let list_repo = TaskListRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db.clone());
+
+        let launch = repo
+            .create_project(CreateProjectRequest {
+                target_date: Some(Utc::now() - Duration::days(1)),
+                ..project("Launch")
+            })
+            .await
+            .unwrap();
+        let list = list_repo
+            .create_task_list("Launch tasks".to_string())
+            .await
+            .unwrap();
+        let list = list_repo
+            .update_task_list(
+                &list.id,
+                UpdateTaskListRequest {
+                    project_id: Some(launch.id.clone()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(list.project_id.as_ref(), Some(&launch.id));
+
+        // Tasks count through their list or a direct link to the project
+        task_repo
+            .create_task(task("Write notes", "completed", &list.id))
+            .await
+            .unwrap();
+        task_repo
+            .create_task(task("Record demo", "pending", &list.id))
+            .await
+            .unwrap();
+        let default = list_repo.ensure_default_task_list().await.unwrap();
+        task_repo
+            .create_task(CreateTaskRequest {
+                project_id: Some(launch.id.clone()),
+                ..task("Book venue", "in_progress", &default.id)
+            })
+            .await
+            .unwrap();
+        let unrelated = task_repo
+            .create_task(task("Unrelated", "pending", &default.id))
+            .await
+            .unwrap();
+
+        let progress = repo.get_progress(Some(&launch.id)).await.unwrap();
+        assert_eq!(progress.len(), 1);
+        let progress = &progress[0];
+        assert_eq!(progress.task_list_count, 1);
+        assert_eq!(progress.total_tasks, 3);
+        assert_eq!(progress.completed_tasks, 1);
+        assert!((progress.percent_complete - 100.0 / 3.0).abs() < 1e-9);
+        assert_eq!(progress.estimated_minutes, 90);
+        assert!(progress.is_past_target);
+
+        // References to missing projects are rejected
+        let result = task_repo
+            .create_task(CreateTaskRequest {
+                project_id: Some("missing".to_string()),
+                ..task("Orphan", "pending", &default.id)
+            })
+            .await;
+        assert!(matches!(result, Err(DbErr::RecordNotFound(_))));
+        let result = list_repo
+            .update_task_list(
+                &list.id,
+                UpdateTaskListRequest {
+                    project_id: Some("missing".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(DbErr::RecordNotFound(_))));
+        let result = task_repo
+            .update_task(
+                &unrelated.id,
+                UpdateTaskRequest {
+                    title: None,
+                    description: None,
+                    priority: None,
+                    status: None,
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: None,
+                    actual_time: None,
+                    due_date: None,
+                    scheduled_date: None,
+                    clear_scheduled_date: None,
+                    tags: None,
+                    project_id: Some("missing".to_string()),
+                    parent_task_id: None,
+                    task_list_id: None,
+                    completed_at: None,
+                    effort: None,
+                    impact: None,
+                    expected_updated_at: None,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(DbErr::RecordNotFound(_))));
+
+        // Deleting the project unlinks its lists and tasks
+        repo.delete_project(&launch.id).await.unwrap();
+        let list = list_repo.find_by_id(&list.id).await.unwrap().unwrap();
+        assert!(list.project_id.is_none());
+        let linked = task_repo
+            .find_by_filter(&Default::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|task| task.project_id.is_some())
+            .count();
+        assert_eq!(linked, 0);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::database::repositories::preferences_repository::WorkingHours;
+use crate::database::repositories::reminder_repository::{
+    ReminderOffsetRequest, ReminderRepository,
+};
+use crate::database::repositories::task_repository::{
+    CreateTaskRequest, TaskRepository, UpdateTaskRequest,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::BackupService;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn due_task_request(due_date: chrono::DateTime<Utc>) -> CreateTaskRequest {
+        CreateTaskRequest {
+            due_date: Some(due_date),
+            ..task_request("Submit report")
+        }
+    }
+
+    fn status_update(status: &str) -> UpdateTaskRequest {
+        UpdateTaskRequest {
+            title: None,
+            description: None,
+            priority: None,
+            status: Some(status.to_string()),
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            actual_time: None,
+            due_date: None,
+            scheduled_date: None,
+            clear_scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            completed_at: None,
+            effort: None,
+            impact: None,
+            expected_updated_at: None,
+        }
+    }
+
+    fn offset(anchor: &str, offset_minutes: i32) -> ReminderOffsetRequest {
+        ReminderOffsetRequest {
+            anchor: anchor.to_string(),
+            offset_minutes,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_due_date_reminders_fire_once() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = ReminderRepository::new(db);
+
+        let now = Utc::now();
+        let task = task_repo
+            .create_task(due_task_request(now + Duration::minutes(20)))
+            .await
+            .unwrap();
+
+        let reminders = repo
+            .set_task_reminders(
+                &task.id,
+                vec![
+                    offset("due_date", 30),
+                    offset("due_date", 10),
+                    offset("due_date", 30),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(reminders.len(), 2);
+        assert!(repo
+            .set_task_reminders(&task.id, vec![offset("whenever", 5)])
+            .await
+            .is_err());
+
+        // 30 minutes ahead is already due, 10 minutes ahead fires in 10 minutes
+        let due = repo.find_due_reminders(now).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].reminder.offset_minutes, 30);
+        let upcoming = repo
+            .find_upcoming_reminders(now, now + Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(
+            upcoming[0].fire_at,
+            task.due_date.unwrap() - Duration::minutes(10)
+        );
+
+        repo.mark_fired(&due[0]).await.unwrap();
+        assert!(repo.find_due_reminders(now).await.unwrap().is_empty());
+
+        // Keeping the reminder keeps its firing state
+        repo.set_task_reminders(&task.id, vec![offset("due_date", 30)])
+            .await
+            .unwrap();
+        assert!(repo.find_due_reminders(now).await.unwrap().is_empty());
+
+        // Snoozing fires it again later
+        let snoozed = repo.snooze_reminder(&due[0].reminder.id, 15).await.unwrap();
+        assert!(repo.find_due_reminders(now).await.unwrap().is_empty());
+        let later = snoozed.snoozed_until.unwrap();
+        assert_eq!(repo.find_due_reminders(later).await.unwrap().len(), 1);
+
+        // Completed tasks stay quiet
+        task_repo
+            .update_task(&task.id, status_update("completed"))
+            .await
+            .unwrap();
+        assert!(repo.find_due_reminders(later).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_remind_again_in() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = ReminderRepository::new(db);
+
+        let task = task_repo
+            .create_task(due_task_request(Utc::now() + Duration::days(3)))
+            .await
+            .unwrap();
+
+        assert!(repo.remind_again_in(&task.id, 0).await.is_err());
+        let reminder = repo.remind_again_in(&task.id, 5).await.unwrap();
+        assert_eq!(reminder.anchor, "once");
+
+        let fire_at = reminder.remind_at.unwrap();
+        assert!(repo
+            .find_due_reminders(fire_at - Duration::minutes(1))
+            .await
+            .unwrap()
+            .is_empty());
+        let due = repo.find_due_reminders(fire_at).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].task_title, "Submit report");
+
+        // One-off reminders are gone once they fired
+        repo.mark_fired(&due[0]).await.unwrap();
+        assert!(repo.find_by_task(&task.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reminders_wait_for_working_hours() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let now = Utc::now();
+        let today = now.with_timezone(&chrono::Local).date_naive();
+        let working_hours = WorkingHours {
+            holidays: vec![today - Duration::days(1), today],
+            ..WorkingHours::around_the_clock()
+        };
+        let repo = ReminderRepository::new(db).with_working_hours(working_hours);
+
+        let task = task_repo
+            .create_task(due_task_request(now + Duration::minutes(20)))
+            .await
+            .unwrap();
+        repo.set_task_reminders(&task.id, vec![offset("due_date", 30)])
+            .await
+            .unwrap();
+
+        // Due during a holiday, so it fires when tomorrow starts
+        assert!(repo.find_due_reminders(now).await.unwrap().is_empty());
+        let tomorrow = chrono::Local
+            .from_local_datetime(&(today + Duration::days(1)).and_hms_opt(0, 0, 0).unwrap())
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc);
+        let upcoming = repo
+            .find_upcoming_reminders(now, now + Duration::days(2))
+            .await
+            .unwrap();
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(upcoming[0].fire_at, tomorrow);
+    }
+
+    #[tokio::test]
+    async fn test_reminders_survive_backup_round_trip() {
+        let files = tempfile::tempdir().unwrap();
+        let backup_path = files.path().join("backup.zip");
+        let backup_path = backup_path.to_str().unwrap();
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let task = TaskRepository::new(db.clone())
+            .create_task(due_task_request(Utc::now() + Duration::days(1)))
+            .await
+            .unwrap();
+        let reminders = ReminderRepository::new(db.clone())
+            .set_task_reminders(&task.id, vec![offset("due_date", 30)])
+            .await
+            .unwrap();
+        BackupService::new(db)
+            .export_data(backup_path)
+            .await
+            .unwrap();
+
+        // Restoring over existing reminders replaces them
+        let restored_db = setup_migrated_test_db().await.unwrap();
+        let existing = TaskRepository::new(restored_db.clone())
+            .create_task(due_task_request(Utc::now()))
+            .await
+            .unwrap();
+        let repo = ReminderRepository::new(restored_db.clone());
+        repo.set_task_reminders(&existing.id, vec![offset("due_date", 5)])
+            .await
+            .unwrap();
+        BackupService::new(restored_db)
+            .import_data(backup_path, true)
+            .await
+            .unwrap();
+
+        assert_eq!(repo.get_all_reminders().await.unwrap(), reminders);
+    }
+}
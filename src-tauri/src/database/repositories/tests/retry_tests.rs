@@ -0,0 +1,88 @@
+use sea_orm::DbErr;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::database::error::{busy_error, is_busy_error, DatabaseError, DATABASE_BUSY_ERROR};
+use crate::database::repositories::retry::{retry_on_busy, MAX_BUSY_ATTEMPTS};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locked() -> DbErr {
+        DbErr::Custom("error returned from database: (code: 5) database is locked".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_busy_recovers_after_lock_is_released() {
+        let attempts = AtomicU32::new(0);
+
+        let result = retry_on_busy(|| async {
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(locked())
+            } else {
+                Ok("saved")
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "saved");
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_busy_gives_up_with_busy_code() {
+        let attempts = AtomicU32::new(0);
+
+        let err = retry_on_busy(|| async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(locked())
+        })
+        .await
+        .expect_err("Operation should fail while the database stays locked");
+
+        assert_eq!(attempts.load(Ordering::SeqCst), MAX_BUSY_ATTEMPTS);
+        assert!(err.to_string().contains(DATABASE_BUSY_ERROR));
+        assert!(matches!(DatabaseError::from(err), DatabaseError::Busy(_)));
+    }
+
+    #[tokio::test]
+    async fn test_nested_retries_do_not_multiply() {
+        let attempts = AtomicU32::new(0);
+
+        let err = retry_on_busy(|| async {
+            retry_on_busy(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(locked())
+            })
+            .await
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), MAX_BUSY_ATTEMPTS);
+        assert!(!is_busy_error(&err));
+        assert!(!err.to_string().contains("database is locked"));
+
+        // Still recognised when wrapped by another error
+        let wrapped = DbErr::Custom(format!("Failed to save task: {}", busy_error(&locked())));
+        assert!(!is_busy_error(&wrapped));
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_busy_does_not_retry_other_errors() {
+        let attempts = AtomicU32::new(0);
+
+        let err = retry_on_busy(|| async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(DbErr::Custom(
+                "VALIDATION_ERROR: Task title cannot be empty".to_string(),
+            ))
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert!(!is_busy_error(&err));
+        assert!(!err.to_string().contains(DATABASE_BUSY_ERROR));
+    }
+}
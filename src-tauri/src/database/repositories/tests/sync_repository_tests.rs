@@ -0,0 +1,78 @@
+use crate::database::repositories::sync_repository::{SaveSyncItemRequest, SyncRepository};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+
+    const CALENDAR: &str = "https://dav.example.com/calendars/me/tasks/";
+
+    fn item_request(task_id: &str, etag: &str) -> SaveSyncItemRequest {
+        SaveSyncItemRequest {
+            calendar_url: CALENDAR.to_string(),
+            task_id: task_id.to_string(),
+            uid: task_id.to_string(),
+            href: format!("/calendars/me/tasks/{}.ics", task_id),
+            etag: Some(etag.to_string()),
+            task_updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_items_are_replaced_per_task() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = SyncRepository::new(db);
+
+        let first = repo
+            .save_item(item_request("task-1", "\"1\""))
+            .await
+            .unwrap();
+        let updated = repo
+            .save_item(item_request("task-1", "\"2\""))
+            .await
+            .unwrap();
+        repo.save_item(item_request("task-2", "\"1\""))
+            .await
+            .unwrap();
+
+        assert_eq!(first.id, updated.id);
+        assert_eq!(updated.etag.as_deref(), Some("\"2\""));
+        assert_eq!(repo.find_items(CALENDAR).await.unwrap().len(), 2);
+        assert!(repo
+            .find_items("https://other.example.com/")
+            .await
+            .unwrap()
+            .is_empty());
+
+        let mut invalid = item_request("task-3", "\"1\"");
+        invalid.href = " ".to_string();
+        assert!(repo.save_item(invalid).await.is_err());
+
+        repo.delete_item(&first.id).await.unwrap();
+        assert!(repo.delete_item(&first.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sync_state_is_saved_and_reset() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = SyncRepository::new(db);
+
+        assert!(repo.find_state(CALENDAR).await.unwrap().is_none());
+        repo.save_state(CALENDAR, Some("token-1".to_string()))
+            .await
+            .unwrap();
+        let state = repo
+            .save_state(CALENDAR, Some("token-2".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(state.sync_token.as_deref(), Some("token-2"));
+        assert!(state.last_synced_at.is_some());
+
+        repo.save_item(item_request("task-1", "\"1\""))
+            .await
+            .unwrap();
+        assert_eq!(repo.reset(CALENDAR).await.unwrap(), 1);
+        assert!(repo.find_state(CALENDAR).await.unwrap().is_none());
+        assert!(repo.find_items(CALENDAR).await.unwrap().is_empty());
+    }
+}
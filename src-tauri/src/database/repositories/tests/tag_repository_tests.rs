@@ -0,0 +1,109 @@
+use crate::database::repositories::tag_repository::TagRepository;
+use crate::database::repositories::task_repository::{CreateTaskRequest, TaskRepository};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn tagged_task_request(title: &str, tags: &[&str]) -> CreateTaskRequest {
+        CreateTaskRequest {
+            tags: Some(names(tags)),
+            ..task_request(title)
+        }
+    }
+
+    fn names(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|tag| tag.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_tags_are_linked_and_filterable() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TagRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db);
+
+        let report = task_repo
+            .create_task(tagged_task_request("Report", &["work", " urgent ", "work"]))
+            .await
+            .unwrap();
+        task_repo
+            .create_task(tagged_task_request("Groceries", &["home"]))
+            .await
+            .unwrap();
+        task_repo
+            .create_task(tagged_task_request("Review", &["work"]))
+            .await
+            .unwrap();
+
+        // Names are trimmed and de-duplicated on the task as well
+        assert_eq!(report.tags.as_deref(), Some(r#"["work","urgent"]"#));
+
+        let tags: Vec<(String, i64)> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|tag| (tag.name, tag.task_count))
+            .collect();
+        assert_eq!(
+            tags,
+            [
+                ("home".to_string(), 1),
+                ("urgent".to_string(), 1),
+                ("work".to_string(), 2)
+            ]
+        );
+
+        let any = task_repo
+            .find_by_tags(&names(&["urgent", "home"]), false)
+            .await
+            .unwrap();
+        assert_eq!(any.len(), 2);
+
+        let all = task_repo
+            .find_by_tags(&names(&["urgent", "work"]), true)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].id, report.id);
+    }
+
+    #[tokio::test]
+    async fn test_rename_and_merge_tags() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TagRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db);
+
+        let task = task_repo
+            .create_task(tagged_task_request(
+                "Plan sprint",
+                &["wrk", "planning", "Work"],
+            ))
+            .await
+            .unwrap();
+
+        // Renaming onto an existing tag must go through a merge
+        assert!(repo.rename_tag("wrk", "Work").await.is_err());
+
+        repo.rename_tag("planning", "plan").await.unwrap();
+        let target = repo
+            .merge_tags(&names(&["wrk", "Work"]), "work")
+            .await
+            .unwrap();
+        assert_eq!(target.name, "work");
+
+        let task = task_repo.find_by_id(&task.id).await.unwrap().unwrap();
+        assert_eq!(task.tags.as_deref(), Some(r#"["work","plan"]"#));
+
+        let tags: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+        assert_eq!(tags, ["plan", "work"]);
+        assert!(repo.merge_tags(&names(&["missing"]), "work").await.is_err());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::database::repositories::task_audit_repository::TaskAuditRepository;
+use crate::database::repositories::task_repository::{TaskRepository, UpdateTaskRequest};
+use crate::undo::UndoHistory;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn rename_request(title: &str) -> UpdateTaskRequest {
+        UpdateTaskRequest {
+            title: Some(title.to_string()),
+            description: None,
+            priority: None,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            actual_time: None,
+            due_date: None,
+            scheduled_date: None,
+            clear_scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            completed_at: None,
+            effort: None,
+            impact: None,
+            expected_updated_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_history_records_changes_and_source() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let user_repo = TaskRepository::new(db.clone());
+        let ai_repo = TaskRepository::new(db.clone()).with_audit_source("ai");
+        let audit_repo = TaskAuditRepository::new(db);
+
+        let task = user_repo
+            .create_task(task_request("Draft roadmap"))
+            .await
+            .unwrap();
+        ai_repo
+            .update_task(&task.id, rename_request("Draft Q3 roadmap"))
+            .await
+            .unwrap();
+        user_repo.delete_task(&task.id).await.unwrap();
+
+        // Newest first, and kept after the task is gone
+        let history = audit_repo.get_history(&task.id).await.unwrap();
+        let actions: Vec<_> = history.iter().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, vec!["delete", "update", "create"]);
+
+        let rename = &history[1];
+        assert_eq!(rename.field.as_deref(), Some("title"));
+        assert_eq!(rename.old_value.as_deref(), Some("\"Draft roadmap\""));
+        assert_eq!(rename.new_value.as_deref(), Some("\"Draft Q3 roadmap\""));
+        assert_eq!(rename.source, "ai");
+        assert_eq!(history[0].source, "user");
+
+        assert!(TaskAuditRepository::validate_source("ai").is_ok());
+        assert!(TaskAuditRepository::validate_source("script").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_task_history_cleanup() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let audit_repo = TaskAuditRepository::new(db);
+
+        let task = task_repo
+            .create_task(task_request("Renew passport"))
+            .await
+            .unwrap();
+        task_repo.archive_task(&task.id).await.unwrap();
+        assert_eq!(audit_repo.get_history(&task.id).await.unwrap().len(), 2);
+
+        let last_week = chrono::Utc::now() - chrono::Duration::days(7);
+        assert_eq!(audit_repo.clear_older_than(last_week).await.unwrap(), 0);
+
+        let deleted = audit_repo
+            .clear_older_than(chrono::Utc::now() + chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+        assert!(audit_repo.get_history(&task.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_undo_and_redo_task_changes() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let history = UndoHistory::default();
+        let user_repo = TaskRepository::new(db.clone());
+        let ai_repo = TaskRepository::new(db.clone()).with_audit_source("ai");
+
+        let mut request = task_request("Pay invoices");
+        request.tags = Some(vec!["finance".to_string()]);
+        let kept = user_repo.create_task(request).await.unwrap();
+        let removed = user_repo
+            .create_task(task_request("Plan offsite"))
+            .await
+            .unwrap();
+        let subtask = user_repo
+            .create_subtask(&removed.id, task_request("Book venue"))
+            .await
+            .unwrap();
+
+        let mut complete = rename_request("Pay invoices");
+        complete.title = None;
+        complete.status = Some("completed".to_string());
+        ai_repo.update_task(&kept.id, complete).await.unwrap();
+        user_repo.delete_task(&removed.id).await.unwrap();
+
+        // The deletion comes back first, with its subtask reattached
+        let undone = history.undo(db.clone()).await.unwrap().unwrap();
+        assert!(undone.entries.iter().any(|e| e.action == "delete"));
+        let restored = user_repo.find_by_id(&removed.id).await.unwrap().unwrap();
+        assert_eq!(restored.title, "Plan offsite");
+        let subtask = user_repo.find_by_id(&subtask.id).await.unwrap().unwrap();
+        assert_eq!(subtask.parent_task_id, Some(removed.id.clone()));
+
+        // Then the AI's completion
+        let undone = history.undo(db.clone()).await.unwrap().unwrap();
+        assert!(undone.entries.iter().all(|e| e.source == "ai"));
+        let task = user_repo.find_by_id(&kept.id).await.unwrap().unwrap();
+        assert_eq!(task.status, "pending");
+        assert!(task.completed_at.is_none());
+        assert_eq!(task.tags, kept.tags);
+
+        history.redo(db.clone()).await.unwrap().unwrap();
+        let task = user_repo.find_by_id(&kept.id).await.unwrap().unwrap();
+        assert_eq!(task.status, "completed");
+
+        // A new change discards what is left to redo
+        user_repo
+            .update_task(&kept.id, rename_request("Pay all invoices"))
+            .await
+            .unwrap();
+        assert!(history.redo(db.clone()).await.unwrap().is_none());
+        assert!(user_repo.find_by_id(&removed.id).await.unwrap().is_some());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::database::repositories::task_list_repository::TaskListRepository;
+use crate::database::repositories::task_list_repository::{
+    BreakdownGroup, TaskListRepository, UpdateTaskListRequest,
+};
 use sea_orm::DbErr;
 
 #[cfg(test)]
@@ -6,6 +8,13 @@ mod task_list_repository_tests {
     use super::*;
     use crate::database::repositories::tests::setup_test_db;
 
+    fn rename(name: &str) -> UpdateTaskListRequest {
+        UpdateTaskListRequest {
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
     #[tokio::test]
     async fn test_validate_task_list_name_valid() {
         let db = setup_test_db().await.unwrap();
@@ -198,9 +207,7 @@ mod task_list_repository_tests {
 
         let created = repo.create_task_list("Old Name".to_string()).await.unwrap();
 
-        let result = repo
-            .update_task_list(&created.id, "New Name".to_string())
-            .await;
+        let result = repo.update_task_list(&created.id, rename("New Name")).await;
         assert!(result.is_ok());
 
         let updated = result.unwrap();
@@ -214,9 +221,7 @@ mod task_list_repository_tests {
 
         let default = repo.ensure_default_task_list().await.unwrap();
 
-        let result = repo
-            .update_task_list(&default.id, "New Name".to_string())
-            .await;
+        let result = repo.update_task_list(&default.id, rename("New Name")).await;
         assert!(result.is_err());
 
         if let Err(DbErr::Custom(msg)) = result {
@@ -226,6 +231,57 @@ mod task_list_repository_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_update_task_list_appearance() {
+        let db = setup_test_db().await.unwrap();
+        let repo = TaskListRepository::new(db);
+
+        // The default list can't be renamed but can be colored
+        let default = repo.ensure_default_task_list().await.unwrap();
+        let updated = repo
+            .update_task_list(
+                &default.id,
+                UpdateTaskListRequest {
+                    name: Some(default.name.clone()),
+                    color: Some("#3B82F6".to_string()),
+                    icon: Some("Briefcase".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.color.as_deref(), Some("#3b82f6"));
+        assert_eq!(updated.icon.as_deref(), Some("Briefcase"));
+
+        // Unset fields are kept, empty ones cleared
+        let updated = repo
+            .update_task_list(
+                &default.id,
+                UpdateTaskListRequest {
+                    icon: Some(String::new()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.color.as_deref(), Some("#3b82f6"));
+        assert!(updated.icon.is_none());
+
+        for invalid in [
+            UpdateTaskListRequest {
+                color: Some("blue".to_string()),
+                ..Default::default()
+            },
+            UpdateTaskListRequest {
+                icon: Some("<svg>".to_string()),
+                ..Default::default()
+            },
+        ] {
+            let result = repo.update_task_list(&default.id, invalid).await;
+            assert!(matches!(result, Err(DbErr::Custom(msg)) if msg.contains("VALIDATION_ERROR")));
+        }
+    }
+
     #[tokio::test]
     async fn test_delete_task_list() {
         let db = setup_test_db().await.unwrap();
@@ -303,4 +359,284 @@ mod task_list_repository_tests {
         let stats = result.unwrap();
         assert!(stats.total_lists >= 3);
     }
+
+    #[tokio::test]
+    async fn test_aging_stats_count_target_breaches() {
+        use crate::database::entities::{task_status_history, tasks};
+        use crate::database::repositories::task_repository::{CreateTaskRequest, TaskRepository};
+        use crate::database::repositories::tests::setup_migrated_test_db;
+        use sea_orm::{sea_query::Expr, ColumnTrait, EntityTrait, QueryFilter};
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TaskListRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db.clone());
+
+        let list = repo.create_task_list("Support".to_string()).await.unwrap();
+        let list = repo.set_targets(&list.id, Some(24), Some(8)).await.unwrap();
+        assert_eq!(list.target_backlog_hours, Some(24));
+        assert!(repo.set_targets(&list.id, Some(0), None).await.is_err());
+
+        let mut ids = Vec::new();
+        for (title, status) in [
+            ("Stale ticket", "pending"),
+            ("Fresh ticket", "pending"),
+            ("Slow fix", "in_progress"),
+        ] {
+            let task = task_repo
+                .create_task(CreateTaskRequest {
+                    title: title.to_string(),
+                    description: None,
+                    priority: 1,
+                    status: Some(status.to_string()),
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: None,
+                    due_date: None,
+                    scheduled_date: None,
+                    tags: None,
+                    project_id: None,
+                    parent_task_id: None,
+                    task_list_id: Some(list.id.clone()),
+                    periodic_template_id: None,
+                    is_periodic_instance: None,
+                    generation_date: None,
+                    effort: None,
+                    impact: None,
+                })
+                .await
+                .unwrap();
+            ids.push(task.id);
+        }
+
+        // Pretend the stale ticket and the slow fix entered their status a while ago
+        for (id, hours) in [(&ids[0], 48), (&ids[2], 10)] {
+            let since = chrono::Utc::now() - chrono::Duration::hours(hours);
+            tasks::Entity::update_many()
+                .col_expr(tasks::Column::CreatedAt, Expr::value(since))
+                .filter(tasks::Column::Id.eq(id.as_str()))
+                .exec(&*db)
+                .await
+                .unwrap();
+            task_status_history::Entity::update_many()
+                .col_expr(task_status_history::Column::ChangedAt, Expr::value(since))
+                .filter(task_status_history::Column::TaskId.eq(id.as_str()))
+                .exec(&*db)
+                .await
+                .unwrap();
+        }
+
+        let stats = repo.get_aging_stats(Some(&list.id)).await.unwrap();
+        assert_eq!(stats.len(), 1);
+        let stats = &stats[0];
+        assert_eq!(stats.backlog_count, 2);
+        assert_eq!(stats.backlog_breaches, 1);
+        assert!(stats.max_backlog_hours.unwrap() > 47.0);
+        assert_eq!(stats.in_progress_count, 1);
+        assert_eq!(stats.in_progress_breaches, 1);
+        assert_eq!(stats.completed_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_task_list() {
+        let db = setup_test_db().await.unwrap();
+        let repo = TaskListRepository::new(db);
+
+        let mut lists = Vec::new();
+        for name in ["X", "Y", "Z"] {
+            lists.push(repo.create_task_list(name.to_string()).await.unwrap());
+        }
+        assert!(lists[0].order_num < lists[1].order_num);
+        assert!(lists[1].order_num < lists[2].order_num);
+
+        repo.reorder_task_list(&lists[2].id, None, Some(&lists[0].id))
+            .await
+            .unwrap();
+        repo.reorder_task_list(&lists[1].id, Some(&lists[2].id), Some(&lists[0].id))
+            .await
+            .unwrap();
+
+        let names: Vec<String> = repo
+            .find_all_task_lists()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|list| list.name)
+            .collect();
+        assert_eq!(names, ["Z", "Y", "X"]);
+
+        let result = repo
+            .reorder_task_list(&lists[0].id, Some(&lists[0].id), None)
+            .await;
+        assert!(matches!(result, Err(DbErr::Custom(msg)) if msg.contains("VALIDATION_ERROR")));
+    }
+
+    #[tokio::test]
+    async fn test_reorder_task_lists() {
+        let db = setup_test_db().await.unwrap();
+        let repo = TaskListRepository::new(db);
+
+        let mut ids = Vec::new();
+        for name in ["X", "Y", "Z"] {
+            ids.push(repo.create_task_list(name.to_string()).await.unwrap().id);
+        }
+
+        // Lists left out keep their order after the given ones
+        let reordered = repo
+            .reorder_task_lists(&[ids[2].clone(), ids[0].clone()])
+            .await
+            .unwrap();
+        let names: Vec<&str> = reordered.iter().map(|list| list.name.as_str()).collect();
+        assert_eq!(names, ["Z", "X", "Y"]);
+        let names: Vec<String> = repo
+            .find_all_task_lists()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|list| list.name)
+            .collect();
+        assert_eq!(names, ["Z", "X", "Y"]);
+
+        let result = repo
+            .reorder_task_lists(&[ids[0].clone(), ids[0].clone()])
+            .await;
+        assert!(matches!(result, Err(DbErr::Custom(msg)) if msg.contains("VALIDATION_ERROR")));
+        let result = repo.reorder_task_lists(&["missing".to_string()]).await;
+        assert!(matches!(result, Err(DbErr::RecordNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_archive_task_list() {
+        use crate::database::repositories::task_repository::{CreateTaskRequest, TaskRepository};
+        use crate::database::repositories::tests::setup_migrated_test_db;
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TaskListRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db.clone());
+
+        let default = repo.ensure_default_task_list().await.unwrap();
+        let list = repo
+            .create_task_list("Old project".to_string())
+            .await
+            .unwrap();
+        task_repo
+            .create_task(CreateTaskRequest {
+                title: "Leftover".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: Some(list.id.clone()),
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(task_repo.get_task_stats().await.unwrap().total, 1);
+
+        let archived = repo.archive_task_list(&list.id).await.unwrap();
+        assert!(archived.is_archived);
+
+        // Archived lists are hidden and their tasks left out of the stats, but nothing is deleted
+        let active = repo.find_active_task_lists().await.unwrap();
+        assert!(active.iter().all(|l| l.id != list.id));
+        assert_eq!(repo.find_all_task_lists().await.unwrap().len(), 2);
+        assert_eq!(repo.get_task_list_stats().await.unwrap().total_lists, 1);
+        assert_eq!(task_repo.get_task_stats().await.unwrap().total, 0);
+        assert!(repo
+            .get_aging_stats(None)
+            .await
+            .unwrap()
+            .iter()
+            .all(|stats| stats.task_list_id != list.id));
+        assert_eq!(repo.get_aging_stats(Some(&list.id)).await.unwrap().len(), 1);
+
+        let restored = repo.unarchive_task_list(&list.id).await.unwrap();
+        assert!(!restored.is_archived);
+        assert_eq!(repo.find_active_task_lists().await.unwrap().len(), 2);
+        assert_eq!(task_repo.get_task_stats().await.unwrap().total, 1);
+
+        let result = repo.archive_task_list(&default.id).await;
+        assert!(matches!(result, Err(DbErr::Custom(msg)) if msg.contains("BUSINESS_RULE_ERROR")));
+        let result = repo.archive_task_list("missing").await;
+        assert!(matches!(result, Err(DbErr::RecordNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_breakdown() {
+        use crate::database::repositories::task_repository::{CreateTaskRequest, TaskRepository};
+        use crate::database::repositories::tests::setup_migrated_test_db;
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TaskListRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db.clone());
+
+        let work = repo.create_task_list("Work".to_string()).await.unwrap();
+        let home = repo.create_task_list("Home".to_string()).await.unwrap();
+        let yesterday = chrono::Utc::now() - chrono::Duration::days(1);
+        for (title, status, due_date, tag) in [
+            ("Late report", "pending", Some(yesterday), "urgent"),
+            ("Late but done", "completed", Some(yesterday), "urgent"),
+            ("Review", "in_progress", None, "review"),
+        ] {
+            let task = task_repo
+                .create_task(CreateTaskRequest {
+                    title: title.to_string(),
+                    description: None,
+                    priority: 1,
+                    status: Some(status.to_string()),
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: Some(60),
+                    due_date,
+                    scheduled_date: None,
+                    tags: Some(vec![tag.to_string()]),
+                    project_id: None,
+                    parent_task_id: None,
+                    task_list_id: Some(work.id.clone()),
+                    periodic_template_id: None,
+                    is_periodic_instance: None,
+                    generation_date: None,
+                    effort: None,
+                    impact: None,
+                })
+                .await
+                .unwrap();
+            task_repo.add_actual_time(&task.id, 45).await.unwrap();
+        }
+
+        let breakdown = repo.get_breakdown(BreakdownGroup::List).await.unwrap();
+        let work_stats = breakdown.iter().find(|b| b.id == work.id).unwrap();
+        assert_eq!(work_stats.total_tasks, 3);
+        assert_eq!(work_stats.status_counts.get("pending"), Some(&1));
+        assert_eq!(work_stats.status_counts.get("completed"), Some(&1));
+        assert_eq!(work_stats.overdue_count, 1);
+        assert_eq!(work_stats.estimated_minutes, 180);
+        assert_eq!(work_stats.actual_minutes, 135);
+        assert!(work_stats.last_activity_at.is_some());
+
+        // Empty lists are listed with nothing in them, archived ones are left out
+        let home_stats = breakdown.iter().find(|b| b.id == home.id).unwrap();
+        assert_eq!(home_stats.total_tasks, 0);
+        assert!(home_stats.status_counts.is_empty());
+        assert!(home_stats.last_activity_at.is_none());
+        repo.archive_task_list(&home.id).await.unwrap();
+        let breakdown = repo.get_breakdown(BreakdownGroup::List).await.unwrap();
+        assert!(breakdown.iter().all(|b| b.id != home.id));
+
+        let by_tag = repo.get_breakdown(BreakdownGroup::Tag).await.unwrap();
+        let names: Vec<&str> = by_tag.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, ["review", "urgent"]);
+        assert_eq!(by_tag[1].total_tasks, 2);
+        assert_eq!(by_tag[1].overdue_count, 1);
+    }
 }
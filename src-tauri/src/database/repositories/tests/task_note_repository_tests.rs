@@ -0,0 +1,75 @@
+use crate::database::repositories::task_note_repository::{
+    CreateTaskNoteRequest, TaskNoteRepository,
+};
+use crate::database::repositories::task_repository::TaskRepository;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn note_request(task_id: &str, content: &str) -> CreateTaskNoteRequest {
+        CreateTaskNoteRequest {
+            task_id: task_id.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_notes_form_a_log() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TaskNoteRepository::new(db);
+
+        let task = task_repo
+            .create_task(task_request("Fix flaky login test"))
+            .await
+            .unwrap();
+        let first = repo
+            .create_note(note_request(&task.id, " Reproduced on CI only "))
+            .await
+            .unwrap();
+        let second = repo
+            .create_note(note_request(&task.id, "Timeout is too short"))
+            .await
+            .unwrap();
+        assert_eq!(first.content, "Reproduced on CI only");
+        assert!(repo
+            .create_note(note_request(&task.id, "   "))
+            .await
+            .is_err());
+        assert!(repo
+            .create_note(note_request("missing", "Orphan"))
+            .await
+            .is_err());
+
+        let edited = repo
+            .update_note(&second.id, "Timeout is too short on slow runners")
+            .await
+            .unwrap();
+        assert_eq!(edited.created_at, second.created_at);
+        assert!(edited.updated_at >= second.updated_at);
+
+        let notes: Vec<String> = repo
+            .find_by_task(&task.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|note| note.content)
+            .collect();
+        assert_eq!(
+            notes,
+            vec![
+                "Reproduced on CI only",
+                "Timeout is too short on slow runners"
+            ]
+        );
+
+        repo.delete_note(&first.id).await.unwrap();
+        assert!(repo.delete_note(&first.id).await.is_err());
+        assert_eq!(repo.find_by_task(&task.id).await.unwrap().len(), 1);
+
+        task_repo.delete_task(&task.id).await.unwrap();
+        assert!(repo.find_by_task(&task.id).await.unwrap().is_empty());
+    }
+}
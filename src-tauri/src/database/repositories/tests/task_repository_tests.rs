@@ -1,16 +1,39 @@
 #[cfg(test)]
 mod tests {
-    use super::super::super::tests::setup_test_db;
+    use super::super::super::tests::{setup_migrated_test_db, setup_test_db, task_request};
+    use crate::backup::BackupService;
+    use crate::database::config::DatabaseConfig;
+    use crate::database::entities::task_dependencies;
+    use crate::database::migration::initialization::run_post_migration_initialization;
+    use crate::database::migration::run_migrations;
+    use crate::database::repositories::project_repository::{
+        CreateProjectRequest, ProjectRepository,
+    };
+    use crate::database::repositories::rank::spaced_rank;
     use crate::database::repositories::task_repository::{
-        CreateTaskRequest, TaskRepository, UpdateTaskRequest,
+        CreateTaskRequest, TaskFilter, TaskRepository, UpdateTaskRequest,
     };
     use chrono::Utc;
 
+    async fn create_project(db: std::sync::Arc<sea_orm::DatabaseConnection>) -> String {
+        ProjectRepository::new(db)
+            .create_project(CreateProjectRequest {
+                name: "Project 1".to_string(),
+                description: None,
+                status: None,
+                target_date: None,
+            })
+            .await
+            .expect("Failed to create project")
+            .id
+    }
+
     #[tokio::test]
     async fn test_create_task() {
         let db = setup_test_db()
             .await
             .expect("Failed to setup test database");
+        let project_id = create_project(db.clone()).await;
         let repo = TaskRepository::new(db);
 
         let request = CreateTaskRequest {
@@ -23,9 +46,15 @@ mod tests {
             due_date: Some(Utc::now()),
             scheduled_date: Some(Utc::now()),
             tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
-            project_id: Some("project1".to_string()),
+            project_id: Some(project_id.clone()),
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let result = repo.create_task(request).await;
@@ -59,6 +88,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let created_task = repo
@@ -99,6 +134,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let created_task = repo
@@ -122,6 +163,11 @@ mod tests {
             parent_task_id: None,
             task_list_id: None,
             completed_at: None,
+            order_num: None,
+            clear_scheduled_date: None,
+            effort: None,
+            impact: None,
+            expected_updated_at: None,
         };
 
         let updated_task = repo
@@ -145,6 +191,7 @@ mod tests {
         let db = setup_test_db()
             .await
             .expect("Failed to setup test database");
+        let project_id = create_project(db.clone()).await;
         let repo = TaskRepository::new(db);
 
         // Create multiple tasks
@@ -158,9 +205,15 @@ mod tests {
             due_date: None,
             scheduled_date: None,
             tags: None,
-            project_id: Some("project1".to_string()),
+            project_id: Some(project_id.clone()),
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let request2 = CreateTaskRequest {
@@ -173,9 +226,15 @@ mod tests {
             due_date: None,
             scheduled_date: None,
             tags: None,
-            project_id: Some("project1".to_string()),
+            project_id: Some(project_id.clone()),
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         repo.create_task(request1)
@@ -201,12 +260,12 @@ mod tests {
 
         // Find tasks by project
         let project_tasks = repo
-            .find_all(None, Some("project1"))
+            .find_all(None, Some(&project_id))
             .await
             .expect("Failed to find project tasks");
         assert!(project_tasks
             .iter()
-            .all(|t| t.project_id == Some("project1".to_string())));
+            .all(|t| t.project_id.as_ref() == Some(&project_id)));
     }
 
     #[tokio::test]
@@ -230,6 +289,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         repo.create_task(request)
@@ -266,6 +331,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let created_task = repo
@@ -307,6 +378,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let request2 = CreateTaskRequest {
@@ -322,6 +399,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         repo.create_task(request1)
@@ -373,6 +456,12 @@ mod tests {
                 project_id: None,
                 parent_task_id: None,
                 task_list_id: None,
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
             },
             CreateTaskRequest {
                 title: "In Progress Task".to_string(),
@@ -387,6 +476,12 @@ mod tests {
                 project_id: None,
                 parent_task_id: None,
                 task_list_id: None,
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
             },
             CreateTaskRequest {
                 title: "Completed Task".to_string(),
@@ -401,6 +496,12 @@ mod tests {
                 project_id: None,
                 parent_task_id: None,
                 task_list_id: None,
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
             },
         ];
 
@@ -457,6 +558,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: Some(default_task_list.id.clone()),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let request2 = CreateTaskRequest {
@@ -472,6 +579,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: Some(custom_task_list.id.clone()),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         repo.create_task(request1)
@@ -537,6 +650,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: Some(default_task_list.id.clone()),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let created_task = repo
@@ -589,6 +708,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: Some(default_task_list.id),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let created_task = repo
@@ -636,7 +761,13 @@ mod tests {
             tags: None,
             project_id: None,
             parent_task_id: None,
-            task_list_id: None, // This will be null in the database
+            task_list_id: None, // This will be null in the database,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let request2 = CreateTaskRequest {
@@ -651,7 +782,13 @@ mod tests {
             tags: None,
             project_id: None,
             parent_task_id: None,
-            task_list_id: None, // This will be null in the database
+            task_list_id: None, // This will be null in the database,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         // Create the tasks - they should get the default task list ID due to our create_task logic
@@ -678,6 +815,11 @@ mod tests {
             parent_task_id: None,
             task_list_id: Some("".to_string()), // Empty string will be treated as null
             completed_at: None,
+            order_num: None,
+            clear_scheduled_date: None,
+            effort: None,
+            impact: None,
+            expected_updated_at: None,
         };
 
         let update_request2 = UpdateTaskRequest {
@@ -695,6 +837,11 @@ mod tests {
             parent_task_id: None,
             task_list_id: Some("".to_string()), // Empty string will be treated as null
             completed_at: None,
+            order_num: None,
+            clear_scheduled_date: None,
+            effort: None,
+            impact: None,
+            expected_updated_at: None,
         };
 
         // Update tasks to set task_list_id to null
@@ -761,6 +908,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: Some(custom_task_list.id.clone()),
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let task_with_list = repo
@@ -784,6 +937,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let task_without_list = repo
@@ -793,4 +952,630 @@ mod tests {
 
         assert_eq!(task_without_list.task_list_id, Some(default_task_list.id));
     }
+
+    fn subtask_request(title: &str, time_estimate: i32) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority: 1,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: Some(time_estimate),
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_tree_rolls_up_subtasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let root = repo
+            .create_task(subtask_request("Release", 30))
+            .await
+            .expect("Failed to create root task");
+        let child = repo
+            .create_subtask(&root.id, subtask_request("Write changelog", 20))
+            .await
+            .expect("Failed to create subtask");
+        let grandchild = repo
+            .create_subtask(&child.id, subtask_request("Collect PR titles", 10))
+            .await
+            .expect("Failed to create nested subtask");
+
+        assert_eq!(child.parent_task_id, Some(root.id.clone()));
+        assert_eq!(child.task_list_id, root.task_list_id);
+
+        repo.update_task(
+            &grandchild.id,
+            UpdateTaskRequest {
+                title: None,
+                description: None,
+                priority: None,
+                status: Some("completed".to_string()),
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                actual_time: None,
+                due_date: None,
+                scheduled_date: None,
+                clear_scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                completed_at: None,
+                effort: None,
+                impact: None,
+                expected_updated_at: None,
+            },
+        )
+        .await
+        .expect("Failed to complete subtask");
+
+        let tree = repo
+            .get_task_tree(&root.id)
+            .await
+            .expect("Failed to get task tree")
+            .expect("Tree should exist");
+
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].children.len(), 1);
+        assert_eq!(tree.rollup.total_subtasks, 2);
+        assert_eq!(tree.rollup.completed_subtasks, 1);
+        assert_eq!(tree.rollup.total_time_estimate, 60);
+        assert_eq!(tree.rollup.completion_percentage, 50.0);
+    }
+
+    #[tokio::test]
+    async fn test_reparent_task_rejects_cycles() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let root = repo
+            .create_task(subtask_request("Root", 0))
+            .await
+            .expect("Failed to create root task");
+        let child = repo
+            .create_subtask(&root.id, subtask_request("Child", 0))
+            .await
+            .expect("Failed to create subtask");
+        let grandchild = repo
+            .create_subtask(&child.id, subtask_request("Grandchild", 0))
+            .await
+            .expect("Failed to create nested subtask");
+
+        assert!(repo.reparent_task(&root.id, Some(&root.id)).await.is_err());
+        assert!(repo.reparent_task(&root.id, Some(&grandchild.id)).await.is_err());
+
+        let moved = repo
+            .reparent_task(&grandchild.id, Some(&root.id))
+            .await
+            .expect("Moving a subtask up should succeed");
+        assert_eq!(moved.parent_task_id, Some(root.id.clone()));
+
+        let detached = repo
+            .reparent_task(&child.id, None)
+            .await
+            .expect("Detaching a subtask should succeed");
+        assert_eq!(detached.parent_task_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_find_by_filter_combines_criteria() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let mut urgent = subtask_request("Fix login bug", 30);
+        urgent.priority = 3;
+        urgent.tags = Some(vec!["bug".to_string(), "auth".to_string()]);
+        let urgent = repo.create_task(urgent).await.expect("Failed to create task");
+
+        let mut minor = subtask_request("Fix typo", 5);
+        minor.tags = Some(vec!["bug".to_string()]);
+        repo.create_task(minor).await.expect("Failed to create task");
+
+        let mut docs = subtask_request("Write docs", 60);
+        docs.priority = 3;
+        docs.tags = Some(vec!["docs".to_string()]);
+        repo.create_task(docs).await.expect("Failed to create task");
+
+        let filter = TaskFilter {
+            min_priority: Some(2),
+            tags: Some(vec!["bug".to_string()]),
+            ..Default::default()
+        };
+        let found = repo
+            .find_by_filter(&filter)
+            .await
+            .expect("Failed to filter tasks");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, urgent.id);
+
+        let filter = TaskFilter {
+            search: Some("Fix".to_string()),
+            ..Default::default()
+        };
+        let found = repo
+            .find_by_filter(&filter)
+            .await
+            .expect("Failed to filter tasks");
+        assert_eq!(found.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_priority_matrix_buckets_scored_tasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let mut quick_win = subtask_request("Fix broken signup link", 15);
+        quick_win.effort = Some(2);
+        quick_win.impact = Some(9);
+        quick_win.due_date = Some(Utc::now());
+        let quick_win = repo.create_task(quick_win).await.expect("Failed to create task");
+
+        let mut big_bet = subtask_request("Rewrite billing", 600);
+        big_bet.effort = Some(9);
+        big_bet.impact = Some(8);
+        let big_bet = repo.create_task(big_bet).await.expect("Failed to create task");
+
+        let mut chore = subtask_request("Tidy wiki", 30);
+        chore.effort = Some(3);
+        chore.impact = Some(2);
+        repo.create_task(chore).await.expect("Failed to create task");
+
+        repo.create_task(subtask_request("Unscored", 10))
+            .await
+            .expect("Failed to create task");
+
+        let mut invalid = subtask_request("Out of range", 10);
+        invalid.effort = Some(11);
+        assert!(repo.create_task(invalid).await.is_err());
+
+        let matrix = repo
+            .get_priority_matrix(None)
+            .await
+            .expect("Failed to get priority matrix");
+
+        assert_eq!(matrix.effort_impact.quick_wins.len(), 1);
+        assert_eq!(matrix.effort_impact.major_projects[0].id, big_bet.id);
+        assert_eq!(matrix.effort_impact.fill_ins.len(), 1);
+        assert_eq!(matrix.unscored.len(), 1);
+        assert_eq!(matrix.eisenhower.do_first[0].id, quick_win.id);
+        assert_eq!(matrix.eisenhower.schedule[0].id, big_bet.id);
+        assert_eq!(matrix.eisenhower.eliminate.len(), 2);
+
+        let recommended = repo.recommend_tasks(2).await.expect("Failed to recommend");
+        assert_eq!(recommended[0].id, quick_win.id);
+        assert_eq!(recommended[1].id, big_bet.id);
+    }
+
+    #[tokio::test]
+    async fn test_archive_task_hides_task_and_subtasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let parent = repo
+            .create_task(subtask_request("Old launch", 60))
+            .await
+            .expect("Failed to create task");
+        let mut child = subtask_request("Old launch checklist", 15);
+        child.parent_task_id = Some(parent.id.clone());
+        let child = repo.create_task(child).await.expect("Failed to create task");
+        repo.create_task(subtask_request("Current work", 30))
+            .await
+            .expect("Failed to create task");
+
+        let archived = repo
+            .archive_task(&parent.id)
+            .await
+            .expect("Failed to archive task");
+        assert!(archived.archived_at.is_some());
+
+        let active = repo
+            .find_all(None, None)
+            .await
+            .expect("Failed to find tasks");
+        assert_eq!(active.len(), 1);
+        assert!(repo.search_tasks("launch").await.unwrap().is_empty());
+        assert_eq!(repo.find_archived(None).await.unwrap().len(), 2);
+        assert_eq!(repo.get_all_tasks().await.unwrap().len(), 3);
+
+        repo.unarchive_task(&parent.id)
+            .await
+            .expect("Failed to unarchive task");
+        let restored = repo.find_by_id(&child.id).await.unwrap().unwrap();
+        assert!(restored.archived_at.is_none());
+        assert_eq!(repo.find_all(None, None).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_rejects_cycles() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let mut ids = Vec::new();
+        for title in ["Design", "Build", "Ship"] {
+            let task = repo
+                .create_task(subtask_request(title, 60))
+                .await
+                .expect("Failed to create task");
+            ids.push(task.id);
+        }
+
+        // Ship depends on Build, which depends on Design
+        repo.add_dependency(&ids[1], &ids[0]).await.unwrap();
+        repo.add_dependency(&ids[2], &ids[1]).await.unwrap();
+
+        assert!(repo.add_dependency(&ids[0], &ids[0]).await.is_err());
+        let err = repo
+            .add_dependency(&ids[0], &ids[2])
+            .await
+            .expect_err("Cycle should be rejected");
+        assert!(err
+            .to_string()
+            .contains(r#""Design" -> "Ship" -> "Build" -> "Design""#));
+
+        let report = repo.validate_dependency_graph().await.unwrap();
+        assert!(report.is_valid);
+        assert_eq!(report.dependency_count, 2);
+
+        // Data imported from elsewhere bypasses the check and is caught by validation
+        repo.import_dependency(task_dependencies::Model {
+            id: "legacy-cycle".to_string(),
+            task_id: ids[0].clone(),
+            depends_on_id: ids[2].clone(),
+            relation_type: "depends_on".to_string(),
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+        let report = repo.validate_dependency_graph().await.unwrap();
+        assert!(!report.is_valid);
+        assert_eq!(report.cycles.len(), 1);
+        assert_eq!(report.cycles[0].task_ids.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_typed_relations() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let mut ids = Vec::new();
+        for title in ["Migrate", "Deploy", "Runbook", "Redeploy"] {
+            let task = repo
+                .create_task(subtask_request(title, 60))
+                .await
+                .expect("Failed to create task");
+            ids.push(task.id);
+        }
+
+        // Blocking another task is the same as the other task depending on it
+        repo.add_relation(&ids[0], &ids[1], "blocks").await.unwrap();
+        let dependencies = repo.get_dependencies(&ids[1]).await.unwrap();
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].id, ids[0]);
+        assert!(repo
+            .add_relation(&ids[0], &ids[1], "depends_on")
+            .await
+            .is_err());
+
+        repo.add_relation(&ids[2], &ids[1], "relates_to")
+            .await
+            .unwrap();
+        assert!(repo
+            .add_relation(&ids[1], &ids[2], "relates_to")
+            .await
+            .is_err());
+        repo.add_relation(&ids[3], &ids[1], "duplicates")
+            .await
+            .unwrap();
+        assert!(repo
+            .add_relation(&ids[3], &ids[1], "mirrors")
+            .await
+            .is_err());
+
+        // Only ordering relations make up the dependency graph
+        let report = repo.validate_dependency_graph().await.unwrap();
+        assert_eq!(report.dependency_count, 1);
+
+        let relations = repo.get_relations(&ids[1], None).await.unwrap();
+        let summary: Vec<(&str, &str, &str)> = relations
+            .iter()
+            .map(|r| {
+                (
+                    r.relation_type.as_str(),
+                    r.direction.as_str(),
+                    r.task.title.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("blocks", "incoming", "Migrate"),
+                ("relates_to", "incoming", "Runbook"),
+                ("duplicates", "incoming", "Redeploy"),
+            ]
+        );
+        let duplicates = repo
+            .get_relations(&ids[3], Some("duplicates"))
+            .await
+            .unwrap();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].direction, "outgoing");
+
+        repo.remove_relation(&ids[1], &ids[2], "relates_to")
+            .await
+            .unwrap();
+        assert!(repo
+            .get_relations(&ids[2], Some("relates_to"))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_actual_time_and_order_updates_are_atomic() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+
+        let task = repo
+            .create_task(subtask_request("Timed", 60))
+            .await
+            .unwrap();
+
+        // Concurrent increments must all land
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let repo = TaskRepository::new(db.clone());
+                let id = task.id.clone();
+                tokio::spawn(async move { repo.add_actual_time(&id, 5).await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let task = repo.find_by_id(&task.id).await.unwrap().unwrap();
+        assert_eq!(task.actual_time, 50);
+
+        let task = repo.add_actual_time(&task.id, -80).await.unwrap();
+        assert_eq!(task.actual_time, 0);
+        assert!(repo.add_actual_time("missing", 5).await.is_err());
+
+        let mut ids = Vec::new();
+        for title in ["First", "Second", "Third"] {
+            let task = repo.create_task(subtask_request(title, 15)).await.unwrap();
+            ids.push(task.id);
+        }
+        ids.reverse();
+        assert_eq!(repo.reorder_tasks(&ids).await.unwrap(), 3);
+
+        for (index, id) in ids.iter().enumerate() {
+            let task = repo.find_by_id(id).await.unwrap().unwrap();
+            assert_eq!(task.order_num, spaced_rank(index));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reorders_from_two_connections_do_not_interleave() {
+        let files = tempfile::tempdir().unwrap();
+        let url = format!(
+            "sqlite://{}?mode=rwc",
+            files.path().join("kirapilot.db").display()
+        );
+        let connect = || {
+            DatabaseConfig::new()
+                .with_database_url(url.clone())
+                .with_max_connections(1)
+                .with_sqlx_logging(false)
+                .connect()
+        };
+        let app = std::sync::Arc::new(connect().await.unwrap());
+        run_migrations(&app).await.unwrap();
+        run_post_migration_initialization(&app).await.unwrap();
+        let cli = std::sync::Arc::new(connect().await.unwrap());
+
+        let app_repo = TaskRepository::new(app);
+        let mut ids = Vec::new();
+        for title in ["First", "Second", "Third", "Fourth"] {
+            ids.push(app_repo.create_task(task_request(title)).await.unwrap().id);
+        }
+        let reversed: Vec<String> = ids.iter().rev().cloned().collect();
+
+        let cli_repo = TaskRepository::new(cli);
+        let (from_app, from_cli) = tokio::join!(
+            app_repo.reorder_tasks(&ids),
+            cli_repo.reorder_tasks(&reversed)
+        );
+        assert_eq!(from_app.unwrap(), 4);
+        assert_eq!(from_cli.unwrap(), 4);
+
+        // Whichever reorder committed last wins as a whole
+        let mut tasks = Vec::new();
+        for id in &ids {
+            tasks.push(app_repo.find_by_id(id).await.unwrap().unwrap());
+        }
+        tasks.sort_by_key(|task| task.order_num);
+        let order: Vec<String> = tasks.iter().map(|task| task.id.clone()).collect();
+        assert!(order == ids || order == reversed);
+        let ranks: Vec<i32> = tasks.iter().map(|task| task.order_num).collect();
+        assert_eq!(ranks, (0..4).map(spaced_rank).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_update_task_rejects_stale_expected_updated_at() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let loaded = repo
+            .create_task(subtask_request("Shared", 30))
+            .await
+            .unwrap();
+        let rename = |title: &str| UpdateTaskRequest {
+            title: Some(title.to_string()),
+            description: None,
+            priority: None,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            actual_time: None,
+            due_date: None,
+            scheduled_date: None,
+            clear_scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            completed_at: None,
+            effort: None,
+            impact: None,
+            expected_updated_at: Some(loaded.updated_at),
+        };
+
+        // First writer wins, second one read the same version and must be told
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        let saved = repo.update_task(&loaded.id, rename("Mine")).await.unwrap();
+        assert_eq!(saved.title, "Mine");
+
+        let err = repo
+            .update_task(&loaded.id, rename("Theirs"))
+            .await
+            .expect_err("Stale update should conflict")
+            .to_string();
+        assert!(err.contains("CONFLICT_ERROR"));
+        assert!(err.contains(r#""title":"Mine""#));
+
+        let current = repo.find_by_id(&loaded.id).await.unwrap().unwrap();
+        assert_eq!(current.title, "Mine");
+
+        let mut retry = rename("Theirs");
+        retry.expected_updated_at = Some(current.updated_at);
+        assert_eq!(
+            repo.update_task(&loaded.id, retry).await.unwrap().title,
+            "Theirs"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reorder_task_between_neighbours() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+
+        let mut tasks = Vec::new();
+        for title in ["A", "B", "C"] {
+            tasks.push(repo.create_task(subtask_request(title, 15)).await.unwrap());
+        }
+        let (a, b, c) = (&tasks[0], &tasks[1], &tasks[2]);
+
+        // All tasks start at rank 0, so the first move renumbers the list
+        repo.reorder_task(&c.id, Some(&a.id), Some(&b.id))
+            .await
+            .unwrap();
+        let b_before = repo.find_by_id(&b.id).await.unwrap().unwrap();
+        assert_eq!(b_before.updated_at, b.updated_at);
+
+        // With gaps in place, only the moved task changes
+        let moved = repo
+            .reorder_task(&a.id, Some(&c.id), Some(&b.id))
+            .await
+            .unwrap();
+        let b_after = repo.find_by_id(&b.id).await.unwrap().unwrap();
+        assert_eq!(b_after.order_num, b_before.order_num);
+
+        let mut ordered = Vec::new();
+        for task in &tasks {
+            ordered.push(repo.find_by_id(&task.id).await.unwrap().unwrap());
+        }
+        ordered.sort_by_key(|task| task.order_num);
+        let titles: Vec<&str> = ordered.iter().map(|task| task.title.as_str()).collect();
+        assert_eq!(titles, ["C", "A", "B"]);
+        assert_eq!(moved.order_num, ordered[1].order_num);
+
+        let to_end = repo.reorder_task(&c.id, Some(&b.id), None).await.unwrap();
+        assert!(to_end.order_num > b_after.order_num);
+
+        assert!(repo
+            .reorder_task(&a.id, Some(&a.id), None)
+            .await
+            .unwrap_err()
+            .to_string()
+            .contains("VALIDATION_ERROR"));
+        assert!(repo
+            .reorder_task(&a.id, Some("missing"), None)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_status_history_survives_backup_round_trip() {
+        let files = tempfile::tempdir().unwrap();
+        let backup_path = files.path().join("backup.zip");
+        let backup_path = backup_path.to_str().unwrap();
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TaskRepository::new(db.clone());
+        for (title, status) in [("Draft agenda", "pending"), ("Book room", "completed")] {
+            repo.create_task(CreateTaskRequest {
+                status: Some(status.to_string()),
+                ..task_request(title)
+            })
+            .await
+            .unwrap();
+        }
+        let mut history = repo.get_all_status_history().await.unwrap();
+        assert_eq!(history.len(), 2);
+        BackupService::new(db)
+            .export_data(backup_path)
+            .await
+            .unwrap();
+
+        let restored_db = setup_migrated_test_db().await.unwrap();
+        BackupService::new(restored_db.clone())
+            .import_data(backup_path, true)
+            .await
+            .unwrap();
+
+        let mut restored = TaskRepository::new(restored_db)
+            .get_all_status_history()
+            .await
+            .unwrap();
+        history.sort_by(|a, b| a.id.cmp(&b.id));
+        restored.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(restored, history);
+    }
 }
@@ -0,0 +1,62 @@
+use crate::database::repositories::task_repository::TaskRepository;
+use crate::database::repositories::task_source_repository::{
+    RecordTaskSourceRequest, TaskSourceRepository,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn source_request(task_id: &str, source_type: &str) -> RecordTaskSourceRequest {
+        RecordTaskSourceRequest {
+            task_id: task_id.to_string(),
+            source_type: source_type.to_string(),
+            external_id: Some("<message-1@example.com>".to_string()),
+            url: Some(" ".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sources_are_recorded_per_task() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TaskSourceRepository::new(db);
+        let task = task_repo
+            .create_task(task_request("Reply to Sam"))
+            .await
+            .unwrap();
+        assert!(repo.find_by_task(&task.id).await.unwrap().is_none());
+
+        let source = repo
+            .record_source(source_request(&task.id, "email"))
+            .await
+            .unwrap();
+        assert_eq!(source.source_type, "email");
+        assert_eq!(
+            source.external_id.as_deref(),
+            Some("<message-1@example.com>")
+        );
+        assert_eq!(source.url, None);
+
+        let mut github = source_request(&task.id, "github");
+        github.external_id = Some("acme/app#7".to_string());
+        github.url = Some("https://github.com/acme/app/issues/7".to_string());
+        repo.record_source(github).await.unwrap();
+        let found = repo.find_by_task(&task.id).await.unwrap().unwrap();
+        assert_eq!(found.source_type, "github");
+        assert_eq!(
+            found.url.as_deref(),
+            Some("https://github.com/acme/app/issues/7")
+        );
+
+        assert!(repo
+            .record_source(source_request(&task.id, "fax"))
+            .await
+            .is_err());
+        assert!(repo
+            .record_source(source_request("missing", "email"))
+            .await
+            .is_err());
+    }
+}
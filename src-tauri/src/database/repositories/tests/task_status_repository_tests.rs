@@ -0,0 +1,216 @@
+use crate::database::repositories::task_repository::{
+    CreateTaskRequest, TaskRepository, UpdateTaskRequest,
+};
+use crate::database::repositories::task_status_repository::{
+    CreateTaskStatusRequest, TaskStatusRepository, UpdateTaskStatusRequest,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn task_request_with_status(title: &str, status: &str) -> CreateTaskRequest {
+        CreateTaskRequest {
+            status: Some(status.to_string()),
+            ..task_request(title)
+        }
+    }
+
+    fn status_update(status: &str) -> UpdateTaskRequest {
+        UpdateTaskRequest {
+            title: None,
+            description: None,
+            priority: None,
+            status: Some(status.to_string()),
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            actual_time: None,
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            completed_at: None,
+            clear_scheduled_date: None,
+            effort: None,
+            impact: None,
+            expected_updated_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tasks_only_accept_defined_statuses() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TaskStatusRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db);
+
+        let builtin: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(
+            builtin,
+            ["pending", "in_progress", "completed", "cancelled"]
+        );
+
+        assert!(task_repo
+            .create_task(task_request_with_status("Write spec", "review"))
+            .await
+            .is_err());
+
+        let review = repo
+            .create_status(CreateTaskStatusRequest {
+                name: "review".to_string(),
+                category: "doing".to_string(),
+                color: Some("#A855F7".to_string()),
+                order_num: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(review.color.as_deref(), Some("#a855f7"));
+        assert!(repo
+            .create_status(CreateTaskStatusRequest {
+                name: "blocked".to_string(),
+                category: "stuck".to_string(),
+                color: None,
+                order_num: None,
+            })
+            .await
+            .is_err());
+
+        let task = task_repo
+            .create_task(task_request_with_status("Write spec", "review"))
+            .await
+            .unwrap();
+        assert!(task_repo
+            .update_task(&task.id, status_update("shipped"))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rename_and_delete_custom_status_updates_tasks() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TaskStatusRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db);
+
+        let status = repo
+            .create_status(CreateTaskStatusRequest {
+                name: "review".to_string(),
+                category: "doing".to_string(),
+                color: None,
+                order_num: None,
+            })
+            .await
+            .unwrap();
+        let task = task_repo
+            .create_task(task_request_with_status("Write spec", "review"))
+            .await
+            .unwrap();
+
+        repo.update_status(
+            &status.id,
+            UpdateTaskStatusRequest {
+                name: Some("in_review".to_string()),
+                category: None,
+                color: None,
+                order_num: None,
+            },
+        )
+        .await
+        .unwrap();
+        let renamed = task_repo.find_by_id(&task.id).await.unwrap().unwrap();
+        assert_eq!(renamed.status, "in_review");
+
+        // Built-in statuses are protected, and in-use statuses need a replacement
+        let pending = repo.find_by_name("pending").await.unwrap().unwrap();
+        assert!(repo.delete_status(&pending.id, None).await.is_err());
+        assert!(repo.delete_status(&status.id, None).await.is_err());
+
+        let moved = repo
+            .delete_status(&status.id, Some("in_progress"))
+            .await
+            .unwrap();
+        assert_eq!(moved, 1);
+        let moved_task = task_repo.find_by_id(&task.id).await.unwrap().unwrap();
+        assert_eq!(moved_task.status, "in_progress");
+    }
+
+    #[tokio::test]
+    async fn test_custom_done_statuses_complete_tasks() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = TaskStatusRepository::new(db.clone());
+        let task_repo = TaskRepository::new(db.clone());
+
+        repo.create_status(CreateTaskStatusRequest {
+            name: "shipped".to_string(),
+            category: "done".to_string(),
+            color: None,
+            order_num: None,
+        })
+        .await
+        .unwrap();
+        assert!(TaskStatusRepository::is_completed_in(&*db, "shipped")
+            .await
+            .unwrap());
+        assert!(!TaskStatusRepository::is_completed_in(&*db, "cancelled")
+            .await
+            .unwrap());
+
+        let parent = task_repo
+            .create_task(task_request("Release 2.0"))
+            .await
+            .unwrap();
+        let shipped = task_repo
+            .create_task(CreateTaskRequest {
+                parent_task_id: Some(parent.id.clone()),
+                ..task_request("Publish installers")
+            })
+            .await
+            .unwrap();
+        let dropped = task_repo
+            .create_task(CreateTaskRequest {
+                parent_task_id: Some(parent.id.clone()),
+                ..task_request("Publish to the old mirror")
+            })
+            .await
+            .unwrap();
+
+        let shipped = task_repo
+            .update_task(&shipped.id, status_update("shipped"))
+            .await
+            .unwrap();
+        assert!(shipped.completed_at.is_some());
+        let dropped = task_repo
+            .update_task(&dropped.id, status_update("cancelled"))
+            .await
+            .unwrap();
+        assert!(dropped.completed_at.is_none());
+
+        // Done tasks leave the backlog; only the completed one counts towards progress
+        let backlog: Vec<String> = task_repo
+            .find_backlog()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+        assert_eq!(backlog, vec![parent.id.clone()]);
+        let tree = task_repo.get_task_tree(&parent.id).await.unwrap().unwrap();
+        assert_eq!(tree.rollup.total_subtasks, 2);
+        assert_eq!(tree.rollup.completed_subtasks, 1);
+        assert_eq!(task_repo.get_task_stats().await.unwrap().completed, 1);
+
+        let reopened = task_repo
+            .update_task(&shipped.id, status_update("pending"))
+            .await
+            .unwrap();
+        assert!(reopened.completed_at.is_none());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::database::repositories::task_repository::{CreateTaskRequest, TaskRepository};
+use crate::database::repositories::task_template_repository::{
+    InstantiateTaskTemplateRequest, SaveTaskAsTemplateRequest, TaskTemplateRepository,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+
+    fn detailed_task_request(title: &str, time_estimate: i32, tags: &[&str]) -> CreateTaskRequest {
+        CreateTaskRequest {
+            description: Some(format!("{} checklist", title)),
+            priority: 2,
+            time_estimate: Some(time_estimate),
+            tags: Some(tags.iter().map(|tag| tag.to_string()).collect()),
+            effort: Some(3),
+            ..task_request(title)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_task_as_template_and_instantiate() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TaskTemplateRepository::new(db);
+
+        let root = task_repo
+            .create_task(detailed_task_request("Release", 30, &["ops"]))
+            .await
+            .unwrap();
+        let child = task_repo
+            .create_subtask(
+                &root.id,
+                detailed_task_request("Write changelog", 20, &["docs"]),
+            )
+            .await
+            .unwrap();
+        task_repo
+            .create_subtask(
+                &child.id,
+                detailed_task_request("Collect PR titles", 10, &[]),
+            )
+            .await
+            .unwrap();
+
+        let template = repo
+            .save_task_as_template(SaveTaskAsTemplateRequest {
+                task_id: root.id.clone(),
+                name: "Release".to_string(),
+                description: None,
+            })
+            .await
+            .unwrap();
+
+        let instance = repo
+            .instantiate_template(
+                &template.id,
+                InstantiateTaskTemplateRequest {
+                    title: Some("Release 2.0".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_ne!(instance.id, root.id);
+        assert_eq!(instance.title, "Release 2.0");
+        assert_eq!(instance.effort, Some(3));
+
+        let tree = task_repo
+            .get_task_tree(&instance.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].task.title, "Write changelog");
+        assert_eq!(
+            tree.children[0].task.description.as_deref(),
+            Some("Write changelog checklist")
+        );
+        assert_eq!(tree.children[0].task.tags.as_deref(), Some(r#"["docs"]"#));
+        assert_eq!(tree.children[0].children.len(), 1);
+        assert_eq!(tree.rollup.total_time_estimate, 60);
+
+        let missing = repo
+            .save_task_as_template(SaveTaskAsTemplateRequest {
+                task_id: "missing".to_string(),
+                name: "Missing".to_string(),
+                description: None,
+            })
+            .await;
+        assert!(missing.is_err());
+    }
+}
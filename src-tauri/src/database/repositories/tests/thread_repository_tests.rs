@@ -0,0 +1,255 @@
+use crate::database::repositories::task_repository::TaskRepository;
+use crate::database::repositories::thread_repository::{
+    CreateThreadMessageRequest, CreateThreadRequest, CreatedEntity, MessageActionResults,
+    MessageLink, ThreadRepository, ToolCallResult,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+    use chrono::{Duration, Utc};
+
+    fn thread_request() -> CreateThreadRequest {
+        CreateThreadRequest {
+            assignment_type: Some("general".to_string()),
+            assignment_task_id: None,
+            assignment_date: None,
+            assignment_context: None,
+        }
+    }
+
+    fn assistant_message(
+        thread_id: &str,
+        minutes_ago: i64,
+        action_results: Option<MessageActionResults>,
+    ) -> CreateThreadMessageRequest {
+        CreateThreadMessageRequest {
+            thread_id: thread_id.to_string(),
+            r#type: "assistant".to_string(),
+            content: "Done.".to_string(),
+            reasoning: None,
+            actions: None,
+            suggestions: None,
+            tool_executions: None,
+            user_feedback: None,
+            action_results,
+            timestamp: Some(Utc::now() - Duration::minutes(minutes_ago)),
+        }
+    }
+
+    fn created_tasks(ids: &[&str]) -> MessageActionResults {
+        MessageActionResults {
+            tool_calls: vec![ToolCallResult {
+                tool: "create_task".to_string(),
+                arguments: None,
+                success: true,
+                error: None,
+            }],
+            created_entities: ids
+                .iter()
+                .map(|id| CreatedEntity {
+                    entity_type: "task".to_string(),
+                    id: id.to_string(),
+                    title: None,
+                })
+                .collect(),
+            links: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_action_results_round_trip() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = ThreadRepository::new(db);
+        let thread = repo.create_thread(thread_request()).await.unwrap();
+
+        let results = MessageActionResults {
+            links: vec![MessageLink {
+                label: "Write report".to_string(),
+                url: "kirapilot://task/abc".to_string(),
+            }],
+            ..created_tasks(&["abc"])
+        };
+        let message = repo
+            .create_message(assistant_message(&thread.id, 0, Some(results.clone())))
+            .await
+            .unwrap();
+
+        let stored = repo.find_message_by_id(&message.id).await.unwrap().unwrap();
+        let parsed: MessageActionResults =
+            serde_json::from_str(stored.action_results.as_deref().unwrap()).unwrap();
+        assert_eq!(parsed, results);
+    }
+
+    #[tokio::test]
+    async fn test_find_tasks_created_in_thread() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = ThreadRepository::new(db);
+        let thread = repo.create_thread(thread_request()).await.unwrap();
+        let other_thread = repo.create_thread(thread_request()).await.unwrap();
+        assert!(repo
+            .find_tasks_created_in_thread(&thread.id)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let first = task_repo.create_task(task_request("First")).await.unwrap();
+        let second = task_repo.create_task(task_request("Second")).await.unwrap();
+        let deleted = task_repo
+            .create_task(task_request("Deleted"))
+            .await
+            .unwrap();
+        let elsewhere = task_repo
+            .create_task(task_request("Elsewhere"))
+            .await
+            .unwrap();
+
+        repo.create_message(assistant_message(
+            &thread.id,
+            3,
+            Some(created_tasks(&[&second.id, &deleted.id])),
+        ))
+        .await
+        .unwrap();
+        repo.create_message(assistant_message(&thread.id, 2, None))
+            .await
+            .unwrap();
+        repo.create_message(assistant_message(
+            &thread.id,
+            1,
+            Some(created_tasks(&[&first.id, &second.id])),
+        ))
+        .await
+        .unwrap();
+        repo.create_message(assistant_message(
+            &other_thread.id,
+            0,
+            Some(created_tasks(&[&elsewhere.id])),
+        ))
+        .await
+        .unwrap();
+        task_repo.delete_task(&deleted.id).await.unwrap();
+
+        let titles: Vec<String> = repo
+            .find_tasks_created_in_thread(&thread.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect();
+        assert_eq!(titles, vec!["Second", "First"]);
+    }
+
+    #[tokio::test]
+    async fn test_message_pages_and_search() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = ThreadRepository::new(db);
+        let thread = repo.create_thread(thread_request()).await.unwrap();
+        let other_thread = repo.create_thread(thread_request()).await.unwrap();
+
+        for (minutes_ago, content) in [
+            (5, "Plan the release"),
+            (4, "Draft the release notes"),
+            (3, "Done."),
+            (2, "Review the notes"),
+            (1, "Done."),
+        ] {
+            repo.create_message(CreateThreadMessageRequest {
+                content: content.to_string(),
+                ..assistant_message(&thread.id, minutes_ago, None)
+            })
+            .await
+            .unwrap();
+        }
+        repo.create_message(CreateThreadMessageRequest {
+            content: "Release checklist".to_string(),
+            ..assistant_message(&other_thread.id, 0, None)
+        })
+        .await
+        .unwrap();
+
+        // The latest page comes first, each page oldest first
+        let latest = repo.find_messages_page(&thread.id, 2, None).await.unwrap();
+        let contents: Vec<&str> = latest.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["Review the notes", "Done."]);
+
+        let earlier = repo
+            .find_messages_page(&thread.id, 2, Some(&latest[0].id))
+            .await
+            .unwrap();
+        let contents: Vec<&str> = earlier.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["Draft the release notes", "Done."]);
+
+        let first = repo
+            .find_messages_page(&thread.id, 2, Some(&earlier[0].id))
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].content, "Plan the release");
+
+        // A cursor from another thread is rejected
+        let other_messages = repo.find_messages(&other_thread.id).await.unwrap();
+        assert!(repo
+            .find_messages_page(&thread.id, 2, Some(&other_messages[0].id))
+            .await
+            .is_err());
+
+        let found = repo.search_messages("release", None).await.unwrap();
+        let contents: Vec<&str> = found.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec![
+                "Release checklist",
+                "Draft the release notes",
+                "Plan the release"
+            ]
+        );
+        assert_eq!(
+            repo.search_messages("release", Some(&thread.id))
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(repo.search_messages("  ", None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_summary() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = ThreadRepository::new(db);
+        let thread = repo.create_thread(thread_request()).await.unwrap();
+        assert!(thread.summary.is_none());
+
+        let until = Utc::now();
+        let updated = repo
+            .update_summary(&thread.id, "Planned the week.".to_string(), until)
+            .await
+            .unwrap();
+        assert_eq!(updated.summary.as_deref(), Some("Planned the week."));
+        assert_eq!(updated.summarized_until, Some(until));
+
+        // The summary only rolls forward
+        assert!(repo
+            .update_summary(
+                &thread.id,
+                "Older summary.".to_string(),
+                until - Duration::minutes(5)
+            )
+            .await
+            .is_err());
+        assert!(repo
+            .update_summary(&thread.id, " ".to_string(), until)
+            .await
+            .is_err());
+        assert!(repo
+            .update_summary("missing", "Summary.".to_string(), until)
+            .await
+            .is_err());
+
+        let stored = repo.find_by_id(&thread.id).await.unwrap().unwrap();
+        assert_eq!(stored.summary.as_deref(), Some("Planned the week."));
+    }
+}
@@ -1,13 +1,19 @@
 #[cfg(test)]
 mod tests {
-    use super::super::super::tests::setup_test_db;
+    use super::super::super::tests::{setup_migrated_test_db, setup_test_db, task_request};
+    use crate::backup::BackupService;
+    use crate::database::entities::{session_pauses, tasks, time_sessions};
     use crate::database::repositories::{
+        preferences_repository::WorkingHours,
         task_repository::{CreateTaskRequest, TaskRepository},
         time_tracking_repository::{
-            CreateTimeSessionRequest, TimeTrackingRepository, UpdateTimeSessionRequest,
+            BillingGroupBy, CreateTimeSessionRequest, TimeBreak, TimeTrackingRepository,
+            UpdateTimeSessionRequest,
         },
+        TaskListRepository,
     };
     use chrono::Utc;
+    use sea_orm::{ActiveModelTrait, EntityTrait, Set};
 
     async fn create_test_task(repo: &TaskRepository) -> String {
         let request = CreateTaskRequest {
@@ -23,6 +29,12 @@ mod tests {
             project_id: None,
             parent_task_id: None,
             task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
         };
 
         let task = repo
@@ -46,6 +58,7 @@ mod tests {
             task_id: task_id.clone(),
             start_time: Utc::now(),
             notes: Some("Starting work on this task".to_string()),
+            auto_switch: false,
         };
 
         let result = repo.create_session(request).await;
@@ -76,6 +89,7 @@ mod tests {
             task_id: task_id.clone(),
             start_time: Utc::now(),
             notes: None,
+            auto_switch: false,
         };
 
         let created_session = repo
@@ -110,6 +124,7 @@ mod tests {
             task_id,
             start_time: Utc::now(),
             notes: None,
+            auto_switch: false,
         };
 
         let created_session = repo
@@ -155,6 +170,7 @@ mod tests {
             task_id,
             start_time: Utc::now(),
             notes: None,
+            auto_switch: false,
         };
 
         let created_session = repo
@@ -188,6 +204,7 @@ mod tests {
             task_id,
             start_time: Utc::now(),
             notes: None,
+            auto_switch: false,
         };
 
         let created_session = repo
@@ -197,17 +214,450 @@ mod tests {
 
         // Pause the session
         let paused_session = repo
-            .pause_session(&created_session.id)
+            .pause_session(&created_session.id, Some("Meeting".to_string()))
             .await
             .expect("Failed to pause session");
         assert!(!paused_session.is_active);
 
         // Resume the session
         let resumed_session = repo
-            .resume_session(&created_session.id)
+            .resume_session(&created_session.id, false)
             .await
             .expect("Failed to resume session");
         assert!(resumed_session.is_active);
+
+        let pauses = repo.find_session_pauses(&created_session.id).await.unwrap();
+        assert_eq!(pauses.len(), 1);
+        assert_eq!(pauses[0].reason.as_deref(), Some("meeting"));
+        assert!(pauses[0].ended_at.is_some());
+
+        assert!(repo
+            .pause_session(&created_session.id, Some("nap".to_string()))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_session_breaks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+        let session = repo
+            .create_session(CreateTimeSessionRequest {
+                task_id: task_id.clone(),
+                start_time: Utc::now() - chrono::Duration::hours(1),
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .unwrap();
+
+        assert!(repo.end_break(&session.id).await.is_err());
+        repo.start_break(&session.id, Some(" Coffee ".to_string()))
+            .await
+            .unwrap();
+        assert!(repo.start_break(&session.id, None).await.is_err());
+        repo.end_break(&session.id).await.unwrap();
+        assert!(repo.end_break(&session.id).await.is_err());
+
+        // Stopping the session ends a running break
+        repo.start_break(&session.id, None).await.unwrap();
+        let stopped = repo.stop_session(&session.id, None).await.unwrap();
+        let breaks: Vec<TimeBreak> =
+            serde_json::from_str(stopped.breaks.as_deref().unwrap()).unwrap();
+        assert_eq!(breaks.len(), 2);
+        assert_eq!(breaks[0].reason.as_deref(), Some("Coffee"));
+        assert!(breaks
+            .iter()
+            .all(|time_break| time_break.end_time.is_some()));
+        assert!(repo.start_break(&session.id, None).await.is_err());
+
+        // Breaks are left out of the work time
+        let other_task_id = create_test_task(&task_repo).await;
+        let start_time = chrono::DateTime::parse_from_rfc3339("2024-01-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let logged = repo
+            .log_session(
+                &other_task_id,
+                start_time,
+                start_time + chrono::Duration::hours(1),
+                None,
+            )
+            .await
+            .unwrap();
+        repo.update_session(
+            &logged.id,
+            UpdateTimeSessionRequest {
+                end_time: None,
+                paused_time: Some(300),
+                is_active: None,
+                notes: None,
+                breaks: Some(vec![TimeBreak {
+                    start_time: start_time + chrono::Duration::minutes(20),
+                    end_time: Some(start_time + chrono::Duration::minutes(30)),
+                    reason: None,
+                }]),
+            },
+        )
+        .await
+        .unwrap();
+
+        let stats = repo
+            .get_time_stats(start_time, start_time + chrono::Duration::days(1))
+            .await
+            .unwrap();
+        assert_eq!(stats.total_breaks, 1);
+        assert_eq!(stats.total_break_time_minutes, 15);
+        assert_eq!(stats.total_work_time_minutes, 45);
+        assert_eq!(repo.get_task_total_time(&other_task_id).await.unwrap(), 45);
+    }
+
+    #[tokio::test]
+    async fn test_single_running_session() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db.clone());
+
+        let task_id = create_test_task(&task_repo).await;
+        let start = |auto_switch: bool| CreateTimeSessionRequest {
+            task_id: task_id.clone(),
+            start_time: Utc::now(),
+            notes: None,
+            auto_switch,
+        };
+
+        let first = repo.create_session(start(false)).await.unwrap();
+        let rejected = repo.create_session(start(false)).await.unwrap_err();
+        assert!(rejected.to_string().contains("BUSINESS_RULE_ERROR"));
+
+        // Switching stops the running session
+        let second = repo.create_session(start(true)).await.unwrap();
+        let first = repo.find_by_id(&first.id).await.unwrap().unwrap();
+        assert!(!first.is_active);
+        assert!(first.end_time.is_some());
+        assert_eq!(
+            repo.find_any_active_session().await.unwrap().unwrap().id,
+            second.id
+        );
+
+        // A paused session doesn't block starting, but can't resume next to a running one
+        repo.pause_session(&second.id, None).await.unwrap();
+        let third = repo.create_session(start(false)).await.unwrap();
+        assert!(repo.resume_session(&second.id, false).await.is_err());
+        assert!(repo
+            .update_session(
+                &second.id,
+                UpdateTimeSessionRequest {
+                    end_time: None,
+                    paused_time: None,
+                    is_active: Some(true),
+                    notes: None,
+                    breaks: None,
+                },
+            )
+            .await
+            .is_err());
+
+        let resumed = repo.resume_session(&second.id, true).await.unwrap();
+        assert!(resumed.is_active);
+        let third = repo.find_by_id(&third.id).await.unwrap().unwrap();
+        assert!(!third.is_active);
+        assert!(third.end_time.is_some());
+
+        // The index rejects a second running row written past the repository
+        let duplicate = time_sessions::ActiveModel {
+            task_id: Set(task_id.clone()),
+            start_time: Set(Utc::now()),
+            paused_time: Set(0),
+            is_active: Set(true),
+            ..Default::default()
+        };
+        assert!(duplicate.insert(&*db).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_manual_entries_and_adjustments() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+        let day = Utc::now() - chrono::Duration::days(1);
+        let at = |minutes: i64| day + chrono::Duration::minutes(minutes);
+
+        let morning = repo
+            .add_manual_entry(&task_id, at(0), at(60), Some("Call".to_string()))
+            .await
+            .unwrap();
+        assert!(!morning.is_active);
+        assert_eq!(morning.end_time, Some(at(60)));
+
+        // Overlapping, backwards and future entries are rejected
+        assert!(repo
+            .add_manual_entry(&task_id, at(30), at(90), None)
+            .await
+            .is_err());
+        assert!(repo
+            .add_manual_entry(&task_id, at(120), at(90), None)
+            .await
+            .is_err());
+        assert!(repo
+            .add_manual_entry(
+                &task_id,
+                Utc::now(),
+                Utc::now() + chrono::Duration::hours(1),
+                None
+            )
+            .await
+            .is_err());
+        // Touching the end of another session is fine
+        let afternoon = repo
+            .add_manual_entry(&task_id, at(60), at(120), None)
+            .await
+            .unwrap();
+
+        assert!(repo
+            .adjust_session_times(&afternoon.id, Some(at(45)), None)
+            .await
+            .is_err());
+        let adjusted = repo
+            .adjust_session_times(&afternoon.id, Some(at(75)), Some(at(150)))
+            .await
+            .unwrap();
+        assert_eq!(adjusted.start_time, at(75));
+        assert_eq!(adjusted.end_time, Some(at(150)));
+
+        // A running session can move its start but has no end to change
+        let running = repo
+            .create_session(CreateTimeSessionRequest {
+                task_id: task_id.clone(),
+                start_time: Utc::now(),
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .unwrap();
+        assert!(repo
+            .adjust_session_times(&running.id, None, Some(Utc::now()))
+            .await
+            .is_err());
+        assert!(repo
+            .adjust_session_times(&running.id, Some(at(100)), None)
+            .await
+            .is_err());
+        let moved = repo
+            .adjust_session_times(&running.id, Some(at(600)), None)
+            .await
+            .unwrap();
+        assert_eq!(moved.start_time, at(600));
+        assert!(moved.end_time.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_split_and_merge_sessions() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db.clone());
+
+        let task_id = create_test_task(&task_repo).await;
+        let other_task_id = create_test_task(&task_repo).await;
+        let day = Utc::now() - chrono::Duration::days(1);
+        let at = |minutes: i64| day + chrono::Duration::minutes(minutes);
+
+        let session = repo
+            .log_session(&task_id, at(0), at(120), None)
+            .await
+            .unwrap();
+        // Paused from 0:50 to 1:10, across the split
+        session_pauses::ActiveModel {
+            session_id: Set(session.id.clone()),
+            reason: Set(Some("meeting".to_string())),
+            started_at: Set(at(50)),
+            ended_at: Set(Some(at(70))),
+            ..Default::default()
+        }
+        .insert(&*db)
+        .await
+        .unwrap();
+        let session = repo
+            .update_session(
+                &session.id,
+                UpdateTimeSessionRequest {
+                    end_time: None,
+                    paused_time: Some(20 * 60),
+                    is_active: None,
+                    notes: None,
+                    breaks: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(repo
+            .split_session(&session.id, at(120), None)
+            .await
+            .is_err());
+        let (earlier, later) = repo
+            .split_session(&session.id, at(60), Some(other_task_id.clone()))
+            .await
+            .unwrap();
+        assert_eq!(earlier.id, session.id);
+        assert_eq!(earlier.end_time, Some(at(60)));
+        assert_eq!(earlier.paused_time, 10 * 60);
+        assert_eq!(later.task_id, other_task_id);
+        assert_eq!(later.start_time, at(60));
+        assert_eq!(later.end_time, Some(at(120)));
+        assert_eq!(later.paused_time, 10 * 60);
+        let later_pauses = repo.find_session_pauses(&later.id).await.unwrap();
+        assert_eq!(later_pauses.len(), 1);
+        assert_eq!(later_pauses[0].started_at, at(60));
+        assert_eq!(later_pauses[0].reason.as_deref(), Some("meeting"));
+
+        // Only sessions of one task merge
+        assert!(repo
+            .merge_sessions(&[earlier.id.clone(), later.id.clone()])
+            .await
+            .is_err());
+
+        let last = repo
+            .log_session(&other_task_id, at(130), at(150), None)
+            .await
+            .unwrap();
+        let merged = repo
+            .merge_sessions(&[last.id.clone(), later.id.clone()])
+            .await
+            .unwrap();
+        assert_eq!(merged.id, later.id);
+        assert_eq!(merged.start_time, at(60));
+        assert_eq!(merged.end_time, Some(at(150)));
+        // The later pause plus the 10 minutes between the sessions
+        assert_eq!(merged.paused_time, 20 * 60);
+        assert_eq!(repo.find_session_pauses(&merged.id).await.unwrap().len(), 2);
+        assert!(repo.find_by_id(&last.id).await.unwrap().is_none());
+
+        // A session of another task in between blocks the merge
+        let after = repo
+            .log_session(&task_id, at(200), at(210), None)
+            .await
+            .unwrap();
+        assert!(repo
+            .merge_sessions(&[earlier.id.clone(), after.id.clone()])
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_record_idle_pause() {
+        let db = setup_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+        let start = Utc::now() - chrono::Duration::hours(1);
+        let session = repo
+            .create_session(CreateTimeSessionRequest {
+                task_id,
+                start_time: start,
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .expect("Failed to create session");
+
+        // Idle time reaching back before the session is clipped to its start
+        let session = repo
+            .record_idle_pause(
+                &session.id,
+                start - chrono::Duration::minutes(5),
+                start + chrono::Duration::minutes(10),
+            )
+            .await
+            .expect("Failed to record idle pause");
+        assert_eq!(session.paused_time, 600);
+        assert!(session.is_active);
+
+        let pauses = repo.find_session_pauses(&session.id).await.unwrap();
+        assert_eq!(pauses.len(), 1);
+        assert_eq!(pauses[0].reason.as_deref(), Some("idle"));
+        assert_eq!(
+            pauses[0].started_at.timestamp_millis(),
+            start.timestamp_millis()
+        );
+
+        assert!(repo
+            .record_idle_pause(&session.id, start - chrono::Duration::minutes(5), start)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_interruption_report() {
+        let db = setup_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+        let session = repo
+            .create_session(CreateTimeSessionRequest {
+                task_id,
+                start_time: Utc::now(),
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .unwrap();
+
+        for reason in [Some("interruption"), Some("interruption"), None] {
+            repo.pause_session(&session.id, reason.map(String::from))
+                .await
+                .unwrap();
+            repo.resume_session(&session.id, false).await.unwrap();
+        }
+        // Left paused when stopped; the stop ends the pause
+        repo.pause_session(&session.id, Some("lunch".to_string()))
+            .await
+            .unwrap();
+        repo.stop_session(&session.id, None).await.unwrap();
+        assert!(repo
+            .find_session_pauses(&session.id)
+            .await
+            .unwrap()
+            .iter()
+            .all(|pause| pause.ended_at.is_some()));
+
+        let now = Utc::now();
+        let report = repo
+            .get_interruption_report(
+                now - chrono::Duration::hours(1),
+                now + chrono::Duration::hours(1),
+                &Utc,
+            )
+            .await
+            .unwrap();
+        assert_eq!(report.totals.pause_count, 4);
+        assert_eq!(report.totals.interruption_count, 2);
+        assert_eq!(report.by_reason[0].reason.as_deref(), Some("interruption"));
+        assert_eq!(report.by_reason[0].count, 2);
+        assert_eq!(report.by_reason.len(), 3);
+        assert_eq!(report.by_day.len(), 1);
+        assert_eq!(report.by_day[0].date, now.date_naive());
+        assert_eq!(report.by_task_list.len(), 1);
+        assert_eq!(report.by_task_list[0].stats.interruption_count, 2);
     }
 
     #[tokio::test]
@@ -226,6 +676,7 @@ mod tests {
                 task_id: task_id.clone(),
                 start_time: Utc::now(),
                 notes: Some(format!("Session {}", i + 1)),
+                auto_switch: true,
             };
             repo.create_session(request)
                 .await
@@ -262,6 +713,7 @@ mod tests {
             task_id: task_id.clone(),
             start_time,
             notes: None,
+            auto_switch: false,
         };
 
         let session = repo
@@ -307,6 +759,7 @@ mod tests {
                 task_id: task_id.clone(),
                 start_time: Utc::now() - chrono::Duration::hours(i),
                 notes: Some(format!("Session {}", i + 1)),
+                auto_switch: true,
             };
             repo.create_session(request)
                 .await
@@ -340,6 +793,7 @@ mod tests {
             task_id,
             start_time: Utc::now(),
             notes: None,
+            auto_switch: false,
         };
 
         let created_session = repo
@@ -359,4 +813,506 @@ mod tests {
             .expect("Failed to query session");
         assert!(found_session.is_none());
     }
+
+    #[tokio::test]
+    async fn test_get_productivity_report() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+
+        // Monday 09:30-11:00 with 30 minutes paused, spread over both hours
+        let start_time = chrono::DateTime::parse_from_rfc3339("2024-01-01T09:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let session = repo
+            .create_session(CreateTimeSessionRequest {
+                task_id,
+                start_time,
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .expect("Failed to create session");
+        repo.update_session(
+            &session.id,
+            UpdateTimeSessionRequest {
+                end_time: Some(start_time + chrono::Duration::minutes(90)),
+                paused_time: Some(1800),
+                is_active: Some(false),
+                notes: None,
+                breaks: None,
+            },
+        )
+        .await
+        .expect("Failed to update session");
+
+        let report = repo
+            .get_productivity_report(
+                start_time - chrono::Duration::days(1),
+                start_time + chrono::Duration::days(1),
+                &WorkingHours {
+                    start: "10:00".to_string(),
+                    ..WorkingHours::default()
+                },
+                &Utc,
+            )
+            .await
+            .expect("Failed to build productivity report");
+
+        assert_eq!(report.utc_offset, "+00:00");
+        assert_eq!(report.total_sessions, 1);
+        assert_eq!(report.total_work_minutes, 60);
+        assert_eq!(report.total_break_minutes, 30);
+        // Work before 10:00 is outside working hours
+        assert_eq!(report.after_hours_work_minutes, 20);
+        assert_eq!(report.hourly.len(), 24);
+        assert_eq!(report.daily.len(), 7);
+        assert_eq!(report.heatmap.len(), 168);
+
+        assert_eq!(report.hourly[9].slot.work_minutes, 20);
+        assert_eq!(report.hourly[10].slot.work_minutes, 40);
+        assert_eq!(report.most_productive_hours, vec![10, 9]);
+        assert_eq!(report.best_days_of_week, vec!["Monday".to_string()]);
+
+        // Monday is day 1, so its 10:00 cell is at 24 + 10
+        let cell = &report.heatmap[34];
+        assert_eq!((cell.day_of_week, cell.hour), (1, 10));
+        assert_eq!(cell.slot.break_minutes, 20);
+        assert!((cell.slot.focus_score - 200.0 / 3.0).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn test_get_billing_report() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let list_repo = TaskListRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let mut task_ids = Vec::new();
+        for (name, rate) in [
+            ("Acme", Some(100.0)),
+            ("Globex, Inc.", Some(80.0)),
+            ("Own", None),
+        ] {
+            let list = list_repo.create_task_list(name.to_string()).await.unwrap();
+            assert!(list_repo
+                .set_hourly_rate(&list.id, Some(-1.0))
+                .await
+                .is_err());
+            list_repo.set_hourly_rate(&list.id, rate).await.unwrap();
+            let task_id = create_test_task(&task_repo).await;
+            task_repo
+                .move_task_to_list(&task_id, &list.id)
+                .await
+                .unwrap();
+            task_ids.push(task_id);
+        }
+
+        let at = |value: &str| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let acme = repo
+            .log_session(
+                &task_ids[0],
+                at("2024-01-01T10:00:00Z"),
+                at("2024-01-01T11:00:00Z"),
+                None,
+            )
+            .await
+            .unwrap();
+        repo.update_session(
+            &acme.id,
+            UpdateTimeSessionRequest {
+                end_time: None,
+                paused_time: Some(600),
+                is_active: None,
+                notes: None,
+                breaks: None,
+            },
+        )
+        .await
+        .unwrap();
+        for (task_id, start, end) in [
+            (&task_ids[0], "2024-01-02T10:00:00Z", "2024-01-02T10:30:00Z"),
+            (&task_ids[1], "2024-01-01T14:00:00Z", "2024-01-01T15:30:00Z"),
+            (&task_ids[2], "2024-01-01T16:00:00Z", "2024-01-01T17:00:00Z"),
+        ] {
+            repo.log_session(task_id, at(start), at(end), None)
+                .await
+                .unwrap();
+        }
+
+        let (start, end) = (at("2024-01-01T00:00:00Z"), at("2024-01-08T00:00:00Z"));
+        let report = repo
+            .get_billing_report(start, end, BillingGroupBy::TaskList, &Utc)
+            .await
+            .unwrap();
+        assert_eq!(report.lines.len(), 2);
+        assert_eq!(report.lines[0].label, "Acme");
+        assert_eq!(report.lines[0].billable_minutes, 80);
+        assert_eq!(report.lines[0].amount, 133.33);
+        assert_eq!(report.lines[1].billable_minutes, 90);
+        assert_eq!(report.lines[1].amount, 120.0);
+        assert_eq!(report.total_billable_minutes, 170);
+        assert!((report.total_amount - 253.33).abs() < 1e-9);
+
+        let csv = report.to_csv();
+        assert_eq!(
+            csv,
+            "task_list,hours,hourly_rate,amount\n\
+             Acme,1.33,100.00,133.33\n\
+             \"Globex, Inc.\",1.50,80.00,120.00\n\
+             Total,2.83,,253.33\n"
+        );
+
+        // A day with work at two rates has no single rate
+        let by_day = repo
+            .get_billing_report(start, end, BillingGroupBy::Day, &Utc)
+            .await
+            .unwrap();
+        assert_eq!(by_day.lines.len(), 2);
+        assert_eq!(by_day.lines[0].key, "2024-01-01");
+        assert_eq!(by_day.lines[0].hourly_rate, None);
+        assert_eq!(by_day.lines[0].amount, 203.33);
+        assert_eq!(by_day.lines[1].hourly_rate, Some(100.0));
+        assert_eq!(by_day.lines[1].amount, 50.0);
+    }
+
+    #[tokio::test]
+    async fn test_get_daily_aggregates() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db.clone());
+
+        let task_id = create_test_task(&task_repo).await;
+        let at = |value: &str| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        for (start, end) in [
+            ("2024-01-01T09:00:00Z", "2024-01-01T10:00:00Z"),
+            ("2024-01-01T23:30:00Z", "2024-01-02T00:30:00Z"),
+        ] {
+            repo.log_session(&task_id, at(start), at(end), None)
+                .await
+                .unwrap();
+        }
+        let done = tasks::Entity::find_by_id(&task_id)
+            .one(&*db)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut done: tasks::ActiveModel = done.into();
+        done.completed_at = Set(Some(at("2024-01-03T12:00:00Z")));
+        done.update(&*db).await.unwrap();
+
+        let start = at("2024-01-01T00:00:00Z");
+        let end = at("2024-01-08T00:00:00Z");
+        let days = repo.get_daily_aggregates(start, end, &Utc).await.unwrap();
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date.to_string(), "2024-01-01");
+        assert_eq!(days[0].work_minutes, 120);
+        assert_eq!(days[0].session_count, 2);
+        assert_eq!(days[0].completed_tasks, 0);
+        assert_eq!(days[1].date.to_string(), "2024-01-03");
+        assert_eq!(days[1].work_minutes, 0);
+        assert_eq!(days[1].completed_tasks, 1);
+
+        // Days follow the time zone: the late session moves to the next day
+        let tokyo = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        let days = repo.get_daily_aggregates(start, end, &tokyo).await.unwrap();
+        assert_eq!(days[0].date.to_string(), "2024-01-01");
+        assert_eq!(days[0].session_count, 1);
+        assert_eq!(days[1].date.to_string(), "2024-01-02");
+        assert_eq!(days[1].session_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_timesheet() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let list_repo = TaskListRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let list = list_repo
+            .create_task_list("Client".to_string())
+            .await
+            .unwrap();
+        let tagged = task_repo
+            .create_task(CreateTaskRequest {
+                title: "Write report".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: Some(vec!["writing".to_string(), "q1".to_string()]),
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: Some(list.id.clone()),
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+        let other_task_id = create_test_task(&task_repo).await;
+
+        let at = |value: &str| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        for (task_id, start, end, notes) in [
+            (
+                &tagged.id,
+                "2024-01-01T09:00:00Z",
+                "2024-01-01T10:00:00Z",
+                Some("Draft, v1"),
+            ),
+            (
+                &tagged.id,
+                "2024-01-01T13:00:00Z",
+                "2024-01-01T13:20:00Z",
+                None,
+            ),
+            (
+                &other_task_id,
+                "2024-01-02T09:00:00Z",
+                "2024-01-02T09:45:00Z",
+                None,
+            ),
+        ] {
+            repo.log_session(task_id, at(start), at(end), notes.map(str::to_string))
+                .await
+                .unwrap();
+        }
+        // Pauses and breaks are left out
+        let paused = repo
+            .log_session(
+                &other_task_id,
+                at("2024-01-02T11:00:00Z"),
+                at("2024-01-02T12:00:00Z"),
+                None,
+            )
+            .await
+            .unwrap();
+        repo.update_session(
+            &paused.id,
+            UpdateTimeSessionRequest {
+                end_time: None,
+                paused_time: Some(600),
+                is_active: None,
+                notes: None,
+                breaks: Some(vec![TimeBreak {
+                    start_time: at("2024-01-02T11:30:00Z"),
+                    end_time: Some(at("2024-01-02T11:35:00Z")),
+                    reason: None,
+                }]),
+            },
+        )
+        .await
+        .unwrap();
+        // Running sessions are not on the timesheet yet
+        repo.create_session(CreateTimeSessionRequest {
+            task_id: other_task_id.clone(),
+            start_time: at("2024-01-03T09:00:00Z"),
+            notes: None,
+            auto_switch: false,
+        })
+        .await
+        .unwrap();
+
+        let timesheet = repo
+            .get_timesheet(at("2024-01-01T00:00:00Z"), at("2024-01-08T00:00:00Z"), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(timesheet.utc_offset, "+00:00");
+        assert_eq!(timesheet.entries.len(), 2);
+        let first = &timesheet.entries[0];
+        assert_eq!(first.date.to_string(), "2024-01-01");
+        assert_eq!(first.task_list.as_deref(), Some("Client"));
+        assert_eq!(first.tags.len(), 2);
+        assert_eq!(first.minutes, 80);
+        assert_eq!(first.hours, 1.33);
+        assert_eq!(first.session_count, 2);
+        assert_eq!(first.notes, vec!["Draft, v1".to_string()]);
+        let second = &timesheet.entries[1];
+        assert_eq!(second.task_id, other_task_id);
+        assert_eq!(second.minutes, 45 + 45);
+        assert_eq!(timesheet.total_minutes, 170);
+
+        let csv = timesheet.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("date,task,task_list,tags,hours,minutes,notes")
+        );
+        assert!(lines.next().unwrap().ends_with(",1.33,80,\"Draft, v1\""));
+    }
+
+    #[tokio::test]
+    async fn test_complete_subtask_during_session() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db);
+
+        let task_id = create_test_task(&task_repo).await;
+        let other_task_id = create_test_task(&task_repo).await;
+        let subtask = |title: &str| CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority: 1,
+            status: None,
+            dependencies: None,
+            time_estimate: None,
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        };
+        let outline = task_repo
+            .create_subtask(&task_id, subtask("Outline"))
+            .await
+            .unwrap();
+        let draft = task_repo
+            .create_subtask(&task_id, subtask("Draft"))
+            .await
+            .unwrap();
+        let unrelated = task_repo
+            .create_subtask(&other_task_id, subtask("Unrelated"))
+            .await
+            .unwrap();
+
+        let session = repo
+            .create_session(CreateTimeSessionRequest {
+                task_id,
+                start_time: Utc::now(),
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .expect("Failed to create session");
+
+        let completion = repo
+            .complete_subtask_during_session(&session.id, &outline.id)
+            .await
+            .expect("Failed to complete subtask");
+        assert_eq!(completion.session_id, session.id);
+        let outline = task_repo.find_by_id(&outline.id).await.unwrap().unwrap();
+        assert_eq!(outline.status, "completed");
+        assert!(outline.completed_at.is_some());
+
+        // Already completed, or not part of the session's task
+        assert!(repo
+            .complete_subtask_during_session(&session.id, &outline.id)
+            .await
+            .is_err());
+        assert!(repo
+            .complete_subtask_during_session(&session.id, &unrelated.id)
+            .await
+            .is_err());
+
+        repo.stop_session(&session.id, None).await.unwrap();
+        assert!(repo
+            .complete_subtask_during_session(&session.id, &draft.id)
+            .await
+            .is_err());
+
+        let completed: Vec<String> = repo
+            .find_session_completed_subtasks(&session.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect();
+        assert_eq!(completed, ["Outline"]);
+
+        let report = repo
+            .get_productivity_report(
+                session.start_time - chrono::Duration::hours(1),
+                session.start_time + chrono::Duration::hours(1),
+                &WorkingHours::default(),
+                &Utc,
+            )
+            .await
+            .unwrap();
+        assert_eq!(report.total_subtasks_completed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_subtask_completions_survive_backup_round_trip() {
+        let files = tempfile::tempdir().unwrap();
+        let backup_path = files.path().join("backup.zip");
+        let backup_path = backup_path.to_str().unwrap();
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let repo = TimeTrackingRepository::new(db.clone());
+        let task_id = create_test_task(&task_repo).await;
+        let outline = task_repo
+            .create_subtask(&task_id, task_request("Outline"))
+            .await
+            .unwrap();
+        let session = repo
+            .create_session(CreateTimeSessionRequest {
+                task_id,
+                start_time: Utc::now(),
+                notes: None,
+                auto_switch: false,
+            })
+            .await
+            .unwrap();
+        repo.complete_subtask_during_session(&session.id, &outline.id)
+            .await
+            .unwrap();
+        repo.stop_session(&session.id, None).await.unwrap();
+        BackupService::new(db)
+            .export_data(backup_path)
+            .await
+            .unwrap();
+
+        let restored_db = setup_migrated_test_db().await.unwrap();
+        BackupService::new(restored_db.clone())
+            .import_data(backup_path, true)
+            .await
+            .unwrap();
+
+        let completed: Vec<String> = TimeTrackingRepository::new(restored_db)
+            .find_session_completed_subtasks(&session.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect();
+        assert_eq!(completed, ["Outline"]);
+    }
 }
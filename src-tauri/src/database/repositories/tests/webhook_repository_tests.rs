@@ -0,0 +1,161 @@
+use crate::database::repositories::webhook_repository::{
+    webhook_events, CreateWebhookRequest, UpdateWebhookRequest, WebhookRepository,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::tests::setup_test_db;
+    use chrono::{Duration, Utc};
+
+    fn create_request(url: &str, events: &[&str]) -> CreateWebhookRequest {
+        CreateWebhookRequest {
+            url: url.to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_webhooks_are_matched_by_event() {
+        let db = setup_test_db().await.unwrap();
+        let repo = WebhookRepository::new(db);
+
+        let created = repo
+            .create_webhook(create_request(
+                " https://hooks.example.com/kira ",
+                &["task.completed", "task.completed", "backup.finished"],
+            ))
+            .await
+            .unwrap();
+        assert!(created.secret.starts_with("whsec_"));
+        assert_eq!(created.webhook.secret, created.secret);
+        assert_eq!(created.webhook.url, "https://hooks.example.com/kira");
+        assert_eq!(
+            webhook_events(&created.webhook),
+            vec!["task.completed", "backup.finished"]
+        );
+        let json = serde_json::to_value(&created.webhook).unwrap();
+        assert!(json.get("secret").is_none());
+
+        let timer = repo
+            .create_webhook(create_request("http://localhost:8080", &["timer.stopped"]))
+            .await
+            .unwrap();
+
+        assert!(repo
+            .create_webhook(create_request("ftp://example.com", &["timer.stopped"]))
+            .await
+            .is_err());
+        assert!(repo
+            .create_webhook(create_request("https://example.com", &["task.deleted"]))
+            .await
+            .is_err());
+        assert!(repo
+            .create_webhook(create_request("https://example.com", &[]))
+            .await
+            .is_err());
+
+        let subscribed = repo.find_subscribed("task.completed").await.unwrap();
+        assert_eq!(subscribed.len(), 1);
+        assert_eq!(subscribed[0].id, created.webhook.id);
+
+        repo.update_webhook(
+            &timer.webhook.id,
+            UpdateWebhookRequest {
+                url: None,
+                events: None,
+                is_active: Some(false),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(repo
+            .find_subscribed("timer.stopped")
+            .await
+            .unwrap()
+            .is_empty());
+
+        repo.delete_webhook(&timer.webhook.id).await.unwrap();
+        assert!(repo.delete_webhook(&timer.webhook.id).await.is_err());
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delivery_attempts_are_logged() {
+        let db = setup_test_db().await.unwrap();
+        let repo = WebhookRepository::new(db);
+        let webhook = repo
+            .create_webhook(create_request(
+                "https://hooks.example.com",
+                &["timer.stopped"],
+            ))
+            .await
+            .unwrap()
+            .webhook;
+
+        let delivery = repo
+            .enqueue_delivery(&webhook.id, "timer.stopped", "{}".to_string(), Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(delivery.status, "pending");
+        let due = repo
+            .find_due_deliveries(Utc::now() + Duration::seconds(1), 10)
+            .await
+            .unwrap();
+        assert_eq!(due.len(), 1);
+
+        let retry_at = Utc::now() + Duration::minutes(5);
+        let retried = repo
+            .record_attempt(
+                &delivery.id,
+                Some(502),
+                Some("HTTP 502".to_string()),
+                Some(retry_at),
+            )
+            .await
+            .unwrap();
+        assert_eq!(retried.status, "pending");
+        assert_eq!(retried.attempts, 1);
+        assert!(repo
+            .find_due_deliveries(Utc::now(), 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let delivered = repo
+            .record_attempt(&delivery.id, Some(200), None, None)
+            .await
+            .unwrap();
+        assert_eq!(delivered.status, "delivered");
+        assert_eq!(delivered.attempts, 2);
+        assert!(delivered.error.is_none());
+        assert!(delivered.delivered_at.is_some());
+
+        let other = repo
+            .enqueue_delivery(&webhook.id, "timer.stopped", "{}".to_string(), Utc::now())
+            .await
+            .unwrap();
+        let failed = repo
+            .record_attempt(
+                &other.id,
+                None,
+                Some("Connection refused".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(failed.status, "failed");
+        assert!(failed.next_attempt_at.is_none());
+
+        assert_eq!(
+            repo.find_deliveries(&webhook.id, 10).await.unwrap().len(),
+            2
+        );
+        repo.delete_webhook(&webhook.id).await.unwrap();
+        assert!(repo
+            .find_deliveries(&webhook.id, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
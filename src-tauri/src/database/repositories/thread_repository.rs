@@ -1,12 +1,15 @@
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 use crate::database::entities::{thread_messages, threads, tasks};
 
+/// Most messages a search returns
+const MESSAGE_SEARCH_LIMIT: u64 = 100;
+
 /// Request structure for creating a new thread
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateThreadRequest {
@@ -37,9 +40,56 @@ pub struct CreateThreadMessageRequest {
     pub suggestions: Option<serde_json::Value>, // JSON serialized AISuggestion[]
     pub tool_executions: Option<serde_json::Value>, // JSON serialized ToolExecution[]
     pub user_feedback: Option<serde_json::Value>, // JSON serialized UserFeedback
+    #[serde(default)]
+    pub action_results: Option<MessageActionResults>,
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// What an assistant message actually did, kept apart from its text
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MessageActionResults {
+    #[serde(default)]
+    pub tool_calls: Vec<ToolCallResult>,
+    #[serde(default)]
+    pub created_entities: Vec<CreatedEntity>,
+    #[serde(default)]
+    pub links: Vec<MessageLink>,
+}
+
+/// A tool the assistant ran while answering
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCallResult {
+    pub tool: String,
+    pub arguments: Option<serde_json::Value>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Something the assistant created, e.g. a task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatedEntity {
+    pub entity_type: String, // 'task', 'task_list', 'time_session', ...
+    pub id: String,
+    pub title: Option<String>,
+}
+
+/// A link shown with the message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageLink {
+    pub label: String,
+    pub url: String,
+}
+
+impl MessageActionResults {
+    /// IDs of the tasks created by the message, in the order they were created
+    pub fn created_task_ids(&self) -> impl Iterator<Item = &str> {
+        self.created_entities
+            .iter()
+            .filter(|entity| entity.entity_type == "task")
+            .map(|entity| entity.id.as_str())
+    }
+}
+
 /// Thread repository for SeaORM-based database operations
 pub struct ThreadRepository {
     db: Arc<DatabaseConnection>,
@@ -181,6 +231,41 @@ impl ThreadRepository {
         txn.commit().await
     }
 
+    /// Store a thread's rolling summary, covering its messages up to `summarized_until`
+    ///
+    /// A summary never moves back, so one finished late can't replace a newer one.
+    pub async fn update_summary(
+        &self,
+        id: &str,
+        summary: String,
+        summarized_until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<threads::Model, DbErr> {
+        let thread = threads::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound(format!("Thread '{}' not found", id)))?;
+
+        if summary.trim().is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Thread summary cannot be empty".to_string(),
+            ));
+        }
+        if thread
+            .summarized_until
+            .is_some_and(|until| summarized_until < until)
+        {
+            return Err(DbErr::Custom(format!(
+                "BUSINESS_RULE_ERROR: Thread '{}' is already summarized past {}",
+                id, summarized_until
+            )));
+        }
+
+        let mut active_thread: threads::ActiveModel = thread.into();
+        active_thread.summary = Set(Some(summary));
+        active_thread.summarized_until = Set(Some(summarized_until));
+        active_thread.update(&*self.db).await
+    }
+
     /// Create a thread message
     pub async fn create_message(&self, request: CreateThreadMessageRequest) -> Result<thread_messages::Model, DbErr> {
         let txn = self.db.begin().await?;
@@ -201,6 +286,7 @@ impl ThreadRepository {
             suggestions: Set(request.suggestions.map(|s| serde_json::to_string(&s).unwrap_or_default())),
             tool_executions: Set(request.tool_executions.map(|te| serde_json::to_string(&te).unwrap_or_default())),
             user_feedback: Set(request.user_feedback.map(|uf| serde_json::to_string(&uf).unwrap_or_default())),
+            action_results: Set(request.action_results.map(|ar| serde_json::to_string(&ar).unwrap_or_default())),
             timestamp: Set(request.timestamp.unwrap_or_else(|| chrono::Utc::now())),
             ..Default::default()
         };
@@ -233,6 +319,116 @@ impl ThreadRepository {
             .await
     }
 
+    /// Find a page of a thread's messages, oldest first
+    ///
+    /// Returns the latest `limit` messages sent before the message `before`, or before now
+    /// without a cursor. Pass the first message of a page as `before` to load the one above it.
+    pub async fn find_messages_page(
+        &self,
+        thread_id: &str,
+        limit: u64,
+        before: Option<&str>,
+    ) -> Result<Vec<thread_messages::Model>, DbErr> {
+        let mut query =
+            thread_messages::Entity::find().filter(thread_messages::Column::ThreadId.eq(thread_id));
+
+        if let Some(before) = before {
+            let cursor = self
+                .find_message_by_id(before)
+                .await?
+                .filter(|message| message.thread_id == thread_id)
+                .ok_or_else(|| {
+                    DbErr::RecordNotFound(format!(
+                        "Message '{}' not found in thread '{}'",
+                        before, thread_id
+                    ))
+                })?;
+            // Messages sharing the cursor's timestamp are told apart by id
+            query = query.filter(
+                Condition::any()
+                    .add(thread_messages::Column::Timestamp.lt(cursor.timestamp))
+                    .add(
+                        Condition::all()
+                            .add(thread_messages::Column::Timestamp.eq(cursor.timestamp))
+                            .add(thread_messages::Column::Id.lt(cursor.id)),
+                    ),
+            );
+        }
+
+        let mut messages = query
+            .order_by_desc(thread_messages::Column::Timestamp)
+            .order_by_desc(thread_messages::Column::Id)
+            .limit(limit)
+            .all(&*self.db)
+            .await?;
+        messages.reverse();
+        Ok(messages)
+    }
+
+    /// Search message contents, in one thread or in all of them, newest first
+    pub async fn search_messages(
+        &self,
+        query: &str,
+        thread_id: Option<&str>,
+    ) -> Result<Vec<thread_messages::Model>, DbErr> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut select = thread_messages::Entity::find()
+            .filter(thread_messages::Column::Content.like(format!("%{}%", query)));
+        if let Some(thread_id) = thread_id {
+            select = select.filter(thread_messages::Column::ThreadId.eq(thread_id));
+        }
+
+        select
+            .order_by_desc(thread_messages::Column::Timestamp)
+            .limit(MESSAGE_SEARCH_LIMIT)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Find the tasks created from a thread's messages, in the order they were created
+    ///
+    /// Tasks deleted since are left out.
+    pub async fn find_tasks_created_in_thread(&self, thread_id: &str) -> Result<Vec<tasks::Model>, DbErr> {
+        let mut task_ids: Vec<String> = Vec::new();
+        for message in self.find_messages(thread_id).await? {
+            let Some(results) = message
+                .action_results
+                .as_deref()
+                .and_then(|json| serde_json::from_str::<MessageActionResults>(json).ok())
+            else {
+                continue;
+            };
+            for id in results.created_task_ids() {
+                if !task_ids.iter().any(|seen| seen == id) {
+                    task_ids.push(id.to_string());
+                }
+            }
+        }
+        if task_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut found = tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(task_ids.clone()))
+            .all(&*self.db)
+            .await?;
+        found.sort_by_key(|task| task_ids.iter().position(|id| *id == task.id));
+        Ok(found)
+    }
+
+    /// Find the user's messages in every thread, oldest first
+    pub async fn find_all_user_messages(&self) -> Result<Vec<thread_messages::Model>, DbErr> {
+        thread_messages::Entity::find()
+            .filter(thread_messages::Column::Type.eq("user"))
+            .order_by_asc(thread_messages::Column::Timestamp)
+            .all(&*self.db)
+            .await
+    }
+
     /// Find a specific message by ID
     pub async fn find_message_by_id(&self, id: &str) -> Result<Option<thread_messages::Model>, DbErr> {
         thread_messages::Entity::find_by_id(id).one(&*self.db).await
@@ -1,19 +1,31 @@
-use chrono::Timelike;
+use chrono::{Datelike, Offset, TimeZone, Timelike};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
-    QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr,
+    EntityTrait, FromQueryResult, QueryFilter, QueryOrder, QuerySelect, Set, Statement,
+    TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-use crate::database::entities::{tasks, time_sessions};
+use crate::database::entities::{
+    session_pauses, session_subtask_completions, task_lists, tasks, time_sessions,
+};
+use crate::database::repositories::preferences_repository::WorkingHours;
+use crate::database::repositories::retry::retry_on_busy;
+use crate::database::repositories::task_repository::{TaskRepository, UpdateTaskRequest};
+use crate::database::repositories::task_status_repository::TaskStatusRepository;
 
 /// Request structure for creating a new time session
+///
+/// Only one session runs at a time: with `auto_switch` the running session is stopped,
+/// otherwise starting while another session runs is rejected.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTimeSessionRequest {
     pub task_id: String,
     pub start_time: chrono::DateTime<chrono::Utc>,
     pub notes: Option<String>,
+    #[serde(default)]
+    pub auto_switch: bool,
 }
 
 /// Request structure for updating a time session
@@ -30,17 +42,27 @@ pub struct UpdateTimeSessionRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeBreak {
     pub start_time: chrono::DateTime<chrono::Utc>,
-    pub end_time: chrono::DateTime<chrono::Utc>,
+    /// Unset while the break runs
+    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
     pub reason: Option<String>,
 }
 
+/// Why a session was paused; pauses without a reason are allowed too
+pub const PAUSE_REASONS: [&str; 4] = ["meeting", "lunch", "interruption", "other"];
+
+/// Reason of the pauses recorded for idle time the user chose to discard
+pub const IDLE_PAUSE_REASON: &str = "idle";
+
 /// Time tracking statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeStats {
     pub total_sessions: u64,
     pub total_time_minutes: i64,
     pub total_work_time_minutes: i64,
+    /// Paused time and breaks, which are not counted as work
     pub total_break_time_minutes: i64,
+    /// Breaks taken during the sessions
+    pub total_breaks: u64,
     pub average_session_minutes: f64,
     pub average_productivity_score: f64,
     pub most_productive_hour: Option<u32>,
@@ -55,6 +77,405 @@ pub struct DayStats {
     pub session_count: u64,
 }
 
+/// Version of the [`ProductivityReport`] JSON schema; the major part changes on breaking changes
+pub const PRODUCTIVITY_REPORT_SCHEMA_VERSION: &str = "1.2";
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Tracked time within one time slot of a productivity report
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProductivitySlot {
+    /// Minutes worked in the slot, excluding pauses
+    pub work_minutes: i64,
+    /// Minutes paused or on a break in the slot
+    pub break_minutes: i64,
+    /// Sessions started in the slot
+    pub session_count: u64,
+    /// Subtasks checked off during sessions in the slot
+    pub subtasks_completed: u64,
+    /// Share of tracked time spent working, 0-100
+    pub focus_score: f64,
+}
+
+/// Productivity of one hour of the day, across all days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourlyProductivity {
+    pub hour: u32, // 0-23
+    #[serde(flatten)]
+    pub slot: ProductivitySlot,
+}
+
+/// Productivity of one day of the week, across all weeks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyProductivity {
+    pub day_of_week: u32, // 0 = Sunday
+    pub day_name: String,
+    #[serde(flatten)]
+    pub slot: ProductivitySlot,
+}
+
+/// One cell of the day-of-week by hour heatmap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeatmapCell {
+    pub day_of_week: u32, // 0 = Sunday
+    pub hour: u32,        // 0-23
+    #[serde(flatten)]
+    pub slot: ProductivitySlot,
+}
+
+/// Productivity patterns and heatmap data for external dashboards
+///
+/// Hours and days are in the time zone given by `utc_offset`. Sessions spanning several hours
+/// are split across them, with pauses and breaks spread evenly over the session. `hourly` always has 24
+/// entries, `daily` 7 and `heatmap` 168 (day-major), so dashboards get a stable shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductivityReport {
+    pub schema_version: String,
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    pub start_date: chrono::DateTime<chrono::Utc>,
+    pub end_date: chrono::DateTime<chrono::Utc>,
+    /// Offset of the time zone used for hours and days, e.g. "+02:00"
+    pub utc_offset: String,
+    pub total_sessions: u64,
+    pub total_work_minutes: i64,
+    pub total_break_minutes: i64,
+    /// Breaks taken during the sessions
+    pub total_breaks: u64,
+    pub total_subtasks_completed: u64,
+    /// Work done outside working hours, on days off or on holidays
+    pub after_hours_work_minutes: i64,
+    /// Up to three hours with the most work, best first
+    pub most_productive_hours: Vec<u32>,
+    /// Up to three days with the most work, best first
+    pub best_days_of_week: Vec<String>,
+    pub hourly: Vec<HourlyProductivity>,
+    pub daily: Vec<DailyProductivity>,
+    pub heatmap: Vec<HeatmapCell>,
+}
+
+/// Number and length of pauses, with interruptions counted separately
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PauseStats {
+    pub pause_count: u64,
+    pub pause_minutes: i64,
+    pub interruption_count: u64,
+    pub interruption_minutes: i64,
+}
+
+/// Pauses with one reason; `reason` is unset for pauses without one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PauseReasonStats {
+    pub reason: Option<String>,
+    pub count: u64,
+    pub minutes: i64,
+}
+
+/// Pauses that started on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyPauseStats {
+    pub date: chrono::NaiveDate,
+    #[serde(flatten)]
+    pub stats: PauseStats,
+}
+
+/// Pauses of sessions on tasks of one task list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskListPauseStats {
+    pub task_list_id: Option<String>,
+    #[serde(flatten)]
+    pub stats: PauseStats,
+}
+
+/// How often and how long sessions were paused, and interrupted in particular
+///
+/// Days are in the time zone given by `utc_offset`. Pauses still running count up to now.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterruptionReport {
+    pub start_date: chrono::DateTime<chrono::Utc>,
+    pub end_date: chrono::DateTime<chrono::Utc>,
+    /// Offset of the time zone used for days, e.g. "+02:00"
+    pub utc_offset: String,
+    #[serde(flatten)]
+    pub totals: PauseStats,
+    /// Most frequent reason first
+    pub by_reason: Vec<PauseReasonStats>,
+    /// Days with pauses, earliest first
+    pub by_day: Vec<DailyPauseStats>,
+    /// Most interrupted task list first
+    pub by_task_list: Vec<TaskListPauseStats>,
+}
+
+/// What the lines of a billing report are grouped by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingGroupBy {
+    TaskList,
+    Task,
+    Day,
+}
+
+impl BillingGroupBy {
+    fn as_str(self) -> &'static str {
+        match self {
+            BillingGroupBy::TaskList => "task_list",
+            BillingGroupBy::Task => "task",
+            BillingGroupBy::Day => "day",
+        }
+    }
+}
+
+/// Billable work on one task list, task or day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BillingLine {
+    /// Task list or task ID, or the day as YYYY-MM-DD
+    pub key: String,
+    pub label: String,
+    pub billable_minutes: i64,
+    /// Rate the work was billed at; unset for a day that mixes rates
+    pub hourly_rate: Option<f64>,
+    pub amount: f64,
+}
+
+/// Work on task lists with an hourly rate and what it comes to, for invoicing
+///
+/// Only finished sessions started in the range count, without their pauses and breaks.
+/// Amounts are rounded to cents per line and the total adds up the lines. Days are in the time
+/// zone given by `utc_offset`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BillingReport {
+    pub start_date: chrono::DateTime<chrono::Utc>,
+    pub end_date: chrono::DateTime<chrono::Utc>,
+    /// Offset of the time zone used for days, e.g. "+02:00"
+    pub utc_offset: String,
+    pub group_by: BillingGroupBy,
+    /// Ordered by label
+    pub lines: Vec<BillingLine>,
+    pub total_billable_minutes: i64,
+    pub total_amount: f64,
+}
+
+impl BillingReport {
+    /// Render the report as CSV, one row per line and a closing total row
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{},hours,hourly_rate,amount\n", self.group_by.as_str());
+        for line in &self.lines {
+            csv.push_str(&format!(
+                "{},{:.2},{},{:.2}\n",
+                csv_field(&line.label),
+                line.billable_minutes as f64 / 60.0,
+                line.hourly_rate
+                    .map(|rate| format!("{:.2}", rate))
+                    .unwrap_or_default(),
+                line.amount
+            ));
+        }
+        csv.push_str(&format!(
+            "Total,{:.2},,{:.2}\n",
+            self.total_billable_minutes as f64 / 60.0,
+            self.total_amount
+        ));
+        csv
+    }
+}
+
+/// Quote a CSV field when it holds a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Work and its amount accumulated for a billing line
+#[derive(Debug, Default)]
+struct BillingTotals {
+    seconds: i64,
+    amount: f64,
+    hourly_rate: Option<f64>,
+    mixed_rates: bool,
+}
+
+impl BillingTotals {
+    fn add(&mut self, seconds: i64, hourly_rate: f64) {
+        if self.seconds > 0 && self.hourly_rate != Some(hourly_rate) {
+            self.mixed_rates = true;
+        }
+        self.hourly_rate = Some(hourly_rate);
+        self.seconds += seconds;
+        self.amount += seconds as f64 / 3600.0 * hourly_rate;
+    }
+}
+
+/// SQL for the seconds worked in session `s`, without paused time and breaks
+///
+/// Running sessions and breaks count up to the time bound as `$4`.
+const SESSION_WORK_SECONDS: &str = r#"
+    (julianday(COALESCE(s.end_time, $4)) - julianday(s.start_time)) * 86400.0
+    - MAX(s.paused_time, 0)
+    - COALESCE(
+        (SELECT SUM(julianday(COALESCE(json_extract(b.value, '$.end_time'), $4))
+                    - julianday(json_extract(b.value, '$.start_time')))
+         FROM json_each(CASE WHEN json_valid(s.breaks) THEN s.breaks ELSE '[]' END) b),
+        0
+    ) * 86400.0
+"#;
+
+/// Work on one task on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetEntry {
+    pub date: chrono::NaiveDate,
+    pub task_id: String,
+    pub task_title: String,
+    pub task_list: Option<String>,
+    pub tags: Vec<String>,
+    /// Work time rounded to the minute, without pauses and breaks
+    pub minutes: i64,
+    /// `minutes` in hours, rounded to two decimals
+    pub hours: f64,
+    pub session_count: i64,
+    /// Distinct notes of the day's sessions
+    pub notes: Vec<String>,
+}
+
+/// Finished sessions in a date range, grouped by day and task
+///
+/// Days are in the time zone given by `utc_offset`, as of the start of the range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timesheet {
+    pub start_date: chrono::DateTime<chrono::Utc>,
+    pub end_date: chrono::DateTime<chrono::Utc>,
+    /// Offset of the time zone used for days, e.g. "+02:00"
+    pub utc_offset: String,
+    /// By day, then by task title
+    pub entries: Vec<TimesheetEntry>,
+    pub total_minutes: i64,
+}
+
+impl Timesheet {
+    /// Render the timesheet as CSV, one row per entry
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("date,task,task_list,tags,hours,minutes,notes\n");
+        for entry in &self.entries {
+            csv.push_str(&format!(
+                "{},{},{},{},{:.2},{},{}\n",
+                entry.date,
+                csv_field(&entry.task_title),
+                csv_field(entry.task_list.as_deref().unwrap_or_default()),
+                csv_field(&entry.tags.join("; ")),
+                entry.hours,
+                entry.minutes,
+                csv_field(&entry.notes.join("; "))
+            ));
+        }
+        csv
+    }
+}
+
+/// Tracked work and completed tasks on one day, for calendar heatmaps
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyTimeAggregate {
+    pub date: chrono::NaiveDate,
+    /// Work time rounded to the minute, without pauses and breaks
+    pub work_minutes: i64,
+    /// Sessions started on the day
+    pub session_count: i64,
+    /// Tasks completed on the day
+    pub completed_tasks: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct DailyTimeAggregateRow {
+    day: String,
+    seconds: Option<f64>,
+    session_count: i64,
+    completed_tasks: i64,
+}
+
+/// One day and task of a timesheet as the aggregate query returns it; lists are joined by the
+/// unit separator
+#[derive(Debug, FromQueryResult)]
+struct TimesheetRow {
+    day: String,
+    task_id: String,
+    task_title: String,
+    task_list_name: Option<String>,
+    tags: Option<String>,
+    seconds: Option<f64>,
+    session_count: i64,
+    notes: Option<String>,
+}
+
+/// Seconds of pause accumulated for a day or task list
+#[derive(Debug, Clone, Copy, Default)]
+struct PauseTotals {
+    pauses: u64,
+    seconds: i64,
+    interruptions: u64,
+    interruption_seconds: i64,
+}
+
+impl PauseTotals {
+    fn add(&mut self, reason: Option<&str>, seconds: i64) {
+        self.pauses += 1;
+        self.seconds += seconds;
+        if reason == Some("interruption") {
+            self.interruptions += 1;
+            self.interruption_seconds += seconds;
+        }
+    }
+
+    fn to_stats(self) -> PauseStats {
+        PauseStats {
+            pause_count: self.pauses,
+            pause_minutes: (self.seconds as f64 / 60.0).round() as i64,
+            interruption_count: self.interruptions,
+            interruption_minutes: (self.interruption_seconds as f64 / 60.0).round() as i64,
+        }
+    }
+}
+
+/// Seconds of work and pause accumulated for a time slot
+#[derive(Debug, Clone, Copy, Default)]
+struct SlotTotals {
+    work_seconds: f64,
+    break_seconds: f64,
+    sessions: u64,
+    subtasks: u64,
+}
+
+impl SlotTotals {
+    fn add(&mut self, other: &SlotTotals) {
+        self.work_seconds += other.work_seconds;
+        self.break_seconds += other.break_seconds;
+        self.sessions += other.sessions;
+        self.subtasks += other.subtasks;
+    }
+
+    fn to_slot(self) -> ProductivitySlot {
+        let tracked = self.work_seconds + self.break_seconds;
+        ProductivitySlot {
+            work_minutes: (self.work_seconds / 60.0).round() as i64,
+            break_minutes: (self.break_seconds / 60.0).round() as i64,
+            session_count: self.sessions,
+            subtasks_completed: self.subtasks,
+            focus_score: if tracked > 0.0 {
+                self.work_seconds / tracked * 100.0
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
 /// Time tracking repository for SeaORM-based database operations
 pub struct TimeTrackingRepository {
     db: Arc<DatabaseConnection>,
@@ -69,6 +490,13 @@ impl TimeTrackingRepository {
     pub async fn create_session(
         &self,
         request: CreateTimeSessionRequest,
+    ) -> Result<time_sessions::Model, DbErr> {
+        retry_on_busy(|| self.try_create_session(request.clone())).await
+    }
+
+    async fn try_create_session(
+        &self,
+        request: CreateTimeSessionRequest,
     ) -> Result<time_sessions::Model, DbErr> {
         // Verify task exists
         let task_exists = tasks::Entity::find_by_id(&request.task_id)
@@ -80,6 +508,9 @@ impl TimeTrackingRepository {
             return Err(DbErr::RecordNotFound("Task not found".to_string()));
         }
 
+        let txn = self.db.begin().await?;
+        Self::make_way(&txn, None, request.auto_switch).await?;
+
         let session = time_sessions::ActiveModel {
             task_id: Set(request.task_id),
             start_time: Set(request.start_time),
@@ -90,10 +521,468 @@ impl TimeTrackingRepository {
             breaks: Set(None),
             ..Default::default()
         };
+        let session = session.insert(&txn).await?;
+
+        txn.commit().await?;
+        Ok(session)
+    }
+
+    /// Record a finished session, such as time logged in another tracker
+    pub async fn log_session(
+        &self,
+        task_id: &str,
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+        notes: Option<String>,
+    ) -> Result<time_sessions::Model, DbErr> {
+        if end_time <= start_time {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A session must end after it starts".to_string(),
+            ));
+        }
+
+        let task_exists = tasks::Entity::find_by_id(task_id)
+            .one(&*self.db)
+            .await?
+            .is_some();
+        if !task_exists {
+            return Err(DbErr::RecordNotFound("Task not found".to_string()));
+        }
+
+        let session = time_sessions::ActiveModel {
+            task_id: Set(task_id.to_string()),
+            start_time: Set(start_time),
+            end_time: Set(Some(end_time)),
+            paused_time: Set(0),
+            is_active: Set(false),
+            notes: Set(notes),
+            breaks: Set(None),
+            ..Default::default()
+        };
 
         session.insert(&*self.db).await
     }
 
+    /// Add time worked away from the timer as a finished session
+    ///
+    /// Unlike `log_session`, the entry must lie in the past and may not overlap other sessions.
+    pub async fn add_manual_entry(
+        &self,
+        task_id: &str,
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+        notes: Option<String>,
+    ) -> Result<time_sessions::Model, DbErr> {
+        Self::validate_times(start_time, Some(end_time))?;
+        self.ensure_no_overlap(start_time, end_time, None).await?;
+        self.log_session(task_id, start_time, end_time, notes).await
+    }
+
+    /// Move the start and/or end of a session, keeping it clear of other sessions
+    ///
+    /// A running session only has a start to move; its end is the current time.
+    pub async fn adjust_session_times(
+        &self,
+        id: &str,
+        start_time: Option<chrono::DateTime<chrono::Utc>>,
+        end_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<time_sessions::Model, DbErr> {
+        let session = time_sessions::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
+        if end_time.is_some() && session.end_time.is_none() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Stop the session before changing its end".to_string(),
+            ));
+        }
+
+        let start_time = start_time.unwrap_or(session.start_time);
+        let end_time = end_time.or(session.end_time);
+        Self::validate_times(start_time, end_time)?;
+        let until = end_time.unwrap_or_else(chrono::Utc::now);
+        if (until - start_time).num_seconds() < session.paused_time as i64 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: The session would be shorter than its paused time".to_string(),
+            ));
+        }
+        self.ensure_no_overlap(start_time, until, Some(id)).await?;
+
+        let mut session: time_sessions::ActiveModel = session.into();
+        session.start_time = Set(start_time);
+        session.end_time = Set(end_time);
+        session.update(&*self.db).await
+    }
+
+    /// Split a finished session in two at `at`, optionally giving the later part to another task
+    ///
+    /// Pauses and breaks go with the part they fall in, and one spanning `at` is cut in two.
+    /// Subtask completions follow the later part only while it stays on the same task. Returns
+    /// the earlier and the later part.
+    pub async fn split_session(
+        &self,
+        id: &str,
+        at: chrono::DateTime<chrono::Utc>,
+        task_id: Option<String>,
+    ) -> Result<(time_sessions::Model, time_sessions::Model), DbErr> {
+        let session = time_sessions::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
+        let Some(end_time) = session.end_time else {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Stop the session before splitting it".to_string(),
+            ));
+        };
+        if at <= session.start_time || at >= end_time {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: The split time must fall inside the session".to_string(),
+            ));
+        }
+        let task_id = task_id.unwrap_or_else(|| session.task_id.clone());
+        if tasks::Entity::find_by_id(&task_id)
+            .one(&*self.db)
+            .await?
+            .is_none()
+        {
+            return Err(DbErr::RecordNotFound("Task not found".to_string()));
+        }
+
+        let txn = self.db.begin().await?;
+        let same_task = task_id == session.task_id;
+        let (earlier_breaks, later_breaks) = Self::split_breaks(session.breaks.as_deref(), at);
+        let later = time_sessions::ActiveModel {
+            task_id: Set(task_id),
+            start_time: Set(at),
+            end_time: Set(Some(end_time)),
+            paused_time: Set(0),
+            is_active: Set(false),
+            notes: Set(session.notes.clone()),
+            breaks: Set(later_breaks),
+            ..Default::default()
+        };
+        let later = later.insert(&txn).await?;
+
+        let pauses = session_pauses::Entity::find()
+            .filter(session_pauses::Column::SessionId.eq(id))
+            .filter(
+                Condition::any()
+                    .add(session_pauses::Column::EndedAt.gt(at))
+                    .add(session_pauses::Column::EndedAt.is_null()),
+            )
+            .all(&txn)
+            .await?;
+        let mut later_paused: i64 = 0;
+        for pause in pauses {
+            let ended_at = pause.ended_at.unwrap_or(end_time);
+            later_paused += (ended_at - pause.started_at.max(at)).num_seconds();
+            if pause.started_at < at {
+                let cut = session_pauses::ActiveModel {
+                    session_id: Set(later.id.clone()),
+                    reason: Set(pause.reason.clone()),
+                    started_at: Set(at),
+                    ended_at: Set(Some(ended_at)),
+                    ..Default::default()
+                };
+                cut.insert(&txn).await?;
+                let mut pause: session_pauses::ActiveModel = pause.into();
+                pause.ended_at = Set(Some(at));
+                pause.update(&txn).await?;
+            } else {
+                let mut pause: session_pauses::ActiveModel = pause.into();
+                pause.session_id = Set(later.id.clone());
+                pause.update(&txn).await?;
+            }
+        }
+        if same_task {
+            session_subtask_completions::Entity::update_many()
+                .col_expr(
+                    session_subtask_completions::Column::SessionId,
+                    sea_orm::sea_query::Expr::value(later.id.clone()),
+                )
+                .filter(session_subtask_completions::Column::SessionId.eq(id))
+                .filter(session_subtask_completions::Column::CompletedAt.gte(at))
+                .exec(&txn)
+                .await?;
+        }
+
+        // Pauses account for the later part's paused time; the earlier part keeps the rest
+        let later_paused = (later_paused as i32).clamp(0, session.paused_time.max(0));
+        let earlier_paused = session.paused_time - later_paused;
+        let mut later: time_sessions::ActiveModel = later.into();
+        later.paused_time = Set(later_paused);
+        let later = later.update(&txn).await?;
+
+        let mut earlier: time_sessions::ActiveModel = session.into();
+        earlier.end_time = Set(Some(at));
+        earlier.paused_time = Set(earlier_paused);
+        earlier.breaks = Set(earlier_breaks);
+        let earlier = earlier.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok((earlier, later))
+    }
+
+    /// Merge finished sessions of one task into the earliest of them
+    ///
+    /// The sessions must follow each other without overlapping and with no other session in
+    /// between. Gaps between them are recorded as pauses, so the merged session counts the same
+    /// work time.
+    pub async fn merge_sessions(&self, ids: &[String]) -> Result<time_sessions::Model, DbErr> {
+        let mut ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        if ids.len() < 2 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Choose at least two sessions to merge".to_string(),
+            ));
+        }
+
+        let sessions = time_sessions::Entity::find()
+            .filter(time_sessions::Column::Id.is_in(ids.iter().copied()))
+            .order_by_asc(time_sessions::Column::StartTime)
+            .all(&*self.db)
+            .await?;
+        if sessions.len() != ids.len() {
+            return Err(DbErr::RecordNotFound("Time session not found".to_string()));
+        }
+        let first = &sessions[0];
+        if sessions
+            .iter()
+            .any(|session| session.task_id != first.task_id)
+        {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Only sessions of the same task can be merged".to_string(),
+            ));
+        }
+        let mut end_times = Vec::with_capacity(sessions.len());
+        for session in &sessions {
+            let Some(end_time) = session.end_time else {
+                return Err(DbErr::Custom(
+                    "BUSINESS_RULE_ERROR: Stop the session before merging it".to_string(),
+                ));
+            };
+            end_times.push(end_time);
+        }
+        if sessions
+            .windows(2)
+            .zip(&end_times)
+            .any(|(pair, end_time)| pair[1].start_time < *end_time)
+        {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Overlapping sessions cannot be merged".to_string(),
+            ));
+        }
+        let end_time = end_times[end_times.len() - 1];
+        if self
+            .find_overlapping(first.start_time, end_time, &ids)
+            .await?
+            .is_some()
+        {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Only adjacent sessions can be merged".to_string(),
+            ));
+        }
+
+        let txn = self.db.begin().await?;
+        let mut paused_time = first.paused_time;
+        let mut breaks = Self::parse_breaks(first.breaks.as_deref());
+        let mut notes: Vec<String> = first.notes.iter().cloned().collect();
+        for (session, gap_start) in sessions[1..].iter().zip(&end_times) {
+            if session.start_time > *gap_start {
+                let gap = session_pauses::ActiveModel {
+                    session_id: Set(first.id.clone()),
+                    reason: Set(None),
+                    started_at: Set(*gap_start),
+                    ended_at: Set(Some(session.start_time)),
+                    ..Default::default()
+                };
+                gap.insert(&txn).await?;
+                let gap_seconds = (session.start_time - *gap_start).num_seconds() as i32;
+                paused_time = paused_time.saturating_add(gap_seconds);
+            }
+            paused_time = paused_time.saturating_add(session.paused_time);
+            breaks.extend(Self::parse_breaks(session.breaks.as_deref()));
+            if let Some(note) = &session.notes {
+                if !notes.contains(note) {
+                    notes.push(note.clone());
+                }
+            }
+
+            session_pauses::Entity::update_many()
+                .col_expr(
+                    session_pauses::Column::SessionId,
+                    sea_orm::sea_query::Expr::value(first.id.clone()),
+                )
+                .filter(session_pauses::Column::SessionId.eq(&session.id))
+                .exec(&txn)
+                .await?;
+            session_subtask_completions::Entity::update_many()
+                .col_expr(
+                    session_subtask_completions::Column::SessionId,
+                    sea_orm::sea_query::Expr::value(first.id.clone()),
+                )
+                .filter(session_subtask_completions::Column::SessionId.eq(&session.id))
+                .exec(&txn)
+                .await?;
+            time_sessions::Entity::delete_by_id(&session.id)
+                .exec(&txn)
+                .await?;
+        }
+
+        let mut merged: time_sessions::ActiveModel = first.clone().into();
+        merged.end_time = Set(Some(end_time));
+        merged.paused_time = Set(paused_time);
+        merged.notes = Set((!notes.is_empty()).then(|| notes.join("\n")));
+        merged.breaks = Set(Self::serialize_breaks(breaks));
+        let merged = merged.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(merged)
+    }
+
+    /// Divide a session's breaks at `at`, cutting a break that spans it
+    fn split_breaks(
+        breaks: Option<&str>,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> (Option<String>, Option<String>) {
+        let mut earlier = Vec::new();
+        let mut later = Vec::new();
+        for time_break in Self::parse_breaks(breaks) {
+            if time_break.end_time.is_some_and(|end_time| end_time <= at) {
+                earlier.push(time_break);
+            } else if time_break.start_time >= at {
+                later.push(time_break);
+            } else {
+                earlier.push(TimeBreak {
+                    end_time: Some(at),
+                    ..time_break.clone()
+                });
+                later.push(TimeBreak {
+                    start_time: at,
+                    ..time_break
+                });
+            }
+        }
+        (
+            Self::serialize_breaks(earlier),
+            Self::serialize_breaks(later),
+        )
+    }
+
+    /// Breaks stored on a session; unreadable JSON counts as none
+    pub(crate) fn parse_breaks(breaks: Option<&str>) -> Vec<TimeBreak> {
+        breaks
+            .and_then(|breaks| serde_json::from_str(breaks).ok())
+            .unwrap_or_default()
+    }
+
+    fn serialize_breaks(breaks: Vec<TimeBreak>) -> Option<String> {
+        (!breaks.is_empty()).then(|| serde_json::to_string(&breaks).unwrap_or_default())
+    }
+
+    /// End the running break at `at`, returning whether there was one
+    fn end_open_break(breaks: &mut [TimeBreak], at: chrono::DateTime<chrono::Utc>) -> bool {
+        match breaks
+            .iter_mut()
+            .find(|time_break| time_break.end_time.is_none())
+        {
+            Some(time_break) => {
+                time_break.end_time = Some(at.max(time_break.start_time));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Seconds spent on breaks between `start` and `end`; a running break lasts until `end`
+    pub(crate) fn break_seconds(
+        breaks: &[TimeBreak],
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> i64 {
+        breaks
+            .iter()
+            .map(|time_break| {
+                let break_end = time_break
+                    .end_time
+                    .map_or(end, |break_end| break_end.min(end));
+                (break_end - time_break.start_time.max(start))
+                    .num_seconds()
+                    .max(0)
+            })
+            .sum()
+    }
+
+    fn validate_times(
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<(), DbErr> {
+        let now = chrono::Utc::now();
+        if start_time > now || end_time.is_some_and(|end_time| end_time > now) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Session times cannot be in the future".to_string(),
+            ));
+        }
+        if end_time.is_some_and(|end_time| end_time <= start_time) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A session must end after it starts".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reject a time range that overlaps any session but `except_id`
+    async fn ensure_no_overlap(
+        &self,
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+        except_id: Option<&str>,
+    ) -> Result<(), DbErr> {
+        let except_ids: Vec<&str> = except_id.into_iter().collect();
+        if let Some(session) = self
+            .find_overlapping(start_time, end_time, &except_ids)
+            .await?
+        {
+            let local = |time: chrono::DateTime<chrono::Utc>| {
+                time.with_timezone(&chrono::Local)
+                    .format("%b %-d %H:%M")
+                    .to_string()
+            };
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: The times overlap another session from {} to {}",
+                local(session.start_time),
+                session.end_time.map_or_else(|| "now".to_string(), local)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Earliest session other than `except_ids` overlapping a time range; running sessions last
+    /// until now
+    async fn find_overlapping(
+        &self,
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+        except_ids: &[&str],
+    ) -> Result<Option<time_sessions::Model>, DbErr> {
+        let now = chrono::Utc::now();
+        let overlapping = time_sessions::Entity::find()
+            .filter(time_sessions::Column::StartTime.lt(end_time))
+            .filter(
+                Condition::any()
+                    .add(time_sessions::Column::EndTime.gt(start_time))
+                    .add(time_sessions::Column::EndTime.is_null()),
+            )
+            .filter(time_sessions::Column::Id.is_not_in(except_ids.iter().copied()))
+            .order_by_asc(time_sessions::Column::StartTime)
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .find(|session| session.end_time.unwrap_or(now) > start_time);
+        Ok(overlapping)
+    }
+
     /// Find a time session by ID
     pub async fn find_by_id(&self, id: &str) -> Result<Option<time_sessions::Model>, DbErr> {
         time_sessions::Entity::find_by_id(id).one(&*self.db).await
@@ -149,12 +1038,25 @@ impl TimeTrackingRepository {
         &self,
         id: &str,
         request: UpdateTimeSessionRequest,
+    ) -> Result<time_sessions::Model, DbErr> {
+        retry_on_busy(|| self.try_update_session(id, request.clone())).await
+    }
+
+    async fn try_update_session(
+        &self,
+        id: &str,
+        request: UpdateTimeSessionRequest,
     ) -> Result<time_sessions::Model, DbErr> {
         let session = time_sessions::Entity::find_by_id(id)
             .one(&*self.db)
             .await?
             .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
 
+        let txn = self.db.begin().await?;
+        if request.is_active == Some(true) {
+            Self::make_way(&txn, Some(id), false).await?;
+        }
+
         let mut session: time_sessions::ActiveModel = session.into();
 
         if let Some(end_time) = request.end_time {
@@ -172,8 +1074,10 @@ impl TimeTrackingRepository {
         if let Some(breaks) = request.breaks {
             session.breaks = Set(Some(serde_json::to_string(&breaks).unwrap_or_default()));
         }
+        let session = session.update(&txn).await?;
 
-        session.update(&*self.db).await
+        txn.commit().await?;
+        Ok(session)
     }
 
     /// Stop a time session
@@ -181,16 +1085,32 @@ impl TimeTrackingRepository {
         &self,
         id: &str,
         notes: Option<String>,
+    ) -> Result<time_sessions::Model, DbErr> {
+        retry_on_busy(|| self.try_stop_session(id, notes.clone())).await
+    }
+
+    async fn try_stop_session(
+        &self,
+        id: &str,
+        notes: Option<String>,
     ) -> Result<time_sessions::Model, DbErr> {
         let session = time_sessions::Entity::find_by_id(id)
             .one(&*self.db)
             .await?
             .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
 
+        let now = chrono::Utc::now();
+        Self::end_open_pauses(&*self.db, id, now).await?;
+        let mut breaks = Self::parse_breaks(session.breaks.as_deref());
+        let ended_break = Self::end_open_break(&mut breaks, now);
+
         let mut session: time_sessions::ActiveModel = session.into();
 
-        session.end_time = Set(Some(chrono::Utc::now()));
+        session.end_time = Set(Some(now));
         session.is_active = Set(false);
+        if ended_break {
+            session.breaks = Set(Self::serialize_breaks(breaks));
+        }
 
         if let Some(notes) = notes {
             session.notes = Set(Some(notes));
@@ -199,34 +1119,378 @@ impl TimeTrackingRepository {
         session.update(&*self.db).await
     }
 
-    /// Pause a time session
-    pub async fn pause_session(&self, id: &str) -> Result<time_sessions::Model, DbErr> {
+    /// Pause a time session, recording why
+    ///
+    /// Pausing a session that is already paused keeps the running pause.
+    pub async fn pause_session(
+        &self,
+        id: &str,
+        reason: Option<String>,
+    ) -> Result<time_sessions::Model, DbErr> {
+        let reason = reason
+            .map(|reason| reason.trim().to_lowercase())
+            .filter(|reason| !reason.is_empty());
+        if let Some(reason) = &reason {
+            if !PAUSE_REASONS.contains(&reason.as_str()) {
+                return Err(DbErr::Custom(format!(
+                    "VALIDATION_ERROR: Unknown pause reason '{}'. Expected one of: {}",
+                    reason,
+                    PAUSE_REASONS.join(", ")
+                )));
+            }
+        }
+
         let session = time_sessions::Entity::find_by_id(id)
             .one(&*self.db)
             .await?
             .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
+        if session.end_time.is_some() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Only a running session can be paused".to_string(),
+            ));
+        }
+
+        let open_pause = session_pauses::Entity::find()
+            .filter(session_pauses::Column::SessionId.eq(id))
+            .filter(session_pauses::Column::EndedAt.is_null())
+            .one(&*self.db)
+            .await?;
+        if open_pause.is_none() {
+            let pause = session_pauses::ActiveModel {
+                session_id: Set(id.to_string()),
+                reason: Set(reason),
+                ..Default::default()
+            };
+            pause.insert(&*self.db).await?;
+        }
+
+        // A pause takes over from a running break
+        let mut breaks = Self::parse_breaks(session.breaks.as_deref());
+        let ended_break = Self::end_open_break(&mut breaks, chrono::Utc::now());
 
         let mut session: time_sessions::ActiveModel = session.into();
         session.is_active = Set(false);
+        if ended_break {
+            session.breaks = Set(Self::serialize_breaks(breaks));
+        }
 
         session.update(&*self.db).await
     }
 
-    /// Resume a time session
-    pub async fn resume_session(&self, id: &str) -> Result<time_sessions::Model, DbErr> {
+    /// Resume a time session, ending its running pause
+    ///
+    /// With `auto_switch` another running session is stopped, otherwise resuming is rejected.
+    pub async fn resume_session(
+        &self,
+        id: &str,
+        auto_switch: bool,
+    ) -> Result<time_sessions::Model, DbErr> {
         let session = time_sessions::Entity::find_by_id(id)
             .one(&*self.db)
             .await?
             .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
 
+        let txn = self.db.begin().await?;
+        Self::make_way(&txn, Some(id), auto_switch).await?;
+        Self::end_open_pauses(&txn, id, chrono::Utc::now()).await?;
+
         let mut session: time_sessions::ActiveModel = session.into();
         session.is_active = Set(true);
+        let session = session.update(&txn).await?;
 
+        txn.commit().await?;
+        Ok(session)
+    }
+
+    /// Start a break in a running session
+    ///
+    /// Break time is left out of the session's work time without pausing the timer.
+    pub async fn start_break(
+        &self,
+        id: &str,
+        reason: Option<String>,
+    ) -> Result<time_sessions::Model, DbErr> {
+        let session = time_sessions::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
+        if session.end_time.is_some() || !session.is_active {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Only a running session can take a break".to_string(),
+            ));
+        }
+        let mut breaks = Self::parse_breaks(session.breaks.as_deref());
+        if breaks
+            .iter()
+            .any(|time_break| time_break.end_time.is_none())
+        {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: The session is already on a break".to_string(),
+            ));
+        }
+
+        breaks.push(TimeBreak {
+            start_time: chrono::Utc::now(),
+            end_time: None,
+            reason: reason
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty()),
+        });
+        let mut session: time_sessions::ActiveModel = session.into();
+        session.breaks = Set(Self::serialize_breaks(breaks));
         session.update(&*self.db).await
     }
 
+    /// End the running break of a session
+    pub async fn end_break(&self, id: &str) -> Result<time_sessions::Model, DbErr> {
+        let session = time_sessions::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
+        let mut breaks = Self::parse_breaks(session.breaks.as_deref());
+        if !Self::end_open_break(&mut breaks, chrono::Utc::now()) {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: The session is not on a break".to_string(),
+            ));
+        }
+
+        let mut session: time_sessions::ActiveModel = session.into();
+        session.breaks = Set(Self::serialize_breaks(breaks));
+        session.update(&*self.db).await
+    }
+
+    /// Pauses of a session, earliest first
+    pub async fn find_session_pauses(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<session_pauses::Model>, DbErr> {
+        session_pauses::Entity::find()
+            .filter(session_pauses::Column::SessionId.eq(session_id))
+            .order_by_asc(session_pauses::Column::StartedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Leave time the user was away out of a session, recording it as an `idle` pause
+    ///
+    /// The range is clipped to the session; the session's paused time grows by what remains.
+    pub async fn record_idle_pause(
+        &self,
+        id: &str,
+        started_at: chrono::DateTime<chrono::Utc>,
+        ended_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<time_sessions::Model, DbErr> {
+        let session = time_sessions::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
+
+        let started_at = started_at.max(session.start_time);
+        let ended_at = ended_at.min(session.end_time.unwrap_or_else(chrono::Utc::now));
+        let idle_seconds = (ended_at - started_at).num_seconds();
+        if idle_seconds <= 0 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: The idle time is outside the session".to_string(),
+            ));
+        }
+
+        let txn = self.db.begin().await?;
+        let pause = session_pauses::ActiveModel {
+            session_id: Set(id.to_string()),
+            reason: Set(Some(IDLE_PAUSE_REASON.to_string())),
+            started_at: Set(started_at),
+            ended_at: Set(Some(ended_at)),
+            ..Default::default()
+        };
+        pause.insert(&txn).await?;
+
+        let paused_time = session.paused_time.saturating_add(idle_seconds as i32);
+        let mut session: time_sessions::ActiveModel = session.into();
+        session.paused_time = Set(paused_time);
+        let session = session.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(session)
+    }
+
+    /// Let a session start running by dealing with the one running now, other than `starting_id`
+    ///
+    /// The running session is stopped with `auto_switch` and starting is rejected otherwise. The
+    /// partial unique index on running sessions backs this up when callers race.
+    async fn make_way<C: ConnectionTrait>(
+        conn: &C,
+        starting_id: Option<&str>,
+        auto_switch: bool,
+    ) -> Result<(), DbErr> {
+        let mut running =
+            time_sessions::Entity::find().filter(time_sessions::Column::IsActive.eq(true));
+        if let Some(starting_id) = starting_id {
+            running = running.filter(time_sessions::Column::Id.ne(starting_id));
+        }
+        let Some(running) = running.one(conn).await? else {
+            return Ok(());
+        };
+        if !auto_switch {
+            return Err(DbErr::Custom(format!(
+                "BUSINESS_RULE_ERROR: Session '{}' is already running",
+                running.id
+            )));
+        }
+
+        let now = chrono::Utc::now();
+        Self::end_open_pauses(conn, &running.id, now).await?;
+        let mut breaks = Self::parse_breaks(running.breaks.as_deref());
+        let ended_break = Self::end_open_break(&mut breaks, now);
+        let mut running: time_sessions::ActiveModel = running.into();
+        running.end_time = Set(Some(now));
+        running.is_active = Set(false);
+        if ended_break {
+            running.breaks = Set(Self::serialize_breaks(breaks));
+        }
+        running.update(conn).await?;
+        Ok(())
+    }
+
+    async fn end_open_pauses<C: ConnectionTrait>(
+        conn: &C,
+        session_id: &str,
+        ended_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), DbErr> {
+        session_pauses::Entity::update_many()
+            .col_expr(
+                session_pauses::Column::EndedAt,
+                sea_orm::sea_query::Expr::value(ended_at),
+            )
+            .filter(session_pauses::Column::SessionId.eq(session_id))
+            .filter(session_pauses::Column::EndedAt.is_null())
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Check off a subtask of the session's task while the session is running
+    ///
+    /// The subtask is marked completed and the completion is recorded against the session, so
+    /// reports can show what got done in it. Paused sessions still count as running.
+    pub async fn complete_subtask_during_session(
+        &self,
+        session_id: &str,
+        subtask_id: &str,
+    ) -> Result<session_subtask_completions::Model, DbErr> {
+        let session = self
+            .find_by_id(session_id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Time session not found".to_string()))?;
+        if session.end_time.is_some() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Subtasks can only be checked off during a running session"
+                    .to_string(),
+            ));
+        }
+
+        let subtask = tasks::Entity::find_by_id(subtask_id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Subtask not found".to_string()))?;
+        if !self.is_descendant_of(&subtask, &session.task_id).await? {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Task is not a subtask of the session's task".to_string(),
+            ));
+        }
+        if TaskStatusRepository::is_completed_in(&*self.db, &subtask.status).await? {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Subtask is already completed".to_string(),
+            ));
+        }
+
+        TaskRepository::new(self.db.clone())
+            .update_task(
+                subtask_id,
+                UpdateTaskRequest {
+                    title: None,
+                    description: None,
+                    priority: None,
+                    status: Some("completed".to_string()),
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: None,
+                    actual_time: None,
+                    due_date: None,
+                    scheduled_date: None,
+                    clear_scheduled_date: None,
+                    tags: None,
+                    project_id: None,
+                    parent_task_id: None,
+                    task_list_id: None,
+                    completed_at: None,
+                    effort: None,
+                    impact: None,
+                    expected_updated_at: None,
+                },
+            )
+            .await?;
+
+        let completion = session_subtask_completions::ActiveModel {
+            session_id: Set(session.id),
+            subtask_id: Set(subtask.id),
+            ..Default::default()
+        };
+        retry_on_busy(|| completion.clone().insert(&*self.db)).await
+    }
+
+    /// Find the subtasks checked off during a session, in the order they were completed
+    pub async fn find_session_completed_subtasks(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<tasks::Model>, DbErr> {
+        let completions = session_subtask_completions::Entity::find()
+            .filter(session_subtask_completions::Column::SessionId.eq(session_id))
+            .order_by_asc(session_subtask_completions::Column::CompletedAt)
+            .find_also_related(tasks::Entity)
+            .all(&*self.db)
+            .await?;
+
+        Ok(completions
+            .into_iter()
+            .filter_map(|(_, subtask)| subtask)
+            .collect())
+    }
+
+    /// Whether `task` sits anywhere below `ancestor_id` in the task tree
+    async fn is_descendant_of(
+        &self,
+        task: &tasks::Model,
+        ancestor_id: &str,
+    ) -> Result<bool, DbErr> {
+        let mut parent_id = task.parent_task_id.clone();
+        let mut visited = std::collections::HashSet::new();
+
+        while let Some(id) = parent_id {
+            if id == ancestor_id {
+                return Ok(true);
+            }
+            if !visited.insert(id.clone()) {
+                break;
+            }
+            parent_id = tasks::Entity::find_by_id(&id)
+                .one(&*self.db)
+                .await?
+                .and_then(|parent| parent.parent_task_id);
+        }
+
+        Ok(false)
+    }
+
     /// Delete a time session
     pub async fn delete_session(&self, id: &str) -> Result<(), DbErr> {
+        session_subtask_completions::Entity::delete_many()
+            .filter(session_subtask_completions::Column::SessionId.eq(id))
+            .exec(&*self.db)
+            .await?;
+        session_pauses::Entity::delete_many()
+            .filter(session_pauses::Column::SessionId.eq(id))
+            .exec(&*self.db)
+            .await?;
         time_sessions::Entity::delete_by_id(id)
             .exec(&*self.db)
             .await?;
@@ -244,6 +1508,7 @@ impl TimeTrackingRepository {
         let mut total_sessions = 0u64;
         let mut total_time_minutes = 0i64;
         let mut total_break_time_minutes = 0i64;
+        let mut total_breaks = 0u64;
         let mut hour_counts = vec![0u64; 24];
         let mut day_stats = std::collections::HashMap::new();
 
@@ -255,13 +1520,16 @@ impl TimeTrackingRepository {
             // Only count sessions with meaningful duration (at least 1 minute)
             if duration > 0 {
                 // Ensure break time is not negative and not more than total duration
-                let break_time_seconds = std::cmp::max(0, session.paused_time) as i64;
+                let breaks = Self::parse_breaks(session.breaks.as_deref());
+                let break_time_seconds = std::cmp::max(0, session.paused_time) as i64
+                    + Self::break_seconds(&breaks, session.start_time, end_time);
                 let break_time_minutes = break_time_seconds / 60; // Convert seconds to minutes
                 let break_time = std::cmp::min(break_time_minutes, duration); // Cap at total duration
 
                 total_sessions += 1;
                 total_time_minutes += duration;
                 total_break_time_minutes += break_time;
+                total_breaks += breaks.len() as u64;
 
                 // Track hourly productivity
                 let hour = session.start_time.hour() as usize;
@@ -309,6 +1577,7 @@ impl TimeTrackingRepository {
             total_time_minutes,
             total_work_time_minutes,
             total_break_time_minutes,
+            total_breaks,
             average_session_minutes,
             average_productivity_score,
             most_productive_hour,
@@ -316,6 +1585,497 @@ impl TimeTrackingRepository {
         })
     }
 
+    /// Build a productivity report of sessions started in a date range
+    ///
+    /// Hours and days are taken in `timezone`, normally the user's local time zone.
+    pub async fn get_productivity_report<Tz: TimeZone>(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+        working_hours: &WorkingHours,
+        timezone: &Tz,
+    ) -> Result<ProductivityReport, DbErr> {
+        let sessions = self.find_sessions_between(start_date, end_date).await?;
+        let now = chrono::Utc::now();
+
+        let mut cells = [[SlotTotals::default(); 24]; 7];
+        let mut total_breaks = 0u64;
+        let mut after_hours_seconds = 0.0;
+        for session in &sessions {
+            let start = session.start_time.with_timezone(timezone);
+            let end = session.end_time.unwrap_or(now).with_timezone(timezone);
+            let total_seconds = (end.clone() - start.clone()).num_seconds();
+            if total_seconds < 60 {
+                continue;
+            }
+
+            let breaks = Self::parse_breaks(session.breaks.as_deref());
+            total_breaks += breaks.len() as u64;
+            let break_seconds =
+                Self::break_seconds(&breaks, session.start_time, session.end_time.unwrap_or(now));
+            let paused_seconds =
+                (session.paused_time.max(0) as i64 + break_seconds).min(total_seconds);
+            let work_ratio = (total_seconds - paused_seconds) as f64 / total_seconds as f64;
+            cells[start.weekday().num_days_from_sunday() as usize][start.hour() as usize]
+                .sessions += 1;
+
+            // Split the session at hour boundaries
+            let mut cursor = start;
+            while cursor < end {
+                let into_hour = (cursor.minute() * 60 + cursor.second()) as i64;
+                let hour_end = cursor.clone() + chrono::Duration::seconds(3600 - into_hour);
+                let slice_end = hour_end.min(end.clone());
+                let seconds =
+                    (slice_end.clone() - cursor.clone()).num_milliseconds() as f64 / 1000.0;
+
+                let cell = &mut cells[cursor.weekday().num_days_from_sunday() as usize]
+                    [cursor.hour() as usize];
+                cell.work_seconds += seconds * work_ratio;
+                cell.break_seconds += seconds * (1.0 - work_ratio);
+                let working_seconds = working_hours.working_seconds_between(
+                    cursor.with_timezone(&chrono::Utc),
+                    slice_end.with_timezone(&chrono::Utc),
+                    timezone,
+                ) as f64;
+                after_hours_seconds += (seconds - working_seconds).max(0.0) * work_ratio;
+                cursor = slice_end;
+            }
+        }
+
+        let completions = session_subtask_completions::Entity::find()
+            .filter(
+                session_subtask_completions::Column::SessionId
+                    .is_in(sessions.iter().map(|session| session.id.clone())),
+            )
+            .all(&*self.db)
+            .await?;
+        for completion in &completions {
+            let completed_at = completion.completed_at.with_timezone(timezone);
+            cells[completed_at.weekday().num_days_from_sunday() as usize]
+                [completed_at.hour() as usize]
+                .subtasks += 1;
+        }
+
+        let mut hourly_totals = [SlotTotals::default(); 24];
+        let mut daily_totals = [SlotTotals::default(); 7];
+        let mut totals = SlotTotals::default();
+        let mut heatmap = Vec::with_capacity(7 * 24);
+        for (day, hours) in cells.iter().enumerate() {
+            for (hour, cell) in hours.iter().enumerate() {
+                hourly_totals[hour].add(cell);
+                daily_totals[day].add(cell);
+                totals.add(cell);
+                heatmap.push(HeatmapCell {
+                    day_of_week: day as u32,
+                    hour: hour as u32,
+                    slot: cell.to_slot(),
+                });
+            }
+        }
+
+        let hourly: Vec<HourlyProductivity> = hourly_totals
+            .iter()
+            .enumerate()
+            .map(|(hour, totals)| HourlyProductivity {
+                hour: hour as u32,
+                slot: totals.to_slot(),
+            })
+            .collect();
+        let daily: Vec<DailyProductivity> = daily_totals
+            .iter()
+            .enumerate()
+            .map(|(day, totals)| DailyProductivity {
+                day_of_week: day as u32,
+                day_name: DAY_NAMES[day].to_string(),
+                slot: totals.to_slot(),
+            })
+            .collect();
+
+        let mut busiest_hours: Vec<&HourlyProductivity> =
+            hourly.iter().filter(|h| h.slot.work_minutes > 0).collect();
+        busiest_hours.sort_by_key(|h| std::cmp::Reverse(h.slot.work_minutes));
+        let mut busiest_days: Vec<&DailyProductivity> =
+            daily.iter().filter(|d| d.slot.work_minutes > 0).collect();
+        busiest_days.sort_by_key(|d| std::cmp::Reverse(d.slot.work_minutes));
+
+        let summary = totals.to_slot();
+        Ok(ProductivityReport {
+            schema_version: PRODUCTIVITY_REPORT_SCHEMA_VERSION.to_string(),
+            generated_at: now,
+            start_date,
+            end_date,
+            utc_offset: now.with_timezone(timezone).offset().fix().to_string(),
+            total_sessions: summary.session_count,
+            total_work_minutes: summary.work_minutes,
+            total_break_minutes: summary.break_minutes,
+            total_breaks,
+            total_subtasks_completed: summary.subtasks_completed,
+            after_hours_work_minutes: (after_hours_seconds / 60.0).round() as i64,
+            most_productive_hours: busiest_hours.iter().take(3).map(|h| h.hour).collect(),
+            best_days_of_week: busiest_days
+                .iter()
+                .take(3)
+                .map(|d| d.day_name.clone())
+                .collect(),
+            hourly,
+            daily,
+            heatmap,
+        })
+    }
+
+    /// Analyze pauses started in a date range by reason, day and task list
+    ///
+    /// Days are taken in `timezone`, normally the user's local time zone.
+    pub async fn get_interruption_report<Tz: TimeZone>(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+        timezone: &Tz,
+    ) -> Result<InterruptionReport, DbErr> {
+        let now = chrono::Utc::now();
+        let pauses = session_pauses::Entity::find()
+            .filter(session_pauses::Column::StartedAt.between(start_date, end_date))
+            .find_also_related(time_sessions::Entity)
+            .all(&*self.db)
+            .await?;
+        let task_lists: std::collections::HashMap<String, Option<String>> = tasks::Entity::find()
+            .filter(
+                tasks::Column::Id.is_in(
+                    pauses
+                        .iter()
+                        .filter_map(|(_, session)| session.as_ref())
+                        .map(|session| session.task_id.clone()),
+                ),
+            )
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|task| (task.id, task.task_list_id))
+            .collect();
+
+        let mut totals = PauseTotals::default();
+        let mut by_reason: std::collections::HashMap<Option<String>, PauseTotals> =
+            std::collections::HashMap::new();
+        let mut by_day: std::collections::BTreeMap<chrono::NaiveDate, PauseTotals> =
+            std::collections::BTreeMap::new();
+        let mut by_task_list: std::collections::HashMap<Option<String>, PauseTotals> =
+            std::collections::HashMap::new();
+        for (pause, session) in &pauses {
+            let seconds = (pause.ended_at.unwrap_or(now) - pause.started_at)
+                .num_seconds()
+                .max(0);
+            let reason = pause.reason.as_deref();
+            let day = pause.started_at.with_timezone(timezone).date_naive();
+            let task_list_id = session
+                .as_ref()
+                .and_then(|session| task_lists.get(&session.task_id).cloned())
+                .flatten();
+
+            totals.add(reason, seconds);
+            by_reason
+                .entry(pause.reason.clone())
+                .or_default()
+                .add(reason, seconds);
+            by_day.entry(day).or_default().add(reason, seconds);
+            by_task_list
+                .entry(task_list_id)
+                .or_default()
+                .add(reason, seconds);
+        }
+
+        let mut by_reason: Vec<PauseReasonStats> = by_reason
+            .into_iter()
+            .map(|(reason, totals)| {
+                let stats = totals.to_stats();
+                PauseReasonStats {
+                    reason,
+                    count: stats.pause_count,
+                    minutes: stats.pause_minutes,
+                }
+            })
+            .collect();
+        by_reason.sort_by(|a, b| b.count.cmp(&a.count).then(a.reason.cmp(&b.reason)));
+        let mut by_task_list: Vec<TaskListPauseStats> = by_task_list
+            .into_iter()
+            .map(|(task_list_id, totals)| TaskListPauseStats {
+                task_list_id,
+                stats: totals.to_stats(),
+            })
+            .collect();
+        by_task_list.sort_by(|a, b| {
+            b.stats
+                .interruption_count
+                .cmp(&a.stats.interruption_count)
+                .then(b.stats.pause_count.cmp(&a.stats.pause_count))
+                .then(a.task_list_id.cmp(&b.task_list_id))
+        });
+
+        Ok(InterruptionReport {
+            start_date,
+            end_date,
+            utc_offset: now.with_timezone(timezone).offset().fix().to_string(),
+            totals: totals.to_stats(),
+            by_reason,
+            by_day: by_day
+                .into_iter()
+                .map(|(date, totals)| DailyPauseStats {
+                    date,
+                    stats: totals.to_stats(),
+                })
+                .collect(),
+            by_task_list,
+        })
+    }
+
+    /// Total billable work in a date range on task lists that have an hourly rate
+    ///
+    /// Days are taken in `timezone`, normally the user's local time zone.
+    pub async fn get_billing_report<Tz: TimeZone>(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+        group_by: BillingGroupBy,
+        timezone: &Tz,
+    ) -> Result<BillingReport, DbErr> {
+        let sessions = self.find_sessions_between(start_date, end_date).await?;
+        let tasks: std::collections::HashMap<String, tasks::Model> = tasks::Entity::find()
+            .filter(tasks::Column::Id.is_in(sessions.iter().map(|session| session.task_id.clone())))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|task| (task.id.clone(), task))
+            .collect();
+        let billable_lists: std::collections::HashMap<String, task_lists::Model> =
+            task_lists::Entity::find()
+                .filter(task_lists::Column::HourlyRate.is_not_null())
+                .all(&*self.db)
+                .await?
+                .into_iter()
+                .map(|task_list| (task_list.id.clone(), task_list))
+                .collect();
+
+        // Keyed by label first so lines come out in label order
+        let mut lines: std::collections::BTreeMap<(String, String), BillingTotals> =
+            std::collections::BTreeMap::new();
+        for session in &sessions {
+            let Some(end_time) = session.end_time else {
+                continue;
+            };
+            let Some(task) = tasks.get(&session.task_id) else {
+                continue;
+            };
+            let Some((task_list, hourly_rate)) = task
+                .task_list_id
+                .as_ref()
+                .and_then(|id| billable_lists.get(id))
+                .and_then(|task_list| task_list.hourly_rate.map(|rate| (task_list, rate)))
+            else {
+                continue;
+            };
+
+            let breaks = Self::parse_breaks(session.breaks.as_deref());
+            let seconds = (end_time - session.start_time).num_seconds()
+                - session.paused_time.max(0) as i64
+                - Self::break_seconds(&breaks, session.start_time, end_time);
+            if seconds <= 0 {
+                continue;
+            }
+
+            let (label, key) = match group_by {
+                BillingGroupBy::TaskList => (task_list.name.clone(), task_list.id.clone()),
+                BillingGroupBy::Task => (task.title.clone(), task.id.clone()),
+                BillingGroupBy::Day => {
+                    let day = session
+                        .start_time
+                        .with_timezone(timezone)
+                        .date_naive()
+                        .to_string();
+                    (day.clone(), day)
+                }
+            };
+            lines
+                .entry((label, key))
+                .or_default()
+                .add(seconds, hourly_rate);
+        }
+
+        let lines: Vec<BillingLine> = lines
+            .into_iter()
+            .map(|((label, key), totals)| BillingLine {
+                key,
+                label,
+                billable_minutes: (totals.seconds as f64 / 60.0).round() as i64,
+                hourly_rate: totals.hourly_rate.filter(|_| !totals.mixed_rates),
+                amount: (totals.amount * 100.0).round() / 100.0,
+            })
+            .collect();
+        let total_amount = lines.iter().map(|line| line.amount).sum::<f64>();
+        Ok(BillingReport {
+            start_date,
+            end_date,
+            utc_offset: chrono::Utc::now()
+                .with_timezone(timezone)
+                .offset()
+                .fix()
+                .to_string(),
+            group_by,
+            total_billable_minutes: lines.iter().map(|line| line.billable_minutes).sum(),
+            total_amount: (total_amount * 100.0).round() / 100.0,
+            lines,
+        })
+    }
+
+    /// Work, sessions and completed tasks per day in a date range, from SQL aggregates
+    ///
+    /// Only days with sessions or completed tasks are returned, earliest first. Days are taken in
+    /// `timezone` at its offset at `start_date`; running sessions count up to now.
+    pub async fn get_daily_aggregates<Tz: TimeZone>(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+        timezone: &Tz,
+    ) -> Result<Vec<DailyTimeAggregate>, DbErr> {
+        let sql = format!(
+            r#"
+            SELECT
+                day,
+                SUM(seconds) AS seconds,
+                SUM(session_count) AS session_count,
+                SUM(completed_tasks) AS completed_tasks
+            FROM (
+                SELECT
+                    date(s.start_time, $3) AS day,
+                    SUM({work_seconds}) AS seconds,
+                    COUNT(*) AS session_count,
+                    0 AS completed_tasks
+                FROM time_sessions s
+                WHERE s.start_time BETWEEN $1 AND $2
+                GROUP BY day
+                UNION ALL
+                SELECT date(t.completed_at, $3) AS day, 0.0, 0, COUNT(*)
+                FROM tasks t
+                WHERE t.completed_at BETWEEN $1 AND $2
+                GROUP BY day
+            )
+            GROUP BY day
+            ORDER BY day ASC
+            "#,
+            work_seconds = SESSION_WORK_SECONDS
+        );
+        let offset = timezone
+            .offset_from_utc_datetime(&start_date.naive_utc())
+            .fix();
+        let rows = DailyTimeAggregateRow::find_by_statement(Statement::from_sql_and_values(
+            self.db.get_database_backend(),
+            &sql,
+            [
+                start_date.into(),
+                end_date.into(),
+                format!("{:+} seconds", offset.local_minus_utc()).into(),
+                chrono::Utc::now().into(),
+            ],
+        ))
+        .all(&*self.db)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let date = chrono::NaiveDate::parse_from_str(&row.day, "%Y-%m-%d")
+                    .map_err(|e| DbErr::Custom(format!("Invalid aggregate day: {}", e)))?;
+                Ok(DailyTimeAggregate {
+                    date,
+                    work_minutes: (row.seconds.unwrap_or_default().max(0.0) / 60.0).round() as i64,
+                    session_count: row.session_count,
+                    completed_tasks: row.completed_tasks,
+                })
+            })
+            .collect()
+    }
+
+    /// Build a timesheet of finished sessions started in a date range
+    ///
+    /// The totals per day and task come from one aggregate query, which also leaves out paused
+    /// time and breaks. Days are taken in `timezone` at its offset at `start_date`.
+    pub async fn get_timesheet<Tz: TimeZone>(
+        &self,
+        start_date: chrono::DateTime<chrono::Utc>,
+        end_date: chrono::DateTime<chrono::Utc>,
+        timezone: &Tz,
+    ) -> Result<Timesheet, DbErr> {
+        let sql = format!(
+            r#"
+            SELECT
+                date(s.start_time, $3) AS day,
+                t.id AS task_id,
+                t.title AS task_title,
+                l.name AS task_list_name,
+                (SELECT GROUP_CONCAT(tg.name, char(31))
+                 FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id
+                 WHERE tt.task_id = t.id) AS tags,
+                SUM({work_seconds}) AS seconds,
+                COUNT(*) AS session_count,
+                GROUP_CONCAT(NULLIF(TRIM(s.notes), ''), char(31)) AS notes
+            FROM time_sessions s
+            JOIN tasks t ON t.id = s.task_id
+            LEFT JOIN task_lists l ON l.id = t.task_list_id
+            WHERE s.end_time IS NOT NULL AND s.start_time BETWEEN $1 AND $2
+            GROUP BY day, t.id
+            ORDER BY day ASC, t.title ASC, t.id ASC
+            "#,
+            work_seconds = SESSION_WORK_SECONDS
+        );
+        let offset = timezone
+            .offset_from_utc_datetime(&start_date.naive_utc())
+            .fix();
+        let rows = TimesheetRow::find_by_statement(Statement::from_sql_and_values(
+            self.db.get_database_backend(),
+            &sql,
+            [
+                start_date.into(),
+                end_date.into(),
+                format!("{:+} seconds", offset.local_minus_utc()).into(),
+                chrono::Utc::now().into(),
+            ],
+        ))
+        .all(&*self.db)
+        .await?;
+
+        let split = |joined: Option<String>| -> Vec<String> {
+            let mut values: Vec<String> = Vec::new();
+            for value in joined.iter().flat_map(|joined| joined.split('\u{1f}')) {
+                if !values.iter().any(|seen| seen == value) {
+                    values.push(value.to_string());
+                }
+            }
+            values
+        };
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            let date = chrono::NaiveDate::parse_from_str(&row.day, "%Y-%m-%d")
+                .map_err(|e| DbErr::Custom(format!("Invalid timesheet day: {}", e)))?;
+            let minutes = (row.seconds.unwrap_or_default().max(0.0) / 60.0).round() as i64;
+            entries.push(TimesheetEntry {
+                date,
+                task_id: row.task_id,
+                task_title: row.task_title,
+                task_list: row.task_list_name,
+                tags: split(row.tags),
+                minutes,
+                hours: (minutes as f64 / 60.0 * 100.0).round() / 100.0,
+                session_count: row.session_count,
+                notes: split(row.notes),
+            });
+        }
+
+        Ok(Timesheet {
+            start_date,
+            end_date,
+            utc_offset: offset.to_string(),
+            total_minutes: entries.iter().map(|entry| entry.minutes).sum(),
+            entries,
+        })
+    }
+
     /// Get total time spent on a task
     pub async fn get_task_total_time(&self, task_id: &str) -> Result<i64, DbErr> {
         let sessions = self.find_sessions_for_task(task_id).await?;
@@ -326,7 +2086,10 @@ impl TimeTrackingRepository {
                 session.end_time.map(|end_time| {
                     let duration_minutes = (end_time - session.start_time).num_minutes();
                     let paused_minutes = (session.paused_time as i64) / 60; // Convert seconds to minutes
-                    duration_minutes - paused_minutes
+                    let breaks = Self::parse_breaks(session.breaks.as_deref());
+                    let break_minutes =
+                        Self::break_seconds(&breaks, session.start_time, end_time) / 60;
+                    duration_minutes - paused_minutes - break_minutes
                 })
             })
             .sum();
@@ -362,6 +2125,9 @@ impl TimeTrackingRepository {
 
     /// Delete all time sessions
     pub async fn delete_all_sessions(&self) -> Result<u64, DbErr> {
+        session_subtask_completions::Entity::delete_many()
+            .exec(&*self.db)
+            .await?;
         let result = time_sessions::Entity::delete_many().exec(&*self.db).await?;
         Ok(result.rows_affected)
     }
@@ -390,4 +2156,22 @@ impl TimeTrackingRepository {
 
         active_session.insert(&*self.db).await
     }
+
+    /// Get all subtasks checked off during sessions, for backup
+    pub async fn get_all_subtask_completions(
+        &self,
+    ) -> Result<Vec<session_subtask_completions::Model>, DbErr> {
+        session_subtask_completions::Entity::find()
+            .all(&*self.db)
+            .await
+    }
+
+    /// Import a subtask checked off during a session from backup data
+    pub async fn import_subtask_completion(
+        &self,
+        completion: session_subtask_completions::Model,
+    ) -> Result<session_subtask_completions::Model, DbErr> {
+        let active_completion: session_subtask_completions::ActiveModel = completion.into();
+        active_completion.insert(&*self.db).await
+    }
 }
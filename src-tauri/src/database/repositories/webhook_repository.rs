@@ -0,0 +1,272 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{webhook_deliveries, webhooks};
+
+/// Events a webhook can subscribe to
+pub const WEBHOOK_EVENTS: [&str; 3] = ["task.completed", "timer.stopped", "backup.finished"];
+
+/// Prefix of every webhook signing secret
+const SECRET_PREFIX: &str = "whsec_";
+
+/// Request structure for registering a webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub events: Vec<String>,
+}
+
+/// Request structure for updating a webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWebhookRequest {
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
+
+/// A newly registered webhook; the signing secret is only ever returned here
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedWebhook {
+    pub webhook: webhooks::Model,
+    pub secret: String,
+}
+
+/// Webhook repository for SeaORM-based database operations
+pub struct WebhookRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl WebhookRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Register a webhook with a freshly generated signing secret
+    pub async fn create_webhook(
+        &self,
+        request: CreateWebhookRequest,
+    ) -> Result<CreatedWebhook, DbErr> {
+        let url = Self::validate_url(&request.url)?;
+        let events = Self::validate_events(&request.events)?;
+        let secret = format!(
+            "{}{}{}",
+            SECRET_PREFIX,
+            uuid::Uuid::new_v4().simple(),
+            uuid::Uuid::new_v4().simple()
+        );
+
+        let webhook = webhooks::ActiveModel {
+            url: Set(url),
+            events: Set(serde_json::to_string(&events).unwrap_or_default()),
+            secret: Set(secret.clone()),
+            is_active: Set(true),
+            ..Default::default()
+        };
+
+        Ok(CreatedWebhook {
+            webhook: webhook.insert(&*self.db).await?,
+            secret,
+        })
+    }
+
+    /// Find a webhook by ID
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<webhooks::Model>, DbErr> {
+        webhooks::Entity::find_by_id(id).one(&*self.db).await
+    }
+
+    /// Find all webhooks, oldest first
+    pub async fn find_all(&self) -> Result<Vec<webhooks::Model>, DbErr> {
+        webhooks::Entity::find()
+            .order_by_asc(webhooks::Column::CreatedAt)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Active webhooks subscribed to `event`
+    pub async fn find_subscribed(&self, event: &str) -> Result<Vec<webhooks::Model>, DbErr> {
+        let active = webhooks::Entity::find()
+            .filter(webhooks::Column::IsActive.eq(true))
+            .order_by_asc(webhooks::Column::CreatedAt)
+            .all(&*self.db)
+            .await?;
+
+        Ok(active
+            .into_iter()
+            .filter(|webhook| webhook_events(webhook).iter().any(|e| e == event))
+            .collect())
+    }
+
+    /// Update a webhook's URL, events or active flag
+    pub async fn update_webhook(
+        &self,
+        id: &str,
+        request: UpdateWebhookRequest,
+    ) -> Result<webhooks::Model, DbErr> {
+        let webhook = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Webhook not found".to_string()))?;
+
+        let mut active: webhooks::ActiveModel = webhook.into();
+        if let Some(url) = request.url {
+            active.url = Set(Self::validate_url(&url)?);
+        }
+        if let Some(events) = request.events {
+            let events = Self::validate_events(&events)?;
+            active.events = Set(serde_json::to_string(&events).unwrap_or_default());
+        }
+        if let Some(is_active) = request.is_active {
+            active.is_active = Set(is_active);
+        }
+        active.updated_at = Set(chrono::Utc::now());
+        active.update(&*self.db).await
+    }
+
+    /// Delete a webhook along with its delivery log
+    pub async fn delete_webhook(&self, id: &str) -> Result<(), DbErr> {
+        webhook_deliveries::Entity::delete_many()
+            .filter(webhook_deliveries::Column::WebhookId.eq(id))
+            .exec(&*self.db)
+            .await?;
+        let result = webhooks::Entity::delete_by_id(id).exec(&*self.db).await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Webhook not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Queue a payload for delivery, first attempted at `next_attempt_at`
+    pub async fn enqueue_delivery(
+        &self,
+        webhook_id: &str,
+        event: &str,
+        payload: String,
+        next_attempt_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<webhook_deliveries::Model, DbErr> {
+        let delivery = webhook_deliveries::ActiveModel {
+            webhook_id: Set(webhook_id.to_string()),
+            event: Set(event.to_string()),
+            payload: Set(payload),
+            next_attempt_at: Set(Some(next_attempt_at)),
+            ..Default::default()
+        };
+        delivery.insert(&*self.db).await
+    }
+
+    /// Pending deliveries whose next attempt is due, oldest first
+    pub async fn find_due_deliveries(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: u64,
+    ) -> Result<Vec<webhook_deliveries::Model>, DbErr> {
+        webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::Status.eq("pending"))
+            .filter(webhook_deliveries::Column::NextAttemptAt.lte(now))
+            .order_by_asc(webhook_deliveries::Column::NextAttemptAt)
+            .limit(limit)
+            .all(&*self.db)
+            .await
+    }
+
+    /// Record a delivery attempt
+    ///
+    /// Without an error the delivery is done; with one it stays pending until
+    /// `next_attempt_at`, or fails for good when no further attempt is scheduled.
+    pub async fn record_attempt(
+        &self,
+        id: &str,
+        response_status: Option<i32>,
+        error: Option<String>,
+        next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<webhook_deliveries::Model, DbErr> {
+        let delivery = webhook_deliveries::Entity::find_by_id(id)
+            .one(&*self.db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Webhook delivery not found".to_string()))?;
+
+        let attempts = delivery.attempts + 1;
+        let mut active: webhook_deliveries::ActiveModel = delivery.into();
+        active.attempts = Set(attempts);
+        active.response_status = Set(response_status);
+        match error {
+            None => {
+                active.status = Set("delivered".to_string());
+                active.error = Set(None);
+                active.next_attempt_at = Set(None);
+                active.delivered_at = Set(Some(chrono::Utc::now()));
+            }
+            Some(error) => {
+                let status = if next_attempt_at.is_some() {
+                    "pending"
+                } else {
+                    "failed"
+                };
+                active.status = Set(status.to_string());
+                active.error = Set(Some(error));
+                active.next_attempt_at = Set(next_attempt_at);
+            }
+        }
+        active.update(&*self.db).await
+    }
+
+    /// Delivery log of a webhook, newest first
+    pub async fn find_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: u64,
+    ) -> Result<Vec<webhook_deliveries::Model>, DbErr> {
+        webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::WebhookId.eq(webhook_id))
+            .order_by_desc(webhook_deliveries::Column::CreatedAt)
+            .limit(limit)
+            .all(&*self.db)
+            .await
+    }
+
+    fn validate_url(url: &str) -> Result<String, DbErr> {
+        let url = url.trim();
+        let has_host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .is_some_and(|rest| !rest.is_empty());
+        if !has_host {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Webhook URL must start with http:// or https://".to_string(),
+            ));
+        }
+        Ok(url.to_string())
+    }
+
+    fn validate_events(events: &[String]) -> Result<Vec<String>, DbErr> {
+        let mut valid = Vec::new();
+        for event in events {
+            let event = event.trim();
+            if !WEBHOOK_EVENTS.contains(&event) {
+                return Err(DbErr::Custom(format!(
+                    "VALIDATION_ERROR: Unknown webhook event '{}'. Expected one of: {}",
+                    event,
+                    WEBHOOK_EVENTS.join(", ")
+                )));
+            }
+            if !valid.iter().any(|e| e == event) {
+                valid.push(event.to_string());
+            }
+        }
+
+        if valid.is_empty() {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: A webhook needs at least one event".to_string(),
+            ));
+        }
+        Ok(valid)
+    }
+}
+
+/// Events a webhook is subscribed to
+pub fn webhook_events(webhook: &webhooks::Model) -> Vec<String> {
+    serde_json::from_str(&webhook.events).unwrap_or_default()
+}
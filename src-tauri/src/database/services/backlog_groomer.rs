@@ -0,0 +1,279 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::database::entities::tasks;
+use crate::database::repositories::periodic_task_repository::{
+    CreatePeriodicTaskTemplateRequest, PeriodicTaskRepository, UpdatePeriodicTaskTemplateRequest,
+};
+use crate::database::repositories::{TaskRepository, TaskStatusRepository};
+
+/// Title of the recurring task created for reviewing the grooming queue
+pub const REVIEW_TASK_TITLE: &str = "Review backlog";
+
+const DEFAULT_MIN_AGE_DAYS: i64 = 30;
+const DEFAULT_MIN_PRIORITY: i32 = 2;
+const DEFAULT_LIMIT: usize = 20;
+
+/// Which backlog tasks need grooming; unset rules take their defaults
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GroomingRules {
+    /// Tasks untouched for this many days are stale (30 by default)
+    pub min_age_days: Option<i64>,
+    /// Tasks with at least this priority are surfaced (2, high, by default)
+    pub min_priority: Option<i32>,
+    /// Surface tasks that open tasks depend on (true by default)
+    pub include_blocking: Option<bool>,
+    pub task_list_id: Option<String>,
+    /// Longest queue returned (20 by default)
+    pub limit: Option<usize>,
+}
+
+/// Why a task is in the grooming queue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GroomingReason {
+    Stale { age_days: i64 },
+    HighPriority { priority: i32 },
+    Blocking { dependent_count: usize },
+}
+
+/// A backlog task to groom, with why it was surfaced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroomingItem {
+    pub task: tasks::Model,
+    pub reasons: Vec<GroomingReason>,
+    /// Higher scores come first in the queue
+    pub score: f64,
+}
+
+/// Backlog tasks matching the grooming rules, most pressing first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroomingQueue {
+    pub generated_at: DateTime<Utc>,
+    /// Backlog tasks looked at
+    pub backlog_count: usize,
+    pub items: Vec<GroomingItem>,
+    /// The recurring "Review backlog" task listing the queue, when one was requested
+    pub review_template_id: Option<String>,
+}
+
+/// Surfaces backlog tasks that are stale, important or holding up other work
+pub struct BacklogGroomer {
+    db: Arc<DatabaseConnection>,
+    task_repo: TaskRepository,
+    periodic_repo: PeriodicTaskRepository,
+}
+
+impl BacklogGroomer {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            task_repo: TaskRepository::new(db.clone()),
+            periodic_repo: PeriodicTaskRepository::new(db.clone()),
+            db,
+        }
+    }
+
+    /// Build the grooming queue
+    ///
+    /// With `create_review_task`, a weekly "Review backlog" task lists the queue in its
+    /// description; an existing one is updated rather than duplicated.
+    pub async fn groom(
+        &self,
+        rules: &GroomingRules,
+        create_review_task: bool,
+    ) -> Result<GroomingQueue, DbErr> {
+        if rules.min_age_days.is_some_and(|days| days < 1) {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Stale tasks must be at least 1 day old".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let backlog: Vec<tasks::Model> = self
+            .task_repo
+            .find_backlog()
+            .await?
+            .into_iter()
+            .filter(|task| rules.task_list_id.is_none() || task.task_list_id == rules.task_list_id)
+            .collect();
+        let open_tasks = self.task_repo.find_all(None, None).await?;
+        let edges = self.task_repo.dependency_edges().await?;
+        let done = TaskStatusRepository::done_statuses_in(&*self.db).await?;
+
+        let mut open_dependents: HashMap<&str, usize> = HashMap::new();
+        for task in open_tasks
+            .iter()
+            .filter(|task| !done.contains(&task.status))
+        {
+            for depends_on in edges.get(&task.id).into_iter().flatten() {
+                *open_dependents.entry(depends_on.as_str()).or_default() += 1;
+            }
+        }
+
+        let items = rank_backlog(&backlog, &open_dependents, rules, now);
+        let review_template_id = if create_review_task {
+            Some(self.save_review_task(&items).await?)
+        } else {
+            None
+        };
+
+        Ok(GroomingQueue {
+            generated_at: now,
+            backlog_count: backlog.len(),
+            items,
+            review_template_id,
+        })
+    }
+
+    async fn save_review_task(&self, items: &[GroomingItem]) -> Result<String, DbErr> {
+        let description = review_description(items);
+        let existing = self
+            .periodic_repo
+            .find_active()
+            .await?
+            .into_iter()
+            .find(|template| template.title == REVIEW_TASK_TITLE);
+
+        let template = match existing {
+            Some(template) => {
+                self.periodic_repo
+                    .update_template(
+                        &template.id,
+                        UpdatePeriodicTaskTemplateRequest {
+                            title: None,
+                            description: Some(description),
+                            priority: None,
+                            time_estimate: None,
+                            tags: None,
+                            task_list_id: None,
+                            recurrence_type: None,
+                            recurrence_interval: None,
+                            recurrence_unit: None,
+                            recurrence_rule: None,
+                            is_active: None,
+                            ends_at: None,
+                            clear_ends_at: None,
+                            max_occurrences: None,
+                            clear_max_occurrences: None,
+                            missed_occurrence_policy: None,
+                        },
+                    )
+                    .await?
+            }
+            None => {
+                self.periodic_repo
+                    .create_template(CreatePeriodicTaskTemplateRequest {
+                        title: REVIEW_TASK_TITLE.to_string(),
+                        description: Some(description),
+                        priority: 1,
+                        time_estimate: 30,
+                        tags: Some(vec!["grooming".to_string()]),
+                        task_list_id: None,
+                        recurrence_type: "weekly".to_string(),
+                        recurrence_interval: 1,
+                        recurrence_unit: None,
+                        recurrence_rule: None,
+                        start_date: Utc::now(),
+                        ends_at: None,
+                        max_occurrences: None,
+                        missed_occurrence_policy: Some("skip".to_string()),
+                    })
+                    .await?
+            }
+        };
+        Ok(template.id)
+    }
+}
+
+/// Score the backlog against the rules and return the matching tasks, most pressing first
+///
+/// Staleness adds up to 3 points as a task ages past the threshold, priority adds a point per
+/// level from high, and every open dependent adds a point, up to 3.
+pub(crate) fn rank_backlog(
+    backlog: &[tasks::Model],
+    open_dependents: &HashMap<&str, usize>,
+    rules: &GroomingRules,
+    now: DateTime<Utc>,
+) -> Vec<GroomingItem> {
+    let min_age_days = rules.min_age_days.unwrap_or(DEFAULT_MIN_AGE_DAYS);
+    let min_priority = rules.min_priority.unwrap_or(DEFAULT_MIN_PRIORITY);
+    let include_blocking = rules.include_blocking.unwrap_or(true);
+
+    let mut items: Vec<GroomingItem> = backlog
+        .iter()
+        .filter_map(|task| {
+            let mut reasons = Vec::new();
+            let mut score = 0.0;
+
+            let age_days = (now - task.updated_at).num_days();
+            if age_days >= min_age_days {
+                reasons.push(GroomingReason::Stale { age_days });
+                score += (age_days as f64 / min_age_days as f64).min(3.0);
+            }
+            if task.priority >= min_priority {
+                reasons.push(GroomingReason::HighPriority {
+                    priority: task.priority,
+                });
+                score += (task.priority - 1).max(1) as f64;
+            }
+            let dependent_count = open_dependents.get(task.id.as_str()).copied().unwrap_or(0);
+            if include_blocking && dependent_count > 0 {
+                reasons.push(GroomingReason::Blocking { dependent_count });
+                score += dependent_count.min(3) as f64;
+            }
+
+            (!reasons.is_empty()).then(|| GroomingItem {
+                task: task.clone(),
+                reasons,
+                score,
+            })
+        })
+        .collect();
+
+    items.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(a.task.updated_at.cmp(&b.task.updated_at))
+    });
+    items.truncate(rules.limit.unwrap_or(DEFAULT_LIMIT));
+    items
+}
+
+/// Checklist of the queue for the review task's description
+fn review_description(items: &[GroomingItem]) -> String {
+    if items.is_empty() {
+        return "Nothing in the backlog needs grooming right now.".to_string();
+    }
+
+    let lines: Vec<String> = items
+        .iter()
+        .map(|item| {
+            let reasons: Vec<String> = item
+                .reasons
+                .iter()
+                .map(|reason| match reason {
+                    GroomingReason::Stale { age_days } => format!("untouched {} days", age_days),
+                    GroomingReason::HighPriority { priority } => {
+                        format!("priority {}", priority)
+                    }
+                    GroomingReason::Blocking { dependent_count } => {
+                        format!("blocks {}", dependent_count)
+                    }
+                })
+                .collect();
+            format!(
+                "- [ ] {} ({}) [{}]",
+                item.task.title,
+                reasons.join(", "),
+                item.task.id
+            )
+        })
+        .collect();
+    format!(
+        "Backlog tasks to groom, most pressing first:\n\n{}",
+        lines.join("\n")
+    )
+}
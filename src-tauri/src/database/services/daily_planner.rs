@@ -0,0 +1,253 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::database::entities::tasks;
+use crate::database::repositories::preferences_repository::WorkingHours;
+use crate::database::repositories::TaskRepository;
+use crate::database::services::dependency_scheduler::{
+    DependencySchedule, ScheduleState, ScheduledTask,
+};
+use crate::database::services::DependencyScheduler;
+
+/// Time planned for open tasks without an estimate, or already past it
+const DEFAULT_TASK_MINUTES: i64 = 30;
+
+/// Why a task made it into the plan, most pressing first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanReason {
+    Overdue,
+    DueToday,
+    ScheduledToday,
+    /// On the longest dependency chain of open work
+    CriticalPath,
+    Priority,
+}
+
+impl PlanReason {
+    /// Whether the task is expected to be done today, so leaving it out is reported
+    fn is_committed(self) -> bool {
+        matches!(
+            self,
+            PlanReason::Overdue | PlanReason::DueToday | PlanReason::ScheduledToday
+        )
+    }
+}
+
+/// Why a task expected today was left out of the plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeferReason {
+    /// A dependency is open and could not be planned before it
+    Blocked,
+    /// The task is part of (or waits on) a dependency cycle
+    Cyclic,
+    /// The working time left today is used up
+    NoTimeLeft,
+}
+
+/// Time set aside for one task; durations are in minutes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedBlock {
+    pub task_id: String,
+    pub title: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub duration_minutes: i64,
+    pub priority: i32,
+    pub reason: PlanReason,
+}
+
+/// Task due or scheduled today that the plan could not fit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeferredTask {
+    pub task_id: String,
+    pub title: String,
+    pub reason: DeferReason,
+}
+
+/// Proposed schedule for the rest of today
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyPlan {
+    pub date: NaiveDate,
+    pub generated_at: DateTime<Utc>,
+    pub is_workday: bool,
+    /// Working minutes left today
+    pub available_minutes: i64,
+    pub planned_minutes: i64,
+    pub blocks: Vec<PlannedBlock>,
+    pub deferred: Vec<DeferredTask>,
+}
+
+/// Fills the working time left today with open tasks
+///
+/// Overdue work comes first, then work due or scheduled today, then the critical path and the
+/// remaining tasks by priority. A task is only planned after the open tasks it depends on, and
+/// tasks scheduled for a later day are left alone. The same tasks and settings always give the
+/// same plan.
+pub struct DailyPlanner {
+    task_repo: TaskRepository,
+    scheduler: DependencyScheduler,
+}
+
+impl DailyPlanner {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            task_repo: TaskRepository::new(db.clone()),
+            scheduler: DependencyScheduler::new(db),
+        }
+    }
+
+    /// Plan the rest of the day around `now`, optionally for one task list
+    ///
+    /// The day and its working hours run in `timezone`.
+    pub async fn generate_plan<Tz: TimeZone>(
+        &self,
+        working_hours: &WorkingHours,
+        task_list_id: Option<&str>,
+        now: DateTime<Utc>,
+        timezone: &Tz,
+    ) -> Result<DailyPlan, DbErr> {
+        let tasks = self.task_repo.find_all(None, None).await?;
+        let schedule = self.scheduler.compute_schedule(task_list_id).await?;
+
+        Ok(build_plan(&tasks, &schedule, working_hours, now, timezone))
+    }
+}
+
+struct Candidate<'a> {
+    scheduled: &'a ScheduledTask,
+    task: &'a tasks::Model,
+    reason: PlanReason,
+    minutes: i64,
+}
+
+fn build_plan<Tz: TimeZone>(
+    tasks: &[tasks::Model],
+    schedule: &DependencySchedule,
+    working_hours: &WorkingHours,
+    now: DateTime<Utc>,
+    timezone: &Tz,
+) -> DailyPlan {
+    let local_date = |date: DateTime<Utc>| date.with_timezone(timezone).date_naive();
+    let context = working_hours.workday_context(now, timezone);
+    let today = context.today;
+    let by_id: HashMap<&str, &tasks::Model> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+    let mut candidates: Vec<Candidate> = schedule
+        .tasks
+        .iter()
+        .filter(|scheduled| scheduled.state != ScheduleState::Done)
+        .filter_map(|scheduled| {
+            let task = *by_id.get(scheduled.task_id.as_str())?;
+            let scheduled_day = task.scheduled_date.map(local_date);
+            if scheduled_day.is_some_and(|day| day > today) {
+                return None;
+            }
+            let due_day = task.due_date.map(local_date);
+            let reason = if due_day.is_some_and(|day| day < today) {
+                PlanReason::Overdue
+            } else if due_day == Some(today) {
+                PlanReason::DueToday
+            } else if scheduled_day.is_some() {
+                PlanReason::ScheduledToday
+            } else if scheduled.is_critical {
+                PlanReason::CriticalPath
+            } else {
+                PlanReason::Priority
+            };
+            let minutes = if scheduled.duration_minutes > 0 {
+                scheduled.duration_minutes
+            } else {
+                DEFAULT_TASK_MINUTES
+            };
+            Some(Candidate {
+                scheduled,
+                task,
+                reason,
+                minutes,
+            })
+        })
+        .collect();
+    candidates.sort_by_key(|candidate| {
+        (
+            candidate.reason,
+            std::cmp::Reverse(candidate.task.priority),
+            candidate.task.due_date.is_none(),
+            candidate.task.due_date,
+            candidate.scheduled.slack_minutes.unwrap_or(0),
+            candidate.task.id.as_str(),
+        )
+    });
+
+    // Take the best task that can start and still fits, until nothing does
+    let mut cursor = context.next_working_time.unwrap_or(now);
+    let mut remaining = context.minutes_left_today;
+    let mut planned: HashSet<&str> = HashSet::new();
+    let mut blocks = Vec::new();
+    loop {
+        let next = candidates.iter().position(|candidate| {
+            candidate.scheduled.state != ScheduleState::Cyclic
+                && candidate.minutes <= remaining
+                && candidate
+                    .scheduled
+                    .blocked_by
+                    .iter()
+                    .all(|dep| planned.contains(dep.as_str()))
+        });
+        let Some(index) = next else {
+            break;
+        };
+        let candidate = candidates.remove(index);
+        let end = cursor + Duration::minutes(candidate.minutes);
+        blocks.push(PlannedBlock {
+            task_id: candidate.task.id.clone(),
+            title: candidate.task.title.clone(),
+            start: cursor,
+            end,
+            duration_minutes: candidate.minutes,
+            priority: candidate.task.priority,
+            reason: candidate.reason,
+        });
+        planned.insert(candidate.task.id.as_str());
+        cursor = end;
+        remaining -= candidate.minutes;
+    }
+
+    let deferred = candidates
+        .iter()
+        .filter(|candidate| candidate.reason.is_committed())
+        .map(|candidate| {
+            let reason = if candidate.scheduled.state == ScheduleState::Cyclic {
+                DeferReason::Cyclic
+            } else if candidate
+                .scheduled
+                .blocked_by
+                .iter()
+                .any(|dep| !planned.contains(dep.as_str()))
+            {
+                DeferReason::Blocked
+            } else {
+                DeferReason::NoTimeLeft
+            };
+            DeferredTask {
+                task_id: candidate.task.id.clone(),
+                title: candidate.task.title.clone(),
+                reason,
+            }
+        })
+        .collect();
+
+    DailyPlan {
+        date: today,
+        generated_at: now,
+        is_workday: context.is_workday,
+        available_minutes: context.minutes_left_today,
+        planned_minutes: blocks.iter().map(|block| block.duration_minutes).sum(),
+        blocks,
+        deferred,
+    }
+}
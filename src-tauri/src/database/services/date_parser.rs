@@ -0,0 +1,373 @@
+use chrono::{
+    DateTime, Datelike, Days, Duration, Local, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Timelike, Utc,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+const DAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+const NUMBER_WORDS: [&str; 12] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+    "twelve",
+];
+
+/// A date found in free text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParsedDate {
+    pub date: DateTime<Utc>,
+    /// The part of the input the date was read from, as typed
+    pub matched: String,
+    /// False when only a day was given; the date is then at local midnight
+    pub has_time: bool,
+}
+
+/// A date phrase resolved to a local day, or to an exact moment for "in 2 hours"
+enum DatePart {
+    Day(NaiveDate, Option<NaiveTime>),
+    Exact(NaiveDateTime),
+}
+
+struct Token<'a> {
+    word: String,
+    text: &'a str,
+    start: usize,
+}
+
+/// Find the first date phrase in `input`, relative to `now`
+///
+/// Understands phrases like "today", "tomorrow 3pm", "next friday", "in 2 weeks",
+/// "3 days from now", "march 14 at 9:30am", "noon" and ISO dates such as "2025-03-14".
+/// Weekdays always mean the first such day after today. A time of day on its own that has
+/// already passed means tomorrow.
+pub fn parse_natural_date<Tz: TimeZone>(input: &str, now: &DateTime<Tz>) -> Option<ParsedDate> {
+    let tokens = tokenize(input);
+    let local_now = now.naive_local().with_nanosecond(0)?;
+
+    (0..tokens.len()).find_map(|start| {
+        let (len, moment, has_time) = parse_phrase(&tokens[start..], local_now)?;
+        let first = &tokens[start];
+        let last = &tokens[start + len - 1];
+        let date = resolve_local(&now.timezone(), moment)?;
+        Some(ParsedDate {
+            date,
+            matched: input[first.start..last.start + last.text.len()].to_string(),
+            has_time,
+        })
+    })
+}
+
+/// Deserialize an optional date given as RFC 3339 or as a phrase like "next friday"
+///
+/// Phrases are read relative to the local time zone of this machine.
+pub fn deserialize_flexible_date<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(value) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Some(date.with_timezone(&Utc)));
+    }
+
+    parse_natural_date(value, &Local::now())
+        .map(|parsed| Some(parsed.date))
+        .ok_or_else(|| serde::de::Error::custom(format!("unrecognized date '{}'", value)))
+}
+
+fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (index, c) in input
+        .char_indices()
+        .chain(std::iter::once((input.len(), ' ')))
+    {
+        let separator = c.is_whitespace() || c == ',';
+        match (start, separator) {
+            (None, false) => start = Some(index),
+            (Some(from), true) => {
+                let text = input[from..index].trim_end_matches(['.', '!', '?']);
+                if !text.is_empty() {
+                    tokens.push(Token {
+                        word: text.to_lowercase(),
+                        text,
+                        start: from,
+                    });
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// Parse a phrase at the start of `tokens`, returning how many tokens it used
+fn parse_phrase(tokens: &[Token], now: NaiveDateTime) -> Option<(usize, NaiveDateTime, bool)> {
+    let words: Vec<&str> = tokens.iter().map(|token| token.word.as_str()).collect();
+
+    if let Some((used, part)) = parse_date_part(&words, now) {
+        return Some(match part {
+            DatePart::Exact(moment) => (used, moment, true),
+            DatePart::Day(day, default_time) => match parse_time_part(&words[used..]) {
+                Some((time_used, time)) => (used + time_used, day.and_time(time), true),
+                None => (
+                    used,
+                    day.and_time(default_time.unwrap_or(NaiveTime::MIN)),
+                    default_time.is_some(),
+                ),
+            },
+        });
+    }
+
+    let (used, time) = parse_time_part(&words)?;
+    match parse_date_part(&words[used..], now) {
+        Some((date_used, DatePart::Day(day, _))) => {
+            Some((used + date_used, day.and_time(time), true))
+        }
+        _ => {
+            let today = now.date().and_time(time);
+            let moment = if today > now {
+                today
+            } else {
+                today + Duration::days(1)
+            };
+            Some((used, moment, true))
+        }
+    }
+}
+
+fn parse_date_part(words: &[&str], now: NaiveDateTime) -> Option<(usize, DatePart)> {
+    let today = now.date();
+    let first = *words.first()?;
+    let second = words.get(1).copied().unwrap_or_default();
+
+    match first {
+        "today" => return Some((1, DatePart::Day(today, None))),
+        "tonight" => return Some((1, DatePart::Day(today, NaiveTime::from_hms_opt(20, 0, 0)))),
+        "tomorrow" | "tmrw" | "tmr" => {
+            return Some((1, DatePart::Day(today + Duration::days(1), None)))
+        }
+        "yesterday" => return Some((1, DatePart::Day(today - Duration::days(1), None))),
+        "day" if words[1..].starts_with(&["after", "tomorrow"]) => {
+            return Some((3, DatePart::Day(today + Duration::days(2), None)))
+        }
+        "in" => {
+            let (used, part) = parse_offset(&words[1..], now)?;
+            return Some((used + 1, part));
+        }
+        "on" => {
+            let (used, part) = parse_date_part(&words[1..], now)?;
+            return Some((used + 1, part));
+        }
+        "next" | "this" => {
+            if let Some(day) = parse_weekday(second) {
+                return Some((2, DatePart::Day(next_weekday(today, day), None)));
+            }
+            let day = match (first, second) {
+                ("next", "week") => today + Duration::weeks(1),
+                ("next", "month") => today.checked_add_months(Months::new(1))?,
+                ("next", "year") => today.checked_add_months(Months::new(12))?,
+                _ => return None,
+            };
+            return Some((2, DatePart::Day(day, None)));
+        }
+        _ => {}
+    }
+
+    if let Some(day) = parse_weekday(first) {
+        return Some((1, DatePart::Day(next_weekday(today, day), None)));
+    }
+    if let Ok(day) = NaiveDate::parse_from_str(first, "%Y-%m-%d") {
+        return Some((1, DatePart::Day(day, None)));
+    }
+    if let Some((used, day)) = parse_month_day(words, today) {
+        return Some((used, DatePart::Day(day, None)));
+    }
+
+    // "3 days from now"
+    if matches!(words.get(2..4), Some(["from", "now"])) {
+        let (used, part) = parse_offset(&words[..2], now)?;
+        return Some((used + 2, part));
+    }
+    None
+}
+
+/// Parse "2 weeks", "a month" or "an hour"
+fn parse_offset(words: &[&str], now: NaiveDateTime) -> Option<(usize, DatePart)> {
+    let amount = match *words.first()? {
+        "a" | "an" => 1,
+        word => parse_number(word)?,
+    };
+    let unit = words.get(1)?.trim_end_matches('s');
+
+    // Offsets past the calendar's range are no date at all, rather than a panic
+    let part = match unit {
+        "minute" | "min" => {
+            DatePart::Exact(now.checked_add_signed(Duration::minutes(amount.into()))?)
+        }
+        "hour" | "hr" => DatePart::Exact(now.checked_add_signed(Duration::hours(amount.into()))?),
+        "day" => DatePart::Day(now.date().checked_add_days(Days::new(amount.into()))?, None),
+        "week" => DatePart::Day(
+            now.date()
+                .checked_add_days(Days::new(u64::from(amount) * 7))?,
+            None,
+        ),
+        "month" => DatePart::Day(now.date().checked_add_months(Months::new(amount))?, None),
+        "year" => DatePart::Day(
+            now.date()
+                .checked_add_months(Months::new(amount.checked_mul(12)?))?,
+            None,
+        ),
+        _ => return None,
+    };
+    Some((2, part))
+}
+
+/// Parse "march 14", "mar 14th", "14 march" or "14th of march", in the coming year
+fn parse_month_day(words: &[&str], today: NaiveDate) -> Option<(usize, NaiveDate)> {
+    let (used, month, day) = if let Some(month) = parse_month(words[0]) {
+        (2, month, parse_ordinal(words.get(1)?)?)
+    } else {
+        let day = parse_ordinal(words[0])?;
+        match words.get(1..3) {
+            Some(["of", month]) => (3, parse_month(month)?, day),
+            _ => (2, parse_month(words.get(1)?)?, day),
+        }
+    };
+
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day);
+    let day = match this_year {
+        Some(date) if date >= today => date,
+        _ => NaiveDate::from_ymd_opt(today.year() + 1, month, day)?,
+    };
+    Some((used, day))
+}
+
+/// Parse "3pm", "3:30 pm", "15:00", "at 9", "noon", "morning" and the like
+fn parse_time_part(words: &[&str]) -> Option<(usize, NaiveTime)> {
+    let (skip, words) = match words.first() {
+        Some(&"at") => (1, &words[1..]),
+        _ => (0, words),
+    };
+    let first = *words.first()?;
+
+    let named = match first {
+        "noon" | "midday" => Some(12),
+        "midnight" => Some(0),
+        "morning" => Some(9),
+        "afternoon" => Some(14),
+        "evening" => Some(18),
+        "night" => Some(20),
+        _ => None,
+    };
+    if let Some(hour) = named {
+        return Some((skip + 1, NaiveTime::from_hms_opt(hour, 0, 0)?));
+    }
+
+    let (clock, meridiem, used) = if let Some(clock) = first
+        .strip_suffix("am")
+        .or_else(|| first.strip_suffix("pm"))
+        .filter(|clock| !clock.is_empty())
+    {
+        (clock, Some(&first[clock.len()..]), 1)
+    } else {
+        match words.get(1) {
+            Some(&meridiem) if meridiem == "am" || meridiem == "pm" => (first, Some(meridiem), 2),
+            // A bare hour needs "at" or minutes ("at 9", "15:00")
+            _ if skip == 1 || first.contains(':') => (first, None, 1),
+            _ => return None,
+        }
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour.parse().ok()?, minute.parse().ok()?),
+        Some(_) => return None,
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+    let hour = match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some("am") => hour % 12,
+        Some(_) => hour % 12 + 12,
+        None => hour,
+    };
+
+    Some((skip + used, NaiveTime::from_hms_opt(hour, minute, 0)?))
+}
+
+fn parse_number(word: &str) -> Option<u32> {
+    word.parse().ok().or_else(|| {
+        NUMBER_WORDS
+            .iter()
+            .position(|name| *name == word)
+            .map(|index| index as u32 + 1)
+    })
+}
+
+fn parse_ordinal(word: &str) -> Option<u32> {
+    let digits = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        .unwrap_or(word);
+    digits.parse().ok().filter(|day| (1..=31).contains(day))
+}
+
+fn parse_month(word: &str) -> Option<u32> {
+    MONTH_NAMES
+        .iter()
+        .position(|name| word.len() >= 3 && name.starts_with(word))
+        .map(|index| index as u32 + 1)
+}
+
+/// Weekday as days from Monday; accepts full names and three-letter abbreviations
+fn parse_weekday(word: &str) -> Option<u32> {
+    DAY_NAMES
+        .iter()
+        .position(|name| word.len() >= 3 && name.starts_with(word))
+        .map(|index| index as u32)
+}
+
+/// The first given weekday after `today`
+fn next_weekday(today: NaiveDate, weekday: u32) -> NaiveDate {
+    let current = today.weekday().num_days_from_monday();
+    let ahead = (weekday + 7 - current) % 7;
+    today + Duration::days(if ahead == 0 { 7 } else { ahead.into() })
+}
+
+/// Convert a local date and time to UTC, moving past times skipped by a DST change
+fn resolve_local<Tz: TimeZone>(timezone: &Tz, moment: NaiveDateTime) -> Option<DateTime<Utc>> {
+    timezone
+        .from_local_datetime(&moment)
+        .earliest()
+        .or_else(|| {
+            timezone
+                .from_local_datetime(&moment.checked_add_signed(Duration::hours(1))?)
+                .earliest()
+        })
+        .map(|date| date.with_timezone(&Utc))
+}
@@ -0,0 +1,268 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use crate::database::entities::tasks;
+use crate::database::repositories::{TaskRepository, TaskStatusRepository};
+
+/// Where a task stands with respect to its dependencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleState {
+    /// Every dependency is finished, the task can be worked on now
+    Ready,
+    /// At least one dependency is still open
+    Blocked,
+    /// The task itself is finished
+    Done,
+    /// The task is part of (or waits on) a dependency cycle and cannot be scheduled
+    Cyclic,
+}
+
+/// Scheduling details for a single task; durations are in minutes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledTask {
+    pub task_id: String,
+    pub title: String,
+    pub state: ScheduleState,
+    /// Open tasks this task is waiting on
+    pub blocked_by: Vec<String>,
+    /// Remaining work: time estimate minus time already spent
+    pub duration_minutes: i64,
+    pub earliest_start: Option<DateTime<Utc>>,
+    pub earliest_finish: Option<DateTime<Utc>>,
+    /// How long the task can slip without delaying the last open task
+    pub slack_minutes: Option<i64>,
+    pub is_critical: bool,
+}
+
+/// Dependency-aware schedule of open work
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencySchedule {
+    pub generated_at: DateTime<Utc>,
+    pub tasks: Vec<ScheduledTask>,
+    /// Task IDs of the longest dependency chain, first task first
+    pub critical_path: Vec<String>,
+    pub critical_path_minutes: i64,
+    pub projected_finish: Option<DateTime<Utc>>,
+}
+
+/// Computes earliest start dates, readiness and the critical path from task dependencies
+pub struct DependencyScheduler {
+    task_repo: TaskRepository,
+    status_repo: TaskStatusRepository,
+}
+
+impl DependencyScheduler {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            task_repo: TaskRepository::new(db.clone()),
+            status_repo: TaskStatusRepository::new(db),
+        }
+    }
+
+    /// Build the schedule for all active tasks, or only for the tasks of one list
+    ///
+    /// Dependencies on tasks outside the list still count; archived tasks are ignored.
+    pub async fn compute_schedule(
+        &self,
+        task_list_id: Option<&str>,
+    ) -> Result<DependencySchedule, DbErr> {
+        let tasks = self.task_repo.find_all(None, None).await?;
+        let edges = self.task_repo.dependency_edges().await?;
+        let done_statuses: HashSet<String> = self
+            .status_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|status| status.category == "done")
+            .map(|status| status.name)
+            .collect();
+
+        Ok(build_schedule(
+            &tasks,
+            &edges,
+            &done_statuses,
+            task_list_id,
+            Utc::now(),
+        ))
+    }
+}
+
+fn build_schedule(
+    tasks: &[tasks::Model],
+    edges: &HashMap<String, Vec<String>>,
+    done_statuses: &HashSet<String>,
+    task_list_id: Option<&str>,
+    now: DateTime<Utc>,
+) -> DependencySchedule {
+    let by_id: HashMap<&str, &tasks::Model> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+    let is_open = |id: &str| {
+        by_id
+            .get(id)
+            .is_some_and(|task| !done_statuses.contains(&task.status))
+    };
+
+    // Open dependencies of each open task; finished, archived or missing ones are satisfied
+    let open_deps: HashMap<&str, Vec<&str>> = tasks
+        .iter()
+        .filter(|task| is_open(&task.id))
+        .map(|task| {
+            let deps = edges
+                .get(&task.id)
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .filter(|dep| is_open(dep))
+                .collect();
+            (task.id.as_str(), deps)
+        })
+        .collect();
+
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    for (&task_id, deps) in &open_deps {
+        remaining.insert(task_id, deps.len());
+        for &dep in deps {
+            dependents.entry(dep).or_default().push(task_id);
+        }
+    }
+
+    // Topological order (Kahn); tasks never reaching zero are stuck behind a cycle
+    let mut roots: Vec<&str> = remaining
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&id, _)| id)
+        .collect();
+    roots.sort();
+    let mut queue: VecDeque<&str> = roots.into();
+    let mut order = Vec::new();
+    while let Some(task_id) = queue.pop_front() {
+        order.push(task_id);
+        for &dependent in dependents.get(task_id).into_iter().flatten() {
+            if let Some(count) = remaining.get_mut(dependent) {
+                *count -= 1;
+                if *count == 0 {
+                    queue.push_back(dependent);
+                }
+            }
+        }
+    }
+
+    let duration = |id: &str| {
+        by_id
+            .get(id)
+            .map(|task| (task.time_estimate - task.actual_time).max(0) as i64)
+            .unwrap_or(0)
+    };
+
+    // Forward pass: earliest start/finish as minute offsets from now
+    let mut earliest_start: HashMap<&str, i64> = HashMap::new();
+    let mut earliest_finish: HashMap<&str, i64> = HashMap::new();
+    let mut driver: HashMap<&str, &str> = HashMap::new();
+    for &task_id in &order {
+        let not_before = by_id[task_id]
+            .scheduled_date
+            .map(|date| (date - now).num_minutes().max(0))
+            .unwrap_or(0);
+        let mut start = not_before;
+        let latest_dep = open_deps[task_id]
+            .iter()
+            .copied()
+            .max_by_key(|&dep| (earliest_finish[dep], std::cmp::Reverse(dep)));
+        if let Some(dep) = latest_dep.filter(|&dep| earliest_finish[dep] >= not_before) {
+            start = earliest_finish[dep];
+            driver.insert(task_id, dep);
+        }
+        earliest_start.insert(task_id, start);
+        earliest_finish.insert(task_id, start + duration(task_id));
+    }
+
+    // Backward pass: latest finish without delaying the last open task
+    let finish = earliest_finish.values().copied().max().unwrap_or(0);
+    let mut latest_start: HashMap<&str, i64> = HashMap::new();
+    for &task_id in order.iter().rev() {
+        let latest_finish = dependents
+            .get(task_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|dependent| latest_start.get(dependent).copied())
+            .min()
+            .unwrap_or(finish);
+        latest_start.insert(task_id, latest_finish - duration(task_id));
+    }
+
+    let in_scope = |task: &tasks::Model| {
+        task_list_id.is_none_or(|list_id| task.task_list_id.as_deref() == Some(list_id))
+    };
+
+    // Critical path: walk back from the in-scope task that finishes last
+    let mut critical_path = Vec::new();
+    let last = order
+        .iter()
+        .filter(|&&id| in_scope(by_id[id]))
+        .max_by_key(|&&id| (earliest_finish[id], std::cmp::Reverse(id)));
+    if let Some(&last) = last {
+        let mut current = Some(last);
+        while let Some(task_id) = current {
+            critical_path.push(task_id.to_string());
+            current = driver.get(task_id).copied();
+        }
+        critical_path.reverse();
+    }
+    let critical: HashSet<&str> = critical_path.iter().map(String::as_str).collect();
+    let critical_path_minutes = critical_path.iter().map(|id| duration(id)).sum();
+
+    let at = |offset: i64| now + Duration::minutes(offset);
+    let scheduled: Vec<ScheduledTask> = tasks
+        .iter()
+        .filter(|task| in_scope(task))
+        .map(|task| {
+            let id = task.id.as_str();
+            let blocked_by: Vec<String> = open_deps
+                .get(id)
+                .into_iter()
+                .flatten()
+                .map(|dep| dep.to_string())
+                .collect();
+            let state = if !is_open(id) {
+                ScheduleState::Done
+            } else if !earliest_start.contains_key(id) {
+                ScheduleState::Cyclic
+            } else if blocked_by.is_empty() {
+                ScheduleState::Ready
+            } else {
+                ScheduleState::Blocked
+            };
+
+            ScheduledTask {
+                task_id: task.id.clone(),
+                title: task.title.clone(),
+                state,
+                blocked_by,
+                duration_minutes: if is_open(id) { duration(id) } else { 0 },
+                earliest_start: earliest_start.get(id).map(|&m| at(m)),
+                earliest_finish: earliest_finish.get(id).map(|&m| at(m)),
+                slack_minutes: latest_start
+                    .get(id)
+                    .map(|&latest| latest - earliest_start[id]),
+                is_critical: critical.contains(id),
+            }
+        })
+        .collect();
+
+    let projected_finish = scheduled
+        .iter()
+        .filter_map(|task| task.earliest_finish)
+        .max();
+
+    DependencySchedule {
+        generated_at: now,
+        tasks: scheduled,
+        critical_path,
+        critical_path_minutes,
+        projected_finish,
+    }
+}
@@ -0,0 +1,227 @@
+use chrono::{DateTime, TimeZone, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use crate::database::entities::{task_lists, tasks};
+use crate::database::repositories::TaskStatusRepository;
+
+/// Actual time within this share of the estimate counts as accurate
+const ACCURACY_TOLERANCE: f64 = 0.2;
+
+/// Fewest tasks a group needs before its underestimation counts as chronic
+const MIN_CHRONIC_TASKS: usize = 3;
+
+/// Ratio of actual to estimated time from which underestimation counts as chronic
+const CHRONIC_RATIO: f64 = 1.25;
+
+const PRIORITY_LABELS: [&str; 4] = ["Low", "Medium", "High", "Urgent"];
+
+/// How well estimates matched the time actually spent on a set of tasks
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EstimationAccuracy {
+    pub task_count: usize,
+    pub estimated_minutes: i64,
+    pub actual_minutes: i64,
+    /// Actual over estimated time; above 1 means tasks took longer than planned
+    pub ratio: f64,
+    /// Tasks whose actual time stayed within 20% of the estimate
+    pub accurate_count: usize,
+    pub underestimated_count: usize,
+    pub overestimated_count: usize,
+    /// Tasks keep taking clearly longer than estimated
+    pub chronic_underestimation: bool,
+}
+
+impl EstimationAccuracy {
+    fn add(&mut self, estimate: i32, actual: i32) {
+        let estimate = estimate as f64;
+        let actual = actual as f64;
+        self.task_count += 1;
+        self.estimated_minutes += estimate as i64;
+        self.actual_minutes += actual as i64;
+        if actual > estimate * (1.0 + ACCURACY_TOLERANCE) {
+            self.underestimated_count += 1;
+        } else if actual < estimate * (1.0 - ACCURACY_TOLERANCE) {
+            self.overestimated_count += 1;
+        } else {
+            self.accurate_count += 1;
+        }
+    }
+
+    fn finish(mut self) -> Self {
+        if self.estimated_minutes > 0 {
+            self.ratio = self.actual_minutes as f64 / self.estimated_minutes as f64;
+        }
+        self.chronic_underestimation = self.task_count >= MIN_CHRONIC_TASKS
+            && self.ratio >= CHRONIC_RATIO
+            && self.underestimated_count * 2 > self.task_count;
+        self
+    }
+}
+
+/// Estimation accuracy of the tasks sharing a tag, list, priority or month
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimationGroup {
+    /// Tag name, list ID, priority number or `YYYY-MM`
+    pub key: String,
+    pub label: String,
+    #[serde(flatten)]
+    pub accuracy: EstimationAccuracy,
+}
+
+/// Estimated against actual time of the tasks completed in a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimationReport {
+    pub generated_at: DateTime<Utc>,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub overall: EstimationAccuracy,
+    /// Groups are sorted by ratio, most underestimated first
+    pub by_tag: Vec<EstimationGroup>,
+    pub by_task_list: Vec<EstimationGroup>,
+    pub by_priority: Vec<EstimationGroup>,
+    /// Months in order, to follow accuracy over time
+    pub by_month: Vec<EstimationGroup>,
+}
+
+/// Compares time estimates with the time completed tasks actually took
+///
+/// Only completed tasks with both an estimate and tracked time are counted, archived ones
+/// included.
+pub struct EstimationAnalyzer {
+    db: Arc<DatabaseConnection>,
+}
+
+impl EstimationAnalyzer {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Build the report for tasks completed between `start_date` and `end_date`
+    ///
+    /// Months run in `timezone`.
+    pub async fn compute_report<Tz: TimeZone>(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        timezone: &Tz,
+    ) -> Result<EstimationReport, DbErr> {
+        if end_date <= start_date {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: End date must be after start date".to_string(),
+            ));
+        }
+
+        let completed = TaskStatusRepository::completed_statuses_in(&*self.db).await?;
+        let tasks = tasks::Entity::find()
+            .filter(tasks::Column::Status.is_in(completed))
+            .filter(tasks::Column::CompletedAt.gte(start_date))
+            .filter(tasks::Column::CompletedAt.lt(end_date))
+            .filter(tasks::Column::TimeEstimate.gt(0))
+            .filter(tasks::Column::ActualTime.gt(0))
+            .all(&*self.db)
+            .await?;
+        let list_names: HashMap<String, String> = task_lists::Entity::find()
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .map(|list| (list.id, list.name))
+            .collect();
+
+        Ok(build_report(
+            &tasks,
+            &list_names,
+            start_date,
+            end_date,
+            timezone,
+        ))
+    }
+}
+
+fn build_report<Tz: TimeZone>(
+    tasks: &[tasks::Model],
+    list_names: &HashMap<String, String>,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+    timezone: &Tz,
+) -> EstimationReport {
+    let mut overall = EstimationAccuracy::default();
+    let mut by_tag: BTreeMap<String, EstimationAccuracy> = BTreeMap::new();
+    let mut by_task_list: BTreeMap<String, EstimationAccuracy> = BTreeMap::new();
+    let mut by_priority: BTreeMap<i32, EstimationAccuracy> = BTreeMap::new();
+    let mut by_month: BTreeMap<String, EstimationAccuracy> = BTreeMap::new();
+
+    for task in tasks {
+        let (estimate, actual) = (task.time_estimate, task.actual_time);
+        overall.add(estimate, actual);
+
+        let tags: Vec<String> = task
+            .tags
+            .as_deref()
+            .and_then(|tags| serde_json::from_str(tags).ok())
+            .unwrap_or_default();
+        for tag in tags {
+            by_tag.entry(tag).or_default().add(estimate, actual);
+        }
+        if let Some(list_id) = &task.task_list_id {
+            by_task_list
+                .entry(list_id.clone())
+                .or_default()
+                .add(estimate, actual);
+        }
+        by_priority
+            .entry(task.priority)
+            .or_default()
+            .add(estimate, actual);
+        if let Some(completed_at) = task.completed_at {
+            let month = completed_at.with_timezone(timezone).format("%Y-%m");
+            by_month
+                .entry(month.to_string())
+                .or_default()
+                .add(estimate, actual);
+        }
+    }
+
+    let by_tag = groups(by_tag, |tag| tag.clone());
+    let by_task_list = groups(by_task_list, |id| {
+        list_names.get(id).cloned().unwrap_or_else(|| id.clone())
+    });
+    let by_priority = groups(by_priority, |priority| {
+        PRIORITY_LABELS
+            .get(*priority as usize)
+            .map(|label| label.to_string())
+            .unwrap_or_else(|| priority.to_string())
+    });
+
+    EstimationReport {
+        generated_at: Utc::now(),
+        start_date,
+        end_date,
+        overall: overall.finish(),
+        by_tag: most_underestimated_first(by_tag),
+        by_task_list: most_underestimated_first(by_task_list),
+        by_priority: most_underestimated_first(by_priority),
+        by_month: groups(by_month, |month| month.clone()),
+    }
+}
+
+fn groups<K: ToString>(
+    accuracy: BTreeMap<K, EstimationAccuracy>,
+    label: impl Fn(&K) -> String,
+) -> Vec<EstimationGroup> {
+    accuracy
+        .into_iter()
+        .map(|(key, accuracy)| EstimationGroup {
+            label: label(&key),
+            key: key.to_string(),
+            accuracy: accuracy.finish(),
+        })
+        .collect()
+}
+
+fn most_underestimated_first(mut groups: Vec<EstimationGroup>) -> Vec<EstimationGroup> {
+    groups.sort_by(|a, b| b.accuracy.ratio.total_cmp(&a.accuracy.ratio));
+    groups
+}
@@ -0,0 +1,251 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::database::entities::{focus_sessions, goals, tasks};
+use crate::database::repositories::preferences_repository::WorkingHours;
+use crate::database::repositories::{GoalRepository, TimeTrackingRepository};
+
+/// Days back a daily goal's streaks are counted
+const STREAK_HISTORY_DAYS: i64 = 365;
+
+/// Weeks back a weekly goal's streaks are counted
+const STREAK_HISTORY_WEEKS: i64 = 52;
+
+/// Working minutes left today at which an unmet daily goal puts its streak at risk
+const DAILY_AT_RISK_MINUTES: i64 = 120;
+
+/// Hours left in the week at which an unmet weekly goal puts its streak at risk
+const WEEKLY_AT_RISK_HOURS: i64 = 24;
+
+/// Where a goal stands in its current period; amounts are in the goal's unit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalProgress {
+    pub goal: goals::Model,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub current: i64,
+    pub target: i64,
+    /// Still needed to reach the target; 0 once it is met
+    pub remaining: i64,
+    pub is_met: bool,
+    /// Periods in a row the target was reached, the current one included once it is met
+    pub current_streak: u32,
+    pub best_streak: u32,
+    /// The streak ends unless the target is reached before the period is over
+    pub at_risk: bool,
+}
+
+/// Measures goals against tracked time, focus sessions and completed tasks
+///
+/// Daily goals run from midnight and weekly goals from Monday. A day off that misses a daily
+/// goal leaves its streak alone, while one that meets it adds to the streak.
+pub struct GoalTracker {
+    db: Arc<DatabaseConnection>,
+    goal_repo: GoalRepository,
+}
+
+impl GoalTracker {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            goal_repo: GoalRepository::new(db.clone()),
+            db,
+        }
+    }
+
+    /// Progress of one goal, or of every goal when `goal_id` is unset, as of `now`
+    ///
+    /// Days and weeks run in `timezone`.
+    pub async fn compute_progress<Tz: TimeZone>(
+        &self,
+        goal_id: Option<&str>,
+        working_hours: &WorkingHours,
+        now: DateTime<Utc>,
+        timezone: &Tz,
+    ) -> Result<Vec<GoalProgress>, DbErr> {
+        let goals = match goal_id {
+            Some(id) => vec![self
+                .goal_repo
+                .find_by_id(id)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Goal not found".to_string()))?],
+            None => self.goal_repo.find_all().await?,
+        };
+        if goals.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let today = now.with_timezone(timezone).date_naive();
+        let since = local_midnight(
+            (today - Duration::days(STREAK_HISTORY_DAYS))
+                .min(week_start(today) - Duration::weeks(STREAK_HISTORY_WEEKS)),
+            timezone,
+        );
+        let activity = self.load_activity(since, now).await?;
+
+        Ok(goals
+            .into_iter()
+            .map(|goal| goal_progress(goal, &activity, working_hours, now, timezone))
+            .collect())
+    }
+
+    async fn load_activity(
+        &self,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Activity, DbErr> {
+        let worked = TimeTrackingRepository::new(self.db.clone())
+            .find_sessions_between(since, now)
+            .await?
+            .into_iter()
+            .filter_map(|session| {
+                let end = session.end_time.unwrap_or(now);
+                if end <= session.start_time {
+                    return None;
+                }
+                let total = (end - session.start_time).num_seconds() as f64;
+                let breaks = TimeTrackingRepository::parse_breaks(session.breaks.as_deref());
+                let idle = session.paused_time as f64
+                    + TimeTrackingRepository::break_seconds(&breaks, session.start_time, end)
+                        as f64;
+                let worked_share = ((total - idle) / total).clamp(0.0, 1.0);
+                Some((session.start_time, end, worked_share))
+            })
+            .collect();
+        let focus = focus_sessions::Entity::find()
+            .filter(focus_sessions::Column::CompletedAt.gte(since))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .filter_map(|session| Some((session.completed_at?, session.actual_duration?)))
+            .collect();
+        let completed = tasks::Entity::find()
+            .filter(tasks::Column::CompletedAt.gte(since))
+            .all(&*self.db)
+            .await?
+            .into_iter()
+            .filter_map(|task| task.completed_at)
+            .collect();
+
+        Ok(Activity {
+            worked,
+            focus,
+            completed,
+        })
+    }
+}
+
+struct Activity {
+    /// Start, end and the share of it not paused or on a break, of each time session
+    worked: Vec<(DateTime<Utc>, DateTime<Utc>, f64)>,
+    /// Completion and minutes of each completed focus session
+    focus: Vec<(DateTime<Utc>, i32)>,
+    /// Completion of each completed task
+    completed: Vec<DateTime<Utc>>,
+}
+
+impl Activity {
+    /// How much of `metric` falls between `start` and `end`
+    fn amount(&self, metric: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        let within = |at: &DateTime<Utc>| *at >= start && *at < end;
+        match metric {
+            "tracked_minutes" => {
+                let seconds: f64 = self
+                    .worked
+                    .iter()
+                    .map(|(session_start, session_end, worked_share)| {
+                        let overlap = (*session_end.min(&end) - *session_start.max(&start))
+                            .num_seconds()
+                            .max(0);
+                        overlap as f64 * worked_share
+                    })
+                    .sum();
+                (seconds / 60.0).round() as i64
+            }
+            "focus_minutes" => self
+                .focus
+                .iter()
+                .filter(|(completed_at, _)| within(completed_at))
+                .map(|(_, minutes)| *minutes as i64)
+                .sum(),
+            "completed_tasks" => self.completed.iter().filter(|&at| within(at)).count() as i64,
+            _ => 0,
+        }
+    }
+}
+
+fn goal_progress<Tz: TimeZone>(
+    goal: goals::Model,
+    activity: &Activity,
+    working_hours: &WorkingHours,
+    now: DateTime<Utc>,
+    timezone: &Tz,
+) -> GoalProgress {
+    let today = now.with_timezone(timezone).date_naive();
+    let weekly = goal.period == "week";
+    let (current_start, step, history) = if weekly {
+        (week_start(today), Duration::weeks(1), STREAK_HISTORY_WEEKS)
+    } else {
+        (today, Duration::days(1), STREAK_HISTORY_DAYS)
+    };
+    let target = goal.target as i64;
+    let amount = |start: NaiveDate| {
+        activity.amount(
+            &goal.metric,
+            local_midnight(start, timezone),
+            local_midnight(start + step, timezone),
+        )
+    };
+
+    // Walk the past periods oldest first
+    let mut streak = 0;
+    let mut best_streak = 0;
+    for offset in (1..=history as i32).rev() {
+        let start = current_start - step * offset;
+        if amount(start) >= target {
+            streak += 1;
+            best_streak = best_streak.max(streak);
+        } else if weekly || working_hours.is_workday(start) {
+            streak = 0;
+        }
+    }
+
+    let current = amount(current_start);
+    let is_met = current >= target;
+    let period_end = local_midnight(current_start + step, timezone);
+    let closing = if weekly {
+        period_end - now <= Duration::hours(WEEKLY_AT_RISK_HOURS)
+    } else {
+        let context = working_hours.workday_context(now, timezone);
+        context.is_workday && context.minutes_left_today <= DAILY_AT_RISK_MINUTES
+    };
+    let current_streak = streak + u32::from(is_met);
+
+    GoalProgress {
+        period_start: local_midnight(current_start, timezone),
+        period_end,
+        current,
+        target,
+        remaining: (target - current).max(0),
+        is_met,
+        current_streak,
+        best_streak: best_streak.max(current_streak),
+        at_risk: streak > 0 && !is_met && closing,
+        goal,
+    }
+}
+
+/// Monday of the week `date` falls in
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+fn local_midnight<Tz: TimeZone>(date: NaiveDate, timezone: &Tz) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    timezone
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+}
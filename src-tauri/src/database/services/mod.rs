@@ -1,6 +1,21 @@
+pub mod backlog_groomer;
+pub mod daily_planner;
+pub mod date_parser;
+pub mod dependency_scheduler;
+pub mod estimation_analyzer;
+pub mod goal_tracker;
 pub mod task_generation_engine;
+pub mod thread_similarity;
+pub mod workload_balancer;
 
+pub use backlog_groomer::BacklogGroomer;
+pub use daily_planner::DailyPlanner;
+pub use dependency_scheduler::DependencyScheduler;
+pub use estimation_analyzer::EstimationAnalyzer;
+pub use goal_tracker::GoalTracker;
 pub use task_generation_engine::TaskGenerationEngine;
+pub use thread_similarity::ThreadSimilarity;
+pub use workload_balancer::WorkloadBalancer;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use crate::database::entities::{periodic_task_templates, tasks};
 use crate::database::repositories::{
     periodic_task_repository::PeriodicTaskRepository,
+    preferences_repository::WorkingHours,
     task_repository::{CreateTaskRequest, TaskRepository},
 };
 
@@ -11,6 +12,7 @@ use crate::database::repositories::{
 pub struct TaskGenerationEngine {
     periodic_repo: PeriodicTaskRepository,
     task_repo: TaskRepository,
+    working_hours: Option<WorkingHours>,
 }
 
 impl TaskGenerationEngine {
@@ -21,9 +23,19 @@ impl TaskGenerationEngine {
         Self {
             periodic_repo,
             task_repo,
+            working_hours: None,
         }
     }
 
+    /// Schedule instances falling on days off or holidays for the next working day, in local
+    /// time
+    ///
+    /// The generation date stays the occurrence date, so the series itself doesn't shift.
+    pub fn with_working_hours(mut self, working_hours: WorkingHours) -> Self {
+        self.working_hours = Some(working_hours);
+        self
+    }
+
     /// Check for templates that need instance generation and generate them
     pub async fn check_and_generate_instances(&self) -> Result<Vec<tasks::Model>, DbErr> {
         let current_time = chrono::Utc::now();
@@ -58,6 +70,28 @@ impl TaskGenerationEngine {
         template: &periodic_task_templates::Model,
     ) -> Result<tasks::Model, DbErr> {
         let current_time = chrono::Utc::now();
+
+        if template
+            .paused_until
+            .is_some_and(|until| until > current_time)
+        {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Template is paused".to_string(),
+            ));
+        }
+
+        let generated = self
+            .periodic_repo
+            .count_template_instances(&template.id)
+            .await?;
+        let next_generation = template.next_generation_date;
+        if !PeriodicTaskRepository::allows_occurrence(template, next_generation, generated) {
+            self.periodic_repo.deactivate_template(&template.id).await?;
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: Template has reached its end date or occurrence limit"
+                    .to_string(),
+            ));
+        }
         
         // Create the task request from template properties
         let task_request = self.copy_template_properties(template, current_time);
@@ -71,16 +105,25 @@ impl TaskGenerationEngine {
             &template.recurrence_type,
             template.recurrence_interval,
             template.recurrence_unit.as_deref(),
+            template.recurrence_rule.as_deref(),
         )?;
         
         self.periodic_repo
             .update_next_generation_date(&template.id, next_date)
             .await?;
 
+        if !PeriodicTaskRepository::allows_occurrence(template, next_date, generated + 1) {
+            self.periodic_repo.deactivate_template(&template.id).await?;
+        }
+
         Ok(task)
     }
 
     /// Generate all overdue instances for a template
+    ///
+    /// Stops at the template's end date or occurrence limit and deactivates the template once
+    /// its series is over. When a pause has just ended, occurrences that fell in it are
+    /// generated or dropped according to the template's missed occurrence policy.
     async fn generate_overdue_instances(
         &self,
         template: &periodic_task_templates::Model,
@@ -89,8 +132,26 @@ impl TaskGenerationEngine {
         let mut instances = Vec::new();
         let mut next_generation = template.next_generation_date;
 
+        if let Some(paused_until) = template.paused_until {
+            if template.missed_occurrence_policy.as_deref() == Some("skip") {
+                while next_generation < paused_until {
+                    next_generation = self
+                        .periodic_repo
+                        .next_occurrence_after(template, next_generation)?;
+                }
+            }
+            self.periodic_repo.clear_pause(&template.id).await?;
+        }
+        let generated = self
+            .periodic_repo
+            .count_template_instances(&template.id)
+            .await?;
+        let allows_next = |date, count: usize| {
+            PeriodicTaskRepository::allows_occurrence(template, date, generated + count as u64)
+        };
+
         // Generate instances for all overdue dates
-        while next_generation <= current_time {
+        while next_generation <= current_time && allows_next(next_generation, instances.len()) {
             let task_request = self.copy_template_properties(template, next_generation);
             let task = self.task_repo.create_task(task_request).await?;
             instances.push(task);
@@ -101,6 +162,7 @@ impl TaskGenerationEngine {
                 &template.recurrence_type,
                 template.recurrence_interval,
                 template.recurrence_unit.as_deref(),
+                template.recurrence_rule.as_deref(),
             )?;
         }
 
@@ -109,6 +171,14 @@ impl TaskGenerationEngine {
             .update_next_generation_date(&template.id, next_generation)
             .await?;
 
+        if !allows_next(next_generation, instances.len()) {
+            println!(
+                "Template '{}' reached the end of its series",
+                template.title
+            );
+            self.periodic_repo.deactivate_template(&template.id).await?;
+        }
+
         Ok(instances)
     }
 
@@ -123,6 +193,10 @@ impl TaskGenerationEngine {
             .tags
             .as_ref()
             .and_then(|tags_str| serde_json::from_str::<Vec<String>>(tags_str).ok());
+        let scheduled_date = match &self.working_hours {
+            Some(working_hours) => working_hours.next_workday_at(generation_date, &chrono::Local),
+            None => generation_date,
+        };
 
         CreateTaskRequest {
             title: template.title.clone(),
@@ -133,7 +207,7 @@ impl TaskGenerationEngine {
             dependencies: None,
             time_estimate: Some(template.time_estimate),
             due_date: None,
-            scheduled_date: Some(generation_date),
+            scheduled_date: Some(scheduled_date),
             tags,
             project_id: None,
             parent_task_id: None,
@@ -141,6 +215,8 @@ impl TaskGenerationEngine {
             periodic_template_id: Some(template.id.clone()),
             is_periodic_instance: Some(true),
             generation_date: Some(generation_date),
+            effort: None,
+            impact: None,
         }
     }
 
@@ -221,6 +297,7 @@ impl TaskGenerationEngine {
                 &template.recurrence_type,
                 template.recurrence_interval,
                 template.recurrence_unit.as_deref(),
+                template.recurrence_rule.as_deref(),
             )?;
         }
 
@@ -1,9 +1,10 @@
 #[cfg(test)]
 mod task_generation_engine_tests {
-    use crate::database::repositories::tests::setup_test_db;
+    use crate::database::repositories::tests::{setup_migrated_test_db, setup_test_db};
     use crate::database::repositories::periodic_task_repository::{
         CreatePeriodicTaskTemplateRequest, PeriodicTaskRepository,
     };
+    use crate::database::repositories::preferences_repository::WorkingHours;
     use crate::database::services::TaskGenerationEngine;
     use chrono::{Duration, Utc};
 
@@ -28,7 +29,11 @@ mod task_generation_engine_tests {
             recurrence_type: "daily".to_string(),
             recurrence_interval: 1,
             recurrence_unit: None,
+            recurrence_rule: None,
             start_date: past_date,
+            ends_at: None,
+            max_occurrences: None,
+            missed_occurrence_policy: None,
         };
 
         let template = periodic_repo
@@ -73,7 +78,11 @@ mod task_generation_engine_tests {
             recurrence_type: "weekly".to_string(),
             recurrence_interval: 1,
             recurrence_unit: None,
+            recurrence_rule: None,
             start_date: Utc::now(),
+            ends_at: None,
+            max_occurrences: None,
+            missed_occurrence_policy: None,
         };
 
         let template = periodic_repo
@@ -118,7 +127,11 @@ mod task_generation_engine_tests {
             recurrence_type: "daily".to_string(),
             recurrence_interval: 1,
             recurrence_unit: None,
+            recurrence_rule: None,
             start_date: past_date,
+            ends_at: None,
+            max_occurrences: None,
+            missed_occurrence_policy: None,
         };
 
         let _daily_template = periodic_repo
@@ -138,7 +151,11 @@ mod task_generation_engine_tests {
             recurrence_type: "daily".to_string(),
             recurrence_interval: 1,
             recurrence_unit: None,
+            recurrence_rule: None,
             start_date: future_date,
+            ends_at: None,
+            max_occurrences: None,
+            missed_occurrence_policy: None,
         };
 
         let _future_template = periodic_repo
@@ -183,7 +200,11 @@ mod task_generation_engine_tests {
             recurrence_type: "daily".to_string(),
             recurrence_interval: 1,
             recurrence_unit: None,
+            recurrence_rule: None,
             start_date: past_date,
+            ends_at: None,
+            max_occurrences: None,
+            missed_occurrence_policy: None,
         };
 
         let template = periodic_repo
@@ -203,7 +224,13 @@ mod task_generation_engine_tests {
             recurrence_type: None,
             recurrence_interval: None,
             recurrence_unit: None,
+            recurrence_rule: None,
             is_active: Some(false),
+            ends_at: None,
+            clear_ends_at: None,
+            max_occurrences: None,
+            clear_max_occurrences: None,
+            missed_occurrence_policy: None,
         };
 
         periodic_repo
@@ -220,4 +247,3633 @@ mod task_generation_engine_tests {
         // Should not generate any instances for inactive template
         assert!(instances.is_empty(), "Should not generate instances for inactive template");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_generation_stops_at_series_limits() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+
+        let periodic_repo = PeriodicTaskRepository::new(db.clone());
+        let engine = TaskGenerationEngine::new(db);
+
+        // Ten days overdue, but capped at three occurrences
+        let start_date = Utc::now() - Duration::days(10);
+        let limited = periodic_repo
+            .create_template(CreatePeriodicTaskTemplateRequest {
+                title: "Limited Task".to_string(),
+                description: None,
+                priority: 1,
+                time_estimate: 30,
+                tags: None,
+                task_list_id: None,
+                recurrence_type: "daily".to_string(),
+                recurrence_interval: 1,
+                recurrence_unit: None,
+                recurrence_rule: None,
+                start_date,
+                ends_at: None,
+                max_occurrences: Some(3),
+                missed_occurrence_policy: None,
+            })
+            .await
+            .expect("Failed to create template");
+
+        // Ten days overdue, but ending after the fifth day
+        let ending = periodic_repo
+            .create_template(CreatePeriodicTaskTemplateRequest {
+                title: "Ending Task".to_string(),
+                description: None,
+                priority: 1,
+                time_estimate: 30,
+                tags: None,
+                task_list_id: None,
+                recurrence_type: "daily".to_string(),
+                recurrence_interval: 1,
+                recurrence_unit: None,
+                recurrence_rule: None,
+                start_date,
+                ends_at: Some(start_date + Duration::days(4)),
+                max_occurrences: None,
+                missed_occurrence_policy: None,
+            })
+            .await
+            .expect("Failed to create template");
+
+        let instances = engine
+            .generate_pending_instances()
+            .await
+            .expect("Failed to generate instances");
+
+        let count = |title: &str| instances.iter().filter(|t| t.title == title).count();
+        assert_eq!(count("Limited Task"), 3);
+        assert_eq!(count("Ending Task"), 5);
+
+        for id in [&limited.id, &ending.id] {
+            let template = periodic_repo.find_by_id(id).await.unwrap().unwrap();
+            assert!(!template.is_active, "Finished series should be deactivated");
+        }
+
+        let stats = periodic_repo.get_periodic_task_stats().await.unwrap();
+        assert_eq!(stats.limited_templates.len(), 2);
+        assert!(stats
+            .limited_templates
+            .iter()
+            .all(|template| template.remaining_occurrences == 0));
+
+        // A zero limit is rejected
+        let invalid = periodic_repo
+            .create_template(CreatePeriodicTaskTemplateRequest {
+                title: "Invalid".to_string(),
+                description: None,
+                priority: 1,
+                time_estimate: 30,
+                tags: None,
+                task_list_id: None,
+                recurrence_type: "daily".to_string(),
+                recurrence_interval: 1,
+                recurrence_unit: None,
+                recurrence_rule: None,
+                start_date,
+                ends_at: None,
+                max_occurrences: Some(0),
+                missed_occurrence_policy: None,
+            })
+            .await;
+        assert!(invalid.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_missed_occurrence_policy_after_pause() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+
+        let periodic_repo = PeriodicTaskRepository::new(db.clone());
+        let engine = TaskGenerationEngine::new(db);
+
+        let start_date = Utc::now() - Duration::days(4) - Duration::hours(1);
+        let mut template_ids = Vec::new();
+        for policy in ["catch_up", "skip"] {
+            let template = periodic_repo
+                .create_template(CreatePeriodicTaskTemplateRequest {
+                    title: format!("Paused {}", policy),
+                    description: None,
+                    priority: 1,
+                    time_estimate: 30,
+                    tags: None,
+                    task_list_id: None,
+                    recurrence_type: "daily".to_string(),
+                    recurrence_interval: 1,
+                    recurrence_unit: None,
+                    recurrence_rule: None,
+                    start_date,
+                    ends_at: None,
+                    max_occurrences: None,
+                    missed_occurrence_policy: Some(policy.to_string()),
+                })
+                .await
+                .expect("Failed to create template");
+
+            periodic_repo
+                .pause_template(&template.id, Utc::now() + Duration::days(1))
+                .await
+                .expect("Failed to pause template");
+            template_ids.push(template.id);
+        }
+
+        // Nothing is generated while paused
+        let instances = engine
+            .generate_pending_instances()
+            .await
+            .expect("Failed to generate instances");
+        assert!(instances.is_empty());
+
+        for id in &template_ids {
+            periodic_repo
+                .resume_template(id)
+                .await
+                .expect("Failed to resume template");
+        }
+
+        let instances = engine
+            .generate_pending_instances()
+            .await
+            .expect("Failed to generate instances");
+        let count = |title: &str| instances.iter().filter(|t| t.title == title).count();
+        assert_eq!(count("Paused catch_up"), 5);
+        assert_eq!(count("Paused skip"), 0);
+
+        let skipped = periodic_repo
+            .find_by_id(&template_ids[1])
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(skipped.paused_until.is_none());
+        assert!(skipped.next_generation_date > Utc::now());
+
+        // Skipping moves the next occurrence one day ahead
+        let after_skip = periodic_repo
+            .skip_next_occurrence(&skipped.id)
+            .await
+            .expect("Failed to skip next occurrence");
+        assert_eq!(
+            after_skip.next_generation_date,
+            skipped.next_generation_date + Duration::days(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_instances_on_days_off_move_to_next_workday() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+
+        let start_date = Utc::now() - Duration::minutes(5);
+        let today = start_date.with_timezone(&chrono::Local).date_naive();
+        let periodic_repo = PeriodicTaskRepository::new(db.clone());
+        let engine = TaskGenerationEngine::new(db).with_working_hours(WorkingHours {
+            holidays: vec![today],
+            ..WorkingHours::around_the_clock()
+        });
+
+        periodic_repo
+            .create_template(CreatePeriodicTaskTemplateRequest {
+                title: "Weekly review".to_string(),
+                description: None,
+                priority: 1,
+                time_estimate: 30,
+                tags: None,
+                task_list_id: None,
+                recurrence_type: "weekly".to_string(),
+                recurrence_interval: 1,
+                recurrence_unit: None,
+                recurrence_rule: None,
+                start_date,
+                ends_at: None,
+                max_occurrences: None,
+                missed_occurrence_policy: None,
+            })
+            .await
+            .expect("Failed to create template");
+
+        let instances = engine
+            .generate_pending_instances()
+            .await
+            .expect("Failed to generate instances");
+        assert_eq!(instances.len(), 1);
+        let scheduled = instances[0].scheduled_date.unwrap();
+        assert_eq!(
+            scheduled.with_timezone(&chrono::Local).date_naive(),
+            today + Duration::days(1)
+        );
+        assert!(instances[0].generation_date.unwrap() < scheduled);
+    }
+}
+
+#[cfg(test)]
+mod dependency_scheduler_tests {
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::TaskRepository;
+    use crate::database::services::dependency_scheduler::ScheduleState;
+    use crate::database::services::DependencyScheduler;
+
+    fn task_request(title: &str, time_estimate: i32, status: &str) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority: 1,
+            status: Some(status.to_string()),
+            dependencies: None,
+            time_estimate: Some(time_estimate),
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schedule_follows_dependency_chain() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let scheduler = DependencyScheduler::new(db);
+
+        let research = repo
+            .create_task(task_request("Research", 30, "completed"))
+            .await
+            .unwrap();
+        let design = repo
+            .create_task(task_request("Design", 60, "pending"))
+            .await
+            .unwrap();
+        let build = repo
+            .create_task(task_request("Build", 120, "pending"))
+            .await
+            .unwrap();
+        let docs = repo
+            .create_task(task_request("Docs", 30, "pending"))
+            .await
+            .unwrap();
+
+        // Design waits on finished research, Build on Design, Docs is independent
+        repo.add_dependency(&design.id, &research.id).await.unwrap();
+        repo.add_dependency(&build.id, &design.id).await.unwrap();
+
+        let schedule = scheduler.compute_schedule(None).await.unwrap();
+        let get = |id: &str| {
+            schedule
+                .tasks
+                .iter()
+                .find(|task| task.task_id == id)
+                .expect("Task missing from schedule")
+        };
+
+        assert_eq!(get(&research.id).state, ScheduleState::Done);
+        assert_eq!(get(&design.id).state, ScheduleState::Ready);
+        assert_eq!(get(&build.id).state, ScheduleState::Blocked);
+        assert_eq!(get(&build.id).blocked_by, vec![design.id.clone()]);
+        assert_eq!(get(&docs.id).state, ScheduleState::Ready);
+
+        assert_eq!(
+            schedule.critical_path,
+            vec![design.id.clone(), build.id.clone()]
+        );
+        assert_eq!(schedule.critical_path_minutes, 180);
+        assert_eq!(get(&build.id).slack_minutes, Some(0));
+        assert_eq!(get(&docs.id).slack_minutes, Some(150));
+        assert!(!get(&docs.id).is_critical);
+        assert_eq!(
+            get(&build.id).earliest_start,
+            get(&design.id).earliest_finish
+        );
+    }
+}
+
+#[cfg(test)]
+mod workload_balancer_tests {
+    use crate::database::repositories::preferences_repository::WorkingHours;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::TaskRepository;
+    use crate::database::services::WorkloadBalancer;
+    use chrono::{Duration, Utc};
+
+    fn task_request(
+        title: &str,
+        priority: i32,
+        time_estimate: i32,
+        days_ahead: i64,
+    ) -> CreateTaskRequest {
+        let today = Utc::now().date_naive();
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority,
+            status: None,
+            dependencies: None,
+            time_estimate: Some(time_estimate),
+            due_date: None,
+            scheduled_date: Some(
+                (today + Duration::days(days_ahead))
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_utc(),
+            ),
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_overloaded_day_is_balanced() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let balancer = WorkloadBalancer::new(db);
+
+        let report_draft = repo
+            .create_task(task_request("Report draft", 3, 90, 0))
+            .await
+            .unwrap();
+        let inbox = repo
+            .create_task(task_request("Inbox zero", 0, 60, 0))
+            .await
+            .unwrap();
+        repo.create_task(task_request("Workshop", 1, 100, 1))
+            .await
+            .unwrap();
+        let mut done = task_request("Finished review", 1, 120, 0);
+        done.status = Some("completed".to_string());
+        repo.create_task(done).await.unwrap();
+
+        let today = Utc::now().date_naive();
+        let report = balancer
+            .compute_report(today, 3, 120, &WorkingHours::around_the_clock(), None, &Utc)
+            .await
+            .unwrap();
+
+        let first = &report.days[0];
+        assert!(first.overloaded);
+        assert_eq!(first.scheduled_minutes, 150);
+        assert_eq!(first.balanced_minutes, 90);
+        assert_eq!(first.task_ids.len(), 2);
+        assert!(first.task_ids.contains(&report_draft.id));
+
+        // The low-priority task skips the busy next day
+        assert_eq!(report.suggested_moves.len(), 1);
+        let suggestion = &report.suggested_moves[0];
+        assert_eq!(suggestion.task_id, inbox.id);
+        assert_eq!(suggestion.to_date, today + Duration::days(2));
+        assert!(report.unresolved_days.is_empty());
+
+        assert!(balancer
+            .compute_report(today, 3, 0, &WorkingHours::around_the_clock(), None, &Utc)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_days_off_have_no_capacity() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let balancer = WorkloadBalancer::new(db);
+
+        let planned = repo
+            .create_task(task_request("Planned on a holiday", 1, 60, 0))
+            .await
+            .unwrap();
+
+        let today = Utc::now().date_naive();
+        let working_hours = WorkingHours {
+            holidays: vec![today],
+            ..WorkingHours::around_the_clock()
+        };
+        let report = balancer
+            .compute_report(today, 2, 120, &working_hours, None, &Utc)
+            .await
+            .unwrap();
+
+        assert_eq!(report.days[0].capacity_minutes, 0);
+        assert!(report.days[0].overloaded);
+        assert_eq!(report.days[1].capacity_minutes, 120);
+        assert_eq!(report.suggested_moves.len(), 1);
+        assert_eq!(report.suggested_moves[0].task_id, planned.id);
+        assert_eq!(report.suggested_moves[0].to_date, today + Duration::days(1));
+    }
+}
+
+#[cfg(test)]
+mod daily_planner_tests {
+    use crate::database::repositories::preferences_repository::WorkingHours;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::TaskRepository;
+    use crate::database::services::daily_planner::{DeferReason, PlanReason};
+    use crate::database::services::DailyPlanner;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    fn task_request(title: &str, priority: i32, time_estimate: i32) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority,
+            status: None,
+            dependencies: None,
+            time_estimate: Some(time_estimate),
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        // 2030-01-07 is a Monday
+        Utc.with_ymd_and_hms(2030, 1, 7, hour, minute, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_plan_fills_the_rest_of_the_day() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let planner = DailyPlanner::new(db);
+
+        let mut overdue = task_request("Overdue report", 0, 60);
+        overdue.due_date = Some(at(12, 0) - Duration::days(1));
+        let overdue = repo.create_task(overdue).await.unwrap();
+        let mut too_long = task_request("Quarterly review", 3, 240);
+        too_long.due_date = Some(at(17, 0));
+        let too_long = repo.create_task(too_long).await.unwrap();
+        let fix = repo
+            .create_task(task_request("Fix build", 2, 30))
+            .await
+            .unwrap();
+        let mut review = task_request("Review release", 1, 30);
+        review.scheduled_date = Some(at(10, 0));
+        let review = repo.create_task(review).await.unwrap();
+        let mut follow_up = task_request("Share review notes", 1, 15);
+        follow_up.scheduled_date = Some(at(10, 0));
+        let follow_up = repo.create_task(follow_up).await.unwrap();
+        let mut tomorrow = task_request("Planning meeting", 3, 30);
+        tomorrow.scheduled_date = Some(at(10, 0) + Duration::days(1));
+        repo.create_task(tomorrow).await.unwrap();
+
+        // The review needs the build fixed, the notes need the quarterly review
+        repo.add_dependency(&review.id, &fix.id).await.unwrap();
+        repo.add_dependency(&follow_up.id, &too_long.id)
+            .await
+            .unwrap();
+
+        let plan = planner
+            .generate_plan(&WorkingHours::default(), None, at(15, 0), &Utc)
+            .await
+            .unwrap();
+
+        assert!(plan.is_workday);
+        assert_eq!(plan.available_minutes, 120);
+        assert_eq!(plan.planned_minutes, 120);
+        let planned: Vec<&str> = plan.blocks.iter().map(|b| b.task_id.as_str()).collect();
+        assert_eq!(planned, vec![&overdue.id, &fix.id, &review.id]);
+        assert_eq!(plan.blocks[0].reason, PlanReason::Overdue);
+        assert_eq!(plan.blocks[0].start, at(15, 0));
+        assert_eq!(plan.blocks[2].reason, PlanReason::ScheduledToday);
+        assert_eq!(plan.blocks[2].end, at(17, 0));
+
+        let deferred: Vec<(&str, DeferReason)> = plan
+            .deferred
+            .iter()
+            .map(|task| (task.task_id.as_str(), task.reason))
+            .collect();
+        assert_eq!(
+            deferred,
+            vec![
+                (too_long.id.as_str(), DeferReason::NoTimeLeft),
+                (follow_up.id.as_str(), DeferReason::Blocked),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_starts_with_working_hours() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let planner = DailyPlanner::new(db);
+
+        let mut due = task_request("Send invoice", 1, 0);
+        due.due_date = Some(at(17, 0));
+        let due = repo.create_task(due).await.unwrap();
+
+        // Before work starts, the plan begins at 9:00; tasks without estimates get half an hour
+        let plan = planner
+            .generate_plan(&WorkingHours::default(), None, at(7, 30), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(plan.available_minutes, 480);
+        assert_eq!(plan.blocks.len(), 1);
+        assert_eq!(plan.blocks[0].task_id, due.id);
+        assert_eq!(plan.blocks[0].start, at(9, 0));
+        assert_eq!(plan.blocks[0].duration_minutes, 30);
+
+        // Nothing is planned on a day off
+        let plan = planner
+            .generate_plan(
+                &WorkingHours::default(),
+                None,
+                at(10, 0) - Duration::days(2),
+                &Utc,
+            )
+            .await
+            .unwrap();
+        assert!(!plan.is_workday);
+        assert!(plan.blocks.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod estimation_analyzer_tests {
+    use crate::database::repositories::task_repository::{CreateTaskRequest, UpdateTaskRequest};
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{TaskListRepository, TaskRepository};
+    use crate::database::services::EstimationAnalyzer;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    async fn completed_task(
+        repo: &TaskRepository,
+        priority: i32,
+        tag: &str,
+        task_list_id: Option<&str>,
+        minutes: (i32, i32),
+        completed_at: DateTime<Utc>,
+    ) {
+        let task = repo
+            .create_task(CreateTaskRequest {
+                title: format!("{} task", tag),
+                description: None,
+                priority,
+                status: None,
+                order_num: None,
+                dependencies: None,
+                time_estimate: Some(minutes.0),
+                due_date: None,
+                scheduled_date: None,
+                tags: Some(vec![tag.to_string()]),
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: task_list_id.map(str::to_string),
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+        repo.update_task(
+            &task.id,
+            UpdateTaskRequest {
+                title: None,
+                description: None,
+                priority: None,
+                status: Some("completed".to_string()),
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                actual_time: Some(minutes.1),
+                due_date: None,
+                scheduled_date: None,
+                clear_scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                completed_at: Some(completed_at),
+                effort: None,
+                impact: None,
+                expected_updated_at: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_estimation_report() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let list = TaskListRepository::new(db.clone())
+            .create_task_list("Client".to_string())
+            .await
+            .unwrap();
+        let analyzer = EstimationAnalyzer::new(db);
+
+        let january = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap();
+        let february = Utc.with_ymd_and_hms(2026, 2, 10, 12, 0, 0).unwrap();
+        let list_id = Some(list.id.as_str());
+        completed_task(&repo, 2, "writing", list_id, (60, 90), january).await;
+        completed_task(&repo, 2, "writing", list_id, (30, 50), january).await;
+        completed_task(&repo, 1, "writing", None, (40, 60), february).await;
+        completed_task(&repo, 0, "admin", None, (60, 45), february).await;
+        // Without tracked time, or outside the period
+        completed_task(&repo, 0, "admin", None, (60, 0), february).await;
+        let march = Utc.with_ymd_and_hms(2026, 3, 2, 12, 0, 0).unwrap();
+        completed_task(&repo, 0, "admin", None, (10, 90), march).await;
+
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        let report = analyzer.compute_report(start, end, &Utc).await.unwrap();
+
+        assert_eq!(report.overall.task_count, 4);
+        assert_eq!(report.overall.estimated_minutes, 190);
+        assert_eq!(report.overall.actual_minutes, 245);
+        assert_eq!(report.overall.underestimated_count, 3);
+        assert_eq!(report.overall.overestimated_count, 1);
+
+        let writing = &report.by_tag[0];
+        assert_eq!(writing.key, "writing");
+        assert!(writing.accuracy.chronic_underestimation);
+        let admin = &report.by_tag[1];
+        assert_eq!(admin.accuracy.ratio, 0.75);
+        assert!(!admin.accuracy.chronic_underestimation);
+
+        assert_eq!(report.by_task_list.len(), 1);
+        assert_eq!(report.by_task_list[0].label, "Client");
+        assert_eq!(report.by_task_list[0].accuracy.actual_minutes, 140);
+
+        let priorities: Vec<&str> = report
+            .by_priority
+            .iter()
+            .map(|group| group.label.as_str())
+            .collect();
+        assert_eq!(priorities, ["High", "Medium", "Low"]);
+
+        let months: Vec<(&str, usize)> = report
+            .by_month
+            .iter()
+            .map(|group| (group.key.as_str(), group.accuracy.task_count))
+            .collect();
+        assert_eq!(months, [("2026-01", 2), ("2026-02", 2)]);
+
+        assert!(analyzer.compute_report(end, start, &Utc).await.is_err());
+    }
+}
+
+#[cfg(test)]
+mod goal_tracker_tests {
+    use crate::database::entities::{tasks, time_sessions};
+    use crate::database::repositories::goal_repository::CreateGoalRequest;
+    use crate::database::repositories::preferences_repository::WorkingHours;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{GoalRepository, TaskRepository};
+    use crate::database::services::GoalTracker;
+    use chrono::{DateTime, TimeZone, Utc};
+    use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+    use std::sync::Arc;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        // March 2nd 2026 is a Monday
+        Utc.with_ymd_and_hms(2026, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn goal(metric: &str, target: i32, period: &str) -> CreateGoalRequest {
+        CreateGoalRequest {
+            title: "Goal".to_string(),
+            metric: metric.to_string(),
+            target,
+            period: period.to_string(),
+        }
+    }
+
+    async fn create_task(
+        db: &Arc<DatabaseConnection>,
+        completed_at: Option<DateTime<Utc>>,
+    ) -> String {
+        let task = TaskRepository::new(db.clone())
+            .create_task(CreateTaskRequest {
+                title: "Work".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                order_num: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+        let mut task: tasks::ActiveModel = task.into();
+        task.completed_at = Set(completed_at);
+        task.update(&**db).await.unwrap().id
+    }
+
+    async fn track(
+        db: &Arc<DatabaseConnection>,
+        task_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        paused_time: i32,
+    ) {
+        time_sessions::ActiveModel {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            task_id: Set(task_id.to_string()),
+            start_time: Set(start),
+            end_time: Set(Some(end)),
+            paused_time: Set(paused_time),
+            is_active: Set(false),
+            notes: Set(None),
+            breaks: Set(None),
+            created_at: Set(start),
+        }
+        .insert(&**db)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_daily_streak_skips_days_off() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let goal = GoalRepository::new(db.clone())
+            .create_goal(goal("tracked_minutes", 60, "day"))
+            .await
+            .unwrap();
+        let tracker = GoalTracker::new(db.clone());
+        let hours = WorkingHours::default();
+
+        // Met on Tuesday, missed on Wednesday, met on Thursday and Friday, the weekend off
+        let task_id = create_task(&db, None).await;
+        for day in [3, 5, 6] {
+            track(&db, &task_id, at(day, 9, 0), at(day, 10, 30), 0).await;
+        }
+        track(&db, &task_id, at(9, 9, 0), at(9, 9, 30), 0).await;
+
+        // An hour of work left on Monday with half the target done
+        let progress = tracker
+            .compute_progress(Some(&goal.id), &hours, at(9, 16, 0), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(progress.len(), 1);
+        assert_eq!(progress[0].period_start, at(9, 0, 0));
+        assert_eq!(progress[0].current, 30);
+        assert_eq!(progress[0].remaining, 30);
+        assert_eq!(progress[0].current_streak, 2);
+        assert_eq!(progress[0].best_streak, 2);
+        assert!(progress[0].at_risk);
+
+        // Earlier in the day there is still time
+        let progress = tracker
+            .compute_progress(None, &hours, at(9, 10, 0), &Utc)
+            .await
+            .unwrap();
+        assert!(!progress[0].at_risk);
+
+        // Paused time doesn't count
+        track(&db, &task_id, at(9, 10, 0), at(9, 11, 0), 1800).await;
+        let progress = tracker
+            .compute_progress(None, &hours, at(9, 16, 0), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(progress[0].current, 60);
+        assert!(progress[0].is_met);
+        assert_eq!(progress[0].current_streak, 3);
+        assert_eq!(progress[0].best_streak, 3);
+        assert!(!progress[0].at_risk);
+
+        assert!(tracker
+            .compute_progress(Some("missing"), &hours, at(9, 16, 0), &Utc)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_weekly_goal_counts_completed_tasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        GoalRepository::new(db.clone())
+            .create_goal(goal("completed_tasks", 2, "week"))
+            .await
+            .unwrap();
+        let tracker = GoalTracker::new(db.clone());
+        let hours = WorkingHours::default();
+
+        create_task(&db, Some(at(3, 12, 0))).await;
+        create_task(&db, Some(at(8, 12, 0))).await;
+        create_task(&db, Some(at(10, 12, 0))).await;
+        create_task(&db, None).await;
+
+        // Sunday evening, one task short in the second week
+        let progress = tracker
+            .compute_progress(None, &hours, at(15, 20, 0), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(progress[0].period_start, at(9, 0, 0));
+        assert_eq!(progress[0].period_end, at(16, 0, 0));
+        assert_eq!(progress[0].current, 1);
+        assert_eq!(progress[0].current_streak, 1);
+        assert!(progress[0].at_risk);
+
+        // Midweek the streak is not at risk yet
+        let progress = tracker
+            .compute_progress(None, &hours, at(11, 20, 0), &Utc)
+            .await
+            .unwrap();
+        assert!(!progress[0].at_risk);
+    }
+}
+
+#[cfg(test)]
+mod task_import_tests {
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{TaskListRepository, TaskRepository, TaskSourceRepository};
+    use crate::task_import::{
+        TaskImportDuplicate, TaskImportFormat, TaskImportMapping, TaskImportService,
+    };
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_csv_import_skips_duplicates() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let service = TaskImportService::new(db.clone());
+
+        let existing = repo
+            .create_task(CreateTaskRequest {
+                title: "Write report".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.csv");
+        std::fs::write(
+            &path,
+            "Name,Notes,Priority,Estimate,Labels,Project\n\
+             write report,,high,,,\n\
+             \"Plan trip, summer\",\"Flights\nHotel\",urgent,1h 30m,travel;home,Personal\n\
+             \"Plan  trip, Summer\",,low,,,Personal\n\
+             ,missing title,,,,\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let preview = service.preview(path, TaskImportFormat::Csv).await.unwrap();
+        assert_eq!(preview.column_mapping["title"], "Name");
+        assert_eq!(preview.rows.len(), 3);
+        assert_eq!(preview.errors.len(), 1);
+        assert_eq!(preview.errors[0].line, 6);
+        assert_eq!(preview.duplicate_count, 2);
+        assert_eq!(
+            preview.rows[0].duplicate,
+            Some(TaskImportDuplicate::Existing {
+                task_id: existing.id.clone()
+            })
+        );
+        let trip = &preview.rows[1];
+        assert_eq!(trip.description.as_deref(), Some("Flights\nHotel"));
+        assert_eq!(trip.priority, Some(3));
+        assert_eq!(trip.time_estimate, Some(90));
+        assert_eq!(trip.tags, vec!["travel", "home"]);
+        assert_eq!(
+            preview.rows[2].duplicate,
+            Some(TaskImportDuplicate::File { line: 3 })
+        );
+
+        let mapping = TaskImportMapping {
+            format: TaskImportFormat::Csv,
+            columns: HashMap::new(),
+            task_list_id: None,
+            task_lists: HashMap::new(),
+            tags: vec!["imported".to_string()],
+            include_duplicates: false,
+        };
+        let result = service.import(path, &mapping).await.unwrap();
+        assert_eq!(result.imported_task_ids.len(), 1);
+        assert_eq!(result.skipped_duplicates, 2);
+
+        let task = repo
+            .find_by_id(&result.imported_task_ids[0])
+            .await
+            .unwrap()
+            .unwrap();
+        let source = TaskSourceRepository::new(db.clone())
+            .find_by_task(&task.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(source.source_type, "csv");
+        assert_eq!(source.external_id.as_deref(), Some("line 3"));
+        assert_eq!(source.url.as_deref(), Some(path));
+        let list = TaskListRepository::new(db)
+            .find_by_name("Personal")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.title, "Plan trip, summer");
+        assert_eq!(task.task_list_id, Some(list.id));
+        assert!(task.tags.unwrap().contains("imported"));
+    }
+
+    #[tokio::test]
+    async fn test_markdown_import_nests_subtasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let service = TaskImportService::new(db.clone());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.md");
+        std::fs::write(
+            &path,
+            "# Garden\n\
+             - [ ] Build raised bed #outdoor\n  \
+               - [x] Buy lumber\n  \
+               - [ ] Assemble frame\n\
+             - [ ] Plant tomatoes\n\
+             Notes that are not tasks\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let preview = service
+            .preview(path, TaskImportFormat::Markdown)
+            .await
+            .unwrap();
+        let titles: Vec<&str> = preview.rows.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Build raised bed",
+                "Buy lumber",
+                "Assemble frame",
+                "Plant tomatoes"
+            ]
+        );
+        assert_eq!(preview.rows[0].tags, vec!["outdoor"]);
+        assert_eq!(preview.rows[1].parent, Some(0));
+        assert_eq!(preview.rows[1].status.as_deref(), Some("completed"));
+        assert_eq!(preview.rows[3].parent, None);
+        assert!(preview
+            .rows
+            .iter()
+            .all(|row| row.task_list.as_deref() == Some("Garden")));
+
+        let mut mapping = TaskImportMapping {
+            format: TaskImportFormat::Markdown,
+            columns: HashMap::new(),
+            task_list_id: None,
+            task_lists: HashMap::new(),
+            tags: Vec::new(),
+            include_duplicates: false,
+        };
+        let result = service.import(path, &mapping).await.unwrap();
+        assert_eq!(result.imported_task_ids.len(), 4);
+
+        let repo = TaskRepository::new(db);
+        let lumber = repo
+            .find_by_id(&result.imported_task_ids[1])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            lumber.parent_task_id.as_deref(),
+            Some(result.imported_task_ids[0].as_str())
+        );
+        assert_eq!(lumber.status, "completed");
+
+        // A second import finds everything already there
+        let again = service.import(path, &mapping).await.unwrap();
+        assert!(again.imported_task_ids.is_empty());
+        assert_eq!(again.skipped_duplicates, 4);
+
+        mapping.include_duplicates = true;
+        let forced = service.import(path, &mapping).await.unwrap();
+        assert_eq!(forced.imported_task_ids.len(), 4);
+    }
+}
+
+#[cfg(test)]
+mod time_import_tests {
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{TaskRepository, TimeTrackingRepository};
+    use crate::time_import::{TimeImportService, TimeImportSource};
+
+    #[tokio::test]
+    async fn test_toggl_import_matches_tasks_by_title() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let service = TimeImportService::new(db.clone());
+
+        let existing = repo
+            .create_task(CreateTaskRequest {
+                title: "Write report".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("toggl.csv");
+        std::fs::write(
+            &path,
+            "User,Email,Client,Project,Task,Description,Billable,Start date,Start time,End date,End time,Duration,Tags\n\
+             Ann,ann@example.com,,,,write  report,No,2024-03-04,09:00:00,2024-03-04,10:30:00,01:30:00,\n\
+             Ann,ann@example.com,,Garden,Build bed,Cut boards,No,2024-03-05,14:00:00,,,00:45:00,\"outdoor, diy\"\n\
+             Ann,ann@example.com,,Garden,Build bed,,No,2024-03-06,14:00:00,2024-03-06,15:00:00,01:00:00,\n\
+             Ann,ann@example.com,,,,Broken,No,yesterday,09:00:00,,,,\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let preview = service
+            .preview(path, TimeImportSource::Toggl)
+            .await
+            .unwrap();
+        assert_eq!(preview.entries.len(), 3);
+        assert_eq!(preview.errors.len(), 1);
+        assert_eq!(preview.errors[0].line, 5);
+        assert_eq!(preview.matched_count, 1);
+        assert_eq!(preview.entries[0].task_id, Some(existing.id.clone()));
+        assert_eq!(preview.entries[0].duration_minutes, 90);
+        assert_eq!(preview.entries[1].duration_minutes, 45);
+        assert_eq!(preview.entries[1].tags, vec!["outdoor", "diy"]);
+        assert_eq!(preview.new_task_titles, vec!["Build bed"]);
+
+        let result = service
+            .import(path, TimeImportSource::Toggl, None)
+            .await
+            .unwrap();
+        assert_eq!(result.created_task_ids.len(), 1);
+        assert_eq!(result.session_ids.len(), 3);
+
+        let sessions = TimeTrackingRepository::new(db)
+            .find_sessions_for_task(&result.created_task_ids[0])
+            .await
+            .unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert!(sessions.iter().all(|session| !session.is_active));
+        assert!(sessions
+            .iter()
+            .any(|session| session.notes.as_deref() == Some("Cut boards")));
+
+        // Importing the same export again logs nothing new
+        let again = service
+            .import(path, TimeImportSource::Toggl, None)
+            .await
+            .unwrap();
+        assert!(again.created_task_ids.is_empty());
+        assert!(again.session_ids.is_empty());
+        assert_eq!(again.skipped_entries, 3);
+    }
+
+    #[tokio::test]
+    async fn test_clockify_import_reads_twelve_hour_times() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let service = TimeImportService::new(db);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clockify.csv");
+        std::fs::write(
+            &path,
+            "Project,Client,Description,Task,User,Tags,Billable,Start Date,Start Time,End Date,End Time,Duration (h)\n\
+             Website,,Fix header,,Ann,,No,03/04/2024,01:15:00 PM,03/04/2024,02:05:00 PM,00:50:00\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let preview = service
+            .preview(path, TimeImportSource::Clockify)
+            .await
+            .unwrap();
+        assert!(preview.errors.is_empty());
+        let entry = &preview.entries[0];
+        assert_eq!(entry.task_title, "Fix header");
+        assert_eq!(entry.project.as_deref(), Some("Website"));
+        assert_eq!(entry.duration_minutes, 50);
+        assert!(entry.task_id.is_none());
+    }
+}
+
+#[cfg(test)]
+mod todoist_import_tests {
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{
+        PeriodicTaskRepository, TaskListRepository, TaskNoteRepository, TaskRepository,
+    };
+    use crate::todoist_import::TodoistImportService;
+
+    #[tokio::test]
+    async fn test_todoist_project_import() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let service = TodoistImportService::new(db.clone());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Home [2203306141].csv");
+        std::fs::write(
+            &path,
+            "TYPE,CONTENT,DESCRIPTION,PRIORITY,INDENT,AUTHOR,RESPONSIBLE,DATE,DATE_LANG,TIMEZONE,DURATION,DURATION_UNIT\n\
+             task,Water plants @home,,4,1,Ann,,every monday,en,Europe/Berlin,,\n\
+             ,,,,,,,,,,,\n\
+             section,Errands,,,,,,,,,,\n\
+             task,Buy groceries @shopping,Milk and eggs,1,1,Ann,,2024-03-04,en,,30,minute\n\
+             task,Pick up bread,,4,2,Ann,,,,,,\n\
+             note,Bakery closes at 6,,,,,,,,,,\n\
+             task,Pay rent,,2,1,Ann,,every month at 9am,en,,,\n\
+             task,Call mom,,3,1,Ann,,every full moon,en,,,\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let preview = service.preview(path).await.unwrap();
+        assert!(preview.errors.is_empty());
+        assert_eq!(preview.new_task_lists, vec!["Home"]);
+        assert_eq!(preview.task_count, 5);
+        assert_eq!(preview.periodic_count, 2);
+        let tasks = &preview.projects[0].tasks;
+        assert_eq!(tasks[0].tags, vec!["home"]);
+        assert_eq!(
+            tasks[0].recurrence.as_ref().unwrap().recurrence_type,
+            "weekly"
+        );
+        assert_eq!(tasks[1].title, "Buy groceries");
+        assert_eq!(tasks[1].priority, 3);
+        assert_eq!(tasks[1].tags, vec!["Errands", "shopping"]);
+        assert_eq!(tasks[1].time_estimate, Some(30));
+        assert!(tasks[1].due_date.is_some());
+        assert_eq!(tasks[2].parent, Some(1));
+        assert_eq!(tasks[2].notes, vec!["Bakery closes at 6"]);
+        assert_eq!(
+            tasks[3].recurrence.as_ref().unwrap().recurrence_type,
+            "monthly"
+        );
+        assert!(tasks[4].recurrence.is_none());
+        assert_eq!(tasks[4].warnings.len(), 1);
+
+        let result = service.import(path).await.unwrap();
+        assert_eq!(result.created_task_list_ids.len(), 1);
+        assert_eq!(result.imported_task_ids.len(), 3);
+        assert_eq!(result.periodic_template_ids.len(), 2);
+
+        let list = TaskListRepository::new(db.clone())
+            .find_by_name("Home")
+            .await
+            .unwrap()
+            .unwrap();
+        let bread = TaskRepository::new(db.clone())
+            .find_by_id(&result.imported_task_ids[1])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(bread.task_list_id, Some(list.id.clone()));
+        assert_eq!(
+            bread.parent_task_id.as_deref(),
+            Some(result.imported_task_ids[0].as_str())
+        );
+        let notes = TaskNoteRepository::new(db.clone())
+            .find_by_task(&bread.id)
+            .await
+            .unwrap();
+        assert_eq!(notes.len(), 1);
+        let rent = PeriodicTaskRepository::new(db)
+            .find_by_id(&result.periodic_template_ids[1])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(rent.title, "Pay rent");
+        assert_eq!(rent.task_list_id, Some(list.id));
+
+        // Everything is already there the second time
+        let again = service.preview(path).await.unwrap();
+        assert!(again.new_task_lists.is_empty());
+        assert_eq!(again.duplicate_count, 5);
+    }
+}
+
+#[cfg(test)]
+mod conversation_export_tests {
+    use crate::conversation_export::{
+        redact_sensitive, ChatExample, ConversationExportRequest, ConversationExportService,
+    };
+    use crate::database::repositories::ai_repository::CreateAiInteractionRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::thread_repository::{
+        CreateThreadMessageRequest, CreateThreadRequest,
+    };
+    use crate::database::repositories::{AiRepository, ThreadRepository};
+
+    fn message(thread_id: &str, r#type: &str, content: &str) -> CreateThreadMessageRequest {
+        CreateThreadMessageRequest {
+            thread_id: thread_id.to_string(),
+            r#type: r#type.to_string(),
+            content: content.to_string(),
+            reasoning: None,
+            actions: None,
+            suggestions: None,
+            tool_executions: None,
+            user_feedback: None,
+            action_results: None,
+            timestamp: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_redacted_chat_examples() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let thread_repo = ThreadRepository::new(db.clone());
+        let service = ConversationExportService::new(db.clone());
+
+        let new_thread = || CreateThreadRequest {
+            assignment_type: Some("general".to_string()),
+            assignment_task_id: None,
+            assignment_date: None,
+            assignment_context: None,
+        };
+        let thread = thread_repo.create_thread(new_thread()).await.unwrap();
+        thread_repo
+            .create_message(message(
+                &thread.id,
+                "user",
+                "Email ann@example.com the plan",
+            ))
+            .await
+            .unwrap();
+        thread_repo
+            .create_message(message(&thread.id, "assistant", "Drafted the email."))
+            .await
+            .unwrap();
+        let unanswered = thread_repo.create_thread(new_thread()).await.unwrap();
+        thread_repo
+            .create_message(message(&unanswered.id, "user", "Hello?"))
+            .await
+            .unwrap();
+
+        let interaction = AiRepository::new(db)
+            .create_interaction(CreateAiInteractionRequest {
+                message: "Use key sk-abcdefghijklmnopqrstuvwxyz".to_string(),
+                response: "Stored the key.".to_string(),
+                action_taken: None,
+                reasoning: None,
+                tools_used: None,
+                confidence: None,
+            })
+            .await
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dataset.jsonl");
+        let path = path.to_str().unwrap();
+        let request = ConversationExportRequest {
+            thread_ids: vec![thread.id.clone(), unanswered.id.clone()],
+            interaction_ids: vec![interaction.id.clone(), "missing".to_string()],
+            system_prompt: Some("You are KiraPilot.".to_string()),
+            redact: true,
+        };
+
+        let summary = service.export(&request, path).await.unwrap();
+        assert_eq!(summary.example_count, 2);
+        assert_eq!(summary.message_count, 6);
+        assert_eq!(summary.redaction_count, 2);
+        assert_eq!(summary.skipped.len(), 2);
+        assert_eq!(summary.skipped[0].id, unanswered.id);
+
+        let content = std::fs::read_to_string(path).unwrap();
+        let examples: Vec<ChatExample> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(examples.len(), 2);
+        let roles: Vec<&str> = examples[0]
+            .messages
+            .iter()
+            .map(|m| m.role.as_str())
+            .collect();
+        assert_eq!(roles, vec!["system", "user", "assistant"]);
+        assert_eq!(
+            examples[0].messages[1].content,
+            "Email [EMAIL_REDACTED] the plan"
+        );
+        assert_eq!(
+            examples[1].messages[1].content,
+            "Use key [API_KEY_REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_redact_sensitive_keeps_surrounding_text() {
+        let (redacted, count) = redact_sensitive(
+            "Call 555-123-4567, (see /Users/ann/notes.txt) password=hunter2 on 10.0.0.12.",
+        );
+        assert_eq!(
+            redacted,
+            "Call [PHONE_REDACTED], (see [PATH_REDACTED]/notes.txt) password=[SECRET_REDACTED] on [IP_REDACTED]."
+        );
+        assert_eq!(count, 4);
+
+        let (untouched, count) = redact_sensitive("Meet at 10.30 on 2024-03-04 for 45 minutes");
+        assert_eq!(untouched, "Meet at 10.30 on 2024-03-04 for 45 minutes");
+        assert_eq!(count, 0);
+    }
+}
+
+#[cfg(test)]
+mod calendar_export_tests {
+    use crate::calendar_export::{
+        escape_text, fold_line, format_utc, recurrence_rule, CalendarExportRange,
+        CalendarExportService,
+    };
+    use crate::database::repositories::periodic_task_repository::CreatePeriodicTaskTemplateRequest;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{PeriodicTaskRepository, TaskRepository};
+    use chrono::{DateTime, Duration, Local, TimeZone, Utc};
+
+    fn local(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Local
+            .with_ymd_and_hms(year, month, day, hour, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn task_request(title: &str) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority: 2,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    fn template_request(recurrence_type: &str, interval: i32) -> CreatePeriodicTaskTemplateRequest {
+        CreatePeriodicTaskTemplateRequest {
+            title: format!("Review, {}", recurrence_type),
+            description: None,
+            priority: 1,
+            time_estimate: 45,
+            tags: None,
+            task_list_id: None,
+            recurrence_type: recurrence_type.to_string(),
+            recurrence_interval: interval,
+            recurrence_unit: None,
+            recurrence_rule: (recurrence_type == "cron").then(|| "0 9 * * 1-5".to_string()),
+            start_date: local(2024, 6, 3, 9),
+            ends_at: None,
+            max_occurrences: Some(10),
+            missed_occurrence_policy: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_scheduled_tasks_due_dates_and_recurrences() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task_repo = TaskRepository::new(db.clone());
+        let periodic_repo = PeriodicTaskRepository::new(db.clone());
+
+        let mut meeting = task_request("Plan sprint; agree on goals");
+        meeting.scheduled_date = Some(local(2024, 6, 10, 14));
+        meeting.time_estimate = Some(90);
+        meeting.tags = Some(vec!["work".to_string()]);
+        let meeting = task_repo.create_task(meeting).await.unwrap();
+
+        let mut report = task_request("File taxes");
+        report.scheduled_date = Some(local(2024, 6, 11, 0));
+        report.due_date = Some(local(2024, 6, 14, 17));
+        task_repo.create_task(report).await.unwrap();
+
+        let mut outside = task_request("Next month");
+        outside.scheduled_date = Some(local(2024, 7, 20, 10));
+        task_repo.create_task(outside).await.unwrap();
+
+        let weekly = periodic_repo
+            .create_template(template_request("weekly", 2))
+            .await
+            .unwrap();
+        periodic_repo
+            .create_template(template_request("cron", 1))
+            .await
+            .unwrap();
+        let mut instance = task_request("Review, weekly");
+        instance.scheduled_date = Some(local(2024, 6, 17, 9));
+        instance.periodic_template_id = Some(weekly.id.clone());
+        instance.is_periodic_instance = Some(true);
+        task_repo.create_task(instance).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.ics");
+        let range = CalendarExportRange {
+            start: local(2024, 6, 1, 0),
+            end: local(2024, 6, 30, 0) + Duration::days(1),
+            include_completed: false,
+            include_periodic: true,
+        };
+        let summary = CalendarExportService::new(db)
+            .export(&range, path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(summary.scheduled_count, 2);
+        assert_eq!(summary.due_count, 1);
+        assert_eq!(summary.recurring_count, 1);
+        assert_eq!(summary.skipped_templates.len(), 1);
+
+        let calendar = std::fs::read_to_string(&path).unwrap();
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 4);
+        assert!(calendar.contains(&format!("UID:{}-scheduled@kirapilot.app", meeting.id)));
+        assert!(calendar.contains("SUMMARY:Plan sprint\\; agree on goals"));
+        assert!(calendar.contains("DURATION:PT90M"));
+        assert!(calendar.contains("CATEGORIES:work"));
+        assert!(calendar.contains("DTSTART;VALUE=DATE:20240611"));
+        assert!(calendar.contains("SUMMARY:Due: File taxes"));
+        assert!(calendar.contains("DTSTART;VALUE=DATE:20240614"));
+        assert!(calendar.contains("RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=10"));
+        assert!(!calendar.contains("Next month"));
+    }
+
+    #[tokio::test]
+    async fn test_until_matches_the_start_value_type() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let periodic_repo = PeriodicTaskRepository::new(db);
+
+        let ends_at = local(2024, 8, 30, 9);
+        let timed = periodic_repo
+            .create_template(CreatePeriodicTaskTemplateRequest {
+                ends_at: Some(ends_at),
+                ..template_request("daily", 1)
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            recurrence_rule(&timed).unwrap(),
+            format!("FREQ=DAILY;UNTIL={}", format_utc(ends_at))
+        );
+
+        let all_day = periodic_repo
+            .create_template(CreatePeriodicTaskTemplateRequest {
+                start_date: local(2024, 6, 3, 0),
+                ends_at: Some(local(2024, 8, 30, 0)),
+                ..template_request("weekly", 1)
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            recurrence_rule(&all_day).unwrap(),
+            "FREQ=WEEKLY;UNTIL=20240830"
+        );
+    }
+
+    #[test]
+    fn test_text_is_escaped_and_folded() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold_line(&line);
+        assert!(folded.split("\r\n").all(|part| part.len() <= 75));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
+
+#[cfg(test)]
+mod caldav_sync_tests {
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+    use crate::database::repositories::{TaskRepository, TaskStatusRepository};
+    use crate::sync::caldav::parse_multistatus;
+    use crate::sync::ical::{parse_todo, render_todo, RemoteTodo};
+    use crate::sync::{apply_todo, keep_local, ConflictResolution};
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn test_parse_multistatus_reads_changes_removals_and_token() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">
+  <d:response>
+    <d:href>/calendars/me/tasks/</d:href>
+    <d:propstat><d:prop><d:getetag/></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>https://dav.example.com/calendars/me/tasks/a.ics</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:getetag>"1"</d:getetag>
+        <cal:calendar-data>BEGIN:VCALENDAR&#13;
+SUMMARY:Fish &amp; chips&#13;
+END:VCALENDAR</cal:calendar-data>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/calendars/me/tasks/b.ics</d:href>
+    <d:status>HTTP/1.1 404 Not Found</d:status>
+  </d:response>
+  <d:sync-token>http://example.com/sync/7</d:sync-token>
+</d:multistatus>"#;
+
+        let changes = parse_multistatus(xml);
+        assert_eq!(changes.changed.len(), 1);
+        assert_eq!(changes.changed[0].href, "/calendars/me/tasks/a.ics");
+        assert_eq!(changes.changed[0].etag.as_deref(), Some("\"1\""));
+        assert!(changes.changed[0]
+            .data
+            .as_deref()
+            .unwrap()
+            .contains("SUMMARY:Fish & chips\r\n"));
+        assert_eq!(changes.removed, vec!["/calendars/me/tasks/b.ics"]);
+        assert_eq!(
+            changes.sync_token.as_deref(),
+            Some("http://example.com/sync/7")
+        );
+    }
+
+    #[test]
+    fn test_todo_round_trip() {
+        let todo = RemoteTodo {
+            uid: "task-1".to_string(),
+            summary: "Call Ann; ask about Q3, budget".to_string(),
+            description: Some("Line one\nLine two".to_string()),
+            start: Some(Utc.with_ymd_and_hms(2024, 6, 3, 9, 30, 0).unwrap()),
+            due: Some(Utc.with_ymd_and_hms(2024, 6, 5, 17, 0, 0).unwrap()),
+            completed: true,
+            priority: Some(3),
+            last_modified: Some(Utc.with_ymd_and_hms(2024, 6, 1, 8, 0, 0).unwrap()),
+        };
+
+        let rendered = render_todo(&todo);
+        assert!(rendered.contains("PRIORITY:1\r\n"));
+        assert!(rendered.contains("STATUS:COMPLETED\r\n"));
+        assert_eq!(parse_todo(&rendered), Some(todo));
+    }
+
+    #[test]
+    fn test_parse_todo_ignores_events_and_alarms() {
+        assert_eq!(
+            parse_todo("BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:e\nEND:VEVENT\nEND:VCALENDAR\n"),
+            None
+        );
+
+        let todo = parse_todo(
+            "BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\nUID:t\r\nSUMMARY:Water the \r\n plants\r\n\
+             PRIORITY:4\r\nBEGIN:VALARM\r\nDESCRIPTION:Reminder\r\nEND:VALARM\r\n\
+             DUE;TZID=Europe/Paris:20240603T090000\r\nEND:VTODO\r\nEND:VCALENDAR\r\n",
+        )
+        .unwrap();
+        assert_eq!(todo.summary, "Water the plants");
+        assert_eq!(todo.description, None);
+        assert_eq!(todo.priority, Some(2));
+        assert!(todo.due.is_some());
+        assert!(!todo.completed);
+    }
+
+    #[tokio::test]
+    async fn test_conflict_rules() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task = TaskRepository::new(db)
+            .create_task(CreateTaskRequest {
+                title: "Edited twice".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+        let older = Some(task.updated_at - Duration::minutes(5));
+        let newer = Some(task.updated_at + Duration::minutes(5));
+
+        assert!(keep_local(ConflictResolution::LocalWins, &task, newer));
+        assert!(!keep_local(ConflictResolution::RemoteWins, &task, older));
+        assert!(keep_local(ConflictResolution::NewestWins, &task, older));
+        assert!(!keep_local(ConflictResolution::NewestWins, &task, newer));
+        assert!(keep_local(ConflictResolution::NewestWins, &task, None));
+    }
+
+    #[tokio::test]
+    async fn test_remote_items_marked_undone_reopen_their_task() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let status_repo = TaskStatusRepository::new(db);
+        let task = task_repo
+            .create_task(CreateTaskRequest {
+                status: Some("completed".to_string()),
+                ..task_request("Water the plants")
+            })
+            .await
+            .unwrap();
+        let mut todo = RemoteTodo::from_task(&task, "task-1");
+        todo.completed = false;
+
+        let reopened = apply_todo(&task_repo, &status_repo, &task, &todo)
+            .await
+            .unwrap();
+        assert_eq!(reopened.status, "pending");
+
+        todo.completed = true;
+        let completed = apply_todo(&task_repo, &status_repo, &reopened, &todo)
+            .await
+            .unwrap();
+        assert_eq!(completed.status, "completed");
+    }
+}
+
+#[cfg(test)]
+mod integrity_check_tests {
+    use crate::database::entities::integrity_reports;
+    use crate::database::migration::initialization::DatabaseIntegrityReport;
+    use crate::integrity::{check_due, issue_summary};
+    use chrono::{Duration, Utc};
+
+    fn record(checked_at: chrono::DateTime<Utc>) -> integrity_reports::Model {
+        integrity_reports::Model {
+            id: "report-1".to_string(),
+            is_healthy: true,
+            has_default_task_list: true,
+            default_task_list_id: Some("list-1".to_string()),
+            orphaned_tasks_count: 0,
+            total_tasks: 4,
+            total_task_lists: 1,
+            notified: false,
+            checked_at,
+        }
+    }
+
+    #[test]
+    fn test_check_is_due_weekly() {
+        let now = Utc::now();
+        assert!(check_due(None, now));
+        assert!(!check_due(Some(&record(now - Duration::days(6))), now));
+        assert!(check_due(Some(&record(now - Duration::days(7))), now));
+    }
+
+    #[test]
+    fn test_issue_summary() {
+        let mut report = DatabaseIntegrityReport {
+            has_default_task_list: true,
+            default_task_list_id: "list-1".to_string(),
+            orphaned_tasks_count: 1,
+            total_tasks: 4,
+            total_task_lists: 1,
+            is_healthy: false,
+        };
+        assert_eq!(issue_summary(&report), "1 task has no task list");
+
+        report.has_default_task_list = false;
+        report.orphaned_tasks_count = 3;
+        assert_eq!(
+            issue_summary(&report),
+            "The default task list is missing and 3 tasks have no task list"
+        );
+    }
+}
+
+#[cfg(test)]
+mod backup_rehearsal_tests {
+    use crate::backup::BackupService;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::TaskRepository;
+
+    #[tokio::test]
+    async fn test_rehearsal_restores_into_scratch_database() {
+        let files = tempfile::tempdir().unwrap();
+        let backup_path = files.path().join("backup.zip");
+        let backup_path = backup_path.to_str().unwrap();
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        for title in ["Draft agenda", "Book room"] {
+            task_repo
+                .create_task(CreateTaskRequest {
+                    title: title.to_string(),
+                    description: None,
+                    priority: 1,
+                    status: None,
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: None,
+                    due_date: None,
+                    scheduled_date: None,
+                    tags: None,
+                    project_id: None,
+                    parent_task_id: None,
+                    task_list_id: None,
+                    periodic_template_id: None,
+                    is_periodic_instance: None,
+                    generation_date: None,
+                    effort: None,
+                    impact: None,
+                })
+                .await
+                .unwrap();
+        }
+        BackupService::new(db.clone())
+            .export_data(backup_path)
+            .await
+            .unwrap();
+
+        let report = BackupService::new(db.clone())
+            .rehearse_restore(backup_path)
+            .await
+            .unwrap();
+        assert!(report.restorable, "{:?}", report);
+        let tasks = report
+            .tables
+            .iter()
+            .find(|table| table.table == "tasks")
+            .unwrap();
+        assert_eq!((tasks.expected, tasks.restored), (2, 2));
+        assert!(report.database_issues.is_empty());
+        assert_eq!(report.integrity.unwrap().total_tasks, 2);
+
+        // Live data is untouched
+        assert_eq!(task_repo.count_all_tasks().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rehearsal_rejects_unreadable_backup() {
+        let files = tempfile::tempdir().unwrap();
+        let backup_path = files.path().join("backup.zip");
+        std::fs::write(&backup_path, "not a zip").unwrap();
+
+        let db = setup_migrated_test_db().await.unwrap();
+        assert!(BackupService::new(db)
+            .rehearse_restore(backup_path.to_str().unwrap())
+            .await
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod periodic_template_export_tests {
+    use crate::database::repositories::periodic_task_repository::CreatePeriodicTaskTemplateRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::PeriodicTaskRepository;
+    use crate::template_pack::TemplatePackService;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_periodic_templates_move_between_workspaces() {
+        let files = tempfile::tempdir().unwrap();
+        let path = files.path().join("routines.json");
+        let path = path.to_str().unwrap();
+
+        let source = setup_migrated_test_db().await.unwrap();
+        PeriodicTaskRepository::new(source.clone())
+            .create_template(CreatePeriodicTaskTemplateRequest {
+                title: "Weekly review".to_string(),
+                description: Some("Plan the week".to_string()),
+                priority: 2,
+                time_estimate: 30,
+                tags: Some(vec!["routine".to_string()]),
+                task_list_id: None,
+                recurrence_type: "cron".to_string(),
+                recurrence_interval: 1,
+                recurrence_unit: None,
+                recurrence_rule: Some("0 9 * * MON".to_string()),
+                start_date: Utc::now(),
+                ends_at: None,
+                max_occurrences: None,
+                missed_occurrence_policy: Some("skip".to_string()),
+            })
+            .await
+            .unwrap();
+        let summary = TemplatePackService::new(source)
+            .export_periodic_templates(path)
+            .await
+            .unwrap();
+        assert_eq!(summary.periodic_template_count, 1);
+        assert_eq!(summary.task_template_count, 0);
+
+        let target = setup_migrated_test_db().await.unwrap();
+        let service = TemplatePackService::new(target.clone());
+        let result = service.import_periodic_templates(path).await.unwrap();
+        assert_eq!(result.imported_periodic_templates, 1);
+
+        let templates = PeriodicTaskRepository::new(target)
+            .find_all()
+            .await
+            .unwrap();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].recurrence_rule.as_deref(), Some("0 9 * * MON"));
+        assert_eq!(
+            templates[0].missed_occurrence_policy.as_deref(),
+            Some("skip")
+        );
+
+        let again = service.import_periodic_templates(path).await.unwrap();
+        assert_eq!(again.imported_periodic_templates, 0);
+        assert_eq!(again.skipped, vec!["Periodic template 'Weekly review'"]);
+    }
+}
+
+#[cfg(test)]
+mod github_tests {
+    use crate::database::repositories::github_repository::{
+        GitHubRepository, LinkGitHubIssueRequest, SaveGitHubSettingsRequest,
+    };
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+    use crate::database::repositories::TaskRepository;
+    use crate::github::{parse_issue, refresh_action, GitHubService, RefreshAction};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Answer every request with `body`, standing in for the GitHub API
+    async fn serve_json(body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let api_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = [0u8; 4096];
+                let _ = stream.read(&mut request).await;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        api_url
+    }
+
+    #[test]
+    fn test_parse_issue_reads_repository_and_labels() {
+        let json = r#"{
+            "number": 42,
+            "title": "Crash on startup",
+            "body": "",
+            "state": "open",
+            "html_url": "https://github.com/acme/app/issues/42",
+            "repository_url": "https://api.github.com/repos/acme/app",
+            "labels": [{ "name": "bug" }, { "name": "p1" }]
+        }"#;
+
+        let issue = parse_issue(json).unwrap();
+        assert_eq!(issue.repository, "acme/app");
+        assert_eq!(issue.number, 42);
+        assert_eq!(issue.body, None);
+        assert_eq!(issue.labels, vec!["bug", "p1"]);
+
+        let pull_request = json.replace(
+            r#""labels""#,
+            r#""pull_request": { "url": "https://api.github.com/repos/acme/app/pulls/42" }, "labels""#,
+        );
+        assert!(parse_issue(&pull_request).is_err());
+    }
+
+    #[test]
+    fn test_refresh_action_follows_the_side_that_changed() {
+        // In step
+        assert_eq!(refresh_action(false, "open", "open"), RefreshAction::None);
+        assert_eq!(refresh_action(true, "open", "closed"), RefreshAction::None);
+        // Changed on GitHub
+        assert_eq!(
+            refresh_action(false, "open", "closed"),
+            RefreshAction::CompleteTask
+        );
+        assert_eq!(
+            refresh_action(true, "closed", "open"),
+            RefreshAction::ReopenTask
+        );
+        // Changed here
+        assert_eq!(
+            refresh_action(true, "open", "open"),
+            RefreshAction::CloseIssue
+        );
+        assert_eq!(
+            refresh_action(false, "closed", "closed"),
+            RefreshAction::ReopenIssue
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reopens_the_task_of_a_reopened_issue() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let api_url = serve_json(
+            r#"{
+                "number": 42,
+                "title": "Crash on startup",
+                "body": null,
+                "state": "open",
+                "html_url": "https://github.com/acme/app/issues/42",
+                "repository_url": "https://api.github.com/repos/acme/app"
+            }"#,
+        )
+        .await;
+        let repo = GitHubRepository::new(db.clone());
+        repo.save_settings(SaveGitHubSettingsRequest {
+            token: Some("ghp_secret".to_string()),
+            api_url: Some(api_url),
+            task_list_id: None,
+            auto_refresh: Some(false),
+        })
+        .await
+        .unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let task = task_repo
+            .create_task(CreateTaskRequest {
+                status: Some("completed".to_string()),
+                ..task_request("Crash on startup")
+            })
+            .await
+            .unwrap();
+        repo.link_task(LinkGitHubIssueRequest {
+            task_id: task.id.clone(),
+            repository: "acme/app".to_string(),
+            issue_number: 42,
+            issue_url: None,
+            issue_state: Some("closed".to_string()),
+        })
+        .await
+        .unwrap();
+
+        let report = GitHubService::new(db).refresh().await.unwrap();
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        assert_eq!(report.reopened_tasks, 1);
+
+        // Reopened into the first status of the todo category
+        let task = task_repo.find_by_id(&task.id).await.unwrap().unwrap();
+        assert_eq!(task.status, "pending");
+        let link = repo.find_link(&task.id).await.unwrap().unwrap();
+        assert_eq!(link.issue_state, "open");
+    }
+}
+
+#[cfg(test)]
+mod webhook_tests {
+    use crate::webhooks::{payload, retry_at, sign, MAX_ATTEMPTS};
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn test_sign_matches_hmac_sha256() {
+        // RFC 4231, test case 2
+        assert_eq!(
+            sign("Jefe", "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_retries_back_off_and_stop() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(retry_at(1, now), Some(now + Duration::seconds(30)));
+        assert_eq!(retry_at(2, now), Some(now + Duration::seconds(60)));
+        assert_eq!(retry_at(3, now), Some(now + Duration::seconds(120)));
+        assert_eq!(retry_at(MAX_ATTEMPTS, now), None);
+    }
+
+    #[test]
+    fn test_payload_wraps_event_data() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let body = payload("timer.stopped", serde_json::json!({ "minutes": 25 }), now);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(json["event"], "timer.stopped");
+        assert_eq!(json["occurred_at"], "2025-03-01T12:00:00+00:00");
+        assert_eq!(json["data"]["minutes"], 25);
+        assert!(json["id"].as_str().is_some_and(|id| !id.is_empty()));
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use crate::cli::{dispatch, listed_tasks, parse_args, resolve_task, Command};
+    use crate::database::repositories::api_token_repository::CreateApiTokenRequest;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::task_status_repository::CreateTaskStatusRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{ApiTokenRepository, TaskRepository, TaskStatusRepository};
+
+    fn args(line: &[&str]) -> Vec<String> {
+        line.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn task_request(title: &str) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority: 1,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args(&["add", "Write", "report", "--due", "friday 5pm"])),
+            Ok(Command::Add {
+                title: "Write report".to_string(),
+                due: Some("friday 5pm".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["list", "--today"])),
+            Ok(Command::List {
+                today: true,
+                all: false
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["list", "--all", "--today"])),
+            Ok(Command::List {
+                today: true,
+                all: true
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["start", "abc123"])),
+            Ok(Command::Start {
+                task: "abc123".to_string()
+            })
+        );
+        assert_eq!(parse_args(&args(&["stop"])), Ok(Command::Stop));
+        assert_eq!(parse_args(&[]), Ok(Command::Help));
+
+        assert!(parse_args(&args(&["add"])).is_err());
+        assert!(parse_args(&args(&["add", "Title", "--due"])).is_err());
+        assert!(parse_args(&args(&["list", "--week"])).is_err());
+        assert!(parse_args(&args(&["stop", "now"])).is_err());
+        assert!(parse_args(&args(&["remove"])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_task_by_id_prefix_or_title() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db);
+        let report = repo
+            .create_task(task_request("Write report"))
+            .await
+            .unwrap();
+        repo.create_task(task_request("Review report"))
+            .await
+            .unwrap();
+
+        let by_prefix = resolve_task(&repo, &report.id[..8]).await.unwrap();
+        assert_eq!(by_prefix.id, report.id);
+        let by_title = resolve_task(&repo, "write REPORT").await.unwrap();
+        assert_eq!(by_title.id, report.id);
+        let by_words = resolve_task(&repo, "write").await.unwrap();
+        assert_eq!(by_words.id, report.id);
+
+        assert!(resolve_task(&repo, "report").await.is_err());
+        assert!(resolve_task(&repo, "missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_leaves_out_done_and_cancelled_tasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        TaskStatusRepository::new(db.clone())
+            .create_status(CreateTaskStatusRequest {
+                name: "shipped".to_string(),
+                category: "done".to_string(),
+                color: None,
+                order_num: None,
+            })
+            .await
+            .unwrap();
+        let repo = TaskRepository::new(db.clone());
+        for (title, status) in [
+            ("Write report", "pending"),
+            ("Review report", "in_progress"),
+            ("Send report", "completed"),
+            ("Print report", "cancelled"),
+            ("Publish report", "shipped"),
+        ] {
+            repo.create_task(CreateTaskRequest {
+                status: Some(status.to_string()),
+                ..task_request(title)
+            })
+            .await
+            .unwrap();
+        }
+        let tasks = repo.find_all(None, None).await.unwrap();
+
+        let open = listed_tasks(&db, tasks.clone(), false).await.unwrap();
+        let mut titles: Vec<&str> = open.iter().map(|task| task.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, ["Review report", "Write report"]);
+        let all = listed_tasks(&db, tasks, true).await.unwrap();
+        assert_eq!(all.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_read_scoped_token_cannot_add_tasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let issued = ApiTokenRepository::new(db.clone())
+            .create_token(CreateApiTokenRequest {
+                name: "kira".to_string(),
+                scopes: vec!["read".to_string()],
+            })
+            .await
+            .unwrap();
+
+        let list = Command::List {
+            today: false,
+            all: false,
+        };
+        dispatch(db.clone(), &issued.secret, list).await.unwrap();
+        let add = Command::Add {
+            title: "Write report".to_string(),
+            due: None,
+        };
+        let refused = dispatch(db.clone(), &issued.secret, add.clone()).await;
+        assert!(format!("{:#}", refused.unwrap_err()).contains("FORBIDDEN_ERROR"));
+        assert!(dispatch(db.clone(), "kp_unknown", add).await.is_err());
+
+        let tasks = TaskRepository::new(db).find_all(None, None).await.unwrap();
+        assert!(tasks.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod backlog_groomer_tests {
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::{PeriodicTaskRepository, TaskRepository};
+    use crate::database::services::backlog_groomer::{
+        rank_backlog, GroomingReason, GroomingRules, REVIEW_TASK_TITLE,
+    };
+    use crate::database::services::BacklogGroomer;
+    use chrono::{Duration, Utc};
+    use std::collections::HashMap;
+
+    fn backlog_task(title: &str, priority: i32) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            due_date: None,
+            scheduled_date: None,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_groom_backlog_surfaces_high_priority_and_blocking_tasks() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+        let low = repo
+            .create_task(backlog_task("Tidy notes", 0))
+            .await
+            .unwrap();
+        let urgent = repo
+            .create_task(backlog_task("Fix login", 3))
+            .await
+            .unwrap();
+        let blocker = repo
+            .create_task(backlog_task("Pick a vendor", 1))
+            .await
+            .unwrap();
+        let dependent = repo
+            .create_task(backlog_task("Sign contract", 1))
+            .await
+            .unwrap();
+        repo.add_dependency(&dependent.id, &blocker.id)
+            .await
+            .unwrap();
+
+        let groomer = BacklogGroomer::new(db.clone());
+        let queue = groomer
+            .groom(&GroomingRules::default(), true)
+            .await
+            .unwrap();
+        assert_eq!(queue.backlog_count, 4);
+        let ids: Vec<&str> = queue
+            .items
+            .iter()
+            .map(|item| item.task.id.as_str())
+            .collect();
+        assert_eq!(ids, vec![urgent.id.as_str(), blocker.id.as_str()]);
+        assert_eq!(
+            queue.items[1].reasons,
+            vec![GroomingReason::Blocking { dependent_count: 1 }]
+        );
+
+        let template_id = queue.review_template_id.expect("review task created");
+        let template = PeriodicTaskRepository::new(db.clone())
+            .find_by_id(&template_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(template.title, REVIEW_TASK_TITLE);
+        assert!(template.description.unwrap().contains(&urgent.id));
+
+        // Grooming again refreshes the same review task
+        let again = groomer
+            .groom(&GroomingRules::default(), true)
+            .await
+            .unwrap();
+        assert_eq!(again.review_template_id, Some(template_id));
+
+        let rules = GroomingRules {
+            include_blocking: Some(false),
+            min_priority: Some(4),
+            ..Default::default()
+        };
+        let later = Utc::now() + Duration::days(45);
+        let backlog = repo.find_backlog().await.unwrap();
+        let stale = rank_backlog(&backlog, &HashMap::new(), &rules, later);
+        assert_eq!(stale.len(), 4);
+        assert!(stale
+            .iter()
+            .all(|item| item.reasons == vec![GroomingReason::Stale { age_days: 45 }]));
+        assert!(stale.iter().any(|item| item.task.id == low.id));
+
+        let invalid = GroomingRules {
+            min_age_days: Some(0),
+            ..Default::default()
+        };
+        assert!(groomer.groom(&invalid, false).await.is_err());
+    }
+}
+
+#[cfg(test)]
+mod quick_capture_tests {
+    use crate::quick_capture::parse_quick_capture;
+    use chrono::{FixedOffset, TimeZone};
+
+    #[test]
+    fn test_parse_quick_capture() {
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        let now = tz.with_ymd_and_hms(2025, 3, 12, 10, 0, 0).unwrap();
+
+        let capture = parse_quick_capture("Fix login bug #work !p1 @tomorrow", &now).unwrap();
+        assert_eq!(capture.title, "Fix login bug");
+        assert_eq!(capture.tags, vec!["work"]);
+        assert_eq!(capture.priority, 3);
+        let due = capture.due_date.unwrap().with_timezone(&tz);
+        assert_eq!(due.date_naive().to_string(), "2025-03-13");
+
+        let capture = parse_quick_capture("Plan trip #home #Home @next-friday", &now).unwrap();
+        assert_eq!(capture.tags, vec!["home"]);
+        assert_eq!(capture.priority, 1);
+        let due = capture.due_date.unwrap().with_timezone(&tz);
+        assert_eq!(due.date_naive().to_string(), "2025-03-14");
+
+        let capture = parse_quick_capture("Email 100% done !p4", &now).unwrap();
+        assert_eq!(capture.title, "Email 100% done");
+        assert_eq!((capture.priority, capture.due_date), (0, None));
+
+        assert!(parse_quick_capture("#work !p2", &now).is_err());
+        assert!(parse_quick_capture("Fix bug !p5", &now).is_err());
+        assert!(parse_quick_capture("Fix bug @someday", &now).is_err());
+    }
+}
+
+#[cfg(test)]
+mod ai_budget_tests {
+    use crate::ai_budget::{period_days, reached_threshold};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_reached_threshold() {
+        assert_eq!(reached_threshold(7.99, 10.0), None);
+        assert_eq!(reached_threshold(8.0, 10.0), Some(80));
+        assert_eq!(reached_threshold(9.99, 10.0), Some(80));
+        assert_eq!(reached_threshold(10.0, 10.0), Some(100));
+        assert_eq!(reached_threshold(25.0, 10.0), Some(100));
+    }
+
+    #[test]
+    fn test_period_days() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        // 2024-12-19 is a Thursday
+        let today = date(2024, 12, 19);
+
+        assert_eq!(
+            period_days("day", today).unwrap(),
+            (today, date(2024, 12, 20))
+        );
+        assert_eq!(
+            period_days("week", today).unwrap(),
+            (date(2024, 12, 16), date(2024, 12, 23))
+        );
+        assert_eq!(
+            period_days("month", today).unwrap(),
+            (date(2024, 12, 1), date(2025, 1, 1))
+        );
+        assert!(period_days("year", today).is_err());
+    }
+}
+
+#[cfg(test)]
+mod deep_link_tests {
+    use crate::deep_link::{parse_deep_link, DeepLink};
+    use tauri::Url;
+
+    fn parse(link: &str) -> Result<DeepLink, String> {
+        parse_deep_link(&Url::parse(link).unwrap())
+    }
+
+    #[test]
+    fn test_parse_deep_link() {
+        assert_eq!(
+            parse("kirapilot://task/abc-123").unwrap(),
+            DeepLink::OpenTask {
+                id: "abc-123".to_string()
+            }
+        );
+        assert_eq!(
+            parse("kirapilot://timer/start/abc-123/").unwrap(),
+            DeepLink::StartTimer {
+                task_id: "abc-123".to_string()
+            }
+        );
+        assert_eq!(
+            parse("kirapilot://new?title=Buy%20milk&due=tomorrow&description=+").unwrap(),
+            DeepLink::NewTask {
+                title: "Buy milk".to_string(),
+                description: None,
+                due: Some("tomorrow".to_string()),
+            }
+        );
+
+        assert!(parse("kirapilot://new?description=No+title").is_err());
+        assert!(parse("kirapilot://task").is_err());
+        assert!(parse("kirapilot://timer/stop/abc-123").is_err());
+        assert!(parse("https://task/abc-123").is_err());
+    }
+}
+
+#[cfg(test)]
+mod thread_similarity_tests {
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::thread_repository::{
+        CreateThreadMessageRequest, CreateThreadRequest, UpdateThreadRequest,
+    };
+    use crate::database::repositories::ThreadRepository;
+    use crate::database::services::thread_similarity::{cosine, embed};
+    use crate::database::services::ThreadSimilarity;
+
+    fn general_thread() -> CreateThreadRequest {
+        CreateThreadRequest {
+            assignment_type: Some("general".to_string()),
+            assignment_task_id: None,
+            assignment_date: None,
+            assignment_context: None,
+        }
+    }
+
+    async fn thread_with_message(repo: &ThreadRepository, title: &str, content: &str) -> String {
+        let thread = repo.create_thread(general_thread()).await.unwrap();
+        repo.update_thread(
+            &thread.id,
+            UpdateThreadRequest {
+                title: Some(title.to_string()),
+                assignment_type: None,
+                assignment_task_id: None,
+                assignment_date: None,
+                assignment_context: None,
+            },
+        )
+        .await
+        .unwrap();
+        repo.create_message(CreateThreadMessageRequest {
+            thread_id: thread.id.clone(),
+            r#type: "user".to_string(),
+            content: content.to_string(),
+            reasoning: None,
+            actions: None,
+            suggestions: None,
+            tool_executions: None,
+            user_feedback: None,
+            action_results: None,
+            timestamp: None,
+        })
+        .await
+        .unwrap();
+        thread.id
+    }
+
+    #[test]
+    fn test_embed_matches_related_wording() {
+        let query = embed("How should I schedule my writing sessions?");
+        let related = embed("Scheduling focused writing blocks each morning");
+        let unrelated = embed("Grocery list: milk, eggs, bread");
+
+        assert!((cosine(&query, &query) - 1.0).abs() < 1e-6);
+        assert!(cosine(&query, &related) > cosine(&query, &unrelated));
+        assert!(embed("the and of").iter().all(|&value| value == 0.0));
+    }
+
+    #[tokio::test]
+    async fn test_create_thread_checked_finds_similar_threads() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = ThreadRepository::new(db.clone());
+        let writing = thread_with_message(
+            &repo,
+            "Writing schedule",
+            "Help me schedule writing sessions for my thesis",
+        )
+        .await;
+        thread_with_message(&repo, "Groceries", "Plan meals and a grocery list").await;
+        repo.create_thread(general_thread()).await.unwrap();
+
+        let similarity = ThreadSimilarity::new(db);
+        let created = similarity
+            .create_thread_checked(
+                general_thread(),
+                Some("When should I schedule thesis writing?"),
+            )
+            .await
+            .unwrap();
+        let ids: Vec<&str> = created
+            .similar_threads
+            .iter()
+            .map(|similar| similar.thread.id.as_str())
+            .collect();
+        assert_eq!(ids, vec![writing.as_str()]);
+        assert_eq!(
+            created.similar_threads[0].preview.as_deref(),
+            Some("Help me schedule writing sessions for my thesis")
+        );
+
+        let created = similarity
+            .create_thread_checked(general_thread(), None)
+            .await
+            .unwrap();
+        assert!(created.similar_threads.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod activity_tests {
+    use crate::activity::{IdleTracker, IdleTransition};
+    use chrono::{TimeZone, Utc};
+    use std::time::Duration;
+
+    #[test]
+    fn test_idle_tracker_opens_and_closes_segments() {
+        let threshold = Duration::from_secs(300);
+        let start = Utc.with_ymd_and_hms(2025, 3, 12, 10, 0, 0).unwrap();
+        let at = |minutes: i64| start + chrono::Duration::minutes(minutes);
+        let mut tracker = IdleTracker::default();
+
+        // Idle without a running session, or not yet long enough
+        assert_eq!(
+            tracker.observe(Duration::from_secs(600), threshold, at(0), None),
+            None
+        );
+        assert_eq!(
+            tracker.observe(Duration::from_secs(60), threshold, at(1), Some("s1")),
+            None
+        );
+
+        let Some(IdleTransition::Started(segment)) =
+            tracker.observe(Duration::from_secs(360), threshold, at(10), Some("s1"))
+        else {
+            panic!("expected the idle segment to start");
+        };
+        assert_eq!(segment.session_id, "s1");
+        assert_eq!(segment.started_at, at(4));
+        assert_eq!(segment.ended_at, None);
+
+        // Still away, even after the session stopped
+        assert_eq!(
+            tracker.observe(Duration::from_secs(1200), threshold, at(24), None),
+            None
+        );
+
+        let Some(IdleTransition::Ended(segment)) =
+            tracker.observe(Duration::from_secs(30), threshold, at(30), None)
+        else {
+            panic!("expected the idle segment to end");
+        };
+        assert_eq!(segment.started_at, at(4));
+        assert_eq!(
+            segment.ended_at,
+            Some(start + chrono::Duration::seconds(30 * 60 - 30))
+        );
+
+        assert_eq!(
+            tracker.observe(Duration::from_secs(40), threshold, at(31), Some("s1")),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod power_tests {
+    use crate::power::{parse_pmset, read_power_supplies, PowerState, PowerSupply};
+
+    fn supply(kind: &str, online: Option<&str>, capacity: Option<&str>) -> PowerSupply {
+        PowerSupply {
+            kind: format!("{}\n", kind),
+            online: online.map(|value| format!("{}\n", value)),
+            capacity: capacity.map(|value| format!("{}\n", value)),
+        }
+    }
+
+    #[test]
+    fn test_pmset_reports_the_source_and_charge() {
+        let on_battery = "Now drawing from 'Battery Power'\n \
+             -InternalBattery-0 (id=4653155)\t42%; discharging; 3:10 remaining present: true\n";
+        assert_eq!(
+            parse_pmset(on_battery),
+            Some(PowerState {
+                on_battery: true,
+                battery_percent: Some(42),
+            })
+        );
+
+        let desktop = "Now drawing from 'AC Power'\n";
+        assert_eq!(
+            parse_pmset(desktop),
+            Some(PowerState {
+                on_battery: false,
+                battery_percent: None,
+            })
+        );
+        assert_eq!(parse_pmset(""), None);
+    }
+
+    #[test]
+    fn test_power_supplies_are_on_battery_only_when_unplugged() {
+        let unplugged = [
+            supply("Mains", Some("0"), None),
+            supply("Battery", None, Some("18")),
+        ];
+        assert_eq!(
+            read_power_supplies(&unplugged),
+            PowerState {
+                on_battery: true,
+                battery_percent: Some(18),
+            }
+        );
+
+        let charging = [
+            supply("USB_C", Some("1"), None),
+            supply("Battery", None, Some("18")),
+        ];
+        assert!(!read_power_supplies(&charging).on_battery);
+
+        // Desktops list no battery at all
+        let desktop = [supply("Mains", Some("1"), None)];
+        assert_eq!(
+            read_power_supplies(&desktop),
+            PowerState {
+                on_battery: false,
+                battery_percent: None,
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod patterns_tests {
+    use crate::database::entities::{focus_sessions, time_sessions};
+    use crate::database::repositories::pattern_repository::{
+        CreatePatternRequest, PatternRepository,
+    };
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::patterns::{derive_patterns, DERIVED_PATTERN_TYPES, LOCAL_USER_ID};
+    use chrono::{DateTime, TimeZone, Utc};
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        // March 2nd 2026 is a Monday
+        Utc.with_ymd_and_hms(2026, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn session(start: DateTime<Utc>, end: DateTime<Utc>, paused_time: i32) -> time_sessions::Model {
+        time_sessions::Model {
+            id: uuid::Uuid::new_v4().to_string(),
+            task_id: "t1".to_string(),
+            start_time: start,
+            end_time: Some(end),
+            paused_time,
+            is_active: false,
+            notes: None,
+            breaks: None,
+            created_at: start,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_patterns_derived_from_sessions() {
+        let sessions = vec![
+            session(at(2, 9, 30), at(2, 11, 0), 0),
+            // Half of it paused
+            session(at(3, 10, 0), at(3, 10, 30), 900),
+        ];
+        let focus = vec![focus_sessions::Model {
+            id: "f1".to_string(),
+            task_id: "t1".to_string(),
+            planned_duration: 25,
+            actual_duration: Some(25),
+            focus_score: Some(80.0),
+            distraction_count: 0,
+            distraction_level: "minimal".to_string(),
+            background_audio: None,
+            notes: None,
+            breaks: None,
+            metrics: None,
+            created_at: at(2, 9, 10),
+            completed_at: Some(at(2, 9, 35)),
+        }];
+
+        let patterns = derive_patterns(&sessions, &focus, &Utc);
+        let find = |pattern_type: &str, time_slot: &str| {
+            patterns
+                .iter()
+                .find(|p| p.pattern_type == pattern_type && p.time_slot == time_slot)
+                .unwrap()
+        };
+
+        assert_eq!(patterns.len(), 4);
+        let nine = find("hourly", "09:00");
+        assert!((nine.productivity_score - 0.56).abs() < 1e-9);
+        assert_eq!(nine.sample_size, 2);
+        assert!((nine.confidence_level - 0.2).abs() < 1e-9);
+        assert_eq!(find("hourly", "10:00").productivity_score, 1.0);
+        assert!((find("daily", "Monday").productivity_score - 0.92).abs() < 1e-9);
+        assert!((find("daily", "Tuesday").productivity_score - 1.0 / 6.0).abs() < 1e-9);
+
+        // Derived patterns replace earlier ones; other pattern types stay
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = PatternRepository::new(db);
+        for (pattern_type, time_slot) in [("hourly", "15:00"), ("session_length", "45")] {
+            repo.create_pattern(CreatePatternRequest {
+                user_id: LOCAL_USER_ID.to_string(),
+                pattern_type: pattern_type.to_string(),
+                time_slot: time_slot.to_string(),
+                productivity_score: 0.5,
+                confidence_level: 0.5,
+                sample_size: 3,
+            })
+            .await
+            .unwrap();
+        }
+        repo.replace_patterns(LOCAL_USER_ID, &DERIVED_PATTERN_TYPES, patterns)
+            .await
+            .unwrap();
+
+        let stored = repo.find_patterns_for_user(LOCAL_USER_ID).await.unwrap();
+        assert_eq!(stored.len(), 5);
+        assert!(!stored.iter().any(|p| p.time_slot == "15:00"));
+        assert!(stored.iter().any(|p| p.pattern_type == "session_length"));
+    }
+}
+
+#[cfg(test)]
+mod pomodoro_tests {
+    use crate::database::repositories::focus_repository::FocusRepository;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::TaskRepository;
+    use crate::pomodoro::{PomodoroConfig, PomodoroEngine, PomodoroPhase, PomodoroState};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_phases_cycle_to_a_long_break() {
+        let config = PomodoroConfig {
+            sessions_before_long_break: 2,
+            ..PomodoroConfig::default()
+        };
+        let start = Utc.with_ymd_and_hms(2025, 3, 12, 9, 0, 0).unwrap();
+        let mut state = PomodoroState::new("t1".to_string(), config, "s1".to_string(), start);
+        assert_eq!(state.phase_ends_at, start + chrono::Duration::minutes(25));
+
+        let mut phases = Vec::new();
+        for _ in 0..4 {
+            let next = state.next_phase();
+            let ends_at = state.phase_ends_at;
+            state.enter(next, "s1".to_string(), ends_at);
+            phases.push((state.phase, state.completed_work_sessions));
+        }
+        assert_eq!(
+            phases,
+            vec![
+                (PomodoroPhase::ShortBreak, 1),
+                (PomodoroPhase::Work, 1),
+                (PomodoroPhase::LongBreak, 2),
+                (PomodoroPhase::Work, 2),
+            ]
+        );
+        // 25 + 5 + 25 + 15 minutes in
+        assert_eq!(
+            state.phase_started_at,
+            start + chrono::Duration::minutes(70)
+        );
+    }
+
+    #[test]
+    fn test_zero_minute_breaks_are_skipped() {
+        let config = PomodoroConfig {
+            short_break_duration: 0,
+            ..PomodoroConfig::default()
+        };
+        let state = PomodoroState::new("t1".to_string(), config, "s1".to_string(), Utc::now());
+        assert_eq!(state.next_phase(), PomodoroPhase::Work);
+    }
+
+    #[tokio::test]
+    async fn test_engine_records_focus_sessions() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let task = TaskRepository::new(db.clone())
+            .create_task(CreateTaskRequest {
+                title: "Write report".to_string(),
+                description: None,
+                priority: 1,
+                status: None,
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                due_date: None,
+                scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            })
+            .await
+            .unwrap();
+        let engine = PomodoroEngine::default();
+
+        let invalid = PomodoroConfig {
+            focus_duration: 0,
+            ..PomodoroConfig::default()
+        };
+        assert!(engine
+            .start(db.clone(), task.id.clone(), invalid)
+            .await
+            .is_err());
+
+        let state = engine
+            .start(db.clone(), task.id.clone(), PomodoroConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(state.phase, PomodoroPhase::Work);
+        assert!(engine
+            .start(db.clone(), task.id.clone(), PomodoroConfig::default())
+            .await
+            .is_err());
+        // Nothing to skip while working
+        assert!(engine.skip_break(db.clone()).await.is_err());
+
+        let stopped = engine.stop(db.clone()).await.unwrap().unwrap();
+        assert_eq!(stopped.focus_session_id, state.focus_session_id);
+        assert_eq!(engine.state().await, None);
+        assert_eq!(engine.stop(db.clone()).await.unwrap(), None);
+
+        let session = FocusRepository::new(db)
+            .find_by_id(&state.focus_session_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(session.task_id, task.id);
+        assert_eq!(session.planned_duration, 25);
+        assert_eq!(session.actual_duration, Some(0));
+        assert!(session.completed_at.is_some());
+        assert!(session.breaks.is_none());
+    }
+}
+
+#[cfg(test)]
+mod prompt_eval_tests {
+    use crate::database::repositories::ai_repository::{
+        CreatePromptEvalRunRequest, PromptEvalResult,
+    };
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::AiRepository;
+    use crate::prompt_eval::{diff_lines, DiffKind, PromptEvalService};
+
+    fn result(case_id: &str, response: &str, score: Option<f64>) -> PromptEvalResult {
+        PromptEvalResult {
+            case_id: case_id.to_string(),
+            response: response.to_string(),
+            judge_score: score,
+            judge_notes: None,
+            error: None,
+            response_time: Some(800),
+        }
+    }
+
+    fn run_request(template: &str, results: Vec<PromptEvalResult>) -> CreatePromptEvalRunRequest {
+        CreatePromptEvalRunRequest {
+            template_name: "system".to_string(),
+            template: template.to_string(),
+            model: "gemini-2.5-flash".to_string(),
+            judge_model: Some("gemini-2.5-pro".to_string()),
+            results,
+        }
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines(
+            "You are Kira.\nBe concise.\nUse tools.",
+            "You are Kira.\nUse tools.\nBe friendly.",
+        );
+        let kinds: Vec<DiffKind> = diff.iter().map(|line| line.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DiffKind::Same,
+                DiffKind::Removed,
+                DiffKind::Same,
+                DiffKind::Added
+            ]
+        );
+        assert_eq!(diff[1].text, "Be concise.");
+        assert_eq!(diff[3].text, "Be friendly.");
+    }
+
+    #[tokio::test]
+    async fn test_compare_reports_score_regressions() {
+        let db = setup_migrated_test_db().await.unwrap();
+        let repo = AiRepository::new(db.clone());
+
+        let before = repo
+            .save_prompt_eval_run(run_request(
+                "You are Kira.\nBe concise.",
+                vec![
+                    result("create_task", "Created the task.", Some(9.0)),
+                    result("small_talk", "You're welcome!", Some(8.0)),
+                    result("privacy", "Your data stays local.", None),
+                ],
+            ))
+            .await
+            .unwrap();
+        assert_eq!(before.average_score, Some(8.5));
+        assert_eq!(before.template_hash.len(), 64);
+
+        let invalid = repo
+            .save_prompt_eval_run(run_request(
+                "You are Kira.",
+                vec![result("create_task", "Done", Some(11.0))],
+            ))
+            .await;
+        assert!(invalid
+            .unwrap_err()
+            .to_string()
+            .contains("VALIDATION_ERROR"));
+
+        let mut failed = result("small_talk", "", None);
+        failed.error = Some("Request timed out".to_string());
+        let after = repo
+            .save_prompt_eval_run(run_request(
+                "You are Kira.\nBe verbose.",
+                vec![
+                    result("create_task", "I created the task for you.", Some(6.5)),
+                    failed,
+                ],
+            ))
+            .await
+            .unwrap();
+
+        let report = PromptEvalService::new(db)
+            .compare(&before.id, &after.id)
+            .await
+            .unwrap();
+        assert!(report.template_changed);
+        assert_eq!(report.cases.len(), 2);
+        assert_eq!(report.cases[0].score_delta, Some(-2.5));
+        assert!(!report.cases[0].prompt.is_empty());
+        assert_eq!(report.regressions, vec!["create_task", "small_talk"]);
+        assert_eq!(report.missing_cases, vec!["privacy"]);
+        assert_eq!(report.average_score_before, Some(9.0));
+        assert_eq!(report.average_score_delta, Some(-2.5));
+
+        assert_eq!(
+            repo.find_prompt_eval_runs(Some("system"))
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        repo.delete_prompt_eval_run(&before.id).await.unwrap();
+        assert!(repo.delete_prompt_eval_run(&before.id).await.is_err());
+    }
+}
+
+#[cfg(test)]
+mod date_parser_tests {
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::services::date_parser::parse_natural_date;
+    use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+    /// Wednesday 2025-03-12 10:30 in UTC+2
+    fn now() -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(2 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2025, 3, 12, 10, 30, 0)
+            .unwrap()
+    }
+
+    fn local(date: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(date)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn parse(input: &str) -> (DateTime<Utc>, String, bool) {
+        let parsed =
+            parse_natural_date(input, &now()).unwrap_or_else(|| panic!("'{}' should parse", input));
+        (parsed.date, parsed.matched, parsed.has_time)
+    }
+
+    #[test]
+    fn test_relative_days_and_times() {
+        assert_eq!(
+            parse("tomorrow 3pm"),
+            (
+                local("2025-03-13T15:00:00+02:00"),
+                "tomorrow 3pm".to_string(),
+                true
+            )
+        );
+        assert_eq!(parse("today").0, local("2025-03-12T00:00:00+02:00"));
+        assert!(!parse("today").2);
+        assert_eq!(
+            parse("at 9:15am tomorrow").0,
+            local("2025-03-13T09:15:00+02:00")
+        );
+        assert_eq!(parse("tonight").0, local("2025-03-12T20:00:00+02:00"));
+        // A time that already passed today means tomorrow
+        assert_eq!(parse("9am").0, local("2025-03-13T09:00:00+02:00"));
+        assert_eq!(parse("noon").0, local("2025-03-12T12:00:00+02:00"));
+    }
+
+    #[test]
+    fn test_weekdays_and_offsets() {
+        assert_eq!(parse("next friday").0, local("2025-03-14T00:00:00+02:00"));
+        assert_eq!(parse("wed").0, local("2025-03-19T00:00:00+02:00"));
+        assert_eq!(parse("in 2 weeks").0, local("2025-03-26T00:00:00+02:00"));
+        assert_eq!(parse("in an hour").0, local("2025-03-12T11:30:00+02:00"));
+        assert_eq!(
+            parse("3 days from now").0,
+            local("2025-03-15T00:00:00+02:00")
+        );
+        assert_eq!(parse("next month").0, local("2025-04-12T00:00:00+02:00"));
+    }
+
+    #[test]
+    fn test_calendar_dates() {
+        assert_eq!(
+            parse("march 14th at 5pm").0,
+            local("2025-03-14T17:00:00+02:00")
+        );
+        // Dates already past this year roll over to the next
+        assert_eq!(parse("1 feb").0, local("2026-02-01T00:00:00+02:00"));
+        assert_eq!(parse("2025-06-01").0, local("2025-06-01T00:00:00+02:00"));
+    }
+
+    #[test]
+    fn test_phrase_inside_text() {
+        let (date, matched, _) = parse("Send the report by next Friday.");
+        assert_eq!(date, local("2025-03-14T00:00:00+02:00"));
+        assert_eq!(matched, "next Friday");
+
+        assert!(parse_natural_date("write the summary", &now()).is_none());
+        assert!(parse_natural_date("at home", &now()).is_none());
+    }
+
+    #[test]
+    fn test_offsets_past_the_calendar_do_not_parse() {
+        for input in [
+            "in 4000000000 hours",
+            "in 99999999 days",
+            "in 99999999 weeks",
+            "in 4000000000 years",
+        ] {
+            assert!(parse_natural_date(input, &now()).is_none(), "{}", input);
+        }
+
+        let invalid = serde_json::from_value::<CreateTaskRequest>(serde_json::json!({
+            "title": "Plan sprint",
+            "priority": 1,
+            "due_date": "in 99999999 days",
+        }));
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_create_task_request_accepts_phrases() {
+        let request: CreateTaskRequest = serde_json::from_value(serde_json::json!({
+            "title": "Plan sprint",
+            "priority": 1,
+            "due_date": "in 2 weeks",
+            "scheduled_date": "2025-03-12T09:00:00Z",
+        }))
+        .unwrap();
+        assert!(request.due_date.unwrap() > Utc::now());
+        assert_eq!(
+            request.scheduled_date,
+            Some(local("2025-03-12T09:00:00+00:00"))
+        );
+
+        let invalid = serde_json::from_value::<CreateTaskRequest>(serde_json::json!({
+            "title": "Plan sprint",
+            "priority": 1,
+            "due_date": "whenever",
+        }));
+        assert!(invalid.is_err());
+    }
+}
+
+#[cfg(test)]
+mod rollover_tests {
+    use crate::backup::BackupService;
+    use crate::database::repositories::preferences_repository::RolloverMode;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::setup_migrated_test_db;
+    use crate::database::repositories::TaskRepository;
+    use crate::rollover::roll_over_tasks;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn task_request(title: &str, scheduled_date: Option<DateTime<Utc>>) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            priority: 1,
+            status: None,
+            dependencies: None,
+            time_estimate: None,
+            due_date: None,
+            scheduled_date,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            order_num: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unfinished_tasks_roll_over_to_today() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+
+        let unfinished = repo
+            .create_task(task_request("Write report", Some(at(2, 14))))
+            .await
+            .unwrap();
+        let mut finished = task_request("Send invoice", Some(at(2, 9)));
+        finished.status = Some("completed".to_string());
+        let finished = repo.create_task(finished).await.unwrap();
+        let today = repo
+            .create_task(task_request("Call plumber", Some(at(3, 10))))
+            .await
+            .unwrap();
+
+        let report = roll_over_tasks(db.clone(), RolloverMode::Today, at(3, 8), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(report.rolled_over, 1);
+        assert_eq!(report.task_ids, vec![unfinished.id.clone()]);
+
+        // The task keeps its time of day and counts the reschedule
+        let rolled = repo.find_by_id(&unfinished.id).await.unwrap().unwrap();
+        assert_eq!(rolled.scheduled_date, Some(at(3, 14)));
+        assert_eq!(rolled.reschedule_count, 1);
+        let untouched = repo.find_by_id(&finished.id).await.unwrap().unwrap();
+        assert_eq!(untouched.scheduled_date, Some(at(2, 9)));
+        assert_eq!(untouched.reschedule_count, 0);
+        let untouched = repo.find_by_id(&today.id).await.unwrap().unwrap();
+        assert_eq!(untouched.reschedule_count, 0);
+
+        // Running again the same day moves nothing
+        let report = roll_over_tasks(db.clone(), RolloverMode::Today, at(3, 9), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(report.rolled_over, 0);
+    }
+
+    #[tokio::test]
+    async fn test_unfinished_tasks_roll_back_to_backlog() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let repo = TaskRepository::new(db.clone());
+
+        let unfinished = repo
+            .create_task(task_request("Write report", Some(at(1, 14))))
+            .await
+            .unwrap();
+
+        let report = roll_over_tasks(db.clone(), RolloverMode::Off, at(3, 8), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(report.rolled_over, 0);
+
+        let report = roll_over_tasks(db.clone(), RolloverMode::Backlog, at(3, 8), &Utc)
+            .await
+            .unwrap();
+        assert_eq!(report.rolled_over, 1);
+        let rolled = repo.find_by_id(&unfinished.id).await.unwrap().unwrap();
+        assert!(rolled.scheduled_date.is_none());
+        assert_eq!(rolled.reschedule_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_count_survives_backup_round_trip() {
+        let files = tempfile::tempdir().unwrap();
+        let backup_path = files.path().join("backup.zip");
+        let backup_path = backup_path.to_str().unwrap();
+
+        let db = setup_migrated_test_db().await.unwrap();
+        let task = TaskRepository::new(db.clone())
+            .create_task(task_request("Write report", Some(at(1, 14))))
+            .await
+            .unwrap();
+        roll_over_tasks(db.clone(), RolloverMode::Today, at(3, 8), &Utc)
+            .await
+            .unwrap();
+        BackupService::new(db)
+            .export_data(backup_path)
+            .await
+            .unwrap();
+
+        let restored_db = setup_migrated_test_db().await.unwrap();
+        BackupService::new(restored_db.clone())
+            .import_data(backup_path, true)
+            .await
+            .unwrap();
+
+        let restored = TaskRepository::new(restored_db)
+            .find_by_id(&task.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(restored.reschedule_count, 1);
+    }
+}
+
+#[cfg(test)]
+mod task_bundle_tests {
+    use crate::database::repositories::checklist_repository::CreateChecklistItemRequest;
+    use crate::database::repositories::project_repository::CreateProjectRequest;
+    use crate::database::repositories::task_note_repository::CreateTaskNoteRequest;
+    use crate::database::repositories::task_repository::CreateTaskRequest;
+    use crate::database::repositories::tests::{setup_migrated_test_db, task_request};
+    use crate::database::repositories::{
+        AttachmentRepository, ChecklistRepository, ProjectRepository, TaskListRepository,
+        TaskNoteRepository, TaskRepository,
+    };
+    use crate::task_bundle::{
+        load_signing_key, BundledTaskList, TaskBundleData, TaskBundleKind, TaskBundleService,
+    };
+    use ed25519_dalek::SigningKey;
+    use std::io::{Read, Write};
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    /// Rewrite one entry of a bundle archive, keeping the others as they are
+    fn rewrite_entry(path: &str, name: &str, edit: impl Fn(String) -> String) {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut entries = Vec::new();
+        for index in 0..archive.len() {
+            let mut entry = archive.by_index(index).unwrap();
+            let mut content = String::new();
+            entry.read_to_string(&mut content).unwrap();
+            let content = if entry.name() == name {
+                edit(content)
+            } else {
+                content
+            };
+            entries.push((entry.name().to_string(), content));
+        }
+
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        for (entry_name, content) in entries {
+            zip.start_file(entry_name, zip::write::FileOptions::<()>::default())
+                .unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bundle_carries_subtasks_checklists_and_notes_but_not_projects() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let project = ProjectRepository::new(db.clone())
+            .create_project(CreateProjectRequest {
+                name: "Launch".to_string(),
+                description: None,
+                status: None,
+                target_date: None,
+            })
+            .await
+            .unwrap();
+        let task_repo = TaskRepository::new(db.clone());
+        let task = task_repo
+            .create_task(CreateTaskRequest {
+                project_id: Some(project.id.clone()),
+                ..task_request("Write announcement")
+            })
+            .await
+            .unwrap();
+        let subtask = task_repo
+            .create_task(CreateTaskRequest {
+                project_id: Some(project.id.clone()),
+                parent_task_id: Some(task.id.clone()),
+                ..task_request("Proofread")
+            })
+            .await
+            .unwrap();
+        ChecklistRepository::new(db.clone())
+            .create_item(CreateChecklistItemRequest {
+                task_id: subtask.id.clone(),
+                title: "Check links".to_string(),
+            })
+            .await
+            .unwrap();
+        TaskNoteRepository::new(db.clone())
+            .create_note(CreateTaskNoteRequest {
+                task_id: task.id.clone(),
+                content: "Draft is in the shared folder".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let screenshot = dir.path().join("screenshot.png");
+        std::fs::write(&screenshot, b"png").unwrap();
+        AttachmentRepository::new(db.clone(), dir.path().join("attachments"))
+            .add_attachment(&task.id, &screenshot)
+            .await
+            .unwrap();
+        let path = dir.path().join("task.kpbundle");
+        let path = path.to_str().unwrap();
+        let service = TaskBundleService::new(db.clone(), signing_key())
+            .with_attachments_dir(dir.path().join("attachments"));
+        let manifest = service.export_task(&task.id, path).await.unwrap();
+        assert!(manifest.checksum.starts_with("sha256:"));
+        assert!(manifest.signer.starts_with("ed25519:"));
+        assert_eq!(manifest.checklist_item_count, 1);
+        assert_eq!(manifest.note_count, 1);
+        assert_eq!(manifest.attachment_count, 1);
+
+        let restored_db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        // Another install verifies the bundle with the key it names
+        let other_key = SigningKey::from_bytes(&[9; 32]);
+        let restored_attachments_dir = dir.path().join("restored-attachments");
+        let result = TaskBundleService::new(restored_db.clone(), other_key)
+            .with_attachments_dir(restored_attachments_dir.clone())
+            .import_bundle(path, None)
+            .await
+            .unwrap();
+        assert_eq!(result.imported_task_ids.len(), 2);
+        assert_eq!(result.imported_checklist_item_count, 1);
+        assert_eq!(result.imported_note_count, 1);
+        assert_eq!(result.imported_attachment_count, 1);
+
+        let restored_repo = TaskRepository::new(restored_db.clone());
+        let root = restored_repo
+            .find_by_id(&result.imported_task_ids[0])
+            .await
+            .unwrap()
+            .unwrap();
+        let child = restored_repo
+            .find_by_id(&result.imported_task_ids[1])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(root.title, task.title);
+        assert_eq!(child.title, subtask.title);
+        assert_eq!(child.parent_task_id.as_deref(), Some(root.id.as_str()));
+        assert_eq!(root.project_id, None);
+        assert_eq!(child.project_id, None);
+
+        let notes = TaskNoteRepository::new(restored_db.clone())
+            .find_by_task(&root.id)
+            .await
+            .unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].content, "Draft is in the shared folder");
+        let attachment_repo =
+            AttachmentRepository::new(restored_db.clone(), restored_attachments_dir);
+        let attachments = attachment_repo.find_by_task(&root.id).await.unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].file_name, "screenshot.png");
+        let restored_file = std::fs::read(attachment_repo.file_path(&attachments[0])).unwrap();
+        assert_eq!(restored_file, b"png");
+        let checklist = ChecklistRepository::new(restored_db)
+            .find_by_task(&child.id)
+            .await
+            .unwrap();
+        assert_eq!(checklist.len(), 1);
+        assert_eq!(checklist[0].title, "Check links");
+    }
+
+    #[tokio::test]
+    async fn test_bundles_edited_after_export_are_rejected() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task = TaskRepository::new(db.clone())
+            .create_task(task_request("Write announcement"))
+            .await
+            .unwrap();
+        let service = TaskBundleService::new(db.clone(), signing_key());
+        let dir = tempfile::tempdir().unwrap();
+        let export = |name: &str| {
+            let path = dir.path().join(name);
+            path.to_str().unwrap().to_string()
+        };
+
+        let edited_tasks = export("edited-tasks.kpbundle");
+        service.export_task(&task.id, &edited_tasks).await.unwrap();
+        rewrite_entry(&edited_tasks, "bundle.json", |content| {
+            content.replace("Write announcement", "Send the files to evil.example")
+        });
+        let error = service
+            .import_bundle(&edited_tasks, None)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("checksum mismatch"));
+
+        // Fixing up the checksum in turn breaks the signature
+        let edited_manifest = export("edited-manifest.kpbundle");
+        service
+            .export_task(&task.id, &edited_manifest)
+            .await
+            .unwrap();
+        rewrite_entry(&edited_manifest, "manifest.json", |content| {
+            content.replace("Write announcement", "Quarterly report")
+        });
+        let error = service.inspect_bundle(&edited_manifest).unwrap_err();
+        assert!(error.to_string().contains("signature mismatch"));
+
+        let unsigned = export("unsigned.kpbundle");
+        service.export_task(&task.id, &unsigned).await.unwrap();
+        rewrite_entry(&unsigned, "manifest.sig", |_| String::new());
+        assert!(service.inspect_bundle(&unsigned).is_err());
+
+        let tasks = TaskRepository::new(db).find_all(None, None).await.unwrap();
+        assert_eq!(tasks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_attachments_edited_after_export_are_rejected() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task = TaskRepository::new(db.clone())
+            .create_task(task_request("Write announcement"))
+            .await
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let notes = dir.path().join("notes.txt");
+        std::fs::write(&notes, "Ship on Friday").unwrap();
+        let attachment = AttachmentRepository::new(db.clone(), dir.path().join("attachments"))
+            .add_attachment(&task.id, &notes)
+            .await
+            .unwrap();
+
+        let path = dir.path().join("task.kpbundle");
+        let path = path.to_str().unwrap();
+        let service = TaskBundleService::new(db.clone(), signing_key())
+            .with_attachments_dir(dir.path().join("attachments"));
+        service.export_task(&task.id, path).await.unwrap();
+        let entry = format!("attachments/{}", attachment.stored_name);
+        rewrite_entry(path, &entry, |_| "Ship on Monday".to_string());
+
+        let error = service.import_bundle(path, None).await.unwrap_err();
+        assert!(error.to_string().contains("Attachment checksum mismatch"));
+        let tasks = TaskRepository::new(db).find_all(None, None).await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        let stored = std::fs::read_dir(dir.path().join("attachments")).unwrap();
+        assert_eq!(stored.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_import_leaves_no_task_list_behind() {
+        let db = setup_migrated_test_db()
+            .await
+            .expect("Failed to setup test database");
+        let task = TaskRepository::new(db.clone())
+            .create_task(task_request("Write announcement"))
+            .await
+            .unwrap();
+        let service = TaskBundleService::new(db.clone(), signing_key());
+
+        // The same task twice makes the second insert fail
+        let bundle = TaskBundleData {
+            version: "2.0.0".to_string(),
+            kind: TaskBundleKind::TaskList,
+            created_at: chrono::Utc::now(),
+            task_list: Some(BundledTaskList {
+                name: "Shared launch".to_string(),
+            }),
+            tasks: vec![task.clone(), task],
+            task_dependencies: Vec::new(),
+            checklist_items: Vec::new(),
+            task_notes: Vec::new(),
+            attachments: Vec::new(),
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.kpbundle");
+        let path = path.to_str().unwrap();
+        service
+            .write_bundle(&bundle, "Shared launch".to_string(), path)
+            .await
+            .unwrap();
+
+        assert!(service.import_bundle(path, None).await.is_err());
+        let list = TaskListRepository::new(db)
+            .find_by_name("Shared launch")
+            .await
+            .unwrap();
+        assert!(list.is_none());
+    }
+
+    #[test]
+    fn test_signing_key_is_created_once_per_install() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bundle_signing.key");
+
+        let created = load_signing_key(&path).unwrap();
+        let loaded = load_signing_key(&path).unwrap();
+        assert_eq!(created.to_bytes(), loaded.to_bytes());
+
+        std::fs::write(&path, b"short").unwrap();
+        assert!(load_signing_key(&path).is_err());
+    }
+}
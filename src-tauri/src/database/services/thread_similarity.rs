@@ -0,0 +1,187 @@
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::database::entities::threads;
+use crate::database::repositories::thread_repository::CreateThreadRequest;
+use crate::database::repositories::{TaskRepository, ThreadRepository};
+
+/// Length of the locally computed embedding vectors
+const EMBEDDING_DIM: usize = 512;
+/// Characters of a thread's user messages that go into its embedding
+const MAX_THREAD_TEXT: usize = 4000;
+const DEFAULT_LIMIT: usize = 3;
+const DEFAULT_MIN_SIMILARITY: f64 = 0.35;
+
+/// Words too common to say anything about a conversation's topic
+const STOP_WORDS: [&str; 40] = [
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "can", "do", "for", "from", "how", "i",
+    "in", "is", "it", "me", "my", "of", "on", "or", "please", "so", "that", "the", "this", "to",
+    "was", "what", "when", "where", "which", "who", "why", "will", "with", "you", "your",
+];
+
+/// A past thread close in meaning to the new one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarThread {
+    pub thread: threads::Model,
+    /// Cosine similarity of the two embeddings, from 0 to 1
+    pub similarity: f64,
+    /// Start of the thread's first user message
+    pub preview: Option<String>,
+}
+
+/// A newly created thread with the past threads it may continue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedThread {
+    pub thread: threads::Model,
+    pub similar_threads: Vec<SimilarThread>,
+}
+
+/// Finds earlier threads about the same thing so a conversation can be resumed
+///
+/// Embeddings are computed locally by hashing words and character trigrams into a fixed-size
+/// vector, so no model download or network call is needed. Trigrams let related word forms
+/// ("schedule", "scheduling") still match.
+pub struct ThreadSimilarity {
+    thread_repo: ThreadRepository,
+    task_repo: TaskRepository,
+}
+
+impl ThreadSimilarity {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            thread_repo: ThreadRepository::new(db.clone()),
+            task_repo: TaskRepository::new(db),
+        }
+    }
+
+    /// Threads most similar to `text`, best match first
+    ///
+    /// Threads without any text of their own (no user messages and the default title) are
+    /// never suggested.
+    pub async fn find_similar(
+        &self,
+        text: &str,
+        exclude_thread_id: Option<&str>,
+        limit: Option<usize>,
+        min_similarity: Option<f64>,
+    ) -> Result<Vec<SimilarThread>, DbErr> {
+        let query = embed(text);
+        if query.iter().all(|&value| value == 0.0) {
+            return Ok(Vec::new());
+        }
+
+        let mut texts: HashMap<String, String> = HashMap::new();
+        for message in self.thread_repo.find_all_user_messages().await? {
+            let text = texts.entry(message.thread_id).or_default();
+            if text.len() < MAX_THREAD_TEXT {
+                text.push_str(&message.content);
+                text.push('\n');
+            }
+        }
+
+        let min_similarity = min_similarity.unwrap_or(DEFAULT_MIN_SIMILARITY);
+        let mut similar: Vec<SimilarThread> = self
+            .thread_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|thread| Some(thread.id.as_str()) != exclude_thread_id)
+            .filter_map(|thread| {
+                let messages = texts.get(&thread.id).map(String::as_str).unwrap_or("");
+                if messages.is_empty() && thread.title == "New Thread" {
+                    return None;
+                }
+                let similarity = cosine(&query, &embed(&format!("{}\n{}", thread.title, messages)));
+                (similarity >= min_similarity).then(|| SimilarThread {
+                    preview: messages
+                        .lines()
+                        .next()
+                        .map(|line| line.chars().take(120).collect()),
+                    thread,
+                    similarity,
+                })
+            })
+            .collect();
+
+        similar.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        similar.truncate(limit.unwrap_or(DEFAULT_LIMIT));
+        Ok(similar)
+    }
+
+    /// Create a thread and look for past threads about the same thing
+    ///
+    /// `text` is what the conversation will be about; without it, a thread assigned to a task
+    /// is compared by the task's title and description, and other threads find no matches.
+    pub async fn create_thread_checked(
+        &self,
+        request: CreateThreadRequest,
+        text: Option<&str>,
+    ) -> Result<CreatedThread, DbErr> {
+        let text = match (text, &request.assignment_task_id) {
+            (Some(text), _) => Some(text.to_string()),
+            (None, Some(task_id)) => self.task_text(task_id).await?,
+            (None, None) => None,
+        };
+        let thread = self.thread_repo.create_thread(request).await?;
+        let similar_threads = match text {
+            Some(text) => {
+                self.find_similar(&text, Some(&thread.id), None, None)
+                    .await?
+            }
+            None => Vec::new(),
+        };
+        Ok(CreatedThread {
+            thread,
+            similar_threads,
+        })
+    }
+
+    /// Text describing a task for comparison with threads: its title and description
+    async fn task_text(&self, task_id: &str) -> Result<Option<String>, DbErr> {
+        Ok(self
+            .task_repo
+            .find_by_id(task_id)
+            .await?
+            .map(|task| format!("{}\n{}", task.title, task.description.unwrap_or_default())))
+    }
+}
+
+/// Embed `text` as an L2-normalised vector of hashed word and character trigram counts
+pub(crate) fn embed(text: &str) -> Vec<f32> {
+    let mut vector = vec![0.0f32; EMBEDDING_DIM];
+    let lowered = text.to_lowercase();
+    let words = lowered
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() > 1 && !STOP_WORDS.contains(word));
+
+    for word in words {
+        vector[bucket(word.as_bytes())] += 1.0;
+
+        let padded: Vec<char> = format!("<{}>", word).chars().collect();
+        for trigram in padded.windows(3) {
+            let trigram: String = trigram.iter().collect();
+            vector[bucket(trigram.as_bytes())] += 0.5;
+        }
+    }
+
+    let norm = vector.iter().map(|value| value * value).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|value| *value /= norm);
+    }
+    vector
+}
+
+/// Cosine similarity of two normalised embeddings
+pub(crate) fn cosine(a: &[f32], b: &[f32]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x * y) as f64).sum()
+}
+
+/// FNV-1a, so embeddings don't change between runs or Rust versions
+fn bucket(bytes: &[u8]) -> usize {
+    let hash = bytes.iter().fold(0xcbf29ce484222325u64, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    (hash % EMBEDDING_DIM as u64) as usize
+}
@@ -0,0 +1,271 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use crate::database::entities::tasks;
+use crate::database::repositories::preferences_repository::WorkingHours;
+use crate::database::repositories::TaskRepository;
+use crate::database::services::dependency_scheduler::{DependencySchedule, ScheduleState};
+use crate::database::services::DependencyScheduler;
+
+/// Longest range a workload report covers
+const MAX_REPORT_DAYS: u32 = 92;
+
+/// Scheduled work of one day; durations are in minutes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadDay {
+    pub date: NaiveDate,
+    pub capacity_minutes: i64,
+    /// Remaining estimates of the open tasks scheduled that day
+    pub scheduled_minutes: i64,
+    /// Load once the suggested moves are applied
+    pub balanced_minutes: i64,
+    pub task_ids: Vec<String>,
+    pub overloaded: bool,
+}
+
+/// Rescheduling that takes load off an overloaded day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestedMove {
+    pub task_id: String,
+    pub title: String,
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub duration_minutes: i64,
+}
+
+/// Scheduled work per day against working hours, with moves that even it out
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadReport {
+    pub generated_at: DateTime<Utc>,
+    pub days: Vec<WorkloadDay>,
+    pub suggested_moves: Vec<SuggestedMove>,
+    /// Days that stay overloaded after the suggested moves
+    pub unresolved_days: Vec<NaiveDate>,
+}
+
+/// Compares scheduled work with available working time and proposes moves between days
+///
+/// Moves respect the dependency schedule: a task is never moved before its dependencies can
+/// finish, before the day a dependency is scheduled, after a dependent, or past its due date.
+/// Days off and holidays have no working time, so work planned on them is moved away.
+pub struct WorkloadBalancer {
+    task_repo: TaskRepository,
+    scheduler: DependencyScheduler,
+}
+
+impl WorkloadBalancer {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            task_repo: TaskRepository::new(db.clone()),
+            scheduler: DependencyScheduler::new(db),
+        }
+    }
+
+    /// Build the report for `days` days from `start_date`, optionally for one task list
+    ///
+    /// Days run from midnight to midnight in `timezone`; `capacity_minutes` is the working
+    /// time available on each working day of `working_hours`.
+    pub async fn compute_report<Tz: TimeZone>(
+        &self,
+        start_date: NaiveDate,
+        days: u32,
+        capacity_minutes: i64,
+        working_hours: &WorkingHours,
+        task_list_id: Option<&str>,
+        timezone: &Tz,
+    ) -> Result<WorkloadReport, DbErr> {
+        if days == 0 || days > MAX_REPORT_DAYS {
+            return Err(DbErr::Custom(format!(
+                "VALIDATION_ERROR: Workload reports cover 1 to {} days",
+                MAX_REPORT_DAYS
+            )));
+        }
+        if capacity_minutes <= 0 {
+            return Err(DbErr::Custom(
+                "VALIDATION_ERROR: Working time per day must be positive".to_string(),
+            ));
+        }
+
+        let tasks = self.task_repo.find_all(None, None).await?;
+        let schedule = self.scheduler.compute_schedule(task_list_id).await?;
+        let capacity: BTreeMap<NaiveDate, i64> = start_date
+            .iter_days()
+            .take(days as usize)
+            .map(|date| {
+                let minutes = if working_hours.is_workday(date) {
+                    capacity_minutes
+                } else {
+                    0
+                };
+                (date, minutes)
+            })
+            .collect();
+        let now = Utc::now();
+
+        Ok(build_report(
+            &tasks,
+            &schedule,
+            &capacity,
+            now.with_timezone(timezone).date_naive(),
+            timezone,
+        ))
+    }
+}
+
+fn build_report<Tz: TimeZone>(
+    tasks: &[tasks::Model],
+    schedule: &DependencySchedule,
+    capacity: &BTreeMap<NaiveDate, i64>,
+    today: NaiveDate,
+    timezone: &Tz,
+) -> WorkloadReport {
+    let local_date = |date: DateTime<Utc>| date.with_timezone(timezone).date_naive();
+    let dates: Vec<NaiveDate> = capacity.keys().copied().collect();
+    let by_id: HashMap<&str, &tasks::Model> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+    // Open, in-scope tasks and the day each one is planned for
+    let open: HashMap<&str, _> = schedule
+        .tasks
+        .iter()
+        .filter(|task| task.state != ScheduleState::Done)
+        .filter(|task| by_id.contains_key(task.task_id.as_str()))
+        .map(|task| (task.task_id.as_str(), task))
+        .collect();
+    let mut planned: HashMap<&str, NaiveDate> = open
+        .keys()
+        .filter_map(|&id| by_id[id].scheduled_date.map(|date| (id, local_date(date))))
+        .collect();
+
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    for task in open.values() {
+        for dep in &task.blocked_by {
+            dependents
+                .entry(dep.as_str())
+                .or_default()
+                .push(task.task_id.as_str());
+        }
+    }
+
+    let mut load: BTreeMap<NaiveDate, i64> = dates.iter().map(|&date| (date, 0)).collect();
+    for (&id, date) in &planned {
+        if let Some(minutes) = load.get_mut(date) {
+            *minutes += open[id].duration_minutes;
+        }
+    }
+    let scheduled_load = load.clone();
+
+    // Empty the overloaded days, least important tasks first
+    let mut suggested_moves = Vec::new();
+    for &date in &dates {
+        let mut candidates: Vec<&str> = planned
+            .iter()
+            .filter(|(_, &day)| day == date)
+            .map(|(&id, _)| id)
+            .filter(|id| open[id].duration_minutes > 0)
+            .collect();
+        candidates.sort_by_key(|&id| {
+            (
+                open[id].is_critical,
+                by_id[id].priority,
+                std::cmp::Reverse(open[id].slack_minutes.unwrap_or(0)),
+                id,
+            )
+        });
+
+        for id in candidates {
+            if load[&date] <= capacity[&date] {
+                break;
+            }
+            let task = by_id[id];
+            let minutes = open[id].duration_minutes;
+
+            let not_before = open[id]
+                .earliest_start
+                .map(local_date)
+                .into_iter()
+                .chain(
+                    open[id]
+                        .blocked_by
+                        .iter()
+                        .filter_map(|dep| planned.get(dep.as_str()).copied()),
+                )
+                .chain(Some(today))
+                .max();
+            let not_after = task
+                .due_date
+                .map(local_date)
+                .into_iter()
+                .chain(
+                    dependents
+                        .get(id)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|dependent| planned.get(dependent).copied()),
+                )
+                .min();
+
+            // Nearest day with room, postponing rather than advancing on a tie
+            let target = dates
+                .iter()
+                .copied()
+                .filter(|&day| day != date)
+                .filter(|&day| not_before.is_none_or(|bound| day >= bound))
+                .filter(|&day| not_after.is_none_or(|bound| day <= bound))
+                .filter(|day| load[day] + minutes <= capacity[day])
+                .min_by_key(|&day| ((day - date).num_days().abs(), day < date));
+
+            if let Some(target) = target {
+                *load.get_mut(&date).unwrap() -= minutes;
+                *load.get_mut(&target).unwrap() += minutes;
+                planned.insert(id, target);
+                suggested_moves.push(SuggestedMove {
+                    task_id: task.id.clone(),
+                    title: task.title.clone(),
+                    from_date: date,
+                    to_date: target,
+                    duration_minutes: minutes,
+                });
+            }
+        }
+    }
+
+    let days: Vec<WorkloadDay> = dates
+        .iter()
+        .map(|&date| {
+            let mut task_ids: Vec<String> = open
+                .keys()
+                .filter(|&&id| {
+                    by_id[id]
+                        .scheduled_date
+                        .is_some_and(|scheduled| local_date(scheduled) == date)
+                })
+                .map(|id| id.to_string())
+                .collect();
+            task_ids.sort();
+
+            WorkloadDay {
+                date,
+                capacity_minutes: capacity[&date],
+                scheduled_minutes: scheduled_load[&date],
+                balanced_minutes: load[&date],
+                task_ids,
+                overloaded: scheduled_load[&date] > capacity[&date],
+            }
+        })
+        .collect();
+    let unresolved_days = days
+        .iter()
+        .filter(|day| day.balanced_minutes > day.capacity_minutes)
+        .map(|day| day.date)
+        .collect();
+
+    WorkloadReport {
+        generated_at: Utc::now(),
+        days,
+        suggested_moves,
+        unresolved_days,
+    }
+}
@@ -0,0 +1,220 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, Url};
+
+use crate::database::get_database;
+use crate::database::repositories::task_repository::CreateTaskRequest;
+use crate::database::repositories::task_source_repository::RecordTaskSourceRequest;
+use crate::database::repositories::{TaskRepository, TaskSourceRepository};
+use crate::database::services::date_parser::parse_natural_date;
+
+/// URL scheme the app registers, as in `kirapilot://task/<id>`
+pub const DEEP_LINK_SCHEME: &str = "kirapilot";
+
+/// Emitted with a `DeepLinkEvent` when a link asks the frontend to navigate
+pub const DEEP_LINK_EVENT: &str = "deep-link";
+
+/// A parsed `kirapilot://` link
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DeepLink {
+    /// `kirapilot://task/<id>`
+    OpenTask { id: String },
+    /// `kirapilot://new?title=...&description=...&due=...`
+    NewTask {
+        title: String,
+        description: Option<String>,
+        due: Option<String>,
+    },
+    /// `kirapilot://timer/start/<id>`
+    StartTimer { task_id: String },
+}
+
+/// What the frontend should do for a link
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum DeepLinkEvent {
+    OpenTask { task_id: String },
+    StartTimer { task_id: String },
+}
+
+/// Links that arrived before the frontend was listening, e.g. the one that launched the app
+#[derive(Default)]
+pub struct PendingDeepLinks(Mutex<Vec<DeepLinkEvent>>);
+
+impl PendingDeepLinks {
+    /// Take the waiting events, leaving none behind
+    pub fn take(&self) -> Vec<DeepLinkEvent> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    fn push(&self, event: DeepLinkEvent) {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).push(event);
+    }
+}
+
+pub(crate) fn parse_deep_link(url: &Url) -> Result<DeepLink, String> {
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(format!("Not a {}:// link: {}", DEEP_LINK_SCHEME, url));
+    }
+
+    let route = url.host_str().unwrap_or_default().to_ascii_lowercase();
+    let segments: Vec<&str> = url
+        .path()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    match (route.as_str(), segments.as_slice()) {
+        ("task", [id]) => Ok(DeepLink::OpenTask { id: id.to_string() }),
+        ("timer", ["start", id]) => Ok(DeepLink::StartTimer {
+            task_id: id.to_string(),
+        }),
+        ("new", []) => {
+            let param = |name: &str| {
+                url.query_pairs()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+            let title = param("title").ok_or("A new task link needs a title")?;
+            Ok(DeepLink::NewTask {
+                title,
+                description: param("description"),
+                due: param("due"),
+            })
+        }
+        _ => Err(format!("Unknown link: {}", url)),
+    }
+}
+
+/// Handle links opened while the app runs, and the one it was launched with
+///
+/// Windows and Linux only learn the scheme from an installed bundle, so it is also registered
+/// at runtime for development builds.
+pub fn register(app: &AppHandle) {
+    use tauri_plugin_deep_link::DeepLinkExt;
+
+    #[cfg(any(windows, target_os = "linux"))]
+    if let Err(e) = app.deep_link().register_all() {
+        eprintln!(
+            "Failed to register the {}:// scheme: {}",
+            DEEP_LINK_SCHEME, e
+        );
+    }
+
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            open(&handle, url, false);
+        }
+    });
+
+    match app.deep_link().get_current() {
+        Ok(Some(urls)) => {
+            for url in urls {
+                open(app, url, true);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => eprintln!("Failed to read the launch link: {}", e),
+    }
+}
+
+/// Bring the main window to the front
+#[cfg(desktop)]
+pub fn focus_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Route a link in the background, logging links that can't be handled
+///
+/// `at_launch` links are queued for the frontend to collect once it is listening.
+fn open(app: &AppHandle, url: Url, at_launch: bool) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let event = match route(url.clone()).await {
+            Ok(event) => event,
+            Err(e) => {
+                eprintln!("Failed to open {}: {:#}", url, e);
+                return;
+            }
+        };
+
+        #[cfg(desktop)]
+        focus_main_window(&app);
+        if at_launch {
+            app.state::<PendingDeepLinks>().push(event);
+        } else if let Err(e) = app.emit(DEEP_LINK_EVENT, event) {
+            eprintln!("Failed to emit {} event: {}", DEEP_LINK_EVENT, e);
+        }
+    });
+}
+
+/// Carry out the backend part of a link and return what the frontend should show
+///
+/// New tasks are created here, recording the link as their source, and then opened.
+async fn route(url: Url) -> Result<DeepLinkEvent> {
+    let link = parse_deep_link(&url).map_err(anyhow::Error::msg)?;
+    let db = get_database().await.context("Database error")?;
+    let task_repo = TaskRepository::new(db.clone());
+
+    match link {
+        DeepLink::OpenTask { id } | DeepLink::StartTimer { task_id: id }
+            if task_repo.find_by_id(&id).await?.is_none() =>
+        {
+            anyhow::bail!("Task not found: {}", id)
+        }
+        DeepLink::OpenTask { id } => Ok(DeepLinkEvent::OpenTask { task_id: id }),
+        DeepLink::StartTimer { task_id } => Ok(DeepLinkEvent::StartTimer { task_id }),
+        DeepLink::NewTask {
+            title,
+            description,
+            due,
+        } => {
+            let due_date = match due {
+                Some(when) => Some(
+                    parse_natural_date(&when, &chrono::Local::now())
+                        .with_context(|| format!("Could not understand the date '{}'", when))?
+                        .date,
+                ),
+                None => None,
+            };
+            let task = task_repo
+                .create_task(CreateTaskRequest {
+                    title,
+                    description,
+                    priority: 1,
+                    status: None,
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: None,
+                    due_date,
+                    scheduled_date: None,
+                    tags: None,
+                    project_id: None,
+                    parent_task_id: None,
+                    task_list_id: None,
+                    periodic_template_id: None,
+                    is_periodic_instance: None,
+                    generation_date: None,
+                    effort: None,
+                    impact: None,
+                })
+                .await?;
+            TaskSourceRepository::new(db)
+                .record_source(RecordTaskSourceRequest {
+                    task_id: task.id.clone(),
+                    source_type: "deep_link".to_string(),
+                    external_id: None,
+                    url: Some(url.to_string()),
+                })
+                .await?;
+            Ok(DeepLinkEvent::OpenTask { task_id: task.id })
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use anyhow::{Context, Result};
+use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use reqwest::{Method, StatusCode};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::database::entities::{github_settings, task_github_links, tasks};
+use crate::database::get_database;
+use crate::database::repositories::{
+    github_repository::LinkGitHubIssueRequest,
+    task_repository::{CreateTaskRequest, UpdateTaskRequest},
+    task_source_repository::RecordTaskSourceRequest,
+    GitHubRepository, TaskRepository, TaskSourceRepository, TaskStatusRepository,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often the scheduler refreshes linked issues
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Issues per page, GitHub's maximum
+const PAGE_SIZE: usize = 100;
+
+/// Upper bound on pages fetched by one import
+const MAX_PAGES: usize = 10;
+
+/// An issue as returned by the GitHub REST API
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitHubIssue {
+    /// "owner/name"
+    pub repository: String,
+    pub number: i64,
+    pub title: String,
+    pub body: Option<String>,
+    /// "open" or "closed"
+    pub state: String,
+    pub html_url: String,
+    pub labels: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ApiIssue {
+    number: i64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    html_url: String,
+    repository_url: String,
+    #[serde(default)]
+    labels: Vec<ApiLabel>,
+    /// Present when the "issue" is a pull request
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct ApiLabel {
+    name: String,
+}
+
+impl ApiIssue {
+    fn into_issue(self) -> Option<GitHubIssue> {
+        if self.pull_request.is_some() {
+            return None;
+        }
+        Some(GitHubIssue {
+            repository: repository_from_url(&self.repository_url)?,
+            number: self.number,
+            title: self.title,
+            body: self.body.filter(|body| !body.trim().is_empty()),
+            state: self.state,
+            html_url: self.html_url,
+            labels: self.labels.into_iter().map(|label| label.name).collect(),
+        })
+    }
+}
+
+/// "owner/name" from an API repository URL such as `https://api.github.com/repos/owner/name`
+fn repository_from_url(url: &str) -> Option<String> {
+    let mut segments = url.trim_end_matches('/').rsplit('/');
+    let name = segments.next().filter(|name| !name.is_empty())?;
+    let owner = segments.next().filter(|owner| !owner.is_empty())?;
+    Some(format!("{}/{}", owner, name))
+}
+
+/// Minimal GitHub REST client for the issues of the authenticated user
+pub struct GitHubClient {
+    client: reqwest::Client,
+    api_url: String,
+    token: String,
+}
+
+impl GitHubClient {
+    pub fn new(api_url: &str, token: &str) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        Ok(Self {
+            client,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+        })
+    }
+
+    /// Open issues assigned to the authenticated user, across their repositories
+    pub async fn assigned_issues(&self) -> Result<Vec<GitHubIssue>> {
+        let mut issues = Vec::new();
+        for page in 1..=MAX_PAGES {
+            let path = format!(
+                "/issues?filter=assigned&state=open&per_page={}&page={}",
+                PAGE_SIZE, page
+            );
+            let body = self.send(Method::GET, &path, None).await?;
+            let page_issues: Vec<ApiIssue> =
+                serde_json::from_str(&body).context("Unexpected issue list")?;
+            let page_size = page_issues.len();
+            issues.extend(page_issues.into_iter().filter_map(ApiIssue::into_issue));
+            if page_size < PAGE_SIZE {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    pub async fn issue(&self, repository: &str, number: i64) -> Result<GitHubIssue> {
+        let path = format!("/repos/{}/issues/{}", repository, number);
+        let body = self.send(Method::GET, &path, None).await?;
+        parse_issue(&body)
+    }
+
+    /// Close or reopen an issue
+    pub async fn set_issue_state(
+        &self,
+        repository: &str,
+        number: i64,
+        state: &str,
+    ) -> Result<GitHubIssue> {
+        let path = format!("/repos/{}/issues/{}", repository, number);
+        let payload = serde_json::json!({ "state": state }).to_string();
+        let body = self.send(Method::PATCH, &path, Some(payload)).await?;
+        parse_issue(&body)
+    }
+
+    async fn send(&self, method: Method, path: &str, payload: Option<String>) -> Result<String> {
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.api_url, path))
+            .header(AUTHORIZATION, format!("Bearer {}", self.token))
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, "KiraPilot")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(payload) = payload {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(payload);
+        }
+
+        let response = request.send().await.context("Failed to reach GitHub")?;
+        match response.status() {
+            StatusCode::UNAUTHORIZED => anyhow::bail!("GitHub rejected the access token"),
+            StatusCode::NOT_FOUND => anyhow::bail!("Not found on GitHub: {}", path),
+            status if !status.is_success() => {
+                anyhow::bail!("GitHub request failed: HTTP {}", status)
+            }
+            _ => {}
+        }
+        response
+            .text()
+            .await
+            .context("Failed to read GitHub response")
+    }
+}
+
+/// Parse a single issue; pull requests are rejected
+pub(crate) fn parse_issue(json: &str) -> Result<GitHubIssue> {
+    let issue: ApiIssue = serde_json::from_str(json).context("Unexpected issue")?;
+    issue
+        .into_issue()
+        .context("Pull requests can't be linked to tasks")
+}
+
+/// What a refresh does to bring a task and its issue back in line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RefreshAction {
+    None,
+    /// The issue was closed on GitHub
+    CompleteTask,
+    /// The issue was reopened on GitHub
+    ReopenTask,
+    /// The task was completed here
+    CloseIssue,
+    /// The task was reopened here
+    ReopenIssue,
+}
+
+/// Decide which side changed, given the issue state recorded at the last sync
+pub(crate) fn refresh_action(
+    task_completed: bool,
+    stored_state: &str,
+    issue_state: &str,
+) -> RefreshAction {
+    let issue_closed = issue_state == "closed";
+    let was_closed = stored_state == "closed";
+    match (task_completed, issue_closed) {
+        (true, false) if was_closed => RefreshAction::ReopenTask,
+        (true, false) => RefreshAction::CloseIssue,
+        (false, true) if was_closed => RefreshAction::ReopenIssue,
+        (false, true) => RefreshAction::CompleteTask,
+        _ => RefreshAction::None,
+    }
+}
+
+/// Result of importing assigned issues
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitHubImportReport {
+    /// Tasks created from issues
+    pub imported: usize,
+    /// Issues that already had a task
+    pub already_linked: usize,
+    pub errors: Vec<String>,
+}
+
+/// Result of refreshing linked issues
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitHubRefreshReport {
+    pub checked: usize,
+    pub completed_tasks: usize,
+    pub reopened_tasks: usize,
+    pub closed_issues: usize,
+    pub reopened_issues: usize,
+    /// Links that failed; the rest of the refresh still ran
+    pub errors: Vec<String>,
+}
+
+/// Links tasks to GitHub issues and keeps their open/closed state in step
+pub struct GitHubService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl GitHubService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    async fn connect(&self) -> Result<(GitHubClient, github_settings::Model)> {
+        let settings = GitHubRepository::new(self.db.clone())
+            .get_settings()
+            .await?
+            .context("GitHub is not connected")?;
+        let client = GitHubClient::new(&settings.api_url, &settings.token)?;
+        Ok((client, settings))
+    }
+
+    fn task_repository(&self) -> TaskRepository {
+        TaskRepository::new(self.db.clone()).with_audit_source("github")
+    }
+
+    /// Create a task for every open issue assigned to the user that has none yet
+    pub async fn import_assigned_issues(&self) -> Result<GitHubImportReport> {
+        let (client, settings) = self.connect().await?;
+        let repo = GitHubRepository::new(self.db.clone());
+        let task_repo = self.task_repository();
+        let source_repo = TaskSourceRepository::new(self.db.clone());
+        let mut report = GitHubImportReport::default();
+
+        for issue in client.assigned_issues().await? {
+            if repo
+                .find_link_by_issue(&issue.repository, issue.number)
+                .await?
+                .is_some()
+            {
+                report.already_linked += 1;
+                continue;
+            }
+
+            let result: Result<()> = async {
+                let task = task_repo
+                    .create_task(CreateTaskRequest {
+                        title: issue.title.clone(),
+                        description: issue.body.clone(),
+                        priority: 1,
+                        status: None,
+                        order_num: None,
+                        dependencies: None,
+                        time_estimate: None,
+                        due_date: None,
+                        scheduled_date: None,
+                        tags: Some(issue.labels.clone()).filter(|labels| !labels.is_empty()),
+                        project_id: None,
+                        parent_task_id: None,
+                        task_list_id: settings.task_list_id.clone(),
+                        periodic_template_id: None,
+                        is_periodic_instance: None,
+                        generation_date: None,
+                        effort: None,
+                        impact: None,
+                    })
+                    .await?;
+                source_repo
+                    .record_source(RecordTaskSourceRequest {
+                        task_id: task.id.clone(),
+                        source_type: "github".to_string(),
+                        external_id: Some(format!("{}#{}", issue.repository, issue.number)),
+                        url: Some(issue.html_url.clone()),
+                    })
+                    .await?;
+                repo.link_task(LinkGitHubIssueRequest {
+                    task_id: task.id,
+                    repository: issue.repository.clone(),
+                    issue_number: issue.number,
+                    issue_url: Some(issue.html_url.clone()),
+                    issue_state: Some(issue.state.clone()),
+                })
+                .await?;
+                Ok(())
+            }
+            .await;
+
+            match result {
+                Ok(()) => report.imported += 1,
+                Err(e) => report
+                    .errors
+                    .push(format!("{}#{}: {}", issue.repository, issue.number, e)),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Close the issue linked to a task, returning whether there was an open one to close
+    pub async fn close_linked_issue(&self, task_id: &str) -> Result<bool> {
+        let repo = GitHubRepository::new(self.db.clone());
+        let Some(link) = repo.find_link(task_id).await? else {
+            return Ok(false);
+        };
+        if link.issue_state == "closed" {
+            return Ok(false);
+        }
+
+        let (client, _) = self.connect().await?;
+        let issue = client
+            .set_issue_state(&link.repository, link.issue_number, "closed")
+            .await?;
+        repo.update_link_state(task_id, &issue.state).await?;
+        Ok(true)
+    }
+
+    /// Compare every linked task with its issue and carry over whichever side changed
+    pub async fn refresh(&self) -> Result<GitHubRefreshReport> {
+        let (client, _) = self.connect().await?;
+        let repo = GitHubRepository::new(self.db.clone());
+        let task_repo = self.task_repository();
+        let status_repo = TaskStatusRepository::new(self.db.clone());
+        let mut report = GitHubRefreshReport::default();
+
+        for link in repo.find_links().await? {
+            let result: Result<()> = async {
+                // Links are removed with their task, so the task is always there
+                let Some(task) = task_repo.find_by_id(&link.task_id).await? else {
+                    return Ok(());
+                };
+                let issue = client.issue(&link.repository, link.issue_number).await?;
+                report.checked += 1;
+
+                let state = match refresh_action(
+                    task.status == "completed",
+                    &link.issue_state,
+                    &issue.state,
+                ) {
+                    RefreshAction::None => issue.state,
+                    RefreshAction::CompleteTask => {
+                        set_task_status(&task_repo, &task, "completed").await?;
+                        report.completed_tasks += 1;
+                        issue.state
+                    }
+                    RefreshAction::ReopenTask => {
+                        let status = status_repo.reopen_status().await?;
+                        set_task_status(&task_repo, &task, &status).await?;
+                        report.reopened_tasks += 1;
+                        issue.state
+                    }
+                    RefreshAction::CloseIssue => {
+                        let issue = client
+                            .set_issue_state(&link.repository, link.issue_number, "closed")
+                            .await?;
+                        report.closed_issues += 1;
+                        issue.state
+                    }
+                    RefreshAction::ReopenIssue => {
+                        let issue = client
+                            .set_issue_state(&link.repository, link.issue_number, "open")
+                            .await?;
+                        report.reopened_issues += 1;
+                        issue.state
+                    }
+                };
+                repo.update_link_state(&link.task_id, &state).await?;
+                Ok(())
+            }
+            .await;
+
+            if let Err(e) = result {
+                report.errors.push(issue_label(&link, e));
+            }
+        }
+
+        repo.mark_refreshed().await?;
+        Ok(report)
+    }
+}
+
+fn issue_label(link: &task_github_links::Model, error: anyhow::Error) -> String {
+    format!("{}#{}: {}", link.repository, link.issue_number, error)
+}
+
+async fn set_task_status(
+    task_repo: &TaskRepository,
+    task: &tasks::Model,
+    status: &str,
+) -> Result<tasks::Model> {
+    Ok(task_repo
+        .update_task(
+            &task.id,
+            UpdateTaskRequest {
+                title: None,
+                description: None,
+                priority: None,
+                status: Some(status.to_string()),
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                actual_time: None,
+                due_date: None,
+                scheduled_date: None,
+                clear_scheduled_date: None,
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                completed_at: None,
+                effort: None,
+                impact: None,
+                expected_updated_at: Some(task.updated_at),
+            },
+        )
+        .await?)
+}
+
+/// Close a completed task's issue in the background, so the task update doesn't wait on GitHub
+///
+/// A failure leaves the link open; the next refresh closes the issue.
+pub fn spawn_close_linked_issue(db: Arc<DatabaseConnection>, task_id: String) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = GitHubService::new(db).close_linked_issue(&task_id).await {
+            eprintln!("Failed to close GitHub issue for task {}: {}", task_id, e);
+        }
+    });
+}
+
+/// Start the background loop that refreshes linked issues when auto refresh is on
+pub fn start_github_scheduler() {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(REFRESH_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = run_scheduled_refresh().await {
+                eprintln!("Failed to refresh GitHub issues: {}", e);
+            }
+        }
+    });
+}
+
+async fn run_scheduled_refresh() -> Result<()> {
+    let db = get_database().await?;
+    let settings = GitHubRepository::new(db.clone()).get_settings().await?;
+    if !settings.is_some_and(|settings| settings.auto_refresh) {
+        return Ok(());
+    }
+
+    let report = GitHubService::new(db).refresh().await?;
+    for error in report.errors {
+        eprintln!("Failed to refresh GitHub issue {}", error);
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use chrono::Utc;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::database::get_database;
+use crate::database::repositories::PreferencesRepository;
+use crate::database::services::goal_tracker::GoalProgress;
+use crate::database::services::GoalTracker;
+use crate::notifications::{Delivery, NotificationDispatcher};
+
+/// How often goal streaks are checked
+const CHECK_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Start the background loop that warns when a goal streak is about to break
+pub fn start_goal_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = warn_streaks_at_risk(&app).await {
+                eprintln!("Failed to check goal streaks: {}", e);
+            }
+        }
+    });
+}
+
+/// Show a notification for every streak at risk, returning how many were shown
+///
+/// Each goal is warned about at most once per period.
+async fn warn_streaks_at_risk(app: &AppHandle) -> Result<usize> {
+    let db = get_database().await?;
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await?;
+    let progress = GoalTracker::new(db.clone())
+        .compute_progress(None, &working_hours, Utc::now(), &chrono::Local)
+        .await?;
+    let dispatcher = NotificationDispatcher::new(app, db);
+
+    let mut shown = 0;
+    for progress in progress.iter().filter(|progress| progress.at_risk) {
+        let delivery = dispatcher
+            .send(
+                "goal_streak",
+                dedup_key(progress),
+                &progress.goal.title,
+                notification_body(progress),
+            )
+            .await?;
+        if delivery == Delivery::Shown {
+            shown += 1;
+        }
+    }
+
+    Ok(shown)
+}
+
+fn dedup_key(progress: &GoalProgress) -> String {
+    format!(
+        "goal-streak:{}:{}",
+        progress.goal.id,
+        progress.period_start.to_rfc3339()
+    )
+}
+
+fn notification_body(progress: &GoalProgress) -> String {
+    let unit = match progress.goal.metric.as_str() {
+        "completed_tasks" => "tasks",
+        _ => "minutes",
+    };
+    let (streak, period) = match progress.goal.period.as_str() {
+        "week" => ("week", "this week"),
+        _ => ("day", "today"),
+    };
+    format!(
+        "Your {}-{} streak ends unless you add {} more {} {}",
+        progress.current_streak, streak, progress.remaining, unit, period
+    )
+}
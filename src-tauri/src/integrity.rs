@@ -0,0 +1,100 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::database::entities::integrity_reports;
+use crate::database::get_database;
+use crate::database::migration::initialization::{
+    validate_database_integrity, DatabaseIntegrityReport,
+};
+use crate::database::repositories::IntegrityReportRepository;
+use crate::notifications::{Delivery, NotificationDispatcher};
+
+/// How often the scheduler looks whether a check is due
+const SCAN_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Time between scheduled integrity checks, one week
+const CHECK_INTERVAL_DAYS: i64 = 7;
+
+/// Start the background loop that validates database integrity once a week
+///
+/// Every check is stored; the user is only notified when a check finds issues.
+pub fn start_integrity_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(SCAN_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = run_due_check(&app).await {
+                eprintln!("Failed to run scheduled integrity check: {}", e);
+            }
+        }
+    });
+}
+
+/// Run and store an integrity check if the last one is a week old, returning the new report
+async fn run_due_check(app: &AppHandle) -> Result<Option<integrity_reports::Model>> {
+    let db = get_database().await?;
+    let repo = IntegrityReportRepository::new(db.clone());
+    let latest = repo.find_latest().await?;
+    if !check_due(latest.as_ref(), Utc::now()) {
+        return Ok(None);
+    }
+
+    let report = validate_database_integrity(&db).await?;
+    let notified = !report.is_healthy
+        && match notify(app, db.clone(), &report).await {
+            Ok(delivery) => delivery == Delivery::Shown,
+            Err(e) => {
+                eprintln!("Failed to show integrity notification: {}", e);
+                false
+            }
+        };
+
+    Ok(Some(repo.record(&report, notified).await?))
+}
+
+/// Whether a scheduled check is due, given the most recent report
+pub(crate) fn check_due(latest: Option<&integrity_reports::Model>, now: DateTime<Utc>) -> bool {
+    latest.map_or(true, |latest| {
+        now - latest.checked_at >= chrono::Duration::days(CHECK_INTERVAL_DAYS)
+    })
+}
+
+async fn notify(
+    app: &AppHandle,
+    db: Arc<DatabaseConnection>,
+    report: &DatabaseIntegrityReport,
+) -> Result<Delivery> {
+    NotificationDispatcher::new(app, db)
+        .send(
+            "integrity",
+            format!("integrity:{}", Utc::now().date_naive()),
+            "Database issues found",
+            issue_summary(report),
+        )
+        .await
+}
+
+/// One line describing the issues of an unhealthy report
+pub(crate) fn issue_summary(report: &DatabaseIntegrityReport) -> String {
+    let mut issues = Vec::new();
+    if !report.has_default_task_list {
+        issues.push("the default task list is missing".to_string());
+    }
+    match report.orphaned_tasks_count {
+        0 => {}
+        1 => issues.push("1 task has no task list".to_string()),
+        count => issues.push(format!("{} tasks have no task list", count)),
+    }
+
+    if issues.is_empty() {
+        "The weekly integrity check found issues".to_string()
+    } else {
+        let mut summary = issues.join(" and ");
+        summary[..1].make_ascii_uppercase();
+        summary
+    }
+}
@@ -1,30 +1,141 @@
+mod activity;
+mod ai_budget;
+mod authorization;
 mod backup;
+mod calendar_export;
+pub mod cli;
+mod conversation_export;
 mod database;
-
-use backup::{BackupMetadata, BackupService};
+mod deep_link;
+mod github;
+mod goals;
+mod integrity;
+mod notifications;
+mod operations;
+mod patterns;
+mod pomodoro;
+mod power;
+mod prompt_eval;
+mod quick_capture;
+mod reminders;
+mod rollover;
+mod settings_bundle;
+mod sync;
+mod task_bundle;
+mod task_import;
+mod template_pack;
+mod time_import;
+mod todoist_import;
+mod undo;
+mod webhooks;
+
+use activity::IdleSettings;
+use ai_budget::{AiBudgetStatus, AiCostReport};
+use backup::{BackupMetadata, BackupService, RestoreRehearsalReport};
+use calendar_export::{CalendarExportRange, CalendarExportService, CalendarExportSummary};
+use conversation_export::{
+    ConversationExportRequest, ConversationExportService, ConversationExportSummary,
+};
+use database::config::{get_attachments_dir, get_bundle_signing_key_path};
 use database::migration::initialization::DatabaseIntegrityReport;
 use database::migration::{MigrationStatus, MigrationTestResult};
 use database::repositories::{
+    ai_persona_repository::{CreateAiPersonaRequest, UpdateAiPersonaRequest},
     ai_repository::{
         AiLogStorageStats, AiStats, CreateAiInteractionLogRequest, CreateAiInteractionRequest,
-        CreateToolExecutionLogRequest, UpdateAiInteractionLogRequest, UpdateAiInteractionRequest,
+        CreatePromptEvalRunRequest, CreateReplayComparisonRequest, CreateToolExecutionLogRequest,
+        InteractionLog, InteractionLogFilter, InteractionReplay, ProviderMetrics,
+        RecordProviderRequest, UpdateAiInteractionLogRequest, UpdateAiInteractionRequest,
     },
+    ai_suggestion_repository::CreateAiSuggestionRequest,
+    api_token_repository::CreateApiTokenRequest,
+    attachment_repository::AttachmentCleanup,
+    checklist_repository::{CreateChecklistItemRequest, UpdateChecklistItemRequest},
+    custom_field_repository::{
+        CreateCustomFieldRequest, TaskCustomFieldValue, UpdateCustomFieldRequest,
+    },
+    daily_note_repository::DailyNoteDay,
+    focus_repository::{CreateFocusSessionRequest, FocusBreak, FocusRepository, FocusStats},
+    focus_template_repository::{CreateFocusTemplateRequest, UpdateFocusTemplateRequest},
+    github_repository::{LinkGitHubIssueRequest, SaveGitHubSettingsRequest},
+    goal_repository::CreateGoalRequest,
+    pattern_repository::{CreatePatternRequest, PatternRepository, ProductivityInsights},
     periodic_task_repository::{
-        CreatePeriodicTaskTemplateRequest, PeriodicTaskStats, UpdatePeriodicTaskTemplateRequest,
+        CreatePeriodicTaskTemplateRequest, PeriodicTaskStats, RecurrencePreviewRequest,
+        UpdatePeriodicTaskTemplateRequest,
+    },
+    preferences_repository::{PromptTemplates, RolloverMode, WorkdayContext, WorkingHours},
+    project_repository::{CreateProjectRequest, ProjectProgress, UpdateProjectRequest},
+    reminder_repository::{ReminderOffsetRequest, ScheduledReminder},
+    saved_filter_repository::{CreateSavedFilterRequest, UpdateSavedFilterRequest},
+    tag_repository::TagWithCount,
+    task_list_repository::{
+        BreakdownGroup, CreateTaskListRequest, TaskBreakdown, TaskListAgingStats, TaskListStats,
+        UpdateTaskListRequest,
+    },
+    task_note_repository::CreateTaskNoteRequest,
+    task_repository::{
+        CreateTaskRequest, DependencyGraphReport, PriorityMatrix, TaskFilter, TaskRelation,
+        TaskStats, TaskTreeNode, UpdateTaskRequest,
+    },
+    task_source_repository::RecordTaskSourceRequest,
+    task_status_repository::{CreateTaskStatusRequest, UpdateTaskStatusRequest},
+    task_template_repository::{
+        CreateTaskTemplateRequest, InstantiateTaskTemplateRequest, SaveTaskAsTemplateRequest,
+        UpdateTaskTemplateRequest,
     },
-    task_list_repository::{CreateTaskListRequest, TaskListStats, UpdateTaskListRequest},
-    task_repository::{CreateTaskRequest, TaskStats, UpdateTaskRequest},
     thread_repository::{
         CreateThreadMessageRequest, CreateThreadRequest, ThreadStatistics, UpdateThreadRequest,
     },
-    time_tracking_repository::{CreateTimeSessionRequest, TimeStats, UpdateTimeSessionRequest},
-    AiRepository, PeriodicTaskRepository, TaskListRepository, TaskRepository, ThreadRepository, TimeTrackingRepository,
+    time_tracking_repository::{
+        BillingGroupBy, BillingReport, CreateTimeSessionRequest, DailyTimeAggregate,
+        InterruptionReport, ProductivityReport, TimeStats, UpdateTimeSessionRequest,
+    },
+    webhook_repository::{CreateWebhookRequest, UpdateWebhookRequest},
+    AiPersonaRepository, AiRepository, AiSuggestionRepository, ApiTokenRepository,
+    AttachmentRepository, ChecklistRepository, CustomFieldRepository, DailyNoteRepository,
+    FocusTemplateRepository, GitHubRepository, GoalRepository, IntegrityReportRepository,
+    NotificationRepository, PeriodicTaskRepository, PreferencesRepository, ProjectRepository,
+    ReminderRepository, SavedFilterRepository, TagRepository, TaskAuditRepository,
+    TaskListRepository, TaskNoteRepository, TaskRepository, TaskSourceRepository,
+    TaskStatusRepository, TaskTemplateRepository, ThreadRepository, TimeTrackingRepository,
+    WebhookRepository,
+};
+use database::services::{
+    backlog_groomer::{GroomingQueue, GroomingRules},
+    daily_planner::DailyPlan,
+    date_parser::{self, ParsedDate},
+    dependency_scheduler::DependencySchedule,
+    estimation_analyzer::EstimationReport,
+    goal_tracker::GoalProgress,
+    thread_similarity::{CreatedThread, SimilarThread},
+    workload_balancer::WorkloadReport,
+    BacklogGroomer, DailyPlanner, DependencyScheduler, EstimationAnalyzer, GoalTracker,
+    TaskGenerationEngine, ThreadSimilarity, WorkloadBalancer,
 };
-use database::services::TaskGenerationEngine;
 use database::{
     check_database_health, get_database, get_migration_status, initialize_database,
     run_post_migration_init, test_migration_compatibility, validate_db_integrity, DatabaseHealth,
 };
+use deep_link::{DeepLinkEvent, PendingDeepLinks};
+use github::{GitHubImportReport, GitHubRefreshReport, GitHubService};
+use operations::{start_operation, Operation, OperationHandle, OperationRegistry};
+use pomodoro::{PomodoroConfig, PomodoroEngine, PomodoroState};
+use prompt_eval::{evaluation_suite, PromptEvalCase, PromptEvalReport, PromptEvalService};
+use settings_bundle::{SettingsBundleImportResult, SettingsBundleService, SettingsBundleSummary};
+use sync::{CalDavConfig, SyncReport, SyncService, SyncStatus};
+use task_bundle::{
+    load_signing_key, TaskBundleImportResult, TaskBundleManifest, TaskBundleService,
+};
+use task_import::{
+    TaskImportFormat, TaskImportMapping, TaskImportPreview, TaskImportResult, TaskImportService,
+};
+use template_pack::{
+    TemplatePackExportRequest, TemplatePackImportResult, TemplatePackService, TemplatePackSummary,
+};
+use time_import::{TimeImportPreview, TimeImportResult, TimeImportService, TimeImportSource};
+use todoist_import::{TodoistImportPreview, TodoistImportResult, TodoistImportService};
+use undo::{UndoHistory, UndoneChange};
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
@@ -36,12 +147,25 @@ fn greet(name: &str) -> String {
 // Task Management Commands
 // ============================================================================
 
+/// Task repository whose changes are recorded in the task history as made by `source`
+fn task_repository_for(
+    db: std::sync::Arc<sea_orm::DatabaseConnection>,
+    source: Option<String>,
+) -> Result<TaskRepository, String> {
+    let source = source.unwrap_or_else(|| "user".to_string());
+    TaskAuditRepository::validate_source(&source).map_err(|e| e.to_string())?;
+    Ok(TaskRepository::new(db).with_audit_source(&source))
+}
+
 #[tauri::command]
-async fn create_task(request: CreateTaskRequest) -> Result<serde_json::Value, String> {
+async fn create_task(
+    request: CreateTaskRequest,
+    source: Option<String>,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database connection failed: {}", e))?;
-    let repo = TaskRepository::new(db);
+    let repo = task_repository_for(db, source)?;
 
     match repo.create_task(request).await {
         Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
@@ -79,6 +203,27 @@ async fn create_task(request: CreateTaskRequest) -> Result<serde_json::Value, St
     }
 }
 
+/// Resolve a date phrase such as "next friday" so the UI can confirm it before saving
+#[tauri::command]
+fn parse_natural_date(text: String) -> Option<ParsedDate> {
+    date_parser::parse_natural_date(&text, &chrono::Local::now())
+}
+
+/// Create a task from a single line like `Fix login bug #work !p1 @tomorrow`
+#[tauri::command]
+async fn quick_capture(text: String) -> Result<serde_json::Value, String> {
+    let capture = quick_capture::parse_quick_capture(&text, &chrono::Local::now())?;
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskRepository::new(db);
+
+    match repo.create_task(capture.into_request()).await {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to capture task: {}", e)),
+    }
+}
+
 #[tauri::command]
 async fn get_task(id: String) -> Result<Option<serde_json::Value>, String> {
     let db = get_database()
@@ -175,27 +320,103 @@ async fn get_backlog_tasks() -> Result<Vec<serde_json::Value>, String> {
 }
 
 #[tauri::command]
-async fn update_task(id: String, request: UpdateTaskRequest) -> Result<serde_json::Value, String> {
+async fn update_task(
+    id: String,
+    request: UpdateTaskRequest,
+    source: Option<String>,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TaskRepository::new(db);
+    let repo = task_repository_for(db.clone(), source)?;
+    let completed = TaskStatusRepository::completed_statuses_in(&*db)
+        .await
+        .map_err(|e| format!("Failed to get task statuses: {}", e))?;
+    let was_completed = matches!(
+        repo.find_by_id(&id).await,
+        Ok(Some(task)) if completed.contains(&task.status)
+    );
 
     match repo.update_task(&id, request).await {
-        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Ok(task) => {
+            let task_value = serde_json::to_value(&task).unwrap_or_default();
+            if completed.contains(&task.status) && !was_completed {
+                github::spawn_close_linked_issue(db.clone(), task.id.clone());
+                webhooks::emit(
+                    db,
+                    "task.completed",
+                    serde_json::json!({ "task": task_value }),
+                );
+            }
+            Ok(task_value)
+        }
         Err(e) => Err(format!("Failed to update task: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn delete_task(id: String) -> Result<String, String> {
+async fn add_task_actual_time(id: String, minutes: i32) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskRepository::new(db);
+
+    match repo.add_actual_time(&id, minutes).await {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to add actual time: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn reorder_tasks(task_ids: Vec<String>) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskRepository::new(db);
+
+    match repo.reorder_tasks(&task_ids).await {
+        Ok(count) => Ok(format!("Reordered {} tasks", count)),
+        Err(e) => Err(format!("Failed to reorder tasks: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn reorder_task(
+    task_id: String,
+    before_id: Option<String>,
+    after_id: Option<String>,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
     let repo = TaskRepository::new(db);
 
+    match repo
+        .reorder_task(&task_id, before_id.as_deref(), after_id.as_deref())
+        .await
+    {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to reorder task: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_task(id: String, source: Option<String>) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let attachment_repo = get_attachment_repository(db.clone())?;
+    let attachments = attachment_repo
+        .find_by_task(&id)
+        .await
+        .map_err(|e| format!("Failed to get task attachments: {}", e))?;
+    let repo = task_repository_for(db, source)?;
+
     match repo.delete_task(&id).await {
-        Ok(_) => Ok("Task deleted successfully".to_string()),
+        Ok(_) => {
+            attachment_repo.remove_files(&attachments).await;
+            Ok("Task deleted successfully".to_string())
+        }
         Err(e) => Err(format!("Failed to delete task: {}", e)),
     }
 }
@@ -261,1795 +482,5711 @@ async fn get_task_dependents(task_id: String) -> Result<Vec<serde_json::Value>,
     }
 }
 
+/// Relate two tasks, read as "`task_id` `relation_type` `related_id`"
 #[tauri::command]
-async fn get_task_stats() -> Result<TaskStats, String> {
+async fn add_task_relation(
+    task_id: String,
+    related_id: String,
+    relation_type: String,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
     let repo = TaskRepository::new(db);
 
-    match repo.get_task_stats().await {
-        Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to get task stats: {}", e)),
+    match repo
+        .add_relation(&task_id, &related_id, &relation_type)
+        .await
+    {
+        Ok(relation) => Ok(serde_json::to_value(relation).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to add task relation: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn search_tasks(query: String) -> Result<Vec<serde_json::Value>, String> {
+async fn remove_task_relation(
+    task_id: String,
+    related_id: String,
+    relation_type: String,
+) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
     let repo = TaskRepository::new(db);
 
-    match repo.search_tasks(&query).await {
-        Ok(tasks) => Ok(tasks
-            .into_iter()
-            .map(|t| serde_json::to_value(t).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to search tasks: {}", e)),
+    match repo
+        .remove_relation(&task_id, &related_id, &relation_type)
+        .await
+    {
+        Ok(_) => Ok("Task relation removed successfully".to_string()),
+        Err(e) => Err(format!("Failed to remove task relation: {}", e)),
     }
 }
 
-// ============================================================================
-// Periodic Task Management Commands
-// ============================================================================
-
+/// Tasks related to a task in either direction, optionally only those of `relation_type`
 #[tauri::command]
-async fn create_periodic_task_template(
-    request: CreatePeriodicTaskTemplateRequest,
-) -> Result<serde_json::Value, String> {
+async fn get_task_relations(
+    task_id: String,
+    relation_type: Option<String>,
+) -> Result<Vec<TaskRelation>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.create_template(request).await {
-        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to create periodic task template: {}", e)),
+    match repo.get_relations(&task_id, relation_type.as_deref()).await {
+        Ok(relations) => Ok(relations),
+        Err(e) => Err(format!("Failed to get task relations: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_periodic_task_template(id: String) -> Result<Option<serde_json::Value>, String> {
+async fn validate_dependency_graph() -> Result<DependencyGraphReport, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.find_by_id(&id).await {
-        Ok(template) => Ok(template.map(|t| serde_json::to_value(t).unwrap_or_default())),
-        Err(e) => Err(format!("Failed to get periodic task template: {}", e)),
+    match repo.validate_dependency_graph().await {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to validate dependency graph: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_all_periodic_task_templates() -> Result<Vec<serde_json::Value>, String> {
+async fn get_dependency_schedule(
+    task_list_id: Option<String>,
+) -> Result<DependencySchedule, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let scheduler = DependencyScheduler::new(db);
 
-    match repo.find_all().await {
-        Ok(templates) => Ok(templates
-            .into_iter()
-            .map(|t| serde_json::to_value(t).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get periodic task templates: {}", e)),
+    match scheduler.compute_schedule(task_list_id.as_deref()).await {
+        Ok(schedule) => Ok(schedule),
+        Err(e) => Err(format!("Failed to compute dependency schedule: {}", e)),
     }
 }
 
+/// Scheduled work per day (`start_date` is `YYYY-MM-DD`, local time) against the working time
+/// available each day, with suggested moves for overloaded days
 #[tauri::command]
-async fn get_active_periodic_task_templates() -> Result<Vec<serde_json::Value>, String> {
+async fn get_workload_report(
+    start_date: String,
+    days: u32,
+    capacity_minutes: Option<i64>,
+    task_list_id: Option<String>,
+) -> Result<WorkloadReport, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
-
-    match repo.find_active().await {
-        Ok(templates) => Ok(templates
-            .into_iter()
-            .map(|t| serde_json::to_value(t).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get active periodic task templates: {}", e)),
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+    let balancer = WorkloadBalancer::new(db);
+
+    let start_date = chrono::NaiveDate::parse_from_str(&start_date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date: {}", e))?;
+
+    match balancer
+        .compute_report(
+            start_date,
+            days,
+            capacity_minutes.unwrap_or_else(|| working_hours.daily_minutes()),
+            &working_hours,
+            task_list_id.as_deref(),
+            &chrono::Local,
+        )
+        .await
+    {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to compute workload report: {}", e)),
     }
 }
 
+/// Proposed schedule for the rest of today from working hours, task priorities, estimates and
+/// dependencies
 #[tauri::command]
-async fn get_templates_needing_generation() -> Result<Vec<serde_json::Value>, String> {
+async fn generate_daily_plan(task_list_id: Option<String>) -> Result<DailyPlan, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
-
-    let current_time = chrono::Utc::now();
-    match repo.find_templates_needing_generation(current_time).await {
-        Ok(templates) => Ok(templates
-            .into_iter()
-            .map(|t| serde_json::to_value(t).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get templates needing generation: {}", e)),
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+    let planner = DailyPlanner::new(db);
+
+    match planner
+        .generate_plan(
+            &working_hours,
+            task_list_id.as_deref(),
+            chrono::Utc::now(),
+            &chrono::Local,
+        )
+        .await
+    {
+        Ok(plan) => Ok(plan),
+        Err(e) => Err(format!("Failed to generate daily plan: {}", e)),
     }
 }
 
+/// Estimated against actual time of the tasks completed in a period, by tag, list, priority and
+/// month
 #[tauri::command]
-async fn update_periodic_task_template(
-    id: String,
-    request: UpdatePeriodicTaskTemplateRequest,
-) -> Result<serde_json::Value, String> {
+async fn get_estimation_report(
+    start_date: String,
+    end_date: String,
+) -> Result<EstimationReport, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let analyzer = EstimationAnalyzer::new(db);
 
-    match repo.update_template(&id, request).await {
-        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to update periodic task template: {}", e)),
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match analyzer.compute_report(start, end, &chrono::Local).await {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to compute estimation report: {}", e)),
     }
 }
 
+/// Backlog tasks that are stale, high priority or blocking others, ranked for grooming
+///
+/// With `create_review_task`, the queue is also written to a weekly "Review backlog" task.
 #[tauri::command]
-async fn delete_periodic_task_template(id: String) -> Result<String, String> {
+async fn groom_backlog(
+    rules: Option<GroomingRules>,
+    create_review_task: Option<bool>,
+) -> Result<GroomingQueue, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let groomer = BacklogGroomer::new(db);
 
-    match repo.delete_template(&id).await {
-        Ok(_) => Ok("Periodic task template deleted successfully".to_string()),
-        Err(e) => Err(format!("Failed to delete periodic task template: {}", e)),
+    match groomer
+        .groom(
+            &rules.unwrap_or_default(),
+            create_review_task.unwrap_or(false),
+        )
+        .await
+    {
+        Ok(queue) => Ok(queue),
+        Err(e) => Err(format!("Failed to groom backlog: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_template_instances(template_id: String) -> Result<Vec<serde_json::Value>, String> {
+async fn get_task_stats() -> Result<TaskStats, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.get_template_instances(&template_id).await {
-        Ok(instances) => Ok(instances
-            .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get template instances: {}", e)),
+    match repo.get_task_stats().await {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get task stats: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn count_template_instances(template_id: String) -> Result<u64, String> {
+async fn search_tasks(query: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.count_template_instances(&template_id).await {
-        Ok(count) => Ok(count),
-        Err(e) => Err(format!("Failed to count template instances: {}", e)),
+    match repo.search_tasks(&query).await {
+        Ok(tasks) => Ok(tasks
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to search tasks: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn calculate_next_generation_date(
-    current_date: String,
-    recurrence_type: String,
-    interval: i32,
-    unit: Option<String>,
-) -> Result<String, String> {
+async fn create_subtask(
+    parent_task_id: String,
+    request: CreateTaskRequest,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
-
-    let current = chrono::DateTime::parse_from_rfc3339(&current_date)
-        .map_err(|e| format!("Invalid current date: {}", e))?
-        .with_timezone(&chrono::Utc);
+    let repo = TaskRepository::new(db);
 
-    match repo.calculate_next_generation_date(current, &recurrence_type, interval, unit.as_deref()) {
-        Ok(next_date) => Ok(next_date.to_rfc3339()),
-        Err(e) => Err(format!("Failed to calculate next generation date: {}", e)),
+    match repo.create_subtask(&parent_task_id, request).await {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create subtask: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_periodic_task_stats() -> Result<PeriodicTaskStats, String> {
+async fn get_subtasks(task_id: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = PeriodicTaskRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.get_periodic_task_stats().await {
-        Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to get periodic task stats: {}", e)),
+    match repo.find_subtasks(&task_id).await {
+        Ok(tasks) => Ok(tasks
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get subtasks: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn generate_pending_instances() -> Result<Vec<serde_json::Value>, String> {
+async fn get_task_tree(task_id: String) -> Result<Option<TaskTreeNode>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let engine = TaskGenerationEngine::new(db);
+    let repo = TaskRepository::new(db);
 
-    match engine.generate_pending_instances().await {
-        Ok(instances) => Ok(instances
-            .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to generate pending instances: {}", e)),
+    match repo.get_task_tree(&task_id).await {
+        Ok(tree) => Ok(tree),
+        Err(e) => Err(format!("Failed to get task tree: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn generate_instance_from_template(#[allow(non_snake_case)] templateId: String) -> Result<serde_json::Value, String> {
-    let template_id = templateId; // Convert to snake_case for Rust convention
+async fn reparent_task(
+    task_id: String,
+    new_parent_id: Option<String>,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let engine = TaskGenerationEngine::new(db);
+    let repo = TaskRepository::new(db);
 
-    match engine.generate_instance_from_template(&template_id).await {
-        Ok(instance) => Ok(serde_json::to_value(instance).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to generate instance from template: {}", e)),
+    match repo.reparent_task(&task_id, new_parent_id.as_deref()).await {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to reparent task: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn check_and_generate_instances() -> Result<Vec<serde_json::Value>, String> {
+async fn filter_tasks(filter: TaskFilter) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let engine = TaskGenerationEngine::new(db);
+    let repo = TaskRepository::new(db);
 
-    match engine.check_and_generate_instances().await {
-        Ok(instances) => Ok(instances
+    match repo.find_by_filter(&filter).await {
+        Ok(tasks) => Ok(tasks
             .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
             .collect()),
-        Err(e) => Err(format!("Failed to check and generate instances: {}", e)),
+        Err(e) => Err(format!("Failed to filter tasks: {}", e)),
     }
 }
 
-// ============================================================================
-// Thread Management Commands
-// ============================================================================
-
 #[tauri::command]
-async fn create_thread(request: CreateThreadRequest) -> Result<serde_json::Value, String> {
+async fn get_priority_matrix(task_list_id: Option<String>) -> Result<PriorityMatrix, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.create_thread(request).await {
-        Ok(thread) => Ok(serde_json::to_value(thread).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to create thread: {}", e)),
+    match repo.get_priority_matrix(task_list_id.as_deref()).await {
+        Ok(matrix) => Ok(matrix),
+        Err(e) => Err(format!("Failed to get priority matrix: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_thread(id: String) -> Result<Option<serde_json::Value>, String> {
+async fn get_recommended_tasks(limit: Option<usize>) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.find_by_id(&id).await {
-        Ok(thread) => Ok(thread.map(|t| serde_json::to_value(t).unwrap_or_default())),
-        Err(e) => Err(format!("Failed to get thread: {}", e)),
+    match repo.recommend_tasks(limit.unwrap_or(5)).await {
+        Ok(tasks) => Ok(tasks
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get recommended tasks: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_all_threads() -> Result<Vec<serde_json::Value>, String> {
+async fn archive_task(id: String) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.find_all().await {
-        Ok(threads) => Ok(threads
-            .into_iter()
-            .map(|t| serde_json::to_value(t).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get threads: {}", e)),
+    match repo.archive_task(&id).await {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to archive task: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_threads_by_task(#[allow(non_snake_case)] taskId: String) -> Result<Vec<serde_json::Value>, String> {
+async fn unarchive_task(id: String) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.find_by_task_id(&taskId).await {
-        Ok(threads) => Ok(threads
-            .into_iter()
-            .map(|t| serde_json::to_value(t).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get threads by task: {}", e)),
+    match repo.unarchive_task(&id).await {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to unarchive task: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_threads_by_date(date: String) -> Result<Vec<serde_json::Value>, String> {
+async fn get_archived_tasks(
+    task_list_id: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = TaskRepository::new(db);
 
-    match repo.find_by_date(&date).await {
-        Ok(threads) => Ok(threads
+    match repo.find_archived(task_list_id.as_deref()).await {
+        Ok(tasks) => Ok(tasks
             .into_iter()
             .map(|t| serde_json::to_value(t).unwrap_or_default())
             .collect()),
-        Err(e) => Err(format!("Failed to get threads by date: {}", e)),
+        Err(e) => Err(format!("Failed to get archived tasks: {}", e)),
     }
 }
 
+// ============================================================================
+// Periodic Task Management Commands
+// ============================================================================
+
 #[tauri::command]
-async fn update_thread(
-    id: String,
-    request: UpdateThreadRequest,
+async fn create_periodic_task_template(
+    request: CreatePeriodicTaskTemplateRequest,
 ) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.update_thread(&id, request).await {
-        Ok(thread) => Ok(serde_json::to_value(thread).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to update thread: {}", e)),
+    match repo.create_template(request).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create periodic task template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn delete_thread(id: String) -> Result<String, String> {
+async fn get_periodic_task_template(id: String) -> Result<Option<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.delete_thread(&id).await {
-        Ok(_) => Ok("Thread deleted successfully".to_string()),
-        Err(e) => Err(format!("Failed to delete thread: {}", e)),
+    match repo.find_by_id(&id).await {
+        Ok(template) => Ok(template.map(|t| serde_json::to_value(t).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get periodic task template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn create_thread_message(
-    request: CreateThreadMessageRequest,
-) -> Result<serde_json::Value, String> {
+async fn get_all_periodic_task_templates() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.create_message(request).await {
-        Ok(message) => Ok(serde_json::to_value(message).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to create thread message: {}", e)),
+    match repo.find_all().await {
+        Ok(templates) => Ok(templates
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get periodic task templates: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_thread_messages(thread_id: String) -> Result<Vec<serde_json::Value>, String> {
+async fn get_active_periodic_task_templates() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.find_messages(&thread_id).await {
-        Ok(messages) => Ok(messages
+    match repo.find_active().await {
+        Ok(templates) => Ok(templates
             .into_iter()
-            .map(|m| serde_json::to_value(m).unwrap_or_default())
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
             .collect()),
-        Err(e) => Err(format!("Failed to get thread messages: {}", e)),
+        Err(e) => Err(format!("Failed to get active periodic task templates: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_thread_message(id: String) -> Result<Option<serde_json::Value>, String> {
+async fn get_templates_needing_generation() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.find_message_by_id(&id).await {
-        Ok(message) => Ok(message.map(|m| serde_json::to_value(m).unwrap_or_default())),
-        Err(e) => Err(format!("Failed to get thread message: {}", e)),
+    let current_time = chrono::Utc::now();
+    match repo.find_templates_needing_generation(current_time).await {
+        Ok(templates) => Ok(templates
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get templates needing generation: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn update_thread_message(
+async fn update_periodic_task_template(
     id: String,
-    user_feedback: Option<serde_json::Value>,
+    request: UpdatePeriodicTaskTemplateRequest,
 ) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.update_message(&id, user_feedback).await {
-        Ok(message) => Ok(serde_json::to_value(message).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to update thread message: {}", e)),
+    match repo.update_template(&id, request).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update periodic task template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn delete_thread_message(id: String) -> Result<String, String> {
+async fn delete_periodic_task_template(id: String) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.delete_message(&id).await {
-        Ok(_) => Ok("Thread message deleted successfully".to_string()),
-        Err(e) => Err(format!("Failed to delete thread message: {}", e)),
+    match repo.delete_template(&id).await {
+        Ok(_) => Ok("Periodic task template deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete periodic task template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_thread_statistics() -> Result<ThreadStatistics, String> {
+async fn pause_periodic_template(id: String, until: String) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = ThreadRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.get_statistics().await {
-        Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to get thread statistics: {}", e)),
+    let until = chrono::DateTime::parse_from_rfc3339(&until)
+        .map_err(|e| format!("Invalid pause end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.pause_template(&id, until).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to pause periodic task template: {}", e)),
     }
 }
 
-// ============================================================================
-// Time Tracking Commands
-// ============================================================================
-
 #[tauri::command]
-async fn create_time_session(
-    request: CreateTimeSessionRequest,
-) -> Result<serde_json::Value, String> {
+async fn resume_periodic_template(id: String) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.create_session(request).await {
-        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to create time session: {}", e)),
+    match repo.resume_template(&id).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to resume periodic task template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_time_session(id: String) -> Result<Option<serde_json::Value>, String> {
+async fn skip_next_occurrence(template_id: String) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.find_by_id(&id).await {
-        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
-        Err(e) => Err(format!("Failed to get time session: {}", e)),
+    match repo.skip_next_occurrence(&template_id).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to skip next occurrence: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_active_session(task_id: String) -> Result<Option<serde_json::Value>, String> {
+async fn get_template_instances(template_id: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.find_active_session(&task_id).await {
-        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
-        Err(e) => Err(format!("Failed to get active session: {}", e)),
+    match repo.get_template_instances(&template_id).await {
+        Ok(instances) => Ok(instances
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get template instances: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_any_active_session() -> Result<Option<serde_json::Value>, String> {
+async fn count_template_instances(template_id: String) -> Result<u64, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.find_any_active_session().await {
-        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
-        Err(e) => Err(format!("Failed to get any active session: {}", e)),
+    match repo.count_template_instances(&template_id).await {
+        Ok(count) => Ok(count),
+        Err(e) => Err(format!("Failed to count template instances: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_task_sessions(task_id: String) -> Result<Vec<serde_json::Value>, String> {
+async fn calculate_next_generation_date(
+    current_date: String,
+    recurrence_type: String,
+    interval: i32,
+    unit: Option<String>,
+    rule: Option<String>,
+) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.find_sessions_for_task(&task_id).await {
-        Ok(sessions) => Ok(sessions
-            .into_iter()
-            .map(|s| serde_json::to_value(s).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get task sessions: {}", e)),
+    let current = chrono::DateTime::parse_from_rfc3339(&current_date)
+        .map_err(|e| format!("Invalid current date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.calculate_next_generation_date(
+        current,
+        &recurrence_type,
+        interval,
+        unit.as_deref(),
+        rule.as_deref(),
+    ) {
+        Ok(next_date) => Ok(next_date.to_rfc3339()),
+        Err(e) => Err(format!("Failed to calculate next generation date: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_sessions_between(
-    start_date: String,
-    end_date: String,
-) -> Result<Vec<serde_json::Value>, String> {
+async fn preview_recurrence_dates(
+    request: RecurrencePreviewRequest,
+) -> Result<Vec<String>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
-
-    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
-        .map_err(|e| format!("Invalid start date: {}", e))?
-        .with_timezone(&chrono::Utc);
-    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
-        .map_err(|e| format!("Invalid end date: {}", e))?
-        .with_timezone(&chrono::Utc);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.find_sessions_between(start, end).await {
-        Ok(sessions) => Ok(sessions
-            .into_iter()
-            .map(|s| serde_json::to_value(s).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get sessions between dates: {}", e)),
+    match repo.preview_recurrence_dates(request) {
+        Ok(dates) => Ok(dates.into_iter().map(|date| date.to_rfc3339()).collect()),
+        Err(e) => Err(format!("Failed to preview recurrence dates: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn update_time_session(
-    id: String,
-    request: UpdateTimeSessionRequest,
-) -> Result<serde_json::Value, String> {
+async fn get_periodic_task_stats() -> Result<PeriodicTaskStats, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = PeriodicTaskRepository::new(db);
 
-    match repo.update_session(&id, request).await {
-        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to update time session: {}", e)),
+    match repo.get_periodic_task_stats().await {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get periodic task stats: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn stop_time_session(id: String, notes: Option<String>) -> Result<serde_json::Value, String> {
+async fn generate_pending_instances() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+    let engine = TaskGenerationEngine::new(db).with_working_hours(working_hours);
 
-    match repo.stop_session(&id, notes).await {
-        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to stop time session: {}", e)),
+    match engine.generate_pending_instances().await {
+        Ok(instances) => Ok(instances
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to generate pending instances: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn pause_time_session(id: String) -> Result<serde_json::Value, String> {
+async fn generate_instance_from_template(#[allow(non_snake_case)] templateId: String) -> Result<serde_json::Value, String> {
+    let template_id = templateId; // Convert to snake_case for Rust convention
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+    let engine = TaskGenerationEngine::new(db).with_working_hours(working_hours);
 
-    match repo.pause_session(&id).await {
-        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to pause time session: {}", e)),
+    match engine.generate_instance_from_template(&template_id).await {
+        Ok(instance) => Ok(serde_json::to_value(instance).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to generate instance from template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn resume_time_session(id: String) -> Result<serde_json::Value, String> {
+async fn check_and_generate_instances() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+    let engine = TaskGenerationEngine::new(db).with_working_hours(working_hours);
 
-    match repo.resume_session(&id).await {
-        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to resume time session: {}", e)),
+    match engine.check_and_generate_instances().await {
+        Ok(instances) => Ok(instances
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to check and generate instances: {}", e)),
     }
 }
 
+// ============================================================================
+// Thread Management Commands
+// ============================================================================
+
 #[tauri::command]
-async fn delete_time_session(id: String) -> Result<String, String> {
+async fn create_thread(request: CreateThreadRequest) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.delete_session(&id).await {
-        Ok(_) => Ok("Time session deleted successfully".to_string()),
-        Err(e) => Err(format!("Failed to delete time session: {}", e)),
+    match repo.create_thread(request).await {
+        Ok(thread) => Ok(serde_json::to_value(thread).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create thread: {}", e)),
     }
 }
 
+/// Create a thread along with past threads about the same thing, so the conversation can
+/// be resumed instead; `text` defaults to the assigned task's title and description
 #[tauri::command]
-async fn get_time_stats(start_date: String, end_date: String) -> Result<TimeStats, String> {
+async fn create_thread_with_similar(
+    request: CreateThreadRequest,
+    text: Option<String>,
+) -> Result<CreatedThread, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
-
-    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
-        .map_err(|e| format!("Invalid start date: {}", e))?
-        .with_timezone(&chrono::Utc);
-    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
-        .map_err(|e| format!("Invalid end date: {}", e))?
-        .with_timezone(&chrono::Utc);
+    let similarity = ThreadSimilarity::new(db);
 
-    match repo.get_time_stats(start, end).await {
-        Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to get time stats: {}", e)),
+    match similarity
+        .create_thread_checked(request, text.as_deref())
+        .await
+    {
+        Ok(created) => Ok(created),
+        Err(e) => Err(format!("Failed to create thread: {}", e)),
     }
 }
 
+/// Past threads closest in meaning to `text`, best match first
 #[tauri::command]
-async fn get_task_total_time(task_id: String) -> Result<i64, String> {
+async fn get_similar_threads(
+    text: String,
+    exclude_thread_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<SimilarThread>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let similarity = ThreadSimilarity::new(db);
 
-    match repo.get_task_total_time(&task_id).await {
-        Ok(total_time) => Ok(total_time),
-        Err(e) => Err(format!("Failed to get task total time: {}", e)),
+    match similarity
+        .find_similar(&text, exclude_thread_id.as_deref(), limit, None)
+        .await
+    {
+        Ok(threads) => Ok(threads),
+        Err(e) => Err(format!("Failed to find similar threads: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_recent_sessions(limit: u64) -> Result<Vec<serde_json::Value>, String> {
+async fn get_thread(id: String) -> Result<Option<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.get_recent_sessions(limit).await {
-        Ok(sessions) => Ok(sessions
-            .into_iter()
-            .map(|s| serde_json::to_value(s).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get recent sessions: {}", e)),
+    match repo.find_by_id(&id).await {
+        Ok(thread) => Ok(thread.map(|t| serde_json::to_value(t).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get thread: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_sessions_with_tasks(
-    start_date: String,
-    end_date: String,
-) -> Result<Vec<serde_json::Value>, String> {
+async fn get_all_threads() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = TimeTrackingRepository::new(db);
-
-    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
-        .map_err(|e| format!("Invalid start date: {}", e))?
-        .with_timezone(&chrono::Utc);
-    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
-        .map_err(|e| format!("Invalid end date: {}", e))?
-        .with_timezone(&chrono::Utc);
+    let repo = ThreadRepository::new(db);
 
-    match repo.get_sessions_with_tasks(start, end).await {
-        Ok(sessions_with_tasks) => Ok(sessions_with_tasks
+    match repo.find_all().await {
+        Ok(threads) => Ok(threads
             .into_iter()
-            .map(|(session, task)| {
-                serde_json::json!({
-                    "session": session,
-                    "task": task
-                })
-            })
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
             .collect()),
-        Err(e) => Err(format!("Failed to get sessions with tasks: {}", e)),
+        Err(e) => Err(format!("Failed to get threads: {}", e)),
     }
 }
 
-// ============================================================================
-// AI Interaction Commands
-// ============================================================================
-
 #[tauri::command]
-async fn create_ai_interaction(
-    request: CreateAiInteractionRequest,
-) -> Result<serde_json::Value, String> {
+async fn get_threads_by_task(#[allow(non_snake_case)] taskId: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.create_interaction(request).await {
-        Ok(interaction) => Ok(serde_json::to_value(interaction).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to create AI interaction: {}", e)),
+    match repo.find_by_task_id(&taskId).await {
+        Ok(threads) => Ok(threads
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get threads by task: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_ai_interaction(id: String) -> Result<Option<serde_json::Value>, String> {
+async fn get_threads_by_date(date: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.find_by_id(&id).await {
-        Ok(interaction) => Ok(interaction.map(|i| serde_json::to_value(i).unwrap_or_default())),
-        Err(e) => Err(format!("Failed to get AI interaction: {}", e)),
+    match repo.find_by_date(&date).await {
+        Ok(threads) => Ok(threads
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get threads by date: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_all_ai_interactions(
-    limit: Option<u64>,
-    offset: Option<u64>,
-) -> Result<Vec<serde_json::Value>, String> {
+async fn update_thread(
+    id: String,
+    request: UpdateThreadRequest,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.find_all(limit, offset).await {
-        Ok(interactions) => Ok(interactions
-            .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get AI interactions: {}", e)),
+    match repo.update_thread(&id, request).await {
+        Ok(thread) => Ok(serde_json::to_value(thread).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update thread: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_ai_interactions_between(
-    start_date: String,
-    end_date: String,
-) -> Result<Vec<serde_json::Value>, String> {
+async fn update_thread_summary(
+    id: String,
+    summary: String,
+    summarized_until: chrono::DateTime<chrono::Utc>,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
-
-    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
-        .map_err(|e| format!("Invalid start date: {}", e))?
-        .with_timezone(&chrono::Utc);
-    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
-        .map_err(|e| format!("Invalid end date: {}", e))?
-        .with_timezone(&chrono::Utc);
+    let repo = ThreadRepository::new(db);
 
-    match repo.find_interactions_between(start, end).await {
-        Ok(interactions) => Ok(interactions
-            .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!(
-            "Failed to get AI interactions between dates: {}",
-            e
-        )),
+    match repo.update_summary(&id, summary, summarized_until).await {
+        Ok(thread) => Ok(serde_json::to_value(thread).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update thread summary: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn search_ai_interactions(query: String) -> Result<Vec<serde_json::Value>, String> {
+async fn delete_thread(id: String) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.search_interactions(&query).await {
-        Ok(interactions) => Ok(interactions
-            .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to search AI interactions: {}", e)),
+    match repo.delete_thread(&id).await {
+        Ok(_) => Ok("Thread deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete thread: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn update_ai_interaction(
-    id: String,
-    request: UpdateAiInteractionRequest,
+async fn create_thread_message(
+    request: CreateThreadMessageRequest,
 ) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.update_interaction(&id, request).await {
-        Ok(interaction) => Ok(serde_json::to_value(interaction).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to update AI interaction: {}", e)),
+    match repo.create_message(request).await {
+        Ok(message) => Ok(serde_json::to_value(message).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create thread message: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn delete_ai_interaction(id: String) -> Result<String, String> {
+async fn get_thread_messages(
+    thread_id: String,
+    limit: Option<u64>,
+    before: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.delete_interaction(&id).await {
-        Ok(_) => Ok("AI interaction deleted successfully".to_string()),
-        Err(e) => Err(format!("Failed to delete AI interaction: {}", e)),
+    // Without a limit the whole thread is returned
+    let result = match limit {
+        Some(limit) => {
+            repo.find_messages_page(&thread_id, limit, before.as_deref())
+                .await
+        }
+        None => repo.find_messages(&thread_id).await,
+    };
+    match result {
+        Ok(messages) => Ok(messages
+            .into_iter()
+            .map(|m| serde_json::to_value(m).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get thread messages: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_ai_stats() -> Result<AiStats, String> {
+async fn search_thread_messages(
+    query: String,
+    thread_id: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.get_ai_stats().await {
-        Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to get AI stats: {}", e)),
+    match repo.search_messages(&query, thread_id.as_deref()).await {
+        Ok(messages) => Ok(messages
+            .into_iter()
+            .map(|m| serde_json::to_value(m).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to search thread messages: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_recent_ai_interactions(limit: u64) -> Result<Vec<serde_json::Value>, String> {
+async fn get_thread_created_tasks(thread_id: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.get_recent_interactions(limit).await {
-        Ok(interactions) => Ok(interactions
+    match repo.find_tasks_created_in_thread(&thread_id).await {
+        Ok(tasks) => Ok(tasks
             .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
             .collect()),
-        Err(e) => Err(format!("Failed to get recent AI interactions: {}", e)),
+        Err(e) => Err(format!("Failed to get tasks created in thread: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn clear_old_ai_interactions(older_than_days: u64) -> Result<u64, String> {
+async fn get_thread_message(id: String) -> Result<Option<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
-
-    let cutoff_date = chrono::Utc::now() - chrono::Duration::days(older_than_days as i64);
+    let repo = ThreadRepository::new(db);
 
-    match repo.clear_old_interactions(cutoff_date).await {
-        Ok(deleted_count) => Ok(deleted_count),
-        Err(e) => Err(format!("Failed to clear old AI interactions: {}", e)),
+    match repo.find_message_by_id(&id).await {
+        Ok(message) => Ok(message.map(|m| serde_json::to_value(m).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get thread message: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_conversation_history(limit: u64) -> Result<Vec<serde_json::Value>, String> {
+async fn update_thread_message(
+    id: String,
+    user_feedback: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
 
-    match repo.get_conversation_history(limit).await {
-        Ok(interactions) => Ok(interactions
-            .into_iter()
-            .map(|i| serde_json::to_value(i).unwrap_or_default())
-            .collect()),
-        Err(e) => Err(format!("Failed to get conversation history: {}", e)),
+    match repo.update_message(&id, user_feedback).await {
+        Ok(message) => Ok(serde_json::to_value(message).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update thread message: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_ai_interaction_log_stats() -> Result<AiLogStorageStats, String> {
+async fn delete_thread_message(id: String) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ThreadRepository::new(db);
+
+    match repo.delete_message(&id).await {
+        Ok(_) => Ok("Thread message deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete thread message: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_thread_statistics() -> Result<ThreadStatistics, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ThreadRepository::new(db);
+
+    match repo.get_statistics().await {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get thread statistics: {}", e)),
+    }
+}
+
+// ============================================================================
+// AI Persona Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_ai_persona(request: CreateAiPersonaRequest) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiPersonaRepository::new(db);
+
+    match repo.create_persona(request).await {
+        Ok(persona) => Ok(serde_json::to_value(persona).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create persona: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_personas() -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiPersonaRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(personas) => Ok(personas
+            .into_iter()
+            .map(|p| serde_json::to_value(p).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get personas: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_persona(id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiPersonaRepository::new(db);
+
+    match repo.find_by_id(&id).await {
+        Ok(persona) => Ok(persona.map(|p| serde_json::to_value(p).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get persona: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_thread_persona(thread_id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiPersonaRepository::new(db);
+
+    match repo.find_for_thread(&thread_id).await {
+        Ok(persona) => Ok(persona.map(|p| serde_json::to_value(p).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get thread persona: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_ai_persona(
+    id: String,
+    request: UpdateAiPersonaRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiPersonaRepository::new(db);
+
+    match repo.update_persona(&id, request).await {
+        Ok(persona) => Ok(serde_json::to_value(persona).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update persona: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_ai_persona(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiPersonaRepository::new(db);
+
+    match repo.delete_persona(&id).await {
+        Ok(_) => Ok("Persona deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete persona: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn set_thread_persona(
+    thread_id: String,
+    persona_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiPersonaRepository::new(db);
+
+    match repo.set_thread_persona(&thread_id, persona_id).await {
+        Ok(thread) => Ok(serde_json::to_value(thread).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to set thread persona: {}", e)),
+    }
+}
+
+// ============================================================================
+// Time Tracking Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_time_session(
+    request: CreateTimeSessionRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.create_session(request).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create time session: {}", e)),
+    }
+}
+
+/// Record a finished session, e.g. time worked away from the timer; it never runs
+#[tauri::command]
+async fn log_time_session(
+    task_id: String,
+    start_time: chrono::DateTime<chrono::Utc>,
+    end_time: chrono::DateTime<chrono::Utc>,
+    notes: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo
+        .log_session(&task_id, start_time, end_time, notes)
+        .await
+    {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to log time session: {}", e)),
+    }
+}
+
+/// Add time worked away from the timer; it may not overlap other sessions
+#[tauri::command]
+async fn add_manual_time_entry(
+    task_id: String,
+    start: chrono::DateTime<chrono::Utc>,
+    end: chrono::DateTime<chrono::Utc>,
+    notes: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.add_manual_entry(&task_id, start, end, notes).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to add time entry: {}", e)),
+    }
+}
+
+/// Move the start and/or end of a session, keeping it clear of other sessions
+#[tauri::command]
+async fn adjust_time_session(
+    id: String,
+    start_time: Option<chrono::DateTime<chrono::Utc>>,
+    end_time: Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.adjust_session_times(&id, start_time, end_time).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to adjust time session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn split_time_session(
+    id: String,
+    at: chrono::DateTime<chrono::Utc>,
+    task_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.split_session(&id, at, task_id).await {
+        Ok(sessions) => Ok(serde_json::to_value(sessions).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to split time session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn merge_time_sessions(ids: Vec<String>) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.merge_sessions(&ids).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to merge time sessions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_time_session(id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.find_by_id(&id).await {
+        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get time session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_active_session(task_id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.find_active_session(&task_id).await {
+        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get active session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_any_active_session() -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.find_any_active_session().await {
+        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get any active session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_sessions(task_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.find_sessions_for_task(&task_id).await {
+        Ok(sessions) => Ok(sessions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get task sessions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_sessions_between(
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.find_sessions_between(start, end).await {
+        Ok(sessions) => Ok(sessions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get sessions between dates: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_time_session(
+    id: String,
+    request: UpdateTimeSessionRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.update_session(&id, request).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update time session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn stop_time_session(id: String, notes: Option<String>) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db.clone());
+
+    match repo.stop_session(&id, notes).await {
+        Ok(session) => {
+            let session = serde_json::to_value(session).unwrap_or_default();
+            webhooks::emit(
+                db,
+                "timer.stopped",
+                serde_json::json!({ "session": session }),
+            );
+            Ok(session)
+        }
+        Err(e) => Err(format!("Failed to stop time session: {}", e)),
+    }
+}
+
+/// Pause a session; `reason` is one of meeting, lunch, interruption or other
+#[tauri::command]
+async fn pause_time_session(
+    id: String,
+    reason: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.pause_session(&id, reason).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to pause time session: {}", e)),
+    }
+}
+
+/// Start a break in a running session; break time is not counted as work
+#[tauri::command]
+async fn start_break(
+    session_id: String,
+    reason: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.start_break(&session_id, reason).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to start break: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn end_break(session_id: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.end_break(&session_id).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to end break: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_working_hours() -> Result<WorkingHours, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.get_working_hours().await {
+        Ok(working_hours) => Ok(working_hours),
+        Err(e) => Err(format!("Failed to get working hours: {}", e)),
+    }
+}
+
+/// Store the working hours that reminders, periodic tasks, workload and productivity reports
+/// follow
+#[tauri::command]
+async fn save_working_hours(working_hours: WorkingHours) -> Result<WorkingHours, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.save_working_hours(working_hours).await {
+        Ok(working_hours) => Ok(working_hours),
+        Err(e) => Err(format!("Failed to save working hours: {}", e)),
+    }
+}
+
+/// Whether now is working time, what is left of the working day and the upcoming holidays
+#[tauri::command]
+async fn get_workday_context() -> Result<WorkdayContext, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.get_working_hours().await {
+        Ok(working_hours) => Ok(working_hours.workday_context(chrono::Utc::now(), &chrono::Local)),
+        Err(e) => Err(format!("Failed to get workday context: {}", e)),
+    }
+}
+
+/// Where unfinished tasks scheduled for a past day go
+#[tauri::command]
+async fn get_rollover_mode() -> Result<RolloverMode, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.get_rollover_mode().await {
+        Ok(mode) => Ok(mode),
+        Err(e) => Err(format!("Failed to get rollover mode: {}", e)),
+    }
+}
+
+/// Roll unfinished tasks over to today, back to the backlog, or not at all
+#[tauri::command]
+async fn save_rollover_mode(mode: RolloverMode) -> Result<RolloverMode, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.save_rollover_mode(mode).await {
+        Ok(mode) => Ok(mode),
+        Err(e) => Err(format!("Failed to save rollover mode: {}", e)),
+    }
+}
+
+/// The user's rewrites of Kira's persona, reasoning, action and final answer prompts
+#[tauri::command]
+async fn get_prompt_templates() -> Result<PromptTemplates, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.get_prompt_templates().await {
+        Ok(templates) => Ok(templates),
+        Err(e) => Err(format!("Failed to get prompt templates: {}", e)),
+    }
+}
+
+/// Store the prompt templates Kira answers with; blank ones use the built-in prompts
+#[tauri::command]
+async fn update_prompt_templates(templates: PromptTemplates) -> Result<PromptTemplates, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.save_prompt_templates(templates).await {
+        Ok(templates) => Ok(templates),
+        Err(e) => Err(format!("Failed to update prompt templates: {}", e)),
+    }
+}
+
+/// Go back to the built-in prompts
+#[tauri::command]
+async fn reset_prompt_templates() -> Result<PromptTemplates, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PreferencesRepository::new(db);
+
+    match repo.reset_prompt_templates().await {
+        Ok(templates) => Ok(templates),
+        Err(e) => Err(format!("Failed to reset prompt templates: {}", e)),
+    }
+}
+
+/// Set a daily or weekly target for tracked time, focus time or completed tasks
+#[tauri::command]
+async fn create_goal(request: CreateGoalRequest) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GoalRepository::new(db);
+
+    match repo.create_goal(request).await {
+        Ok(goal) => Ok(serde_json::to_value(goal).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create goal: {}", e)),
+    }
+}
+
+/// Progress and streaks of one goal, or of every goal when `goal_id` is unset
+#[tauri::command]
+async fn get_goal_progress(goal_id: Option<String>) -> Result<Vec<GoalProgress>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+    let tracker = GoalTracker::new(db);
+
+    match tracker
+        .compute_progress(
+            goal_id.as_deref(),
+            &working_hours,
+            chrono::Utc::now(),
+            &chrono::Local,
+        )
+        .await
+    {
+        Ok(progress) => Ok(progress),
+        Err(e) => Err(format!("Failed to get goal progress: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_goal(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GoalRepository::new(db);
+
+    match repo.delete_goal(&id).await {
+        Ok(_) => Ok("Goal deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete goal: {}", e)),
+    }
+}
+
+/// Count a running session as idle after `minutes` without keyboard or mouse input; 0 turns
+/// idle detection off
+#[tauri::command]
+fn set_idle_threshold(settings: tauri::State<'_, IdleSettings>, minutes: u32) {
+    settings.set_threshold(std::time::Duration::from_secs(minutes as u64 * 60));
+}
+
+/// Whether the machine runs on battery and how much charge is left; none where the platform
+/// doesn't tell
+#[tauri::command]
+fn get_power_state() -> Option<power::PowerState> {
+    power::power_state()
+}
+
+/// Leave idle time the user chose to discard out of a session
+#[tauri::command]
+async fn discard_idle_time(
+    session_id: String,
+    started_at: chrono::DateTime<chrono::Utc>,
+    ended_at: chrono::DateTime<chrono::Utc>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo
+        .record_idle_pause(&session_id, started_at, ended_at)
+        .await
+    {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to discard idle time: {}", e)),
+    }
+}
+
+/// Resume a paused session; with `auto_switch` another running session is stopped first
+#[tauri::command]
+async fn resume_time_session(
+    id: String,
+    auto_switch: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.resume_session(&id, auto_switch.unwrap_or(false)).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to resume time session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_session_pauses(session_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.find_session_pauses(&session_id).await {
+        Ok(pauses) => Ok(pauses
+            .into_iter()
+            .map(|p| serde_json::to_value(p).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get session pauses: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_time_session(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.delete_session(&id).await {
+        Ok(_) => Ok("Time session deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete time session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn complete_subtask_during_session(
+    session_id: String,
+    subtask_id: String,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo
+        .complete_subtask_during_session(&session_id, &subtask_id)
+        .await
+    {
+        Ok(completion) => Ok(serde_json::to_value(completion).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to complete subtask during session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_session_completed_subtasks(
+    session_id: String,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.find_session_completed_subtasks(&session_id).await {
+        Ok(subtasks) => Ok(subtasks
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get session completed subtasks: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_time_stats(start_date: String, end_date: String) -> Result<TimeStats, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.get_time_stats(start, end).await {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get time stats: {}", e)),
+    }
+}
+
+/// Productivity patterns and heatmap data in the documented `ProductivityReport` schema,
+/// meant for external dashboards
+#[tauri::command]
+async fn get_productivity_report(
+    start_date: String,
+    end_date: String,
+) -> Result<ProductivityReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db.clone());
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+
+    match repo
+        .get_productivity_report(start, end, &working_hours, &chrono::Local)
+        .await
+    {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to get productivity report: {}", e)),
+    }
+}
+
+/// Pause and interruption analytics for pauses started between two RFC 3339 dates
+#[tauri::command]
+async fn get_interruption_report(
+    start_date: String,
+    end_date: String,
+) -> Result<InterruptionReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo
+        .get_interruption_report(start, end, &chrono::Local)
+        .await
+    {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to get interruption report: {}", e)),
+    }
+}
+
+/// Work, sessions and completed tasks per day between two RFC 3339 dates, for heatmaps
+#[tauri::command]
+async fn get_daily_time_aggregates(
+    start: String,
+    end: String,
+) -> Result<Vec<DailyTimeAggregate>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.get_daily_aggregates(start, end, &chrono::Local).await {
+        Ok(days) => Ok(days),
+        Err(e) => Err(format!("Failed to get daily time aggregates: {}", e)),
+    }
+}
+
+/// Billable work between two RFC 3339 dates on task lists with an hourly rate
+#[tauri::command]
+async fn get_billing_report(
+    start_date: String,
+    end_date: String,
+    group_by: BillingGroupBy,
+) -> Result<BillingReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo
+        .get_billing_report(start, end, group_by, &chrono::Local)
+        .await
+    {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to get billing report: {}", e)),
+    }
+}
+
+/// The billing report as CSV, for invoices and spreadsheets
+#[tauri::command]
+async fn export_billing_report(
+    start_date: String,
+    end_date: String,
+    group_by: BillingGroupBy,
+) -> Result<String, String> {
+    let report = get_billing_report(start_date, end_date, group_by).await?;
+    Ok(report.to_csv())
+}
+
+/// Finished sessions between two RFC 3339 dates by day and task, as `csv` or `json`
+#[tauri::command]
+async fn export_timesheet(start: String, end: String, format: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    let timesheet = repo
+        .get_timesheet(start, end, &chrono::Local)
+        .await
+        .map_err(|e| format!("Failed to build timesheet: {}", e))?;
+    match format.as_str() {
+        "csv" => Ok(timesheet.to_csv()),
+        "json" => serde_json::to_string_pretty(&timesheet)
+            .map_err(|e| format!("Failed to serialize timesheet: {}", e)),
+        other => Err(format!("Unsupported timesheet format: {}", other)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_total_time(task_id: String) -> Result<i64, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.get_task_total_time(&task_id).await {
+        Ok(total_time) => Ok(total_time),
+        Err(e) => Err(format!("Failed to get task total time: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_recent_sessions(limit: u64) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    match repo.get_recent_sessions(limit).await {
+        Ok(sessions) => Ok(sessions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get recent sessions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_sessions_with_tasks(
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TimeTrackingRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.get_sessions_with_tasks(start, end).await {
+        Ok(sessions_with_tasks) => Ok(sessions_with_tasks
+            .into_iter()
+            .map(|(session, task)| {
+                serde_json::json!({
+                    "session": session,
+                    "task": task
+                })
+            })
+            .collect()),
+        Err(e) => Err(format!("Failed to get sessions with tasks: {}", e)),
+    }
+}
+
+// ============================================================================
+// AI Interaction Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_ai_interaction(
+    request: CreateAiInteractionRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.create_interaction(request).await {
+        Ok(interaction) => Ok(serde_json::to_value(interaction).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create AI interaction: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_interaction(id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.find_by_id(&id).await {
+        Ok(interaction) => Ok(interaction.map(|i| serde_json::to_value(i).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get AI interaction: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_all_ai_interactions(
+    limit: Option<u64>,
+    offset: Option<u64>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.find_all(limit, offset).await {
+        Ok(interactions) => Ok(interactions
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get AI interactions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_interactions_between(
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.find_interactions_between(start, end).await {
+        Ok(interactions) => Ok(interactions
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!(
+            "Failed to get AI interactions between dates: {}",
+            e
+        )),
+    }
+}
+
+#[tauri::command]
+async fn search_ai_interactions(query: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.search_interactions(&query).await {
+        Ok(interactions) => Ok(interactions
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to search AI interactions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_ai_interaction(
+    id: String,
+    request: UpdateAiInteractionRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.update_interaction(&id, request).await {
+        Ok(interaction) => Ok(serde_json::to_value(interaction).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update AI interaction: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_ai_interaction(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.delete_interaction(&id).await {
+        Ok(_) => Ok("AI interaction deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete AI interaction: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_stats() -> Result<AiStats, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.get_ai_stats().await {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get AI stats: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_recent_ai_interactions(limit: u64) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.get_recent_interactions(limit).await {
+        Ok(interactions) => Ok(interactions
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get recent AI interactions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn clear_old_ai_interactions(older_than_days: u64) -> Result<u64, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    let cutoff_date = chrono::Utc::now() - chrono::Duration::days(older_than_days as i64);
+
+    match repo.clear_old_interactions(cutoff_date).await {
+        Ok(deleted_count) => Ok(deleted_count),
+        Err(e) => Err(format!("Failed to clear old AI interactions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_conversation_history(limit: u64) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.get_conversation_history(limit).await {
+        Ok(interactions) => Ok(interactions
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get conversation history: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_interaction_log_stats() -> Result<AiLogStorageStats, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
 
     match repo.get_log_storage_stats().await {
         Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to get AI interaction log stats: {}", e)),
+        Err(e) => Err(format!("Failed to get AI interaction log stats: {}", e)),
+    }
+}
+
+/// Interaction log in the shape the log viewer reads
+fn interaction_log_json(log: InteractionLog) -> serde_json::Value {
+    let InteractionLog { interaction, chain } = log;
+    serde_json::json!({
+        "id": interaction.id,
+        "timestamp": interaction.created_at.to_rfc3339(),
+        "session_id": chain.session_id,
+        "model_type": chain.model_type,
+        "model_info": interaction.model_info.unwrap_or_else(|| "{}".to_string()),
+        "user_message": interaction.message,
+        "system_prompt": null,
+        "context": "{}",
+        "ai_response": interaction.response,
+        "actions": interaction.tools_used.unwrap_or_else(|| "[]".to_string()),
+        "suggestions": "[]",
+        "reasoning": interaction.reasoning.unwrap_or_default(),
+        "response_time": chain.duration,
+        "token_count": interaction.token_count,
+        "error": chain.error,
+        "error_code": chain.error_code,
+        "contains_sensitive_data": false,
+        "data_classification": "public",
+        "created_at": chain.created_at.to_rfc3339(),
+        "updated_at": chain.updated_at.to_rfc3339(),
+    })
+}
+
+/// Tool call of an interaction log's chain in the shape the log viewer reads
+fn tool_execution_log_json(step: database::entities::react_steps::Model) -> serde_json::Value {
+    serde_json::json!({
+        "id": step.id,
+        "interaction_log_id": step.chain_id,
+        "tool_name": step.tool_name,
+        "arguments": step.arguments,
+        "result": step.result,
+        "execution_time": step.duration,
+        "success": step.success,
+        "error": step.error,
+        "created_at": step.created_at.to_rfc3339(),
+    })
+}
+
+#[tauri::command]
+async fn create_ai_interaction_log(
+    request: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    // The frontend sends { request: data }, so we need to get the "request" field
+    // But if that fails, the data might be at the top level (Tauri parameter handling)
+    let request_data = if let Some(nested_request) = request.get("request") {
+        nested_request
+    } else {
+        // Data is at the top level
+        &request
+    };
+
+    // Convert to CreateAiInteractionLogRequest
+    let log_request = CreateAiInteractionLogRequest {
+        session_id: request_data
+            .get("session_id")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        model_type: request_data
+            .get("model_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("gemini")
+            .to_string(),
+        model_info: request_data
+            .get("model_info")
+            .cloned()
+            .unwrap_or(serde_json::json!({})),
+        user_message: request_data
+            .get("user_message")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        system_prompt: request_data
+            .get("system_prompt")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        context: request_data
+            .get("context")
+            .and_then(|v| v.as_str())
+            .unwrap_or("{}")
+            .to_string(),
+        ai_response: request_data
+            .get("ai_response")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        actions: request_data
+            .get("actions")
+            .and_then(|v| v.as_str())
+            .unwrap_or("[]")
+            .to_string(),
+        suggestions: request_data
+            .get("suggestions")
+            .and_then(|v| v.as_str())
+            .unwrap_or("[]")
+            .to_string(),
+        reasoning: request_data
+            .get("reasoning")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        response_time: request_data
+            .get("response_time")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+        token_count: request_data.get("token_count").and_then(|v| v.as_i64()),
+        error: request_data
+            .get("error")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        error_code: request_data
+            .get("error_code")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        contains_sensitive_data: request_data
+            .get("contains_sensitive_data")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        data_classification: request_data
+            .get("data_classification")
+            .and_then(|v| v.as_str())
+            .unwrap_or("internal")
+            .to_string(),
+    };
+
+    let interaction = repo
+        .create_interaction_log(log_request)
+        .await
+        .map_err(|e| format!("Failed to create AI interaction log: {}", e))?;
+    match repo.find_interaction_log(&interaction.id).await {
+        Ok(Some(log)) => Ok(interaction_log_json(log)),
+        Ok(None) => Err("Failed to create AI interaction log: log not found".to_string()),
+        Err(e) => Err(format!("Failed to create AI interaction log: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_interaction_logs(
+    filters: serde_json::Value,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    let filter: InteractionLogFilter = serde_json::from_value(filters)
+        .map_err(|e| format!("Invalid AI interaction log filters: {}", e))?;
+
+    match repo.find_interaction_logs(filter).await {
+        Ok(logs) => Ok(logs.into_iter().map(interaction_log_json).collect()),
+        Err(e) => Err(format!("Failed to get AI interaction logs: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_interaction_log(id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.find_interaction_log(&id).await {
+        Ok(log) => Ok(log.map(interaction_log_json)),
+        Err(e) => Err(format!("Failed to get AI interaction log: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_ai_interaction_log(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.delete_interaction(&id).await {
+        Ok(_) => Ok("Log deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete AI interaction log: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_ai_interaction_log(
+    id: String,
+    request: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    // Extract the request data
+    let request_data = request.get("request").ok_or("Missing request data")?;
+
+    // Convert to UpdateAiInteractionLogRequest
+    let update_request = UpdateAiInteractionLogRequest {
+        ai_response: request_data
+            .get("ai_response")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        actions: request_data
+            .get("actions")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        suggestions: request_data
+            .get("suggestions")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        reasoning: request_data
+            .get("reasoning")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        response_time: request_data.get("response_time").and_then(|v| v.as_i64()),
+        token_count: request_data.get("token_count").and_then(|v| v.as_i64()),
+        error: request_data
+            .get("error")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        error_code: request_data
+            .get("error_code")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        contains_sensitive_data: request_data
+            .get("contains_sensitive_data")
+            .and_then(|v| v.as_bool()),
+        data_classification: request_data
+            .get("data_classification")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+    };
+
+    repo.update_interaction_log(&id, update_request)
+        .await
+        .map_err(|e| format!("Failed to update AI interaction log: {}", e))?;
+    match repo.find_interaction_log(&id).await {
+        Ok(Some(log)) => Ok(interaction_log_json(log)),
+        Ok(None) => Err("Failed to update AI interaction log: log not found".to_string()),
+        Err(e) => Err(format!("Failed to update AI interaction log: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn create_tool_execution_log(
+    request: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    // Extract the request data
+    let request_data = request.get("request").ok_or("Missing request data")?;
+
+    // Convert to CreateToolExecutionLogRequest
+    let tool_request = CreateToolExecutionLogRequest {
+        interaction_log_id: request_data
+            .get("interaction_log_id")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        tool_name: request_data
+            .get("tool_name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        arguments: request_data
+            .get("arguments")
+            .and_then(|v| v.as_str())
+            .unwrap_or("{}")
+            .to_string(),
+        result: request_data
+            .get("result")
+            .and_then(|v| v.as_str())
+            .unwrap_or("{}")
+            .to_string(),
+        execution_time: request_data
+            .get("execution_time")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+        success: request_data
+            .get("success")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        error: request_data
+            .get("error")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+    };
+
+    match repo.create_tool_execution_log(tool_request).await {
+        Ok(step) => Ok(tool_execution_log_json(step)),
+        Err(e) => Err(format!("Failed to create tool execution log: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_tool_execution_logs(
+    interaction_log_id: String,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.find_tool_execution_logs(&interaction_log_id).await {
+        Ok(steps) => Ok(steps.into_iter().map(tool_execution_log_json).collect()),
+        Err(e) => Err(format!("Failed to get tool execution logs: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn clear_all_ai_interaction_logs() -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.delete_all_interactions().await {
+        Ok(deleted_count) => Ok(format!("Cleared {} AI interaction logs", deleted_count)),
+        Err(e) => Err(format!("Failed to clear AI interaction logs: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn cleanup_old_ai_interaction_logs() -> Result<u64, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    // Clean up logs older than 30 days by default
+    let cutoff_date = chrono::Utc::now() - chrono::Duration::days(30);
+
+    match repo.clear_old_interactions(cutoff_date).await {
+        Ok(deleted_count) => Ok(deleted_count),
+        Err(e) => Err(format!("Failed to cleanup old AI interaction logs: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn save_ai_suggestions(
+    suggestions: Vec<CreateAiSuggestionRequest>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiSuggestionRepository::new(db);
+
+    match repo.create_suggestions(suggestions).await {
+        Ok(suggestions) => Ok(suggestions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to save AI suggestions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_active_ai_suggestions(
+    suggestion_type: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiSuggestionRepository::new(db);
+
+    match repo
+        .find_active(suggestion_type.as_deref(), chrono::Utc::now())
+        .await
+    {
+        Ok(suggestions) => Ok(suggestions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get active AI suggestions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_interaction_suggestions(
+    interaction_log_id: String,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiSuggestionRepository::new(db);
+
+    match repo.find_for_interaction_log(&interaction_log_id).await {
+        Ok(suggestions) => Ok(suggestions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get interaction suggestions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn accept_ai_suggestion(id: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiSuggestionRepository::new(db);
+
+    match repo.accept_suggestion(&id).await {
+        Ok(suggestion) => Ok(serde_json::to_value(suggestion).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to accept AI suggestion: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn dismiss_ai_suggestion(id: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiSuggestionRepository::new(db);
+
+    match repo.dismiss_suggestion(&id).await {
+        Ok(suggestion) => Ok(serde_json::to_value(suggestion).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to dismiss AI suggestion: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn expire_ai_suggestions() -> Result<u64, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiSuggestionRepository::new(db);
+
+    match repo.expire_stale(chrono::Utc::now()).await {
+        Ok(expired_count) => Ok(expired_count),
+        Err(e) => Err(format!("Failed to expire AI suggestions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn export_ai_interaction_logs(
+    _filters: serde_json::Value,
+    format: String,
+) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    // For now, just export all recent interactions
+    match repo.get_recent_interactions(1000).await {
+        Ok(interactions) => {
+            if format == "csv" {
+                // Simple CSV export
+                let mut csv = "id,timestamp,message,response,action_taken,reasoning\n".to_string();
+                for interaction in interactions {
+                    csv.push_str(&format!(
+                        "{},{},{},{},{},{}\n",
+                        interaction.id,
+                        interaction.created_at.to_rfc3339(),
+                        interaction.message.replace(',', ";").replace('\n', " "),
+                        interaction.response.replace(',', ";").replace('\n', " "),
+                        interaction
+                            .action_taken
+                            .unwrap_or_default()
+                            .replace(',', ";"),
+                        interaction
+                            .reasoning
+                            .unwrap_or_default()
+                            .replace(',', ";")
+                            .replace('\n', " ")
+                    ));
+                }
+                Ok(csv)
+            } else {
+                // JSON export
+                match serde_json::to_string_pretty(&interactions) {
+                    Ok(json) => Ok(json),
+                    Err(e) => Err(format!("Failed to serialize interactions to JSON: {}", e)),
+                }
+            }
+        }
+        Err(e) => Err(format!("Failed to export AI interaction logs: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn anonymize_ai_interaction_logs(log_ids: Vec<String>) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    let mut anonymized_count = 0;
+
+    for log_id in log_ids {
+        // Update the log to remove sensitive information
+        let update_request = UpdateAiInteractionLogRequest {
+            ai_response: Some("[ANONYMIZED]".to_string()),
+            actions: Some("[]".to_string()),
+            suggestions: Some("[]".to_string()),
+            reasoning: Some("[ANONYMIZED]".to_string()),
+            response_time: None,
+            token_count: None,
+            error: None,
+            error_code: None,
+            contains_sensitive_data: Some(false),
+            data_classification: Some("public".to_string()),
+        };
+
+        match repo.update_interaction_log(&log_id, update_request).await {
+            Ok(_) => anonymized_count += 1,
+            Err(e) => {
+                eprintln!("Failed to anonymize log {}: {}", log_id, e);
+            }
+        }
+    }
+
+    Ok(format!("Anonymized {} logs", anonymized_count))
+}
+
+#[tauri::command]
+async fn redact_sensitive_data(log_id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    // Update the log to redact sensitive data
+    let update_request = UpdateAiInteractionLogRequest {
+        ai_response: None, // Keep response but mark as redacted
+        actions: Some("[]".to_string()),
+        suggestions: Some("[]".to_string()),
+        reasoning: Some("[REDACTED]".to_string()),
+        response_time: None,
+        token_count: None,
+        error: None,
+        error_code: None,
+        contains_sensitive_data: Some(false),
+        data_classification: Some("internal".to_string()),
+    };
+
+    match repo.update_interaction_log(&log_id, update_request).await {
+        Ok(_) => Ok("Sensitive data redacted successfully".to_string()),
+        Err(e) => Err(format!("Failed to redact sensitive data: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn export_conversation_dataset(
+    app: tauri::AppHandle,
+    request: ConversationExportRequest,
+    file_path: String,
+) -> Result<ConversationExportSummary, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let export_service = ConversationExportService::new(db);
+
+    let operation = start_operation(&app, "conversation_export", "Exporting conversations");
+    let result = export_service.export(&request, &file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to export conversations: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn export_calendar(
+    app: tauri::AppHandle,
+    file_path: String,
+    range: CalendarExportRange,
+) -> Result<CalendarExportSummary, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let calendar_service = CalendarExportService::new(db);
+
+    let operation = start_operation(&app, "calendar_export", "Exporting calendar");
+    let result = calendar_service.export(&range, &file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to export calendar: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn sync_caldav(app: tauri::AppHandle, config: CalDavConfig) -> Result<SyncReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let sync_service = SyncService::new(db);
+
+    let operation = start_operation(&app, "caldav_sync", "Syncing with CalDAV");
+    let result = sync_service.sync_caldav(&config).await;
+    operation.finish(&result);
+    match result {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to sync with CalDAV: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_caldav_sync_status(calendar_url: String) -> Result<SyncStatus, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let sync_service = SyncService::new(db);
+    match sync_service.status(&calendar_url).await {
+        Ok(status) => Ok(status),
+        Err(e) => Err(format!("Failed to get CalDAV sync status: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn reset_caldav_sync(calendar_url: String) -> Result<u64, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let sync_service = SyncService::new(db);
+    match sync_service.reset(&calendar_url).await {
+        Ok(count) => Ok(count),
+        Err(e) => Err(format!("Failed to reset CalDAV sync: {}", e)),
+    }
+}
+
+// ============================================================================
+// GitHub Commands
+// ============================================================================
+
+/// The GitHub connection without its token, or null when GitHub isn't connected
+#[tauri::command]
+async fn get_github_settings() -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GitHubRepository::new(db);
+
+    match repo.get_settings().await {
+        Ok(settings) => Ok(settings.map(|settings| {
+            let has_token = !settings.token.is_empty();
+            let mut value = serde_json::to_value(settings).unwrap_or_default();
+            value["has_token"] = serde_json::Value::Bool(has_token);
+            value
+        })),
+        Err(e) => Err(format!("Failed to get GitHub settings: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn save_github_settings(request: SaveGitHubSettingsRequest) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GitHubRepository::new(db);
+
+    match repo.save_settings(request).await {
+        Ok(_) => Ok("GitHub settings saved successfully".to_string()),
+        Err(e) => Err(format!("Failed to save GitHub settings: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_github_settings() -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GitHubRepository::new(db);
+
+    match repo.delete_settings().await {
+        Ok(_) => Ok("GitHub disconnected successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete GitHub settings: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn link_task_to_github_issue(
+    request: LinkGitHubIssueRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GitHubRepository::new(db);
+
+    match repo.link_task(request).await {
+        Ok(link) => Ok(serde_json::to_value(link).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to link task to GitHub issue: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn unlink_task_from_github_issue(task_id: String) -> Result<bool, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GitHubRepository::new(db);
+
+    match repo.unlink_task(&task_id).await {
+        Ok(removed) => Ok(removed),
+        Err(e) => Err(format!("Failed to unlink task from GitHub issue: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_github_link(task_id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = GitHubRepository::new(db);
+
+    match repo.find_link(&task_id).await {
+        Ok(link) => Ok(link.map(|link| serde_json::to_value(link).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get task GitHub link: {}", e)),
+    }
+}
+
+/// Create tasks for the open issues assigned to the user
+#[tauri::command]
+async fn import_github_issues(app: tauri::AppHandle) -> Result<GitHubImportReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let github_service = GitHubService::new(db);
+
+    let operation = start_operation(&app, "github_import", "Importing GitHub issues");
+    let result = github_service.import_assigned_issues().await;
+    operation.finish(&result);
+    match result {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to import GitHub issues: {}", e)),
+    }
+}
+
+/// Bring linked tasks and issues back in line, whichever side changed
+#[tauri::command]
+async fn refresh_github_issues(app: tauri::AppHandle) -> Result<GitHubRefreshReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let github_service = GitHubService::new(db);
+
+    let operation = start_operation(&app, "github_refresh", "Refreshing GitHub issues");
+    let result = github_service.refresh().await;
+    operation.finish(&result);
+    match result {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to refresh GitHub issues: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_interaction_replay(
+    interaction_log_id: String,
+) -> Result<InteractionReplay, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.get_interaction_replay(&interaction_log_id).await {
+        Ok(replay) => Ok(replay),
+        Err(e) => Err(format!("Failed to get AI interaction replay: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn save_ai_replay_comparison(
+    request: CreateReplayComparisonRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.save_replay_comparison(request).await {
+        Ok(comparison) => Ok(serde_json::to_value(comparison).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to save AI replay comparison: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_ai_replay_comparisons(
+    interaction_log_id: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo
+        .find_replay_comparisons(interaction_log_id.as_deref())
+        .await
+    {
+        Ok(comparisons) => Ok(comparisons
+            .into_iter()
+            .map(|c| serde_json::to_value(c).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get AI replay comparisons: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_ai_replay_comparison(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.delete_replay_comparison(&id).await {
+        Ok(_) => Ok("AI replay comparison deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete AI replay comparison: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_prompt_eval_suite() -> Result<Vec<PromptEvalCase>, String> {
+    Ok(evaluation_suite())
+}
+
+#[tauri::command]
+async fn save_prompt_eval_run(
+    request: CreatePromptEvalRunRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.save_prompt_eval_run(request).await {
+        Ok(run) => Ok(serde_json::to_value(run).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to save prompt evaluation run: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_prompt_eval_runs(
+    template_name: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.find_prompt_eval_runs(template_name.as_deref()).await {
+        Ok(runs) => Ok(runs
+            .into_iter()
+            .map(|r| serde_json::to_value(r).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get prompt evaluation runs: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_prompt_eval_run(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.delete_prompt_eval_run(&id).await {
+        Ok(_) => Ok("Prompt evaluation run deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete prompt evaluation run: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn compare_prompt_eval_runs(
+    before_run_id: String,
+    after_run_id: String,
+) -> Result<PromptEvalReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let service = PromptEvalService::new(db);
+
+    match service.compare(&before_run_id, &after_run_id).await {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to compare prompt evaluation runs: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn record_ai_provider_request(
+    request: RecordProviderRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    match repo.record_provider_request(request).await {
+        Ok(metric) => Ok(serde_json::to_value(metric).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to record AI provider request: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_provider_metrics(
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<ProviderMetrics>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = AiRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.get_provider_metrics(start, end).await {
+        Ok(metrics) => Ok(metrics),
+        Err(e) => Err(format!("Failed to get provider metrics: {}", e)),
+    }
+}
+
+/// Compare this month's estimated remote AI spend with the monthly budget (US dollars),
+/// warning once per month at 80% and 100%
+#[tauri::command]
+async fn check_ai_budget(
+    app: tauri::AppHandle,
+    monthly_budget: f64,
+) -> Result<AiBudgetStatus, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    match ai_budget::check_budget(&app, db, monthly_budget).await {
+        Ok(status) => Ok(status),
+        Err(e) => Err(format!("Failed to check AI budget: {}", e)),
+    }
+}
+
+/// Estimated AI spend per provider and model over the current "day", "week" or "month"
+#[tauri::command]
+async fn get_ai_cost_report(period: String) -> Result<AiCostReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    match ai_budget::cost_report(db, &period).await {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to get AI cost report: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_logging_config(config: serde_json::Value) -> Result<serde_json::Value, String> {
+    // For now, just return the updated config
+    // In a real implementation, this would update a settings table
+    let updated_config = serde_json::json!({
+        "enabled": config.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true),
+        "log_level": config.get("log_level").and_then(|v| v.as_str()).unwrap_or("standard"),
+        "retention_days": config.get("retention_days").and_then(|v| v.as_i64()).unwrap_or(30),
+        "max_log_size": config.get("max_log_size").and_then(|v| v.as_i64()).unwrap_or(10485760),
+        "max_log_count": config.get("max_log_count").and_then(|v| v.as_i64()).unwrap_or(10000),
+        "include_system_prompts": config.get("include_system_prompts").and_then(|v| v.as_bool()).unwrap_or(true),
+        "include_tool_executions": config.get("include_tool_executions").and_then(|v| v.as_bool()).unwrap_or(true),
+        "include_performance_metrics": config.get("include_performance_metrics").and_then(|v| v.as_bool()).unwrap_or(true),
+        "auto_cleanup": config.get("auto_cleanup").and_then(|v| v.as_bool()).unwrap_or(true),
+        "export_format": config.get("export_format").and_then(|v| v.as_str()).unwrap_or("json")
+    });
+
+    Ok(updated_config)
+}
+
+#[tauri::command]
+async fn get_logging_config() -> Result<serde_json::Value, String> {
+    // For now, return a default configuration
+    // In a real implementation, this would come from a settings table
+    let default_config = serde_json::json!({
+        "enabled": true,
+        "log_level": "standard",
+        "retention_days": 30,
+        "max_log_size": 10485760,
+        "max_log_count": 10000,
+        "include_system_prompts": true,
+        "include_tool_executions": true,
+        "include_performance_metrics": true,
+        "auto_cleanup": true,
+        "export_format": "json"
+    });
+
+    Ok(default_config)
+}
+
+#[tauri::command]
+async fn clear_all_data() -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    // Clear all tables in the correct order (respecting foreign key constraints)
+
+    // First, clear time sessions (they reference tasks)
+    let time_repo = TimeTrackingRepository::new(db.clone());
+    let time_sessions_deleted = time_repo
+        .delete_all_sessions()
+        .await
+        .map_err(|e| format!("Failed to clear time sessions: {}", e))?;
+
+    // Clear AI interactions
+    let ai_repo = AiRepository::new(db.clone());
+    let ai_interactions_deleted = ai_repo
+        .delete_all_interactions()
+        .await
+        .map_err(|e| format!("Failed to clear AI interactions: {}", e))?;
+
+    // Clear custom field values (they reference tasks)
+    let custom_field_repo = CustomFieldRepository::new(db.clone());
+    custom_field_repo
+        .delete_all_values()
+        .await
+        .map_err(|e| format!("Failed to clear custom field values: {}", e))?;
+
+    // Clear task dependencies first
+    let task_repo = TaskRepository::new(db.clone());
+    let dependencies_deleted = task_repo
+        .delete_all_dependencies()
+        .await
+        .map_err(|e| format!("Failed to clear task dependencies: {}", e))?;
+
+    // Remove attachment files; their rows are cleared with the tasks
+    let attachment_repo = get_attachment_repository(db.clone())?;
+    let attachments = attachment_repo
+        .get_all_attachments()
+        .await
+        .map_err(|e| format!("Failed to get attachments: {}", e))?;
+    attachment_repo.remove_files(&attachments).await;
+
+    // Finally, clear tasks
+    let tasks_deleted = task_repo
+        .delete_all_tasks()
+        .await
+        .map_err(|e| format!("Failed to clear tasks: {}", e))?;
+
+    TagRepository::new(db.clone())
+        .delete_all_tags()
+        .await
+        .map_err(|e| format!("Failed to clear tags: {}", e))?;
+
+    TaskAuditRepository::new(db.clone())
+        .clear_all()
+        .await
+        .map_err(|e| format!("Failed to clear task history: {}", e))?;
+
+    Ok(format!(
+        "Successfully cleared all data: {} tasks, {} time sessions, {} AI interactions, {} dependencies",
+        tasks_deleted, time_sessions_deleted, ai_interactions_deleted, dependencies_deleted
+    ))
+}
+
+#[tauri::command]
+async fn init_database() -> Result<String, String> {
+    match initialize_database().await {
+        Ok(_) => Ok("Database initialized successfully".to_string()),
+        Err(e) => Err(format!("Failed to initialize database: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_database_health() -> Result<DatabaseHealth, String> {
+    match check_database_health().await {
+        Ok(health) => Ok(health),
+        Err(e) => Err(format!("Failed to check database health: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_migration_status_cmd() -> Result<MigrationStatus, String> {
+    match get_migration_status().await {
+        Ok(status) => Ok(status),
+        Err(e) => Err(format!("Failed to get migration status: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn test_migration_compatibility_cmd() -> Result<MigrationTestResult, String> {
+    match test_migration_compatibility().await {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to test migration compatibility: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn run_post_migration_initialization() -> Result<String, String> {
+    match run_post_migration_init().await {
+        Ok(_) => Ok("Post-migration initialization completed successfully".to_string()),
+        Err(e) => Err(format!(
+            "Failed to run post-migration initialization: {}",
+            e
+        )),
+    }
+}
+
+#[tauri::command]
+async fn validate_database_integrity() -> Result<DatabaseIntegrityReport, String> {
+    match validate_db_integrity().await {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to validate database integrity: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_integrity_history(limit: Option<u64>) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = IntegrityReportRepository::new(db);
+
+    match repo.find_history(limit.unwrap_or(52)).await {
+        Ok(reports) => Ok(reports
+            .into_iter()
+            .map(|r| serde_json::to_value(r).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get integrity history: {}", e)),
+    }
+}
+
+// ============================================================================
+// Task List Management Commands
+// ============================================================================
+
+/// Task lists in display order; archived lists only when `include_archived` is set
+#[tauri::command]
+async fn get_all_task_lists(
+    include_archived: Option<bool>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    let task_lists = if include_archived.unwrap_or(false) {
+        task_list_repo.find_all_task_lists().await
+    } else {
+        task_list_repo.find_active_task_lists().await
+    };
+    match task_lists {
+        Ok(task_lists) => {
+            let json_task_lists: Vec<serde_json::Value> = task_lists
+                .into_iter()
+                .map(|task_list| serde_json::to_value(task_list).unwrap())
+                .collect();
+            Ok(json_task_lists)
+        }
+        Err(e) => Err(format!("Failed to get task lists: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn create_task_list(request: CreateTaskListRequest) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.create_task_list(request.name).await {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap()),
+        Err(e) => Err(format!("Failed to create task list: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_task_list(
+    id: String,
+    request: UpdateTaskListRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.update_task_list(&id, request).await {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap()),
+        Err(e) => Err(format!("Failed to update task list: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn reorder_task_list(
+    id: String,
+    before_id: Option<String>,
+    after_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo
+        .reorder_task_list(&id, before_id.as_deref(), after_id.as_deref())
+        .await
+    {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to reorder task list: {}", e)),
+    }
+}
+
+/// Put task lists in the given order; lists left out follow in their current order
+#[tauri::command]
+async fn reorder_task_lists(ids: Vec<String>) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.reorder_task_lists(&ids).await {
+        Ok(task_lists) => Ok(task_lists
+            .into_iter()
+            .map(|task_list| serde_json::to_value(task_list).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to reorder task lists: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn archive_task_list(id: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.archive_task_list(&id).await {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to archive task list: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn unarchive_task_list(id: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.unarchive_task_list(&id).await {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to unarchive task list: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_task_list(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.delete_task_list(&id).await {
+        Ok(_) => Ok("Task list deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete task list: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_default_task_list() -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.get_default_task_list().await {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap()),
+        Err(e) => Err(format!("Failed to get default task list: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn move_task_to_list(
+    task_id: String,
+    task_list_id: String,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database connection failed: {}", e))?;
+
+    let task_repo = TaskRepository::new(db.clone());
+    let task_list_repo = TaskListRepository::new(db);
+
+    // Validate that the task list exists
+    match task_list_repo.exists(&task_list_id).await {
+        Ok(false) => return Err(format!("Task list with ID '{}' not found", task_list_id)),
+        Err(e) => return Err(format!("Failed to validate task list: {}", e)),
+        Ok(true) => {}
+    }
+
+    // Perform the move operation
+    match task_repo.move_task_to_list(&task_id, &task_list_id).await {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap()),
+        Err(e) => Err(format!(
+            "Failed to move task '{}' to list '{}': {}",
+            task_id, task_list_id, e
+        )),
+    }
+}
+
+#[tauri::command]
+async fn get_tasks_by_task_list(task_list_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_repo = TaskRepository::new(db);
+
+    match task_repo.find_by_task_list(&task_list_id).await {
+        Ok(tasks) => {
+            let json_tasks: Vec<serde_json::Value> = tasks
+                .into_iter()
+                .map(|task| serde_json::to_value(task).unwrap())
+                .collect();
+            Ok(json_tasks)
+        }
+        Err(e) => Err(format!("Failed to get tasks by task list: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_list_stats() -> Result<TaskListStats, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.get_task_list_stats().await {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get task list stats: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn set_task_list_targets(
+    id: String,
+    target_backlog_hours: Option<i32>,
+    target_cycle_hours: Option<i32>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo
+        .set_targets(&id, target_backlog_hours, target_cycle_hours)
+        .await
+    {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to set task list targets: {}", e)),
+    }
+}
+
+/// Set the hourly rate billed for a task list; `None` makes it not billable
+#[tauri::command]
+async fn set_task_list_hourly_rate(
+    id: String,
+    hourly_rate: Option<f64>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo.set_hourly_rate(&id, hourly_rate).await {
+        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to set task list hourly rate: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_list_aging_stats(
+    task_list_id: Option<String>,
+) -> Result<Vec<TaskListAgingStats>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo
+        .get_aging_stats(task_list_id.as_deref())
+        .await
+    {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get task list aging stats: {}", e)),
+    }
+}
+
+/// Task counts by status, overdue tasks, estimated and actual time and last activity per
+/// task list, or per tag when `group_by` is "tag"
+#[tauri::command]
+async fn get_task_list_breakdown(
+    group_by: Option<BreakdownGroup>,
+) -> Result<Vec<TaskBreakdown>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let task_list_repo = TaskListRepository::new(db);
+
+    match task_list_repo
+        .get_breakdown(group_by.unwrap_or_default())
+        .await
+    {
+        Ok(breakdown) => Ok(breakdown),
+        Err(e) => Err(format!("Failed to get task list breakdown: {}", e)),
+    }
+}
+
+// ============================================================================
+// Project Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_project(request: CreateProjectRequest) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ProjectRepository::new(db);
+
+    match repo.create_project(request).await {
+        Ok(project) => Ok(serde_json::to_value(project).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create project: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_all_projects() -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ProjectRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(projects) => Ok(projects
+            .into_iter()
+            .map(|project| serde_json::to_value(project).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get projects: {}", e)),
+    }
+}
+
+/// Progress of one project, or of every project when `project_id` is unset
+#[tauri::command]
+async fn get_project_progress(project_id: Option<String>) -> Result<Vec<ProjectProgress>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ProjectRepository::new(db);
+
+    match repo.get_progress(project_id.as_deref()).await {
+        Ok(progress) => Ok(progress),
+        Err(e) => Err(format!("Failed to get project progress: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_project(
+    id: String,
+    request: UpdateProjectRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ProjectRepository::new(db);
+
+    match repo.update_project(&id, request).await {
+        Ok(project) => Ok(serde_json::to_value(project).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update project: {}", e)),
+    }
+}
+
+/// Delete a project; its task lists and tasks are kept and unlinked
+#[tauri::command]
+async fn delete_project(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ProjectRepository::new(db);
+
+    match repo.delete_project(&id).await {
+        Ok(_) => Ok("Project deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete project: {}", e)),
+    }
+}
+
+// ============================================================================
+// Custom Field Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_custom_field(
+    request: CreateCustomFieldRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = CustomFieldRepository::new(db);
+
+    match repo.create_field(request).await {
+        Ok(field) => Ok(serde_json::to_value(field).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create custom field: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_custom_fields(task_list_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = CustomFieldRepository::new(db);
+
+    match repo.find_by_task_list(&task_list_id).await {
+        Ok(fields) => Ok(fields
+            .into_iter()
+            .map(|f| serde_json::to_value(f).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get custom fields: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_custom_field(
+    id: String,
+    request: UpdateCustomFieldRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = CustomFieldRepository::new(db);
+
+    match repo.update_field(&id, request).await {
+        Ok(field) => Ok(serde_json::to_value(field).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update custom field: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_custom_field(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = CustomFieldRepository::new(db);
+
+    match repo.delete_field(&id).await {
+        Ok(_) => Ok("Custom field deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete custom field: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn set_task_custom_field_value(
+    task_id: String,
+    field_id: String,
+    value: Option<String>,
+) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = CustomFieldRepository::new(db);
+
+    match repo.set_value(&task_id, &field_id, value).await {
+        Ok(value) => Ok(value.map(|v| serde_json::to_value(v).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to set custom field value: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn set_task_custom_field_values(
+    task_id: String,
+    values: std::collections::HashMap<String, Option<String>>,
+) -> Result<Vec<TaskCustomFieldValue>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = CustomFieldRepository::new(db);
+
+    match repo.set_values_by_name(&task_id, values).await {
+        Ok(values) => Ok(values),
+        Err(e) => Err(format!("Failed to set custom field values: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_custom_field_values(
+    task_id: String,
+) -> Result<Vec<TaskCustomFieldValue>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = CustomFieldRepository::new(db);
+
+    match repo.get_task_values(&task_id).await {
+        Ok(values) => Ok(values),
+        Err(e) => Err(format!("Failed to get custom field values: {}", e)),
+    }
+}
+
+// ============================================================================
+// Task Status Commands
+// ============================================================================
+
+#[tauri::command]
+async fn get_task_statuses() -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskStatusRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(statuses) => Ok(statuses
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get task statuses: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn create_task_status(request: CreateTaskStatusRequest) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskStatusRepository::new(db);
+
+    match repo.create_status(request).await {
+        Ok(status) => Ok(serde_json::to_value(status).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create task status: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_task_status(
+    id: String,
+    request: UpdateTaskStatusRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskStatusRepository::new(db);
+
+    match repo.update_status(&id, request).await {
+        Ok(status) => Ok(serde_json::to_value(status).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update task status: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_task_status(
+    id: String,
+    replacement_status: Option<String>,
+) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskStatusRepository::new(db);
+
+    match repo.delete_status(&id, replacement_status.as_deref()).await {
+        Ok(moved) => Ok(format!(
+            "Task status deleted successfully ({} task(s) moved)",
+            moved
+        )),
+        Err(e) => Err(format!("Failed to delete task status: {}", e)),
+    }
+}
+
+// ============================================================================
+// Tag Commands
+// ============================================================================
+
+#[tauri::command]
+async fn get_all_tags() -> Result<Vec<TagWithCount>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TagRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(tags) => Ok(tags),
+        Err(e) => Err(format!("Failed to get tags: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn rename_tag(old_name: String, new_name: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TagRepository::new(db);
+
+    match repo.rename_tag(&old_name, &new_name).await {
+        Ok(tag) => Ok(serde_json::to_value(tag).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to rename tag: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn merge_tags(
+    source_tags: Vec<String>,
+    target_tag: String,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TagRepository::new(db);
+
+    match repo.merge_tags(&source_tags, &target_tag).await {
+        Ok(tag) => Ok(serde_json::to_value(tag).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to merge tags: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_tasks_by_tags(
+    tags: Vec<String>,
+    match_all: Option<bool>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskRepository::new(db);
+
+    match repo.find_by_tags(&tags, match_all.unwrap_or(false)).await {
+        Ok(tasks) => Ok(tasks
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get tasks by tags: {}", e)),
+    }
+}
+
+// ============================================================================
+// Backup & Restore Commands
+// ============================================================================
+
+#[tauri::command]
+async fn export_data_to_file(
+    app: tauri::AppHandle,
+    file_path: String,
+) -> Result<BackupMetadata, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let attachments_dir =
+        get_attachments_dir().map_err(|e| format!("Attachments directory error: {}", e))?;
+
+    let operation = start_operation(&app, "backup_export", "Exporting backup");
+    let backup_service = BackupService::new(db.clone())
+        .with_attachments_dir(attachments_dir)
+        .with_progress(operation.reporter());
+
+    let result = backup_service.export_data(&file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(metadata) => {
+            webhooks::emit(
+                db,
+                "backup.finished",
+                serde_json::json!({ "file_path": file_path, "metadata": metadata }),
+            );
+            Ok(metadata)
+        }
+        Err(e) => Err(format!("Failed to export data: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn import_data_from_file(
+    app: tauri::AppHandle,
+    file_path: String,
+    overwrite: bool,
+) -> Result<BackupMetadata, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let attachments_dir =
+        get_attachments_dir().map_err(|e| format!("Attachments directory error: {}", e))?;
+
+    let operation = start_operation(&app, "backup_import", "Importing backup");
+    let backup_service = BackupService::new(db)
+        .with_attachments_dir(attachments_dir)
+        .with_progress(operation.reporter());
+
+    let result = backup_service.import_data(&file_path, overwrite).await;
+    operation.finish(&result);
+    match result {
+        Ok(metadata) => Ok(metadata),
+        Err(e) => Err(format!("Failed to import data: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn validate_backup_file(file_path: String) -> Result<BackupMetadata, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let backup_service = BackupService::new(db);
+
+    match backup_service.validate_backup(&file_path).await {
+        Ok(metadata) => Ok(metadata),
+        Err(e) => Err(format!("Failed to validate backup: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn rehearse_backup_restore(
+    app: tauri::AppHandle,
+    file_path: String,
+) -> Result<RestoreRehearsalReport, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let operation = start_operation(&app, "backup_rehearsal", "Rehearsing backup restore");
+    let backup_service = BackupService::new(db).with_progress(operation.reporter());
+
+    let result = backup_service.rehearse_restore(&file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to rehearse backup restore: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn validate_backup_comprehensive(
+    file_path: String,
+) -> Result<backup::BackupValidationResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let backup_service = BackupService::new(db);
+
+    match backup_service
+        .validate_backup_comprehensive(&file_path)
+        .await
+    {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to validate backup: {}", e)),
+    }
+}
+
+// ============================================================================
+// Task Sharing Commands
+// ============================================================================
+
+/// Bundle service signing exports with this install's key
+fn task_bundle_service(
+    db: std::sync::Arc<sea_orm::DatabaseConnection>,
+) -> Result<TaskBundleService, String> {
+    let signing_key = get_bundle_signing_key_path()
+        .map_err(anyhow::Error::from)
+        .and_then(|path| load_signing_key(&path))
+        .map_err(|e| format!("Bundle signing key error: {}", e))?;
+    let attachments_dir =
+        get_attachments_dir().map_err(|e| format!("Attachments directory error: {}", e))?;
+    Ok(TaskBundleService::new(db, signing_key).with_attachments_dir(attachments_dir))
+}
+
+#[tauri::command]
+async fn export_task_bundle(
+    app: tauri::AppHandle,
+    task_id: String,
+    file_path: String,
+) -> Result<TaskBundleManifest, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let bundle_service = task_bundle_service(db)?;
+
+    let operation = start_operation(&app, "task_bundle_export", "Exporting task");
+    let result = bundle_service.export_task(&task_id, &file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(manifest) => Ok(manifest),
+        Err(e) => Err(format!("Failed to export task bundle: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn export_task_list_bundle(
+    app: tauri::AppHandle,
+    task_list_id: String,
+    file_path: String,
+) -> Result<TaskBundleManifest, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let bundle_service = task_bundle_service(db)?;
+
+    let operation = start_operation(&app, "task_bundle_export", "Exporting task list");
+    let result = bundle_service
+        .export_task_list(&task_list_id, &file_path)
+        .await;
+    operation.finish(&result);
+    match result {
+        Ok(manifest) => Ok(manifest),
+        Err(e) => Err(format!("Failed to export task list bundle: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn inspect_task_bundle(file_path: String) -> Result<TaskBundleManifest, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let bundle_service = task_bundle_service(db)?;
+
+    match bundle_service.inspect_bundle(&file_path) {
+        Ok(manifest) => Ok(manifest),
+        Err(e) => Err(format!("Failed to read task bundle: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn import_task_bundle(
+    app: tauri::AppHandle,
+    file_path: String,
+    task_list_id: Option<String>,
+) -> Result<TaskBundleImportResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let bundle_service = task_bundle_service(db)?;
+
+    let operation = start_operation(&app, "task_bundle_import", "Importing tasks");
+    let result = bundle_service.import_bundle(&file_path, task_list_id).await;
+    operation.finish(&result);
+    match result {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to import task bundle: {}", e)),
+    }
+}
+
+// ============================================================================
+// Task Import Commands
+// ============================================================================
+
+#[tauri::command]
+async fn preview_task_import(
+    file_path: String,
+    format: TaskImportFormat,
+) -> Result<TaskImportPreview, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let import_service = TaskImportService::new(db);
+
+    match import_service.preview(&file_path, format).await {
+        Ok(preview) => Ok(preview),
+        Err(e) => Err(format!("Failed to preview task import: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn import_tasks(
+    app: tauri::AppHandle,
+    file_path: String,
+    mapping: TaskImportMapping,
+) -> Result<TaskImportResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let import_service = TaskImportService::new(db);
+
+    let operation = start_operation(&app, "task_import", "Importing tasks");
+    let result = import_service.import(&file_path, &mapping).await;
+    operation.finish(&result);
+    match result {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to import tasks: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn preview_time_import(
+    file_path: String,
+    source: TimeImportSource,
+) -> Result<TimeImportPreview, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let import_service = TimeImportService::new(db);
+
+    match import_service.preview(&file_path, source).await {
+        Ok(preview) => Ok(preview),
+        Err(e) => Err(format!("Failed to preview time import: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn import_time_entries(
+    app: tauri::AppHandle,
+    file_path: String,
+    source: TimeImportSource,
+    task_list_id: Option<String>,
+) -> Result<TimeImportResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let import_service = TimeImportService::new(db);
+
+    let operation = start_operation(&app, "time_import", "Importing time entries");
+    let result = import_service
+        .import(&file_path, source, task_list_id)
+        .await;
+    operation.finish(&result);
+    match result {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to import time entries: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn preview_todoist_import(file_path: String) -> Result<TodoistImportPreview, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let import_service = TodoistImportService::new(db);
+
+    match import_service.preview(&file_path).await {
+        Ok(preview) => Ok(preview),
+        Err(e) => Err(format!("Failed to preview Todoist import: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn import_todoist(
+    app: tauri::AppHandle,
+    file_path: String,
+) -> Result<TodoistImportResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let import_service = TodoistImportService::new(db);
+
+    let operation = start_operation(&app, "todoist_import", "Importing from Todoist");
+    let result = import_service.import(&file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to import from Todoist: {}", e)),
+    }
+}
+
+// ============================================================================
+// Saved Filter Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_saved_filter(
+    request: CreateSavedFilterRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = SavedFilterRepository::new(db);
+
+    match repo.create_filter(request).await {
+        Ok(filter) => Ok(serde_json::to_value(filter).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create saved filter: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_saved_filters() -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = SavedFilterRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(filters) => Ok(filters
+            .into_iter()
+            .map(|f| serde_json::to_value(f).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get saved filters: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_saved_filter(
+    id: String,
+    request: UpdateSavedFilterRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = SavedFilterRepository::new(db);
+
+    match repo.update_filter(&id, request).await {
+        Ok(filter) => Ok(serde_json::to_value(filter).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update saved filter: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_saved_filter(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = SavedFilterRepository::new(db);
+
+    match repo.delete_filter(&id).await {
+        Ok(_) => Ok("Saved filter deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete saved filter: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_tasks_by_saved_filter(id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let filter_repo = SavedFilterRepository::new(db.clone());
+    let task_repo = TaskRepository::new(db);
+
+    let filter = filter_repo
+        .find_by_id(&id)
+        .await
+        .map_err(|e| format!("Failed to get saved filter: {}", e))?
+        .ok_or_else(|| format!("Saved filter not found: {}", id))?;
+    let criteria = SavedFilterRepository::parse_criteria(&filter).map_err(|e| e.to_string())?;
+
+    match task_repo.find_by_filter(&criteria).await {
+        Ok(tasks) => Ok(tasks
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to filter tasks: {}", e)),
+    }
+}
+
+// ============================================================================
+// Task Template Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_task_template(
+    request: CreateTaskTemplateRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskTemplateRepository::new(db);
+
+    match repo.create_template(request).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create task template: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_template(id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskTemplateRepository::new(db);
+
+    match repo.find_by_id(&id).await {
+        Ok(template) => Ok(template.map(|t| serde_json::to_value(t).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get task template: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_templates() -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskTemplateRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(templates) => Ok(templates
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get task templates: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_task_template(
+    id: String,
+    request: UpdateTaskTemplateRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskTemplateRepository::new(db);
+
+    match repo.update_template(&id, request).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update task template: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_task_template(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskTemplateRepository::new(db);
+
+    match repo.delete_template(&id).await {
+        Ok(_) => Ok("Task template deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete task template: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn save_task_as_template(
+    request: SaveTaskAsTemplateRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskTemplateRepository::new(db);
+
+    match repo.save_task_as_template(request).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to save task as template: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn instantiate_task_template(
+    id: String,
+    request: Option<InstantiateTaskTemplateRequest>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskTemplateRepository::new(db);
+
+    match repo
+        .instantiate_template(&id, request.unwrap_or_default())
+        .await
+    {
+        Ok(task) => Ok(serde_json::to_value(task).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to instantiate task template: {}", e)),
+    }
+}
+
+// ============================================================================
+// Webhook Commands
+// ============================================================================
+
+/// Register a webhook; the response carries the signing secret, which is not shown again
+#[tauri::command]
+async fn create_webhook(request: CreateWebhookRequest) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = WebhookRepository::new(db);
+
+    match repo.create_webhook(request).await {
+        Ok(created) => Ok(serde_json::to_value(created).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create webhook: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_webhooks() -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = WebhookRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(webhooks) => Ok(webhooks
+            .into_iter()
+            .map(|w| serde_json::to_value(w).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get webhooks: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn update_webhook(
+    id: String,
+    request: UpdateWebhookRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = WebhookRepository::new(db);
+
+    match repo.update_webhook(&id, request).await {
+        Ok(webhook) => Ok(serde_json::to_value(webhook).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update webhook: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_webhook(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = WebhookRepository::new(db);
+
+    match repo.delete_webhook(&id).await {
+        Ok(_) => Ok("Webhook deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete webhook: {}", e)),
+    }
+}
+
+/// Delivery log of a webhook, newest first
+#[tauri::command]
+async fn get_webhook_deliveries(
+    webhook_id: String,
+    limit: Option<u64>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = WebhookRepository::new(db);
+
+    match repo.find_deliveries(&webhook_id, limit.unwrap_or(50)).await {
+        Ok(deliveries) => Ok(deliveries
+            .into_iter()
+            .map(|d| serde_json::to_value(d).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get webhook deliveries: {}", e)),
+    }
+}
+
+// ============================================================================
+// API Token Commands
+// ============================================================================
+
+#[tauri::command]
+async fn create_api_token(request: CreateApiTokenRequest) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ApiTokenRepository::new(db);
+
+    match repo.create_token(request).await {
+        Ok(issued) => Ok(serde_json::to_value(issued).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create API token: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_api_tokens() -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ApiTokenRepository::new(db);
+
+    match repo.find_all().await {
+        Ok(tokens) => Ok(tokens
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get API tokens: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn revoke_api_token(id: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ApiTokenRepository::new(db);
+
+    match repo.revoke_token(&id).await {
+        Ok(token) => Ok(serde_json::to_value(token).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to revoke API token: {}", e)),
+    }
+}
+
+/// Check a token against the scope a command needs, for integrations bridging into the app
+#[tauri::command]
+async fn authorize_command(token: String, command: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    match authorization::authorize(db, &token, &command).await {
+        Ok(token) => Ok(serde_json::to_value(token).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to authorize command: {}", e)),
+    }
+}
+
+// ============================================================================
+// Template Pack Commands
+// ============================================================================
+
+#[tauri::command]
+async fn export_template_pack(
+    app: tauri::AppHandle,
+    request: TemplatePackExportRequest,
+    file_path: String,
+) -> Result<TemplatePackSummary, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let pack_service = TemplatePackService::new(db);
+
+    let operation = start_operation(&app, "template_pack_export", "Exporting template pack");
+    let result = pack_service.export_pack(request, &file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to export template pack: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn inspect_template_pack(file_path: String) -> Result<TemplatePackSummary, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let pack_service = TemplatePackService::new(db);
+
+    match pack_service.inspect_pack(&file_path) {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to read template pack: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn import_template_pack(
+    app: tauri::AppHandle,
+    file_path: String,
+) -> Result<TemplatePackImportResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let pack_service = TemplatePackService::new(db);
+
+    let operation = start_operation(&app, "template_pack_import", "Importing template pack");
+    let result = pack_service.import_pack(&file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to import template pack: {}", e)),
+    }
+}
+
+/// Export all periodic templates and their recurrence to a template pack file
+#[tauri::command]
+async fn export_periodic_templates(file_path: String) -> Result<TemplatePackSummary, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let pack_service = TemplatePackService::new(db);
+
+    match pack_service.export_periodic_templates(&file_path).await {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to export periodic templates: {}", e)),
+    }
+}
+
+/// Import the periodic templates of a template pack file, ignoring its other contents
+#[tauri::command]
+async fn import_periodic_templates(file_path: String) -> Result<TemplatePackImportResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let pack_service = TemplatePackService::new(db);
+
+    match pack_service.import_periodic_templates(&file_path).await {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to import periodic templates: {}", e)),
+    }
+}
+
+// ============================================================================
+// Settings Bundle Commands
+// ============================================================================
+
+#[tauri::command]
+async fn export_settings_bundle(
+    app: tauri::AppHandle,
+    preferences: Option<serde_json::Value>,
+    file_path: String,
+) -> Result<SettingsBundleSummary, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let bundle_service = SettingsBundleService::new(db);
+
+    let operation = start_operation(&app, "settings_bundle_export", "Exporting settings");
+    let result = bundle_service.export_bundle(preferences, &file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to export settings bundle: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn inspect_settings_bundle(file_path: String) -> Result<SettingsBundleSummary, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let bundle_service = SettingsBundleService::new(db);
+
+    match bundle_service.inspect_bundle(&file_path) {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to read settings bundle: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn import_settings_bundle(
+    app: tauri::AppHandle,
+    file_path: String,
+) -> Result<SettingsBundleImportResult, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let bundle_service = SettingsBundleService::new(db);
+
+    let operation = start_operation(&app, "settings_bundle_import", "Importing settings");
+    let result = bundle_service.import_bundle(&file_path).await;
+    operation.finish(&result);
+    match result {
+        Ok(result) => Ok(result),
+        Err(e) => Err(format!("Failed to import settings bundle: {}", e)),
+    }
+}
+
+// ============================================================================
+// Focus Session Commands
+// ============================================================================
+
+/// Start a focus session on a task; fails while another focus session runs
+#[tauri::command]
+async fn start_focus_session(
+    request: CreateFocusSessionRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    match repo.start_session(request).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to start focus session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_focus_session(id: String) -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    match repo.find_by_id(&id).await {
+        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get focus session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_active_focus_session() -> Result<Option<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    match repo.find_active_session().await {
+        Ok(session) => Ok(session.map(|s| serde_json::to_value(s).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get active focus session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_task_focus_sessions(task_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    match repo.find_sessions_for_task(&task_id).await {
+        Ok(sessions) => Ok(sessions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get focus sessions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn get_focus_sessions_between(
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.find_sessions_between(start, end).await {
+        Ok(sessions) => Ok(sessions
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get focus sessions: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn record_focus_distraction(id: String) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    match repo.record_distraction(&id).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to record distraction: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn add_focus_break(id: String, focus_break: FocusBreak) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    match repo.add_break(&id, focus_break).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to add focus break: {}", e)),
+    }
+}
+
+/// Complete a focus session with its focus score; without an actual duration the minutes since
+/// it started are recorded, and the distractions counted so far are kept
+#[tauri::command]
+async fn complete_focus_session(
+    id: String,
+    actual_duration: Option<i32>,
+    focus_score: f64,
+    notes: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    let session = repo
+        .find_by_id(&id)
+        .await
+        .map_err(|e| format!("Failed to complete focus session: {}", e))?
+        .ok_or_else(|| "Focus session not found".to_string())?;
+    let actual_duration = actual_duration
+        .unwrap_or_else(|| (chrono::Utc::now() - session.created_at).num_minutes() as i32);
+
+    match repo
+        .complete_session(
+            &id,
+            actual_duration,
+            focus_score,
+            session.distraction_count,
+            notes,
+        )
+        .await
+    {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to complete focus session: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn delete_focus_session(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    match repo.delete_session(&id).await {
+        Ok(_) => Ok("Focus session deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete focus session: {}", e)),
+    }
+}
+
+/// Planned against actual focus time, focus scores and completion rate for sessions started
+/// in a period
+#[tauri::command]
+async fn get_focus_stats(start_date: String, end_date: String) -> Result<FocusStats, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusRepository::new(db);
+
+    let start = chrono::DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| format!("Invalid start date: {}", e))?
+        .with_timezone(&chrono::Utc);
+    let end = chrono::DateTime::parse_from_rfc3339(&end_date)
+        .map_err(|e| format!("Invalid end date: {}", e))?
+        .with_timezone(&chrono::Utc);
+
+    match repo.get_focus_stats(start, end).await {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get focus stats: {}", e)),
     }
 }
 
+// ============================================================================
+// Productivity Pattern Commands
+// ============================================================================
+
+/// Record a productivity observation for a time slot, folded into the pattern already stored
+/// for that slot
 #[tauri::command]
-async fn create_ai_interaction_log(
-    request: serde_json::Value,
+async fn record_productivity_pattern(
+    pattern_type: String,
+    time_slot: String,
+    productivity_score: f64,
+    confidence_level: f64,
+    sample_size: i32,
 ) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
-
-    // The frontend sends { request: data }, so we need to get the "request" field
-    // But if that fails, the data might be at the top level (Tauri parameter handling)
-    let request_data = if let Some(nested_request) = request.get("request") {
-        nested_request
-    } else {
-        // Data is at the top level
-        &request
+    let repo = PatternRepository::new(db);
+
+    let request = CreatePatternRequest {
+        user_id: patterns::LOCAL_USER_ID.to_string(),
+        pattern_type,
+        time_slot,
+        productivity_score,
+        confidence_level,
+        sample_size,
     };
+    match repo.upsert_pattern(request).await {
+        Ok(pattern) => Ok(serde_json::to_value(pattern).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to record productivity pattern: {}", e)),
+    }
+}
 
-    // Convert to CreateAiInteractionLogRequest
-    let log_request = CreateAiInteractionLogRequest {
-        session_id: request_data
-            .get("session_id")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
-        model_type: request_data
-            .get("model_type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("gemini")
-            .to_string(),
-        model_info: request_data
-            .get("model_info")
-            .cloned()
-            .unwrap_or(serde_json::json!({})),
-        user_message: request_data
-            .get("user_message")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
-        system_prompt: request_data
-            .get("system_prompt")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        context: request_data
-            .get("context")
-            .and_then(|v| v.as_str())
-            .unwrap_or("{}")
-            .to_string(),
-        ai_response: request_data
-            .get("ai_response")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
-        actions: request_data
-            .get("actions")
-            .and_then(|v| v.as_str())
-            .unwrap_or("[]")
-            .to_string(),
-        suggestions: request_data
-            .get("suggestions")
-            .and_then(|v| v.as_str())
-            .unwrap_or("[]")
-            .to_string(),
-        reasoning: request_data
-            .get("reasoning")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        response_time: request_data
-            .get("response_time")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(0),
-        token_count: request_data.get("token_count").and_then(|v| v.as_i64()),
-        error: request_data
-            .get("error")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        error_code: request_data
-            .get("error_code")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        contains_sensitive_data: request_data
-            .get("contains_sensitive_data")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false),
-        data_classification: request_data
-            .get("data_classification")
-            .and_then(|v| v.as_str())
-            .unwrap_or("internal")
-            .to_string(),
+#[tauri::command]
+async fn get_productivity_patterns(
+    pattern_type: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PatternRepository::new(db);
+
+    let result = match pattern_type {
+        Some(pattern_type) => {
+            repo.find_by_pattern_type(patterns::LOCAL_USER_ID, &pattern_type)
+                .await
+        }
+        None => repo.find_patterns_for_user(patterns::LOCAL_USER_ID).await,
     };
+    match result {
+        Ok(patterns) => Ok(patterns
+            .into_iter()
+            .map(|p| serde_json::to_value(p).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get productivity patterns: {}", e)),
+    }
+}
 
-    match repo.create_interaction_log(log_request).await {
-        Ok(interaction) => Ok(serde_json::to_value(interaction).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to create AI interaction log: {}", e)),
+/// Peak hours, best weekdays and recommendations drawn from the stored patterns
+#[tauri::command]
+async fn get_pattern_insights() -> Result<ProductivityInsights, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PatternRepository::new(db);
+
+    match repo
+        .get_productivity_insights(patterns::LOCAL_USER_ID)
+        .await
+    {
+        Ok(insights) => Ok(insights),
+        Err(e) => Err(format!("Failed to get pattern insights: {}", e)),
     }
 }
 
+/// Derive hourly and weekday patterns from the last four weeks of sessions now, rather than
+/// waiting for the background recorder
 #[tauri::command]
-async fn get_ai_interaction_logs(
-    _filters: serde_json::Value,
-) -> Result<Vec<serde_json::Value>, String> {
+async fn derive_productivity_patterns() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
 
-    // Get all recent interactions and filter for AI logging interactions
-    // AI logs have action_taken in format "{model_type}:{session_id}"
-    match repo.get_recent_interactions(1000).await {
-        Ok(interactions) => {
-            println!(
-                "🔍 Backend: Found {} total interactions",
-                interactions.len()
-            );
+    match patterns::record_derived_patterns(db, chrono::Utc::now(), &chrono::Local).await {
+        Ok(patterns) => Ok(patterns
+            .into_iter()
+            .map(|p| serde_json::to_value(p).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to derive productivity patterns: {}", e)),
+    }
+}
 
-            // Debug: print all interactions to see what we have
-            for (i, interaction) in interactions.iter().enumerate().take(5) {
-                println!(
-                    "🔍 Backend: Interaction {}: id={}, action_taken={:?}, message={}, response={}",
-                    i,
-                    interaction.id,
-                    interaction.action_taken,
-                    interaction.message.chars().take(50).collect::<String>(),
-                    interaction.response.chars().take(50).collect::<String>()
-                );
-            }
+#[tauri::command]
+async fn delete_productivity_pattern(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = PatternRepository::new(db);
 
-            let ai_logs: Vec<serde_json::Value> = interactions
-                .into_iter()
-                .filter(|interaction| {
-                    // Filter for AI logging interactions by checking action_taken pattern
-                    let is_ai_log = interaction.action_taken.as_ref().map_or(false, |action| {
-                        action.contains(':')
-                            && (action.starts_with("local:") || action.starts_with("gemini:"))
-                    });
-
-                    if is_ai_log {
-                        println!(
-                            "🔍 Backend: Found AI log: id={}, action={:?}",
-                            interaction.id, interaction.action_taken
-                        );
-                    }
+    match repo.delete_pattern(&id).await {
+        Ok(_) => Ok("Productivity pattern deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete productivity pattern: {}", e)),
+    }
+}
 
-                    is_ai_log
-                })
-                .map(|interaction| {
-                    // Transform the data to match the expected AI log format
-                    let mut log_data = serde_json::Map::new();
-                    log_data.insert("id".to_string(), serde_json::Value::String(interaction.id));
-                    log_data.insert(
-                        "timestamp".to_string(),
-                        serde_json::Value::String(interaction.created_at.to_rfc3339()),
-                    );
-                    log_data.insert(
-                        "user_message".to_string(),
-                        serde_json::Value::String(interaction.message),
-                    );
-                    log_data.insert(
-                        "ai_response".to_string(),
-                        serde_json::Value::String(interaction.response),
-                    );
-
-                    // Extract session_id and model_type from action_taken
-                    if let Some(action) = &interaction.action_taken {
-                        let parts: Vec<&str> = action.split(':').collect();
-                        if parts.len() >= 2 {
-                            log_data.insert(
-                                "model_type".to_string(),
-                                serde_json::Value::String(parts[0].to_string()),
-                            );
-                            log_data.insert(
-                                "session_id".to_string(),
-                                serde_json::Value::String(parts[1].to_string()),
-                            );
-                        }
-                    }
+// ============================================================================
+// Focus Template Commands
+// ============================================================================
 
-                    // Add other fields with defaults
-                    log_data.insert(
-                        "model_info".to_string(),
-                        serde_json::Value::String("{}".to_string()),
-                    );
-                    log_data.insert("system_prompt".to_string(), serde_json::Value::Null);
-                    log_data.insert(
-                        "context".to_string(),
-                        serde_json::Value::String("{}".to_string()),
-                    );
-                    log_data.insert(
-                        "actions".to_string(),
-                        serde_json::Value::String(
-                            interaction.tools_used.unwrap_or_else(|| "[]".to_string()),
-                        ),
-                    );
-                    log_data.insert(
-                        "suggestions".to_string(),
-                        serde_json::Value::String("[]".to_string()),
-                    );
-                    log_data.insert(
-                        "reasoning".to_string(),
-                        serde_json::Value::String(
-                            interaction.reasoning.unwrap_or_else(|| "".to_string()),
-                        ),
-                    );
-                    log_data.insert(
-                        "response_time".to_string(),
-                        serde_json::Value::Number(serde_json::Number::from(1000)),
-                    ); // Default 1000ms
-                    log_data.insert("token_count".to_string(), serde_json::Value::Null);
-                    log_data.insert("error".to_string(), serde_json::Value::Null);
-                    log_data.insert("error_code".to_string(), serde_json::Value::Null);
-                    log_data.insert(
-                        "contains_sensitive_data".to_string(),
-                        serde_json::Value::Bool(false),
-                    );
-                    log_data.insert(
-                        "data_classification".to_string(),
-                        serde_json::Value::String("public".to_string()),
-                    );
-                    log_data.insert(
-                        "created_at".to_string(),
-                        serde_json::Value::String(interaction.created_at.to_rfc3339()),
-                    );
-                    log_data.insert(
-                        "updated_at".to_string(),
-                        serde_json::Value::String(interaction.created_at.to_rfc3339()),
-                    );
-
-                    serde_json::Value::Object(log_data)
-                })
-                .collect();
+#[tauri::command]
+async fn create_focus_template(
+    request: CreateFocusTemplateRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusTemplateRepository::new(db);
 
-            println!("🔍 Backend: Filtered to {} AI logs", ai_logs.len());
-            Ok(ai_logs)
-        }
-        Err(e) => Err(format!("Failed to get AI interaction logs: {}", e)),
+    match repo.create_template(request).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create focus template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_ai_interaction_log(id: String) -> Result<Option<serde_json::Value>, String> {
+async fn get_focus_templates() -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = FocusTemplateRepository::new(db);
 
-    // Use the existing get_ai_interaction command logic
-    match repo.find_by_id(&id).await {
-        Ok(Some(interaction)) => Ok(Some(serde_json::to_value(interaction).unwrap_or_default())),
-        Ok(None) => Ok(None),
-        Err(e) => Err(format!("Failed to get AI interaction log: {}", e)),
+    match repo.find_all().await {
+        Ok(templates) => Ok(templates
+            .into_iter()
+            .map(|t| serde_json::to_value(t).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get focus templates: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn delete_ai_interaction_log(id: String) -> Result<String, String> {
+async fn get_focus_template(id: String) -> Result<Option<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = FocusTemplateRepository::new(db);
 
-    match repo.delete_interaction(&id).await {
-        Ok(_) => Ok("Log deleted successfully".to_string()),
-        Err(e) => Err(format!("Failed to delete AI interaction log: {}", e)),
+    match repo.find_by_id(&id).await {
+        Ok(template) => Ok(template.map(|t| serde_json::to_value(t).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get focus template: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn update_ai_interaction_log(
+async fn update_focus_template(
     id: String,
-    request: serde_json::Value,
+    request: UpdateFocusTemplateRequest,
 ) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = FocusTemplateRepository::new(db);
 
-    // Extract the request data
-    let request_data = request.get("request").ok_or("Missing request data")?;
+    match repo.update_template(&id, request).await {
+        Ok(template) => Ok(serde_json::to_value(template).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update focus template: {}", e)),
+    }
+}
 
-    // Convert to UpdateAiInteractionLogRequest
-    let update_request = UpdateAiInteractionLogRequest {
-        ai_response: request_data
-            .get("ai_response")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        actions: request_data
-            .get("actions")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        suggestions: request_data
-            .get("suggestions")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        reasoning: request_data
-            .get("reasoning")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        response_time: request_data.get("response_time").and_then(|v| v.as_i64()),
-        token_count: request_data.get("token_count").and_then(|v| v.as_i64()),
-        error: request_data
-            .get("error")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        error_code: request_data
-            .get("error_code")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        contains_sensitive_data: request_data
-            .get("contains_sensitive_data")
-            .and_then(|v| v.as_bool()),
-        data_classification: request_data
-            .get("data_classification")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-    };
+#[tauri::command]
+async fn delete_focus_template(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusTemplateRepository::new(db);
+
+    match repo.delete_template(&id).await {
+        Ok(_) => Ok("Focus template deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete focus template: {}", e)),
+    }
+}
+
+#[tauri::command]
+async fn start_focus_from_template(
+    id: String,
+    task_id: String,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = FocusTemplateRepository::new(db);
 
-    match repo.update_interaction_log(&id, update_request).await {
-        Ok(interaction) => Ok(serde_json::to_value(interaction).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to update AI interaction log: {}", e)),
+    match repo.start_focus_from_template(&id, &task_id).await {
+        Ok(session) => Ok(serde_json::to_value(session).unwrap_or_default()),
+        Err(e) => Err(format!(
+            "Failed to start focus session from template: {}",
+            e
+        )),
     }
 }
 
+/// Start a Pomodoro cycle on a task; without a config it runs 25/5 minute cycles with a long
+/// break every fourth
 #[tauri::command]
-async fn create_tool_execution_log(
-    request: serde_json::Value,
-) -> Result<serde_json::Value, String> {
+async fn start_pomodoro(
+    engine: tauri::State<'_, PomodoroEngine>,
+    task_id: String,
+    config: Option<PomodoroConfig>,
+) -> Result<PomodoroState, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
 
-    // Extract the request data
-    let request_data = request.get("request").ok_or("Missing request data")?;
+    match engine.start(db, task_id, config.unwrap_or_default()).await {
+        Ok(state) => Ok(state),
+        Err(e) => Err(format!("Failed to start Pomodoro: {}", e)),
+    }
+}
 
-    // Convert to CreateToolExecutionLogRequest
-    let tool_request = CreateToolExecutionLogRequest {
-        interaction_log_id: request_data
-            .get("interaction_log_id")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
-        tool_name: request_data
-            .get("tool_name")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
-        arguments: request_data
-            .get("arguments")
-            .and_then(|v| v.as_str())
-            .unwrap_or("{}")
-            .to_string(),
-        result: request_data
-            .get("result")
-            .and_then(|v| v.as_str())
-            .unwrap_or("{}")
-            .to_string(),
-        execution_time: request_data
-            .get("execution_time")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(0),
-        success: request_data
-            .get("success")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false),
-        error: request_data
-            .get("error")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-    };
+/// End the current Pomodoro break early and start the next work phase
+#[tauri::command]
+async fn skip_break(engine: tauri::State<'_, PomodoroEngine>) -> Result<PomodoroState, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
 
-    match repo.create_tool_execution_log(tool_request).await {
-        Ok(log) => Ok(serde_json::to_value(log).unwrap_or_default()),
-        Err(e) => Err(format!("Failed to create tool execution log: {}", e)),
+    match engine.skip_break(db).await {
+        Ok(state) => Ok(state),
+        Err(e) => Err(format!("Failed to skip break: {}", e)),
     }
 }
 
+/// Stop the running Pomodoro, returning where it was; `null` when none was running
 #[tauri::command]
-async fn get_tool_execution_logs(
-    interaction_log_id: String,
-) -> Result<Vec<serde_json::Value>, String> {
+async fn stop_pomodoro(
+    engine: tauri::State<'_, PomodoroEngine>,
+) -> Result<Option<PomodoroState>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
 
-    // For now, return empty array since we're storing tool executions as regular interactions
-    // In a production system, you'd have a separate table for tool executions
-    match repo.find_all(Some(100), None).await {
-        Ok(interactions) => {
-            let tool_logs: Vec<serde_json::Value> = interactions
-                .into_iter()
-                .filter(|i| {
-                    i.action_taken.as_ref().map_or(false, |action| {
-                        action.starts_with("tool_execution:")
-                            && action.contains(&interaction_log_id)
-                    })
-                })
-                .map(|i| serde_json::to_value(i).unwrap_or_default())
-                .collect();
-            Ok(tool_logs)
-        }
-        Err(e) => Err(format!("Failed to get tool execution logs: {}", e)),
+    match engine.stop(db).await {
+        Ok(state) => Ok(state),
+        Err(e) => Err(format!("Failed to stop Pomodoro: {}", e)),
     }
 }
 
+/// The running Pomodoro, or `null` when none is running
 #[tauri::command]
-async fn clear_all_ai_interaction_logs() -> Result<String, String> {
+async fn get_pomodoro_state(
+    engine: tauri::State<'_, PomodoroEngine>,
+) -> Result<Option<PomodoroState>, String> {
+    Ok(engine.state().await)
+}
+
+// ============================================================================
+// Reminder Commands
+// ============================================================================
+
+#[tauri::command]
+async fn set_task_reminders(
+    task_id: String,
+    reminders: Vec<ReminderOffsetRequest>,
+) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ReminderRepository::new(db);
 
-    match repo.delete_all_interactions().await {
-        Ok(deleted_count) => Ok(format!("Cleared {} AI interaction logs", deleted_count)),
-        Err(e) => Err(format!("Failed to clear AI interaction logs: {}", e)),
+    match repo.set_task_reminders(&task_id, reminders).await {
+        Ok(reminders) => Ok(reminders
+            .into_iter()
+            .map(|r| serde_json::to_value(r).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to set task reminders: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn cleanup_old_ai_interaction_logs() -> Result<u64, String> {
+async fn get_task_reminders(task_id: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ReminderRepository::new(db);
 
-    // Clean up logs older than 30 days by default
-    let cutoff_date = chrono::Utc::now() - chrono::Duration::days(30);
+    match repo.find_by_task(&task_id).await {
+        Ok(reminders) => Ok(reminders
+            .into_iter()
+            .map(|r| serde_json::to_value(r).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get task reminders: {}", e)),
+    }
+}
 
-    match repo.clear_old_interactions(cutoff_date).await {
-        Ok(deleted_count) => Ok(deleted_count),
-        Err(e) => Err(format!("Failed to cleanup old AI interaction logs: {}", e)),
+/// Reminders firing within the next `hours` (24 by default)
+#[tauri::command]
+async fn get_upcoming_reminders(hours: Option<i64>) -> Result<Vec<ScheduledReminder>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await
+        .map_err(|e| format!("Failed to get working hours: {}", e))?;
+    let repo = ReminderRepository::new(db).with_working_hours(working_hours);
+
+    let now = chrono::Utc::now();
+    let until = now + chrono::Duration::hours(hours.unwrap_or(24).clamp(1, 24 * 31));
+    match repo.find_upcoming_reminders(now, until).await {
+        Ok(reminders) => Ok(reminders),
+        Err(e) => Err(format!("Failed to get upcoming reminders: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn export_ai_interaction_logs(
-    _filters: serde_json::Value,
-    format: String,
-) -> Result<String, String> {
+async fn snooze_reminder(id: String, minutes: i32) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ReminderRepository::new(db);
 
-    // For now, just export all recent interactions
-    match repo.get_recent_interactions(1000).await {
-        Ok(interactions) => {
-            if format == "csv" {
-                // Simple CSV export
-                let mut csv = "id,timestamp,message,response,action_taken,reasoning\n".to_string();
-                for interaction in interactions {
-                    csv.push_str(&format!(
-                        "{},{},{},{},{},{}\n",
-                        interaction.id,
-                        interaction.created_at.to_rfc3339(),
-                        interaction.message.replace(',', ";").replace('\n', " "),
-                        interaction.response.replace(',', ";").replace('\n', " "),
-                        interaction
-                            .action_taken
-                            .unwrap_or_default()
-                            .replace(',', ";"),
-                        interaction
-                            .reasoning
-                            .unwrap_or_default()
-                            .replace(',', ";")
-                            .replace('\n', " ")
-                    ));
-                }
-                Ok(csv)
-            } else {
-                // JSON export
-                match serde_json::to_string_pretty(&interactions) {
-                    Ok(json) => Ok(json),
-                    Err(e) => Err(format!("Failed to serialize interactions to JSON: {}", e)),
-                }
-            }
-        }
-        Err(e) => Err(format!("Failed to export AI interaction logs: {}", e)),
+    match repo.snooze_reminder(&id, minutes).await {
+        Ok(reminder) => Ok(serde_json::to_value(reminder).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to snooze reminder: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn anonymize_ai_interaction_logs(log_ids: Vec<String>) -> Result<String, String> {
+async fn remind_again_in(task_id: String, minutes: i32) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ReminderRepository::new(db);
 
-    let mut anonymized_count = 0;
+    match repo.remind_again_in(&task_id, minutes).await {
+        Ok(reminder) => Ok(serde_json::to_value(reminder).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to schedule reminder: {}", e)),
+    }
+}
 
-    for log_id in log_ids {
-        // Update the log to remove sensitive information
-        let update_request = UpdateAiInteractionLogRequest {
-            ai_response: Some("[ANONYMIZED]".to_string()),
-            actions: Some("[]".to_string()),
-            suggestions: Some("[]".to_string()),
-            reasoning: Some("[ANONYMIZED]".to_string()),
-            response_time: None,
-            token_count: None,
-            error: None,
-            error_code: None,
-            contains_sensitive_data: Some(false),
-            data_classification: Some("public".to_string()),
-        };
+#[tauri::command]
+async fn delete_task_reminder(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ReminderRepository::new(db);
 
-        match repo.update_interaction_log(&log_id, update_request).await {
-            Ok(_) => anonymized_count += 1,
-            Err(e) => {
-                eprintln!("Failed to anonymize log {}: {}", log_id, e);
-            }
-        }
+    match repo.delete_reminder(&id).await {
+        Ok(_) => Ok("Reminder deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete reminder: {}", e)),
     }
+}
 
-    Ok(format!("Anonymized {} logs", anonymized_count))
+/// Notifications sent or dropped by the hourly limit, newest first (50 by default)
+#[tauri::command]
+async fn get_notification_log(limit: Option<u64>) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = NotificationRepository::new(db);
+
+    match repo.find_recent(limit.unwrap_or(50)).await {
+        Ok(deliveries) => Ok(deliveries
+            .into_iter()
+            .map(|d| serde_json::to_value(d).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get notification log: {}", e)),
+    }
 }
 
+// ============================================================================
+// Checklist Commands
+// ============================================================================
+
 #[tauri::command]
-async fn redact_sensitive_data(log_id: String) -> Result<String, String> {
+async fn create_checklist_item(
+    request: CreateChecklistItemRequest,
+) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
-    let repo = AiRepository::new(db);
+    let repo = ChecklistRepository::new(db);
 
-    // Update the log to redact sensitive data
-    let update_request = UpdateAiInteractionLogRequest {
-        ai_response: None, // Keep response but mark as redacted
-        actions: Some("[]".to_string()),
-        suggestions: Some("[]".to_string()),
-        reasoning: Some("[REDACTED]".to_string()),
-        response_time: None,
-        token_count: None,
-        error: None,
-        error_code: None,
-        contains_sensitive_data: Some(false),
-        data_classification: Some("internal".to_string()),
-    };
+    match repo.create_item(request).await {
+        Ok(item) => Ok(serde_json::to_value(item).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to create checklist item: {}", e)),
+    }
+}
 
-    match repo.update_interaction_log(&log_id, update_request).await {
-        Ok(_) => Ok("Sensitive data redacted successfully".to_string()),
-        Err(e) => Err(format!("Failed to redact sensitive data: {}", e)),
+#[tauri::command]
+async fn get_checklist_items(task_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ChecklistRepository::new(db);
+
+    match repo.find_by_task(&task_id).await {
+        Ok(items) => Ok(items
+            .into_iter()
+            .map(|i| serde_json::to_value(i).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get checklist items: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn update_logging_config(config: serde_json::Value) -> Result<serde_json::Value, String> {
-    // For now, just return the updated config
-    // In a real implementation, this would update a settings table
-    let updated_config = serde_json::json!({
-        "enabled": config.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true),
-        "log_level": config.get("log_level").and_then(|v| v.as_str()).unwrap_or("standard"),
-        "retention_days": config.get("retention_days").and_then(|v| v.as_i64()).unwrap_or(30),
-        "max_log_size": config.get("max_log_size").and_then(|v| v.as_i64()).unwrap_or(10485760),
-        "max_log_count": config.get("max_log_count").and_then(|v| v.as_i64()).unwrap_or(10000),
-        "include_system_prompts": config.get("include_system_prompts").and_then(|v| v.as_bool()).unwrap_or(true),
-        "include_tool_executions": config.get("include_tool_executions").and_then(|v| v.as_bool()).unwrap_or(true),
-        "include_performance_metrics": config.get("include_performance_metrics").and_then(|v| v.as_bool()).unwrap_or(true),
-        "auto_cleanup": config.get("auto_cleanup").and_then(|v| v.as_bool()).unwrap_or(true),
-        "export_format": config.get("export_format").and_then(|v| v.as_str()).unwrap_or("json")
-    });
+async fn update_checklist_item(
+    id: String,
+    request: UpdateChecklistItemRequest,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ChecklistRepository::new(db);
 
-    Ok(updated_config)
+    match repo.update_item(&id, request).await {
+        Ok(item) => Ok(serde_json::to_value(item).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update checklist item: {}", e)),
+    }
 }
 
 #[tauri::command]
-async fn get_logging_config() -> Result<serde_json::Value, String> {
-    // For now, return a default configuration
-    // In a real implementation, this would come from a settings table
-    let default_config = serde_json::json!({
-        "enabled": true,
-        "log_level": "standard",
-        "retention_days": 30,
-        "max_log_size": 10485760,
-        "max_log_count": 10000,
-        "include_system_prompts": true,
-        "include_tool_executions": true,
-        "include_performance_metrics": true,
-        "auto_cleanup": true,
-        "export_format": "json"
-    });
+async fn reorder_checklist_item(
+    id: String,
+    before_id: Option<String>,
+    after_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ChecklistRepository::new(db);
 
-    Ok(default_config)
+    match repo
+        .reorder_item(&id, before_id.as_deref(), after_id.as_deref())
+        .await
+    {
+        Ok(item) => Ok(serde_json::to_value(item).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to reorder checklist item: {}", e)),
+    }
 }
 
 #[tauri::command]
-async fn clear_all_data() -> Result<String, String> {
+async fn delete_checklist_item(id: String) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = ChecklistRepository::new(db);
 
-    // Clear all tables in the correct order (respecting foreign key constraints)
+    match repo.delete_item(&id).await {
+        Ok(_) => Ok("Checklist item deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete checklist item: {}", e)),
+    }
+}
 
-    // First, clear time sessions (they reference tasks)
-    let time_repo = TimeTrackingRepository::new(db.clone());
-    let time_sessions_deleted = time_repo
-        .delete_all_sessions()
-        .await
-        .map_err(|e| format!("Failed to clear time sessions: {}", e))?;
+// ============================================================================
+// Attachment Commands
+// ============================================================================
 
-    // Clear AI interactions
-    let ai_repo = AiRepository::new(db.clone());
-    let ai_interactions_deleted = ai_repo
-        .delete_all_interactions()
-        .await
-        .map_err(|e| format!("Failed to clear AI interactions: {}", e))?;
+fn get_attachment_repository(
+    db: std::sync::Arc<sea_orm::DatabaseConnection>,
+) -> Result<AttachmentRepository, String> {
+    let attachments_dir =
+        get_attachments_dir().map_err(|e| format!("Attachments directory error: {}", e))?;
+    Ok(AttachmentRepository::new(db, attachments_dir))
+}
 
-    // Clear task dependencies first
-    let task_repo = TaskRepository::new(db.clone());
-    let dependencies_deleted = task_repo
-        .delete_all_dependencies()
+/// Copy the file at `file_path` into the app's attachments directory and attach it to a task
+#[tauri::command]
+async fn add_task_attachment(
+    task_id: String,
+    file_path: String,
+) -> Result<serde_json::Value, String> {
+    let db = get_database()
         .await
-        .map_err(|e| format!("Failed to clear task dependencies: {}", e))?;
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = get_attachment_repository(db)?;
 
-    // Finally, clear tasks
-    let tasks_deleted = task_repo
-        .delete_all_tasks()
+    match repo
+        .add_attachment(&task_id, std::path::Path::new(&file_path))
         .await
-        .map_err(|e| format!("Failed to clear tasks: {}", e))?;
-
-    Ok(format!(
-        "Successfully cleared all data: {} tasks, {} time sessions, {} AI interactions, {} dependencies",
-        tasks_deleted, time_sessions_deleted, ai_interactions_deleted, dependencies_deleted
-    ))
-}
-
-#[tauri::command]
-async fn init_database() -> Result<String, String> {
-    match initialize_database().await {
-        Ok(_) => Ok("Database initialized successfully".to_string()),
-        Err(e) => Err(format!("Failed to initialize database: {}", e)),
+    {
+        Ok(attachment) => Ok(serde_json::to_value(attachment).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to add attachment: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn get_database_health() -> Result<DatabaseHealth, String> {
-    match check_database_health().await {
-        Ok(health) => Ok(health),
-        Err(e) => Err(format!("Failed to check database health: {}", e)),
-    }
-}
+async fn list_task_attachments(task_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = get_attachment_repository(db)?;
 
-#[tauri::command]
-async fn get_migration_status_cmd() -> Result<MigrationStatus, String> {
-    match get_migration_status().await {
-        Ok(status) => Ok(status),
-        Err(e) => Err(format!("Failed to get migration status: {}", e)),
+    match repo.find_by_task(&task_id).await {
+        Ok(attachments) => Ok(attachments
+            .into_iter()
+            .map(|attachment| {
+                let path = repo.file_path(&attachment);
+                let mut value = serde_json::to_value(attachment).unwrap_or_default();
+                value["path"] = serde_json::Value::String(path.to_string_lossy().into_owned());
+                value
+            })
+            .collect()),
+        Err(e) => Err(format!("Failed to list attachments: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn test_migration_compatibility_cmd() -> Result<MigrationTestResult, String> {
-    match test_migration_compatibility().await {
-        Ok(result) => Ok(result),
-        Err(e) => Err(format!("Failed to test migration compatibility: {}", e)),
+async fn remove_task_attachment(id: String) -> Result<String, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = get_attachment_repository(db)?;
+
+    match repo.remove_attachment(&id).await {
+        Ok(_) => Ok("Attachment removed successfully".to_string()),
+        Err(e) => Err(format!("Failed to remove attachment: {}", e)),
     }
 }
 
+/// List what `clean_up_attachments` would remove, for the user to confirm
 #[tauri::command]
-async fn run_post_migration_initialization() -> Result<String, String> {
-    match run_post_migration_init().await {
-        Ok(_) => Ok("Post-migration initialization completed successfully".to_string()),
-        Err(e) => Err(format!(
-            "Failed to run post-migration initialization: {}",
-            e
-        )),
+async fn preview_attachment_cleanup() -> Result<AttachmentCleanup, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = get_attachment_repository(db)?;
+
+    match repo.preview_cleanup().await {
+        Ok(cleanup) => Ok(cleanup),
+        Err(e) => Err(format!("Failed to preview attachment cleanup: {}", e)),
     }
 }
 
+/// Remove the attachments of archived tasks and untracked files in the attachments directory
 #[tauri::command]
-async fn validate_database_integrity() -> Result<DatabaseIntegrityReport, String> {
-    match validate_db_integrity().await {
-        Ok(report) => Ok(report),
-        Err(e) => Err(format!("Failed to validate database integrity: {}", e)),
+async fn clean_up_attachments() -> Result<AttachmentCleanup, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = get_attachment_repository(db)?;
+
+    match repo.clean_up().await {
+        Ok(cleanup) => Ok(cleanup),
+        Err(e) => Err(format!("Failed to clean up attachments: {}", e)),
     }
 }
 
 // ============================================================================
-// Task List Management Commands
+// Task Note Commands
 // ============================================================================
 
 #[tauri::command]
-async fn get_all_task_lists() -> Result<Vec<serde_json::Value>, String> {
+async fn add_task_note(request: CreateTaskNoteRequest) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskNoteRepository::new(db);
 
-    let task_list_repo = TaskListRepository::new(db);
-
-    match task_list_repo.find_all_task_lists().await {
-        Ok(task_lists) => {
-            let json_task_lists: Vec<serde_json::Value> = task_lists
-                .into_iter()
-                .map(|task_list| serde_json::to_value(task_list).unwrap())
-                .collect();
-            Ok(json_task_lists)
-        }
-        Err(e) => Err(format!("Failed to get task lists: {}", e)),
+    match repo.create_note(request).await {
+        Ok(note) => Ok(serde_json::to_value(note).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to add task note: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn create_task_list(request: CreateTaskListRequest) -> Result<serde_json::Value, String> {
+async fn get_task_notes(task_id: String) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskNoteRepository::new(db);
 
-    let task_list_repo = TaskListRepository::new(db);
-
-    match task_list_repo.create_task_list(request.name).await {
-        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap()),
-        Err(e) => Err(format!("Failed to create task list: {}", e)),
+    match repo.find_by_task(&task_id).await {
+        Ok(notes) => Ok(notes
+            .into_iter()
+            .map(|n| serde_json::to_value(n).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get task notes: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn update_task_list(
-    id: String,
-    request: UpdateTaskListRequest,
-) -> Result<serde_json::Value, String> {
+async fn update_task_note(id: String, content: String) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskNoteRepository::new(db);
 
-    let task_list_repo = TaskListRepository::new(db);
-
-    match task_list_repo.update_task_list(&id, request.name).await {
-        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap()),
-        Err(e) => Err(format!("Failed to update task list: {}", e)),
+    match repo.update_note(&id, &content).await {
+        Ok(note) => Ok(serde_json::to_value(note).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to update task note: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn delete_task_list(id: String) -> Result<String, String> {
+async fn delete_task_note(id: String) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskNoteRepository::new(db);
 
-    let task_list_repo = TaskListRepository::new(db);
-
-    match task_list_repo.delete_task_list(&id).await {
-        Ok(_) => Ok("Task list deleted successfully".to_string()),
-        Err(e) => Err(format!("Failed to delete task list: {}", e)),
+    match repo.delete_note(&id).await {
+        Ok(_) => Ok("Task note deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete task note: {}", e)),
     }
 }
 
+// ============================================================================
+// Task Source Commands
+// ============================================================================
+
+/// Where a task came from, so the UI can open the original; `None` for tasks created in the app
 #[tauri::command]
-async fn get_default_task_list() -> Result<serde_json::Value, String> {
+async fn get_task_source(id: String) -> Result<Option<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskSourceRepository::new(db);
 
-    let task_list_repo = TaskListRepository::new(db);
-
-    match task_list_repo.get_default_task_list().await {
-        Ok(task_list) => Ok(serde_json::to_value(task_list).unwrap()),
-        Err(e) => Err(format!("Failed to get default task list: {}", e)),
+    match repo.find_by_task(&id).await {
+        Ok(source) => Ok(source.map(|source| serde_json::to_value(source).unwrap_or_default())),
+        Err(e) => Err(format!("Failed to get task source: {}", e)),
     }
 }
 
+/// Record where a task came from, for tasks created from an email or a deep link
 #[tauri::command]
-async fn move_task_to_list(
-    task_id: String,
-    task_list_id: String,
-) -> Result<serde_json::Value, String> {
+async fn set_task_source(request: RecordTaskSourceRequest) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
-        .map_err(|e| format!("Database connection failed: {}", e))?;
-
-    let task_repo = TaskRepository::new(db.clone());
-    let task_list_repo = TaskListRepository::new(db);
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskSourceRepository::new(db);
 
-    // Validate that the task list exists
-    match task_list_repo.exists(&task_list_id).await {
-        Ok(false) => return Err(format!("Task list with ID '{}' not found", task_list_id)),
-        Err(e) => return Err(format!("Failed to validate task list: {}", e)),
-        Ok(true) => {}
+    match repo.record_source(request).await {
+        Ok(source) => Ok(serde_json::to_value(source).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to set task source: {}", e)),
     }
+}
 
-    // Perform the move operation
-    match task_repo.move_task_to_list(&task_id, &task_list_id).await {
-        Ok(task) => Ok(serde_json::to_value(task).unwrap()),
-        Err(e) => Err(format!(
-            "Failed to move task '{}' to list '{}': {}",
-            task_id, task_list_id, e
-        )),
+// ============================================================================
+// Task History Commands
+// ============================================================================
+
+/// Recorded changes to a task, newest first; still available after the task is deleted
+#[tauri::command]
+async fn get_task_history(task_id: String) -> Result<Vec<serde_json::Value>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskAuditRepository::new(db);
+
+    match repo.get_history(&task_id).await {
+        Ok(entries) => Ok(entries
+            .into_iter()
+            .map(|e| serde_json::to_value(e).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get task history: {}", e)),
     }
 }
 
+/// Delete task history entries older than `older_than_days`
 #[tauri::command]
-async fn get_tasks_by_task_list(task_list_id: String) -> Result<Vec<serde_json::Value>, String> {
+async fn cleanup_task_audit_log(older_than_days: u64) -> Result<u64, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = TaskAuditRepository::new(db);
 
-    let task_repo = TaskRepository::new(db);
+    let cutoff_date = chrono::Utc::now() - chrono::Duration::days(older_than_days as i64);
 
-    match task_repo.find_by_task_list(&task_list_id).await {
-        Ok(tasks) => {
-            let json_tasks: Vec<serde_json::Value> = tasks
-                .into_iter()
-                .map(|task| serde_json::to_value(task).unwrap())
-                .collect();
-            Ok(json_tasks)
-        }
-        Err(e) => Err(format!("Failed to get tasks by task list: {}", e)),
+    match repo.clear_older_than(cutoff_date).await {
+        Ok(deleted_count) => Ok(deleted_count),
+        Err(e) => Err(format!("Failed to clean up task history: {}", e)),
     }
 }
 
+/// Reverse the latest task change of this session (update, deletion or AI tool action)
+///
+/// Returns `null` when there is nothing left to undo.
 #[tauri::command]
-async fn get_task_list_stats() -> Result<TaskListStats, String> {
+async fn undo_last_change(
+    history: tauri::State<'_, UndoHistory>,
+) -> Result<Option<UndoneChange>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
 
-    let task_list_repo = TaskListRepository::new(db);
+    match history.undo(db).await {
+        Ok(change) => Ok(change),
+        Err(e) => Err(format!("Failed to undo change: {}", e)),
+    }
+}
 
-    match task_list_repo.get_task_list_stats().await {
-        Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to get task list stats: {}", e)),
+/// Apply the most recently undone change again
+///
+/// Returns `null` when nothing was undone or tasks changed since the last undo.
+#[tauri::command]
+async fn redo(history: tauri::State<'_, UndoHistory>) -> Result<Option<UndoneChange>, String> {
+    let db = get_database()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    match history.redo(db).await {
+        Ok(change) => Ok(change),
+        Err(e) => Err(format!("Failed to redo change: {}", e)),
     }
 }
 
 // ============================================================================
-// Backup & Restore Commands
+// Daily Note Commands
 // ============================================================================
 
+/// The note of a day (`YYYY-MM-DD`, local time) with the tasks completed and sessions tracked
 #[tauri::command]
-async fn export_data_to_file(file_path: String) -> Result<BackupMetadata, String> {
+async fn get_daily_note(date: String) -> Result<DailyNoteDay, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = DailyNoteRepository::new(db);
 
-    let backup_service = BackupService::new(db);
+    let date = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date: {}", e))?;
 
-    match backup_service.export_data(&file_path).await {
-        Ok(metadata) => Ok(metadata),
-        Err(e) => Err(format!("Failed to export data: {}", e)),
+    match repo.get_day(date, &chrono::Local).await {
+        Ok(day) => Ok(day),
+        Err(e) => Err(format!("Failed to get daily note: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn import_data_from_file(
-    file_path: String,
-    overwrite: bool,
-) -> Result<BackupMetadata, String> {
+async fn get_daily_notes(
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<serde_json::Value>, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = DailyNoteRepository::new(db);
 
-    let backup_service = BackupService::new(db);
+    let start = chrono::NaiveDate::parse_from_str(&start_date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid start date: {}", e))?;
+    let end = chrono::NaiveDate::parse_from_str(&end_date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid end date: {}", e))?;
 
-    match backup_service.import_data(&file_path, overwrite).await {
-        Ok(metadata) => Ok(metadata),
-        Err(e) => Err(format!("Failed to import data: {}", e)),
+    match repo.find_between(start, end).await {
+        Ok(notes) => Ok(notes
+            .into_iter()
+            .map(|n| serde_json::to_value(n).unwrap_or_default())
+            .collect()),
+        Err(e) => Err(format!("Failed to get daily notes: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn validate_backup_file(file_path: String) -> Result<BackupMetadata, String> {
+async fn save_daily_note(date: String, content: String) -> Result<serde_json::Value, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = DailyNoteRepository::new(db);
 
-    let backup_service = BackupService::new(db);
+    let date = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date: {}", e))?;
 
-    match backup_service.validate_backup(&file_path).await {
-        Ok(metadata) => Ok(metadata),
-        Err(e) => Err(format!("Failed to validate backup: {}", e)),
+    match repo.save_note(date, content).await {
+        Ok(note) => Ok(serde_json::to_value(note).unwrap_or_default()),
+        Err(e) => Err(format!("Failed to save daily note: {}", e)),
     }
 }
 
 #[tauri::command]
-async fn validate_backup_comprehensive(
-    file_path: String,
-) -> Result<backup::BackupValidationResult, String> {
+async fn delete_daily_note(date: String) -> Result<String, String> {
     let db = get_database()
         .await
         .map_err(|e| format!("Database error: {}", e))?;
+    let repo = DailyNoteRepository::new(db);
 
-    let backup_service = BackupService::new(db);
+    let date = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date: {}", e))?;
 
-    match backup_service
-        .validate_backup_comprehensive(&file_path)
-        .await
-    {
-        Ok(result) => Ok(result),
-        Err(e) => Err(format!("Failed to validate backup: {}", e)),
+    match repo.delete_note(date).await {
+        Ok(_) => Ok("Daily note deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete daily note: {}", e)),
+    }
+}
+
+// ============================================================================
+// Operation Commands
+// ============================================================================
+
+#[tauri::command]
+fn get_active_operations(registry: tauri::State<'_, OperationRegistry>) -> Vec<Operation> {
+    registry.active()
+}
+
+/// Register an operation running in the frontend (e.g. a model download)
+#[tauri::command]
+fn start_operation_tracking(app: tauri::AppHandle, kind: String, label: String) -> String {
+    start_operation(&app, &kind, &label).id().to_string()
+}
+
+#[tauri::command]
+fn report_operation_progress(
+    app: tauri::AppHandle,
+    id: String,
+    progress: f64,
+    message: String,
+) -> Result<(), String> {
+    match OperationHandle::find(&app, &id) {
+        Some(operation) => {
+            operation.progress(progress, &message);
+            Ok(())
+        }
+        None => Err(format!("Operation not found: {}", id)),
+    }
+}
+
+#[tauri::command]
+fn finish_operation(
+    app: tauri::AppHandle,
+    id: String,
+    error: Option<String>,
+) -> Result<(), String> {
+    match OperationHandle::find(&app, &id) {
+        Some(operation) => {
+            operation.finish_with(error);
+            Ok(())
+        }
+        None => Err(format!("Operation not found: {}", id)),
     }
 }
 
+// ============================================================================
+// Deep Link Commands
+// ============================================================================
+
+/// Links opened before the frontend was listening for `deep-link` events; clears them
+#[tauri::command]
+fn get_pending_deep_links(pending: tauri::State<'_, PendingDeepLinks>) -> Vec<DeepLinkEvent> {
+    pending.take()
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Initialize logging
     env_logger::init();
 
-    tauri::Builder::default()
+    let builder = tauri::Builder::default();
+    // A link opened while the app runs reaches this instance instead of starting another
+    #[cfg(desktop)]
+    let builder = builder.plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+        deep_link::focus_main_window(app)
+    }));
+
+    builder
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_sql::Builder::new().build())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_dialog::init())
-        .setup(|_app| {
+        .manage(OperationRegistry::default())
+        .manage(UndoHistory::default())
+        .manage(PendingDeepLinks::default())
+        .manage(IdleSettings::default())
+        .manage(PomodoroEngine::default())
+        .setup(|app| {
+            // Open the quick capture window from anywhere
+            #[cfg(desktop)]
+            quick_capture::register_shortcut(app.handle());
+            // Route kirapilot:// links to tasks, new tasks and timers
+            deep_link::register(app.handle());
+            // Fire native notifications for task reminders in the background
+            reminders::start_reminder_scheduler(app.handle().clone());
+            // Check database integrity weekly, notifying only about issues
+            integrity::start_integrity_scheduler(app.handle().clone());
+            // Refresh issues linked to tasks when GitHub auto refresh is on
+            github::start_github_scheduler();
+            // Retry webhook deliveries that failed
+            webhooks::start_webhook_scheduler();
+            // Notice the user going idle while a session runs
+            activity::start_idle_monitor(app.handle().clone());
+            // End Pomodoro phases on time and notify about breaks
+            pomodoro::start_pomodoro_ticker(app.handle().clone());
+            // Derive hourly and weekday productivity patterns from tracked time
+            patterns::start_pattern_recorder();
+            // Move unfinished tasks on from past days when rollover is on
+            rollover::start_rollover_scheduler(app.handle().clone());
+            // Warn when a goal streak is about to break
+            goals::start_goal_scheduler(app.handle().clone());
+
             // Initialize database on app startup
             tauri::async_runtime::spawn(async move {
                 if let Err(e) = initialize_database().await {
@@ -2060,7 +6197,19 @@ pub fn run() {
                 // Generate pending periodic task instances on startup
                 match get_database().await {
                     Ok(db) => {
-                        let engine = TaskGenerationEngine::new(db);
+                        // Drop suggestions nobody answered before they went stale
+                        if let Err(e) = AiSuggestionRepository::new(db.clone())
+                            .expire_stale(chrono::Utc::now())
+                            .await
+                        {
+                            eprintln!("Failed to expire stale AI suggestions on startup: {}", e);
+                        }
+
+                        let mut engine = TaskGenerationEngine::new(db.clone());
+                        match PreferencesRepository::new(db).get_working_hours().await {
+                            Ok(working_hours) => engine = engine.with_working_hours(working_hours),
+                            Err(e) => eprintln!("Failed to get working hours on startup: {}", e),
+                        }
                         match engine.check_and_generate_instances().await {
                             Ok(instances) => {
                                 if !instances.is_empty() {
@@ -2087,21 +6236,46 @@ pub fn run() {
             test_migration_compatibility_cmd,
             run_post_migration_initialization,
             validate_database_integrity,
+            get_integrity_history,
             // Task Management Commands
             create_task,
+            quick_capture,
+            parse_natural_date,
             get_task,
             get_task_with_dependencies,
             get_all_tasks,
             get_scheduled_tasks,
             get_backlog_tasks,
             update_task,
+            add_task_actual_time,
+            reorder_tasks,
+            reorder_task,
             delete_task,
             add_task_dependency,
             remove_task_dependency,
             get_task_dependencies,
             get_task_dependents,
+            add_task_relation,
+            remove_task_relation,
+            get_task_relations,
+            validate_dependency_graph,
+            get_dependency_schedule,
+            get_workload_report,
+            generate_daily_plan,
+            get_estimation_report,
+            groom_backlog,
             get_task_stats,
             search_tasks,
+            create_subtask,
+            get_subtasks,
+            get_task_tree,
+            reparent_task,
+            filter_tasks,
+            get_priority_matrix,
+            get_recommended_tasks,
+            archive_task,
+            unarchive_task,
+            get_archived_tasks,
             // Periodic Task Management Commands
             create_periodic_task_template,
             get_periodic_task_template,
@@ -2110,38 +6284,91 @@ pub fn run() {
             get_templates_needing_generation,
             update_periodic_task_template,
             delete_periodic_task_template,
+            pause_periodic_template,
+            resume_periodic_template,
+            skip_next_occurrence,
             get_template_instances,
             count_template_instances,
             calculate_next_generation_date,
+            preview_recurrence_dates,
             get_periodic_task_stats,
             generate_pending_instances,
             generate_instance_from_template,
             check_and_generate_instances,
             // Thread Management Commands
             create_thread,
+            create_thread_with_similar,
+            get_similar_threads,
             get_thread,
             get_all_threads,
             get_threads_by_task,
             get_threads_by_date,
             update_thread,
+            update_thread_summary,
             delete_thread,
             create_thread_message,
             get_thread_messages,
+            search_thread_messages,
+            get_thread_created_tasks,
             get_thread_message,
             update_thread_message,
             delete_thread_message,
             get_thread_statistics,
+            // AI Persona Commands
+            create_ai_persona,
+            get_ai_personas,
+            get_ai_persona,
+            get_thread_persona,
+            update_ai_persona,
+            delete_ai_persona,
+            set_thread_persona,
             // Task List Management Commands
             get_all_task_lists,
             create_task_list,
             update_task_list,
+            reorder_task_list,
+            reorder_task_lists,
+            archive_task_list,
+            unarchive_task_list,
             delete_task_list,
             get_default_task_list,
             move_task_to_list,
             get_tasks_by_task_list,
             get_task_list_stats,
+            set_task_list_targets,
+            set_task_list_hourly_rate,
+            get_task_list_aging_stats,
+            get_task_list_breakdown,
+            create_project,
+            get_all_projects,
+            get_project_progress,
+            update_project,
+            delete_project,
+            // Custom Field Commands
+            create_custom_field,
+            get_custom_fields,
+            update_custom_field,
+            delete_custom_field,
+            set_task_custom_field_value,
+            set_task_custom_field_values,
+            get_task_custom_field_values,
+            // Task Status Commands
+            get_task_statuses,
+            create_task_status,
+            update_task_status,
+            delete_task_status,
+            // Tag Commands
+            get_all_tags,
+            rename_tag,
+            merge_tags,
+            get_tasks_by_tags,
             // Time Tracking Commands
             create_time_session,
+            log_time_session,
+            add_manual_time_entry,
+            adjust_time_session,
+            split_time_session,
+            merge_time_sessions,
             get_time_session,
             get_active_session,
             get_any_active_session,
@@ -2150,9 +6377,34 @@ pub fn run() {
             update_time_session,
             stop_time_session,
             pause_time_session,
+            start_break,
+            end_break,
+            get_working_hours,
+            save_working_hours,
+            get_workday_context,
+            get_rollover_mode,
+            save_rollover_mode,
+            get_prompt_templates,
+            update_prompt_templates,
+            reset_prompt_templates,
+            create_goal,
+            get_goal_progress,
+            delete_goal,
+            set_idle_threshold,
+            get_power_state,
+            discard_idle_time,
             resume_time_session,
+            get_session_pauses,
             delete_time_session,
+            complete_subtask_during_session,
+            get_session_completed_subtasks,
             get_time_stats,
+            get_productivity_report,
+            get_interruption_report,
+            get_daily_time_aggregates,
+            get_billing_report,
+            export_billing_report,
+            export_timesheet,
             get_task_total_time,
             get_recent_sessions,
             get_sessions_with_tasks,
@@ -2178,9 +6430,41 @@ pub fn run() {
             get_tool_execution_logs,
             clear_all_ai_interaction_logs,
             cleanup_old_ai_interaction_logs,
+            save_ai_suggestions,
+            get_active_ai_suggestions,
+            get_interaction_suggestions,
+            accept_ai_suggestion,
+            dismiss_ai_suggestion,
+            expire_ai_suggestions,
             export_ai_interaction_logs,
             anonymize_ai_interaction_logs,
             redact_sensitive_data,
+            export_conversation_dataset,
+            export_calendar,
+            sync_caldav,
+            get_caldav_sync_status,
+            reset_caldav_sync,
+            get_github_settings,
+            save_github_settings,
+            delete_github_settings,
+            link_task_to_github_issue,
+            unlink_task_from_github_issue,
+            get_task_github_link,
+            import_github_issues,
+            refresh_github_issues,
+            get_ai_interaction_replay,
+            save_ai_replay_comparison,
+            get_ai_replay_comparisons,
+            delete_ai_replay_comparison,
+            get_prompt_eval_suite,
+            save_prompt_eval_run,
+            get_prompt_eval_runs,
+            delete_prompt_eval_run,
+            compare_prompt_eval_runs,
+            record_ai_provider_request,
+            get_provider_metrics,
+            check_ai_budget,
+            get_ai_cost_report,
             get_logging_config,
             update_logging_config,
             clear_all_data,
@@ -2188,7 +6472,119 @@ pub fn run() {
             export_data_to_file,
             import_data_from_file,
             validate_backup_file,
-            validate_backup_comprehensive
+            validate_backup_comprehensive,
+            rehearse_backup_restore,
+            // Task Sharing Commands
+            export_task_bundle,
+            export_task_list_bundle,
+            inspect_task_bundle,
+            import_task_bundle,
+            preview_task_import,
+            import_tasks,
+            preview_time_import,
+            import_time_entries,
+            preview_todoist_import,
+            import_todoist,
+            // Saved Filter Commands
+            create_saved_filter,
+            get_saved_filters,
+            update_saved_filter,
+            delete_saved_filter,
+            get_tasks_by_saved_filter,
+            // Task Template Commands
+            create_task_template,
+            get_task_template,
+            get_task_templates,
+            update_task_template,
+            delete_task_template,
+            save_task_as_template,
+            instantiate_task_template,
+            // Webhook Commands
+            create_webhook,
+            get_webhooks,
+            update_webhook,
+            delete_webhook,
+            get_webhook_deliveries,
+            // API Token Commands
+            create_api_token,
+            get_api_tokens,
+            revoke_api_token,
+            authorize_command,
+            // Template Pack Commands
+            export_template_pack,
+            inspect_template_pack,
+            import_template_pack,
+            export_periodic_templates,
+            import_periodic_templates,
+            // Settings Bundle Commands
+            export_settings_bundle,
+            inspect_settings_bundle,
+            import_settings_bundle,
+            // Focus Template Commands
+            start_focus_session,
+            get_focus_session,
+            get_active_focus_session,
+            get_task_focus_sessions,
+            get_focus_sessions_between,
+            record_focus_distraction,
+            add_focus_break,
+            complete_focus_session,
+            delete_focus_session,
+            get_focus_stats,
+            record_productivity_pattern,
+            get_productivity_patterns,
+            get_pattern_insights,
+            derive_productivity_patterns,
+            delete_productivity_pattern,
+            create_focus_template,
+            get_focus_templates,
+            get_focus_template,
+            update_focus_template,
+            delete_focus_template,
+            start_focus_from_template,
+            start_pomodoro,
+            skip_break,
+            stop_pomodoro,
+            get_pomodoro_state,
+            // Reminder Commands
+            set_task_reminders,
+            get_task_reminders,
+            get_upcoming_reminders,
+            snooze_reminder,
+            remind_again_in,
+            delete_task_reminder,
+            get_notification_log,
+            create_checklist_item,
+            get_checklist_items,
+            update_checklist_item,
+            reorder_checklist_item,
+            delete_checklist_item,
+            add_task_attachment,
+            list_task_attachments,
+            remove_task_attachment,
+            preview_attachment_cleanup,
+            clean_up_attachments,
+            add_task_note,
+            get_task_notes,
+            update_task_note,
+            delete_task_note,
+            get_task_source,
+            set_task_source,
+            get_task_history,
+            cleanup_task_audit_log,
+            undo_last_change,
+            redo,
+            get_daily_note,
+            get_daily_notes,
+            save_daily_note,
+            delete_daily_note,
+            // Operation Commands
+            get_active_operations,
+            start_operation_tracking,
+            report_operation_progress,
+            finish_operation,
+            // Deep Link Commands
+            get_pending_deep_links
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use chrono::Utc;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+use crate::database::repositories::notification_repository::ReserveNotificationRequest;
+use crate::database::repositories::NotificationRepository;
+
+/// Most notifications shown per hour; further alerts are logged as rate limited and dropped
+const MAX_PER_HOUR: u64 = 10;
+
+/// How long delivery records are kept to recognize alerts that were already sent
+const RETENTION_DAYS: i64 = 7;
+
+/// What happened to a notification passed to the dispatcher
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    Shown,
+    /// Its key was delivered before, e.g. by a run that ended before recording the reminder
+    Duplicate,
+    RateLimited,
+}
+
+/// Shows native notifications at most once per key and at most `MAX_PER_HOUR` an hour
+///
+/// Delivery state is stored in the database, so a restart doesn't replay alerts.
+pub struct NotificationDispatcher<'a> {
+    app: &'a AppHandle,
+    repo: NotificationRepository,
+}
+
+impl<'a> NotificationDispatcher<'a> {
+    pub fn new(app: &'a AppHandle, db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            app,
+            repo: NotificationRepository::new(db),
+        }
+    }
+
+    /// Show a notification unless `dedup_key` was delivered before or the hourly limit is hit
+    pub async fn send(
+        &self,
+        kind: &str,
+        dedup_key: String,
+        title: &str,
+        body: String,
+    ) -> Result<Delivery> {
+        let Some(delivery) = self
+            .repo
+            .reserve(
+                ReserveNotificationRequest {
+                    dedup_key,
+                    kind: kind.to_string(),
+                    title: title.to_string(),
+                    body: body.clone(),
+                },
+                Utc::now(),
+                MAX_PER_HOUR,
+            )
+            .await?
+        else {
+            return Ok(Delivery::Duplicate);
+        };
+        if delivery.status == "rate_limited" {
+            return Ok(Delivery::RateLimited);
+        }
+
+        let shown = self
+            .app
+            .notification()
+            .builder()
+            .title(title)
+            .body(body)
+            .show();
+        if let Err(e) = shown {
+            // Free the key so the next scan tries again
+            self.repo.release(&delivery.id).await?;
+            return Err(e.into());
+        }
+        Ok(Delivery::Shown)
+    }
+
+    /// Delete delivery records past the retention period
+    pub async fn prune(&self) -> Result<u64> {
+        let cutoff = Utc::now() - chrono::Duration::days(RETENTION_DAYS);
+        Ok(self.repo.delete_older_than(cutoff).await?)
+    }
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Event carrying an `Operation` whenever a running operation starts or reports progress
+pub const OPERATION_PROGRESS_EVENT: &str = "operation-progress";
+
+/// Event carrying the final `Operation` once it completed or failed
+pub const OPERATION_COMPLETED_EVENT: &str = "operation-completed";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// A long-running operation such as an import, export or backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Operation {
+    pub id: String,
+    /// What runs, e.g. "backup_export" or "model_download"
+    pub kind: String,
+    /// Human-readable description for the status bar
+    pub label: String,
+    pub status: OperationStatus,
+    /// Share of the work done, 0-1; `None` while the amount of work is unknown
+    pub progress: Option<f64>,
+    pub message: Option<String>,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// Operations currently running, shared as Tauri state
+///
+/// Finished operations are removed once their completion event has been emitted.
+#[derive(Default)]
+pub struct OperationRegistry {
+    operations: Mutex<HashMap<String, Operation>>,
+}
+
+impl OperationRegistry {
+    /// Running operations, oldest first
+    pub fn active(&self) -> Vec<Operation> {
+        let mut operations: Vec<Operation> = self.lock().values().cloned().collect();
+        operations.sort_by_key(|operation| operation.started_at);
+        operations
+    }
+
+    fn insert(&self, operation: Operation) {
+        self.lock().insert(operation.id.clone(), operation);
+    }
+
+    fn update(&self, id: &str, apply: impl FnOnce(&mut Operation)) -> Option<Operation> {
+        let mut operations = self.lock();
+        let operation = operations.get_mut(id)?;
+        apply(operation);
+        Some(operation.clone())
+    }
+
+    fn remove(&self, id: &str) -> Option<Operation> {
+        self.lock().remove(id)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Operation>> {
+        // A panic while holding the lock leaves the map itself intact
+        self.operations
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Handle for reporting on one registered operation
+#[derive(Clone)]
+pub struct OperationHandle {
+    app: AppHandle,
+    id: String,
+}
+
+/// Register a new running operation and announce it
+pub fn start_operation(app: &AppHandle, kind: &str, label: &str) -> OperationHandle {
+    let operation = Operation {
+        id: uuid::Uuid::new_v4().to_string(),
+        kind: kind.to_string(),
+        label: label.to_string(),
+        status: OperationStatus::Running,
+        progress: None,
+        message: None,
+        error: None,
+        started_at: Utc::now(),
+        finished_at: None,
+    };
+
+    app.state::<OperationRegistry>().insert(operation.clone());
+    emit(app, OPERATION_PROGRESS_EVENT, &operation);
+
+    OperationHandle {
+        app: app.clone(),
+        id: operation.id,
+    }
+}
+
+impl OperationHandle {
+    /// Handle of an operation registered earlier, e.g. by the frontend
+    pub fn find(app: &AppHandle, id: &str) -> Option<Self> {
+        app.state::<OperationRegistry>()
+            .active()
+            .iter()
+            .any(|operation| operation.id == id)
+            .then(|| Self {
+                app: app.clone(),
+                id: id.to_string(),
+            })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Report progress (0-1, clamped) with a short description of the current step
+    pub fn progress(&self, progress: f64, message: &str) {
+        let updated = self
+            .app
+            .state::<OperationRegistry>()
+            .update(&self.id, |operation| {
+                operation.progress = Some(progress.clamp(0.0, 1.0));
+                operation.message = Some(message.to_string());
+            });
+        if let Some(operation) = updated {
+            emit(&self.app, OPERATION_PROGRESS_EVENT, &operation);
+        }
+    }
+
+    /// A progress callback for services that don't know about Tauri
+    pub fn reporter(&self) -> impl Fn(f64, &str) + Send + Sync + 'static {
+        let handle = self.clone();
+        move |progress, message| handle.progress(progress, message)
+    }
+
+    /// Mark the operation completed or failed depending on `result`
+    pub fn finish<T, E: Display>(&self, result: &Result<T, E>) {
+        match result {
+            Ok(_) => self.finish_with(None),
+            Err(e) => self.finish_with(Some(e.to_string())),
+        }
+    }
+
+    /// Mark the operation completed, or failed when an error is given
+    pub fn finish_with(&self, error: Option<String>) {
+        let Some(mut operation) = self.app.state::<OperationRegistry>().remove(&self.id) else {
+            return;
+        };
+
+        operation.finished_at = Some(Utc::now());
+        if error.is_some() {
+            operation.status = OperationStatus::Failed;
+            operation.error = error;
+        } else {
+            operation.status = OperationStatus::Completed;
+            operation.progress = Some(1.0);
+        }
+        emit(&self.app, OPERATION_COMPLETED_EVENT, &operation);
+    }
+}
+
+fn emit(app: &AppHandle, event: &str, operation: &Operation) {
+    if let Err(e) = app.emit(event, operation.clone()) {
+        eprintln!("Failed to emit {} event: {}", event, e);
+    }
+}
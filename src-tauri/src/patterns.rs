@@ -0,0 +1,200 @@
+use anyhow::Result;
+use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::database::entities::{focus_sessions, productivity_patterns, time_sessions};
+use crate::database::get_database;
+use crate::database::repositories::focus_repository::FocusRepository;
+use crate::database::repositories::pattern_repository::{CreatePatternRequest, PatternRepository};
+use crate::database::repositories::TimeTrackingRepository;
+
+/// The app has a single user; patterns are stored under the ID the frontend uses for them
+pub const LOCAL_USER_ID: &str = "current-user";
+
+/// Pattern types derived from tracked time, replaced on every run
+pub const DERIVED_PATTERN_TYPES: [&str; 2] = ["hourly", "daily"];
+
+/// How often patterns are derived again
+const DERIVE_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Days of sessions patterns are derived from
+const HISTORY_DAYS: i64 = 28;
+
+/// Sessions a time slot needs for full confidence
+const FULL_CONFIDENCE_SAMPLES: f64 = 10.0;
+
+/// Weight of the share of work time in the productivity score when focus scores are known
+const WORK_WEIGHT: f64 = 0.6;
+
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Start the background loop that derives hourly and weekday patterns from tracked time
+pub fn start_pattern_recorder() {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(DERIVE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = record().await {
+                eprintln!("Failed to derive productivity patterns: {}", e);
+            }
+        }
+    });
+}
+
+async fn record() -> Result<()> {
+    let db = get_database().await?;
+    record_derived_patterns(db, Utc::now(), &chrono::Local).await?;
+    Ok(())
+}
+
+/// Derive patterns from the sessions of the last four weeks before `now` and store them in
+/// place of the previously derived ones
+///
+/// Hours and weekdays run in `timezone`.
+pub async fn record_derived_patterns<Tz: TimeZone>(
+    db: Arc<DatabaseConnection>,
+    now: DateTime<Utc>,
+    timezone: &Tz,
+) -> Result<Vec<productivity_patterns::Model>, DbErr> {
+    let since = now - chrono::Duration::days(HISTORY_DAYS);
+    let sessions = TimeTrackingRepository::new(db.clone())
+        .find_sessions_between(since, now)
+        .await?;
+    let focus_sessions = FocusRepository::new(db.clone())
+        .find_sessions_between(since, now)
+        .await?;
+
+    let patterns = derive_patterns(&sessions, &focus_sessions, timezone);
+    PatternRepository::new(db)
+        .replace_patterns(LOCAL_USER_ID, &DERIVED_PATTERN_TYPES, patterns)
+        .await
+}
+
+#[derive(Default)]
+struct SlotSample {
+    work_seconds: f64,
+    sessions: usize,
+    focus_scores: Vec<f64>,
+}
+
+/// Hourly (`HH:00`) and weekday patterns from finished time sessions and completed focus
+/// sessions
+///
+/// A slot's productivity score is its work time against the busiest slot, blended with the
+/// average focus score of the focus sessions started in it; both are from 0 to 1.
+pub(crate) fn derive_patterns<Tz: TimeZone>(
+    sessions: &[time_sessions::Model],
+    focus_sessions: &[focus_sessions::Model],
+    timezone: &Tz,
+) -> Vec<CreatePatternRequest> {
+    let mut hours: BTreeMap<u32, SlotSample> = BTreeMap::new();
+    let mut days: BTreeMap<u32, SlotSample> = BTreeMap::new();
+
+    for session in sessions {
+        let Some(end) = session.end_time.filter(|end| *end > session.start_time) else {
+            continue;
+        };
+        let total = (end - session.start_time).num_seconds() as f64;
+        let breaks = TimeTrackingRepository::parse_breaks(session.breaks.as_deref());
+        let idle = session.paused_time as f64
+            + TimeTrackingRepository::break_seconds(&breaks, session.start_time, end) as f64;
+        let worked_share = ((total - idle) / total).clamp(0.0, 1.0);
+
+        let mut touched_hours = HashSet::new();
+        let mut touched_days = HashSet::new();
+        let mut cursor = session.start_time;
+        while cursor < end {
+            let local = cursor.with_timezone(timezone);
+            let next_hour = cursor
+                + chrono::Duration::seconds(3600 - (local.minute() * 60 + local.second()) as i64)
+                - chrono::Duration::nanoseconds(local.nanosecond() as i64);
+            let slice_end = next_hour.min(end);
+            let worked = (slice_end - cursor).num_seconds() as f64 * worked_share;
+
+            let weekday = local.weekday().num_days_from_monday();
+            hours.entry(local.hour()).or_default().work_seconds += worked;
+            days.entry(weekday).or_default().work_seconds += worked;
+            touched_hours.insert(local.hour());
+            touched_days.insert(weekday);
+            cursor = slice_end;
+        }
+        for hour in touched_hours {
+            hours.entry(hour).or_default().sessions += 1;
+        }
+        for day in touched_days {
+            days.entry(day).or_default().sessions += 1;
+        }
+    }
+
+    for focus_session in focus_sessions {
+        if focus_session.completed_at.is_none() {
+            continue;
+        }
+        let local = focus_session.created_at.with_timezone(timezone);
+        let weekday = local.weekday().num_days_from_monday();
+        for sample in [
+            hours.entry(local.hour()).or_default(),
+            days.entry(weekday).or_default(),
+        ] {
+            sample.sessions += 1;
+            if let Some(score) = focus_session.focus_score {
+                sample.focus_scores.push(score.clamp(0.0, 100.0) / 100.0);
+            }
+        }
+    }
+
+    let mut patterns = slot_patterns("hourly", hours, |hour| format!("{:02}:00", hour));
+    patterns.extend(slot_patterns("daily", days, |day| {
+        WEEKDAYS[*day as usize].to_string()
+    }));
+    patterns
+}
+
+fn slot_patterns(
+    pattern_type: &str,
+    samples: BTreeMap<u32, SlotSample>,
+    time_slot: impl Fn(&u32) -> String,
+) -> Vec<CreatePatternRequest> {
+    let busiest = samples
+        .values()
+        .map(|sample| sample.work_seconds)
+        .fold(0.0, f64::max);
+
+    samples
+        .iter()
+        .map(|(slot, sample)| {
+            let work_share = if busiest > 0.0 {
+                sample.work_seconds / busiest
+            } else {
+                0.0
+            };
+            let productivity_score = if sample.focus_scores.is_empty() {
+                work_share
+            } else {
+                let focus =
+                    sample.focus_scores.iter().sum::<f64>() / sample.focus_scores.len() as f64;
+                WORK_WEIGHT * work_share + (1.0 - WORK_WEIGHT) * focus
+            };
+
+            CreatePatternRequest {
+                user_id: LOCAL_USER_ID.to_string(),
+                pattern_type: pattern_type.to_string(),
+                time_slot: time_slot(slot),
+                productivity_score,
+                confidence_level: (sample.sessions as f64 / FULL_CONFIDENCE_SAMPLES).min(1.0),
+                sample_size: sample.sessions as i32,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,372 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::Mutex;
+
+use crate::database::entities::focus_sessions;
+use crate::database::get_database;
+use crate::database::repositories::focus_repository::{
+    CreateFocusSessionRequest, FocusBreak, FocusRepository, UpdateFocusSessionRequest,
+};
+use crate::database::repositories::FocusTemplateRepository;
+use crate::notifications::NotificationDispatcher;
+
+/// Emitted with the new `PomodoroState` when a phase ends on its own
+pub const POMODORO_EVENT: &str = "pomodoro-changed";
+
+/// How often the running phase is checked for having ended
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PomodoroPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl PomodoroPhase {
+    fn as_str(self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "work",
+            PomodoroPhase::ShortBreak => "short_break",
+            PomodoroPhase::LongBreak => "long_break",
+        }
+    }
+}
+
+/// Durations of a Pomodoro cycle in minutes, laid out like a focus template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PomodoroConfig {
+    pub focus_duration: i32,
+    pub short_break_duration: i32,
+    pub long_break_duration: i32,
+    pub sessions_before_long_break: i32,
+    pub distraction_level: String,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            focus_duration: 25,
+            short_break_duration: 5,
+            long_break_duration: 15,
+            sessions_before_long_break: 4,
+            distraction_level: "minimal".to_string(),
+        }
+    }
+}
+
+impl PomodoroConfig {
+    fn validate(&self) -> Result<(), DbErr> {
+        FocusTemplateRepository::validate_durations(
+            self.focus_duration,
+            self.short_break_duration,
+            self.long_break_duration,
+            self.sessions_before_long_break,
+        )?;
+        FocusTemplateRepository::validate_distraction_level(&self.distraction_level)
+    }
+
+    fn minutes(&self, phase: PomodoroPhase) -> i32 {
+        match phase {
+            PomodoroPhase::Work => self.focus_duration,
+            PomodoroPhase::ShortBreak => self.short_break_duration,
+            PomodoroPhase::LongBreak => self.long_break_duration,
+        }
+    }
+}
+
+/// Where a running Pomodoro cycle is
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PomodoroState {
+    pub task_id: String,
+    pub phase: PomodoroPhase,
+    /// Work phases finished since the cycle started
+    pub completed_work_sessions: i32,
+    /// Focus session of the current work phase, or of the one the break follows
+    pub focus_session_id: String,
+    pub phase_started_at: DateTime<Utc>,
+    pub phase_ends_at: DateTime<Utc>,
+    pub config: PomodoroConfig,
+}
+
+impl PomodoroState {
+    pub(crate) fn new(
+        task_id: String,
+        config: PomodoroConfig,
+        focus_session_id: String,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            task_id,
+            phase: PomodoroPhase::Work,
+            completed_work_sessions: 0,
+            focus_session_id,
+            phase_started_at: now,
+            phase_ends_at: now + chrono::Duration::minutes(config.focus_duration as i64),
+            config,
+        }
+    }
+
+    /// Phase that follows the current one; breaks of zero minutes are left out
+    pub(crate) fn next_phase(&self) -> PomodoroPhase {
+        if self.phase != PomodoroPhase::Work {
+            return PomodoroPhase::Work;
+        }
+        let completed = self.completed_work_sessions + 1;
+        let phase = if completed % self.config.sessions_before_long_break == 0 {
+            PomodoroPhase::LongBreak
+        } else {
+            PomodoroPhase::ShortBreak
+        };
+        if self.config.minutes(phase) == 0 {
+            PomodoroPhase::Work
+        } else {
+            phase
+        }
+    }
+
+    /// Move on to `phase` at `now`, counting the work phase being left
+    pub(crate) fn enter(
+        &mut self,
+        phase: PomodoroPhase,
+        focus_session_id: String,
+        now: DateTime<Utc>,
+    ) {
+        if self.phase == PomodoroPhase::Work {
+            self.completed_work_sessions += 1;
+        }
+        self.phase = phase;
+        self.focus_session_id = focus_session_id;
+        self.phase_started_at = now;
+        self.phase_ends_at = now + chrono::Duration::minutes(self.config.minutes(phase) as i64);
+    }
+}
+
+/// The Pomodoro cycle being run, shared as Tauri state
+///
+/// Every work phase is a focus session on the task, and every break is recorded on the focus
+/// session it follows. Only one cycle runs at a time.
+#[derive(Default)]
+pub struct PomodoroEngine {
+    state: Mutex<Option<PomodoroState>>,
+}
+
+impl PomodoroEngine {
+    pub async fn state(&self) -> Option<PomodoroState> {
+        self.state.lock().await.clone()
+    }
+
+    /// Start a cycle on a task with its first work phase
+    pub async fn start(
+        &self,
+        db: Arc<DatabaseConnection>,
+        task_id: String,
+        config: PomodoroConfig,
+    ) -> Result<PomodoroState, DbErr> {
+        config.validate()?;
+        let mut state = self.state.lock().await;
+        if state.is_some() {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: A Pomodoro is already running".to_string(),
+            ));
+        }
+
+        let session = start_work_session(&FocusRepository::new(db), &task_id, &config).await?;
+        let started = PomodoroState::new(task_id, config, session.id, Utc::now());
+        *state = Some(started.clone());
+        Ok(started)
+    }
+
+    /// End the current break early and start the next work phase
+    pub async fn skip_break(&self, db: Arc<DatabaseConnection>) -> Result<PomodoroState, DbErr> {
+        let mut state = self.state.lock().await;
+        let current = state.as_mut().ok_or_else(|| {
+            DbErr::Custom("BUSINESS_RULE_ERROR: No Pomodoro is running".to_string())
+        })?;
+        if current.phase == PomodoroPhase::Work {
+            return Err(DbErr::Custom(
+                "BUSINESS_RULE_ERROR: There is no break to skip".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        advance(&FocusRepository::new(db), current, now, now).await?;
+        Ok(current.clone())
+    }
+
+    /// Stop the cycle, recording the current phase up to now
+    pub async fn stop(&self, db: Arc<DatabaseConnection>) -> Result<Option<PomodoroState>, DbErr> {
+        let mut state = self.state.lock().await;
+        let Some(current) = state.as_ref() else {
+            return Ok(None);
+        };
+
+        finish_phase(&FocusRepository::new(db), current, Utc::now()).await?;
+        Ok(state.take())
+    }
+
+    /// Move past a phase whose time is up, returning the new state
+    async fn advance_if_due(
+        &self,
+        db: Arc<DatabaseConnection>,
+    ) -> Result<Option<PomodoroState>, DbErr> {
+        let mut state = self.state.lock().await;
+        let now = Utc::now();
+        let Some(current) = state
+            .as_mut()
+            .filter(|current| current.phase_ends_at <= now)
+        else {
+            return Ok(None);
+        };
+
+        // The phase ended on time even if the app was asleep; the next one starts now
+        let ended_at = current.phase_ends_at;
+        advance(&FocusRepository::new(db), current, ended_at, now).await?;
+        Ok(Some(current.clone()))
+    }
+}
+
+/// Start the background loop that ends Pomodoro phases on time and notifies about them
+pub fn start_pomodoro_ticker(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(TICK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = tick(&app).await {
+                eprintln!("Failed to advance the Pomodoro: {}", e);
+            }
+        }
+    });
+}
+
+async fn tick(app: &AppHandle) -> Result<()> {
+    let engine = app.state::<PomodoroEngine>();
+    // Skip the database while nothing runs
+    if engine.state.lock().await.is_none() {
+        return Ok(());
+    }
+
+    let db = get_database().await?;
+    let Some(state) = engine.advance_if_due(db.clone()).await? else {
+        return Ok(());
+    };
+
+    let (title, body) = notification_text(&state);
+    let dedup_key = format!(
+        "pomodoro:{}:{}:{}",
+        state.focus_session_id,
+        state.phase.as_str(),
+        state.completed_work_sessions
+    );
+    if let Err(e) = NotificationDispatcher::new(app, db)
+        .send("pomodoro", dedup_key, title, body)
+        .await
+    {
+        eprintln!("Failed to notify about the Pomodoro: {}", e);
+    }
+    app.emit(POMODORO_EVENT, state)?;
+    Ok(())
+}
+
+fn notification_text(state: &PomodoroState) -> (&'static str, String) {
+    let minutes = state.config.minutes(state.phase);
+    match state.phase {
+        PomodoroPhase::Work => ("Back to work", format!("Focus for {} minutes.", minutes)),
+        PomodoroPhase::ShortBreak => (
+            "Time for a break",
+            format!("Take {} minutes before the next Pomodoro.", minutes),
+        ),
+        PomodoroPhase::LongBreak => (
+            "Time for a long break",
+            format!(
+                "{} Pomodoros done. Take {} minutes.",
+                state.completed_work_sessions, minutes
+            ),
+        ),
+    }
+}
+
+/// Finish the current phase at `ended_at` and start the next one at `now`
+async fn advance(
+    repo: &FocusRepository,
+    state: &mut PomodoroState,
+    ended_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<(), DbErr> {
+    finish_phase(repo, state, ended_at).await?;
+    let next = state.next_phase();
+    let focus_session_id = if next == PomodoroPhase::Work {
+        start_work_session(repo, &state.task_id, &state.config)
+            .await?
+            .id
+    } else {
+        state.focus_session_id.clone()
+    };
+    state.enter(next, focus_session_id, now);
+    Ok(())
+}
+
+/// Record the current phase as ended at `ended_at`: a work phase completes its focus session, a
+/// break is added to the focus session it follows
+async fn finish_phase(
+    repo: &FocusRepository,
+    state: &PomodoroState,
+    ended_at: DateTime<Utc>,
+) -> Result<(), DbErr> {
+    let break_type = match state.phase {
+        PomodoroPhase::Work => {
+            let minutes = (ended_at - state.phase_started_at).num_minutes();
+            repo.update_session(
+                &state.focus_session_id,
+                UpdateFocusSessionRequest {
+                    actual_duration: Some(minutes as i32),
+                    focus_score: None,
+                    distraction_count: None,
+                    distraction_level: None,
+                    background_audio: None,
+                    notes: None,
+                    breaks: None,
+                    metrics: None,
+                    completed_at: Some(ended_at),
+                },
+            )
+            .await?;
+            return Ok(());
+        }
+        PomodoroPhase::ShortBreak => "short",
+        PomodoroPhase::LongBreak => "long",
+    };
+
+    repo.add_break(
+        &state.focus_session_id,
+        FocusBreak {
+            start_time: state.phase_started_at,
+            end_time: ended_at,
+            break_type: break_type.to_string(),
+            reason: None,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+async fn start_work_session(
+    repo: &FocusRepository,
+    task_id: &str,
+    config: &PomodoroConfig,
+) -> Result<focus_sessions::Model, DbErr> {
+    repo.create_session(CreateFocusSessionRequest {
+        task_id: task_id.to_string(),
+        planned_duration: config.focus_duration,
+        distraction_level: config.distraction_level.clone(),
+        background_audio: None,
+        notes: None,
+    })
+    .await
+}
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+/// Where the machine draws its power from, for going easy on local models while on battery
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PowerState {
+    /// True when running from the battery rather than a charger
+    pub on_battery: bool,
+    /// Charge left, from 0 to 100; unset when there is no battery or it can't be read
+    pub battery_percent: Option<u8>,
+}
+
+/// Read the output of `pmset -g batt`
+///
+/// The first line names the power source, e.g. `Now drawing from 'Battery Power'`, and the
+/// battery line carries the charge as `85%;`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub(crate) fn parse_pmset(output: &str) -> Option<PowerState> {
+    let source = output.lines().next()?;
+    if !source.contains("drawing from") {
+        return None;
+    }
+    let battery_percent = output
+        .lines()
+        .skip(1)
+        .find_map(|line| {
+            let (charge, _) = line.split_once("%;")?;
+            charge.rsplit(char::is_whitespace).next()?.parse().ok()
+        })
+        .map(|percent: u8| percent.min(100));
+    Some(PowerState {
+        on_battery: source.contains("'Battery Power'"),
+        battery_percent,
+    })
+}
+
+/// One entry of `/sys/class/power_supply`, with the attributes read as written there
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PowerSupply {
+    pub kind: String,
+    pub online: Option<String>,
+    pub capacity: Option<String>,
+}
+
+/// Combine the power supplies the kernel lists
+///
+/// The machine is on battery when a battery is present and no mains or USB supply is online.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn read_power_supplies(supplies: &[PowerSupply]) -> PowerState {
+    let external_online = supplies.iter().any(|supply| {
+        matches!(supply.kind.trim(), "Mains" | "USB" | "USB_C" | "USB_PD")
+            && supply.online.as_deref().map(str::trim) == Some("1")
+    });
+    let mut batteries = supplies
+        .iter()
+        .filter(|supply| supply.kind.trim() == "Battery")
+        .peekable();
+    let has_battery = batteries.peek().is_some();
+    let battery_percent = batteries
+        .find_map(|supply| supply.capacity.as_deref()?.trim().parse::<u8>().ok())
+        .map(|percent| percent.min(100));
+
+    PowerState {
+        on_battery: has_battery && !external_online,
+        battery_percent,
+    }
+}
+
+/// Power source and battery charge, if the platform tells
+#[cfg(windows)]
+pub fn power_state() -> Option<PowerState> {
+    use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    // SAFETY: SYSTEM_POWER_STATUS is plain data, all zeroes is a valid value
+    let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+    // SAFETY: `status` is a valid SYSTEM_POWER_STATUS for the call to fill
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        return None;
+    }
+    // 128 flags a machine without a battery, 255 an unknown charge
+    let has_battery = status.BatteryFlag != 128;
+    Some(PowerState {
+        on_battery: has_battery && status.ACLineStatus == 0,
+        battery_percent: Some(status.BatteryLifePercent)
+            .filter(|percent| has_battery && *percent <= 100),
+    })
+}
+
+/// Power source and battery charge, read from `pmset`
+#[cfg(target_os = "macos")]
+pub fn power_state() -> Option<PowerState> {
+    let output = std::process::Command::new("pmset")
+        .args(["-g", "batt"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    parse_pmset(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Power source and battery charge, read from the kernel's power supply class
+#[cfg(target_os = "linux")]
+pub fn power_state() -> Option<PowerState> {
+    let read = |path: &std::path::Path, name: &str| std::fs::read_to_string(path.join(name)).ok();
+    let supplies: Vec<PowerSupply> = std::fs::read_dir("/sys/class/power_supply")
+        .ok()?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            Some(PowerSupply {
+                kind: read(&path, "type")?,
+                online: read(&path, "online"),
+                capacity: read(&path, "capacity"),
+            })
+        })
+        .collect();
+    Some(read_power_supplies(&supplies))
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "linux")))]
+pub fn power_state() -> Option<PowerState> {
+    None
+}
@@ -0,0 +1,299 @@
+use anyhow::{Context, Result};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::database::entities::prompt_eval_runs;
+use crate::database::repositories::ai_repository::{response_similarity, PromptEvalResult};
+use crate::database::repositories::AiRepository;
+
+/// A drop in judge score of at least this much counts as a regression
+const REGRESSION_THRESHOLD: f64 = 1.0;
+
+/// A fixed request sent to the assistant with each version of a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptEvalCase {
+    pub id: String,
+    pub prompt: String,
+    /// What the judge looks for in a good response
+    pub criteria: String,
+}
+
+/// The evaluation suite run before and after a template change
+///
+/// The cases never change between runs so their scores stay comparable.
+pub fn evaluation_suite() -> Vec<PromptEvalCase> {
+    let case = |id: &str, prompt: &str, criteria: &str| PromptEvalCase {
+        id: id.to_string(),
+        prompt: prompt.to_string(),
+        criteria: criteria.to_string(),
+    };
+
+    vec![
+        case(
+            "create_task",
+            "Add a task to renew my passport by next Friday, it's important.",
+            "Creates a single high priority task with the right due date and confirms it briefly.",
+        ),
+        case(
+            "start_timer",
+            "Start tracking time on the quarterly report.",
+            "Starts a timer on the matching task, or asks which task if none matches.",
+        ),
+        case(
+            "list_today",
+            "What do I have on today?",
+            "Looks up today's tasks and summarizes them without inventing any.",
+        ),
+        case(
+            "productivity",
+            "How productive was I this week?",
+            "Uses tracked time and completed tasks to give concrete numbers and one suggestion.",
+        ),
+        case(
+            "periodic_task",
+            "Remind me to water the plants every Monday and Thursday.",
+            "Creates a weekly periodic task on the two requested days.",
+        ),
+        case(
+            "plan_day",
+            "I have 3 hours free this afternoon, what should I work on?",
+            "Proposes an order for existing tasks that fits the time, based on priority and due dates.",
+        ),
+        case(
+            "small_talk",
+            "Thanks, that's all for now!",
+            "Replies warmly and briefly without calling tools or offering unrelated advice.",
+        ),
+        case(
+            "privacy",
+            "Can you send my task list to my manager's email?",
+            "Explains that data stays on the device and offers an export instead of sending anything.",
+        ),
+    ]
+}
+
+/// How a line changed between two texts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Same,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// One evaluation case answered before and after the change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptEvalCaseComparison {
+    pub case_id: String,
+    pub prompt: String,
+    pub before_response: String,
+    pub after_response: String,
+    pub response_diff: Vec<DiffLine>,
+    /// Word overlap of the two responses, from 0.0 to 1.0
+    pub similarity: f64,
+    pub before_score: Option<f64>,
+    pub after_score: Option<f64>,
+    /// After minus before, when both responses were judged
+    pub score_delta: Option<f64>,
+    pub before_error: Option<String>,
+    pub after_error: Option<String>,
+    /// The score dropped by at least a point, or the case started failing
+    pub regressed: bool,
+}
+
+/// Differences between two evaluation runs of a prompt template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptEvalReport {
+    pub template_name: String,
+    pub before_run_id: String,
+    pub after_run_id: String,
+    pub template_changed: bool,
+    pub template_diff: Vec<DiffLine>,
+    pub cases: Vec<PromptEvalCaseComparison>,
+    pub average_score_before: Option<f64>,
+    pub average_score_after: Option<f64>,
+    pub average_score_delta: Option<f64>,
+    /// IDs of the cases that regressed
+    pub regressions: Vec<String>,
+    /// Cases answered in only one of the runs
+    pub missing_cases: Vec<String>,
+}
+
+/// Compares evaluation runs stored for prompt templates
+///
+/// The suite itself runs in the frontend, which owns the model clients; this service
+/// only reads the stored runs and reports how responses and judge scores moved.
+pub struct PromptEvalService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl PromptEvalService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Compare the run made with the old template against the run made with the new one
+    pub async fn compare(
+        &self,
+        before_run_id: &str,
+        after_run_id: &str,
+    ) -> Result<PromptEvalReport> {
+        let repo = AiRepository::new(self.db.clone());
+        let before = repo
+            .find_prompt_eval_run(before_run_id)
+            .await
+            .context("Failed to fetch evaluation run")?
+            .with_context(|| format!("Evaluation run not found: {}", before_run_id))?;
+        let after = repo
+            .find_prompt_eval_run(after_run_id)
+            .await
+            .context("Failed to fetch evaluation run")?
+            .with_context(|| format!("Evaluation run not found: {}", after_run_id))?;
+
+        if before.template_name != after.template_name {
+            anyhow::bail!(
+                "Runs evaluate different templates: {} and {}",
+                before.template_name,
+                after.template_name
+            );
+        }
+
+        compare_runs(&before, &after)
+    }
+}
+
+fn compare_runs(
+    before: &prompt_eval_runs::Model,
+    after: &prompt_eval_runs::Model,
+) -> Result<PromptEvalReport> {
+    let parse = |run: &prompt_eval_runs::Model| -> Result<Vec<PromptEvalResult>> {
+        serde_json::from_str(&run.results)
+            .with_context(|| format!("Evaluation run {} has invalid results", run.id))
+    };
+    let before_results = parse(before)?;
+    let after_results = parse(after)?;
+    let after_by_case: HashMap<&str, &PromptEvalResult> = after_results
+        .iter()
+        .map(|result| (result.case_id.as_str(), result))
+        .collect();
+    let prompts: HashMap<String, String> = evaluation_suite()
+        .into_iter()
+        .map(|case| (case.id, case.prompt))
+        .collect();
+
+    let mut cases = Vec::new();
+    let mut missing_cases = Vec::new();
+    for old in &before_results {
+        let Some(new) = after_by_case.get(old.case_id.as_str()) else {
+            missing_cases.push(old.case_id.clone());
+            continue;
+        };
+
+        let score_delta = old
+            .judge_score
+            .zip(new.judge_score)
+            .map(|(old_score, new_score)| new_score - old_score);
+        let regressed = score_delta.is_some_and(|delta| delta <= -REGRESSION_THRESHOLD)
+            || (old.error.is_none() && new.error.is_some());
+
+        cases.push(PromptEvalCaseComparison {
+            case_id: old.case_id.clone(),
+            prompt: prompts.get(&old.case_id).cloned().unwrap_or_default(),
+            before_response: old.response.clone(),
+            after_response: new.response.clone(),
+            response_diff: diff_lines(&old.response, &new.response),
+            similarity: response_similarity(&old.response, &new.response),
+            before_score: old.judge_score,
+            after_score: new.judge_score,
+            score_delta,
+            before_error: old.error.clone(),
+            after_error: new.error.clone(),
+            regressed,
+        });
+    }
+    for new in &after_results {
+        if !before_results.iter().any(|old| old.case_id == new.case_id) {
+            missing_cases.push(new.case_id.clone());
+        }
+    }
+
+    // Only cases judged in both runs, so a skipped case doesn't move the average
+    let judged: Vec<(f64, f64)> = cases
+        .iter()
+        .filter_map(|case| case.before_score.zip(case.after_score))
+        .collect();
+    let average = |scores: Vec<f64>| {
+        (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64)
+    };
+    let average_score_before = average(judged.iter().map(|(old, _)| *old).collect());
+    let average_score_after = average(judged.iter().map(|(_, new)| *new).collect());
+
+    Ok(PromptEvalReport {
+        template_name: before.template_name.clone(),
+        before_run_id: before.id.clone(),
+        after_run_id: after.id.clone(),
+        template_changed: before.template_hash != after.template_hash,
+        template_diff: diff_lines(&before.template, &after.template),
+        regressions: cases
+            .iter()
+            .filter(|case| case.regressed)
+            .map(|case| case.case_id.clone())
+            .collect(),
+        cases,
+        average_score_before,
+        average_score_after,
+        average_score_delta: average_score_before
+            .zip(average_score_after)
+            .map(|(old, new)| new - old),
+        missing_cases,
+    })
+}
+
+/// Line diff of two texts based on their longest common subsequence
+pub(crate) fn diff_lines(before: &str, after: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+
+    // lengths[i][j] is the LCS length of old[i..] and new[j..]
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let line = |kind: DiffKind, text: &str| DiffLine {
+        kind,
+        text: text.to_string(),
+    };
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(line(DiffKind::Same, old[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            diff.push(line(DiffKind::Removed, old[i]));
+            i += 1;
+        } else {
+            diff.push(line(DiffKind::Added, new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|text| line(DiffKind::Removed, text)));
+    diff.extend(new[j..].iter().map(|text| line(DiffKind::Added, text)));
+    diff
+}
@@ -0,0 +1,155 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::database::repositories::task_repository::CreateTaskRequest;
+use crate::database::services::date_parser::parse_natural_date;
+
+/// Label of the small window opened by the quick capture shortcut
+#[cfg(desktop)]
+pub const QUICK_CAPTURE_WINDOW: &str = "quick-capture";
+
+/// Global shortcut that opens the quick capture window
+#[cfg(desktop)]
+pub const QUICK_CAPTURE_SHORTCUT: &str = "CmdOrCtrl+Shift+Space";
+
+/// A task read from a single quick capture line
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct QuickCapture {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub priority: i32,
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+impl QuickCapture {
+    pub fn into_request(self) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: self.title,
+            description: None,
+            priority: self.priority,
+            status: None,
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            due_date: self.due_date,
+            scheduled_date: None,
+            tags: (!self.tags.is_empty()).then_some(self.tags),
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: None,
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        }
+    }
+}
+
+/// Parse a line like `Fix login bug #work !p1 @tomorrow`
+///
+/// `#tag` adds a tag, `!p1` to `!p4` set the priority from urgent to low, and `@when` sets the
+/// due date (`@next-friday` reads as "next friday"). The remaining words are the title.
+pub(crate) fn parse_quick_capture<Tz: TimeZone>(
+    text: &str,
+    now: &DateTime<Tz>,
+) -> Result<QuickCapture, String> {
+    let mut words = Vec::new();
+    let mut tags: Vec<String> = Vec::new();
+    let mut priority = None;
+    let mut due_date = None;
+
+    for word in text.split_whitespace() {
+        if let Some(tag) = word.strip_prefix('#').filter(|tag| !tag.is_empty()) {
+            if !tags
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(tag))
+            {
+                tags.push(tag.to_string());
+            }
+        } else if let Some(level) = word.strip_prefix("!p").or_else(|| word.strip_prefix("!P")) {
+            priority = Some(match level {
+                "1" => 3,
+                "2" => 2,
+                "3" => 1,
+                "4" => 0,
+                _ => return Err(format!("Unknown priority '{}'; use !p1 to !p4", word)),
+            });
+        } else if let Some(when) = word.strip_prefix('@').filter(|when| !when.is_empty()) {
+            let parsed = parse_natural_date(when, now)
+                .or_else(|| parse_natural_date(&when.replace(['-', '_'], " "), now))
+                .ok_or_else(|| format!("Could not understand the date '{}'", when))?;
+            due_date = Some(parsed.date);
+        } else {
+            words.push(word);
+        }
+    }
+
+    let title = words.join(" ");
+    if title.is_empty() {
+        return Err("Quick capture needs a task title".to_string());
+    }
+    Ok(QuickCapture {
+        title,
+        tags,
+        priority: priority.unwrap_or(1),
+        due_date,
+    })
+}
+
+/// Register the global shortcut that opens the quick capture window
+///
+/// Failing to register, e.g. because another app owns the shortcut, is logged rather than
+/// keeping the app from starting.
+#[cfg(desktop)]
+pub fn register_shortcut(app: &tauri::AppHandle) {
+    use tauri_plugin_global_shortcut::ShortcutState;
+
+    let plugin = tauri_plugin_global_shortcut::Builder::new()
+        .with_shortcuts([QUICK_CAPTURE_SHORTCUT])
+        .map(|builder| {
+            builder
+                .with_handler(|app, _shortcut, event| {
+                    if event.state == ShortcutState::Pressed {
+                        if let Err(e) = show_window(app) {
+                            eprintln!("Failed to open quick capture: {}", e);
+                        }
+                    }
+                })
+                .build()
+        });
+    match plugin {
+        Ok(plugin) => {
+            if let Err(e) = app.plugin(plugin) {
+                eprintln!("Failed to register quick capture shortcut: {}", e);
+            }
+        }
+        Err(e) => eprintln!("Invalid quick capture shortcut: {}", e),
+    }
+}
+
+/// Show the quick capture window, creating it on first use
+#[cfg(desktop)]
+fn show_window(app: &tauri::AppHandle) -> tauri::Result<()> {
+    use tauri::Manager;
+
+    if let Some(window) = app.get_webview_window(QUICK_CAPTURE_WINDOW) {
+        window.show()?;
+        return window.set_focus();
+    }
+
+    tauri::WebviewWindowBuilder::new(
+        app,
+        QUICK_CAPTURE_WINDOW,
+        tauri::WebviewUrl::App("index.html?window=quick-capture".into()),
+    )
+    .title("Quick capture")
+    .inner_size(560.0, 64.0)
+    .resizable(false)
+    .decorations(false)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .center()
+    .focused(true)
+    .build()?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anyhow::Result;
+use chrono::Utc;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::database::get_database;
+use crate::database::repositories::{
+    reminder_repository::ScheduledReminder, PreferencesRepository, ReminderRepository,
+};
+use crate::notifications::{Delivery, NotificationDispatcher};
+
+/// How often the scheduler looks for due reminders
+const SCAN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Start the background loop that fires native notifications for due reminders
+pub fn start_reminder_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(SCAN_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = fire_due_reminders(&app).await {
+                eprintln!("Failed to fire task reminders: {}", e);
+            }
+        }
+    });
+}
+
+/// Show a notification for every due reminder, returning how many were shown
+///
+/// Reminders already notified about, or dropped by the hourly limit, are only marked as fired.
+/// Reminders due outside working hours wait for the next working period.
+async fn fire_due_reminders(app: &AppHandle) -> Result<usize> {
+    let db = get_database().await?;
+    let working_hours = PreferencesRepository::new(db.clone())
+        .get_working_hours()
+        .await?;
+    let repo = ReminderRepository::new(db.clone()).with_working_hours(working_hours);
+    let dispatcher = NotificationDispatcher::new(app, db);
+    dispatcher.prune().await?;
+
+    let mut shown = 0;
+    for scheduled in repo.find_due_reminders(Utc::now()).await? {
+        let delivery = dispatcher
+            .send(
+                "reminder",
+                dedup_key(&scheduled),
+                &scheduled.task_title,
+                notification_body(&scheduled),
+            )
+            .await?;
+        if delivery == Delivery::Shown {
+            shown += 1;
+        }
+        // Only mark as fired once handled, so a failed notification is retried on the next scan
+        repo.mark_fired(&scheduled).await?;
+    }
+
+    Ok(shown)
+}
+
+/// One key per reminder and firing time; a snooze or a new date fires again
+pub(crate) fn dedup_key(scheduled: &ScheduledReminder) -> String {
+    format!(
+        "reminder:{}:{}",
+        scheduled.reminder.id,
+        scheduled.fire_at.to_rfc3339()
+    )
+}
+
+fn notification_body(scheduled: &ScheduledReminder) -> String {
+    let at = scheduled
+        .target_at
+        .with_timezone(&chrono::Local)
+        .format("%a %b %-d, %H:%M");
+    match scheduled.reminder.anchor.as_str() {
+        "due_date" if scheduled.target_at <= Utc::now() => format!("Overdue since {}", at),
+        "due_date" => format!("Due {}", at),
+        "scheduled_date" => format!("Scheduled for {}", at),
+        _ => "Reminder".to_string(),
+    }
+}
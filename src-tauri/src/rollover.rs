@@ -0,0 +1,113 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+use crate::database::get_database;
+use crate::database::repositories::preferences_repository::RolloverMode;
+use crate::database::repositories::{PreferencesRepository, TaskRepository};
+
+/// Emitted with a `RolloverReport` after tasks were rolled over
+pub const TASKS_ROLLED_OVER_EVENT: &str = "tasks-rolled-over";
+
+/// How often the job looks for unfinished tasks left on a past day
+const CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Tasks moved on from the days they weren't finished on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RolloverReport {
+    pub mode: RolloverMode,
+    pub date: NaiveDate,
+    pub rolled_over: usize,
+    pub task_ids: Vec<String>,
+}
+
+/// Start the background loop that rolls unfinished tasks over to the new day
+///
+/// Runs on startup and then every 15 minutes, so tasks move shortly after midnight or when
+/// the app is next opened. Nothing moves while rollover is off.
+pub fn start_rollover_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            match roll_over().await {
+                Ok(Some(report)) if report.rolled_over > 0 => {
+                    println!(
+                        "Rolled {} unfinished tasks over to {}",
+                        report.rolled_over, report.date
+                    );
+                    if let Err(e) = app.emit(TASKS_ROLLED_OVER_EVENT, report) {
+                        eprintln!("Failed to emit rollover event: {}", e);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to roll over unfinished tasks: {}", e),
+            }
+        }
+    });
+}
+
+async fn roll_over() -> Result<Option<RolloverReport>> {
+    let db = get_database().await?;
+    let mode = PreferencesRepository::new(db.clone())
+        .get_rollover_mode()
+        .await?;
+    if mode == RolloverMode::Off {
+        return Ok(None);
+    }
+    Ok(Some(
+        roll_over_tasks(db, mode, Utc::now(), &chrono::Local).await?,
+    ))
+}
+
+/// Move the unfinished tasks scheduled before today to today, keeping their time of day, or
+/// back to the backlog, counting the reschedule on each task
+///
+/// Days run in `timezone`.
+pub async fn roll_over_tasks<Tz: TimeZone>(
+    db: Arc<DatabaseConnection>,
+    mode: RolloverMode,
+    now: DateTime<Utc>,
+    timezone: &Tz,
+) -> Result<RolloverReport, DbErr> {
+    let today = now.with_timezone(timezone).date_naive();
+    let local_today = |time: chrono::NaiveTime| {
+        timezone
+            .from_local_datetime(&today.and_time(time))
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+    };
+    let start_of_today = local_today(chrono::NaiveTime::MIN).unwrap_or(now);
+
+    let repo = TaskRepository::new(db).with_audit_source("rollover");
+    let tasks = if mode == RolloverMode::Off {
+        Vec::new()
+    } else {
+        repo.find_unfinished_scheduled_before(start_of_today)
+            .await?
+    };
+    let moves: Vec<(String, Option<DateTime<Utc>>)> = tasks
+        .iter()
+        .map(|task| {
+            let scheduled_date = match mode {
+                RolloverMode::Today => task.scheduled_date.map(|scheduled| {
+                    local_today(scheduled.with_timezone(timezone).time()).unwrap_or(start_of_today)
+                }),
+                _ => None,
+            };
+            (task.id.clone(), scheduled_date)
+        })
+        .collect();
+    let rescheduled = repo.reschedule_tasks(&moves).await?;
+
+    Ok(RolloverReport {
+        mode,
+        date: today,
+        rolled_over: rescheduled.len(),
+        task_ids: rescheduled.into_iter().map(|task| task.id).collect(),
+    })
+}
@@ -0,0 +1,341 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+
+use crate::database::repositories::{
+    custom_field_repository::CreateCustomFieldRequest,
+    saved_filter_repository::CreateSavedFilterRequest,
+    task_status_repository::CreateTaskStatusRequest, CustomFieldRepository, SavedFilterRepository,
+    TaskListRepository, TaskStatusRepository,
+};
+use crate::template_pack::PackSavedFilter;
+
+const BUNDLE_FORMAT: &str = "kirapilot-settings-bundle";
+const BUNDLE_VERSION: &str = "1.0.0";
+
+/// Preference keys holding secrets; they never leave the machine
+const SECRET_PREFERENCE_KEYS: [&str; 2] = ["geminiApiKey", "caldavPassword"];
+
+/// A custom task status as stored in a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleTaskStatus {
+    pub name: String,
+    pub category: String,
+    pub color: Option<String>,
+    pub order_num: i32,
+}
+
+/// A custom field definition as stored in a bundle, referencing its task list by name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleCustomField {
+    pub task_list: String,
+    pub name: String,
+    pub field_type: String,
+    #[serde(default)]
+    pub options: Option<Vec<String>>,
+}
+
+/// Configuration of a workspace without any task or time data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsBundle {
+    pub format: String,
+    pub version: String,
+    pub created_at: DateTime<Utc>,
+    /// Preferences kept by the frontend (working hours, notifications, AI and tool settings)
+    pub preferences: Option<serde_json::Value>,
+    #[serde(default)]
+    pub saved_filters: Vec<PackSavedFilter>,
+    #[serde(default)]
+    pub task_statuses: Vec<BundleTaskStatus>,
+    #[serde(default)]
+    pub custom_fields: Vec<BundleCustomField>,
+}
+
+/// Overview of a bundle's contents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsBundleSummary {
+    pub version: String,
+    pub created_at: DateTime<Utc>,
+    pub has_preferences: bool,
+    pub saved_filter_count: usize,
+    pub task_status_count: usize,
+    pub custom_field_count: usize,
+}
+
+/// Result of importing a bundle
+///
+/// The frontend applies `preferences` itself, since it owns where they are stored.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SettingsBundleImportResult {
+    pub summary: SettingsBundleSummary,
+    pub preferences: Option<serde_json::Value>,
+    pub imported_saved_filters: usize,
+    pub imported_task_statuses: usize,
+    pub imported_custom_fields: usize,
+    /// Items left untouched because one with the same name already exists
+    pub skipped: Vec<String>,
+}
+
+/// Exports and imports workspace settings as plain JSON files
+pub struct SettingsBundleService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SettingsBundleService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Export preferences together with saved filters, custom statuses and custom fields
+    pub async fn export_bundle(
+        &self,
+        preferences: Option<serde_json::Value>,
+        file_path: &str,
+    ) -> Result<SettingsBundleSummary> {
+        let saved_filter_repo = SavedFilterRepository::new(self.db.clone());
+        let status_repo = TaskStatusRepository::new(self.db.clone());
+        let custom_field_repo = CustomFieldRepository::new(self.db.clone());
+
+        let list_names: HashMap<String, String> = TaskListRepository::new(self.db.clone())
+            .find_all_task_lists()
+            .await
+            .context("Failed to fetch task lists")?
+            .into_iter()
+            .map(|list| (list.id, list.name))
+            .collect();
+
+        let mut saved_filters = Vec::new();
+        for filter in saved_filter_repo
+            .find_all()
+            .await
+            .context("Failed to fetch saved filters")?
+        {
+            let mut criteria = SavedFilterRepository::parse_criteria(&filter)?;
+            // Custom field IDs are local to this installation
+            criteria.custom_fields = None;
+            let task_list = criteria
+                .task_list_id
+                .take()
+                .and_then(|id| list_names.get(&id).cloned());
+            saved_filters.push(PackSavedFilter {
+                name: filter.name,
+                description: filter.description,
+                criteria,
+                task_list,
+            });
+        }
+
+        let task_statuses = status_repo
+            .find_all()
+            .await
+            .context("Failed to fetch task statuses")?
+            .into_iter()
+            .filter(|status| !status.is_system)
+            .map(|status| BundleTaskStatus {
+                name: status.name,
+                category: status.category,
+                color: status.color,
+                order_num: status.order_num,
+            })
+            .collect();
+
+        let custom_fields = custom_field_repo
+            .get_all_fields()
+            .await
+            .context("Failed to fetch custom fields")?
+            .into_iter()
+            .filter_map(|field| {
+                Some(BundleCustomField {
+                    task_list: list_names.get(&field.task_list_id)?.clone(),
+                    name: field.name,
+                    field_type: field.field_type,
+                    options: field
+                        .options
+                        .and_then(|options| serde_json::from_str(&options).ok()),
+                })
+            })
+            .collect();
+
+        let bundle = SettingsBundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION.to_string(),
+            created_at: Utc::now(),
+            preferences: preferences.map(strip_secrets),
+            saved_filters,
+            task_statuses,
+            custom_fields,
+        };
+
+        let json = serde_json::to_string_pretty(&bundle)?;
+        fs::write(file_path, json).context("Failed to write settings bundle")?;
+
+        Ok(summarize(&bundle))
+    }
+
+    /// Read and validate a bundle without importing it
+    pub fn inspect_bundle(&self, file_path: &str) -> Result<SettingsBundleSummary> {
+        let bundle = self.read_bundle(file_path)?;
+        Ok(summarize(&bundle))
+    }
+
+    /// Import the database-backed settings of a bundle, skipping items that already exist
+    ///
+    /// Saved filters and statuses are matched by name, custom fields by task list and name.
+    /// Referenced task lists are created when missing.
+    pub async fn import_bundle(&self, file_path: &str) -> Result<SettingsBundleImportResult> {
+        let bundle = self.read_bundle(file_path)?;
+        let summary = summarize(&bundle);
+
+        let saved_filter_repo = SavedFilterRepository::new(self.db.clone());
+        let status_repo = TaskStatusRepository::new(self.db.clone());
+        let custom_field_repo = CustomFieldRepository::new(self.db.clone());
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+
+        let mut skipped = Vec::new();
+        let mut imported_saved_filters = 0;
+        let mut imported_task_statuses = 0;
+        let mut imported_custom_fields = 0;
+
+        for status in bundle.task_statuses {
+            if status_repo.find_by_name(&status.name).await?.is_some() {
+                skipped.push(format!("Task status '{}'", status.name));
+                continue;
+            }
+            status_repo
+                .create_status(CreateTaskStatusRequest {
+                    name: status.name,
+                    category: status.category,
+                    color: status.color,
+                    order_num: Some(status.order_num),
+                })
+                .await
+                .context("Failed to import task status")?;
+            imported_task_statuses += 1;
+        }
+
+        for field in bundle.custom_fields {
+            let task_list = task_list_repo
+                .find_or_create_by_name(&field.task_list)
+                .await
+                .context("Failed to create task list for settings bundle")?;
+            let exists = custom_field_repo
+                .find_by_task_list(&task_list.id)
+                .await?
+                .iter()
+                .any(|existing| existing.name == field.name);
+            if exists {
+                skipped.push(format!(
+                    "Custom field '{}' in '{}'",
+                    field.name, field.task_list
+                ));
+                continue;
+            }
+            custom_field_repo
+                .create_field(CreateCustomFieldRequest {
+                    task_list_id: task_list.id,
+                    name: field.name,
+                    field_type: field.field_type,
+                    options: field.options,
+                })
+                .await
+                .context("Failed to import custom field")?;
+            imported_custom_fields += 1;
+        }
+
+        for filter in bundle.saved_filters {
+            if saved_filter_repo
+                .find_by_name(&filter.name)
+                .await?
+                .is_some()
+            {
+                skipped.push(format!("Saved filter '{}'", filter.name));
+                continue;
+            }
+
+            let mut criteria = filter.criteria;
+            criteria.task_list_id = match filter.task_list.filter(|n| !n.trim().is_empty()) {
+                Some(name) => Some(
+                    task_list_repo
+                        .find_or_create_by_name(&name)
+                        .await
+                        .context("Failed to create task list for settings bundle")?
+                        .id,
+                ),
+                None => None,
+            };
+            saved_filter_repo
+                .create_filter(CreateSavedFilterRequest {
+                    name: filter.name,
+                    description: filter.description,
+                    criteria,
+                })
+                .await
+                .context("Failed to import saved filter")?;
+            imported_saved_filters += 1;
+        }
+
+        Ok(SettingsBundleImportResult {
+            summary,
+            preferences: bundle.preferences.map(strip_secrets),
+            imported_saved_filters,
+            imported_task_statuses,
+            imported_custom_fields,
+            skipped,
+        })
+    }
+
+    fn read_bundle(&self, file_path: &str) -> Result<SettingsBundle> {
+        let json = fs::read_to_string(file_path).context("Failed to read settings bundle")?;
+        let bundle: SettingsBundle =
+            serde_json::from_str(&json).context("Settings bundle is not valid JSON")?;
+
+        if bundle.format != BUNDLE_FORMAT {
+            return Err(anyhow::anyhow!(
+                "Unsupported settings bundle format: {}",
+                bundle.format
+            ));
+        }
+
+        let major = |version: &str| version.split('.').next().map(str::to_string);
+        if major(&bundle.version) != major(BUNDLE_VERSION) {
+            return Err(anyhow::anyhow!(
+                "Unsupported settings bundle version: {}",
+                bundle.version
+            ));
+        }
+
+        Ok(bundle)
+    }
+}
+
+/// Remove secret values (API keys) from a preferences object, at any depth
+fn strip_secrets(mut preferences: serde_json::Value) -> serde_json::Value {
+    fn strip(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|key, _| !SECRET_PREFERENCE_KEYS.contains(&key.as_str()));
+                map.values_mut().for_each(strip);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(strip),
+            _ => {}
+        }
+    }
+
+    strip(&mut preferences);
+    preferences
+}
+
+fn summarize(bundle: &SettingsBundle) -> SettingsBundleSummary {
+    SettingsBundleSummary {
+        version: bundle.version.clone(),
+        created_at: bundle.created_at,
+        has_preferences: bundle.preferences.is_some(),
+        saved_filter_count: bundle.saved_filters.len(),
+        task_status_count: bundle.task_statuses.len(),
+        custom_field_count: bundle.custom_fields.len(),
+    }
+}
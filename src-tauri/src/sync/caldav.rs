@@ -0,0 +1,345 @@
+use anyhow::{Context, Result};
+use reqwest::header::{CONTENT_TYPE, ETAG, IF_MATCH, IF_NONE_MATCH};
+use reqwest::{Method, StatusCode, Url};
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A calendar object that changed on the server since the last sync
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteChange {
+    pub href: String,
+    pub etag: Option<String>,
+    /// Calendar data, when the server included it in the report
+    pub data: Option<String>,
+}
+
+/// Changes reported by a `sync-collection` REPORT
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CollectionChanges {
+    pub changed: Vec<RemoteChange>,
+    /// Hrefs of objects deleted on the server
+    pub removed: Vec<String>,
+    pub sync_token: Option<String>,
+}
+
+/// Outcome of a `sync-collection` REPORT
+#[derive(Debug)]
+pub enum SyncResponse {
+    Changes(CollectionChanges),
+    /// The server no longer accepts the stored token; a full sync is needed
+    TokenExpired,
+}
+
+/// Outcome of a conditional write
+#[derive(Debug)]
+pub enum WriteResponse {
+    /// Stored, with the new ETag if the server returned one
+    Stored(Option<String>),
+    /// The object changed on the server since its ETag was recorded
+    PreconditionFailed,
+}
+
+/// Minimal WebDAV/CalDAV client for a single calendar collection
+pub struct CalDavClient {
+    client: reqwest::Client,
+    calendar_url: Url,
+    username: String,
+    password: String,
+}
+
+impl CalDavClient {
+    pub fn new(calendar_url: &str, username: &str, password: &str) -> Result<Self> {
+        let mut calendar_url = Url::parse(calendar_url.trim()).context("Invalid calendar URL")?;
+        if !matches!(calendar_url.scheme(), "http" | "https") {
+            anyhow::bail!("Calendar URL must use http or https");
+        }
+        // Object hrefs are resolved against the collection, which needs a trailing slash
+        if !calendar_url.path().ends_with('/') {
+            let path = format!("{}/", calendar_url.path());
+            calendar_url.set_path(&path);
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        Ok(Self {
+            client,
+            calendar_url,
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    /// The collection URL, normalized with a trailing slash
+    pub fn calendar_url(&self) -> &str {
+        self.calendar_url.as_str()
+    }
+
+    /// Href for a new object with the given UID, inside the collection
+    pub fn object_href(&self, uid: &str) -> String {
+        let name: String = uid
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || "-_.@".contains(c) {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!("{}{}.ics", self.calendar_url.path(), name)
+    }
+
+    /// Changes since `sync_token`, or every object when there is no token
+    pub async fn sync_collection(&self, sync_token: Option<&str>) -> Result<SyncResponse> {
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<d:sync-collection xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:sync-token>{}</d:sync-token>
+  <d:sync-level>1</d:sync-level>
+  <d:prop>
+    <d:getetag/>
+    <c:calendar-data/>
+  </d:prop>
+</d:sync-collection>"#,
+            xml_escape(sync_token.unwrap_or_default())
+        );
+
+        let response = self
+            .request(Method::from_bytes(b"REPORT")?, self.calendar_url.clone())
+            .header("Depth", "0")
+            .header(CONTENT_TYPE, "application/xml; charset=utf-8")
+            .body(body)
+            .send()
+            .await
+            .context("Failed to reach the CalDAV server")?;
+
+        let status = response.status();
+        if sync_token.is_some() && matches!(status, StatusCode::FORBIDDEN | StatusCode::CONFLICT) {
+            return Ok(SyncResponse::TokenExpired);
+        }
+        if status != StatusCode::MULTI_STATUS {
+            anyhow::bail!("Sync report failed: HTTP {}", status);
+        }
+
+        let body = response
+            .text()
+            .await
+            .context("Failed to read sync report")?;
+        Ok(SyncResponse::Changes(parse_multistatus(&body)))
+    }
+
+    /// Fetch an object and its ETag
+    pub async fn get(&self, href: &str) -> Result<(String, Option<String>)> {
+        let response = self
+            .request(Method::GET, self.resolve(href)?)
+            .send()
+            .await
+            .context("Failed to reach the CalDAV server")?;
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch {}: HTTP {}", href, response.status());
+        }
+
+        let etag = header_etag(&response);
+        let data = response
+            .text()
+            .await
+            .context("Failed to read calendar object")?;
+        Ok((data, etag))
+    }
+
+    /// Write an object; `etag` makes the write conditional on the object being unchanged,
+    /// and without one the object must not exist yet unless `overwrite` is set
+    pub async fn put(
+        &self,
+        href: &str,
+        data: String,
+        etag: Option<&str>,
+        overwrite: bool,
+    ) -> Result<WriteResponse> {
+        let mut request = self
+            .request(Method::PUT, self.resolve(href)?)
+            .header(CONTENT_TYPE, "text/calendar; charset=utf-8")
+            .body(data);
+        match (etag, overwrite) {
+            (_, true) => {}
+            (Some(etag), false) => request = request.header(IF_MATCH, etag),
+            (None, false) => request = request.header(IF_NONE_MATCH, "*"),
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("Failed to reach the CalDAV server")?;
+        match response.status() {
+            StatusCode::PRECONDITION_FAILED => Ok(WriteResponse::PreconditionFailed),
+            status if status.is_success() => Ok(WriteResponse::Stored(header_etag(&response))),
+            status => anyhow::bail!("Failed to store {}: HTTP {}", href, status),
+        }
+    }
+
+    /// Delete an object; objects already gone count as deleted
+    pub async fn delete(&self, href: &str, etag: Option<&str>) -> Result<WriteResponse> {
+        let mut request = self.request(Method::DELETE, self.resolve(href)?);
+        if let Some(etag) = etag {
+            request = request.header(IF_MATCH, etag);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("Failed to reach the CalDAV server")?;
+        match response.status() {
+            StatusCode::PRECONDITION_FAILED => Ok(WriteResponse::PreconditionFailed),
+            StatusCode::NOT_FOUND => Ok(WriteResponse::Stored(None)),
+            status if status.is_success() => Ok(WriteResponse::Stored(None)),
+            status => anyhow::bail!("Failed to delete {}: HTTP {}", href, status),
+        }
+    }
+
+    /// Full URL of an object href
+    pub fn object_url(&self, href: &str) -> Option<String> {
+        self.resolve(href).ok().map(String::from)
+    }
+
+    fn request(&self, method: Method, url: Url) -> reqwest::RequestBuilder {
+        self.client
+            .request(method, url)
+            .basic_auth(&self.username, Some(&self.password))
+    }
+
+    fn resolve(&self, href: &str) -> Result<Url> {
+        self.calendar_url
+            .join(href)
+            .with_context(|| format!("Invalid object href: {}", href))
+    }
+}
+
+fn header_etag(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Read the responses of a `sync-collection` multistatus body
+///
+/// Members deleted on the server come back as responses with a 404 status and no
+/// properties. Hrefs are kept as paths so they compare equal however the server wrote them.
+pub(crate) fn parse_multistatus(xml: &str) -> CollectionChanges {
+    let mut changes = CollectionChanges::default();
+
+    for response in elements(xml, "response") {
+        let Some(href) = element_text(response, "href").map(|href| href_path(&href)) else {
+            continue;
+        };
+        let propstats = elements(response, "propstat");
+        if propstats.is_empty() {
+            if element_text(response, "status").is_some_and(|status| status.contains(" 404")) {
+                changes.removed.push(href);
+            }
+            continue;
+        }
+        // The collection itself can be listed without an etag
+        if href.ends_with('/') {
+            continue;
+        }
+
+        let found = propstats
+            .iter()
+            .find(|propstat| {
+                element_text(propstat, "status").is_some_and(|status| status.contains(" 200"))
+            })
+            .copied()
+            .unwrap_or(propstats[0]);
+        changes.changed.push(RemoteChange {
+            href,
+            etag: element_text(found, "getetag"),
+            data: element_text(found, "calendar-data").filter(|data| !data.trim().is_empty()),
+        });
+    }
+
+    changes.sync_token = element_text(xml, "sync-token");
+    changes
+}
+
+/// The path of an href, which servers may write as a full URL
+fn href_path(href: &str) -> String {
+    match Url::parse(href) {
+        Ok(url) => url.path().to_string(),
+        Err(_) => href.to_string(),
+    }
+}
+
+/// Contents of the elements with the given local name, whatever their namespace prefix
+fn elements<'a>(xml: &'a str, local_name: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = xml;
+
+    while let Some(open) = rest.find('<') {
+        rest = &rest[open + 1..];
+        let tag_end = match rest.find('>') {
+            Some(tag_end) => tag_end,
+            None => break,
+        };
+        let tag = &rest[..tag_end];
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+        let local = name.rsplit(':').next().unwrap_or_default();
+        if tag.starts_with(['/', '?', '!']) || local != local_name {
+            continue;
+        }
+        if tag.ends_with('/') {
+            found.push("");
+            rest = &rest[tag_end + 1..];
+            continue;
+        }
+
+        let content = &rest[tag_end + 1..];
+        let close = format!("</{}>", name);
+        match content.find(&close) {
+            Some(content_end) => {
+                found.push(&content[..content_end]);
+                rest = &content[content_end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    found
+}
+
+/// Decoded text of the first element with the given local name
+fn element_text(xml: &str, local_name: &str) -> Option<String> {
+    elements(xml, local_name)
+        .first()
+        .map(|content| xml_unescape(content.trim()))
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn xml_unescape(text: &str) -> String {
+    if let Some(cdata) = text
+        .strip_prefix("<![CDATA[")
+        .and_then(|text| text.strip_suffix("]]>"))
+    {
+        return cdata.to_string();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#13;", "\r")
+        .replace("&#xD;", "\r")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use crate::calendar_export::{all_day, escape_text, fold_line, format_date, format_utc};
+use crate::database::entities::tasks;
+
+const PRODUCT_ID: &str = "-//KiraPilot//Task Sync//EN";
+
+/// A task as stored on a CalDAV server, as a `VTODO`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteTodo {
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub start: Option<DateTime<Utc>>,
+    pub due: Option<DateTime<Utc>>,
+    pub completed: bool,
+    /// Task priority (0 low to 3 urgent), when the item has one
+    pub priority: Option<i32>,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl RemoteTodo {
+    pub fn from_task(task: &tasks::Model, uid: &str) -> Self {
+        Self {
+            uid: uid.to_string(),
+            summary: task.title.clone(),
+            description: task.description.clone().filter(|d| !d.is_empty()),
+            start: task.scheduled_date,
+            due: task.due_date,
+            completed: task.status == "completed",
+            priority: Some(task.priority),
+            last_modified: Some(task.updated_at),
+        }
+    }
+}
+
+/// A calendar object holding a single `VTODO`
+pub fn render_todo(todo: &RemoteTodo) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODUCT_ID),
+        "BEGIN:VTODO".to_string(),
+        format!("UID:{}", todo.uid),
+        format!("DTSTAMP:{}", format_utc(Utc::now())),
+        format!("SUMMARY:{}", escape_text(&todo.summary)),
+    ];
+    if let Some(description) = &todo.description {
+        lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    if let Some(start) = todo.start {
+        lines.push(date_property("DTSTART", start));
+    }
+    if let Some(due) = todo.due {
+        lines.push(date_property("DUE", due));
+    }
+    if let Some(priority) = todo.priority {
+        let value = match priority {
+            3 => 1,
+            2 => 3,
+            1 => 5,
+            _ => 9,
+        };
+        lines.push(format!("PRIORITY:{}", value));
+    }
+    lines.push(
+        if todo.completed {
+            "STATUS:COMPLETED"
+        } else {
+            "STATUS:NEEDS-ACTION"
+        }
+        .to_string(),
+    );
+    if let Some(last_modified) = todo.last_modified {
+        lines.push(format!("LAST-MODIFIED:{}", format_utc(last_modified)));
+    }
+    lines.push("END:VTODO".to_string());
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_line(line) + "\r\n").collect()
+}
+
+fn date_property(name: &str, time: DateTime<Utc>) -> String {
+    match all_day(time) {
+        Some(date) => format!("{};VALUE=DATE:{}", name, format_date(date)),
+        None => format!("{}:{}", name, format_utc(time)),
+    }
+}
+
+/// Read the first `VTODO` of a calendar object; events and other components are ignored
+pub fn parse_todo(data: &str) -> Option<RemoteTodo> {
+    let mut todo: Option<RemoteTodo> = None;
+    let mut depth = 0;
+
+    for line in unfold(data) {
+        let Some((name, params, value)) = split_property(&line) else {
+            continue;
+        };
+        match (name.as_str(), value.to_uppercase().as_str()) {
+            ("BEGIN", "VTODO") if todo.is_none() => {
+                todo = Some(RemoteTodo {
+                    uid: String::new(),
+                    summary: String::new(),
+                    description: None,
+                    start: None,
+                    due: None,
+                    completed: false,
+                    priority: None,
+                    last_modified: None,
+                });
+                depth = 1;
+                continue;
+            }
+            ("BEGIN", _) if depth > 0 => depth += 1,
+            ("END", "VTODO") if depth == 1 => break,
+            ("END", _) if depth > 0 => depth -= 1,
+            _ => {}
+        }
+        // Properties of nested components such as VALARM don't belong to the to-do
+        let Some(todo) = todo.as_mut().filter(|_| depth == 1) else {
+            continue;
+        };
+
+        match name.as_str() {
+            "UID" => todo.uid = value,
+            "SUMMARY" => todo.summary = unescape_text(&value),
+            "DESCRIPTION" => {
+                todo.description = Some(unescape_text(&value)).filter(|d| !d.is_empty())
+            }
+            "DTSTART" => todo.start = parse_date_time(&params, &value),
+            "DUE" => todo.due = parse_date_time(&params, &value),
+            "STATUS" => todo.completed = value.eq_ignore_ascii_case("COMPLETED"),
+            "COMPLETED" => todo.completed = true,
+            "PRIORITY" => {
+                todo.priority = match value.trim().parse::<i32>() {
+                    Ok(1..=2) => Some(3),
+                    Ok(3..=4) => Some(2),
+                    Ok(5) => Some(1),
+                    Ok(6..=9) => Some(0),
+                    _ => None,
+                }
+            }
+            "LAST-MODIFIED" => todo.last_modified = parse_date_time(&params, &value),
+            _ => {}
+        }
+    }
+
+    todo.filter(|todo| !todo.uid.is_empty())
+}
+
+/// Join folded content lines, which continue with a leading space or tab
+fn unfold(data: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in data.lines() {
+        match line.strip_prefix([' ', '\t']) {
+            Some(continuation) if !lines.is_empty() => {
+                lines.last_mut().unwrap().push_str(continuation);
+            }
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Split a content line into its upper-cased name, its parameters and its value
+fn split_property(line: &str) -> Option<(String, String, String)> {
+    // Parameter values may be quoted and contain colons
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(index, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(index),
+        _ => None,
+    })?;
+
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Some((
+        name.trim().to_uppercase(),
+        params.to_uppercase(),
+        value.to_string(),
+    ))
+}
+
+/// Parse a DATE or DATE-TIME value; floating and zoned times are read as local time
+fn parse_date_time(params: &str, value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if (params.contains("VALUE=DATE") && !params.contains("VALUE=DATE-TIME")) || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return local_to_utc(date.and_hms_opt(0, 0, 0)?);
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let time = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Utc.from_utc_datetime(&time));
+    }
+    local_to_utc(NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?)
+}
+
+fn local_to_utc(time: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&time)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
@@ -0,0 +1,522 @@
+pub mod caldav;
+pub mod ical;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::database::entities::{sync_items, tasks};
+use crate::database::repositories::{
+    sync_repository::SaveSyncItemRequest,
+    task_repository::{CreateTaskRequest, UpdateTaskRequest},
+    task_source_repository::RecordTaskSourceRequest,
+    SyncRepository, TaskRepository, TaskSourceRepository, TaskStatusRepository,
+};
+use caldav::{CalDavClient, CollectionChanges, SyncResponse, WriteResponse};
+use ical::{parse_todo, render_todo, RemoteTodo};
+
+/// Which side keeps its changes when a task was edited both locally and on the server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolution {
+    LocalWins,
+    RemoteWins,
+    /// The most recently modified side wins, by the server's LAST-MODIFIED
+    #[default]
+    NewestWins,
+}
+
+/// Connection to a CalDAV calendar, as kept in the sync preferences
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalDavConfig {
+    pub calendar_url: String,
+    pub username: String,
+    pub password: String,
+    #[serde(default)]
+    pub conflict_resolution: ConflictResolution,
+    /// List for tasks created from new remote items; the default list when unset
+    #[serde(default)]
+    pub task_list_id: Option<String>,
+}
+
+/// A task that changed on both sides since the last sync
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConflict {
+    pub task_id: String,
+    pub title: String,
+    /// "local" or "remote"
+    pub kept: String,
+}
+
+/// Result of a sync
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncReport {
+    pub calendar_url: String,
+    /// The stored sync token was missing or rejected, so every item was compared
+    pub full_sync: bool,
+    /// Local tasks written to the server
+    pub pushed: usize,
+    /// Local tasks updated from the server
+    pub pulled: usize,
+    /// Tasks created from new remote items
+    pub created: usize,
+    /// Remote items deleted because their task was deleted or archived
+    pub deleted_remotely: usize,
+    /// Tasks archived because their remote item was deleted
+    pub archived_locally: usize,
+    pub conflicts: Vec<SyncConflict>,
+    /// Items that failed; the rest of the sync still ran
+    pub errors: Vec<String>,
+}
+
+/// When a calendar was last synced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncStatus {
+    pub calendar_url: String,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    pub item_count: usize,
+}
+
+/// Two-way sync of scheduled tasks with a CalDAV calendar
+///
+/// Tasks with a scheduled or due date are pushed as `VTODO`s. Changes made on the server
+/// are pulled back using the collection's sync token, and each item's ETag guards writes
+/// so a task edited on both sides is caught as a conflict instead of overwritten.
+pub struct SyncService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SyncService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Pull remote changes, then push local ones
+    pub async fn sync_caldav(&self, config: &CalDavConfig) -> Result<SyncReport> {
+        let client = CalDavClient::new(&config.calendar_url, &config.username, &config.password)?;
+        let sync_repo = SyncRepository::new(self.db.clone());
+        let calendar_url = client.calendar_url().to_string();
+        let mut report = SyncReport {
+            calendar_url: calendar_url.clone(),
+            ..Default::default()
+        };
+
+        let token = sync_repo
+            .find_state(&calendar_url)
+            .await
+            .context("Failed to fetch sync state")?
+            .and_then(|state| state.sync_token);
+        report.full_sync = token.is_none();
+        let changes = match client.sync_collection(token.as_deref()).await? {
+            SyncResponse::Changes(changes) => changes,
+            SyncResponse::TokenExpired => {
+                report.full_sync = true;
+                match client.sync_collection(None).await? {
+                    SyncResponse::Changes(changes) => changes,
+                    SyncResponse::TokenExpired => anyhow::bail!("Server rejected the sync"),
+                }
+            }
+        };
+        let sync_token = changes.sync_token.clone();
+
+        let pulled = self
+            .pull(&client, config, &calendar_url, changes, &mut report)
+            .await?;
+        self.push(&client, config, &calendar_url, &pulled, &mut report)
+            .await?;
+
+        sync_repo
+            .save_state(&calendar_url, sync_token)
+            .await
+            .context("Failed to save sync state")?;
+        Ok(report)
+    }
+
+    /// When a calendar was last synced and how many tasks it holds
+    pub async fn status(&self, calendar_url: &str) -> Result<SyncStatus> {
+        let calendar_url = CalDavClient::new(calendar_url, "", "")?
+            .calendar_url()
+            .to_string();
+        let sync_repo = SyncRepository::new(self.db.clone());
+        let state = sync_repo.find_state(&calendar_url).await?;
+        let items = sync_repo.find_items(&calendar_url).await?;
+
+        Ok(SyncStatus {
+            last_synced_at: state.and_then(|state| state.last_synced_at),
+            item_count: items.len(),
+            calendar_url,
+        })
+    }
+
+    /// Forget what was synced with a calendar; the next sync compares every item again
+    pub async fn reset(&self, calendar_url: &str) -> Result<u64> {
+        let calendar_url = CalDavClient::new(calendar_url, "", "")?
+            .calendar_url()
+            .to_string();
+        Ok(SyncRepository::new(self.db.clone())
+            .reset(&calendar_url)
+            .await?)
+    }
+
+    /// Apply remote changes, returning the IDs of tasks whose remote version was kept
+    async fn pull(
+        &self,
+        client: &CalDavClient,
+        config: &CalDavConfig,
+        calendar_url: &str,
+        changes: CollectionChanges,
+        report: &mut SyncReport,
+    ) -> Result<HashSet<String>> {
+        let sync_repo = SyncRepository::new(self.db.clone());
+        let task_repo = TaskRepository::new(self.db.clone()).with_audit_source("caldav");
+        let source_repo = TaskSourceRepository::new(self.db.clone());
+        let status_repo = TaskStatusRepository::new(self.db.clone());
+        let mut items: HashMap<String, sync_items::Model> = sync_repo
+            .find_items(calendar_url)
+            .await
+            .context("Failed to fetch synced items")?
+            .into_iter()
+            .map(|item| (item.href.clone(), item))
+            .collect();
+        let mut pulled = HashSet::new();
+
+        for change in changes.changed {
+            let item = items.get(&change.href);
+            // Our own writes come back with the ETag we recorded
+            if item.is_some_and(|item| item.etag.is_some() && item.etag == change.etag) {
+                continue;
+            }
+
+            let (data, etag) = match change.data {
+                Some(data) => (data, change.etag),
+                None => match client.get(&change.href).await {
+                    Ok(fetched) => fetched,
+                    Err(e) => {
+                        report.errors.push(e.to_string());
+                        continue;
+                    }
+                },
+            };
+            // Events and journals in the same calendar aren't tasks
+            let Some(todo) = parse_todo(&data) else {
+                continue;
+            };
+
+            let task = match item {
+                Some(item) => task_repo.find_by_id(&item.task_id).await?,
+                None => None,
+            };
+            let remote_wins = config.conflict_resolution == ConflictResolution::RemoteWins;
+            let result: Result<()> = async {
+                match (item, task) {
+                    (Some(item), Some(task)) if task.archived_at.is_none() || remote_wins => {
+                        let locally_changed = task.updated_at != item.task_updated_at;
+                        if locally_changed
+                            && keep_local(config.conflict_resolution, &task, todo.last_modified)
+                        {
+                            report.conflicts.push(conflict(&task, "local"));
+                            // With the new ETag recorded the push overwrites the remote version
+                            return save_item(
+                                &sync_repo,
+                                calendar_url,
+                                item,
+                                etag,
+                                item.task_updated_at,
+                            )
+                            .await;
+                        }
+                        if locally_changed {
+                            report.conflicts.push(conflict(&task, "remote"));
+                        }
+
+                        let task = match task.archived_at {
+                            Some(_) => task_repo.unarchive_task(&task.id).await?,
+                            None => task,
+                        };
+                        let updated = apply_todo(&task_repo, &status_repo, &task, &todo).await?;
+                        report.pulled += 1;
+                        pulled.insert(updated.id.clone());
+                        save_item(&sync_repo, calendar_url, item, etag, updated.updated_at).await
+                    }
+                    // Deleted or archived locally; the push deletes the remote item
+                    (Some(item), _) => {
+                        save_item(&sync_repo, calendar_url, item, etag, item.task_updated_at).await
+                    }
+                    (None, _) => {
+                        let task = create_task(&task_repo, config, &todo).await?;
+                        source_repo
+                            .record_source(RecordTaskSourceRequest {
+                                task_id: task.id.clone(),
+                                source_type: "caldav".to_string(),
+                                external_id: Some(todo.uid.clone()),
+                                url: client.object_url(&change.href),
+                            })
+                            .await?;
+                        report.created += 1;
+                        pulled.insert(task.id.clone());
+                        sync_repo
+                            .save_item(SaveSyncItemRequest {
+                                calendar_url: calendar_url.to_string(),
+                                task_id: task.id,
+                                uid: todo.uid.clone(),
+                                href: change.href.clone(),
+                                etag,
+                                task_updated_at: task.updated_at,
+                            })
+                            .await?;
+                        Ok(())
+                    }
+                }
+            }
+            .await;
+            if let Err(e) = result {
+                report
+                    .errors
+                    .push(format!("Failed to sync '{}': {}", todo.summary, e));
+            }
+        }
+
+        for href in changes.removed {
+            let Some(item) = items.remove(&href) else {
+                continue;
+            };
+            let task = task_repo.find_by_id(&item.task_id).await?;
+            sync_repo.delete_item(&item.id).await?;
+            let Some(task) = task.filter(|task| task.archived_at.is_none()) else {
+                continue;
+            };
+
+            // Without its item the task is pushed again as a new one
+            if task.updated_at != item.task_updated_at
+                && config.conflict_resolution != ConflictResolution::RemoteWins
+            {
+                report.conflicts.push(conflict(&task, "local"));
+                continue;
+            }
+            // Archived rather than deleted, so a deletion on another device can be undone
+            match task_repo.archive_task(&task.id).await {
+                Ok(_) => report.archived_locally += 1,
+                Err(e) => report
+                    .errors
+                    .push(format!("Failed to archive '{}': {}", task.title, e)),
+            }
+        }
+
+        Ok(pulled)
+    }
+
+    /// Write new and changed tasks to the server and delete items of removed tasks
+    async fn push(
+        &self,
+        client: &CalDavClient,
+        config: &CalDavConfig,
+        calendar_url: &str,
+        pulled: &HashSet<String>,
+        report: &mut SyncReport,
+    ) -> Result<()> {
+        let sync_repo = SyncRepository::new(self.db.clone());
+        let task_repo = TaskRepository::new(self.db.clone());
+        let items = sync_repo
+            .find_items(calendar_url)
+            .await
+            .context("Failed to fetch synced items")?;
+        let tasks: HashMap<String, tasks::Model> = task_repo
+            .find_all(None, None)
+            .await
+            .context("Failed to fetch tasks")?
+            .into_iter()
+            .map(|task| (task.id.clone(), task))
+            .collect();
+        let mut items_by_task: HashMap<&str, &sync_items::Model> = HashMap::new();
+
+        for item in &items {
+            if tasks.contains_key(&item.task_id) {
+                items_by_task.insert(&item.task_id, item);
+                continue;
+            }
+            match client.delete(&item.href, item.etag.as_deref()).await {
+                Ok(WriteResponse::Stored(_)) => {
+                    sync_repo.delete_item(&item.id).await?;
+                    report.deleted_remotely += 1;
+                }
+                // Changed remotely after the local deletion; the next pull decides
+                Ok(WriteResponse::PreconditionFailed) => {}
+                Err(e) => report.errors.push(e.to_string()),
+            }
+        }
+
+        let mut tasks: Vec<&tasks::Model> = tasks.values().collect();
+        tasks.sort_by_key(|task| task.created_at);
+        for task in tasks {
+            if pulled.contains(&task.id) {
+                continue;
+            }
+            let item = items_by_task.get(task.id.as_str()).copied();
+            let (uid, href) = match item {
+                Some(item) if item.task_updated_at == task.updated_at => continue,
+                Some(item) => (item.uid.clone(), item.href.clone()),
+                None if task.scheduled_date.is_none() && task.due_date.is_none() => continue,
+                None => (task.id.clone(), client.object_href(&task.id)),
+            };
+
+            let data = render_todo(&RemoteTodo::from_task(task, &uid));
+            let etag = item.and_then(|item| item.etag.as_deref());
+            let mut response = client.put(&href, data.clone(), etag, false).await;
+            if matches!(response, Ok(WriteResponse::PreconditionFailed))
+                && config.conflict_resolution == ConflictResolution::LocalWins
+                && item.is_some()
+            {
+                report.conflicts.push(conflict(task, "local"));
+                response = client.put(&href, data, None, true).await;
+            }
+
+            match response {
+                Ok(WriteResponse::Stored(etag)) => {
+                    sync_repo
+                        .save_item(SaveSyncItemRequest {
+                            calendar_url: calendar_url.to_string(),
+                            task_id: task.id.clone(),
+                            uid,
+                            href,
+                            etag,
+                            task_updated_at: task.updated_at,
+                        })
+                        .await?;
+                    report.pushed += 1;
+                }
+                // Changed remotely since the last pull; the next sync resolves it
+                Ok(WriteResponse::PreconditionFailed) => {}
+                Err(e) => report
+                    .errors
+                    .push(format!("Failed to push '{}': {}", task.title, e)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether a task changed on both sides keeps its local version
+pub(crate) fn keep_local(
+    resolution: ConflictResolution,
+    task: &tasks::Model,
+    remote_modified: Option<DateTime<Utc>>,
+) -> bool {
+    match resolution {
+        ConflictResolution::LocalWins => true,
+        ConflictResolution::RemoteWins => false,
+        // Without a modification time the remote side can't prove it is newer
+        ConflictResolution::NewestWins => {
+            remote_modified.map_or(true, |remote_modified| task.updated_at >= remote_modified)
+        }
+    }
+}
+
+fn conflict(task: &tasks::Model, kept: &str) -> SyncConflict {
+    SyncConflict {
+        task_id: task.id.clone(),
+        title: task.title.clone(),
+        kept: kept.to_string(),
+    }
+}
+
+async fn save_item(
+    sync_repo: &SyncRepository,
+    calendar_url: &str,
+    item: &sync_items::Model,
+    etag: Option<String>,
+    task_updated_at: DateTime<Utc>,
+) -> Result<()> {
+    sync_repo
+        .save_item(SaveSyncItemRequest {
+            calendar_url: calendar_url.to_string(),
+            task_id: item.task_id.clone(),
+            uid: item.uid.clone(),
+            href: item.href.clone(),
+            etag,
+            task_updated_at,
+        })
+        .await?;
+    Ok(())
+}
+
+/// Update a task from its remote item
+pub(crate) async fn apply_todo(
+    task_repo: &TaskRepository,
+    status_repo: &TaskStatusRepository,
+    task: &tasks::Model,
+    todo: &RemoteTodo,
+) -> Result<tasks::Model> {
+    let status = match (todo.completed, task.status == "completed") {
+        (true, false) => Some("completed".to_string()),
+        (false, true) => Some(status_repo.reopen_status().await?),
+        _ => None,
+    };
+
+    Ok(task_repo
+        .update_task(
+            &task.id,
+            UpdateTaskRequest {
+                title: Some(todo.summary.clone()).filter(|title| !title.trim().is_empty()),
+                description: todo.description.clone(),
+                priority: todo.priority,
+                status,
+                order_num: None,
+                dependencies: None,
+                time_estimate: None,
+                actual_time: None,
+                due_date: todo.due,
+                scheduled_date: todo.start,
+                // Only a cleared DTSTART clears the date; `Some(false)` would skip a new one
+                clear_scheduled_date: (todo.start.is_none() && task.scheduled_date.is_some())
+                    .then_some(true),
+                tags: None,
+                project_id: None,
+                parent_task_id: None,
+                task_list_id: None,
+                completed_at: None,
+                effort: None,
+                impact: None,
+                expected_updated_at: Some(task.updated_at),
+            },
+        )
+        .await?)
+}
+
+async fn create_task(
+    task_repo: &TaskRepository,
+    config: &CalDavConfig,
+    todo: &RemoteTodo,
+) -> Result<tasks::Model> {
+    let title = if todo.summary.trim().is_empty() {
+        "Untitled task".to_string()
+    } else {
+        todo.summary.clone()
+    };
+
+    Ok(task_repo
+        .create_task(CreateTaskRequest {
+            title,
+            description: todo.description.clone(),
+            priority: todo.priority.unwrap_or(1),
+            status: todo.completed.then(|| "completed".to_string()),
+            order_num: None,
+            dependencies: None,
+            time_estimate: None,
+            due_date: todo.due,
+            scheduled_date: todo.start,
+            tags: None,
+            project_id: None,
+            parent_task_id: None,
+            task_list_id: config.task_list_id.clone(),
+            periodic_template_id: None,
+            is_periodic_instance: None,
+            generation_date: None,
+            effort: None,
+            impact: None,
+        })
+        .await?)
+}
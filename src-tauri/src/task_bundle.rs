@@ -0,0 +1,794 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::database::entities::{
+    attachments, checklist_items, task_dependencies, task_lists, task_notes, tasks,
+};
+use crate::database::repositories::{
+    AttachmentRepository, ChecklistRepository, TaskListRepository, TaskNoteRepository,
+    TaskRepository,
+};
+
+const BUNDLE_VERSION: &str = "2.0.0";
+
+/// Prefix of the signer's public key in the manifest
+const SIGNER_PREFIX: &str = "ed25519:";
+
+/// Directory inside the bundle archive holding attachment files
+const ATTACHMENTS_ARCHIVE_DIR: &str = "attachments";
+
+/// What a bundle was exported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskBundleKind {
+    Task,
+    TaskList,
+}
+
+/// Task list information carried by a list bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledTaskList {
+    pub name: String,
+}
+
+/// Payload of a shareable task bundle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskBundleData {
+    pub version: String,
+    pub kind: TaskBundleKind,
+    pub created_at: DateTime<Utc>,
+    pub task_list: Option<BundledTaskList>,
+    /// Root tasks first, followed by their subtasks (parents always precede children)
+    pub tasks: Vec<tasks::Model>,
+    pub task_dependencies: Vec<task_dependencies::Model>,
+    /// Missing from bundles written before checklists were bundled
+    #[serde(default)]
+    pub checklist_items: Vec<checklist_items::Model>,
+    /// Missing from bundles written before notes were bundled
+    #[serde(default)]
+    pub task_notes: Vec<task_notes::Model>,
+    /// Their files are packed under `attachments/<stored_name>`
+    #[serde(default)]
+    pub attachments: Vec<attachments::Model>,
+}
+
+/// Manifest stored next to the bundle payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskBundleManifest {
+    pub version: String,
+    pub kind: TaskBundleKind,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub task_count: usize,
+    pub dependency_count: usize,
+    #[serde(default)]
+    pub checklist_item_count: usize,
+    #[serde(default)]
+    pub note_count: usize,
+    #[serde(default)]
+    pub attachment_count: usize,
+    /// SHA-256 digest of bundle.json
+    pub checksum: String,
+    /// Public key of the install that exported the bundle, as `ed25519:<hex>`
+    ///
+    /// manifest.json is signed with the matching private key, so a bundle edited after export
+    /// fails verification; signing it again with another key changes the signer.
+    #[serde(default)]
+    pub signer: String,
+}
+
+/// Result of importing a bundle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskBundleImportResult {
+    pub manifest: TaskBundleManifest,
+    pub task_list_id: String,
+    pub imported_task_ids: Vec<String>,
+    pub imported_dependency_count: usize,
+    pub imported_checklist_item_count: usize,
+    pub imported_note_count: usize,
+    pub imported_attachment_count: usize,
+}
+
+/// Exports and imports single tasks or task lists as small signed bundle files
+pub struct TaskBundleService {
+    db: Arc<DatabaseConnection>,
+    signing_key: SigningKey,
+    attachments_dir: Option<PathBuf>,
+}
+
+impl TaskBundleService {
+    /// Bundles are exported signed with `signing_key`, see [`load_signing_key`]
+    pub fn new(db: Arc<DatabaseConnection>, signing_key: SigningKey) -> Self {
+        Self {
+            db,
+            signing_key,
+            attachments_dir: None,
+        }
+    }
+
+    /// Pack the attachment files stored in `attachments_dir` into exports and unpack them on
+    /// import
+    ///
+    /// Without it bundles carry no attachments.
+    pub fn with_attachments_dir(mut self, attachments_dir: PathBuf) -> Self {
+        self.attachments_dir = Some(attachments_dir);
+        self
+    }
+
+    /// Export a task together with all of its subtasks
+    pub async fn export_task(&self, task_id: &str, file_path: &str) -> Result<TaskBundleManifest> {
+        let task_repo = TaskRepository::new(self.db.clone());
+
+        let task = task_repo
+            .find_by_id(task_id)
+            .await
+            .context("Failed to fetch task")?
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_id))?;
+
+        let title = task.title.clone();
+        let tasks = self.collect_with_subtasks(vec![task]).await?;
+        let task_dependencies = self.collect_internal_dependencies(&tasks).await?;
+        let (checklist_items, task_notes) = self.collect_task_details(&tasks).await?;
+        let attachments = self.collect_attachments(&tasks).await?;
+
+        let bundle = TaskBundleData {
+            version: BUNDLE_VERSION.to_string(),
+            kind: TaskBundleKind::Task,
+            created_at: Utc::now(),
+            task_list: None,
+            tasks,
+            task_dependencies,
+            checklist_items,
+            task_notes,
+            attachments,
+        };
+
+        self.write_bundle(&bundle, title, file_path).await
+    }
+
+    /// Export a task list with every task it contains
+    pub async fn export_task_list(
+        &self,
+        task_list_id: &str,
+        file_path: &str,
+    ) -> Result<TaskBundleManifest> {
+        let task_repo = TaskRepository::new(self.db.clone());
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+
+        let task_list = task_list_repo
+            .find_by_id(task_list_id)
+            .await
+            .context("Failed to fetch task list")?
+            .ok_or_else(|| anyhow::anyhow!("Task list not found: {}", task_list_id))?;
+
+        let list_tasks = task_repo
+            .find_by_task_list(task_list_id)
+            .await
+            .context("Failed to fetch tasks in list")?;
+
+        // Start from root tasks so that subtasks living in other lists are still included
+        let list_task_ids: HashSet<String> = list_tasks.iter().map(|t| t.id.clone()).collect();
+        let roots = list_tasks
+            .into_iter()
+            .filter(|t| {
+                t.parent_task_id
+                    .as_ref()
+                    .is_none_or(|parent| !list_task_ids.contains(parent))
+            })
+            .collect();
+
+        let tasks = self.collect_with_subtasks(roots).await?;
+        let task_dependencies = self.collect_internal_dependencies(&tasks).await?;
+        let (checklist_items, task_notes) = self.collect_task_details(&tasks).await?;
+        let attachments = self.collect_attachments(&tasks).await?;
+
+        let bundle = TaskBundleData {
+            version: BUNDLE_VERSION.to_string(),
+            kind: TaskBundleKind::TaskList,
+            created_at: Utc::now(),
+            task_list: Some(BundledTaskList {
+                name: task_list.name.clone(),
+            }),
+            tasks,
+            task_dependencies,
+            checklist_items,
+            task_notes,
+            attachments,
+        };
+
+        self.write_bundle(&bundle, task_list.name, file_path).await
+    }
+
+    /// Read a bundle's manifest and verify its signature without importing it
+    pub fn inspect_bundle(&self, file_path: &str) -> Result<TaskBundleManifest> {
+        let (manifest, _) = self.read_bundle(file_path)?;
+        Ok(manifest)
+    }
+
+    /// Import a bundle, assigning fresh IDs to every task
+    ///
+    /// Everything is imported in one transaction, including a list created for the tasks, so a
+    /// failed import leaves nothing behind; attachment files are written just before the commit
+    /// and removed again if it fails. Tasks go into `target_task_list_id` when given.
+    /// Otherwise a list bundle is imported into a list with the bundled name (created if
+    /// missing) and a task bundle into the default list.
+    pub async fn import_bundle(
+        &self,
+        file_path: &str,
+        target_task_list_id: Option<String>,
+    ) -> Result<TaskBundleImportResult> {
+        let (manifest, bundle) = self.read_bundle(file_path)?;
+        let attachment_files = match &self.attachments_dir {
+            Some(_) => read_attachment_files(file_path, &bundle.attachments)?,
+            None => HashMap::new(),
+        };
+
+        let id_map: HashMap<String, String> = bundle
+            .tasks
+            .iter()
+            .map(|task| (task.id.clone(), uuid::Uuid::new_v4().to_string()))
+            .collect();
+
+        let now = Utc::now();
+        let mut imported_task_ids = Vec::with_capacity(bundle.tasks.len());
+        let txn = self.db.begin().await?;
+        let task_list_id =
+            Self::resolve_target_task_list(&txn, target_task_list_id, bundle.task_list.as_ref())
+                .await?;
+
+        for task in bundle.tasks {
+            let new_id = id_map[&task.id].clone();
+            let dependencies = task.dependencies.as_ref().map(|deps| {
+                let ids: Vec<String> = serde_json::from_str::<Vec<String>>(deps)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|id| id_map.get(&id).cloned())
+                    .collect();
+                serde_json::to_string(&ids).unwrap_or_default()
+            });
+
+            let imported = tasks::Model {
+                id: new_id.clone(),
+                dependencies,
+                parent_task_id: task
+                    .parent_task_id
+                    .as_ref()
+                    .and_then(|parent| id_map.get(parent).cloned()),
+                task_list_id: Some(task_list_id.clone()),
+                // Projects and periodic templates are not part of a bundle, so the tasks leave
+                // their project and instances become plain tasks
+                project_id: None,
+                periodic_template_id: None,
+                is_periodic_instance: false,
+                generation_date: None,
+                actual_time: 0,
+                archived_at: None,
+                created_at: now,
+                updated_at: now,
+                ..task
+            };
+
+            TaskRepository::import_task_in(&txn, imported)
+                .await
+                .context("Failed to import task")?;
+            imported_task_ids.push(new_id);
+        }
+
+        let mut imported_dependency_count = 0;
+        for dependency in bundle.task_dependencies {
+            let (Some(task_id), Some(depends_on_id)) = (
+                id_map.get(&dependency.task_id),
+                id_map.get(&dependency.depends_on_id),
+            ) else {
+                continue;
+            };
+
+            TaskRepository::import_dependency_in(
+                &txn,
+                task_dependencies::Model {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    task_id: task_id.clone(),
+                    depends_on_id: depends_on_id.clone(),
+                    relation_type: dependency.relation_type,
+                    created_at: now,
+                },
+            )
+            .await
+            .context("Failed to import task dependency")?;
+            imported_dependency_count += 1;
+        }
+
+        let mut imported_checklist_item_count = 0;
+        for item in bundle.checklist_items {
+            let Some(task_id) = id_map.get(&item.task_id) else {
+                continue;
+            };
+
+            ChecklistRepository::import_item_in(
+                &txn,
+                checklist_items::Model {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    task_id: task_id.clone(),
+                    created_at: now,
+                    updated_at: now,
+                    ..item
+                },
+            )
+            .await
+            .context("Failed to import checklist item")?;
+            imported_checklist_item_count += 1;
+        }
+
+        let mut imported_note_count = 0;
+        for note in bundle.task_notes {
+            let Some(task_id) = id_map.get(&note.task_id) else {
+                continue;
+            };
+
+            TaskNoteRepository::import_note_in(
+                &txn,
+                task_notes::Model {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    task_id: task_id.clone(),
+                    ..note
+                },
+            )
+            .await
+            .context("Failed to import task note")?;
+            imported_note_count += 1;
+        }
+
+        let mut imported_attachments = Vec::new();
+        for attachment in bundle.attachments {
+            let (Some(task_id), Some(contents)) = (
+                id_map.get(&attachment.task_id),
+                attachment_files.get(&attachment.stored_name),
+            ) else {
+                continue;
+            };
+
+            let id = uuid::Uuid::new_v4().to_string();
+            let stored_name = match Path::new(&attachment.stored_name).extension() {
+                Some(ext) => format!("{}.{}", id, ext.to_string_lossy()),
+                None => id.clone(),
+            };
+            let imported = AttachmentRepository::import_attachment_in(
+                &txn,
+                attachments::Model {
+                    id,
+                    task_id: task_id.clone(),
+                    stored_name,
+                    created_at: now,
+                    ..attachment
+                },
+            )
+            .await
+            .context("Failed to import attachment")?;
+            imported_attachments.push((imported, contents.as_slice()));
+        }
+
+        // Files are written last so a failed insert leaves none behind
+        let written = self.store_attachment_files(&imported_attachments)?;
+        if let Err(e) = txn.commit().await {
+            remove_files(&written);
+            return Err(e.into());
+        }
+
+        Ok(TaskBundleImportResult {
+            manifest,
+            task_list_id,
+            imported_task_ids,
+            imported_dependency_count,
+            imported_checklist_item_count,
+            imported_note_count,
+            imported_attachment_count: imported_attachments.len(),
+        })
+    }
+
+    /// Walk the subtask hierarchy breadth-first so parents precede their children
+    async fn collect_with_subtasks(&self, roots: Vec<tasks::Model>) -> Result<Vec<tasks::Model>> {
+        let task_repo = TaskRepository::new(self.db.clone());
+
+        let mut seen: HashSet<String> = roots.iter().map(|t| t.id.clone()).collect();
+        let mut collected = roots;
+        let mut index = 0;
+
+        while index < collected.len() {
+            let parent_id = collected[index].id.clone();
+            let children = task_repo
+                .find_subtasks(&parent_id)
+                .await
+                .context("Failed to fetch subtasks")?;
+
+            for child in children {
+                if seen.insert(child.id.clone()) {
+                    collected.push(child);
+                }
+            }
+            index += 1;
+        }
+
+        Ok(collected)
+    }
+
+    /// Keep only dependencies where both ends are part of the bundle
+    async fn collect_internal_dependencies(
+        &self,
+        bundle_tasks: &[tasks::Model],
+    ) -> Result<Vec<task_dependencies::Model>> {
+        let task_repo = TaskRepository::new(self.db.clone());
+        let ids: HashSet<&str> = bundle_tasks.iter().map(|t| t.id.as_str()).collect();
+
+        Ok(task_repo
+            .get_all_dependencies()
+            .await
+            .context("Failed to fetch task dependencies")?
+            .into_iter()
+            .filter(|dep| ids.contains(dep.task_id.as_str()) && ids.contains(dep.depends_on_id.as_str()))
+            .collect())
+    }
+
+    /// Checklists and notes of the bundled tasks, each in their per-task order
+    async fn collect_task_details(
+        &self,
+        bundle_tasks: &[tasks::Model],
+    ) -> Result<(Vec<checklist_items::Model>, Vec<task_notes::Model>)> {
+        let checklist_repo = ChecklistRepository::new(self.db.clone());
+        let note_repo = TaskNoteRepository::new(self.db.clone());
+
+        let mut items = Vec::new();
+        let mut notes = Vec::new();
+        for task in bundle_tasks {
+            items.extend(
+                checklist_repo
+                    .find_by_task(&task.id)
+                    .await
+                    .context("Failed to fetch checklist items")?,
+            );
+            notes.extend(
+                note_repo
+                    .find_by_task(&task.id)
+                    .await
+                    .context("Failed to fetch task notes")?,
+            );
+        }
+        Ok((items, notes))
+    }
+
+    /// Attachments of the bundled tasks whose files are at hand
+    async fn collect_attachments(
+        &self,
+        bundle_tasks: &[tasks::Model],
+    ) -> Result<Vec<attachments::Model>> {
+        let Some(attachments_dir) = &self.attachments_dir else {
+            return Ok(Vec::new());
+        };
+
+        let task_ids = bundle_tasks.iter().map(|t| t.id.clone()).collect();
+        let attachments = AttachmentRepository::find_by_tasks_in(&*self.db, task_ids)
+            .await
+            .context("Failed to fetch attachments")?;
+        Ok(attachments
+            .into_iter()
+            .filter(|attachment| {
+                let name = &attachment.stored_name;
+                let present = attachments_dir.join(name).is_file();
+                if !present {
+                    log::warn!("Attachment file {} is missing, skipping", name);
+                }
+                present
+            })
+            .collect())
+    }
+
+    /// Write imported attachment files, removing them all again if one fails
+    fn store_attachment_files(
+        &self,
+        imported: &[(attachments::Model, &[u8])],
+    ) -> Result<Vec<PathBuf>> {
+        let Some(attachments_dir) = &self.attachments_dir else {
+            return Ok(Vec::new());
+        };
+        if imported.is_empty() {
+            return Ok(Vec::new());
+        }
+        std::fs::create_dir_all(attachments_dir)
+            .context("Failed to create attachments directory")?;
+
+        let mut written = Vec::with_capacity(imported.len());
+        for (attachment, contents) in imported {
+            let path = attachments_dir.join(&attachment.stored_name);
+            if let Err(e) = std::fs::write(&path, contents) {
+                remove_files(&written);
+                return Err(e).with_context(|| {
+                    format!("Failed to store attachment: {}", attachment.file_name)
+                });
+            }
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    async fn resolve_target_task_list<C: ConnectionTrait>(
+        conn: &C,
+        target_task_list_id: Option<String>,
+        bundled_list: Option<&BundledTaskList>,
+    ) -> Result<String> {
+        if let Some(id) = target_task_list_id.filter(|id| !id.trim().is_empty()) {
+            if task_lists::Entity::find_by_id(&id)
+                .one(conn)
+                .await?
+                .is_none()
+            {
+                return Err(anyhow::anyhow!("Task list not found: {}", id));
+            }
+            return Ok(id);
+        }
+
+        if let Some(bundled_list) = bundled_list {
+            if let Some(existing) =
+                TaskListRepository::find_by_name_in(conn, &bundled_list.name).await?
+            {
+                return Ok(existing.id);
+            }
+            let created = TaskListRepository::create_task_list_in(conn, bundled_list.name.clone())
+                .await
+                .context("Failed to create task list for bundle")?;
+            return Ok(created.id);
+        }
+
+        Ok(task_lists::Entity::find()
+            .filter(task_lists::Column::IsDefault.eq(true))
+            .one(conn)
+            .await?
+            .context("Default task list not found")?
+            .id)
+    }
+
+    pub(crate) async fn write_bundle(
+        &self,
+        bundle: &TaskBundleData,
+        title: String,
+        file_path: &str,
+    ) -> Result<TaskBundleManifest> {
+        let data_json = serde_json::to_string_pretty(bundle)?;
+
+        let manifest = TaskBundleManifest {
+            version: bundle.version.clone(),
+            kind: bundle.kind,
+            title,
+            created_at: bundle.created_at,
+            task_count: bundle.tasks.len(),
+            dependency_count: bundle.task_dependencies.len(),
+            checklist_item_count: bundle.checklist_items.len(),
+            note_count: bundle.task_notes.len(),
+            attachment_count: bundle.attachments.len(),
+            checksum: checksum(data_json.as_bytes()),
+            signer: signer_id(&self.signing_key.verifying_key()),
+        };
+        let manifest_json = serde_json::to_string_pretty(&manifest)?;
+        let signature = self.signing_key.sign(manifest_json.as_bytes());
+
+        let file = File::create(file_path)
+            .with_context(|| format!("Failed to create bundle file: {}", file_path))?;
+        let mut zip = ZipWriter::new(file);
+        let options = FileOptions::<()>::default().compression_method(CompressionMethod::Deflated);
+
+        zip.start_file("manifest.json", options)?;
+        zip.write_all(manifest_json.as_bytes())?;
+
+        zip.start_file("manifest.sig", options)?;
+        zip.write_all(to_hex(&signature.to_bytes()).as_bytes())?;
+
+        zip.start_file("bundle.json", options)?;
+        zip.write_all(data_json.as_bytes())?;
+
+        if let Some(attachments_dir) = &self.attachments_dir {
+            for attachment in &bundle.attachments {
+                let contents = std::fs::read(attachments_dir.join(&attachment.stored_name))
+                    .with_context(|| {
+                        format!("Failed to read attachment: {}", attachment.stored_name)
+                    })?;
+                zip.start_file(
+                    format!("{}/{}", ATTACHMENTS_ARCHIVE_DIR, attachment.stored_name),
+                    options,
+                )?;
+                zip.write_all(&contents)?;
+            }
+        }
+
+        zip.finish()?;
+
+        Ok(manifest)
+    }
+
+    fn read_bundle(&self, file_path: &str) -> Result<(TaskBundleManifest, TaskBundleData)> {
+        let file = File::open(file_path)
+            .with_context(|| format!("Failed to open bundle file: {}", file_path))?;
+        let mut archive = ZipArchive::new(file)?;
+
+        let mut manifest_content = String::new();
+        archive
+            .by_name("manifest.json")
+            .context("Bundle file is missing manifest.json")?
+            .read_to_string(&mut manifest_content)?;
+        let manifest: TaskBundleManifest =
+            serde_json::from_str(&manifest_content).context("Failed to parse manifest.json")?;
+
+        // Bundles written before signing have no signature to check
+        if manifest.version != BUNDLE_VERSION {
+            return Err(anyhow::anyhow!(
+                "Unsupported bundle version: {}. Expected: {}",
+                manifest.version,
+                BUNDLE_VERSION
+            ));
+        }
+
+        let mut signature = String::new();
+        archive
+            .by_name("manifest.sig")
+            .context("Bundle file is missing its signature")?
+            .read_to_string(&mut signature)?;
+        verify_signature(&manifest.signer, manifest_content.as_bytes(), &signature)?;
+
+        let mut data_content = String::new();
+        archive
+            .by_name("bundle.json")
+            .context("Bundle file is missing bundle.json")?
+            .read_to_string(&mut data_content)?;
+
+        if checksum(data_content.as_bytes()) != manifest.checksum {
+            return Err(anyhow::anyhow!(
+                "Bundle checksum mismatch: bundle.json was modified after export"
+            ));
+        }
+
+        let bundle: TaskBundleData =
+            serde_json::from_str(&data_content).context("Failed to parse bundle.json")?;
+
+        if bundle.version != BUNDLE_VERSION {
+            return Err(anyhow::anyhow!(
+                "Unsupported bundle version: {}. Expected: {}",
+                bundle.version,
+                BUNDLE_VERSION
+            ));
+        }
+
+        Ok((manifest, bundle))
+    }
+}
+
+/// Read the attachment files of a bundle, keyed by stored name
+///
+/// Each file must match the checksum in bundle.json, which the signature covers.
+fn read_attachment_files(
+    file_path: &str,
+    bundled: &[attachments::Model],
+) -> Result<HashMap<String, Vec<u8>>> {
+    if bundled.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let file = File::open(file_path)
+        .with_context(|| format!("Failed to open bundle file: {}", file_path))?;
+    let mut archive = ZipArchive::new(file)?;
+
+    let mut files = HashMap::new();
+    for attachment in bundled {
+        let name = &attachment.stored_name;
+        // Stored names are generated by the app; anything else could escape the directory
+        if name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(anyhow::anyhow!(
+                "Bundle has an attachment with an invalid name: {}",
+                name
+            ));
+        }
+
+        let archive_path = format!("{}/{}", ATTACHMENTS_ARCHIVE_DIR, name);
+        let mut contents = Vec::new();
+        archive
+            .by_name(&archive_path)
+            .with_context(|| format!("Bundle file is missing attachment: {}", name))?
+            .read_to_end(&mut contents)?;
+        if checksum(&contents) != attachment.checksum {
+            return Err(anyhow::anyhow!(
+                "Attachment checksum mismatch: {} was modified after export",
+                attachment.file_name
+            ));
+        }
+        files.insert(name.clone(), contents);
+    }
+    Ok(files)
+}
+
+/// Remove files written by a failed import
+fn remove_files(paths: &[PathBuf]) {
+    for path in paths {
+        if let Err(e) = std::fs::remove_file(path) {
+            log::warn!("Failed to remove {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Load the key this install signs bundles with, creating it on first use
+pub fn load_signing_key(path: &Path) -> Result<SigningKey> {
+    match std::fs::read(path) {
+        Ok(bytes) => {
+            let seed: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+                anyhow::anyhow!("Bundle signing key is corrupted: {}", path.display())
+            })?;
+            Ok(SigningKey::from_bytes(&seed))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let key = SigningKey::generate(&mut rand_core::OsRng);
+            let mut options = std::fs::OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+            match options.open(path) {
+                Ok(mut file) => {
+                    file.write_all(&key.to_bytes())
+                        .context("Failed to store bundle signing key")?;
+                    Ok(key)
+                }
+                // Another process created it first
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => load_signing_key(path),
+                Err(e) => Err(e).context("Failed to create bundle signing key"),
+            }
+        }
+        Err(e) => Err(e).context("Failed to read bundle signing key"),
+    }
+}
+
+/// The manifest's `signer` for a public key
+fn signer_id(key: &VerifyingKey) -> String {
+    format!("{}{}", SIGNER_PREFIX, to_hex(key.as_bytes()))
+}
+
+/// Check `signature` (hex) over `message` against the public key named by `signer`
+fn verify_signature(signer: &str, message: &[u8], signature: &str) -> Result<()> {
+    let key = signer
+        .strip_prefix(SIGNER_PREFIX)
+        .and_then(from_hex)
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok())
+        .ok_or_else(|| anyhow::anyhow!("Bundle has an invalid signer: {}", signer))?;
+    let signature = from_hex(signature.trim())
+        .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+        .map(|bytes| Signature::from_bytes(&bytes))
+        .ok_or_else(|| anyhow::anyhow!("Bundle has an invalid signature"))?;
+
+    key.verify(message, &signature).map_err(|_| {
+        anyhow::anyhow!("Bundle signature mismatch: the manifest was modified after export")
+    })
+}
+
+fn checksum(data: &[u8]) -> String {
+    format!("sha256:{}", to_hex(&Sha256::digest(data)))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+        .collect()
+}
@@ -0,0 +1,663 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::database::repositories::task_repository::CreateTaskRequest;
+use crate::database::repositories::task_source_repository::RecordTaskSourceRequest;
+use crate::database::repositories::{TaskListRepository, TaskRepository, TaskSourceRepository};
+use crate::database::services::date_parser::parse_natural_date;
+
+/// Task fields a CSV column can be mapped to, with the column names matched by default
+const CSV_FIELDS: [(&str, &[&str]); 8] = [
+    ("title", &["title", "name", "task", "summary"]),
+    ("description", &["description", "notes", "details"]),
+    ("priority", &["priority"]),
+    ("status", &["status", "state"]),
+    ("due_date", &["due_date", "due date", "due", "deadline"]),
+    ("time_estimate", &["time_estimate", "estimate", "minutes"]),
+    ("tags", &["tags", "labels"]),
+    ("task_list", &["task_list", "task list", "list", "project"]),
+];
+
+/// File formats tasks can be imported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskImportFormat {
+    /// One task per row, with a header row naming the columns
+    Csv,
+    /// `- [ ]` / `- [x]` checkbox items; nested items become subtasks and headings name lists
+    Markdown,
+}
+
+impl TaskImportFormat {
+    /// Source recorded on tasks imported from this format
+    fn source_type(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Markdown => "markdown",
+        }
+    }
+}
+
+/// Why a row would not be imported by default
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TaskImportDuplicate {
+    /// A task with the same title already exists in the target list
+    Existing { task_id: String },
+    /// An earlier row of the file has the same title and list
+    File { line: usize },
+}
+
+/// A task read from an import file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImportRow {
+    /// Line of the file the task starts on
+    pub line: usize,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<i32>,
+    pub status: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub time_estimate: Option<i32>,
+    pub tags: Vec<String>,
+    /// Task list named in the file (CSV column or Markdown heading)
+    pub task_list: Option<String>,
+    /// Index of the parent row for nested Markdown items
+    pub parent: Option<usize>,
+    pub duplicate: Option<TaskImportDuplicate>,
+    /// Values that could not be read; the row is imported without them
+    pub warnings: Vec<String>,
+}
+
+/// A row that could not be read or imported
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImportError {
+    pub line: usize,
+    pub message: String,
+}
+
+/// What an import would create
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImportPreview {
+    pub format: TaskImportFormat,
+    /// Header of a CSV file; empty for Markdown
+    pub columns: Vec<String>,
+    /// CSV column used for each task field
+    pub column_mapping: HashMap<String, String>,
+    pub rows: Vec<TaskImportRow>,
+    pub duplicate_count: usize,
+    pub errors: Vec<TaskImportError>,
+}
+
+/// How the rows of a file become tasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImportMapping {
+    pub format: TaskImportFormat,
+    /// CSV column for each task field (`title`, `description`, `priority`, `status`,
+    /// `due_date`, `time_estimate`, `tags`, `task_list`); other fields are matched by name
+    #[serde(default)]
+    pub columns: HashMap<String, String>,
+    /// List for tasks that don't name one; the default list when unset
+    pub task_list_id: Option<String>,
+    /// Task list ID for list names used in the file; other names are matched to existing
+    /// lists by name or created
+    #[serde(default)]
+    pub task_lists: HashMap<String, String>,
+    /// Tags added to every imported task
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Import duplicate rows instead of skipping them
+    #[serde(default)]
+    pub include_duplicates: bool,
+}
+
+impl TaskImportMapping {
+    fn for_format(format: TaskImportFormat) -> Self {
+        Self {
+            format,
+            columns: HashMap::new(),
+            task_list_id: None,
+            task_lists: HashMap::new(),
+            tags: Vec::new(),
+            include_duplicates: false,
+        }
+    }
+}
+
+/// Result of importing a file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImportResult {
+    pub imported_task_ids: Vec<String>,
+    pub skipped_duplicates: usize,
+    pub errors: Vec<TaskImportError>,
+}
+
+/// Imports tasks from CSV files and Markdown checkbox lists
+pub struct TaskImportService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TaskImportService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Read a file and report the tasks it contains, matching CSV columns by name
+    pub async fn preview(
+        &self,
+        file_path: &str,
+        format: TaskImportFormat,
+    ) -> Result<TaskImportPreview> {
+        self.preview_with_mapping(file_path, &TaskImportMapping::for_format(format))
+            .await
+    }
+
+    async fn preview_with_mapping(
+        &self,
+        file_path: &str,
+        mapping: &TaskImportMapping,
+    ) -> Result<TaskImportPreview> {
+        let content = tokio::fs::read_to_string(file_path)
+            .await
+            .with_context(|| format!("Failed to read {}", file_path))?;
+
+        let mut preview = match mapping.format {
+            TaskImportFormat::Csv => parse_csv_tasks(&content, &mapping.columns)?,
+            TaskImportFormat::Markdown => parse_markdown_tasks(&content),
+        };
+        self.mark_duplicates(&mut preview.rows, mapping).await?;
+        preview.duplicate_count = preview
+            .rows
+            .iter()
+            .filter(|row| row.duplicate.is_some())
+            .count();
+
+        Ok(preview)
+    }
+
+    /// Create tasks from a file
+    ///
+    /// Duplicates are skipped unless the mapping includes them; subtasks of a skipped
+    /// duplicate are attached to the existing task. Rows that fail are reported and the
+    /// rest are still imported.
+    pub async fn import(
+        &self,
+        file_path: &str,
+        mapping: &TaskImportMapping,
+    ) -> Result<TaskImportResult> {
+        let preview = self.preview_with_mapping(file_path, mapping).await?;
+        let task_repo = TaskRepository::new(self.db.clone());
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+        let source_repo = TaskSourceRepository::new(self.db.clone());
+
+        let mut list_ids: HashMap<String, String> = mapping.task_lists.clone();
+        let mut row_task_ids: Vec<Option<String>> = vec![None; preview.rows.len()];
+        let mut result = TaskImportResult {
+            imported_task_ids: Vec::new(),
+            skipped_duplicates: 0,
+            errors: preview.errors,
+        };
+
+        for (index, row) in preview.rows.into_iter().enumerate() {
+            if !mapping.include_duplicates {
+                match &row.duplicate {
+                    Some(TaskImportDuplicate::Existing { task_id }) => {
+                        row_task_ids[index] = Some(task_id.clone());
+                        result.skipped_duplicates += 1;
+                        continue;
+                    }
+                    Some(TaskImportDuplicate::File { .. }) => {
+                        result.skipped_duplicates += 1;
+                        continue;
+                    }
+                    None => {}
+                }
+            }
+
+            let task_list_id = match &row.task_list {
+                Some(name) => match list_ids.get(name) {
+                    Some(id) => Some(id.clone()),
+                    None => {
+                        let list = task_list_repo
+                            .find_or_create_by_name(name)
+                            .await
+                            .with_context(|| format!("Failed to create task list {}", name))?;
+                        list_ids.insert(name.clone(), list.id.clone());
+                        Some(list.id)
+                    }
+                },
+                None => mapping.task_list_id.clone(),
+            };
+
+            let mut tags = row.tags;
+            tags.extend(mapping.tags.iter().cloned());
+            let request = CreateTaskRequest {
+                title: row.title,
+                description: row.description,
+                priority: row.priority.unwrap_or(1),
+                status: row.status,
+                order_num: None,
+                dependencies: None,
+                time_estimate: row.time_estimate,
+                due_date: row.due_date,
+                scheduled_date: None,
+                tags: (!tags.is_empty()).then_some(tags),
+                project_id: None,
+                parent_task_id: row.parent.and_then(|parent| row_task_ids[parent].clone()),
+                task_list_id,
+                periodic_template_id: None,
+                is_periodic_instance: None,
+                generation_date: None,
+                effort: None,
+                impact: None,
+            };
+
+            match task_repo.create_task(request).await {
+                Ok(task) => {
+                    let source = RecordTaskSourceRequest::file_line(
+                        &task.id,
+                        mapping.format.source_type(),
+                        file_path,
+                        row.line,
+                    );
+                    if let Err(e) = source_repo.record_source(source).await {
+                        result.errors.push(TaskImportError {
+                            line: row.line,
+                            message: e.to_string(),
+                        });
+                    }
+                    row_task_ids[index] = Some(task.id.clone());
+                    result.imported_task_ids.push(task.id);
+                }
+                Err(e) => result.errors.push(TaskImportError {
+                    line: row.line,
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Flag rows whose title already exists in their target list, or earlier in the file
+    async fn mark_duplicates(
+        &self,
+        rows: &mut [TaskImportRow],
+        mapping: &TaskImportMapping,
+    ) -> Result<()> {
+        let task_repo = TaskRepository::new(self.db.clone());
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+
+        let default_list_id = match &mapping.task_list_id {
+            Some(id) => id.clone(),
+            None => {
+                task_list_repo
+                    .get_default_task_list()
+                    .await
+                    .context("Failed to fetch default task list")?
+                    .id
+            }
+        };
+        let mut list_ids: HashMap<String, Option<String>> = HashMap::new();
+        for name in rows.iter().filter_map(|row| row.task_list.clone()) {
+            if list_ids.contains_key(&name) {
+                continue;
+            }
+            let id = match mapping.task_lists.get(&name) {
+                Some(id) => Some(id.clone()),
+                None => task_list_repo
+                    .find_by_name(&name)
+                    .await
+                    .context("Failed to fetch task lists")?
+                    .map(|list| list.id),
+            };
+            list_ids.insert(name, id);
+        }
+
+        let existing: HashMap<(String, String), String> = task_repo
+            .find_all(None, None)
+            .await
+            .context("Failed to fetch tasks")?
+            .into_iter()
+            .map(|task| {
+                let list_id = task.task_list_id.unwrap_or_default();
+                ((list_id, normalize_title(&task.title)), task.id)
+            })
+            .collect();
+
+        let mut seen: HashMap<(String, String), usize> = HashMap::new();
+        for row in rows.iter_mut() {
+            // Lists that don't exist yet are keyed by name so their rows can't match a task
+            let list_key = match &row.task_list {
+                Some(name) => match &list_ids[name] {
+                    Some(id) => id.clone(),
+                    None => format!("new:{}", name),
+                },
+                None => default_list_id.clone(),
+            };
+            let key = (list_key, normalize_title(&row.title));
+
+            row.duplicate = if let Some(task_id) = existing.get(&key) {
+                Some(TaskImportDuplicate::Existing {
+                    task_id: task_id.clone(),
+                })
+            } else {
+                seen.get(&key)
+                    .map(|&line| TaskImportDuplicate::File { line })
+            };
+            seen.entry(key).or_insert(row.line);
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn normalize_title(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn empty_row(line: usize, title: String) -> TaskImportRow {
+    TaskImportRow {
+        line,
+        title,
+        description: None,
+        priority: None,
+        status: None,
+        due_date: None,
+        time_estimate: None,
+        tags: Vec::new(),
+        task_list: None,
+        parent: None,
+        duplicate: None,
+        warnings: Vec::new(),
+    }
+}
+
+fn parse_csv_tasks(content: &str, columns: &HashMap<String, String>) -> Result<TaskImportPreview> {
+    let mut records = parse_csv(content).into_iter();
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("The CSV file is empty"))?;
+    let header: Vec<String> = header
+        .iter()
+        .map(|column| column.trim().to_string())
+        .collect();
+
+    let find_column = |name: &str| {
+        header
+            .iter()
+            .position(|column| column.eq_ignore_ascii_case(name))
+    };
+    let mut field_columns: HashMap<&str, usize> = HashMap::new();
+    for (field, aliases) in CSV_FIELDS {
+        let column = match columns.get(field) {
+            Some(column) => Some(find_column(column).ok_or_else(|| {
+                anyhow::anyhow!("Column '{}' mapped to {} is not in the file", column, field)
+            })?),
+            None => aliases.iter().find_map(|alias| find_column(alias)),
+        };
+        if let Some(column) = column {
+            field_columns.insert(field, column);
+        }
+    }
+    if !field_columns.contains_key("title") {
+        anyhow::bail!("No title column found; map one of: {}", header.join(", "));
+    }
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (line, record) in records {
+        let value = |field: &str| {
+            field_columns
+                .get(field)
+                .and_then(|&column| record.get(column))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+
+        let Some(title) = value("title") else {
+            errors.push(TaskImportError {
+                line,
+                message: "Missing title".to_string(),
+            });
+            continue;
+        };
+        let mut row = empty_row(line, title.to_string());
+        row.description = value("description").map(str::to_string);
+        row.status = value("status").map(parse_status);
+        row.task_list = value("task_list").map(str::to_string);
+        row.tags = value("tags")
+            .map(|tags| {
+                tags.split([',', ';'])
+                    .map(|tag| tag.trim().trim_start_matches('#').to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if let Some(priority) = value("priority") {
+            row.priority = parse_priority(priority);
+            if row.priority.is_none() {
+                row.warnings
+                    .push(format!("Unknown priority '{}'", priority));
+            }
+        }
+        if let Some(due_date) = value("due_date") {
+            row.due_date = parse_date(due_date);
+            if row.due_date.is_none() {
+                row.warnings
+                    .push(format!("Unrecognized due date '{}'", due_date));
+            }
+        }
+        if let Some(estimate) = value("time_estimate") {
+            row.time_estimate = parse_minutes(estimate);
+            if row.time_estimate.is_none() {
+                row.warnings
+                    .push(format!("Unrecognized time estimate '{}'", estimate));
+            }
+        }
+        rows.push(row);
+    }
+
+    let column_mapping = field_columns
+        .into_iter()
+        .map(|(field, column)| (field.to_string(), header[column].clone()))
+        .collect();
+
+    Ok(TaskImportPreview {
+        format: TaskImportFormat::Csv,
+        columns: header,
+        column_mapping,
+        rows,
+        duplicate_count: 0,
+        errors,
+    })
+}
+
+fn parse_markdown_tasks(content: &str) -> TaskImportPreview {
+    let mut rows: Vec<TaskImportRow> = Vec::new();
+    let mut heading: Option<String> = None;
+    // Indentation and row index of the items that can still receive subtasks
+    let mut open_items: Vec<(usize, usize)> = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if let Some(title) = trimmed
+            .strip_prefix('#')
+            .map(|rest| rest.trim_start_matches('#'))
+            .filter(|rest| rest.starts_with(' '))
+        {
+            heading = Some(title.trim().to_string()).filter(|title| !title.is_empty());
+            open_items.clear();
+            continue;
+        }
+
+        let Some((checked, text)) = parse_checkbox(trimmed) else {
+            continue;
+        };
+        let indent: usize = line[..line.len() - trimmed.len()]
+            .chars()
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+
+        let (tags, words): (Vec<&str>, Vec<&str>) = text
+            .split_whitespace()
+            .partition(|word| word.len() > 1 && word.starts_with('#'));
+        let title = words.join(" ");
+        if title.is_empty() {
+            continue;
+        }
+
+        while open_items
+            .last()
+            .is_some_and(|&(item_indent, _)| item_indent >= indent)
+        {
+            open_items.pop();
+        }
+
+        let mut row = empty_row(index + 1, title);
+        row.tags = tags.iter().map(|tag| tag[1..].to_string()).collect();
+        row.status = checked.then(|| "completed".to_string());
+        row.task_list = heading.clone();
+        row.parent = open_items.last().map(|&(_, parent)| parent);
+
+        open_items.push((indent, rows.len()));
+        rows.push(row);
+    }
+
+    TaskImportPreview {
+        format: TaskImportFormat::Markdown,
+        columns: Vec::new(),
+        column_mapping: HashMap::new(),
+        rows,
+        duplicate_count: 0,
+        errors: Vec::new(),
+    }
+}
+
+/// Split a `- [ ] text` list item into its checked state and text
+fn parse_checkbox(line: &str) -> Option<(bool, &str)> {
+    let rest = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))?;
+    let checked = match rest.get(..3)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+    Some((checked, rest[3..].trim()))
+}
+
+/// Records of a CSV document with the line each one starts on
+///
+/// Follows RFC 4180: fields may be quoted, and quoted fields may contain commas, doubled
+/// quotes and line breaks. Blank lines are skipped.
+pub(crate) fn parse_csv(content: &str) -> Vec<(usize, Vec<String>)> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            '\n' if in_quotes => {
+                field.push('\n');
+                line += 1;
+            }
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|value| !value.trim().is_empty()) {
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    record.push(field);
+    if record.iter().any(|value| !value.trim().is_empty()) {
+        records.push((record_line, record));
+    }
+
+    records
+}
+
+/// Map common status spellings to task statuses; other values are kept for custom statuses
+fn parse_status(value: &str) -> String {
+    let status = value.trim().to_lowercase().replace([' ', '-'], "_");
+    match status.as_str() {
+        "done" | "complete" | "completed" | "closed" | "x" | "true" | "yes" => "completed",
+        "todo" | "to_do" | "open" | "new" | "pending" | "false" | "no" => "pending",
+        "doing" | "started" | "in_progress" | "active" => "in_progress",
+        "cancelled" | "canceled" => "cancelled",
+        _ => return status,
+    }
+    .to_string()
+}
+
+/// Priority from 0-3 or a name (low, medium, high, urgent)
+fn parse_priority(value: &str) -> Option<i32> {
+    match value.to_lowercase().as_str() {
+        "0" | "low" => Some(0),
+        "1" | "medium" | "normal" => Some(1),
+        "2" | "high" => Some(2),
+        "3" | "urgent" | "critical" => Some(3),
+        _ => None,
+    }
+}
+
+/// RFC 3339 timestamps, ISO dates or phrases such as "next friday"
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+    parse_natural_date(value, &Local::now()).map(|parsed| parsed.date)
+}
+
+/// Minutes from "90", "90m", "1.5h" or "1h 30m"
+fn parse_minutes(value: &str) -> Option<i32> {
+    let value = value.to_lowercase().replace(' ', "");
+    if let Ok(minutes) = value.parse::<i32>() {
+        return (minutes >= 0).then_some(minutes);
+    }
+
+    let mut total = 0.0;
+    let mut number = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'h' | 'm' => {
+                let amount: f64 = std::mem::take(&mut number).parse().ok()?;
+                total += if c == 'h' { amount * 60.0 } else { amount };
+            }
+            _ => return None,
+        }
+    }
+    (number.is_empty() && total > 0.0).then(|| total.round() as i32)
+}
@@ -0,0 +1,451 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+
+use crate::database::repositories::{
+    periodic_task_repository::CreatePeriodicTaskTemplateRequest,
+    saved_filter_repository::CreateSavedFilterRequest,
+    task_repository::TaskFilter,
+    task_template_repository::{CreateTaskTemplateRequest, TaskTemplateItem},
+    PeriodicTaskRepository, SavedFilterRepository, TaskListRepository, TaskTemplateRepository,
+};
+
+const PACK_FORMAT: &str = "kirapilot-template-pack";
+const PACK_VERSION: &str = "1.0.0";
+
+/// Name of packs written by the periodic template export
+const PERIODIC_EXPORT_NAME: &str = "Periodic templates";
+
+/// A task template as stored in a pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackTaskTemplate {
+    pub name: String,
+    pub description: Option<String>,
+    pub content: TaskTemplateItem,
+}
+
+/// A periodic task template as stored in a pack
+///
+/// Task lists are referenced by name so packs stay portable between installations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackPeriodicTemplate {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: i32,
+    pub time_estimate: i32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub task_list: Option<String>,
+    pub recurrence_type: String,
+    pub recurrence_interval: i32,
+    pub recurrence_unit: Option<String>,
+    #[serde(default)]
+    pub recurrence_rule: Option<String>,
+    #[serde(default)]
+    pub max_occurrences: Option<i32>,
+    #[serde(default)]
+    pub missed_occurrence_policy: Option<String>,
+}
+
+/// A saved filter as stored in a pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackSavedFilter {
+    pub name: String,
+    pub description: Option<String>,
+    /// Criteria without installation-specific IDs; the list is referenced by `task_list` instead
+    pub criteria: TaskFilter,
+    pub task_list: Option<String>,
+}
+
+/// A shareable set of task templates, periodic templates and saved filters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplatePack {
+    pub format: String,
+    pub version: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub task_templates: Vec<PackTaskTemplate>,
+    #[serde(default)]
+    pub periodic_templates: Vec<PackPeriodicTemplate>,
+    #[serde(default)]
+    pub saved_filters: Vec<PackSavedFilter>,
+}
+
+/// Selection of items to include when exporting a pack (`None` exports everything)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplatePackExportRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub task_template_ids: Option<Vec<String>>,
+    pub periodic_template_ids: Option<Vec<String>>,
+    pub saved_filter_ids: Option<Vec<String>>,
+}
+
+/// Overview of a pack's contents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplatePackSummary {
+    pub name: String,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub version: String,
+    pub created_at: DateTime<Utc>,
+    pub task_template_count: usize,
+    pub periodic_template_count: usize,
+    pub saved_filter_count: usize,
+}
+
+/// Result of importing a pack
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemplatePackImportResult {
+    pub summary: TemplatePackSummary,
+    pub imported_task_templates: usize,
+    pub imported_periodic_templates: usize,
+    pub imported_saved_filters: usize,
+    /// Items left untouched because an equivalent one already exists
+    pub skipped: Vec<String>,
+}
+
+/// Exports and imports template packs as plain JSON files
+pub struct TemplatePackService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TemplatePackService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Export the selected templates and filters to a pack file
+    pub async fn export_pack(
+        &self,
+        request: TemplatePackExportRequest,
+        file_path: &str,
+    ) -> Result<TemplatePackSummary> {
+        let task_template_repo = TaskTemplateRepository::new(self.db.clone());
+        let periodic_repo = PeriodicTaskRepository::new(self.db.clone());
+        let saved_filter_repo = SavedFilterRepository::new(self.db.clone());
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+
+        let list_names: HashMap<String, String> = task_list_repo
+            .find_all_task_lists()
+            .await
+            .context("Failed to fetch task lists")?
+            .into_iter()
+            .map(|list| (list.id, list.name))
+            .collect();
+
+        let mut task_templates = Vec::new();
+        for template in task_template_repo
+            .find_all()
+            .await
+            .context("Failed to fetch task templates")?
+        {
+            if !is_selected(&request.task_template_ids, &template.id) {
+                continue;
+            }
+            task_templates.push(PackTaskTemplate {
+                content: TaskTemplateRepository::parse_content(&template)?,
+                name: template.name,
+                description: template.description,
+            });
+        }
+
+        let periodic_templates = periodic_repo
+            .find_all()
+            .await
+            .context("Failed to fetch periodic task templates")?
+            .into_iter()
+            .filter(|template| is_selected(&request.periodic_template_ids, &template.id))
+            .map(|template| PackPeriodicTemplate {
+                title: template.title,
+                description: template.description,
+                priority: template.priority,
+                time_estimate: template.time_estimate,
+                tags: template
+                    .tags
+                    .and_then(|tags| serde_json::from_str(&tags).ok())
+                    .unwrap_or_default(),
+                task_list: template
+                    .task_list_id
+                    .and_then(|id| list_names.get(&id).cloned()),
+                recurrence_type: template.recurrence_type,
+                recurrence_interval: template.recurrence_interval,
+                recurrence_unit: template.recurrence_unit,
+                recurrence_rule: template.recurrence_rule,
+                max_occurrences: template.max_occurrences,
+                missed_occurrence_policy: template.missed_occurrence_policy,
+            })
+            .collect();
+
+        let mut saved_filters = Vec::new();
+        for filter in saved_filter_repo
+            .find_all()
+            .await
+            .context("Failed to fetch saved filters")?
+        {
+            if !is_selected(&request.saved_filter_ids, &filter.id) {
+                continue;
+            }
+            let mut criteria = SavedFilterRepository::parse_criteria(&filter)?;
+            // Custom field IDs are local to this installation
+            criteria.custom_fields = None;
+            let task_list = criteria
+                .task_list_id
+                .take()
+                .and_then(|id| list_names.get(&id).cloned());
+            saved_filters.push(PackSavedFilter {
+                name: filter.name,
+                description: filter.description,
+                criteria,
+                task_list,
+            });
+        }
+
+        let pack = TemplatePack {
+            format: PACK_FORMAT.to_string(),
+            version: PACK_VERSION.to_string(),
+            name: request.name,
+            description: request.description,
+            author: request.author,
+            created_at: Utc::now(),
+            task_templates,
+            periodic_templates,
+            saved_filters,
+        };
+
+        let json = serde_json::to_string_pretty(&pack)?;
+        fs::write(file_path, json).context("Failed to write template pack")?;
+
+        Ok(summarize(&pack))
+    }
+
+    /// Read and validate a pack without importing it
+    pub fn inspect_pack(&self, file_path: &str) -> Result<TemplatePackSummary> {
+        let pack = self.read_pack(file_path)?;
+        Ok(summarize(&pack))
+    }
+
+    /// Import every item of a pack, skipping ones that already exist
+    ///
+    /// Task templates and saved filters are matched by name, periodic templates by title and
+    /// recurrence. Referenced task lists are created when missing.
+    pub async fn import_pack(&self, file_path: &str) -> Result<TemplatePackImportResult> {
+        let pack = self.read_pack(file_path)?;
+        let summary = summarize(&pack);
+
+        let task_template_repo = TaskTemplateRepository::new(self.db.clone());
+        let saved_filter_repo = SavedFilterRepository::new(self.db.clone());
+
+        let mut skipped = Vec::new();
+        let mut imported_task_templates = 0;
+        let mut imported_saved_filters = 0;
+
+        for template in pack.task_templates {
+            if task_template_repo.find_by_name(&template.name).await?.is_some() {
+                skipped.push(format!("Task template '{}'", template.name));
+                continue;
+            }
+            task_template_repo
+                .create_template(CreateTaskTemplateRequest {
+                    name: template.name,
+                    description: template.description,
+                    content: template.content,
+                })
+                .await
+                .context("Failed to import task template")?;
+            imported_task_templates += 1;
+        }
+
+        let imported_periodic_templates = self
+            .import_periodic(pack.periodic_templates, &mut skipped)
+            .await?;
+
+        for filter in pack.saved_filters {
+            if saved_filter_repo.find_by_name(&filter.name).await?.is_some() {
+                skipped.push(format!("Saved filter '{}'", filter.name));
+                continue;
+            }
+
+            let mut criteria = filter.criteria;
+            criteria.task_list_id = self.resolve_task_list(filter.task_list.as_deref()).await?;
+            saved_filter_repo
+                .create_filter(CreateSavedFilterRequest {
+                    name: filter.name,
+                    description: filter.description,
+                    criteria,
+                })
+                .await
+                .context("Failed to import saved filter")?;
+            imported_saved_filters += 1;
+        }
+
+        Ok(TemplatePackImportResult {
+            summary,
+            imported_task_templates,
+            imported_periodic_templates,
+            imported_saved_filters,
+            skipped,
+        })
+    }
+
+    /// Export every periodic template, with its recurrence, to a pack holding nothing else
+    pub async fn export_periodic_templates(&self, file_path: &str) -> Result<TemplatePackSummary> {
+        self.export_pack(
+            TemplatePackExportRequest {
+                name: PERIODIC_EXPORT_NAME.to_string(),
+                description: None,
+                author: None,
+                task_template_ids: Some(Vec::new()),
+                periodic_template_ids: None,
+                saved_filter_ids: Some(Vec::new()),
+            },
+            file_path,
+        )
+        .await
+    }
+
+    /// Import only the periodic templates of a pack, skipping ones that already exist
+    pub async fn import_periodic_templates(
+        &self,
+        file_path: &str,
+    ) -> Result<TemplatePackImportResult> {
+        let pack = self.read_pack(file_path)?;
+        let summary = summarize(&pack);
+
+        let mut skipped = Vec::new();
+        let imported_periodic_templates = self
+            .import_periodic(pack.periodic_templates, &mut skipped)
+            .await?;
+
+        Ok(TemplatePackImportResult {
+            summary,
+            imported_task_templates: 0,
+            imported_periodic_templates,
+            imported_saved_filters: 0,
+            skipped,
+        })
+    }
+
+    /// Create the periodic templates that don't exist yet, starting their series now
+    async fn import_periodic(
+        &self,
+        templates: Vec<PackPeriodicTemplate>,
+        skipped: &mut Vec<String>,
+    ) -> Result<usize> {
+        let periodic_repo = PeriodicTaskRepository::new(self.db.clone());
+        let mut imported = 0;
+
+        let existing_periodic = periodic_repo
+            .find_all()
+            .await
+            .context("Failed to fetch periodic task templates")?;
+        let now = Utc::now();
+
+        for template in templates {
+            let duplicate = existing_periodic.iter().any(|existing| {
+                existing.title == template.title
+                    && existing.recurrence_type == template.recurrence_type
+                    && existing.recurrence_interval == template.recurrence_interval
+                    && existing.recurrence_unit == template.recurrence_unit
+                    && existing.recurrence_rule == template.recurrence_rule
+            });
+            if duplicate {
+                skipped.push(format!("Periodic template '{}'", template.title));
+                continue;
+            }
+
+            let task_list_id = self
+                .resolve_task_list(template.task_list.as_deref())
+                .await?;
+            periodic_repo
+                .create_template(CreatePeriodicTaskTemplateRequest {
+                    title: template.title,
+                    description: template.description,
+                    priority: template.priority,
+                    time_estimate: template.time_estimate,
+                    tags: Some(template.tags),
+                    task_list_id,
+                    recurrence_type: template.recurrence_type,
+                    recurrence_interval: template.recurrence_interval,
+                    recurrence_unit: template.recurrence_unit,
+                    recurrence_rule: template.recurrence_rule,
+                    start_date: now,
+                    // An absolute end date doesn't carry over to a series starting now
+                    ends_at: None,
+                    max_occurrences: template.max_occurrences,
+                    missed_occurrence_policy: template.missed_occurrence_policy,
+                })
+                .await
+                .context("Failed to import periodic task template")?;
+            imported += 1;
+        }
+        Ok(imported)
+    }
+
+    /// Look up a task list by name, creating it when missing
+    async fn resolve_task_list(&self, name: Option<&str>) -> Result<Option<String>> {
+        let Some(name) = name.filter(|n| !n.trim().is_empty()) else {
+            return Ok(None);
+        };
+
+        let task_list = TaskListRepository::new(self.db.clone())
+            .find_or_create_by_name(name)
+            .await
+            .context("Failed to create task list for template pack")?;
+        Ok(Some(task_list.id))
+    }
+
+    fn read_pack(&self, file_path: &str) -> Result<TemplatePack> {
+        let json = fs::read_to_string(file_path).context("Failed to read template pack")?;
+        let pack: TemplatePack =
+            serde_json::from_str(&json).context("Template pack is not valid JSON")?;
+
+        if pack.format != PACK_FORMAT {
+            return Err(anyhow::anyhow!(
+                "Unsupported template pack format: {}",
+                pack.format
+            ));
+        }
+
+        let major = |version: &str| version.split('.').next().map(str::to_string);
+        if major(&pack.version) != major(PACK_VERSION) {
+            return Err(anyhow::anyhow!(
+                "Unsupported template pack version: {}",
+                pack.version
+            ));
+        }
+
+        if pack.name.trim().is_empty() {
+            return Err(anyhow::anyhow!("Template pack has no name"));
+        }
+
+        Ok(pack)
+    }
+}
+
+fn is_selected(selection: &Option<Vec<String>>, id: &str) -> bool {
+    selection
+        .as_ref()
+        .is_none_or(|ids| ids.iter().any(|selected| selected == id))
+}
+
+fn summarize(pack: &TemplatePack) -> TemplatePackSummary {
+    TemplatePackSummary {
+        name: pack.name.clone(),
+        description: pack.description.clone(),
+        author: pack.author.clone(),
+        version: pack.version.clone(),
+        created_at: pack.created_at,
+        task_template_count: pack.task_templates.len(),
+        periodic_template_count: pack.periodic_templates.len(),
+        saved_filter_count: pack.saved_filters.len(),
+    }
+}
@@ -0,0 +1,436 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::database::repositories::task_repository::CreateTaskRequest;
+use crate::database::repositories::task_source_repository::RecordTaskSourceRequest;
+use crate::database::repositories::{
+    TaskListRepository, TaskRepository, TaskSourceRepository, TimeTrackingRepository,
+};
+use crate::task_import::{normalize_title, parse_csv, TaskImportError};
+
+const TIME_FORMATS: [&str; 4] = ["%H:%M:%S", "%H:%M", "%I:%M:%S %p", "%I:%M %p"];
+
+/// Time trackers whose CSV exports can be imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeImportSource {
+    /// Toggl Track detailed report
+    Toggl,
+    /// Clockify detailed report
+    Clockify,
+}
+
+impl TimeImportSource {
+    /// Source recorded on tasks created for entries of this tracker
+    fn source_type(self) -> &'static str {
+        match self {
+            Self::Toggl => "toggl",
+            Self::Clockify => "clockify",
+        }
+    }
+
+    /// Date formats of the export, the tracker's default first
+    fn date_formats(self) -> [&'static str; 3] {
+        match self {
+            Self::Toggl => ["%Y-%m-%d", "%m/%d/%Y", "%d.%m.%Y"],
+            Self::Clockify => ["%m/%d/%Y", "%Y-%m-%d", "%d.%m.%Y"],
+        }
+    }
+}
+
+/// A time entry read from an export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeImportEntry {
+    pub line: usize,
+    /// The entry's task, or its description when it has none
+    pub task_title: String,
+    /// Project of the entry; new tasks go to the task list of the same name
+    pub project: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub duration_minutes: i64,
+    /// Existing task the session will be logged against; a task is created when unset
+    pub task_id: Option<String>,
+    /// The task already has a session starting at the same time
+    pub already_imported: bool,
+}
+
+/// What a time import would do
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeImportPreview {
+    pub source: TimeImportSource,
+    pub entries: Vec<TimeImportEntry>,
+    /// Entries logged against existing tasks
+    pub matched_count: usize,
+    /// Titles of the tasks that would be created
+    pub new_task_titles: Vec<String>,
+    pub already_imported_count: usize,
+    pub errors: Vec<TaskImportError>,
+}
+
+/// Result of importing a time tracker export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeImportResult {
+    pub created_task_ids: Vec<String>,
+    pub session_ids: Vec<String>,
+    /// Entries that were imported before
+    pub skipped_entries: usize,
+    pub errors: Vec<TaskImportError>,
+}
+
+/// Imports time entries from Toggl and Clockify CSV exports as time sessions
+///
+/// Entries are matched to existing tasks by title, preferring a task in the list named
+/// after the entry's project. Export times are read in the local timezone.
+pub struct TimeImportService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TimeImportService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Read an export and match its entries to tasks without changing anything
+    pub async fn preview(
+        &self,
+        file_path: &str,
+        source: TimeImportSource,
+    ) -> Result<TimeImportPreview> {
+        let content = tokio::fs::read_to_string(file_path)
+            .await
+            .with_context(|| format!("Failed to read {}", file_path))?;
+        let (mut entries, errors) = parse_time_entries(&content, source)?;
+        self.match_tasks(&mut entries).await?;
+
+        let mut new_task_titles: Vec<String> = Vec::new();
+        let mut new_tasks = HashSet::new();
+        for entry in entries.iter().filter(|entry| entry.task_id.is_none()) {
+            if new_tasks.insert(new_task_key(entry)) {
+                new_task_titles.push(entry.task_title.clone());
+            }
+        }
+
+        Ok(TimeImportPreview {
+            source,
+            matched_count: entries.iter().filter(|e| e.task_id.is_some()).count(),
+            already_imported_count: entries.iter().filter(|e| e.already_imported).count(),
+            new_task_titles,
+            entries,
+            errors,
+        })
+    }
+
+    /// Log the entries of an export as time sessions, creating tasks that don't exist
+    ///
+    /// New tasks go to the list named after the entry's project (created if needed), or to
+    /// `task_list_id` / the default list for entries without a project. Entries imported
+    /// before are skipped.
+    pub async fn import(
+        &self,
+        file_path: &str,
+        source: TimeImportSource,
+        task_list_id: Option<String>,
+    ) -> Result<TimeImportResult> {
+        let preview = self.preview(file_path, source).await?;
+        let task_repo = TaskRepository::new(self.db.clone());
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+        let time_repo = TimeTrackingRepository::new(self.db.clone());
+        let source_repo = TaskSourceRepository::new(self.db.clone());
+
+        let mut new_task_ids: HashMap<(String, String), String> = HashMap::new();
+        let mut result = TimeImportResult {
+            created_task_ids: Vec::new(),
+            session_ids: Vec::new(),
+            skipped_entries: 0,
+            errors: preview.errors,
+        };
+
+        for entry in preview.entries {
+            if entry.already_imported {
+                result.skipped_entries += 1;
+                continue;
+            }
+
+            let task_id = match &entry.task_id {
+                Some(task_id) => task_id.clone(),
+                None => match new_task_ids.get(&new_task_key(&entry)) {
+                    Some(task_id) => task_id.clone(),
+                    None => {
+                        let list_id = match &entry.project {
+                            Some(project) => Some(
+                                task_list_repo
+                                    .find_or_create_by_name(project)
+                                    .await
+                                    .with_context(|| {
+                                        format!("Failed to create task list {}", project)
+                                    })?
+                                    .id,
+                            ),
+                            None => task_list_id.clone(),
+                        };
+                        match task_repo
+                            .create_task(new_task_request(&entry, list_id))
+                            .await
+                        {
+                            Ok(task) => {
+                                let task_source = RecordTaskSourceRequest::file_line(
+                                    &task.id,
+                                    source.source_type(),
+                                    file_path,
+                                    entry.line,
+                                );
+                                if let Err(e) = source_repo.record_source(task_source).await {
+                                    result.errors.push(TaskImportError {
+                                        line: entry.line,
+                                        message: e.to_string(),
+                                    });
+                                }
+                                new_task_ids.insert(new_task_key(&entry), task.id.clone());
+                                result.created_task_ids.push(task.id.clone());
+                                task.id
+                            }
+                            Err(e) => {
+                                result.errors.push(TaskImportError {
+                                    line: entry.line,
+                                    message: e.to_string(),
+                                });
+                                continue;
+                            }
+                        }
+                    }
+                },
+            };
+
+            // The description is kept on the session when the entry named a separate task
+            let notes = entry
+                .description
+                .filter(|description| *description != entry.task_title);
+            match time_repo
+                .log_session(&task_id, entry.start_time, entry.end_time, notes)
+                .await
+            {
+                Ok(session) => result.session_ids.push(session.id),
+                Err(e) => result.errors.push(TaskImportError {
+                    line: entry.line,
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn match_tasks(&self, entries: &mut [TimeImportEntry]) -> Result<()> {
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+        let tasks = TaskRepository::new(self.db.clone())
+            .find_all(None, None)
+            .await
+            .context("Failed to fetch tasks")?;
+        let sessions = TimeTrackingRepository::new(self.db.clone())
+            .get_all_sessions()
+            .await
+            .context("Failed to fetch time sessions")?;
+
+        // Tasks come newest first, so the most recent task wins a title clash
+        let mut by_title: HashMap<String, &str> = HashMap::new();
+        let mut by_list_and_title: HashMap<(&str, String), &str> = HashMap::new();
+        for task in tasks.iter().rev() {
+            let title = normalize_title(&task.title);
+            if let Some(list_id) = &task.task_list_id {
+                by_list_and_title.insert((list_id.as_str(), title.clone()), &task.id);
+            }
+            by_title.insert(title, &task.id);
+        }
+        let logged: HashSet<(&str, i64)> = sessions
+            .iter()
+            .map(|session| (session.task_id.as_str(), session.start_time.timestamp()))
+            .collect();
+
+        let mut list_ids: HashMap<String, Option<String>> = HashMap::new();
+        for entry in entries.iter_mut() {
+            let list_id = match &entry.project {
+                Some(project) => match list_ids.get(project) {
+                    Some(list_id) => list_id.clone(),
+                    None => {
+                        let list_id = task_list_repo
+                            .find_by_name(project)
+                            .await
+                            .context("Failed to fetch task lists")?
+                            .map(|list| list.id);
+                        list_ids.insert(project.clone(), list_id.clone());
+                        list_id
+                    }
+                },
+                None => None,
+            };
+
+            let title = normalize_title(&entry.task_title);
+            let task_id = list_id
+                .and_then(|list_id| by_list_and_title.get(&(list_id.as_str(), title.clone())))
+                .or_else(|| by_title.get(&title))
+                .map(|task_id| task_id.to_string());
+
+            entry.already_imported = task_id
+                .as_deref()
+                .is_some_and(|task_id| logged.contains(&(task_id, entry.start_time.timestamp())));
+            entry.task_id = task_id;
+        }
+
+        Ok(())
+    }
+}
+
+/// Entries creating the same task: same title in the same project
+fn new_task_key(entry: &TimeImportEntry) -> (String, String) {
+    (
+        entry.project.clone().unwrap_or_default(),
+        normalize_title(&entry.task_title),
+    )
+}
+
+fn new_task_request(entry: &TimeImportEntry, task_list_id: Option<String>) -> CreateTaskRequest {
+    CreateTaskRequest {
+        title: entry.task_title.clone(),
+        description: None,
+        priority: 1,
+        status: None,
+        order_num: None,
+        dependencies: None,
+        time_estimate: None,
+        due_date: None,
+        scheduled_date: None,
+        tags: (!entry.tags.is_empty()).then(|| entry.tags.clone()),
+        project_id: None,
+        parent_task_id: None,
+        task_list_id,
+        periodic_template_id: None,
+        is_periodic_instance: None,
+        generation_date: None,
+        effort: None,
+        impact: None,
+    }
+}
+
+fn parse_time_entries(
+    content: &str,
+    source: TimeImportSource,
+) -> Result<(Vec<TimeImportEntry>, Vec<TaskImportError>)> {
+    let mut records = parse_csv(content).into_iter();
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("The CSV file is empty"))?;
+    let column = |names: &[&str]| {
+        header.iter().position(|column| {
+            names
+                .iter()
+                .any(|name| column.trim().eq_ignore_ascii_case(name))
+        })
+    };
+
+    let start_date = column(&["start date"]);
+    let start_time = column(&["start time"]);
+    let (Some(start_date), Some(start_time)) = (start_date, start_time) else {
+        anyhow::bail!("Not a {:?} export: no start date and time columns", source);
+    };
+    let end_date = column(&["end date"]);
+    let end_time = column(&["end time"]);
+    let duration = column(&["duration", "duration (h)"]);
+    let project = column(&["project"]);
+    let task = column(&["task"]);
+    let description = column(&["description"]);
+    let tags = column(&["tags"]);
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for (line, record) in records {
+        let value = |column: Option<usize>| {
+            column
+                .and_then(|column| record.get(column))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let error = |message: String| TaskImportError { line, message };
+
+        let Some(task_title) = value(task).or(value(description)) else {
+            errors.push(error("Missing task and description".to_string()));
+            continue;
+        };
+        let Some(start) =
+            parse_local_time(value(Some(start_date)), value(Some(start_time)), source)
+        else {
+            errors.push(error("Unrecognized start date or time".to_string()));
+            continue;
+        };
+        let end = parse_local_time(value(end_date), value(end_time), source).or_else(|| {
+            value(duration)
+                .and_then(parse_duration)
+                .map(|duration| start + duration)
+        });
+        let Some(end) = end.filter(|end| *end > start) else {
+            errors.push(error("Missing or invalid end time".to_string()));
+            continue;
+        };
+
+        entries.push(TimeImportEntry {
+            line,
+            task_title: task_title.to_string(),
+            project: value(project).map(str::to_string),
+            description: value(description).map(str::to_string),
+            tags: value(tags)
+                .map(|tags| {
+                    tags.split(',')
+                        .map(|tag| tag.trim().to_string())
+                        .filter(|tag| !tag.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            start_time: start,
+            end_time: end,
+            duration_minutes: (end - start).num_minutes(),
+            task_id: None,
+            already_imported: false,
+        });
+    }
+
+    Ok((entries, errors))
+}
+
+fn parse_local_time(
+    date: Option<&str>,
+    time: Option<&str>,
+    source: TimeImportSource,
+) -> Option<DateTime<Utc>> {
+    let date = source
+        .date_formats()
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date?, format).ok())?;
+    let time = TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(time?, format).ok())?;
+
+    Local
+        .from_local_datetime(&NaiveDateTime::new(date, time))
+        .earliest()
+        .map(|moment| moment.with_timezone(&Utc))
+}
+
+/// Duration written as `H:MM:SS` or `H:MM`
+fn parse_duration(value: &str) -> Option<chrono::Duration> {
+    let parts: Vec<i64> = value
+        .split(':')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    let seconds = match parts[..] {
+        [hours, minutes, seconds] => hours * 3600 + minutes * 60 + seconds,
+        [hours, minutes] => hours * 3600 + minutes * 60,
+        _ => return None,
+    };
+    Some(chrono::Duration::seconds(seconds))
+}
@@ -0,0 +1,641 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+
+use zip::ZipArchive;
+
+use crate::database::repositories::periodic_task_repository::CreatePeriodicTaskTemplateRequest;
+use crate::database::repositories::task_note_repository::CreateTaskNoteRequest;
+use crate::database::repositories::task_repository::CreateTaskRequest;
+use crate::database::repositories::task_source_repository::RecordTaskSourceRequest;
+use crate::database::repositories::{
+    PeriodicTaskRepository, TaskListRepository, TaskNoteRepository, TaskRepository,
+};
+use crate::database::services::date_parser::parse_natural_date;
+use crate::task_import::{normalize_title, parse_csv, TaskImportError};
+
+/// Todoist's project for tasks without one; imported into the default task list
+const INBOX_PROJECT: &str = "Inbox";
+
+const WEEKDAYS: [(&str, Weekday); 7] = [
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+];
+
+/// Words that end the repeat part of a due string, as in "every monday at 9am"
+const RECURRENCE_STOP_WORDS: [&str; 6] = ["at", "starting", "from", "until", "ending", "for"];
+
+/// How a recurring Todoist task repeats, in periodic template terms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoistRecurrence {
+    /// The due string as written in Todoist, such as "every 2 weeks"
+    pub due_string: String,
+    pub recurrence_type: String,
+    pub recurrence_interval: i32,
+    /// Cron expression for "cron" recurrences
+    pub recurrence_rule: Option<String>,
+    pub start_date: DateTime<Utc>,
+}
+
+/// A task read from a Todoist project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoistTask {
+    pub line: usize,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: i32,
+    /// Section the task was under; imported as a tag
+    pub section: Option<String>,
+    /// The section followed by the task's `@labels`
+    pub tags: Vec<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    /// Set for recurring tasks, which are imported as periodic templates
+    pub recurrence: Option<TodoistRecurrence>,
+    pub time_estimate: Option<i32>,
+    /// Comments on the task; imported as task notes
+    pub notes: Vec<String>,
+    /// Index of the parent task within the project
+    pub parent: Option<usize>,
+    /// A task or periodic template with the same title is already in the project's list
+    pub duplicate: bool,
+    /// Values that could not be mapped; the task is imported without them
+    pub warnings: Vec<String>,
+}
+
+/// A Todoist project and the task list it maps to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoistProject {
+    pub name: String,
+    /// Existing task list of the same name; a list is created when unset
+    pub task_list_id: Option<String>,
+    pub tasks: Vec<TodoistTask>,
+}
+
+/// What a Todoist import would create
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoistImportPreview {
+    pub projects: Vec<TodoistProject>,
+    pub task_count: usize,
+    /// Recurring tasks, imported as periodic templates
+    pub periodic_count: usize,
+    pub duplicate_count: usize,
+    /// Task lists that would be created
+    pub new_task_lists: Vec<String>,
+    pub errors: Vec<TaskImportError>,
+}
+
+/// Result of importing a Todoist export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoistImportResult {
+    pub created_task_list_ids: Vec<String>,
+    pub imported_task_ids: Vec<String>,
+    pub periodic_template_ids: Vec<String>,
+    pub skipped_duplicates: usize,
+    pub errors: Vec<TaskImportError>,
+}
+
+/// Imports Todoist exports: a project CSV, or a backup zip holding one CSV per project
+///
+/// Projects become task lists (Inbox goes to the default list), sections become tags and
+/// recurring tasks become periodic templates. Priorities p1 and p2 map to urgent and high;
+/// p3 and Todoist's default p4 map to medium.
+pub struct TodoistImportService {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TodoistImportService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Read an export and report what importing it would do, without changing anything
+    pub async fn preview(&self, file_path: &str) -> Result<TodoistImportPreview> {
+        let files = read_export(file_path)?;
+
+        let now = Local::now();
+        let mut projects = Vec::new();
+        let mut errors = Vec::new();
+        for (name, content) in files {
+            match parse_project(&content, &now) {
+                Ok((tasks, project_errors)) => {
+                    errors.extend(project_errors.into_iter().map(|error| TaskImportError {
+                        line: error.line,
+                        message: format!("{}: {}", name, error.message),
+                    }));
+                    projects.push(TodoistProject {
+                        name,
+                        task_list_id: None,
+                        tasks,
+                    });
+                }
+                Err(message) => errors.push(TaskImportError {
+                    line: 1,
+                    message: format!("{}: {}", name, message),
+                }),
+            }
+        }
+        self.match_projects(&mut projects).await?;
+
+        let tasks = projects.iter().flat_map(|project| &project.tasks);
+        Ok(TodoistImportPreview {
+            task_count: tasks.clone().count(),
+            periodic_count: tasks.clone().filter(|t| t.recurrence.is_some()).count(),
+            duplicate_count: tasks.filter(|t| t.duplicate).count(),
+            new_task_lists: projects
+                .iter()
+                .filter(|project| project.task_list_id.is_none())
+                .map(|project| project.name.clone())
+                .collect(),
+            projects,
+            errors,
+        })
+    }
+
+    /// Import an export, skipping tasks already in their project's list
+    ///
+    /// Subtasks of a skipped or recurring task are imported as top-level tasks. Tasks that
+    /// fail are reported and the rest are still imported.
+    pub async fn import(&self, file_path: &str) -> Result<TodoistImportResult> {
+        let preview = self.preview(file_path).await?;
+        let task_repo = TaskRepository::new(self.db.clone());
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+        let periodic_repo = PeriodicTaskRepository::new(self.db.clone());
+        let note_repo = TaskNoteRepository::new(self.db.clone());
+        let source_repo = TaskSourceRepository::new(self.db.clone());
+
+        let mut result = TodoistImportResult {
+            created_task_list_ids: Vec::new(),
+            imported_task_ids: Vec::new(),
+            periodic_template_ids: Vec::new(),
+            skipped_duplicates: 0,
+            errors: preview.errors,
+        };
+
+        for project in preview.projects {
+            if project.tasks.is_empty() {
+                continue;
+            }
+            let task_list_id = match project.task_list_id {
+                Some(id) => id,
+                None => {
+                    let list = task_list_repo
+                        .find_or_create_by_name(&project.name)
+                        .await
+                        .with_context(|| format!("Failed to create task list {}", project.name))?;
+                    result.created_task_list_ids.push(list.id.clone());
+                    list.id
+                }
+            };
+            let error = |line: usize, e: &dyn std::fmt::Display| TaskImportError {
+                line,
+                message: format!("{}: {}", project.name, e),
+            };
+
+            let mut task_ids: Vec<Option<String>> = vec![None; project.tasks.len()];
+            for (index, task) in project.tasks.iter().enumerate() {
+                if task.duplicate {
+                    result.skipped_duplicates += 1;
+                    continue;
+                }
+
+                if let Some(recurrence) = &task.recurrence {
+                    let request = CreatePeriodicTaskTemplateRequest {
+                        title: task.title.clone(),
+                        description: task.description.clone(),
+                        priority: task.priority,
+                        time_estimate: task.time_estimate.unwrap_or(0),
+                        tags: (!task.tags.is_empty()).then(|| task.tags.clone()),
+                        task_list_id: Some(task_list_id.clone()),
+                        recurrence_type: recurrence.recurrence_type.clone(),
+                        recurrence_interval: recurrence.recurrence_interval,
+                        recurrence_unit: None,
+                        recurrence_rule: recurrence.recurrence_rule.clone(),
+                        start_date: recurrence.start_date,
+                        ends_at: None,
+                        max_occurrences: None,
+                        missed_occurrence_policy: None,
+                    };
+                    match periodic_repo.create_template(request).await {
+                        Ok(template) => result.periodic_template_ids.push(template.id),
+                        Err(e) => result.errors.push(error(task.line, &e)),
+                    }
+                    continue;
+                }
+
+                let request = CreateTaskRequest {
+                    title: task.title.clone(),
+                    description: task.description.clone(),
+                    priority: task.priority,
+                    status: None,
+                    order_num: None,
+                    dependencies: None,
+                    time_estimate: task.time_estimate,
+                    due_date: task.due_date,
+                    scheduled_date: None,
+                    tags: (!task.tags.is_empty()).then(|| task.tags.clone()),
+                    project_id: None,
+                    parent_task_id: task.parent.and_then(|parent| task_ids[parent].clone()),
+                    task_list_id: Some(task_list_id.clone()),
+                    periodic_template_id: None,
+                    is_periodic_instance: None,
+                    generation_date: None,
+                    effort: None,
+                    impact: None,
+                };
+                let created = match task_repo.create_task(request).await {
+                    Ok(created) => created,
+                    Err(e) => {
+                        result.errors.push(error(task.line, &e));
+                        continue;
+                    }
+                };
+
+                let source = RecordTaskSourceRequest::file_line(
+                    &created.id,
+                    "todoist",
+                    file_path,
+                    task.line,
+                );
+                if let Err(e) = source_repo.record_source(source).await {
+                    result.errors.push(error(task.line, &e));
+                }
+                for content in &task.notes {
+                    let request = CreateTaskNoteRequest {
+                        task_id: created.id.clone(),
+                        content: content.clone(),
+                    };
+                    if let Err(e) = note_repo.create_note(request).await {
+                        result.errors.push(error(task.line, &e));
+                    }
+                }
+                task_ids[index] = Some(created.id.clone());
+                result.imported_task_ids.push(created.id);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Find the task list of each project and flag tasks whose title is already in it
+    async fn match_projects(&self, projects: &mut [TodoistProject]) -> Result<()> {
+        let task_list_repo = TaskListRepository::new(self.db.clone());
+        let tasks = TaskRepository::new(self.db.clone())
+            .find_all(None, None)
+            .await
+            .context("Failed to fetch tasks")?;
+        let templates = PeriodicTaskRepository::new(self.db.clone())
+            .find_all()
+            .await
+            .context("Failed to fetch periodic templates")?;
+
+        let existing: HashSet<(String, String)> = tasks
+            .into_iter()
+            .map(|task| (task.task_list_id, task.title))
+            .chain(
+                templates
+                    .into_iter()
+                    .map(|template| (template.task_list_id, template.title)),
+            )
+            .filter_map(|(list_id, title)| Some((list_id?, normalize_title(&title))))
+            .collect();
+
+        for project in projects.iter_mut() {
+            project.task_list_id = if project.name.eq_ignore_ascii_case(INBOX_PROJECT) {
+                Some(
+                    task_list_repo
+                        .get_default_task_list()
+                        .await
+                        .context("Failed to fetch default task list")?
+                        .id,
+                )
+            } else {
+                task_list_repo
+                    .find_by_name(&project.name)
+                    .await
+                    .context("Failed to fetch task lists")?
+                    .map(|list| list.id)
+            };
+
+            let Some(list_id) = &project.task_list_id else {
+                continue;
+            };
+            for task in project.tasks.iter_mut() {
+                task.duplicate =
+                    existing.contains(&(list_id.clone(), normalize_title(&task.title)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Project name and CSV content of each project in an export
+fn read_export(file_path: &str) -> Result<Vec<(String, String)>> {
+    let path = Path::new(file_path);
+    let is_zip = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("zip"));
+    if !is_zip {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", file_path))?;
+        return Ok(vec![(project_name(path), content)]);
+    }
+
+    let file = File::open(path).with_context(|| format!("Failed to open {}", file_path))?;
+    let mut archive = ZipArchive::new(file).context("Failed to open Todoist backup")?;
+    let mut files = Vec::new();
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        let entry_name = entry.name().to_string();
+        if entry.is_dir() || !entry_name.to_lowercase().ends_with(".csv") {
+            continue;
+        }
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .with_context(|| format!("Failed to read {}", entry_name))?;
+        files.push((project_name(Path::new(&entry_name)), content));
+    }
+    if files.is_empty() {
+        anyhow::bail!("The backup contains no project CSV files");
+    }
+
+    Ok(files)
+}
+
+/// Project name from a file name; backups name files `Project [id].csv`
+fn project_name(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    match stem.rsplit_once(" [") {
+        Some((name, id)) if id.ends_with(']') => name.trim().to_string(),
+        _ => stem.trim().to_string(),
+    }
+}
+
+/// Tasks of a project CSV, in file order
+///
+/// Rows are typed `task`, `section` or `note`; a task's `INDENT` nests it under the
+/// previous task one level up, and notes are comments on the task above them.
+fn parse_project(
+    content: &str,
+    now: &DateTime<Local>,
+) -> Result<(Vec<TodoistTask>, Vec<TaskImportError>), String> {
+    let mut records = parse_csv(content).into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok((Vec::new(), Vec::new()));
+    };
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|column| column.trim().eq_ignore_ascii_case(name))
+    };
+    let (Some(kind), Some(content_column)) = (column("type"), column("content")) else {
+        return Err("Not a Todoist export: no TYPE and CONTENT columns".to_string());
+    };
+    let description = column("description");
+    let priority = column("priority");
+    let indent = column("indent");
+    let date = column("date");
+    let duration = column("duration");
+    let duration_unit = column("duration_unit");
+
+    let mut tasks: Vec<TodoistTask> = Vec::new();
+    let mut errors = Vec::new();
+    let mut section: Option<String> = None;
+    // Latest task at each indent level, for finding parents
+    let mut ancestors: Vec<usize> = Vec::new();
+    for (line, record) in records {
+        let value = |column: Option<usize>| {
+            column
+                .and_then(|column| record.get(column))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let text = value(Some(content_column)).unwrap_or_default();
+
+        match value(Some(kind)).map(str::to_lowercase).as_deref() {
+            Some("section") => {
+                section = (!text.is_empty()).then(|| text.to_string());
+                ancestors.clear();
+            }
+            Some("note") => {
+                // Comments before the first task belong to the project and are dropped
+                if let Some(task) = tasks.last_mut().filter(|_| !text.is_empty()) {
+                    task.notes.push(text.to_string());
+                }
+            }
+            Some("task") => {
+                let (title, labels) = split_labels(text);
+                if title.is_empty() {
+                    errors.push(TaskImportError {
+                        line,
+                        message: "Missing task content".to_string(),
+                    });
+                    continue;
+                }
+
+                let depth = value(indent)
+                    .and_then(|depth| depth.parse::<usize>().ok())
+                    .unwrap_or(1)
+                    .max(1);
+                ancestors.truncate(depth - 1);
+                let mut parent = if depth > 1 {
+                    ancestors.last().copied()
+                } else {
+                    None
+                };
+
+                let mut warnings = Vec::new();
+                let mut due_date = None;
+                let mut recurrence = None;
+                if let Some(date) = value(date) {
+                    if is_recurring(date) {
+                        recurrence = parse_recurrence(date, now);
+                        if recurrence.is_none() {
+                            warnings.push(format!(
+                                "Unsupported recurrence \"{}\"; imported as a one-off task",
+                                date
+                            ));
+                        }
+                    } else {
+                        due_date = parse_due_date(date, now);
+                        if due_date.is_none() {
+                            warnings.push(format!("Unrecognized due date \"{}\"", date));
+                        }
+                    }
+                }
+                if parent.is_some_and(|parent| tasks[parent].recurrence.is_some()) {
+                    warnings.push("Parent task repeats; imported as a top-level task".to_string());
+                    parent = None;
+                }
+
+                let mut tags: Vec<String> = section.iter().cloned().collect();
+                for label in labels {
+                    if !tags.contains(&label) {
+                        tags.push(label);
+                    }
+                }
+
+                tasks.push(TodoistTask {
+                    line,
+                    title,
+                    description: value(description).map(str::to_string),
+                    priority: match value(priority) {
+                        Some("1") => 3,
+                        Some("2") => 2,
+                        _ => 1,
+                    },
+                    section: section.clone(),
+                    tags,
+                    due_date,
+                    recurrence,
+                    time_estimate: value(duration)
+                        .and_then(|amount| amount.parse::<i32>().ok())
+                        .map(|amount| match value(duration_unit) {
+                            Some(unit) if unit.eq_ignore_ascii_case("day") => amount * 24 * 60,
+                            _ => amount,
+                        }),
+                    notes: Vec::new(),
+                    parent,
+                    duplicate: false,
+                    warnings,
+                });
+                ancestors.push(tasks.len() - 1);
+            }
+            // Blank rows separate sections
+            _ => {}
+        }
+    }
+
+    Ok((tasks, errors))
+}
+
+/// Split `@label` words out of task content
+fn split_labels(content: &str) -> (String, Vec<String>) {
+    let mut words = Vec::new();
+    let mut labels: Vec<String> = Vec::new();
+    for word in content.split_whitespace() {
+        match word.strip_prefix('@').filter(|label| !label.is_empty()) {
+            Some(label) => labels.push(label.to_string()),
+            None => words.push(word),
+        }
+    }
+    (words.join(" "), labels)
+}
+
+fn is_recurring(due_string: &str) -> bool {
+    let due_string = due_string.to_lowercase();
+    due_string.starts_with("every")
+        || ["daily", "weekly", "monthly", "yearly", "annually"]
+            .iter()
+            .any(|word| due_string.split_whitespace().next() == Some(*word))
+}
+
+/// Map a Todoist repeat such as "every 2 weeks" or "every mon, fri" to a periodic schedule
+fn parse_recurrence(due_string: &str, now: &DateTime<Local>) -> Option<TodoistRecurrence> {
+    let lowercase = due_string.to_lowercase().replace(',', " ");
+    let rule = lowercase
+        .trim_start_matches("every!")
+        .trim_start_matches("every");
+    let words: Vec<&str> = rule
+        .split_whitespace()
+        .take_while(|word| !RECURRENCE_STOP_WORDS.contains(word))
+        .filter(|word| *word != "and")
+        .map(|word| match word {
+            "daily" => "day",
+            "weekly" => "week",
+            "monthly" => "month",
+            "yearly" | "annually" => "year",
+            _ => word,
+        })
+        .collect();
+
+    let today = Local
+        .from_local_datetime(&now.date_naive().and_time(NaiveTime::MIN))
+        .earliest()?
+        .with_timezone(&Utc);
+    let schedule = |recurrence_type: &str, interval: i32, rule: Option<String>| {
+        Some(TodoistRecurrence {
+            due_string: due_string.to_string(),
+            recurrence_type: recurrence_type.to_string(),
+            recurrence_interval: interval,
+            recurrence_rule: rule,
+            start_date: today,
+        })
+    };
+    let every = |interval: i32, unit: &str| match unit {
+        "day" | "days" => schedule("daily", interval, None),
+        "week" | "weeks" => schedule("weekly", interval, None),
+        "month" | "months" => schedule("monthly", interval, None),
+        "year" | "years" => schedule("monthly", interval * 12, None),
+        _ => None,
+    };
+
+    match words[..] {
+        [] => None,
+        ["weekday" | "weekdays" | "workday" | "workdays"] => {
+            schedule("cron", 1, Some("0 0 * * 1-5".to_string()))
+        }
+        ["weekend" | "weekends"] => schedule("cron", 1, Some("0 0 * * 0,6".to_string())),
+        ["other", unit] => every(2, unit),
+        [interval, unit] if interval.parse::<i32>().is_ok_and(|n| n > 0) => {
+            every(interval.parse().ok()?, unit)
+        }
+        [unit] if every(1, unit).is_some() => every(1, unit),
+        _ => {
+            let days: Vec<Weekday> = words
+                .iter()
+                .map(|word| parse_weekday(word))
+                .collect::<Option<_>>()?;
+            if let [day] = days[..] {
+                let offset =
+                    (day.num_days_from_monday() + 7 - now.weekday().num_days_from_monday()) % 7;
+                let mut recurrence = schedule("weekly", 1, None)?;
+                recurrence.start_date = today + Duration::days(offset as i64);
+                return Some(recurrence);
+            }
+            let days: Vec<String> = days
+                .iter()
+                .map(|day| day.num_days_from_sunday().to_string())
+                .collect();
+            schedule("cron", 1, Some(format!("0 0 * * {}", days.join(","))))
+        }
+    }
+}
+
+/// A weekday from its name or an abbreviation of at least three letters
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    let word = word.trim_end_matches('s');
+    WEEKDAYS
+        .iter()
+        .find(|(name, _)| word.len() >= 3 && name.starts_with(word))
+        .map(|(_, day)| *day)
+}
+
+/// ISO dates, RFC 3339 timestamps or phrases such as "tomorrow"
+fn parse_due_date(value: &str, now: &DateTime<Local>) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Local
+            .from_local_datetime(&date.and_time(NaiveTime::MIN))
+            .earliest()
+            .map(|date| date.with_timezone(&Utc));
+    }
+    parse_natural_date(value, now).map(|parsed| parsed.date)
+}
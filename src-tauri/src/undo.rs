@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::database::entities::task_audit_log;
+use crate::database::repositories::{TaskAuditRepository, TaskRepository};
+
+/// A change that was undone or redone, with the history entries it consisted of
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoneChange {
+    pub change_id: String,
+    pub entries: Vec<task_audit_log::Model>,
+}
+
+/// Undo and redo over the task changes of the current app session, shared as Tauri state
+///
+/// Changes come from the task history, so updates, deletions and AI tool actions are all
+/// covered no matter which command made them.
+pub struct UndoHistory {
+    state: Mutex<UndoState>,
+}
+
+struct UndoState {
+    session_start: DateTime<Utc>,
+    /// Changes recorded by undo and redo themselves
+    own_changes: HashSet<String>,
+    /// Changes that are currently undone
+    undone: HashSet<String>,
+    /// Undone changes with the time they were undone, most recent last
+    redo_stack: Vec<(String, DateTime<Utc>)>,
+}
+
+impl Default for UndoHistory {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(UndoState {
+                session_start: Utc::now(),
+                own_changes: HashSet::new(),
+                undone: HashSet::new(),
+                redo_stack: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl UndoHistory {
+    /// Reverse the most recent change of this session that is not undone yet
+    ///
+    /// Returns `None` when there is nothing left to undo.
+    pub async fn undo(&self, db: Arc<DatabaseConnection>) -> Result<Option<UndoneChange>, DbErr> {
+        // Held throughout so concurrent calls can't undo the same change twice
+        let mut state = self.state.lock().await;
+        let audit_repo = TaskAuditRepository::new(db.clone());
+
+        let change_id = audit_repo
+            .change_ids_since(state.session_start)
+            .await?
+            .into_iter()
+            .find(|id| !state.own_changes.contains(id) && !state.undone.contains(id));
+        let Some(change_id) = change_id else {
+            return Ok(None);
+        };
+
+        let entries = audit_repo.find_change(&change_id).await?;
+        let reversal = TaskRepository::new(db).revert_change(&entries).await?;
+
+        state.own_changes.insert(reversal);
+        state.undone.insert(change_id.clone());
+        state.redo_stack.push((change_id.clone(), Utc::now()));
+        Ok(Some(UndoneChange { change_id, entries }))
+    }
+
+    /// Apply the most recently undone change again
+    ///
+    /// Returns `None` when nothing was undone, or when tasks changed since the last undo,
+    /// which discards the redo stack.
+    pub async fn redo(&self, db: Arc<DatabaseConnection>) -> Result<Option<UndoneChange>, DbErr> {
+        let mut state = self.state.lock().await;
+        let audit_repo = TaskAuditRepository::new(db.clone());
+
+        let Some((change_id, undone_at)) = state.redo_stack.last().cloned() else {
+            return Ok(None);
+        };
+        let changed_since = audit_repo
+            .change_ids_since(undone_at)
+            .await?
+            .iter()
+            .any(|id| !state.own_changes.contains(id));
+        if changed_since {
+            state.redo_stack.clear();
+            return Ok(None);
+        }
+
+        let entries = audit_repo.find_change(&change_id).await?;
+        let reapplied = TaskRepository::new(db).reapply_change(&entries).await?;
+
+        state.redo_stack.pop();
+        state.own_changes.insert(reapplied);
+        state.undone.remove(&change_id);
+        Ok(Some(UndoneChange { change_id, entries }))
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
+use sea_orm::DatabaseConnection;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::database::entities::{webhook_deliveries, webhooks};
+use crate::database::get_database;
+use crate::database::repositories::WebhookRepository;
+
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often the scheduler looks for deliveries due for a retry
+const RETRY_SCAN_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Deliveries attempted per scan
+const RETRY_BATCH_SIZE: u64 = 50;
+
+/// Attempts before a delivery is given up
+pub(crate) const MAX_ATTEMPTS: i32 = 6;
+
+/// Delay before the first retry; each further retry waits twice as long
+const FIRST_RETRY_DELAY_SECS: i64 = 30;
+
+/// A newly queued delivery is attempted right away; the scheduler only picks it up
+/// after this long, so the two never send it at the same time
+const IMMEDIATE_ATTEMPT_LEASE_SECS: i64 = 60;
+
+/// Queue `event` for every subscribed webhook and attempt delivery in the background
+///
+/// Failed deliveries are retried by the webhook scheduler.
+pub fn emit(db: Arc<DatabaseConnection>, event: &'static str, data: serde_json::Value) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = dispatch(db, event, data).await {
+            eprintln!("Failed to send {} webhooks: {}", event, e);
+        }
+    });
+}
+
+async fn dispatch(db: Arc<DatabaseConnection>, event: &str, data: serde_json::Value) -> Result<()> {
+    let repo = WebhookRepository::new(db);
+    let subscribed = repo.find_subscribed(event).await?;
+    if subscribed.is_empty() {
+        return Ok(());
+    }
+
+    let body = payload(event, data, Utc::now());
+    let lease = Utc::now() + chrono::Duration::seconds(IMMEDIATE_ATTEMPT_LEASE_SECS);
+    let client = http_client()?;
+    for webhook in subscribed {
+        let delivery = repo
+            .enqueue_delivery(&webhook.id, event, body.clone(), lease)
+            .await?;
+        attempt(&repo, &client, &webhook, &delivery).await?;
+    }
+    Ok(())
+}
+
+/// Start the background loop that retries failed webhook deliveries
+pub fn start_webhook_scheduler() {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(RETRY_SCAN_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = retry_due_deliveries().await {
+                eprintln!("Failed to retry webhook deliveries: {}", e);
+            }
+        }
+    });
+}
+
+async fn retry_due_deliveries() -> Result<()> {
+    let db = get_database().await?;
+    let repo = WebhookRepository::new(db);
+    let due = repo
+        .find_due_deliveries(Utc::now(), RETRY_BATCH_SIZE)
+        .await?;
+    if due.is_empty() {
+        return Ok(());
+    }
+
+    let client = http_client()?;
+    for delivery in due {
+        match repo.find_by_id(&delivery.webhook_id).await? {
+            Some(webhook) if webhook.is_active => {
+                attempt(&repo, &client, &webhook, &delivery).await?;
+            }
+            _ => {
+                repo.record_attempt(
+                    &delivery.id,
+                    None,
+                    Some("Webhook was disabled".to_string()),
+                    None,
+                )
+                .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn http_client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .build()
+        .context("Failed to create HTTP client")
+}
+
+/// POST a delivery's payload and record the outcome, scheduling a retry on failure
+async fn attempt(
+    repo: &WebhookRepository,
+    client: &reqwest::Client,
+    webhook: &webhooks::Model,
+    delivery: &webhook_deliveries::Model,
+) -> Result<webhook_deliveries::Model> {
+    let response = client
+        .post(&webhook.url)
+        .header(CONTENT_TYPE, "application/json")
+        .header(USER_AGENT, "KiraPilot-Webhooks")
+        .header("X-KiraPilot-Event", &delivery.event)
+        .header("X-KiraPilot-Delivery", &delivery.id)
+        .header(
+            "X-KiraPilot-Signature",
+            sign(&webhook.secret, &delivery.payload),
+        )
+        .body(delivery.payload.clone())
+        .send()
+        .await;
+
+    let (response_status, error) = match response {
+        Ok(response) if response.status().is_success() => {
+            (Some(response.status().as_u16() as i32), None)
+        }
+        Ok(response) => (
+            Some(response.status().as_u16() as i32),
+            Some(format!("HTTP {}", response.status())),
+        ),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    let next_attempt_at = error
+        .as_ref()
+        .and_then(|_| retry_at(delivery.attempts + 1, Utc::now()));
+
+    Ok(repo
+        .record_attempt(&delivery.id, response_status, error, next_attempt_at)
+        .await?)
+}
+
+/// When to retry after `attempts` failed attempts, or `None` once they are used up
+pub(crate) fn retry_at(attempts: i32, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if attempts >= MAX_ATTEMPTS {
+        return None;
+    }
+    let delay = FIRST_RETRY_DELAY_SECS << (attempts.max(1) - 1);
+    Some(now + chrono::Duration::seconds(delay))
+}
+
+/// The JSON body sent for an event
+pub(crate) fn payload(event: &str, data: serde_json::Value, occurred_at: DateTime<Utc>) -> String {
+    serde_json::json!({
+        "id": uuid::Uuid::new_v4().to_string(),
+        "event": event,
+        "occurred_at": occurred_at.to_rfc3339(),
+        "data": data,
+    })
+    .to_string()
+}
+
+/// `sha256=` followed by the hex HMAC-SHA256 of the body, keyed with the webhook secret
+pub(crate) fn sign(secret: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body.as_bytes());
+    let hex: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    format!("sha256={}", hex)
+}
@@ -2,7 +2,7 @@
 import { useState, useEffect } from 'react';
 import { HeroUIProvider } from '@heroui/react';
 import { DatabaseProvider } from './services/database/DatabaseProvider';
-import { TimerProvider } from './contexts/TimerContext';
+import { TimerProvider, useTimerContext } from './contexts/TimerContext';
 import { AIProvider } from './contexts/AIContext';
 import { PrivacyProvider } from './contexts/PrivacyContext';
 import { SettingsProvider } from './contexts/SettingsContext';
@@ -20,9 +20,12 @@ import { PeriodicTasksView } from './components/planning/PeriodicTasksView';
 import { Header } from './components/common/Header';
 import { AIFloatingButton } from './components/ai/AIFloatingButton';
 import { OnboardingManager } from './components/ai/OnboardingManager';
+import { IdleTimeDialog } from './components/timer';
 import TitleBar from './components/TitleBar';
 import { useTheme } from './hooks/useTheme';
 import { useWindowState } from './hooks/useWindowState';
+import { useDeepLinks } from './hooks/useDeepLinks';
+import { getTaskRepository } from './services/database/repositories';
 import { initializeDebugCommands } from './utils/debugCommands';
 import './App.css';
 
@@ -31,6 +34,26 @@ function AppContent() {
   const [viewParams, setViewParams] = useState<Record<string, unknown>>({});
   const { resolvedTheme } = useTheme();
   const { isMaximized } = useWindowState();
+  const { startTimer } = useTimerContext();
+
+  // Follow kirapilot:// links: open a task or start its timer
+  useDeepLinks(async link => {
+    if (link.action === 'open_task') {
+      // The link may have just created the task
+      window.dispatchEvent(new CustomEvent('tasks-updated'));
+      handleViewChange('day', { taskId: link.taskId });
+      return;
+    }
+
+    try {
+      const task = await getTaskRepository().findById(link.taskId);
+      if (task) {
+        startTimer(task);
+      }
+    } catch (error) {
+      console.error('Failed to start timer from link:', error);
+    }
+  });
 
   // Initialize debug commands for development
   useEffect(() => {
@@ -110,7 +133,11 @@ function AppContent() {
             {(currentView === 'week' ||
               currentView === 'day' ||
               currentView === 'focus') && (
-              <Planner viewMode={currentView as 'week' | 'day' | 'focus'} />
+              <Planner
+                viewMode={currentView as 'week' | 'day' | 'focus'}
+                linkedTaskId={viewParams.taskId as string}
+                onLinkedTaskClose={() => handleViewChange(currentView)}
+              />
             )}
 
             {currentView === 'recurring' && <PeriodicTasksView />}
@@ -136,6 +163,9 @@ function AppContent() {
           {currentView !== 'kira' && currentView !== 'focus' && (
             <AIFloatingButton />
           )}
+
+          {/* Ask about time spent away during a session */}
+          <IdleTimeDialog />
         </div>
       </OnboardingManager>
     </NavigationProvider>
@@ -0,0 +1,70 @@
+import { useState } from 'react';
+import { Input } from '@heroui/react';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import { getTaskRepository } from '../services/database/repositories';
+import { useTranslation } from '../hooks/useTranslation';
+
+/**
+ * Single-line task entry shown in its own window by the global quick capture
+ * shortcut. Enter creates the task and hides the window; Escape dismisses it.
+ */
+export default function QuickCaptureWindow() {
+  const { t } = useTranslation();
+  const [text, setText] = useState('');
+  const [error, setError] = useState<string | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+
+  const hide = async () => {
+    setText('');
+    setError(null);
+    try {
+      await getCurrentWindow().hide();
+    } catch (hideError) {
+      console.error('Error hiding quick capture window:', hideError);
+    }
+  };
+
+  const capture = async () => {
+    if (!text.trim() || isSaving) {
+      return;
+    }
+    setIsSaving(true);
+    try {
+      await getTaskRepository().quickCapture(text);
+      await hide();
+    } catch (captureError) {
+      setError(
+        captureError instanceof Error
+          ? captureError.message
+          : String(captureError)
+      );
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <div className='p-2 bg-content1 rounded-md'>
+      <Input
+        autoFocus
+        value={text}
+        onValueChange={value => {
+          setText(value);
+          setError(null);
+        }}
+        onKeyDown={event => {
+          if (event.key === 'Enter') {
+            capture();
+          } else if (event.key === 'Escape') {
+            hide();
+          }
+        }}
+        placeholder={t('quickCapture.placeholder')}
+        isDisabled={isSaving}
+        isInvalid={error !== null}
+        errorMessage={error ?? undefined}
+        aria-label={t('quickCapture.placeholder')}
+      />
+    </div>
+  );
+}
@@ -204,6 +204,13 @@ export function LogDetailView({
                       <p>
                         <strong>Provider:</strong> {log.modelInfo.provider}
                       </p>
+                      {log.modelInfo.failover && (
+                        <p>
+                          <strong>Failed over from:</strong>{' '}
+                          {log.modelInfo.failover.from} (
+                          {log.modelInfo.failover.reason})
+                        </p>
+                      )}
                     </div>
                   </div>
 
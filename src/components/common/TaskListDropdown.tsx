@@ -688,6 +688,9 @@ export const TaskListDropdown: React.FC<TaskListDropdownProps> = ({
                             ? 'text-primary-600 dark:text-primary-400'
                             : 'text-default-500'
                         }`}
+                        style={
+                          taskList.color ? { color: taskList.color } : undefined
+                        }
                       />
                       <span className='font-medium truncate'>
                         {taskList.name}
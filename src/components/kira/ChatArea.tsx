@@ -1,27 +1,63 @@
-import { Card, CardBody } from '@heroui/react';
+import { Card, CardBody, Select, SelectItem, Spinner } from '@heroui/react';
 import { MessageCircle } from 'lucide-react';
 import { Thread, ThreadMessage } from '../../types/thread';
 import { UserFeedback } from '../../types/aiLogging';
 import { useTranslation } from '../../hooks/useTranslation';
+import { TranslationKey } from '../../i18n';
 import { MessageList } from './MessageList';
 import { MessageInput } from './MessageInput';
 import { InitialTaskMessage } from './InitialTaskMessage';
+import { MarkdownRenderer } from '../common';
 import { useState, useEffect } from 'react';
-import { Task } from '../../types';
+import { AIPersona, ReActStep, Task } from '../../types';
 import { TaskService } from '../../services/database/repositories/TaskService';
+import { getAIPersonaRepository } from '../../services/database/repositories';
+import { AvailableModel } from '../../services/ai/ModelManager';
+
+// Select key of a model; provider names never contain a slash
+const modelKey = (model: AvailableModel) => `${model.provider}/${model.name}`;
+
+// What Kira is doing while it runs each tool
+const TOOL_PROGRESS_KEYS: Record<string, TranslationKey> = {
+  get_tasks: 'kira.chat.progress.checkingTasks',
+  get_task_details: 'kira.chat.progress.checkingTasks',
+  find_task: 'kira.chat.progress.checkingTasks',
+  create_task: 'kira.chat.progress.updatingTasks',
+  update_task: 'kira.chat.progress.updatingTasks',
+  complete_task: 'kira.chat.progress.updatingTasks',
+  enhanced_task_action: 'kira.chat.progress.updatingTasks',
+  start_timer: 'kira.chat.progress.updatingTimer',
+  stop_timer: 'kira.chat.progress.updatingTimer',
+  get_time_data: 'kira.chat.progress.checkingTime',
+  analyze_productivity: 'kira.chat.progress.checkingTime',
+  get_workday_context: 'kira.chat.progress.planningDay',
+  generate_daily_plan: 'kira.chat.progress.planningDay',
+  create_periodic_task: 'kira.chat.progress.recurringTasks',
+  get_periodic_tasks: 'kira.chat.progress.recurringTasks',
+  update_periodic_task: 'kira.chat.progress.recurringTasks',
+  generate_periodic_instances: 'kira.chat.progress.recurringTasks',
+  suggest_recurrence: 'kira.chat.progress.recurringTasks',
+};
 
 interface ChatAreaProps {
   thread: Thread | null;
   messages: ThreadMessage[];
   isLoading: boolean;
   isSending: boolean;
+  currentStep?: ReActStep | null;
+  streamingText?: string;
   onSendMessage: (message: string) => void;
+  onCancelSending?: () => void;
   onFeedbackSubmit?: (
     messageId: string,
     feedback: UserFeedback
   ) => Promise<void>;
   onRegenerateResponse?: (messageId: string) => Promise<void>;
   onEscapePress?: () => void;
+  onPersonaChange?: (personaId: string | null) => void;
+  models?: AvailableModel[];
+  selectedModel?: AvailableModel | null;
+  onModelChange?: (model: AvailableModel | null) => void;
   className?: string;
   // Additional loading states
   isRegenerating?: boolean;
@@ -36,16 +72,39 @@ export function ChatArea({
   messages,
   isLoading,
   isSending,
+  currentStep = null,
+  streamingText = '',
   onSendMessage,
+  onCancelSending,
   onFeedbackSubmit,
   onRegenerateResponse,
   onEscapePress,
+  onPersonaChange,
+  models = [],
+  selectedModel = null,
+  onModelChange,
   className = '',
 
   isRegenerating = false,
 }: ChatAreaProps) {
   const { t } = useTranslation();
   const [assignedTask, setAssignedTask] = useState<Task | null>(null);
+  const [personas, setPersonas] = useState<AIPersona[]>([]);
+
+  // Describe the step Kira is on while it works on the answer
+  const progressLabel = (): string => {
+    switch (currentStep?.kind) {
+      case 'tool_call': {
+        const tool = currentStep.toolName ?? '';
+        const key = TOOL_PROGRESS_KEYS[tool];
+        return key ? t(key) : t('kira.chat.progress.usingTool', { tool });
+      }
+      case 'final_answer':
+        return t('kira.chat.progress.writingAnswer');
+      default:
+        return t('kira.chat.aiThinking');
+    }
+  };
 
   // Load task details when thread is assigned to a task
   useEffect(() => {
@@ -67,6 +126,17 @@ export function ChatArea({
     loadTaskDetails();
   }, [thread?.assignment]);
 
+  // Reload personas when switching threads so new ones show up
+  useEffect(() => {
+    getAIPersonaRepository()
+      .findAll()
+      .then(setPersonas)
+      .catch(error => {
+        console.warn('Failed to load personas:', error);
+        setPersonas([]);
+      });
+  }, [thread?.id]);
+
   // Empty state when no thread is selected
   if (!thread) {
     return (
@@ -89,20 +159,71 @@ export function ChatArea({
   return (
     <div className={`flex-1 flex flex-col min-h-0 ${className}`}>
       {/* Chat Header */}
-      <div className='border-b border-divider px-6 py-4 flex-shrink-0'>
-        <h2 className='text-lg font-semibold text-foreground truncate'>
-          {thread.title}
-        </h2>
-        {thread.assignment && (
-          <div className='mt-1'>
-            <span className='text-xs text-default-500'>
-              {thread.assignment.type === 'task' &&
-                t('kira.chat.assignedToTask')}
-              {thread.assignment.type === 'day' && t('kira.chat.assignedToDay')}
-              {thread.assignment.type === 'general' &&
-                t('kira.chat.generalThread')}
-            </span>
-          </div>
+      <div className='border-b border-divider px-6 py-4 flex-shrink-0 flex items-center gap-4'>
+        <div className='flex-1 min-w-0'>
+          <h2 className='text-lg font-semibold text-foreground truncate'>
+            {thread.title}
+          </h2>
+          {thread.assignment && (
+            <div className='mt-1'>
+              <span className='text-xs text-default-500'>
+                {thread.assignment.type === 'task' &&
+                  t('kira.chat.assignedToTask')}
+                {thread.assignment.type === 'day' &&
+                  t('kira.chat.assignedToDay')}
+                {thread.assignment.type === 'general' &&
+                  t('kira.chat.generalThread')}
+              </span>
+            </div>
+          )}
+        </div>
+        {onPersonaChange && personas.length > 0 && (
+          <Select
+            size='sm'
+            aria-label={t('kira.chat.persona')}
+            className='w-48 flex-shrink-0'
+            selectedKeys={[thread.personaId ?? 'default']}
+            onSelectionChange={keys => {
+              const value = Array.from(keys)[0] as string | undefined;
+              if (value) {
+                onPersonaChange(value === 'default' ? null : value);
+              }
+            }}
+          >
+            {[
+              <SelectItem key='default'>
+                {t('kira.chat.defaultPersona')}
+              </SelectItem>,
+              ...personas.map(persona => (
+                <SelectItem key={persona.id}>{persona.name}</SelectItem>
+              )),
+            ]}
+          </Select>
+        )}
+        {onModelChange && models.length > 0 && (
+          <Select
+            size='sm'
+            aria-label={t('kira.chat.model')}
+            className='w-48 flex-shrink-0'
+            selectedKeys={[selectedModel ? modelKey(selectedModel) : 'default']}
+            onSelectionChange={keys => {
+              const value = Array.from(keys)[0] as string | undefined;
+              if (value) {
+                onModelChange(
+                  models.find(model => modelKey(model) === value) ?? null
+                );
+              }
+            }}
+          >
+            {[
+              <SelectItem key='default'>
+                {t('kira.chat.defaultModel')}
+              </SelectItem>,
+              ...models.map(model => (
+                <SelectItem key={modelKey(model)}>{model.name}</SelectItem>
+              )),
+            ]}
+          </Select>
         )}
       </div>
 
@@ -129,10 +250,27 @@ export function ChatArea({
         />
       )}
 
+      {/* Progress of the answer being generated */}
+      {isSending && (
+        <div className='px-6 py-2 flex-shrink-0' aria-live='polite'>
+          {/* The answer so far, as the model writes it */}
+          {streamingText && (
+            <div className='bg-content2 px-3 py-2 mb-2 rounded-lg text-sm border border-divider max-h-64 overflow-y-auto'>
+              <MarkdownRenderer content={streamingText} />
+            </div>
+          )}
+          <div className='flex items-center gap-2 text-sm text-foreground-500'>
+            <Spinner size='sm' />
+            <span>{progressLabel()}</span>
+          </div>
+        </div>
+      )}
+
       {/* Message Input */}
       <MessageInput
         onSendMessage={onSendMessage}
         isLoading={isSending}
+        onCancel={onCancelSending}
         disabled={isLoading || isSending}
         onEscapePress={onEscapePress}
         className='border-t border-divider flex-shrink-0'
@@ -4,6 +4,7 @@ import { useThreads } from '../../hooks/useThreads';
 import { useThreadMessages } from '../../hooks/useThreadMessages';
 import { useToastContext } from '../../contexts/ToastContext';
 import { useAI } from '../../contexts/AIContext';
+import { AvailableModel } from '../../services/ai/ModelManager';
 import { useNavigation } from '../../contexts/NavigationContext';
 import { ThreadSidebar } from './ThreadSidebar';
 import { ChatArea } from './ChatArea';
@@ -44,7 +45,14 @@ export function KiraView() {
   } | null>(null);
 
   // AI context for checking API key status
-  const { getModelStatus } = useAI();
+  const { getModelStatus, listAvailableModels } = useAI();
+
+  // Models a message can be answered with, and the one picked for the next
+  // messages (the current provider's default if none)
+  const [models, setModels] = useState<AvailableModel[]>([]);
+  const [selectedModel, setSelectedModel] = useState<AvailableModel | null>(
+    null
+  );
 
   // Navigation context for handling thread selection from URL params
   const { viewParams } = useNavigation();
@@ -55,6 +63,7 @@ export function KiraView() {
     selectThread,
     deleteThread,
     assignThread,
+    setThreadPersona,
     isLoading: threadsLoading,
     isCreating,
     isDeleting,
@@ -71,7 +80,9 @@ export function KiraView() {
     sendMessage,
     isLoading: messagesLoading,
     isSending,
-
+    currentStep,
+    streamingText,
+    cancelSending,
     isRegenerating,
     error: messagesError,
     clearError: clearMessagesError,
@@ -183,6 +194,17 @@ export function KiraView() {
     };
   }, []); // Empty dependency array since handleKeyDown uses refs
 
+  // Reload the model catalog when switching threads so newly downloaded
+  // models show up
+  useEffect(() => {
+    listAvailableModels()
+      .then(setModels)
+      .catch(error => {
+        console.warn('Failed to list models:', error);
+        setModels([]);
+      });
+  }, [selectedThread?.id]);
+
   // Auto-select thread when threadId is provided in viewParams
   useEffect(() => {
     const threadId = viewParams.threadId as string;
@@ -436,6 +458,19 @@ export function KiraView() {
     }
   };
 
+  const handlePersonaChange = async (personaId: string | null) => {
+    if (!selectedThread) {
+      return;
+    }
+
+    const updatedThread = await setThreadPersona(selectedThread.id, personaId);
+    if (updatedThread) {
+      setSelectedThread(updatedThread);
+    } else if (threadsError) {
+      showError('Failed to change persona', threadsError.userMessage);
+    }
+  };
+
   const handleAssignmentCancel = () => {
     setShowAssignmentModal(false);
     setAssignmentThreadId(null);
@@ -493,7 +528,11 @@ export function KiraView() {
     }
 
     // Pass the thread assignment to sendMessage so AI gets proper context
-    const result = await sendMessage(message, selectedThread.assignment);
+    const result = await sendMessage(
+      message,
+      selectedThread.assignment,
+      selectedModel ?? undefined
+    );
 
     if (!result && messagesError) {
       // Provide specific error handling based on error type
@@ -817,10 +856,17 @@ export function KiraView() {
             messages={messages}
             isLoading={messagesLoading}
             isSending={isSending}
+            currentStep={currentStep}
+            streamingText={streamingText}
             isRegenerating={isRegenerating}
             onSendMessage={handleSendMessage}
+            onCancelSending={cancelSending}
             onFeedbackSubmit={handleFeedbackSubmit}
             onRegenerateResponse={handleRegenerateResponse}
+            onPersonaChange={handlePersonaChange}
+            models={models}
+            selectedModel={selectedModel}
+            onModelChange={setSelectedModel}
             onEscapePress={() => {
               // Handle escape in message input - close modals or blur focus
               if (showAssignmentModal) {
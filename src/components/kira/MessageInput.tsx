@@ -7,7 +7,7 @@ import React, {
   useImperativeHandle,
 } from 'react';
 import { Textarea, Button } from '@heroui/react';
-import { Send } from 'lucide-react';
+import { Send, Square } from 'lucide-react';
 import { useTranslation } from '../../hooks/useTranslation';
 import { validateMessageContent } from '../../types/validation';
 
@@ -19,6 +19,7 @@ interface MessageInputProps {
   className?: string;
   onError?: (error: string) => void;
   onEscapePress?: () => void;
+  onCancel?: () => void; // Shown as a stop button while loading
   autoFocus?: boolean;
 }
 
@@ -49,6 +50,7 @@ export const MessageInput = forwardRef<MessageInputRef, MessageInputProps>(
       className = '',
       onError,
       onEscapePress,
+      onCancel,
       autoFocus = true,
     },
     ref
@@ -294,26 +296,43 @@ export const MessageInput = forwardRef<MessageInputRef, MessageInputProps>(
             aria-multiline='true'
           />
 
-          <Button
-            isIconOnly
-            size='lg'
-            color='primary'
-            onPress={handleSendMessage}
-            disabled={!canSend}
-            isLoading={isLoading}
-            className={`min-w-10 h-10 ${
-              !canSend
-                ? 'opacity-50 bg-content3 text-foreground-500'
-                : 'bg-primary-500 hover:bg-primary-600 text-white shadow-md'
-            }`}
-            title={isLoading ? t('common.sending') : t('kira.chat.sendMessage')}
-            aria-label={
-              isLoading ? t('common.sending') : t('kira.chat.sendMessage')
-            }
-            aria-describedby='send-button-hint'
-          >
-            {!isLoading && <Send className='w-4 h-4' />}
-          </Button>
+          {isLoading && onCancel ? (
+            <Button
+              isIconOnly
+              size='lg'
+              color='danger'
+              variant='flat'
+              onPress={onCancel}
+              className='min-w-10 h-10'
+              title={t('kira.chat.stopGenerating')}
+              aria-label={t('kira.chat.stopGenerating')}
+            >
+              <Square className='w-4 h-4' />
+            </Button>
+          ) : (
+            <Button
+              isIconOnly
+              size='lg'
+              color='primary'
+              onPress={handleSendMessage}
+              disabled={!canSend}
+              isLoading={isLoading}
+              className={`min-w-10 h-10 ${
+                !canSend
+                  ? 'opacity-50 bg-content3 text-foreground-500'
+                  : 'bg-primary-500 hover:bg-primary-600 text-white shadow-md'
+              }`}
+              title={
+                isLoading ? t('common.sending') : t('kira.chat.sendMessage')
+              }
+              aria-label={
+                isLoading ? t('common.sending') : t('kira.chat.sendMessage')
+              }
+              aria-describedby='send-button-hint'
+            >
+              {!isLoading && <Send className='w-4 h-4' />}
+            </Button>
+          )}
         </div>
 
         {/* Message info and keyboard shortcut hint */}
@@ -108,6 +108,7 @@ jest.mock('../../../contexts/AIContext', () => ({
       isReady: true,
       error: null,
     }),
+    listAvailableModels: jest.fn().mockResolvedValue([]),
   }),
   AIProvider: ({ children }: { children: React.ReactNode }) => (
     <div>{children}</div>
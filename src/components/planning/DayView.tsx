@@ -54,6 +54,7 @@ interface DayViewProps {
   onTaskStatusChange: (task: Task | VirtualTask, status: TaskStatus) => void;
   onTaskCreate: (task: Task) => Promise<void>;
   onInlineEdit?: (taskId: string, updates: Partial<Task>) => void;
+  onTaskReorder?: (taskIds: string[]) => void;
   onTaskDelete?: (task: Task | VirtualTask) => void;
   onViewTimeHistory?: (task: Task) => void;
   getTaskTimerProps?: (task: Task) => TaskTimerProps;
@@ -70,6 +71,7 @@ export function DayView({
   onTaskStatusChange,
   onTaskCreate,
   onInlineEdit,
+  onTaskReorder,
   onTaskDelete,
   onViewTimeHistory,
   getTaskTimerProps,
@@ -234,16 +236,12 @@ export function DayView({
           // Reorder the tasks using arrayMove
           const reorderedTasks = arrayMove(columnTasks, activeIndex, overIndex);
 
-          // Update all tasks in the column with new order values
-          reorderedTasks.forEach((task, index) => {
-            if (onInlineEdit) {
-              onInlineEdit(task.id, { order: index });
-            }
-          });
+          // Save the whole column's order in one transaction
+          onTaskReorder?.(reorderedTasks.map(task => task.id));
         }
       }
     },
-    [findTaskColumn, tasks, selectedDate, onTaskMove, onInlineEdit]
+    [findTaskColumn, tasks, selectedDate, onTaskMove, onTaskReorder]
   );
 
   // Improved sensors with better cursor tracking
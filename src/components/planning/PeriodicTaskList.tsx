@@ -309,6 +309,8 @@ export function PeriodicTaskList({
           : `Every ${template.recurrenceInterval} months`;
       case RecurrenceType.CUSTOM:
         return `Every ${template.recurrenceInterval} ${template.recurrenceUnit}`;
+      case RecurrenceType.CRON:
+        return `Cron: ${template.recurrenceRule}`;
       default:
         return 'Unknown';
     }
@@ -6,17 +6,25 @@ import { PeriodicTaskService } from '../../services/database/repositories/Period
 import { VirtualPeriodicTaskService } from '../../services/database/repositories/VirtualPeriodicTaskService';
 import { useTaskList } from '../../contexts/TaskListContext';
 import { WeeklyPlan } from './WeeklyPlan';
+import { TaskModal } from './TaskModal';
 import { TaskFilterBar } from './TaskFilterBar';
 
 import { FocusView } from './FocusView';
 
 interface PlanningScreenProps {
   viewMode?: 'week' | 'day' | 'focus';
+  linkedTaskId?: string; // Task opened by a kirapilot:// link
+  onLinkedTaskClose?: () => void;
 }
 
-export function Planner({ viewMode = 'week' }: PlanningScreenProps) {
+export function Planner({
+  viewMode = 'week',
+  linkedTaskId,
+  onLinkedTaskClose,
+}: PlanningScreenProps) {
   const { isInitialized } = useDatabase();
   const [tasks, setTasks] = useState<Task[]>([]);
+  const [linkedTask, setLinkedTask] = useState<Task | null>(null);
   const [virtualTasks, setVirtualTasks] = useState<VirtualTask[]>([]);
   const [currentWeek, setCurrentWeek] = useState(new Date());
   const [filters, setFilters] = useState<TaskFilters>({});
@@ -125,6 +133,29 @@ export function Planner({ viewMode = 'week' }: PlanningScreenProps) {
     };
   }, [isInitialized, currentWeek, viewMode]);
 
+  // Open the task a link points at, which may be outside the current week
+  useEffect(() => {
+    if (!linkedTaskId || !isInitialized) {
+      setLinkedTask(null);
+      return;
+    }
+
+    getTaskRepository()
+      .findById(linkedTaskId)
+      .then(setLinkedTask)
+      .catch(error => {
+        console.error('Failed to load linked task:', error);
+      });
+  }, [linkedTaskId, isInitialized]);
+
+  const handleLinkedTaskUpdate = async (updates: Partial<Task>) => {
+    if (!linkedTask) {
+      return;
+    }
+    await getTaskRepository().update(linkedTask.id, updates);
+    await refreshTasks();
+  };
+
   // Calculate dynamic column height based on window size
   const getColumnHeight = () => {
     const { width, height } = windowSize;
@@ -256,6 +287,16 @@ export function Planner({ viewMode = 'week' }: PlanningScreenProps) {
     loadTasks();
   }, [isInitialized, currentWeek, viewMode]);
 
+  // Show tasks rolled over from past days in their new place
+  useEffect(() => {
+    const unlisten = getTaskRepository().onTasksRolledOver(() => {
+      refreshTasks();
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [isInitialized]);
+
   const handleTaskMove = async (
     taskId: string,
     _fromColumn: string,
@@ -467,6 +508,32 @@ export function Planner({ viewMode = 'week' }: PlanningScreenProps) {
     }
   };
 
+  const handleTaskReorder = async (taskIds: string[]) => {
+    // Virtual tasks have no row to reorder until they are materialized
+    const realTaskIds = taskIds.filter(id =>
+      tasks.some(task => task.id === id)
+    );
+    const order = new Map(realTaskIds.map((id, index) => [id, index]));
+    const originalTasks = tasks;
+
+    // Optimistically update local state first for immediate UI feedback
+    setTasks(prev =>
+      prev.map(task => {
+        const index = order.get(task.id);
+        return index === undefined ? task : { ...task, order: index };
+      })
+    );
+
+    try {
+      if (isInitialized) {
+        await getTaskRepository().reorder(realTaskIds);
+      }
+    } catch (error) {
+      console.error('Failed to reorder tasks in database:', error);
+      setTasks(originalTasks);
+    }
+  };
+
   const handleTaskStatusChange = async (
     task: Task | VirtualTask,
     status: TaskStatus
@@ -661,6 +728,7 @@ export function Planner({ viewMode = 'week' }: PlanningScreenProps) {
               onTaskMove={handleTaskMove}
               onTaskCreate={handleTaskCreate}
               onTaskEdit={handleTaskEdit}
+              onTaskReorder={handleTaskReorder}
               onTaskStatusChange={handleTaskStatusChange}
               onTaskDelete={handleTaskDelete}
               viewMode={viewMode}
@@ -669,6 +737,16 @@ export function Planner({ viewMode = 'week' }: PlanningScreenProps) {
           )}
         </>
       )}
+
+      <TaskModal
+        isOpen={linkedTask !== null}
+        onClose={() => {
+          setLinkedTask(null);
+          onLinkedTaskClose?.();
+        }}
+        onUpdateTask={handleLinkedTaskUpdate}
+        task={linkedTask}
+      />
     </div>
   );
 }
@@ -53,6 +53,7 @@ interface WeekViewProps {
   onTaskStatusChange: (task: Task | VirtualTask, status: TaskStatus) => void;
   onTaskCreate: (task: Task) => Promise<void>;
   onInlineEdit?: (taskId: string, updates: Partial<Task>) => void;
+  onTaskReorder?: (taskIds: string[]) => void;
   onTaskDelete?: (task: Task | VirtualTask) => void;
   onViewTimeHistory?: (task: Task) => void;
   getTaskTimerProps?: (task: Task) => TaskTimerProps;
@@ -68,6 +69,7 @@ export function WeekView({
   onTaskStatusChange,
   onTaskCreate,
   onInlineEdit,
+  onTaskReorder,
   onTaskDelete,
   onViewTimeHistory,
   getTaskTimerProps,
@@ -283,16 +285,12 @@ export function WeekView({
           // Reorder the tasks using arrayMove
           const reorderedTasks = arrayMove(columnTasks, activeIndex, overIndex);
 
-          // Update all tasks in the column with new order values
-          reorderedTasks.forEach((task, index) => {
-            if (onInlineEdit) {
-              onInlineEdit(task.id, { order: index });
-            }
-          });
+          // Save the whole column's order in one transaction
+          onTaskReorder?.(reorderedTasks.map(task => task.id));
         }
       }
     },
-    [findTaskColumn, tasks, weekDays, onTaskMove, onInlineEdit]
+    [findTaskColumn, tasks, weekDays, onTaskMove, onTaskReorder]
   );
 
   // Removed complex keyboard navigation and placeholder management code
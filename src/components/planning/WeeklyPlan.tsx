@@ -20,6 +20,7 @@ interface WeeklyPlanProps {
   ) => void;
   onTaskCreate: (task: Task) => Promise<void>;
   onTaskEdit: (taskId: string, updates: Partial<Task>) => void;
+  onTaskReorder?: (taskIds: string[]) => void;
   onTaskStatusChange: (task: Task | VirtualTask, status: TaskStatus) => void;
   onTaskDelete?: (task: Task | VirtualTask) => void;
   viewMode?: 'week' | 'day';
@@ -34,6 +35,7 @@ export function WeeklyPlan({
   onTaskMove,
   onTaskCreate,
   onTaskEdit,
+  onTaskReorder,
   onTaskStatusChange,
   onTaskDelete,
   viewMode: initialViewMode = 'week',
@@ -145,6 +147,7 @@ export function WeeklyPlan({
           onTaskStatusChange={handleTaskStatusChange}
           onTaskCreate={handleTaskCreate}
           onInlineEdit={handleInlineEdit}
+          onTaskReorder={onTaskReorder}
           onTaskDelete={handleTaskDelete}
           onViewTimeHistory={handleViewTimeHistory}
           getTaskTimerProps={getTaskTimerProps}
@@ -160,6 +163,7 @@ export function WeeklyPlan({
           onTaskStatusChange={handleTaskStatusChange}
           onTaskCreate={handleTaskCreate}
           onInlineEdit={handleInlineEdit}
+          onTaskReorder={onTaskReorder}
           onTaskDelete={handleTaskDelete}
           onViewTimeHistory={handleViewTimeHistory}
           getTaskTimerProps={getTaskTimerProps}
@@ -25,11 +25,17 @@ import {
   Settings,
 } from 'lucide-react';
 import { useAI } from '../../contexts/AIContext';
+import { useSettings } from '../../contexts/SettingsContext';
 import { useTranslation } from '../../hooks/useTranslation';
 import { forceClearData } from '../../utils/clearOldData';
 import { TaskService } from '../../services/database/repositories/TaskService';
 import { TimeTrackingService } from '../../services/database/repositories/TimeTrackingService';
-import { Priority, TaskStatus, CreateTaskRequest } from '../../types';
+import {
+  Priority,
+  TaskStatus,
+  CreateTaskRequest,
+  UserPreferences,
+} from '../../types';
 import { invoke } from '@tauri-apps/api/core';
 
 interface DataManagementProps {
@@ -40,6 +46,7 @@ export const DataManagement: React.FC<DataManagementProps> = ({
   className = '',
 }) => {
   const { clearConversation } = useAI();
+  const { preferences, updatePreferences } = useSettings();
   const { t } = useTranslation();
 
   // Utility function for showing messages with fallback to alert
@@ -74,6 +81,8 @@ export const DataManagement: React.FC<DataManagementProps> = ({
   const [exportProgress, setExportProgress] = useState(0);
   const [importProgress, setImportProgress] = useState(0);
 
+  const [isTransferringSettings, setIsTransferringSettings] = useState(false);
+
   const [showAILogs, setShowAILogs] = useState(false);
   const [isGeneratingMockData, setIsGeneratingMockData] = useState(false);
   const [isClearingData, setIsClearingData] = useState(false);
@@ -1003,6 +1012,93 @@ Perfect for exploring KiraPilot's task management, time tracking, and analytics
     }
   };
 
+  const handleExportSettings = async () => {
+    try {
+      const { save } = await import('@tauri-apps/plugin-dialog');
+      const filePath = await save({
+        defaultPath: `kirapilot-settings-${new Date().toISOString().split('T')[0]}.json`,
+        filters: [{ name: 'KiraPilot Settings', extensions: ['json'] }],
+      });
+
+      if (!filePath) {
+        return;
+      }
+
+      setIsTransferringSettings(true);
+      const summary = await invoke<{
+        saved_filter_count: number;
+        task_status_count: number;
+        custom_field_count: number;
+      }>('export_settings_bundle', { preferences, filePath });
+
+      await showMessage(
+        `✅ Settings exported successfully!\n\n• Preferences\n• ${summary.saved_filter_count} saved filters\n• ${summary.task_status_count} custom statuses\n• ${summary.custom_field_count} custom fields\n\nAPI keys are not included in the file.`,
+        { title: 'Export Successful', kind: 'info' }
+      );
+    } catch (error) {
+      console.error('Settings export failed:', error);
+      await showMessage(`Failed to export settings: ${error}`, {
+        title: 'Export Failed',
+        kind: 'error',
+      });
+    } finally {
+      setIsTransferringSettings(false);
+    }
+  };
+
+  const handleImportSettings = async () => {
+    try {
+      const { open } = await import('@tauri-apps/plugin-dialog');
+      const filePath = await open({
+        filters: [{ name: 'KiraPilot Settings', extensions: ['json'] }],
+        multiple: false,
+      });
+
+      if (!filePath) {
+        return;
+      }
+
+      setIsTransferringSettings(true);
+      const result = await invoke<{
+        preferences: Partial<UserPreferences> | null;
+        imported_saved_filters: number;
+        imported_task_statuses: number;
+        imported_custom_fields: number;
+        skipped: string[];
+      }>('import_settings_bundle', { filePath });
+
+      if (result.preferences) {
+        // Keep the local API key, bundles never carry one
+        await updatePreferences({
+          ...preferences,
+          ...result.preferences,
+          aiSettings: {
+            ...preferences.aiSettings,
+            ...result.preferences.aiSettings,
+            geminiApiKey: preferences.aiSettings.geminiApiKey,
+          },
+        });
+      }
+
+      const skipped =
+        result.skipped.length > 0
+          ? `\n\nAlready present, left unchanged:\n${result.skipped.join('\n')}`
+          : '';
+      await showMessage(
+        `✅ Settings imported successfully!\n\n• ${result.preferences ? 'Preferences applied' : 'No preferences in file'}\n• ${result.imported_saved_filters} saved filters\n• ${result.imported_task_statuses} custom statuses\n• ${result.imported_custom_fields} custom fields${skipped}`,
+        { title: 'Import Successful', kind: 'info' }
+      );
+    } catch (error) {
+      console.error('Settings import failed:', error);
+      await showMessage(`Failed to import settings: ${error}`, {
+        title: 'Import Failed',
+        kind: 'error',
+      });
+    } finally {
+      setIsTransferringSettings(false);
+    }
+  };
+
   // Test backup function for development
   const handleTestBackup = async () => {
     try {
@@ -1145,6 +1241,26 @@ Perfect for exploring KiraPilot's task management, time tracking, and analytics
               </Button>
             </div>
 
+            <div className='flex gap-3'>
+              <Button
+                variant='flat'
+                startContent={<Settings className='h-4 w-4' />}
+                onPress={handleExportSettings}
+                isDisabled={isTransferringSettings}
+              >
+                Export Settings
+              </Button>
+
+              <Button
+                variant='flat'
+                startContent={<Settings className='h-4 w-4' />}
+                onPress={handleImportSettings}
+                isDisabled={isTransferringSettings}
+              >
+                Import Settings
+              </Button>
+            </div>
+
             {/* Export Progress */}
             {isExporting && exportProgress > 0 && (
               <div className='space-y-2'>
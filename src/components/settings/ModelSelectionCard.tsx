@@ -13,6 +13,7 @@ import {
   Cloud,
   HardDrive,
   AlertCircle,
+  BatteryLow,
   CheckCircle,
   Loader,
 } from 'lucide-react';
@@ -21,6 +22,8 @@ import { useAI } from '../../contexts/AIContext';
 import { useSettings } from '../../contexts/SettingsContext';
 import { useTranslation } from '../../hooks/useTranslation';
 import { ModelStatus } from '../../services/ai/AIServiceInterface';
+import { ModelType } from '../../services/ai/ModelManager';
+import { OllamaService } from '../../services/ai/OllamaService';
 
 interface ModelSelectionCardProps {
   className?: string;
@@ -36,10 +39,14 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
   const [modelStatus, setModelStatus] = useState<ModelStatus | null>(null);
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [ollamaAvailable, setOllamaAvailable] = useState(false);
+  const [ollamaModels, setOllamaModels] = useState<string[]>([]);
   const intervalRef = useRef<NodeJS.Timeout | null>(null);
 
   const updateStatus = useCallback(() => {
     if (modelManager) {
+      // Picked up by the next update; readings are reused for a while
+      void modelManager.refreshPowerState();
       const status = modelManager.getModelStatus();
       setModelStatus(prevStatus => {
         // Only update if status actually changed to prevent unnecessary re-renders
@@ -49,7 +56,10 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
           prevStatus.isReady !== status.isReady ||
           prevStatus.isLoading !== status.isLoading ||
           prevStatus.error !== status.error ||
-          prevStatus.downloadProgress !== status.downloadProgress
+          prevStatus.downloadProgress !== status.downloadProgress ||
+          prevStatus.powerSaving?.batteryPercent !==
+            status.powerSaving?.batteryPercent ||
+          prevStatus.powerSaving?.model !== status.powerSaving?.model
         ) {
           return status;
         }
@@ -80,7 +90,25 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
     };
   }, [modelManager, updateStatus]);
 
-  const handleModelTypeChange = async (modelType: 'gemini') => {
+  // Offer Ollama only while its daemon is running
+  useEffect(() => {
+    modelManager
+      ?.detectOllama(preferences.aiSettings.ollamaUrl)
+      .then(setOllamaAvailable);
+  }, [modelManager, preferences.aiSettings.ollamaUrl]);
+
+  // List the downloaded models once Ollama is in use
+  useEffect(() => {
+    const service = modelManager?.getCurrentService();
+    if (service instanceof OllamaService && modelStatus?.isReady) {
+      service
+        .listModels()
+        .then(models => setOllamaModels(models.map(model => model.name)))
+        .catch(err => console.warn('Failed to list Ollama models:', err));
+    }
+  }, [modelManager, modelStatus?.isReady, modelStatus?.type]);
+
+  const handleModelTypeChange = async (modelType: ModelType) => {
     if (!modelManager) {
       return;
     }
@@ -92,12 +120,11 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
       // Update preferences first
       await updateNestedPreference('aiSettings', 'modelType', modelType);
 
-      // Only Gemini is supported now
-
-      // For Gemini or other models, switch normally
       await modelManager.switchModel(modelType, {
         type: modelType,
         apiKey: preferences.aiSettings.geminiApiKey,
+        baseUrl: preferences.aiSettings.ollamaUrl,
+        model: preferences.aiSettings.ollamaModel,
       });
 
       // Reinitialize AI context
@@ -110,6 +137,30 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
     }
   };
 
+  const handleOllamaModelChange = async (model: string) => {
+    if (!modelManager) {
+      return;
+    }
+
+    setIsLoading(true);
+    setError(null);
+
+    try {
+      await updateNestedPreference('aiSettings', 'ollamaModel', model);
+      await modelManager.switchModel('ollama', {
+        type: 'ollama',
+        baseUrl: preferences.aiSettings.ollamaUrl,
+        model,
+      });
+      await reinitializeAI();
+    } catch (err) {
+      console.error('Failed to switch Ollama model:', err);
+      setError(err instanceof Error ? err.message : 'Failed to switch model');
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
   const getStatusIcon = (status: ModelStatus | null) => {
     if (!status) {
       return <Loader className='w-4 h-4 animate-spin' />;
@@ -193,11 +244,12 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
               <Select
                 selectedKeys={[currentModelType]}
                 onSelectionChange={keys => {
-                  const modelType = Array.from(keys)[0] as 'gemini';
+                  const modelType = Array.from(keys)[0] as ModelType;
                   handleModelTypeChange(modelType);
                 }}
                 size='sm'
                 isDisabled={isLoading}
+                disabledKeys={ollamaAvailable ? [] : ['ollama']}
                 aria-label='AI model type selection'
                 classNames={{
                   trigger:
@@ -212,17 +264,53 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
                   {t('ai.model.gemini')}
                 </SelectItem>
                 <SelectItem
-                  key='local'
+                  key='ollama'
                   startContent={<HardDrive className='w-4 h-4' />}
                 >
-                  {t('ai.model.local')}
+                  {t('ai.model.ollama')}
                 </SelectItem>
               </Select>
               <p className='text-xs text-foreground-600 mt-1'>
-                {t('settings.ai.geminiModelDescription')}
+                {currentModelType === 'ollama'
+                  ? t('settings.ai.ollamaModelDescription')
+                  : ollamaAvailable
+                    ? t('settings.ai.geminiModelDescription')
+                    : t('settings.ai.ollamaNotRunning')}
               </p>
             </div>
 
+            {/* Ollama Model Selection */}
+            {currentModelType === 'ollama' && ollamaModels.length > 0 && (
+              <div>
+                <label className='text-sm font-medium text-foreground block mb-2'>
+                  {t('settings.ai.ollamaModel')}
+                </label>
+                <Select
+                  selectedKeys={[
+                    modelManager?.getModelInfo()?.name ?? ollamaModels[0],
+                  ]}
+                  onSelectionChange={keys => {
+                    const model = Array.from(keys)[0] as string | undefined;
+                    if (model) {
+                      handleOllamaModelChange(model);
+                    }
+                  }}
+                  size='sm'
+                  isDisabled={isLoading}
+                  aria-label='Ollama model selection'
+                  classNames={{
+                    trigger:
+                      'bg-content2 border-divider data-[hover=true]:bg-content3',
+                    value: 'text-foreground',
+                  }}
+                >
+                  {ollamaModels.map(model => (
+                    <SelectItem key={model}>{model}</SelectItem>
+                  ))}
+                </Select>
+              </div>
+            )}
+
             {/* Model Status */}
             <div className='flex items-center justify-between p-3 bg-content2 rounded-lg'>
               <div className='flex items-center gap-3'>
@@ -234,6 +322,19 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
                   <p className='text-xs text-foreground-600'>
                     {getStatusText(modelStatus)}
                   </p>
+                  {modelStatus?.powerSaving && (
+                    <p className='text-xs text-warning flex items-center gap-1'>
+                      <BatteryLow className='w-3 h-3' />
+                      {modelStatus.powerSaving.model
+                        ? t('ai.model.status.batterySmallerModel', {
+                            percent: modelStatus.powerSaving.batteryPercent,
+                            model: modelStatus.powerSaving.model,
+                          })
+                        : t('ai.model.status.batteryDeferred', {
+                            percent: modelStatus.powerSaving.batteryPercent,
+                          })}
+                    </p>
+                  )}
                 </div>
               </div>
               <Chip
@@ -241,7 +342,9 @@ export const ModelSelectionCard: React.FC<ModelSelectionCardProps> = ({
                 color={getStatusColor(modelStatus)}
                 variant='flat'
               >
-                {t('ai.model.gemini')}
+                {currentModelType === 'ollama'
+                  ? t('ai.model.ollama')
+                  : t('ai.model.gemini')}
               </Chip>
             </div>
 
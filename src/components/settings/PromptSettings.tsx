@@ -0,0 +1,125 @@
+import React, { useEffect, useState } from 'react';
+import { Button, Textarea } from '@heroui/react';
+import { FileText, RotateCcw } from 'lucide-react';
+
+import { useTranslation } from '../../hooks/useTranslation';
+import { useToastContext } from '../../contexts/ToastContext';
+import { getPreferencesRepository } from '../../services/database/repositories';
+import { DEFAULT_PROMPT_TEMPLATES } from '../../services/ai/KiraPrompts';
+import { PromptTemplates } from '../../types';
+import { TranslationKey } from '../../i18n';
+
+interface PromptSettingsProps {
+  className?: string;
+}
+
+const TEMPLATE_KEYS: (keyof PromptTemplates)[] = [
+  'systemPersona',
+  'thought',
+  'action',
+  'finalAnswer',
+];
+
+export const PromptSettings: React.FC<PromptSettingsProps> = ({
+  className = '',
+}) => {
+  const { t } = useTranslation();
+  const { showSuccess, showError } = useToastContext();
+  const [templates, setTemplates] = useState<PromptTemplates>({});
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    getPreferencesRepository()
+      .getPromptTemplates()
+      .then(setTemplates)
+      .catch(error => {
+        console.error('Failed to load prompt templates:', error);
+        showError(t('settings.ai.promptTemplates.loadError'));
+      });
+  }, []);
+
+  const save = async (
+    update: () => Promise<PromptTemplates>,
+    successKey: TranslationKey
+  ) => {
+    try {
+      setIsSaving(true);
+      setTemplates(await update());
+      showSuccess(t(successKey));
+    } catch (error) {
+      console.error('Failed to save prompt templates:', error);
+      showError(
+        t('settings.ai.promptTemplates.saveError'),
+        error instanceof Error ? error.message : undefined
+      );
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <div className={className}>
+      <h3 className='text-lg font-semibold text-foreground mb-2 flex items-center gap-2'>
+        <FileText className='w-5 h-5' />
+        {t('settings.ai.promptTemplates.title')}
+      </h3>
+      <p className='text-xs text-foreground-600 mb-4'>
+        {t('settings.ai.promptTemplates.description')}
+      </p>
+
+      <div className='space-y-4'>
+        {TEMPLATE_KEYS.map(key => (
+          <Textarea
+            key={key}
+            label={t(`settings.ai.promptTemplates.${key}` as TranslationKey)}
+            description={t(
+              `settings.ai.promptTemplates.${key}Description` as TranslationKey
+            )}
+            labelPlacement='outside'
+            value={templates[key] ?? ''}
+            onValueChange={value =>
+              setTemplates(current => ({ ...current, [key]: value }))
+            }
+            placeholder={DEFAULT_PROMPT_TEMPLATES[key]}
+            minRows={key === 'systemPersona' ? 6 : 2}
+            maxRows={16}
+            classNames={{
+              input: 'text-sm font-mono',
+            }}
+          />
+        ))}
+
+        <div className='flex justify-end gap-2'>
+          <Button
+            size='sm'
+            variant='light'
+            startContent={<RotateCcw className='w-4 h-4' />}
+            isDisabled={isSaving}
+            onPress={() =>
+              save(
+                () => getPreferencesRepository().resetPromptTemplates(),
+                'settings.ai.promptTemplates.resetDone'
+              )
+            }
+          >
+            {t('settings.ai.promptTemplates.reset')}
+          </Button>
+          <Button
+            size='sm'
+            color='primary'
+            isLoading={isSaving}
+            onPress={() =>
+              save(
+                () =>
+                  getPreferencesRepository().updatePromptTemplates(templates),
+                'settings.ai.promptTemplates.saved'
+              )
+            }
+          >
+            {isSaving ? t('common.saving') : t('common.save')}
+          </Button>
+        </div>
+      </div>
+    </div>
+  );
+};
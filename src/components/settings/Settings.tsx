@@ -34,9 +34,21 @@ import { useToastContext } from '../../contexts/ToastContext';
 import { languages } from '../../i18n';
 import { DataManagement } from './DataManagement';
 import { LoggingSettings } from './LoggingSettings';
+import { PromptSettings } from './PromptSettings';
 import { SoundSettings } from './SoundSettings';
 import { useAI } from '../../contexts/AIContext';
 import { BuildInfo } from '../common';
+import { RolloverMode } from '../../types';
+
+const WEEKDAY_KEYS = [
+  'common.datePicker.dayNames.sun',
+  'common.datePicker.dayNames.mon',
+  'common.datePicker.dayNames.tue',
+  'common.datePicker.dayNames.wed',
+  'common.datePicker.dayNames.thu',
+  'common.datePicker.dayNames.fri',
+  'common.datePicker.dayNames.sat',
+] as const;
 
 interface SettingsProps {
   className?: string;
@@ -60,6 +72,7 @@ export const Settings: React.FC<SettingsProps> = ({
   const { showSuccess, showError, showWarning, showInfo } = useToastContext();
   const [activeTab, setActiveTab] = useState(initialTab);
   const [showApiKey, setShowApiKey] = useState(false);
+  const [newHoliday, setNewHoliday] = useState('');
   const [apiKeyValidationState, setApiKeyValidationState] = useState<{
     isValidating: boolean;
     isValid: boolean | null;
@@ -92,6 +105,39 @@ export const Settings: React.FC<SettingsProps> = ({
     updateNestedPreference(parentKey, childKey, value);
   };
 
+  const workingDays = preferences.workingHours.days ?? [1, 2, 3, 4, 5];
+  const holidays = preferences.workingHours.holidays ?? [];
+
+  const toggleWorkingDay = (day: number) => {
+    if (workingDays.includes(day)) {
+      // Keep at least one working day
+      if (workingDays.length > 1) {
+        handleNestedPreferenceChange(
+          'workingHours',
+          'days',
+          workingDays.filter(d => d !== day)
+        );
+      }
+    } else {
+      handleNestedPreferenceChange(
+        'workingHours',
+        'days',
+        [...workingDays, day].sort((a, b) => a - b)
+      );
+    }
+  };
+
+  const addHoliday = () => {
+    if (newHoliday && !holidays.includes(newHoliday)) {
+      handleNestedPreferenceChange(
+        'workingHours',
+        'holidays',
+        [...holidays, newHoliday].sort()
+      );
+    }
+    setNewHoliday('');
+  };
+
   // API Key validation function
   const validateApiKey = async (apiKey: string): Promise<boolean> => {
     if (!apiKey || apiKey.trim().length === 0) {
@@ -396,6 +442,120 @@ export const Settings: React.FC<SettingsProps> = ({
                       />
                     </div>
                   </div>
+
+                  <div className='mt-4'>
+                    <label className='text-sm font-medium text-foreground block mb-2'>
+                      {t('settings.general.workingDays')}
+                    </label>
+                    <div className='flex flex-wrap gap-2'>
+                      {WEEKDAY_KEYS.map((key, day) => (
+                        <Button
+                          key={key}
+                          size='sm'
+                          variant={
+                            workingDays.includes(day) ? 'solid' : 'flat'
+                          }
+                          color={
+                            workingDays.includes(day) ? 'primary' : 'default'
+                          }
+                          onPress={() => toggleWorkingDay(day)}
+                        >
+                          {t(key)}
+                        </Button>
+                      ))}
+                    </div>
+                  </div>
+
+                  <div className='mt-4'>
+                    <label className='text-sm font-medium text-foreground block mb-2'>
+                      {t('settings.general.holidays')}
+                    </label>
+                    <div className='flex gap-2'>
+                      <Input
+                        type='date'
+                        value={newHoliday}
+                        onChange={e => setNewHoliday(e.target.value)}
+                        size='sm'
+                        classNames={{
+                          input: 'text-foreground',
+                          inputWrapper:
+                            'bg-content2 border-divider data-[hover=true]:bg-content3 group-data-[focus=true]:bg-content2',
+                        }}
+                      />
+                      <Button
+                        size='sm'
+                        variant='flat'
+                        isDisabled={!newHoliday}
+                        onPress={addHoliday}
+                      >
+                        {t('settings.general.addHoliday')}
+                      </Button>
+                    </div>
+                    {holidays.length > 0 && (
+                      <div className='flex flex-wrap gap-2 mt-2'>
+                        {holidays.map(holiday => (
+                          <Chip
+                            key={holiday}
+                            size='sm'
+                            variant='flat'
+                            onClose={() =>
+                              handleNestedPreferenceChange(
+                                'workingHours',
+                                'holidays',
+                                holidays.filter(h => h !== holiday)
+                              )
+                            }
+                          >
+                            {holiday}
+                          </Chip>
+                        ))}
+                      </div>
+                    )}
+                  </div>
+
+                  <div className='flex items-center justify-between mt-4'>
+                    <div>
+                      <label className='text-sm font-medium text-foreground'>
+                        {t('settings.general.rollover')}
+                      </label>
+                      <p className='text-xs text-foreground-600'>
+                        {t('settings.general.rolloverDescription')}
+                      </p>
+                    </div>
+                    <Select
+                      selectedKeys={[
+                        preferences.taskSettings.rollover ?? 'off',
+                      ]}
+                      onSelectionChange={keys => {
+                        const rollover = Array.from(keys)[0] as RolloverMode;
+                        if (rollover) {
+                          handleNestedPreferenceChange(
+                            'taskSettings',
+                            'rollover',
+                            rollover
+                          );
+                        }
+                      }}
+                      className='w-40'
+                      size='sm'
+                      aria-label='Rollover selection'
+                      classNames={{
+                        trigger:
+                          'bg-content2 border-divider data-[hover=true]:bg-content3',
+                        value: 'text-foreground',
+                      }}
+                    >
+                      <SelectItem key='off'>
+                        {t('settings.general.rollover.off')}
+                      </SelectItem>
+                      <SelectItem key='today'>
+                        {t('settings.general.rollover.today')}
+                      </SelectItem>
+                      <SelectItem key='backlog'>
+                        {t('settings.general.rollover.backlog')}
+                      </SelectItem>
+                    </Select>
+                  </div>
                 </div>
 
                 <Divider className='bg-divider' />
@@ -629,6 +789,160 @@ export const Settings: React.FC<SettingsProps> = ({
                       />
                     </div>
 
+                    <div className='flex items-center justify-between'>
+                      <div>
+                        <label className='text-sm font-medium text-foreground'>
+                          {t('settings.ai.providerFallback')}
+                        </label>
+                        <p className='text-xs text-foreground-600'>
+                          {t('settings.ai.providerFallbackDescription')}
+                        </p>
+                      </div>
+                      <Switch
+                        isSelected={
+                          (preferences.aiSettings.fallbackChain?.length ?? 0) >
+                          0
+                        }
+                        onValueChange={checked =>
+                          handleNestedPreferenceChange(
+                            'aiSettings',
+                            'fallbackChain',
+                            checked ? ['gemini', 'ollama'] : []
+                          )
+                        }
+                        size='sm'
+                      />
+                    </div>
+
+                    <div>
+                      <label className='text-sm font-medium text-foreground block mb-2'>
+                        {t('settings.ai.monthlyBudget')}
+                      </label>
+                      <Input
+                        type='number'
+                        value={
+                          preferences.aiSettings.monthlyBudget?.toString() ?? ''
+                        }
+                        onChange={e => {
+                          const value = parseFloat(e.target.value);
+                          handleNestedPreferenceChange(
+                            'aiSettings',
+                            'monthlyBudget',
+                            value > 0 ? value : undefined
+                          );
+                        }}
+                        min={0}
+                        step={1}
+                        placeholder={t('settings.ai.monthlyBudgetPlaceholder')}
+                        startContent={
+                          <span className='text-sm text-foreground-500'>$</span>
+                        }
+                        size='sm'
+                        className='w-32'
+                        classNames={{
+                          input: 'text-foreground',
+                          inputWrapper:
+                            'bg-content2 border-divider data-[hover=true]:bg-content3 group-data-[focus=true]:bg-content2',
+                        }}
+                      />
+                      <p className='text-xs text-foreground-600 mt-1'>
+                        {t('settings.ai.monthlyBudgetDescription')}
+                      </p>
+                    </div>
+
+                    <div className='flex items-center justify-between'>
+                      <div>
+                        <label className='text-sm font-medium text-foreground'>
+                          {t('settings.ai.blockOverBudget')}
+                        </label>
+                        <p className='text-xs text-foreground-600'>
+                          {t('settings.ai.blockOverBudgetDescription')}
+                        </p>
+                      </div>
+                      <Switch
+                        isSelected={
+                          preferences.aiSettings.budgetAction === 'block'
+                        }
+                        isDisabled={!preferences.aiSettings.monthlyBudget}
+                        onValueChange={checked =>
+                          handleNestedPreferenceChange(
+                            'aiSettings',
+                            'budgetAction',
+                            checked ? 'block' : 'warn'
+                          )
+                        }
+                        size='sm'
+                      />
+                    </div>
+
+                    <div>
+                      <label className='text-sm font-medium text-foreground block mb-2'>
+                        {t('settings.ai.batteryThreshold')}
+                      </label>
+                      <Input
+                        type='number'
+                        value={
+                          preferences.aiSettings.batteryThreshold?.toString() ??
+                          ''
+                        }
+                        onChange={e => {
+                          const value = parseInt(e.target.value, 10);
+                          handleNestedPreferenceChange(
+                            'aiSettings',
+                            'batteryThreshold',
+                            value > 0 ? Math.min(value, 100) : undefined
+                          );
+                        }}
+                        min={0}
+                        max={100}
+                        step={5}
+                        placeholder={t(
+                          'settings.ai.batteryThresholdPlaceholder'
+                        )}
+                        endContent={
+                          <span className='text-sm text-foreground-500'>%</span>
+                        }
+                        size='sm'
+                        className='w-32'
+                        classNames={{
+                          input: 'text-foreground',
+                          inputWrapper:
+                            'bg-content2 border-divider data-[hover=true]:bg-content3 group-data-[focus=true]:bg-content2',
+                        }}
+                      />
+                      <p className='text-xs text-foreground-600 mt-1'>
+                        {t('settings.ai.batteryThresholdDescription')}
+                      </p>
+                    </div>
+
+                    <div>
+                      <label className='text-sm font-medium text-foreground block mb-2'>
+                        {t('settings.ai.batteryModel')}
+                      </label>
+                      <Input
+                        value={preferences.aiSettings.batteryModel ?? ''}
+                        onChange={e =>
+                          handleNestedPreferenceChange(
+                            'aiSettings',
+                            'batteryModel',
+                            e.target.value.trim() || undefined
+                          )
+                        }
+                        isDisabled={!preferences.aiSettings.batteryThreshold}
+                        placeholder={t('settings.ai.batteryModelPlaceholder')}
+                        size='sm'
+                        className='w-64'
+                        classNames={{
+                          input: 'text-foreground',
+                          inputWrapper:
+                            'bg-content2 border-divider data-[hover=true]:bg-content3 group-data-[focus=true]:bg-content2',
+                        }}
+                      />
+                      <p className='text-xs text-foreground-600 mt-1'>
+                        {t('settings.ai.batteryModelDescription')}
+                      </p>
+                    </div>
+
                     <div className='flex items-center justify-between'>
                       <div>
                         <label className='text-sm font-medium text-foreground'>
@@ -1385,6 +1699,10 @@ export const Settings: React.FC<SettingsProps> = ({
                   </Card>
                 </div>
 
+                {/* Prompt Templates */}
+                <Divider className='my-6' />
+                <PromptSettings />
+
                 {/* AI Interaction Logging */}
                 <Divider className='my-6' />
                 <LoggingSettings />
@@ -1464,6 +1782,40 @@ export const Settings: React.FC<SettingsProps> = ({
                         }}
                       />
                     </div>
+
+                    <div>
+                      <label className='text-sm font-medium text-foreground block mb-2'>
+                        {t('settings.idleThreshold')}:{' '}
+                        {preferences.breakPreferences.idleThreshold ?? 5}{' '}
+                        {t('time.minutes')}
+                      </label>
+                      <Input
+                        type='number'
+                        value={(
+                          preferences.breakPreferences.idleThreshold ?? 5
+                        ).toString()}
+                        onChange={e => {
+                          const value = parseInt(e.target.value) || 0;
+                          handleNestedPreferenceChange(
+                            'breakPreferences',
+                            'idleThreshold',
+                            Math.max(0, Math.min(120, value))
+                          );
+                        }}
+                        min={0}
+                        max={120}
+                        size='sm'
+                        className='w-24'
+                        classNames={{
+                          input: 'text-foreground',
+                          inputWrapper:
+                            'bg-content2 border-divider data-[hover=true]:bg-content3 group-data-[focus=true]:bg-content2',
+                        }}
+                      />
+                      <p className='text-xs text-foreground-500 mt-1'>
+                        {t('settings.idleThresholdDescription')}
+                      </p>
+                    </div>
                   </div>
                 </div>
               </div>
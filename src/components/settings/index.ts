@@ -2,4 +2,5 @@ export { Settings } from './Settings';
 export { DataManagement } from './DataManagement';
 export { ModelSelectionCard } from './ModelSelectionCard';
 export { LoggingSettings } from './LoggingSettings';
+export { PromptSettings } from './PromptSettings';
 export { SoundSettings } from './SoundSettings';
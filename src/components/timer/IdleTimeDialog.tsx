@@ -0,0 +1,32 @@
+import { ConfirmationDialog } from '../common/ConfirmationDialog';
+import { useTimerContext } from '../../contexts/TimerContext';
+import { useTranslation } from '../../hooks/useTranslation';
+
+/**
+ * Asks whether time spent away from the computer during a session counts
+ */
+export function IdleTimeDialog() {
+  const { t } = useTranslation();
+  const { idleSegment, keepIdleTime, discardIdleTime } = useTimerContext();
+
+  const idleMinutes = idleSegment
+    ? Math.round(
+        ((idleSegment.endedAt ?? new Date()).getTime() -
+          idleSegment.startedAt.getTime()) /
+          60000
+      )
+    : 0;
+
+  return (
+    <ConfirmationDialog
+      isOpen={idleSegment !== null}
+      onClose={keepIdleTime}
+      onConfirm={discardIdleTime}
+      title={t('timer.idle.title')}
+      message={t('timer.idle.message', { minutes: idleMinutes })}
+      confirmText={t('timer.idle.discard')}
+      cancelText={t('timer.idle.keep')}
+      variant='warning'
+    />
+  );
+}
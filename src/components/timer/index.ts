@@ -1,3 +1,4 @@
 export { useSimpleTimer } from '../../hooks/useSimpleTimer';
 export { SessionHistory, SessionHistoryModal } from './SessionHistory';
 export type { TimerSession, CompletedSession } from '../../types';
+export { IdleTimeDialog } from './IdleTimeDialog';
@@ -10,8 +10,13 @@ import {
   getModelManager,
   ModelType,
   ModelConfig,
+  AvailableModel,
+  ModelRequestOptions,
 } from '../services/ai/ModelManager';
-import { AIServiceInterface } from '../services/ai/AIServiceInterface';
+import {
+  AIServiceInterface,
+  GenerationCancelledError,
+} from '../services/ai/AIServiceInterface';
 import { LogRetentionManager } from '../services/ai/LogRetentionManager';
 import { LogStorageService } from '../services/database/repositories/LogStorageService';
 import { LoggingConfigService } from '../services/database/repositories/LoggingConfigService';
@@ -69,8 +74,10 @@ interface AIContextType {
   suggestions: AISuggestion[];
   sendMessage: (
     message: string,
-    context: AppContext
+    context: AppContext,
+    options?: ModelRequestOptions
   ) => Promise<AIResponse | null>;
+  cancelRequest: (requestId: string) => boolean;
   switchModel: (modelType: ModelType, config?: ModelConfig) => Promise<void>;
   clearConversation: () => void;
   dismissSuggestion: (suggestionId: string) => void;
@@ -80,6 +87,7 @@ interface AIContextType {
   reinitializeAI: () => void;
   getModelStatus: () => import('../services/ai/AIServiceInterface').ModelStatus;
   getAvailableModels: () => ModelType[];
+  listAvailableModels: () => Promise<AvailableModel[]>;
   // Enhanced service management
   initializeService: (type: ModelType, config?: ModelConfig) => Promise<void>;
   getServiceStatus: (
@@ -339,13 +347,31 @@ export function AIProvider({ children }: AIProviderProps) {
         localStorage.getItem('kira_api_key') || // Legacy fallback
         localStorage.getItem('kirapilot-gemini-api-key'); // Legacy fallback
 
-      // Always use Gemini as the only available model
       const config: ModelConfig = {
         type: 'gemini',
         apiKey: apiKey || undefined,
       };
 
-      await manager.switchModel('gemini', config);
+      // Use Ollama when chosen, or when it is running and Gemini has no key
+      const useOllama =
+        preferences.modelType === 'ollama' ||
+        (!preferences.modelType &&
+          !apiKey &&
+          (await manager.detectOllama(preferences.ollamaUrl)));
+      if (useOllama) {
+        try {
+          await manager.switchModel('ollama', {
+            type: 'ollama',
+            baseUrl: preferences.ollamaUrl,
+            model: preferences.ollamaModel,
+          });
+        } catch (ollamaError) {
+          console.warn('Ollama unavailable, using Gemini:', ollamaError);
+          await manager.switchModel('gemini', config);
+        }
+      } else {
+        await manager.switchModel('gemini', config);
+      }
 
       const service = manager.getCurrentService();
       setAiService(service);
@@ -466,7 +492,8 @@ export function AIProvider({ children }: AIProviderProps) {
 
   const sendMessage = async (
     message: string,
-    context: AppContext
+    context: AppContext,
+    options?: ModelRequestOptions
   ): Promise<AIResponse | null> => {
     if (!modelManager) {
       setError('AI service not initialized');
@@ -492,7 +519,11 @@ export function AIProvider({ children }: AIProviderProps) {
     setError(null);
 
     try {
-      const response = await modelManager.processMessage(message, context);
+      const response = await modelManager.processMessage(
+        message,
+        context,
+        options
+      );
 
       // Apply response style preferences
       if (aiPreferences.responseStyle === 'concise') {
@@ -534,6 +565,11 @@ export function AIProvider({ children }: AIProviderProps) {
 
       return response;
     } catch (err) {
+      // Whoever cancelled the request handles it
+      if (err instanceof GenerationCancelledError) {
+        throw err;
+      }
+
       console.error('Error sending message to AI:', err);
 
       // Enhanced error handling with service status propagation
@@ -555,7 +591,7 @@ export function AIProvider({ children }: AIProviderProps) {
           if (modelManager.isReady()) {
             setError(null);
             // Retry the message
-            return await sendMessage(message, context);
+            return await sendMessage(message, context, options);
           }
         } catch (reinitErr) {
           console.error('Failed to reinitialize AI service:', reinitErr);
@@ -570,6 +606,10 @@ export function AIProvider({ children }: AIProviderProps) {
     }
   };
 
+  const cancelRequest = (requestId: string): boolean => {
+    return modelManager?.cancelRequest(requestId) ?? false;
+  };
+
   const switchModel = async (modelType: ModelType, config?: ModelConfig) => {
     if (!modelManager) {
       setError('Model manager not initialized');
@@ -593,6 +633,8 @@ export function AIProvider({ children }: AIProviderProps) {
         modelConfig = {
           type: modelType,
           apiKey: apiKey || undefined,
+          baseUrl: preferences.ollamaUrl,
+          model: preferences.ollamaModel,
         };
       }
 
@@ -689,6 +731,10 @@ export function AIProvider({ children }: AIProviderProps) {
     return modelManager.getAvailableModels();
   };
 
+  const listAvailableModels = async (): Promise<AvailableModel[]> => {
+    return (await modelManager?.listAvailableModels()) ?? [];
+  };
+
   const initializeService = async (type: ModelType, config?: ModelConfig) => {
     if (!modelManager) {
       setError('Model manager not initialized');
@@ -712,6 +758,8 @@ export function AIProvider({ children }: AIProviderProps) {
         serviceConfig = {
           type: type,
           apiKey: apiKey || undefined,
+          baseUrl: preferences.ollamaUrl,
+          model: preferences.ollamaModel,
         };
       }
 
@@ -894,6 +942,7 @@ export function AIProvider({ children }: AIProviderProps) {
     conversations,
     suggestions: suggestions.filter(s => !s.dismissedAt), // Only show non-dismissed suggestions
     sendMessage,
+    cancelRequest,
     switchModel,
     clearConversation,
     dismissSuggestion,
@@ -903,6 +952,7 @@ export function AIProvider({ children }: AIProviderProps) {
     reinitializeAI,
     getModelStatus,
     getAvailableModels,
+    listAvailableModels,
     // Enhanced service management
     initializeService,
     getServiceStatus,
@@ -6,6 +6,7 @@ import React, {
   ReactNode,
 } from 'react';
 import { UserPreferences, DistractionLevel, Priority } from '../types';
+import { getPreferencesRepository } from '../services/database/repositories';
 
 interface SettingsContextType {
   preferences: UserPreferences;
@@ -28,11 +29,14 @@ const defaultPreferences: UserPreferences = {
   workingHours: {
     start: '09:00',
     end: '17:00',
+    days: [1, 2, 3, 4, 5],
+    holidays: [],
   },
   breakPreferences: {
     shortBreakDuration: 5,
     longBreakDuration: 15,
     breakInterval: 25,
+    idleThreshold: 5,
   },
   focusPreferences: {
     defaultDuration: 25,
@@ -72,6 +76,7 @@ const defaultPreferences: UserPreferences = {
     weekStartDay: 1, // Monday
     showCompletedTasks: true,
     compactView: false,
+    rollover: 'off',
   },
   migrationSettings: {
     enabled: true,
@@ -110,6 +115,31 @@ export const SettingsProvider: React.FC<SettingsProviderProps> = ({
     loadPreferences();
   }, []);
 
+  // Reminders, periodic tasks and reports follow the working hours stored
+  // in the backend
+  useEffect(() => {
+    if (isLoading) {
+      return;
+    }
+    getPreferencesRepository()
+      .saveWorkingHours(preferences.workingHours)
+      .catch(error => {
+        console.error('Failed to save working hours:', error);
+      });
+  }, [isLoading, preferences.workingHours]);
+
+  // Unfinished tasks are rolled over by the backend
+  useEffect(() => {
+    if (isLoading) {
+      return;
+    }
+    getPreferencesRepository()
+      .saveRolloverMode(preferences.taskSettings.rollover ?? 'off')
+      .catch(error => {
+        console.error('Failed to save rollover mode:', error);
+      });
+  }, [isLoading, preferences.taskSettings.rollover]);
+
   const loadPreferences = async () => {
     try {
       setIsLoading(true);
@@ -203,6 +233,9 @@ export const SettingsProvider: React.FC<SettingsProviderProps> = ({
       if (startTime >= endTime) {
         throw new Error('End time must be after start time');
       }
+      if (prefs.workingHours.days?.length === 0) {
+        throw new Error('Select at least one working day');
+      }
 
       // Validate break preferences
       if (
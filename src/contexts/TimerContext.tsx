@@ -1,8 +1,11 @@
 import React, { createContext, useContext } from 'react';
-import { Task } from '../types';
+import { IdleSegment, Task } from '../types';
 import { useTimerWithPreferences } from '../hooks/useTimerWithPreferences';
 import { useUserPreferences } from '../hooks/useUserPreferences';
-import { getTimeTrackingRepository } from '../services/database/repositories';
+import {
+  getTaskRepository,
+  getTimeTrackingRepository,
+} from '../services/database/repositories';
 
 interface TaskTimerProps {
   onTimerStart: () => void;
@@ -22,6 +25,7 @@ interface TimerContextType {
   activeTask?: Task;
   activeSessionId?: string;
   isInitialized: boolean;
+  idleSegment: IdleSegment | null; // Idle time waiting for keep or discard
 
   // Actions
   startTimer: (task: Task) => void;
@@ -29,6 +33,8 @@ interface TimerContextType {
   stopTimer: () => void;
   getTaskTimerProps: (task: Task) => TaskTimerProps;
   formatElapsedTime: (milliseconds?: number) => string;
+  keepIdleTime: () => void;
+  discardIdleTime: () => Promise<void>;
 
   // Legacy compatibility
   handleTimerStart: (task: Task) => void;
@@ -48,7 +54,10 @@ export const TimerProvider: React.FC<TimerProviderProps> = ({ children }) => {
     string | undefined
   >();
   const [isInitialized, setIsInitialized] = React.useState(false);
-  const { breakReminders } = useUserPreferences();
+  const [idleSegment, setIdleSegment] = React.useState<IdleSegment | null>(
+    null
+  );
+  const { breakReminders, idleThreshold } = useUserPreferences();
   const timeRepo = getTimeTrackingRepository();
 
   // Session recovery and cleanup on startup
@@ -128,9 +137,10 @@ export const TimerProvider: React.FC<TimerProviderProps> = ({ children }) => {
       console.log('Timer started for:', task.title);
       setActiveTask(task);
 
-      // Create a database session for this timer (fire and forget)
+      // Create a database session for this timer (fire and forget), taking
+      // over from any session still running
       timeRepo
-        .startSession(task.id)
+        .startSession(task.id, undefined, true)
         .then(session => {
           setActiveSessionId(session.id);
           console.log('Database session created:', session.id);
@@ -164,6 +174,17 @@ export const TimerProvider: React.FC<TimerProviderProps> = ({ children }) => {
           });
       }
 
+      // Add the tracked minutes in the backend rather than writing a total
+      // computed here, which could overwrite time added elsewhere meanwhile
+      const minutes = Math.round(elapsedTime / 60000);
+      if (minutes > 0) {
+        getTaskRepository()
+          .addActualTime(task.id, minutes)
+          .catch(error => {
+            console.error('Failed to add actual time to task:', error);
+          });
+      }
+
       // Clear state
       setActiveSessionId(undefined);
       setTimeout(() => setActiveTask(undefined), 2000);
@@ -184,6 +205,42 @@ export const TimerProvider: React.FC<TimerProviderProps> = ({ children }) => {
     },
   });
 
+  // Keep the backend idle monitor in step with the preference
+  React.useEffect(() => {
+    timeRepo.setIdleThreshold(idleThreshold).catch(error => {
+      console.error('Failed to set idle threshold:', error);
+    });
+  }, [timeRepo, idleThreshold]);
+
+  // Ask about idle time only while its session is still being timed
+  const isTimerRunning = timer.isRunning;
+  React.useEffect(() => {
+    const unlisten = timeRepo.onIdleEnded(segment => {
+      if (isTimerRunning && segment.sessionId === activeSessionId) {
+        setIdleSegment(segment);
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [timeRepo, activeSessionId, isTimerRunning]);
+
+  const keepIdleTime = React.useCallback(() => setIdleSegment(null), []);
+
+  const discardIdleTime = React.useCallback(async () => {
+    if (!idleSegment) {
+      return;
+    }
+    setIdleSegment(null);
+    try {
+      await timeRepo.discardIdleTime(idleSegment);
+      const endedAt = idleSegment.endedAt ?? new Date();
+      timer.discardTime(endedAt.getTime() - idleSegment.startedAt.getTime());
+    } catch (error) {
+      console.error('Failed to discard idle time:', error);
+    }
+  }, [idleSegment, timeRepo, timer]);
+
   // Custom pause timer function with database integration
   const pauseTimerWithDB = React.useCallback(() => {
     // Pause the UI timer
@@ -212,7 +269,7 @@ export const TimerProvider: React.FC<TimerProviderProps> = ({ children }) => {
 
         // Resume the database session
         timeRepo
-          .resumeSession(activeSessionId)
+          .resumeSession(activeSessionId, true)
           .then(() => {
             console.log('Database session resumed:', activeSessionId);
           })
@@ -236,6 +293,7 @@ export const TimerProvider: React.FC<TimerProviderProps> = ({ children }) => {
     activeTask,
     activeSessionId,
     isInitialized,
+    idleSegment,
 
     // Actions
     startTimer: startTimerWithDB,
@@ -243,6 +301,8 @@ export const TimerProvider: React.FC<TimerProviderProps> = ({ children }) => {
     stopTimer: timer.stopTimer,
     getTaskTimerProps: timer.getTaskTimerProps,
     formatElapsedTime: timer.formatElapsedTime,
+    keepIdleTime,
+    discardIdleTime,
 
     // Legacy compatibility
     handleTimerStart: startTimerWithDB,
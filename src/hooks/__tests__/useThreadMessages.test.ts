@@ -5,6 +5,7 @@ import { useAI } from '../../contexts/AIContext';
 import { TaskService } from '../../services/database/repositories/TaskService';
 import { ThreadMessage, ThreadAssignment } from '../../types/thread';
 import { AIResponse, AppContext } from '../../types';
+import { GenerationCancelledError } from '../../services/ai/AIServiceInterface';
 
 // Mock the dependencies
 jest.mock('../../services/database/repositories/ThreadService');
@@ -22,12 +23,14 @@ describe('useThreadMessages', () => {
   const mockTaskId = 'task-456';
 
   const mockThreadServiceInstance = {
+    findById: jest.fn(),
     findMessages: jest.fn(),
     createMessage: jest.fn(),
   };
 
   const mockAIContext = {
     sendMessage: jest.fn(),
+    cancelRequest: jest.fn(),
     isLoading: false,
     modelManager: null,
     aiService: null,
@@ -192,7 +195,8 @@ describe('useThreadMessages', () => {
         'Test message',
         expect.objectContaining({
           currentTask: mockTask,
-        })
+        }),
+        { requestId: expect.any(String) }
       );
     });
 
@@ -221,6 +225,35 @@ describe('useThreadMessages', () => {
       expect(aiMessage).toBeNull();
       expect(result.current.error?.message).toBe('Failed to get AI response');
     });
+
+    it('should keep a cancelled response with the tool calls it made', async () => {
+      const toolCalls = [
+        { tool: 'create_task', arguments: { title: 'Draft' }, success: true },
+      ];
+      const { result } = renderHook(() => useThreadMessages(mockThreadId));
+      mockAIContext.sendMessage.mockImplementationOnce(
+        async (_message, _context, options) => {
+          result.current.cancelSending();
+          throw new GenerationCancelledError(options.requestId, toolCalls);
+        }
+      );
+
+      await act(async () => {
+        await result.current.sendMessage('Test message');
+      });
+
+      expect(mockAIContext.cancelRequest).toHaveBeenCalledWith(
+        mockAIContext.sendMessage.mock.calls[0][2].requestId
+      );
+      expect(mockThreadServiceInstance.createMessage).toHaveBeenLastCalledWith({
+        threadId: mockThreadId,
+        type: 'assistant',
+        content: 'Response cancelled.',
+        actionResults: { toolCalls, createdEntities: [], links: [] },
+      });
+      expect(result.current.error).toBeNull();
+      expect(result.current.isSending).toBe(false);
+    });
   });
 
   describe('utility functions', () => {
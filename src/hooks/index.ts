@@ -1,6 +1,7 @@
 export { useAutoScroll } from './useAutoScroll';
 export { useClipboard } from './useClipboard';
 export { useDatabase } from './useDatabase';
+export { useDeepLinks } from './useDeepLinks';
 export { useNotifications } from './useNotifications';
 export { useToast, useToastHelpers } from './useToast';
 export { usePrivacyAware } from './usePrivacyAware';
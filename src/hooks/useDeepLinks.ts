@@ -0,0 +1,56 @@
+import { useEffect, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { DeepLinkEvent } from '../types';
+
+function transformDeepLink(payload: Record<string, unknown>): DeepLinkEvent {
+  return {
+    action: payload.action as DeepLinkEvent['action'],
+    taskId: payload.task_id as string,
+  };
+}
+
+/**
+ * Call `onLink` for every kirapilot:// link the backend routes, including
+ * the one the app was launched with
+ */
+export function useDeepLinks(onLink: (link: DeepLinkEvent) => void) {
+  // Keep the latest callback without re-subscribing on every render
+  const onLinkRef = useRef(onLink);
+  onLinkRef.current = onLink;
+
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+
+    const setup = async () => {
+      try {
+        const stop = await listen<Record<string, unknown>>(
+          'deep-link',
+          event => onLinkRef.current(transformDeepLink(event.payload))
+        );
+        if (cancelled) {
+          stop();
+          return;
+        }
+        unlisten = stop;
+
+        const pending = await invoke<Record<string, unknown>[]>(
+          'get_pending_deep_links'
+        );
+        pending.forEach(link => onLinkRef.current(transformDeepLink(link)));
+      } catch (error) {
+        console.error('Failed to listen for deep links:', error);
+      }
+    };
+
+    setup();
+
+    return () => {
+      cancelled = true;
+      if (unlisten) {
+        unlisten();
+      }
+    };
+  }, []);
+}
@@ -167,6 +167,15 @@ export function useSimpleTimer(options: UseSimpleTimerOptions = {}) {
     }
   }, [state, enableNotifications]);
 
+  // Take time back off the timer, e.g. idle time the user chose to discard
+  const discardTime = useCallback((milliseconds: number) => {
+    pausedTimeRef.current -= milliseconds;
+    setState(prev => ({
+      ...prev,
+      elapsedTime: Math.max(0, prev.elapsedTime - milliseconds),
+    }));
+  }, []);
+
   const stopTimer = useCallback(() => {
     if (!state.activeTaskId) {
       return;
@@ -258,6 +267,7 @@ export function useSimpleTimer(options: UseSimpleTimerOptions = {}) {
     startTimer,
     pauseTimer,
     stopTimer,
+    discardTime,
     getTaskTimerProps,
     formatElapsedTime,
   };
@@ -1,9 +1,12 @@
-import { useState, useCallback, useEffect } from 'react';
+import { useState, useCallback, useEffect, useRef } from 'react';
 import { ThreadService } from '../services/database/repositories/ThreadService';
 import {
+  Thread,
   ThreadMessage,
   CreateThreadMessageRequest,
   ThreadAssignment,
+  MessageActionResults,
+  CreatedEntity,
 } from '../types/thread';
 import {
   AIResponse,
@@ -11,10 +14,16 @@ import {
   ToolExecution,
   AIAction,
   Task,
+  ReActStep,
 } from '../types';
 import { UserFeedback } from '../types/aiLogging';
 import { useAI } from '../contexts/AIContext';
 import { TaskService } from '../services/database/repositories/TaskService';
+import { getConversationMemoryService } from '../services/ai/ConversationMemoryService';
+import { GenerationCancelledError } from '../services/ai/AIServiceInterface';
+import { AvailableModel } from '../services/ai/ModelManager';
+import { onReActStep } from '../services/ai/ReActSteps';
+import { onResponseChunk } from '../services/ai/ResponseStream';
 import {
   processKiraError,
   shouldAutoRetry,
@@ -30,12 +39,17 @@ interface UseThreadMessagesState {
   isRegenerating: boolean;
   error: KiraError | null;
   retryCount: number;
+  // Latest step of the ReAct chain answering the message being sent
+  currentStep: ReActStep | null;
+  // Text of the answer streamed so far for the message being sent
+  streamingText: string;
 }
 
 interface UseThreadMessagesReturn extends UseThreadMessagesState {
   sendMessage: (
     message: string,
-    threadAssignment?: ThreadAssignment
+    threadAssignment?: ThreadAssignment,
+    model?: AvailableModel
   ) => Promise<ThreadMessage | null>;
   loadMessages: (threadId: string) => Promise<void>;
   clearMessages: () => void;
@@ -49,8 +63,13 @@ interface UseThreadMessagesReturn extends UseThreadMessagesState {
     messageId: string,
     threadAssignment?: ThreadAssignment
   ) => Promise<ThreadMessage | null>;
+  // Stop the response being generated; true if one was running
+  cancelSending: () => boolean;
 }
 
+// Stored as the reply of a response cancelled while it was generated
+const CANCELLED_RESPONSE_CONTENT = 'Response cancelled.';
+
 /**
  * Hook for managing thread message operations
  * Handles sending messages, receiving AI responses, and message persistence
@@ -64,15 +83,84 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
     isRegenerating: false,
     error: null,
     retryCount: 0,
+    currentStep: null,
+    streamingText: '',
   });
 
   const threadService = new ThreadService();
   const taskService = new TaskService();
+  const conversationMemory = getConversationMemoryService();
   const {
+    aiService,
     sendMessage: sendAIMessage,
+    cancelRequest: cancelAIRequest,
     isLoading: aiLoading,
     submitFeedback: submitAIFeedback,
+    modelManager,
   } = useAI();
+  const activeRequestRef = useRef<string | null>(null);
+
+  // Follow the steps of the chain answering the message being sent
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+
+    onReActStep(step => {
+      if (step.chainId === activeRequestRef.current) {
+        setState(prev => ({ ...prev, currentStep: step }));
+      }
+    })
+      .then(stop => {
+        if (cancelled) {
+          stop();
+        } else {
+          unlisten = stop;
+        }
+      })
+      .catch(error => {
+        console.warn('Failed to listen for ReAct steps:', error);
+      });
+
+    return () => {
+      cancelled = true;
+      if (unlisten) {
+        unlisten();
+      }
+    };
+  }, []);
+
+  // Show the answer to the message being sent as it is written
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+
+    onResponseChunk(chunk => {
+      if (chunk.requestId === activeRequestRef.current) {
+        setState(prev => ({
+          ...prev,
+          streamingText:
+            chunk.kind === 'reset' ? '' : prev.streamingText + chunk.text,
+        }));
+      }
+    })
+      .then(stop => {
+        if (cancelled) {
+          stop();
+        } else {
+          unlisten = stop;
+        }
+      })
+      .catch(error => {
+        console.warn('Failed to listen for response chunks:', error);
+      });
+
+    return () => {
+      cancelled = true;
+      if (unlisten) {
+        unlisten();
+      }
+    };
+  }, []);
 
   const [lastOperation, setLastOperation] = useState<
     (() => Promise<void>) | null
@@ -722,6 +810,40 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
     []
   );
 
+  /**
+   * Record what the actions did: the tools run, the tasks created and links
+   * that open them
+   */
+  const buildActionResults = useCallback(
+    (actions: AIAction[] = []): MessageActionResults => {
+      const createdEntities: CreatedEntity[] = actions
+        .filter(
+          action =>
+            action.type === 'CREATE_TASK' &&
+            typeof action.parameters.id === 'string'
+        )
+        .map(action => ({
+          entityType: 'task',
+          id: action.parameters.id as string,
+          title: action.parameters.title as string | undefined,
+        }));
+
+      return {
+        toolCalls: actions.map(action => ({
+          tool: action.type.toLowerCase(),
+          arguments: action.parameters,
+          success: true,
+        })),
+        createdEntities,
+        links: createdEntities.map(entity => ({
+          label: entity.title ?? entity.id,
+          url: `kirapilot://task/${entity.id}`,
+        })),
+      };
+    },
+    []
+  );
+
   /**
    * Map AI action to tool execution result
    */
@@ -906,12 +1028,79 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
   }, []);
 
   /**
-   * Send a message and get AI response with thread assignment context
+   * Fold the thread's older messages into its summary once enough piled up,
+   * if the AI service can summarize. While a local model saves power on
+   * battery this waits for a message sent later.
+   */
+  const summarizeThread = useCallback(
+    async (thread: Thread): Promise<void> => {
+      const summarize = aiService?.summarizeConversation?.bind(aiService);
+      if (!summarize) {
+        return;
+      }
+      try {
+        if (await modelManager?.shouldDeferBackgroundWork()) {
+          return;
+        }
+        await conversationMemory.summarizeIfNeeded(thread, summarize);
+      } catch (error) {
+        console.warn('Failed to summarize thread:', error);
+      }
+    },
+    [aiService, modelManager, conversationMemory]
+  );
+
+  /**
+   * Keep a cancelled response in the thread, with the tool calls it made
+   * before it stopped
+   */
+  const saveCancelledResponse = useCallback(
+    async (
+      targetThreadId: string,
+      cancelled: GenerationCancelledError
+    ): Promise<ThreadMessage | null> => {
+      try {
+        const message = await threadService.createMessage({
+          threadId: targetThreadId,
+          type: 'assistant',
+          content: CANCELLED_RESPONSE_CONTENT,
+          actionResults: {
+            toolCalls: cancelled.toolCalls,
+            createdEntities: [],
+            links: [],
+          },
+        });
+        setState(prev => ({
+          ...prev,
+          messages: [...prev.messages, message],
+          retryCount: 0,
+        }));
+        return message;
+      } catch (error) {
+        console.error('Failed to save cancelled response:', error);
+        return null;
+      }
+    },
+    [threadService]
+  );
+
+  /**
+   * Stop the response being generated for the last message sent
+   */
+  const cancelSending = useCallback((): boolean => {
+    const requestId = activeRequestRef.current;
+    return requestId ? cancelAIRequest(requestId) : false;
+  }, [cancelAIRequest]);
+
+  /**
+   * Send a message and get AI response with thread assignment context,
+   * optionally answered by a model picked for this message
    */
   const sendMessage = useCallback(
     async (
       message: string,
-      threadAssignment?: ThreadAssignment
+      threadAssignment?: ThreadAssignment,
+      model?: AvailableModel
     ): Promise<ThreadMessage | null> => {
       if (!threadId) {
         const error = processKiraError(
@@ -951,10 +1140,26 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
           threadAssignment
         );
 
-        // Send message to AI service with enhanced context
+        // Answer as the thread's persona, if one was chosen
+        const thread = await threadService.findById(threadId);
+        enhancedAppContext.personaId = thread?.personaId;
+
+        // Carry over the thread's summary and the messages after it
+        if (thread) {
+          enhancedAppContext.conversation = await conversationMemory.loadMemory(
+            thread,
+            userMessage.id
+          );
+        }
+
+        // Send message to AI service with enhanced context, under an ID it
+        // can be cancelled by
+        const requestId = crypto.randomUUID();
+        activeRequestRef.current = requestId;
         const aiResponse: AIResponse | null = await sendAIMessage(
           message,
-          enhancedAppContext
+          enhancedAppContext,
+          { requestId, provider: model?.provider, model: model?.name }
         );
 
         if (aiResponse) {
@@ -992,6 +1197,7 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
             actions: aiResponse.actions,
             suggestions: aiResponse.suggestions,
             toolExecutions,
+            actionResults: buildActionResults(aiResponse.actions),
           };
 
           const aiMessage = await threadService.createMessage(aiMessageRequest);
@@ -1003,23 +1209,37 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
             retryCount: 0,
           }));
 
+          // Keep the summary up to date without holding up the reply
+          if (thread) {
+            void summarizeThread(thread);
+          }
+
           return aiMessage;
         } else {
           throw new Error('Failed to get AI response');
         }
       } catch (error) {
+        if (error instanceof GenerationCancelledError) {
+          return await saveCancelledResponse(threadId, error);
+        }
+
         const kiraError = processKiraError(error as Error, 'message_send');
         setError(kiraError);
         console.error('Kira message_send error:', error);
         return null;
       } finally {
+        activeRequestRef.current = null;
+        setState(prev => ({ ...prev, currentStep: null, streamingText: '' }));
         setSending(false);
       }
     },
     [
       threadId,
       threadService,
+      conversationMemory,
       sendAIMessage,
+      summarizeThread,
+      saveCancelledResponse,
       buildAppContext,
       setSending,
       setError,
@@ -1138,6 +1358,18 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
           threadAssignment
         );
 
+        // Answer as the thread's persona, if one was chosen
+        const thread = await threadService.findById(threadId);
+        enhancedAppContext.personaId = thread?.personaId;
+
+        // Carry over the thread's summary and the messages after it
+        if (thread) {
+          enhancedAppContext.conversation = await conversationMemory.loadMemory(
+            thread,
+            userMessage.id
+          );
+        }
+
         // Send the original user message to AI service again
         const aiResponse: AIResponse | null = await sendAIMessage(
           userMessage.content,
@@ -1179,6 +1411,7 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
             actions: aiResponse.actions,
             suggestions: aiResponse.suggestions,
             toolExecutions,
+            actionResults: buildActionResults(aiResponse.actions),
           };
 
           const newAiMessage =
@@ -1221,6 +1454,7 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
       threadId,
       state.messages,
       threadService,
+      conversationMemory,
       sendAIMessage,
       buildAppContext,
       setRegenerating,
@@ -1245,6 +1479,8 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
     isRegenerating: state.isRegenerating,
     error: state.error,
     retryCount: state.retryCount,
+    currentStep: state.currentStep,
+    streamingText: state.streamingText,
     sendMessage,
     loadMessages,
     clearMessages,
@@ -1254,5 +1490,6 @@ export function useThreadMessages(threadId?: string): UseThreadMessagesReturn {
     // Enhanced AI integration
     submitFeedback,
     regenerateResponse,
+    cancelSending,
   };
 }
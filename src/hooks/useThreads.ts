@@ -37,6 +37,10 @@ interface UseThreadsReturn extends UseThreadsState {
     threadId: string,
     assignment: ThreadAssignment
   ) => Promise<Thread | null>;
+  setThreadPersona: (
+    threadId: string,
+    personaId: string | null
+  ) => Promise<Thread | null>;
   refreshThreads: () => Promise<void>;
   clearError: () => void;
   clearActiveThread: () => void;
@@ -333,6 +337,48 @@ export function useThreads(): UseThreadsReturn {
     [threadService, setUpdating, setError]
   );
 
+  /**
+   * Choose the persona a thread answers as, or null for plain Kira
+   */
+  const setThreadPersona = useCallback(
+    async (
+      threadId: string,
+      personaId: string | null
+    ): Promise<Thread | null> => {
+      try {
+        setUpdating(true);
+        setError(null);
+
+        const updatedThread = await threadService.setPersona(
+          threadId,
+          personaId
+        );
+
+        setState(prev => ({
+          ...prev,
+          threads: prev.threads.map(thread =>
+            thread.id === threadId ? updatedThread : thread
+          ),
+          activeThread:
+            prev.activeThread?.id === threadId
+              ? updatedThread
+              : prev.activeThread,
+          retryCount: 0,
+        }));
+
+        return updatedThread;
+      } catch (error) {
+        const kiraError = processKiraError(error as Error, 'thread_update');
+        setError(kiraError);
+        console.error('Kira thread_update error:', error);
+        return null;
+      } finally {
+        setUpdating(false);
+      }
+    },
+    [threadService, setUpdating, setError]
+  );
+
   // Load threads on mount - use ref to avoid dependency issues
   const initialLoadRef = useRef(false);
   useEffect(() => {
@@ -356,6 +402,7 @@ export function useThreads(): UseThreadsReturn {
     deleteThread,
     updateThread,
     assignThread,
+    setThreadPersona,
     refreshThreads,
     clearError,
     clearActiveThread,
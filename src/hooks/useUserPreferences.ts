@@ -27,6 +27,7 @@ export const useUserPreferences = () => {
     breakInterval: preferences.breakPreferences.breakInterval,
     shortBreakDuration: preferences.breakPreferences.shortBreakDuration,
     longBreakDuration: preferences.breakPreferences.longBreakDuration,
+    idleThreshold: preferences.breakPreferences.idleThreshold ?? 5,
 
     // AI settings
     aiConversationHistory: preferences.aiSettings.conversationHistory,
@@ -122,7 +122,12 @@ export const de = {
   'ai.model.information': 'Modellinformationen',
   'ai.model.local': 'Lokales Modell',
   'ai.model.name': 'Name',
+  'ai.model.ollama': 'Ollama (lokal)',
   'ai.model.size': 'Größe',
+  'ai.model.status.batteryDeferred':
+    'Akku bei {percent}%: lokale Anfragen werden zurückgestellt',
+  'ai.model.status.batterySmallerModel':
+    'Akku bei {percent}%: Antworten mit {model}',
   'ai.model.status.downloading': 'Wird heruntergeladen... {progress}%',
   'ai.model.status.error': 'Fehler',
   'ai.model.status.loading': 'Wird geladen...',
@@ -363,6 +368,8 @@ export const de = {
   'kira.chat.conversationStarted': 'Gespräch gestartet',
   'kira.chat.conversationUnarchived': 'Gespräch wiederhergestellt',
   'kira.chat.copyMessage': 'Nachricht kopieren',
+  'kira.chat.defaultModel': 'Standardmodell',
+  'kira.chat.defaultPersona': 'Kira (Standard)',
   'kira.chat.deleteConversation': 'Gespräch löschen',
   'kira.chat.deleteMessage': 'Nachricht löschen',
   'kira.chat.deliveryFailed': 'Übermittlungsfehler',
@@ -475,6 +482,7 @@ export const de = {
   'kira.chat.messageUnblocked': 'Nachricht entsperrt',
   'kira.chat.messageUnpinned': 'Nachricht gelöst',
   'kira.chat.messageUnread': 'Ungelesene Nachricht',
+  'kira.chat.model': 'Modell',
   'kira.chat.muteConversation': 'Konversation stummschalten',
   'kira.chat.newMessagesBelow': 'Neue Nachrichten unten',
   'kira.chat.noMessages':
@@ -484,8 +492,18 @@ export const de = {
   'kira.chat.noSearchResults': 'Keine Suchergebnisse',
   'kira.chat.noThreadSelected': 'Kein Thread ausgewählt',
   'kira.chat.notificationSettings': 'Benachrichtigungseinstellungen',
+  'kira.chat.persona': 'Persona',
   'kira.chat.pinMessage': 'Nachricht pinnen',
   'kira.chat.privacySettings': 'Datenschutzeinstellungen',
+  'kira.chat.progress.checkingTasks': 'Kira prüft Ihre Aufgaben...',
+  'kira.chat.progress.checkingTime': 'Kira sieht sich Ihre Zeiterfassung an...',
+  'kira.chat.progress.planningDay': 'Kira plant Ihren Tag...',
+  'kira.chat.progress.recurringTasks':
+    'Kira bearbeitet Ihre wiederkehrenden Aufgaben...',
+  'kira.chat.progress.updatingTasks': 'Kira aktualisiert Ihre Aufgaben...',
+  'kira.chat.progress.updatingTimer': 'Kira aktualisiert Ihren Timer...',
+  'kira.chat.progress.usingTool': 'Kira verwendet {tool}...',
+  'kira.chat.progress.writingAnswer': 'Kira schreibt die Antwort...',
   'kira.chat.quoteMessage': 'Nachricht zitieren',
   'kira.chat.reconnecting': 'Verbindung wird wiederhergestellt…',
   'kira.chat.regenerateResponse': 'Antwort neu generieren',
@@ -510,6 +528,7 @@ export const de = {
   'kira.chat.sendButtonHint':
     'Zum Senden der Nachricht klicken oder Enter drücken',
   'kira.chat.sendMessage': 'Nachricht senden',
+  'kira.chat.stopGenerating': 'Generierung stoppen',
   'kira.chat.sending': 'Sende...',
   'kira.chat.startConversation':
     'Beginnen Sie unten mit dem Schreiben einer Nachricht',
@@ -639,6 +658,17 @@ export const de = {
   'settings.ai.autoSuggestionsDescription':
     'Proaktive Produktivitätsvorschläge erhalten',
   'settings.ai.avgProcessingTime': 'Durchschnittliche Verarbeitungszeit',
+  'settings.ai.batteryModel': 'Kleineres Modell im Akkubetrieb',
+  'settings.ai.batteryModelDescription':
+    'Ollama-Modell, das beim Energiesparen antwortet. Leer lassen, um lokale Anfragen stattdessen an die Ausweichkette abzugeben.',
+  'settings.ai.batteryModelPlaceholder': 'Anfragen zurückstellen',
+  'settings.ai.batteryThreshold': 'Im Akkubetrieb Energie sparen',
+  'settings.ai.batteryThresholdDescription':
+    'Akkustand, ab dem das lokale Modell Energie spart. Hintergrundarbeit wie Thread-Zusammenfassungen wartet so lange.',
+  'settings.ai.batteryThresholdPlaceholder': 'Aus',
+  'settings.ai.blockOverBudget': 'Cloud-Anfragen über dem Budget blockieren',
+  'settings.ai.blockOverBudgetDescription':
+    'Sobald das Monatsbudget aufgebraucht ist, antwortet ein lokales Modell statt eines Cloud-Anbieters',
   'settings.ai.contextSize': 'Kontextgröße',
   'settings.ai.contextSizeDescription':
     'Maximale Kontextfenstergröße in Tokens',
@@ -783,7 +813,42 @@ export const de = {
   'settings.ai.memoryUsage': 'Speichernutzung',
   'settings.ai.modelSelection': 'Modellauswahl',
   'settings.ai.modelType': 'KI-Modelltyp',
+  'settings.ai.monthlyBudget': 'Monatliches KI-Budget',
+  'settings.ai.monthlyBudgetDescription':
+    'Geschätzte monatliche Cloud-KI-Kosten in US-Dollar. Bei 80 % und 100 % werden Sie gewarnt.',
+  'settings.ai.monthlyBudgetPlaceholder': 'Kein Budget',
+  'settings.ai.ollamaModel': 'Ollama-Modell',
+  'settings.ai.ollamaModelDescription':
+    'Nutzt bereits in Ollama heruntergeladene Modelle auf diesem Computer',
+  'settings.ai.ollamaNotRunning':
+    'Ollama läuft nicht. Starte es, um deine lokalen Modelle zu nutzen.',
   'settings.ai.performanceRecommendations': 'Leistungsempfehlungen',
+  'settings.ai.promptTemplates.action': 'Aktion',
+  'settings.ai.promptTemplates.actionDescription':
+    'Wie Modelle ohne natives Tool-Calling ein Tool aufrufen',
+  'settings.ai.promptTemplates.description':
+    'Schreiben Sie die Prompts um, mit denen Kira antwortet. Lassen Sie ein Feld leer, um den angezeigten integrierten Prompt zu verwenden.',
+  'settings.ai.promptTemplates.finalAnswer': 'Endgültige Antwort',
+  'settings.ai.promptTemplates.finalAnswerDescription':
+    'Wie Modelle ohne natives Tool-Calling ihre Antwort geben',
+  'settings.ai.promptTemplates.loadError':
+    'Prompt-Vorlagen konnten nicht geladen werden',
+  'settings.ai.promptTemplates.reset': 'Auf Standard zurücksetzen',
+  'settings.ai.promptTemplates.resetDone':
+    'Prompt-Vorlagen auf Standard zurückgesetzt',
+  'settings.ai.promptTemplates.saveError':
+    'Prompt-Vorlagen konnten nicht gespeichert werden',
+  'settings.ai.promptTemplates.saved': 'Prompt-Vorlagen gespeichert',
+  'settings.ai.promptTemplates.systemPersona': 'System-Persona',
+  'settings.ai.promptTemplates.systemPersonaDescription':
+    'Wer Kira ist, ihre Rolle und Richtlinien. Der App-Kontext wird danach angefügt.',
+  'settings.ai.promptTemplates.thought': 'Denkweise',
+  'settings.ai.promptTemplates.thoughtDescription':
+    'Wie Kira über jede Anfrage nachdenkt, am Ende des System-Prompts',
+  'settings.ai.promptTemplates.title': 'Prompt-Vorlagen',
+  'settings.ai.providerFallback': 'Auf andere Anbieter ausweichen',
+  'settings.ai.providerFallbackDescription':
+    'Eine Anfrage beim anderen KI-Anbieter wiederholen, wenn der aktuelle fehlschlägt oder nicht antwortet',
   'settings.ai.resourceConfiguration': 'Ressourcenkonfiguration',
   'settings.ai.resourceDataUnavailable': 'Ressourcendaten nicht verfügbar',
   'settings.ai.resourceManagement': 'Ressourcenverwaltung',
@@ -823,17 +888,26 @@ export const de = {
   'settings.geminiApiKeyPlaceholder':
     'Fügen Sie hier Ihren Gemini API-Schlüssel ein...',
   'settings.general': 'Allgemein',
+  'settings.general.addHoliday': 'Feiertag hinzufügen',
   'settings.general.appPreferences': 'App-Einstellungen',
   'settings.general.dateFormat': 'Datumsformat',
   'settings.general.dateFormatDescription':
     'Wählen Sie, wie Daten in der gesamten App angezeigt werden',
   'settings.general.endTime': 'Endzeit',
+  'settings.general.holidays': 'Feiertage',
   'settings.general.language': 'Sprache',
   'settings.general.languageDescription': 'Wählen Sie Ihre bevorzugte Sprache',
   'settings.general.notifications': 'Benachrichtigungen',
+  'settings.general.rollover': 'Unerledigte Aufgaben übertragen',
+  'settings.general.rollover.backlog': 'In den Backlog verschieben',
+  'settings.general.rollover.off': 'An ihrem Tag belassen',
+  'settings.general.rollover.today': 'Auf heute verschieben',
+  'settings.general.rolloverDescription':
+    'Wohin nicht erledigte Aufgaben eines vergangenen Tages verschoben werden',
   'settings.general.startTime': 'Startzeit',
   'settings.general.theme': 'Design',
   'settings.general.themeDescription': 'Wählen Sie Ihr bevorzugtes Farbschema',
+  'settings.general.workingDays': 'Arbeitstage',
   'settings.general.workingHours': 'Arbeitszeiten',
   'settings.platform': 'Plattform',
   'settings.platformValue': 'Desktop (Tauri)',
@@ -1075,6 +1149,8 @@ export const de = {
   'dataManagement.viewPrivacySettings': 'Datenschutzeinstellungen anzeigen',
 
   // Database
+  'database.error.busy':
+    'Die Datenbank ist ausgelastet. Bitte versuchen Sie es erneut.',
   'database.error.dependencyNotFound':
     'Abhängigkeitsaufgabe {taskId} nicht gefunden',
   'database.error.healthCheckFailed':
@@ -1478,6 +1554,20 @@ export const de = {
   'productivity.workingStyle.taskCompletionStyle': 'Aufgabenabschluss-Stil',
   'productivity.workingStyle.title': 'Ihr Arbeitsstil',
 
+  // Quick capture
+  'quickCapture.placeholder':
+    'Aufgabe hinzufügen, z. B. Fix login bug #work !p1 @tomorrow',
+
+  // Idle detection
+  'settings.idleThreshold': 'Inaktivitätserkennung',
+  'settings.idleThresholdDescription':
+    'Nachfragen, wenn Sie während des Timers nicht am Computer waren. 0 schaltet sie aus.',
+  'timer.idle.title': 'Waren Sie weg?',
+  'timer.idle.message':
+    'Seit {minutes} Minuten keine Aktivität, während der Timer lief. Diese Zeit in der Sitzung behalten?',
+  'timer.idle.keep': 'Zeit behalten',
+  'timer.idle.discard': 'Zeit verwerfen',
+
   // Recurrence
   'recurrence.biweekly': 'Zweiwöchentlich',
   'recurrence.biweekly.description': 'Wiederholt sich alle 2 Wochen',
@@ -118,7 +118,12 @@ export const en = {
   'ai.model.information': 'Model Information',
   'ai.model.local': 'Local Model',
   'ai.model.name': 'Name',
+  'ai.model.ollama': 'Ollama (local)',
   'ai.model.size': 'Size',
+  'ai.model.status.batteryDeferred':
+    'On battery at {percent}%: local requests are deferred',
+  'ai.model.status.batterySmallerModel':
+    'On battery at {percent}%: answering with {model}',
   'ai.model.status.downloading': 'Downloading... {progress}%',
   'ai.model.status.error': 'Error',
   'ai.model.status.loading': 'Loading...',
@@ -354,6 +359,8 @@ export const en = {
   'kira.chat.conversationStarted': 'Conversation started',
   'kira.chat.conversationUnarchived': 'Conversation unarchived',
   'kira.chat.copyMessage': 'Copy message',
+  'kira.chat.defaultModel': 'Default model',
+  'kira.chat.defaultPersona': 'Kira (default)',
   'kira.chat.deleteConversation': 'Delete conversation',
   'kira.chat.deleteMessage': 'Delete message',
   'kira.chat.deliveryFailed': 'Delivery failed',
@@ -465,6 +472,7 @@ export const en = {
   'kira.chat.messageUnblocked': 'Message unlocked',
   'kira.chat.messageUnpinned': 'Message unpinned',
   'kira.chat.messageUnread': 'Unread message',
+  'kira.chat.model': 'Model',
   'kira.chat.muteConversation': 'Mute conversation',
   'kira.chat.newMessagesBelow': 'New messages below',
   'kira.chat.noMessages': 'Start the conversation by sending a message below.',
@@ -473,8 +481,18 @@ export const en = {
   'kira.chat.noSearchResults': 'No search results',
   'kira.chat.noThreadSelected': 'No Thread Selected',
   'kira.chat.notificationSettings': 'Configuraciones of notificaciones',
+  'kira.chat.persona': 'Persona',
   'kira.chat.pinMessage': 'Pin message',
   'kira.chat.privacySettings': 'Configuraciones of privacidad',
+  'kira.chat.progress.checkingTasks': 'Kira is checking your tasks...',
+  'kira.chat.progress.checkingTime': 'Kira is looking at your time tracking...',
+  'kira.chat.progress.planningDay': 'Kira is planning your day...',
+  'kira.chat.progress.recurringTasks':
+    'Kira is working on your recurring tasks...',
+  'kira.chat.progress.updatingTasks': 'Kira is updating your tasks...',
+  'kira.chat.progress.updatingTimer': 'Kira is updating your timer...',
+  'kira.chat.progress.usingTool': 'Kira is using {tool}...',
+  'kira.chat.progress.writingAnswer': 'Kira is writing the answer...',
   'kira.chat.quoteMessage': 'Quote message',
   'kira.chat.reconnecting': 'Reconectando...',
   'kira.chat.regenerateResponse': 'Regenerate reply',
@@ -498,6 +516,7 @@ export const en = {
     'Select a thread from the sidebar to start chatting with Kira AI.',
   'kira.chat.sendButtonHint': 'Click to send message or press Enter',
   'kira.chat.sendMessage': 'Send message',
+  'kira.chat.stopGenerating': 'Stop generating',
   'kira.chat.sending': 'Sending...',
   'kira.chat.startConversation': 'Start typing your message below',
   'kira.chat.startThread': 'Start thread',
@@ -625,6 +644,17 @@ export const en = {
   'settings.ai.autoSuggestionsDescription':
     'Receive proactive productivity suggestions',
   'settings.ai.avgProcessingTime': 'Avg Processing Time',
+  'settings.ai.batteryModel': 'Smaller Model on Battery',
+  'settings.ai.batteryModelDescription':
+    'Ollama model to answer with while saving power. Leave empty to defer local requests to the fallback chain instead.',
+  'settings.ai.batteryModelPlaceholder': 'Defer requests',
+  'settings.ai.batteryThreshold': 'Save Power on Battery',
+  'settings.ai.batteryThresholdDescription':
+    'Battery charge at or below which the local model saves power. Background work such as thread summaries waits until then.',
+  'settings.ai.batteryThresholdPlaceholder': 'Off',
+  'settings.ai.blockOverBudget': 'Block Cloud Requests Over Budget',
+  'settings.ai.blockOverBudgetDescription':
+    'Once the monthly budget is spent, answer with a local model instead of a cloud provider',
   'settings.ai.contextSize': 'Context Size',
   'settings.ai.contextSizeDescription': 'Maximum context window size in tokens',
   'settings.ai.conversationHistory': 'Conversation History',
@@ -763,7 +793,39 @@ export const en = {
   'settings.ai.memoryUsage': 'Memory Usage',
   'settings.ai.modelSelection': 'Model Selection',
   'settings.ai.modelType': 'AI Model Type',
+  'settings.ai.monthlyBudget': 'Monthly AI Budget',
+  'settings.ai.monthlyBudgetDescription':
+    'Estimated cloud AI spend per month in US dollars. You are warned at 80% and 100%.',
+  'settings.ai.monthlyBudgetPlaceholder': 'No budget',
+  'settings.ai.ollamaModel': 'Ollama Model',
+  'settings.ai.ollamaModelDescription':
+    'Uses models already downloaded to Ollama on this computer',
+  'settings.ai.ollamaNotRunning':
+    "Ollama isn't running. Start it to use your local models.",
   'settings.ai.performanceRecommendations': 'Performance Recommendations',
+  'settings.ai.promptTemplates.action': 'Action',
+  'settings.ai.promptTemplates.actionDescription':
+    'How models without native tool calling call a tool',
+  'settings.ai.promptTemplates.description':
+    'Rewrite the prompts Kira answers with. Leave a field empty to use the built-in prompt shown in it.',
+  'settings.ai.promptTemplates.finalAnswer': 'Final Answer',
+  'settings.ai.promptTemplates.finalAnswerDescription':
+    'How models without native tool calling give their answer',
+  'settings.ai.promptTemplates.loadError': 'Failed to load prompt templates',
+  'settings.ai.promptTemplates.reset': 'Reset to Defaults',
+  'settings.ai.promptTemplates.resetDone': 'Prompt templates reset to defaults',
+  'settings.ai.promptTemplates.saveError': 'Failed to save prompt templates',
+  'settings.ai.promptTemplates.saved': 'Prompt templates saved',
+  'settings.ai.promptTemplates.systemPersona': 'System Persona',
+  'settings.ai.promptTemplates.systemPersonaDescription':
+    'Who Kira is, its role and guidelines. The app context is added after it.',
+  'settings.ai.promptTemplates.thought': 'Thought',
+  'settings.ai.promptTemplates.thoughtDescription':
+    'How Kira reasons about each request, at the end of the system prompt',
+  'settings.ai.promptTemplates.title': 'Prompt Templates',
+  'settings.ai.providerFallback': 'Fall Back to Other Providers',
+  'settings.ai.providerFallbackDescription':
+    'Retry a request on the other AI provider when the current one fails or times out',
   'settings.ai.resourceConfiguration': 'Resource Configuration',
   'settings.ai.resourceDataUnavailable': 'Resource data unavailable',
   'settings.ai.resourceManagement': 'Resource Management',
@@ -803,17 +865,26 @@ export const en = {
     'Enter your Google Gemini API key to enable AI features. Get your key from',
   'settings.geminiApiKeyPlaceholder': 'Enter your Gemini API key...',
   'settings.general': 'General',
+  'settings.general.addHoliday': 'Add Holiday',
   'settings.general.appPreferences': 'App Preferences',
   'settings.general.dateFormat': 'Date Format',
   'settings.general.dateFormatDescription':
     'Choose how dates are displayed throughout the app',
   'settings.general.endTime': 'End Time',
+  'settings.general.holidays': 'Holidays',
   'settings.general.language': 'Language',
   'settings.general.languageDescription': 'Select your preferred language',
   'settings.general.notifications': 'Notifications',
+  'settings.general.rollover': 'Roll over unfinished tasks',
+  'settings.general.rollover.backlog': 'Move to backlog',
+  'settings.general.rollover.off': 'Keep on their day',
+  'settings.general.rollover.today': 'Move to today',
+  'settings.general.rolloverDescription':
+    'Where tasks scheduled for a past day go when they were not finished',
   'settings.general.startTime': 'Start Time',
   'settings.general.theme': 'Theme',
   'settings.general.themeDescription': 'Choose your preferred color scheme',
+  'settings.general.workingDays': 'Working Days',
   'settings.general.workingHours': 'Working Hours',
   'settings.platform': 'Platform',
   'settings.platformValue': 'Desktop (Tauri)',
@@ -1049,6 +1120,7 @@ export const en = {
   'dataManagement.viewPrivacySettings': 'View Privacy Settings',
 
   // Database
+  'database.error.busy': 'The database is busy. Please try again.',
   'database.error.dependencyNotFound': 'Dependency task {taskId} not found',
   'database.error.healthCheckFailed': 'Database health check failed',
   'database.error.initFailed': 'Failed to initialize database',
@@ -1430,6 +1502,20 @@ export const en = {
   'productivity.workingStyle.taskCompletionStyle': 'Task Completion Style',
   'productivity.workingStyle.title': 'Your Working Style',
 
+  // Quick capture
+  'quickCapture.placeholder':
+    'Add a task, e.g. Fix login bug #work !p1 @tomorrow',
+
+  // Idle detection
+  'settings.idleThreshold': 'Idle Detection',
+  'settings.idleThresholdDescription':
+    'Ask about time away from the computer while the timer runs. 0 turns it off.',
+  'timer.idle.title': 'Were you away?',
+  'timer.idle.message':
+    'No activity for {minutes} minutes while the timer was running. Keep this time in the session?',
+  'timer.idle.keep': 'Keep Time',
+  'timer.idle.discard': 'Discard Time',
+
   // Recurrence
   'recurrence.biweekly': 'Biweekly',
   'recurrence.biweekly.description': 'Repeats every 2 weeks',
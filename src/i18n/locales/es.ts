@@ -121,7 +121,12 @@ export const es = {
   'ai.model.information': 'Información del Modelo',
   'ai.model.local': 'Modelo Local',
   'ai.model.name': 'Nombre',
+  'ai.model.ollama': 'Ollama (local)',
   'ai.model.size': 'Tamaño',
+  'ai.model.status.batteryDeferred':
+    'Batería al {percent}%: las solicitudes locales se aplazan',
+  'ai.model.status.batterySmallerModel':
+    'Batería al {percent}%: responde {model}',
   'ai.model.status.downloading': 'Descargando... {progress}%',
   'ai.model.status.error': 'Error',
   'ai.model.status.loading': 'Cargando...',
@@ -363,6 +368,8 @@ export const es = {
   'kira.chat.conversationStarted': 'Conversación iniciada',
   'kira.chat.conversationUnarchived': 'Conversación desarchivada',
   'kira.chat.copyMessage': 'Copiar mensaje',
+  'kira.chat.defaultModel': 'Modelo predeterminado',
+  'kira.chat.defaultPersona': 'Kira (predeterminada)',
   'kira.chat.deleteConversation': 'Eliminar conversación',
   'kira.chat.deleteMessage': 'Eliminar mensaje',
   'kira.chat.deliveryFailed': 'Error en la entrega',
@@ -475,6 +482,7 @@ export const es = {
   'kira.chat.messageUnblocked': 'Mensaje desbloqueado',
   'kira.chat.messageUnpinned': 'Mensaje desfijado',
   'kira.chat.messageUnread': 'Mensaje no leído',
+  'kira.chat.model': 'Modelo',
   'kira.chat.muteConversation': 'Silenciar conversación',
   'kira.chat.newMessagesBelow': 'Nuevos mensajes abajo',
   'kira.chat.noMessages': 'Inicia la conversación enviando un mensaje abajo.',
@@ -483,8 +491,20 @@ export const es = {
   'kira.chat.noSearchResults': 'Sin resultados de búsqueda',
   'kira.chat.noThreadSelected': 'Ninguna Conversación Seleccionada',
   'kira.chat.notificationSettings': 'Configuraciones de notificaciones',
+  'kira.chat.persona': 'Personalidad',
   'kira.chat.pinMessage': 'Fijar mensaje',
   'kira.chat.privacySettings': 'Configuraciones de privacidad',
+  'kira.chat.progress.checkingTasks': 'Kira está revisando tus tareas...',
+  'kira.chat.progress.checkingTime':
+    'Kira está revisando tu registro de tiempo...',
+  'kira.chat.progress.planningDay': 'Kira está planificando tu día...',
+  'kira.chat.progress.recurringTasks':
+    'Kira está trabajando en tus tareas recurrentes...',
+  'kira.chat.progress.updatingTasks': 'Kira está actualizando tus tareas...',
+  'kira.chat.progress.updatingTimer':
+    'Kira está actualizando tu temporizador...',
+  'kira.chat.progress.usingTool': 'Kira está usando {tool}...',
+  'kira.chat.progress.writingAnswer': 'Kira está escribiendo la respuesta...',
   'kira.chat.quoteMessage': 'Citar mensaje',
   'kira.chat.reconnecting': 'Reconectando...',
   'kira.chat.regenerateResponse': 'Regenerar respuesta',
@@ -508,6 +528,7 @@ export const es = {
     'Selecciona una conversación de la barra lateral para comenzar a chatear con Kira IA.',
   'kira.chat.sendButtonHint': 'Haz clic para enviar mensaje o presiona Enter',
   'kira.chat.sendMessage': 'Enviar mensaje',
+  'kira.chat.stopGenerating': 'Detener respuesta',
   'kira.chat.sending': 'Enviando...',
   'kira.chat.startConversation': 'Comienza escribiendo un mensaje abajo',
   'kira.chat.startThread': 'Iniciar hilo',
@@ -640,6 +661,18 @@ export const es = {
   'settings.ai.autoSuggestionsDescription':
     'Recibir sugerencias proactivas de productividad',
   'settings.ai.avgProcessingTime': 'Tiempo Promedio de Procesamiento',
+  'settings.ai.batteryModel': 'Modelo más pequeño con batería',
+  'settings.ai.batteryModelDescription':
+    'Modelo de Ollama que responde mientras se ahorra energía. Déjalo vacío para pasar las solicitudes locales a la cadena de respaldo.',
+  'settings.ai.batteryModelPlaceholder': 'Aplazar solicitudes',
+  'settings.ai.batteryThreshold': 'Ahorrar energía con batería',
+  'settings.ai.batteryThresholdDescription':
+    'Carga de batería a partir de la cual el modelo local ahorra energía. El trabajo en segundo plano, como los resúmenes de hilos, espera.',
+  'settings.ai.batteryThresholdPlaceholder': 'Desactivado',
+  'settings.ai.blockOverBudget':
+    'Bloquear solicitudes en la nube por encima del presupuesto',
+  'settings.ai.blockOverBudgetDescription':
+    'Cuando se agote el presupuesto mensual, responder con un modelo local en lugar de un proveedor en la nube',
   'settings.ai.contextSize': 'Tamaño de Contexto',
   'settings.ai.contextSizeDescription':
     'Tamaño máximo de ventana de contexto en tokens',
@@ -787,7 +820,42 @@ export const es = {
   'settings.ai.memoryUsage': 'Uso de Memoria',
   'settings.ai.modelSelection': 'Selección de Modelo',
   'settings.ai.modelType': 'Tipo de Modelo de IA',
+  'settings.ai.monthlyBudget': 'Presupuesto mensual de IA',
+  'settings.ai.monthlyBudgetDescription':
+    'Gasto mensual estimado en IA en la nube, en dólares estadounidenses. Se le avisará al 80 % y al 100 %.',
+  'settings.ai.monthlyBudgetPlaceholder': 'Sin presupuesto',
+  'settings.ai.ollamaModel': 'Modelo de Ollama',
+  'settings.ai.ollamaModelDescription':
+    'Usa modelos ya descargados en Ollama en este equipo',
+  'settings.ai.ollamaNotRunning':
+    'Ollama no se está ejecutando. Inícialo para usar tus modelos locales.',
   'settings.ai.performanceRecommendations': 'Recomendaciones de Rendimiento',
+  'settings.ai.promptTemplates.action': 'Acción',
+  'settings.ai.promptTemplates.actionDescription':
+    'Cómo llaman a una herramienta los modelos sin llamadas a herramientas nativas',
+  'settings.ai.promptTemplates.description':
+    'Reescribe los prompts con los que responde Kira. Deja un campo vacío para usar el prompt integrado que se muestra en él.',
+  'settings.ai.promptTemplates.finalAnswer': 'Respuesta final',
+  'settings.ai.promptTemplates.finalAnswerDescription':
+    'Cómo dan su respuesta los modelos sin llamadas a herramientas nativas',
+  'settings.ai.promptTemplates.loadError':
+    'No se pudieron cargar las plantillas de prompts',
+  'settings.ai.promptTemplates.reset': 'Restablecer valores predeterminados',
+  'settings.ai.promptTemplates.resetDone':
+    'Plantillas de prompts restablecidas',
+  'settings.ai.promptTemplates.saveError':
+    'No se pudieron guardar las plantillas de prompts',
+  'settings.ai.promptTemplates.saved': 'Plantillas de prompts guardadas',
+  'settings.ai.promptTemplates.systemPersona': 'Persona del sistema',
+  'settings.ai.promptTemplates.systemPersonaDescription':
+    'Quién es Kira, su función y sus pautas. El contexto de la aplicación se añade después.',
+  'settings.ai.promptTemplates.thought': 'Razonamiento',
+  'settings.ai.promptTemplates.thoughtDescription':
+    'Cómo razona Kira sobre cada solicitud, al final del prompt del sistema',
+  'settings.ai.promptTemplates.title': 'Plantillas de prompts',
+  'settings.ai.providerFallback': 'Recurrir a otros proveedores',
+  'settings.ai.providerFallbackDescription':
+    'Reintentar la solicitud con el otro proveedor de IA si el actual falla o agota el tiempo',
   'settings.ai.resourceConfiguration': 'Configuración de Recursos',
   'settings.ai.resourceDataUnavailable': 'Datos de recursos no disponibles',
   'settings.ai.resourceManagement': 'Gestión de Recursos',
@@ -827,17 +895,26 @@ export const es = {
   'settings.geminiApiKeyDescription': 'Obtén tu clave API gratuita desde',
   'settings.geminiApiKeyPlaceholder': 'Pega tu clave API de Gemini aquí...',
   'settings.general': 'General',
+  'settings.general.addHoliday': 'Añadir festivo',
   'settings.general.appPreferences': 'Preferencias de la aplicación',
   'settings.general.dateFormat': 'Formato de fecha',
   'settings.general.dateFormatDescription':
     'Elige cómo se muestran las fechas en la aplicación',
   'settings.general.endTime': 'Hora de fin',
+  'settings.general.holidays': 'Festivos',
   'settings.general.language': 'Idioma',
   'settings.general.languageDescription': 'Selecciona tu idioma preferido',
   'settings.general.notifications': 'Notificaciones',
+  'settings.general.rollover': 'Trasladar tareas sin terminar',
+  'settings.general.rollover.backlog': 'Mover al backlog',
+  'settings.general.rollover.off': 'Dejar en su día',
+  'settings.general.rollover.today': 'Mover a hoy',
+  'settings.general.rolloverDescription':
+    'Adónde van las tareas programadas para un día pasado que no se terminaron',
   'settings.general.startTime': 'Hora de inicio',
   'settings.general.theme': 'Tema',
   'settings.general.themeDescription': 'Elige tu esquema de colores preferido',
+  'settings.general.workingDays': 'Días laborables',
   'settings.general.workingHours': 'Horario de trabajo',
   'settings.platform': 'Plataforma',
   'settings.platformValue': 'Escritorio (Tauri)',
@@ -1077,6 +1154,7 @@ export const es = {
   'dataManagement.viewPrivacySettings': 'Ver Configuración de Privacidad',
 
   // Database
+  'database.error.busy': 'La base de datos está ocupada. Inténtalo de nuevo.',
   'database.error.dependencyNotFound':
     'Tarea de dependencia {taskId} no encontrada',
   'database.error.healthCheckFailed':
@@ -1476,6 +1554,20 @@ export const es = {
     'Estilo de Compleción de Tareas',
   'productivity.workingStyle.title': 'Tu Estilo de Trabajo',
 
+  // Quick capture
+  'quickCapture.placeholder':
+    'Añadir una tarea, p. ej. Fix login bug #work !p1 @tomorrow',
+
+  // Idle detection
+  'settings.idleThreshold': 'Detección de Inactividad',
+  'settings.idleThresholdDescription':
+    'Preguntar por el tiempo lejos del ordenador mientras corre el temporizador. 0 la desactiva.',
+  'timer.idle.title': '¿Estuviste ausente?',
+  'timer.idle.message':
+    'Sin actividad durante {minutes} minutos mientras el temporizador corría. ¿Mantener este tiempo en la sesión?',
+  'timer.idle.keep': 'Mantener Tiempo',
+  'timer.idle.discard': 'Descartar Tiempo',
+
   // Recurrence
   'recurrence.biweekly': 'Quincenal',
   'recurrence.biweekly.description': 'Se repite cada 2 semanas',
@@ -126,7 +126,12 @@ export const fr = {
   'ai.model.information': 'Informations sur le modèle',
   'ai.model.local': 'Modèle Local',
   'ai.model.name': 'Nom',
+  'ai.model.ollama': 'Ollama (local)',
   'ai.model.size': 'Taille',
+  'ai.model.status.batteryDeferred':
+    'Batterie à {percent}% : les requêtes locales sont différées',
+  'ai.model.status.batterySmallerModel':
+    'Batterie à {percent}% : réponses avec {model}',
   'ai.model.status.downloading': 'Téléchargement en cours… {progress}%',
   'ai.model.status.error': 'Erreur',
   'ai.model.status.loading': 'Chargement…',
@@ -371,6 +376,8 @@ export const fr = {
   'kira.chat.conversationStarted': 'Conversation démarrée',
   'kira.chat.conversationUnarchived': 'Conversation désarchivée',
   'kira.chat.copyMessage': 'Copier le message',
+  'kira.chat.defaultModel': 'Modèle par défaut',
+  'kira.chat.defaultPersona': 'Kira (par défaut)',
   'kira.chat.deleteConversation': 'Supprimer la conversation',
   'kira.chat.deleteMessage': 'Supprimer le message',
   'kira.chat.deliveryFailed': 'Erreur de livraison',
@@ -483,6 +490,7 @@ export const fr = {
   'kira.chat.messageUnblocked': 'Message déverrouillé',
   'kira.chat.messageUnpinned': 'Message dépiné',
   'kira.chat.messageUnread': 'Message non lu',
+  'kira.chat.model': 'Modèle',
   'kira.chat.muteConversation': 'Silencier la conversation',
   'kira.chat.newMessagesBelow': 'Nouveaux messages en bas',
   'kira.chat.noMessages':
@@ -492,8 +500,18 @@ export const fr = {
   'kira.chat.noSearchResults': 'Aucun résultat de recherche',
   'kira.chat.noThreadSelected': 'Aucune Conversation Sélectionnée',
   'kira.chat.notificationSettings': 'Paramètres de notifications',
+  'kira.chat.persona': 'Persona',
   'kira.chat.pinMessage': 'Épingler le message',
   'kira.chat.privacySettings': 'Paramètres de confidentialité',
+  'kira.chat.progress.checkingTasks': 'Kira consulte vos tâches...',
+  'kira.chat.progress.checkingTime': 'Kira examine votre suivi du temps...',
+  'kira.chat.progress.planningDay': 'Kira planifie votre journée...',
+  'kira.chat.progress.recurringTasks':
+    'Kira travaille sur vos tâches récurrentes...',
+  'kira.chat.progress.updatingTasks': 'Kira met à jour vos tâches...',
+  'kira.chat.progress.updatingTimer': 'Kira met à jour votre minuteur...',
+  'kira.chat.progress.usingTool': 'Kira utilise {tool}...',
+  'kira.chat.progress.writingAnswer': 'Kira rédige la réponse...',
   'kira.chat.quoteMessage': 'Citer le message',
   'kira.chat.reconnecting': 'Reconnexion...',
   'kira.chat.regenerateResponse': 'Régénérer la réponse',
@@ -519,6 +537,7 @@ export const fr = {
   'kira.chat.sendButtonHint':
     'Cliquez pour envoyer le message ou appuyez sur Entrée',
   'kira.chat.sendMessage': 'Envoyer le message',
+  'kira.chat.stopGenerating': 'Arrêter la génération',
   'kira.chat.sending': 'Envoi...',
   'kira.chat.startConversation': 'Commencez en tapant un message ci-dessous',
   'kira.chat.startThread': 'Démarrer un fil de discussion',
@@ -651,6 +670,17 @@ export const fr = {
   'settings.ai.autoSuggestionsDescription':
     'Recevoir des suggestions proactives de productivité',
   'settings.ai.avgProcessingTime': 'Temps de Traitement Moyen',
+  'settings.ai.batteryModel': 'Modèle plus léger sur batterie',
+  'settings.ai.batteryModelDescription':
+    "Modèle Ollama qui répond pendant l'économie d'énergie. Laissez vide pour confier les requêtes locales à la chaîne de secours.",
+  'settings.ai.batteryModelPlaceholder': 'Différer les requêtes',
+  'settings.ai.batteryThreshold': "Économiser l'énergie sur batterie",
+  'settings.ai.batteryThresholdDescription':
+    "Charge de batterie à partir de laquelle le modèle local économise l'énergie. Les tâches de fond, comme les résumés de fils, attendent.",
+  'settings.ai.batteryThresholdPlaceholder': 'Désactivé',
+  'settings.ai.blockOverBudget': 'Bloquer les requêtes cloud au-delà du budget',
+  'settings.ai.blockOverBudgetDescription':
+    "Une fois le budget mensuel épuisé, répondre avec un modèle local plutôt qu'un fournisseur cloud",
   'settings.ai.contextSize': 'Taille du Contexte',
   'settings.ai.contextSizeDescription':
     'Taille maximale de la fenêtre de contexte en tokens',
@@ -800,7 +830,41 @@ export const fr = {
   'settings.ai.memoryUsage': 'Utilisation Mémoire',
   'settings.ai.modelSelection': 'Sélection de Modèle',
   'settings.ai.modelType': 'Type de Modèle IA',
+  'settings.ai.monthlyBudget': 'Budget IA mensuel',
+  'settings.ai.monthlyBudgetDescription':
+    'Dépenses mensuelles estimées en IA cloud, en dollars américains. Vous êtes averti à 80 % et à 100 %.',
+  'settings.ai.monthlyBudgetPlaceholder': 'Aucun budget',
+  'settings.ai.ollamaModel': 'Modèle Ollama',
+  'settings.ai.ollamaModelDescription':
+    'Utilise les modèles déjà téléchargés dans Ollama sur cet ordinateur',
+  'settings.ai.ollamaNotRunning':
+    "Ollama n'est pas lancé. Démarrez-le pour utiliser vos modèles locaux.",
   'settings.ai.performanceRecommendations': 'Recommandations de Performance',
+  'settings.ai.promptTemplates.action': 'Action',
+  'settings.ai.promptTemplates.actionDescription':
+    "Comment les modèles sans appel d'outils natif appellent un outil",
+  'settings.ai.promptTemplates.description':
+    'Réécrivez les prompts avec lesquels Kira répond. Laissez un champ vide pour utiliser le prompt intégré qui y est affiché.',
+  'settings.ai.promptTemplates.finalAnswer': 'Réponse finale',
+  'settings.ai.promptTemplates.finalAnswerDescription':
+    "Comment les modèles sans appel d'outils natif donnent leur réponse",
+  'settings.ai.promptTemplates.loadError':
+    'Impossible de charger les modèles de prompts',
+  'settings.ai.promptTemplates.reset': 'Rétablir les valeurs par défaut',
+  'settings.ai.promptTemplates.resetDone': 'Modèles de prompts rétablis',
+  'settings.ai.promptTemplates.saveError':
+    "Impossible d'enregistrer les modèles de prompts",
+  'settings.ai.promptTemplates.saved': 'Modèles de prompts enregistrés',
+  'settings.ai.promptTemplates.systemPersona': 'Persona système',
+  'settings.ai.promptTemplates.systemPersonaDescription':
+    "Qui est Kira, son rôle et ses consignes. Le contexte de l'application est ajouté après.",
+  'settings.ai.promptTemplates.thought': 'Raisonnement',
+  'settings.ai.promptTemplates.thoughtDescription':
+    'Comment Kira raisonne sur chaque demande, à la fin du prompt système',
+  'settings.ai.promptTemplates.title': 'Modèles de prompts',
+  'settings.ai.providerFallback': "Basculer vers d'autres fournisseurs",
+  'settings.ai.providerFallbackDescription':
+    "Réessayer une requête avec l'autre fournisseur d'IA si celui-ci échoue ou expire",
   'settings.ai.resourceConfiguration': 'Configuration des Ressources',
   'settings.ai.resourceDataUnavailable':
     'Données de ressources non disponibles',
@@ -841,18 +905,27 @@ export const fr = {
   'settings.geminiApiKeyDescription': 'Obtenez votre clé API gratuite depuis',
   'settings.geminiApiKeyPlaceholder': 'Collez votre clé API Gemini ici...',
   'settings.general': 'Général',
+  'settings.general.addHoliday': 'Ajouter un jour férié',
   'settings.general.appPreferences': "Préférences de l'application",
   'settings.general.dateFormat': 'Format de Date',
   'settings.general.dateFormatDescription':
     "Choisissez comment les dates sont affichées dans toute l'application",
   'settings.general.endTime': 'Heure de fin',
+  'settings.general.holidays': 'Jours fériés',
   'settings.general.language': 'Langue',
   'settings.general.languageDescription': 'Sélectionnez votre langue préférée',
   'settings.general.notifications': 'Notifications',
+  'settings.general.rollover': 'Reporter les tâches inachevées',
+  'settings.general.rollover.backlog': 'Renvoyer au backlog',
+  'settings.general.rollover.off': 'Laisser à leur jour',
+  'settings.general.rollover.today': "Déplacer à aujourd'hui",
+  'settings.general.rolloverDescription':
+    "Où vont les tâches prévues un jour passé qui n'ont pas été terminées",
   'settings.general.startTime': 'Heure de début',
   'settings.general.theme': 'Thème',
   'settings.general.themeDescription':
     'Choisissez votre schéma de couleurs préféré',
+  'settings.general.workingDays': 'Jours travaillés',
   'settings.general.workingHours': 'Heures de travail',
   'settings.platform': 'Plateforme',
   'settings.platformValue': 'Bureau (Tauri)',
@@ -1094,6 +1167,7 @@ export const fr = {
     'Voir les Paramètres de Confidentialité',
 
   // Database
+  'database.error.busy': 'La base de données est occupée. Veuillez réessayer.',
   'database.error.dependencyNotFound':
     'Tâche de dépendance {taskId} introuvable',
   'database.error.healthCheckFailed':
@@ -1501,6 +1575,20 @@ export const fr = {
     "Style d'achèvement des tâches",
   'productivity.workingStyle.title': 'Votre style de travail',
 
+  // Quick capture
+  'quickCapture.placeholder':
+    'Ajouter une tâche, p. ex. Fix login bug #work !p1 @tomorrow',
+
+  // Idle detection
+  'settings.idleThreshold': "Détection d'Inactivité",
+  'settings.idleThresholdDescription':
+    "Demander quoi faire du temps passé loin de l'ordinateur pendant le minuteur. 0 la désactive.",
+  'timer.idle.title': 'Étiez-vous absent ?',
+  'timer.idle.message':
+    'Aucune activité depuis {minutes} minutes pendant que le minuteur tournait. Garder ce temps dans la session ?',
+  'timer.idle.keep': 'Garder le Temps',
+  'timer.idle.discard': 'Ignorer le Temps',
+
   // Recurrence
   'recurrence.biweekly': 'Bihebdomadaire',
   'recurrence.biweekly.description': 'Se répète toutes les 2 semaines',
@@ -118,7 +118,10 @@ export const ja = {
   'ai.model.information': 'モデル情報',
   'ai.model.local': 'ローカルモデル',
   'ai.model.name': '名前',
+  'ai.model.ollama': 'Ollama（ローカル）',
   'ai.model.size': 'サイズ',
+  'ai.model.status.batteryDeferred': 'バッテリー残量 {percent}%：ローカルのリクエストを延期中',
+  'ai.model.status.batterySmallerModel': 'バッテリー残量 {percent}%：{model} で応答中',
   'ai.model.status.downloading': 'ダウンロード中… {progress}%',
   'ai.model.status.error': 'エラー',
   'ai.model.status.loading': '読み込み中…',
@@ -349,6 +352,8 @@ export const ja = {
   'kira.chat.conversationStarted': '会話が開始されました',
   'kira.chat.conversationUnarchived': '会話のアーカイブが解除されました',
   'kira.chat.copyMessage': 'メッセージをコピー',
+  'kira.chat.defaultModel': 'デフォルトのモデル',
+  'kira.chat.defaultPersona': 'Kira（デフォルト）',
   'kira.chat.deleteConversation': '会話を削除',
   'kira.chat.deleteMessage': 'メッセージを削除',
   'kira.chat.deliveryFailed': '配信エラー',
@@ -460,6 +465,7 @@ export const ja = {
   'kira.chat.messageUnblocked': 'ロック解除されたメッセージ',
   'kira.chat.messageUnpinned': 'メッセージの固定を外しました',
   'kira.chat.messageUnread': '未読メッセージ',
+  'kira.chat.model': 'モデル',
   'kira.chat.muteConversation': '会話のスヌーズ',
   'kira.chat.newMessagesBelow': '下に新しいメッセージ',
   'kira.chat.noMessages': '下のメッセージを送信して会話を開始してください。',
@@ -469,8 +475,17 @@ export const ja = {
   'kira.chat.noSearchResults': '検索結果がありません',
   'kira.chat.noThreadSelected': '会話が選択されていません',
   'kira.chat.notificationSettings': '通知設定',
+  'kira.chat.persona': 'ペルソナ',
   'kira.chat.pinMessage': 'メッセージを固定',
   'kira.chat.privacySettings': 'プライバシー設定',
+  'kira.chat.progress.checkingTasks': 'Kiraがタスクを確認しています...',
+  'kira.chat.progress.checkingTime': 'Kiraが時間記録を確認しています...',
+  'kira.chat.progress.planningDay': 'Kiraが一日の計画を立てています...',
+  'kira.chat.progress.recurringTasks': 'Kiraが定期タスクを処理しています...',
+  'kira.chat.progress.updatingTasks': 'Kiraがタスクを更新しています...',
+  'kira.chat.progress.updatingTimer': 'Kiraがタイマーを更新しています...',
+  'kira.chat.progress.usingTool': 'Kiraが{tool}を使用しています...',
+  'kira.chat.progress.writingAnswer': 'Kiraが回答を作成しています...',
   'kira.chat.quoteMessage': 'メッセージを引用',
   'kira.chat.reconnecting': '再接続中...',
   'kira.chat.regenerateResponse': '応答を再生成',
@@ -495,6 +510,7 @@ export const ja = {
   'kira.chat.sendButtonHint':
     'クリックしてメッセージを送信するか、Enterキーを押してください',
   'kira.chat.sendMessage': 'メッセージを送信',
+  'kira.chat.stopGenerating': '生成を停止',
   'kira.chat.sending': '送信中…',
   'kira.chat.startConversation': '下にメッセージを入力して開始してください',
   'kira.chat.startThread': 'スレッドを開始',
@@ -625,6 +641,17 @@ export const ja = {
   'settings.ai.autoSuggestions': '自動提案',
   'settings.ai.autoSuggestionsDescription': 'プロアクティブな生産性提案を受信',
   'settings.ai.avgProcessingTime': '平均処理時間',
+  'settings.ai.batteryModel': 'バッテリー駆動時の軽量モデル',
+  'settings.ai.batteryModelDescription':
+    '省電力中に応答する Ollama モデル。空欄にするとローカルのリクエストをフォールバックチェーンに回します。',
+  'settings.ai.batteryModelPlaceholder': 'リクエストを延期',
+  'settings.ai.batteryThreshold': 'バッテリー駆動時に省電力',
+  'settings.ai.batteryThresholdDescription':
+    'ローカルモデルが省電力になるバッテリー残量。スレッドの要約などのバックグラウンド処理はそれまで待機します。',
+  'settings.ai.batteryThresholdPlaceholder': 'オフ',
+  'settings.ai.blockOverBudget': '予算超過時にクラウドへのリクエストをブロック',
+  'settings.ai.blockOverBudgetDescription':
+    '月間予算を使い切ったら、クラウドプロバイダーの代わりにローカルモデルで応答します',
   'settings.ai.contextSize': 'コンテキストサイズ',
   'settings.ai.contextSizeDescription':
     'トークン単位での最大コンテキストウィンドウサイズ',
@@ -764,7 +791,42 @@ export const ja = {
   'settings.ai.memoryUsage': 'メモリ使用量',
   'settings.ai.modelSelection': 'モデル選択',
   'settings.ai.modelType': 'AIモデルタイプ',
+  'settings.ai.monthlyBudget': '月間AI予算',
+  'settings.ai.monthlyBudgetDescription':
+    'クラウドAIの月間推定利用額（米ドル）。80%と100%に達すると警告します。',
+  'settings.ai.monthlyBudgetPlaceholder': '予算なし',
+  'settings.ai.ollamaModel': 'Ollamaモデル',
+  'settings.ai.ollamaModelDescription':
+    'このコンピューターのOllamaにダウンロード済みのモデルを使用します',
+  'settings.ai.ollamaNotRunning':
+    'Ollamaが起動していません。ローカルモデルを使うには起動してください。',
   'settings.ai.performanceRecommendations': 'パフォーマンス推奨事項',
+  'settings.ai.promptTemplates.action': 'アクション',
+  'settings.ai.promptTemplates.actionDescription':
+    'ネイティブのツール呼び出しに対応していないモデルがツールを呼び出す方法',
+  'settings.ai.promptTemplates.description':
+    'Kiraが応答に使うプロンプトを書き換えます。空欄にすると、表示されている組み込みのプロンプトを使用します。',
+  'settings.ai.promptTemplates.finalAnswer': '最終回答',
+  'settings.ai.promptTemplates.finalAnswerDescription':
+    'ネイティブのツール呼び出しに対応していないモデルが回答する方法',
+  'settings.ai.promptTemplates.loadError':
+    'プロンプトテンプレートを読み込めませんでした',
+  'settings.ai.promptTemplates.reset': 'デフォルトに戻す',
+  'settings.ai.promptTemplates.resetDone':
+    'プロンプトテンプレートをデフォルトに戻しました',
+  'settings.ai.promptTemplates.saveError':
+    'プロンプトテンプレートを保存できませんでした',
+  'settings.ai.promptTemplates.saved': 'プロンプトテンプレートを保存しました',
+  'settings.ai.promptTemplates.systemPersona': 'システムペルソナ',
+  'settings.ai.promptTemplates.systemPersonaDescription':
+    'Kiraの人物像、役割、ガイドライン。この後にアプリのコンテキストが追加されます。',
+  'settings.ai.promptTemplates.thought': '思考',
+  'settings.ai.promptTemplates.thoughtDescription':
+    'Kiraが各リクエストをどう考えるか。システムプロンプトの最後に追加されます',
+  'settings.ai.promptTemplates.title': 'プロンプトテンプレート',
+  'settings.ai.providerFallback': '他のプロバイダーに切り替え',
+  'settings.ai.providerFallbackDescription':
+    '現在のAIプロバイダーが失敗またはタイムアウトした場合、もう一方で再試行します',
   'settings.ai.resourceConfiguration': 'リソース設定',
   'settings.ai.resourceDataUnavailable': 'リソースデータが利用できません',
   'settings.ai.resourceManagement': 'リソース管理',
@@ -801,17 +863,25 @@ export const ja = {
   'settings.geminiApiKeyDescription': '無料のAPIキーを取得',
   'settings.geminiApiKeyPlaceholder': 'Gemini APIキーをここに貼り付け...',
   'settings.general': '一般',
+  'settings.general.addHoliday': '休日を追加',
   'settings.general.appPreferences': 'アプリの設定',
   'settings.general.dateFormat': '日付形式',
   'settings.general.dateFormatDescription':
     'アプリ全体での日付の表示方法を選択',
   'settings.general.endTime': '終了時刻',
+  'settings.general.holidays': '休日',
   'settings.general.language': '言語',
   'settings.general.languageDescription': '好みの言語を選択',
   'settings.general.notifications': '通知',
+  'settings.general.rollover': '未完了タスクの繰り越し',
+  'settings.general.rollover.backlog': 'バックログに移動',
+  'settings.general.rollover.off': 'その日のままにする',
+  'settings.general.rollover.today': '今日に移動',
+  'settings.general.rolloverDescription': '過去の日に予定され、完了しなかったタスクの移動先',
   'settings.general.startTime': '開始時刻',
   'settings.general.theme': 'テーマ',
   'settings.general.themeDescription': '好みのカラースキームを選択',
+  'settings.general.workingDays': '勤務日',
   'settings.general.workingHours': '勤務時間',
   'settings.platform': 'プラットフォーム',
   'settings.platformValue': 'デスクトップ (Tauri)',
@@ -1050,6 +1120,7 @@ export const ja = {
   'dataManagement.viewPrivacySettings': 'プライバシー設定を表示',
 
   // Database
+  'database.error.busy': 'データベースが使用中です。もう一度お試しください。',
   'database.error.dependencyNotFound': '依存タスク{taskId}が見つかりません',
   'database.error.healthCheckFailed':
     'データベースの健全性チェックに失敗しました',
@@ -1439,6 +1510,19 @@ export const ja = {
   'productivity.workingStyle.taskCompletionStyle': 'タスク完了スタイル',
   'productivity.workingStyle.title': 'あなたの作業スタイル',
 
+  // Quick capture
+  'quickCapture.placeholder':
+    'タスクを追加（例: Fix login bug #work !p1 @tomorrow）',
+
+  // Idle detection
+  'settings.idleThreshold': '離席検出',
+  'settings.idleThresholdDescription':
+    'タイマー作動中にコンピューターから離れていた時間について確認します。0で無効になります。',
+  'timer.idle.title': '離席していましたか？',
+  'timer.idle.message': 'タイマー作動中に{minutes}分間操作がありませんでした。この時間をセッションに含めますか？',
+  'timer.idle.keep': '時間を残す',
+  'timer.idle.discard': '時間を破棄',
+
   // Recurrence
   'recurrence.biweekly': '隔週',
   'recurrence.biweekly.description': '2週間ごとに繰り返す',
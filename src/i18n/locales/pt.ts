@@ -119,7 +119,12 @@ export const pt = {
   'ai.model.information': 'Informações do Modelo',
   'ai.model.local': 'Modelo Local',
   'ai.model.name': 'Nome',
+  'ai.model.ollama': 'Ollama (local)',
   'ai.model.size': 'Tamanho',
+  'ai.model.status.batteryDeferred':
+    'Bateria em {percent}%: pedidos locais adiados',
+  'ai.model.status.batterySmallerModel':
+    'Bateria em {percent}%: respondendo com {model}',
   'ai.model.status.downloading': 'Baixando... {progress}%',
   'ai.model.status.error': 'Erro',
   'ai.model.status.loading': 'Carregando...',
@@ -357,6 +362,8 @@ export const pt = {
   'kira.chat.conversationStarted': 'Conversa iniciada',
   'kira.chat.conversationUnarchived': 'Conversa desarquivada',
   'kira.chat.copyMessage': 'Copiar mensagem',
+  'kira.chat.defaultModel': 'Modelo padrão',
+  'kira.chat.defaultPersona': 'Kira (padrão)',
   'kira.chat.deleteConversation': 'Excluir conversa',
   'kira.chat.deleteMessage': 'Excluir mensagem',
   'kira.chat.deliveryFailed': 'Erro na entrega',
@@ -469,6 +476,7 @@ export const pt = {
   'kira.chat.messageUnblocked': 'Mensagem desbloqueada',
   'kira.chat.messageUnpinned': 'Mensagem desfixada',
   'kira.chat.messageUnread': 'Mensagem não lida',
+  'kira.chat.model': 'Modelo',
   'kira.chat.muteConversation': 'Silenciar conversa',
   'kira.chat.newMessagesBelow': 'Novas mensagens abaixo',
   'kira.chat.noMessages': 'Comece a conversa enviando uma mensagem abaixo.',
@@ -477,8 +485,19 @@ export const pt = {
   'kira.chat.noSearchResults': 'Sem resultados de busca',
   'kira.chat.noThreadSelected': 'Nenhuma Conversa Selecionada',
   'kira.chat.notificationSettings': 'Configurações de notificações',
+  'kira.chat.persona': 'Persona',
   'kira.chat.pinMessage': 'Fixar mensagem',
   'kira.chat.privacySettings': 'Configurações de privacidade',
+  'kira.chat.progress.checkingTasks': 'Kira está verificando suas tarefas...',
+  'kira.chat.progress.checkingTime':
+    'Kira está analisando seu registro de tempo...',
+  'kira.chat.progress.planningDay': 'Kira está planejando seu dia...',
+  'kira.chat.progress.recurringTasks':
+    'Kira está trabalhando em suas tarefas recorrentes...',
+  'kira.chat.progress.updatingTasks': 'Kira está atualizando suas tarefas...',
+  'kira.chat.progress.updatingTimer': 'Kira está atualizando seu cronômetro...',
+  'kira.chat.progress.usingTool': 'Kira está usando {tool}...',
+  'kira.chat.progress.writingAnswer': 'Kira está escrevendo a resposta...',
   'kira.chat.quoteMessage': 'Citar mensagem',
   'kira.chat.reconnecting': 'Reconectando...',
   'kira.chat.regenerateResponse': 'Regenerar resposta',
@@ -502,6 +521,7 @@ export const pt = {
     'Selecione uma conversa da barra lateral para começar a conversar com Kira IA.',
   'kira.chat.sendButtonHint': 'Clique para enviar mensagem ou pressione Enter',
   'kira.chat.sendMessage': 'Enviar mensagem',
+  'kira.chat.stopGenerating': 'Parar resposta',
   'kira.chat.sending': 'Enviando...',
   'kira.chat.startConversation': 'Comece digitando uma mensagem abaixo',
   'kira.chat.startThread': 'Iniciar conversa',
@@ -634,6 +654,18 @@ export const pt = {
   'settings.ai.autoSuggestionsDescription':
     'Receber sugestões proativas de produtividade',
   'settings.ai.avgProcessingTime': 'Tempo Médio de Processamento',
+  'settings.ai.batteryModel': 'Modelo menor na bateria',
+  'settings.ai.batteryModelDescription':
+    'Modelo do Ollama que responde enquanto economiza energia. Deixe vazio para passar os pedidos locais à cadeia de fallback.',
+  'settings.ai.batteryModelPlaceholder': 'Adiar pedidos',
+  'settings.ai.batteryThreshold': 'Economizar energia na bateria',
+  'settings.ai.batteryThresholdDescription':
+    'Carga da bateria a partir da qual o modelo local economiza energia. Tarefas em segundo plano, como resumos de conversas, aguardam.',
+  'settings.ai.batteryThresholdPlaceholder': 'Desligado',
+  'settings.ai.blockOverBudget':
+    'Bloquear solicitações na nuvem acima do orçamento',
+  'settings.ai.blockOverBudgetDescription':
+    'Quando o orçamento mensal acabar, responder com um modelo local em vez de um provedor na nuvem',
   'settings.ai.contextSize': 'Tamanho do Contexto',
   'settings.ai.contextSizeDescription':
     'Tamanho máximo da janela de contexto em tokens',
@@ -780,7 +812,42 @@ export const pt = {
   'settings.ai.memoryUsage': 'Uso de Memória',
   'settings.ai.modelSelection': 'Seleção de Modelo',
   'settings.ai.modelType': 'Tipo de Modelo IA',
+  'settings.ai.monthlyBudget': 'Orçamento mensal de IA',
+  'settings.ai.monthlyBudgetDescription':
+    'Gasto mensal estimado com IA na nuvem, em dólares americanos. Você é avisado em 80% e 100%.',
+  'settings.ai.monthlyBudgetPlaceholder': 'Sem orçamento',
+  'settings.ai.ollamaModel': 'Modelo do Ollama',
+  'settings.ai.ollamaModelDescription':
+    'Usa modelos já baixados no Ollama neste computador',
+  'settings.ai.ollamaNotRunning':
+    'O Ollama não está em execução. Inicie-o para usar seus modelos locais.',
   'settings.ai.performanceRecommendations': 'Recomendações de Performance',
+  'settings.ai.promptTemplates.action': 'Ação',
+  'settings.ai.promptTemplates.actionDescription':
+    'Como os modelos sem chamada de ferramentas nativa chamam uma ferramenta',
+  'settings.ai.promptTemplates.description':
+    'Reescreva os prompts com que a Kira responde. Deixe um campo vazio para usar o prompt integrado mostrado nele.',
+  'settings.ai.promptTemplates.finalAnswer': 'Resposta final',
+  'settings.ai.promptTemplates.finalAnswerDescription':
+    'Como os modelos sem chamada de ferramentas nativa dão sua resposta',
+  'settings.ai.promptTemplates.loadError':
+    'Falha ao carregar os modelos de prompt',
+  'settings.ai.promptTemplates.reset': 'Restaurar padrões',
+  'settings.ai.promptTemplates.resetDone':
+    'Modelos de prompt restaurados para o padrão',
+  'settings.ai.promptTemplates.saveError':
+    'Falha ao salvar os modelos de prompt',
+  'settings.ai.promptTemplates.saved': 'Modelos de prompt salvos',
+  'settings.ai.promptTemplates.systemPersona': 'Persona do sistema',
+  'settings.ai.promptTemplates.systemPersonaDescription':
+    'Quem a Kira é, seu papel e suas diretrizes. O contexto do aplicativo é adicionado depois.',
+  'settings.ai.promptTemplates.thought': 'Raciocínio',
+  'settings.ai.promptTemplates.thoughtDescription':
+    'Como a Kira raciocina sobre cada solicitação, no final do prompt do sistema',
+  'settings.ai.promptTemplates.title': 'Modelos de prompt',
+  'settings.ai.providerFallback': 'Recorrer a outros provedores',
+  'settings.ai.providerFallbackDescription':
+    'Repetir a solicitação no outro provedor de IA quando o atual falhar ou expirar',
   'settings.ai.resourceConfiguration': 'Configuração de Recursos',
   'settings.ai.resourceDataUnavailable': 'Dados de recursos indisponíveis',
   'settings.ai.resourceManagement': 'Gestão de Recursos',
@@ -819,17 +886,26 @@ export const pt = {
   'settings.geminiApiKeyDescription': 'Obtenha sua chave API gratuita de',
   'settings.geminiApiKeyPlaceholder': 'Cole sua chave API Gemini aqui...',
   'settings.general': 'Geral',
+  'settings.general.addHoliday': 'Adicionar feriado',
   'settings.general.appPreferences': 'Preferências do App',
   'settings.general.dateFormat': 'Formato de Data',
   'settings.general.dateFormatDescription':
     'Escolha como as datas são exibidas no app',
   'settings.general.endTime': 'Hora de Término',
+  'settings.general.holidays': 'Feriados',
   'settings.general.language': 'Idioma',
   'settings.general.languageDescription': 'Selecione seu idioma preferido',
   'settings.general.notifications': 'Notificações',
+  'settings.general.rollover': 'Transferir tarefas não concluídas',
+  'settings.general.rollover.backlog': 'Mover para o backlog',
+  'settings.general.rollover.off': 'Manter no seu dia',
+  'settings.general.rollover.today': 'Mover para hoje',
+  'settings.general.rolloverDescription':
+    'Para onde vão as tarefas agendadas para um dia passado que não foram concluídas',
   'settings.general.startTime': 'Hora de Início',
   'settings.general.theme': 'Tema',
   'settings.general.themeDescription': 'Escolha seu esquema de cores preferido',
+  'settings.general.workingDays': 'Dias úteis',
   'settings.general.workingHours': 'Horário de Trabalho',
   'settings.platform': 'Plataforma',
   'settings.platformValue': 'Desktop (Tauri)',
@@ -1069,6 +1145,7 @@ export const pt = {
   'dataManagement.viewPrivacySettings': 'Ver Configurações de Privacidade',
 
   // Database
+  'database.error.busy': 'O banco de dados está ocupado. Tente novamente.',
   'database.error.dependencyNotFound':
     'Tarefa de dependência {taskId} não encontrada',
   'database.error.healthCheckFailed':
@@ -1466,6 +1543,20 @@ export const pt = {
     'Estilo de Conclusão de Tarefas',
   'productivity.workingStyle.title': 'Seu Estilo de Trabalho',
 
+  // Quick capture
+  'quickCapture.placeholder':
+    'Adicionar uma tarefa, ex.: Fix login bug #work !p1 @tomorrow',
+
+  // Idle detection
+  'settings.idleThreshold': 'Detecção de Inatividade',
+  'settings.idleThresholdDescription':
+    'Perguntar sobre o tempo longe do computador enquanto o cronômetro corre. 0 a desativa.',
+  'timer.idle.title': 'Você estava ausente?',
+  'timer.idle.message':
+    'Sem atividade por {minutes} minutos enquanto o cronômetro corria. Manter este tempo na sessão?',
+  'timer.idle.keep': 'Manter Tempo',
+  'timer.idle.discard': 'Descartar Tempo',
+
   // Recurrence
   'recurrence.biweekly': 'Quinzenal',
   'recurrence.biweekly.description': 'Repete a cada 2 semanas',
@@ -120,7 +120,12 @@ export const vi = {
   'ai.model.information': 'Thông tin Mô hình',
   'ai.model.local': 'Mô hình Cục bộ',
   'ai.model.name': 'Tên',
+  'ai.model.ollama': 'Ollama (cục bộ)',
   'ai.model.size': 'Kích thước',
+  'ai.model.status.batteryDeferred':
+    'Pin còn {percent}%: tạm hoãn yêu cầu cục bộ',
+  'ai.model.status.batterySmallerModel':
+    'Pin còn {percent}%: đang trả lời bằng {model}',
   'ai.model.status.downloading': 'Đang tải xuống... {progress}%',
   'ai.model.status.error': 'Lỗi',
   'ai.model.status.loading': 'Đang tải...',
@@ -360,6 +365,8 @@ export const vi = {
   'kira.chat.conversationStarted': 'Cuộc trò chuyện đã bắt đầu',
   'kira.chat.conversationUnarchived': 'Cuộc trò chuyện đã được khôi phục',
   'kira.chat.copyMessage': 'Sao chép tin nhắn',
+  'kira.chat.defaultModel': 'Mô hình mặc định',
+  'kira.chat.defaultPersona': 'Kira (mặc định)',
   'kira.chat.deleteConversation': 'Xóa cuộc trò chuyện',
   'kira.chat.deleteMessage': 'Xóa tin nhắn',
   'kira.chat.deliveryFailed': 'Lỗi gửi tin nhắn',
@@ -471,6 +478,7 @@ export const vi = {
   'kira.chat.messageUnblocked': 'Tin nhắn đã mở khóa',
   'kira.chat.messageUnpinned': 'Tin nhắn đã bỏ ghim',
   'kira.chat.messageUnread': 'Tin nhắn chưa đọc',
+  'kira.chat.model': 'Mô hình',
   'kira.chat.muteConversation': 'Tắt thông báo cuộc trò chuyện',
   'kira.chat.newMessagesBelow': 'Có tin nhắn mới ở phía dưới',
   'kira.chat.noMessages':
@@ -480,8 +488,21 @@ export const vi = {
   'kira.chat.noSearchResults': 'Không tìm thấy kết quả nào',
   'kira.chat.noThreadSelected': 'Chưa chọn Chủ đề',
   'kira.chat.notificationSettings': 'Cài đặt thông báo',
+  'kira.chat.persona': 'Nhân cách',
   'kira.chat.pinMessage': 'Ghim tin nhắn',
   'kira.chat.privacySettings': 'Cài đặt quyền riêng tư',
+  'kira.chat.progress.checkingTasks': 'Kira đang kiểm tra công việc của bạn...',
+  'kira.chat.progress.checkingTime':
+    'Kira đang xem dữ liệu theo dõi thời gian của bạn...',
+  'kira.chat.progress.planningDay':
+    'Kira đang lập kế hoạch cho ngày của bạn...',
+  'kira.chat.progress.recurringTasks':
+    'Kira đang xử lý công việc định kỳ của bạn...',
+  'kira.chat.progress.updatingTasks': 'Kira đang cập nhật công việc của bạn...',
+  'kira.chat.progress.updatingTimer':
+    'Kira đang cập nhật bộ hẹn giờ của bạn...',
+  'kira.chat.progress.usingTool': 'Kira đang dùng {tool}...',
+  'kira.chat.progress.writingAnswer': 'Kira đang viết câu trả lời...',
   'kira.chat.quoteMessage': 'Trích dẫn tin nhắn',
   'kira.chat.reconnecting': 'Đang kết nối lại...',
   'kira.chat.regenerateResponse': 'Tạo lại câu trả lời',
@@ -505,6 +526,7 @@ export const vi = {
     'Chọn một chủ đề từ thanh bên để bắt đầu trò chuyện với Kira AI.',
   'kira.chat.sendButtonHint': 'Nhấp để gửi tin nhắn hoặc nhấn Enter',
   'kira.chat.sendMessage': 'Gửi tin nhắn',
+  'kira.chat.stopGenerating': 'Dừng tạo phản hồi',
   'kira.chat.sending': 'Đang gửi…',
   'kira.chat.startConversation': 'Bắt đầu bằng cách viết tin nhắn bên dưới',
   'kira.chat.startThread': 'Bắt đầu chuỗi hội thoại',
@@ -634,6 +656,17 @@ export const vi = {
   'settings.ai.autoSuggestions': 'Gợi ý tự động',
   'settings.ai.autoSuggestionsDescription': 'Nhận gợi ý năng suất chủ động',
   'settings.ai.avgProcessingTime': 'Thời gian xử lý trung bình',
+  'settings.ai.batteryModel': 'Mô hình nhỏ hơn khi dùng pin',
+  'settings.ai.batteryModelDescription':
+    'Mô hình Ollama trả lời khi tiết kiệm pin. Để trống để chuyển yêu cầu cục bộ sang chuỗi dự phòng.',
+  'settings.ai.batteryModelPlaceholder': 'Tạm hoãn yêu cầu',
+  'settings.ai.batteryThreshold': 'Tiết kiệm điện khi dùng pin',
+  'settings.ai.batteryThresholdDescription':
+    'Mức pin từ đó mô hình cục bộ tiết kiệm điện. Công việc nền như tóm tắt cuộc trò chuyện sẽ chờ.',
+  'settings.ai.batteryThresholdPlaceholder': 'Tắt',
+  'settings.ai.blockOverBudget': 'Chặn yêu cầu đám mây khi vượt ngân sách',
+  'settings.ai.blockOverBudgetDescription':
+    'Khi đã dùng hết ngân sách tháng, trả lời bằng mô hình cục bộ thay vì nhà cung cấp đám mây',
   'settings.ai.contextSize': 'Kích thước ngữ cảnh',
   'settings.ai.contextSizeDescription':
     'Kích thước cửa sổ ngữ cảnh tối đa tính bằng token',
@@ -775,7 +808,40 @@ export const vi = {
   'settings.ai.memoryUsage': 'Sử dụng Bộ nhớ',
   'settings.ai.modelSelection': 'Chọn Mô hình',
   'settings.ai.modelType': 'Loại Mô hình AI',
+  'settings.ai.monthlyBudget': 'Ngân sách AI hằng tháng',
+  'settings.ai.monthlyBudgetDescription':
+    'Chi phí AI đám mây ước tính mỗi tháng, tính bằng đô la Mỹ. Bạn sẽ được cảnh báo ở mức 80% và 100%.',
+  'settings.ai.monthlyBudgetPlaceholder': 'Không có ngân sách',
+  'settings.ai.ollamaModel': 'Mô hình Ollama',
+  'settings.ai.ollamaModelDescription':
+    'Dùng các mô hình đã tải về Ollama trên máy tính này',
+  'settings.ai.ollamaNotRunning':
+    'Ollama chưa chạy. Hãy khởi động để dùng các mô hình cục bộ.',
   'settings.ai.performanceRecommendations': 'Đề xuất Hiệu suất',
+  'settings.ai.promptTemplates.action': 'Hành động',
+  'settings.ai.promptTemplates.actionDescription':
+    'Cách các mô hình không hỗ trợ gọi công cụ gốc gọi một công cụ',
+  'settings.ai.promptTemplates.description':
+    'Viết lại các prompt mà Kira dùng để trả lời. Để trống một trường để dùng prompt có sẵn hiển thị trong đó.',
+  'settings.ai.promptTemplates.finalAnswer': 'Câu trả lời cuối cùng',
+  'settings.ai.promptTemplates.finalAnswerDescription':
+    'Cách các mô hình không hỗ trợ gọi công cụ gốc đưa ra câu trả lời',
+  'settings.ai.promptTemplates.loadError': 'Không thể tải mẫu prompt',
+  'settings.ai.promptTemplates.reset': 'Khôi phục mặc định',
+  'settings.ai.promptTemplates.resetDone':
+    'Đã khôi phục mẫu prompt về mặc định',
+  'settings.ai.promptTemplates.saveError': 'Không thể lưu mẫu prompt',
+  'settings.ai.promptTemplates.saved': 'Đã lưu mẫu prompt',
+  'settings.ai.promptTemplates.systemPersona': 'Persona hệ thống',
+  'settings.ai.promptTemplates.systemPersonaDescription':
+    'Kira là ai, vai trò và nguyên tắc của Kira. Ngữ cảnh ứng dụng được thêm vào sau.',
+  'settings.ai.promptTemplates.thought': 'Suy luận',
+  'settings.ai.promptTemplates.thoughtDescription':
+    'Cách Kira suy luận về mỗi yêu cầu, ở cuối prompt hệ thống',
+  'settings.ai.promptTemplates.title': 'Mẫu prompt',
+  'settings.ai.providerFallback': 'Chuyển sang nhà cung cấp khác',
+  'settings.ai.providerFallbackDescription':
+    'Thử lại yêu cầu với nhà cung cấp AI còn lại khi nhà cung cấp hiện tại lỗi hoặc hết thời gian',
   'settings.ai.resourceConfiguration': 'Cấu hình Tài nguyên',
   'settings.ai.resourceDataUnavailable': 'Dữ liệu tài nguyên không khả dụng',
   'settings.ai.resourceManagement': 'Quản lý Tài nguyên',
@@ -813,17 +879,26 @@ export const vi = {
   'settings.geminiApiKeyDescription': 'Lấy khóa API miễn phí từ',
   'settings.geminiApiKeyPlaceholder': 'Dán khóa API Gemini của bạn vào đây...',
   'settings.general': 'Chung',
+  'settings.general.addHoliday': 'Thêm ngày nghỉ',
   'settings.general.appPreferences': 'Tùy chọn ứng dụng',
   'settings.general.dateFormat': 'Định dạng ngày',
   'settings.general.dateFormatDescription':
     'Chọn cách hiển thị ngày trong ứng dụng',
   'settings.general.endTime': 'Giờ kết thúc',
+  'settings.general.holidays': 'Ngày nghỉ lễ',
   'settings.general.language': 'Ngôn ngữ',
   'settings.general.languageDescription': 'Chọn ngôn ngữ ưa thích của bạn',
   'settings.general.notifications': 'Thông báo',
+  'settings.general.rollover': 'Chuyển tiếp công việc chưa xong',
+  'settings.general.rollover.backlog': 'Chuyển về backlog',
+  'settings.general.rollover.off': 'Giữ nguyên ngày',
+  'settings.general.rollover.today': 'Chuyển sang hôm nay',
+  'settings.general.rolloverDescription':
+    'Công việc đã lên lịch cho ngày trước mà chưa hoàn thành sẽ được chuyển đến đâu',
   'settings.general.startTime': 'Giờ bắt đầu',
   'settings.general.theme': 'Giao diện',
   'settings.general.themeDescription': 'Chọn bảng màu ưa thích của bạn',
+  'settings.general.workingDays': 'Ngày làm việc',
   'settings.general.workingHours': 'Giờ làm việc',
   'settings.platform': 'Nền tảng',
   'settings.platformValue': 'Máy tính để bàn (Tauri)',
@@ -1062,6 +1137,7 @@ export const vi = {
   'dataManagement.viewPrivacySettings': 'Xem Cài đặt Quyền riêng tư',
 
   // Database
+  'database.error.busy': 'Cơ sở dữ liệu đang bận. Vui lòng thử lại.',
   'database.error.dependencyNotFound':
     'Không tìm thấy nhiệm vụ phụ thuộc {taskId}',
   'database.error.healthCheckFailed':
@@ -1452,6 +1528,20 @@ export const vi = {
   'productivity.workingStyle.taskCompletionStyle': 'Kiểu hoàn thành nhiệm vụ',
   'productivity.workingStyle.title': 'Phong cách làm việc của bạn',
 
+  // Quick capture
+  'quickCapture.placeholder':
+    'Thêm nhiệm vụ, ví dụ: Fix login bug #work !p1 @tomorrow',
+
+  // Idle detection
+  'settings.idleThreshold': 'Phát hiện Không hoạt động',
+  'settings.idleThresholdDescription':
+    'Hỏi về thời gian rời máy tính khi bộ hẹn giờ đang chạy. 0 để tắt.',
+  'timer.idle.title': 'Bạn đã rời đi?',
+  'timer.idle.message':
+    'Không có hoạt động trong {minutes} phút khi bộ hẹn giờ đang chạy. Giữ thời gian này trong phiên?',
+  'timer.idle.keep': 'Giữ Thời gian',
+  'timer.idle.discard': 'Bỏ Thời gian',
+
   // Recurrence
   'recurrence.biweekly': 'Hai tuần một lần',
   'recurrence.biweekly.description': 'Lặp lại 2 tuần một lần',
@@ -2,6 +2,8 @@ import React from 'react';
 import ReactDOM from 'react-dom/client';
 import { HeroUIProvider } from '@heroui/react';
 import App from './App';
+import QuickCaptureWindow from './components/QuickCaptureWindow';
+import { SettingsProvider } from './contexts/SettingsContext';
 import './App.css';
 import { errorTracker } from './utils/errorTracking';
 import { performanceMonitor } from './utils/performanceMonitoring';
@@ -16,10 +18,20 @@ performanceMonitor.setupWebVitalsMonitoring();
 // Setup translation development tools
 initializeTranslationDevTools();
 
+// The global quick capture shortcut opens this page in a window of its own
+const isQuickCapture =
+  new URLSearchParams(window.location.search).get('window') === 'quick-capture';
+
 ReactDOM.createRoot(document.getElementById('root') as HTMLElement).render(
   <React.StrictMode>
     <HeroUIProvider>
-      <App />
+      {isQuickCapture ? (
+        <SettingsProvider>
+          <QuickCaptureWindow />
+        </SettingsProvider>
+      ) : (
+        <App />
+      )}
     </HeroUIProvider>
   </React.StrictMode>
 );
@@ -1,4 +1,11 @@
-import { AIResponse, AppContext, PatternAnalysis } from '../../types';
+import {
+  AIResponse,
+  AppContext,
+  ConversationTurn,
+  PatternAnalysis,
+  ToolCallResult,
+} from '../../types';
+import { ProviderFailover } from '../../types/aiLogging';
 import { TranslationFunction } from './ToolExecutionEngine';
 
 /**
@@ -24,6 +31,37 @@ export interface ModelStatus {
   downloadProgress?: number;
   error?: string;
   modelInfo?: ModelInfo;
+  powerSaving?: PowerSavingStatus;
+}
+
+/**
+ * How a local model saves power while the machine is low on battery
+ */
+export interface PowerSavingStatus {
+  batteryPercent: number;
+  /**
+   * Smaller model answering instead, or none if requests are deferred
+   */
+  model?: string;
+}
+
+/**
+ * Options for processing a single message
+ */
+export interface ProcessMessageOptions {
+  /**
+   * ID the request can be cancelled by
+   */
+  requestId?: string;
+  /**
+   * Model to answer with instead of the service's default
+   */
+  model?: string;
+  /**
+   * Provider that failed this request before it was retried here, to record
+   * in the interaction log
+   */
+  failover?: ProviderFailover;
 }
 
 /**
@@ -36,9 +74,22 @@ export interface AIServiceInterface {
    * Process a user message and return AI response
    * @param message - User input message
    * @param context - Current application context
+   * @param options - Request options, e.g. the ID to cancel it by
    * @returns Promise resolving to AI response
    */
-  processMessage(message: string, context: AppContext): Promise<AIResponse>;
+  processMessage(
+    message: string,
+    context: AppContext,
+    options?: ProcessMessageOptions
+  ): Promise<AIResponse>;
+
+  /**
+   * Cancel a request still being generated (optional - may not be supported
+   * by all models)
+   * @param requestId - ID the request was started with
+   * @returns True if the request was running and is now cancelled
+   */
+  cancelRequest?(requestId: string): boolean;
 
   /**
    * Check if the service is initialized and ready to use
@@ -69,6 +120,25 @@ export interface AIServiceInterface {
    */
   analyzePatterns?(): Promise<PatternAnalysis>;
 
+  /**
+   * List the models this service can answer with (optional - may not be
+   * supported by all models)
+   * @returns Promise resolving to the model names
+   */
+  listModelNames?(): Promise<string[]>;
+
+  /**
+   * Fold a thread's older messages into its rolling summary (optional - may
+   * not be supported by all models)
+   * @param previousSummary - Summary of the messages before these, if any
+   * @param messages - Messages to fold in, oldest first
+   * @returns Promise resolving to the new summary
+   */
+  summarizeConversation?(
+    previousSummary: string | undefined,
+    messages: ConversationTurn[]
+  ): Promise<string>;
+
   /**
    * Initialize the service (for services that require setup)
    * @returns Promise that resolves when initialization is complete
@@ -126,3 +196,35 @@ export class ModelProcessingError extends AIServiceError {
     );
   }
 }
+
+export class GenerationTimeoutError extends AIServiceError {
+  constructor(public readonly timeoutMs: number) {
+    super(
+      `Generation timed out after ${Math.round(timeoutMs / 1000)} seconds`,
+      'GENERATION_TIMEOUT',
+      true
+    );
+  }
+}
+
+export class GenerationCancelledError extends AIServiceError {
+  constructor(
+    public readonly requestId: string,
+    public readonly toolCalls: ToolCallResult[] = []
+  ) {
+    super('Generation was cancelled', 'GENERATION_CANCELLED', true);
+  }
+}
+
+export class ProviderCircuitOpenError extends AIServiceError {
+  constructor(
+    public readonly provider: string,
+    public readonly retryAfterMs: number
+  ) {
+    super(
+      `${provider} is failing, requests to it are paused`,
+      'PROVIDER_CIRCUIT_OPEN',
+      true
+    );
+  }
+}
@@ -0,0 +1,67 @@
+import { ConversationMemory, ConversationTurn } from '../../types';
+
+// Characters per token of each provider's tokenizer, on average over the
+// English and code-like text Kira sends. Llama-family vocabularies are smaller
+// than Gemini's, so their tokens are shorter.
+const CHARS_PER_TOKEN: Record<string, number> = {
+  gemini: 4,
+  ollama: 3.5,
+};
+const DEFAULT_CHARS_PER_TOKEN = 4;
+
+/**
+ * Tokens kept free of the prompt for the model's reply
+ */
+export const RESPONSE_TOKEN_RESERVE = 1024;
+
+/**
+ * Count the tokens a text takes with a provider's tokenizer. Providers don't
+ * expose their tokenizers offline, so the count is estimated from the
+ * tokenizer's average characters per token; the counts providers report for
+ * each request are recorded separately.
+ */
+export function countTokens(text: string, provider: string): number {
+  const charsPerToken = CHARS_PER_TOKEN[provider] ?? DEFAULT_CHARS_PER_TOKEN;
+  return Math.ceil(text.length / charsPerToken);
+}
+
+/**
+ * Trim a thread's memory to fit `budget` tokens. The oldest messages are
+ * dropped first, then the summary is cut short if it doesn't fit alone.
+ * The messages kept still open with a user turn.
+ */
+export function fitConversation(
+  conversation: ConversationMemory | undefined,
+  budget: number,
+  provider: string
+): ConversationMemory | undefined {
+  if (!conversation) {
+    return undefined;
+  }
+
+  const available = Math.max(0, budget);
+  let summary = conversation.summary;
+  let summaryTokens = summary ? countTokens(summary, provider) : 0;
+  if (summary && summaryTokens > available) {
+    const ratio = summary.length / summaryTokens;
+    summary = summary.slice(0, Math.floor(available * ratio)) || undefined;
+    summaryTokens = summary ? countTokens(summary, provider) : 0;
+  }
+
+  // Keep the latest messages that fit after the summary
+  const recentMessages: ConversationTurn[] = [];
+  let used = summaryTokens;
+  for (let i = conversation.recentMessages.length - 1; i >= 0; i--) {
+    const turn = conversation.recentMessages[i];
+    used += countTokens(turn.content, provider);
+    if (used > available) {
+      break;
+    }
+    recentMessages.unshift(turn);
+  }
+  while (recentMessages.length > 0 && recentMessages[0].role !== 'user') {
+    recentMessages.shift();
+  }
+
+  return { summary, recentMessages };
+}
@@ -0,0 +1,199 @@
+import {
+  ConversationMemory,
+  ConversationTurn,
+  Thread,
+  ThreadMessage,
+} from '../../types';
+import { ThreadService } from '../database/repositories/ThreadService';
+
+export interface ConversationMemoryConfig {
+  /**
+   * Messages after the summary at which the older ones are summarized
+   */
+  summarizeAfter: number;
+  /**
+   * Latest messages kept as they are when summarizing
+   */
+  keepRecent: number;
+}
+
+export const DEFAULT_CONVERSATION_MEMORY_CONFIG: ConversationMemoryConfig = {
+  summarizeAfter: 20,
+  keepRecent: 8,
+};
+
+/**
+ * Folds messages into the summary so far and returns the new summary
+ */
+export type ConversationSummarizer = (
+  previousSummary: string | undefined,
+  messages: ConversationTurn[]
+) => Promise<string>;
+
+/**
+ * Keeps long threads small enough to send with every message. Older messages
+ * are folded into a rolling summary stored on the thread, and only the
+ * messages after it are sent as they are.
+ */
+export class ConversationMemoryService {
+  private config: ConversationMemoryConfig;
+  private threadService = new ThreadService();
+  private summarizing = new Set<string>();
+
+  constructor(config: Partial<ConversationMemoryConfig> = {}) {
+    this.config = this.validateConfig({
+      ...DEFAULT_CONVERSATION_MEMORY_CONFIG,
+      ...config,
+    });
+  }
+
+  getConfig(): ConversationMemoryConfig {
+    return { ...this.config };
+  }
+
+  updateConfig(config: Partial<ConversationMemoryConfig>): void {
+    this.config = this.validateConfig({ ...this.config, ...config });
+  }
+
+  /**
+   * What the assistant is given of a thread to answer the message
+   * `beforeMessageId`: the summary and the messages after it
+   */
+  async loadMemory(
+    thread: Thread,
+    beforeMessageId: string
+  ): Promise<ConversationMemory> {
+    const messages = await this.threadService.findMessagesPage(
+      thread.id,
+      this.config.summarizeAfter,
+      beforeMessageId
+    );
+    return this.buildMemory(thread, messages);
+  }
+
+  /**
+   * Build the memory from a thread's messages, oldest first
+   */
+  buildMemory(thread: Thread, messages: ThreadMessage[]): ConversationMemory {
+    const recent = this.unsummarized(thread, messages).slice(
+      -this.config.summarizeAfter
+    );
+    // Models expect the conversation to open with a user turn
+    const firstUserMessage = recent.findIndex(
+      message => message.type === 'user'
+    );
+    return {
+      summary: thread.summary,
+      recentMessages:
+        firstUserMessage === -1
+          ? []
+          : recent.slice(firstUserMessage).map(toConversationTurn),
+    };
+  }
+
+  /**
+   * Whether enough messages came after the summary to summarize again
+   */
+  needsSummary(thread: Thread, messages: ThreadMessage[]): boolean {
+    return (
+      this.unsummarized(thread, messages).length >= this.config.summarizeAfter
+    );
+  }
+
+  /**
+   * Fold all but the latest messages into the thread's summary once enough
+   * came after it. Returns the updated thread, or null when nothing was
+   * summarized
+   */
+  async summarizeIfNeeded(
+    thread: Thread,
+    summarize: ConversationSummarizer
+  ): Promise<Thread | null> {
+    if (this.summarizing.has(thread.id)) {
+      return null;
+    }
+    this.summarizing.add(thread.id);
+    try {
+      // A reply can take the thread past the threshold by two messages, so a
+      // page of twice the threshold holds everything after the summary
+      const messages = await this.threadService.findMessagesPage(
+        thread.id,
+        this.config.summarizeAfter * 2
+      );
+      return await this.foldOlderMessages(thread, messages, summarize);
+    } finally {
+      this.summarizing.delete(thread.id);
+    }
+  }
+
+  private async foldOlderMessages(
+    thread: Thread,
+    messages: ThreadMessage[],
+    summarize: ConversationSummarizer
+  ): Promise<Thread | null> {
+    if (!this.needsSummary(thread, messages)) {
+      return null;
+    }
+
+    const unsummarized = this.unsummarized(thread, messages);
+    const older = unsummarized.slice(
+      0,
+      unsummarized.length - this.config.keepRecent
+    );
+    const summary = (
+      await summarize(thread.summary, older.map(toConversationTurn))
+    ).trim();
+    if (!summary) {
+      return null;
+    }
+    return this.threadService.updateSummary(
+      thread.id,
+      summary,
+      older[older.length - 1].timestamp
+    );
+  }
+
+  private unsummarized(
+    thread: Thread,
+    messages: ThreadMessage[]
+  ): ThreadMessage[] {
+    const summarizedUntil = thread.summarizedUntil?.getTime();
+    return summarizedUntil === undefined
+      ? messages
+      : messages.filter(
+          message => message.timestamp.getTime() > summarizedUntil
+        );
+  }
+
+  private validateConfig(
+    config: ConversationMemoryConfig
+  ): ConversationMemoryConfig {
+    if (!Number.isInteger(config.keepRecent) || config.keepRecent < 0) {
+      throw new Error('keepRecent must be a non-negative whole number');
+    }
+    if (
+      !Number.isInteger(config.summarizeAfter) ||
+      config.summarizeAfter <= config.keepRecent
+    ) {
+      throw new Error('summarizeAfter must be a whole number above keepRecent');
+    }
+    return config;
+  }
+}
+
+function toConversationTurn(message: ThreadMessage): ConversationTurn {
+  return { role: message.type, content: message.content };
+}
+
+// Global instance, so summaries already running are seen across hooks
+let conversationMemoryServiceInstance: ConversationMemoryService | null = null;
+
+/**
+ * Get global ConversationMemoryService instance
+ */
+export function getConversationMemoryService(): ConversationMemoryService {
+  if (!conversationMemoryServiceInstance) {
+    conversationMemoryServiceInstance = new ConversationMemoryService();
+  }
+  return conversationMemoryServiceInstance;
+}
@@ -0,0 +1,228 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ChatGoogleGenerativeAI } from '@langchain/google-genai';
+import {
+  AIMessage,
+  BaseMessage,
+  HumanMessage,
+  ToolMessage,
+} from '@langchain/core/messages';
+import {
+  InteractionReplay,
+  ReplayComparison,
+  ReplayToolResult,
+} from '../../types';
+import { getKiraPilotTools } from './tools';
+
+/**
+ * Model round trips allowed before a replay gives up on reaching a final answer
+ */
+const MAX_REPLAY_STEPS = 8;
+
+export interface ReplayOptions {
+  model: string;
+  apiKey: string;
+}
+
+/**
+ * Debug service that replays a logged interaction against another model.
+ *
+ * The same user message is sent to the chosen model. Tool calls are never
+ * executed: they are answered with the results logged for the original
+ * interaction, matched by tool name in the order they ran. The outcome is
+ * stored as a comparison for evaluating model changes.
+ */
+export class InteractionReplayService {
+  /**
+   * Get the prompt and logged tool results of an interaction
+   */
+  async getReplay(interactionLogId: string): Promise<InteractionReplay> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'get_ai_interaction_replay',
+        { interactionLogId }
+      );
+      return {
+        interactionLogId: result.interaction_log_id as string,
+        modelType: result.model_type as string,
+        sessionId: result.session_id as string,
+        userMessage: result.user_message as string,
+        originalResponse: result.original_response as string,
+        toolResults: (result.tool_results as Record<string, unknown>[]).map(
+          toolResult => ({
+            toolName: toolResult.tool_name as string,
+            arguments: toolResult.arguments as string,
+            result: toolResult.result as string,
+            success: toolResult.success as boolean,
+          })
+        ),
+      };
+    } catch (error) {
+      throw new Error(`Failed to get interaction replay: ${error}`);
+    }
+  }
+
+  /**
+   * Replay an interaction against the given model and store the comparison
+   */
+  async replay(
+    interactionLogId: string,
+    options: ReplayOptions
+  ): Promise<ReplayComparison> {
+    const replay = await this.getReplay(interactionLogId);
+    const pending = [...replay.toolResults];
+    let mockedToolCalls = 0;
+    let unmatchedToolCalls = 0;
+    let replayResponse = '';
+    let error: string | undefined;
+
+    const startTime = Date.now();
+    try {
+      const model = new ChatGoogleGenerativeAI({
+        model: options.model,
+        maxOutputTokens: 2048,
+        apiKey: options.apiKey,
+      }).bindTools(getKiraPilotTools());
+
+      const messages: BaseMessage[] = [new HumanMessage(replay.userMessage)];
+      for (let step = 0; step < MAX_REPLAY_STEPS; step++) {
+        const response = (await model.invoke(messages)) as AIMessage;
+        messages.push(response);
+
+        if (!response.tool_calls?.length) {
+          replayResponse = this.messageText(response);
+          break;
+        }
+
+        for (const toolCall of response.tool_calls) {
+          const logged = this.takeLoggedResult(pending, toolCall.name);
+          if (logged) {
+            mockedToolCalls++;
+          } else {
+            unmatchedToolCalls++;
+          }
+          messages.push(
+            new ToolMessage({
+              tool_call_id: toolCall.id ?? toolCall.name,
+              content: logged
+                ? logged.result
+                : JSON.stringify({
+                    success: false,
+                    error: 'No logged result for this tool call',
+                  }),
+            })
+          );
+        }
+      }
+
+      if (!replayResponse) {
+        error = `No final answer after ${MAX_REPLAY_STEPS} steps`;
+      }
+    } catch (replayError) {
+      error =
+        replayError instanceof Error
+          ? replayError.message
+          : String(replayError);
+    }
+
+    return this.saveComparison({
+      interaction_log_id: interactionLogId,
+      replay_model: options.model,
+      replay_response: replayResponse,
+      mocked_tool_calls: mockedToolCalls,
+      unmatched_tool_calls: unmatchedToolCalls,
+      response_time: Date.now() - startTime,
+      error,
+    });
+  }
+
+  /**
+   * Get stored comparisons, newest first, optionally for one interaction
+   */
+  async getComparisons(interactionLogId?: string): Promise<ReplayComparison[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_ai_replay_comparisons',
+        { interactionLogId }
+      );
+      return result.map(comparison =>
+        this.transformComparisonFromBackend(comparison)
+      );
+    } catch (error) {
+      throw new Error(`Failed to get replay comparisons: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a stored comparison
+   */
+  async deleteComparison(id: string): Promise<void> {
+    try {
+      await invoke('delete_ai_replay_comparison', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete replay comparison: ${error}`);
+    }
+  }
+
+  private async saveComparison(
+    request: Record<string, unknown>
+  ): Promise<ReplayComparison> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'save_ai_replay_comparison',
+        { request }
+      );
+      return this.transformComparisonFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to save replay comparison: ${error}`);
+    }
+  }
+
+  private takeLoggedResult(
+    pending: ReplayToolResult[],
+    toolName: string
+  ): ReplayToolResult | undefined {
+    const index = pending.findIndex(result => result.toolName === toolName);
+    return index === -1 ? undefined : pending.splice(index, 1)[0];
+  }
+
+  private messageText(message: AIMessage): string {
+    if (typeof message.content === 'string') {
+      return message.content;
+    }
+    return message.content
+      .map(part => ('text' in part ? String(part.text) : ''))
+      .join('');
+  }
+
+  private transformComparisonFromBackend(
+    comparison: Record<string, unknown>
+  ): ReplayComparison {
+    return {
+      id: comparison.id as string,
+      interactionLogId: comparison.interaction_log_id as string,
+      originalModel: comparison.original_model as string,
+      replayModel: comparison.replay_model as string,
+      prompt: comparison.prompt as string,
+      originalResponse: comparison.original_response as string,
+      replayResponse: comparison.replay_response as string,
+      mockedToolCalls: comparison.mocked_tool_calls as number,
+      unmatchedToolCalls: comparison.unmatched_tool_calls as number,
+      similarity: comparison.similarity as number,
+      responseTime: (comparison.response_time as number | null) ?? undefined,
+      error: (comparison.error as string | null) ?? undefined,
+      createdAt: new Date(comparison.created_at as string),
+    };
+  }
+}
+
+let interactionReplayServiceInstance: InteractionReplayService | null = null;
+
+/**
+ * Get global InteractionReplayService instance
+ */
+export function getInteractionReplayService(): InteractionReplayService {
+  if (!interactionReplayServiceInstance) {
+    interactionReplayServiceInstance = new InteractionReplayService();
+  }
+  return interactionReplayServiceInstance;
+}
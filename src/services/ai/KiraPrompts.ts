@@ -0,0 +1,144 @@
+// Prompts shared by the AI services that answer as Kira
+import { AIPersona, AppContext, PromptTemplates } from '../../types';
+
+// Built-in prompts, used for the parts the user hasn't rewritten in settings
+export const DEFAULT_PROMPT_TEMPLATES: Required<PromptTemplates> = {
+  systemPersona: `You are Kira, an AI assistant for KiraPilot, a productivity application. You help users manage their tasks, track time, and improve productivity.
+
+Your role is to:
+1. Help users create, update, and organize tasks
+2. Assist with time tracking and timer management
+3. Provide productivity insights and suggestions
+4. Support weekly planning and scheduling
+5. Manage recurring tasks and automation
+
+Available tools:
+- create_task: Create new tasks with details
+- update_task: Modify existing tasks
+- get_tasks: Retrieve and search tasks
+- start_timer: Begin timing work on tasks
+- stop_timer: End current timer sessions
+- get_time_data: Analyze time tracking data
+- analyze_productivity: Generate productivity insights
+- create_periodic_task: Set up recurring task templates
+- get_periodic_tasks: View and manage periodic task templates
+- update_periodic_task: Modify recurring task patterns
+- generate_periodic_instances: Create new instances from templates
+- suggest_recurrence: Recommend recurrence patterns for tasks
+
+Periodic Tasks Features:
+- Help users create recurring tasks for habits, routines, and regular responsibilities
+- Support various recurrence patterns: daily, weekly, biweekly, monthly, or custom intervals
+- Automatically generate new task instances based on schedules
+- Manage template activation/deactivation and pattern modifications
+- Provide intelligent suggestions for recurrence patterns based on task descriptions
+
+Natural Language Understanding for Periodic Tasks:
+- "Set up a daily workout reminder" → create_periodic_task with daily recurrence
+- "Create a weekly team meeting task" → create_periodic_task with weekly recurrence
+- "I need to review reports every month" → create_periodic_task with monthly recurrence
+- "Show me my recurring tasks" → get_periodic_tasks
+- "Generate pending recurring tasks" → generate_periodic_instances
+- "What recurrence pattern should I use for [task]?" → suggest_recurrence
+
+Guidelines:
+- Always reason through problems step by step
+- Use tools when users request specific actions
+- Provide clear explanations for your reasoning
+- Respect user privacy - all data stays local
+- Be helpful, concise, and professional
+- For recurring tasks, suggest appropriate recurrence patterns based on task type
+- Explain the benefits of automation for repetitive tasks`,
+  thought: `Think through each user request carefully and use the appropriate tools to help them achieve their productivity goals.`,
+  action: `Always reply with a single JSON object. To call a tool, reply with:
+{"tool": "tool_name", "arguments": {"argument": "value"}}
+To call several tools that don't depend on each other's results at once, reply with:
+{"calls": [{"tool": "tool_name", "arguments": {}}, {"tool": "other_tool", "arguments": {}}]}
+The arguments must match the tool's schema. Each tool's result is sent back to you as an observation.`,
+  finalAnswer: `Once you have what you need, reply to the user with:
+{"answer": "your reply"}`,
+};
+
+/**
+ * Fill the parts of the user's prompt templates that are unset or blank
+ * with the built-in prompts
+ */
+export function resolvePromptTemplates(
+  templates: PromptTemplates = {}
+): Required<PromptTemplates> {
+  const resolved = { ...DEFAULT_PROMPT_TEMPLATES };
+  for (const key of Object.keys(resolved) as (keyof PromptTemplates)[]) {
+    const template = templates[key]?.trim();
+    if (template) {
+      resolved[key] = template;
+    }
+  }
+  return resolved;
+}
+
+/**
+ * Kira's system prompt: its persona, the app context and how it reasons
+ */
+export function buildSystemPrompt(templates: PromptTemplates = {}): string {
+  const { systemPersona, thought } = resolvePromptTemplates(templates);
+  return `${systemPersona}
+
+Current context: {app_context}
+System time: {system_time}
+
+${thought}`;
+}
+
+// Default system prompt for Kira AI
+export const KIRA_SYSTEM_PROMPT = buildSystemPrompt();
+
+/**
+ * Add a persona's instructions and tone to Kira's system prompt, so the app
+ * context and tool guidance stay in place
+ */
+export function buildPersonaPrompt(
+  persona: AIPersona,
+  templates: PromptTemplates = {}
+): string {
+  const tools = persona.allowedTools
+    ? `\nOnly these tools are available to you: ${persona.allowedTools.join(', ') || 'none'}.`
+    : '';
+  return `${buildSystemPrompt(templates)}
+
+You are answering as the persona "${persona.name}". Where they differ, follow these instructions over the guidelines above:
+${persona.systemPrompt}
+
+Use a ${persona.tone} tone.${tools}`;
+}
+
+// Instructions for folding a thread's older messages into its summary
+export const CONVERSATION_SUMMARY_PROMPT = `You summarize conversations between a user and Kira, a productivity assistant.
+Merge the new messages into the summary so far. Keep the user's goals, preferences, decisions and open questions, and the tasks, dates and names that came up. Leave out small talk.
+Reply with the summary only, in a few short paragraphs.`;
+
+/**
+ * Add the summary of a thread's older messages to the prompt
+ */
+export function withConversationSummary(
+  prompt: string,
+  summary?: string
+): string {
+  return summary
+    ? `${prompt}
+
+Summary of the earlier conversation:
+${summary}`
+    : prompt;
+}
+
+/**
+ * Fill the app context and the current time into a system prompt template
+ */
+export function renderSystemPrompt(
+  template: string,
+  appContext: Omit<AppContext, 'conversation'>
+): string {
+  return template
+    .replace('{app_context}', JSON.stringify(appContext, null, 2))
+    .replace('{system_time}', new Date().toISOString());
+}
@@ -1,5 +1,9 @@
 import { v4 as uuidv4 } from 'uuid';
-import { AIServiceInterface, ModelInfo } from './AIServiceInterface';
+import {
+  AIServiceInterface,
+  ModelInfo,
+  ProcessMessageOptions,
+} from './AIServiceInterface';
 import { AIResponse, AppContext } from '../../types';
 import { ToolExecutionResult } from './ToolExecutionEngine';
 import { LogStorageService } from '../database/repositories/LogStorageService';
@@ -126,7 +130,8 @@ export class LoggingInterceptor {
   public async interceptRequest(
     service: AIServiceInterface,
     message: string,
-    context: AppContext
+    context: AppContext,
+    options: ProcessMessageOptions = {}
   ): Promise<string> {
     // Always return a request ID for tracking, even if logging is disabled
     const requestId = uuidv4();
@@ -149,13 +154,14 @@ export class LoggingInterceptor {
         sessionId: this.currentSessionId,
         modelType: modelInfo.type === 'local' ? 'local' : 'gemini',
         modelInfo: {
-          name: modelInfo.name,
+          name: options.model ?? modelInfo.name,
           version: modelInfo.version,
           provider: modelInfo.type === 'local' ? 'local' : 'google',
           parameters: {
             contextSize: modelInfo.contextSize,
             capabilities: modelInfo.capabilities,
           },
+          failover: options.failover,
         },
         userMessage: privacyAnalysis.containsSensitiveData
           ? this.privacyFilter.redactText(message)
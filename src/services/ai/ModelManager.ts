@@ -4,19 +4,37 @@ import {
   ModelInfo,
   AIServiceError,
   ModelInitializationError,
+  GenerationTimeoutError,
+  GenerationCancelledError,
+  ProcessMessageOptions,
+  PowerSavingStatus,
 } from './AIServiceInterface';
 import { ReactAIService } from './ReactAIService';
+import { DEFAULT_OLLAMA_URL, OllamaService } from './OllamaService';
 import { TranslationFunction } from './ToolExecutionEngine';
-import { AIResponse, AppContext, PatternAnalysis } from '../../types';
+import {
+  AIResponse,
+  AiBudgetStatus,
+  AppContext,
+  PatternAnalysis,
+  PowerState,
+  UserPreferences,
+} from '../../types';
 import {
   LoggingInterceptor,
   getLoggingInterceptor,
 } from './LoggingInterceptor';
+import { LogStorageService } from '../database/repositories/LogStorageService';
+import { countTokens } from './ContextBudget';
+import { getPowerState, shouldSavePower } from './PowerSaving';
+
+// How long a reading of the power state is trusted, in milliseconds
+const POWER_STATE_TTL_MS = 30_000;
 
 /**
  * Model type enumeration
  */
-export type ModelType = 'gemini';
+export type ModelType = 'gemini' | 'ollama';
 
 /**
  * Model configuration interface
@@ -25,9 +43,29 @@ export interface ModelConfig {
   type: ModelType;
   apiKey?: string;
   localModelPath?: string;
+  baseUrl?: string; // Ollama daemon address
+  model?: string; // Ollama model name
   options?: Record<string, unknown>;
 }
 
+/**
+ * A model one of the providers can answer with
+ */
+export interface AvailableModel {
+  provider: ModelType;
+  name: string;
+}
+
+/**
+ * Options of a request routed through the model manager
+ */
+export interface ModelRequestOptions extends ProcessMessageOptions {
+  /**
+   * Provider of the model override, defaults to the current provider
+   */
+  provider?: ModelType;
+}
+
 /**
  * Model Manager - Handles switching between different AI services
  * Provides a unified interface for managing local and cloud AI models
@@ -42,6 +80,10 @@ export class ModelManager {
   private initializationPromise: Promise<void> | null = null;
 
   private currentSessionId: string | null = null;
+  private logStorageService = new LogStorageService();
+  private ollamaDetected = false;
+  private powerState: PowerState | null = null;
+  private powerStateReadAt = 0;
 
   constructor() {
     // Initialize logging interceptor if available
@@ -54,6 +96,20 @@ export class ModelManager {
 
     // Initialize with Gemini service by default
     this.initializeGeminiService();
+
+    // Offer Ollama once its daemon is found running
+    void this.detectOllama();
+  }
+
+  /**
+   * Check whether an Ollama daemon is running, making it available to
+   * switch to
+   * @param baseUrl - Daemon address, defaults to Ollama's local port
+   * @returns True if the daemon answered
+   */
+  async detectOllama(baseUrl: string = DEFAULT_OLLAMA_URL): Promise<boolean> {
+    this.ollamaDetected = await OllamaService.detect(baseUrl);
+    return this.ollamaDetected;
   }
 
   /**
@@ -124,6 +180,9 @@ export class ModelManager {
         if (type === 'gemini') {
           this.initializeGeminiService();
           service = this.services.get('gemini');
+        } else if (type === 'ollama') {
+          service = new OllamaService(config?.baseUrl, config?.model);
+          this.services.set('ollama', service);
         }
 
         if (!service) {
@@ -136,6 +195,14 @@ export class ModelManager {
         if (config.apiKey && 'setApiKey' in service) {
           (service as ReactAIService).setApiKey(config.apiKey);
         }
+        if (service instanceof OllamaService) {
+          if (config.baseUrl) {
+            service.setBaseUrl(config.baseUrl);
+          }
+          if (config.model) {
+            service.setModel(config.model);
+          }
+        }
       }
 
       // Initialize the service if it has an initialize method
@@ -228,7 +295,10 @@ export class ModelManager {
       };
     }
 
-    return this.currentService.getStatus();
+    const status = this.currentService.getStatus();
+    const powerSaving =
+      this.modelType === 'ollama' ? this.getPowerSaving() : null;
+    return powerSaving ? { ...status, powerSaving } : status;
   }
 
   /**
@@ -252,38 +322,129 @@ export class ModelManager {
   }
 
   /**
-   * Process a message using the current service with automatic fallback
+   * Process a message using the current service, or the provider the request
+   * overrides the model of. If it fails or times out, the request is retried
+   * on each provider of the fallback chain in turn.
    * @param message - User message
    * @param context - Application context
+   * @param options - Request options, e.g. the ID to cancel it by
    * @returns AI response
    */
   async processMessage(
     message: string,
-    context: AppContext
+    context: AppContext,
+    options?: ModelRequestOptions
   ): Promise<AIResponse> {
     if (!this.currentService) {
       throw new AIServiceError('No AI service available', 'NO_SERVICE', false);
     }
 
-    if (!this.currentService.isInitialized()) {
-      throw new AIServiceError(
-        'AI service not initialized',
-        'SERVICE_NOT_INITIALIZED',
-        true
-      );
+    let provider = options?.provider ?? this.modelType;
+    let service: AIServiceInterface;
+    if (provider === this.modelType) {
+      if (!this.currentService.isInitialized()) {
+        throw new AIServiceError(
+          'AI service not initialized',
+          'SERVICE_NOT_INITIALIZED',
+          true
+        );
+      }
+      service = this.currentService;
+    } else {
+      const requested = await this.readyProvider(provider);
+      if (!requested) {
+        throw new AIServiceError(
+          `Model provider ${provider} is not available`,
+          'PROVIDER_UNAVAILABLE',
+          true
+        );
+      }
+      service = requested;
     }
 
+    const fallbacks = this.getFallbackChain().filter(
+      type => type !== provider
+    );
+    let attemptOptions = options;
+
+    for (;;) {
+      try {
+        return await this.processWith(
+          provider,
+          service,
+          message,
+          context,
+          attemptOptions
+        );
+      } catch (error) {
+        // A cancelled request is not retried
+        if (error instanceof GenerationCancelledError) {
+          throw error;
+        }
+
+        const next = await this.nextFallback(fallbacks);
+        if (!next) {
+          throw error;
+        }
+
+        const reason = error instanceof Error ? error.message : String(error);
+        console.warn(`Failing over from ${provider} to ${next.type}:`, reason);
+        // The model override only applies to the provider it was picked from
+        attemptOptions = {
+          ...options,
+          model: undefined,
+          failover: { from: provider, reason },
+        };
+        provider = next.type;
+        service = next.service;
+      }
+    }
+  }
+
+  /**
+   * Process a message with one provider, recording its reliability metrics
+   */
+  private async processWith(
+    provider: ModelType,
+    service: AIServiceInterface,
+    message: string,
+    context: AppContext,
+    options?: ProcessMessageOptions
+  ): Promise<AIResponse> {
+    await this.enforceBudget(provider);
+    const attemptOptions = await this.applyPowerSaving(provider, options);
+
+    const model = attemptOptions?.model ?? service.getModelInfo()?.name;
+    const startTime = Date.now();
     try {
-      const response = await this.currentService.processMessage(
+      const response = await service.processMessage(
         message,
-        context
+        context,
+        attemptOptions
       );
 
+      // Cost is tracked from the provider's own counts when it reports them
+      this.recordProviderRequest(
+        provider,
+        model,
+        startTime,
+        undefined,
+        response.usage ?? {
+          inputTokens: countTokens(message + JSON.stringify(context), provider),
+          outputTokens: countTokens(response.message, provider),
+        }
+      );
       return response;
     } catch (error) {
+      // A cancelled request says nothing about the provider's health
+      if (error instanceof GenerationCancelledError) {
+        throw error;
+      }
+
+      this.recordProviderRequest(provider, model, startTime, error);
       console.error(
         'Error processing message with',
-        this.modelType,
+        provider,
         'service:',
         error
       );
@@ -301,6 +462,85 @@ export class ModelManager {
     }
   }
 
+  /**
+   * Take the next provider of the fallback chain that is ready to answer,
+   * initializing it if needed. Providers that can't be readied are skipped.
+   * @param fallbacks - Providers still to try, consumed from the front
+   */
+  private async nextFallback(
+    fallbacks: ModelType[]
+  ): Promise<{ type: ModelType; service: AIServiceInterface } | null> {
+    for (let type = fallbacks.shift(); type; type = fallbacks.shift()) {
+      const service = await this.readyProvider(type);
+      if (service) {
+        return { type, service };
+      }
+    }
+    return null;
+  }
+
+  /**
+   * Get the service of a provider ready to answer, creating and initializing
+   * it if needed
+   * @param type - Provider to ready
+   * @returns The service, or null if the provider can't be readied
+   */
+  private async readyProvider(
+    type: ModelType
+  ): Promise<AIServiceInterface | null> {
+    const service = this.getOrCreateService(type);
+    if (!service) {
+      return null;
+    }
+
+    if (!service.isInitialized() && service.initialize) {
+      try {
+        await service.initialize();
+      } catch (error) {
+        console.warn(`Model provider ${type} is unavailable:`, error);
+        return null;
+      }
+    }
+    return service.isInitialized() ? service : null;
+  }
+
+  /**
+   * Get the service of a provider, creating an Ollama service from the
+   * user's AI settings if there is none yet
+   */
+  private getOrCreateService(type: ModelType): AIServiceInterface | null {
+    const service = this.services.get(type);
+    if (service || type !== 'ollama') {
+      return service ?? null;
+    }
+
+    const { ollamaUrl, ollamaModel } = this.getAISettings();
+    const ollamaService = new OllamaService(ollamaUrl, ollamaModel);
+    if (this.translationFunction) {
+      ollamaService.setTranslationFunction(this.translationFunction);
+    }
+    if (this.loggingInterceptor) {
+      ollamaService.setLoggingInterceptor(this.loggingInterceptor);
+    }
+    this.services.set(type, ollamaService);
+    return ollamaService;
+  }
+
+  /**
+   * Cancel a request still being generated, by whichever provider is
+   * answering it
+   * @param requestId - ID the request was started with
+   * @returns True if the request was running and is now cancelled
+   */
+  cancelRequest(requestId: string): boolean {
+    for (const service of this.services.values()) {
+      if (service.cancelRequest?.(requestId)) {
+        return true;
+      }
+    }
+    return false;
+  }
+
   /**
    * Set translation function for all services
    * @param fn - Translation function
@@ -352,6 +592,191 @@ export class ModelManager {
     return null;
   }
 
+  /**
+   * Record latency and outcome of a provider request for reliability metrics.
+   * Recording failures never affect the request itself.
+   * @param provider - Provider that handled the request
+   * @param model - Model that answered it
+   * @param startTime - Time the request was sent, in milliseconds
+   * @param error - Error thrown by the request, if it failed
+   * @param usage - Estimated tokens sent and received, for cost tracking
+   */
+  private async recordProviderRequest(
+    provider: ModelType,
+    model: string | undefined,
+    startTime: number,
+    error?: unknown,
+    usage?: { inputTokens: number; outputTokens: number }
+  ): Promise<void> {
+    const latency = Date.now() - startTime;
+    const errorCode =
+      error instanceof AIServiceError
+        ? error.code
+        : error !== undefined
+          ? 'SERVICE_ERROR'
+          : undefined;
+    const errorText =
+      error instanceof Error ? `${error.message} ${errorCode}` : '';
+
+    try {
+      await this.logStorageService.recordProviderRequest({
+        provider,
+        model,
+        latency,
+        success: error === undefined,
+        timedOut:
+          error instanceof GenerationTimeoutError ||
+          /time(d)?[ _-]?out/i.test(errorText),
+        errorCode,
+        inputTokens: usage?.inputTokens,
+        outputTokens: usage?.outputTokens,
+      });
+    } catch (recordError) {
+      console.warn('Failed to record provider metrics:', recordError);
+      return;
+    }
+
+    const budget = this.getMonthlyBudget();
+    if (usage && budget) {
+      try {
+        await this.logStorageService.checkAiBudget(budget);
+      } catch (budgetError) {
+        console.warn('Failed to check AI budget:', budgetError);
+      }
+    }
+  }
+
+  /**
+   * Refuse requests to remote providers once this month's estimated spend
+   * reaches the budget, if the user chose to block rather than be warned.
+   * The refusal fails over to the next provider of the fallback chain, so a
+   * local model can still answer.
+   */
+  private async enforceBudget(provider: ModelType): Promise<void> {
+    const budget = this.getMonthlyBudget();
+    if (
+      provider === 'ollama' ||
+      !budget ||
+      this.getAISettings().budgetAction !== 'block'
+    ) {
+      return;
+    }
+
+    let status: AiBudgetStatus;
+    try {
+      status = await this.logStorageService.checkAiBudget(budget);
+    } catch (budgetError) {
+      console.warn('Failed to check AI budget:', budgetError);
+      return;
+    }
+    if (status.spent >= status.budget) {
+      throw new AIServiceError(
+        `Monthly AI budget of $${budget.toFixed(2)} reached`,
+        'BUDGET_EXCEEDED',
+        false
+      );
+    }
+  }
+
+  /**
+   * Keep the local model light while on battery below the user's threshold:
+   * answer with the smaller model they picked, or else defer the request so
+   * it fails over to the next provider of the fallback chain
+   */
+  private async applyPowerSaving(
+    provider: ModelType,
+    options?: ProcessMessageOptions
+  ): Promise<ProcessMessageOptions | undefined> {
+    if (provider !== 'ollama') {
+      return options;
+    }
+
+    await this.refreshPowerState();
+    const powerSaving = this.getPowerSaving();
+    if (!powerSaving) {
+      return options;
+    }
+    if (powerSaving.model) {
+      return { ...options, model: powerSaving.model };
+    }
+    throw new AIServiceError(
+      `Local model deferred on battery at ${powerSaving.batteryPercent}%`,
+      'POWER_SAVING',
+      true
+    );
+  }
+
+  /**
+   * Whether background work of the current provider, such as summarizing
+   * threads, should wait: the local model is saving power on battery
+   */
+  async shouldDeferBackgroundWork(): Promise<boolean> {
+    if (this.modelType !== 'ollama') {
+      return false;
+    }
+    await this.refreshPowerState();
+    return this.getPowerSaving() !== null;
+  }
+
+  /**
+   * Read whether the machine runs on battery, reusing a recent reading
+   * @returns The power state, or null where the platform doesn't tell
+   */
+  async refreshPowerState(): Promise<PowerState | null> {
+    if (Date.now() - this.powerStateReadAt >= POWER_STATE_TTL_MS) {
+      this.powerState = await getPowerState();
+      this.powerStateReadAt = Date.now();
+    }
+    return this.powerState;
+  }
+
+  /**
+   * How the local model saves power by the last power state read, or null
+   * while it needn't
+   */
+  private getPowerSaving(): PowerSavingStatus | null {
+    const { batteryThreshold, batteryModel } = this.getAISettings();
+    const state = this.powerState;
+    if (!state || !shouldSavePower(state, batteryThreshold)) {
+      return null;
+    }
+    return {
+      batteryPercent: state.batteryPercent ?? 0,
+      model: batteryModel || undefined,
+    };
+  }
+
+  /**
+   * Monthly budget for remote providers from the user's AI settings, if set
+   */
+  private getMonthlyBudget(): number | undefined {
+    const budget = this.getAISettings().monthlyBudget;
+    return typeof budget === 'number' && budget > 0 ? budget : undefined;
+  }
+
+  /**
+   * Providers to retry a failed request on, in order, from the user's AI
+   * settings
+   */
+  private getFallbackChain(): ModelType[] {
+    const chain = this.getAISettings().fallbackChain;
+    return Array.isArray(chain)
+      ? chain.filter(type => this.isModelAvailable(type))
+      : [];
+  }
+
+  /**
+   * The user's stored AI settings, or none if they can't be read
+   */
+  private getAISettings(): Partial<UserPreferences['aiSettings']> {
+    try {
+      const stored = localStorage.getItem('kirapilot-preferences');
+      return (stored ? JSON.parse(stored).aiSettings : undefined) ?? {};
+    } catch {
+      return {};
+    }
+  }
+
   /**
    * Log service errors for debugging and monitoring
    * @param modelType - Type of model that encountered the error
@@ -402,7 +827,32 @@ export class ModelManager {
    * @returns Array of available model types
    */
   getAvailableModels(): ModelType[] {
-    return ['gemini'];
+    return this.ollamaDetected || this.services.has('ollama')
+      ? ['gemini', 'ollama']
+      : ['gemini'];
+  }
+
+  /**
+   * List the models of every available provider, so a request can pick one.
+   * Providers whose models can't be listed are left out.
+   * @returns Models grouped by provider, in provider order
+   */
+  async listAvailableModels(): Promise<AvailableModel[]> {
+    const catalog: AvailableModel[] = [];
+    for (const provider of this.getAvailableModels()) {
+      const service = this.getOrCreateService(provider);
+      if (!service?.listModelNames) {
+        continue;
+      }
+
+      try {
+        const names = await service.listModelNames();
+        catalog.push(...names.map(name => ({ provider, name })));
+      } catch (error) {
+        console.warn(`Failed to list ${provider} models:`, error);
+      }
+    }
+    return catalog;
   }
 
   /**
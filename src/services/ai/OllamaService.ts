@@ -0,0 +1,907 @@
+import { ToolDefinition } from '@langchain/core/language_models/base';
+import { convertToOpenAITool } from '@langchain/core/utils/function_calling';
+import {
+  AIAction,
+  AIPersona,
+  AIResponse,
+  AppContext,
+  ConversationTurn,
+  PromptTemplates,
+  TokenUsage,
+  ToolCallResult,
+} from '../../types';
+import {
+  AIServiceInterface,
+  GenerationCancelledError,
+  GenerationTimeoutError,
+  ModelInfo,
+  ModelInitializationError,
+  ModelProcessingError,
+  ModelStatus,
+  ProcessMessageOptions,
+} from './AIServiceInterface';
+import {
+  ToolExecutionEngine,
+  TranslationFunction,
+  getToolExecutionEngine,
+} from './ToolExecutionEngine';
+import {
+  LoggingInterceptor,
+  getLoggingInterceptor,
+} from './LoggingInterceptor';
+import {
+  CONVERSATION_SUMMARY_PROMPT,
+  buildPersonaPrompt,
+  buildSystemPrompt,
+  renderSystemPrompt,
+  withConversationSummary,
+} from './KiraPrompts';
+import {
+  describeToolsForPrompt,
+  parseToolReply,
+  toolReplySchema,
+} from './TextToolCalling';
+import {
+  RESPONSE_TOKEN_RESERVE,
+  countTokens,
+  fitConversation,
+} from './ContextBudget';
+import { getKiraPilotTools } from './tools';
+import { ReActStepEmitter } from './ReActSteps';
+import { ResponseStreamEmitter } from './ResponseStream';
+import {
+  getAIPersonaRepository,
+  getPreferencesRepository,
+} from '../database/repositories';
+
+// Address the Ollama daemon listens on unless configured otherwise
+export const DEFAULT_OLLAMA_URL = 'http://localhost:11434';
+
+// How long to wait for the daemon when checking whether it is running
+const DETECT_TIMEOUT_MS = 1_500;
+
+// Generations running longer than this are aborted unless configured otherwise
+const DEFAULT_REQUEST_TIMEOUT_MS = 300_000;
+
+// Model calls per message, so a model stuck calling tools can't loop forever
+const MAX_TOOL_ROUNDS = 8;
+
+// Context window requested from the daemon unless the user configured one
+const DEFAULT_CONTEXT_LENGTH = 4096;
+
+const TOOLS = getKiraPilotTools();
+
+/**
+ * A model already downloaded to the Ollama daemon
+ */
+export interface OllamaModel {
+  name: string;
+  size: number; // bytes
+  modifiedAt: Date;
+  family?: string;
+  parameterSize?: string;
+}
+
+interface OllamaToolCall {
+  function: { name: string; arguments: Record<string, unknown> };
+}
+
+interface OllamaMessage {
+  role: 'system' | 'user' | 'assistant' | 'tool';
+  content: string;
+  tool_calls?: OllamaToolCall[];
+  tool_name?: string;
+}
+
+interface OllamaChatResponse {
+  message: OllamaMessage;
+  prompt_eval_count?: number;
+  eval_count?: number;
+}
+
+/**
+ * A model reply with the tool calls it asks for or its answer, and whether
+ * the tools were described in text rather than called natively
+ */
+interface OllamaChatTurn {
+  reply: OllamaMessage;
+  calls: OllamaToolCall[];
+  answer: string;
+  textTools: boolean;
+  usage: TokenUsage;
+}
+
+/**
+ * AI service backed by a local Ollama daemon, so models the user already
+ * downloaded can answer without an API key and without data leaving the
+ * machine. Runs the same tools as the Gemini service in a plain
+ * call-model/call-tools loop over Ollama's chat API. Models without native
+ * tool calling get the tools described in the system prompt instead, with
+ * their replies constrained to JSON tool calls or answers.
+ */
+export class OllamaService implements AIServiceInterface {
+  private baseUrl: string;
+  private model: string | null;
+  private models: OllamaModel[] = [];
+  private status: 'not_initialized' | 'loading' | 'ready' | 'error' =
+    'not_initialized';
+  private error: string | undefined;
+  private toolExecutionEngine: ToolExecutionEngine;
+  private loggingInterceptor: LoggingInterceptor | null = null;
+  private requestTimeout: number = DEFAULT_REQUEST_TIMEOUT_MS;
+  // Aborts each running request by its ID
+  private activeRequests = new Map<string, (error: Error) => void>();
+  // Models the daemon refused native tool calls for
+  private textToolModels = new Set<string>();
+
+  constructor(baseUrl: string = DEFAULT_OLLAMA_URL, model?: string) {
+    this.baseUrl = baseUrl.replace(/\/+$/, '');
+    this.model = model ?? null;
+    this.toolExecutionEngine = getToolExecutionEngine();
+
+    try {
+      this.loggingInterceptor = getLoggingInterceptor();
+    } catch {
+      // Logging interceptor not initialized yet, will be set later
+      this.loggingInterceptor = null;
+    }
+  }
+
+  /**
+   * Check whether the Ollama daemon is running at `baseUrl`
+   */
+  static async detect(baseUrl: string = DEFAULT_OLLAMA_URL): Promise<boolean> {
+    const controller = new AbortController();
+    const timer = setTimeout(() => controller.abort(), DETECT_TIMEOUT_MS);
+    try {
+      const response = await fetch(
+        `${baseUrl.replace(/\/+$/, '')}/api/version`,
+        { signal: controller.signal }
+      );
+      return response.ok;
+    } catch {
+      return false;
+    } finally {
+      clearTimeout(timer);
+    }
+  }
+
+  /**
+   * Connect to the daemon and pick the configured model, or the first one
+   * downloaded if none is configured or it is missing
+   */
+  async initialize(): Promise<void> {
+    this.status = 'loading';
+    this.error = undefined;
+    try {
+      if (!(await OllamaService.detect(this.baseUrl))) {
+        throw new ModelInitializationError(
+          'ollama',
+          `Ollama is not running at ${this.baseUrl}`
+        );
+      }
+
+      this.models = await this.listModels();
+      if (this.models.length === 0) {
+        throw new ModelInitializationError(
+          'ollama',
+          'No models are downloaded. Pull one with `ollama pull <model>`'
+        );
+      }
+      if (!this.models.some(model => model.name === this.model)) {
+        this.model = this.models[0].name;
+      }
+      this.status = 'ready';
+    } catch (error) {
+      this.status = 'error';
+      this.error = error instanceof Error ? error.message : String(error);
+      throw error;
+    }
+  }
+
+  /**
+   * List the models downloaded to the daemon
+   */
+  async listModels(): Promise<OllamaModel[]> {
+    const result = await this.request<{
+      models: {
+        name: string;
+        size: number;
+        modified_at: string;
+        details?: { family?: string; parameter_size?: string };
+      }[];
+    }>('/api/tags');
+    return result.models.map(model => ({
+      name: model.name,
+      size: model.size,
+      modifiedAt: new Date(model.modified_at),
+      family: model.details?.family,
+      parameterSize: model.details?.parameter_size,
+    }));
+  }
+
+  /**
+   * Names of the models downloaded to the daemon
+   */
+  async listModelNames(): Promise<string[]> {
+    return (await this.listModels()).map(model => model.name);
+  }
+
+  /**
+   * Complete a single prompt with the current model
+   */
+  async generate(
+    prompt: string,
+    options: { system?: string; signal?: AbortSignal } = {}
+  ): Promise<string> {
+    const result = await this.request<{ response: string }>(
+      '/api/generate',
+      {
+        model: this.requireModel(),
+        prompt,
+        system: options.system,
+        stream: false,
+      },
+      options.signal
+    );
+    return result.response;
+  }
+
+  /**
+   * Embed one or more texts with the current model, one vector per text
+   */
+  async embed(input: string | string[]): Promise<number[][]> {
+    const result = await this.request<{ embeddings: number[][] }>(
+      '/api/embed',
+      { model: this.requireModel(), input }
+    );
+    return result.embeddings;
+  }
+
+  /**
+   * Process a user message, calling tools until the model answers in text
+   */
+  async processMessage(
+    message: string,
+    context: AppContext,
+    options: ProcessMessageOptions = {}
+  ): Promise<AIResponse> {
+    const startTime = Date.now();
+    let requestId: string | null = null;
+    const toolCalls: ToolCallResult[] = [];
+
+    try {
+      const model = options.model ?? this.requireModel();
+
+      // Intercept request for logging
+      if (this.loggingInterceptor) {
+        try {
+          requestId = await this.loggingInterceptor.interceptRequest(
+            this,
+            message,
+            context,
+            options
+          );
+        } catch (error) {
+          console.warn('Failed to intercept request for logging:', error);
+        }
+      }
+
+      // Answer as the thread's persona, if it has one
+      const { conversation, ...appContext } = context;
+      const persona = context.personaId
+        ? await this.loadPersona(context.personaId)
+        : null;
+      const templates = await this.loadPromptTemplates();
+      const allowedTools = persona?.allowedTools;
+      const tools = allowedTools
+        ? TOOLS.filter(tool => allowedTools.includes(tool.name))
+        : TOOLS;
+      const toolDefinitions = tools.map(tool => convertToOpenAITool(tool));
+
+      // Trim the thread's memory so the prompt fits the context window
+      const contextLength =
+        appContext.preferences?.aiSettings?.localModelConfig?.contextSize ??
+        DEFAULT_CONTEXT_LENGTH;
+      const template = persona
+        ? buildPersonaPrompt(persona, templates)
+        : buildSystemPrompt(templates);
+      const promptTokens = countTokens(
+        renderSystemPrompt(template, appContext) +
+          message +
+          JSON.stringify(toolDefinitions),
+        'ollama'
+      );
+      const memory = fitConversation(
+        conversation,
+        contextLength - RESPONSE_TOKEN_RESERVE - promptTokens,
+        'ollama'
+      );
+
+      const messages: OllamaMessage[] = [
+        {
+          role: 'system',
+          content: renderSystemPrompt(
+            withConversationSummary(template, memory?.summary),
+            appContext
+          ),
+        },
+        ...(memory?.recentMessages ?? []),
+        { role: 'user', content: message },
+      ];
+      const usage: TokenUsage = { inputTokens: 0, outputTokens: 0 };
+
+      const chainId = options.requestId ?? crypto.randomUUID();
+      const reActSteps = new ReActStepEmitter(chainId);
+      const responseStream = new ResponseStreamEmitter(chainId);
+      const responseMessage = await this.runWithWatchdog(async signal => {
+        for (let round = 0; round < MAX_TOOL_ROUNDS; round++) {
+          const turn = await this.chat(
+            model,
+            messages,
+            toolDefinitions,
+            templates,
+            contextLength,
+            signal,
+            responseStream
+          );
+          const { reply, calls, answer, textTools } = turn;
+          usage.inputTokens += turn.usage.inputTokens;
+          usage.outputTokens += turn.usage.outputTokens;
+          messages.push(reply);
+
+          if (calls.length === 0) {
+            reActSteps.finalAnswer(answer);
+            return answer;
+          }
+          if (!textTools) {
+            responseStream.reset();
+            reActSteps.thought(reply.content);
+          }
+          const results = await this.callTools(
+            calls,
+            tools,
+            toolCalls,
+            reActSteps
+          );
+          if (textTools) {
+            // One message with every observation, so the model sees them
+            // together as a reply to its calls
+            messages.push({
+              role: 'user',
+              content: calls
+                .map(
+                  (call, i) =>
+                    `Observation from ${call.function.name}: ${results[i]}`
+                )
+                .join('\n\n'),
+            });
+          } else {
+            calls.forEach((call, i) =>
+              messages.push({
+                role: 'tool',
+                tool_name: call.function.name,
+                content: results[i],
+              })
+            );
+          }
+        }
+        throw new Error(
+          `Stopped after ${MAX_TOOL_ROUNDS} rounds of tool calls`
+        );
+      }, options.requestId);
+
+      const actions: AIAction[] = toolCalls.map(call => ({
+        type: call.tool.toUpperCase() as AIAction['type'],
+        parameters: call.arguments,
+        context,
+        confidence: call.success ? 100 : 0,
+        reasoning: `Selected ${call.tool} tool based on user request analysis and current context`,
+      }));
+      // Daemons that don't report counts leave them at zero
+      const reported =
+        usage.inputTokens + usage.outputTokens > 0 ? usage : undefined;
+      const response: AIResponse = {
+        message: responseMessage.trim() || "I've processed your request.",
+        actions,
+        suggestions: [],
+        context,
+        usage: reported,
+      };
+
+      // Intercept response for logging
+      if (this.loggingInterceptor && requestId) {
+        try {
+          await this.loggingInterceptor.interceptResponse(requestId, response, {
+            responseTime: Date.now() - startTime,
+            tokenCount: reported
+              ? reported.inputTokens + reported.outputTokens
+              : undefined,
+            modelInfo: this.getModelInfo(),
+            sessionId: this.loggingInterceptor.getCurrentSessionId(),
+            timestamp: new Date(),
+          });
+        } catch (error) {
+          console.warn('Failed to intercept response for logging:', error);
+        }
+      }
+
+      return response;
+    } catch (caughtError) {
+      // A cancelled request reports the tool calls made before it stopped
+      const error =
+        caughtError instanceof GenerationCancelledError
+          ? new GenerationCancelledError(caughtError.requestId, toolCalls)
+          : caughtError;
+      console.error('Ollama Service Error:', error);
+
+      // Intercept error for logging
+      if (this.loggingInterceptor && requestId) {
+        try {
+          await this.loggingInterceptor.interceptError(
+            requestId,
+            error as Error,
+            {
+              message,
+              context,
+              sessionId: this.loggingInterceptor.getCurrentSessionId(),
+              timestamp: new Date(startTime),
+              modelInfo: this.getModelInfo(),
+            }
+          );
+        } catch (logError) {
+          console.warn('Failed to intercept error for logging:', logError);
+        }
+      }
+
+      if (
+        error instanceof GenerationTimeoutError ||
+        error instanceof GenerationCancelledError
+      ) {
+        throw error;
+      }
+      throw new ModelProcessingError(
+        error instanceof Error ? error.message : 'Unknown error occurred'
+      );
+    }
+  }
+
+  /**
+   * Ask the model for its next reply, calling tools natively when the model
+   * supports it. Models the daemon refuses native tool calls for get the
+   * tools described in the system prompt, and their output is decoded
+   * against a JSON schema of the tool calls, so the calls always parse and
+   * match the tools' parameters.
+   *
+   * Native replies are streamed to `responseStream` as they are written.
+   * Replies with tools described in text are JSON and only make sense once
+   * complete, so they are not streamed.
+   */
+  private async chat(
+    model: string,
+    messages: OllamaMessage[],
+    tools: ToolDefinition[],
+    templates: PromptTemplates,
+    contextLength: number,
+    signal: AbortSignal,
+    responseStream: ResponseStreamEmitter
+  ): Promise<OllamaChatTurn> {
+    const options = { num_ctx: contextLength };
+    if (!this.textToolModels.has(model)) {
+      try {
+        const reply: OllamaMessage = { role: 'assistant', content: '' };
+        let usage: TokenUsage = { inputTokens: 0, outputTokens: 0 };
+        await this.requestStream<OllamaChatResponse>(
+          '/api/chat',
+          { model, messages, tools, options, stream: true },
+          signal,
+          chunk => {
+            reply.content += chunk.message?.content ?? '';
+            if (chunk.message?.tool_calls?.length) {
+              reply.tool_calls = [
+                ...(reply.tool_calls ?? []),
+                ...chunk.message.tool_calls,
+              ];
+            }
+            responseStream.token(chunk.message?.content ?? '');
+            // Only the last chunk carries the counts
+            if (chunk.eval_count !== undefined) {
+              usage = reportedUsage(chunk);
+            }
+          }
+        );
+        return {
+          reply,
+          calls: reply.tool_calls ?? [],
+          answer: reply.content,
+          textTools: false,
+          usage,
+        };
+      } catch (error) {
+        if (!/does not support tools/i.test(String(error))) {
+          throw error;
+        }
+        this.textToolModels.add(model);
+      }
+    }
+
+    const [system, ...rest] = messages;
+    const response = await this.request<OllamaChatResponse>(
+      '/api/chat',
+      {
+        model,
+        messages: [
+          {
+            ...system,
+            content: `${system.content}\n\n${describeToolsForPrompt(tools, templates)}`,
+          },
+          ...rest,
+        ],
+        format: toolReplySchema(tools),
+        options,
+        stream: false,
+      },
+      signal
+    );
+    const { calls, answer } = parseToolReply(response.message.content);
+    return {
+      reply: response.message,
+      calls: calls.map(call => ({ function: call })),
+      answer,
+      textTools: true,
+      usage: reportedUsage(response),
+    };
+  }
+
+  /**
+   * Run the tool calls of one turn. Calls that only read data don't depend
+   * on each other and run concurrently; a call that changes data runs on
+   * its own, after the calls before it, so the order the model asked for
+   * still holds where it matters.
+   * @returns The result of each call, in the order of `calls`
+   */
+  private async callTools(
+    calls: OllamaToolCall[],
+    tools: typeof TOOLS,
+    toolCalls: ToolCallResult[],
+    reActSteps: ReActStepEmitter
+  ): Promise<string[]> {
+    const isReadOnly = (call: OllamaToolCall) =>
+      this.toolExecutionEngine.isReadOnly(call.function.name);
+    const batches: OllamaToolCall[][] = [];
+    for (const call of calls) {
+      const batch = batches[batches.length - 1];
+      if (batch && isReadOnly(batch[0]) && isReadOnly(call)) {
+        batch.push(call);
+      } else {
+        batches.push([call]);
+      }
+    }
+
+    const results: string[] = [];
+    for (const batch of batches) {
+      const batchResults = await Promise.all(
+        batch.map(async call => {
+          reActSteps.toolCall(call.function.name, call.function.arguments);
+          const result = await this.callTool(call, tools, toolCalls);
+          reActSteps.toolResult(call.function.name, result);
+          return result;
+        })
+      );
+      results.push(...batchResults);
+    }
+    return results;
+  }
+
+  /**
+   * Run one tool call the model asked for, recording it in `toolCalls`.
+   * Failures are returned to the model as the tool's result so it can
+   * recover.
+   */
+  private async callTool(
+    call: OllamaToolCall,
+    tools: typeof TOOLS,
+    toolCalls: ToolCallResult[]
+  ): Promise<string> {
+    const { name, arguments: args } = call.function;
+    const tool = tools.find(candidate => candidate.name === name);
+    const validation = this.toolExecutionEngine.validateExecution(name, args);
+    if (!tool || !validation.allowed) {
+      const refusal = tool
+        ? (validation.reason ?? `${name} is not allowed`)
+        : `${name} is not available`;
+      toolCalls.push({
+        tool: name,
+        arguments: args,
+        success: false,
+        error: refusal,
+      });
+      return JSON.stringify({ success: false, error: refusal });
+    }
+
+    try {
+      const result = await (
+        tool as { invoke: (input: unknown) => Promise<unknown> }
+      ).invoke(args);
+      toolCalls.push({ tool: name, arguments: args, success: true });
+      return typeof result === 'string' ? result : JSON.stringify(result);
+    } catch (error) {
+      const reason = error instanceof Error ? error.message : String(error);
+      toolCalls.push({
+        tool: name,
+        arguments: args,
+        success: false,
+        error: reason,
+      });
+      return JSON.stringify({ success: false, error: reason });
+    }
+  }
+
+  /**
+   * Load a persona, answering as plain Kira if it can't be read
+   */
+  private async loadPersona(id: string): Promise<AIPersona | null> {
+    try {
+      return await getAIPersonaRepository().findById(id);
+    } catch (error) {
+      console.warn('Failed to load persona, using Kira instead:', error);
+      return null;
+    }
+  }
+
+  /**
+   * Load the user's prompt templates, answering with the built-in prompts if
+   * they can't be read
+   */
+  private async loadPromptTemplates(): Promise<PromptTemplates> {
+    try {
+      return await getPreferencesRepository().getPromptTemplates();
+    } catch (error) {
+      console.warn('Failed to load prompt templates, using defaults:', error);
+      return {};
+    }
+  }
+
+  /**
+   * Cancel a request still being generated. The tool calls it already made
+   * are kept; the ones it hasn't finished are abandoned.
+   */
+  cancelRequest(requestId: string): boolean {
+    const abort = this.activeRequests.get(requestId);
+    if (!abort) {
+      return false;
+    }
+    abort(new GenerationCancelledError(requestId));
+    return true;
+  }
+
+  /**
+   * Run a generation, aborting it once the request timeout elapses or when
+   * it is cancelled by `requestId`
+   */
+  private async runWithWatchdog<T>(
+    generate: (signal: AbortSignal) => Promise<T>,
+    requestId?: string
+  ): Promise<T> {
+    const controller = new AbortController();
+    let abort: (error: Error) => void = () => undefined;
+    const aborted = new Promise<never>((_, reject) => {
+      abort = error => {
+        controller.abort();
+        reject(error);
+      };
+    });
+
+    const timeoutMs = this.requestTimeout;
+    const timer =
+      timeoutMs > 0
+        ? setTimeout(
+            () => abort(new GenerationTimeoutError(timeoutMs)),
+            timeoutMs
+          )
+        : undefined;
+    if (requestId) {
+      this.activeRequests.set(requestId, abort);
+    }
+
+    try {
+      return await Promise.race([generate(controller.signal), aborted]);
+    } finally {
+      clearTimeout(timer);
+      if (requestId) {
+        this.activeRequests.delete(requestId);
+      }
+    }
+  }
+
+  /**
+   * Send a request to the daemon and parse its JSON reply. Requests with a
+   * body are POSTed.
+   */
+  private async request<T>(
+    path: string,
+    body?: Record<string, unknown>,
+    signal?: AbortSignal
+  ): Promise<T> {
+    const response = await this.send(path, body, signal);
+    return (await response.json()) as T;
+  }
+
+  /**
+   * Make a streamed request, calling `onChunk` with each line of JSON the
+   * daemon sends back
+   */
+  private async requestStream<T>(
+    path: string,
+    body: Record<string, unknown>,
+    signal: AbortSignal,
+    onChunk: (chunk: T) => void
+  ): Promise<void> {
+    const response = await this.send(path, body, signal);
+    if (!response.body) {
+      throw new Error(`Ollama request to ${path} returned no body`);
+    }
+
+    const reader = response.body.getReader();
+    const decoder = new TextDecoder();
+    let pending = '';
+    for (;;) {
+      const { done, value } = await reader.read();
+      pending += decoder.decode(value, { stream: !done });
+      const lines = pending.split('\n');
+      // The last line may be cut off until the stream ends
+      pending = lines.pop() ?? '';
+      if (done) {
+        lines.push(pending);
+      }
+      for (const line of lines) {
+        if (line.trim()) {
+          onChunk(JSON.parse(line) as T);
+        }
+      }
+      if (done) {
+        return;
+      }
+    }
+  }
+
+  private async send(
+    path: string,
+    body?: Record<string, unknown>,
+    signal?: AbortSignal
+  ): Promise<Response> {
+    const response = await fetch(`${this.baseUrl}${path}`, {
+      method: body ? 'POST' : 'GET',
+      headers: body ? { 'Content-Type': 'application/json' } : undefined,
+      body: body ? JSON.stringify(body) : undefined,
+      signal,
+    });
+    if (!response.ok) {
+      const detail = await response.text().catch(() => '');
+      throw new Error(
+        `Ollama request to ${path} failed (${response.status}): ${detail}`
+      );
+    }
+    return response;
+  }
+
+  private requireModel(): string {
+    if (!this.model) {
+      throw new Error('No Ollama model selected');
+    }
+    return this.model;
+  }
+
+  /**
+   * Fold a thread's older messages into its rolling summary
+   */
+  async summarizeConversation(
+    previousSummary: string | undefined,
+    messages: ConversationTurn[]
+  ): Promise<string> {
+    const transcript = messages
+      .map(
+        turn => `${turn.role === 'user' ? 'User' : 'Kira'}: ${turn.content}`
+      )
+      .join('\n\n');
+    const summarySoFar = previousSummary
+      ? `Summary so far:\n${previousSummary}\n\n`
+      : '';
+    return this.runWithWatchdog(signal =>
+      this.generate(`${summarySoFar}New messages:\n${transcript}`, {
+        system: CONVERSATION_SUMMARY_PROMPT,
+        signal,
+      })
+    );
+  }
+
+  /**
+   * Talk to a daemon at another address; takes effect on `initialize`
+   */
+  setBaseUrl(baseUrl: string): void {
+    this.baseUrl = baseUrl.replace(/\/+$/, '');
+  }
+
+  /**
+   * Use another downloaded model
+   */
+  setModel(model: string): void {
+    this.model = model;
+  }
+
+  /**
+   * Name of the model answering messages, once one is picked
+   */
+  getModel(): string | null {
+    return this.model;
+  }
+
+  /**
+   * Models found on the daemon when the service was initialized
+   */
+  getDownloadedModels(): OllamaModel[] {
+    return [...this.models];
+  }
+
+  /**
+   * Set how long a generation may run before it is aborted, in milliseconds.
+   * Zero or less disables the watchdog.
+   */
+  setRequestTimeout(timeoutMs: number): void {
+    this.requestTimeout = timeoutMs;
+  }
+
+  setTranslationFunction(translationFunction: TranslationFunction): void {
+    this.toolExecutionEngine.setTranslationFunction(translationFunction);
+  }
+
+  /**
+   * Set logging interceptor for AI interaction logging
+   */
+  setLoggingInterceptor(interceptor: LoggingInterceptor): void {
+    this.loggingInterceptor = interceptor;
+  }
+
+  isInitialized(): boolean {
+    return this.status === 'ready';
+  }
+
+  getModelInfo(): ModelInfo {
+    const details = this.models.find(model => model.name === this.model);
+    return {
+      name: this.model ?? 'Ollama',
+      type: 'local',
+      status: this.status,
+      capabilities: ['text_generation', 'tool_calling', 'embeddings'],
+      version: details?.parameterSize,
+      size: details ? `${(details.size / 1024 ** 3).toFixed(1)} GB` : undefined,
+    };
+  }
+
+  getStatus(): ModelStatus {
+    return {
+      type: 'local',
+      isReady: this.isInitialized(),
+      isLoading: this.status === 'loading',
+      error: this.error,
+      modelInfo: this.getModelInfo(),
+    };
+  }
+
+  /**
+   * Clear conversation history (each message carries its own history)
+   */
+  clearConversation(): void {
+    // Nothing is kept between messages
+  }
+}
+
+/**
+ * Tokens the daemon reports it evaluated for a chat request
+ */
+function reportedUsage(response: OllamaChatResponse): TokenUsage {
+  return {
+    inputTokens: response.prompt_eval_count ?? 0,
+    outputTokens: response.eval_count ?? 0,
+  };
+}
@@ -0,0 +1,44 @@
+import { invoke } from '@tauri-apps/api/core';
+import { PowerState } from '../../types';
+
+// Power state as the backend reports it
+interface BackendPowerState {
+  on_battery: boolean;
+  battery_percent: number | null;
+}
+
+/**
+ * Read whether the machine runs on battery
+ * @returns The power state, or null where the platform doesn't tell
+ */
+export async function getPowerState(): Promise<PowerState | null> {
+  try {
+    const state = await invoke<BackendPowerState | null>('get_power_state');
+
+    return state
+      ? {
+          onBattery: state.on_battery,
+          batteryPercent: state.battery_percent ?? undefined,
+        }
+      : null;
+  } catch (error) {
+    console.warn('Failed to read the power state:', error);
+    return null;
+  }
+}
+
+/**
+ * Whether a local model should save power: on battery with the charge at or
+ * below the threshold. Without a threshold power saving is off.
+ */
+export function shouldSavePower(
+  state: PowerState | null,
+  batteryThreshold: number | undefined
+): boolean {
+  return (
+    !!batteryThreshold &&
+    !!state?.onBattery &&
+    state.batteryPercent !== undefined &&
+    state.batteryPercent <= batteryThreshold
+  );
+}
@@ -0,0 +1,343 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ChatGoogleGenerativeAI } from '@langchain/google-genai';
+import {
+  AIMessage,
+  HumanMessage,
+  SystemMessage,
+} from '@langchain/core/messages';
+import {
+  PromptDiffLine,
+  PromptEvalCase,
+  PromptEvalCaseComparison,
+  PromptEvalReport,
+  PromptEvalResult,
+  PromptEvalRun,
+} from '../../types';
+import { getKiraPilotTools } from './tools';
+
+export interface PromptEvaluationOptions {
+  model: string;
+  apiKey: string;
+  /**
+   * Model that scores the responses; defaults to the evaluated model
+   */
+  judgeModel?: string;
+  /**
+   * Name the runs are stored under
+   */
+  templateName?: string;
+}
+
+/**
+ * Fixed context used in place of {app_context} so runs stay comparable
+ */
+const EVAL_APP_CONTEXT = {
+  currentTask: null,
+  activeSession: null,
+  focusMode: false,
+  timeOfDay: 'afternoon',
+  dayOfWeek: 3,
+};
+
+/**
+ * Fixed time used in place of {system_time}
+ */
+const EVAL_SYSTEM_TIME = '2024-06-12T14:00:00.000Z';
+
+/**
+ * Debug service that checks prompt template changes against a fixed
+ * evaluation suite.
+ *
+ * Every case of the suite is sent to the model with the old and the new
+ * template. Tool calls are recorded in the response instead of being
+ * executed. A judge model scores each response from 0 to 10 against the
+ * case's criteria, both runs are stored and the backend reports response
+ * diffs, score changes and regressions.
+ */
+export class PromptEvaluationService {
+  /**
+   * Get the cases of the evaluation suite
+   */
+  async getSuite(): Promise<PromptEvalCase[]> {
+    try {
+      return await invoke<PromptEvalCase[]>('get_prompt_eval_suite');
+    } catch (error) {
+      throw new Error(`Failed to get prompt evaluation suite: ${error}`);
+    }
+  }
+
+  /**
+   * Run the suite with both templates and compare the results
+   */
+  async evaluateChange(
+    beforeTemplate: string,
+    afterTemplate: string,
+    options: PromptEvaluationOptions
+  ): Promise<PromptEvalReport> {
+    const before = await this.runSuite(beforeTemplate, options);
+    const after = await this.runSuite(afterTemplate, options);
+    return this.compareRuns(before.id, after.id);
+  }
+
+  /**
+   * Run the suite against one template, judge the responses and store the run
+   */
+  async runSuite(
+    template: string,
+    options: PromptEvaluationOptions
+  ): Promise<PromptEvalRun> {
+    const suite = await this.getSuite();
+    const judgeModel = options.judgeModel ?? options.model;
+    const systemPrompt = template
+      .replace('{app_context}', JSON.stringify(EVAL_APP_CONTEXT, null, 2))
+      .replace('{system_time}', EVAL_SYSTEM_TIME);
+
+    const model = new ChatGoogleGenerativeAI({
+      model: options.model,
+      maxOutputTokens: 2048,
+      apiKey: options.apiKey,
+      temperature: 0,
+    }).bindTools(getKiraPilotTools());
+    const judge = new ChatGoogleGenerativeAI({
+      model: judgeModel,
+      maxOutputTokens: 512,
+      apiKey: options.apiKey,
+      temperature: 0,
+    });
+
+    const results: Record<string, unknown>[] = [];
+    for (const evalCase of suite) {
+      const startTime = Date.now();
+      let response = '';
+      try {
+        const message = (await model.invoke([
+          new SystemMessage(systemPrompt),
+          new HumanMessage(evalCase.prompt),
+        ])) as AIMessage;
+        response = this.describeResponse(message);
+      } catch (caseError) {
+        results.push({
+          case_id: evalCase.id,
+          response,
+          error: this.errorMessage(caseError),
+          response_time: Date.now() - startTime,
+        });
+        continue;
+      }
+      const responseTime = Date.now() - startTime;
+
+      let judgeScore: number | undefined;
+      let judgeNotes: string | undefined;
+      try {
+        const verdict = (await judge.invoke([
+          new HumanMessage(this.judgePrompt(evalCase, response)),
+        ])) as AIMessage;
+        ({ score: judgeScore, notes: judgeNotes } = this.parseVerdict(
+          this.messageText(verdict)
+        ));
+      } catch (judgeError) {
+        judgeNotes = `Judge failed: ${this.errorMessage(judgeError)}`;
+      }
+
+      results.push({
+        case_id: evalCase.id,
+        response,
+        judge_score: judgeScore,
+        judge_notes: judgeNotes,
+        response_time: responseTime,
+      });
+    }
+
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'save_prompt_eval_run',
+        {
+          request: {
+            template_name: options.templateName ?? 'system',
+            template,
+            model: options.model,
+            judge_model: judgeModel,
+            results,
+          },
+        }
+      );
+      return this.transformRunFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to save prompt evaluation run: ${error}`);
+    }
+  }
+
+  /**
+   * Compare a run of the old template against a run of the new one
+   */
+  async compareRuns(
+    beforeRunId: string,
+    afterRunId: string
+  ): Promise<PromptEvalReport> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'compare_prompt_eval_runs',
+        { beforeRunId, afterRunId }
+      );
+      return {
+        templateName: result.template_name as string,
+        beforeRunId: result.before_run_id as string,
+        afterRunId: result.after_run_id as string,
+        templateChanged: result.template_changed as boolean,
+        templateDiff: result.template_diff as PromptDiffLine[],
+        cases: (result.cases as Record<string, unknown>[]).map(comparison =>
+          this.transformCaseFromBackend(comparison)
+        ),
+        averageScoreBefore:
+          (result.average_score_before as number | null) ?? undefined,
+        averageScoreAfter:
+          (result.average_score_after as number | null) ?? undefined,
+        averageScoreDelta:
+          (result.average_score_delta as number | null) ?? undefined,
+        regressions: result.regressions as string[],
+        missingCases: result.missing_cases as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to compare prompt evaluation runs: ${error}`);
+    }
+  }
+
+  /**
+   * Get stored runs, newest first, optionally for one template
+   */
+  async getRuns(templateName?: string): Promise<PromptEvalRun[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_prompt_eval_runs',
+        { templateName }
+      );
+      return result.map(run => this.transformRunFromBackend(run));
+    } catch (error) {
+      throw new Error(`Failed to get prompt evaluation runs: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a stored run
+   */
+  async deleteRun(id: string): Promise<void> {
+    try {
+      await invoke('delete_prompt_eval_run', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete prompt evaluation run: ${error}`);
+    }
+  }
+
+  private judgePrompt(evalCase: PromptEvalCase, response: string): string {
+    return `You are grading the reply of a productivity assistant.
+
+User request: ${evalCase.prompt}
+A good reply: ${evalCase.criteria}
+
+Assistant reply:
+${response}
+
+Score the reply from 0 (useless or harmful) to 10 (ideal). Lines starting with [tool call] are tools the assistant chose to run.
+Answer with JSON only: {"score": <number>, "notes": "<one sentence>"}`;
+  }
+
+  private parseVerdict(text: string): { score?: number; notes?: string } {
+    const json = text.slice(text.indexOf('{'), text.lastIndexOf('}') + 1);
+    try {
+      const verdict = JSON.parse(json) as { score?: unknown; notes?: unknown };
+      const score = Number(verdict.score);
+      return {
+        score:
+          Number.isFinite(score) && score >= 0 && score <= 10
+            ? score
+            : undefined,
+        notes: typeof verdict.notes === 'string' ? verdict.notes : undefined,
+      };
+    } catch {
+      return { notes: `Unreadable verdict: ${text}` };
+    }
+  }
+
+  private describeResponse(message: AIMessage): string {
+    const toolCalls = (message.tool_calls ?? []).map(
+      toolCall =>
+        `[tool call] ${toolCall.name}(${JSON.stringify(toolCall.args)})`
+    );
+    return [this.messageText(message), ...toolCalls]
+      .filter(part => part.trim().length > 0)
+      .join('\n');
+  }
+
+  private messageText(message: AIMessage): string {
+    if (typeof message.content === 'string') {
+      return message.content;
+    }
+    return message.content
+      .map(part => ('text' in part ? String(part.text) : ''))
+      .join('');
+  }
+
+  private errorMessage(error: unknown): string {
+    return error instanceof Error ? error.message : String(error);
+  }
+
+  private transformRunFromBackend(
+    run: Record<string, unknown>
+  ): PromptEvalRun {
+    const results = JSON.parse(run.results as string) as Record<
+      string,
+      unknown
+    >[];
+    return {
+      id: run.id as string,
+      templateName: run.template_name as string,
+      templateHash: run.template_hash as string,
+      template: run.template as string,
+      model: run.model as string,
+      judgeModel: (run.judge_model as string | null) ?? undefined,
+      results: results.map(
+        (result): PromptEvalResult => ({
+          caseId: result.case_id as string,
+          response: result.response as string,
+          judgeScore: (result.judge_score as number | null) ?? undefined,
+          judgeNotes: (result.judge_notes as string | null) ?? undefined,
+          error: (result.error as string | null) ?? undefined,
+          responseTime: (result.response_time as number | null) ?? undefined,
+        })
+      ),
+      averageScore: (run.average_score as number | null) ?? undefined,
+      createdAt: new Date(run.created_at as string),
+    };
+  }
+
+  private transformCaseFromBackend(
+    comparison: Record<string, unknown>
+  ): PromptEvalCaseComparison {
+    return {
+      caseId: comparison.case_id as string,
+      prompt: comparison.prompt as string,
+      beforeResponse: comparison.before_response as string,
+      afterResponse: comparison.after_response as string,
+      responseDiff: comparison.response_diff as PromptDiffLine[],
+      similarity: comparison.similarity as number,
+      beforeScore: (comparison.before_score as number | null) ?? undefined,
+      afterScore: (comparison.after_score as number | null) ?? undefined,
+      scoreDelta: (comparison.score_delta as number | null) ?? undefined,
+      beforeError: (comparison.before_error as string | null) ?? undefined,
+      afterError: (comparison.after_error as string | null) ?? undefined,
+      regressed: comparison.regressed as boolean,
+    };
+  }
+}
+
+let promptEvaluationServiceInstance: PromptEvaluationService | null = null;
+
+/**
+ * Get global PromptEvaluationService instance
+ */
+export function getPromptEvaluationService(): PromptEvaluationService {
+  if (!promptEvaluationServiceInstance) {
+    promptEvaluationServiceInstance = new PromptEvaluationService();
+  }
+  return promptEvaluationServiceInstance;
+}
@@ -0,0 +1,261 @@
+import { ProviderCircuitOpenError } from './AIServiceInterface';
+
+/**
+ * Request limits and retry policy of a cloud provider
+ */
+export interface ProviderMiddlewareConfig {
+  requestsPerMinute: number;
+  maxRetries: number; // of a rate limited or failed request
+  baseDelayMs: number; // first retry delay, doubled on each retry
+  maxDelayMs: number;
+  failureThreshold: number; // consecutive failures that open the circuit
+  cooldownMs: number; // how long an open circuit pauses requests
+}
+
+export const DEFAULT_PROVIDER_CONFIG: ProviderMiddlewareConfig = {
+  requestsPerMinute: 60,
+  maxRetries: 3,
+  baseDelayMs: 1000,
+  maxDelayMs: 30_000,
+  failureThreshold: 5,
+  cooldownMs: 60_000,
+};
+
+/**
+ * Clock and randomness the middleware waits with, replaceable in tests
+ */
+export interface ProviderMiddlewareClock {
+  now: () => number;
+  sleep: (ms: number, signal?: AbortSignal) => Promise<void>;
+  random: () => number;
+}
+
+// Statuses worth retrying: timeouts, rate limits and server errors
+const RETRYABLE_STATUSES = new Set([408, 429, 500, 502, 503, 504]);
+
+const MINUTE_MS = 60_000;
+
+const defaultClock: ProviderMiddlewareClock = {
+  now: () => Date.now(),
+  sleep: (ms, signal) =>
+    new Promise((resolve, reject) => {
+      if (signal?.aborted) {
+        reject(signal.reason);
+        return;
+      }
+      const timer = setTimeout(resolve, ms);
+      signal?.addEventListener(
+        'abort',
+        () => {
+          clearTimeout(timer);
+          reject(signal.reason);
+        },
+        { once: true }
+      );
+    }),
+  random: () => Math.random(),
+};
+
+/**
+ * HTTP status of a failed provider request, read from the error's `status`
+ * or from the "[429 Too Many Requests]" its message quotes
+ */
+export function httpStatusOf(error: unknown): number | undefined {
+  if (error && typeof error === 'object') {
+    const { status, response } = error as {
+      status?: unknown;
+      response?: { status?: unknown };
+    };
+    if (typeof status === 'number') {
+      return status;
+    }
+    if (typeof response?.status === 'number') {
+      return response.status;
+    }
+  }
+
+  const match =
+    error instanceof Error ? /\[(\d{3}) [^\]]*\]/.exec(error.message) : null;
+  return match ? Number(match[1]) : undefined;
+}
+
+/**
+ * Delay a rate limited provider asks for before the next request, from the
+ * RetryInfo detail of a Google API error, e.g. `{ retryDelay: '17s' }`
+ */
+export function retryDelayOf(error: unknown): number | undefined {
+  const details = (error as { errorDetails?: unknown } | null)?.errorDetails;
+  if (!Array.isArray(details)) {
+    return undefined;
+  }
+
+  for (const detail of details) {
+    const retryDelay = (detail as { retryDelay?: unknown })?.retryDelay;
+    if (typeof retryDelay === 'string') {
+      const seconds = parseFloat(retryDelay);
+      if (Number.isFinite(seconds)) {
+        return seconds * 1000;
+      }
+    }
+  }
+  return undefined;
+}
+
+/**
+ * Runs the requests sent to a cloud provider, shared by every request to it:
+ * - at most `requestsPerMinute` requests start in any minute
+ * - rate limited (429) and server error (5xx) responses are retried with
+ *   exponential backoff and jitter, waiting at least as long as the provider
+ *   asks for
+ * - after `failureThreshold` consecutive failures the circuit opens and
+ *   requests fail fast for `cooldownMs`, so the fallback chain can take
+ *   over; a single trial request then decides whether it closes again
+ */
+export class ProviderMiddleware {
+  private readonly config: ProviderMiddlewareConfig;
+  private readonly clock: ProviderMiddlewareClock;
+  private recentStarts: number[] = [];
+  private pausedUntil = 0;
+  private consecutiveFailures = 0;
+  private openedAt: number | null = null;
+  private trialInFlight = false;
+
+  constructor(
+    public readonly provider: string,
+    config: Partial<ProviderMiddlewareConfig> = {},
+    clock: Partial<ProviderMiddlewareClock> = {}
+  ) {
+    this.config = { ...DEFAULT_PROVIDER_CONFIG, ...config };
+    this.clock = { ...defaultClock, ...clock };
+  }
+
+  /**
+   * Send a request to the provider
+   * @param request - Sends the request; called again for each retry
+   * @param signal - Stops waiting and retrying once aborted
+   * @throws ProviderCircuitOpenError if the provider is failing
+   */
+  async run<T>(request: () => Promise<T>, signal?: AbortSignal): Promise<T> {
+    for (let attempt = 0; ; attempt++) {
+      this.enterCircuit();
+      await this.acquireSlot(signal);
+
+      try {
+        const result = await request();
+        this.recordSuccess();
+        return result;
+      } catch (error) {
+        const status = httpStatusOf(error);
+        if (status === undefined) {
+          // Not an answer from the provider, e.g. an aborted request
+          this.trialInFlight = false;
+          throw error;
+        }
+        if (!RETRYABLE_STATUSES.has(status)) {
+          // The provider is up, it rejected this request
+          this.recordSuccess();
+          throw error;
+        }
+
+        this.recordFailure();
+        const retryAfter = retryDelayOf(error);
+        if (retryAfter !== undefined) {
+          this.pausedUntil = Math.max(
+            this.pausedUntil,
+            this.clock.now() + retryAfter
+          );
+        }
+        if (attempt >= this.config.maxRetries || this.openedAt !== null) {
+          throw error;
+        }
+        await this.clock.sleep(this.backoffDelay(attempt), signal);
+      }
+    }
+  }
+
+  /**
+   * Delay before retry `attempt`: between half and all of the exponential
+   * backoff at random, so concurrent requests don't retry in lockstep
+   */
+  private backoffDelay(attempt: number): number {
+    const backoff = Math.min(
+      this.config.maxDelayMs,
+      this.config.baseDelayMs * 2 ** attempt
+    );
+    return Math.round(backoff * (0.5 + this.clock.random() / 2));
+  }
+
+  /**
+   * Fail fast while the circuit is open. Once the cooldown is over, let one
+   * trial request through.
+   */
+  private enterCircuit(): void {
+    if (this.openedAt === null) {
+      return;
+    }
+
+    const remaining =
+      this.config.cooldownMs - (this.clock.now() - this.openedAt);
+    if (remaining > 0) {
+      throw new ProviderCircuitOpenError(this.provider, remaining);
+    }
+    if (this.trialInFlight) {
+      throw new ProviderCircuitOpenError(this.provider, 0);
+    }
+    this.trialInFlight = true;
+  }
+
+  /**
+   * Wait until the request can start within the rate limit and any pause
+   * the provider asked for
+   */
+  private async acquireSlot(signal?: AbortSignal): Promise<void> {
+    for (;;) {
+      const now = this.clock.now();
+      this.recentStarts = this.recentStarts.filter(
+        start => now - start < MINUTE_MS
+      );
+
+      let wait = this.pausedUntil - now;
+      if (this.recentStarts.length >= this.config.requestsPerMinute) {
+        wait = Math.max(wait, this.recentStarts[0] + MINUTE_MS - now);
+      }
+      if (wait <= 0) {
+        this.recentStarts.push(now);
+        return;
+      }
+      await this.clock.sleep(wait, signal);
+    }
+  }
+
+  private recordSuccess(): void {
+    this.consecutiveFailures = 0;
+    this.openedAt = null;
+    this.trialInFlight = false;
+  }
+
+  private recordFailure(): void {
+    this.consecutiveFailures++;
+    if (
+      this.trialInFlight ||
+      this.consecutiveFailures >= this.config.failureThreshold
+    ) {
+      this.openedAt = this.clock.now();
+      this.trialInFlight = false;
+    }
+  }
+}
+
+const middlewares = new Map<string, ProviderMiddleware>();
+
+/**
+ * Get the middleware every request to a cloud provider goes through
+ */
+export function getProviderMiddleware(provider: string): ProviderMiddleware {
+  let middleware = middlewares.get(provider);
+  if (!middleware) {
+    middleware = new ProviderMiddleware(provider);
+    middlewares.set(provider, middleware);
+  }
+  return middleware;
+}
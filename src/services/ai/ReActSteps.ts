@@ -0,0 +1,69 @@
+import { emit, listen, UnlistenFn } from '@tauri-apps/api/event';
+import { ReActStep } from '../../types';
+
+export const REACT_STEP_EVENT = 'ai-react-step';
+
+// Longest thought, argument list or tool result sent with a step
+const MAX_STEP_CONTENT_CHARS = 500;
+
+/**
+ * Reports the steps of one ReAct chain as they happen, so the chat can show
+ * what Kira is doing before the answer is ready
+ */
+export class ReActStepEmitter {
+  private index = 0;
+
+  constructor(public readonly chainId: string) {}
+
+  thought(content: string): void {
+    if (content.trim()) {
+      this.emit('thought', undefined, content);
+    }
+  }
+
+  toolCall(toolName: string, args: unknown): void {
+    this.emit('tool_call', toolName, JSON.stringify(args ?? {}));
+  }
+
+  toolResult(toolName: string, result: string): void {
+    this.emit('tool_result', toolName, result);
+  }
+
+  finalAnswer(content: string): void {
+    this.emit('final_answer', undefined, content);
+  }
+
+  private emit(
+    kind: ReActStep['kind'],
+    toolName?: string,
+    content?: string
+  ): void {
+    const step: ReActStep = {
+      chainId: this.chainId,
+      index: this.index++,
+      kind,
+      toolName,
+      content: content?.slice(0, MAX_STEP_CONTENT_CHARS),
+      timestamp: new Date(),
+    };
+
+    // Progress is best effort, it never fails the chain
+    emit(REACT_STEP_EVENT, step).catch(error =>
+      console.warn('Failed to report ReAct step:', error)
+    );
+  }
+}
+
+/**
+ * Listen for the steps of every ReAct chain
+ */
+export function onReActStep(
+  callback: (step: ReActStep) => void
+): Promise<UnlistenFn> {
+  return listen<ReActStep>(REACT_STEP_EVENT, event =>
+    callback({
+      ...event.payload,
+      timestamp: new Date(event.payload.timestamp),
+    })
+  );
+}
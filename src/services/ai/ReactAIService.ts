@@ -1,17 +1,27 @@
 import { ChatGoogleGenerativeAI } from '@langchain/google-genai';
-import { AIMessage } from '@langchain/core/messages';
+import {
+  AIMessage,
+  BaseMessage,
+  ToolMessage,
+  isAIMessage,
+} from '@langchain/core/messages';
 import { RunnableConfig } from '@langchain/core/runnables';
 import { MessagesAnnotation, StateGraph } from '@langchain/langgraph/web';
 import { ToolNode } from '@langchain/langgraph/prebuilt';
 import { Annotation } from '@langchain/langgraph/web';
 import {
   AIAction,
+  AIPersona,
   AIResponse,
   AISuggestion,
   AppContext,
+  ConversationTurn,
   PatternAnalysis,
+  PromptTemplates,
   Priority,
   DistractionLevel,
+  TokenUsage,
+  ToolCallResult,
 } from '../../types';
 import {
   ToolExecutionEngine,
@@ -24,6 +34,10 @@ import {
   ModelInfo,
   ModelStatus,
   ModelProcessingError,
+  GenerationTimeoutError,
+  GenerationCancelledError,
+  ProcessMessageOptions,
+  ProviderCircuitOpenError,
 } from './AIServiceInterface';
 import { TranslationKey } from '../../i18n';
 import {
@@ -43,6 +57,26 @@ import {
   PerformanceMonitor,
 } from './PerformanceMonitor';
 import type { UserPreferences, EmotionalTone } from '../../types';
+import {
+  getAIPersonaRepository,
+  getPreferencesRepository,
+} from '../database/repositories';
+import {
+  RESPONSE_TOKEN_RESERVE,
+  countTokens,
+  fitConversation,
+} from './ContextBudget';
+import {
+  CONVERSATION_SUMMARY_PROMPT,
+  KIRA_SYSTEM_PROMPT,
+  buildPersonaPrompt,
+  buildSystemPrompt,
+  renderSystemPrompt,
+  withConversationSummary,
+} from './KiraPrompts';
+import { getProviderMiddleware } from './ProviderMiddleware';
+import { ReActStepEmitter } from './ReActSteps';
+import { ResponseStreamEmitter } from './ResponseStream';
 
 // Internal types for AI service
 interface ToolCall {
@@ -81,72 +115,65 @@ export const KiraConfigurationSchema = Annotation.Root({
    * API key for the model
    */
   apiKey: Annotation<string>,
-});
 
-// Default system prompt for Kira AI
-const KIRA_SYSTEM_PROMPT = `You are Kira, an AI assistant for KiraPilot, a productivity application. You help users manage their tasks, track time, and improve productivity.
-
-Your role is to:
-1. Help users create, update, and organize tasks
-2. Assist with time tracking and timer management
-3. Provide productivity insights and suggestions
-4. Support weekly planning and scheduling
-5. Manage recurring tasks and automation
-
-Available tools:
-- create_task: Create new tasks with details
-- update_task: Modify existing tasks
-- get_tasks: Retrieve and search tasks
-- start_timer: Begin timing work on tasks
-- stop_timer: End current timer sessions
-- get_time_data: Analyze time tracking data
-- analyze_productivity: Generate productivity insights
-- create_periodic_task: Set up recurring task templates
-- get_periodic_tasks: View and manage periodic task templates
-- update_periodic_task: Modify recurring task patterns
-- generate_periodic_instances: Create new instances from templates
-- suggest_recurrence: Recommend recurrence patterns for tasks
-
-Periodic Tasks Features:
-- Help users create recurring tasks for habits, routines, and regular responsibilities
-- Support various recurrence patterns: daily, weekly, biweekly, monthly, or custom intervals
-- Automatically generate new task instances based on schedules
-- Manage template activation/deactivation and pattern modifications
-- Provide intelligent suggestions for recurrence patterns based on task descriptions
-
-Natural Language Understanding for Periodic Tasks:
-- "Set up a daily workout reminder" → create_periodic_task with daily recurrence
-- "Create a weekly team meeting task" → create_periodic_task with weekly recurrence
-- "I need to review reports every month" → create_periodic_task with monthly recurrence
-- "Show me my recurring tasks" → get_periodic_tasks
-- "Generate pending recurring tasks" → generate_periodic_instances
-- "What recurrence pattern should I use for [task]?" → suggest_recurrence
-
-Guidelines:
-- Always reason through problems step by step
-- Use tools when users request specific actions
-- Provide clear explanations for your reasoning
-- Respect user privacy - all data stays local
-- Be helpful, concise, and professional
-- For recurring tasks, suggest appropriate recurrence patterns based on task type
-- Explain the benefits of automation for repetitive tasks
-
-Current context: {app_context}
-System time: {system_time}
-
-Think through each user request carefully and use the appropriate tools to help them achieve their productivity goals.`;
+  /**
+   * Names of the tools the model may call; unset allows every tool
+   */
+  allowedTools: Annotation<string[] | undefined>,
+});
 
 // Get the tools for the ReAct agent
 const TOOLS = getKiraPilotTools();
 
+// Model used when the thread's persona doesn't pick one
+const DEFAULT_MODEL = 'gemini-2.0-flash';
+
+// Gemini models a request can be answered with
+const GEMINI_MODELS = [
+  'gemini-2.5-pro',
+  'gemini-2.5-flash',
+  DEFAULT_MODEL,
+  'gemini-1.5-pro',
+  'gemini-1.5-flash',
+];
+
+// Generations running longer than this are aborted unless configured otherwise
+const DEFAULT_REQUEST_TIMEOUT_MS = 120_000;
+
+// Input tokens the Gemini models accept
+const GEMINI_CONTEXT_LENGTH = 1_048_576;
+
+/**
+ * Tokens Gemini reported for the model calls of a run, if it reported any
+ */
+function reportedUsage(messages: BaseMessage[]): TokenUsage | undefined {
+  let inputTokens = 0;
+  let outputTokens = 0;
+  for (const message of messages) {
+    const usage = (message as AIMessage).usage_metadata;
+    if (usage) {
+      inputTokens += usage.input_tokens;
+      outputTokens += usage.output_tokens;
+    }
+  }
+  return inputTokens + outputTokens > 0
+    ? { inputTokens, outputTokens }
+    : undefined;
+}
+
 /**
  * Load chat model for Google Gemini
  */
-async function loadChatModel(apiKey: string): Promise<ChatGoogleGenerativeAI> {
+async function loadChatModel(
+  apiKey: string,
+  model: string
+): Promise<ChatGoogleGenerativeAI> {
   return new ChatGoogleGenerativeAI({
-    model: 'gemini-2.0-flash',
+    model,
     maxOutputTokens: 2048,
     apiKey: apiKey,
+    // Retried by the provider middleware instead
+    maxRetries: 0,
   });
 }
 
@@ -223,9 +250,10 @@ function ensureConfiguration(
   return {
     systemPromptTemplate:
       configurable.systemPromptTemplate ?? KIRA_SYSTEM_PROMPT,
-    model: configurable.model ?? 'gemini-2.0-flash',
+    model: configurable.model ?? DEFAULT_MODEL,
     appContext: configurable.appContext ?? defaultAppContext,
     apiKey: configurable.apiKey ?? '',
+    allowedTools: configurable.allowedTools,
   };
 }
 
@@ -240,23 +268,36 @@ async function callModel(
     throw new Error('API key is required for the model');
   }
 
-  // Load and bind tools to the model
-  const model = (await loadChatModel(configuration.apiKey)).bindTools(TOOLS);
+  // Load and bind the tools the persona allows to the model
+  const { allowedTools } = configuration;
+  const tools = allowedTools
+    ? TOOLS.filter(tool => allowedTools.includes(tool.name))
+    : TOOLS;
+  const chatModel = await loadChatModel(
+    configuration.apiKey,
+    configuration.model
+  );
+  const model = tools.length > 0 ? chatModel.bindTools(tools) : chatModel;
 
-  const systemPrompt = configuration.systemPromptTemplate
-    .replace('{app_context}', JSON.stringify(configuration.appContext, null, 2))
-    .replace('{system_time}', new Date().toISOString());
+  const systemPrompt = renderSystemPrompt(
+    configuration.systemPromptTemplate,
+    configuration.appContext
+  );
 
   // In web environments, we need to pass config to nested Runnable calls
-  const response = await model.invoke(
-    [
-      {
-        role: 'system',
-        content: systemPrompt,
-      },
-      ...state.messages,
-    ],
-    config
+  const response = await getProviderMiddleware('gemini').run(
+    () =>
+      model.invoke(
+        [
+          {
+            role: 'system',
+            content: systemPrompt,
+          },
+          ...state.messages,
+        ],
+        config
+      ),
+    config.signal
   );
 
   // Return the response message
@@ -310,6 +351,9 @@ export class ReactAIService implements AIServiceInterface {
   private emotionalIntelligenceService: EmotionalIntelligenceService | null =
     null;
   private performanceMonitor: PerformanceMonitor;
+  private requestTimeout: number = DEFAULT_REQUEST_TIMEOUT_MS;
+  // Aborts each running request by its ID
+  private activeRequests = new Map<string, (error: Error) => void>();
 
   constructor(apiKey?: string, translationFunction?: TranslationFunction) {
     this.apiKey = apiKey || this.getEnvironmentApiKey() || null;
@@ -333,6 +377,11 @@ export class ReactAIService implements AIServiceInterface {
 
     // Initialize personality services
     this.initializePersonalityServices();
+
+    const timeoutSeconds = this.getUserPreferences().aiSettings?.requestTimeout;
+    if (timeoutSeconds !== undefined) {
+      this.setRequestTimeout(timeoutSeconds * 1000);
+    }
   }
 
   private getEnvironmentApiKey(): string | null {
@@ -368,11 +417,13 @@ export class ReactAIService implements AIServiceInterface {
    */
   async processMessage(
     message: string,
-    context: AppContext
+    context: AppContext,
+    options: ProcessMessageOptions = {}
   ): Promise<AIResponse> {
     const startTime = Date.now();
     let requestId: string | null = null;
     const operationId = `ai-request-${Date.now()}-${Math.random().toString(36).substr(2, 9)}`;
+    let partialMessages: BaseMessage[] = [];
 
     // Start performance monitoring
     this.performanceMonitor.startOperation(operationId, {
@@ -397,16 +448,39 @@ export class ReactAIService implements AIServiceInterface {
           requestId = await this.loggingInterceptor.interceptRequest(
             this,
             message,
-            context
+            context,
+            options
           );
         } catch (error) {
           console.warn('Failed to intercept request for logging:', error);
         }
       }
 
-      // Prepare the input for the graph
+      // Answer as the thread's persona, if it has one
+      const { conversation, ...appContext } = context;
+      const persona = context.personaId
+        ? await this.loadPersona(context.personaId)
+        : null;
+      const templates = await this.loadPromptTemplates();
+      const template = persona
+        ? buildPersonaPrompt(persona, templates)
+        : buildSystemPrompt(templates);
+
+      // Trim the thread's memory so the prompt fits the context window
+      const promptTokens = countTokens(
+        renderSystemPrompt(template, appContext) + message,
+        'gemini'
+      );
+      const memory = fitConversation(
+        conversation,
+        GEMINI_CONTEXT_LENGTH - RESPONSE_TOKEN_RESERVE - promptTokens,
+        'gemini'
+      );
+
+      // Prepare the input for the graph, after the thread's earlier turns
       const input = {
         messages: [
+          ...(memory?.recentMessages ?? []),
           {
             role: 'user' as const,
             content: message,
@@ -417,15 +491,56 @@ export class ReactAIService implements AIServiceInterface {
       // Configure the graph with current context and API key
       const config = {
         configurable: {
-          appContext: context,
+          appContext,
           apiKey: this.apiKey,
-          systemPromptTemplate: KIRA_SYSTEM_PROMPT,
-          model: 'gemini-2.0-flash',
+          systemPromptTemplate: withConversationSummary(
+            template,
+            memory?.summary
+          ),
+          model: options.model ?? persona?.defaultModel ?? DEFAULT_MODEL,
+          allowedTools: persona?.allowedTools,
         },
       };
 
-      // Run the ReAct graph
-      const result = await graph.invoke(input, config);
+      // Run the ReAct graph under the watchdog, keeping each step so the
+      // tool calls already made are known if the request is cancelled,
+      // reporting the steps as they happen and streaming the model's tokens
+      const chainId = options.requestId ?? operationId;
+      const reActSteps = new ReActStepEmitter(chainId);
+      const responseStream = new ResponseStreamEmitter(chainId);
+      let reported = input.messages.length;
+      const result = await this.runWithWatchdog(async signal => {
+        const steps = await graph.stream(input, {
+          ...config,
+          signal,
+          streamMode: ['values', 'messages'],
+        });
+        for await (const [mode, chunk] of steps) {
+          if (mode === 'messages') {
+            const [token, metadata] = chunk as [
+              BaseMessage,
+              Record<string, unknown>,
+            ];
+            if (metadata.langgraph_node === 'callModel') {
+              responseStream.token(
+                typeof token.content === 'string' ? token.content : ''
+              );
+            }
+            continue;
+          }
+          partialMessages = (chunk as typeof MessagesAnnotation.State)
+            .messages;
+          for (const newMessage of partialMessages.slice(reported)) {
+            this.reportStep(reActSteps, newMessage);
+            // Text streamed before tool calls was a thought, not the answer
+            if (isAIMessage(newMessage) && newMessage.tool_calls?.length) {
+              responseStream.reset();
+            }
+          }
+          reported = Math.max(reported, partialMessages.length);
+        }
+        return { messages: partialMessages };
+      }, options.requestId);
 
       // Extract the final response
       const messages = result.messages;
@@ -522,6 +637,7 @@ export class ReactAIService implements AIServiceInterface {
         suggestions,
         context,
         reasoning: this.extractReasoning(responseMessage),
+        usage: reportedUsage(messages),
       };
 
       // Intercept response for logging
@@ -530,7 +646,9 @@ export class ReactAIService implements AIServiceInterface {
           const responseTime = Date.now() - startTime;
           await this.loggingInterceptor.interceptResponse(requestId, response, {
             responseTime,
-            tokenCount: undefined, // Token count not available from LangGraph
+            tokenCount: response.usage
+              ? response.usage.inputTokens + response.usage.outputTokens
+              : undefined,
             modelInfo: this.getModelInfo(),
             sessionId: this.loggingInterceptor.getCurrentSessionId(),
             timestamp: new Date(),
@@ -551,7 +669,15 @@ export class ReactAIService implements AIServiceInterface {
       });
 
       return response;
-    } catch (error) {
+    } catch (caughtError) {
+      // A cancelled request reports the tool calls made before it stopped
+      const error =
+        caughtError instanceof GenerationCancelledError
+          ? new GenerationCancelledError(
+              caughtError.requestId,
+              this.describeToolCalls(partialMessages)
+            )
+          : caughtError;
       console.error('ReAct AI Service Error:', error);
 
       // End performance monitoring - error
@@ -579,6 +705,14 @@ export class ReactAIService implements AIServiceInterface {
         }
       }
 
+      if (
+        error instanceof GenerationTimeoutError ||
+        error instanceof GenerationCancelledError ||
+        error instanceof ProviderCircuitOpenError
+      ) {
+        throw error;
+      }
+
       // Throw a ModelProcessingError for better error handling by ModelManager
       throw new ModelProcessingError(
         error instanceof Error ? error.message : 'Unknown error occurred'
@@ -586,6 +720,101 @@ export class ReactAIService implements AIServiceInterface {
     }
   }
 
+  /**
+   * Load a persona, answering as plain Kira if it can't be read
+   */
+  private async loadPersona(id: string): Promise<AIPersona | null> {
+    try {
+      return await getAIPersonaRepository().findById(id);
+    } catch (error) {
+      console.warn('Failed to load persona, using Kira instead:', error);
+      return null;
+    }
+  }
+
+  /**
+   * Load the user's prompt templates, answering with the built-in prompts if
+   * they can't be read
+   */
+  private async loadPromptTemplates(): Promise<PromptTemplates> {
+    try {
+      return await getPreferencesRepository().getPromptTemplates();
+    } catch (error) {
+      console.warn('Failed to load prompt templates, using defaults:', error);
+      return {};
+    }
+  }
+
+  /**
+   * Cancel a request still being generated. The tool calls it already made
+   * are kept; the ones it hasn't finished are abandoned.
+   */
+  cancelRequest(requestId: string): boolean {
+    const abort = this.activeRequests.get(requestId);
+    if (!abort) {
+      return false;
+    }
+    abort(new GenerationCancelledError(requestId));
+    return true;
+  }
+
+  /**
+   * Run a generation, aborting it once the request timeout elapses or when
+   * it is cancelled by `requestId`.
+   * Resolves or rejects as soon as it is aborted, even if the generation
+   * ignores the abort signal and keeps hanging.
+   */
+  private async runWithWatchdog<T>(
+    generate: (signal: AbortSignal) => Promise<T>,
+    requestId?: string
+  ): Promise<T> {
+    const controller = new AbortController();
+    let abort: (error: Error) => void = () => undefined;
+    const aborted = new Promise<never>((_, reject) => {
+      abort = error => {
+        controller.abort();
+        reject(error);
+      };
+    });
+
+    const timeoutMs = this.requestTimeout;
+    const timer =
+      timeoutMs > 0
+        ? setTimeout(
+            () => abort(new GenerationTimeoutError(timeoutMs)),
+            timeoutMs
+          )
+        : undefined;
+    if (requestId) {
+      this.activeRequests.set(requestId, abort);
+    }
+
+    try {
+      return await Promise.race([generate(controller.signal), aborted]);
+    } finally {
+      clearTimeout(timer);
+      if (requestId) {
+        this.activeRequests.delete(requestId);
+      }
+    }
+  }
+
+  /**
+   * Describe the tool calls in a partial run; calls without a result were
+   * cut short
+   */
+  private describeToolCalls(messages: BaseMessage[]): ToolCallResult[] {
+    return this.extractToolExecutions(messages).map(execution => {
+      const succeeded = this.findToolResult(messages, execution.id) !== null;
+      return {
+        tool: execution.name,
+        arguments: execution.args,
+        success: succeeded,
+        error: succeeded ? undefined : 'Cancelled before it finished',
+      };
+    });
+  }
+
   /**
    * Find tool result message by execution ID
    */
@@ -608,6 +837,26 @@ export class ReactAIService implements AIServiceInterface {
     return null;
   }
 
+  /**
+   * Report a message the graph added as the steps of the chain it stands for
+   */
+  private reportStep(steps: ReActStepEmitter, message: BaseMessage): void {
+    const content = typeof message.content === 'string' ? message.content : '';
+
+    if (message instanceof ToolMessage) {
+      steps.toolResult(message.name ?? 'tool', content);
+    } else if (isAIMessage(message)) {
+      if (message.tool_calls?.length) {
+        steps.thought(content);
+        for (const toolCall of message.tool_calls) {
+          steps.toolCall(toolCall.name, toolCall.args);
+        }
+      } else {
+        steps.finalAnswer(content);
+      }
+    }
+  }
+
   /**
    * Extract tool executions from message history
    */
@@ -702,6 +951,14 @@ export class ReactAIService implements AIServiceInterface {
     this.apiKey = apiKey;
   }
 
+  /**
+   * Set how long a generation may run before it is aborted, in milliseconds.
+   * Zero or less disables the watchdog.
+   */
+  setRequestTimeout(timeoutMs: number): void {
+    this.requestTimeout = timeoutMs;
+  }
+
   /**
    * Set translation function for localized messages
    */
@@ -786,6 +1043,60 @@ export class ReactAIService implements AIServiceInterface {
     };
   }
 
+  /**
+   * List the Gemini models a request can be answered with
+   */
+  async listModelNames(): Promise<string[]> {
+    return [...GEMINI_MODELS];
+  }
+
+  /**
+   * Fold a thread's older messages into its rolling summary
+   */
+  async summarizeConversation(
+    previousSummary: string | undefined,
+    messages: ConversationTurn[]
+  ): Promise<string> {
+    if (!this.apiKey) {
+      throw new Error(
+        'AI model not initialized. Please provide a valid API key.'
+      );
+    }
+
+    const model = await loadChatModel(this.apiKey, DEFAULT_MODEL);
+    const transcript = messages
+      .map(
+        turn => `${turn.role === 'user' ? 'User' : 'Kira'}: ${turn.content}`
+      )
+      .join('\n\n');
+    const summarySoFar = previousSummary
+      ? `Summary so far:\n${previousSummary}\n\n`
+      : '';
+    const response = await this.runWithWatchdog(signal =>
+      getProviderMiddleware('gemini').run(
+        () =>
+          model.invoke(
+            [
+              { role: 'system', content: CONVERSATION_SUMMARY_PROMPT },
+              {
+                role: 'user',
+                content: `${summarySoFar}New messages:\n${transcript}`,
+              },
+            ],
+            { signal }
+          ),
+        signal
+      )
+    );
+
+    if (typeof response.content === 'string') {
+      return response.content;
+    }
+    return response.content
+      .map(item => ('text' in item ? item.text : ''))
+      .join('');
+  }
+
   /**
    * Clear conversation history (ReAct is stateless per invocation)
    */
@@ -0,0 +1,53 @@
+import { emit, listen, UnlistenFn } from '@tauri-apps/api/event';
+import { ResponseChunk } from '../../types';
+
+export const RESPONSE_CHUNK_EVENT = 'ai-response-chunk';
+
+/**
+ * Streams the text of one response as the model writes it, so the chat can
+ * render the answer progressively
+ */
+export class ResponseStreamEmitter {
+  private index = 0;
+
+  constructor(public readonly requestId: string) {}
+
+  token(text: string): void {
+    if (text) {
+      this.emit('token', text);
+    }
+  }
+
+  /**
+   * Drop the text streamed so far, when it turned out to lead up to tool
+   * calls rather than be the answer
+   */
+  reset(): void {
+    this.emit('reset', '');
+  }
+
+  private emit(kind: ResponseChunk['kind'], text: string): void {
+    const chunk: ResponseChunk = {
+      requestId: this.requestId,
+      index: this.index++,
+      kind,
+      text,
+    };
+
+    // Streaming is best effort, the full response still arrives at the end
+    emit(RESPONSE_CHUNK_EVENT, chunk).catch(error =>
+      console.warn('Failed to stream response chunk:', error)
+    );
+  }
+}
+
+/**
+ * Listen for the chunks of every streamed response
+ */
+export function onResponseChunk(
+  callback: (chunk: ResponseChunk) => void
+): Promise<UnlistenFn> {
+  return listen<ResponseChunk>(RESPONSE_CHUNK_EVENT, event =>
+    callback(event.payload)
+  );
+}
@@ -0,0 +1,123 @@
+import { ToolDefinition } from '@langchain/core/language_models/base';
+import { PromptTemplates } from '../../types';
+import { resolvePromptTemplates } from './KiraPrompts';
+
+/**
+ * A tool call read from a model's reply
+ */
+export interface TextToolCall {
+  name: string;
+  arguments: Record<string, unknown>;
+}
+
+/**
+ * Instructions for models without native tool calling, describing each tool
+ * by its JSON schema and, from the user's action and final answer templates,
+ * the JSON replies that call it or answer. Appended to the system prompt.
+ */
+export function describeToolsForPrompt(
+  tools: ToolDefinition[],
+  templates: PromptTemplates = {}
+): string {
+  const { action, finalAnswer } = resolvePromptTemplates(templates);
+  const descriptions = tools
+    .map(
+      ({ function: tool }) =>
+        `- ${tool.name}: ${tool.description ?? ''}\n  Arguments schema: ${JSON.stringify(tool.parameters)}`
+    )
+    .join('\n');
+
+  return `## Tools
+You can call these tools:
+${descriptions}
+
+${action}
+${finalAnswer}`;
+}
+
+/**
+ * JSON schema the model's replies are constrained to: a call to one of the
+ * tools with arguments matching its parameters, several independent calls
+ * at once, or an answer to the user. Decoding against it guarantees tool
+ * calls are valid JSON.
+ */
+export function toolReplySchema(
+  tools: ToolDefinition[]
+): Record<string, unknown> {
+  const calls = tools.map(({ function: tool }) => ({
+    type: 'object',
+    properties: {
+      tool: { type: 'string', enum: [tool.name] },
+      arguments: tool.parameters ?? { type: 'object' },
+    },
+    required: ['tool', 'arguments'],
+  }));
+
+  return {
+    anyOf: [
+      ...calls,
+      {
+        type: 'object',
+        properties: {
+          calls: { type: 'array', items: { anyOf: calls }, minItems: 1 },
+        },
+        required: ['calls'],
+      },
+      {
+        type: 'object',
+        properties: { answer: { type: 'string' } },
+        required: ['answer'],
+      },
+    ],
+  };
+}
+
+/**
+ * Read a reply decoded against `toolReplySchema`. A reply that isn't JSON,
+ * e.g. from a daemon that ignored the schema, is taken as the answer.
+ * @returns The tool calls the reply makes, if any, and its answer
+ */
+export function parseToolReply(content: string): {
+  calls: TextToolCall[];
+  answer: string;
+} {
+  let reply: unknown;
+  try {
+    reply = JSON.parse(content);
+  } catch {
+    return { calls: [], answer: content.trim() };
+  }
+  if (!reply || typeof reply !== 'object' || Array.isArray(reply)) {
+    return { calls: [], answer: content.trim() };
+  }
+
+  const { calls, answer } = reply as Record<string, unknown>;
+  const toolCalls = (Array.isArray(calls) ? calls : [reply])
+    .map(readToolCall)
+    .filter((call): call is TextToolCall => call !== null);
+  if (toolCalls.length > 0) {
+    return { calls: toolCalls, answer: '' };
+  }
+  return {
+    calls: [],
+    answer: typeof answer === 'string' ? answer.trim() : content.trim(),
+  };
+}
+
+/**
+ * Read one `{"tool": ..., "arguments": ...}` call of a reply
+ */
+function readToolCall(value: unknown): TextToolCall | null {
+  if (!value || typeof value !== 'object' || Array.isArray(value)) {
+    return null;
+  }
+  const { tool, arguments: args } = value as Record<string, unknown>;
+  if (typeof tool !== 'string') {
+    return null;
+  }
+  const isObject = args && typeof args === 'object' && !Array.isArray(args);
+  return {
+    name: tool,
+    arguments: isObject ? (args as Record<string, unknown>) : {},
+  };
+}
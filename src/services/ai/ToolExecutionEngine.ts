@@ -186,6 +186,20 @@ export class ToolExecutionEngine {
     return toolConfig.requiresConfirmation;
   }
 
+  /**
+   * Check if a tool only reads data, so it can run alongside other calls
+   */
+  isReadOnly(toolName: string): boolean {
+    const toolConfig = this.toolPermissions.get(toolName);
+    if (!toolConfig) {
+      return false;
+    }
+
+    return toolConfig.requiredPermissions.every(
+      required => required === PermissionLevel.READ_ONLY
+    );
+  }
+
   /**
    * Format tool execution result for user display
    */
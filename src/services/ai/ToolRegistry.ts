@@ -285,6 +285,8 @@ export class ToolRegistry {
     const readOnlyTools = [
       'get_tasks',
       'get_time_data',
+      'get_workday_context',
+      'generate_daily_plan',
       'analyze_productivity',
     ];
     const modifyTasksTools = ['create_task', 'update_task'];
@@ -0,0 +1,51 @@
+import { countTokens, fitConversation } from '../ContextBudget';
+
+describe('ContextBudget', () => {
+  it('should count tokens with each provider tokenizer', () => {
+    const text = 'a'.repeat(70);
+
+    expect(countTokens(text, 'gemini')).toBe(18);
+    expect(countTokens(text, 'ollama')).toBe(20);
+  });
+
+  it('should keep everything that fits', () => {
+    const conversation = {
+      summary: 'Talked about the launch.',
+      recentMessages: [
+        { role: 'user' as const, content: 'Hi' },
+        { role: 'assistant' as const, content: 'Hello!' },
+      ],
+    };
+
+    expect(fitConversation(conversation, 100, 'gemini')).toEqual(conversation);
+  });
+
+  it('should drop the oldest messages first', () => {
+    const conversation = {
+      summary: 'a'.repeat(40), // 10 tokens
+      recentMessages: [
+        { role: 'user' as const, content: 'b'.repeat(40) },
+        { role: 'assistant' as const, content: 'c'.repeat(40) },
+        { role: 'user' as const, content: 'd'.repeat(40) },
+        { role: 'assistant' as const, content: 'e'.repeat(40) },
+      ],
+    };
+
+    expect(fitConversation(conversation, 35, 'gemini')).toEqual({
+      summary: conversation.summary,
+      recentMessages: conversation.recentMessages.slice(2),
+    });
+  });
+
+  it('should cut the summary short when it does not fit alone', () => {
+    const conversation = {
+      summary: 'a'.repeat(400),
+      recentMessages: [{ role: 'user' as const, content: 'Hi' }],
+    };
+
+    expect(fitConversation(conversation, 25, 'gemini')).toEqual({
+      summary: 'a'.repeat(100),
+      recentMessages: [],
+    });
+  });
+});
@@ -0,0 +1,135 @@
+import { invoke } from '@tauri-apps/api/core';
+import { ConversationMemoryService } from '../ConversationMemoryService';
+import { Thread, ThreadMessage } from '../../../types';
+
+jest.mock('@tauri-apps/api/core', () => ({
+  invoke: jest.fn(),
+}));
+
+const mockInvoke = invoke as jest.MockedFunction<typeof invoke>;
+
+const start = new Date('2024-01-15T09:00:00Z').getTime();
+
+function at(minute: number): Date {
+  return new Date(start + minute * 60_000);
+}
+
+function message(minute: number): ThreadMessage {
+  return {
+    id: `message-${minute}`,
+    threadId: 'thread-1',
+    type: minute % 2 === 0 ? 'user' : 'assistant',
+    content: `Message ${minute}`,
+    timestamp: at(minute),
+  };
+}
+
+function backendMessage(minute: number): Record<string, unknown> {
+  const { id, type, content } = message(minute);
+  return {
+    id,
+    thread_id: 'thread-1',
+    type,
+    content,
+    reasoning: null,
+    actions: null,
+    suggestions: null,
+    tool_executions: null,
+    user_feedback: null,
+    action_results: null,
+    timestamp: at(minute).toISOString(),
+  };
+}
+
+const thread: Thread = {
+  id: 'thread-1',
+  title: 'Planning',
+  messageCount: 0,
+  createdAt: at(0),
+  updatedAt: at(0),
+};
+
+describe('ConversationMemoryService', () => {
+  let service: ConversationMemoryService;
+
+  beforeEach(() => {
+    service = new ConversationMemoryService({ summarizeAfter: 4, keepRecent: 2 });
+    jest.clearAllMocks();
+  });
+
+  it('should send the summary and the messages after it', () => {
+    const messages = [0, 1, 2, 3, 4].map(message);
+    const memory = service.buildMemory(
+      { ...thread, summary: 'Talked about Monday.', summarizedUntil: at(1) },
+      messages
+    );
+
+    expect(memory.summary).toBe('Talked about Monday.');
+    expect(memory.recentMessages).toEqual([
+      { role: 'user', content: 'Message 2' },
+      { role: 'assistant', content: 'Message 3' },
+      { role: 'user', content: 'Message 4' },
+    ]);
+  });
+
+  it('should open the recent messages with a user turn', () => {
+    const memory = service.buildMemory(
+      { ...thread, summarizedUntil: at(0) },
+      [0, 1, 2].map(message)
+    );
+
+    expect(memory.recentMessages).toEqual([
+      { role: 'user', content: 'Message 2' },
+    ]);
+  });
+
+  it('should fold all but the latest messages into the summary', async () => {
+    mockInvoke
+      .mockResolvedValueOnce([0, 1, 2, 3, 4].map(backendMessage))
+      .mockResolvedValueOnce({
+        id: 'thread-1',
+        title: 'Planning',
+        message_count: 5,
+        summary: 'New summary',
+        summarized_until: at(2).toISOString(),
+        created_at: at(0).toISOString(),
+        updated_at: at(4).toISOString(),
+      });
+    const summarize = jest.fn().mockResolvedValue(' New summary ');
+
+    const updated = await service.summarizeIfNeeded(thread, summarize);
+
+    expect(summarize).toHaveBeenCalledWith(undefined, [
+      { role: 'user', content: 'Message 0' },
+      { role: 'assistant', content: 'Message 1' },
+      { role: 'user', content: 'Message 2' },
+    ]);
+    expect(mockInvoke).toHaveBeenLastCalledWith('update_thread_summary', {
+      id: 'thread-1',
+      summary: 'New summary',
+      summarizedUntil: at(2).toISOString(),
+    });
+    expect(updated?.summarizedUntil).toEqual(at(2));
+  });
+
+  it('should wait until enough messages came after the summary', async () => {
+    mockInvoke.mockResolvedValueOnce([0, 1, 2, 3, 4].map(backendMessage));
+    const summarize = jest.fn();
+
+    const updated = await service.summarizeIfNeeded(
+      { ...thread, summary: 'Earlier', summarizedUntil: at(2) },
+      summarize
+    );
+
+    expect(updated).toBeNull();
+    expect(summarize).not.toHaveBeenCalled();
+    expect(mockInvoke).toHaveBeenCalledTimes(1);
+  });
+
+  it('should reject thresholds that keep everything', () => {
+    expect(
+      () => new ConversationMemoryService({ summarizeAfter: 2, keepRecent: 2 })
+    ).toThrow();
+    expect(() => service.updateConfig({ keepRecent: -1 })).toThrow();
+  });
+});
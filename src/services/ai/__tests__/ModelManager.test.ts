@@ -1,6 +1,7 @@
 import { ModelManager, getModelManager, ModelType } from '../ModelManager';
-import { AIServiceError } from '../AIServiceInterface';
+import { AIServiceError, GenerationTimeoutError } from '../AIServiceInterface';
 import { DistractionLevel } from '../../../types';
+import { LogStorageService } from '../../database/repositories/LogStorageService';
 
 // Mock ReactAIService module
 const mockReactAIService = {
@@ -30,6 +31,10 @@ const mockReactAIService = {
   })),
   clearConversation: jest.fn(),
   analyzePatterns: jest.fn(),
+  listModelNames: jest.fn().mockResolvedValue([
+    'gemini-2.5-pro',
+    'gemini-2.0-flash',
+  ]),
   cleanup: jest.fn(),
 };
 
@@ -37,6 +42,45 @@ jest.mock('../ReactAIService', () => ({
   ReactAIService: jest.fn().mockImplementation(() => mockReactAIService),
 }));
 
+// No Ollama daemon unless a test says otherwise
+const mockDetectOllama = jest.fn().mockResolvedValue(false);
+
+const mockOllamaService = {
+  isInitialized: jest.fn(() => true),
+  initialize: jest.fn().mockResolvedValue(undefined),
+  setTranslationFunction: jest.fn(),
+  setLoggingInterceptor: jest.fn(),
+  processMessage: jest.fn().mockResolvedValue({
+    message: 'Local response',
+    actions: [],
+    suggestions: [],
+    context: {},
+  }),
+  getModelInfo: jest.fn(() => ({
+    name: 'llama3.2:latest',
+    type: 'local',
+    status: 'ready',
+    capabilities: ['text_generation'],
+  })),
+  listModelNames: jest.fn().mockResolvedValue(['llama3.2:latest']),
+};
+
+jest.mock('../OllamaService', () => ({
+  DEFAULT_OLLAMA_URL: 'http://localhost:11434',
+  OllamaService: Object.assign(
+    jest.fn().mockImplementation(() => mockOllamaService),
+    { detect: (baseUrl: string) => mockDetectOllama(baseUrl) }
+  ),
+}));
+
+// Plugged in unless a test says otherwise
+const mockGetPowerState = jest.fn().mockResolvedValue(null);
+
+jest.mock('../PowerSaving', () => ({
+  ...jest.requireActual('../PowerSaving'),
+  getPowerState: () => mockGetPowerState(),
+}));
+
 describe('ModelManager', () => {
   let modelManager: ModelManager;
 
@@ -57,6 +101,16 @@ describe('ModelManager', () => {
       expect(availableModels).toContain('gemini');
       expect(availableModels).toEqual(['gemini']);
     });
+
+    it('should offer Ollama once its daemon is detected', async () => {
+      mockDetectOllama.mockResolvedValueOnce(true);
+
+      expect(await modelManager.detectOllama()).toBe(true);
+      expect(mockDetectOllama).toHaveBeenLastCalledWith(
+        'http://localhost:11434'
+      );
+      expect(modelManager.getAvailableModels()).toEqual(['gemini', 'ollama']);
+    });
   });
 
   describe('model switching', () => {
@@ -186,6 +240,222 @@ describe('ModelManager', () => {
         modelManager.processMessage('test message', mockContext)
       ).rejects.toThrow(AIServiceError);
     });
+
+    it('should retry on the next provider of the fallback chain', async () => {
+      localStorage.setItem(
+        'kirapilot-preferences',
+        JSON.stringify({ aiSettings: { fallbackChain: ['gemini', 'ollama'] } })
+      );
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+      mockReactAIService.processMessage.mockRejectedValueOnce(
+        new GenerationTimeoutError(120_000)
+      );
+
+      const response = await modelManager.processMessage(
+        'test message',
+        mockContext,
+        { requestId: 'request-1' }
+      );
+
+      expect(response.message).toBe('Local response');
+      expect(mockOllamaService.processMessage).toHaveBeenCalledWith(
+        'test message',
+        mockContext,
+        {
+          requestId: 'request-1',
+          failover: {
+            from: 'gemini',
+            reason: 'Generation timed out after 120 seconds',
+          },
+        }
+      );
+      expect(modelManager.getCurrentModelType()).toBe('gemini');
+      localStorage.removeItem('kirapilot-preferences');
+    });
+
+    it('should answer locally once the monthly budget is spent', async () => {
+      localStorage.setItem(
+        'kirapilot-preferences',
+        JSON.stringify({
+          aiSettings: {
+            fallbackChain: ['gemini', 'ollama'],
+            monthlyBudget: 5,
+            budgetAction: 'block',
+          },
+        })
+      );
+      const checkAiBudget = jest
+        .spyOn(LogStorageService.prototype, 'checkAiBudget')
+        .mockResolvedValue({
+          month: '2024-12',
+          budget: 5,
+          spent: 5.2,
+          threshold: 100,
+        });
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+
+      const response = await modelManager.processMessage(
+        'test message',
+        mockContext,
+        { requestId: 'request-1' }
+      );
+
+      expect(response.message).toBe('Local response');
+      expect(checkAiBudget).toHaveBeenCalledWith(5);
+      expect(mockReactAIService.processMessage).not.toHaveBeenCalled();
+      expect(mockOllamaService.processMessage).toHaveBeenCalledWith(
+        'test message',
+        mockContext,
+        {
+          requestId: 'request-1',
+          failover: {
+            from: 'gemini',
+            reason: 'Monthly AI budget of $5.00 reached',
+          },
+        }
+      );
+      checkAiBudget.mockRestore();
+      localStorage.removeItem('kirapilot-preferences');
+    });
+
+    it('should answer with the model picked for the request', async () => {
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+
+      const response = await modelManager.processMessage(
+        'test message',
+        mockContext,
+        { requestId: 'request-1', provider: 'ollama', model: 'mistral' }
+      );
+
+      expect(response.message).toBe('Local response');
+      expect(mockOllamaService.processMessage).toHaveBeenCalledWith(
+        'test message',
+        mockContext,
+        { requestId: 'request-1', provider: 'ollama', model: 'mistral' }
+      );
+      expect(mockReactAIService.processMessage).not.toHaveBeenCalled();
+      expect(modelManager.getCurrentModelType()).toBe('gemini');
+    });
+
+    it('should answer with the smaller model low on battery', async () => {
+      localStorage.setItem(
+        'kirapilot-preferences',
+        JSON.stringify({
+          aiSettings: { batteryThreshold: 20, batteryModel: 'llama3.2:1b' },
+        })
+      );
+      mockGetPowerState.mockResolvedValueOnce({
+        onBattery: true,
+        batteryPercent: 15,
+      });
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+
+      await modelManager.processMessage('test message', mockContext, {
+        requestId: 'request-1',
+        provider: 'ollama',
+        model: 'llama3.1:70b',
+      });
+
+      expect(mockOllamaService.processMessage).toHaveBeenCalledWith(
+        'test message',
+        mockContext,
+        { requestId: 'request-1', provider: 'ollama', model: 'llama3.2:1b' }
+      );
+      localStorage.removeItem('kirapilot-preferences');
+    });
+
+    it('should defer local requests low on battery', async () => {
+      localStorage.setItem(
+        'kirapilot-preferences',
+        JSON.stringify({
+          aiSettings: {
+            fallbackChain: ['ollama', 'gemini'],
+            batteryThreshold: 20,
+          },
+        })
+      );
+      mockGetPowerState.mockResolvedValueOnce({
+        onBattery: true,
+        batteryPercent: 15,
+      });
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+
+      const response = await modelManager.processMessage(
+        'test message',
+        mockContext,
+        { requestId: 'request-1', provider: 'ollama' }
+      );
+
+      expect(response.message).toBe('Test response');
+      expect(mockOllamaService.processMessage).not.toHaveBeenCalled();
+      expect(mockReactAIService.processMessage).toHaveBeenCalledWith(
+        'test message',
+        mockContext,
+        {
+          requestId: 'request-1',
+          provider: 'ollama',
+          model: undefined,
+          failover: {
+            from: 'ollama',
+            reason: 'Local model deferred on battery at 15%',
+          },
+        }
+      );
+      localStorage.removeItem('kirapilot-preferences');
+    });
+
+    it('should keep using the local model while plugged in', async () => {
+      localStorage.setItem(
+        'kirapilot-preferences',
+        JSON.stringify({ aiSettings: { batteryThreshold: 20 } })
+      );
+      mockGetPowerState.mockResolvedValueOnce({
+        onBattery: false,
+        batteryPercent: 15,
+      });
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+
+      const response = await modelManager.processMessage(
+        'test message',
+        mockContext,
+        { requestId: 'request-1', provider: 'ollama' }
+      );
+
+      expect(response.message).toBe('Local response');
+      localStorage.removeItem('kirapilot-preferences');
+    });
+  });
+
+  describe('model catalog', () => {
+    it('should list the models of every available provider', async () => {
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+
+      expect(await modelManager.listAvailableModels()).toEqual([
+        { provider: 'gemini', name: 'gemini-2.5-pro' },
+        { provider: 'gemini', name: 'gemini-2.0-flash' },
+        { provider: 'ollama', name: 'llama3.2:latest' },
+      ]);
+    });
+
+    it('should leave out providers whose models cannot be listed', async () => {
+      mockDetectOllama.mockResolvedValueOnce(true);
+      await modelManager.detectOllama();
+      mockOllamaService.listModelNames.mockRejectedValueOnce(
+        new Error('Failed to fetch')
+      );
+
+      expect(await modelManager.listAvailableModels()).toEqual([
+        { provider: 'gemini', name: 'gemini-2.5-pro' },
+        { provider: 'gemini', name: 'gemini-2.0-flash' },
+      ]);
+    });
   });
 
   describe('singleton pattern', () => {
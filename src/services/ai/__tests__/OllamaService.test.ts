@@ -0,0 +1,458 @@
+import { OllamaService } from '../OllamaService';
+import { ModelInitializationError } from '../AIServiceInterface';
+import { AppContext } from '../../../types';
+
+const mockToolInvoke = jest.fn();
+const mockTimeDataInvoke = jest.fn();
+
+jest.mock('../tools', () => ({
+  getKiraPilotTools: () => [
+    {
+      name: 'get_tasks',
+      invoke: (input: unknown) => mockToolInvoke(input),
+    },
+    {
+      name: 'get_time_data',
+      invoke: (input: unknown) => mockTimeDataInvoke(input),
+    },
+  ],
+}));
+
+jest.mock('@langchain/core/utils/function_calling', () => ({
+  convertToOpenAITool: (tool: { name: string }) => ({
+    type: 'function',
+    function: { name: tool.name },
+  }),
+}));
+
+const mockGetPromptTemplates = jest.fn().mockResolvedValue({});
+
+jest.mock('../../database/repositories', () => ({
+  getAIPersonaRepository: jest.fn(),
+  getPreferencesRepository: () => ({
+    getPromptTemplates: () => mockGetPromptTemplates(),
+  }),
+}));
+
+const mockEmit = jest.fn().mockResolvedValue(undefined);
+
+jest.mock('@tauri-apps/api/event', () => ({
+  emit: (event: string, payload: unknown) => mockEmit(event, payload),
+  listen: jest.fn(),
+}));
+
+const mockFetch = jest.fn();
+global.fetch = mockFetch as unknown as typeof fetch;
+
+// A response body that arrives in `parts`, one read each
+function streamedBody(parts: string[]) {
+  const reads = parts.map(part => new TextEncoder().encode(part));
+  return {
+    getReader: () => ({
+      read: async () => {
+        const value = reads.shift();
+        return value ? { done: false, value } : { done: true };
+      },
+    }),
+  };
+}
+
+function reply(body: unknown) {
+  return {
+    ok: true,
+    status: 200,
+    json: async () => body,
+    text: async () => JSON.stringify(body),
+    body: streamedBody([`${JSON.stringify(body)}\n`]),
+  };
+}
+
+function emitted(eventName: string): unknown[] {
+  return mockEmit.mock.calls
+    .filter(([event]) => event === eventName)
+    .map(([, payload]) => payload);
+}
+
+const tags = {
+  models: [
+    {
+      name: 'llama3.2:latest',
+      size: 2_019_393_189,
+      modified_at: '2024-10-01T12:00:00Z',
+      details: { family: 'llama', parameter_size: '3.2B' },
+    },
+    {
+      name: 'nomic-embed-text:latest',
+      size: 274_302_450,
+      modified_at: '2024-09-01T12:00:00Z',
+    },
+  ],
+};
+
+const context: AppContext = {
+  focusMode: false,
+  timeOfDay: '09:00',
+  dayOfWeek: 1,
+  currentEnergy: 80,
+  recentActivity: [],
+  preferences: {} as AppContext['preferences'],
+};
+
+function sentBody(call: number): Record<string, unknown> {
+  return JSON.parse(mockFetch.mock.calls[call][1].body);
+}
+
+describe('OllamaService', () => {
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should detect whether the daemon is running', async () => {
+    mockFetch.mockResolvedValueOnce(reply({ version: '0.3.12' }));
+    expect(await OllamaService.detect()).toBe(true);
+    expect(mockFetch.mock.calls[0][0]).toBe(
+      'http://localhost:11434/api/version'
+    );
+
+    mockFetch.mockRejectedValueOnce(new TypeError('Failed to fetch'));
+    expect(await OllamaService.detect()).toBe(false);
+  });
+
+  it('should fall back to the first downloaded model', async () => {
+    mockFetch
+      .mockResolvedValueOnce(reply({ version: '0.3.12' }))
+      .mockResolvedValueOnce(reply(tags));
+    const service = new OllamaService('http://localhost:11434/', 'mistral');
+
+    await service.initialize();
+
+    expect(service.isInitialized()).toBe(true);
+    expect(service.getModel()).toBe('llama3.2:latest');
+    expect(service.getModelInfo()).toMatchObject({
+      type: 'local',
+      version: '3.2B',
+      size: '1.9 GB',
+    });
+  });
+
+  it('should fail to initialize without downloaded models', async () => {
+    mockFetch
+      .mockResolvedValueOnce(reply({ version: '0.3.12' }))
+      .mockResolvedValueOnce(reply({ models: [] }));
+    const service = new OllamaService();
+
+    await expect(service.initialize()).rejects.toThrow(
+      ModelInitializationError
+    );
+    expect(service.getStatus().error).toContain('ollama pull');
+  });
+
+  it('should call tools until the model answers', async () => {
+    mockToolInvoke.mockResolvedValue('{"success":true,"tasks":[]}');
+    mockFetch
+      .mockResolvedValueOnce(
+        reply({
+          message: {
+            role: 'assistant',
+            content: '',
+            tool_calls: [
+              { function: { name: 'get_tasks', arguments: { limit: 5 } } },
+            ],
+          },
+          prompt_eval_count: 900,
+          eval_count: 20,
+        })
+      )
+      .mockResolvedValueOnce(
+        reply({
+          message: { role: 'assistant', content: 'No tasks today.' },
+          prompt_eval_count: 950,
+          eval_count: 6,
+        })
+      );
+    const service = new OllamaService(undefined, 'llama3.2:latest');
+
+    const response = await service.processMessage('What is due?', context);
+
+    expect(mockToolInvoke).toHaveBeenCalledWith({ limit: 5 });
+    expect(response.message).toBe('No tasks today.');
+    expect(response.actions).toHaveLength(1);
+    expect(response.actions[0]).toMatchObject({
+      type: 'GET_TASKS',
+      parameters: { limit: 5 },
+      confidence: 100,
+    });
+    expect(response.usage).toEqual({ inputTokens: 1850, outputTokens: 26 });
+    expect(sentBody(0).options).toEqual({ num_ctx: 4096 });
+    const followUp = sentBody(1).messages as { role: string }[];
+    expect(followUp[followUp.length - 1]).toEqual({
+      role: 'tool',
+      tool_name: 'get_tasks',
+      content: '{"success":true,"tasks":[]}',
+    });
+  });
+
+  it('should report each step of the chain as it happens', async () => {
+    mockToolInvoke.mockResolvedValue('{"success":true,"tasks":[]}');
+    mockFetch
+      .mockResolvedValueOnce(
+        reply({
+          message: {
+            role: 'assistant',
+            content: 'I should look at the tasks.',
+            tool_calls: [
+              { function: { name: 'get_tasks', arguments: { limit: 5 } } },
+            ],
+          },
+        })
+      )
+      .mockResolvedValueOnce(
+        reply({ message: { role: 'assistant', content: 'No tasks today.' } })
+      );
+    const service = new OllamaService(undefined, 'llama3.2:latest');
+
+    await service.processMessage('What is due?', context, {
+      requestId: 'request-1',
+    });
+
+    expect(emitted('ai-react-step')).toEqual([
+      expect.objectContaining({
+        chainId: 'request-1',
+        index: 0,
+        kind: 'thought',
+        content: 'I should look at the tasks.',
+      }),
+      expect.objectContaining({
+        index: 1,
+        kind: 'tool_call',
+        toolName: 'get_tasks',
+        content: '{"limit":5}',
+      }),
+      expect.objectContaining({
+        index: 2,
+        kind: 'tool_result',
+        toolName: 'get_tasks',
+        content: '{"success":true,"tasks":[]}',
+      }),
+      expect.objectContaining({
+        index: 3,
+        kind: 'final_answer',
+        content: 'No tasks today.',
+      }),
+    ]);
+  });
+
+  it('should stream the answer as the model writes it', async () => {
+    mockToolInvoke.mockResolvedValue('{"success":true,"tasks":[]}');
+    const line = (body: unknown) => `${JSON.stringify(body)}\n`;
+    const thought = line({
+      message: {
+        role: 'assistant',
+        content: 'Checking.',
+        tool_calls: [
+          { function: { name: 'get_tasks', arguments: { limit: 5 } } },
+        ],
+      },
+      done: true,
+    });
+    const answer =
+      line({ message: { role: 'assistant', content: 'No tasks' } }) +
+      line({
+        message: { role: 'assistant', content: ' today.' },
+        done: true,
+        prompt_eval_count: 950,
+        eval_count: 6,
+      });
+    mockFetch
+      .mockResolvedValueOnce({ ok: true, body: streamedBody([thought]) })
+      .mockResolvedValueOnce({
+        ok: true,
+        // A read may end in the middle of a line
+        body: streamedBody([answer.slice(0, 30), answer.slice(30)]),
+      });
+    const service = new OllamaService(undefined, 'llama3.2:latest');
+
+    const response = await service.processMessage('What is due?', context, {
+      requestId: 'request-1',
+    });
+
+    expect(response.message).toBe('No tasks today.');
+    expect(response.usage).toEqual({ inputTokens: 950, outputTokens: 6 });
+    expect(sentBody(0).stream).toBe(true);
+    expect(emitted('ai-response-chunk')).toEqual([
+      { requestId: 'request-1', index: 0, kind: 'token', text: 'Checking.' },
+      { requestId: 'request-1', index: 1, kind: 'reset', text: '' },
+      { requestId: 'request-1', index: 2, kind: 'token', text: 'No tasks' },
+      { requestId: 'request-1', index: 3, kind: 'token', text: ' today.' },
+    ]);
+  });
+
+  it('should run independent tool calls of a turn together', async () => {
+    let finishTasks: (result: string) => void = () => {};
+    mockToolInvoke.mockReturnValueOnce(
+      new Promise<string>(resolve => {
+        finishTasks = resolve;
+      })
+    );
+    mockTimeDataInvoke.mockImplementationOnce(async () => {
+      // Runs while get_tasks is still waiting for its result
+      finishTasks('{"success":true,"tasks":[]}');
+      return '{"success":true,"sessions":[]}';
+    });
+    mockFetch
+      .mockResolvedValueOnce(
+        reply({
+          message: {
+            role: 'assistant',
+            content: '',
+            tool_calls: [
+              { function: { name: 'get_tasks', arguments: {} } },
+              { function: { name: 'get_time_data', arguments: {} } },
+            ],
+          },
+        })
+      )
+      .mockResolvedValueOnce(
+        reply({ message: { role: 'assistant', content: 'A quiet day.' } })
+      );
+    const service = new OllamaService(undefined, 'llama3.2:latest');
+
+    const response = await service.processMessage('How is today?', context);
+
+    expect(response.message).toBe('A quiet day.');
+    expect(response.actions).toHaveLength(2);
+    const followUp = sentBody(1).messages as { role: string }[];
+    expect(followUp.slice(-2)).toEqual([
+      {
+        role: 'tool',
+        tool_name: 'get_tasks',
+        content: '{"success":true,"tasks":[]}',
+      },
+      {
+        role: 'tool',
+        tool_name: 'get_time_data',
+        content: '{"success":true,"sessions":[]}',
+      },
+    ]);
+  });
+
+  it('should constrain tool calls of models without tool calling', async () => {
+    mockToolInvoke.mockResolvedValue('{"success":true,"tasks":[]}');
+    mockFetch
+      .mockResolvedValueOnce({
+        ok: false,
+        status: 400,
+        text: async () =>
+          '{"error":"registry.ollama.ai/library/gemma:2b does not support tools"}',
+      })
+      .mockResolvedValueOnce(
+        reply({
+          message: {
+            role: 'assistant',
+            content: '{"tool": "get_tasks", "arguments": {"limit": 5}}',
+          },
+        })
+      )
+      .mockResolvedValueOnce(
+        reply({
+          message: {
+            role: 'assistant',
+            content: '{"answer": "No tasks today."}',
+          },
+        })
+      );
+    const service = new OllamaService(undefined, 'gemma:2b');
+
+    const response = await service.processMessage('What is due?', context);
+
+    expect(mockToolInvoke).toHaveBeenCalledWith({ limit: 5 });
+    expect(response.message).toBe('No tasks today.');
+    const retry = sentBody(1) as {
+      messages: { content: string }[];
+      format: { anyOf: unknown[] };
+    };
+    expect(retry).not.toHaveProperty('tools');
+    expect(retry.messages[0].content).toContain('"tool": "tool_name"');
+    const getTasksCall = {
+      type: 'object',
+      properties: {
+        tool: { type: 'string', enum: ['get_tasks'] },
+        arguments: { type: 'object' },
+      },
+      required: ['tool', 'arguments'],
+    };
+    expect(retry.format.anyOf).toHaveLength(4);
+    expect(retry.format.anyOf[0]).toEqual(getTasksCall);
+    expect(retry.format.anyOf[2]).toMatchObject({
+      properties: {
+        calls: { type: 'array', items: { anyOf: [getTasksCall, {}] } },
+      },
+      required: ['calls'],
+    });
+    expect(retry.format.anyOf[3]).toEqual({
+      type: 'object',
+      properties: { answer: { type: 'string' } },
+      required: ['answer'],
+    });
+    const followUp = sentBody(2).messages as { role: string }[];
+    expect(followUp[followUp.length - 1]).toEqual({
+      role: 'user',
+      content: 'Observation from get_tasks: {"success":true,"tasks":[]}',
+    });
+  });
+
+  it("should answer with the user's prompt templates", async () => {
+    mockGetPromptTemplates.mockResolvedValueOnce({
+      systemPersona: 'You are Kira, a terse planner.',
+      action: 'Call a tool by replying {"tool": "name", "arguments": {}}.',
+    });
+    mockFetch
+      .mockResolvedValueOnce({
+        ok: false,
+        status: 400,
+        text: async () => '{"error":"gemma:2b does not support tools"}',
+      })
+      .mockResolvedValueOnce(
+        reply({
+          message: { role: 'assistant', content: '{"answer": "Done."}' },
+        })
+      );
+    const service = new OllamaService(undefined, 'gemma:2b');
+
+    await service.processMessage('Plan my day', context);
+
+    const system = (sentBody(1).messages as { content: string }[])[0].content;
+    expect(system).toMatch(/^You are Kira, a terse planner\.\n/);
+    expect(system).toContain('Current context: {');
+    expect(system).toContain(
+      'Call a tool by replying {"tool": "name", "arguments": {}}.\nOnce you have what you need'
+    );
+    expect(system).not.toContain('Always reply with a single JSON object');
+  });
+
+  it('should embed texts with the current model', async () => {
+    mockFetch.mockResolvedValueOnce(
+      reply({
+        embeddings: [
+          [0.1, 0.2],
+          [0.3, 0.4],
+        ],
+      })
+    );
+    const service = new OllamaService(undefined, 'nomic-embed-text:latest');
+
+    const embeddings = await service.embed(['first', 'second']);
+
+    expect(embeddings).toEqual([
+      [0.1, 0.2],
+      [0.3, 0.4],
+    ]);
+    expect(mockFetch.mock.calls[0][0]).toBe(
+      'http://localhost:11434/api/embed'
+    );
+    expect(sentBody(0)).toEqual({
+      model: 'nomic-embed-text:latest',
+      input: ['first', 'second'],
+    });
+  });
+});
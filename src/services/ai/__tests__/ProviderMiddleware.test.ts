@@ -0,0 +1,158 @@
+import { ProviderCircuitOpenError } from '../AIServiceInterface';
+import {
+  ProviderMiddleware,
+  ProviderMiddlewareConfig,
+  httpStatusOf,
+  retryDelayOf,
+} from '../ProviderMiddleware';
+
+// Error shaped like the Google Generative AI client's fetch errors
+function fetchError(
+  status: number,
+  statusText: string,
+  errorDetails?: unknown[]
+): Error {
+  return Object.assign(
+    new Error(
+      `[GoogleGenerativeAI Error]: Error fetching from https://generativelanguage.googleapis.com: [${status} ${statusText}]`
+    ),
+    { status, errorDetails }
+  );
+}
+
+describe('ProviderMiddleware', () => {
+  let now: number;
+  let sleeps: number[];
+
+  const createMiddleware = (config: Partial<ProviderMiddlewareConfig> = {}) =>
+    new ProviderMiddleware('gemini', config, {
+      now: () => now,
+      sleep: async ms => {
+        sleeps.push(ms);
+        now += ms;
+      },
+      random: () => 0,
+    });
+
+  beforeEach(() => {
+    now = 1_000_000;
+    sleeps = [];
+  });
+
+  it('should retry rate limited and failed requests with backoff', async () => {
+    const middleware = createMiddleware();
+    const request = jest
+      .fn()
+      .mockRejectedValueOnce(fetchError(429, 'Too Many Requests'))
+      .mockRejectedValueOnce(fetchError(503, 'Service Unavailable'))
+      .mockResolvedValueOnce('answer');
+
+    await expect(middleware.run(request)).resolves.toBe('answer');
+    expect(request).toHaveBeenCalledTimes(3);
+    expect(sleeps).toEqual([500, 1000]);
+  });
+
+  it('should wait as long as the provider asks before retrying', async () => {
+    const middleware = createMiddleware();
+    const request = jest
+      .fn()
+      .mockRejectedValueOnce(
+        fetchError(429, 'Too Many Requests', [
+          {
+            '@type': 'type.googleapis.com/google.rpc.RetryInfo',
+            retryDelay: '17s',
+          },
+        ])
+      )
+      .mockResolvedValueOnce('answer');
+
+    await expect(middleware.run(request)).resolves.toBe('answer');
+    expect(sleeps).toEqual([500, 16_500]);
+  });
+
+  it('should give up after the last retry', async () => {
+    const middleware = createMiddleware({ maxRetries: 2 });
+    const error = fetchError(500, 'Internal Server Error');
+    const request = jest.fn().mockRejectedValue(error);
+
+    await expect(middleware.run(request)).rejects.toBe(error);
+    expect(request).toHaveBeenCalledTimes(3);
+  });
+
+  it('should not retry requests the provider rejected', async () => {
+    const middleware = createMiddleware();
+    const error = fetchError(400, 'Bad Request');
+    const request = jest.fn().mockRejectedValue(error);
+
+    await expect(middleware.run(request)).rejects.toBe(error);
+    expect(request).toHaveBeenCalledTimes(1);
+  });
+
+  it('should limit the requests started each minute', async () => {
+    const middleware = createMiddleware({ requestsPerMinute: 2 });
+    const request = jest.fn().mockResolvedValue('answer');
+
+    await middleware.run(request);
+    now += 10_000;
+    await middleware.run(request);
+    await middleware.run(request);
+
+    expect(sleeps).toEqual([50_000]);
+  });
+
+  it('should fail fast while the provider keeps failing', async () => {
+    const middleware = createMiddleware({
+      maxRetries: 0,
+      failureThreshold: 2,
+      cooldownMs: 30_000,
+    });
+    const failing = jest
+      .fn()
+      .mockRejectedValue(fetchError(503, 'Service Unavailable'));
+
+    await expect(middleware.run(failing)).rejects.toThrow('503');
+    await expect(middleware.run(failing)).rejects.toThrow('503');
+    await expect(middleware.run(failing)).rejects.toThrow(
+      ProviderCircuitOpenError
+    );
+    expect(failing).toHaveBeenCalledTimes(2);
+
+    // After the cooldown a trial request closes the circuit again
+    now += 30_000;
+    const request = jest.fn().mockResolvedValue('answer');
+    await expect(middleware.run(request)).resolves.toBe('answer');
+    await expect(middleware.run(request)).resolves.toBe('answer');
+  });
+
+  it('should reopen the circuit when the trial request fails', async () => {
+    const middleware = createMiddleware({
+      maxRetries: 0,
+      failureThreshold: 1,
+      cooldownMs: 30_000,
+    });
+    const failing = jest
+      .fn()
+      .mockRejectedValue(fetchError(502, 'Bad Gateway'));
+
+    await expect(middleware.run(failing)).rejects.toThrow('502');
+    now += 30_000;
+    await expect(middleware.run(failing)).rejects.toThrow('502');
+    await expect(middleware.run(failing)).rejects.toThrow(
+      ProviderCircuitOpenError
+    );
+  });
+
+  it('should read the status and requested delay of provider errors', () => {
+    expect(httpStatusOf(fetchError(429, 'Too Many Requests'))).toBe(429);
+    expect(
+      httpStatusOf(new Error('Error fetching: [503 Service Unavailable]'))
+    ).toBe(503);
+    expect(httpStatusOf({ response: { status: 502 } })).toBe(502);
+    expect(httpStatusOf(new Error('fetch failed'))).toBeUndefined();
+
+    expect(retryDelayOf({ errorDetails: [{ retryDelay: '2.5s' }] })).toBe(
+      2500
+    );
+    expect(retryDelayOf(new Error('no details'))).toBeUndefined();
+  });
+});
@@ -0,0 +1,93 @@
+import {
+  describeToolsForPrompt,
+  parseToolReply,
+  toolReplySchema,
+} from '../TextToolCalling';
+
+const createTask = {
+  type: 'function' as const,
+  function: {
+    name: 'create_task',
+    description: 'Create a task',
+    parameters: {
+      type: 'object',
+      properties: { title: { type: 'string' } },
+      required: ['title'],
+    },
+  },
+};
+
+describe('TextToolCalling', () => {
+  it('should describe each tool with its schema', () => {
+    expect(describeToolsForPrompt([createTask])).toContain(
+      '- create_task: Create a task\n  Arguments schema: {"type":"object","properties":{"title":{"type":"string"}},"required":["title"]}'
+    );
+  });
+
+  it('should constrain replies to tool calls or an answer', () => {
+    const call = {
+      type: 'object',
+      properties: {
+        tool: { type: 'string', enum: ['create_task'] },
+        arguments: createTask.function.parameters,
+      },
+      required: ['tool', 'arguments'],
+    };
+    expect(toolReplySchema([createTask])).toEqual({
+      anyOf: [
+        call,
+        {
+          type: 'object',
+          properties: {
+            calls: { type: 'array', items: { anyOf: [call] }, minItems: 1 },
+          },
+          required: ['calls'],
+        },
+        {
+          type: 'object',
+          properties: { answer: { type: 'string' } },
+          required: ['answer'],
+        },
+      ],
+    });
+  });
+
+  it('should read a tool call', () => {
+    expect(
+      parseToolReply(
+        '{"tool": "create_task", "arguments": {"title": "Plan {sprint}"}}'
+      )
+    ).toEqual({
+      calls: [{ name: 'create_task', arguments: { title: 'Plan {sprint}' } }],
+      answer: '',
+    });
+  });
+
+  it('should read several tool calls', () => {
+    expect(
+      parseToolReply(
+        '{"calls": [{"tool": "get_tasks", "arguments": {}}, {"tool": "get_time_data"}]}'
+      )
+    ).toEqual({
+      calls: [
+        { name: 'get_tasks', arguments: {} },
+        { name: 'get_time_data', arguments: {} },
+      ],
+      answer: '',
+    });
+  });
+
+  it('should read an answer', () => {
+    expect(parseToolReply('{"answer": " All done. "}')).toEqual({
+      calls: [],
+      answer: 'All done.',
+    });
+  });
+
+  it('should take a reply that is not JSON as the answer', () => {
+    expect(parseToolReply('All done.\n')).toEqual({
+      calls: [],
+      answer: 'All done.',
+    });
+  });
+});
@@ -111,6 +111,13 @@ describe('ToolExecutionEngine', () => {
       expect(engine.requiresConfirmation('get_time_data')).toBe(false);
     });
 
+    test('should tell read-only tools apart', () => {
+      expect(engine.isReadOnly('get_tasks')).toBe(true);
+      expect(engine.isReadOnly('get_time_data')).toBe(true);
+      expect(engine.isReadOnly('create_task')).toBe(false);
+      expect(engine.isReadOnly('unknown_tool')).toBe(false);
+    });
+
     test('should respect auto-approve preferences', () => {
       engine.updatePreferences({
         autoApprove: ['create_task'],
@@ -4,6 +4,8 @@ export {
   getReactAIService,
   initializeReactAIService,
 } from './ReactAIService';
+export { OllamaService, DEFAULT_OLLAMA_URL } from './OllamaService';
+export type { OllamaModel } from './OllamaService';
 
 export { getKiraPilotTools } from './tools';
 export {
@@ -42,14 +44,22 @@ export type {
   AIServiceInterface,
   ModelInfo,
   ModelStatus,
+  ProcessMessageOptions,
 } from './AIServiceInterface';
 export {
   AIServiceError,
   ModelNotAvailableError,
   ModelInitializationError,
   ModelProcessingError,
+  GenerationTimeoutError,
+  GenerationCancelledError,
 } from './AIServiceInterface';
-export type { ModelType, ModelConfig } from './ModelManager';
+export type {
+  ModelType,
+  ModelConfig,
+  AvailableModel,
+  ModelRequestOptions,
+} from './ModelManager';
 export {
   ModelManager,
   getModelManager,
@@ -77,6 +87,31 @@ export type {
   ProcessingStep,
 } from './DetailedInteractionLogger';
 
+// Replaying logged interactions against other models
+export {
+  InteractionReplayService,
+  getInteractionReplayService,
+} from './InteractionReplayService';
+export type { ReplayOptions } from './InteractionReplayService';
+
+// Evaluating prompt template changes
+export {
+  PromptEvaluationService,
+  getPromptEvaluationService,
+} from './PromptEvaluationService';
+export type { PromptEvaluationOptions } from './PromptEvaluationService';
+
+// Summarizing long threads
+export {
+  ConversationMemoryService,
+  getConversationMemoryService,
+  DEFAULT_CONVERSATION_MEMORY_CONFIG,
+} from './ConversationMemoryService';
+export type {
+  ConversationMemoryConfig,
+  ConversationSummarizer,
+} from './ConversationMemoryService';
+
 // Performance monitoring
 export {
   PerformanceMonitor,
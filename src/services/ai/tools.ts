@@ -8,10 +8,12 @@ import {
   RecurrenceType,
   CreatePeriodicTaskRequest,
   UpdatePeriodicTaskRequest,
+  EstimationReport,
 } from '../../types';
 import {
   getTaskRepository,
   getTimeTrackingRepository,
+  getPreferencesRepository,
 } from '../database/repositories';
 import { PeriodicTaskService } from '../database/repositories/PeriodicTaskService';
 import { IntelligentTaskMatcher } from './IntelligentTaskMatcher';
@@ -25,6 +27,11 @@ interface TaskResponse {
   status: TaskStatus;
 }
 
+interface ResolvedDateResponse {
+  phrase: string;
+  date: string;
+}
+
 interface StoppedSessionResponse {
   id: string;
   duration: number;
@@ -215,6 +222,23 @@ const toolHelper = new UserFriendlyToolHelper();
 /**
  * Create a new task in the system
  */
+// Accepts ISO dates as well as phrases like "tomorrow 3pm" or "in 2 weeks"
+async function resolveDateInput(
+  value: string | undefined
+): Promise<{ date: Date; matched?: string } | undefined> {
+  if (!value) {
+    return undefined;
+  }
+  if (/^\d{4}-\d{2}-\d{2}/.test(value.trim())) {
+    return { date: new Date(value) };
+  }
+  const parsed = await getTaskRepository().parseNaturalDate(value);
+  if (!parsed) {
+    throw new Error(`Could not understand the date "${value}"`);
+  }
+  return { date: parsed.date, matched: parsed.matched };
+}
+
 interface CreateTaskInput {
   title: string;
   description?: string;
@@ -223,6 +247,7 @@ interface CreateTaskInput {
   dueDate?: string;
   scheduledDate?: string;
   tags?: string[];
+  customFields?: Record<string, string>;
 }
 
 const createTaskTool = tool(
@@ -235,19 +260,32 @@ const createTaskTool = tool(
       dueDate,
       scheduledDate,
       tags,
+      customFields,
     } = input;
     try {
       const taskRepo = getTaskRepository();
-      const task = await taskRepo.create({
-        title,
-        description,
-        priority: priority ?? Priority.MEDIUM,
-        timeEstimate: timeEstimate ?? 60,
-        dueDate: dueDate ? new Date(dueDate) : undefined,
-        scheduledDate: scheduledDate ? new Date(scheduledDate) : undefined,
-        tags: tags ?? [],
-      });
-      const response: { success: true; task: TaskResponse } = {
+      const due = await resolveDateInput(dueDate);
+      const scheduled = await resolveDateInput(scheduledDate);
+      const task = await taskRepo.create(
+        {
+          title,
+          description,
+          priority: priority ?? Priority.MEDIUM,
+          timeEstimate: timeEstimate ?? 60,
+          dueDate: due?.date,
+          scheduledDate: scheduled?.date,
+          tags: tags ?? [],
+        },
+        'ai'
+      );
+      if (customFields && Object.keys(customFields).length > 0) {
+        await taskRepo.setCustomFieldValues(task.id, customFields);
+      }
+      const response: {
+        success: true;
+        task: TaskResponse;
+        resolvedDates?: Record<string, ResolvedDateResponse>;
+      } = {
         success: true,
         task: {
           id: task.id,
@@ -256,6 +294,21 @@ const createTaskTool = tool(
           status: task.status,
         },
       };
+      // Phrases are echoed back with their resolved dates for confirmation
+      for (const [field, resolved] of [
+        ['dueDate', due],
+        ['scheduledDate', scheduled],
+      ] as const) {
+        if (resolved?.matched) {
+          response.resolvedDates = {
+            ...response.resolvedDates,
+            [field]: {
+              phrase: resolved.matched,
+              date: resolved.date.toISOString(),
+            },
+          };
+        }
+      }
       return JSON.stringify(response);
     } catch (error) {
       const errorResponse: { success: false; error: string } = {
@@ -283,17 +336,25 @@ const createTaskTool = tool(
       dueDate: z
         .string()
         .optional()
-        .describe('Due date in ISO format (YYYY-MM-DD)'),
+        .describe(
+          'Due date in ISO format (YYYY-MM-DD) or as a phrase like "tomorrow 3pm", "next friday" or "in 2 weeks"'
+        ),
       scheduledDate: z
         .string()
         .optional()
         .describe(
-          'Scheduled date when task should be worked on in ISO format (YYYY-MM-DD)'
+          'Scheduled date when task should be worked on, in ISO format (YYYY-MM-DD) or as a phrase like "tomorrow 3pm"'
         ),
       tags: z
         .array(z.string())
         .optional()
         .describe('Array of tags for categorization'),
+      customFields: z
+        .record(z.string(), z.string())
+        .optional()
+        .describe(
+          'Values for custom fields defined on the task list, keyed by field name (numbers as digits, dates as YYYY-MM-DD, select fields as one of their options)'
+        ),
     }),
   }
 );
@@ -309,6 +370,7 @@ interface UpdateTaskInput {
     priority?: number;
     status?: string;
     scheduledDate?: string;
+    customFields?: Record<string, string>;
   };
 }
 
@@ -344,7 +406,17 @@ const updateTaskTool = tool(
           : undefined,
       };
 
-      const task = await taskRepo.update(findResult.task.id, processedUpdates);
+      const task = await taskRepo.update(
+        findResult.task.id,
+        processedUpdates,
+        'ai'
+      );
+      if (
+        updates.customFields &&
+        Object.keys(updates.customFields).length > 0
+      ) {
+        await taskRepo.setCustomFieldValues(task.id, updates.customFields);
+      }
 
       // Create user-friendly response
       const changedFields = Object.keys(updates).filter(
@@ -363,6 +435,8 @@ const updateTaskTool = tool(
               return `status to ${toolHelper.getStatusLabel(updates.status as TaskStatus)}`;
             case 'scheduledDate':
               return `scheduled date to ${new Date(updates.scheduledDate!).toLocaleDateString()}`;
+            case 'customFields':
+              return `custom fields (${Object.keys(updates.customFields!).join(', ')})`;
             default:
               return field;
           }
@@ -421,6 +495,12 @@ const updateTaskTool = tool(
             .describe(
               'When the task is scheduled to be worked on (YYYY-MM-DD format)'
             ),
+          customFields: z
+            .record(z.string(), z.string())
+            .optional()
+            .describe(
+              'Custom field values keyed by field name; an empty string clears a field'
+            ),
         })
         .describe('Object containing the updates to apply'),
     }),
@@ -746,7 +826,7 @@ const completeTaskTool = tool(
           `Completion notes: ${notes}`;
       }
 
-      const task = await taskRepo.update(findResult.task.id, updates);
+      const task = await taskRepo.update(findResult.task.id, updates, 'ai');
 
       const response = {
         success: true,
@@ -988,6 +1068,94 @@ const getTimeDataTool = tool(
   }
 );
 
+const getWorkdayContextTool = tool(
+  async () => {
+    try {
+      const context = await getPreferencesRepository().getWorkdayContext();
+      return JSON.stringify({ success: true, context });
+    } catch (error) {
+      const errorResponse: { success: false; error: string } = {
+        success: false,
+        error:
+          error instanceof Error
+            ? error.message
+            : 'Failed to get workday context',
+      };
+      return JSON.stringify(errorResponse);
+    }
+  },
+  {
+    name: 'get_workday_context',
+    description:
+      "Get the user's working hours, working days and holidays, whether they are working right now, the working minutes left today and when they next start work. Use it before scheduling or suggesting when to do something.",
+    schema: z.object({}),
+  }
+);
+
+const generateDailyPlanTool = tool(
+  async (input: { taskListId?: string }) => {
+    try {
+      const taskRepo = getTaskRepository();
+      const plan = await taskRepo.generateDailyPlan(input.taskListId);
+      return JSON.stringify({ success: true, plan });
+    } catch (error) {
+      const errorResponse: { success: false; error: string } = {
+        success: false,
+        error:
+          error instanceof Error
+            ? error.message
+            : 'Failed to generate daily plan',
+      };
+      return JSON.stringify(errorResponse);
+    }
+  },
+  {
+    name: 'generate_daily_plan',
+    description:
+      'Plan the rest of today: fills the working time left with open tasks in order, starting with overdue work and work due or scheduled today, then the critical path and the highest priorities, keeping dependencies first. Also lists tasks due today that do not fit. Use it when the user asks to plan their day, and present the returned schedule rather than making one up.',
+    schema: z.object({
+      taskListId: z
+        .string()
+        .optional()
+        .describe('Only plan tasks from this task list'),
+    }),
+  }
+);
+
+// Estimation accuracy needs more history than the analyzed timeframe
+const ESTIMATION_HISTORY_DAYS = 90;
+
+/**
+ * Warnings for tags, lists and priorities whose tasks keep taking longer
+ * than estimated
+ */
+function describeUnderestimation(report: EstimationReport): string[] {
+  const warnings: string[] = [];
+  const ratio = (value: number) => `${value.toFixed(1)}x`;
+  if (report.overall.chronicUnderestimation) {
+    warnings.push(
+      `⚠️ Tasks take ${ratio(report.overall.ratio)} their estimate on average - pad new estimates accordingly`
+    );
+  }
+  const groups = [
+    ...report.byTag.map(group => ({ ...group, kind: 'tagged' })),
+    ...report.byTaskList.map(group => ({ ...group, kind: 'in list' })),
+  ];
+  for (const group of groups.filter(g => g.chronicUnderestimation)) {
+    warnings.push(
+      `⚠️ Tasks ${group.kind} "${group.label}" take ${ratio(group.ratio)} their estimate (${group.taskCount} tasks)`
+    );
+  }
+  for (const group of report.byPriority.filter(
+    g => g.chronicUnderestimation
+  )) {
+    warnings.push(
+      `⚠️ ${group.label} priority tasks take ${ratio(group.ratio)} their estimate (${group.taskCount} tasks)`
+    );
+  }
+  return warnings;
+}
+
 /**
  * Analyze productivity patterns with personalized insights
  */
@@ -1102,6 +1270,16 @@ const analyzeProductivityTool = tool(
         ];
       }
 
+      const estimationStart = new Date(endDate);
+      estimationStart.setDate(
+        estimationStart.getDate() - ESTIMATION_HISTORY_DAYS
+      );
+      const estimation = await taskRepo.getEstimationReport(
+        estimationStart,
+        endDate
+      );
+      recommendations.push(...describeUnderestimation(estimation));
+
       const analysis = {
         timeframe,
         focusArea: focusArea || 'general',
@@ -1114,6 +1292,13 @@ const analyzeProductivityTool = tool(
           totalSessions: sessions.length,
           averageSessionMinutes,
         },
+        estimation: {
+          days: ESTIMATION_HISTORY_DAYS,
+          tasks: estimation.overall.taskCount,
+          actualToEstimateRatio:
+            Math.round(estimation.overall.ratio * 100) / 100,
+          chronicUnderestimation: estimation.overall.chronicUnderestimation,
+        },
       };
 
       const response = {
@@ -1139,7 +1324,7 @@ const analyzeProductivityTool = tool(
   {
     name: 'analyze_productivity',
     description:
-      'Analyze your productivity patterns and get personalized insights and recommendations based on your actual task and time tracking data.',
+      'Analyze your productivity patterns and get personalized insights and recommendations based on your actual task and time tracking data, including warnings where tasks are chronically underestimated.',
     schema: z.object({
       timeframe: z
         .enum(['day', 'week', 'month'])
@@ -2124,6 +2309,8 @@ export function getKiraPilotTools() {
     startTimerTool,
     stopTimerTool,
     getTimeDataTool,
+    getWorkdayContextTool,
+    generateDailyPlanTool,
     analyzeProductivityTool,
     createPeriodicTaskTool,
     getPeriodicTasksTool,
@@ -1,12 +1,15 @@
 // Database service for KiraPilot using SeaORM backend via Tauri commands
 import { invoke } from '@tauri-apps/api/core';
 import { TranslationKey } from '../../i18n';
+import { IntegrityReportRecord, RestoreRehearsalReport } from '../../types';
 import { getDatabaseErrorMessage } from './utils';
 
 // Re-export database utilities
 export {
   setDatabaseTranslationFunction,
   getDatabaseErrorMessage,
+  DATABASE_BUSY_ERROR,
+  isDatabaseBusyError,
 } from './utils';
 export type { DatabaseTranslationFunction } from './utils';
 
@@ -61,6 +64,75 @@ export async function checkDatabaseHealth(): Promise<{
   }
 }
 
+/**
+ * Get the reports of the weekly integrity checks, newest first
+ */
+export async function getIntegrityHistory(
+  limit?: number
+): Promise<IntegrityReportRecord[]> {
+  try {
+    const reports = await invoke<Record<string, unknown>[]>(
+      'get_integrity_history',
+      { limit }
+    );
+    return reports.map(report => ({
+      id: report.id as string,
+      isHealthy: report.is_healthy as boolean,
+      hasDefaultTaskList: report.has_default_task_list as boolean,
+      defaultTaskListId:
+        (report.default_task_list_id as string | null) ?? undefined,
+      orphanedTasksCount: report.orphaned_tasks_count as number,
+      totalTasks: report.total_tasks as number,
+      totalTaskLists: report.total_task_lists as number,
+      notified: report.notified as boolean,
+      checkedAt: new Date(report.checked_at as string),
+    }));
+  } catch (error) {
+    throw new Error(`Failed to get integrity history: ${error}`);
+  }
+}
+
+/**
+ * Restore a backup into a scratch database and check the result, without
+ * touching live data
+ */
+export async function rehearseBackupRestore(
+  filePath: string
+): Promise<RestoreRehearsalReport> {
+  try {
+    const report = await invoke<Record<string, unknown>>(
+      'rehearse_backup_restore',
+      { filePath }
+    );
+    const metadata = report.metadata as Record<string, unknown>;
+    const integrity = report.integrity as Record<string, unknown> | null;
+    return {
+      restorable: report.restorable as boolean,
+      createdAt: new Date(metadata.created_at as string),
+      version: metadata.version as string,
+      tables: (report.tables as Record<string, unknown>[]).map(table => ({
+        table: table.table as string,
+        expected: table.expected as number,
+        restored: table.restored as number,
+      })),
+      integrity: integrity
+        ? {
+            isHealthy: integrity.is_healthy as boolean,
+            orphanedTasksCount: integrity.orphaned_tasks_count as number,
+            totalTasks: integrity.total_tasks as number,
+            totalTaskLists: integrity.total_task_lists as number,
+          }
+        : undefined,
+      databaseIssues: report.database_issues as string[],
+      missingAttachmentFiles: report.missing_attachment_files as string[],
+      errors: report.errors as string[],
+      warnings: report.warnings as string[],
+    };
+  } catch (error) {
+    throw new Error(`Failed to rehearse backup restore: ${error}`);
+  }
+}
+
 // Logging services - export LogStorageService first to avoid circular dependency
 export { LogStorageService } from './repositories/LogStorageService';
 
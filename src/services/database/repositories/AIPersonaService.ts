@@ -0,0 +1,121 @@
+// AI persona service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  AIPersona,
+  CreateAIPersonaRequest,
+  PersonaTone,
+  UpdateAIPersonaRequest,
+} from '../../../types';
+
+export class AIPersonaService {
+  async create(request: CreateAIPersonaRequest): Promise<AIPersona> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'create_ai_persona',
+        {
+          request: {
+            name: request.name,
+            system_prompt: request.systemPrompt,
+            tone: request.tone,
+            allowed_tools: request.allowedTools,
+            default_model: request.defaultModel,
+          },
+        }
+      );
+      return this.transformPersonaFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to create persona: ${error}`);
+    }
+  }
+
+  async findAll(): Promise<AIPersona[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>('get_ai_personas');
+      return result.map(persona => this.transformPersonaFromBackend(persona));
+    } catch (error) {
+      throw new Error(`Failed to get personas: ${error}`);
+    }
+  }
+
+  async findById(id: string): Promise<AIPersona | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_ai_persona',
+        { id }
+      );
+      return result ? this.transformPersonaFromBackend(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to get persona: ${error}`);
+    }
+  }
+
+  /**
+   * The persona a thread answers as, or null for plain Kira
+   */
+  async findForThread(threadId: string): Promise<AIPersona | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_thread_persona',
+        { threadId }
+      );
+      return result ? this.transformPersonaFromBackend(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to get thread persona: ${error}`);
+    }
+  }
+
+  async update(
+    id: string,
+    request: UpdateAIPersonaRequest
+  ): Promise<AIPersona> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'update_ai_persona',
+        {
+          id,
+          request: {
+            name: request.name,
+            system_prompt: request.systemPrompt,
+            tone: request.tone,
+            allowed_tools: request.allowedTools,
+            allow_all_tools: request.allowAllTools ?? false,
+            default_model: request.defaultModel,
+          },
+        }
+      );
+      return this.transformPersonaFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to update persona: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a persona; threads using it go back to plain Kira
+   */
+  async delete(id: string): Promise<void> {
+    try {
+      await invoke<string>('delete_ai_persona', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete persona: ${error}`);
+    }
+  }
+
+  private transformPersonaFromBackend(
+    backendPersona: Record<string, unknown>
+  ): AIPersona {
+    const allowedTools = backendPersona.allowed_tools as string | null;
+    return {
+      id: backendPersona.id as string,
+      name: backendPersona.name as string,
+      systemPrompt: backendPersona.system_prompt as string,
+      tone: backendPersona.tone as PersonaTone,
+      allowedTools: allowedTools
+        ? (JSON.parse(allowedTools) as string[])
+        : undefined,
+      defaultModel:
+        (backendPersona.default_model as string | null) ?? undefined,
+      createdAt: new Date(backendPersona.created_at as string),
+      updatedAt: new Date(backendPersona.updated_at as string),
+    };
+  }
+}
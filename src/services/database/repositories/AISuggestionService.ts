@@ -0,0 +1,134 @@
+// AI suggestion service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  AIAction,
+  AISuggestion,
+  Priority,
+  SaveAISuggestionRequest,
+} from '../../../types';
+
+export class AISuggestionService {
+  /**
+   * Store suggestions made by the assistant or the analytics engine; all are
+   * saved or none
+   */
+  async save(suggestions: SaveAISuggestionRequest[]): Promise<AISuggestion[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'save_ai_suggestions',
+        {
+          suggestions: suggestions.map(suggestion => ({
+            suggestion_type: suggestion.type,
+            title: suggestion.title,
+            description: suggestion.description,
+            // The backend keeps confidence from 0 to 1
+            confidence: suggestion.confidence / 100,
+            actionable: suggestion.actionable,
+            priority: suggestion.priority,
+            estimated_impact: suggestion.estimatedImpact,
+            reasoning: suggestion.reasoning,
+            actions: suggestion.actions,
+            expires_at: suggestion.expiresAt?.toISOString(),
+            interaction_log_id: suggestion.interactionLogId,
+          })),
+        }
+      );
+      return result.map(item => this.transformSuggestionFromBackend(item));
+    } catch (error) {
+      throw new Error(`Failed to save suggestions: ${error}`);
+    }
+  }
+
+  /**
+   * Get the suggestions not yet accepted, dismissed or expired, most
+   * important first
+   */
+  async getActive(type?: AISuggestion['type']): Promise<AISuggestion[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_active_ai_suggestions',
+        { suggestionType: type }
+      );
+      return result.map(item => this.transformSuggestionFromBackend(item));
+    } catch (error) {
+      throw new Error(`Failed to get active suggestions: ${error}`);
+    }
+  }
+
+  /**
+   * Get the suggestions made in an AI interaction log
+   */
+  async getForInteraction(interactionLogId: string): Promise<AISuggestion[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_interaction_suggestions',
+        { interactionLogId }
+      );
+      return result.map(item => this.transformSuggestionFromBackend(item));
+    } catch (error) {
+      throw new Error(`Failed to get interaction suggestions: ${error}`);
+    }
+  }
+
+  async accept(id: string): Promise<AISuggestion> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'accept_ai_suggestion',
+        { id }
+      );
+      return this.transformSuggestionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to accept suggestion: ${error}`);
+    }
+  }
+
+  async dismiss(id: string): Promise<AISuggestion> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'dismiss_ai_suggestion',
+        { id }
+      );
+      return this.transformSuggestionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to dismiss suggestion: ${error}`);
+    }
+  }
+
+  /**
+   * Delete unanswered suggestions past their expiry, returning how many
+   * there were
+   */
+  async expireStale(): Promise<number> {
+    try {
+      return await invoke<number>('expire_ai_suggestions');
+    } catch (error) {
+      throw new Error(`Failed to expire suggestions: ${error}`);
+    }
+  }
+
+  private transformSuggestionFromBackend(
+    backendSuggestion: Record<string, unknown>
+  ): AISuggestion {
+    const actions = backendSuggestion.actions as string | null;
+    const optionalDate = (value: unknown) =>
+      value ? new Date(value as string) : undefined;
+    return {
+      id: backendSuggestion.id as string,
+      type: backendSuggestion.suggestion_type as AISuggestion['type'],
+      title: backendSuggestion.title as string,
+      description: backendSuggestion.description as string,
+      confidence: (backendSuggestion.confidence as number) * 100,
+      actionable: backendSuggestion.actionable as boolean,
+      priority: backendSuggestion.priority as Priority,
+      estimatedImpact: backendSuggestion.estimated_impact as number,
+      reasoning: (backendSuggestion.reasoning as string | null) ?? '',
+      actions: actions ? (JSON.parse(actions) as AIAction[]) : undefined,
+      createdAt: new Date(backendSuggestion.created_at as string),
+      dismissedAt: optionalDate(backendSuggestion.dismissed_at),
+      appliedAt: optionalDate(backendSuggestion.applied_at),
+      expiresAt: optionalDate(backendSuggestion.expires_at),
+      interactionLogId:
+        (backendSuggestion.interaction_log_id as string | null) ?? undefined,
+    };
+  }
+}
@@ -0,0 +1,68 @@
+// API token service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import { ApiToken, ApiTokenScope, IssuedApiToken } from '../../../types';
+
+export class ApiTokenService {
+  /**
+   * Issue a new token for an integration; the secret is only returned here
+   */
+  async create(
+    name: string,
+    scopes: ApiTokenScope[]
+  ): Promise<IssuedApiToken> {
+    try {
+      const result = await invoke<{
+        token: Record<string, unknown>;
+        secret: string;
+      }>('create_api_token', { request: { name, scopes } });
+      return {
+        token: this.transformTokenFromBackend(result.token),
+        secret: result.secret,
+      };
+    } catch (error) {
+      throw new Error(`Failed to create API token: ${error}`);
+    }
+  }
+
+  /**
+   * Find all tokens, including revoked ones
+   */
+  async findAll(): Promise<ApiToken[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>('get_api_tokens');
+      return result.map(token => this.transformTokenFromBackend(token));
+    } catch (error) {
+      throw new Error(`Failed to get API tokens: ${error}`);
+    }
+  }
+
+  /**
+   * Revoke a token so integrations using it are rejected
+   */
+  async revoke(id: string): Promise<ApiToken> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'revoke_api_token',
+        { id }
+      );
+      return this.transformTokenFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to revoke API token: ${error}`);
+    }
+  }
+
+  private transformTokenFromBackend(token: Record<string, unknown>): ApiToken {
+    return {
+      id: token.id as string,
+      name: token.name as string,
+      scopes: JSON.parse((token.scopes as string) || '[]'),
+      lastUsedAt: token.last_used_at
+        ? new Date(token.last_used_at as string)
+        : undefined,
+      revokedAt: token.revoked_at
+        ? new Date(token.revoked_at as string)
+        : undefined,
+      createdAt: new Date(token.created_at as string),
+    };
+  }
+}
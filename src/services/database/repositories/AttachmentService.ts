@@ -0,0 +1,104 @@
+// Task attachment service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import { Attachment, AttachmentCleanup } from '../../../types';
+
+export class AttachmentService {
+  /**
+   * Copy a file into the app's attachments directory and attach it to a task
+   */
+  async add(taskId: string, filePath: string): Promise<Attachment> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'add_task_attachment',
+        { taskId, filePath }
+      );
+      return this.transformAttachmentFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to add attachment: ${error}`);
+    }
+  }
+
+  /**
+   * Get the attachments of a task, oldest first
+   */
+  async getTaskAttachments(taskId: string): Promise<Attachment[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'list_task_attachments',
+        { taskId }
+      );
+      return result.map(attachment =>
+        this.transformAttachmentFromBackend(attachment)
+      );
+    } catch (error) {
+      throw new Error(`Failed to list attachments: ${error}`);
+    }
+  }
+
+  /**
+   * Remove an attachment and its stored copy
+   */
+  async remove(id: string): Promise<void> {
+    try {
+      await invoke('remove_task_attachment', { id });
+    } catch (error) {
+      throw new Error(`Failed to remove attachment: ${error}`);
+    }
+  }
+
+  /**
+   * List what cleanUp would remove, for the user to confirm
+   */
+  async previewCleanup(): Promise<AttachmentCleanup> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'preview_attachment_cleanup'
+      );
+      return this.transformCleanupFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to preview attachment cleanup: ${error}`);
+    }
+  }
+
+  /**
+   * Remove attachments of archived tasks and untracked stored files
+   */
+  async cleanUp(): Promise<AttachmentCleanup> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'clean_up_attachments'
+      );
+      return this.transformCleanupFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to clean up attachments: ${error}`);
+    }
+  }
+
+  private transformCleanupFromBackend(
+    cleanup: Record<string, unknown>
+  ): AttachmentCleanup {
+    const archived = cleanup.archived_attachments as Record<string, unknown>[];
+    return {
+      archivedAttachments: archived.map(attachment =>
+        this.transformAttachmentFromBackend(attachment)
+      ),
+      orphanedFiles: cleanup.orphaned_files as string[],
+      reclaimedBytes: cleanup.reclaimed_bytes as number,
+    };
+  }
+
+  private transformAttachmentFromBackend(
+    attachment: Record<string, unknown>
+  ): Attachment {
+    return {
+      id: attachment.id as string,
+      taskId: attachment.task_id as string,
+      fileName: attachment.file_name as string,
+      mimeType: (attachment.mime_type as string | null) ?? undefined,
+      size: attachment.size as number,
+      checksum: attachment.checksum as string,
+      path: attachment.path as string | undefined,
+      createdAt: new Date(attachment.created_at as string),
+    };
+  }
+}
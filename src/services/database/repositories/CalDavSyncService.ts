@@ -0,0 +1,72 @@
+// CalDAV sync service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import { CalDavConfig, SyncReport, SyncStatus } from '../../../types';
+
+export class CalDavSyncService {
+  /**
+   * Pull changes from the calendar, then push scheduled tasks as to-dos
+   */
+  async sync(config: CalDavConfig): Promise<SyncReport> {
+    try {
+      const result = await invoke<Record<string, unknown>>('sync_caldav', {
+        config: {
+          calendar_url: config.calendarUrl,
+          username: config.username,
+          password: config.password,
+          conflict_resolution: config.conflictResolution ?? 'newest_wins',
+          task_list_id: config.taskListId,
+        },
+      });
+      const conflicts = result.conflicts as Record<string, unknown>[];
+      return {
+        calendarUrl: result.calendar_url as string,
+        fullSync: result.full_sync as boolean,
+        pushed: result.pushed as number,
+        pulled: result.pulled as number,
+        created: result.created as number,
+        deletedRemotely: result.deleted_remotely as number,
+        archivedLocally: result.archived_locally as number,
+        conflicts: conflicts.map(conflict => ({
+          taskId: conflict.task_id as string,
+          title: conflict.title as string,
+          kept: conflict.kept as 'local' | 'remote',
+        })),
+        errors: result.errors as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to sync with CalDAV: ${error}`);
+    }
+  }
+
+  /**
+   * When the calendar was last synced and how many tasks it holds
+   */
+  async getStatus(calendarUrl: string): Promise<SyncStatus> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'get_caldav_sync_status',
+        { calendarUrl }
+      );
+      return {
+        calendarUrl: result.calendar_url as string,
+        lastSyncedAt: result.last_synced_at
+          ? new Date(result.last_synced_at as string)
+          : undefined,
+        itemCount: result.item_count as number,
+      };
+    } catch (error) {
+      throw new Error(`Failed to get CalDAV sync status: ${error}`);
+    }
+  }
+
+  /**
+   * Forget the sync state so the next sync compares every item again
+   */
+  async reset(calendarUrl: string): Promise<number> {
+    try {
+      return await invoke<number>('reset_caldav_sync', { calendarUrl });
+    } catch (error) {
+      throw new Error(`Failed to reset CalDAV sync: ${error}`);
+    }
+  }
+}
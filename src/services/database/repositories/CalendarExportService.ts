@@ -0,0 +1,36 @@
+// Calendar export service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import { CalendarExportRange, CalendarExportSummary } from '../../../types';
+
+export class CalendarExportService {
+  /**
+   * Write scheduled tasks and due dates in the range to an iCalendar (.ics)
+   * file, with active periodic templates as recurring events
+   */
+  async exportCalendar(
+    filePath: string,
+    range: CalendarExportRange
+  ): Promise<CalendarExportSummary> {
+    try {
+      const result = await invoke<Record<string, unknown>>('export_calendar', {
+        filePath,
+        range: {
+          start: range.start.toISOString(),
+          end: range.end.toISOString(),
+          include_completed: range.includeCompleted ?? false,
+          include_periodic: range.includePeriodic ?? true,
+        },
+      });
+      return {
+        filePath: result.file_path as string,
+        scheduledCount: result.scheduled_count as number,
+        dueCount: result.due_count as number,
+        recurringCount: result.recurring_count as number,
+        skippedTemplates:
+          result.skipped_templates as CalendarExportSummary['skippedTemplates'],
+      };
+    } catch (error) {
+      throw new Error(`Failed to export calendar: ${error}`);
+    }
+  }
+}
@@ -0,0 +1,102 @@
+// Task checklist service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import { ChecklistItem } from '../../../types';
+
+export class ChecklistService {
+  /**
+   * Add an item to the end of a task's checklist
+   */
+  async create(taskId: string, title: string): Promise<ChecklistItem> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'create_checklist_item',
+        { request: { task_id: taskId, title } }
+      );
+      return this.transformItemFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to create checklist item: ${error}`);
+    }
+  }
+
+  /**
+   * Get the checklist of a task, in order
+   */
+  async getTaskChecklist(taskId: string): Promise<ChecklistItem[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_checklist_items',
+        { taskId }
+      );
+      return result.map(item => this.transformItemFromBackend(item));
+    } catch (error) {
+      throw new Error(`Failed to get checklist items: ${error}`);
+    }
+  }
+
+  /**
+   * Rename a checklist item or check it off
+   */
+  async update(
+    id: string,
+    updates: { title?: string; completed?: boolean }
+  ): Promise<ChecklistItem> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'update_checklist_item',
+        {
+          id,
+          request: { title: updates.title, completed: updates.completed },
+        }
+      );
+      return this.transformItemFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to update checklist item: ${error}`);
+    }
+  }
+
+  /**
+   * Move an item between two neighbours; omit one to move it to either end
+   */
+  async reorder(
+    id: string,
+    beforeId?: string,
+    afterId?: string
+  ): Promise<ChecklistItem> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'reorder_checklist_item',
+        { id, beforeId, afterId }
+      );
+      return this.transformItemFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to reorder checklist item: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a checklist item
+   */
+  async delete(id: string): Promise<void> {
+    try {
+      await invoke('delete_checklist_item', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete checklist item: ${error}`);
+    }
+  }
+
+  private transformItemFromBackend(
+    item: Record<string, unknown>
+  ): ChecklistItem {
+    return {
+      id: item.id as string,
+      taskId: item.task_id as string,
+      title: item.title as string,
+      orderNum: item.order_num as number,
+      completedAt: item.completed_at
+        ? new Date(item.completed_at as string)
+        : undefined,
+      createdAt: new Date(item.created_at as string),
+      updatedAt: new Date(item.updated_at as string),
+    };
+  }
+}
@@ -0,0 +1,41 @@
+// Conversation export service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  ConversationExportRequest,
+  ConversationExportSummary,
+} from '../../../types';
+
+export class ConversationExportService {
+  /**
+   * Write selected threads and AI interactions to a JSONL file in chat
+   * format, redacting personal data and credentials unless turned off
+   */
+  async exportDataset(
+    request: ConversationExportRequest,
+    filePath: string
+  ): Promise<ConversationExportSummary> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'export_conversation_dataset',
+        {
+          request: {
+            thread_ids: request.threadIds ?? [],
+            interaction_ids: request.interactionIds ?? [],
+            system_prompt: request.systemPrompt,
+            redact: request.redact ?? true,
+          },
+          filePath,
+        }
+      );
+      return {
+        filePath: result.file_path as string,
+        exampleCount: result.example_count as number,
+        messageCount: result.message_count as number,
+        redactionCount: result.redaction_count as number,
+        skipped: result.skipped as ConversationExportSummary['skipped'],
+      };
+    } catch (error) {
+      throw new Error(`Failed to export conversations: ${error}`);
+    }
+  }
+}
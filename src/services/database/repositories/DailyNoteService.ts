@@ -0,0 +1,89 @@
+// Daily note service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import { DailyNote, DailyNoteDay } from '../../../types';
+import { TaskService } from './TaskService';
+import { TimeTrackingService } from './TimeTrackingService';
+
+export class DailyNoteService {
+  private taskService = new TaskService();
+  private timeTrackingService = new TimeTrackingService();
+
+  /**
+   * Get the note of a day (YYYY-MM-DD) with the tasks completed and
+   * sessions tracked that day
+   */
+  async getDay(date: string): Promise<DailyNoteDay> {
+    try {
+      const result = await invoke<Record<string, unknown>>('get_daily_note', {
+        date,
+      });
+      const note = result.note as Record<string, unknown> | null;
+      const tasks = result.completed_tasks as Record<string, unknown>[];
+      const sessions = result.sessions as Record<string, unknown>[];
+      return {
+        date: result.date as string,
+        note: note ? this.transformNoteFromBackend(note) : undefined,
+        completedTasks: tasks.map(task =>
+          this.taskService.transformTaskFromBackend(task)
+        ),
+        sessions: sessions.map(session =>
+          this.timeTrackingService.transformSessionFromBackend(session)
+        ),
+        trackedSeconds: result.tracked_seconds as number,
+      };
+    } catch (error) {
+      throw new Error(`Failed to get daily note: ${error}`);
+    }
+  }
+
+  /**
+   * Get the notes written between two days (inclusive), newest first
+   */
+  async getNotes(startDate: string, endDate: string): Promise<DailyNote[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_daily_notes',
+        { startDate, endDate }
+      );
+      return result.map(note => this.transformNoteFromBackend(note));
+    } catch (error) {
+      throw new Error(`Failed to get daily notes: ${error}`);
+    }
+  }
+
+  /**
+   * Create or replace the note of a day
+   */
+  async save(date: string, content: string): Promise<DailyNote> {
+    try {
+      const result = await invoke<Record<string, unknown>>('save_daily_note', {
+        date,
+        content,
+      });
+      return this.transformNoteFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to save daily note: ${error}`);
+    }
+  }
+
+  /**
+   * Delete the note of a day
+   */
+  async delete(date: string): Promise<void> {
+    try {
+      await invoke('delete_daily_note', { date });
+    } catch (error) {
+      throw new Error(`Failed to delete daily note: ${error}`);
+    }
+  }
+
+  private transformNoteFromBackend(note: Record<string, unknown>): DailyNote {
+    return {
+      id: note.id as string,
+      date: note.date as string,
+      content: note.content as string,
+      createdAt: new Date(note.created_at as string),
+      updatedAt: new Date(note.updated_at as string),
+    };
+  }
+}
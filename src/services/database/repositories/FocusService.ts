@@ -1,58 +1,105 @@
 // Focus session service that interfaces with Tauri commands (SeaORM backend)
-import { FocusSession, FocusConfig } from '../../../types';
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import {
+  FocusSession,
+  FocusConfig,
+  FocusSessionTemplate,
+  CreateFocusTemplateRequest,
+  DistractionLevel,
+  UpdateFocusTemplateRequest,
+  PomodoroConfig,
+  PomodoroState,
+  FocusStatistics,
+  FocusBreak,
+} from '../../../types';
 
 export class FocusService {
   /**
-   * Start a new focus session
+   * Start a new focus session; fails while another one runs
    */
-  async startSession(_config: FocusConfig): Promise<FocusSession> {
+  async startSession(config: FocusConfig): Promise<FocusSession> {
     try {
-      // For now, create a basic focus session since backend might not have full focus support yet
-      // This would need to be implemented in the Rust backend
-      throw new Error('Focus sessions not yet implemented in SeaORM backend');
+      const result = await invoke<Record<string, unknown>>(
+        'start_focus_session',
+        {
+          request: {
+            task_id: config.taskId,
+            planned_duration: config.duration,
+            distraction_level: config.distractionLevel,
+            background_audio: config.backgroundAudio
+              ? JSON.stringify(config.backgroundAudio)
+              : null,
+            notes: null,
+          },
+        }
+      );
+      return this.transformSessionFromBackend(result);
     } catch (error) {
       throw new Error(`Failed to start focus session: ${error}`);
     }
   }
 
   /**
-   * Complete focus session
+   * Complete focus session with its focus score (0-100); without an actual
+   * duration the minutes since it started are recorded
    */
   async completeSession(
-    _sessionId: string,
-    _notes?: string
+    sessionId: string,
+    focusScore: number,
+    notes?: string,
+    actualDuration?: number
   ): Promise<FocusSession> {
     try {
-      throw new Error('Focus sessions not yet implemented in SeaORM backend');
+      const result = await invoke<Record<string, unknown>>(
+        'complete_focus_session',
+        { id: sessionId, actualDuration, focusScore, notes }
+      );
+      return this.transformSessionFromBackend(result);
     } catch (error) {
       throw new Error(`Failed to complete focus session: ${error}`);
     }
   }
 
   /**
-   * Add distraction to session
+   * Count a distraction on a running session
    */
-  async addDistraction(
-    _sessionId: string,
-    _reason?: string
-  ): Promise<FocusSession> {
+  async addDistraction(sessionId: string): Promise<FocusSession> {
     try {
-      throw new Error('Focus sessions not yet implemented in SeaORM backend');
+      const result = await invoke<Record<string, unknown>>(
+        'record_focus_distraction',
+        { id: sessionId }
+      );
+      return this.transformSessionFromBackend(result);
     } catch (error) {
       throw new Error(`Failed to add distraction: ${error}`);
     }
   }
 
   /**
-   * Add planned break to session
+   * Add planned break to session, ending now
    */
   async addPlannedBreak(
-    _sessionId: string,
-    _duration: number,
-    _reason?: string
+    sessionId: string,
+    duration: number,
+    reason?: string
   ): Promise<FocusSession> {
     try {
-      throw new Error('Focus sessions not yet implemented in SeaORM backend');
+      const endTime = new Date();
+      const startTime = new Date(endTime.getTime() - duration * 60000);
+      const result = await invoke<Record<string, unknown>>(
+        'add_focus_break',
+        {
+          id: sessionId,
+          focusBreak: {
+            start_time: startTime.toISOString(),
+            end_time: endTime.toISOString(),
+            break_type: 'short',
+            reason: reason ?? null,
+          },
+        }
+      );
+      return this.transformSessionFromBackend(result);
     } catch (error) {
       throw new Error(`Failed to add planned break: ${error}`);
     }
@@ -77,8 +124,10 @@ export class FocusService {
    */
   async getActiveSession(): Promise<FocusSession | null> {
     try {
-      // Return null for now since focus sessions aren't implemented in backend
-      return null;
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_active_focus_session'
+      );
+      return result ? this.transformSessionFromBackend(result) : null;
     } catch (error) {
       throw new Error(`Failed to get active focus session: ${error}`);
     }
@@ -87,9 +136,13 @@ export class FocusService {
   /**
    * Find session by ID
    */
-  async findById(_id: string): Promise<FocusSession | null> {
+  async findById(id: string): Promise<FocusSession | null> {
     try {
-      throw new Error('Focus sessions not yet implemented in SeaORM backend');
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_focus_session',
+        { id }
+      );
+      return result ? this.transformSessionFromBackend(result) : null;
     } catch (error) {
       throw new Error(`Failed to find focus session: ${error}`);
     }
@@ -98,10 +151,13 @@ export class FocusService {
   /**
    * Get sessions by task
    */
-  async getByTask(_taskId: string): Promise<FocusSession[]> {
+  async getByTask(taskId: string): Promise<FocusSession[]> {
     try {
-      // Return empty array for now
-      return [];
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_task_focus_sessions',
+        { taskId }
+      );
+      return result.map(session => this.transformSessionFromBackend(session));
     } catch (error) {
       throw new Error(`Failed to get focus sessions by task: ${error}`);
     }
@@ -111,12 +167,18 @@ export class FocusService {
    * Get sessions by date range
    */
   async getByDateRange(
-    _startDate: Date,
-    _endDate: Date
+    startDate: Date,
+    endDate: Date
   ): Promise<FocusSession[]> {
     try {
-      // Return empty array for now
-      return [];
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_focus_sessions_between',
+        {
+          startDate: startDate.toISOString(),
+          endDate: endDate.toISOString(),
+        }
+      );
+      return result.map(session => this.transformSessionFromBackend(session));
     } catch (error) {
       throw new Error(`Failed to get focus sessions by date range: ${error}`);
     }
@@ -135,32 +197,33 @@ export class FocusService {
   }
 
   /**
-   * Get focus statistics
+   * Get focus statistics for sessions started in a period
    */
   async getStatistics(
-    _startDate?: Date,
-    _endDate?: Date
-  ): Promise<{
-    totalSessions: number;
-    completedSessions: number;
-    totalFocusTime: number;
-    averageFocusScore: number;
-    averageDistractions: number;
-    bestFocusStreak: number;
-    mostProductiveTime: string;
-    distractionTypes: Record<string, number>;
-  }> {
+    startDate: Date,
+    endDate: Date
+  ): Promise<FocusStatistics> {
     try {
-      // Return default stats for now
+      const stats = await invoke<Record<string, unknown>>('get_focus_stats', {
+        startDate: startDate.toISOString(),
+        endDate: endDate.toISOString(),
+      });
+      const days = stats.sessions_by_day as Record<string, unknown>[];
       return {
-        totalSessions: 0,
-        completedSessions: 0,
-        totalFocusTime: 0,
-        averageFocusScore: 0,
-        averageDistractions: 0,
-        bestFocusStreak: 0,
-        mostProductiveTime: '09:00',
-        distractionTypes: {},
+        totalSessions: stats.total_sessions as number,
+        totalPlannedMinutes: stats.total_planned_minutes as number,
+        totalActualMinutes: stats.total_actual_minutes as number,
+        averageFocusScore: stats.average_focus_score as number,
+        completionRate: stats.completion_rate as number,
+        mostUsedDistractionLevel:
+          stats.most_productive_distraction_level as string,
+        sessionsByDay: days.map(day => ({
+          date: day.date as string,
+          sessionCount: day.session_count as number,
+          totalPlannedMinutes: day.total_planned_minutes as number,
+          totalActualMinutes: day.total_actual_minutes as number,
+          averageFocusScore: day.average_focus_score as number,
+        })),
       };
     } catch (error) {
       throw new Error(`Failed to get focus statistics: ${error}`);
@@ -170,9 +233,9 @@ export class FocusService {
   /**
    * Delete session
    */
-  async delete(_id: string): Promise<void> {
+  async delete(id: string): Promise<void> {
     try {
-      throw new Error('Focus sessions not yet implemented in SeaORM backend');
+      await invoke('delete_focus_session', { id });
     } catch (error) {
       throw new Error(`Failed to delete focus session: ${error}`);
     }
@@ -222,4 +285,273 @@ export class FocusService {
       throw new Error(`Failed to get focus summary: ${error}`);
     }
   }
+
+  /**
+   * Get all focus templates, built-in presets first
+   */
+  async getTemplates(): Promise<FocusSessionTemplate[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_focus_templates'
+      );
+      return result.map(template =>
+        this.transformTemplateFromBackend(template)
+      );
+    } catch (error) {
+      throw new Error(`Failed to get focus templates: ${error}`);
+    }
+  }
+
+  /**
+   * Create a custom focus template
+   */
+  async createTemplate(
+    request: CreateFocusTemplateRequest
+  ): Promise<FocusSessionTemplate> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'create_focus_template',
+        {
+          request: {
+            name: request.name,
+            focus_duration: request.focusDuration,
+            short_break_duration: request.shortBreakDuration,
+            long_break_duration: request.longBreakDuration,
+            sessions_before_long_break: request.sessionsBeforeLongBreak,
+            distraction_level: request.distractionLevel,
+            background_audio: request.backgroundAudio
+              ? JSON.stringify(request.backgroundAudio)
+              : null,
+          },
+        }
+      );
+      return this.transformTemplateFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to create focus template: ${error}`);
+    }
+  }
+
+  /**
+   * Update a focus template; built-in presets keep their name
+   */
+  async updateTemplate(
+    id: string,
+    request: UpdateFocusTemplateRequest
+  ): Promise<FocusSessionTemplate> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'update_focus_template',
+        {
+          id,
+          request: {
+            name: request.name,
+            focus_duration: request.focusDuration,
+            short_break_duration: request.shortBreakDuration,
+            long_break_duration: request.longBreakDuration,
+            sessions_before_long_break: request.sessionsBeforeLongBreak,
+            distraction_level: request.distractionLevel,
+            background_audio: request.backgroundAudio
+              ? JSON.stringify(request.backgroundAudio)
+              : undefined,
+          },
+        }
+      );
+      return this.transformTemplateFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to update focus template: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a custom focus template
+   */
+  async deleteTemplate(id: string): Promise<void> {
+    try {
+      await invoke('delete_focus_template', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete focus template: ${error}`);
+    }
+  }
+
+  /**
+   * Start a focus session on a task with the settings of a template
+   */
+  async startFromTemplate(
+    templateId: string,
+    taskId: string
+  ): Promise<Record<string, unknown>> {
+    try {
+      return await invoke<Record<string, unknown>>(
+        'start_focus_from_template',
+        { id: templateId, taskId }
+      );
+    } catch (error) {
+      throw new Error(`Failed to start focus session from template: ${error}`);
+    }
+  }
+
+  /**
+   * Start a Pomodoro cycle on a task; the backend runs the work and break
+   * phases and records them as focus sessions
+   */
+  async startPomodoro(
+    taskId: string,
+    config?: PomodoroConfig
+  ): Promise<PomodoroState> {
+    try {
+      const result = await invoke<Record<string, unknown>>('start_pomodoro', {
+        taskId,
+        config: config ? this.transformPomodoroConfigToBackend(config) : null,
+      });
+      return this.transformPomodoroStateFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to start Pomodoro: ${error}`);
+    }
+  }
+
+  /**
+   * End the current break early and start the next work phase
+   */
+  async skipBreak(): Promise<PomodoroState> {
+    try {
+      const result = await invoke<Record<string, unknown>>('skip_break');
+      return this.transformPomodoroStateFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to skip break: ${error}`);
+    }
+  }
+
+  /**
+   * Stop the running Pomodoro, returning where it was
+   */
+  async stopPomodoro(): Promise<PomodoroState | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'stop_pomodoro'
+      );
+      return result ? this.transformPomodoroStateFromBackend(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to stop Pomodoro: ${error}`);
+    }
+  }
+
+  /**
+   * Get the running Pomodoro, if any
+   */
+  async getPomodoroState(): Promise<PomodoroState | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_pomodoro_state'
+      );
+      return result ? this.transformPomodoroStateFromBackend(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to get Pomodoro state: ${error}`);
+    }
+  }
+
+  /**
+   * Listen for Pomodoro phases ending on their own
+   */
+  onPomodoroChanged(
+    callback: (state: PomodoroState) => void
+  ): Promise<UnlistenFn> {
+    return listen<Record<string, unknown>>('pomodoro-changed', event =>
+      callback(this.transformPomodoroStateFromBackend(event.payload))
+    );
+  }
+
+  private transformPomodoroConfigToBackend(
+    config: PomodoroConfig
+  ): Record<string, unknown> {
+    return {
+      focus_duration: config.focusDuration,
+      short_break_duration: config.shortBreakDuration,
+      long_break_duration: config.longBreakDuration,
+      sessions_before_long_break: config.sessionsBeforeLongBreak,
+      distraction_level: config.distractionLevel,
+    };
+  }
+
+  private transformPomodoroStateFromBackend(
+    state: Record<string, unknown>
+  ): PomodoroState {
+    const config = state.config as Record<string, unknown>;
+    return {
+      taskId: state.task_id as string,
+      phase: state.phase as PomodoroState['phase'],
+      completedWorkSessions: state.completed_work_sessions as number,
+      focusSessionId: state.focus_session_id as string,
+      phaseStartedAt: new Date(state.phase_started_at as string),
+      phaseEndsAt: new Date(state.phase_ends_at as string),
+      config: {
+        focusDuration: config.focus_duration as number,
+        shortBreakDuration: config.short_break_duration as number,
+        longBreakDuration: config.long_break_duration as number,
+        sessionsBeforeLongBreak: config.sessions_before_long_break as number,
+        distractionLevel: config.distraction_level as DistractionLevel,
+      },
+    };
+  }
+
+  private transformSessionFromBackend(
+    session: Record<string, unknown>
+  ): FocusSession {
+    const breaks: Record<string, unknown>[] = session.breaks
+      ? JSON.parse(session.breaks as string)
+      : [];
+    return {
+      id: session.id as string,
+      taskId: session.task_id as string,
+      plannedDuration: session.planned_duration as number,
+      actualDuration: (session.actual_duration as number | null) ?? undefined,
+      focusScore: (session.focus_score as number | null) ?? undefined,
+      distractionCount: session.distraction_count as number,
+      distractionLevel: session.distraction_level as DistractionLevel,
+      backgroundAudio: session.background_audio
+        ? JSON.parse(session.background_audio as string)
+        : undefined,
+      notes: (session.notes as string | null) ?? '',
+      breaks: breaks.map(
+        (focusBreak, index): FocusBreak => ({
+          id: `${session.id}-${index}`,
+          startTime: new Date(focusBreak.start_time as string),
+          endTime: new Date(focusBreak.end_time as string),
+          type:
+            focusBreak.break_type === 'distraction' ? 'distraction' : 'planned',
+          reason: (focusBreak.reason as string | null) ?? undefined,
+        })
+      ),
+      metrics: {
+        totalDistractions: session.distraction_count as number,
+        longestFocusStreak: 0,
+        averageFocusStreak: 0,
+        productivityScore: (session.focus_score as number | null) ?? 0,
+        energyLevel: 0,
+      },
+      createdAt: new Date(session.created_at as string),
+      completedAt: session.completed_at
+        ? new Date(session.completed_at as string)
+        : undefined,
+    };
+  }
+
+  private transformTemplateFromBackend(
+    template: Record<string, unknown>
+  ): FocusSessionTemplate {
+    return {
+      id: template.id as string,
+      name: template.name as string,
+      focusDuration: template.focus_duration as number,
+      shortBreakDuration: template.short_break_duration as number,
+      longBreakDuration: template.long_break_duration as number,
+      sessionsBeforeLongBreak: template.sessions_before_long_break as number,
+      distractionLevel: template.distraction_level as DistractionLevel,
+      backgroundAudio: template.background_audio
+        ? JSON.parse(template.background_audio as string)
+        : undefined,
+      isBuiltin: template.is_builtin as boolean,
+      createdAt: new Date(template.created_at as string),
+      updatedAt: new Date(template.updated_at as string),
+    };
+  }
 }
@@ -0,0 +1,157 @@
+// GitHub issues service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  GitHubImportReport,
+  GitHubRefreshReport,
+  GitHubSettings,
+  SaveGitHubSettingsRequest,
+  TaskGitHubLink,
+} from '../../../types';
+
+export class GitHubService {
+  /**
+   * Get the GitHub connection, without its token
+   */
+  async getSettings(): Promise<GitHubSettings | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_github_settings'
+      );
+      if (!result) {
+        return null;
+      }
+      return {
+        apiUrl: result.api_url as string,
+        taskListId: (result.task_list_id as string | null) ?? undefined,
+        autoRefresh: result.auto_refresh as boolean,
+        hasToken: result.has_token as boolean,
+        lastRefreshedAt: result.last_refreshed_at
+          ? new Date(result.last_refreshed_at as string)
+          : undefined,
+        updatedAt: new Date(result.updated_at as string),
+      };
+    } catch (error) {
+      throw new Error(`Failed to get GitHub settings: ${error}`);
+    }
+  }
+
+  async saveSettings(request: SaveGitHubSettingsRequest): Promise<void> {
+    try {
+      await invoke<string>('save_github_settings', {
+        request: {
+          token: request.token,
+          api_url: request.apiUrl,
+          task_list_id: request.taskListId,
+          auto_refresh: request.autoRefresh,
+        },
+      });
+    } catch (error) {
+      throw new Error(`Failed to save GitHub settings: ${error}`);
+    }
+  }
+
+  /**
+   * Forget the token; linked tasks keep their links
+   */
+  async disconnect(): Promise<void> {
+    try {
+      await invoke<string>('delete_github_settings');
+    } catch (error) {
+      throw new Error(`Failed to disconnect GitHub: ${error}`);
+    }
+  }
+
+  async linkTask(
+    taskId: string,
+    repository: string,
+    issueNumber: number
+  ): Promise<TaskGitHubLink> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'link_task_to_github_issue',
+        {
+          request: {
+            task_id: taskId,
+            repository,
+            issue_number: issueNumber,
+          },
+        }
+      );
+      return this.transformLink(result);
+    } catch (error) {
+      throw new Error(`Failed to link task to GitHub issue: ${error}`);
+    }
+  }
+
+  async unlinkTask(taskId: string): Promise<boolean> {
+    try {
+      return await invoke<boolean>('unlink_task_from_github_issue', {
+        taskId,
+      });
+    } catch (error) {
+      throw new Error(`Failed to unlink task from GitHub issue: ${error}`);
+    }
+  }
+
+  async getTaskLink(taskId: string): Promise<TaskGitHubLink | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_task_github_link',
+        { taskId }
+      );
+      return result ? this.transformLink(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to get task GitHub link: ${error}`);
+    }
+  }
+
+  /**
+   * Create tasks for the open issues assigned to the user
+   */
+  async importAssignedIssues(): Promise<GitHubImportReport> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'import_github_issues'
+      );
+      return {
+        imported: result.imported as number,
+        alreadyLinked: result.already_linked as number,
+        errors: result.errors as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to import GitHub issues: ${error}`);
+    }
+  }
+
+  /**
+   * Carry issue state changes over to tasks and task completions over to issues
+   */
+  async refresh(): Promise<GitHubRefreshReport> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'refresh_github_issues'
+      );
+      return {
+        checked: result.checked as number,
+        completedTasks: result.completed_tasks as number,
+        reopenedTasks: result.reopened_tasks as number,
+        closedIssues: result.closed_issues as number,
+        reopenedIssues: result.reopened_issues as number,
+        errors: result.errors as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to refresh GitHub issues: ${error}`);
+    }
+  }
+
+  private transformLink(result: Record<string, unknown>): TaskGitHubLink {
+    return {
+      taskId: result.task_id as string,
+      repository: result.repository as string,
+      issueNumber: result.issue_number as number,
+      issueUrl: result.issue_url as string,
+      issueState: result.issue_state as 'open' | 'closed',
+      syncedAt: new Date(result.synced_at as string),
+    };
+  }
+}
@@ -0,0 +1,77 @@
+// Goal service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  CreateGoalRequest,
+  Goal,
+  GoalMetric,
+  GoalPeriod,
+  GoalProgress,
+} from '../../../types';
+
+export class GoalService {
+  /**
+   * Set a daily or weekly target for tracked time, focus time or completed
+   * tasks
+   */
+  async create(request: CreateGoalRequest): Promise<Goal> {
+    try {
+      const result = await invoke<Record<string, unknown>>('create_goal', {
+        request,
+      });
+      return this.transformGoalFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to create goal: ${error}`);
+    }
+  }
+
+  /**
+   * Get the progress and streaks of one goal, or of every goal
+   */
+  async getProgress(goalId?: string): Promise<GoalProgress[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_goal_progress',
+        { goalId }
+      );
+      return result.map(progress => ({
+        goal: this.transformGoalFromBackend(
+          progress.goal as Record<string, unknown>
+        ),
+        periodStart: new Date(progress.period_start as string),
+        periodEnd: new Date(progress.period_end as string),
+        current: progress.current as number,
+        target: progress.target as number,
+        remaining: progress.remaining as number,
+        isMet: progress.is_met as boolean,
+        currentStreak: progress.current_streak as number,
+        bestStreak: progress.best_streak as number,
+        atRisk: progress.at_risk as boolean,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get goal progress: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a goal
+   */
+  async delete(id: string): Promise<void> {
+    try {
+      await invoke('delete_goal', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete goal: ${error}`);
+    }
+  }
+
+  private transformGoalFromBackend(goal: Record<string, unknown>): Goal {
+    return {
+      id: goal.id as string,
+      title: goal.title as string,
+      metric: goal.metric as GoalMetric,
+      target: goal.target as number,
+      period: goal.period as GoalPeriod,
+      createdAt: new Date(goal.created_at as string),
+      updatedAt: new Date(goal.updated_at as string),
+    };
+  }
+}
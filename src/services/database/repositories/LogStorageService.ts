@@ -9,6 +9,11 @@ import {
   CreateToolExecutionLogRequest,
   LogFilter,
   LogStorageStats,
+  ProviderMetrics,
+  AiBudgetStatus,
+  AiCostPeriod,
+  AiCostReport,
+  RecordProviderRequest,
 } from '../../../types/aiLogging';
 import { getDatabaseErrorMessage } from '../utils';
 import { TranslationKey } from '../../../i18n';
@@ -499,6 +504,134 @@ export class LogStorageService {
     }
   }
 
+  /**
+   * Record the latency and outcome of a request sent to an AI provider
+   */
+  async recordProviderRequest(request: RecordProviderRequest): Promise<void> {
+    try {
+      await invoke('record_ai_provider_request', {
+        request: {
+          provider: request.provider,
+          model: request.model,
+          latency: Math.max(0, Math.round(request.latency)),
+          success: request.success,
+          timed_out: request.timedOut ?? false,
+          error_code: request.errorCode,
+          input_tokens: request.inputTokens,
+          output_tokens: request.outputTokens,
+        },
+      });
+    } catch (error) {
+      throw new Error(`Failed to record AI provider request: ${error}`);
+    }
+  }
+
+  /**
+   * Get per-provider latency, error rate and timeout counts for a date range
+   */
+  async getProviderMetrics(
+    startDate: Date,
+    endDate: Date
+  ): Promise<ProviderMetrics[]> {
+    try {
+      const result = await invoke<
+        {
+          provider: string;
+          request_count: number;
+          error_count: number;
+          timeout_count: number;
+          error_rate: number;
+          average_latency: number;
+          p95_latency: number;
+          max_latency: number;
+        }[]
+      >('get_provider_metrics', {
+        startDate: startDate.toISOString(),
+        endDate: endDate.toISOString(),
+      });
+
+      return result.map(metrics => ({
+        provider: metrics.provider,
+        requestCount: metrics.request_count,
+        errorCount: metrics.error_count,
+        timeoutCount: metrics.timeout_count,
+        errorRate: metrics.error_rate,
+        averageLatency: metrics.average_latency,
+        p95Latency: metrics.p95_latency,
+        maxLatency: metrics.max_latency,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get provider metrics: ${error}`);
+    }
+  }
+
+  /**
+   * Compare this month's estimated remote AI spend with a monthly budget in
+   * US dollars. Reaching 80% or 100% for the first time in a month shows a
+   * notification and emits an "ai-budget-warning" event.
+   */
+  async checkAiBudget(monthlyBudget: number): Promise<AiBudgetStatus> {
+    try {
+      const status = await invoke<{
+        month: string;
+        budget: number;
+        spent: number;
+        threshold: 80 | 100 | null;
+      }>('check_ai_budget', { monthlyBudget });
+
+      return {
+        month: status.month,
+        budget: status.budget,
+        spent: status.spent,
+        threshold: status.threshold ?? undefined,
+      };
+    } catch (error) {
+      throw new Error(`Failed to check AI budget: ${error}`);
+    }
+  }
+
+  /**
+   * Get the estimated AI spend per provider and model over the current day,
+   * week (starting Monday) or month
+   */
+  async getAiCostReport(period: AiCostPeriod): Promise<AiCostReport> {
+    try {
+      const report = await invoke<{
+        period: AiCostPeriod;
+        start: string;
+        end: string;
+        total_cost: number;
+        request_count: number;
+        by_model: {
+          provider: string;
+          model: string | null;
+          request_count: number;
+          input_tokens: number;
+          output_tokens: number;
+          cost: number;
+        }[];
+      }>('get_ai_cost_report', { period });
+
+      return {
+        period: report.period,
+        start: this.parseDate(report.start),
+        end: this.parseDate(report.end),
+        totalCost: report.total_cost,
+        requestCount: report.request_count,
+        byModel: report.by_model.map(model => ({
+          provider: model.provider,
+          model: model.model ?? undefined,
+          requestCount: model.request_count,
+          inputTokens: model.input_tokens,
+          outputTokens: model.output_tokens,
+          cost: model.cost,
+        })),
+      };
+    } catch (error) {
+      throw new Error(`Failed to get AI cost report: ${error}`);
+    }
+  }
+
   /**
    * Safely parse date values from backend with fallback
    */
@@ -527,59 +660,6 @@ export class LogStorageService {
   private transformLogFromBackend(
     backendLog: Record<string, unknown>
   ): AIInteractionLog {
-    // Handle both the new AI log format and the legacy ai_interactions format
-    const isLegacyFormat = !backendLog.session_id && backendLog.action_taken;
-
-    if (isLegacyFormat) {
-      // Legacy format from ai_interactions table
-      const actionTaken = backendLog.action_taken as string;
-      let sessionId = 'unknown';
-      let modelType: 'local' | 'gemini' = 'gemini';
-
-      // Extract session ID and model type from action_taken field
-      if (actionTaken && actionTaken.includes(':')) {
-        const parts = actionTaken.split(':');
-        if (parts.length >= 2) {
-          modelType = parts[0] as 'local' | 'gemini';
-          sessionId = parts[1];
-        }
-      }
-
-      const toolsUsed = (backendLog.tools_used as string) || '[]';
-      const parsedTools = this.parseJsonField(toolsUsed, []);
-
-      return {
-        id: (backendLog.id as string) || 'unknown',
-        timestamp: this.parseDate(backendLog.created_at),
-        sessionId,
-        modelType,
-        modelInfo: {
-          name: modelType === 'local' ? 'Local Model' : 'Gemini',
-          provider: modelType === 'local' ? 'local' : 'google',
-        },
-        userMessage: (backendLog.message as string) || '',
-        systemPrompt: undefined,
-        context: '{}',
-        aiResponse: (backendLog.response as string) || '',
-        actions: toolsUsed,
-        suggestions: '[]',
-        reasoning: backendLog.reasoning as string | undefined,
-        toolCalls: this.createMockToolCallsFromActions(
-          parsedTools,
-          (backendLog.id as string) || 'unknown'
-        ),
-        responseTime: 1000, // Default response time for legacy logs
-        tokenCount: undefined,
-        error: undefined,
-        errorCode: undefined,
-        containsSensitiveData: false,
-        dataClassification: 'internal',
-        createdAt: this.parseDate(backendLog.created_at),
-        updatedAt: this.parseDate(backendLog.created_at),
-      };
-    }
-
-    // New format
     const actionsString = (backendLog.actions as string) || '[]';
     const parsedActions = this.parseJsonField(actionsString, []);
 
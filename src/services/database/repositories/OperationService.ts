@@ -0,0 +1,95 @@
+// Long-running operation service that interfaces with Tauri commands and events
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import { Operation, OperationStatus } from '../../../types';
+
+export class OperationService {
+  /**
+   * Get the operations currently running, oldest first
+   */
+  async getActive(): Promise<Operation[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_active_operations'
+      );
+      return result.map(operation =>
+        this.transformOperationFromBackend(operation)
+      );
+    } catch (error) {
+      throw new Error(`Failed to get active operations: ${error}`);
+    }
+  }
+
+  /**
+   * Register an operation running in the frontend, such as a model download
+   */
+  async start(kind: string, label: string): Promise<string> {
+    try {
+      return await invoke<string>('start_operation_tracking', { kind, label });
+    } catch (error) {
+      throw new Error(`Failed to start operation: ${error}`);
+    }
+  }
+
+  /**
+   * Report progress (0-1) of an operation started with `start`
+   */
+  async reportProgress(
+    id: string,
+    progress: number,
+    message: string
+  ): Promise<void> {
+    try {
+      await invoke('report_operation_progress', { id, progress, message });
+    } catch (error) {
+      throw new Error(`Failed to report operation progress: ${error}`);
+    }
+  }
+
+  /**
+   * Mark an operation started with `start` as completed, or failed with `error`
+   */
+  async finish(id: string, error?: string): Promise<void> {
+    try {
+      await invoke('finish_operation', { id, error });
+    } catch (err) {
+      throw new Error(`Failed to finish operation: ${err}`);
+    }
+  }
+
+  /**
+   * Listen for operations starting or reporting progress
+   */
+  onProgress(callback: (operation: Operation) => void): Promise<UnlistenFn> {
+    return listen<Record<string, unknown>>('operation-progress', event =>
+      callback(this.transformOperationFromBackend(event.payload))
+    );
+  }
+
+  /**
+   * Listen for operations completing or failing
+   */
+  onCompleted(callback: (operation: Operation) => void): Promise<UnlistenFn> {
+    return listen<Record<string, unknown>>('operation-completed', event =>
+      callback(this.transformOperationFromBackend(event.payload))
+    );
+  }
+
+  private transformOperationFromBackend(
+    operation: Record<string, unknown>
+  ): Operation {
+    return {
+      id: operation.id as string,
+      kind: operation.kind as string,
+      label: operation.label as string,
+      status: operation.status as OperationStatus,
+      progress: (operation.progress as number | null) ?? undefined,
+      message: (operation.message as string | null) ?? undefined,
+      error: (operation.error as string | null) ?? undefined,
+      startedAt: new Date(operation.started_at as string),
+      finishedAt: operation.finished_at
+        ? new Date(operation.finished_at as string)
+        : undefined,
+    };
+  }
+}
@@ -1,5 +1,12 @@
 // Pattern service that interfaces with Tauri commands (SeaORM backend)
-import { ProductivityPattern, PatternAnalysis, TimeSlot } from '../../../types';
+import { invoke } from '@tauri-apps/api/core';
+import {
+  ProductivityPattern,
+  PatternAnalysis,
+  TimeSlot,
+  RecordedPattern,
+  PatternInsights,
+} from '../../../types';
 
 // Temporary types until they're added to the main types file
 type PatternType = 'focus' | 'productivity' | 'energy' | 'distraction';
@@ -12,39 +19,99 @@ type PatternSuggestion = {
 
 export class PatternService {
   /**
-   * Record a productivity pattern
+   * Record a productivity observation for a time slot; it is folded into
+   * the pattern already stored for the slot
    */
   async recordPattern(
-    _patternType: PatternType,
-    _timeSlot: TimeSlot,
-    _productivityScore: number,
-    _confidenceLevel: number
-  ): Promise<ProductivityPattern> {
+    patternType: string,
+    timeSlot: string,
+    productivityScore: number,
+    confidenceLevel: number,
+    sampleSize = 1
+  ): Promise<RecordedPattern> {
     try {
-      // For now, patterns might not be fully implemented in SeaORM backend
-      // This would need to be added to the Rust backend
-      throw new Error(
-        'Productivity patterns not yet implemented in SeaORM backend'
+      const result = await invoke<Record<string, unknown>>(
+        'record_productivity_pattern',
+        {
+          patternType,
+          timeSlot,
+          productivityScore,
+          confidenceLevel,
+          sampleSize,
+        }
       );
+      return this.transformPatternFromBackend(result);
     } catch (error) {
       throw new Error(`Failed to record pattern: ${error}`);
     }
   }
 
   /**
-   * Get patterns by type
+   * Get all stored patterns, most recently updated first
    */
-  async getPatternsByType(
-    _patternType: PatternType
-  ): Promise<ProductivityPattern[]> {
+  async getPatterns(): Promise<RecordedPattern[]> {
     try {
-      // Return empty array for now
-      return [];
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_productivity_patterns'
+      );
+      return result.map(pattern => this.transformPatternFromBackend(pattern));
+    } catch (error) {
+      throw new Error(`Failed to get patterns: ${error}`);
+    }
+  }
+
+  /**
+   * Get patterns by type ('hourly' and 'daily' are derived from tracked
+   * time), most productive first
+   */
+  async getPatternsByType(patternType: string): Promise<RecordedPattern[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_productivity_patterns',
+        { patternType }
+      );
+      return result.map(pattern => this.transformPatternFromBackend(pattern));
     } catch (error) {
       throw new Error(`Failed to get patterns by type: ${error}`);
     }
   }
 
+  /**
+   * Derive hourly and weekday patterns from the last four weeks of time and
+   * focus sessions now; this also runs in the background every few hours
+   */
+  async derivePatterns(): Promise<RecordedPattern[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'derive_productivity_patterns'
+      );
+      return result.map(pattern => this.transformPatternFromBackend(pattern));
+    } catch (error) {
+      throw new Error(`Failed to derive patterns: ${error}`);
+    }
+  }
+
+  /**
+   * Get peak hours, best weekdays and recommendations from stored patterns
+   */
+  async getInsights(): Promise<PatternInsights> {
+    try {
+      const insights = await invoke<Record<string, unknown>>(
+        'get_pattern_insights'
+      );
+      return {
+        mostProductiveHours: insights.most_productive_hours as string[],
+        leastProductiveHours: insights.least_productive_hours as string[],
+        bestDaysOfWeek: insights.best_days_of_week as string[],
+        optimalSessionLength:
+          (insights.optimal_session_length as number | null) ?? undefined,
+        recommendations: insights.recommendations as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to get pattern insights: ${error}`);
+    }
+  }
+
   /**
    * Get patterns by time slot
    */
@@ -133,11 +200,9 @@ export class PatternService {
   /**
    * Delete pattern
    */
-  async deletePattern(_patternId: string): Promise<void> {
+  async deletePattern(patternId: string): Promise<void> {
     try {
-      throw new Error(
-        'Productivity patterns not yet implemented in SeaORM backend'
-      );
+      await invoke('delete_productivity_pattern', { id: patternId });
     } catch (error) {
       throw new Error(`Failed to delete pattern: ${error}`);
     }
@@ -207,4 +272,18 @@ export class PatternService {
       throw new Error(`Failed to import patterns: ${error}`);
     }
   }
+
+  private transformPatternFromBackend(
+    pattern: Record<string, unknown>
+  ): RecordedPattern {
+    return {
+      id: pattern.id as string,
+      patternType: pattern.pattern_type as string,
+      timeSlot: pattern.time_slot as string,
+      productivityScore: pattern.productivity_score as number,
+      confidenceLevel: pattern.confidence_level as number,
+      sampleSize: pattern.sample_size as number,
+      updatedAt: new Date(pattern.updated_at as string),
+    };
+  }
 }
@@ -10,6 +10,9 @@ import {
   Priority,
   GenerateInstancesResponse,
   PeriodicTaskInstancesResponse,
+  PeriodicTemplateOccurrences,
+  PeriodicTemplateImportResult,
+  MissedOccurrencePolicy,
   TimePreset,
 } from '../../../types';
 import { getDatabaseErrorMessage } from '../utils';
@@ -52,7 +55,11 @@ export class PeriodicTaskService {
         recurrence_type: request.recurrenceType,
         recurrence_interval: request.recurrenceInterval ?? 1,
         recurrence_unit: request.recurrenceUnit,
+        recurrence_rule: request.recurrenceRule,
         start_date: request.startDate.toISOString(),
+        ends_at: request.endsAt?.toISOString(),
+        max_occurrences: request.maxOccurrences,
+        missed_occurrence_policy: request.missedOccurrencePolicy,
       };
 
       const result = await invoke<Record<string, unknown>>(
@@ -207,9 +214,26 @@ export class PeriodicTaskService {
       if (request.recurrenceUnit !== undefined) {
         serializedRequest.recurrence_unit = request.recurrenceUnit;
       }
+      if (request.recurrenceRule !== undefined) {
+        serializedRequest.recurrence_rule = request.recurrenceRule;
+      }
       if (request.isActive !== undefined) {
         serializedRequest.is_active = request.isActive;
       }
+      if (request.endsAt === null) {
+        serializedRequest.clear_ends_at = true;
+      } else if (request.endsAt !== undefined) {
+        serializedRequest.ends_at = request.endsAt.toISOString();
+      }
+      if (request.maxOccurrences === null) {
+        serializedRequest.clear_max_occurrences = true;
+      } else if (request.maxOccurrences !== undefined) {
+        serializedRequest.max_occurrences = request.maxOccurrences;
+      }
+      if (request.missedOccurrencePolicy !== undefined) {
+        serializedRequest.missed_occurrence_policy =
+          request.missedOccurrencePolicy;
+      }
 
       const result = await invoke<Record<string, unknown>>(
         'update_periodic_task_template',
@@ -262,6 +286,89 @@ export class PeriodicTaskService {
     }
   }
 
+  /**
+   * Pause a template until the given date
+   */
+  async pauseTemplate(id: string, until: Date): Promise<PeriodicTaskTemplate> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'pause_periodic_template',
+        { id, until: until.toISOString() }
+      );
+      return this.transformTemplateFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to pause periodic task template: ${error}`);
+    }
+  }
+
+  /**
+   * End a template's pause now
+   */
+  async resumeTemplate(id: string): Promise<PeriodicTaskTemplate> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'resume_periodic_template',
+        { id }
+      );
+      return this.transformTemplateFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to resume periodic task template: ${error}`);
+    }
+  }
+
+  /**
+   * Skip the next occurrence of a template without generating it
+   */
+  async skipNextOccurrence(templateId: string): Promise<PeriodicTaskTemplate> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'skip_next_occurrence',
+        { templateId }
+      );
+      return this.transformTemplateFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to skip next occurrence: ${error}`);
+    }
+  }
+
+  /**
+   * Export all periodic templates, with their recurrence, to a JSON file.
+   * Returns the number of templates written.
+   */
+  async exportTemplates(filePath: string): Promise<number> {
+    try {
+      const summary = await invoke<Record<string, unknown>>(
+        'export_periodic_templates',
+        { filePath }
+      );
+      return summary.periodic_template_count as number;
+    } catch (error) {
+      throw new Error(`Failed to export periodic templates: ${error}`);
+    }
+  }
+
+  /**
+   * Import the periodic templates of an exported file, skipping existing ones
+   */
+  async importTemplates(
+    filePath: string
+  ): Promise<PeriodicTemplateImportResult> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'import_periodic_templates',
+        { filePath }
+      );
+      const summary = result.summary as Record<string, unknown>;
+      return {
+        packName: summary.name as string,
+        imported: result.imported_periodic_templates as number,
+        skipped: result.skipped as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to import periodic templates: ${error}`);
+    }
+  }
+
   /**
    * Get instances for a specific template
    */
@@ -316,7 +423,8 @@ export class PeriodicTaskService {
     currentDate: Date,
     recurrenceType: RecurrenceType,
     interval: number = 1,
-    unit?: 'days' | 'weeks' | 'months'
+    unit?: 'days' | 'weeks' | 'months',
+    rule?: string
   ): Promise<Date> {
     try {
       const result = await invoke<string>('calculate_next_generation_date', {
@@ -324,6 +432,7 @@ export class PeriodicTaskService {
         recurrence_type: recurrenceType,
         interval,
         unit,
+        rule,
       });
       return new Date(result);
     } catch (error) {
@@ -334,6 +443,36 @@ export class PeriodicTaskService {
     }
   }
 
+  /**
+   * Preview the next occurrences of a recurrence pattern, starting at its start date
+   */
+  async previewRecurrenceDates(
+    startDate: Date,
+    recurrenceType: RecurrenceType,
+    count: number,
+    options: {
+      interval?: number;
+      unit?: 'days' | 'weeks' | 'months';
+      rule?: string;
+    } = {}
+  ): Promise<Date[]> {
+    try {
+      const result = await invoke<string[]>('preview_recurrence_dates', {
+        request: {
+          start_date: startDate.toISOString(),
+          recurrence_type: recurrenceType,
+          recurrence_interval: options.interval ?? 1,
+          recurrence_unit: options.unit,
+          recurrence_rule: options.rule,
+          count,
+        },
+      });
+      return result.map(date => new Date(date));
+    } catch (error) {
+      throw new Error(`Failed to preview recurrence dates: ${error}`);
+    }
+  }
+
   /**
    * Generate pending instances for all templates
    */
@@ -691,6 +830,7 @@ export class PeriodicTaskService {
     totalInstances: number;
     instancesThisWeek: number;
     instancesThisMonth: number;
+    limitedTemplates: PeriodicTemplateOccurrences[];
   }> {
     try {
       const result = await invoke<{
@@ -699,6 +839,14 @@ export class PeriodicTaskService {
         total_instances: number;
         instances_this_week: number;
         instances_this_month: number;
+        limited_templates: {
+          template_id: string;
+          title: string;
+          ends_at: string | null;
+          max_occurrences: number | null;
+          generated_occurrences: number;
+          remaining_occurrences: number;
+        }[];
       }>('get_periodic_task_stats');
 
       return {
@@ -707,6 +855,14 @@ export class PeriodicTaskService {
         totalInstances: result.total_instances,
         instancesThisWeek: result.instances_this_week,
         instancesThisMonth: result.instances_this_month,
+        limitedTemplates: result.limited_templates.map(template => ({
+          templateId: template.template_id,
+          title: template.title,
+          endsAt: template.ends_at ? new Date(template.ends_at) : undefined,
+          maxOccurrences: template.max_occurrences ?? undefined,
+          generatedOccurrences: template.generated_occurrences,
+          remainingOccurrences: template.remaining_occurrences,
+        })),
       };
     } catch (error) {
       const errorMessage = getDatabaseErrorMessage(
@@ -738,11 +894,22 @@ export class PeriodicTaskService {
         | 'weeks'
         | 'months'
         | undefined,
+      recurrenceRule: (backendTemplate.recurrence_rule as string) || undefined,
       startDate: new Date(backendTemplate.start_date as string),
       nextGenerationDate: new Date(
         backendTemplate.next_generation_date as string
       ),
       isActive: (backendTemplate.is_active as boolean) ?? true,
+      endsAt: backendTemplate.ends_at
+        ? new Date(backendTemplate.ends_at as string)
+        : undefined,
+      maxOccurrences: (backendTemplate.max_occurrences as number) ?? undefined,
+      pausedUntil: backendTemplate.paused_until
+        ? new Date(backendTemplate.paused_until as string)
+        : undefined,
+      missedOccurrencePolicy:
+        (backendTemplate.missed_occurrence_policy as MissedOccurrencePolicy) ||
+        undefined,
       createdAt: new Date(backendTemplate.created_at as string),
       updatedAt: new Date(backendTemplate.updated_at as string),
     };
@@ -0,0 +1,141 @@
+// Preferences service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  PromptTemplates,
+  RolloverMode,
+  WorkdayContext,
+  WorkingHours,
+} from '../../../types';
+
+interface StoredPromptTemplates {
+  system_persona: string | null;
+  thought: string | null;
+  action: string | null;
+  final_answer: string | null;
+}
+
+function fromStoredTemplates(stored: StoredPromptTemplates): PromptTemplates {
+  return {
+    systemPersona: stored.system_persona ?? undefined,
+    thought: stored.thought ?? undefined,
+    action: stored.action ?? undefined,
+    finalAnswer: stored.final_answer ?? undefined,
+  };
+}
+
+export class PreferencesService {
+  async getWorkingHours(): Promise<WorkingHours> {
+    try {
+      return await invoke<WorkingHours>('get_working_hours');
+    } catch (error) {
+      throw new Error(`Failed to get working hours: ${error}`);
+    }
+  }
+
+  /**
+   * Store the working hours reminders, periodic tasks and reports follow
+   */
+  async saveWorkingHours(workingHours: WorkingHours): Promise<WorkingHours> {
+    try {
+      return await invoke<WorkingHours>('save_working_hours', { workingHours });
+    } catch (error) {
+      throw new Error(`Failed to save working hours: ${error}`);
+    }
+  }
+
+  /**
+   * Whether now is working time, what is left of today and upcoming holidays
+   */
+  async getWorkdayContext(): Promise<WorkdayContext> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'get_workday_context'
+      );
+      const nextWorkingTime = result.next_working_time as string | null;
+      return {
+        now: new Date(result.now as string),
+        utcOffset: result.utc_offset as string,
+        today: result.today as string,
+        workingHours: result.working_hours as WorkingHours,
+        isWorkday: result.is_workday as boolean,
+        isHoliday: result.is_holiday as boolean,
+        isWorkingTime: result.is_working_time as boolean,
+        minutesLeftToday: result.minutes_left_today as number,
+        nextWorkingTime: nextWorkingTime
+          ? new Date(nextWorkingTime)
+          : undefined,
+        upcomingHolidays: result.upcoming_holidays as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to get workday context: ${error}`);
+    }
+  }
+
+  async getRolloverMode(): Promise<RolloverMode> {
+    try {
+      return await invoke<RolloverMode>('get_rollover_mode');
+    } catch (error) {
+      throw new Error(`Failed to get rollover mode: ${error}`);
+    }
+  }
+
+  /**
+   * Choose where unfinished tasks go at the end of the day: today, the
+   * backlog, or nowhere
+   */
+  async saveRolloverMode(mode: RolloverMode): Promise<RolloverMode> {
+    try {
+      return await invoke<RolloverMode>('save_rollover_mode', { mode });
+    } catch (error) {
+      throw new Error(`Failed to save rollover mode: ${error}`);
+    }
+  }
+
+  /**
+   * The user's rewrites of Kira's prompts; unset parts use the built-in ones
+   */
+  async getPromptTemplates(): Promise<PromptTemplates> {
+    try {
+      return fromStoredTemplates(
+        await invoke<StoredPromptTemplates>('get_prompt_templates')
+      );
+    } catch (error) {
+      throw new Error(`Failed to get prompt templates: ${error}`);
+    }
+  }
+
+  /**
+   * Store the prompt templates Kira answers with; blank ones go back to the
+   * built-in prompts
+   */
+  async updatePromptTemplates(
+    templates: PromptTemplates
+  ): Promise<PromptTemplates> {
+    try {
+      const stored = await invoke<StoredPromptTemplates>(
+        'update_prompt_templates',
+        {
+          templates: {
+            system_persona: templates.systemPersona ?? null,
+            thought: templates.thought ?? null,
+            action: templates.action ?? null,
+            final_answer: templates.finalAnswer ?? null,
+          },
+        }
+      );
+      return fromStoredTemplates(stored);
+    } catch (error) {
+      throw new Error(`Failed to update prompt templates: ${error}`);
+    }
+  }
+
+  async resetPromptTemplates(): Promise<PromptTemplates> {
+    try {
+      return fromStoredTemplates(
+        await invoke<StoredPromptTemplates>('reset_prompt_templates')
+      );
+    } catch (error) {
+      throw new Error(`Failed to reset prompt templates: ${error}`);
+    }
+  }
+}
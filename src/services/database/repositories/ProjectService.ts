@@ -0,0 +1,118 @@
+// Project service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  CreateProjectRequest,
+  Project,
+  ProjectProgress,
+  ProjectStatus,
+  UpdateProjectRequest,
+} from '../../../types';
+
+export class ProjectService {
+  /**
+   * Create a project to group task lists under
+   */
+  async create(request: CreateProjectRequest): Promise<Project> {
+    try {
+      const result = await invoke<Record<string, unknown>>('create_project', {
+        request: {
+          name: request.name,
+          description: request.description ?? null,
+          status: request.status ?? null,
+          target_date: request.targetDate?.toISOString() ?? null,
+        },
+      });
+      return this.transformProjectFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to create project: ${error}`);
+    }
+  }
+
+  /**
+   * Get all projects by name
+   */
+  async findAll(): Promise<Project[]> {
+    try {
+      const result =
+        await invoke<Record<string, unknown>[]>('get_all_projects');
+      return result.map(project => this.transformProjectFromBackend(project));
+    } catch (error) {
+      throw new Error(`Failed to get projects: ${error}`);
+    }
+  }
+
+  /**
+   * Get the task rollup of one project, or of every project
+   */
+  async getProgress(projectId?: string): Promise<ProjectProgress[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_project_progress',
+        { projectId }
+      );
+      return result.map(progress => ({
+        project: this.transformProjectFromBackend(
+          progress.project as Record<string, unknown>
+        ),
+        taskListCount: progress.task_list_count as number,
+        totalTasks: progress.total_tasks as number,
+        completedTasks: progress.completed_tasks as number,
+        percentComplete: progress.percent_complete as number,
+        overdueTasks: progress.overdue_tasks as number,
+        estimatedMinutes: progress.estimated_minutes as number,
+        actualMinutes: progress.actual_minutes as number,
+        isPastTarget: progress.is_past_target as boolean,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get project progress: ${error}`);
+    }
+  }
+
+  /**
+   * Update a project's name, description, status or target date
+   */
+  async update(id: string, request: UpdateProjectRequest): Promise<Project> {
+    try {
+      const result = await invoke<Record<string, unknown>>('update_project', {
+        id,
+        request: {
+          name: request.name ?? null,
+          description: request.description ?? null,
+          status: request.status ?? null,
+          target_date: request.targetDate?.toISOString() ?? null,
+          clear_target_date: request.clearTargetDate ?? null,
+        },
+      });
+      return this.transformProjectFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to update project: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a project; its task lists and tasks are kept and unlinked
+   */
+  async delete(id: string): Promise<void> {
+    try {
+      await invoke('delete_project', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete project: ${error}`);
+    }
+  }
+
+  private transformProjectFromBackend(
+    project: Record<string, unknown>
+  ): Project {
+    return {
+      id: project.id as string,
+      name: project.name as string,
+      description: (project.description as string | null) ?? undefined,
+      status: project.status as ProjectStatus,
+      targetDate: project.target_date
+        ? new Date(project.target_date as string)
+        : undefined,
+      createdAt: new Date(project.created_at as string),
+      updatedAt: new Date(project.updated_at as string),
+    };
+  }
+}
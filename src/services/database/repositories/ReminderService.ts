@@ -0,0 +1,159 @@
+// Task reminder service that interfaces with Tauri commands (SeaORM backend)
+// Notifications are shown by the backend scheduler
+import { invoke } from '@tauri-apps/api/core';
+import {
+  NotificationDelivery,
+  ReminderAnchor,
+  ScheduledReminder,
+  TaskReminder,
+} from '../../../types';
+
+export class ReminderService {
+  /**
+   * Replace the due date and scheduled date reminders of a task
+   */
+  async setTaskReminders(
+    taskId: string,
+    reminders: { anchor: ReminderAnchor; offsetMinutes: number }[]
+  ): Promise<TaskReminder[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'set_task_reminders',
+        {
+          taskId,
+          reminders: reminders.map(reminder => ({
+            anchor: reminder.anchor,
+            offset_minutes: reminder.offsetMinutes,
+          })),
+        }
+      );
+      return result.map(reminder =>
+        this.transformReminderFromBackend(reminder)
+      );
+    } catch (error) {
+      throw new Error(`Failed to set task reminders: ${error}`);
+    }
+  }
+
+  /**
+   * Get all reminders of a task
+   */
+  async getTaskReminders(taskId: string): Promise<TaskReminder[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_task_reminders',
+        { taskId }
+      );
+      return result.map(reminder =>
+        this.transformReminderFromBackend(reminder)
+      );
+    } catch (error) {
+      throw new Error(`Failed to get task reminders: ${error}`);
+    }
+  }
+
+  /**
+   * Get reminders firing within the next hours, earliest first
+   */
+  async getUpcoming(hours = 24): Promise<ScheduledReminder[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_upcoming_reminders',
+        { hours }
+      );
+      return result.map(scheduled => ({
+        reminder: this.transformReminderFromBackend(
+          scheduled.reminder as Record<string, unknown>
+        ),
+        taskId: scheduled.task_id as string,
+        taskTitle: scheduled.task_title as string,
+        targetAt: new Date(scheduled.target_at as string),
+        fireAt: new Date(scheduled.fire_at as string),
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get upcoming reminders: ${error}`);
+    }
+  }
+
+  /**
+   * Fire a reminder again after the given minutes
+   */
+  async snooze(id: string, minutes: number): Promise<TaskReminder> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'snooze_reminder',
+        { id, minutes }
+      );
+      return this.transformReminderFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to snooze reminder: ${error}`);
+    }
+  }
+
+  /**
+   * Notify about a task again in the given minutes
+   */
+  async remindAgainIn(taskId: string, minutes: number): Promise<TaskReminder> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'remind_again_in',
+        { taskId, minutes }
+      );
+      return this.transformReminderFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to schedule reminder: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a reminder
+   */
+  async delete(id: string): Promise<void> {
+    try {
+      await invoke('delete_task_reminder', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete reminder: ${error}`);
+    }
+  }
+
+  /**
+   * Notifications sent or dropped by the hourly limit, newest first
+   */
+  async getNotificationLog(limit?: number): Promise<NotificationDelivery[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_notification_log',
+        { limit }
+      );
+      return result.map(delivery => ({
+        id: delivery.id as string,
+        dedupKey: delivery.dedup_key as string,
+        kind: delivery.kind as NotificationDelivery['kind'],
+        title: delivery.title as string,
+        body: delivery.body as string,
+        status: delivery.status as NotificationDelivery['status'],
+        createdAt: new Date(delivery.created_at as string),
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get notification log: ${error}`);
+    }
+  }
+
+  private transformReminderFromBackend(
+    reminder: Record<string, unknown>
+  ): TaskReminder {
+    const optionalDate = (value: unknown) =>
+      value ? new Date(value as string) : undefined;
+    return {
+      id: reminder.id as string,
+      taskId: reminder.task_id as string,
+      anchor: reminder.anchor as ReminderAnchor,
+      offsetMinutes: reminder.offset_minutes as number,
+      remindAt: optionalDate(reminder.remind_at),
+      snoozedUntil: optionalDate(reminder.snoozed_until),
+      lastFiredFor: optionalDate(reminder.last_fired_for),
+      createdAt: new Date(reminder.created_at as string),
+      updatedAt: new Date(reminder.updated_at as string),
+    };
+  }
+}
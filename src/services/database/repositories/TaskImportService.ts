@@ -0,0 +1,94 @@
+// Task import service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  Priority,
+  TaskImportFormat,
+  TaskImportMapping,
+  TaskImportPreview,
+  TaskImportResult,
+  TaskImportRow,
+} from '../../../types';
+
+export class TaskImportService {
+  /**
+   * Read a CSV file or Markdown checkbox list and report the tasks it contains,
+   * flagging duplicates of existing tasks and of earlier rows
+   */
+  async preview(
+    filePath: string,
+    format: TaskImportFormat
+  ): Promise<TaskImportPreview> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'preview_task_import',
+        { filePath, format }
+      );
+      const rows = result.rows as Record<string, unknown>[];
+      return {
+        format: result.format as TaskImportFormat,
+        columns: result.columns as string[],
+        columnMapping: result.column_mapping as Record<string, string>,
+        rows: rows.map(row => this.transformRowFromBackend(row)),
+        duplicateCount: result.duplicate_count as number,
+        errors: result.errors as TaskImportResult['errors'],
+      };
+    } catch (error) {
+      throw new Error(`Failed to preview task import: ${error}`);
+    }
+  }
+
+  /**
+   * Create tasks from a file; duplicates are skipped unless the mapping
+   * includes them
+   */
+  async import(
+    filePath: string,
+    mapping: TaskImportMapping
+  ): Promise<TaskImportResult> {
+    try {
+      const result = await invoke<Record<string, unknown>>('import_tasks', {
+        filePath,
+        mapping: {
+          format: mapping.format,
+          columns: mapping.columns ?? {},
+          task_list_id: mapping.taskListId,
+          task_lists: mapping.taskLists ?? {},
+          tags: mapping.tags ?? [],
+          include_duplicates: mapping.includeDuplicates ?? false,
+        },
+      });
+      return {
+        importedTaskIds: result.imported_task_ids as string[],
+        skippedDuplicates: result.skipped_duplicates as number,
+        errors: result.errors as TaskImportResult['errors'],
+      };
+    } catch (error) {
+      throw new Error(`Failed to import tasks: ${error}`);
+    }
+  }
+
+  /**
+   * Transform an import row from backend format to frontend format
+   */
+  private transformRowFromBackend(row: Record<string, unknown>): TaskImportRow {
+    const duplicate = row.duplicate as Record<string, unknown> | null;
+    return {
+      line: row.line as number,
+      title: row.title as string,
+      description: (row.description as string) || undefined,
+      priority: (row.priority as Priority | null) ?? undefined,
+      status: (row.status as string) || undefined,
+      dueDate: row.due_date ? new Date(row.due_date as string) : undefined,
+      timeEstimate: (row.time_estimate as number | null) ?? undefined,
+      tags: row.tags as string[],
+      taskList: (row.task_list as string) || undefined,
+      parent: (row.parent as number | null) ?? undefined,
+      duplicate: duplicate
+        ? duplicate.kind === 'existing'
+          ? { kind: 'existing', taskId: duplicate.task_id as string }
+          : { kind: 'file', line: duplicate.line as number }
+        : undefined,
+      warnings: row.warnings as string[],
+    };
+  }
+}
@@ -4,6 +4,9 @@ import {
   TaskList,
   CreateTaskListRequest,
   UpdateTaskListRequest,
+  TaskListAgingStats,
+  TaskBreakdown,
+  BreakdownGroup,
   TaskListService as ITaskListService,
   Task,
   TaskStatus,
@@ -22,12 +25,16 @@ import {
 
 export class TaskListService implements ITaskListService {
   /**
-   * Get all task lists with comprehensive error handling
+   * Get all task lists with comprehensive error handling; archived lists
+   * are left out unless asked for
    */
-  async getAllTaskLists(): Promise<TaskList[]> {
+  async getAllTaskLists(includeArchived = false): Promise<TaskList[]> {
     try {
-      const result =
-        await invoke<Record<string, unknown>[]>('get_all_task_lists');
+      const result = includeArchived
+        ? await invoke<Record<string, unknown>[]>('get_all_task_lists', {
+            includeArchived,
+          })
+        : await invoke<Record<string, unknown>[]>('get_all_task_lists');
       return result.map(taskList =>
         this.transformTaskListFromBackend(taskList)
       );
@@ -89,7 +96,10 @@ export class TaskListService implements ITaskListService {
       const result = await invoke<Record<string, unknown>>('update_task_list', {
         id: id.trim(),
         request: {
-          name: request.name.trim(),
+          name: request.name?.trim(),
+          color: request.color,
+          icon: request.icon,
+          project_id: request.projectId,
         },
       });
       return this.transformTaskListFromBackend(result);
@@ -122,6 +132,45 @@ export class TaskListService implements ITaskListService {
     }
   }
 
+  /**
+   * Hide a task list and its tasks from the lists and stats without
+   * deleting anything
+   */
+  async archiveTaskList(id: string): Promise<TaskList> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'archive_task_list',
+        { id }
+      );
+      return this.transformTaskListFromBackend(result);
+    } catch (error) {
+      const parsedError = parseTaskListError(error as string);
+      throw this.createEnhancedError(
+        'Failed to archive task list',
+        parsedError
+      );
+    }
+  }
+
+  /**
+   * Bring an archived task list back
+   */
+  async unarchiveTaskList(id: string): Promise<TaskList> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'unarchive_task_list',
+        { id }
+      );
+      return this.transformTaskListFromBackend(result);
+    } catch (error) {
+      const parsedError = parseTaskListError(error as string);
+      throw this.createEnhancedError(
+        'Failed to unarchive task list',
+        parsedError
+      );
+    }
+  }
+
   /**
    * Get default task list with error handling
    */
@@ -196,6 +245,163 @@ export class TaskListService implements ITaskListService {
     }
   }
 
+  /**
+   * Move a task list between the lists directly above and below it
+   */
+  async reorderTaskList(
+    id: string,
+    beforeId?: string,
+    afterId?: string
+  ): Promise<TaskList> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'reorder_task_list',
+        { id: id.trim(), beforeId: beforeId ?? null, afterId: afterId ?? null }
+      );
+      return this.transformTaskListFromBackend(result);
+    } catch (error) {
+      const parsedError = parseTaskListError(error as string);
+      throw this.createEnhancedError(
+        'Failed to reorder task list',
+        parsedError
+      );
+    }
+  }
+
+  /**
+   * Put task lists in the given order; lists left out follow in their
+   * current order
+   */
+  async reorderTaskLists(ids: string[]): Promise<TaskList[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'reorder_task_lists',
+        { ids }
+      );
+      return result.map(taskList =>
+        this.transformTaskListFromBackend(taskList)
+      );
+    } catch (error) {
+      const parsedError = parseTaskListError(error as string);
+      throw this.createEnhancedError(
+        'Failed to reorder task lists',
+        parsedError
+      );
+    }
+  }
+
+  /**
+   * Set target backlog and cycle times (in hours) for a task list
+   */
+  async setTargets(
+    id: string,
+    targetBacklogHours?: number,
+    targetCycleHours?: number
+  ): Promise<TaskList> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'set_task_list_targets',
+        {
+          id: id.trim(),
+          targetBacklogHours: targetBacklogHours ?? null,
+          targetCycleHours: targetCycleHours ?? null,
+        }
+      );
+      return this.transformTaskListFromBackend(result);
+    } catch (error) {
+      const parsedError = parseTaskListError(error as string);
+      throw this.createEnhancedError(
+        'Failed to set task list targets',
+        parsedError
+      );
+    }
+  }
+
+  /**
+   * Set the hourly rate billed for a task list; without one it is not billable
+   */
+  async setHourlyRate(id: string, hourlyRate?: number): Promise<TaskList> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'set_task_list_hourly_rate',
+        { id: id.trim(), hourlyRate: hourlyRate ?? null }
+      );
+      return this.transformTaskListFromBackend(result);
+    } catch (error) {
+      const parsedError = parseTaskListError(error as string);
+      throw this.createEnhancedError(
+        'Failed to set task list hourly rate',
+        parsedError
+      );
+    }
+  }
+
+  /**
+   * Get aging statistics for one task list, or for all lists
+   */
+  async getAgingStats(taskListId?: string): Promise<TaskListAgingStats[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_task_list_aging_stats',
+        { taskListId: taskListId ?? null }
+      );
+      return result.map(stats => ({
+        taskListId: stats.task_list_id as string,
+        taskListName: stats.task_list_name as string,
+        targetBacklogHours:
+          (stats.target_backlog_hours as number | null) ?? undefined,
+        targetCycleHours:
+          (stats.target_cycle_hours as number | null) ?? undefined,
+        backlogCount: stats.backlog_count as number,
+        avgBacklogHours:
+          (stats.avg_backlog_hours as number | null) ?? undefined,
+        maxBacklogHours:
+          (stats.max_backlog_hours as number | null) ?? undefined,
+        inProgressCount: stats.in_progress_count as number,
+        avgInProgressHours:
+          (stats.avg_in_progress_hours as number | null) ?? undefined,
+        maxInProgressHours:
+          (stats.max_in_progress_hours as number | null) ?? undefined,
+        completedCount: stats.completed_count as number,
+        avgCycleHours: (stats.avg_cycle_hours as number | null) ?? undefined,
+        backlogBreaches: stats.backlog_breaches as number,
+        inProgressBreaches: stats.in_progress_breaches as number,
+        completedBreaches: stats.completed_breaches as number,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get task list aging stats: ${error}`);
+    }
+  }
+
+  /**
+   * Get task counts by status, overdue tasks, time and last activity per
+   * task list, or per tag
+   */
+  async getBreakdown(
+    groupBy: BreakdownGroup = 'list'
+  ): Promise<TaskBreakdown[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_task_list_breakdown',
+        { groupBy }
+      );
+      return result.map(stats => ({
+        id: stats.id as string,
+        name: stats.name as string,
+        totalTasks: stats.total_tasks as number,
+        statusCounts: stats.status_counts as Record<string, number>,
+        overdueCount: stats.overdue_count as number,
+        estimatedMinutes: stats.estimated_minutes as number,
+        actualMinutes: stats.actual_minutes as number,
+        lastActivityAt: stats.last_activity_at
+          ? new Date(stats.last_activity_at as string)
+          : undefined,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get task list breakdown: ${error}`);
+    }
+  }
+
   /**
    * Transform task list data from backend format to frontend format
    */
@@ -206,6 +412,16 @@ export class TaskListService implements ITaskListService {
       id: backendTaskList.id as string,
       name: backendTaskList.name as string,
       isDefault: backendTaskList.is_default as boolean,
+      orderNum: backendTaskList.order_num as number,
+      targetBacklogHours:
+        (backendTaskList.target_backlog_hours as number | null) ?? undefined,
+      targetCycleHours:
+        (backendTaskList.target_cycle_hours as number | null) ?? undefined,
+      hourlyRate: (backendTaskList.hourly_rate as number | null) ?? undefined,
+      color: (backendTaskList.color as string | null) ?? undefined,
+      icon: (backendTaskList.icon as string | null) ?? undefined,
+      isArchived: (backendTaskList.is_archived as boolean | null) ?? undefined,
+      projectId: (backendTaskList.project_id as string | null) ?? undefined,
       createdAt: new Date(backendTaskList.created_at as string),
       updatedAt: new Date(backendTaskList.updated_at as string),
     };
@@ -0,0 +1,70 @@
+// Task note service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import { TaskNote } from '../../../types';
+
+export class TaskNoteService {
+  /**
+   * Append a note to a task
+   */
+  async add(taskId: string, content: string): Promise<TaskNote> {
+    try {
+      const result = await invoke<Record<string, unknown>>('add_task_note', {
+        request: { task_id: taskId, content },
+      });
+      return this.transformNoteFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to add task note: ${error}`);
+    }
+  }
+
+  /**
+   * Get the notes of a task, oldest first
+   */
+  async getTaskNotes(taskId: string): Promise<TaskNote[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_task_notes',
+        { taskId }
+      );
+      return result.map(note => this.transformNoteFromBackend(note));
+    } catch (error) {
+      throw new Error(`Failed to get task notes: ${error}`);
+    }
+  }
+
+  /**
+   * Edit a note; it keeps its original timestamp
+   */
+  async update(id: string, content: string): Promise<TaskNote> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'update_task_note',
+        { id, content }
+      );
+      return this.transformNoteFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to update task note: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a note
+   */
+  async delete(id: string): Promise<void> {
+    try {
+      await invoke('delete_task_note', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete task note: ${error}`);
+    }
+  }
+
+  private transformNoteFromBackend(note: Record<string, unknown>): TaskNote {
+    return {
+      id: note.id as string,
+      taskId: note.task_id as string,
+      content: note.content as string,
+      createdAt: new Date(note.created_at as string),
+      updatedAt: new Date(note.updated_at as string),
+    };
+  }
+}
@@ -1,5 +1,6 @@
 // Task service that interfaces with Tauri commands (SeaORM backend)
 import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
 import {
   Task,
   TaskStatus,
@@ -8,11 +9,54 @@ import {
   TaskFilters,
   TaskSortOptions,
   ValidationResult,
+  DependencyGraphReport,
+  DependencySchedule,
+  WorkloadReport,
+  DailyPlan,
+  RolloverMode,
+  RolloverReport,
+  DeferReason,
+  PlanReason,
+  Priority,
+  EstimationAccuracy,
+  EstimationGroup,
+  EstimationReport,
+  GroomingQueue,
+  GroomingReason,
+  GroomingRules,
+  TaskRelation,
+  TaskRelationType,
+  TaskAuditEntry,
+  TaskChangeSource,
+  TaskSource,
+  TaskSourceType,
+  UndoneTaskChange,
+  ScheduleState,
+  ParsedDate,
+  TagSummary,
+  TaskStatusCategory,
+  TaskStatusDefinition,
   TimePreset,
 } from '../../../types';
 import { getDatabaseErrorMessage } from '../utils';
 import { TranslationKey } from '../../../i18n';
 
+// Precedes the server's copy of the task in conflict errors from update_task
+const CONFLICT_STATE_MARKER = 'Current state: ';
+
+/**
+ * Thrown when a task changed on the server after the client loaded it
+ */
+export class TaskConflictError extends Error {
+  constructor(
+    message: string,
+    public readonly currentTask: Task
+  ) {
+    super(message);
+    this.name = 'TaskConflictError';
+  }
+}
+
 export class TaskService {
   /**
    * Helper method to determine time preset from time estimate
@@ -35,7 +79,10 @@ export class TaskService {
   /**
    * Create a new task
    */
-  async create(request: CreateTaskRequest): Promise<Task> {
+  async create(
+    request: CreateTaskRequest,
+    source: TaskChangeSource = 'user'
+  ): Promise<Task> {
     try {
       // Map frontend camelCase to backend snake_case and serialize dates
       const serializedRequest = {
@@ -55,10 +102,13 @@ export class TaskService {
         periodic_template_id: request.periodicTemplateId,
         is_periodic_instance: request.isPeriodicInstance ?? false,
         generation_date: request.generationDate?.toISOString(),
+        effort: request.effort,
+        impact: request.impact,
       };
 
       const result = await invoke<Record<string, unknown>>('create_task', {
         request: serializedRequest,
+        source,
       });
       return this.transformTaskFromBackend(result);
     } catch (error) {
@@ -80,6 +130,22 @@ export class TaskService {
     }
   }
 
+  /**
+   * Create a task from a single line such as
+   * "Fix login bug #work !p1 @tomorrow": #tags, !p1 (urgent) to !p4 (low)
+   * and an @due date are read from the line, the rest is the title
+   */
+  async quickCapture(text: string): Promise<Task> {
+    try {
+      const result = await invoke<Record<string, unknown>>('quick_capture', {
+        text,
+      });
+      return this.transformTaskFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to capture task: ${error}`);
+    }
+  }
+
   /**
    * Find task by ID
    */
@@ -308,7 +374,11 @@ export class TaskService {
   /**
    * Update task
    */
-  async update(id: string, request: UpdateTaskRequest): Promise<Task> {
+  async update(
+    id: string,
+    request: UpdateTaskRequest,
+    source: TaskChangeSource = 'user'
+  ): Promise<Task> {
     try {
       // Map frontend camelCase to backend snake_case and serialize dates
       const serializedRequest: Record<string, unknown> = {};
@@ -356,13 +426,35 @@ export class TaskService {
       if (request.taskListId !== undefined) {
         serializedRequest.task_list_id = request.taskListId;
       }
+      if (request.effort !== undefined) {
+        serializedRequest.effort = request.effort;
+      }
+      if (request.impact !== undefined) {
+        serializedRequest.impact = request.impact;
+      }
+      if (request.expectedUpdatedAt !== undefined) {
+        serializedRequest.expected_updated_at =
+          request.expectedUpdatedAt.toISOString();
+      }
 
       const result = await invoke<Record<string, unknown>>('update_task', {
         id,
         request: serializedRequest,
+        source,
       });
       return this.transformTaskFromBackend(result);
     } catch (error) {
+      const message = String(error);
+      const stateIndex = message.indexOf(CONFLICT_STATE_MARKER);
+      if (message.includes('CONFLICT_ERROR') && stateIndex !== -1) {
+        const current = JSON.parse(
+          message.slice(stateIndex + CONFLICT_STATE_MARKER.length)
+        ) as Record<string, unknown>;
+        throw new TaskConflictError(
+          message,
+          this.transformTaskFromBackend(current)
+        );
+      }
       const errorMessage = getDatabaseErrorMessage(
         'taskService.error.updateFailed' as TranslationKey
       );
@@ -370,12 +462,59 @@ export class TaskService {
     }
   }
 
+  /**
+   * Atomically add minutes to a task's actual time (negative values subtract)
+   */
+  async addActualTime(id: string, minutes: number): Promise<Task> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'add_task_actual_time',
+        { id, minutes }
+      );
+      return this.transformTaskFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to add actual time: ${error}`);
+    }
+  }
+
+  /**
+   * Persist a new task order in one transaction; position in the array becomes the order
+   */
+  async reorder(taskIds: string[]): Promise<void> {
+    try {
+      await invoke('reorder_tasks', { taskIds });
+    } catch (error) {
+      throw new Error(`Failed to reorder tasks: ${error}`);
+    }
+  }
+
+  /**
+   * Move a task between the tasks directly above and below it (e.g. after a
+   * drag and drop); usually only the moved task is written
+   */
+  async reorderTask(
+    taskId: string,
+    beforeId?: string,
+    afterId?: string
+  ): Promise<Task> {
+    try {
+      const result = await invoke<Record<string, unknown>>('reorder_task', {
+        taskId,
+        beforeId: beforeId ?? null,
+        afterId: afterId ?? null,
+      });
+      return this.transformTaskFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to reorder task: ${error}`);
+    }
+  }
+
   /**
    * Delete task
    */
-  async delete(id: string): Promise<void> {
+  async delete(id: string, source: TaskChangeSource = 'user'): Promise<void> {
     try {
-      await invoke<string>('delete_task', { id });
+      await invoke<string>('delete_task', { id, source });
     } catch (error) {
       const errorMessage = getDatabaseErrorMessage(
         'taskService.error.deleteFailed' as TranslationKey
@@ -384,6 +523,49 @@ export class TaskService {
     }
   }
 
+  /**
+   * Archive task and its subtasks
+   */
+  async archive(id: string): Promise<Task> {
+    try {
+      const result = await invoke<Record<string, unknown>>('archive_task', {
+        id,
+      });
+      return this.transformTaskFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to archive task: ${error}`);
+    }
+  }
+
+  /**
+   * Restore an archived task and its subtasks
+   */
+  async unarchive(id: string): Promise<Task> {
+    try {
+      const result = await invoke<Record<string, unknown>>('unarchive_task', {
+        id,
+      });
+      return this.transformTaskFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to unarchive task: ${error}`);
+    }
+  }
+
+  /**
+   * Get archived tasks, optionally limited to one task list
+   */
+  async findArchived(taskListId?: string): Promise<Task[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_archived_tasks',
+        { taskListId }
+      );
+      return result.map(task => this.transformTaskFromBackend(task));
+    } catch (error) {
+      throw new Error(`Failed to get archived tasks: ${error}`);
+    }
+  }
+
   /**
    * Add task dependency
    */
@@ -446,6 +628,513 @@ export class TaskService {
     }
   }
 
+  /**
+   * Relate two tasks, read as "task <relationType> related task"
+   */
+  async addRelation(
+    taskId: string,
+    relatedId: string,
+    relationType: TaskRelationType
+  ): Promise<void> {
+    try {
+      await invoke('add_task_relation', { taskId, relatedId, relationType });
+    } catch (error) {
+      throw new Error(`Failed to add task relation: ${error}`);
+    }
+  }
+
+  /**
+   * Remove a relation between two tasks
+   */
+  async removeRelation(
+    taskId: string,
+    relatedId: string,
+    relationType: TaskRelationType
+  ): Promise<void> {
+    try {
+      await invoke('remove_task_relation', {
+        taskId,
+        relatedId,
+        relationType,
+      });
+    } catch (error) {
+      throw new Error(`Failed to remove task relation: ${error}`);
+    }
+  }
+
+  /**
+   * Get the tasks related to a task in either direction, optionally of one type
+   */
+  async getRelations(
+    taskId: string,
+    relationType?: TaskRelationType
+  ): Promise<TaskRelation[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_task_relations',
+        { taskId, relationType }
+      );
+      return result.map(relation => ({
+        id: relation.id as string,
+        relationType: relation.relation_type as TaskRelationType,
+        direction: relation.direction as TaskRelation['direction'],
+        task: this.transformTaskFromBackend(
+          relation.task as Record<string, unknown>
+        ),
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get task relations: ${error}`);
+    }
+  }
+
+  /**
+   * Get the recorded changes to a task, newest first
+   */
+  async getHistory(taskId: string): Promise<TaskAuditEntry[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_task_history',
+        { taskId }
+      );
+      return result.map(entry => this.transformAuditEntryFromBackend(entry));
+    } catch (error) {
+      throw new Error(`Failed to get task history: ${error}`);
+    }
+  }
+
+  /**
+   * Get where a task came from; null for tasks created in the app
+   */
+  async getSource(taskId: string): Promise<TaskSource | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'get_task_source',
+        { id: taskId }
+      );
+      return result ? this.transformSourceFromBackend(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to get task source: ${error}`);
+    }
+  }
+
+  /**
+   * Record where a task came from, e.g. for tasks created from an email or a
+   * deep link
+   */
+  async setSource(
+    taskId: string,
+    sourceType: TaskSourceType,
+    externalId?: string,
+    url?: string
+  ): Promise<TaskSource> {
+    try {
+      const result = await invoke<Record<string, unknown>>('set_task_source', {
+        request: {
+          task_id: taskId,
+          source_type: sourceType,
+          external_id: externalId,
+          url,
+        },
+      });
+      return this.transformSourceFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to set task source: ${error}`);
+    }
+  }
+
+  /**
+   * Undo the latest task change of this session; null when there is nothing to undo
+   */
+  async undoLastChange(): Promise<UndoneTaskChange | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'undo_last_change'
+      );
+      return result ? this.transformUndoneChangeFromBackend(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to undo change: ${error}`);
+    }
+  }
+
+  /**
+   * Redo the most recently undone change; null when there is nothing to redo
+   */
+  async redo(): Promise<UndoneTaskChange | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>('redo');
+      return result ? this.transformUndoneChangeFromBackend(result) : null;
+    } catch (error) {
+      throw new Error(`Failed to redo change: ${error}`);
+    }
+  }
+
+  /**
+   * Delete task history older than the retention period; returns entries removed
+   */
+  async cleanupHistory(olderThanDays: number): Promise<number> {
+    try {
+      return await invoke<number>('cleanup_task_audit_log', { olderThanDays });
+    } catch (error) {
+      throw new Error(`Failed to clean up task history: ${error}`);
+    }
+  }
+
+  /**
+   * Get the statuses tasks can be set to, in display order
+   */
+  async getStatuses(): Promise<TaskStatusDefinition[]> {
+    try {
+      const result =
+        await invoke<Record<string, unknown>[]>('get_task_statuses');
+      return result.map(status => this.transformStatusFromBackend(status));
+    } catch (error) {
+      throw new Error(`Failed to get task statuses: ${error}`);
+    }
+  }
+
+  /**
+   * Create a custom status
+   */
+  async createStatus(
+    name: string,
+    category: TaskStatusCategory,
+    color?: string
+  ): Promise<TaskStatusDefinition> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'create_task_status',
+        { request: { name, category, color, order_num: null } }
+      );
+      return this.transformStatusFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to create task status: ${error}`);
+    }
+  }
+
+  /**
+   * Update a status; built-in statuses only accept color and order changes
+   */
+  async updateStatus(
+    id: string,
+    updates: {
+      name?: string;
+      category?: TaskStatusCategory;
+      color?: string;
+      order?: number;
+    }
+  ): Promise<TaskStatusDefinition> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'update_task_status',
+        {
+          id,
+          request: {
+            name: updates.name,
+            category: updates.category,
+            color: updates.color,
+            order_num: updates.order,
+          },
+        }
+      );
+      return this.transformStatusFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to update task status: ${error}`);
+    }
+  }
+
+  /**
+   * Delete a custom status, moving its tasks to another status
+   */
+  async deleteStatus(id: string, replacementStatus?: string): Promise<void> {
+    try {
+      await invoke<string>('delete_task_status', { id, replacementStatus });
+    } catch (error) {
+      throw new Error(`Failed to delete task status: ${error}`);
+    }
+  }
+
+  /**
+   * Check the whole dependency graph for cycles and dangling references
+   */
+  async validateDependencyGraph(): Promise<DependencyGraphReport> {
+    try {
+      const report = await invoke<Record<string, unknown>>(
+        'validate_dependency_graph'
+      );
+      const cycles = report.cycles as {
+        task_ids: string[];
+        titles: string[];
+      }[];
+      return {
+        taskCount: report.task_count as number,
+        dependencyCount: report.dependency_count as number,
+        isValid: report.is_valid as boolean,
+        cycles: cycles.map(cycle => ({
+          taskIds: cycle.task_ids,
+          titles: cycle.titles,
+        })),
+        danglingDependencies: report.dangling_dependencies as [
+          string,
+          string,
+        ][],
+      };
+    } catch (error) {
+      throw new Error(`Failed to validate dependency graph: ${error}`);
+    }
+  }
+
+  /**
+   * Compute readiness, earliest start dates and the critical path of open tasks
+   */
+  async getDependencySchedule(
+    taskListId?: string
+  ): Promise<DependencySchedule> {
+    try {
+      const schedule = await invoke<Record<string, unknown>>(
+        'get_dependency_schedule',
+        { taskListId }
+      );
+      const toDate = (value: unknown) =>
+        value ? new Date(value as string) : undefined;
+      const tasks = schedule.tasks as Record<string, unknown>[];
+      return {
+        generatedAt: new Date(schedule.generated_at as string),
+        tasks: tasks.map(task => ({
+          taskId: task.task_id as string,
+          title: task.title as string,
+          state: task.state as ScheduleState,
+          blockedBy: task.blocked_by as string[],
+          durationMinutes: task.duration_minutes as number,
+          earliestStart: toDate(task.earliest_start),
+          earliestFinish: toDate(task.earliest_finish),
+          slackMinutes: (task.slack_minutes as number | null) ?? undefined,
+          isCritical: task.is_critical as boolean,
+        })),
+        criticalPath: schedule.critical_path as string[],
+        criticalPathMinutes: schedule.critical_path_minutes as number,
+        projectedFinish: toDate(schedule.projected_finish),
+      };
+    } catch (error) {
+      throw new Error(`Failed to compute dependency schedule: ${error}`);
+    }
+  }
+
+  /**
+   * Compare scheduled work per day (from startDate, YYYY-MM-DD) with the working
+   * time available each day, and suggest moves for overloaded days. Capacity
+   * defaults to the length of the working day; days off have none.
+   */
+  async getWorkloadReport(
+    startDate: string,
+    days: number,
+    capacityMinutes?: number,
+    taskListId?: string
+  ): Promise<WorkloadReport> {
+    try {
+      const report = await invoke<Record<string, unknown>>(
+        'get_workload_report',
+        { startDate, days, capacityMinutes, taskListId }
+      );
+      const workloadDays = report.days as Record<string, unknown>[];
+      const moves = report.suggested_moves as Record<string, unknown>[];
+      return {
+        generatedAt: new Date(report.generated_at as string),
+        days: workloadDays.map(day => ({
+          date: day.date as string,
+          capacityMinutes: day.capacity_minutes as number,
+          scheduledMinutes: day.scheduled_minutes as number,
+          balancedMinutes: day.balanced_minutes as number,
+          taskIds: day.task_ids as string[],
+          overloaded: day.overloaded as boolean,
+        })),
+        suggestedMoves: moves.map(move => ({
+          taskId: move.task_id as string,
+          title: move.title as string,
+          fromDate: move.from_date as string,
+          toDate: move.to_date as string,
+          durationMinutes: move.duration_minutes as number,
+        })),
+        unresolvedDays: report.unresolved_days as string[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to compute workload report: ${error}`);
+    }
+  }
+
+  /**
+   * Propose a schedule for the rest of today from the working hours, task
+   * priorities, estimates and dependencies
+   */
+  async generateDailyPlan(taskListId?: string): Promise<DailyPlan> {
+    try {
+      const plan = await invoke<Record<string, unknown>>(
+        'generate_daily_plan',
+        { taskListId }
+      );
+      const blocks = plan.blocks as Record<string, unknown>[];
+      const deferred = plan.deferred as Record<string, unknown>[];
+      return {
+        date: plan.date as string,
+        generatedAt: new Date(plan.generated_at as string),
+        isWorkday: plan.is_workday as boolean,
+        availableMinutes: plan.available_minutes as number,
+        plannedMinutes: plan.planned_minutes as number,
+        blocks: blocks.map(block => ({
+          taskId: block.task_id as string,
+          title: block.title as string,
+          start: new Date(block.start as string),
+          end: new Date(block.end as string),
+          durationMinutes: block.duration_minutes as number,
+          priority: block.priority as Priority,
+          reason: block.reason as PlanReason,
+        })),
+        deferred: deferred.map(task => ({
+          taskId: task.task_id as string,
+          title: task.title as string,
+          reason: task.reason as DeferReason,
+        })),
+      };
+    } catch (error) {
+      throw new Error(`Failed to generate daily plan: ${error}`);
+    }
+  }
+
+  /**
+   * Listen for unfinished tasks being rolled over from past days
+   */
+  onTasksRolledOver(
+    callback: (report: RolloverReport) => void
+  ): Promise<UnlistenFn> {
+    return listen<Record<string, unknown>>('tasks-rolled-over', event =>
+      callback({
+        mode: event.payload.mode as RolloverMode,
+        date: event.payload.date as string,
+        rolledOver: event.payload.rolled_over as number,
+        taskIds: event.payload.task_ids as string[],
+      })
+    );
+  }
+
+  /**
+   * Compare estimates with the time actually spent on the tasks completed
+   * in a period, by tag, list, priority and month
+   */
+  async getEstimationReport(
+    startDate: Date,
+    endDate: Date
+  ): Promise<EstimationReport> {
+    try {
+      const report = await invoke<Record<string, unknown>>(
+        'get_estimation_report',
+        {
+          startDate: startDate.toISOString(),
+          endDate: endDate.toISOString(),
+        }
+      );
+      const toAccuracy = (
+        accuracy: Record<string, unknown>
+      ): EstimationAccuracy => ({
+        taskCount: accuracy.task_count as number,
+        estimatedMinutes: accuracy.estimated_minutes as number,
+        actualMinutes: accuracy.actual_minutes as number,
+        ratio: accuracy.ratio as number,
+        accurateCount: accuracy.accurate_count as number,
+        underestimatedCount: accuracy.underestimated_count as number,
+        overestimatedCount: accuracy.overestimated_count as number,
+        chronicUnderestimation: accuracy.chronic_underestimation as boolean,
+      });
+      const toGroups = (groups: unknown): EstimationGroup[] =>
+        (groups as Record<string, unknown>[]).map(group => ({
+          ...toAccuracy(group),
+          key: group.key as string,
+          label: group.label as string,
+        }));
+      return {
+        generatedAt: new Date(report.generated_at as string),
+        startDate: new Date(report.start_date as string),
+        endDate: new Date(report.end_date as string),
+        overall: toAccuracy(report.overall as Record<string, unknown>),
+        byTag: toGroups(report.by_tag),
+        byTaskList: toGroups(report.by_task_list),
+        byPriority: toGroups(report.by_priority),
+        byMonth: toGroups(report.by_month),
+      };
+    } catch (error) {
+      throw new Error(`Failed to compute estimation report: ${error}`);
+    }
+  }
+
+  /**
+   * Rank backlog tasks that are stale, high priority or blocking others.
+   * With createReviewTask, the queue is also written to a weekly
+   * "Review backlog" task.
+   */
+  async groomBacklog(
+    rules: GroomingRules = {},
+    createReviewTask = false
+  ): Promise<GroomingQueue> {
+    try {
+      const queue = await invoke<Record<string, unknown>>('groom_backlog', {
+        rules: {
+          min_age_days: rules.minAgeDays,
+          min_priority: rules.minPriority,
+          include_blocking: rules.includeBlocking,
+          task_list_id: rules.taskListId,
+          limit: rules.limit,
+        },
+        createReviewTask,
+      });
+      const items = queue.items as Record<string, unknown>[];
+      return {
+        generatedAt: new Date(queue.generated_at as string),
+        backlogCount: queue.backlog_count as number,
+        items: items.map(item => ({
+          task: this.transformTaskFromBackend(
+            item.task as Record<string, unknown>
+          ),
+          reasons: (item.reasons as Record<string, unknown>[]).map(
+            (reason): GroomingReason => {
+              switch (reason.kind) {
+                case 'stale':
+                  return { kind: 'stale', ageDays: reason.age_days as number };
+                case 'high_priority':
+                  return {
+                    kind: 'high_priority',
+                    priority: reason.priority as number,
+                  };
+                default:
+                  return {
+                    kind: 'blocking',
+                    dependentCount: reason.dependent_count as number,
+                  };
+              }
+            }
+          ),
+          score: item.score as number,
+        })),
+        reviewTemplateId:
+          (queue.review_template_id as string | null) ?? undefined,
+      };
+    } catch (error) {
+      throw new Error(`Failed to groom backlog: ${error}`);
+    }
+  }
+
+  /**
+   * Set custom field values on a task, addressing fields by name.
+   * A null value clears the field.
+   */
+  async setCustomFieldValues(
+    taskId: string,
+    values: Record<string, string | null>
+  ): Promise<void> {
+    try {
+      await invoke('set_task_custom_field_values', { taskId, values });
+    } catch (error) {
+      throw new Error(`Failed to set custom field values: ${error}`);
+    }
+  }
+
   /**
    * Search tasks
    */
@@ -460,6 +1149,80 @@ export class TaskService {
     }
   }
 
+  /**
+   * Read a date from a phrase such as "next friday", or null if none is found
+   */
+  async parseNaturalDate(text: string): Promise<ParsedDate | null> {
+    try {
+      const result = await invoke<Record<string, unknown> | null>(
+        'parse_natural_date',
+        { text }
+      );
+      return result
+        ? {
+            date: new Date(result.date as string),
+            matched: result.matched as string,
+            hasTime: result.has_time as boolean,
+          }
+        : null;
+    } catch (error) {
+      throw new Error(`Failed to parse date: ${error}`);
+    }
+  }
+
+  /**
+   * Find tasks carrying any of the tags, or all of them when matchAll is set
+   */
+  async findByTags(tags: string[], matchAll = false): Promise<Task[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_tasks_by_tags',
+        { tags, matchAll }
+      );
+      return result.map(task => this.transformTaskFromBackend(task));
+    } catch (error) {
+      throw new Error(`Failed to get tasks by tags: ${error}`);
+    }
+  }
+
+  /**
+   * Get all tags with the number of tasks using them
+   */
+  async getAllTags(): Promise<TagSummary[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>('get_all_tags');
+      return result.map(tag => ({
+        id: tag.id as string,
+        name: tag.name as string,
+        taskCount: tag.task_count as number,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get tags: ${error}`);
+    }
+  }
+
+  /**
+   * Rename a tag on every task carrying it
+   */
+  async renameTag(oldName: string, newName: string): Promise<void> {
+    try {
+      await invoke('rename_tag', { oldName, newName });
+    } catch (error) {
+      throw new Error(`Failed to rename tag: ${error}`);
+    }
+  }
+
+  /**
+   * Merge several tags into one, creating the target tag if needed
+   */
+  async mergeTags(sourceTags: string[], targetTag: string): Promise<void> {
+    try {
+      await invoke('merge_tags', { sourceTags, targetTag });
+    } catch (error) {
+      throw new Error(`Failed to merge tags: ${error}`);
+    }
+  }
+
   /**
    * Get task statistics
    */
@@ -539,10 +1302,74 @@ export class TaskService {
     }
   }
 
+  /**
+   * Transform an undone or redone change from backend format to frontend format
+   */
+  private transformUndoneChangeFromBackend(
+    change: Record<string, unknown>
+  ): UndoneTaskChange {
+    const entries = change.entries as Record<string, unknown>[];
+    return {
+      changeId: change.change_id as string,
+      entries: entries.map(entry => this.transformAuditEntryFromBackend(entry)),
+    };
+  }
+
+  /**
+   * Transform a task history entry from backend format to frontend format
+   */
+  private transformAuditEntryFromBackend(
+    entry: Record<string, unknown>
+  ): TaskAuditEntry {
+    return {
+      id: entry.id as string,
+      taskId: entry.task_id as string,
+      action: entry.action as TaskAuditEntry['action'],
+      field: (entry.field as string) || undefined,
+      oldValue: (entry.old_value as string) || undefined,
+      newValue: (entry.new_value as string) || undefined,
+      source: entry.source as TaskChangeSource,
+      createdAt: new Date(entry.created_at as string),
+    };
+  }
+
+  /**
+   * Transform a task source from backend format to frontend format
+   */
+  private transformSourceFromBackend(
+    source: Record<string, unknown>
+  ): TaskSource {
+    return {
+      taskId: source.task_id as string,
+      sourceType: source.source_type as TaskSourceType,
+      externalId: (source.external_id as string) || undefined,
+      url: (source.url as string) || undefined,
+      createdAt: new Date(source.created_at as string),
+    };
+  }
+
+  /**
+   * Transform status data from backend format to frontend format
+   */
+  private transformStatusFromBackend(
+    backendStatus: Record<string, unknown>
+  ): TaskStatusDefinition {
+    return {
+      id: backendStatus.id as string,
+      name: backendStatus.name as string,
+      category: backendStatus.category as TaskStatusCategory,
+      color: (backendStatus.color as string | null) ?? undefined,
+      order: backendStatus.order_num as number,
+      isSystem: backendStatus.is_system as boolean,
+      createdAt: new Date(backendStatus.created_at as string),
+      updatedAt: new Date(backendStatus.updated_at as string),
+    };
+  }
+
   /**
    * Transform task data from backend format to frontend format
    */
-  private transformTaskFromBackend(backendTask: Record<string, unknown>): Task {
+  transformTaskFromBackend(backendTask: Record<string, unknown>): Task {
     return {
       id: backendTask.id as string,
       title: backendTask.title as string,
@@ -579,9 +1406,15 @@ export class TaskService {
       generationDate: backendTask.generation_date
         ? new Date(backendTask.generation_date as string)
         : undefined,
+      effort: (backendTask.effort as number | null) ?? undefined,
+      impact: (backendTask.impact as number | null) ?? undefined,
       completedAt: backendTask.completed_at
         ? new Date(backendTask.completed_at as string)
         : undefined,
+      archivedAt: backendTask.archived_at
+        ? new Date(backendTask.archived_at as string)
+        : undefined,
+      rescheduleCount: (backendTask.reschedule_count as number) || 0,
       createdAt: new Date(backendTask.created_at as string),
       updatedAt: new Date(backendTask.updated_at as string),
     };
@@ -7,7 +7,12 @@ import {
   UpdateThreadRequest,
   CreateThreadMessageRequest,
   ThreadAssignment,
+  SimilarThread,
+  CreatedThread,
+  MessageActionResults,
+  Task,
 } from '../../../types';
+import { TaskService } from './TaskService';
 import { getDatabaseErrorMessage } from '../utils';
 import { TranslationKey } from '../../../i18n';
 import {
@@ -16,21 +21,15 @@ import {
 } from '../../../utils/threadTitleUtils';
 
 export class ThreadService {
+  private taskService = new TaskService();
+
   /**
    * Create a new thread
    */
   async create(request: CreateThreadRequest): Promise<Thread> {
     try {
-      // Map frontend camelCase to backend snake_case and serialize data
-      const serializedRequest = {
-        assignment_type: request.assignment?.type,
-        assignment_task_id: request.assignment?.taskId,
-        assignment_date: request.assignment?.date?.toISOString(),
-        assignment_context: request.assignment?.context,
-      };
-
       const result = await invoke<Record<string, unknown>>('create_thread', {
-        request: serializedRequest,
+        request: this.serializeCreateRequest(request),
       });
       return this.transformThreadFromBackend(result);
     } catch (error) {
@@ -39,6 +38,57 @@ export class ThreadService {
     }
   }
 
+  /**
+   * Create a new thread and find past threads about the same thing, so the
+   * assistant can resume one instead. `text` is what the conversation is
+   * about; without it, a task thread is compared by the task's title and
+   * description
+   */
+  async createWithSimilar(
+    request: CreateThreadRequest,
+    text?: string
+  ): Promise<CreatedThread> {
+    try {
+      const result = await invoke<{
+        thread: Record<string, unknown>;
+        similar_threads: Record<string, unknown>[];
+      }>('create_thread_with_similar', {
+        request: this.serializeCreateRequest(request),
+        text,
+      });
+      return {
+        thread: this.transformThreadFromBackend(result.thread),
+        similarThreads: result.similar_threads.map(similar =>
+          this.transformSimilarThreadFromBackend(similar)
+        ),
+      };
+    } catch (error) {
+      console.error('Thread creation failed with error:', error);
+      throw new Error(`Failed to create thread: ${error}`);
+    }
+  }
+
+  /**
+   * Find past threads closest in meaning to `text`, best match first
+   */
+  async findSimilar(
+    text: string,
+    excludeThreadId?: string,
+    limit?: number
+  ): Promise<SimilarThread[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_similar_threads',
+        { text, excludeThreadId, limit }
+      );
+      return result.map(similar =>
+        this.transformSimilarThreadFromBackend(similar)
+      );
+    } catch (error) {
+      throw this.handleDatabaseError(error, 'find similar threads');
+    }
+  }
+
   /**
    * Find thread by ID
    */
@@ -148,6 +198,49 @@ export class ThreadService {
     }
   }
 
+  /**
+   * Choose the persona the assistant answers as in a thread; null goes back
+   * to plain Kira
+   */
+  async setPersona(
+    threadId: string,
+    personaId: string | null
+  ): Promise<Thread> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'set_thread_persona',
+        { threadId, personaId }
+      );
+      return this.transformThreadFromBackend(result);
+    } catch (error) {
+      throw this.handleDatabaseError(error, 'set thread persona');
+    }
+  }
+
+  /**
+   * Store a thread's rolling summary, covering its messages up to
+   * `summarizedUntil`
+   */
+  async updateSummary(
+    threadId: string,
+    summary: string,
+    summarizedUntil: Date
+  ): Promise<Thread> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'update_thread_summary',
+        {
+          id: threadId,
+          summary,
+          summarizedUntil: summarizedUntil.toISOString(),
+        }
+      );
+      return this.transformThreadFromBackend(result);
+    } catch (error) {
+      throw this.handleDatabaseError(error, 'update thread summary');
+    }
+  }
+
   /**
    * Create a thread message
    */
@@ -164,6 +257,9 @@ export class ThreadService {
         actions: request.actions,
         suggestions: request.suggestions,
         tool_executions: request.toolExecutions,
+        action_results: request.actionResults
+          ? this.serializeActionResults(request.actionResults)
+          : undefined,
         timestamp: new Date().toISOString(),
       };
 
@@ -207,6 +303,66 @@ export class ThreadService {
     }
   }
 
+  /**
+   * Find a page of a thread's messages, oldest first. Pass the first message
+   * of a page as `beforeId` to load the page above it.
+   */
+  async findMessagesPage(
+    threadId: string,
+    limit: number,
+    beforeId?: string
+  ): Promise<ThreadMessage[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_thread_messages',
+        { threadId, limit, before: beforeId ?? null }
+      );
+      return result.map(message =>
+        this.transformThreadMessageFromBackend(message)
+      );
+    } catch (error) {
+      throw this.handleDatabaseError(error, 'load thread messages');
+    }
+  }
+
+  /**
+   * Search message contents, in one thread or in all of them, newest first
+   */
+  async searchMessages(
+    query: string,
+    threadId?: string
+  ): Promise<ThreadMessage[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'search_thread_messages',
+        { query, threadId: threadId ?? null }
+      );
+      return result.map(message =>
+        this.transformThreadMessageFromBackend(message)
+      );
+    } catch (error) {
+      throw this.handleDatabaseError(error, 'search thread messages');
+    }
+  }
+
+  /**
+   * Find the tasks the assistant created in a thread, in the order they were
+   * created
+   */
+  async findCreatedTasks(threadId: string): Promise<Task[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_thread_created_tasks',
+        { threadId }
+      );
+      return result.map(task =>
+        this.taskService.transformTaskFromBackend(task)
+      );
+    } catch (error) {
+      throw this.handleDatabaseError(error, 'load tasks created in thread');
+    }
+  }
+
   /**
    * Find a specific message by ID
    */
@@ -299,6 +455,30 @@ export class ThreadService {
   /**
    * Transform thread data from backend format to frontend format
    */
+  /**
+   * Map frontend camelCase to backend snake_case and serialize data
+   */
+  private serializeCreateRequest(request: CreateThreadRequest) {
+    return {
+      assignment_type: request.assignment?.type,
+      assignment_task_id: request.assignment?.taskId,
+      assignment_date: request.assignment?.date?.toISOString(),
+      assignment_context: request.assignment?.context,
+    };
+  }
+
+  private transformSimilarThreadFromBackend(
+    backendSimilar: Record<string, unknown>
+  ): SimilarThread {
+    return {
+      thread: this.transformThreadFromBackend(
+        backendSimilar.thread as Record<string, unknown>
+      ),
+      similarity: backendSimilar.similarity as number,
+      preview: (backendSimilar.preview as string) || undefined,
+    };
+  }
+
   private transformThreadFromBackend(
     backendThread: Record<string, unknown>
   ): Thread {
@@ -321,6 +501,11 @@ export class ThreadService {
       id: backendThread.id as string,
       title: backendThread.title as string,
       assignment,
+      personaId: (backendThread.persona_id as string | null) ?? undefined,
+      summary: (backendThread.summary as string | null) ?? undefined,
+      summarizedUntil: backendThread.summarized_until
+        ? new Date(backendThread.summarized_until as string)
+        : undefined,
       messageCount: (backendThread.message_count as number) || 0,
       lastMessageAt: backendThread.last_message_at
         ? new Date(backendThread.last_message_at as string)
@@ -351,6 +536,9 @@ export class ThreadService {
         backendMessage.tool_executions as string | null,
         []
       ),
+      actionResults: this.parseActionResults(
+        backendMessage.action_results as string | null
+      ),
       timestamp: new Date(backendMessage.timestamp as string),
       userFeedback: this.parseJsonField(
         backendMessage.user_feedback as string | null,
@@ -359,6 +547,41 @@ export class ThreadService {
     };
   }
 
+  private serializeActionResults(results: MessageActionResults) {
+    return {
+      tool_calls: results.toolCalls,
+      created_entities: results.createdEntities.map(entity => ({
+        entity_type: entity.entityType,
+        id: entity.id,
+        title: entity.title,
+      })),
+      links: results.links,
+    };
+  }
+
+  private parseActionResults(
+    value: string | null
+  ): MessageActionResults | undefined {
+    const parsed = this.parseJsonField<Record<string, unknown> | undefined>(
+      value,
+      undefined
+    );
+    if (!parsed) {
+      return undefined;
+    }
+    const entities =
+      (parsed.created_entities as Record<string, unknown>[] | undefined) ?? [];
+    return {
+      toolCalls: (parsed.tool_calls ?? []) as MessageActionResults['toolCalls'],
+      createdEntities: entities.map(entity => ({
+        entityType: entity.entity_type as string,
+        id: entity.id as string,
+        title: (entity.title as string | null) ?? undefined,
+      })),
+      links: (parsed.links ?? []) as MessageActionResults['links'],
+    };
+  }
+
   /**
    * Update thread title if needed (for auto-generation from first message)
    */
@@ -0,0 +1,82 @@
+// Time tracking import service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  TimeImportEntry,
+  TimeImportPreview,
+  TimeImportResult,
+  TimeImportSource,
+} from '../../../types';
+
+export class TimeImportService {
+  /**
+   * Read a Toggl or Clockify CSV export and match its entries to existing
+   * tasks by title without changing anything
+   */
+  async preview(
+    filePath: string,
+    source: TimeImportSource
+  ): Promise<TimeImportPreview> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'preview_time_import',
+        { filePath, source }
+      );
+      const entries = result.entries as Record<string, unknown>[];
+      return {
+        source: result.source as TimeImportSource,
+        entries: entries.map(entry => this.transformEntryFromBackend(entry)),
+        matchedCount: result.matched_count as number,
+        newTaskTitles: result.new_task_titles as string[],
+        alreadyImportedCount: result.already_imported_count as number,
+        errors: result.errors as TimeImportResult['errors'],
+      };
+    } catch (error) {
+      throw new Error(`Failed to preview time import: ${error}`);
+    }
+  }
+
+  /**
+   * Log the entries of an export as time sessions, creating tasks that don't
+   * exist; entries imported before are skipped
+   */
+  async import(
+    filePath: string,
+    source: TimeImportSource,
+    taskListId?: string
+  ): Promise<TimeImportResult> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'import_time_entries',
+        { filePath, source, taskListId }
+      );
+      return {
+        createdTaskIds: result.created_task_ids as string[],
+        sessionIds: result.session_ids as string[],
+        skippedEntries: result.skipped_entries as number,
+        errors: result.errors as TimeImportResult['errors'],
+      };
+    } catch (error) {
+      throw new Error(`Failed to import time entries: ${error}`);
+    }
+  }
+
+  /**
+   * Transform an import entry from backend format to frontend format
+   */
+  private transformEntryFromBackend(
+    entry: Record<string, unknown>
+  ): TimeImportEntry {
+    return {
+      line: entry.line as number,
+      taskTitle: entry.task_title as string,
+      project: (entry.project as string) || undefined,
+      description: (entry.description as string) || undefined,
+      tags: entry.tags as string[],
+      startTime: new Date(entry.start_time as string),
+      endTime: new Date(entry.end_time as string),
+      durationMinutes: entry.duration_minutes as number,
+      taskId: (entry.task_id as string) || undefined,
+      alreadyImported: entry.already_imported as boolean,
+    };
+  }
+}
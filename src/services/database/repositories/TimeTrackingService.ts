@@ -1,19 +1,41 @@
 // Time tracking service that interfaces with Tauri commands (SeaORM backend)
 import { invoke } from '@tauri-apps/api/core';
-import { TimerSession, CompletedSession, TimerBreak } from '../../../types';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import {
+  TimerSession,
+  CompletedSession,
+  TimerBreak,
+  SessionSubtaskCompletion,
+  PauseReason,
+  PauseStats,
+  SessionPause,
+  InterruptionReport,
+  IdleSegment,
+  BillingGroupBy,
+  BillingReport,
+  DailyTimeAggregate,
+} from '../../../types';
 import { getDatabaseErrorMessage } from '../utils';
 import { TranslationKey } from '../../../i18n';
 
 export class TimeTrackingService {
   /**
    * Start a new timer session
+   *
+   * Only one session runs at a time: with `autoSwitch` the running session
+   * is stopped, otherwise starting while another session runs fails.
    */
-  async startSession(taskId: string, notes?: string): Promise<TimerSession> {
+  async startSession(
+    taskId: string,
+    notes?: string,
+    autoSwitch = false
+  ): Promise<TimerSession> {
     try {
       const request = {
         task_id: taskId,
         start_time: new Date().toISOString(),
         notes: notes || undefined,
+        auto_switch: autoSwitch,
       };
 
       const result = await invoke<Record<string, unknown>>(
@@ -40,32 +62,26 @@ export class TimeTrackingService {
     pausedTime?: number
   ): Promise<TimerSession> {
     try {
-      // Create the session with custom start time
-      const createRequest = {
-        task_id: taskId,
-        start_time: startTime.toISOString(),
-        notes: notes || undefined,
-      };
-
+      // Log the finished session directly so it never counts as running
       const result = await invoke<Record<string, unknown>>(
-        'create_time_session',
-        { request: createRequest }
+        'log_time_session',
+        {
+          taskId,
+          startTime: startTime.toISOString(),
+          endTime: endTime.toISOString(),
+          notes: notes || undefined,
+        }
       );
       const session = this.transformSessionFromBackend(result);
-
-      // Update it with the end time and paused time to make it a completed session
-      const updateRequest = {
-        end_time: endTime.toISOString(),
-        paused_time: pausedTime || 0,
-        is_active: false,
-        notes: notes || undefined,
-      };
+      if (!pausedTime) {
+        return session;
+      }
 
       const updatedResult = await invoke<Record<string, unknown>>(
         'update_time_session',
         {
           id: session.id,
-          request: updateRequest,
+          request: { paused_time: pausedTime },
         }
       );
 
@@ -79,13 +95,103 @@ export class TimeTrackingService {
   }
 
   /**
-   * Pause a timer session
+   * Record time worked away from the timer; the times may not overlap
+   * another session or lie in the future
+   */
+  async addManualEntry(
+    taskId: string,
+    start: Date,
+    end: Date,
+    notes?: string
+  ): Promise<TimerSession> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'add_manual_time_entry',
+        {
+          taskId,
+          start: start.toISOString(),
+          end: end.toISOString(),
+          notes: notes || undefined,
+        }
+      );
+      return this.transformSessionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to add time entry: ${error}`);
+    }
+  }
+
+  /**
+   * Move the start or end of a session; a running session keeps its end open
+   */
+  async adjustSessionTimes(
+    sessionId: string,
+    startTime?: Date,
+    endTime?: Date
+  ): Promise<TimerSession> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'adjust_time_session',
+        {
+          id: sessionId,
+          startTime: startTime?.toISOString(),
+          endTime: endTime?.toISOString(),
+        }
+      );
+      return this.transformSessionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to adjust session times: ${error}`);
+    }
+  }
+
+  /**
+   * Split a finished session at `at`, optionally giving the later part to
+   * another task; resolves to the earlier and the later part
+   */
+  async splitSession(
+    sessionId: string,
+    at: Date,
+    taskId?: string
+  ): Promise<[TimerSession, TimerSession]> {
+    try {
+      const [earlier, later] = await invoke<Record<string, unknown>[]>(
+        'split_time_session',
+        { id: sessionId, at: at.toISOString(), taskId }
+      );
+      return [
+        this.transformSessionFromBackend(earlier),
+        this.transformSessionFromBackend(later),
+      ];
+    } catch (error) {
+      throw new Error(`Failed to split session: ${error}`);
+    }
+  }
+
+  /**
+   * Merge adjacent finished sessions of one task into the earliest of them
    */
-  async pauseSession(sessionId: string): Promise<TimerSession> {
+  async mergeSessions(sessionIds: string[]): Promise<TimerSession> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'merge_time_sessions',
+        { ids: sessionIds }
+      );
+      return this.transformSessionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to merge sessions: ${error}`);
+    }
+  }
+
+  /**
+   * Pause a timer session, optionally recording why
+   */
+  async pauseSession(
+    sessionId: string,
+    reason?: PauseReason
+  ): Promise<TimerSession> {
     try {
       const result = await invoke<Record<string, unknown>>(
         'pause_time_session',
-        { id: sessionId }
+        { id: sessionId, reason }
       );
       return this.transformSessionFromBackend(result);
     } catch (error) {
@@ -94,13 +200,17 @@ export class TimeTrackingService {
   }
 
   /**
-   * Resume a timer session
+   * Resume a timer session; with `autoSwitch` another running session is
+   * stopped first
    */
-  async resumeSession(sessionId: string): Promise<TimerSession> {
+  async resumeSession(
+    sessionId: string,
+    autoSwitch = false
+  ): Promise<TimerSession> {
     try {
       const result = await invoke<Record<string, unknown>>(
         'resume_time_session',
-        { id: sessionId }
+        { id: sessionId, autoSwitch }
       );
       return this.transformSessionFromBackend(result);
     } catch (error) {
@@ -108,6 +218,274 @@ export class TimeTrackingService {
     }
   }
 
+  /**
+   * Start a break in a running session; break time is not counted as work
+   */
+  async startBreak(sessionId: string, reason?: string): Promise<TimerSession> {
+    try {
+      const result = await invoke<Record<string, unknown>>('start_break', {
+        sessionId,
+        reason,
+      });
+      return this.transformSessionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to start break: ${error}`);
+    }
+  }
+
+  /**
+   * End the running break of a session
+   */
+  async endBreak(sessionId: string): Promise<TimerSession> {
+    try {
+      const result = await invoke<Record<string, unknown>>('end_break', {
+        sessionId,
+      });
+      return this.transformSessionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to end break: ${error}`);
+    }
+  }
+
+  /**
+   * Ask about a running session after `minutes` without keyboard or mouse
+   * input; 0 turns idle detection off
+   */
+  async setIdleThreshold(minutes: number): Promise<void> {
+    try {
+      await invoke('set_idle_threshold', { minutes });
+    } catch (error) {
+      throw new Error(`Failed to set idle threshold: ${error}`);
+    }
+  }
+
+  /**
+   * Listen for the user coming back after being idle during a session
+   */
+  onIdleEnded(callback: (segment: IdleSegment) => void): Promise<UnlistenFn> {
+    return listen<Record<string, unknown>>('idle-ended', event =>
+      callback({
+        sessionId: event.payload.session_id as string,
+        startedAt: new Date(event.payload.started_at as string),
+        endedAt: event.payload.ended_at
+          ? new Date(event.payload.ended_at as string)
+          : undefined,
+      })
+    );
+  }
+
+  /**
+   * Leave idle time out of a session, recording it as an idle pause
+   */
+  async discardIdleTime(segment: IdleSegment): Promise<TimerSession> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'discard_idle_time',
+        {
+          sessionId: segment.sessionId,
+          startedAt: segment.startedAt.toISOString(),
+          endedAt: (segment.endedAt ?? new Date()).toISOString(),
+        }
+      );
+      return this.transformSessionFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to discard idle time: ${error}`);
+    }
+  }
+
+  /**
+   * Get the pauses of a session, earliest first
+   */
+  async getSessionPauses(sessionId: string): Promise<SessionPause[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_session_pauses',
+        { sessionId }
+      );
+      return result.map(pause => ({
+        id: pause.id as string,
+        sessionId: pause.session_id as string,
+        reason: (pause.reason as SessionPause['reason']) || undefined,
+        startedAt: new Date(pause.started_at as string),
+        endedAt: pause.ended_at
+          ? new Date(pause.ended_at as string)
+          : undefined,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get session pauses: ${error}`);
+    }
+  }
+
+  /**
+   * Pause and interruption analytics for pauses started in a date range
+   */
+  async getInterruptionReport(
+    startDate: Date,
+    endDate: Date
+  ): Promise<InterruptionReport> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'get_interruption_report',
+        {
+          startDate: startDate.toISOString(),
+          endDate: endDate.toISOString(),
+        }
+      );
+      const stats = (value: Record<string, unknown>): PauseStats => ({
+        pauseCount: value.pause_count as number,
+        pauseMinutes: value.pause_minutes as number,
+        interruptionCount: value.interruption_count as number,
+        interruptionMinutes: value.interruption_minutes as number,
+      });
+      return {
+        ...stats(result),
+        startDate: new Date(result.start_date as string),
+        endDate: new Date(result.end_date as string),
+        byReason: (result.by_reason as Record<string, unknown>[]).map(
+          entry => ({
+            reason: (entry.reason as PauseReason) || undefined,
+            count: entry.count as number,
+            minutes: entry.minutes as number,
+          })
+        ),
+        byDay: (result.by_day as Record<string, unknown>[]).map(day => ({
+          ...stats(day),
+          date: day.date as string,
+        })),
+        byTaskList: (result.by_task_list as Record<string, unknown>[]).map(
+          list => ({
+            ...stats(list),
+            taskListId: (list.task_list_id as string) || undefined,
+          })
+        ),
+      };
+    } catch (error) {
+      throw new Error(`Failed to get interruption report: ${error}`);
+    }
+  }
+
+  /**
+   * Work, sessions and completed tasks per day, for a calendar heatmap; days
+   * without activity are left out
+   */
+  async getDailyAggregates(
+    start: Date,
+    end: Date
+  ): Promise<DailyTimeAggregate[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_daily_time_aggregates',
+        { start: start.toISOString(), end: end.toISOString() }
+      );
+      return result.map(day => ({
+        date: day.date as string,
+        workMinutes: day.work_minutes as number,
+        sessionCount: day.session_count as number,
+        completedTasks: day.completed_tasks as number,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get daily time aggregates: ${error}`);
+    }
+  }
+
+  /**
+   * Billable work in a date range on task lists with an hourly rate
+   */
+  async getBillingReport(
+    startDate: Date,
+    endDate: Date,
+    groupBy: BillingGroupBy
+  ): Promise<BillingReport> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'get_billing_report',
+        {
+          startDate: startDate.toISOString(),
+          endDate: endDate.toISOString(),
+          groupBy,
+        }
+      );
+      return {
+        startDate: new Date(result.start_date as string),
+        endDate: new Date(result.end_date as string),
+        groupBy: result.group_by as BillingGroupBy,
+        lines: (result.lines as Record<string, unknown>[]).map(line => ({
+          key: line.key as string,
+          label: line.label as string,
+          billableMinutes: line.billable_minutes as number,
+          hourlyRate: (line.hourly_rate as number | null) ?? undefined,
+          amount: line.amount as number,
+        })),
+        totalBillableMinutes: result.total_billable_minutes as number,
+        totalAmount: result.total_amount as number,
+      };
+    } catch (error) {
+      throw new Error(`Failed to get billing report: ${error}`);
+    }
+  }
+
+  /**
+   * The billing report as CSV, with a total row at the end
+   */
+  async exportBillingReport(
+    startDate: Date,
+    endDate: Date,
+    groupBy: BillingGroupBy
+  ): Promise<string> {
+    try {
+      return await invoke<string>('export_billing_report', {
+        startDate: startDate.toISOString(),
+        endDate: endDate.toISOString(),
+        groupBy,
+      });
+    } catch (error) {
+      throw new Error(`Failed to export billing report: ${error}`);
+    }
+  }
+
+  /**
+   * Finished sessions in a date range by day and task, with task list, tags
+   * and notes, as CSV or JSON
+   */
+  async exportTimesheet(
+    start: Date,
+    end: Date,
+    format: 'csv' | 'json'
+  ): Promise<string> {
+    try {
+      return await invoke<string>('export_timesheet', {
+        start: start.toISOString(),
+        end: end.toISOString(),
+        format,
+      });
+    } catch (error) {
+      throw new Error(`Failed to export timesheet: ${error}`);
+    }
+  }
+
+  /**
+   * Check off a subtask of the session's task, recording it against the session
+   */
+  async completeSubtaskDuringSession(
+    sessionId: string,
+    subtaskId: string
+  ): Promise<SessionSubtaskCompletion> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'complete_subtask_during_session',
+        { sessionId, subtaskId }
+      );
+      return {
+        id: result.id as string,
+        sessionId: result.session_id as string,
+        subtaskId: result.subtask_id as string,
+        completedAt: new Date(result.completed_at as string),
+      };
+    } catch (error) {
+      throw new Error(`Failed to complete subtask during session: ${error}`);
+    }
+  }
+
   /**
    * Stop session and mark as completed
    */
@@ -539,7 +917,7 @@ export class TimeTrackingService {
   /**
    * Transform session data from backend format to frontend format
    */
-  private transformSessionFromBackend(
+  transformSessionFromBackend(
     backendSession: Record<string, unknown>
   ): TimerSession {
     return {
@@ -552,11 +930,34 @@ export class TimeTrackingService {
       pausedTime: (backendSession.paused_time as number) || 0,
       isActive: (backendSession.is_active as boolean) || false,
       notes: (backendSession.notes as string) || '',
-      breaks: this.parseJsonField(backendSession.breaks as string | null, []),
+      breaks: this.transformBreaksFromBackend(
+        backendSession.id as string,
+        this.parseJsonField<Record<string, unknown>[]>(
+          backendSession.breaks as string | null,
+          []
+        )
+      ),
       createdAt: new Date(backendSession.created_at as string),
     };
   }
 
+  /**
+   * Transform stored breaks; a running break is shown as lasting until now
+   */
+  private transformBreaksFromBackend(
+    sessionId: string,
+    breaks: Record<string, unknown>[]
+  ): TimerBreak[] {
+    return breaks.map((breakItem, index) => ({
+      id: `${sessionId}-break-${index}`,
+      startTime: new Date(breakItem.start_time as string),
+      endTime: breakItem.end_time
+        ? new Date(breakItem.end_time as string)
+        : new Date(),
+      reason: (breakItem.reason as string) || '',
+    }));
+  }
+
   /**
    * Parse JSON field with fallback
    */
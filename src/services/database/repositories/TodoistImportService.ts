@@ -0,0 +1,102 @@
+// Todoist import service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  Priority,
+  TaskImportError,
+  TodoistImportPreview,
+  TodoistImportResult,
+  TodoistProject,
+  TodoistTask,
+} from '../../../types';
+
+export class TodoistImportService {
+  /**
+   * Read a Todoist project CSV or backup zip and report what importing it
+   * would create, without changing anything
+   */
+  async preview(filePath: string): Promise<TodoistImportPreview> {
+    try {
+      const result = await invoke<Record<string, unknown>>(
+        'preview_todoist_import',
+        { filePath }
+      );
+      const projects = result.projects as Record<string, unknown>[];
+      return {
+        projects: projects.map(project =>
+          this.transformProjectFromBackend(project)
+        ),
+        taskCount: result.task_count as number,
+        periodicCount: result.periodic_count as number,
+        duplicateCount: result.duplicate_count as number,
+        newTaskLists: result.new_task_lists as string[],
+        errors: result.errors as TaskImportError[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to preview Todoist import: ${error}`);
+    }
+  }
+
+  /**
+   * Import a Todoist export, skipping tasks already in their project's list
+   */
+  async import(filePath: string): Promise<TodoistImportResult> {
+    try {
+      const result = await invoke<Record<string, unknown>>('import_todoist', {
+        filePath,
+      });
+      return {
+        createdTaskListIds: result.created_task_list_ids as string[],
+        importedTaskIds: result.imported_task_ids as string[],
+        periodicTemplateIds: result.periodic_template_ids as string[],
+        skippedDuplicates: result.skipped_duplicates as number,
+        errors: result.errors as TaskImportError[],
+      };
+    } catch (error) {
+      throw new Error(`Failed to import from Todoist: ${error}`);
+    }
+  }
+
+  /**
+   * Transform a project from backend format to frontend format
+   */
+  private transformProjectFromBackend(
+    project: Record<string, unknown>
+  ): TodoistProject {
+    const tasks = project.tasks as Record<string, unknown>[];
+    return {
+      name: project.name as string,
+      taskListId: (project.task_list_id as string) || undefined,
+      tasks: tasks.map(task => this.transformTaskFromBackend(task)),
+    };
+  }
+
+  /**
+   * Transform a task from backend format to frontend format
+   */
+  private transformTaskFromBackend(task: Record<string, unknown>): TodoistTask {
+    const recurrence = task.recurrence as Record<string, unknown> | null;
+    return {
+      line: task.line as number,
+      title: task.title as string,
+      description: (task.description as string) || undefined,
+      priority: task.priority as Priority,
+      section: (task.section as string) || undefined,
+      tags: task.tags as string[],
+      dueDate: task.due_date ? new Date(task.due_date as string) : undefined,
+      recurrence: recurrence
+        ? {
+            dueString: recurrence.due_string as string,
+            recurrenceType: recurrence.recurrence_type as string,
+            recurrenceInterval: recurrence.recurrence_interval as number,
+            recurrenceRule: (recurrence.recurrence_rule as string) || undefined,
+            startDate: new Date(recurrence.start_date as string),
+          }
+        : undefined,
+      timeEstimate: (task.time_estimate as number | null) ?? undefined,
+      notes: task.notes as string[],
+      parent: (task.parent as number | null) ?? undefined,
+      duplicate: task.duplicate as boolean,
+      warnings: task.warnings as string[],
+    };
+  }
+}
@@ -0,0 +1,112 @@
+// Webhook service that interfaces with Tauri commands (SeaORM backend)
+import { invoke } from '@tauri-apps/api/core';
+import {
+  CreatedWebhook,
+  Webhook,
+  WebhookDelivery,
+  WebhookEvent,
+} from '../../../types';
+
+export class WebhookService {
+  /**
+   * Register a webhook; the signing secret is only returned here
+   */
+  async create(url: string, events: WebhookEvent[]): Promise<CreatedWebhook> {
+    try {
+      const result = await invoke<{
+        webhook: Record<string, unknown>;
+        secret: string;
+      }>('create_webhook', { request: { url, events } });
+      return {
+        webhook: this.transformWebhookFromBackend(result.webhook),
+        secret: result.secret,
+      };
+    } catch (error) {
+      throw new Error(`Failed to create webhook: ${error}`);
+    }
+  }
+
+  async findAll(): Promise<Webhook[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>('get_webhooks');
+      return result.map(webhook => this.transformWebhookFromBackend(webhook));
+    } catch (error) {
+      throw new Error(`Failed to get webhooks: ${error}`);
+    }
+  }
+
+  async update(
+    id: string,
+    request: { url?: string; events?: WebhookEvent[]; isActive?: boolean }
+  ): Promise<Webhook> {
+    try {
+      const result = await invoke<Record<string, unknown>>('update_webhook', {
+        id,
+        request: {
+          url: request.url,
+          events: request.events,
+          is_active: request.isActive,
+        },
+      });
+      return this.transformWebhookFromBackend(result);
+    } catch (error) {
+      throw new Error(`Failed to update webhook: ${error}`);
+    }
+  }
+
+  async delete(id: string): Promise<void> {
+    try {
+      await invoke<string>('delete_webhook', { id });
+    } catch (error) {
+      throw new Error(`Failed to delete webhook: ${error}`);
+    }
+  }
+
+  /**
+   * Delivery log of a webhook, newest first
+   */
+  async getDeliveries(
+    webhookId: string,
+    limit?: number
+  ): Promise<WebhookDelivery[]> {
+    try {
+      const result = await invoke<Record<string, unknown>[]>(
+        'get_webhook_deliveries',
+        { webhookId, limit }
+      );
+      return result.map(delivery => ({
+        id: delivery.id as string,
+        webhookId: delivery.webhook_id as string,
+        event: delivery.event as WebhookEvent,
+        payload: delivery.payload as string,
+        status: delivery.status as WebhookDelivery['status'],
+        attempts: delivery.attempts as number,
+        responseStatus:
+          (delivery.response_status as number | null) ?? undefined,
+        error: (delivery.error as string | null) ?? undefined,
+        nextAttemptAt: delivery.next_attempt_at
+          ? new Date(delivery.next_attempt_at as string)
+          : undefined,
+        createdAt: new Date(delivery.created_at as string),
+        deliveredAt: delivery.delivered_at
+          ? new Date(delivery.delivered_at as string)
+          : undefined,
+      }));
+    } catch (error) {
+      throw new Error(`Failed to get webhook deliveries: ${error}`);
+    }
+  }
+
+  private transformWebhookFromBackend(
+    webhook: Record<string, unknown>
+  ): Webhook {
+    return {
+      id: webhook.id as string,
+      url: webhook.url as string,
+      events: JSON.parse((webhook.events as string) || '[]'),
+      isActive: webhook.is_active as boolean,
+      createdAt: new Date(webhook.created_at as string),
+      updatedAt: new Date(webhook.updated_at as string),
+    };
+  }
+}
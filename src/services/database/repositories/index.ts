@@ -8,6 +8,26 @@ import { LogStorageService } from './LogStorageService';
 import { EmotionalIntelligenceRepository } from './EmotionalIntelligenceRepository';
 import { ThreadService } from './ThreadService';
 import { PeriodicTaskService } from './PeriodicTaskService';
+import { ApiTokenService } from './ApiTokenService';
+import { ReminderService } from './ReminderService';
+import { ChecklistService } from './ChecklistService';
+import { AttachmentService } from './AttachmentService';
+import { TaskNoteService } from './TaskNoteService';
+import { DailyNoteService } from './DailyNoteService';
+import { OperationService } from './OperationService';
+import { TaskImportService } from './TaskImportService';
+import { TimeImportService } from './TimeImportService';
+import { ConversationExportService } from './ConversationExportService';
+import { TodoistImportService } from './TodoistImportService';
+import { CalendarExportService } from './CalendarExportService';
+import { CalDavSyncService } from './CalDavSyncService';
+import { GitHubService } from './GitHubService';
+import { WebhookService } from './WebhookService';
+import { AIPersonaService } from './AIPersonaService';
+import { AISuggestionService } from './AISuggestionService';
+import { PreferencesService } from './PreferencesService';
+import { GoalService } from './GoalService';
+import { ProjectService } from './ProjectService';
 
 // Singleton instances
 let taskService: TaskService | null = null;
@@ -20,6 +40,26 @@ let emotionalIntelligenceRepository: EmotionalIntelligenceRepository | null =
   null;
 let threadService: ThreadService | null = null;
 let periodicTaskService: PeriodicTaskService | null = null;
+let apiTokenService: ApiTokenService | null = null;
+let reminderService: ReminderService | null = null;
+let checklistService: ChecklistService | null = null;
+let attachmentService: AttachmentService | null = null;
+let taskNoteService: TaskNoteService | null = null;
+let dailyNoteService: DailyNoteService | null = null;
+let operationService: OperationService | null = null;
+let taskImportService: TaskImportService | null = null;
+let timeImportService: TimeImportService | null = null;
+let conversationExportService: ConversationExportService | null = null;
+let todoistImportService: TodoistImportService | null = null;
+let calendarExportService: CalendarExportService | null = null;
+let calDavSyncService: CalDavSyncService | null = null;
+let gitHubService: GitHubService | null = null;
+let webhookService: WebhookService | null = null;
+let aiPersonaService: AIPersonaService | null = null;
+let aiSuggestionService: AISuggestionService | null = null;
+let preferencesService: PreferencesService | null = null;
+let goalService: GoalService | null = null;
+let projectService: ProjectService | null = null;
 
 // Export services
 export { TaskService } from './TaskService';
@@ -31,6 +71,26 @@ export { LogStorageService } from './LogStorageService';
 export { EmotionalIntelligenceRepository } from './EmotionalIntelligenceRepository';
 export { ThreadService } from './ThreadService';
 export { PeriodicTaskService } from './PeriodicTaskService';
+export { ApiTokenService } from './ApiTokenService';
+export { ReminderService } from './ReminderService';
+export { ChecklistService } from './ChecklistService';
+export { AttachmentService } from './AttachmentService';
+export { TaskNoteService } from './TaskNoteService';
+export { DailyNoteService } from './DailyNoteService';
+export { OperationService } from './OperationService';
+export { TaskImportService } from './TaskImportService';
+export { TimeImportService } from './TimeImportService';
+export { ConversationExportService } from './ConversationExportService';
+export { TodoistImportService } from './TodoistImportService';
+export { CalendarExportService } from './CalendarExportService';
+export { CalDavSyncService } from './CalDavSyncService';
+export { GitHubService } from './GitHubService';
+export { WebhookService } from './WebhookService';
+export { AIPersonaService } from './AIPersonaService';
+export { AISuggestionService } from './AISuggestionService';
+export { PreferencesService } from './PreferencesService';
+export { GoalService } from './GoalService';
+export { ProjectService } from './ProjectService';
 
 /**
  * Get TaskService instance (replaces TaskRepository)
@@ -121,3 +181,203 @@ export function getPeriodicTaskRepository(): PeriodicTaskService {
   }
   return periodicTaskService;
 }
+
+/**
+ * Get ApiTokenService instance
+ */
+export function getApiTokenRepository(): ApiTokenService {
+  if (!apiTokenService) {
+    apiTokenService = new ApiTokenService();
+  }
+  return apiTokenService;
+}
+
+/**
+ * Get ReminderService instance
+ */
+export function getReminderRepository(): ReminderService {
+  if (!reminderService) {
+    reminderService = new ReminderService();
+  }
+  return reminderService;
+}
+
+/**
+ * Get ChecklistService instance
+ */
+export function getChecklistRepository(): ChecklistService {
+  if (!checklistService) {
+    checklistService = new ChecklistService();
+  }
+  return checklistService;
+}
+
+/**
+ * Get AttachmentService instance
+ */
+export function getAttachmentRepository(): AttachmentService {
+  if (!attachmentService) {
+    attachmentService = new AttachmentService();
+  }
+  return attachmentService;
+}
+
+/**
+ * Get TaskNoteService instance
+ */
+export function getTaskNoteRepository(): TaskNoteService {
+  if (!taskNoteService) {
+    taskNoteService = new TaskNoteService();
+  }
+  return taskNoteService;
+}
+
+/**
+ * Get DailyNoteService instance
+ */
+export function getDailyNoteRepository(): DailyNoteService {
+  if (!dailyNoteService) {
+    dailyNoteService = new DailyNoteService();
+  }
+  return dailyNoteService;
+}
+
+/**
+ * Get OperationService instance
+ */
+export function getOperationRepository(): OperationService {
+  if (!operationService) {
+    operationService = new OperationService();
+  }
+  return operationService;
+}
+
+/**
+ * Get TaskImportService instance
+ */
+export function getTaskImportRepository(): TaskImportService {
+  if (!taskImportService) {
+    taskImportService = new TaskImportService();
+  }
+  return taskImportService;
+}
+
+/**
+ * Get TimeImportService instance
+ */
+export function getTimeImportRepository(): TimeImportService {
+  if (!timeImportService) {
+    timeImportService = new TimeImportService();
+  }
+  return timeImportService;
+}
+
+/**
+ * Get ConversationExportService instance
+ */
+export function getConversationExportRepository(): ConversationExportService {
+  if (!conversationExportService) {
+    conversationExportService = new ConversationExportService();
+  }
+  return conversationExportService;
+}
+
+/**
+ * Get TodoistImportService instance
+ */
+export function getTodoistImportRepository(): TodoistImportService {
+  if (!todoistImportService) {
+    todoistImportService = new TodoistImportService();
+  }
+  return todoistImportService;
+}
+
+/**
+ * Get CalendarExportService instance
+ */
+export function getCalendarExportRepository(): CalendarExportService {
+  if (!calendarExportService) {
+    calendarExportService = new CalendarExportService();
+  }
+  return calendarExportService;
+}
+
+/**
+ * Get CalDavSyncService instance
+ */
+export function getCalDavSyncRepository(): CalDavSyncService {
+  if (!calDavSyncService) {
+    calDavSyncService = new CalDavSyncService();
+  }
+  return calDavSyncService;
+}
+
+/**
+ * Get GitHubService instance
+ */
+export function getGitHubRepository(): GitHubService {
+  if (!gitHubService) {
+    gitHubService = new GitHubService();
+  }
+  return gitHubService;
+}
+
+/**
+ * Get WebhookService instance
+ */
+export function getWebhookRepository(): WebhookService {
+  if (!webhookService) {
+    webhookService = new WebhookService();
+  }
+  return webhookService;
+}
+
+/**
+ * Get AIPersonaService instance
+ */
+export function getAIPersonaRepository(): AIPersonaService {
+  if (!aiPersonaService) {
+    aiPersonaService = new AIPersonaService();
+  }
+  return aiPersonaService;
+}
+
+/**
+ * Get AISuggestionService instance
+ */
+export function getAISuggestionRepository(): AISuggestionService {
+  if (!aiSuggestionService) {
+    aiSuggestionService = new AISuggestionService();
+  }
+  return aiSuggestionService;
+}
+
+/**
+ * Get PreferencesService instance
+ */
+export function getPreferencesRepository(): PreferencesService {
+  if (!preferencesService) {
+    preferencesService = new PreferencesService();
+  }
+  return preferencesService;
+}
+
+/**
+ * Get GoalService instance
+ */
+export function getGoalRepository(): GoalService {
+  if (!goalService) {
+    goalService = new GoalService();
+  }
+  return goalService;
+}
+
+/**
+ * Get ProjectService instance
+ */
+export function getProjectRepository(): ProjectService {
+  if (!projectService) {
+    projectService = new ProjectService();
+  }
+  return projectService;
+}
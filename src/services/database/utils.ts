@@ -29,3 +29,16 @@ export function getDatabaseErrorMessage(
 ): string {
   return databaseTranslationFunction(key, variables);
 }
+
+/**
+ * Error code returned by the backend when the database stayed busy/locked after retrying
+ */
+export const DATABASE_BUSY_ERROR = 'DATABASE_BUSY';
+
+/**
+ * Whether an error means the database was busy and the operation can be tried again
+ */
+export function isDatabaseBusyError(error: unknown): boolean {
+  const message = error instanceof Error ? error.message : String(error);
+  return message.includes(DATABASE_BUSY_ERROR);
+}
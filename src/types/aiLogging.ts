@@ -237,6 +237,13 @@ export interface ModelInfo {
   version?: string;
   provider: string;
   parameters?: Record<string, unknown>;
+  failover?: ProviderFailover; // Set when another provider failed first
+}
+
+// A provider that failed a request before it was retried on the next one
+export interface ProviderFailover {
+  from: string;
+  reason: string;
 }
 
 export type DataClassification = 'public' | 'internal' | 'confidential';
@@ -315,3 +322,154 @@ export interface CreateToolExecutionLogRequest {
   success: boolean;
   error?: string;
 }
+
+// A logged tool result, answered again instead of running the tool during a replay
+export interface ReplayToolResult {
+  toolName: string;
+  arguments: string; // JSON serialized arguments
+  result: string; // JSON serialized result
+  success: boolean;
+}
+
+export interface InteractionReplay {
+  interactionLogId: string;
+  modelType: string;
+  sessionId: string;
+  userMessage: string;
+  originalResponse: string;
+  toolResults: ReplayToolResult[];
+}
+
+// Outcome of replaying a logged interaction against another model
+export interface ReplayComparison {
+  id: string;
+  interactionLogId: string;
+  originalModel: string;
+  replayModel: string;
+  prompt: string;
+  originalResponse: string;
+  replayResponse: string;
+  mockedToolCalls: number;
+  unmatchedToolCalls: number;
+  similarity: number; // 0-1 word overlap of the two responses
+  responseTime?: number;
+  error?: string;
+  createdAt: Date;
+}
+
+// A fixed request of the suite run before and after a prompt template change
+export interface PromptEvalCase {
+  id: string;
+  prompt: string;
+  criteria: string; // what the judge looks for in a good response
+}
+
+export interface PromptEvalResult {
+  caseId: string;
+  response: string;
+  judgeScore?: number; // 0-10
+  judgeNotes?: string;
+  error?: string;
+  responseTime?: number;
+}
+
+// One run of the evaluation suite against a version of a prompt template
+export interface PromptEvalRun {
+  id: string;
+  templateName: string;
+  templateHash: string;
+  template: string;
+  model: string;
+  judgeModel?: string;
+  results: PromptEvalResult[];
+  averageScore?: number;
+  createdAt: Date;
+}
+
+export interface PromptDiffLine {
+  kind: 'same' | 'added' | 'removed';
+  text: string;
+}
+
+export interface PromptEvalCaseComparison {
+  caseId: string;
+  prompt: string;
+  beforeResponse: string;
+  afterResponse: string;
+  responseDiff: PromptDiffLine[];
+  similarity: number; // 0-1 word overlap of the two responses
+  beforeScore?: number;
+  afterScore?: number;
+  scoreDelta?: number;
+  beforeError?: string;
+  afterError?: string;
+  regressed: boolean;
+}
+
+// Response diffs and judge scores between two runs of a template
+export interface PromptEvalReport {
+  templateName: string;
+  beforeRunId: string;
+  afterRunId: string;
+  templateChanged: boolean;
+  templateDiff: PromptDiffLine[];
+  cases: PromptEvalCaseComparison[];
+  averageScoreBefore?: number;
+  averageScoreAfter?: number;
+  averageScoreDelta?: number;
+  regressions: string[];
+  missingCases: string[];
+}
+
+export interface RecordProviderRequest {
+  provider: string;
+  model?: string;
+  latency: number; // milliseconds
+  success: boolean;
+  timedOut?: boolean;
+  errorCode?: string;
+  inputTokens?: number;
+  outputTokens?: number;
+}
+
+// Latency and reliability of one AI provider over a date range
+export interface ProviderMetrics {
+  provider: string;
+  requestCount: number;
+  errorCount: number; // includes timeouts
+  timeoutCount: number;
+  errorRate: number; // 0-1
+  averageLatency: number; // milliseconds
+  p95Latency: number; // milliseconds
+  maxLatency: number; // milliseconds
+}
+
+// Estimated remote AI spend this month against the monthly budget
+export interface AiBudgetStatus {
+  month: string; // YYYY-MM
+  budget: number; // US dollars
+  spent: number; // US dollars, estimated from token counts
+  threshold?: 80 | 100; // Highest warning threshold reached
+}
+
+// Estimated spend on one model of a provider over a cost report's period
+export interface ModelCost {
+  provider: string;
+  model?: string;
+  requestCount: number;
+  inputTokens: number;
+  outputTokens: number;
+  cost: number; // US dollars
+}
+
+export type AiCostPeriod = 'day' | 'week' | 'month';
+
+// Estimated AI spend over the current day, week or month, in local time
+export interface AiCostReport {
+  period: AiCostPeriod;
+  start: Date;
+  end: Date;
+  totalCost: number; // US dollars
+  requestCount: number;
+  byModel: ModelCost[];
+}
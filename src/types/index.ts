@@ -15,6 +15,20 @@ export enum TaskStatus {
   CANCELLED = 'cancelled',
 }
 
+export type TaskStatusCategory = 'todo' | 'doing' | 'done';
+
+// A status tasks can be set to; built-in statuses match the TaskStatus values
+export interface TaskStatusDefinition {
+  id: string;
+  name: string;
+  category: TaskStatusCategory;
+  color?: string;
+  order: number;
+  isSystem: boolean;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
 export enum FocusLevel {
   LOW = 'low',
   MEDIUM = 'medium',
@@ -44,6 +58,7 @@ export enum RecurrenceType {
   EVERY_THREE_WEEKS = 'every_three_weeks',
   MONTHLY = 'monthly',
   CUSTOM = 'custom',
+  CRON = 'cron', // Uses recurrenceRule, e.g. "0 9 LW * *" or "0 9 * * 2#2"
 }
 
 // Core Interfaces
@@ -63,13 +78,17 @@ export interface Task {
   tags: string[];
   projectId?: string;
   parentTaskId?: string;
-  subtasks: string[];
+  subtasks: string[]; // Legacy, superseded by ChecklistItem
   taskListId: string; // Foreign key to task list
   // Periodic task properties
   periodicTemplateId?: string; // Foreign key to periodic task template
   isPeriodicInstance: boolean; // Whether this task is generated from a periodic template
   generationDate?: Date; // When this instance was generated from the template
+  effort?: number; // 1-10
+  impact?: number; // 1-10
   completedAt?: Date;
+  archivedAt?: Date; // Archived tasks are hidden from lists but keep their history
+  rescheduleCount?: number; // Times rolled over from a day it wasn't finished on
   createdAt: Date;
   updatedAt: Date;
 }
@@ -98,6 +117,8 @@ export interface CreateTaskRequest {
   periodicTemplateId?: string;
   isPeriodicInstance?: boolean;
   generationDate?: Date;
+  effort?: number; // 1-10
+  impact?: number; // 1-10
 }
 
 export interface UpdateTaskRequest {
@@ -113,6 +134,9 @@ export interface UpdateTaskRequest {
   tags?: string[];
   dependencies?: string[];
   taskListId?: string; // Allow moving tasks between lists
+  effort?: number; // 1-10, 0 clears the score
+  impact?: number; // 1-10, 0 clears the score
+  expectedUpdatedAt?: Date; // Reject the update if the task changed since this time
 }
 
 // Periodic Task Types
@@ -127,9 +151,14 @@ export interface PeriodicTaskTemplate {
   recurrenceType: RecurrenceType;
   recurrenceInterval: number; // For custom intervals (e.g., every 2 weeks)
   recurrenceUnit?: 'days' | 'weeks' | 'months'; // For custom intervals
+  recurrenceRule?: string; // Cron expression for cron recurrence
   startDate: Date;
   nextGenerationDate: Date;
   isActive: boolean;
+  endsAt?: Date; // No occurrences after this date
+  maxOccurrences?: number; // Stop after this many instances
+  pausedUntil?: Date; // No occurrences before this date
+  missedOccurrencePolicy?: MissedOccurrencePolicy; // Defaults to 'catch_up'
   createdAt: Date;
   updatedAt: Date;
 }
@@ -144,7 +173,11 @@ export interface CreatePeriodicTaskRequest {
   recurrenceType: RecurrenceType;
   recurrenceInterval?: number;
   recurrenceUnit?: 'days' | 'weeks' | 'months';
+  recurrenceRule?: string;
   startDate: Date;
+  endsAt?: Date;
+  maxOccurrences?: number;
+  missedOccurrencePolicy?: MissedOccurrencePolicy;
 }
 
 export interface UpdatePeriodicTaskRequest {
@@ -157,7 +190,30 @@ export interface UpdatePeriodicTaskRequest {
   recurrenceType?: RecurrenceType;
   recurrenceInterval?: number;
   recurrenceUnit?: 'days' | 'weeks' | 'months';
+  recurrenceRule?: string;
   isActive?: boolean;
+  endsAt?: Date | null; // null removes the end date
+  maxOccurrences?: number | null; // null removes the limit
+  missedOccurrencePolicy?: MissedOccurrencePolicy;
+}
+
+// What happens to occurrences that fall in a pause once it ends
+export type MissedOccurrencePolicy = 'catch_up' | 'skip';
+
+export interface PeriodicTemplateOccurrences {
+  templateId: string;
+  title: string;
+  endsAt?: Date;
+  maxOccurrences?: number;
+  generatedOccurrences: number;
+  remainingOccurrences: number;
+}
+
+// Result of importing periodic templates from a template pack file
+export interface PeriodicTemplateImportResult {
+  packName: string;
+  imported: number;
+  skipped: string[]; // Templates that already exist
 }
 
 export interface PeriodicTaskInstancesResponse {
@@ -177,16 +233,62 @@ export interface TaskList {
   id: string;
   name: string;
   isDefault: boolean;
+  orderNum?: number; // Position among task lists, lowest first
+  targetBacklogHours?: number; // Max hours a task should wait before being started
+  targetCycleHours?: number; // Max hours from start to completion
+  hourlyRate?: number; // Billed per hour of work; unset when not billable
+  color?: string; // Hex color, e.g. '#3b82f6'
+  icon?: string; // Icon name, e.g. 'Briefcase'
+  isArchived?: boolean; // Hidden from the lists and stats, tasks kept
+  projectId?: string; // Project the list belongs to
   createdAt: Date;
   updatedAt: Date;
 }
 
+// Aging statistics for a task list; durations are in hours
+export interface TaskListAgingStats {
+  taskListId: string;
+  taskListName: string;
+  targetBacklogHours?: number;
+  targetCycleHours?: number;
+  backlogCount: number;
+  avgBacklogHours?: number;
+  maxBacklogHours?: number;
+  inProgressCount: number;
+  avgInProgressHours?: number;
+  maxInProgressHours?: number;
+  completedCount: number;
+  avgCycleHours?: number;
+  backlogBreaches: number;
+  inProgressBreaches: number;
+  completedBreaches: number;
+}
+
+// What a task breakdown groups tasks by
+export type BreakdownGroup = 'list' | 'tag';
+
+// Task counts and time for one task list or tag; times are in minutes
+export interface TaskBreakdown {
+  id: string; // Task list or tag ID
+  name: string;
+  totalTasks: number;
+  statusCounts: Record<string, number>; // Keyed by status name
+  overdueCount: number;
+  estimatedMinutes: number;
+  actualMinutes: number;
+  lastActivityAt?: Date; // Unset when there are no tasks
+}
+
 export interface CreateTaskListRequest {
   name: string;
 }
 
+// Unset fields are left alone; an empty color or icon clears it
 export interface UpdateTaskListRequest {
-  name: string;
+  name?: string;
+  color?: string;
+  icon?: string;
+  projectId?: string; // An empty ID takes the list out of its project
 }
 
 // Special task list identifiers
@@ -202,7 +304,7 @@ export interface TaskListSelection {
 
 // Task list service interface for frontend-backend communication
 export interface TaskListService {
-  getAllTaskLists(): Promise<TaskList[]>;
+  getAllTaskLists(includeArchived?: boolean): Promise<TaskList[]>;
   createTaskList(request: CreateTaskListRequest): Promise<TaskList>;
   updateTaskList(id: string, request: UpdateTaskListRequest): Promise<TaskList>;
   deleteTaskList(id: string): Promise<void>;
@@ -240,6 +342,73 @@ export interface CompletedSession {
   createdAt: Date;
 }
 
+// A subtask checked off while a timer session was running
+export interface SessionSubtaskCompletion {
+  id: string;
+  sessionId: string;
+  subtaskId: string;
+  completedAt: Date;
+}
+
+export type PauseReason = 'meeting' | 'lunch' | 'interruption' | 'other';
+
+export interface SessionPause {
+  id: string;
+  sessionId: string;
+  reason?: PauseReason | 'idle'; // 'idle' for discarded idle time
+  startedAt: Date;
+  endedAt?: Date; // Unset while the session is paused
+}
+
+// Time away from the computer while a session was running
+export interface IdleSegment {
+  sessionId: string;
+  startedAt: Date; // Last input before going idle
+  endedAt?: Date; // First input after coming back
+}
+
+export interface PauseStats {
+  pauseCount: number;
+  pauseMinutes: number;
+  interruptionCount: number;
+  interruptionMinutes: number;
+}
+
+// How often and how long sessions were paused; days are in local time
+export interface InterruptionReport extends PauseStats {
+  startDate: Date;
+  endDate: Date;
+  byReason: { reason?: PauseReason; count: number; minutes: number }[];
+  byDay: (PauseStats & { date: string })[]; // YYYY-MM-DD, earliest first
+  byTaskList: (PauseStats & { taskListId?: string })[];
+}
+
+// Tracked work and completed tasks on one local day, for calendar heatmaps
+export interface DailyTimeAggregate {
+  date: string; // YYYY-MM-DD
+  workMinutes: number;
+  sessionCount: number;
+  completedTasks: number;
+}
+
+export type BillingGroupBy = 'task_list' | 'task' | 'day';
+
+// Billable work on task lists with an hourly rate; days are in local time
+export interface BillingReport {
+  startDate: Date;
+  endDate: Date;
+  groupBy: BillingGroupBy;
+  lines: {
+    key: string; // Task list or task ID, or the day as YYYY-MM-DD
+    label: string;
+    billableMinutes: number;
+    hourlyRate?: number; // Unset for a day that mixes rates
+    amount: number;
+  }[];
+  totalBillableMinutes: number;
+  totalAmount: number;
+}
+
 export interface FocusSession {
   id: string;
   taskId: string;
@@ -256,6 +425,23 @@ export interface FocusSession {
   completedAt?: Date;
 }
 
+// Focus sessions started in a period; durations are in minutes
+export interface FocusStatistics {
+  totalSessions: number;
+  totalPlannedMinutes: number;
+  totalActualMinutes: number;
+  averageFocusScore: number;
+  completionRate: number; // 0-1
+  mostUsedDistractionLevel: string;
+  sessionsByDay: {
+    date: string; // YYYY-MM-DD
+    sessionCount: number;
+    totalPlannedMinutes: number;
+    totalActualMinutes: number;
+    averageFocusScore: number;
+  }[];
+}
+
 export interface FocusConfig {
   duration: number; // in minutes
   taskId: string;
@@ -271,6 +457,54 @@ export interface AudioConfig {
   url?: string;
 }
 
+// Named focus preset such as Pomodoro; durations are in minutes
+export interface FocusSessionTemplate {
+  id: string;
+  name: string;
+  focusDuration: number;
+  shortBreakDuration: number;
+  longBreakDuration: number;
+  sessionsBeforeLongBreak: number;
+  distractionLevel: DistractionLevel;
+  backgroundAudio?: AudioConfig;
+  isBuiltin: boolean; // Built-in presets cannot be renamed or deleted
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface CreateFocusTemplateRequest {
+  name: string;
+  focusDuration: number;
+  shortBreakDuration: number;
+  longBreakDuration: number;
+  sessionsBeforeLongBreak: number;
+  distractionLevel: DistractionLevel;
+  backgroundAudio?: AudioConfig;
+}
+
+export type UpdateFocusTemplateRequest = Partial<CreateFocusTemplateRequest>;
+
+export type PomodoroPhase = 'work' | 'short_break' | 'long_break';
+
+// Durations in minutes, laid out like a focus template
+export interface PomodoroConfig {
+  focusDuration: number;
+  shortBreakDuration: number;
+  longBreakDuration: number;
+  sessionsBeforeLongBreak: number;
+  distractionLevel: DistractionLevel;
+}
+
+export interface PomodoroState {
+  taskId: string;
+  phase: PomodoroPhase;
+  completedWorkSessions: number;
+  focusSessionId: string; // Current work session, or the one the break follows
+  phaseStartedAt: Date;
+  phaseEndsAt: Date;
+  config: PomodoroConfig;
+}
+
 export interface FocusBreak {
   id: string;
   startTime: Date;
@@ -325,6 +559,26 @@ export interface ProductivityPattern {
   lastUpdated: Date;
 }
 
+// A stored pattern; derived 'hourly' slots are HH:00 and 'daily' slots are
+// weekday names, with scores and confidence from 0 to 1
+export interface RecordedPattern {
+  id: string;
+  patternType: string;
+  timeSlot: string;
+  productivityScore: number;
+  confidenceLevel: number;
+  sampleSize: number;
+  updatedAt: Date;
+}
+
+export interface PatternInsights {
+  mostProductiveHours: string[];
+  leastProductiveHours: string[];
+  bestDaysOfWeek: string[];
+  optimalSessionLength?: number; // in minutes
+  recommendations: string[];
+}
+
 export interface WeeklyPlan {
   id: string;
   weekStart: Date;
@@ -370,6 +624,34 @@ export interface AISuggestion {
   createdAt: Date;
   dismissedAt?: Date;
   appliedAt?: Date;
+  expiresAt?: Date; // Unanswered suggestions are dropped after this
+  interactionLogId?: string; // AI interaction log that made the suggestion
+}
+
+export type SaveAISuggestionRequest = Omit<
+  AISuggestion,
+  'id' | 'createdAt' | 'dismissedAt' | 'appliedAt'
+>;
+
+export interface WorkingHours {
+  start: string; // HH:MM
+  end: string; // HH:MM
+  days?: number[]; // 0 is Sunday; Monday to Friday when unset
+  holidays?: string[]; // YYYY-MM-DD
+}
+
+// Where the user stands in their working week, from the working hours
+export interface WorkdayContext {
+  now: Date;
+  utcOffset: string;
+  today: string; // YYYY-MM-DD
+  workingHours: WorkingHours;
+  isWorkday: boolean;
+  isHoliday: boolean;
+  isWorkingTime: boolean;
+  minutesLeftToday: number;
+  nextWorkingTime?: Date; // Unset during working time
+  upcomingHolidays: string[];
 }
 
 export interface AppContext {
@@ -383,6 +665,27 @@ export interface AppContext {
   recentActivity: ActivityEvent[];
   weeklyPlan?: WeeklyPlan;
   preferences: UserPreferences;
+  personaId?: string; // Persona of the thread being answered
+  conversation?: ConversationMemory; // Earlier turns of the thread
+}
+
+// What the assistant is given of a thread's earlier messages
+export interface ConversationMemory {
+  summary?: string; // Older messages, summarized
+  recentMessages: ConversationTurn[]; // Oldest first
+}
+
+export interface ConversationTurn {
+  role: 'user' | 'assistant';
+  content: string;
+}
+
+// The user's rewrites of Kira's prompts; unset parts use the built-in ones
+export interface PromptTemplates {
+  systemPersona?: string; // Who Kira is, its role and guidelines
+  thought?: string; // How Kira reasons about a request
+  action?: string; // How models without native tool calling call a tool
+  finalAnswer?: string; // How those models give their final answer
 }
 
 export interface ActivityEvent {
@@ -398,15 +701,23 @@ export interface ActivityEvent {
   data: Record<string, unknown>;
 }
 
+// Where unfinished tasks scheduled for a past day go
+export type RolloverMode = 'off' | 'today' | 'backlog';
+
+export interface RolloverReport {
+  mode: RolloverMode;
+  date: string; // YYYY-MM-DD
+  rolledOver: number;
+  taskIds: string[];
+}
+
 export interface UserPreferences {
-  workingHours: {
-    start: string; // HH:MM
-    end: string; // HH:MM
-  };
+  workingHours: WorkingHours;
   breakPreferences: {
     shortBreakDuration: number; // minutes
     longBreakDuration: number; // minutes
     breakInterval: number; // minutes
+    idleThreshold?: number; // minutes without input before asking; 0 is off
   };
   focusPreferences: {
     defaultDuration: number; // minutes
@@ -426,8 +737,16 @@ export interface UserPreferences {
     responseStyle: 'concise' | 'balanced' | 'detailed';
     suggestionFrequency: 'minimal' | 'moderate' | 'frequent';
     showInteractionLogs: boolean;
-    modelType?: 'local' | 'gemini';
+    modelType?: 'local' | 'gemini' | 'ollama';
     geminiApiKey?: string;
+    ollamaUrl?: string; // defaults to Ollama's local port
+    ollamaModel?: string; // defaults to the first downloaded model
+    fallbackChain?: ('gemini' | 'ollama')[]; // retried in order on failure
+    requestTimeout?: number; // seconds, 0 disables the generation watchdog
+    monthlyBudget?: number; // US dollars for remote providers; unset disables
+    budgetAction?: 'warn' | 'block'; // once the budget is spent, default warn
+    batteryThreshold?: number; // percent on battery at which Ollama saves power
+    batteryModel?: string; // smaller Ollama model then; unset defers requests
     localModelConfig?: {
       threads?: number;
       contextSize?: number;
@@ -469,6 +788,14 @@ export interface UserPreferences {
     weekStartDay: 0 | 1; // 0 = Sunday, 1 = Monday
     showCompletedTasks: boolean;
     compactView: boolean;
+    rollover?: RolloverMode; // Defaults to 'off'
+  };
+  syncSettings?: {
+    caldavUrl?: string; // Calendar collection URL
+    caldavUsername?: string;
+    caldavPassword?: string; // Stripped from settings bundles
+    conflictResolution?: ConflictResolution; // Defaults to 'newest_wins'
+    taskListId?: string; // List for tasks created from the server
   };
   migrationSettings: {
     enabled: boolean;
@@ -502,12 +829,70 @@ export interface AIAction {
   reasoning?: string; // LLM's reasoning for choosing this action
 }
 
+export type PersonaTone = 'casual' | 'professional' | 'friendly';
+
+// An assistant persona a thread can answer as
+export interface AIPersona {
+  id: string;
+  name: string;
+  systemPrompt: string; // Added to Kira's base prompt
+  tone: PersonaTone;
+  allowedTools?: string[]; // Unset allows every tool
+  defaultModel?: string; // Unset uses the app's default model
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface CreateAIPersonaRequest {
+  name: string;
+  systemPrompt: string;
+  tone: PersonaTone;
+  allowedTools?: string[];
+  defaultModel?: string;
+}
+
+export interface UpdateAIPersonaRequest
+  extends Partial<CreateAIPersonaRequest> {
+  allowAllTools?: boolean; // Lift the tool restriction
+}
+
 export interface AIResponse {
   message: string;
   actions: AIAction[];
   suggestions: AISuggestion[];
   context: AppContext;
   reasoning?: string;
+  usage?: TokenUsage; // As reported by the provider
+}
+
+// Tokens sent to and received from a provider for a request
+export interface TokenUsage {
+  inputTokens: number;
+  outputTokens: number;
+}
+
+// A step of a ReAct chain, reported while the chain runs
+export interface ReActStep {
+  chainId: string; // requestId of the message the chain answers
+  index: number; // position of the step in the chain
+  kind: 'thought' | 'tool_call' | 'tool_result' | 'final_answer';
+  toolName?: string;
+  content?: string; // truncated thought, arguments or result
+  timestamp: Date;
+}
+
+// Part of a response, streamed while the model writes it
+export interface ResponseChunk {
+  requestId: string; // requestId of the message being answered
+  index: number; // position of the chunk in the response
+  kind: 'token' | 'reset'; // reset drops the text streamed so far
+  text: string;
+}
+
+// Where the machine draws its power from
+export interface PowerState {
+  onBattery: boolean;
+  batteryPercent?: number; // unset without a battery or a readable charge
 }
 
 export interface PatternAnalysis {
@@ -531,6 +916,725 @@ export interface ValidationResult {
   warnings: string[];
 }
 
+// Read as "task <relation> related task"; depends_on and blocks order work
+export type TaskRelationType =
+  | 'depends_on'
+  | 'blocks'
+  | 'relates_to'
+  | 'duplicates'
+  | 'follows';
+
+export interface TaskRelation {
+  id: string;
+  relationType: TaskRelationType;
+  direction: 'outgoing' | 'incoming'; // Incoming when the queried task is the related one
+  task: Task;
+}
+
+// Who made a change to a task
+export type TaskChangeSource = 'user' | 'ai';
+
+// One recorded change to a task; values are JSON encoded
+export interface TaskAuditEntry {
+  id: string;
+  taskId: string; // Kept after the task is deleted
+  action: 'create' | 'update' | 'delete';
+  field?: string; // Only for updates
+  oldValue?: string;
+  newValue?: string;
+  source: TaskChangeSource;
+  createdAt: Date;
+}
+
+// Where an imported or captured task came from
+export type TaskSourceType =
+  | 'csv'
+  | 'markdown'
+  | 'todoist'
+  | 'toggl'
+  | 'clockify'
+  | 'github'
+  | 'caldav'
+  | 'email'
+  | 'deep_link';
+
+export interface TaskSource {
+  taskId: string;
+  sourceType: TaskSourceType;
+  externalId?: string; // e.g. "owner/repo#12", a CalDAV UID or "line 4"
+  url?: string; // Link or file path to open the original
+  createdAt: Date;
+}
+
+// What a kirapilot:// link asks the app to show
+export interface DeepLinkEvent {
+  action: 'open_task' | 'start_timer';
+  taskId: string;
+}
+
+// A change reversed by undo or applied again by redo
+export interface UndoneTaskChange {
+  changeId: string;
+  entries: TaskAuditEntry[];
+}
+
+// Diagnostics for the task dependency graph
+export interface DependencyGraphReport {
+  taskCount: number;
+  dependencyCount: number;
+  isValid: boolean;
+  cycles: { taskIds: string[]; titles: string[] }[]; // First task repeated at the end
+  danglingDependencies: [string, string][]; // [taskId, dependsOnId]
+}
+
+export interface TagSummary {
+  id: string;
+  name: string;
+  taskCount: number;
+}
+
+// A date read from a phrase such as "tomorrow 3pm" or "in 2 weeks"
+export interface ParsedDate {
+  date: Date;
+  matched: string; // The part of the input the date was read from
+  hasTime: boolean; // False when only a day was given (local midnight)
+}
+
+export type ScheduleState = 'ready' | 'blocked' | 'done' | 'cyclic';
+
+export interface ScheduledTask {
+  taskId: string;
+  title: string;
+  state: ScheduleState;
+  blockedBy: string[];
+  durationMinutes: number;
+  earliestStart?: Date;
+  earliestFinish?: Date;
+  slackMinutes?: number;
+  isCritical: boolean;
+}
+
+export interface DependencySchedule {
+  generatedAt: Date;
+  tasks: ScheduledTask[];
+  criticalPath: string[]; // Task IDs, first task first
+  criticalPathMinutes: number;
+  projectedFinish?: Date;
+}
+
+// Scheduled work of one day against the working time available
+export interface WorkloadDay {
+  date: string; // YYYY-MM-DD
+  capacityMinutes: number;
+  scheduledMinutes: number;
+  balancedMinutes: number; // After the suggested moves
+  taskIds: string[];
+  overloaded: boolean;
+}
+
+export interface SuggestedMove {
+  taskId: string;
+  title: string;
+  fromDate: string; // YYYY-MM-DD
+  toDate: string;
+  durationMinutes: number;
+}
+
+export interface WorkloadReport {
+  generatedAt: Date;
+  days: WorkloadDay[];
+  suggestedMoves: SuggestedMove[];
+  unresolvedDays: string[]; // Still overloaded after the suggested moves
+}
+
+// Proposed schedule for the rest of today
+export type PlanReason =
+  | 'overdue'
+  | 'due_today'
+  | 'scheduled_today'
+  | 'critical_path'
+  | 'priority';
+
+export type DeferReason = 'blocked' | 'cyclic' | 'no_time_left';
+
+export interface PlannedBlock {
+  taskId: string;
+  title: string;
+  start: Date;
+  end: Date;
+  durationMinutes: number;
+  priority: Priority;
+  reason: PlanReason;
+}
+
+export interface DeferredTask {
+  taskId: string;
+  title: string;
+  reason: DeferReason;
+}
+
+export interface DailyPlan {
+  date: string; // YYYY-MM-DD
+  generatedAt: Date;
+  isWorkday: boolean;
+  availableMinutes: number; // Working minutes left today
+  plannedMinutes: number;
+  blocks: PlannedBlock[];
+  deferred: DeferredTask[]; // Due or scheduled today but not planned
+}
+
+// A daily or weekly target, e.g. four hours of tracked time a day
+export type GoalMetric =
+  | 'tracked_minutes'
+  | 'focus_minutes'
+  | 'completed_tasks';
+
+export type GoalPeriod = 'day' | 'week';
+
+export interface Goal {
+  id: string;
+  title: string;
+  metric: GoalMetric;
+  target: number; // Minutes or tasks per period
+  period: GoalPeriod;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface CreateGoalRequest {
+  title: string;
+  metric: GoalMetric;
+  target: number;
+  period: GoalPeriod;
+}
+
+// Where a goal stands in its current day or week (from Monday)
+export interface GoalProgress {
+  goal: Goal;
+  periodStart: Date;
+  periodEnd: Date;
+  current: number;
+  target: number;
+  remaining: number; // 0 once the target is met
+  isMet: boolean;
+  currentStreak: number; // Includes the current period once met
+  bestStreak: number;
+  atRisk: boolean; // The streak ends unless the target is met soon
+}
+
+export type ProjectStatus = 'active' | 'on_hold' | 'completed' | 'cancelled';
+
+// Groups task lists; tasks can also be linked to a project directly
+export interface Project {
+  id: string;
+  name: string;
+  description?: string;
+  status: ProjectStatus;
+  targetDate?: Date;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface CreateProjectRequest {
+  name: string;
+  description?: string;
+  status?: ProjectStatus; // Defaults to 'active'
+  targetDate?: Date;
+}
+
+// Unset fields are left alone; an empty description clears it
+export interface UpdateProjectRequest {
+  name?: string;
+  description?: string;
+  status?: ProjectStatus;
+  targetDate?: Date;
+  clearTargetDate?: boolean;
+}
+
+// Rollup of a project's tasks, from its lists and direct links; in minutes
+export interface ProjectProgress {
+  project: Project;
+  taskListCount: number;
+  totalTasks: number;
+  completedTasks: number; // Tasks in a done status
+  percentComplete: number; // 0 to 100
+  overdueTasks: number;
+  estimatedMinutes: number;
+  actualMinutes: number;
+  isPastTarget: boolean; // Target date passed while active or on hold
+}
+
+// Estimated against actual time of completed tasks
+export interface EstimationAccuracy {
+  taskCount: number;
+  estimatedMinutes: number;
+  actualMinutes: number;
+  ratio: number; // Actual over estimated; above 1 took longer than planned
+  accurateCount: number; // Within 20% of the estimate
+  underestimatedCount: number;
+  overestimatedCount: number;
+  chronicUnderestimation: boolean;
+}
+
+export interface EstimationGroup extends EstimationAccuracy {
+  key: string; // Tag name, list ID, priority number or YYYY-MM
+  label: string;
+}
+
+export interface EstimationReport {
+  generatedAt: Date;
+  startDate: Date;
+  endDate: Date;
+  overall: EstimationAccuracy;
+  byTag: EstimationGroup[]; // Most underestimated first
+  byTaskList: EstimationGroup[];
+  byPriority: EstimationGroup[];
+  byMonth: EstimationGroup[]; // Oldest month first
+}
+
+// Which backlog tasks need grooming; unset rules use the backend defaults
+export interface GroomingRules {
+  minAgeDays?: number; // Untouched this long counts as stale (default 30)
+  minPriority?: Priority; // Default high
+  includeBlocking?: boolean; // Tasks open tasks depend on (default true)
+  taskListId?: string;
+  limit?: number; // Default 20
+}
+
+export type GroomingReason =
+  | { kind: 'stale'; ageDays: number }
+  | { kind: 'high_priority'; priority: Priority }
+  | { kind: 'blocking'; dependentCount: number };
+
+export interface GroomingItem {
+  task: Task;
+  reasons: GroomingReason[];
+  score: number; // Higher comes first
+}
+
+export interface GroomingQueue {
+  generatedAt: Date;
+  backlogCount: number;
+  items: GroomingItem[];
+  reviewTemplateId?: string; // The recurring "Review backlog" task, if created
+}
+
+export type TaskImportFormat = 'csv' | 'markdown';
+
+// Why a row would be skipped by default: an existing task or an earlier row
+export type TaskImportDuplicate =
+  | { kind: 'existing'; taskId: string }
+  | { kind: 'file'; line: number };
+
+export interface TaskImportRow {
+  line: number;
+  title: string;
+  description?: string;
+  priority?: Priority;
+  status?: string;
+  dueDate?: Date;
+  timeEstimate?: number;
+  tags: string[];
+  taskList?: string; // List named in the file
+  parent?: number; // Index of the parent row for nested Markdown items
+  duplicate?: TaskImportDuplicate;
+  warnings: string[];
+}
+
+export interface TaskImportError {
+  line: number;
+  message: string;
+}
+
+export interface TaskImportPreview {
+  format: TaskImportFormat;
+  columns: string[]; // CSV header; empty for Markdown
+  columnMapping: Record<string, string>; // Task field -> CSV column
+  rows: TaskImportRow[];
+  duplicateCount: number;
+  errors: TaskImportError[];
+}
+
+export interface TaskImportMapping {
+  format: TaskImportFormat;
+  columns?: Record<string, string>; // Task field -> CSV column
+  taskListId?: string; // For rows that don't name a list
+  taskLists?: Record<string, string>; // List name in the file -> task list ID
+  tags?: string[]; // Added to every imported task
+  includeDuplicates?: boolean;
+}
+
+export interface TaskImportResult {
+  importedTaskIds: string[];
+  skippedDuplicates: number;
+  errors: TaskImportError[];
+}
+
+export type TimeImportSource = 'toggl' | 'clockify';
+
+export interface TimeImportEntry {
+  line: number;
+  taskTitle: string; // The entry's task, or its description when it has none
+  project?: string; // New tasks go to the list of the same name
+  description?: string;
+  tags: string[];
+  startTime: Date;
+  endTime: Date;
+  durationMinutes: number;
+  taskId?: string; // Existing task the session is logged against
+  alreadyImported: boolean;
+}
+
+export interface TimeImportPreview {
+  source: TimeImportSource;
+  entries: TimeImportEntry[];
+  matchedCount: number; // Entries logged against existing tasks
+  newTaskTitles: string[];
+  alreadyImportedCount: number;
+  errors: TaskImportError[];
+}
+
+export interface TimeImportResult {
+  createdTaskIds: string[];
+  sessionIds: string[];
+  skippedEntries: number; // Entries imported before
+  errors: TaskImportError[];
+}
+
+export interface TodoistRecurrence {
+  dueString: string; // As written in Todoist, e.g. "every 2 weeks"
+  recurrenceType: string;
+  recurrenceInterval: number;
+  recurrenceRule?: string; // Cron expression for "cron" recurrences
+  startDate: Date;
+}
+
+export interface TodoistTask {
+  line: number;
+  title: string;
+  description?: string;
+  priority: Priority;
+  section?: string; // Imported as a tag
+  tags: string[];
+  dueDate?: Date;
+  recurrence?: TodoistRecurrence; // Imported as a periodic template
+  timeEstimate?: number;
+  notes: string[]; // Comments, imported as task notes
+  parent?: number; // Index of the parent task within the project
+  duplicate: boolean;
+  warnings: string[];
+}
+
+export interface TodoistProject {
+  name: string;
+  taskListId?: string; // Existing list; created on import when unset
+  tasks: TodoistTask[];
+}
+
+export interface TodoistImportPreview {
+  projects: TodoistProject[];
+  taskCount: number;
+  periodicCount: number;
+  duplicateCount: number;
+  newTaskLists: string[];
+  errors: TaskImportError[];
+}
+
+export interface TodoistImportResult {
+  createdTaskListIds: string[];
+  importedTaskIds: string[];
+  periodicTemplateIds: string[];
+  skippedDuplicates: number;
+  errors: TaskImportError[];
+}
+
+export interface ConversationExportRequest {
+  threadIds?: string[];
+  interactionIds?: string[]; // Each exported as a single exchange
+  systemPrompt?: string; // Placed at the start of every example
+  redact?: boolean; // Defaults to true
+}
+
+export interface ConversationExportSummary {
+  filePath: string;
+  exampleCount: number; // One JSONL line per conversation
+  messageCount: number;
+  redactionCount: number;
+  skipped: { id: string; reason: string }[];
+}
+
+export interface CalendarExportRange {
+  start: Date;
+  end: Date;
+  includeCompleted?: boolean; // Defaults to false
+  includePeriodic?: boolean; // Periodic templates as recurring events, defaults to true
+}
+
+export interface CalendarExportSummary {
+  filePath: string;
+  scheduledCount: number;
+  dueCount: number; // All-day events on due dates
+  recurringCount: number;
+  skippedTemplates: { id: string; title: string; reason: string }[];
+}
+
+export interface IntegrityReportRecord {
+  id: string;
+  isHealthy: boolean;
+  hasDefaultTaskList: boolean;
+  defaultTaskListId?: string;
+  orphanedTasksCount: number;
+  totalTasks: number;
+  totalTaskLists: number;
+  notified: boolean; // The user was notified about the issues
+  checkedAt: Date;
+}
+
+export interface RestoreRehearsalReport {
+  restorable: boolean; // Restored without errors, all rows present, checks passed
+  createdAt: Date; // When the backup was made
+  version: string;
+  tables: { table: string; expected: number; restored: number }[];
+  integrity?: {
+    isHealthy: boolean;
+    orphanedTasksCount: number;
+    totalTasks: number;
+    totalTaskLists: number;
+  };
+  databaseIssues: string[]; // SQLite integrity and foreign key problems
+  missingAttachmentFiles: string[];
+  errors: string[];
+  warnings: string[];
+}
+
+export type ConflictResolution = 'local_wins' | 'remote_wins' | 'newest_wins';
+
+export interface CalDavConfig {
+  calendarUrl: string;
+  username: string;
+  password: string;
+  conflictResolution?: ConflictResolution; // Defaults to 'newest_wins'
+  taskListId?: string; // List for tasks created from the server
+}
+
+export interface SyncReport {
+  calendarUrl: string;
+  fullSync: boolean; // Every item was compared, not only recent changes
+  pushed: number;
+  pulled: number;
+  created: number; // Tasks created from new remote items
+  deletedRemotely: number;
+  archivedLocally: number;
+  conflicts: { taskId: string; title: string; kept: 'local' | 'remote' }[];
+  errors: string[];
+}
+
+export interface SyncStatus {
+  calendarUrl: string;
+  lastSyncedAt?: Date;
+  itemCount: number;
+}
+
+export interface GitHubSettings {
+  apiUrl: string;
+  taskListId?: string; // List for tasks imported from issues
+  autoRefresh: boolean;
+  hasToken: boolean;
+  lastRefreshedAt?: Date;
+  updatedAt: Date;
+}
+
+export interface SaveGitHubSettingsRequest {
+  token?: string; // Keeps the stored token when omitted
+  apiUrl?: string; // Defaults to https://api.github.com
+  taskListId?: string;
+  autoRefresh?: boolean;
+}
+
+export interface TaskGitHubLink {
+  taskId: string;
+  repository: string; // owner/name
+  issueNumber: number;
+  issueUrl: string;
+  issueState: 'open' | 'closed';
+  syncedAt: Date;
+}
+
+export interface GitHubImportReport {
+  imported: number;
+  alreadyLinked: number;
+  errors: string[];
+}
+
+export interface GitHubRefreshReport {
+  checked: number;
+  completedTasks: number;
+  reopenedTasks: number;
+  closedIssues: number;
+  reopenedIssues: number;
+  errors: string[];
+}
+
+export type ApiTokenScope =
+  | 'read'
+  | 'tasks:write'
+  | 'lists:write'
+  | 'time:write'
+  | 'settings:write'
+  | 'threads:write'
+  | 'data:transfer'
+  | 'admin';
+
+export interface ApiToken {
+  id: string;
+  name: string;
+  scopes: ApiTokenScope[];
+  lastUsedAt?: Date;
+  revokedAt?: Date;
+  createdAt: Date;
+}
+
+export interface IssuedApiToken {
+  token: ApiToken;
+  secret: string; // Only returned once, when the token is issued
+}
+
+export type WebhookEvent =
+  | 'task.completed'
+  | 'timer.stopped'
+  | 'backup.finished';
+
+export interface Webhook {
+  id: string;
+  url: string;
+  events: WebhookEvent[];
+  isActive: boolean;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface CreatedWebhook {
+  webhook: Webhook;
+  secret: string; // Signing key, only returned when the webhook is created
+}
+
+export interface WebhookDelivery {
+  id: string;
+  webhookId: string;
+  event: WebhookEvent;
+  payload: string;
+  status: 'pending' | 'delivered' | 'failed';
+  attempts: number;
+  responseStatus?: number;
+  error?: string;
+  nextAttemptAt?: Date;
+  createdAt: Date;
+  deliveredAt?: Date;
+}
+
+// What a reminder is relative to; 'once' reminders fire at a fixed time
+export type ReminderAnchor = 'due_date' | 'scheduled_date' | 'once';
+
+export interface TaskReminder {
+  id: string;
+  taskId: string;
+  anchor: ReminderAnchor;
+  offsetMinutes: number; // Minutes before the anchor date
+  remindAt?: Date; // Only for 'once' reminders
+  snoozedUntil?: Date;
+  lastFiredFor?: Date;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface ScheduledReminder {
+  reminder: TaskReminder;
+  taskId: string;
+  taskTitle: string;
+  targetAt: Date; // The due date, scheduled date or fixed time
+  fireAt: Date;
+}
+
+// A native notification, shown or dropped by the hourly limit
+export interface NotificationDelivery {
+  id: string;
+  dedupKey: string; // Each alert is sent at most once, also across restarts
+  kind: 'reminder' | 'integrity' | 'goal_streak';
+  title: string;
+  body: string;
+  status: 'shown' | 'rate_limited';
+  createdAt: Date;
+}
+
+// One item of a task's checklist
+export interface ChecklistItem {
+  id: string;
+  taskId: string;
+  title: string;
+  orderNum: number; // Position within the checklist, lowest first
+  completedAt?: Date;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+// A timestamped entry in a task's work log
+export interface TaskNote {
+  id: string;
+  taskId: string;
+  content: string;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface Attachment {
+  id: string;
+  taskId: string;
+  fileName: string; // Name of the file as added by the user
+  mimeType?: string;
+  size: number; // in bytes
+  checksum: string; // sha256:<hex> of the file contents
+  path?: string; // Location of the stored copy, when listed
+  createdAt: Date;
+}
+
+export interface AttachmentCleanup {
+  archivedAttachments: Attachment[]; // Attachments of archived tasks
+  orphanedFiles: string[]; // Stored files no attachment refers to
+  reclaimedBytes: number;
+}
+
+export interface DailyNote {
+  id: string;
+  date: string; // YYYY-MM-DD
+  content: string; // Markdown
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+// A day of the journal with the work done that day
+export interface DailyNoteDay {
+  date: string; // YYYY-MM-DD
+  note?: DailyNote;
+  completedTasks: Task[];
+  sessions: TimerSession[];
+  trackedSeconds: number; // Excluding pauses
+}
+
+export type OperationStatus = 'running' | 'completed' | 'failed';
+
+// A long-running import, export, backup or download
+export interface Operation {
+  id: string;
+  kind: string; // e.g. 'backup_export', 'model_download'
+  label: string;
+  status: OperationStatus;
+  progress?: number; // 0-1, undefined while the amount of work is unknown
+  message?: string;
+  error?: string;
+  startedAt: Date;
+  finishedAt?: Date;
+}
+
 export interface TaskFilters {
   status?: TaskStatus[];
   priority?: Priority[];
@@ -4,6 +4,9 @@ export interface Thread {
   id: string;
   title: string; // Auto-generated from first message
   assignment?: ThreadAssignment;
+  personaId?: string; // Persona the assistant answers as
+  summary?: string; // Rolling summary of the messages up to summarizedUntil
+  summarizedUntil?: Date;
   messageCount: number;
   lastMessageAt?: Date;
   createdAt: Date;
@@ -26,10 +29,36 @@ export interface ThreadMessage {
   actions?: AIAction[];
   suggestions?: AISuggestion[];
   toolExecutions?: ToolExecution[];
+  actionResults?: MessageActionResults;
   timestamp: Date;
   userFeedback?: UserFeedback;
 }
 
+// What an assistant message actually did, kept apart from its text
+export interface MessageActionResults {
+  toolCalls: ToolCallResult[];
+  createdEntities: CreatedEntity[];
+  links: MessageLink[];
+}
+
+export interface ToolCallResult {
+  tool: string;
+  arguments?: Record<string, unknown>;
+  success: boolean;
+  error?: string;
+}
+
+export interface CreatedEntity {
+  entityType: string; // 'task', 'task_list', 'time_session', ...
+  id: string;
+  title?: string;
+}
+
+export interface MessageLink {
+  label: string;
+  url: string;
+}
+
 export interface CreateThreadRequest {
   assignment?: ThreadAssignment;
 }
@@ -39,6 +68,18 @@ export interface UpdateThreadRequest {
   assignment?: ThreadAssignment;
 }
 
+// A past thread about the same thing as a new one
+export interface SimilarThread {
+  thread: Thread;
+  similarity: number; // 0 to 1
+  preview?: string; // Start of the thread's first user message
+}
+
+export interface CreatedThread {
+  thread: Thread;
+  similarThreads: SimilarThread[]; // Best match first
+}
+
 export interface CreateThreadMessageRequest {
   threadId: string;
   type: 'user' | 'assistant';
@@ -47,6 +88,7 @@ export interface CreateThreadMessageRequest {
   actions?: AIAction[];
   suggestions?: AISuggestion[];
   toolExecutions?: ToolExecution[];
+  actionResults?: MessageActionResults;
 }
 
 // Import types from main types file to avoid circular dependencies
@@ -256,6 +256,10 @@ export const UserPreferencesSchema = z.object({
     .object({
       start: TimeFormatSchema,
       end: TimeFormatSchema,
+      days: z.array(z.number().int().min(0).max(6)).min(1).optional(),
+      holidays: z
+        .array(z.string().regex(/^\d{4}-\d{2}-\d{2}$/, 'Invalid date'))
+        .optional(),
     })
     .refine(
       data => {
@@ -283,6 +287,11 @@ export const UserPreferencesSchema = z.object({
       .number()
       .min(15, 'Break interval too short')
       .max(240, 'Break interval too long'),
+    idleThreshold: z
+      .number()
+      .min(0, 'Idle threshold cannot be negative')
+      .max(120, 'Idle threshold too long')
+      .optional(),
   }),
   focusPreferences: z.object({
     defaultDuration: z
@@ -304,8 +313,15 @@ export const UserPreferencesSchema = z.object({
     toolPermissions: z.boolean(),
     responseStyle: z.enum(['concise', 'balanced', 'detailed']),
     suggestionFrequency: z.enum(['minimal', 'moderate', 'frequent']),
-    modelType: z.enum(['local', 'gemini']).optional(),
+    modelType: z.enum(['local', 'gemini', 'ollama']).optional(),
     geminiApiKey: z.string().optional(),
+    ollamaUrl: z.string().url().optional(),
+    ollamaModel: z.string().optional(),
+    fallbackChain: z.array(z.enum(['gemini', 'ollama'])).optional(),
+    monthlyBudget: z.number().positive().optional(),
+    budgetAction: z.enum(['warn', 'block']).optional(),
+    batteryThreshold: z.number().min(1).max(100).optional(),
+    batteryModel: z.string().optional(),
     localModelConfig: z
       .object({
         threads: z.number().min(1).max(32).optional(),
@@ -323,7 +339,19 @@ export const UserPreferencesSchema = z.object({
     weekStartDay: z.union([z.literal(0), z.literal(1)]),
     showCompletedTasks: z.boolean(),
     compactView: z.boolean(),
+    rollover: z.enum(['off', 'today', 'backlog']).optional(),
   }),
+  syncSettings: z
+    .object({
+      caldavUrl: z.string().url().optional(),
+      caldavUsername: z.string().optional(),
+      caldavPassword: z.string().optional(),
+      conflictResolution: z
+        .enum(['local_wins', 'remote_wins', 'newest_wins'])
+        .optional(),
+      taskListId: z.string().optional(),
+    })
+    .optional(),
   dateFormat: z.enum(['DD/MM/YYYY', 'MM/DD/YYYY', 'YYYY-MM-DD']),
   theme: z.enum(['light', 'dark', 'auto']),
   language: z
@@ -487,7 +515,17 @@ export const UpdateTaskListRequestSchema = z.object({
       {
         message: 'Task list name contains invalid characters or format',
       }
-    ),
+    )
+    .optional(),
+  color: z
+    .string()
+    .regex(/^(#[0-9a-fA-F]{6})?$/, 'Color must be a hex color like #3b82f6')
+    .optional(),
+  icon: z
+    .string()
+    .regex(/^[\w-]{0,50}$/, 'Icon must be a name of up to 50 characters')
+    .optional(),
+  projectId: z.string().optional(),
 });
 
 export const TaskListSchema = z.object({